@@ -0,0 +1,81 @@
+//! Build script for vx-api
+//!
+//! With the `builtin-providers` feature, embeds every
+//! `crates/vx-providers/*/provider.star` together with its runtime names and
+//! a digest of all scripts. Without it, the generated tables are empty.
+
+use std::env;
+use std::fs;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::Path;
+use vx_star_metadata::StarMetadata;
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let providers_dir = Path::new(&manifest_dir).join("../vx-providers");
+
+    let mut entries: Vec<(String, String, Vec<String>)> = Vec::new();
+    let mut contents: Vec<(String, String)> = Vec::new();
+    if env::var_os("CARGO_FEATURE_BUILTIN_PROVIDERS").is_some()
+        && let Ok(dir_entries) = fs::read_dir(&providers_dir)
+    {
+        println!("cargo:rerun-if-changed={}", providers_dir.display());
+        for entry in dir_entries.flatten() {
+            let star_path = entry.path().join("provider.star");
+            let Ok(content) = fs::read_to_string(&star_path) else {
+                continue;
+            };
+            println!("cargo:rerun-if-changed={}", star_path.display());
+
+            let dir_name = entry.file_name().to_string_lossy().into_owned();
+            let meta = StarMetadata::parse(&content);
+            let mut names: Vec<String> = meta
+                .runtimes
+                .iter()
+                .flat_map(|rt| rt.name.iter().chain(rt.aliases.iter()).cloned())
+                .collect();
+            if names.is_empty() {
+                names.push(dir_name.clone());
+            }
+            names.sort();
+            names.dedup();
+
+            let path = star_path.display().to_string().replace('\\', "\\\\");
+            entries.push((dir_name.clone(), path, names));
+            contents.push((dir_name, content));
+        }
+    }
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    contents.sort();
+
+    // Digest of all embedded scripts, keying caches derived from them
+    let mut hasher = DefaultHasher::new();
+    for (name, content) in &contents {
+        name.hash(&mut hasher);
+        content.hash(&mut hasher);
+    }
+
+    let mut code = String::from("// Auto-generated by build.rs — DO NOT EDIT\n\n");
+    code.push_str(
+        "/// Embedded provider.star files: `(provider_name, runtime_names, star_content)`\n",
+    );
+    code.push_str("pub static BUILTIN_PROVIDERS: &[(&str, &[&str], &str)] = &[\n");
+    for (name, path, names) in &entries {
+        let names: Vec<String> = names.iter().map(|n| format!("{:?}", n)).collect();
+        code.push_str(&format!(
+            "    ({:?}, &[{}], include_str!(\"{}\")),\n",
+            name,
+            names.join(", "),
+            path
+        ));
+    }
+    code.push_str("];\n\n");
+    code.push_str("/// Digest of all embedded provider.star contents\n");
+    code.push_str(&format!(
+        "pub static BUILTIN_PROVIDERS_DIGEST: &str = \"{:016x}\";\n",
+        hasher.finish()
+    ));
+
+    fs::write(Path::new(&out_dir).join("builtin_providers.rs"), code).unwrap();
+}
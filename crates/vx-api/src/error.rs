@@ -0,0 +1,32 @@
+//! Error type of the vx API
+
+use thiserror::Error;
+
+/// Result type of the vx API
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors returned by the vx API
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// No provider offers the tool
+    #[error("Unknown tool '{0}'")]
+    UnknownTool(String),
+
+    /// The tool does not run on this platform
+    #[error("{tool} is not supported on this platform: {reason}")]
+    UnsupportedPlatform {
+        /// Tool name
+        tool: String,
+        /// Supported platforms, as reported by the provider
+        reason: String,
+    },
+
+    /// A tool specification could not be parsed
+    #[error("Invalid tool specification '{0}' (expected <name> or <name>@<version>)")]
+    InvalidSpec(String),
+
+    /// Anything else: network, filesystem, provider scripts, the tool itself
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
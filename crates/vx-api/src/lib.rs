@@ -0,0 +1,52 @@
+//! # vx-api
+//!
+//! Stable Rust API for embedding vx in other tools.
+//!
+//! This crate wraps what vx-cli wires together — provider registration,
+//! version resolution, installation and execution — behind a small facade,
+//! so Rust programs can use vx as a library instead of running the `vx`
+//! binary. It has no dependency on vx-cli: no argument parsing, prompts or
+//! CLI output. Installed tools share the store (`~/.vx`) with the CLI.
+//!
+//! The items exported here follow semver. Types of the internal crates are
+//! only reachable with the `unstable-internals` feature.
+//!
+//! ## Features
+//!
+//! - `builtin-providers` (default): embed the built-in provider.star files
+//! - `user-providers` (default): load `~/.vx/providers` and `.vx/providers`
+//! - `cdn-acceleration`, `extended-formats`: download options of the CLI
+//! - `unstable-internals`: [`Vx::registry`], [`Vx::context`] and the
+//!   provider wiring shared with vx-cli (`vx_api::providers`)
+//!
+//! ## Example
+//!
+//! ```no_run
+//! # async fn example() -> vx_api::Result<()> {
+//! use vx_api::Vx;
+//!
+//! let vx = Vx::builder()
+//!     .home("/opt/my-tool/vx")
+//!     .user_providers(false)
+//!     .build()
+//!     .await?;
+//!
+//! let version = vx.resolve("node@20").await?;
+//! let node = vx.install(&format!("node@{version}")).await?;
+//! let code = vx.run("node@20", &["-e".into(), "console.log(1)".into()]).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+mod error;
+#[cfg(feature = "unstable-internals")]
+pub mod providers;
+#[cfg(not(feature = "unstable-internals"))]
+mod providers;
+mod spec;
+mod vx;
+
+pub use error::{Error, Result};
+pub use providers::builtin_provider_names;
+pub use spec::ToolSpec;
+pub use vx::{DegradedProvider, Installed, Version, Vx, VxBuilder};
@@ -0,0 +1,351 @@
+//! Provider registration
+//!
+//! The wiring shared by vx-cli and the [`Vx`](crate::Vx) facade: built-in
+//! providers are registered lazily into the [`ProviderRegistry`] together
+//! with user providers and asdf plugins, and the process-wide provider
+//! handles and dependency constraints the executor relies on are initialized
+//! once. Providers that fail to load are recorded and reported by
+//! [`load_failures`].
+//!
+//! Public with the `unstable-internals` feature, without semver guarantees.
+
+// Parts of this module are only used through `unstable-internals`
+#![cfg_attr(not(feature = "unstable-internals"), allow(dead_code))]
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio::sync::OnceCell;
+use vx_paths::{PROJECT_VX_DIR, find_project_root};
+use vx_resolver::{RuntimeDependency, RuntimeMap, RuntimeSpec};
+use vx_runtime::{
+    AsdfPlugin, AsdfProvider, ConstraintRule, ProviderFailure, ProviderRegistry,
+    discover_asdf_plugins,
+};
+use vx_starlark::StarMetadata;
+use vx_starlark::handle::{GLOBAL_REGISTRY, ProviderHandle, global_registry_mut};
+
+include!(concat!(env!("OUT_DIR"), "/builtin_providers.rs"));
+
+static BUILTIN_HANDLES_INIT: OnceCell<()> = OnceCell::const_new();
+
+/// Providers that failed to load outside of a registry factory
+static LOAD_FAILURES: Mutex<Vec<ProviderFailure>> = Mutex::new(Vec::new());
+
+/// Names of the embedded built-in providers
+pub fn builtin_provider_names() -> Vec<&'static str> {
+    BUILTIN_PROVIDERS.iter().map(|(name, _, _)| *name).collect()
+}
+
+/// Embedded built-in providers as `(provider_name, star_content)` pairs
+pub fn builtin_provider_stars() -> impl Iterator<Item = (&'static str, &'static str)> {
+    BUILTIN_PROVIDERS
+        .iter()
+        .map(|(name, _, content)| (*name, *content))
+}
+
+/// Record a provider that failed to load, for [`load_failures`]
+pub fn record_load_failure(provider: &str, error: String) {
+    LOAD_FAILURES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(ProviderFailure::new(provider, error));
+}
+
+/// Providers that failed to load in this process
+///
+/// Covers `provider.star` files whose `ProviderHandle` could not be built and
+/// user providers or asdf plugins skipped during discovery. Failures of
+/// registry factories are tracked by the [`ProviderRegistry`] itself.
+pub fn load_failures() -> Vec<ProviderFailure> {
+    LOAD_FAILURES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// Directories scanned for user providers, lowest precedence first:
+/// `<home>/providers` and `<project>/.vx/providers`
+pub fn user_provider_roots(home: &Path) -> Vec<PathBuf> {
+    let mut roots = vec![home.join("providers")];
+    if let Ok(cwd) = std::env::current_dir()
+        && let Some(project_root) = find_project_root(&cwd)
+    {
+        roots.push(project_root.join(PROJECT_VX_DIR).join("providers"));
+    }
+    roots
+}
+
+/// Directory holding the links of `vx provider dev` sessions
+pub fn dev_provider_links_dir(home: &Path) -> PathBuf {
+    home.join("providers").join(vx_starlark::DEV_LINKS_DIR)
+}
+
+/// Whether the machine policy allows user providers and asdf plugins
+pub(crate) fn user_providers_allowed() -> bool {
+    let policy = vx_config::PolicyConfig::load(&vx_paths::policy_file()).unwrap_or_else(|e| {
+        tracing::warn!("Ignoring machine policy: {}", e);
+        None
+    });
+    let allowed = policy.is_none_or(|p| p.allows_user_providers());
+    if !allowed {
+        tracing::debug!("User providers disabled by machine policy");
+    }
+    allowed
+}
+
+/// Providers found in `roots` and linked by `vx provider dev` sessions
+///
+/// Scripts are validated and sandboxed by
+/// [`vx_starlark::discover_user_providers_checked`]; invalid ones are skipped
+/// and recorded as load failures.
+pub fn discover_user_providers(
+    roots: &[PathBuf],
+    dev_links_dir: Option<&Path>,
+) -> Vec<(String, String)> {
+    let (mut providers, mut skipped) = vx_starlark::discover_user_providers_checked(roots);
+    if let Some(links_dir) = dev_links_dir {
+        let (dev, dev_skipped) = vx_starlark::discover_dev_providers_checked(links_dir);
+        providers.extend(dev);
+        skipped.extend(dev_skipped);
+    }
+    for skip in skipped {
+        tracing::debug!(
+            provider = %skip.name,
+            "Skipping provider {}: {}",
+            skip.path.display(),
+            skip.error
+        );
+        record_load_failure(
+            &skip.name,
+            format!("{}: {}", skip.path.display(), skip.error),
+        );
+    }
+    providers.into_iter().map(|p| (p.name, p.content)).collect()
+}
+
+/// User, project and `vx provider dev` providers of `home`
+pub(crate) fn user_providers(home: &Path) -> Vec<(String, String)> {
+    discover_user_providers(
+        &user_provider_roots(home),
+        Some(&dev_provider_links_dir(home)),
+    )
+}
+
+/// asdf plugins in `plugins_dir`
+///
+/// Plugins named like a provider or runtime (built-in when `builtin`, or one
+/// of `dynamic`) are skipped, so they never shadow a native provider. Invalid
+/// plugins are skipped and recorded as load failures.
+pub fn asdf_plugins(
+    plugins_dir: &Path,
+    builtin: bool,
+    dynamic: &[(String, String)],
+) -> Vec<AsdfPlugin> {
+    let (plugins, skipped) = discover_asdf_plugins(plugins_dir);
+    for (path, error) in skipped {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        record_load_failure(&name, format!("{}: {}", path.display(), error));
+    }
+
+    let mut native: Vec<String> = Vec::new();
+    if builtin {
+        for (name, runtime_names, _) in BUILTIN_PROVIDERS {
+            native.push(name.to_string());
+            native.extend(runtime_names.iter().map(|n| n.to_string()));
+        }
+    }
+    for (name, content) in dynamic {
+        native.push(name.clone());
+        native.extend(runtime_lookup_names(name, content));
+    }
+
+    plugins
+        .into_iter()
+        .filter(|plugin| {
+            let shadowed = native.iter().any(|n| n == plugin.name());
+            if shadowed {
+                tracing::debug!(
+                    "Skipping asdf plugin '{}': a provider with that name exists",
+                    plugin.name()
+                );
+            }
+            !shadowed
+        })
+        .collect()
+}
+
+/// Register the built-in providers (when embedded), `dynamic` providers and
+/// `asdf` plugins into `registry`; providers are only evaluated when a
+/// runtime is requested
+pub fn register(
+    registry: &ProviderRegistry,
+    builtin: bool,
+    dynamic: &[(String, String)],
+    asdf: &[AsdfPlugin],
+) {
+    if builtin {
+        for (name, runtime_names, content) in BUILTIN_PROVIDERS {
+            registry.register_lazy(
+                name.to_string(),
+                runtime_names.iter().map(|n| n.to_string()).collect(),
+                Box::new(move || vx_starlark::create_provider(*name, *content)),
+            );
+        }
+    }
+    for (name, content) in dynamic {
+        let (name, content) = (name.clone(), content.clone());
+        registry.register_lazy(
+            name.clone(),
+            runtime_lookup_names(&name, &content),
+            Box::new(move || vx_starlark::create_provider(&name, &content)),
+        );
+    }
+    for plugin in asdf {
+        let plugin = plugin.clone();
+        let name = plugin.name().to_string();
+        registry.register_lazy(
+            name.clone(),
+            vec![name],
+            Box::new(move || Arc::new(AsdfProvider::new(plugin.clone()))),
+        );
+    }
+}
+
+/// Runtime names and aliases a `provider.star` answers to
+pub fn runtime_lookup_names(provider_name: &str, content: &str) -> Vec<String> {
+    let meta = StarMetadata::parse(content);
+    let mut names: Vec<String> = meta
+        .runtimes
+        .into_iter()
+        .flat_map(|rt| rt.name.into_iter().chain(rt.aliases))
+        .collect();
+    if names.is_empty() {
+        names.push(provider_name.to_string());
+    }
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Initialize the process-wide provider handles
+///
+/// Built-in handles are created once per process; `dynamic` providers are
+/// (re-)registered on every call, so the latest definition wins. Providers
+/// whose handle cannot be built are recorded as load failures.
+pub async fn init_handles(builtin: bool, dynamic: &[(String, String)]) {
+    if builtin {
+        BUILTIN_HANDLES_INIT
+            .get_or_init(|| async {
+                // Parse concurrently, outside of the registry write lock
+                let mut tasks = Vec::with_capacity(BUILTIN_PROVIDERS.len());
+                for (name, _, content) in BUILTIN_PROVIDERS {
+                    tasks.push((
+                        *name,
+                        tokio::spawn(ProviderHandle::from_content(*name, content)),
+                    ));
+                }
+                let mut built = Vec::with_capacity(tasks.len());
+                for (name, task) in tasks {
+                    match task.await {
+                        Ok(result) => built.push((name, result)),
+                        Err(e) => {
+                            tracing::warn!(provider = %name, error = %e, "Provider task failed");
+                            record_load_failure(name, e.to_string());
+                        }
+                    }
+                }
+
+                let mut reg = global_registry_mut().await;
+                for (name, result) in built {
+                    match result {
+                        Ok(handle) => reg.insert_handle(handle),
+                        Err(e) => {
+                            tracing::warn!(provider = %name, error = %e, "Failed to load provider");
+                            record_load_failure(name, e.to_string());
+                        }
+                    }
+                }
+            })
+            .await;
+    }
+
+    let mut reg = global_registry_mut().await;
+    for (name, content) in dynamic {
+        if let Err(e) = reg.register_dynamic(name, content.clone()).await {
+            tracing::warn!(provider = %name, error = %e, "Failed to load provider");
+            record_load_failure(name, e.to_string());
+        }
+    }
+}
+
+/// Initialize the process-wide dependency constraints from the
+/// `constraints = [...]` declarations of the providers
+///
+/// The constraints registry can only be set once per process; later calls
+/// have no effect.
+pub fn init_constraints(builtin: bool, dynamic: &[(String, String)]) {
+    let mut rules: HashMap<String, Vec<ConstraintRule>> = HashMap::new();
+    let mut add = |name: &str, content: &str| {
+        for (runtime, runtime_rules) in vx_starlark::star_constraints(name, content) {
+            rules.entry(runtime).or_default().extend(runtime_rules);
+        }
+    };
+    if builtin {
+        for (name, content) in builtin_provider_stars() {
+            add(name, content);
+        }
+    }
+    for (name, content) in dynamic {
+        add(name, content);
+    }
+    if let Err(e) = vx_runtime::init_constraints_from_star(rules.into_iter().collect()) {
+        tracing::warn!("Failed to initialize constraints registry: {}", e);
+    }
+}
+
+/// The resolver's runtime map, built from the registered provider handles
+/// and `asdf` plugins
+///
+/// Empty until [`init_handles`] ran.
+pub fn runtime_map(asdf: &[AsdfPlugin]) -> RuntimeMap {
+    let Ok(registry) = GLOBAL_REGISTRY.try_read() else {
+        return RuntimeMap::empty();
+    };
+
+    let mut map = RuntimeMap::empty();
+    for (_, handle) in registry.iter() {
+        for meta in handle.runtime_metas() {
+            let mut spec = RuntimeSpec::new(&meta.name, &meta.description);
+            spec.executable = Some(meta.executable.clone());
+            spec.aliases = meta.aliases.clone();
+            spec.priority = meta.priority as i32;
+            spec.command_prefix = meta.command_prefix.clone();
+            // A bundled runtime (uvx) is found in its parent's store (uv)
+            if let Some(parent) = &meta.bundled_with {
+                spec.dependencies.push(
+                    RuntimeDependency::required(
+                        parent.clone(),
+                        format!("{} is bundled with {}", meta.name, parent),
+                    )
+                    .provided_by(parent.clone()),
+                );
+            }
+            // Detection globs for executables outside PATH (MSVC cl.exe)
+            if !meta.system_paths.is_empty() {
+                map.register_system_paths(meta.name.clone(), meta.system_paths.clone());
+            }
+            map.register(spec);
+        }
+    }
+
+    for plugin in asdf {
+        let mut spec = RuntimeSpec::new(plugin.name(), "asdf plugin");
+        spec.executable = Some(plugin.executable().to_string());
+        map.register(spec);
+    }
+    map
+}
@@ -0,0 +1,70 @@
+//! Tool specifications (`node`, `node@20`)
+
+use crate::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A tool name with an optional version request
+///
+/// The version is anything vx accepts on the command line: `20`, `20.11`,
+/// `20.11.0`, `^20`, `lts`, `latest`. Without one, the project's pin (vx.toml)
+/// or the latest version is used.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ToolSpec {
+    /// Tool (runtime) name, e.g. `node`
+    pub name: String,
+    /// Version request, e.g. `20`
+    pub version: Option<String>,
+}
+
+impl ToolSpec {
+    /// A spec for `name` without a version
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: None,
+        }
+    }
+
+    /// Request `version` of the tool
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Parse `name` or `name@version`
+    pub fn parse(spec: &str) -> Result<Self, Error> {
+        let spec = spec.trim();
+        let (name, version) = match spec.split_once('@') {
+            Some((name, version)) => (name, Some(version)),
+            None => (spec, None),
+        };
+        if name.is_empty()
+            || name.chars().any(char::is_whitespace)
+            || version.is_some_and(|v| v.is_empty() || v.contains('@'))
+        {
+            return Err(Error::InvalidSpec(spec.to_string()));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            version: version.map(str::to_string),
+        })
+    }
+}
+
+impl FromStr for ToolSpec {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for ToolSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.version {
+            Some(version) => write!(f, "{}@{}", self.name, version),
+            None => f.write_str(&self.name),
+        }
+    }
+}
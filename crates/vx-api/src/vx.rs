@@ -0,0 +1,314 @@
+//! The [`Vx`] facade and its builder
+
+use crate::error::{Error, Result};
+use crate::providers;
+use crate::spec::ToolSpec;
+use std::path::PathBuf;
+use std::sync::Arc;
+use vx_resolver::{Executor, ResolverConfig};
+use vx_runtime::{AsdfPlugin, ProviderRegistry, Runtime, RuntimeContext};
+
+/// A released version of a tool
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Version {
+    /// Version string, e.g. `20.11.0`
+    pub version: String,
+    /// Long-term support release
+    pub lts: bool,
+    /// Pre-release (alpha, beta, rc, nightly)
+    pub prerelease: bool,
+}
+
+/// An installed tool version
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Installed {
+    /// Tool name
+    pub tool: String,
+    /// Installed version
+    pub version: String,
+    /// Directory the version is installed in
+    pub install_dir: PathBuf,
+    /// Main executable of the tool
+    pub executable: PathBuf,
+    /// `false` when this call downloaded and installed it
+    pub already_installed: bool,
+}
+
+/// A provider that failed to load and is left out of the registry
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DegradedProvider {
+    /// Provider name
+    pub provider: String,
+    /// Why it failed to load
+    pub error: String,
+}
+
+/// Builder of a [`Vx`] instance
+#[derive(Debug, Clone)]
+pub struct VxBuilder {
+    home: Option<PathBuf>,
+    builtin_providers: bool,
+    user_providers: bool,
+    providers: Vec<(String, String)>,
+    system_only: bool,
+}
+
+impl Default for VxBuilder {
+    fn default() -> Self {
+        Self {
+            home: None,
+            builtin_providers: true,
+            user_providers: true,
+            providers: Vec::new(),
+            system_only: false,
+        }
+    }
+}
+
+impl VxBuilder {
+    /// Use `home` instead of `VX_HOME` / `~/.vx` for the store, caches and
+    /// user providers
+    pub fn home(mut self, home: impl Into<PathBuf>) -> Self {
+        self.home = Some(home.into());
+        self
+    }
+
+    /// Register the embedded built-in providers (default: on with the
+    /// `builtin-providers` feature)
+    pub fn builtin_providers(mut self, enabled: bool) -> Self {
+        self.builtin_providers = enabled;
+        self
+    }
+
+    /// Load user and project providers and asdf plugins (default: on with
+    /// the `user-providers` feature)
+    pub fn user_providers(mut self, enabled: bool) -> Self {
+        self.user_providers = enabled;
+        self
+    }
+
+    /// Add a provider from provider.star source, taking precedence over a
+    /// built-in provider of the same name
+    pub fn provider(mut self, name: impl Into<String>, star_content: impl Into<String>) -> Self {
+        self.providers.push((name.into(), star_content.into()));
+        self
+    }
+
+    /// Only run tools found on the system PATH, never install (like
+    /// `vx --use-system-path`)
+    pub fn system_only(mut self, enabled: bool) -> Self {
+        self.system_only = enabled;
+        self
+    }
+
+    /// Create the instance
+    ///
+    /// Provider scripts are only evaluated when one of their tools is used.
+    pub async fn build(self) -> Result<Vx> {
+        let builtin = self.builtin_providers && cfg!(feature = "builtin-providers");
+        let context = match &self.home {
+            Some(home) => vx_runtime_http::create_runtime_context_with_base(home),
+            None => vx_runtime_http::create_runtime_context()?,
+        };
+
+        let home = context.paths.vx_home();
+        let user = self.user_providers
+            && cfg!(feature = "user-providers")
+            && providers::user_providers_allowed();
+        let mut dynamic = if user {
+            providers::user_providers(&home)
+        } else {
+            Vec::new()
+        };
+        dynamic.extend(self.providers);
+        let asdf_plugins = if user {
+            let plugins_dir = vx_paths::VxPaths::with_base_dir(&home).asdf_plugins_dir;
+            providers::asdf_plugins(&plugins_dir, builtin, &dynamic)
+        } else {
+            Vec::new()
+        };
+
+        let registry = ProviderRegistry::new();
+        providers::register(&registry, builtin, &dynamic, &asdf_plugins);
+        providers::init_handles(builtin, &dynamic).await;
+        providers::init_constraints(builtin, &dynamic);
+
+        Ok(Vx {
+            registry,
+            context,
+            asdf_plugins,
+            system_only: self.system_only,
+        })
+    }
+}
+
+/// Embedded vx: resolve, install and run tools
+///
+/// ```no_run
+/// # async fn example() -> vx_api::Result<()> {
+/// let vx = vx_api::Vx::new().await?;
+/// let node = vx.install("node@20").await?;
+/// println!("node {} at {}", node.version, node.executable.display());
+///
+/// let code = vx.run("node@20", &["--version".to_string()]).await?;
+/// assert_eq!(code, 0);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Vx {
+    registry: ProviderRegistry,
+    context: RuntimeContext,
+    asdf_plugins: Vec<AsdfPlugin>,
+    system_only: bool,
+}
+
+impl Vx {
+    /// An instance with the default configuration
+    pub async fn new() -> Result<Self> {
+        Self::builder().build().await
+    }
+
+    /// Configure an instance
+    pub fn builder() -> VxBuilder {
+        VxBuilder::default()
+    }
+
+    /// Names of all known tools, including aliases
+    pub fn tools(&self) -> Vec<String> {
+        let mut names = self.registry.runtime_names();
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Whether a provider offers `tool`
+    pub fn supports(&self, tool: &str) -> bool {
+        self.registry.supports(tool)
+    }
+
+    /// Providers that failed to load, sorted by name
+    ///
+    /// The other providers keep working without them; this is what
+    /// `vx doctor` reports as degraded. Loads every provider.
+    pub fn degraded_providers(&self) -> Vec<DegradedProvider> {
+        for failure in providers::load_failures() {
+            self.registry.record_failure(failure);
+        }
+        self.registry
+            .failed_providers()
+            .into_iter()
+            .map(|failure| DegradedProvider {
+                provider: failure.provider,
+                error: failure.error,
+            })
+            .collect()
+    }
+
+    fn runtime(&self, tool: &str) -> Result<Arc<dyn Runtime>> {
+        let runtime = self
+            .registry
+            .get_runtime(tool)
+            .ok_or_else(|| Error::UnknownTool(tool.to_string()))?;
+        runtime
+            .check_platform_support()
+            .map_err(|reason| Error::UnsupportedPlatform {
+                tool: tool.to_string(),
+                reason,
+            })?;
+        Ok(runtime)
+    }
+
+    /// Released versions of `tool`, as listed by `vx versions`
+    pub async fn versions(&self, tool: &str) -> Result<Vec<Version>> {
+        let runtime = self.runtime(tool)?;
+        let versions = runtime.fetch_versions(&self.context).await?;
+        Ok(versions
+            .into_iter()
+            .map(|v| Version {
+                version: v.version,
+                lts: v.lts,
+                prerelease: v.prerelease,
+            })
+            .collect())
+    }
+
+    /// Resolve a spec (`node`, `node@20`, `node@lts`) to a concrete version
+    ///
+    /// A spec without a version resolves to the latest release.
+    pub async fn resolve(&self, spec: &str) -> Result<String> {
+        let spec = ToolSpec::parse(spec)?;
+        let runtime = self.runtime(&spec.name)?;
+        let request = spec.version.as_deref().unwrap_or("latest");
+        Ok(runtime.resolve_version(request, &self.context).await?)
+    }
+
+    /// Install the version a spec resolves to, unless it is installed
+    pub async fn install(&self, spec: &str) -> Result<Installed> {
+        let spec = ToolSpec::parse(spec)?;
+        let runtime = self.runtime(&spec.name)?;
+        let request = spec.version.as_deref().unwrap_or("latest");
+        let version = runtime.resolve_version(request, &self.context).await?;
+        let result = runtime.install(&version, &self.context).await?;
+        Ok(Installed {
+            tool: spec.name,
+            version: result.version,
+            install_dir: result.install_path,
+            executable: result.executable_path,
+            already_installed: result.already_installed,
+        })
+    }
+
+    /// Installed versions of `tool`
+    pub async fn installed_versions(&self, tool: &str) -> Result<Vec<String>> {
+        let runtime = self.runtime(tool)?;
+        Ok(runtime.installed_versions(&self.context).await?)
+    }
+
+    /// Run a tool like `vx <spec> <args>` and return its exit code
+    ///
+    /// Missing tools and their dependencies are installed first; without a
+    /// version the project's pin (vx.toml in the current directory) applies.
+    /// The tool inherits stdin, stdout and stderr.
+    pub async fn run(&self, spec: &str, args: &[String]) -> Result<i32> {
+        let spec = ToolSpec::parse(spec)?;
+        if !self.system_only && !self.registry.supports(&spec.name) {
+            return Err(Error::UnknownTool(spec.name));
+        }
+
+        let config = if self.system_only {
+            ResolverConfig::default().system_only()
+        } else {
+            ResolverConfig::default()
+        };
+        let executor = Executor::new(
+            config,
+            &self.registry,
+            &self.context,
+            providers::runtime_map(&self.asdf_plugins),
+        )?;
+        Ok(executor
+            .execute_with_with_deps(&spec.name, spec.version.as_deref(), None, args, false, &[])
+            .await?)
+    }
+
+    /// The provider registry
+    ///
+    /// Its types come from internal crates and are not covered by the semver
+    /// guarantees of this crate.
+    #[cfg(feature = "unstable-internals")]
+    pub fn registry(&self) -> &ProviderRegistry {
+        &self.registry
+    }
+
+    /// The runtime context (paths, HTTP client, installer)
+    ///
+    /// Its types come from internal crates and are not covered by the semver
+    /// guarantees of this crate.
+    #[cfg(feature = "unstable-internals")]
+    pub fn context(&self) -> &RuntimeContext {
+        &self.context
+    }
+}
@@ -0,0 +1,132 @@
+//! Tests for the vx-api facade
+
+use vx_api::{Error, ToolSpec, Vx};
+
+const DEMO_PROVIDER: &str = r#"
+load("@vx//stdlib:provider.star", "runtime_def")
+
+name        = "demo-tool"
+description = "Demo tool"
+ecosystem   = "system"
+
+runtimes = [runtime_def("demo-tool", aliases = ["demo"])]
+
+def fetch_versions(_ctx):
+    return [
+        {"version": "1.2.0", "lts": False, "prerelease": False},
+        {"version": "1.1.0", "lts": True, "prerelease": False},
+        {"version": "2.0.0-rc.1", "lts": False, "prerelease": True},
+    ]
+
+def download_url(_ctx, _version):
+    return None
+"#;
+
+async fn demo_vx(home: &std::path::Path) -> Vx {
+    Vx::builder()
+        .home(home)
+        .builtin_providers(false)
+        .user_providers(false)
+        .provider("demo-tool", DEMO_PROVIDER)
+        .build()
+        .await
+        .unwrap()
+}
+
+#[test]
+fn test_tool_spec_parse() {
+    assert_eq!(ToolSpec::parse("node").unwrap(), ToolSpec::new("node"));
+    assert_eq!(
+        "node@20".parse::<ToolSpec>().unwrap(),
+        ToolSpec::new("node").with_version("20")
+    );
+    assert_eq!(
+        ToolSpec::new("go").with_version("1.23").to_string(),
+        "go@1.23"
+    );
+    for invalid in ["", "@20", "node@", "node@1@2", "my tool"] {
+        assert!(
+            matches!(ToolSpec::parse(invalid), Err(Error::InvalidSpec(_))),
+            "{invalid}"
+        );
+    }
+}
+
+#[cfg(feature = "builtin-providers")]
+#[test]
+fn test_builtin_providers_are_embedded() {
+    let names = vx_api::builtin_provider_names();
+    assert!(names.contains(&"node"), "{names:?}");
+    assert!(names.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[tokio::test]
+async fn test_custom_provider_only() {
+    let home = tempfile::tempdir().unwrap();
+    let vx = demo_vx(home.path()).await;
+
+    assert!(vx.supports("demo-tool"));
+    assert!(vx.supports("demo"));
+    assert!(!vx.supports("node"));
+    assert!(vx.tools().contains(&"demo-tool".to_string()));
+}
+
+#[tokio::test]
+async fn test_versions_and_resolve() {
+    let home = tempfile::tempdir().unwrap();
+    let vx = demo_vx(home.path()).await;
+
+    let versions = vx.versions("demo-tool").await.unwrap();
+    assert_eq!(versions.len(), 3);
+    assert!(versions.iter().any(|v| v.version == "1.1.0" && v.lts));
+
+    assert_eq!(vx.resolve("demo-tool@1.1").await.unwrap(), "1.1.0");
+    assert!(vx.installed_versions("demo-tool").await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_unknown_tool() {
+    let home = tempfile::tempdir().unwrap();
+    let vx = demo_vx(home.path()).await;
+
+    assert!(matches!(
+        vx.resolve("no-such-tool").await,
+        Err(Error::UnknownTool(name)) if name == "no-such-tool"
+    ));
+    assert!(matches!(
+        vx.run("no-such-tool", &[]).await,
+        Err(Error::UnknownTool(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_degraded_user_providers_and_asdf_plugins() {
+    let home = tempfile::tempdir().unwrap();
+    let providers = home.path().join("providers");
+    std::fs::create_dir_all(providers.join("demo-tool")).unwrap();
+    std::fs::write(providers.join("demo-tool/provider.star"), DEMO_PROVIDER).unwrap();
+    std::fs::create_dir_all(providers.join("broken-tool")).unwrap();
+    std::fs::write(providers.join("broken-tool/provider.star"), "def (").unwrap();
+    std::fs::create_dir_all(home.path().join("asdf-plugins/no-scripts")).unwrap();
+
+    let vx = Vx::builder()
+        .home(home.path())
+        .builtin_providers(false)
+        .build()
+        .await
+        .unwrap();
+
+    assert!(vx.supports("demo-tool"));
+    assert!(!vx.supports("broken-tool"));
+    assert!(!vx.supports("no-scripts"));
+    let degraded: Vec<String> = vx
+        .degraded_providers()
+        .into_iter()
+        .map(|d| d.provider)
+        .collect();
+    assert!(
+        degraded.contains(&"broken-tool".to_string()),
+        "{degraded:?}"
+    );
+    assert!(degraded.contains(&"no-scripts".to_string()), "{degraded:?}");
+}
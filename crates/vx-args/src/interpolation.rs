@@ -266,9 +266,10 @@ impl Interpolator {
 
     /// Resolve a variable name to its value
     fn resolve_var(&self, name: &str, vars: &dyn VarSource) -> ArgResult<Option<String>> {
-        // Check for env.VAR syntax
+        // Check for env.VAR syntax; callers can supply `env.VAR` themselves
+        // to resolve against an environment other than the process's
         if let Some(env_name) = name.strip_prefix("env.") {
-            return Ok(std::env::var(env_name).ok());
+            return Ok(vars.get(name).or_else(|| std::env::var(env_name).ok()));
         }
 
         // Check built-ins first
@@ -373,6 +374,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_env_var_from_vars() {
+        let interpolator = Interpolator::new();
+        let mut vars = HashMap::new();
+        vars.insert("env.VX_TEST_TASK_ENV".to_string(), "from-task".to_string());
+
+        let result = interpolator
+            .interpolate("{{env.VX_TEST_TASK_ENV}}", &vars)
+            .unwrap();
+        assert_eq!(result, "from-task");
+    }
+
     #[test]
     fn test_missing_var() {
         let interpolator = Interpolator::new();
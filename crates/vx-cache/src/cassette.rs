@@ -0,0 +1,320 @@
+//! Record/replay cassettes for deterministic command runs
+//!
+//! With `VX_RECORD=cassette.json`, every HTTP response vx receives and every
+//! process it runs through its command executor is written to the cassette.
+//! With `VX_REPLAY=cassette.json`, the same command answers those requests
+//! from the cassette instead, without touching the network or spawning the
+//! recorded processes. This makes install and sync flows reproducible in CI
+//! and lets users attach a failing run to a bug report.
+//!
+//! ## Format
+//!
+//! ```json
+//! {
+//!   "format_version": 1,
+//!   "http": [
+//!     { "method": "GET", "url": "https://api.github.com/...", "status": 200,
+//!       "headers": { "content-type": "application/json" }, "body": "[...]" }
+//!   ],
+//!   "processes": [
+//!     { "program": "node", "args": ["--version"], "exit_code": 0, "stdout": "v22.0.0\n" }
+//!   ]
+//! }
+//! ```
+//!
+//! Bodies that are not valid UTF-8 (archives) are stored in `body_base64`.
+//! Interactions are replayed in the order they were recorded: the n-th
+//! request for a URL gets the n-th recorded response, and the last one is
+//! repeated once they run out. Processes match on the program's file name
+//! and the arguments, so store paths under a different home still match.
+
+use crate::file::write_json_file;
+use anyhow::{Context, Result};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+/// Record the command's HTTP traffic and processes into this file
+pub const RECORD_ENV: &str = "VX_RECORD";
+
+/// Answer HTTP requests and processes from this file
+pub const REPLAY_ENV: &str = "VX_REPLAY";
+
+/// Bumped when the cassette layout changes
+pub const CASSETTE_FORMAT_VERSION: u32 = 1;
+
+/// Whether a cassette is being written or read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    Record,
+    Replay,
+}
+
+/// One HTTP response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpInteraction {
+    pub method: String,
+    pub url: String,
+    pub status: u16,
+    /// Final URL after redirects, when it differs from `url`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub final_url: Option<String>,
+    /// Response headers vx reads (content type, validators, disposition)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body_base64: Option<String>,
+}
+
+impl HttpInteraction {
+    pub fn new(
+        method: impl Into<String>,
+        url: impl Into<String>,
+        status: u16,
+        headers: BTreeMap<String, String>,
+        body: &[u8],
+    ) -> Self {
+        let (body, body_base64) = match std::str::from_utf8(body) {
+            Ok(text) => (Some(text.to_string()), None),
+            Err(_) => (None, Some(BASE64.encode(body))),
+        };
+        Self {
+            method: method.into(),
+            url: url.into(),
+            status,
+            final_url: None,
+            headers,
+            body,
+            body_base64,
+        }
+    }
+
+    /// Set the final URL after redirects
+    pub fn with_final_url(mut self, final_url: impl Into<String>) -> Self {
+        let final_url = final_url.into();
+        self.final_url = (final_url != self.url).then_some(final_url);
+        self
+    }
+
+    /// The response body
+    pub fn body(&self) -> Result<Vec<u8>> {
+        match (&self.body, &self.body_base64) {
+            (Some(text), _) => Ok(text.as_bytes().to_vec()),
+            (None, Some(encoded)) => BASE64
+                .decode(encoded)
+                .with_context(|| format!("Invalid body_base64 for {}", self.url)),
+            (None, None) => Ok(Vec::new()),
+        }
+    }
+
+    /// Look up a header by (case-insensitive) name
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// One process run and its result
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProcessInteraction {
+    pub program: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    pub exit_code: i32,
+    /// Captured output (`None` when the process wrote to the terminal)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stdout: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stderr: Option<String>,
+}
+
+/// Everything a cassette file holds
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CassetteData {
+    pub format_version: u32,
+    #[serde(default)]
+    pub http: Vec<HttpInteraction>,
+    #[serde(default)]
+    pub processes: Vec<ProcessInteraction>,
+}
+
+impl Default for CassetteData {
+    fn default() -> Self {
+        Self {
+            format_version: CASSETTE_FORMAT_VERSION,
+            http: Vec::new(),
+            processes: Vec::new(),
+        }
+    }
+}
+
+/// A cassette being recorded or replayed
+#[derive(Debug)]
+pub struct Cassette {
+    mode: CassetteMode,
+    path: PathBuf,
+    data: Mutex<CassetteData>,
+    /// Replay position per request key
+    cursors: Mutex<HashMap<String, usize>>,
+}
+
+impl Cassette {
+    /// Start recording into `path`, replacing any earlier recording
+    pub fn record(path: impl Into<PathBuf>) -> Result<Self> {
+        let cassette = Self::with_data(CassetteMode::Record, path.into(), CassetteData::default());
+        cassette.save(&CassetteData::default())?;
+        Ok(cassette)
+    }
+
+    /// Load `path` for replay
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read cassette {}", path.display()))?;
+        let data: CassetteData = serde_json::from_str(&content)
+            .with_context(|| format!("Invalid cassette {}", path.display()))?;
+        if data.format_version != CASSETTE_FORMAT_VERSION {
+            anyhow::bail!(
+                "Cassette {} has format version {}, this vx reads version {}; record it again",
+                path.display(),
+                data.format_version,
+                CASSETTE_FORMAT_VERSION
+            );
+        }
+        Ok(Self::with_data(CassetteMode::Replay, path, data))
+    }
+
+    /// The cassette selected by `VX_RECORD` or `VX_REPLAY`, if any
+    pub fn from_env() -> Result<Option<Self>> {
+        let var = |name| std::env::var_os(name).filter(|v| !v.is_empty());
+        match (var(RECORD_ENV), var(REPLAY_ENV)) {
+            (Some(_), Some(_)) => {
+                anyhow::bail!("Set either {} or {}, not both", RECORD_ENV, REPLAY_ENV)
+            }
+            (Some(path), None) => Self::record(path).map(Some),
+            (None, Some(path)) => Self::replay(path).map(Some),
+            (None, None) => Ok(None),
+        }
+    }
+
+    fn with_data(mode: CassetteMode, path: PathBuf, data: CassetteData) -> Self {
+        Self {
+            mode,
+            path,
+            data: Mutex::new(data),
+            cursors: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// A copy of the recorded interactions
+    pub fn data(&self) -> CassetteData {
+        self.lock_data().clone()
+    }
+
+    /// Add an HTTP response and write the cassette
+    pub fn record_http(&self, interaction: HttpInteraction) -> Result<()> {
+        let mut data = self.lock_data();
+        data.http.push(interaction);
+        self.save(&data)
+    }
+
+    /// Add a process run and write the cassette
+    pub fn record_process(&self, interaction: ProcessInteraction) -> Result<()> {
+        let mut data = self.lock_data();
+        data.processes.push(interaction);
+        self.save(&data)
+    }
+
+    /// The recorded response to `method url`
+    pub fn replay_http(&self, method: &str, url: &str) -> Option<HttpInteraction> {
+        let data = self.lock_data();
+        let matches: Vec<&HttpInteraction> = data
+            .http
+            .iter()
+            .filter(|i| i.method.eq_ignore_ascii_case(method) && i.url == url)
+            .collect();
+        let index = self.next_index(format!("http {} {}", method, url), matches.len())?;
+        Some(matches[index].clone())
+    }
+
+    /// The recorded result of running `program args`
+    pub fn replay_process(&self, program: &str, args: &[String]) -> Option<ProcessInteraction> {
+        let data = self.lock_data();
+        let name = program_name(program);
+        let matches: Vec<&ProcessInteraction> = data
+            .processes
+            .iter()
+            .filter(|i| program_name(&i.program) == name && i.args == args)
+            .collect();
+        let index = self.next_index(format!("process {} {:?}", name, args), matches.len())?;
+        Some(matches[index].clone())
+    }
+
+    /// Advance the cursor for `key`, staying on the last of `count` matches
+    fn next_index(&self, key: String, count: usize) -> Option<usize> {
+        if count == 0 {
+            return None;
+        }
+        let mut cursors = self.cursors.lock().unwrap_or_else(|e| e.into_inner());
+        let cursor = cursors.entry(key).or_insert(0);
+        let index = (*cursor).min(count - 1);
+        *cursor += 1;
+        Some(index)
+    }
+
+    fn lock_data(&self) -> std::sync::MutexGuard<'_, CassetteData> {
+        self.data.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn save(&self, data: &CassetteData) -> Result<()> {
+        write_json_file(&self.path, data)
+            .with_context(|| format!("Failed to write cassette {}", self.path.display()))
+    }
+}
+
+/// File name of a program, without `.exe`, for matching across machines
+fn program_name(program: &str) -> String {
+    let name = Path::new(program)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| program.to_string());
+    name.strip_suffix(".exe")
+        .map(str::to_string)
+        .unwrap_or(name)
+}
+
+static ACTIVE: OnceLock<Option<Cassette>> = OnceLock::new();
+
+/// Activate the cassette selected by `VX_RECORD` / `VX_REPLAY`
+///
+/// Call this once at startup to surface an unreadable cassette as an error;
+/// [`active`] falls back to ignoring it.
+pub fn init_from_env() -> Result<Option<&'static Cassette>> {
+    if let Some(active) = ACTIVE.get() {
+        return Ok(active.as_ref());
+    }
+    let cassette = Cassette::from_env()?;
+    Ok(ACTIVE.get_or_init(|| cassette).as_ref())
+}
+
+/// The cassette of this process, if recording or replaying
+pub fn active() -> Option<&'static Cassette> {
+    ACTIVE
+        .get_or_init(|| Cassette::from_env().ok().flatten())
+        .as_ref()
+}
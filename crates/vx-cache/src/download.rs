@@ -15,15 +15,19 @@
 //! ~/.vx/cache/downloads/
 //! ├── ab/
 //! │   ├── cd1234567890abcdef...        # Cached file
-//! │   └── cd1234567890abcdef...meta    # Metadata (bincode)
+//! │   ├── cd1234567890abcdef...meta    # Metadata (bincode)
+//! │   └── cd1234567890abcdef...sha256  # SHA-256 of the file contents
 //! └── ef/
 //!     └── gh9876543210fedcba...
 //! ```
 
+use crate::PruneSummary;
+use crate::usage::{self, UsageCounters};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::io::{BufReader, BufWriter};
 use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
 /// Download cache metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,6 +48,21 @@ pub struct DownloadCacheMetadata {
     pub filename: String,
 }
 
+/// One file in the download cache
+#[derive(Debug, Clone)]
+pub struct DownloadCacheEntry {
+    /// Cache key (SHA-256 of the URL)
+    pub key: String,
+    /// Path of the cached file
+    pub path: PathBuf,
+    /// File size in bytes
+    pub size: u64,
+    /// When the file was stored or last looked up
+    pub last_used: SystemTime,
+    /// Metadata, if it is readable
+    pub metadata: Option<DownloadCacheMetadata>,
+}
+
 /// Result of a cache lookup
 #[derive(Debug)]
 pub enum CacheLookupResult {
@@ -68,6 +87,8 @@ pub struct DownloadCache {
     cache_dir: PathBuf,
     /// Whether to use ETag revalidation
     use_etag: bool,
+    /// Hit and miss counts of lookups
+    usage: UsageCounters,
 }
 
 impl DownloadCache {
@@ -77,6 +98,7 @@ impl DownloadCache {
         Self {
             cache_dir: downloads_dir,
             use_etag: true,
+            usage: UsageCounters::new(cache_dir),
         }
     }
 
@@ -116,32 +138,58 @@ impl DownloadCache {
             .join(format!("{}.meta", cache_key))
     }
 
-    /// Look up a URL in the cache
-    pub fn lookup(&self, url: &str) -> CacheLookupResult {
-        let cache_key = Self::cache_key(url);
-        let file_path = self.file_path(&cache_key);
-        let meta_path = self.meta_path(&cache_key);
+    /// Get the content hash path for a cache key
+    fn sha256_path(&self, cache_key: &str) -> PathBuf {
+        self.shard_dir(cache_key)
+            .join(format!("{}.sha256", cache_key))
+    }
 
-        // Check if both file and metadata exist
-        if !file_path.exists() || !meta_path.exists() {
-            return CacheLookupResult::Miss;
-        }
+    /// Look up a complete cache entry by its cache key
+    ///
+    /// Returns `None` when the file or its metadata is missing, or when the
+    /// file size does not match the metadata.
+    pub fn entry(&self, cache_key: &str) -> Option<(PathBuf, DownloadCacheMetadata)> {
+        let file_path = self.file_path(cache_key);
+        let metadata = self.read_metadata(&self.meta_path(cache_key))?;
+        let size = std::fs::metadata(&file_path).ok()?.len();
+        (size == metadata.size).then_some((file_path, metadata))
+    }
 
-        // Read metadata
-        let metadata = match self.read_metadata(&meta_path) {
-            Some(m) => m,
-            None => return CacheLookupResult::Miss,
+    /// SHA-256 of a cached file's contents (hex)
+    ///
+    /// The hash is computed when the file is stored; entries cached before
+    /// hashes were recorded are hashed on first use.
+    pub fn content_sha256(&self, cache_key: &str) -> std::io::Result<Option<String>> {
+        let Some((file_path, _)) = self.entry(cache_key) else {
+            return Ok(None);
         };
-
-        // Verify file size matches
-        if let Ok(file_meta) = std::fs::metadata(&file_path) {
-            if file_meta.len() != metadata.size {
-                // Size mismatch, treat as miss
-                return CacheLookupResult::Miss;
+        let sha256_path = self.sha256_path(cache_key);
+        if let Ok(hash) = std::fs::read_to_string(&sha256_path) {
+            let hash = hash.trim();
+            if hash.len() == 64 {
+                return Ok(Some(hash.to_string()));
             }
-        } else {
-            return CacheLookupResult::Miss;
         }
+        let hash = sha256_file(&file_path)?;
+        crate::atomic_write_string(&sha256_path, &hash).map_err(std::io::Error::other)?;
+        Ok(Some(hash))
+    }
+
+    /// Look up a URL in the cache
+    ///
+    /// Found entries are marked as used, so size-based pruning evicts the
+    /// least recently used downloads first. Each lookup counts as a hit or
+    /// miss in the [`UsageCounters`] of the cache directory.
+    pub fn lookup(&self, url: &str) -> CacheLookupResult {
+        let Some((file_path, metadata)) = self.entry(&Self::cache_key(url)) else {
+            self.usage.increment(usage::DOWNLOAD_CACHE_MISSES);
+            return CacheLookupResult::Miss;
+        };
+        self.usage.increment(usage::DOWNLOAD_CACHE_HITS);
+        let _ = std::fs::File::options()
+            .append(true)
+            .open(&file_path)
+            .and_then(|file| file.set_modified(SystemTime::now()));
 
         // If ETag is available and we use ETag validation
         if self.use_etag && metadata.etag.is_some() {
@@ -212,6 +260,11 @@ impl DownloadCache {
         // Write metadata
         self.write_metadata(&meta_path, &metadata)?;
 
+        // Record the content hash so the entry can be shared with peers
+        let hash = sha256_file(&file_path)?;
+        crate::atomic_write_string(&self.sha256_path(&cache_key), &hash)
+            .map_err(std::io::Error::other)?;
+
         Ok(file_path)
     }
 
@@ -282,6 +335,7 @@ impl DownloadCache {
         if meta_path.exists() {
             std::fs::remove_file(&meta_path)?;
         }
+        let _ = std::fs::remove_file(self.sha256_path(&cache_key));
         Ok(removed)
     }
 
@@ -307,6 +361,83 @@ impl DownloadCache {
         Ok(total_size)
     }
 
+    /// All cached files, least recently used first
+    pub fn entries(&self) -> Vec<DownloadCacheEntry> {
+        let mut entries = Vec::new();
+        let Ok(shards) = std::fs::read_dir(&self.cache_dir) else {
+            return entries;
+        };
+        for shard in shards.flatten() {
+            let Ok(files) = std::fs::read_dir(shard.path()) else {
+                continue;
+            };
+            for file in files.flatten() {
+                let path = file.path();
+                if path.extension().is_some() {
+                    continue; // metadata, hashes and partial writes
+                }
+                let Ok(meta) = file.metadata() else {
+                    continue;
+                };
+                if !meta.is_file() {
+                    continue;
+                }
+                let key = file.file_name().to_string_lossy().into_owned();
+                entries.push(DownloadCacheEntry {
+                    metadata: self.read_metadata(&self.meta_path(&key)),
+                    key,
+                    path,
+                    size: meta.len(),
+                    last_used: meta.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+                });
+            }
+        }
+        entries.sort_by_key(|e| e.last_used);
+        entries
+    }
+
+    /// Remove cached files unused for longer than `older_than`, then the
+    /// least recently used ones until the cache fits in `max_size` bytes
+    ///
+    /// With `dry_run`, only reports what would be removed.
+    pub fn prune(
+        &self,
+        older_than: Option<Duration>,
+        max_size: Option<u64>,
+        dry_run: bool,
+    ) -> PruneSummary {
+        let now = SystemTime::now();
+        let entries = self.entries();
+        let mut remaining: u64 = entries.iter().map(|e| e.size).sum();
+        let mut summary = PruneSummary::default();
+
+        for entry in entries {
+            let expired = older_than.is_some_and(|age| {
+                now.duration_since(entry.last_used)
+                    .is_ok_and(|unused| unused > age)
+            });
+            let over_size = max_size.is_some_and(|max| remaining > max);
+            if !expired && !over_size {
+                continue;
+            }
+            if !dry_run && self.remove_entry(&entry.key).is_err() {
+                continue;
+            }
+            remaining -= entry.size;
+            summary.removed += 1;
+            summary.freed_bytes += entry.size;
+        }
+        summary
+    }
+
+    /// Remove a cached file with its metadata and hash
+    fn remove_entry(&self, cache_key: &str) -> std::io::Result<()> {
+        std::fs::remove_file(self.file_path(cache_key))?;
+        let _ = std::fs::remove_file(self.meta_path(cache_key));
+        let _ = std::fs::remove_file(self.sha256_path(cache_key));
+        Ok(())
+    }
+
     /// Get cache statistics
     pub fn stats(&self) -> DownloadCacheStats {
         let mut stats = DownloadCacheStats::default();
@@ -322,7 +453,10 @@ impl DownloadCache {
                 {
                     for file in files.flatten() {
                         let path = file.path();
-                        if path.extension().is_some_and(|e| e == "meta") {
+                        if path
+                            .extension()
+                            .is_some_and(|e| e == "meta" || e == "sha256")
+                        {
                             continue; // Skip metadata files in count
                         }
                         if let Ok(meta) = file.metadata()
@@ -340,6 +474,23 @@ impl DownloadCache {
     }
 }
 
+/// SHA-256 of a file's contents (hex)
+fn sha256_file(path: &Path) -> std::io::Result<String> {
+    use std::io::Read;
+
+    let mut hasher = Sha256::new();
+    let mut file = std::fs::File::open(path)?;
+    let mut buf = [0u8; 64 * 1024];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
 /// Download cache statistics
 #[derive(Debug, Clone, Default)]
 pub struct DownloadCacheStats {
@@ -1,6 +1,7 @@
 use anyhow::Result;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
+use std::fs::OpenOptions;
 use std::path::Path;
 
 /// Write bytes to `dest` using a best-effort atomic replace (tmp + rename).
@@ -36,3 +37,24 @@ pub fn write_json_file<T: Serialize>(path: &Path, value: &T) -> Result<()> {
     let s = serde_json::to_string_pretty(value)?;
     atomic_write_string(path, &s)
 }
+
+/// Run `f` while holding an exclusive advisory lock on `path` (`<path>.lock`).
+///
+/// Serializes read-modify-write updates of a shared file across threads and
+/// vx processes. The lock file is left in place; removing it could let two
+/// writers lock different files.
+pub fn with_file_lock<T>(path: &Path, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let lock_path = path.with_extension("lock");
+    if let Some(parent) = lock_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let lock = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_path)?;
+    lock.lock()?;
+    let result = f();
+    let _ = lock.unlock();
+    result
+}
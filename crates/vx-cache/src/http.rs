@@ -0,0 +1,406 @@
+//! Shared on-disk HTTP response cache
+//!
+//! Version fetchers, Starlark `http` calls and the installer's checksum
+//! lookups all go through this cache (see `vx_proxy::http_cache`), so a
+//! response fetched by one component is reused by the others.
+//!
+//! Freshness follows the response's `Cache-Control` header:
+//!
+//! - `no-store`: never cached
+//! - `no-cache`: cached, but revalidated on every use
+//! - `max-age=N`: fresh for `N` seconds, then revalidated
+//!
+//! Stale entries are revalidated with `If-None-Match` / `If-Modified-Since`,
+//! so an unchanged resource costs a `304 Not Modified` instead of a full
+//! download (and does not count against GitHub's rate limit). Responses
+//! without `Cache-Control` are only kept when they carry an `ETag` or
+//! `Last-Modified` validator.
+//!
+//! ## Cache Directory Structure
+//!
+//! ```text
+//! ~/.vx/cache/http/
+//! ├── stats.json                 # Hit/miss counters
+//! └── ab/
+//!     ├── cd1234...              # Response body
+//!     └── cd1234....json         # Entry metadata
+//! ```
+
+use crate::file::{atomic_write_bytes, read_json_file, write_json_file};
+use crate::stats::format_size;
+use crate::time::now_epoch_secs;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+/// Largest response body the cache keeps
+pub const MAX_BODY_SIZE: usize = 16 * 1024 * 1024;
+
+/// Parsed `Cache-Control` directives relevant to a private cache
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheControl {
+    /// `no-store`: the response must not be cached
+    pub no_store: bool,
+    /// `no-cache`: the response must be revalidated before each use
+    pub no_cache: bool,
+    /// `max-age=N` in seconds
+    pub max_age: Option<u64>,
+}
+
+impl CacheControl {
+    /// Parse a `Cache-Control` header value
+    pub fn parse(header: &str) -> Self {
+        let mut control = Self::default();
+        for directive in header.split(',') {
+            let directive = directive.trim();
+            let (name, value) = match directive.split_once('=') {
+                Some((name, value)) => (name.trim(), Some(value.trim().trim_matches('"'))),
+                None => (directive, None),
+            };
+            match name.to_ascii_lowercase().as_str() {
+                "no-store" => control.no_store = true,
+                "no-cache" => control.no_cache = true,
+                "max-age" => control.max_age = value.and_then(|v| v.parse().ok()),
+                _ => {}
+            }
+        }
+        control
+    }
+}
+
+/// Caching-related response headers
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HttpCacheHeaders {
+    /// `Cache-Control`
+    pub cache_control: Option<String>,
+    /// `ETag`
+    pub etag: Option<String>,
+    /// `Last-Modified`
+    pub last_modified: Option<String>,
+    /// `Content-Type`
+    pub content_type: Option<String>,
+}
+
+impl HttpCacheHeaders {
+    fn control(&self) -> CacheControl {
+        self.cache_control
+            .as_deref()
+            .map(CacheControl::parse)
+            .unwrap_or_default()
+    }
+
+    fn has_validator(&self) -> bool {
+        self.etag.is_some() || self.last_modified.is_some()
+    }
+}
+
+/// Metadata stored next to a cached response body
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpCacheEntry {
+    /// Request URL
+    pub url: String,
+    /// `ETag` to revalidate with
+    pub etag: Option<String>,
+    /// `Last-Modified` to revalidate with
+    pub last_modified: Option<String>,
+    /// `Content-Type` of the cached body
+    pub content_type: Option<String>,
+    /// Body size in bytes
+    pub size: u64,
+    /// When the response was stored or last revalidated (epoch seconds)
+    pub stored_at: u64,
+    /// Until when the response is fresh (epoch seconds)
+    pub fresh_until: u64,
+}
+
+impl HttpCacheEntry {
+    /// Whether the entry can be used without revalidation
+    pub fn is_fresh(&self) -> bool {
+        now_epoch_secs() < self.fresh_until
+    }
+
+    /// Whether the entry can be revalidated with a conditional request
+    pub fn has_validator(&self) -> bool {
+        self.etag.is_some() || self.last_modified.is_some()
+    }
+}
+
+/// Result of looking up a URL
+#[derive(Debug)]
+pub enum HttpCacheLookup {
+    /// Fresh response, usable as is
+    Fresh {
+        entry: HttpCacheEntry,
+        body: Vec<u8>,
+    },
+    /// Expired response; revalidate it, or fall back to it when offline
+    Stale {
+        entry: HttpCacheEntry,
+        body: Vec<u8>,
+    },
+    /// Nothing cached
+    Miss,
+}
+
+/// How a request was answered, for statistics
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpCacheOutcome {
+    /// Served from a fresh entry
+    Hit,
+    /// Served from a stale entry after a `304 Not Modified`
+    Revalidated,
+    /// Served from a stale entry because the network failed
+    StaleFallback,
+    /// Fetched from the network
+    Miss,
+}
+
+/// Persistent hit/miss counters
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+struct Counters {
+    hits: u64,
+    revalidated: u64,
+    stale_fallbacks: u64,
+    misses: u64,
+}
+
+/// HTTP cache statistics
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HttpCacheStats {
+    /// Cached responses
+    pub entries: usize,
+    /// Cached responses that are currently fresh
+    pub fresh_entries: usize,
+    /// Total size of cached bodies
+    pub total_size_bytes: u64,
+    /// Requests answered from a fresh entry
+    pub hits: u64,
+    /// Requests answered with `304 Not Modified`
+    pub revalidated: u64,
+    /// Requests answered from a stale entry while offline
+    pub stale_fallbacks: u64,
+    /// Requests that downloaded the response
+    pub misses: u64,
+}
+
+impl HttpCacheStats {
+    /// Requests seen by the cache
+    pub fn requests(&self) -> u64 {
+        self.hits + self.revalidated + self.stale_fallbacks + self.misses
+    }
+
+    /// Share of requests served without downloading the body (0.0 to 1.0)
+    pub fn hit_rate(&self) -> f64 {
+        match self.requests() {
+            0 => 0.0,
+            total => (total - self.misses) as f64 / total as f64,
+        }
+    }
+
+    pub fn formatted_size(&self) -> String {
+        format_size(self.total_size_bytes)
+    }
+}
+
+/// Shared on-disk HTTP response cache
+#[derive(Debug, Clone)]
+pub struct HttpCache {
+    cache_dir: PathBuf,
+}
+
+impl HttpCache {
+    /// Create a cache under `<cache_dir>/http`
+    pub fn new(cache_dir: PathBuf) -> Self {
+        Self {
+            cache_dir: cache_dir.join("http"),
+        }
+    }
+
+    /// Get the cache directory
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    fn cache_key(url: &str) -> String {
+        hex::encode(Sha256::digest(url.as_bytes()))
+    }
+
+    fn body_path(&self, key: &str) -> PathBuf {
+        self.cache_dir.join(&key[..2]).join(key)
+    }
+
+    fn meta_path(&self, key: &str) -> PathBuf {
+        self.cache_dir.join(&key[..2]).join(format!("{}.json", key))
+    }
+
+    fn stats_path(&self) -> PathBuf {
+        self.cache_dir.join("stats.json")
+    }
+
+    /// Look up a URL
+    pub fn lookup(&self, url: &str) -> HttpCacheLookup {
+        let key = Self::cache_key(url);
+        let Ok(entry) = read_json_file::<HttpCacheEntry>(&self.meta_path(&key)) else {
+            return HttpCacheLookup::Miss;
+        };
+        let body = match std::fs::read(self.body_path(&key)) {
+            Ok(body) if body.len() as u64 == entry.size && entry.url == url => body,
+            _ => return HttpCacheLookup::Miss,
+        };
+        if entry.is_fresh() {
+            HttpCacheLookup::Fresh { entry, body }
+        } else {
+            HttpCacheLookup::Stale { entry, body }
+        }
+    }
+
+    /// Store a successful response
+    ///
+    /// Returns `false` when the response is not cacheable.
+    pub fn store(&self, url: &str, body: &[u8], headers: &HttpCacheHeaders) -> Result<bool> {
+        let control = headers.control();
+        let lifetime = if control.no_cache {
+            0
+        } else {
+            control.max_age.unwrap_or(0)
+        };
+        let cacheable = !control.no_store
+            && body.len() <= MAX_BODY_SIZE
+            && (lifetime > 0 || headers.has_validator());
+        if !cacheable {
+            return Ok(false);
+        }
+
+        let key = Self::cache_key(url);
+        let now = now_epoch_secs();
+        let entry = HttpCacheEntry {
+            url: url.to_string(),
+            etag: headers.etag.clone(),
+            last_modified: headers.last_modified.clone(),
+            content_type: headers.content_type.clone(),
+            size: body.len() as u64,
+            stored_at: now,
+            fresh_until: now + lifetime,
+        };
+        atomic_write_bytes(&self.body_path(&key), body)?;
+        write_json_file(&self.meta_path(&key), &entry)?;
+        Ok(true)
+    }
+
+    /// Mark an entry as revalidated after a `304 Not Modified`
+    ///
+    /// Returns the cached body, or `None` when the entry disappeared.
+    pub fn revalidate(&self, url: &str, headers: &HttpCacheHeaders) -> Result<Option<Vec<u8>>> {
+        let (mut entry, body) = match self.lookup(url) {
+            HttpCacheLookup::Fresh { entry, body } | HttpCacheLookup::Stale { entry, body } => {
+                (entry, body)
+            }
+            HttpCacheLookup::Miss => return Ok(None),
+        };
+
+        let control = headers.control();
+        if control.no_store {
+            self.remove(url);
+            return Ok(Some(body));
+        }
+        let lifetime = if control.no_cache {
+            0
+        } else {
+            control.max_age.unwrap_or(0)
+        };
+        let now = now_epoch_secs();
+        entry.stored_at = now;
+        entry.fresh_until = now + lifetime;
+        if headers.etag.is_some() {
+            entry.etag = headers.etag.clone();
+        }
+        if headers.last_modified.is_some() {
+            entry.last_modified = headers.last_modified.clone();
+        }
+        write_json_file(&self.meta_path(&Self::cache_key(url)), &entry)?;
+        Ok(Some(body))
+    }
+
+    /// Remove the entry for a URL
+    pub fn remove(&self, url: &str) {
+        let key = Self::cache_key(url);
+        let _ = std::fs::remove_file(self.body_path(&key));
+        let _ = std::fs::remove_file(self.meta_path(&key));
+    }
+
+    /// Count a request in the hit/miss statistics
+    pub fn record(&self, outcome: HttpCacheOutcome) {
+        let path = self.stats_path();
+        let mut counters: Counters = read_json_file(&path).unwrap_or_default();
+        match outcome {
+            HttpCacheOutcome::Hit => counters.hits += 1,
+            HttpCacheOutcome::Revalidated => counters.revalidated += 1,
+            HttpCacheOutcome::StaleFallback => counters.stale_fallbacks += 1,
+            HttpCacheOutcome::Miss => counters.misses += 1,
+        }
+        // Statistics are best-effort
+        let _ = write_json_file(&path, &counters);
+    }
+
+    fn entries(&self) -> Vec<(PathBuf, HttpCacheEntry)> {
+        let Ok(shards) = std::fs::read_dir(&self.cache_dir) else {
+            return Vec::new();
+        };
+        shards
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_dir())
+            .filter_map(|shard| std::fs::read_dir(shard.path()).ok())
+            .flatten()
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|p| read_json_file::<HttpCacheEntry>(&p).ok().map(|m| (p, m)))
+            .collect()
+    }
+
+    /// Entry counts, size and hit statistics
+    pub fn stats(&self) -> HttpCacheStats {
+        let counters: Counters = read_json_file(&self.stats_path()).unwrap_or_default();
+        let entries = self.entries();
+        HttpCacheStats {
+            entries: entries.len(),
+            fresh_entries: entries.iter().filter(|(_, e)| e.is_fresh()).count(),
+            total_size_bytes: entries.iter().map(|(_, e)| e.size).sum(),
+            hits: counters.hits,
+            revalidated: counters.revalidated,
+            stale_fallbacks: counters.stale_fallbacks,
+            misses: counters.misses,
+        }
+    }
+
+    /// Remove expired entries that cannot be revalidated, plus entries not
+    /// refreshed within `older_than_secs` when given
+    ///
+    /// Returns the number of entries removed.
+    pub fn prune(&self, older_than_secs: Option<u64>, dry_run: bool) -> usize {
+        let now = now_epoch_secs();
+        let mut pruned = 0;
+        for (meta_path, entry) in self.entries() {
+            let unusable = !entry.is_fresh() && !entry.has_validator();
+            let old =
+                older_than_secs.is_some_and(|secs| now.saturating_sub(entry.stored_at) > secs);
+            if unusable || old {
+                if !dry_run {
+                    let _ = std::fs::remove_file(meta_path.with_extension(""));
+                    let _ = std::fs::remove_file(&meta_path);
+                }
+                pruned += 1;
+            }
+        }
+        pruned
+    }
+
+    /// Remove every cached response and reset the statistics
+    pub fn clear(&self) -> Result<()> {
+        if self.cache_dir.exists() {
+            std::fs::remove_dir_all(&self.cache_dir)?;
+        }
+        Ok(())
+    }
+}
@@ -5,22 +5,52 @@
 //! This crate provides:
 //! - **Version cache**: High-performance bincode-based version list caching
 //! - **Download cache**: Content-addressable storage for downloaded files
+//! - **HTTP cache**: Shared `Cache-Control`/`ETag`-aware response cache
+//! - **Cassettes**: Recorded HTTP responses and processes for `VX_RECORD` / `VX_REPLAY`
+//! - **Network statistics**: Per-source download success, latency and throughput
+//! - **Usage counters**: Lifetime install and download cache hit counts
+//! - **Peer cache**: Discovery and serving of download caches on the LAN
+//! - **Registry index**: Provider metadata read by `vx list` and `vx search`
+//! - **Pruning**: Age- and size-based cleanup of downloads and temp files
 //! - **File utilities**: Atomic file operations
 //! - **Cache statistics**: Size and count tracking
 
 pub mod bin_dir;
+pub mod cassette;
 pub mod download;
 pub mod exec_path;
 pub mod file;
+pub mod http;
 pub mod mode;
+pub mod network;
+pub mod peer;
+pub mod prune;
+pub mod registry_index;
 pub mod stats;
 pub mod time;
+pub mod usage;
 
 pub use bin_dir::BinDirCache;
-pub use download::{CacheLookupResult, DownloadCache, DownloadCacheMetadata, DownloadCacheStats};
+pub use cassette::{
+    Cassette, CassetteData, CassetteMode, HttpInteraction, ProcessInteraction, RECORD_ENV,
+    REPLAY_ENV,
+};
+pub use download::{
+    CacheLookupResult, DownloadCache, DownloadCacheEntry, DownloadCacheMetadata, DownloadCacheStats,
+};
 pub use exec_path::ExecPathCache;
-pub use file::{atomic_write_bytes, atomic_write_string, read_json_file, write_json_file};
+pub use file::{
+    atomic_write_bytes, atomic_write_string, read_json_file, with_file_lock, write_json_file,
+};
+pub use http::{
+    CacheControl, HttpCache, HttpCacheEntry, HttpCacheHeaders, HttpCacheLookup, HttpCacheOutcome,
+    HttpCacheStats,
+};
 pub use mode::CacheMode;
+pub use network::{DownloadAttempt, NetworkStats, SourceStats};
+pub use prune::{PruneSummary, TempArea};
+pub use registry_index::{IndexedRuntime, RegistryIndex};
 pub use stats::{CacheStats, format_size};
+pub use usage::UsageCounters;
 
 pub use time::now_epoch_secs;
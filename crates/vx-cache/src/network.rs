@@ -0,0 +1,291 @@
+//! Per-source download statistics
+//!
+//! Every download attempt is recorded against the host it went to, so
+//! `vx stats --network` can show which mirrors and upstreams are slow or
+//! failing on this machine. Only the most recent attempts are kept per host,
+//! so the numbers follow changes in the network instead of averaging over
+//! months of history.
+//!
+//! ## Storage
+//!
+//! ```text
+//! ~/.vx/cache/network/
+//! └── stats.json                 # Per-host counters and recent samples
+//! ```
+
+use crate::file::{read_json_file, write_json_file};
+use crate::stats::format_size;
+use crate::time::now_epoch_secs;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Recent attempts kept per host
+pub const MAX_SAMPLES: usize = 50;
+
+/// Share of recent attempts that must fail for a source to count as failing
+pub const FAILING_RATE: f64 = 0.25;
+
+/// Throughput below which a source counts as slow (bytes per second)
+pub const SLOW_THROUGHPUT: f64 = 512.0 * 1024.0;
+
+/// Transfers smaller than this say little about throughput and are ignored for it
+const MIN_THROUGHPUT_SAMPLE: u64 = 256 * 1024;
+
+/// Result of one download attempt
+#[derive(Debug, Clone)]
+pub enum DownloadAttempt {
+    /// The transfer completed
+    Success {
+        /// Time until the response headers arrived
+        latency: Duration,
+        /// Time for the whole transfer
+        elapsed: Duration,
+        /// Bytes received in this attempt
+        bytes: u64,
+    },
+    /// The attempt failed and was retried, failed over or given up on
+    Failure {
+        /// Time until the attempt failed
+        elapsed: Duration,
+        /// Error message
+        error: String,
+    },
+}
+
+/// One recorded attempt
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct Sample {
+    ok: bool,
+    latency_ms: u64,
+    elapsed_ms: u64,
+    bytes: u64,
+}
+
+/// Persistent record of one host
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct SourceRecord {
+    successes: u64,
+    failures: u64,
+    bytes: u64,
+    #[serde(default)]
+    samples: Vec<Sample>,
+    last_error: Option<String>,
+    last_success_at: Option<u64>,
+    last_failure_at: Option<u64>,
+}
+
+/// Download statistics of one source host
+#[derive(Debug, Clone, Serialize)]
+pub struct SourceStats {
+    /// Host (with port, if not the default)
+    pub host: String,
+    /// Successful transfers since the statistics were cleared
+    pub successes: u64,
+    /// Failed attempts since the statistics were cleared
+    pub failures: u64,
+    /// Bytes downloaded since the statistics were cleared
+    pub bytes: u64,
+    /// Recent attempts the figures below are based on
+    pub recent_attempts: usize,
+    /// Share of recent attempts that failed (0.0 to 1.0)
+    pub recent_failure_rate: f64,
+    /// Median time to first response of recent successful attempts
+    pub median_latency_ms: Option<u64>,
+    /// 95th percentile time to first response of recent successful attempts
+    pub p95_latency_ms: Option<u64>,
+    /// Average throughput of recent non-trivial transfers (bytes per second)
+    pub throughput: Option<f64>,
+    /// Most recent error
+    pub last_error: Option<String>,
+    /// Unix time of the last successful transfer
+    pub last_success_at: Option<u64>,
+    /// Unix time of the last failed attempt
+    pub last_failure_at: Option<u64>,
+}
+
+impl SourceStats {
+    /// Whether recent attempts fail often enough to point at a problem
+    pub fn is_failing(&self) -> bool {
+        self.recent_attempts >= 2 && self.recent_failure_rate >= FAILING_RATE
+    }
+
+    /// Whether recent transfers were slow
+    pub fn is_slow(&self) -> bool {
+        self.throughput.is_some_and(|t| t < SLOW_THROUGHPUT)
+    }
+
+    /// Throughput as text, e.g. `3.2 MB/s`
+    pub fn formatted_throughput(&self) -> Option<String> {
+        self.throughput
+            .map(|t| format!("{}/s", format_size(t.round() as u64)))
+    }
+}
+
+/// On-disk download statistics per source host
+#[derive(Debug, Clone)]
+pub struct NetworkStats {
+    dir: PathBuf,
+}
+
+impl NetworkStats {
+    /// Statistics stored under `<cache_dir>/network`
+    pub fn new(cache_dir: PathBuf) -> Self {
+        Self {
+            dir: cache_dir.join("network"),
+        }
+    }
+
+    /// Directory holding the statistics
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn stats_path(&self) -> PathBuf {
+        self.dir.join("stats.json")
+    }
+
+    fn load(&self) -> BTreeMap<String, SourceRecord> {
+        read_json_file(&self.stats_path()).unwrap_or_default()
+    }
+
+    /// Record one download attempt from `url`
+    pub fn record(&self, url: &str, attempt: &DownloadAttempt) {
+        let Some(host) = host_of(url) else {
+            return;
+        };
+        let mut records = self.load();
+        let record = records.entry(host).or_default();
+        let now = now_epoch_secs();
+
+        let sample = match attempt {
+            DownloadAttempt::Success {
+                latency,
+                elapsed,
+                bytes,
+            } => {
+                record.successes += 1;
+                record.bytes += bytes;
+                record.last_success_at = Some(now);
+                Sample {
+                    ok: true,
+                    latency_ms: latency.as_millis() as u64,
+                    elapsed_ms: elapsed.as_millis() as u64,
+                    bytes: *bytes,
+                }
+            }
+            DownloadAttempt::Failure { elapsed, error } => {
+                record.failures += 1;
+                record.last_error = Some(error.clone());
+                record.last_failure_at = Some(now);
+                Sample {
+                    ok: false,
+                    elapsed_ms: elapsed.as_millis() as u64,
+                    ..Sample::default()
+                }
+            }
+        };
+        record.samples.push(sample);
+        if record.samples.len() > MAX_SAMPLES {
+            let excess = record.samples.len() - MAX_SAMPLES;
+            record.samples.drain(..excess);
+        }
+
+        // Statistics are best-effort
+        let _ = std::fs::create_dir_all(&self.dir);
+        let _ = write_json_file(&self.stats_path(), &records);
+    }
+
+    /// Statistics of every source seen, most failing first, then slowest
+    pub fn sources(&self) -> Vec<SourceStats> {
+        let mut sources: Vec<_> = self
+            .load()
+            .into_iter()
+            .map(|(host, record)| summarize(host, record))
+            .collect();
+        sources.sort_by(|a, b| {
+            b.recent_failure_rate
+                .total_cmp(&a.recent_failure_rate)
+                .then_with(|| {
+                    let throughput = |s: &SourceStats| s.throughput.unwrap_or(f64::INFINITY);
+                    throughput(a).total_cmp(&throughput(b))
+                })
+                .then_with(|| a.host.cmp(&b.host))
+        });
+        sources
+    }
+
+    /// Forget all recorded attempts
+    pub fn clear(&self) -> Result<()> {
+        if self.dir.exists() {
+            std::fs::remove_dir_all(&self.dir)?;
+        }
+        Ok(())
+    }
+}
+
+fn summarize(host: String, record: SourceRecord) -> SourceStats {
+    let samples = &record.samples;
+    let failed = samples.iter().filter(|s| !s.ok).count();
+
+    let mut latencies: Vec<u64> = samples
+        .iter()
+        .filter(|s| s.ok)
+        .map(|s| s.latency_ms)
+        .collect();
+    latencies.sort_unstable();
+
+    let (bytes, millis) = samples
+        .iter()
+        .filter(|s| s.ok && s.bytes >= MIN_THROUGHPUT_SAMPLE)
+        .fold((0u64, 0u64), |(bytes, millis), s| {
+            (bytes + s.bytes, millis + s.elapsed_ms)
+        });
+    let throughput = (bytes > 0).then(|| bytes as f64 * 1000.0 / millis.max(1) as f64);
+
+    SourceStats {
+        host,
+        successes: record.successes,
+        failures: record.failures,
+        bytes: record.bytes,
+        recent_attempts: samples.len(),
+        recent_failure_rate: match samples.len() {
+            0 => 0.0,
+            total => failed as f64 / total as f64,
+        },
+        median_latency_ms: percentile(&latencies, 50),
+        p95_latency_ms: percentile(&latencies, 95),
+        throughput,
+        last_error: record.last_error,
+        last_success_at: record.last_success_at,
+        last_failure_at: record.last_failure_at,
+    }
+}
+
+/// Nearest-rank percentile of sorted values
+fn percentile(sorted: &[u64], pct: usize) -> Option<u64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    Some(sorted[rank - 1])
+}
+
+/// Host (and non-default port) of a URL, lowercased
+pub fn host_of(url: &str) -> Option<String> {
+    let (scheme, rest) = url.split_once("://")?;
+    let authority = rest.split(['/', '?', '#']).next()?;
+    let authority = authority.rsplit('@').next()?.to_ascii_lowercase();
+    let default_port = match scheme.to_ascii_lowercase().as_str() {
+        "http" => Some(":80"),
+        "https" => Some(":443"),
+        _ => None,
+    };
+    let host = match default_port {
+        Some(port) => authority.strip_suffix(port).unwrap_or(&authority),
+        None => &authority,
+    };
+    (!host.is_empty()).then(|| host.to_string())
+}
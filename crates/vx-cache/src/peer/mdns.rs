@@ -0,0 +1,259 @@
+//! Minimal mDNS / DNS-SD for finding peer caches
+//!
+//! Only what peer discovery needs is implemented: a `PTR` query for
+//! [`SERVICE`](super::SERVICE), and answers carrying a `PTR` record for the
+//! instance plus an `SRV` record with its port. The peer address is the
+//! source address of the answer.
+
+use std::collections::BTreeSet;
+use std::io;
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4, UdpSocket};
+use std::time::{Duration, Instant};
+
+/// mDNS multicast group
+pub const MDNS_ADDR: Ipv4Addr = Ipv4Addr::new(224, 0, 0, 251);
+
+/// mDNS port
+pub const MDNS_PORT: u16 = 5353;
+
+const TYPE_PTR: u16 = 12;
+const TYPE_SRV: u16 = 33;
+const CLASS_IN: u16 = 1;
+/// Unicast-response bit in questions, cache-flush bit in answers
+const CLASS_TOP_BIT: u16 = 0x8000;
+const FLAGS_RESPONSE: u16 = 0x8400;
+const TTL: u32 = 120;
+
+fn service_labels() -> Vec<String> {
+    super::SERVICE.split('.').map(str::to_string).collect()
+}
+
+/// A `PTR` query asking for unicast answers
+pub fn query_packet() -> Vec<u8> {
+    let mut packet = header(0, 0, 1, 0);
+    write_name(&mut packet, &service_labels());
+    packet.extend_from_slice(&TYPE_PTR.to_be_bytes());
+    packet.extend_from_slice(&(CLASS_IN | CLASS_TOP_BIT).to_be_bytes());
+    packet
+}
+
+/// An answer announcing `instance` on `port`
+pub fn response_packet(id: u16, instance: &str, port: u16) -> Vec<u8> {
+    let service = service_labels();
+    let mut instance_name = vec![instance_label(instance)];
+    instance_name.extend(service.iter().cloned());
+    let target = vec![instance_label(instance), "local".to_string()];
+
+    let mut packet = header(id, FLAGS_RESPONSE, 0, 2);
+
+    write_name(&mut packet, &service);
+    packet.extend_from_slice(&TYPE_PTR.to_be_bytes());
+    packet.extend_from_slice(&CLASS_IN.to_be_bytes());
+    packet.extend_from_slice(&TTL.to_be_bytes());
+    let mut rdata = Vec::new();
+    write_name(&mut rdata, &instance_name);
+    packet.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+    packet.extend_from_slice(&rdata);
+
+    write_name(&mut packet, &instance_name);
+    packet.extend_from_slice(&TYPE_SRV.to_be_bytes());
+    packet.extend_from_slice(&(CLASS_IN | CLASS_TOP_BIT).to_be_bytes());
+    packet.extend_from_slice(&TTL.to_be_bytes());
+    let mut rdata = vec![0, 0, 0, 0];
+    rdata.extend_from_slice(&port.to_be_bytes());
+    write_name(&mut rdata, &target);
+    packet.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+    packet.extend_from_slice(&rdata);
+
+    packet
+}
+
+/// The query id when `packet` is a query for the peer cache service
+pub fn parse_query(packet: &[u8]) -> Option<u16> {
+    let id = read_u16(packet, 0)?;
+    let flags = read_u16(packet, 2)?;
+    if flags & 0x8000 != 0 {
+        return None;
+    }
+    let questions = read_u16(packet, 4)?;
+    let service = service_labels();
+
+    let mut pos = 12;
+    for _ in 0..questions {
+        let (name, next) = read_name(packet, pos)?;
+        let qtype = read_u16(packet, next)?;
+        pos = next + 4;
+        if same_name(&name, &service) && matches!(qtype, TYPE_PTR | 255) {
+            return Some(id);
+        }
+    }
+    None
+}
+
+/// Ports announced in an answer to the peer cache query
+pub fn parse_response(packet: &[u8]) -> Vec<u16> {
+    parse_response_records(packet).unwrap_or_default()
+}
+
+fn parse_response_records(packet: &[u8]) -> Option<Vec<u16>> {
+    let flags = read_u16(packet, 2)?;
+    if flags & 0x8000 == 0 {
+        return None;
+    }
+    let questions = read_u16(packet, 4)?;
+    let records = [6, 8, 10]
+        .iter()
+        .map(|offset| read_u16(packet, *offset).map(usize::from))
+        .sum::<Option<usize>>()?;
+    let service = service_labels();
+
+    let mut pos = 12;
+    for _ in 0..questions {
+        let (_, next) = read_name(packet, pos)?;
+        pos = next + 4;
+    }
+
+    let mut ports = Vec::new();
+    for _ in 0..records {
+        let (name, next) = read_name(packet, pos)?;
+        let rtype = read_u16(packet, next)?;
+        let rdlen = usize::from(read_u16(packet, next + 8)?);
+        let rdata = next + 10;
+        if rdata + rdlen > packet.len() {
+            return None;
+        }
+        // Instance names are `<instance>._vx-cache._tcp.local`
+        if rtype == TYPE_SRV && name.len() > service.len() && same_name(&name[1..], &service) {
+            ports.push(read_u16(packet, rdata + 4)?);
+        }
+        pos = rdata + rdlen;
+    }
+    Some(ports)
+}
+
+/// Ask the local network for peer caches, collecting answers until `timeout`
+pub fn discover(timeout: Duration) -> io::Result<Vec<SocketAddr>> {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))?;
+    socket.set_multicast_ttl_v4(255)?;
+    socket.send_to(&query_packet(), SocketAddrV4::new(MDNS_ADDR, MDNS_PORT))?;
+
+    let deadline = Instant::now() + timeout;
+    let mut peers = BTreeSet::new();
+    let mut buf = [0u8; 1500];
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        socket.set_read_timeout(Some(remaining))?;
+        match socket.recv_from(&mut buf) {
+            Ok((n, from)) => {
+                for port in parse_response(&buf[..n]) {
+                    peers.insert(SocketAddr::new(from.ip(), port));
+                }
+            }
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                ) =>
+            {
+                break;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(peers.into_iter().collect())
+}
+
+/// Answer peer cache queries on the mDNS port until an error occurs
+///
+/// The socket is bound with `SO_REUSEADDR` so it can share the port with a
+/// system mDNS responder.
+pub fn respond(instance: &str, port: u16) -> io::Result<()> {
+    use socket2::{Domain, Protocol, Socket, Type};
+
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    socket.set_reuse_address(true)?;
+    socket.bind(&SocketAddr::from((Ipv4Addr::UNSPECIFIED, MDNS_PORT)).into())?;
+    let socket: UdpSocket = socket.into();
+    socket.join_multicast_v4(&MDNS_ADDR, &Ipv4Addr::UNSPECIFIED)?;
+
+    let mut buf = [0u8; 1500];
+    loop {
+        let (n, from) = socket.recv_from(&mut buf)?;
+        if let Some(id) = parse_query(&buf[..n]) {
+            socket.send_to(&response_packet(id, instance, port), from)?;
+        }
+    }
+}
+
+fn header(id: u16, flags: u16, questions: u16, answers: u16) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(64);
+    for value in [id, flags, questions, answers, 0, 0] {
+        packet.extend_from_slice(&value.to_be_bytes());
+    }
+    packet
+}
+
+/// A DNS label derived from `instance` (at most 63 bytes)
+fn instance_label(instance: &str) -> String {
+    let label: String = instance
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .take(63)
+        .collect();
+    if label.is_empty() {
+        "vx".to_string()
+    } else {
+        label
+    }
+}
+
+fn write_name(packet: &mut Vec<u8>, labels: &[String]) {
+    for label in labels {
+        packet.push(label.len() as u8);
+        packet.extend_from_slice(label.as_bytes());
+    }
+    packet.push(0);
+}
+
+/// Read a possibly compressed name, returning its labels and the position after it
+fn read_name(packet: &[u8], mut pos: usize) -> Option<(Vec<String>, usize)> {
+    let mut labels = Vec::new();
+    let mut end = None;
+    // Bound pointer chasing so malformed packets cannot loop forever
+    for _ in 0..128 {
+        let len = *packet.get(pos)?;
+        match len {
+            0 => return Some((labels, end.unwrap_or(pos + 1))),
+            l if l & 0xC0 == 0xC0 => {
+                let pointer = usize::from(read_u16(packet, pos)? & 0x3FFF);
+                end.get_or_insert(pos + 2);
+                pos = pointer;
+            }
+            l => {
+                let start = pos + 1;
+                let label = packet.get(start..start + usize::from(l))?;
+                labels.push(String::from_utf8_lossy(label).into_owned());
+                pos = start + usize::from(l);
+            }
+        }
+    }
+    None
+}
+
+fn same_name(a: &[String], b: &[String]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.eq_ignore_ascii_case(y))
+}
+
+fn read_u16(packet: &[u8], pos: usize) -> Option<u16> {
+    let bytes = packet.get(pos..pos + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
@@ -0,0 +1,21 @@
+//! LAN peer cache
+//!
+//! Machines on the same network can share their download caches:
+//! `vx cache serve` answers mDNS queries for [`SERVICE`] and serves verified
+//! cache entries over HTTP, and other vx instances fetch a download from a
+//! peer before going upstream. Clients only keep a peer's file when its
+//! SHA-256 matches the expected checksum.
+
+pub mod mdns;
+pub mod server;
+
+pub use mdns::discover;
+pub use server::{
+    DOWNLOADS_PATH, METRICS_CONTENT_TYPE, METRICS_PATH, PeerServer, PeerServerStats, SHA256_HEADER,
+};
+
+/// DNS-SD service type announced by peer caches
+pub const SERVICE: &str = "_vx-cache._tcp.local";
+
+/// Default port of `vx cache serve`
+pub const DEFAULT_PEER_PORT: u16 = 7878;
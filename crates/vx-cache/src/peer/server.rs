@@ -0,0 +1,189 @@
+//! HTTP server sharing the download cache with peers
+//!
+//! Serves `GET /v1/downloads/<cache-key>` with the cached file, where the
+//! cache key is [`DownloadCache::cache_key`] of the original URL. Each
+//! response carries the file's SHA-256 in [`SHA256_HEADER`] so the client can
+//! check it against the upstream checksum before using it.
+//!
+//! With [`PeerServer::with_metrics`], `GET /metrics` answers with the text
+//! the given function renders, for monitoring the server and its machine.
+
+use crate::DownloadCache;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Response header carrying the SHA-256 of the served file
+pub const SHA256_HEADER: &str = "x-vx-sha256";
+
+/// Path prefix of cache entries
+pub const DOWNLOADS_PATH: &str = "/v1/downloads/";
+
+/// Path of the metrics endpoint
+pub const METRICS_PATH: &str = "/metrics";
+
+/// `Content-Type` of the metrics endpoint, the Prometheus text format
+pub const METRICS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+type MetricsRenderer = dyn Fn(&PeerServerStats) -> String + Send + Sync;
+
+/// Requests answered since the server started
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PeerServerStats {
+    /// Cache entries served
+    pub hits: u64,
+    /// Requests for entries the cache doesn't have
+    pub misses: u64,
+    /// Bytes of cache entries sent
+    pub bytes_served: u64,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    bytes_served: AtomicU64,
+}
+
+/// Serves download cache entries to peers
+#[derive(Clone)]
+pub struct PeerServer {
+    cache: DownloadCache,
+    counters: Arc<Counters>,
+    metrics: Option<Arc<MetricsRenderer>>,
+}
+
+impl std::fmt::Debug for PeerServer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PeerServer")
+            .field("cache", &self.cache)
+            .field("metrics", &self.metrics.is_some())
+            .finish()
+    }
+}
+
+impl PeerServer {
+    /// Serve entries of `cache`
+    pub fn new(cache: DownloadCache) -> Self {
+        Self {
+            cache,
+            counters: Arc::default(),
+            metrics: None,
+        }
+    }
+
+    /// Also answer `GET /metrics` with what `render` returns for the
+    /// server's current [`stats`](Self::stats)
+    pub fn with_metrics(
+        mut self,
+        render: impl Fn(&PeerServerStats) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.metrics = Some(Arc::new(render));
+        self
+    }
+
+    /// Requests answered so far
+    pub fn stats(&self) -> PeerServerStats {
+        PeerServerStats {
+            hits: self.counters.hits.load(Ordering::Relaxed),
+            misses: self.counters.misses.load(Ordering::Relaxed),
+            bytes_served: self.counters.bytes_served.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Accept connections on `listener` until it fails
+    ///
+    /// Each connection is handled on its own thread.
+    pub fn serve(&self, listener: TcpListener) -> io::Result<()> {
+        for stream in listener.incoming() {
+            let stream = stream?;
+            let server = self.clone();
+            // Errors only affect one client, e.g. one going away mid-transfer
+            std::thread::spawn(move || {
+                let _ = server.handle(stream);
+            });
+        }
+        Ok(())
+    }
+
+    fn handle(&self, mut stream: TcpStream) -> io::Result<()> {
+        stream.set_read_timeout(Some(Duration::from_secs(10)))?;
+
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        // Drain the headers; none of them change the response
+        let mut line = String::new();
+        while reader.read_line(&mut line)? > 2 {
+            line.clear();
+        }
+
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next().unwrap_or_default();
+        let path = parts.next().unwrap_or_default();
+
+        if method != "GET" && method != "HEAD" {
+            return respond(&mut stream, "405 Method Not Allowed", &[]);
+        }
+        if path == METRICS_PATH
+            && let Some(render) = &self.metrics
+        {
+            let body = render(&self.stats());
+            let headers = [
+                ("content-length", body.len().to_string()),
+                ("content-type", METRICS_CONTENT_TYPE.to_string()),
+            ];
+            respond(&mut stream, "200 OK", &headers)?;
+            if method == "GET" {
+                stream.write_all(body.as_bytes())?;
+            }
+            return stream.flush();
+        }
+        let Some(key) = path
+            .strip_prefix(DOWNLOADS_PATH)
+            .filter(|k| is_cache_key(k))
+        else {
+            return respond(&mut stream, "404 Not Found", &[]);
+        };
+        let (Some((file_path, metadata)), Some(sha256)) =
+            (self.cache.entry(key), self.cache.content_sha256(key)?)
+        else {
+            self.counters.misses.fetch_add(1, Ordering::Relaxed);
+            return respond(&mut stream, "404 Not Found", &[]);
+        };
+
+        let headers = [
+            ("content-length", metadata.size.to_string()),
+            ("content-type", "application/octet-stream".to_string()),
+            (SHA256_HEADER, sha256),
+        ];
+        respond(&mut stream, "200 OK", &headers)?;
+        self.counters.hits.fetch_add(1, Ordering::Relaxed);
+        if method == "GET" {
+            let mut file = std::fs::File::open(file_path)?;
+            let sent = io::copy(&mut file, &mut stream)?;
+            self.counters
+                .bytes_served
+                .fetch_add(sent, Ordering::Relaxed);
+        }
+        stream.flush()
+    }
+}
+
+fn respond(stream: &mut TcpStream, status: &str, headers: &[(&str, String)]) -> io::Result<()> {
+    let mut head = format!("HTTP/1.1 {}\r\nconnection: close\r\n", status);
+    for (name, value) in headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    if headers.is_empty() {
+        head.push_str("content-length: 0\r\n");
+    }
+    head.push_str("\r\n");
+    stream.write_all(head.as_bytes())
+}
+
+fn is_cache_key(key: &str) -> bool {
+    key.len() == 64 && key.bytes().all(|b| b.is_ascii_hexdigit())
+}
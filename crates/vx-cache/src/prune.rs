@@ -0,0 +1,135 @@
+//! Age- and size-based cleanup of cached data
+//!
+//! The download cache is pruned by [`DownloadCache::prune`](crate::DownloadCache::prune).
+//! [`TempArea`] covers `~/.vx/tmp`, where installers extract archives
+//! before moving them into the store. Anything left there belongs to an
+//! interrupted install and is safe to remove once it is old enough not to
+//! be in use.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// What a prune removed (or would remove in a dry run)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PruneSummary {
+    /// Number of entries removed
+    pub removed: usize,
+    /// Bytes freed
+    pub freed_bytes: u64,
+}
+
+impl PruneSummary {
+    /// Add the result of another prune
+    pub fn add(&mut self, other: PruneSummary) {
+        self.removed += other.removed;
+        self.freed_bytes += other.freed_bytes;
+    }
+}
+
+/// One leftover file or directory in the temp area
+#[derive(Debug, Clone)]
+pub struct TempEntry {
+    pub path: PathBuf,
+    /// Total size, including directory contents
+    pub size: u64,
+    pub modified: SystemTime,
+}
+
+/// The installers' temporary extraction area
+#[derive(Debug, Clone)]
+pub struct TempArea {
+    dir: PathBuf,
+}
+
+impl TempArea {
+    /// Temp area rooted at `dir` (usually `~/.vx/tmp`)
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// The temp directory
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Top-level entries, oldest first
+    ///
+    /// A directory is as old as the newest file inside it, so an install
+    /// still extracting into it never looks stale.
+    pub fn entries(&self) -> Vec<TempEntry> {
+        let Ok(children) = std::fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+        let mut entries: Vec<TempEntry> = children
+            .flatten()
+            .filter_map(|child| {
+                let meta = child.metadata().ok()?;
+                let modified = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                let (size, modified) = if meta.is_dir() {
+                    let (size, newest) = dir_usage(&child.path());
+                    (size, newest.unwrap_or(modified))
+                } else {
+                    (meta.len(), modified)
+                };
+                Some(TempEntry {
+                    path: child.path(),
+                    size,
+                    modified,
+                })
+            })
+            .collect();
+        entries.sort_by_key(|e| e.modified);
+        entries
+    }
+
+    /// Remove entries not modified for longer than `older_than`
+    ///
+    /// With `dry_run`, only reports what would be removed.
+    pub fn prune(&self, older_than: Duration, dry_run: bool) -> PruneSummary {
+        let now = SystemTime::now();
+        let mut summary = PruneSummary::default();
+        for entry in self.entries() {
+            let old = now
+                .duration_since(entry.modified)
+                .is_ok_and(|age| age > older_than);
+            if !old {
+                continue;
+            }
+            if !dry_run {
+                let removed = if entry.path.is_dir() {
+                    std::fs::remove_dir_all(&entry.path)
+                } else {
+                    std::fs::remove_file(&entry.path)
+                };
+                if removed.is_err() {
+                    continue;
+                }
+            }
+            summary.removed += 1;
+            summary.freed_bytes += entry.size;
+        }
+        summary
+    }
+}
+
+/// Total size and newest modification time of the files below `path`
+fn dir_usage(path: &Path) -> (u64, Option<SystemTime>) {
+    let mut size = 0;
+    let mut newest: Option<SystemTime> = None;
+    let Ok(children) = std::fs::read_dir(path) else {
+        return (size, newest);
+    };
+    for child in children.flatten() {
+        let Ok(meta) = child.metadata() else {
+            continue;
+        };
+        let (child_size, child_newest) = if meta.is_dir() {
+            dir_usage(&child.path())
+        } else {
+            (meta.len(), meta.modified().ok())
+        };
+        size += child_size;
+        newest = newest.max(child_newest);
+    }
+    (size, newest)
+}
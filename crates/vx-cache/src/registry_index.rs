@@ -0,0 +1,163 @@
+//! Provider metadata index for vx
+//!
+//! `vx list`, `vx search` and tool suggestions only need the names,
+//! descriptions and a few metadata fields of every runtime. Loading them
+//! from the providers parses dozens of `provider.star` files, so the fields
+//! are stored in a single bincode-serialized file at
+//! `~/.vx/cache/registry.idx`.
+//!
+//! ## Cache invalidation
+//!
+//! The index is rebuilt when:
+//! - Its key differs: the key identifies the vx release and the provider
+//!   sources, so it changes on `vx self-update` and `vx provider add`
+//! - It was built for another platform
+//! - The user runs `vx cache purge`
+
+use serde::{Deserialize, Serialize};
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+
+const INDEX_VERSION: u32 = 1;
+const INDEX_FILENAME: &str = "registry.idx";
+
+/// Indexed metadata of one runtime
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IndexedRuntime {
+    pub name: String,
+    pub aliases: Vec<String>,
+    pub description: String,
+    /// Ecosystem the runtime belongs to (e.g. `nodejs`)
+    pub ecosystem: Option<String>,
+    /// Runtime this runtime is bundled with (npm with node)
+    pub bundled_with: Option<String>,
+    /// Whether the runtime supports the platform the index was built on
+    pub platform_supported: bool,
+}
+
+/// Index of all runtimes of the provider registry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegistryIndex {
+    /// Version for index format migration
+    version: u32,
+    /// Identifies the vx release and provider sources the index was built from
+    key: String,
+    /// Platform the index was built on (e.g. `linux-x64`)
+    platform: String,
+    /// Runtimes, sorted by name
+    runtimes: Vec<IndexedRuntime>,
+}
+
+impl RegistryIndex {
+    /// Create an index of `runtimes`
+    pub fn new(
+        key: impl Into<String>,
+        platform: impl Into<String>,
+        mut runtimes: Vec<IndexedRuntime>,
+    ) -> Self {
+        runtimes.sort_by(|a, b| a.name.cmp(&b.name));
+        runtimes.dedup_by(|a, b| a.name == b.name);
+        Self {
+            version: INDEX_VERSION,
+            key: key.into(),
+            platform: platform.into(),
+            runtimes,
+        }
+    }
+
+    /// All indexed runtimes, sorted by name
+    pub fn runtimes(&self) -> &[IndexedRuntime] {
+        &self.runtimes
+    }
+
+    /// Look up a runtime by name or alias
+    pub fn get(&self, name: &str) -> Option<&IndexedRuntime> {
+        self.runtimes
+            .binary_search_by(|r| r.name.as_str().cmp(name))
+            .ok()
+            .map(|i| &self.runtimes[i])
+            .or_else(|| {
+                self.runtimes
+                    .iter()
+                    .find(|r| r.aliases.iter().any(|a| a == name))
+            })
+    }
+
+    /// Runtime names and aliases, sorted, for suggestions
+    pub fn runtime_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .runtimes
+            .iter()
+            .flat_map(|r| std::iter::once(&r.name).chain(&r.aliases))
+            .cloned()
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Whether the index was built from `key` on `platform`
+    pub fn is_current(&self, key: &str, platform: &str) -> bool {
+        self.key == key && self.platform == platform
+    }
+
+    /// Number of indexed runtimes.
+    pub fn len(&self) -> usize {
+        self.runtimes.len()
+    }
+
+    /// Whether the index is empty.
+    pub fn is_empty(&self) -> bool {
+        self.runtimes.is_empty()
+    }
+
+    /// Get the index file path within a cache directory.
+    pub fn index_file_path(cache_dir: &Path) -> PathBuf {
+        cache_dir.join(INDEX_FILENAME)
+    }
+
+    /// Load the index from disk if it was built from `key` on `platform`.
+    ///
+    /// Returns `None` if the index is missing, unreadable or outdated.
+    pub fn load(cache_dir: &Path, key: &str, platform: &str) -> Option<Self> {
+        let file = std::fs::File::open(Self::index_file_path(cache_dir)).ok()?;
+        let mut reader = BufReader::new(file);
+        let index: Self =
+            bincode::serde::decode_from_std_read(&mut reader, bincode::config::standard()).ok()?;
+
+        if index.version != INDEX_VERSION || !index.is_current(key, platform) {
+            return None;
+        }
+        Some(index)
+    }
+
+    /// Save the index to disk (atomic write).
+    pub fn save(&self, cache_dir: &Path) -> std::io::Result<()> {
+        let path = Self::index_file_path(cache_dir);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let temp_path = path.with_extension("idx.tmp");
+        let file = std::fs::File::create(&temp_path)?;
+        let mut writer = BufWriter::new(file);
+        bincode::serde::encode_into_std_write(self, &mut writer, bincode::config::standard())
+            .map_err(|e| std::io::Error::other(e.to_string()))?;
+        drop(writer);
+
+        if path.exists() {
+            let _ = std::fs::remove_file(&path);
+        }
+        std::fs::rename(&temp_path, &path)?;
+        Ok(())
+    }
+
+    /// Remove the index file from disk.
+    pub fn remove_file(cache_dir: &Path) -> std::io::Result<()> {
+        let path = Self::index_file_path(cache_dir);
+        if path.exists() {
+            std::fs::remove_file(&path)?;
+        }
+        Ok(())
+    }
+}
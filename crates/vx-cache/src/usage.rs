@@ -0,0 +1,69 @@
+//! Lifetime usage counters
+//!
+//! Counts events that no cache entry records by itself, such as installs and
+//! download cache lookups, for the `/metrics` endpoint of `vx cache serve`.
+//! Counters only grow; clearing the cache does not reset them.
+//!
+//! ## Storage
+//!
+//! ```text
+//! ~/.vx/cache/
+//! ├── usage.json                 # Counter name -> count
+//! └── usage.lock                 # Lock serializing updates
+//! ```
+
+use crate::file::{read_json_file, with_file_lock, write_json_file};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Runtimes installed into the store
+pub const INSTALLS: &str = "installs";
+
+/// Downloads served from the download cache
+pub const DOWNLOAD_CACHE_HITS: &str = "download_cache_hits";
+
+/// Downloads missing from the download cache
+pub const DOWNLOAD_CACHE_MISSES: &str = "download_cache_misses";
+
+/// On-disk usage counters
+#[derive(Debug, Clone)]
+pub struct UsageCounters {
+    path: PathBuf,
+}
+
+impl UsageCounters {
+    /// Counters stored in `<cache_dir>/usage.json`
+    pub fn new(cache_dir: impl AsRef<Path>) -> Self {
+        Self {
+            path: cache_dir.as_ref().join("usage.json"),
+        }
+    }
+
+    /// Path of the counter file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Add one to `counter`
+    ///
+    /// The update holds a file lock, so concurrent installs and vx processes
+    /// don't lose increments.
+    pub fn increment(&self, counter: &str) {
+        // Counters are best-effort
+        let _ = with_file_lock(&self.path, || {
+            let mut counts = self.load();
+            *counts.entry(counter.to_string()).or_default() += 1;
+            write_json_file(&self.path, &counts)
+        });
+    }
+
+    /// Current value of `counter`
+    pub fn get(&self, counter: &str) -> u64 {
+        self.load().get(counter).copied().unwrap_or_default()
+    }
+
+    /// Every counter recorded so far
+    pub fn load(&self) -> BTreeMap<String, u64> {
+        read_json_file(&self.path).unwrap_or_default()
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+use tempfile::TempDir;
+use vx_cache::{Cassette, CassetteMode, HttpInteraction, ProcessInteraction};
+
+const URL: &str = "https://api.github.com/repos/casey/just/releases";
+
+fn json_response(body: &str) -> HttpInteraction {
+    let headers = BTreeMap::from([("content-type".to_string(), "application/json".to_string())]);
+    HttpInteraction::new("GET", URL, 200, headers, body.as_bytes())
+}
+
+#[test]
+fn test_record_then_replay_http() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("cassette.json");
+
+    let recorder = Cassette::record(&path).unwrap();
+    assert_eq!(recorder.mode(), CassetteMode::Record);
+    recorder.record_http(json_response("[1]")).unwrap();
+    let archive = [0x1f, 0x8b, 0xff, 0x00];
+    let download = HttpInteraction::new(
+        "GET",
+        "https://example.com/tool.tar.gz",
+        200,
+        BTreeMap::new(),
+        &archive,
+    )
+    .with_final_url("https://cdn.example.com/tool.tar.gz");
+    recorder.record_http(download).unwrap();
+
+    // Binary bodies are stored as base64, text as is
+    let raw: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(raw["http"][0]["body"], "[1]");
+    assert!(raw["http"][1]["body"].is_null());
+    assert!(raw["http"][1]["body_base64"].is_string());
+
+    let replay = Cassette::replay(&path).unwrap();
+    assert_eq!(replay.mode(), CassetteMode::Replay);
+    let response = replay.replay_http("get", URL).unwrap();
+    assert_eq!(response.body().unwrap(), b"[1]");
+    assert_eq!(response.header("Content-Type"), Some("application/json"));
+
+    let response = replay
+        .replay_http("GET", "https://example.com/tool.tar.gz")
+        .unwrap();
+    assert_eq!(response.body().unwrap(), archive);
+    assert_eq!(
+        response.final_url.as_deref(),
+        Some("https://cdn.example.com/tool.tar.gz")
+    );
+    assert!(
+        replay
+            .replay_http("GET", "https://example.com/other")
+            .is_none()
+    );
+}
+
+#[test]
+fn test_replay_follows_recorded_order_and_repeats_the_last() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("cassette.json");
+    let recorder = Cassette::record(&path).unwrap();
+    recorder.record_http(json_response("first")).unwrap();
+    recorder.record_http(json_response("second")).unwrap();
+
+    let replay = Cassette::replay(&path).unwrap();
+    let bodies: Vec<Vec<u8>> = (0..3)
+        .map(|_| replay.replay_http("GET", URL).unwrap().body().unwrap())
+        .collect();
+    assert_eq!(
+        bodies,
+        [b"first".to_vec(), b"second".to_vec(), b"second".to_vec()]
+    );
+}
+
+#[test]
+fn test_replay_process_matches_program_name() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("cassette.json");
+    let recorder = Cassette::record(&path).unwrap();
+    recorder
+        .record_process(ProcessInteraction {
+            program: "/home/alice/.vx/store/node/22.0.0/bin/node".to_string(),
+            args: vec!["--version".to_string()],
+            exit_code: 0,
+            stdout: Some("v22.0.0\n".to_string()),
+            stderr: Some(String::new()),
+        })
+        .unwrap();
+
+    let replay = Cassette::replay(&path).unwrap();
+    let args = vec!["--version".to_string()];
+    let run = replay
+        .replay_process("/runner/.vx/store/node/22.0.0/bin/node", &args)
+        .unwrap();
+    assert_eq!(run.exit_code, 0);
+    assert_eq!(run.stdout.as_deref(), Some("v22.0.0\n"));
+    assert!(replay.replay_process("node", &[]).is_none());
+}
+
+#[test]
+fn test_record_replaces_an_earlier_recording() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("cassette.json");
+    Cassette::record(&path)
+        .unwrap()
+        .record_http(json_response("old"))
+        .unwrap();
+
+    let recorder = Cassette::record(&path).unwrap();
+    assert!(recorder.data().http.is_empty());
+    assert!(Cassette::replay(&path).unwrap().data().http.is_empty());
+}
+
+#[test]
+fn test_replay_rejects_missing_and_foreign_cassettes() {
+    let dir = TempDir::new().unwrap();
+    let missing = dir.path().join("missing.json");
+    let err = Cassette::replay(&missing).unwrap_err();
+    assert!(
+        err.to_string().contains("Failed to read cassette"),
+        "{}",
+        err
+    );
+
+    let future = dir.path().join("future.json");
+    std::fs::write(&future, r#"{"format_version": 99}"#).unwrap();
+    let err = Cassette::replay(&future).unwrap_err();
+    assert!(err.to_string().contains("format version 99"), "{}", err);
+}
@@ -0,0 +1,122 @@
+use rstest::rstest;
+use tempfile::TempDir;
+use vx_cache::{CacheControl, HttpCache, HttpCacheHeaders, HttpCacheLookup, HttpCacheOutcome};
+
+const URL: &str = "https://nodejs.org/dist/index.json";
+
+fn headers(cache_control: Option<&str>, etag: Option<&str>) -> HttpCacheHeaders {
+    HttpCacheHeaders {
+        cache_control: cache_control.map(str::to_string),
+        etag: etag.map(str::to_string),
+        last_modified: None,
+        content_type: Some("application/json".to_string()),
+    }
+}
+
+#[rstest]
+#[case("max-age=300", false, false, Some(300))]
+#[case("public, max-age=\"60\"", false, false, Some(60))]
+#[case("no-cache, max-age=60", false, true, Some(60))]
+#[case("No-Store", true, false, None)]
+#[case("private", false, false, None)]
+fn test_parse_cache_control(
+    #[case] header: &str,
+    #[case] no_store: bool,
+    #[case] no_cache: bool,
+    #[case] max_age: Option<u64>,
+) {
+    let control = CacheControl::parse(header);
+    assert_eq!(control.no_store, no_store);
+    assert_eq!(control.no_cache, no_cache);
+    assert_eq!(control.max_age, max_age);
+}
+
+#[rstest]
+fn test_fresh_response_is_served_from_cache() {
+    let dir = TempDir::new().unwrap();
+    let cache = HttpCache::new(dir.path().to_path_buf());
+
+    assert!(matches!(cache.lookup(URL), HttpCacheLookup::Miss));
+    assert!(
+        cache
+            .store(URL, b"[1]", &headers(Some("max-age=600"), None))
+            .unwrap()
+    );
+
+    match cache.lookup(URL) {
+        HttpCacheLookup::Fresh { entry, body } => {
+            assert_eq!(body, b"[1]");
+            assert_eq!(entry.content_type.as_deref(), Some("application/json"));
+        }
+        other => panic!("expected fresh entry, got {:?}", other),
+    }
+}
+
+#[rstest]
+fn test_no_store_and_unvalidated_responses_are_not_cached() {
+    let dir = TempDir::new().unwrap();
+    let cache = HttpCache::new(dir.path().to_path_buf());
+
+    let no_store = headers(Some("no-store, max-age=600"), Some("\"abc\""));
+    assert!(!cache.store(URL, b"[1]", &no_store).unwrap());
+    assert!(!cache.store(URL, b"[1]", &headers(None, None)).unwrap());
+    assert!(matches!(cache.lookup(URL), HttpCacheLookup::Miss));
+}
+
+#[rstest]
+fn test_stale_entry_is_revalidated() {
+    let dir = TempDir::new().unwrap();
+    let cache = HttpCache::new(dir.path().to_path_buf());
+
+    let stored = headers(Some("no-cache"), Some("\"v1\""));
+    assert!(cache.store(URL, b"[1]", &stored).unwrap());
+    match cache.lookup(URL) {
+        HttpCacheLookup::Stale { entry, .. } => assert_eq!(entry.etag.as_deref(), Some("\"v1\"")),
+        other => panic!("expected stale entry, got {:?}", other),
+    }
+
+    let body = cache
+        .revalidate(URL, &headers(Some("max-age=600"), None))
+        .unwrap();
+    assert_eq!(body.as_deref(), Some(&b"[1]"[..]));
+    assert!(matches!(cache.lookup(URL), HttpCacheLookup::Fresh { .. }));
+}
+
+#[rstest]
+fn test_stats_report_hit_rate() {
+    let dir = TempDir::new().unwrap();
+    let cache = HttpCache::new(dir.path().to_path_buf());
+
+    assert_eq!(cache.stats().hit_rate(), 0.0);
+    cache
+        .store(URL, b"[1]", &headers(Some("max-age=600"), None))
+        .unwrap();
+    cache.record(HttpCacheOutcome::Miss);
+    cache.record(HttpCacheOutcome::Hit);
+    cache.record(HttpCacheOutcome::Revalidated);
+    cache.record(HttpCacheOutcome::StaleFallback);
+
+    let stats = cache.stats();
+    assert_eq!(stats.entries, 1);
+    assert_eq!(stats.fresh_entries, 1);
+    assert_eq!(stats.total_size_bytes, 3);
+    assert_eq!(stats.requests(), 4);
+    assert_eq!(stats.hit_rate(), 0.75);
+}
+
+#[rstest]
+fn test_prune_and_clear() {
+    let dir = TempDir::new().unwrap();
+    let cache = HttpCache::new(dir.path().to_path_buf());
+
+    cache
+        .store(URL, b"[1]", &headers(Some("max-age=600"), None))
+        .unwrap();
+    assert_eq!(cache.prune(None, false), 0);
+
+    cache.record(HttpCacheOutcome::Hit);
+    cache.clear().unwrap();
+    let stats = cache.stats();
+    assert_eq!(stats.entries, 0);
+    assert_eq!(stats.requests(), 0);
+}
@@ -0,0 +1,108 @@
+use rstest::rstest;
+use std::time::Duration;
+use tempfile::TempDir;
+use vx_cache::network::{MAX_SAMPLES, host_of};
+use vx_cache::{DownloadAttempt, NetworkStats};
+
+fn success(latency_ms: u64, elapsed_ms: u64, bytes: u64) -> DownloadAttempt {
+    DownloadAttempt::Success {
+        latency: Duration::from_millis(latency_ms),
+        elapsed: Duration::from_millis(elapsed_ms),
+        bytes,
+    }
+}
+
+fn failure(error: &str) -> DownloadAttempt {
+    DownloadAttempt::Failure {
+        elapsed: Duration::from_millis(100),
+        error: error.to_string(),
+    }
+}
+
+#[rstest]
+#[case("https://nodejs.org/dist/v20.0.0/node.tar.gz", Some("nodejs.org"))]
+#[case("https://GitHub.com:443/a/b", Some("github.com"))]
+#[case("http://user:pw@127.0.0.1:8080/x?y=1", Some("127.0.0.1:8080"))]
+#[case("http://mirror.local:80", Some("mirror.local"))]
+#[case("not a url", None)]
+fn test_host_of(#[case] url: &str, #[case] expected: Option<&str>) {
+    assert_eq!(host_of(url).as_deref(), expected);
+}
+
+#[test]
+fn test_network_stats_per_host() {
+    let temp = TempDir::new().unwrap();
+    let stats = NetworkStats::new(temp.path().to_path_buf());
+    assert!(stats.sources().is_empty());
+
+    for latency in [100, 200, 300, 400] {
+        stats.record(
+            "https://fast.example.com/tool.tar.gz",
+            &success(latency, 1000, 10 * 1024 * 1024),
+        );
+    }
+    stats.record(
+        "https://slow.example.com/a.zip",
+        &success(50, 10_000, 1024 * 1024),
+    );
+    stats.record("https://broken.example.com/a.zip", &failure("HTTP 503"));
+    stats.record("https://broken.example.com/a.zip", &failure("timed out"));
+    stats.record("https://broken.example.com/a.zip", &success(10, 10, 100));
+
+    let sources = stats.sources();
+    let hosts: Vec<_> = sources.iter().map(|s| s.host.as_str()).collect();
+    assert_eq!(
+        hosts,
+        ["broken.example.com", "slow.example.com", "fast.example.com"]
+    );
+
+    let broken = &sources[0];
+    assert_eq!((broken.successes, broken.failures), (1, 2));
+    assert!(broken.is_failing());
+    assert_eq!(broken.last_error.as_deref(), Some("timed out"));
+    // Tiny transfers do not count towards throughput
+    assert_eq!(broken.throughput, None);
+
+    let slow = &sources[1];
+    assert!(slow.is_slow() && !slow.is_failing());
+
+    let fast = &sources[2];
+    assert!(!fast.is_slow() && !fast.is_failing());
+    assert_eq!(fast.bytes, 40 * 1024 * 1024);
+    assert_eq!(fast.median_latency_ms, Some(200));
+    assert_eq!(fast.p95_latency_ms, Some(400));
+    assert_eq!(fast.formatted_throughput().as_deref(), Some("10.0 MB/s"));
+}
+
+#[test]
+fn test_network_stats_keep_recent_samples() {
+    let temp = TempDir::new().unwrap();
+    let stats = NetworkStats::new(temp.path().to_path_buf());
+    let url = "https://example.com/file";
+
+    for _ in 0..MAX_SAMPLES {
+        stats.record(url, &failure("HTTP 500"));
+    }
+    for _ in 0..MAX_SAMPLES {
+        stats.record(url, &success(10, 100, 1024 * 1024));
+    }
+
+    let source = &stats.sources()[0];
+    // Totals cover everything, rates only the recent window
+    assert_eq!(source.failures, MAX_SAMPLES as u64);
+    assert_eq!(source.recent_attempts, MAX_SAMPLES);
+    assert_eq!(source.recent_failure_rate, 0.0);
+    assert!(!source.is_failing());
+}
+
+#[test]
+fn test_network_stats_clear() {
+    let temp = TempDir::new().unwrap();
+    let stats = NetworkStats::new(temp.path().to_path_buf());
+    stats.record("https://example.com/file", &failure("HTTP 500"));
+    assert_eq!(stats.sources().len(), 1);
+
+    stats.clear().unwrap();
+    assert!(stats.sources().is_empty());
+    assert!(!stats.dir().exists());
+}
@@ -0,0 +1,116 @@
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use tempfile::TempDir;
+use vx_cache::DownloadCache;
+use vx_cache::peer::mdns::{parse_query, parse_response, query_packet, response_packet};
+use vx_cache::peer::{
+    DOWNLOADS_PATH, METRICS_CONTENT_TYPE, METRICS_PATH, PeerServer, PeerServerStats, SHA256_HEADER,
+};
+
+const URL: &str = "https://nodejs.org/dist/v22.0.0/node-v22.0.0-linux-x64.tar.gz";
+
+#[test]
+fn test_mdns_round_trip() {
+    let id = parse_query(&query_packet()).unwrap();
+
+    let response = response_packet(id, "build host.lan", 7878);
+    assert_eq!(parse_response(&response), vec![7878]);
+    // Answers are not queries, and queries carry no ports
+    assert_eq!(parse_query(&response), None);
+    assert!(parse_response(&query_packet()).is_empty());
+}
+
+#[test]
+fn test_mdns_ignores_garbage() {
+    assert_eq!(parse_query(&[]), None);
+    assert!(parse_response(&[0x12, 0x34, 0x84, 0x00, 0x00]).is_empty());
+    // A self-referencing compression pointer must not hang
+    let mut looping = vec![0, 0, 0x84, 0, 0, 0, 0, 1, 0, 0, 0, 0];
+    looping.extend_from_slice(&[0xC0, 12]);
+    assert!(parse_response(&looping).is_empty());
+}
+
+fn get(addr: std::net::SocketAddr, path: &str) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(stream, "GET {} HTTP/1.1\r\nhost: peer\r\n\r\n", path).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[test]
+fn test_peer_server_serves_cache_entries() {
+    let temp = TempDir::new().unwrap();
+    let cache = DownloadCache::new(temp.path().join("downloads"));
+    let source = temp.path().join("node.tar.gz");
+    std::fs::write(&source, b"node archive").unwrap();
+    cache.store(URL, &source, None, None, None).unwrap();
+
+    let key = DownloadCache::cache_key(URL);
+    let sha256 = cache.content_sha256(&key).unwrap().unwrap();
+    assert_eq!(sha256.len(), 64);
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = PeerServer::new(cache);
+    std::thread::spawn(move || server.serve(listener));
+
+    let response = get(addr, &format!("{}{}", DOWNLOADS_PATH, key));
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+    assert!(response.contains(&format!("{}: {}", SHA256_HEADER, sha256)));
+    assert!(response.ends_with("\r\n\r\nnode archive"));
+
+    let missing = get(addr, &format!("{}{}", DOWNLOADS_PATH, "0".repeat(64)));
+    assert!(missing.starts_with("HTTP/1.1 404"));
+    assert!(get(addr, "/v1/downloads/../../etc/passwd").starts_with("HTTP/1.1 404"));
+}
+
+#[test]
+fn test_peer_server_metrics_endpoint() {
+    let temp = TempDir::new().unwrap();
+    let cache = DownloadCache::new(temp.path().join("downloads"));
+    let source = temp.path().join("node.tar.gz");
+    std::fs::write(&source, b"node archive").unwrap();
+    cache.store(URL, &source, None, None, None).unwrap();
+    let key = DownloadCache::cache_key(URL);
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = PeerServer::new(cache).with_metrics(|stats| {
+        format!(
+            "hits {}\nmisses {}\nbytes {}\n",
+            stats.hits, stats.misses, stats.bytes_served
+        )
+    });
+    let handle = server.clone();
+    std::thread::spawn(move || server.serve(listener));
+
+    assert!(get(addr, &format!("{}{}", DOWNLOADS_PATH, key)).starts_with("HTTP/1.1 200 OK"));
+    assert!(
+        get(addr, &format!("{}{}", DOWNLOADS_PATH, "0".repeat(64))).starts_with("HTTP/1.1 404")
+    );
+    assert_eq!(
+        handle.stats(),
+        PeerServerStats {
+            hits: 1,
+            misses: 1,
+            bytes_served: 12,
+        }
+    );
+
+    let metrics = get(addr, METRICS_PATH);
+    assert!(metrics.starts_with("HTTP/1.1 200 OK"));
+    assert!(metrics.contains(&format!("content-type: {}", METRICS_CONTENT_TYPE)));
+    assert!(metrics.ends_with("\r\n\r\nhits 1\nmisses 1\nbytes 12\n"));
+}
+
+#[test]
+fn test_peer_server_without_metrics() {
+    let temp = TempDir::new().unwrap();
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = PeerServer::new(DownloadCache::new(temp.path().to_path_buf()));
+    std::thread::spawn(move || server.serve(listener));
+
+    assert!(get(addr, METRICS_PATH).starts_with("HTTP/1.1 404"));
+}
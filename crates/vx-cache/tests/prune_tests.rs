@@ -0,0 +1,108 @@
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use tempfile::TempDir;
+use vx_cache::{DownloadCache, PruneSummary, TempArea};
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+fn set_age(path: &Path, age: Duration) {
+    std::fs::File::options()
+        .append(true)
+        .open(path)
+        .unwrap()
+        .set_modified(SystemTime::now() - age)
+        .unwrap();
+}
+
+/// Store `size` bytes under `url`, last used `age_days` ago
+fn store(cache: &DownloadCache, temp: &TempDir, url: &str, size: usize, age_days: u32) {
+    let source = temp.path().join("source");
+    std::fs::write(&source, vec![0u8; size]).unwrap();
+    let path = cache.store(url, &source, None, None, None).unwrap();
+    set_age(&path, DAY * age_days);
+}
+
+#[test]
+fn test_download_cache_entries_oldest_first() {
+    let temp = TempDir::new().unwrap();
+    let cache = DownloadCache::new(temp.path().join("cache"));
+    store(&cache, &temp, "https://example.com/new.zip", 10, 1);
+    store(&cache, &temp, "https://example.com/old.zip", 20, 9);
+
+    let entries = cache.entries();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].size, 20);
+    assert_eq!(
+        entries[0].metadata.as_ref().unwrap().url,
+        "https://example.com/old.zip"
+    );
+    assert_eq!(entries[1].size, 10);
+}
+
+#[test]
+fn test_download_cache_prune_by_age_and_size() {
+    let temp = TempDir::new().unwrap();
+    let cache = DownloadCache::new(temp.path().join("cache"));
+    store(&cache, &temp, "https://example.com/a.zip", 100, 40);
+    store(&cache, &temp, "https://example.com/b.zip", 100, 20);
+    store(&cache, &temp, "https://example.com/c.zip", 100, 10);
+    store(&cache, &temp, "https://example.com/d.zip", 100, 0);
+
+    // A dry run removes nothing
+    let preview = cache.prune(Some(DAY * 30), None, true);
+    assert_eq!(preview.removed, 1);
+    assert_eq!(cache.entries().len(), 4);
+
+    let pruned = cache.prune(Some(DAY * 30), Some(250), false);
+    assert_eq!(
+        pruned,
+        PruneSummary {
+            removed: 2,
+            freed_bytes: 200
+        }
+    );
+    assert!(!cache.is_cached("https://example.com/a.zip"));
+    assert!(!cache.is_cached("https://example.com/b.zip"));
+    assert!(cache.is_cached("https://example.com/c.zip"));
+    assert!(cache.is_cached("https://example.com/d.zip"));
+}
+
+#[test]
+fn test_download_cache_lookup_marks_entry_used() {
+    let temp = TempDir::new().unwrap();
+    let cache = DownloadCache::new(temp.path().join("cache")).with_etag(false);
+    store(&cache, &temp, "https://example.com/a.zip", 100, 10);
+    store(&cache, &temp, "https://example.com/b.zip", 100, 5);
+
+    cache.lookup("https://example.com/a.zip");
+    let pruned = cache.prune(None, Some(100), false);
+    assert_eq!(pruned.removed, 1);
+    assert!(cache.is_cached("https://example.com/a.zip"));
+}
+
+#[test]
+fn test_temp_area_prune() {
+    let temp = TempDir::new().unwrap();
+    let area = TempArea::new(temp.path().to_path_buf());
+
+    let stale = temp.path().join("node-20.0.0");
+    std::fs::create_dir_all(stale.join("bin")).unwrap();
+    std::fs::write(stale.join("bin/node"), vec![0u8; 64]).unwrap();
+    set_age(&stale.join("bin/node"), DAY * 3);
+    std::fs::write(temp.path().join("fresh.part"), vec![0u8; 8]).unwrap();
+
+    let entries = area.entries();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].size, 64);
+
+    let pruned = area.prune(DAY, false);
+    assert_eq!(
+        pruned,
+        PruneSummary {
+            removed: 1,
+            freed_bytes: 64
+        }
+    );
+    assert!(!stale.exists());
+    assert!(temp.path().join("fresh.part").exists());
+}
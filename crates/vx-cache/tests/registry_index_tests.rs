@@ -0,0 +1,57 @@
+use tempfile::TempDir;
+use vx_cache::{IndexedRuntime, RegistryIndex};
+
+fn runtime(name: &str, aliases: &[&str]) -> IndexedRuntime {
+    IndexedRuntime {
+        name: name.to_string(),
+        aliases: aliases.iter().map(|a| a.to_string()).collect(),
+        description: format!("{} runtime", name),
+        ecosystem: None,
+        bundled_with: None,
+        platform_supported: true,
+    }
+}
+
+#[test]
+fn test_lookup_by_name_and_alias() {
+    let index = RegistryIndex::new(
+        "key",
+        "linux-x64",
+        vec![runtime("node", &["nodejs"]), runtime("go", &["golang"])],
+    );
+
+    assert_eq!(index.len(), 2);
+    assert_eq!(index.runtimes()[0].name, "go");
+    assert_eq!(index.get("node").unwrap().name, "node");
+    assert_eq!(index.get("golang").unwrap().name, "go");
+    assert!(index.get("ruby").is_none());
+    assert_eq!(
+        index.runtime_names(),
+        vec!["go", "golang", "node", "nodejs"]
+    );
+}
+
+#[test]
+fn test_save_and_load() {
+    let temp = TempDir::new().unwrap();
+    let index = RegistryIndex::new("key", "linux-x64", vec![runtime("node", &[])]);
+    index.save(temp.path()).unwrap();
+
+    let loaded = RegistryIndex::load(temp.path(), "key", "linux-x64").unwrap();
+    assert_eq!(loaded.runtimes(), index.runtimes());
+}
+
+#[test]
+fn test_load_outdated_index() {
+    let temp = TempDir::new().unwrap();
+    assert!(RegistryIndex::load(temp.path(), "key", "linux-x64").is_none());
+
+    RegistryIndex::new("key", "linux-x64", vec![runtime("node", &[])])
+        .save(temp.path())
+        .unwrap();
+    assert!(RegistryIndex::load(temp.path(), "other-key", "linux-x64").is_none());
+    assert!(RegistryIndex::load(temp.path(), "key", "windows-x64").is_none());
+
+    RegistryIndex::remove_file(temp.path()).unwrap();
+    assert!(!RegistryIndex::index_file_path(temp.path()).exists());
+}
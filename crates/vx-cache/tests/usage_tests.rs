@@ -0,0 +1,58 @@
+use tempfile::TempDir;
+use vx_cache::usage::{DOWNLOAD_CACHE_HITS, DOWNLOAD_CACHE_MISSES, INSTALLS};
+use vx_cache::{CacheLookupResult, DownloadCache, UsageCounters};
+
+#[test]
+fn test_usage_counters_persist() {
+    let temp = TempDir::new().unwrap();
+    let counters = UsageCounters::new(temp.path().join("cache"));
+    assert_eq!(counters.get(INSTALLS), 0);
+
+    counters.increment(INSTALLS);
+    counters.increment(INSTALLS);
+    assert_eq!(counters.get(INSTALLS), 2);
+
+    // Another handle on the same directory sees the same counts
+    let reopened = UsageCounters::new(temp.path().join("cache"));
+    assert_eq!(reopened.load().get(INSTALLS), Some(&2));
+    assert_eq!(reopened.get(DOWNLOAD_CACHE_HITS), 0);
+}
+
+#[test]
+fn test_concurrent_increments_are_not_lost() {
+    let temp = TempDir::new().unwrap();
+    let cache_dir = temp.path().join("cache");
+    std::thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| {
+                let counters = UsageCounters::new(&cache_dir);
+                for _ in 0..25 {
+                    counters.increment(INSTALLS);
+                }
+            });
+        }
+    });
+    assert_eq!(UsageCounters::new(&cache_dir).get(INSTALLS), 200);
+}
+
+#[test]
+fn test_download_cache_lookups_are_counted() {
+    let temp = TempDir::new().unwrap();
+    let cache = DownloadCache::new(temp.path().to_path_buf()).with_etag(false);
+    let url = "https://example.com/tool-1.0.0.tar.gz";
+
+    assert!(matches!(cache.lookup(url), CacheLookupResult::Miss));
+    let source = temp.path().join("tool.tar.gz");
+    std::fs::write(&source, b"archive").unwrap();
+    cache.store(url, &source, None, None, None).unwrap();
+    assert!(matches!(cache.lookup(url), CacheLookupResult::Hit { .. }));
+    assert!(matches!(cache.lookup(url), CacheLookupResult::Hit { .. }));
+
+    let counters = UsageCounters::new(temp.path());
+    assert_eq!(counters.get(DOWNLOAD_CACHE_HITS), 2);
+    assert_eq!(counters.get(DOWNLOAD_CACHE_MISSES), 1);
+
+    // Purging the cache keeps the counts
+    cache.clear().unwrap();
+    assert_eq!(counters.get(DOWNLOAD_CACHE_HITS), 2);
+}
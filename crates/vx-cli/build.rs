@@ -2,7 +2,8 @@
 //!
 //! This script:
 //! 1. Collects all `provider.star` manifests at compile time and embeds them
-//!    into the binary (RFC 0013: Manifest-Driven Provider Registration)
+//!    into the binary (RFC 0013: Manifest-Driven Provider Registration); the
+//!    provider.star files themselves are embedded by vx-api
 //! 2. On Windows, locates and embeds bridge binaries (e.g., `MSBuild.exe`)
 //!    so they can be deployed without shipping separate files
 
@@ -113,118 +114,6 @@ fn embed_provider_manifests() {
             }
         }
     }
-
-    // -------------------------------------------------------------------------
-    // Generate provider_stars.rs — embeds all provider.star files so that
-    // ProviderHandle can be registered at startup without filesystem access.
-    //
-    // Format: a static array of (name, star_content) pairs.
-    // -------------------------------------------------------------------------
-    embed_provider_stars(&out_dir, &providers_dir);
-}
-
-fn embed_provider_stars(out_dir: &str, providers_dir: &Path) {
-    let dest_path = Path::new(out_dir).join("provider_stars.rs");
-
-    let mut entries: Vec<(String, PathBuf, Vec<String>)> = Vec::new();
-
-    if providers_dir.exists()
-        && let Ok(dir_entries) = fs::read_dir(providers_dir)
-    {
-        for entry in dir_entries.flatten() {
-            let provider_dir = entry.path();
-            if !provider_dir.is_dir() {
-                continue;
-            }
-            let star_path = provider_dir.join("provider.star");
-            if star_path.exists() {
-                let dir_name = provider_dir
-                    .file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("unknown")
-                    .to_string();
-                // Pre-compute runtime lookup names at build time to avoid
-                // repeated StarMetadata::parse() calls at startup.
-                let runtime_names = if let Ok(content) = fs::read_to_string(&star_path) {
-                    let meta = StarMetadata::parse(&content);
-                    let mut names: Vec<String> = meta
-                        .runtimes
-                        .iter()
-                        .flat_map(|rt| {
-                            let mut n = Vec::new();
-                            if let Some(ref name) = rt.name {
-                                n.push(name.clone());
-                            }
-                            n.extend(rt.aliases.clone());
-                            n
-                        })
-                        .collect();
-                    if names.is_empty() {
-                        names.push(dir_name.clone());
-                    }
-                    names.sort();
-                    names.dedup();
-                    names
-                } else {
-                    vec![dir_name.clone()]
-                };
-                entries.push((dir_name, star_path, runtime_names));
-            }
-        }
-    }
-
-    // Sort for deterministic output
-    entries.sort_by(|a, b| a.0.cmp(&b.0));
-
-    let mut code = String::new();
-    code.push_str("// Auto-generated by build.rs — DO NOT EDIT\n");
-    code.push_str("// Embedded provider.star contents for ProviderHandle registration\n\n");
-
-    // Generate individual constants
-    for (name, star_path, _) in &entries {
-        let const_name = name.to_uppercase().replace('-', "_");
-        let path_escaped = star_path.display().to_string().replace('\\', "\\\\");
-        code.push_str(&format!(
-            "pub(crate) static PROVIDER_STAR_{}: &str = include_str!(\"{}\");\n",
-            const_name, path_escaped
-        ));
-    }
-
-    code.push('\n');
-
-    // Generate the combined array
-    code.push_str("/// All embedded provider.star contents: `(provider_name, star_content)`\n");
-    code.push_str("pub(crate) static ALL_PROVIDER_STARS: &[(&str, &str)] = &[\n");
-    for (name, _, _) in &entries {
-        let const_name = name.to_uppercase().replace('-', "_");
-        code.push_str(&format!(
-            "    (\"{}\", PROVIDER_STAR_{}),\n",
-            name, const_name
-        ));
-    }
-    code.push_str("];\n\n");
-
-    // Generate pre-computed runtime names lookup table.
-    // This avoids calling StarMetadata::parse() at startup for each provider
-    // in extract_runtime_lookup_names(), saving ~114x string parsing operations.
-    code.push_str("/// Pre-computed runtime name → provider name lookup table.\n");
-    code.push_str("/// Generated at build time from provider.star metadata.\n");
-    code.push_str("/// Format: `(runtime_or_alias_name, provider_name)`\n");
-    code.push_str("pub(crate) static PROVIDER_RUNTIME_NAMES: &[(&str, &[&str])] = &[\n");
-    for (name, _, runtime_names) in &entries {
-        if runtime_names.is_empty() {
-            continue;
-        }
-        let names_lit: Vec<String> = runtime_names.iter().map(|n| format!("\"{}\"", n)).collect();
-        code.push_str(&format!(
-            "    (\"{}\", &[{}]),\n",
-            name,
-            names_lit.join(", ")
-        ));
-    }
-    code.push_str("];\n");
-
-    fs::write(&dest_path, code).unwrap();
 }
 
 /// Extract provider metadata from a Starlark provider.star file and generate
@@ -0,0 +1,203 @@
+//! Cache pruning policy (`~/.vx/config/cache.toml`)
+//!
+//! The policy bounds the download cache by age and size, and clears
+//! leftovers of interrupted installs from the installer temp area. It is
+//! the default for `vx cache prune` and, with `auto_prune`, applied after
+//! installs at most once per `prune_interval`:
+//!
+//! ```toml
+//! # ~/.vx/config/cache.toml
+//! max_size = "5GB"          # Evict least recently used downloads beyond this
+//! older_than = "30d"        # Remove downloads unused for this long
+//! auto_prune = true         # Apply the policy after installs (default: false)
+//! prune_interval = "1d"     # At most this often (default: 1d)
+//! ```
+
+use anyhow::{Context, Result, anyhow};
+use serde::Deserialize;
+use std::path::Path;
+use std::time::Duration;
+use vx_cache::{DownloadCache, PruneSummary, TempArea};
+use vx_paths::VxPaths;
+
+/// Cache policy file name inside the vx config directory
+pub const CACHE_CONFIG_FILE: &str = "cache.toml";
+
+/// Records when the policy was last applied automatically (epoch seconds)
+const LAST_PRUNE_FILE: &str = "cache-prune";
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Installer temp entries older than this belong to interrupted installs
+pub const TEMP_MAX_AGE: Duration = vx_paths::ORPHAN_MAX_AGE;
+
+/// How `~/.vx/config/cache.toml` is written
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct CachePolicyFile {
+    max_size: Option<String>,
+    older_than: Option<String>,
+    auto_prune: bool,
+    prune_interval: Option<String>,
+}
+
+/// Limits for the download cache and when to enforce them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachePolicy {
+    /// Maximum download cache size in bytes
+    pub max_size: Option<u64>,
+    /// Downloads unused for longer than this are removed
+    pub older_than: Option<Duration>,
+    /// Apply the policy automatically after installs
+    pub auto_prune: bool,
+    /// Minimum time between automatic prunes
+    pub prune_interval: Duration,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self {
+            max_size: None,
+            older_than: None,
+            auto_prune: false,
+            prune_interval: DAY,
+        }
+    }
+}
+
+impl CachePolicy {
+    /// Load the policy from `~/.vx/config/cache.toml`
+    ///
+    /// A missing file gives the default policy; an invalid one is logged
+    /// and ignored.
+    pub fn load() -> Self {
+        let Some(path) = VxPaths::new()
+            .ok()
+            .map(|paths| paths.config_dir.join(CACHE_CONFIG_FILE))
+            .filter(|path| path.exists())
+        else {
+            return Self::default();
+        };
+        Self::from_file(&path).unwrap_or_else(|e| {
+            tracing::warn!(path = %path.display(), error = %e, "Ignoring invalid cache policy");
+            Self::default()
+        })
+    }
+
+    /// Load the policy from a config file
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Parse the policy from TOML content
+    pub fn parse(content: &str) -> Result<Self> {
+        let file: CachePolicyFile = toml::from_str(content)?;
+        Ok(Self {
+            max_size: file
+                .max_size
+                .as_deref()
+                .map(parse_size_arg)
+                .transpose()
+                .map_err(|e| anyhow!("max_size: {}", e))?,
+            older_than: file
+                .older_than
+                .as_deref()
+                .map(parse_age_arg)
+                .transpose()
+                .map_err(|e| anyhow!("older_than: {}", e))?,
+            auto_prune: file.auto_prune,
+            prune_interval: file
+                .prune_interval
+                .as_deref()
+                .map(parse_age_arg)
+                .transpose()
+                .map_err(|e| anyhow!("prune_interval: {}", e))?
+                .unwrap_or(DAY),
+        })
+    }
+
+    /// Whether the policy limits the download cache at all
+    pub fn is_limited(&self) -> bool {
+        self.max_size.is_some() || self.older_than.is_some()
+    }
+}
+
+/// Prune the download cache to `older_than`/`max_size` and clear stale
+/// installer temp files
+pub fn prune_with(
+    paths: &VxPaths,
+    older_than: Option<Duration>,
+    max_size: Option<u64>,
+    dry_run: bool,
+) -> PruneSummary {
+    let mut summary =
+        DownloadCache::new(paths.cache_dir.clone()).prune(older_than, max_size, dry_run);
+    summary.add(TempArea::new(paths.tmp_dir.clone()).prune(TEMP_MAX_AGE, dry_run));
+    summary
+}
+
+/// Apply the configured policy if `auto_prune` is on and it is due
+///
+/// Failures never affect the command that triggered the prune.
+pub fn auto_prune() {
+    let policy = CachePolicy::load();
+    if !policy.auto_prune || !policy.is_limited() {
+        return;
+    }
+    let Ok(paths) = VxPaths::new() else {
+        return;
+    };
+    let marker = paths.state_dir.join(LAST_PRUNE_FILE);
+    let now = vx_cache::now_epoch_secs();
+    let last = std::fs::read_to_string(&marker)
+        .ok()
+        .and_then(|s| s.trim().parse::<u64>().ok())
+        .unwrap_or(0);
+    if now.saturating_sub(last) < policy.prune_interval.as_secs() {
+        return;
+    }
+
+    let summary = prune_with(&paths, policy.older_than, policy.max_size, false);
+    let _ = vx_cache::atomic_write_string(&marker, &now.to_string());
+    if summary.removed > 0 {
+        tracing::debug!(
+            removed = summary.removed,
+            freed = %vx_cache::format_size(summary.freed_bytes),
+            "Pruned cache by policy"
+        );
+    }
+}
+
+/// Parse an age such as `30d`, `12h`, `2w`, `90m` or `45s`; a bare number is days
+pub fn parse_age_arg(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("invalid age '{}' (expected e.g. 30d, 12h)", value))?;
+    let unit_secs = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "d" | "day" | "days" => DAY.as_secs(),
+        "w" | "week" | "weeks" => 7 * DAY.as_secs(),
+        "h" | "hour" | "hours" => 60 * 60,
+        "m" | "min" | "mins" | "minutes" => 60,
+        "s" | "sec" | "secs" | "seconds" => 1,
+        _ => {
+            return Err(format!(
+                "invalid age unit in '{}' (use s, m, h, d or w)",
+                value
+            ));
+        }
+    };
+    Ok(Duration::from_secs(number * unit_secs))
+}
+
+/// Parse a size such as `5GB` or `512MiB` (binary units)
+pub fn parse_size_arg(value: &str) -> Result<u64, String> {
+    vx_config::parse_size(value)
+        .ok_or_else(|| format!("invalid size '{}' (expected e.g. 5GB, 500MB)", value))
+}
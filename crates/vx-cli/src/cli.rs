@@ -13,7 +13,7 @@ use crate::commands::{
 
 use anyhow::Result;
 use async_trait::async_trait;
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 use vx_runtime::CacheMode;
 
@@ -104,6 +104,16 @@ pub enum Channel {
     Dev,
 }
 
+/// Document format for `vx sbom`
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SbomFormat {
+    /// CycloneDX 1.5 JSON (default)
+    #[default]
+    Cyclonedx,
+    /// SPDX 2.3 JSON
+    Spdx,
+}
+
 #[derive(Parser)]
 #[command(name = "vx")]
 #[command(about = "Universal version executor for development tools")]
@@ -180,6 +190,7 @@ GLOBAL OPTIONS:
   --compact / -u      Ultra-compact output (shortcut for --output-format compact)
   --output-format     Explicit output mode: text|json|toon|compact
   --cache-mode        Cache strategy: normal|refresh|offline|no-cache
+  --isolated          Run with a throwaway VX_HOME (real store/config untouched)
 
 EXAMPLES:
   vx node --version
@@ -285,6 +296,18 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub no_auto_install: bool,
 
+    /// Run with a throwaway VX_HOME that never touches the real store or config.
+    ///
+    /// A temporary directory is created for the duration of the command and
+    /// removed afterwards. Useful for trialing risky operations or testing.
+    /// Equivalent to setting `VX_ISOLATED=1`.
+    ///
+    /// Examples:
+    ///   vx --isolated install node@22
+    ///   vx --isolated node --version
+    #[arg(long, global = true)]
+    pub isolated: bool,
+
     /// Field mask: comma-separated list of fields to include in output.
     ///
     /// Reduces context-window consumption for AI agents by returning only the
@@ -336,6 +359,7 @@ impl From<&Cli> for GlobalOptions {
             output_format,
             no_auto_install: cli.no_auto_install,
             fields: cli.fields.clone(),
+            isolated: crate::isolation::isolation_requested(cli.isolated),
         }
     }
 }
@@ -347,68 +371,30 @@ pub enum Commands {
     // =========================================================================
     /// Install tool(s) - supports multiple tools at once
     #[command(alias = "i")]
-    Install {
-        /// Tools to install (e.g., uv, node@22, go@1.22, rust)
-        #[arg(required = true, num_args = 1..)]
-        tools: Vec<String>,
-        /// Force reinstallation even if already installed
-        #[arg(short, long)]
-        force: bool,
-    },
+    Install(commands::install::Args),
 
     /// Uninstall tool versions from global store
-    Uninstall {
-        /// Tool name (e.g., python, python@3.7)
-        tool: String,
-        /// Version to uninstall (optional, removes all if not specified)
-        version: Option<String>,
-        /// Force removal without confirmation
-        #[arg(short, long)]
-        force: bool,
-    },
+    Uninstall(UninstallArgs),
 
     /// List installed tools and available runtimes
     #[command(alias = "ls")]
-    List {
-        /// Tool name to show details for (optional)
-        tool: Option<String>,
-        /// Show installation status for tools
-        #[arg(long)]
-        status: bool,
-        /// Show only installed tools
-        #[arg(long)]
-        installed: bool,
-        /// Show only available tools
-        #[arg(long)]
-        available: bool,
-        /// Show all tools including those not supported on current platform
+    List(commands::list::Args),
+
+    /// Show the tools that depend on a tool
+    ///
+    /// Examples:
+    ///   vx rdeps node
+    ///   vx rdeps node --all
+    Rdeps {
+        /// Tool name
+        tool: String,
+        /// Include tools that are not installed
         #[arg(long, short = 'a')]
         all: bool,
-        /// Show system tools (discovered from PATH and known locations)
-        #[arg(long)]
-        system: bool,
-        /// When used with --system, also detect tool versions (slower)
-        #[arg(long, requires = "system")]
-        version_check: bool,
     },
 
     /// Show available versions for a tool
-    Versions {
-        /// Tool name
-        tool: String,
-        /// Show only latest versions (limit results)
-        #[arg(long)]
-        latest: Option<usize>,
-        /// Include pre-release versions
-        #[arg(long)]
-        prerelease: bool,
-        /// Show detailed version information
-        #[arg(long)]
-        detailed: bool,
-        /// Interactive mode for version selection
-        #[arg(short, long)]
-        interactive: bool,
-    },
+    Versions(VersionsArgs),
 
     /// Show which tool version is being used
     #[command(alias = "where")]
@@ -421,22 +407,7 @@ pub enum Commands {
     },
 
     /// Search available tools
-    Search {
-        /// Search query
-        query: Option<String>,
-        /// Filter by category
-        #[arg(long)]
-        category: Option<String>,
-        /// Show only installed tools
-        #[arg(long)]
-        installed_only: bool,
-        /// Show only available (not installed) tools
-        #[arg(long)]
-        available_only: bool,
-        /// Show verbose information
-        #[arg(short, long)]
-        verbose: bool,
-    },
+    Search(SearchArgs),
 
     /// Global package management (RFC 0025)
     ///
@@ -452,115 +423,13 @@ pub enum Commands {
     },
 
     /// Test runtime availability and providers (CI-friendly)
-    Test {
-        /// Runtime name to test (e.g., "yarn", "node", "go")
-        runtime: Option<String>,
-
-        /// Test all registered runtimes
-        #[arg(long, conflicts_with_all = &["runtime", "extension", "local"])]
-        all: bool,
-
-        /// Test a provider from URL
-        #[arg(long, conflicts_with_all = &["runtime", "all", "local"])]
-        extension: Option<String>,
-
-        /// Test a local provider directory (for development)
-        #[arg(long, conflicts_with_all = &["runtime", "all", "extension"])]
-        local: Option<PathBuf>,
-
-        /// Only test platform support (no installation required)
-        #[arg(long)]
-        platform_only: bool,
-
-        /// Run functional tests (execute --version, etc.)
-        #[arg(long)]
-        functional: bool,
-
-        /// Test installation process
-        #[arg(long)]
-        install: bool,
-
-        /// Full CI test: install + functional tests for all runtimes
-        #[arg(long)]
-        ci: bool,
-
-        /// Specify runtimes to test in CI mode (comma-separated)
-        #[arg(long, value_delimiter = ',')]
-        ci_runtimes: Option<Vec<String>>,
-
-        /// Skip these runtimes in CI mode (comma-separated)
-        #[arg(long, value_delimiter = ',')]
-        ci_skip: Option<Vec<String>>,
-
-        /// Timeout for each runtime test in seconds (default: 300)
-        #[arg(long, default_value = "300")]
-        timeout: u64,
-
-        /// Continue testing even if some runtimes fail
-        #[arg(long)]
-        keep_going: bool,
-
-        /// Use a custom VX root directory for testing (isolated environment)
-        #[arg(long)]
-        vx_root: Option<PathBuf>,
-
-        /// Use a temporary directory as VX root (auto-cleaned after test)
-        #[arg(long)]
-        temp_root: bool,
-
-        /// Clean up after CI test: uninstall runtimes and verify removal
-        #[arg(long)]
-        cleanup: bool,
-
-        /// Check if runtime is installed in vx store
-        #[arg(long)]
-        installed: bool,
-
-        /// Check if runtime is available on system PATH
-        #[arg(long)]
-        system: bool,
-
-        /// Show detailed test information
-        #[arg(long)]
-        detailed: bool,
-
-        /// Silent mode: exit code only, no output
-        #[arg(short, long)]
-        quiet: bool,
-
-        /// JSON output format (for CI integration)
-        #[arg(long)]
-        json: bool,
-
-        /// Verbose output (show all test steps)
-        #[arg(short, long)]
-        verbose: bool,
-    },
+    Test(commands::test::Args),
 
     // =========================================================================
     // Project Management
     // =========================================================================
     /// Initialize vx configuration for current project
-    Init {
-        /// Interactive initialization
-        #[arg(short, long)]
-        interactive: bool,
-        /// Use predefined template
-        #[arg(short, long)]
-        template: Option<String>,
-        /// Specify tools to include (comma-separated)
-        #[arg(long)]
-        tools: Option<String>,
-        /// Force overwrite existing configuration
-        #[arg(short, long)]
-        force: bool,
-        /// Preview configuration without creating file
-        #[arg(long)]
-        dry_run: bool,
-        /// List available templates
-        #[arg(long)]
-        list_templates: bool,
-    },
+    Init(InitArgs),
 
     /// Add one or more tools to project configuration (vx.toml + vx.lock)
     ///
@@ -573,32 +442,7 @@ pub enum Commands {
     ///   vx add pwsh@7.4 --os windows     # platform-scoped
     ///   vx add node --no-install         # only edit vx.toml + vx.lock
     ///   vx add node --dry-run            # preview changes
-    Add {
-        /// Tools to add (e.g., node, python@3.12, uv@latest)
-        #[arg(required = true, num_args = 1..)]
-        tools: Vec<String>,
-        /// Don't install tools after adding (only edit vx.toml + vx.lock)
-        #[arg(long)]
-        no_install: bool,
-        /// Don't update vx.lock
-        #[arg(long)]
-        no_lock: bool,
-        /// Fail if resolution would change vx.lock
-        #[arg(long)]
-        frozen: bool,
-        /// Preview changes without writing files
-        #[arg(long)]
-        dry_run: bool,
-        /// Overwrite existing entries even when the version matches
-        #[arg(long)]
-        force: bool,
-        /// Restrict to specific platforms (comma-separated: windows,linux,macos)
-        #[arg(long, value_delimiter = ',')]
-        os: Vec<String>,
-        /// Show verbose output
-        #[arg(short, long)]
-        verbose: bool,
-    },
+    Add(AddArgs),
 
     /// Remove one or more tools from project configuration (vx.toml + vx.lock)
     ///
@@ -618,60 +462,86 @@ pub enum Commands {
     },
 
     /// Sync project tools from vx.toml
-    Sync {
-        /// Only check, don't install
-        #[arg(long)]
-        check: bool,
-        /// Force reinstall all tools
-        #[arg(short, long)]
-        force: bool,
-        /// Preview operations without executing
-        #[arg(long)]
-        dry_run: bool,
-        /// Show verbose output
-        #[arg(short, long)]
-        verbose: bool,
-        /// Disable parallel installation
-        #[arg(long)]
-        no_parallel: bool,
-        /// Disable auto-install
-        #[arg(long)]
-        no_auto_install: bool,
-        /// Automatically generate/update vx.lock if needed
+    Sync(SyncArgs),
+
+    /// Generate or update vx.lock for reproducible environments
+    Lock(LockArgs),
+
+    /// Upgrade outdated project tools within vx.toml constraints
+    Update(UpdateArgs),
+
+    /// Report project tools with newer or end-of-life versions
+    Outdated {
+        /// Only list tools pinned to an end-of-life version
         #[arg(long)]
-        auto_lock: bool,
+        eol: bool,
     },
 
-    /// Generate or update vx.lock for reproducible environments
-    Lock {
-        /// Update all tools to latest compatible versions
+    /// Bulk-upgrade installed tools to their latest releases
+    Upgrade {
+        /// Installed tools to upgrade (requires --all when omitted)
+        tools: Vec<String>,
+        /// Upgrade every installed tool
         #[arg(long)]
-        update: bool,
-        /// Update specific tool only
+        all: bool,
+        /// Print the upgrade plan without installing
         #[arg(long)]
-        tool: Option<String>,
-        /// Preview changes without writing
+        dry_run: bool,
+    },
+
+    /// Restore the version that was active before the last install or upgrade
+    Rollback {
+        /// Tool to roll back
+        tool: String,
+        /// Print the plan without changing anything
         #[arg(long)]
         dry_run: bool,
-        /// Check lock file consistency with vx.toml (don't update)
+    },
+
+    /// Show release notes between two versions of a tool
+    Changelog {
+        /// Tool name (e.g. node, go, uv)
+        tool: String,
+        /// Versions to show: FROM..TO, FROM.., ..TO or a single version (default: latest)
+        range: Option<String>,
+        /// Maximum number of releases to show
+        #[arg(long, default_value = "20")]
+        limit: usize,
+    },
+
+    /// Generate a software bill of materials for the installed toolchain
+    ///
+    /// Examples:
+    ///   vx sbom > vx.cdx.json
+    ///   vx sbom --format spdx --output vx.spdx.json
+    Sbom {
+        /// Document format
+        #[arg(long, value_enum, default_value = "cyclonedx")]
+        format: SbomFormat,
+        /// Write to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Diagnose what slows vx down on this machine (e.g. antivirus scanning)
+    ///
+    /// Examples:
+    ///   vx doctor
+    ///   vx doctor --exclude-store
+    Doctor {
+        /// Output as JSON
         #[arg(long)]
-        check: bool,
-        /// Show verbose output
+        json: bool,
+        /// Register the vx store as an antivirus exclusion (needs administrator rights)
+        #[arg(long)]
+        exclude_store: bool,
+        /// Do not ask for confirmation before changing antivirus settings
         #[arg(short, long)]
-        verbose: bool,
+        yes: bool,
     },
 
     /// Check version constraints and tool availability (RFC 0023)
-    Check {
-        /// Tool name to check (optional, checks all if not specified)
-        tool: Option<String>,
-        /// Show detailed information about each tool
-        #[arg(long, short = 'd')]
-        detailed: bool,
-        /// Quiet mode: exit code only, no output
-        #[arg(long, short = 'q')]
-        quiet: bool,
-    },
+    Check(CheckArgs),
 
     /// Create offline development environment bundle
     Bundle {
@@ -679,21 +549,71 @@ pub enum Commands {
         command: BundleCommand,
     },
 
-    /// Run a script defined in vx.toml
-    Run {
-        /// Script name (use --list to see available scripts)
-        script: Option<String>,
-        /// List available scripts
-        #[arg(long, short = 'l')]
-        list: bool,
-        /// Show help for the run command or script-specific help
-        #[arg(long, short = 'H', action = clap::ArgAction::SetTrue)]
-        script_help: bool,
-        /// Additional arguments to pass to the script
-        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
-        args: Vec<String>,
+    /// Export the project toolchain to another package manager's manifest
+    ///
+    /// Examples:
+    ///   vx export brewfile > Brewfile
+    ///   vx export scoop --output scoop.json
+    Export {
+        #[command(subcommand)]
+        command: ExportCommand,
     },
 
+    /// Import a winget or Chocolatey package as a vx provider
+    ///
+    /// Converts the package manifest into ~/.vx/providers/<name>/provider.star
+    /// and installs the tool.
+    ///
+    /// Examples:
+    ///   vx import winget jqlang.jq
+    ///   vx import choco ripgrep@14.1.0 --no-install
+    Import {
+        #[command(subcommand)]
+        command: ImportCommand,
+    },
+
+    /// Install vx and the project's locked tools on another machine
+    ///
+    /// Examples:
+    ///   vx bootstrap ssh ci@build-agent-1
+    ///   vx bootstrap script > bootstrap.sh
+    Bootstrap {
+        #[command(subcommand)]
+        command: BootstrapCommand,
+    },
+
+    /// Generate checked-in wrapper scripts that run tools through vx
+    ///
+    /// The wrappers install vx when it is missing, so contributors can build
+    /// the project without vx preinstalled (like gradlew).
+    ///
+    /// Examples:
+    ///   vx wrap node npm python
+    ///   vx wrap --dir scripts/bin
+    Wrap {
+        /// Tools to wrap (default: all tools in vx.toml)
+        tools: Vec<String>,
+        /// Directory for the wrappers, relative to the project root
+        #[arg(long, default_value = "tools")]
+        dir: PathBuf,
+        /// Overwrite files that were not generated by `vx wrap`
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Generate CI configuration that uses the project's pinned toolchain
+    ///
+    /// Examples:
+    ///   vx ci generate
+    ///   vx ci generate --provider gitlab --script test
+    Ci {
+        #[command(subcommand)]
+        command: CiCommand,
+    },
+
+    /// Run a script defined in vx.toml
+    Run(RunArgs),
+
     /// Analyze project dependencies, scripts, and tools
     Analyze {
         /// Output as JSON
@@ -708,61 +628,35 @@ pub enum Commands {
     // Environment Management
     // =========================================================================
     /// Enter development environment with all project tools
-    Dev {
-        /// Shell to use (auto-detected if not specified)
-        #[arg(long)]
-        shell: Option<String>,
-        /// Run a command in the dev environment instead of spawning a shell
-        #[arg(short, long, num_args = 1..)]
-        command: Option<Vec<String>>,
-        /// Don't install missing tools
-        #[arg(long)]
-        no_install: bool,
-        /// Show verbose output
-        #[arg(short, long)]
-        verbose: bool,
-        /// Export environment variables for shell activation
-        #[arg(long, short = 'e')]
-        export: bool,
-        /// Output format for --export: shell, powershell, batch, github
-        #[arg(long, short = 'f')]
-        format: Option<String>,
-        /// Show detailed environment information (tools, paths, conflicts)
-        #[arg(long, short = 'i')]
-        info: bool,
-        /// Inherit system PATH (disable isolation mode)
-        ///
-        /// By default, vx dev uses isolation mode where only vx-managed tools
-        /// are available. Use this flag to include system tools in PATH.
-        #[arg(long)]
-        inherit_system: bool,
-        /// Additional environment variables to pass through (can be specified multiple times)
-        ///
-        /// Supports glob patterns like SSH_*, GITHUB_*
-        #[arg(long = "passenv", short = 'p', action = clap::ArgAction::Append)]
-        passenv: Vec<String>,
-    },
+    Dev(commands::dev::Args),
 
     /// Setup development environment (install all project tools)
-    Setup {
-        /// Force reinstall all tools
-        #[arg(short, long)]
-        force: bool,
-        /// Preview operations without executing
-        #[arg(long)]
-        dry_run: bool,
-        /// Show verbose output
+    ///
+    /// With --global, set up vx itself on this machine instead: the bin
+    /// directory, PATH, shell integration and default preferences.
+    ///
+    /// Examples:
+    ///   vx setup
+    ///   vx setup --global
+    ///   vx setup --global --yes   # accept the defaults (non-interactive)
+    Setup(SetupArgs),
+
+    /// Walk a new contributor through trusting the project, installing tools,
+    /// hooks and services, and verifying the environment
+    ///
+    /// Progress is saved in .vx/onboard.json, so an interrupted run resumes
+    /// where it stopped.
+    ///
+    /// Examples:
+    ///   vx onboard
+    ///   vx onboard --yes       # accept every step (non-interactive)
+    Onboard {
+        /// Accept every step without prompting
         #[arg(short, long)]
-        verbose: bool,
-        /// Disable parallel installation
-        #[arg(long)]
-        no_parallel: bool,
-        /// Skip lifecycle hooks (pre_setup, post_setup)
-        #[arg(long)]
-        no_hooks: bool,
-        /// CI mode: output tool paths for CI environment
+        yes: bool,
+        /// Discard saved progress and start from the first step
         #[arg(long)]
-        ci: bool,
+        restart: bool,
     },
 
     /// Environment management
@@ -771,6 +665,66 @@ pub enum Commands {
         command: EnvCommand,
     },
 
+    /// Print a script that activates project tools in the current shell
+    ///
+    /// Examples:
+    ///   eval "$(vx activate)"
+    ///   vx activate --shell fish | source
+    ///   vx activate my-env --shell powershell | Out-String | Invoke-Expression
+    Activate {
+        /// Environment name (defaults to the project's vx.toml)
+        env: Option<String>,
+        /// Target shell: bash, zsh, fish, nu, powershell, cmd (auto-detected if not specified)
+        #[arg(long)]
+        shell: Option<String>,
+    },
+
+    /// Print a script that restores the shell after `vx activate`
+    ///
+    /// Example:
+    ///   eval "$(vx deactivate)"
+    Deactivate {
+        /// Target shell: bash, zsh, fish, nu, powershell, cmd (auto-detected if not specified)
+        #[arg(long)]
+        shell: Option<String>,
+    },
+
+    /// Run a command with ad-hoc tool versions (without touching vx.toml)
+    ///
+    /// Tools are selected with the global `--with` flag and installed on
+    /// demand; the command runs with them prepended to PATH.
+    ///
+    /// Examples:
+    ///   vx exec --with node@18 -- node --version
+    ///   vx exec --with node@18 --with python@3.11 -- npm run build
+    Exec {
+        /// Command and arguments to run
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true, required = true)]
+        command: Vec<String>,
+    },
+
+    /// Run a command once per tool version (local CI version matrix)
+    ///
+    /// Each `--tool` lists the versions of one tool; several `--tool` flags
+    /// run every combination. Versions are installed on demand and vx.toml
+    /// is left untouched.
+    ///
+    /// Examples:
+    ///   vx matrix --tool node=18,20,22 -- npm test
+    ///   vx matrix --tool python=3.10,3.11,3.12 --parallel -- python -m pytest
+    ///   vx matrix --tool node=18,20 --tool pnpm=8,9 --json -- pnpm test
+    Matrix(MatrixArgs),
+
+    /// Android SDK helpers (licenses and SDK packages)
+    ///
+    /// Examples:
+    ///   vx android setup
+    ///   vx android setup "platform-tools" "platforms;android-35" "build-tools;35.0.0"
+    Android {
+        #[command(subcommand)]
+        command: AndroidCommand,
+    },
+
     // =========================================================================
     // Cache & Maintenance
     // =========================================================================
@@ -780,6 +734,12 @@ pub enum Commands {
         command: CacheCommand,
     },
 
+    /// Store management (export store entries as OCI artifacts)
+    Store {
+        #[command(subcommand)]
+        command: StoreCommand,
+    },
+
     // =========================================================================
     // Configuration
     // =========================================================================
@@ -790,6 +750,21 @@ pub enum Commands {
         command: Option<ConfigCommand>,
     },
 
+    /// Share presets through a preset index (git repo or static JSON)
+    ///
+    /// A preset carries the tools, scripts, hooks and services of a vx.toml.
+    /// The index is given with --index or VX_PRESET_INDEX: a directory, a
+    /// URL, or github:owner/repo.
+    ///
+    /// Examples:
+    ///   vx preset search ml --index github:acme/vx-presets
+    ///   vx preset apply python-ml --index github:acme/vx-presets
+    ///   vx preset publish python-ml --index ../vx-presets -d "Python for ML"
+    Preset {
+        #[command(subcommand)]
+        command: PresetCommand,
+    },
+
     // =========================================================================
     // Shell Integration
     // =========================================================================
@@ -850,40 +825,43 @@ pub enum Commands {
     Version,
 
     /// View execution performance metrics and reports
-    Metrics {
-        #[command(subcommand)]
-        command: Option<MetricsCommand>,
-        /// Number of recent runs to show (default: 10)
-        #[arg(long, short = 'n', default_value = "10")]
-        last: usize,
-        /// Output as JSON (AI-friendly summary)
+    Metrics(MetricsArgs),
+
+    /// Show local download statistics per source
+    Stats {
+        /// Show per-source download success, latency and throughput
         #[arg(long)]
-        json: bool,
-        /// Export interactive HTML report (optionally specify output path)
+        network: bool,
+        /// Output as JSON
         #[arg(long)]
-        html: Option<String>,
-        /// Remove all metrics data
+        json: bool,
+        /// Forget all recorded statistics
         #[arg(long)]
-        clean: bool,
+        clear: bool,
     },
 
     /// Update vx itself to the latest version
     #[command(name = "self-update")]
-    SelfUpdate {
-        /// Only check for updates, don't install
-        #[arg(long)]
-        check: bool,
-        /// Specific version to install
-        version: Option<String>,
-        /// GitHub token for authenticated API requests
-        #[arg(long)]
-        token: Option<String>,
-        /// Update channel (stable, beta, dev)
-        #[arg(long, value_enum)]
-        channel: Option<Channel>,
-        /// Force update even if already up to date
-        #[arg(short, long)]
-        force: bool,
+    SelfUpdate(SelfUpdateArgs),
+
+    /// Interactive dashboard of tools, updates, disk usage and services
+    Ui,
+
+    /// Serve JSON-RPC over stdio for build systems and editors
+    ///
+    /// Reads one JSON-RPC 2.0 request per line on stdin and writes responses
+    /// and progress notifications to stdout. Methods: list, resolve, install,
+    /// execute, shutdown.
+    ///
+    /// Examples:
+    ///   echo '{"jsonrpc":"2.0","id":1,"method":"resolve","params":{"tool":"node"}}' | vx rpc
+    Rpc,
+
+    /// List the runtimes this vx build supports and how they install
+    Capabilities {
+        /// Output format (defaults to the global --output-format)
+        #[arg(long, short = 'f', value_enum)]
+        format: Option<OutputFormat>,
     },
 
     /// Show system information and capabilities
@@ -894,26 +872,13 @@ pub enum Commands {
         /// Show build warnings and diagnostics
         #[arg(long)]
         warnings: bool,
+        /// Show machine information: platform, CPUs, NVIDIA GPU and CUDA
+        #[arg(long, conflicts_with = "warnings")]
+        system: bool,
     },
 
     /// Migrate configuration and data to latest format
-    Migrate {
-        /// Path to project directory (default: current directory)
-        #[arg(short, long)]
-        path: Option<String>,
-        /// Preview changes without writing
-        #[arg(long)]
-        dry_run: bool,
-        /// Create backup before migration
-        #[arg(long, default_value_t = true, action = clap::ArgAction::Set)]
-        backup: bool,
-        /// Only check which migrations are needed
-        #[arg(long)]
-        check: bool,
-        /// Show verbose output
-        #[arg(short, long)]
-        verbose: bool,
-    },
+    Migrate(MigrateArgs),
 
     // =========================================================================
     // Authentication
@@ -947,6 +912,8 @@ pub enum Commands {
     ///   vx provider add ./my-tool/provider.star
     ///   vx provider list
     ///   vx provider remove my-tool
+    ///   vx provider dev ./my-tool/
+    #[command(alias = "plugin")]
     Provider {
         #[command(subcommand)]
         command: ProviderCommand,
@@ -976,6 +943,334 @@ pub enum Commands {
     },
 }
 
+/// Arguments of `vx add`
+#[derive(Args, Clone)]
+pub struct AddArgs {
+    /// Tools to add (e.g., node, python@3.12, uv@latest)
+    #[arg(required = true, num_args = 1..)]
+    pub tools: Vec<String>,
+    /// Don't install tools after adding (only edit vx.toml + vx.lock)
+    #[arg(long)]
+    pub no_install: bool,
+    /// Don't update vx.lock
+    #[arg(long)]
+    pub no_lock: bool,
+    /// Fail if resolution would change vx.lock
+    #[arg(long)]
+    pub frozen: bool,
+    /// Preview changes without writing files
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Overwrite existing entries even when the version matches
+    #[arg(long)]
+    pub force: bool,
+    /// Restrict to specific platforms (comma-separated: windows,linux,macos)
+    #[arg(long, value_delimiter = ',')]
+    pub os: Vec<String>,
+    /// Show verbose output
+    #[arg(short, long)]
+    pub verbose: bool,
+}
+
+/// Arguments of `vx sync`
+#[derive(Args, Clone)]
+pub struct SyncArgs {
+    /// Only check, don't install
+    #[arg(long)]
+    pub check: bool,
+    /// Force reinstall all tools
+    #[arg(short, long)]
+    pub force: bool,
+    /// Preview operations without executing
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Show verbose output
+    #[arg(short, long)]
+    pub verbose: bool,
+    /// Disable parallel installation
+    #[arg(long)]
+    pub no_parallel: bool,
+    /// Disable auto-install
+    #[arg(long)]
+    pub no_auto_install: bool,
+    /// Automatically generate/update vx.lock if needed
+    #[arg(long)]
+    pub auto_lock: bool,
+    /// Retries of a failed installation (default: `[settings] install_retries` or 2)
+    #[arg(long, value_name = "N")]
+    pub retries: Option<u32>,
+}
+
+/// Arguments of `vx setup`
+#[derive(Args, Clone)]
+pub struct SetupArgs {
+    /// Force reinstall all tools
+    #[arg(short, long)]
+    pub force: bool,
+    /// Preview operations without executing
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Show verbose output
+    #[arg(short, long)]
+    pub verbose: bool,
+    /// Disable parallel installation
+    #[arg(long)]
+    pub no_parallel: bool,
+    /// Skip lifecycle hooks (pre_setup, post_setup)
+    #[arg(long)]
+    pub no_hooks: bool,
+    /// CI mode: output tool paths for CI environment
+    #[arg(long)]
+    pub ci: bool,
+    /// Set up vx on this machine (PATH, shell integration, preferences)
+    #[arg(long, conflicts_with = "ci")]
+    pub global: bool,
+    /// With --global: accept the defaults without prompting
+    #[arg(short, long, requires = "global")]
+    pub yes: bool,
+}
+
+/// Arguments of `vx init`
+#[derive(Args, Clone)]
+pub struct InitArgs {
+    /// Interactive initialization
+    #[arg(short, long)]
+    pub interactive: bool,
+    /// Use predefined template
+    #[arg(short, long)]
+    pub template: Option<String>,
+    /// Specify tools to include (comma-separated)
+    #[arg(long)]
+    pub tools: Option<String>,
+    /// Force overwrite existing configuration
+    #[arg(short, long)]
+    pub force: bool,
+    /// Preview configuration without creating file
+    #[arg(long)]
+    pub dry_run: bool,
+    /// List available templates
+    #[arg(long)]
+    pub list_templates: bool,
+    /// Set a template variable (NAME=VALUE, repeatable)
+    #[arg(long = "var", value_name = "NAME=VALUE")]
+    pub vars: Vec<String>,
+}
+
+/// Arguments of `vx run`
+#[derive(Args, Clone)]
+pub struct RunArgs {
+    /// Script name (use --list to see available scripts)
+    pub script: Option<String>,
+    /// List available scripts
+    #[arg(long, short = 'l')]
+    pub list: bool,
+    /// Show help for the run command or script-specific help
+    #[arg(long, short = 'H', action = clap::ArgAction::SetTrue)]
+    pub script_help: bool,
+    /// Dependency scripts to run in parallel (default: number of CPUs)
+    ///
+    /// Long form only, so `-j` keeps reaching scripts like `cargo build -j 4`.
+    #[arg(long, value_parser = clap::value_parser!(usize))]
+    pub jobs: Option<usize>,
+    /// Re-run the script when watched files change
+    #[arg(long)]
+    pub watch: bool,
+    /// Additional arguments to pass to the script
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+    pub args: Vec<String>,
+}
+
+/// Arguments of `vx versions`
+#[derive(Args, Clone)]
+pub struct VersionsArgs {
+    /// Tool name
+    pub tool: String,
+    /// Show only latest versions (limit results)
+    #[arg(long)]
+    pub latest: Option<usize>,
+    /// Include pre-release versions
+    #[arg(long)]
+    pub prerelease: bool,
+    /// Show detailed version information
+    #[arg(long)]
+    pub detailed: bool,
+    /// Interactive mode for version selection
+    #[arg(short, long)]
+    pub interactive: bool,
+}
+
+/// Arguments of `vx search`
+#[derive(Args, Clone)]
+pub struct SearchArgs {
+    /// Search query
+    pub query: Option<String>,
+    /// Filter by category
+    #[arg(long)]
+    pub category: Option<String>,
+    /// Show only installed tools
+    #[arg(long)]
+    pub installed_only: bool,
+    /// Show only available (not installed) tools
+    #[arg(long)]
+    pub available_only: bool,
+    /// Show verbose information
+    #[arg(short, long)]
+    pub verbose: bool,
+}
+
+/// Arguments of `vx lock`
+#[derive(Args, Clone)]
+pub struct LockArgs {
+    /// Update all tools to latest compatible versions
+    #[arg(long)]
+    pub update: bool,
+    /// Update specific tool only
+    #[arg(long)]
+    pub tool: Option<String>,
+    /// Preview changes without writing
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Check lock file consistency with vx.toml (don't update)
+    #[arg(long)]
+    pub check: bool,
+    /// Show verbose output
+    #[arg(short, long)]
+    pub verbose: bool,
+}
+
+/// Arguments of `vx update`
+#[derive(Args, Clone)]
+pub struct UpdateArgs {
+    /// Tools to update (requires --all when omitted)
+    pub tools: Vec<String>,
+    /// Update every tool in vx.toml
+    #[arg(long)]
+    pub all: bool,
+    /// Install the updates and refresh vx.lock (default: only report)
+    #[arg(long)]
+    pub apply: bool,
+    /// Write a Markdown summary for PR descriptions (`-` for stdout)
+    #[arg(long, value_name = "FILE")]
+    pub summary: Option<PathBuf>,
+    /// Print the plan `--apply` would carry out without installing
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// Arguments of `vx metrics`
+#[derive(Args, Clone)]
+pub struct MetricsArgs {
+    #[command(subcommand)]
+    pub command: Option<MetricsCommand>,
+    /// Number of recent runs to show (default: 10)
+    #[arg(long, short = 'n', default_value = "10")]
+    pub last: usize,
+    /// Output as JSON (AI-friendly summary)
+    #[arg(long)]
+    pub json: bool,
+    /// Export interactive HTML report (optionally specify output path)
+    #[arg(long)]
+    pub html: Option<String>,
+    /// Remove all metrics data
+    #[arg(long)]
+    pub clean: bool,
+}
+
+/// Arguments of `vx self-update`
+#[derive(Args, Clone)]
+pub struct SelfUpdateArgs {
+    /// Only check for updates, don't install
+    #[arg(long)]
+    pub check: bool,
+    /// Specific version to install
+    pub version: Option<String>,
+    /// GitHub token for authenticated API requests
+    #[arg(long)]
+    pub token: Option<String>,
+    /// Update channel (stable, beta, dev)
+    #[arg(long, value_enum)]
+    pub channel: Option<Channel>,
+    /// Force update even if already up to date
+    #[arg(short, long)]
+    pub force: bool,
+}
+
+/// Arguments of `vx migrate`
+#[derive(Args, Clone)]
+pub struct MigrateArgs {
+    /// Path to project directory (default: current directory)
+    #[arg(short, long)]
+    pub path: Option<String>,
+    /// Preview changes without writing
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Create backup before migration
+    #[arg(long, default_value_t = true, action = clap::ArgAction::Set)]
+    pub backup: bool,
+    /// Only check which migrations are needed
+    #[arg(long)]
+    pub check: bool,
+    /// Show verbose output
+    #[arg(short, long)]
+    pub verbose: bool,
+}
+
+/// Arguments of `vx uninstall`
+#[derive(Args, Clone)]
+pub struct UninstallArgs {
+    /// Tool name (e.g., python, python@3.7)
+    pub tool: String,
+    /// Version to uninstall (optional, removes all if not specified)
+    pub version: Option<String>,
+    /// Force removal without confirmation
+    #[arg(short, long)]
+    pub force: bool,
+    /// Print the directories that would be deleted without removing them
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// Arguments of `vx check`
+#[derive(Args, Clone)]
+pub struct CheckArgs {
+    /// Tool name to check (optional, checks all if not specified)
+    pub tool: Option<String>,
+    /// Show detailed information about each tool
+    #[arg(long, short = 'd')]
+    pub detailed: bool,
+    /// Quiet mode: exit code only, no output
+    #[arg(long, short = 'q')]
+    pub quiet: bool,
+    /// Skip network reachability checks from [requirements]
+    #[arg(long)]
+    pub no_network: bool,
+}
+
+/// Arguments of `vx matrix`
+#[derive(Args, Clone)]
+pub struct MatrixArgs {
+    /// Tool and versions to run, as <tool>=<version>[,<version>...]
+    #[arg(
+        long = "tool",
+        short = 't',
+        value_name = "TOOL=VERSIONS",
+        required = true
+    )]
+    pub tools: Vec<String>,
+
+    /// Run all combinations at the same time (output is shown per run)
+    #[arg(long, short = 'p')]
+    pub parallel: bool,
+
+    /// Stop after the first failing combination (sequential runs only)
+    #[arg(long, conflicts_with = "parallel")]
+    pub fail_fast: bool,
+
+    /// Command and arguments to run
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true, required = true)]
+    pub command: Vec<String>,
+}
+
 // =============================================================================
 // Subcommands
 // =============================================================================
@@ -986,10 +1281,10 @@ pub enum CacheCommand {
     #[command(alias = "stats")]
     Info,
 
-    /// List cached items
+    /// List cached version lists, downloads and temp files
     #[command(alias = "ls")]
     List {
-        /// Show detailed information
+        /// Show sizes, ages and source URLs
         #[arg(short, long)]
         verbose: bool,
     },
@@ -1008,12 +1303,21 @@ pub enum CacheCommand {
         /// Only prune resolution cache
         #[arg(long)]
         resolutions: bool,
+        /// Only prune HTTP response cache
+        #[arg(long)]
+        http: bool,
         /// Only prune orphaned tool versions
         #[arg(long)]
         orphaned: bool,
-        /// Prune files older than specified days
-        #[arg(long)]
-        older_than: Option<u32>,
+        /// Only prune leftovers of interrupted installs in the temp area
+        #[arg(long)]
+        temp: bool,
+        /// Prune downloads unused for longer than this (e.g. 30d, 12h; a bare number is days)
+        #[arg(long, value_parser = crate::cache_policy::parse_age_arg)]
+        older_than: Option<std::time::Duration>,
+        /// Evict least recently used downloads until the cache fits (e.g. 5GB)
+        #[arg(long, value_parser = crate::cache_policy::parse_size_arg)]
+        max_size: Option<u64>,
         /// Show verbose output
         #[arg(short, long)]
         verbose: bool,
@@ -1030,6 +1334,9 @@ pub enum CacheCommand {
         /// Only purge resolution cache
         #[arg(long)]
         resolutions: bool,
+        /// Only purge HTTP response cache
+        #[arg(long)]
+        http: bool,
         /// Purge cache for specific tool only
         #[arg(long)]
         tool: Option<String>,
@@ -1040,6 +1347,19 @@ pub enum CacheCommand {
 
     /// Show cache directory path
     Dir,
+
+    /// Share the download cache with vx on the local network
+    Serve {
+        /// Port to listen on (default: `port` in peers.toml, 7878)
+        #[arg(long)]
+        port: Option<u16>,
+        /// Don't announce the cache with mDNS
+        #[arg(long)]
+        no_announce: bool,
+    },
+
+    /// List peer caches on the local network
+    Peers,
 }
 
 #[derive(Subcommand, Clone)]
@@ -1102,10 +1422,183 @@ pub enum BundleCommand {
     },
 }
 
+#[derive(Subcommand, Clone)]
+pub enum ExportCommand {
+    /// Generate a Homebrew Brewfile (install with `brew bundle`)
+    Brewfile {
+        /// Write to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Generate a Scoop manifest (install with `scoop import`)
+    Scoop {
+        /// Write to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand, Clone)]
+pub enum ImportCommand {
+    /// Import a package from the winget repository (microsoft/winget-pkgs)
+    Winget {
+        /// Package identifier, optionally with a version (e.g., jqlang.jq@1.7.1)
+        id: String,
+        /// Provider name (default: derived from the identifier)
+        #[arg(long)]
+        name: Option<String>,
+        /// Overwrite an existing provider with the same name
+        #[arg(long)]
+        force: bool,
+        /// Only write the provider, without installing the tool
+        #[arg(long)]
+        no_install: bool,
+    },
+    /// Import a package from the Chocolatey community repository
+    Choco {
+        /// Package id, optionally with a version (e.g., ripgrep@14.1.0)
+        id: String,
+        /// Provider name (default: derived from the id)
+        #[arg(long)]
+        name: Option<String>,
+        /// Overwrite an existing provider with the same name
+        #[arg(long)]
+        force: bool,
+        /// Only write the provider, without installing the tool
+        #[arg(long)]
+        no_install: bool,
+    },
+}
+
+#[derive(Subcommand, Clone)]
+pub enum BootstrapCommand {
+    /// Run the bootstrap script on a remote machine over ssh
+    Ssh {
+        /// Remote machine (e.g., user@host)
+        target: String,
+        /// Remote directory for vx.toml and vx.lock (default: the project's name)
+        #[arg(long)]
+        dir: Option<String>,
+        /// ssh port
+        #[arg(short, long)]
+        port: Option<u16>,
+        /// ssh identity file
+        #[arg(short, long)]
+        identity: Option<PathBuf>,
+        /// Only install vx, without the current project
+        #[arg(long)]
+        no_project: bool,
+    },
+    /// Print the bootstrap script (POSIX shell)
+    Script {
+        /// Directory for vx.toml and vx.lock (default: the project's name)
+        #[arg(long)]
+        dir: Option<String>,
+        /// Only install vx, without the current project
+        #[arg(long)]
+        no_project: bool,
+        /// Write to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand, Clone)]
+pub enum CiCommand {
+    /// Write a CI workflow that caches ~/.vx, checks the toolchain with
+    /// `vx sync --check` and runs the project's scripts
+    ///
+    /// Files are written relative to the project root. Files not generated
+    /// by `vx ci generate` are only overwritten with --force.
+    Generate {
+        /// CI system to generate configuration for
+        #[arg(long, value_enum, default_value_t = CiProvider::Github)]
+        provider: CiProvider,
+        /// Script from vx.toml to run, repeatable (default: lint, check, test and build, when defined)
+        #[arg(short, long = "script", value_name = "NAME")]
+        scripts: Vec<String>,
+        /// Print the files instead of writing them
+        #[arg(long)]
+        dry_run: bool,
+        /// Overwrite files that were not generated by `vx ci generate`
+        #[arg(short, long)]
+        force: bool,
+    },
+}
+
+/// CI system targeted by `vx ci generate`
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CiProvider {
+    /// GitHub Actions (.github/workflows/vx.yml and a composite setup action)
+    #[default]
+    Github,
+    /// GitLab CI (.gitlab-ci.yml)
+    Gitlab,
+    /// CircleCI (.circleci/config.yml)
+    Circleci,
+}
+
+#[derive(Subcommand, Clone)]
+pub enum StoreCommand {
+    /// Package an installed tool version as an OCI artifact and push it
+    ///
+    /// Examples:
+    ///   vx store export-oci node@20.11.0 --ref ghcr.io/org/toolchains:node-20
+    ///   vx store export-oci go@1.22.0 --ref oci:./artifacts:go-1.22
+    #[command(name = "export-oci")]
+    ExportOci {
+        /// Installed tool version (e.g., node@20.11.0)
+        tool: String,
+        /// Target reference: <registry>/<repository>:<tag> or oci:<dir>:<tag>
+        #[arg(long = "ref", value_name = "REF")]
+        reference: String,
+    },
+}
+
+#[derive(Subcommand, Clone)]
+pub enum AndroidCommand {
+    /// Accept the SDK licenses and install SDK packages with sdkmanager
+    ///
+    /// Installs the android-sdk command-line tools (and java) on demand,
+    /// answers "y" to every license prompt and installs the given packages
+    /// (default: platform-tools, which provides adb) into ANDROID_HOME.
+    ///
+    /// Examples:
+    ///   vx android setup
+    ///   vx android setup "platforms;android-35" "build-tools;35.0.0"
+    ///   vx android setup --sdk-root ./android-sdk
+    Setup {
+        /// SDK packages to install (sdkmanager package paths)
+        packages: Vec<String>,
+        /// SDK root to set up (default: ~/.vx/android/sdk)
+        #[arg(long, value_name = "DIR")]
+        sdk_root: Option<std::path::PathBuf>,
+        /// Command-line tools version to use (default: latest)
+        #[arg(long)]
+        version: Option<String>,
+    },
+}
+
 #[derive(Subcommand, Clone)]
 pub enum ConfigCommand {
     /// Show current configuration
-    Show,
+    Show {
+        /// Show where each value comes from (policy, user, project)
+        #[arg(long)]
+        origin: bool,
+    },
+    /// Explain which layer (policy, user, version file, preset, project, env) sets a value
+    ///
+    /// Tool pins also come from .nvmrc, .node-version, .python-version,
+    /// rust-toolchain.toml and .tool-versions when vx.toml doesn't set them.
+    ///
+    /// Examples:
+    ///   vx config explain tools.node   # where the node version comes from
+    ///   vx config explain tools        # every tool pin
+    Explain {
+        /// Configuration key (e.g., tools.node) or section (e.g., tools)
+        key: String,
+    },
     /// Set configuration value
     Set {
         /// Configuration key (e.g., defaults.auto_install)
@@ -1144,6 +1637,40 @@ pub enum ConfigCommand {
     Dir,
 }
 
+#[derive(Subcommand, Clone)]
+pub enum PresetCommand {
+    /// List presets in the index matching a query
+    Search {
+        /// Text matched against names, descriptions, tags and tools
+        query: Option<String>,
+        /// Preset index (directory, URL or github:owner/repo; default: $VX_PRESET_INDEX)
+        #[arg(long)]
+        index: Option<String>,
+    },
+    /// Extend the project vx.toml with a preset pinned to its sha256
+    Apply {
+        /// Preset name
+        name: String,
+        /// Preset index (directory, URL or github:owner/repo; default: $VX_PRESET_INDEX)
+        #[arg(long)]
+        index: Option<String>,
+    },
+    /// Publish the project vx.toml as a preset into a local index checkout
+    Publish {
+        /// Preset name (lowercase letters, digits and dashes)
+        name: String,
+        /// Index directory, a git checkout of the preset repository (default: $VX_PRESET_INDEX)
+        #[arg(long)]
+        index: Option<PathBuf>,
+        /// One-line description
+        #[arg(short, long)]
+        description: Option<String>,
+        /// Search tags
+        #[arg(short, long = "tag")]
+        tags: Vec<String>,
+    },
+}
+
 #[derive(Subcommand, Clone)]
 pub enum MetricsCommand {
     /// Show estimated token savings from TOON and compact output
@@ -1155,6 +1682,42 @@ pub enum MetricsCommand {
         #[arg(long)]
         json: bool,
     },
+    /// Show bottlenecks and stages over their time budgets, with suggestions
+    ///
+    /// Budgets default to resolve 100ms, ensure 500ms and prepare 100ms;
+    /// override them in vx.toml under `[telemetry.budgets]`.
+    Insights {
+        /// Number of recent runs to inspect (default: 10)
+        #[arg(long, short = 'n', default_value = "10")]
+        last: usize,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show shim dispatch overhead (recorded with VX_SHIM_TELEMETRY=1)
+    ///
+    /// Splits the time spent in vx before a shimmed tool starts into target
+    /// resolution and environment setup, per executable.
+    Shim {
+        /// Number of recent invocations to inspect (default: 500)
+        #[arg(long, short = 'n', default_value = "500")]
+        last: usize,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// Release layout of a tool scaffolded with `vx provider new`
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProviderTemplate {
+    /// Archive per Rust target triple (mytool-v1.0.0-x86_64-unknown-linux-musl.tar.gz)
+    #[default]
+    Rust,
+    /// Archive per Go os/arch (mytool_1.0.0_linux_amd64.tar.gz)
+    Go,
+    /// Single executable, no archive (mytool-linux-amd64)
+    Binary,
 }
 
 #[derive(Subcommand, Clone)]
@@ -1215,16 +1778,102 @@ pub enum ProviderCommand {
         /// Override the provider name (only valid when adding a single file; defaults to
         /// the `name` field inside provider.star)
         #[arg(long, short)]
-        name: Option<String>,
-        /// Overwrite existing providers with the same name
+        name: Option<String>,
+        /// Overwrite existing providers with the same name
+        #[arg(long, short)]
+        force: bool,
+    },
+    /// Add an asdf plugin as a provider
+    ///
+    /// Clones the plugin into `~/.vx/asdf-plugins/<name>`; its tool is then
+    /// available as `vx <name>`. Without a repository, the plugin is looked
+    /// up in the asdf plugin index. Plugin scripts run in a sandbox with a
+    /// cleared environment and a private HOME. Not available on Windows.
+    ///
+    /// Examples:
+    ///   vx provider asdf kubectx
+    ///   vx provider asdf nodejs https://github.com/asdf-vm/asdf-nodejs --executable node
+    ///   vx provider asdf mytool ./asdf-mytool
+    Asdf {
+        /// Plugin name, also used as the runtime name
+        name: String,
+        /// Git URL or local directory of the plugin (default: asdf plugin index)
+        repository: Option<String>,
+        /// Main executable, when it is not named like the plugin
+        #[arg(long)]
+        executable: Option<String>,
+        /// Replace an existing plugin with the same name
+        #[arg(long, short)]
+        force: bool,
+    },
+    /// Remove a previously added user provider or asdf plugin
+    Remove {
+        /// Provider name to remove
+        name: String,
+    },
+    /// Develop a local provider with hot reload
+    ///
+    /// Watches the provider.star, re-analyzes it on every save and prints
+    /// validation errors and lint warnings right away. While it runs, other
+    /// vx invocations use the local script, so there is no need to rebuild vx
+    /// or copy the file into `~/.vx/providers`.
+    ///
+    /// Examples:
+    ///   vx provider dev ./my-provider/
+    ///   vx provider dev ./my-provider/provider.star
+    Dev {
+        /// Provider directory or provider.star file
+        path: PathBuf,
+    },
+    /// Scaffold a new provider for a tool released on GitHub
+    ///
+    /// Generates a provider.star with working fetch_versions and
+    /// download_url from one of the stdlib release templates. With
+    /// `--builtin`, creates `crates/vx-providers/<name>/` in a vx checkout,
+    /// including tests.
+    ///
+    /// Asset placeholders: {version}, {vversion}, {triple} (rust),
+    /// {os} / {arch} (go, binary), {ext}, {exe}.
+    ///
+    /// Examples:
+    ///   vx provider new mytool --repo owner/mytool
+    ///   vx provider new mytool --repo owner/mytool --template go
+    ///   vx provider new mytool --repo owner/mytool --asset "mytool-{version}-{triple}.{ext}" --tag-prefix ""
+    ///   vx provider new mytool --repo owner/mytool --builtin
+    New {
+        /// Provider name, also used as the runtime name
+        name: String,
+        /// GitHub repository (owner/repo or its https://github.com URL)
+        #[arg(long)]
+        repo: String,
+        /// Release layout
+        #[arg(long, value_enum, default_value_t = ProviderTemplate::Rust)]
+        template: ProviderTemplate,
+        /// Release asset file name pattern (default depends on --template)
+        #[arg(long)]
+        asset: Option<String>,
+        /// Executable name (default: the provider name)
+        #[arg(long)]
+        executable: Option<String>,
+        /// One-line description
+        #[arg(long)]
+        description: Option<String>,
+        /// Prefix of release tags before the version
+        #[arg(long, default_value = "v")]
+        tag_prefix: String,
+        /// Top-level directory inside the archive to strip (same placeholders as --asset)
+        #[arg(long)]
+        strip_prefix: Option<String>,
+        /// Create a built-in provider under crates/vx-providers/ of a vx checkout
+        #[arg(long)]
+        builtin: bool,
+        /// Output directory (default: ./<name>, or crates/vx-providers/<name> with --builtin)
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+        /// Overwrite existing files
         #[arg(long, short)]
         force: bool,
     },
-    /// Remove a previously added user provider
-    Remove {
-        /// Provider name to remove
-        name: String,
-    },
 }
 
 #[derive(Subcommand, Clone)]
@@ -1298,6 +1947,9 @@ pub enum ServicesCommand {
         /// Force restart if already running
         #[arg(short, long)]
         force: bool,
+        /// Keep running and restart services that crash
+        #[arg(long, conflicts_with = "foreground")]
+        supervise: bool,
         /// Show verbose output
         #[arg(short, long)]
         verbose: bool,
@@ -1319,14 +1971,24 @@ pub enum ServicesCommand {
     },
     /// Show service logs
     Logs {
-        /// Service name
-        service: String,
+        /// Service names (all services if not specified)
+        #[arg(num_args = 0..)]
+        services: Vec<String>,
         /// Follow log output
         #[arg(short, long)]
         follow: bool,
-        /// Number of lines to show
+        /// Number of lines to show per service
         #[arg(long)]
         tail: Option<usize>,
+        /// Only show lines since a time (e.g. 10m, 2h, 1d, 2024-05-01, RFC 3339)
+        #[arg(long)]
+        since: Option<String>,
+        /// Only show lines matching a regular expression
+        #[arg(long)]
+        grep: Option<String>,
+        /// Print JSON lines with service, timestamp, stream and message
+        #[arg(long)]
+        json: bool,
     },
     /// Restart services
     Restart {
@@ -1337,13 +1999,25 @@ pub enum ServicesCommand {
         #[arg(short, long)]
         verbose: bool,
     },
+    /// Watch services and restart them when they crash
+    Supervise {
+        /// Service names (all services with a restart policy if not specified)
+        #[arg(num_args = 0..)]
+        services: Vec<String>,
+        /// Seconds between checks
+        #[arg(long, default_value_t = commands::services::supervisor::DEFAULT_INTERVAL)]
+        interval: u64,
+        /// Show verbose output
+        #[arg(short, long)]
+        verbose: bool,
+    },
 }
 
 #[derive(Subcommand, Clone)]
 pub enum ContainerCommand {
     /// Generate Dockerfile from configuration
     Generate {
-        /// Output path (default: Dockerfile)
+        /// Output path (default: Dockerfile, or .devcontainer with --devcontainer)
         #[arg(short, long)]
         output: Option<String>,
         /// Generate .dockerignore as well
@@ -1355,6 +2029,14 @@ pub enum ContainerCommand {
         /// Use ecosystem-specific template (node, python, rust, go)
         #[arg(long)]
         template: Option<String>,
+        /// Generate .devcontainer/devcontainer.json with a feature that installs
+        /// vx and runs `vx setup`, instead of a Dockerfile
+        #[arg(long, conflicts_with_all = ["template", "with_ignore"])]
+        devcontainer: bool,
+        /// Also write a reusable vx feature for the project's tools to DIR,
+        /// ready for `devcontainer features publish`
+        #[arg(long, value_name = "DIR", requires = "devcontainer")]
+        publish_feature: Option<String>,
     },
     /// Build container image
     Build {
@@ -1461,25 +2143,35 @@ pub enum ExtCommand {
 
 #[derive(Subcommand, Clone)]
 pub enum AuthCommand {
-    /// Login to a service (GitHub, etc.)
+    /// Login to GitHub or a registry; tokens are stored in the OS keyring
     Login {
-        /// Service to authenticate with (github)
+        /// Service to authenticate with (github, registry)
         #[arg(default_value = "github")]
         service: String,
-        /// Provide token directly instead of using device flow
+        /// Registry URL (required for `registry`)
+        url: Option<String>,
+        /// Username for registries that use basic auth
         #[arg(long)]
+        username: Option<String>,
+        /// Read the token from stdin instead of prompting or using device flow
+        #[arg(long)]
+        with_token: bool,
+        /// Provide token directly (deprecated: ends up in shell history)
+        #[arg(long, hide = true)]
         token: Option<String>,
     },
     /// Logout from a service
     Logout {
-        /// Service to logout from (github)
+        /// Service to logout from (github, registry)
         #[arg(default_value = "github")]
         service: String,
+        /// Registry URL (required for `registry`)
+        url: Option<String>,
     },
     /// Show authentication status
     Status {
-        /// Service to check (github, or all)
-        #[arg(default_value = "github")]
+        /// Service to check (github, registry, or all)
+        #[arg(default_value = "all")]
         service: String,
     },
 }
@@ -1716,37 +2408,62 @@ impl CommandHandler for Commands {
         match self {
             Commands::Version => "version",
             Commands::Analyze { .. } => "analyze",
-            Commands::List { .. } => "list",
-            Commands::Install { .. } => "install",
-            Commands::SelfUpdate { .. } => "self-update",
-            Commands::Uninstall { .. } => "uninstall",
+            Commands::List(_) => "list",
+            Commands::Rdeps { .. } => "rdeps",
+            Commands::Install(_) => "install",
+            Commands::SelfUpdate(_) => "self-update",
+            Commands::Uninstall(_) => "uninstall",
             Commands::Which { .. } => "which",
-            Commands::Versions { .. } => "versions",
+            Commands::Versions(_) => "versions",
             Commands::Config { .. } => "config",
-            Commands::Search { .. } => "search",
+            Commands::Search(_) => "search",
             Commands::Global { .. } => "global",
-            Commands::Test { .. } => "test",
-            Commands::Sync { .. } => "sync",
-            Commands::Init { .. } => "init",
+            Commands::Test(_) => "test",
+            Commands::Sync(_) => "sync",
+            Commands::Init(_) => "init",
             Commands::Cache { .. } => "cache",
+            Commands::Store { .. } => "store",
+            Commands::Preset { .. } => "preset",
             Commands::Shell { .. } => "shell",
             Commands::Env { .. } => "env",
-            Commands::Dev { .. } => "dev",
-            Commands::Setup { .. } => "setup",
-            Commands::Add { .. } => "add",
+            Commands::Activate { .. } => "activate",
+            Commands::Deactivate { .. } => "deactivate",
+            Commands::Exec { .. } => "exec",
+            Commands::Matrix(_) => "matrix",
+            Commands::Android { .. } => "android",
+            Commands::Dev(_) => "dev",
+            Commands::Setup(_) => "setup",
+            Commands::Onboard { .. } => "onboard",
+            Commands::Add(_) => "add",
             Commands::Remove { .. } => "remove",
-            Commands::Run { .. } => "run",
+            Commands::Run(_) => "run",
             Commands::Services { .. } => "services",
             Commands::Hook { .. } => "hook",
             Commands::Container { .. } => "container",
             Commands::Ext { .. } => "ext",
             Commands::X { .. } => "x",
-            Commands::Migrate { .. } => "migrate",
-            Commands::Lock { .. } => "lock",
-            Commands::Check { .. } => "check",
+            Commands::Migrate(_) => "migrate",
+            Commands::Lock(_) => "lock",
+            Commands::Update(_) => "update",
+            Commands::Outdated { .. } => "outdated",
+            Commands::Upgrade { .. } => "upgrade",
+            Commands::Rollback { .. } => "rollback",
+            Commands::Changelog { .. } => "changelog",
+            Commands::Sbom { .. } => "sbom",
+            Commands::Check(_) => "check",
+            Commands::Doctor { .. } => "doctor",
             Commands::Bundle { .. } => "bundle",
+            Commands::Export { .. } => "export",
+            Commands::Import { .. } => "import",
+            Commands::Bootstrap { .. } => "bootstrap",
+            Commands::Wrap { .. } => "wrap",
+            Commands::Ci { .. } => "ci",
+            Commands::Capabilities { .. } => "capabilities",
+            Commands::Ui => "ui",
+            Commands::Rpc => "rpc",
             Commands::Info { .. } => "info",
-            Commands::Metrics { .. } => "metrics",
+            Commands::Metrics(_) => "metrics",
+            Commands::Stats { .. } => "stats",
             Commands::Auth { .. } => "auth",
             Commands::Ai { .. } => "ai",
             Commands::Provider { .. } => "provider",
@@ -1760,42 +2477,26 @@ impl CommandHandler for Commands {
 
             Commands::Analyze { json, verbose } => commands::analyze::handle(*json, *verbose).await,
 
-            Commands::List {
-                tool,
-                status,
-                installed: _,
-                available: _,
-                all,
-                system,
-                version_check,
-            } => {
-                let args = commands::list::Args {
-                    tool: tool.clone(),
-                    status: *status,
-                    installed: false,
-                    available: false,
-                    all: *all,
-                    system: *system,
-                    version_check: *version_check,
-                };
-                commands::list::handle(ctx, &args).await
+            Commands::List(args) => commands::list::handle(ctx, args).await,
+
+            Commands::Rdeps { tool, all } => {
+                commands::rdeps::handle(ctx.registry(), ctx.runtime_context(), tool, *all).await
             }
 
-            Commands::Install { tools, force } => {
-                let args = commands::install::Args {
-                    tools: tools.clone(),
-                    force: *force,
-                };
-                commands::install::handle(ctx, &args).await
+            Commands::Install(args) => {
+                if let Some(reference) = &args.from_oci {
+                    return commands::store::handle_install_from_oci(reference, args.force).await;
+                }
+                commands::install::handle(ctx, args).await
             }
 
-            Commands::SelfUpdate {
+            Commands::SelfUpdate(SelfUpdateArgs {
                 check,
                 version,
                 token,
                 channel,
                 force,
-            } => {
+            }) => {
                 commands::self_update::handle(
                     token.as_deref(),
                     *channel,
@@ -1806,11 +2507,12 @@ impl CommandHandler for Commands {
                 .await
             }
 
-            Commands::Uninstall {
+            Commands::Uninstall(UninstallArgs {
                 tool,
                 version,
                 force,
-            } => {
+                dry_run,
+            }) => {
                 // Use RuntimeRequest::parse to correctly handle all formats
                 let request = vx_resolver::RuntimeRequest::parse(tool);
                 let tool_name = request.name.as_str();
@@ -1821,6 +2523,18 @@ impl CommandHandler for Commands {
                 // This makes `vx uninstall rez` equivalent to `vx global uninstall uv:rez`
                 if let Some(alias) = ctx.get_package_alias(tool_name) {
                     let pkg_spec = format!("{}:{}", alias.ecosystem, alias.package);
+                    if *dry_run {
+                        let mut plan = commands::plan::ActionPlan::new("uninstall");
+                        plan.push(commands::plan::PlannedAction::Skip {
+                            tool: tool_name.to_string(),
+                            version: final_version,
+                            reason: format!(
+                                "global package; removed with vx global uninstall {}",
+                                pkg_spec
+                            ),
+                        });
+                        return plan.render(ctx.output_format());
+                    }
                     tracing::debug!(
                         "RFC 0033: Routing uninstall {} -> global uninstall {} via package_alias",
                         tool_name,
@@ -1838,6 +2552,18 @@ impl CommandHandler for Commands {
                     .await;
                 }
 
+                if *dry_run {
+                    return commands::remove::plan(
+                        ctx.registry(),
+                        ctx.runtime_context(),
+                        tool_name,
+                        final_version.as_deref(),
+                        *force,
+                    )
+                    .await?
+                    .render(ctx.output_format());
+                }
+
                 commands::remove::handle(
                     ctx.registry(),
                     ctx.runtime_context(),
@@ -1862,13 +2588,13 @@ impl CommandHandler for Commands {
                 .await
             }
 
-            Commands::Versions {
+            Commands::Versions(VersionsArgs {
                 tool,
                 latest,
                 prerelease,
                 detailed,
                 interactive,
-            } => {
+            }) => {
                 // Use RuntimeRequest::parse to extract just the tool name
                 let request = vx_resolver::RuntimeRequest::parse(tool);
                 let tool_name = request.name;
@@ -1886,9 +2612,15 @@ impl CommandHandler for Commands {
             }
 
             Commands::Config { command } => match command {
-                Some(ConfigCommand::Show) | None => {
+                Some(ConfigCommand::Show { origin: true }) => {
+                    commands::config::handle_origin(ctx.output_format()).await
+                }
+                Some(ConfigCommand::Show { origin: false }) | None => {
                     commands::config::handle(ctx.output_format()).await
                 }
+                Some(ConfigCommand::Explain { key }) => {
+                    commands::config::handle_explain(key, ctx.output_format()).await
+                }
                 Some(ConfigCommand::Set { key, value }) => {
                     commands::config::handle_set(key, value).await
                 }
@@ -1906,14 +2638,15 @@ impl CommandHandler for Commands {
                 Some(ConfigCommand::Dir) => commands::config::handle_dir().await,
             },
 
-            Commands::Init {
+            Commands::Init(InitArgs {
                 interactive,
                 template,
                 tools,
                 force,
                 dry_run,
                 list_templates,
-            } => {
+                vars,
+            }) => {
                 commands::init::handle(
                     *interactive,
                     template.clone(),
@@ -1921,12 +2654,17 @@ impl CommandHandler for Commands {
                     *force,
                     *dry_run,
                     *list_templates,
+                    vars,
                 )
                 .await
             }
 
             Commands::Cache { command } => commands::cache::handle(command.clone()).await,
 
+            Commands::Store { command } => commands::store::handle(command).await,
+
+            Commands::Preset { command } => commands::preset::handle(command).await,
+
             Commands::Provider { command } => {
                 commands::provider::handle(ctx.registry(), command.clone()).await
             }
@@ -1935,16 +2673,35 @@ impl CommandHandler for Commands {
                 let args = commands::env::Args {
                     command: command.clone(),
                 };
-                commands::env::handle(&args).await
+                commands::env::handle(&args, ctx.output_format()).await
+            }
+
+            Commands::Activate { env, shell } => {
+                commands::activate::handle_activate(env.as_deref(), shell.as_deref()).await
             }
 
-            Commands::Search {
+            Commands::Deactivate { shell } => {
+                commands::activate::handle_deactivate(shell.as_deref()).await
+            }
+
+            Commands::Exec { command } => commands::exec::handle(ctx, command).await,
+
+            Commands::Matrix(MatrixArgs {
+                tools,
+                parallel,
+                fail_fast,
+                command,
+            }) => commands::matrix::handle(ctx, tools, *parallel, *fail_fast, command).await,
+
+            Commands::Android { command } => commands::android::handle(ctx, command).await,
+
+            Commands::Search(SearchArgs {
                 query,
                 category,
                 installed_only,
                 available_only,
                 verbose,
-            } => {
+            }) => {
                 commands::search::handle(
                     ctx.registry(),
                     query.clone(),
@@ -1959,56 +2716,9 @@ impl CommandHandler for Commands {
 
             Commands::Global { command } => commands::global::handle(ctx, command).await,
 
-            Commands::Test {
-                runtime,
-                all,
-                extension,
-                local,
-                platform_only,
-                functional,
-                install,
-                ci,
-                ci_runtimes,
-                ci_skip,
-                timeout,
-                keep_going,
-                vx_root,
-                temp_root,
-                cleanup,
-                installed,
-                system,
-                detailed,
-                quiet,
-                json,
-                verbose,
-            } => {
-                let args = commands::test::Args {
-                    runtime: runtime.clone(),
-                    all: *all,
-                    extension: extension.clone(),
-                    local: local.clone(),
-                    platform_only: *platform_only,
-                    functional: *functional,
-                    install: *install,
-                    ci: *ci,
-                    ci_runtimes: ci_runtimes.clone(),
-                    ci_skip: ci_skip.clone(),
-                    timeout: *timeout,
-                    keep_going: *keep_going,
-                    vx_root: vx_root.clone(),
-                    temp_root: *temp_root,
-                    cleanup: *cleanup,
-                    installed: *installed,
-                    system: *system,
-                    detailed: *detailed,
-                    quiet: *quiet,
-                    json: *json,
-                    verbose: *verbose,
-                };
-                commands::test::handle(ctx, &args).await
-            }
+            Commands::Test(args) => commands::test::handle(ctx, args).await,
 
-            Commands::Sync {
+            Commands::Sync(SyncArgs {
                 check,
                 force,
                 dry_run,
@@ -2016,7 +2726,8 @@ impl CommandHandler for Commands {
                 no_parallel,
                 no_auto_install: _,
                 auto_lock,
-            } => {
+                retries,
+            }) => {
                 commands::sync::handle_with_options(
                     ctx.registry(),
                     commands::sync::SyncOptions {
@@ -2027,6 +2738,7 @@ impl CommandHandler for Commands {
                         no_parallel: *no_parallel,
                         auto_lock: *auto_lock,
                         analyze: true, // Enable project analysis by default
+                        retries: *retries,
                     },
                 )
                 .await
@@ -2037,7 +2749,11 @@ impl CommandHandler for Commands {
                     commands::shell::handle_shell_init(shell.clone()).await
                 }
                 ShellCommand::Completions { shell } => {
-                    commands::shell::handle_completion(shell.clone()).await
+                    commands::shell::handle_completion_with_contributions(
+                        shell.clone(),
+                        ctx.registry(),
+                    )
+                    .await
                 }
                 ShellCommand::Launch {
                     runtime,
@@ -2073,39 +2789,21 @@ impl CommandHandler for Commands {
                 }
             },
 
-            Commands::Dev {
-                shell,
-                command,
-                no_install,
-                verbose,
-                export,
-                format,
-                info,
-                inherit_system,
-                passenv,
-            } => {
-                let args = commands::dev::Args {
-                    shell: shell.clone(),
-                    command: command.clone(),
-                    no_install: *no_install,
-                    verbose: *verbose,
-                    export: *export,
-                    format: format.clone(),
-                    info: *info,
-                    inherit_system: *inherit_system,
-                    passenv: passenv.clone(),
-                };
-                commands::dev::handle(&args).await
-            }
+            Commands::Dev(args) => commands::dev::handle(args).await,
 
-            Commands::Setup {
+            Commands::Setup(SetupArgs {
                 force,
                 dry_run,
                 verbose,
                 no_parallel,
                 no_hooks,
                 ci,
-            } => {
+                global,
+                yes,
+            }) => {
+                if *global {
+                    return commands::global_setup::handle(*yes, *dry_run).await;
+                }
                 commands::setup::handle(
                     ctx.registry(),
                     *force,
@@ -2118,7 +2816,11 @@ impl CommandHandler for Commands {
                 .await
             }
 
-            Commands::Add {
+            Commands::Onboard { yes, restart } => {
+                commands::onboard::handle(ctx.registry(), *yes, *restart).await
+            }
+
+            Commands::Add(AddArgs {
                 tools,
                 no_install,
                 no_lock,
@@ -2127,7 +2829,7 @@ impl CommandHandler for Commands {
                 force,
                 os,
                 verbose,
-            } => {
+            }) => {
                 let opts = commands::add::AddOptions {
                     no_install: *no_install,
                     no_lock: *no_lock,
@@ -2146,18 +2848,24 @@ impl CommandHandler for Commands {
                 no_lock,
             } => commands::setup::remove_tools(tools, *dry_run, *no_lock).await,
 
-            Commands::Run {
+            Commands::Run(RunArgs {
                 script,
                 list,
                 script_help,
+                jobs,
+                watch,
                 args,
-            } => commands::run::handle(script.as_deref(), *list, *script_help, args).await,
+            }) => {
+                commands::run::handle(script.as_deref(), *list, *script_help, *jobs, *watch, args)
+                    .await
+            }
 
             Commands::Services { command } => match command {
                 ServicesCommand::Start {
                     services,
                     foreground,
                     force,
+                    supervise,
                     verbose,
                 } => {
                     let services = if services.is_empty() {
@@ -2165,7 +2873,14 @@ impl CommandHandler for Commands {
                     } else {
                         Some(services.clone())
                     };
-                    commands::services::handle_start(services, !*foreground, *force, *verbose).await
+                    commands::services::handle_start(
+                        services,
+                        !*foreground,
+                        *force,
+                        *supervise,
+                        *verbose,
+                    )
+                    .await
                 }
                 ServicesCommand::Stop { services, verbose } => {
                     let services = if services.is_empty() {
@@ -2179,10 +2894,23 @@ impl CommandHandler for Commands {
                     commands::services::handle_status(*verbose).await
                 }
                 ServicesCommand::Logs {
-                    service,
+                    services,
                     follow,
                     tail,
-                } => commands::services::handle_logs(service, *follow, *tail).await,
+                    since,
+                    grep,
+                    json,
+                } => {
+                    commands::services::handle_logs(
+                        services,
+                        *follow,
+                        *tail,
+                        since.as_deref(),
+                        grep.as_deref(),
+                        *json,
+                    )
+                    .await
+                }
                 ServicesCommand::Restart { services, verbose } => {
                     let services = if services.is_empty() {
                         None
@@ -2191,6 +2919,19 @@ impl CommandHandler for Commands {
                     };
                     commands::services::handle_restart(services, *verbose).await
                 }
+                ServicesCommand::Supervise {
+                    services,
+                    interval,
+                    verbose,
+                } => {
+                    let services = if services.is_empty() {
+                        None
+                    } else {
+                        Some(services.clone())
+                    };
+                    commands::services::supervisor::handle_supervise(services, *interval, *verbose)
+                        .await
+                }
             },
 
             Commands::Hook { command } => match command {
@@ -2206,11 +2947,26 @@ impl CommandHandler for Commands {
             },
 
             Commands::Container { command } => match command {
+                ContainerCommand::Generate {
+                    output,
+                    dry_run,
+                    devcontainer: true,
+                    publish_feature,
+                    ..
+                } => {
+                    commands::container::handle_generate_devcontainer(
+                        output.clone(),
+                        *dry_run,
+                        publish_feature.as_deref().map(std::path::Path::new),
+                    )
+                    .await
+                }
                 ContainerCommand::Generate {
                     output,
                     with_ignore,
                     dry_run,
                     template,
+                    ..
                 } => {
                     commands::container::handle_generate(
                         output.clone(),
@@ -2275,21 +3031,23 @@ impl CommandHandler for Commands {
 
             Commands::X { extension, args } => commands::ext::handle_execute(extension, args).await,
 
-            Commands::Migrate {
+            Commands::Migrate(MigrateArgs {
                 path,
                 dry_run,
                 backup,
                 check,
                 verbose,
-            } => commands::migrate::handle(path.clone(), *dry_run, *backup, *check, *verbose).await,
+            }) => {
+                commands::migrate::handle(path.clone(), *dry_run, *backup, *check, *verbose).await
+            }
 
-            Commands::Lock {
+            Commands::Lock(LockArgs {
                 update,
                 tool,
                 dry_run,
                 check,
                 verbose,
-            } => {
+            }) => {
                 if *check {
                     commands::lock::handle_check(*verbose).await
                 } else {
@@ -2305,16 +3063,90 @@ impl CommandHandler for Commands {
                 }
             }
 
-            Commands::Check {
+            Commands::Update(UpdateArgs {
+                tools,
+                all,
+                apply,
+                summary,
+                dry_run,
+            }) => {
+                let options = commands::update::UpdateOptions {
+                    tools: tools.clone(),
+                    all: *all,
+                    apply: *apply,
+                    summary: summary.clone(),
+                };
+                if *dry_run {
+                    return commands::update::plan(ctx.registry(), ctx.runtime_context(), &options)
+                        .await?
+                        .render(ctx.output_format());
+                }
+                commands::update::handle(ctx.registry(), ctx.runtime_context(), &options).await
+            }
+
+            Commands::Outdated { eol } => {
+                commands::outdated::handle(ctx.registry(), ctx.runtime_context(), *eol).await
+            }
+
+            Commands::Upgrade {
+                tools,
+                all,
+                dry_run,
+            } => {
+                let options = commands::update::UpgradeOptions {
+                    tools: tools.clone(),
+                    all: *all,
+                    dry_run: *dry_run,
+                };
+                if *dry_run {
+                    return commands::update::plan_upgrade(
+                        ctx.registry(),
+                        ctx.runtime_context(),
+                        &options,
+                    )
+                    .await?
+                    .render(ctx.output_format());
+                }
+                commands::update::handle_upgrade(ctx.registry(), ctx.runtime_context(), &options)
+                    .await
+            }
+
+            Commands::Rollback { tool, dry_run } => {
+                if *dry_run {
+                    return commands::rollback::plan(ctx.registry(), ctx.runtime_context(), tool)
+                        .await?
+                        .render(ctx.output_format());
+                }
+                commands::rollback::handle(ctx.registry(), ctx.runtime_context(), tool).await
+            }
+
+            Commands::Changelog { tool, range, limit } => {
+                commands::changelog::handle(
+                    ctx.registry(),
+                    ctx.runtime_context(),
+                    tool,
+                    range.as_deref(),
+                    *limit,
+                )
+                .await
+            }
+
+            Commands::Sbom { format, output } => {
+                commands::sbom::handle(ctx.registry(), *format, output.as_deref()).await
+            }
+
+            Commands::Check(CheckArgs {
                 tool,
                 detailed,
                 quiet,
-            } => {
+                no_network,
+            }) => {
                 commands::check::handle(
                     ctx.registry(),
                     tool.clone(),
                     *detailed,
                     *quiet,
+                    *no_network,
                     ctx.output_format(),
                 )
                 .await
@@ -2364,33 +3196,163 @@ impl CommandHandler for Commands {
                 BundleCommand::Clean { force } => commands::bundle::handle_clean(*force).await,
             },
 
-            Commands::Info { json, warnings } => {
+            Commands::Export { command } => match command {
+                ExportCommand::Brewfile { output } => {
+                    commands::export::handle_brewfile(output.as_deref()).await
+                }
+                ExportCommand::Scoop { output } => {
+                    commands::export::handle_scoop(output.as_deref()).await
+                }
+            },
+
+            Commands::Import { command } => {
+                let (source, id, name, force, no_install) = match command {
+                    ImportCommand::Winget {
+                        id,
+                        name,
+                        force,
+                        no_install,
+                    } => (
+                        commands::import::ImportSource::Winget,
+                        id,
+                        name,
+                        force,
+                        no_install,
+                    ),
+                    ImportCommand::Choco {
+                        id,
+                        name,
+                        force,
+                        no_install,
+                    } => (
+                        commands::import::ImportSource::Choco,
+                        id,
+                        name,
+                        force,
+                        no_install,
+                    ),
+                };
+                commands::import::handle(source, id, name.as_deref(), *force, *no_install).await
+            }
+
+            Commands::Bootstrap { command } => match command {
+                BootstrapCommand::Ssh {
+                    target,
+                    dir,
+                    port,
+                    identity,
+                    no_project,
+                } => {
+                    commands::bootstrap::handle_ssh(
+                        target,
+                        dir.as_deref(),
+                        *port,
+                        identity.clone(),
+                        *no_project,
+                    )
+                    .await
+                }
+                BootstrapCommand::Script {
+                    dir,
+                    no_project,
+                    output,
+                } => {
+                    commands::bootstrap::handle_script(
+                        dir.as_deref(),
+                        *no_project,
+                        output.as_deref(),
+                    )
+                    .await
+                }
+            },
+
+            Commands::Wrap { tools, dir, force } => {
+                commands::wrap::handle(ctx.registry(), tools, dir, *force).await
+            }
+
+            Commands::Ci { command } => match command {
+                CiCommand::Generate {
+                    provider,
+                    scripts,
+                    dry_run,
+                    force,
+                } => commands::ci::handle_generate(*provider, scripts, *dry_run, *force).await,
+            },
+
+            Commands::Ui => commands::dashboard::handle(ctx).await,
+            Commands::Rpc => commands::rpc::handle(ctx).await,
+
+            Commands::Capabilities { format } => {
+                commands::capabilities::handle_build(ctx.registry(), *format, ctx.output_format())
+                    .await
+            }
+
+            Commands::Info {
+                json,
+                warnings,
+                system,
+            } => {
                 if *warnings {
                     commands::capabilities::handle_warnings().await
+                } else if *system {
+                    commands::capabilities::handle_system(*json)
                 } else {
                     commands::capabilities::handle(ctx.registry(), *json).await
                 }
             }
 
-            Commands::Metrics {
+            Commands::Metrics(MetricsArgs {
                 command,
                 last,
                 json,
                 html,
                 clean,
-            } => match command {
+            }) => match command {
                 Some(MetricsCommand::Tokens { last, json }) => {
                     commands::metrics::handle_tokens(*last, *json).await
                 }
+                Some(MetricsCommand::Insights { last, json }) => {
+                    commands::metrics::handle_insights(*last, *json).await
+                }
+                Some(MetricsCommand::Shim { last, json }) => {
+                    commands::metrics::handle_shim(*last, *json).await
+                }
                 None => commands::metrics::handle(*last, *json, html.clone(), *clean).await,
             },
 
+            Commands::Stats {
+                network,
+                json,
+                clear,
+            } => commands::stats::handle(*network, *json, *clear).await,
+
+            Commands::Doctor {
+                json,
+                exclude_store,
+                yes,
+            } => commands::doctor::handle(ctx.registry(), *json, *exclude_store, *yes).await,
+
             Commands::Auth { command } => match command {
-                AuthCommand::Login { service, token } => {
-                    handle_auth_login(service, token.as_deref()).await
+                AuthCommand::Login {
+                    service,
+                    url,
+                    username,
+                    with_token,
+                    token,
+                } => {
+                    commands::auth::handle_login(
+                        service,
+                        url.as_deref(),
+                        username.as_deref(),
+                        *with_token,
+                        token.as_deref(),
+                    )
+                    .await
+                }
+                AuthCommand::Logout { service, url } => {
+                    commands::auth::handle_logout(service, url.as_deref()).await
                 }
-                AuthCommand::Logout { service } => handle_auth_logout(service).await,
-                AuthCommand::Status { service } => handle_auth_status(service).await,
+                AuthCommand::Status { service } => commands::auth::handle_status(service).await,
             },
 
             Commands::Ai { command } => match command {
@@ -2421,155 +3383,3 @@ impl CommandHandler for Commands {
         }
     }
 }
-
-// =============================================================================
-// Auth Command Handlers
-// =============================================================================
-
-async fn handle_auth_login(service: &str, token: Option<&str>) -> Result<()> {
-    use crate::commands::auth::{
-        GitHubDeviceFlow, TokenSource, get_token_status, store_github_token,
-    };
-
-    match service {
-        "github" | "gh" => {
-            // Check if already authenticated
-            let status = get_token_status().await?;
-            if !matches!(status.source, TokenSource::None) {
-                println!("Already authenticated with GitHub via {}", status.source);
-                if let Some(rl) = status.rate_limit {
-                    println!("Rate limit: {}/{} remaining", rl.remaining, rl.limit);
-                }
-                println!(
-                    "\nTo re-authenticate, run: vx auth logout github && vx auth login github"
-                );
-                return Ok(());
-            }
-
-            // If token provided directly, store it
-            if let Some(t) = token {
-                store_github_token(t)?;
-                println!("GitHub token stored successfully!");
-                println!("\nYou can also set GITHUB_TOKEN or GH_TOKEN environment variable.");
-                return Ok(());
-            }
-
-            // Use Device Flow
-            println!("Authenticating with GitHub using Device Flow...\n");
-
-            let flow = GitHubDeviceFlow::new();
-            let device_code = flow.start().await?;
-
-            println!("Please visit: {}", device_code.verification_uri);
-            println!("And enter code: {}\n", device_code.user_code);
-
-            // Try to open browser
-            #[cfg(target_os = "windows")]
-            {
-                let _ = std::process::Command::new("cmd")
-                    .args(["/c", "start", &device_code.verification_uri])
-                    .spawn();
-            }
-            #[cfg(target_os = "macos")]
-            {
-                let _ = std::process::Command::new("open")
-                    .arg(&device_code.verification_uri)
-                    .spawn();
-            }
-            #[cfg(target_os = "linux")]
-            {
-                let _ = std::process::Command::new("xdg-open")
-                    .arg(&device_code.verification_uri)
-                    .spawn();
-            }
-
-            println!("Waiting for authorization...");
-
-            let token = flow
-                .poll_for_token(
-                    &device_code.device_code,
-                    device_code.interval,
-                    device_code.expires_in,
-                )
-                .await?;
-
-            store_github_token(&token)?;
-
-            println!("\n✓ Successfully authenticated with GitHub!");
-            println!("  Token stored in ~/.vx/config/github_token");
-            println!("\nYou can also set GITHUB_TOKEN or GH_TOKEN environment variable for CI.");
-
-            Ok(())
-        }
-        _ => {
-            anyhow::bail!("Unknown service: {}. Supported services: github", service);
-        }
-    }
-}
-
-async fn handle_auth_logout(service: &str) -> Result<()> {
-    use crate::commands::auth::remove_github_token;
-
-    match service {
-        "github" | "gh" => {
-            remove_github_token()?;
-            println!("✓ Logged out from GitHub");
-            println!("\nNote: This only removes the stored token.");
-            println!("Environment variables (GITHUB_TOKEN, GH_TOKEN) are not affected.");
-            Ok(())
-        }
-        _ => {
-            anyhow::bail!("Unknown service: {}. Supported services: github", service);
-        }
-    }
-}
-
-async fn handle_auth_status(service: &str) -> Result<()> {
-    use crate::commands::auth::{TokenSource, get_token_status};
-
-    match service {
-        "github" | "gh" | "all" => {
-            println!("GitHub Authentication Status\n");
-
-            let status = get_token_status().await?;
-
-            match &status.source {
-                TokenSource::None => {
-                    println!("  Status: Not authenticated");
-                    println!("\n  To authenticate:");
-                    println!("    vx auth login github");
-                    println!("\n  Or set environment variable:");
-                    println!("    export GITHUB_TOKEN=<your-token>");
-                }
-                source => {
-                    println!("  Status: ✓ Authenticated");
-                    println!("  Source: {}", source);
-
-                    if !status.scopes.is_empty() {
-                        println!("  Scopes: {}", status.scopes.join(", "));
-                    }
-
-                    if let Some(rl) = status.rate_limit {
-                        println!("\n  Rate Limit:");
-                        println!("    Remaining: {}/{}", rl.remaining, rl.limit);
-
-                        // Calculate reset time
-                        let now = std::time::SystemTime::now()
-                            .duration_since(std::time::UNIX_EPOCH)
-                            .unwrap_or_default()
-                            .as_secs();
-                        if rl.reset > now {
-                            let mins = (rl.reset - now) / 60;
-                            println!("    Resets in: {} minutes", mins);
-                        }
-                    }
-                }
-            }
-
-            Ok(())
-        }
-        _ => {
-            anyhow::bail!("Unknown service: {}. Supported services: github", service);
-        }
-    }
-}
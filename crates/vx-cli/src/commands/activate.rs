@@ -0,0 +1,102 @@
+//! Activate/deactivate command implementation
+//!
+//! `vx activate [env]` prints a shell script that puts the project's (or a
+//! named environment's) vx-managed tools on PATH for the current session:
+//!
+//! ```bash
+//! eval "$(vx activate)"                  # bash / zsh
+//! vx activate --shell fish | source      # fish
+//! vx activate --shell powershell | Out-String | Invoke-Expression
+//! ```
+//!
+//! `vx deactivate` prints the matching script that restores the previous
+//! environment. Scripts are generated by [`vx_env::shell`].
+
+use crate::commands::common::{self, ShellType};
+use crate::commands::env::{build_tools_from_env_dir, resolve_env_for_shell};
+use anyhow::{Result, bail};
+use std::collections::HashMap;
+use vx_env::shell::Shell;
+use vx_env::{SessionContext, SessionSource, ShellSpawner};
+use vx_paths::PathManager;
+
+/// Resolve the target shell from `--shell` or the current environment
+pub fn resolve_shell(shell: Option<&str>) -> Result<Shell> {
+    match shell {
+        Some(name) => Shell::parse(name).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unsupported shell: {}. Use: bash, zsh, fish, nu, powershell or cmd",
+                name
+            )
+        }),
+        None => Ok(match common::detect_shell() {
+            ShellType::Unknown => Shell::detect(),
+            detected => Shell::parse(detected.name()).unwrap_or_else(Shell::detect),
+        }),
+    }
+}
+
+/// Handle `vx activate [env]`
+pub async fn handle_activate(env: Option<&str>, shell: Option<&str>) -> Result<()> {
+    let shell = resolve_shell(shell)?;
+    let session = activation_session(env)?;
+
+    if session.tools.is_empty() {
+        bail!(
+            "'{}' has no tools to activate. Add tools to vx.toml or with 'vx env add <tool>@<version>'",
+            session.prompt_name()
+        );
+    }
+
+    let spawner = ShellSpawner::new(session)?;
+    print!("{}", shell.activation_script(&spawner.activation_config()));
+    Ok(())
+}
+
+/// Handle `vx deactivate`
+pub async fn handle_deactivate(shell: Option<&str>) -> Result<()> {
+    let shell = resolve_shell(shell)?;
+    print!("{}", shell.deactivation_script());
+    Ok(())
+}
+
+/// Build the session to activate
+///
+/// An explicit environment name selects that environment. Otherwise the
+/// project's `vx.toml` is used, falling back to the project or default
+/// environment directory.
+fn activation_session(env: Option<&str>) -> Result<SessionContext> {
+    let cwd = std::env::current_dir()?;
+
+    if env.is_none()
+        && let Ok(config_path) = common::find_project_config(&cwd)
+    {
+        let (_, config) = common::load_config_view(&config_path)?;
+        let mut env_vars: HashMap<String, String> = config.env.clone();
+        env_vars.extend(config.setenv.clone());
+
+        let root = config_path.parent().map(|p| p.to_path_buf()).unwrap_or(cwd);
+        return Ok(SessionContext::new(&config.project_name)
+            .tools(&config.tools)
+            .env_vars(&env_vars)
+            .isolated(false)
+            .project_root(root)
+            .source(SessionSource::VxToml {
+                path: config_path,
+                project_name: config.project_name.clone(),
+            }));
+    }
+
+    let path_manager = PathManager::new()?;
+    let (env_dir, env_name) = resolve_env_for_shell(env, false, &path_manager)?;
+    let tools = build_tools_from_env_dir(&env_dir, &path_manager)?;
+
+    Ok(SessionContext::new(&env_name)
+        .tools(&tools)
+        .isolated(false)
+        .project_root(cwd)
+        .source(SessionSource::EnvDir {
+            path: env_dir,
+            name: env_name,
+        }))
+}
@@ -546,10 +546,7 @@ async fn collect_project_scripts(project_root: &std::path::Path) -> Result<Vec<S
         && let Ok(config) = vx_config::parse_config(&vx_toml)
     {
         for (name, script) in &config.scripts {
-            let command = match script {
-                vx_config::ScriptConfig::Simple(cmd) => cmd.clone(),
-                vx_config::ScriptConfig::Detailed(d) => d.command.clone(),
-            };
+            let command = script.for_current_os().command;
             scripts.push(ScriptInfo {
                 name: name.clone(),
                 command,
@@ -0,0 +1,156 @@
+//! Android SDK helper commands
+//!
+//! `vx android setup` prepares the shared Android SDK root used by the
+//! `android-sdk` provider (ANDROID_HOME, `~/.vx/android/sdk` by default):
+//! it installs the command-line tools on demand, accepts the SDK licenses
+//! non-interactively and installs SDK packages such as `platform-tools`
+//! (which provides `adb`).
+
+use crate::cli::AndroidCommand;
+use crate::commands::CommandContext;
+use crate::commands::exec::resolve_tool;
+use crate::ui::UI;
+use anyhow::{Context, Result, bail};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use vx_env::ToolEnvironment;
+use vx_paths::VxPaths;
+use vx_runtime_core::WithDependency;
+
+/// SDK packages installed by `vx android setup` when none are given
+pub const DEFAULT_PACKAGES: &[&str] = &["platform-tools"];
+
+/// Number of "y" answers fed to `sdkmanager --licenses`
+///
+/// sdkmanager asks once per unaccepted license; it ignores extra input.
+const LICENSE_ANSWERS: usize = 32;
+
+/// Handle android subcommands
+pub async fn handle(ctx: &CommandContext, command: &AndroidCommand) -> Result<()> {
+    match command {
+        AndroidCommand::Setup {
+            packages,
+            sdk_root,
+            version,
+        } => handle_setup(ctx, packages, sdk_root.as_deref(), version.as_deref()).await,
+    }
+}
+
+/// Default SDK root shared by every command-line tools version
+///
+/// Must match `sdk_root()` in the android-sdk provider.star.
+pub fn default_sdk_root(vx_home: &Path) -> PathBuf {
+    vx_home.join("android").join("sdk")
+}
+
+/// Arguments for accepting all SDK licenses
+pub fn license_args(sdk_root: &Path) -> Vec<String> {
+    vec![sdk_root_arg(sdk_root), "--licenses".to_string()]
+}
+
+/// Arguments for installing `packages`, falling back to [`DEFAULT_PACKAGES`]
+pub fn install_args(sdk_root: &Path, packages: &[String]) -> Vec<String> {
+    let mut args = vec![sdk_root_arg(sdk_root)];
+    if packages.is_empty() {
+        args.extend(DEFAULT_PACKAGES.iter().map(|p| p.to_string()));
+    } else {
+        args.extend(packages.iter().cloned());
+    }
+    args
+}
+
+/// sdkmanager cannot infer the SDK root from the vx store layout, so it is
+/// always passed explicitly
+fn sdk_root_arg(sdk_root: &Path) -> String {
+    format!("--sdk_root={}", sdk_root.display())
+}
+
+async fn handle_setup(
+    ctx: &CommandContext,
+    packages: &[String],
+    sdk_root: Option<&Path>,
+    version: Option<&str>,
+) -> Result<()> {
+    let sdk_root = match sdk_root {
+        Some(root) => root.to_path_buf(),
+        None => default_sdk_root(&VxPaths::new()?.base_dir),
+    };
+    std::fs::create_dir_all(&sdk_root)
+        .with_context(|| format!("Failed to create SDK root {}", sdk_root.display()))?;
+
+    // sdkmanager is a JVM tool: put the vx-managed java next to it
+    let tools = [
+        WithDependency::new("android-sdk", version.map(str::to_string)),
+        WithDependency::new("java", None),
+    ];
+    let mut specs = Vec::with_capacity(tools.len());
+    for tool in &tools {
+        specs.push(resolve_tool(ctx, tool).await?);
+    }
+    let mut env = ToolEnvironment::new()
+        .tools_from_specs(specs)
+        .include_vx_bin(true)
+        .inherit_path(true)
+        .warn_missing(false)
+        .build()
+        .map_err(|e| anyhow::anyhow!("Failed to build Android environment: {}", e))?;
+    let root = sdk_root.display().to_string();
+    env.insert("ANDROID_HOME".to_string(), root.clone());
+    env.insert("ANDROID_SDK_ROOT".to_string(), root);
+
+    let cwd = std::env::current_dir()?;
+    let sdkmanager = which::which_in("sdkmanager", env.get("PATH"), &cwd)
+        .context("sdkmanager not found after installing android-sdk")?;
+
+    UI::info("Accepting Android SDK licenses...");
+    accept_licenses(&sdkmanager, &env, &sdk_root)?;
+
+    let args = install_args(&sdk_root, packages);
+    UI::info(&format!("Installing SDK packages: {}", args[1..].join(" ")));
+    let status = Command::new(&sdkmanager)
+        .args(&args)
+        .envs(&env)
+        .status()
+        .with_context(|| format!("Failed to run {}", sdkmanager.display()))?;
+    if !status.success() {
+        bail!(
+            "sdkmanager failed to install packages (exit code {})",
+            status.code().unwrap_or(1)
+        );
+    }
+
+    UI::success(&format!("Android SDK ready at {}", sdk_root.display()));
+    UI::hint("Tools run through vx get ANDROID_HOME set; pass it to sdkmanager with --sdk_root");
+    Ok(())
+}
+
+/// Run `sdkmanager --licenses`, answering "y" to every prompt
+fn accept_licenses(
+    sdkmanager: &Path,
+    env: &HashMap<String, String>,
+    sdk_root: &Path,
+) -> Result<()> {
+    let mut child = Command::new(sdkmanager)
+        .args(license_args(sdk_root))
+        .envs(env)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .with_context(|| format!("Failed to run {}", sdkmanager.display()))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // sdkmanager may exit before reading every answer
+        let _ = stdin.write_all("y\n".repeat(LICENSE_ANSWERS).as_bytes());
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        bail!(
+            "sdkmanager --licenses failed (exit code {})",
+            status.code().unwrap_or(1)
+        );
+    }
+    Ok(())
+}
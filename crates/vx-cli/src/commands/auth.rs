@@ -1,10 +1,14 @@
 //! Authentication commands for vx
 //!
-//! Provides authentication support for various services like GitHub.
-//! Supports GitHub Device Flow OAuth for easy token acquisition.
+//! Provides authentication support for GitHub and package/container
+//! registries. Supports GitHub Device Flow OAuth for easy token acquisition;
+//! tokens are kept in the OS keyring through [`vx_proxy::credentials`].
 
+use crate::ui::UI;
 use anyhow::{Context, Result};
+use std::io::{IsTerminal, Read};
 use std::time::Duration;
+use vx_proxy::credentials::{self, CredentialStore, Storage};
 
 /// GitHub OAuth Device Flow authentication
 ///
@@ -55,7 +59,7 @@ impl GitHubDeviceFlow {
 
     /// Start the device flow and get the user code
     pub async fn start(&self) -> Result<DeviceCodeResponse> {
-        let client = reqwest::Client::new();
+        let client = vx_proxy::client_builder().build()?;
 
         let response = client
             .post("https://github.com/login/device/code")
@@ -87,7 +91,7 @@ impl GitHubDeviceFlow {
         interval: u64,
         expires_in: u64,
     ) -> Result<String> {
-        let client = reqwest::Client::new();
+        let client = vx_proxy::client_builder().build()?;
         let start = std::time::Instant::now();
         let timeout = Duration::from_secs(expires_in);
         let poll_interval = Duration::from_secs(interval.max(5)); // Minimum 5 seconds as per GitHub docs
@@ -158,68 +162,25 @@ impl Default for GitHubDeviceFlow {
     }
 }
 
-/// Store a GitHub token securely
-pub fn store_github_token(token: &str) -> Result<()> {
-    let paths = vx_paths::VxPaths::new()?;
-    let config_dir = paths.config_dir;
-    std::fs::create_dir_all(&config_dir).context("Failed to create config directory")?;
-
-    let token_file = config_dir.join("github_token");
-
-    // Write token with restricted permissions
-    std::fs::write(&token_file, token).context("Failed to write token file")?;
-
-    // Set file permissions (Unix only)
-    #[cfg(unix)]
-    {
-        use std::os::unix::fs::PermissionsExt;
-        let mut perms = std::fs::metadata(&token_file)?.permissions();
-        perms.set_mode(0o600); // Owner read/write only
-        std::fs::set_permissions(&token_file, perms)?;
-    }
-
-    Ok(())
+/// Store a GitHub token in the credential store
+///
+/// Returns where the token was stored. A token file left by older vx
+/// versions is removed.
+pub fn store_github_token(token: &str) -> Result<Storage> {
+    let storage = CredentialStore::open()?.save(credentials::GITHUB, None, token)?;
+    credentials::remove_legacy_github_token()?;
+    Ok(storage)
 }
 
-/// Load a stored GitHub token
+/// Load the GitHub token (environment, credential store, legacy file)
 pub fn load_github_token() -> Option<String> {
-    // First check environment variables (highest priority)
-    if let Ok(token) = std::env::var("GITHUB_TOKEN")
-        && !token.is_empty()
-    {
-        return Some(token);
-    }
-    if let Ok(token) = std::env::var("GH_TOKEN")
-        && !token.is_empty()
-    {
-        return Some(token);
-    }
-
-    // Then check stored token file
-    let token_file = vx_paths::VxPaths::new()
-        .ok()?
-        .config_dir
-        .join("github_token");
-    if token_file.exists()
-        && let Ok(token) = std::fs::read_to_string(&token_file)
-    {
-        let token = token.trim();
-        if !token.is_empty() {
-            return Some(token.to_string());
-        }
-    }
-
-    None
+    credentials::github_token()
 }
 
-/// Remove stored GitHub token
-pub fn remove_github_token() -> Result<()> {
-    let paths = vx_paths::VxPaths::new()?;
-    let token_file = paths.config_dir.join("github_token");
-    if token_file.exists() {
-        std::fs::remove_file(&token_file).context("Failed to remove token file")?;
-    }
-    Ok(())
+/// Remove the stored GitHub token
+pub fn remove_github_token() -> Result<bool> {
+    let removed = CredentialStore::open()?.remove(credentials::GITHUB)?;
+    Ok(credentials::remove_legacy_github_token()? || removed)
 }
 
 /// Check GitHub token status
@@ -235,7 +196,9 @@ pub enum TokenSource {
     EnvGitHubToken,
     /// Token from GH_TOKEN environment variable
     EnvGhToken,
-    /// Token from vx config file
+    /// Token from the vx credential store
+    Stored(Storage),
+    /// Token file written by older vx versions
     ConfigFile,
     /// No token configured
     None,
@@ -246,6 +209,10 @@ impl std::fmt::Display for TokenSource {
         match self {
             TokenSource::EnvGitHubToken => write!(f, "GITHUB_TOKEN environment variable"),
             TokenSource::EnvGhToken => write!(f, "GH_TOKEN environment variable"),
+            TokenSource::Stored(Storage::Keyring) => write!(f, "OS keyring"),
+            TokenSource::Stored(Storage::File) => {
+                write!(f, "vx credentials file (~/.vx/config/credentials.toml)")
+            }
             TokenSource::ConfigFile => write!(f, "vx config file (~/.vx/config/github_token)"),
             TokenSource::None => write!(f, "not configured"),
         }
@@ -259,37 +226,31 @@ pub struct RateLimitInfo {
     pub reset: u64,
 }
 
+fn github_token_source() -> TokenSource {
+    let env_set = |name: &str| std::env::var(name).is_ok_and(|t| !t.is_empty());
+    if env_set("GITHUB_TOKEN") {
+        return TokenSource::EnvGitHubToken;
+    }
+    if env_set("GH_TOKEN") {
+        return TokenSource::EnvGhToken;
+    }
+    let stored = CredentialStore::open()
+        .and_then(|store| store.entries())
+        .unwrap_or_default()
+        .into_iter()
+        .find(|entry| entry.name == credentials::GITHUB);
+    if let Some(entry) = stored {
+        return TokenSource::Stored(entry.storage);
+    }
+    if credentials::legacy_github_token().is_some() {
+        return TokenSource::ConfigFile;
+    }
+    TokenSource::None
+}
+
 /// Get current token status
 pub async fn get_token_status() -> Result<TokenStatus> {
-    // Determine token source
-    let source = if std::env::var("GITHUB_TOKEN")
-        .ok()
-        .filter(|t| !t.is_empty())
-        .is_some()
-    {
-        TokenSource::EnvGitHubToken
-    } else if std::env::var("GH_TOKEN")
-        .ok()
-        .filter(|t| !t.is_empty())
-        .is_some()
-    {
-        TokenSource::EnvGhToken
-    } else {
-        let token_file = vx_paths::VxPaths::new()
-            .ok()
-            .map(|p| p.config_dir.join("github_token"));
-        if token_file.as_ref().map(|f| f.exists()).unwrap_or(false)
-            && token_file
-                .as_ref()
-                .and_then(|f| std::fs::read_to_string(f).ok())
-                .filter(|t| !t.trim().is_empty())
-                .is_some()
-        {
-            TokenSource::ConfigFile
-        } else {
-            TokenSource::None
-        }
-    };
+    let source = github_token_source();
 
     // If no token, return early
     if matches!(source, TokenSource::None) {
@@ -303,7 +264,7 @@ pub async fn get_token_status() -> Result<TokenStatus> {
     // Get token and check with GitHub API
     let token = load_github_token()
         .context("Failed to load GitHub token despite detecting a token source")?;
-    let client = reqwest::Client::new();
+    let client = vx_proxy::client_builder().build()?;
 
     let response = client
         .get("https://api.github.com/rate_limit")
@@ -350,6 +311,262 @@ pub async fn get_token_status() -> Result<TokenStatus> {
     })
 }
 
+/// Read a secret from stdin (`--with-token`, or when stdin is not a
+/// terminal), otherwise prompt for it without echo
+fn read_secret(prompt: &str, from_stdin: bool) -> Result<String> {
+    let secret = if from_stdin || !std::io::stdin().is_terminal() {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .context("Failed to read token from stdin")?;
+        input
+    } else {
+        dialoguer::Password::new()
+            .with_prompt(prompt)
+            .interact()
+            .context("Failed to read token")?
+    };
+    let secret = secret.trim().to_string();
+    if secret.is_empty() {
+        anyhow::bail!("No token provided");
+    }
+    Ok(secret)
+}
+
+fn registry_url(url: Option<&str>, action: &str) -> Result<String> {
+    let url = url.with_context(|| {
+        format!(
+            "Missing registry URL (usage: vx auth {} registry <url>)",
+            action
+        )
+    })?;
+    credentials::registry_name(url)
+}
+
+/// Handle `vx auth login`
+pub async fn handle_login(
+    service: &str,
+    url: Option<&str>,
+    username: Option<&str>,
+    with_token: bool,
+    token: Option<&str>,
+) -> Result<()> {
+    if token.is_some() {
+        UI::warn(
+            "--token leaves the token in your shell history; prefer --with-token (reads stdin)",
+        );
+    }
+
+    match service {
+        "github" | "gh" => {
+            let token = match token {
+                Some(t) => Some(t.to_string()),
+                None if with_token => Some(read_secret("GitHub token", true)?),
+                None => None,
+            };
+
+            if let Some(token) = token {
+                let storage = store_github_token(&token)?;
+                UI::success(&format!("GitHub token stored in {}", storage));
+                return Ok(());
+            }
+
+            // Check if already authenticated
+            let status = get_token_status().await?;
+            if !matches!(status.source, TokenSource::None) {
+                UI::info(&format!(
+                    "Already authenticated with GitHub via {}",
+                    status.source
+                ));
+                if let Some(rl) = status.rate_limit {
+                    UI::detail(&format!(
+                        "Rate limit: {}/{} remaining",
+                        rl.remaining, rl.limit
+                    ));
+                }
+                UI::hint("To re-authenticate, run: vx auth logout github && vx auth login github");
+                return Ok(());
+            }
+
+            // Use Device Flow
+            UI::info("Authenticating with GitHub using Device Flow...");
+
+            let flow = GitHubDeviceFlow::new();
+            let device_code = flow.start().await?;
+
+            println!("\nPlease visit: {}", device_code.verification_uri);
+            println!("And enter code: {}\n", device_code.user_code);
+            open_browser(&device_code.verification_uri);
+
+            UI::info("Waiting for authorization...");
+
+            let token = flow
+                .poll_for_token(
+                    &device_code.device_code,
+                    device_code.interval,
+                    device_code.expires_in,
+                )
+                .await?;
+
+            let storage = store_github_token(&token)?;
+            UI::success(&format!(
+                "Successfully authenticated with GitHub (token stored in {})",
+                storage
+            ));
+            UI::hint("Set GITHUB_TOKEN or GH_TOKEN in CI instead of logging in");
+            Ok(())
+        }
+        "registry" => {
+            let name = registry_url(url, "login")?;
+            let secret = match token {
+                Some(t) => t.to_string(),
+                None => read_secret(&format!("Password or token for {}", name), with_token)?,
+            };
+            let storage = CredentialStore::open()?.save(&name, username, &secret)?;
+            UI::success(&format!("Credentials for {} stored in {}", name, storage));
+            Ok(())
+        }
+        _ => {
+            anyhow::bail!(
+                "Unknown service: {}. Supported services: github, registry",
+                service
+            );
+        }
+    }
+}
+
+/// Handle `vx auth logout`
+pub async fn handle_logout(service: &str, url: Option<&str>) -> Result<()> {
+    match service {
+        "github" | "gh" => {
+            if remove_github_token()? {
+                UI::success("Logged out from GitHub");
+            } else {
+                UI::info("No stored GitHub token");
+            }
+            UI::hint("Environment variables (GITHUB_TOKEN, GH_TOKEN) are not affected");
+            Ok(())
+        }
+        "registry" => {
+            let name = registry_url(url, "logout")?;
+            if CredentialStore::open()?.remove(&name)? {
+                UI::success(&format!("Logged out from {}", name));
+            } else {
+                UI::info(&format!("No stored credentials for {}", name));
+            }
+            Ok(())
+        }
+        _ => {
+            anyhow::bail!(
+                "Unknown service: {}. Supported services: github, registry",
+                service
+            );
+        }
+    }
+}
+
+/// Handle `vx auth status`
+pub async fn handle_status(service: &str) -> Result<()> {
+    match service {
+        "github" | "gh" => github_status().await,
+        "registry" => registry_status(),
+        "all" => {
+            github_status().await?;
+            println!();
+            registry_status()
+        }
+        _ => {
+            anyhow::bail!(
+                "Unknown service: {}. Supported services: github, registry, all",
+                service
+            );
+        }
+    }
+}
+
+async fn github_status() -> Result<()> {
+    println!("GitHub Authentication Status\n");
+
+    let status = get_token_status().await?;
+
+    match &status.source {
+        TokenSource::None => {
+            println!("  Status: Not authenticated");
+            println!("\n  To authenticate:");
+            println!("    vx auth login github");
+            println!("\n  Or set environment variable:");
+            println!("    export GITHUB_TOKEN=<your-token>");
+        }
+        source => {
+            println!("  Status: ✓ Authenticated");
+            println!("  Source: {}", source);
+
+            if !status.scopes.is_empty() {
+                println!("  Scopes: {}", status.scopes.join(", "));
+            }
+
+            if let Some(rl) = status.rate_limit {
+                println!("\n  Rate Limit:");
+                println!("    Remaining: {}/{}", rl.remaining, rl.limit);
+
+                // Calculate reset time
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs();
+                if rl.reset > now {
+                    let mins = (rl.reset - now) / 60;
+                    println!("    Resets in: {} minutes", mins);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn registry_status() -> Result<()> {
+    println!("Registry Credentials\n");
+
+    let registries: Vec<_> = CredentialStore::open()?
+        .entries()?
+        .into_iter()
+        .filter(|entry| entry.name != credentials::GITHUB)
+        .collect();
+
+    if registries.is_empty() {
+        println!("  No registry credentials stored");
+        println!("\n  To add one:");
+        println!("    vx auth login registry <url>");
+        return Ok(());
+    }
+
+    for entry in registries {
+        match &entry.username {
+            Some(user) => println!("  {} ({}, {})", entry.name, user, entry.storage),
+            None => println!("  {} (token, {})", entry.name, entry.storage),
+        }
+    }
+    Ok(())
+}
+
+fn open_browser(url: &str) {
+    #[cfg(target_os = "windows")]
+    {
+        let _ = std::process::Command::new("cmd")
+            .args(["/c", "start", url])
+            .spawn();
+    }
+    #[cfg(target_os = "macos")]
+    {
+        let _ = std::process::Command::new("open").arg(url).spawn();
+    }
+    #[cfg(target_os = "linux")]
+    {
+        let _ = std::process::Command::new("xdg-open").arg(url).spawn();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
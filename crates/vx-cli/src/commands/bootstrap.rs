@@ -0,0 +1,242 @@
+//! Bootstrap command implementation
+//!
+//! `vx bootstrap script` prints a POSIX shell script that installs vx,
+//! writes the current project's `vx.toml` and `vx.lock` and installs the
+//! locked tools with `vx sync`. `vx bootstrap ssh user@host` runs the same
+//! script on a remote machine over ssh, which provisions a fresh build agent
+//! or cloud dev box in one step. The project files travel inside the script,
+//! so nothing but `ssh` and `sh` is needed.
+
+use crate::commands::wrap::INSTALL_SH_URL;
+use crate::ui::UI;
+use anyhow::{Context, Result, bail};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use vx_paths::project::{LOCK_FILE_NAMES, find_vx_config};
+
+/// Project files copied to the bootstrapped machine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BootstrapProject {
+    /// Directory name of the project, the default remote directory
+    pub name: String,
+    /// File name and content of the project configuration
+    pub config: (String, String),
+    /// File name and content of the lock file, if the project has one
+    pub lock: Option<(String, String)>,
+}
+
+impl BootstrapProject {
+    /// The project containing `dir`, or `None` outside a project
+    pub fn find(dir: &Path) -> Result<Option<Self>> {
+        let Ok(config_path) = find_vx_config(dir) else {
+            return Ok(None);
+        };
+        let root = config_path.parent().unwrap_or(dir);
+        let read = |path: &Path| -> Result<(String, String)> {
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let content = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            Ok((name, content))
+        };
+
+        let lock = LOCK_FILE_NAMES
+            .iter()
+            .map(|name| root.join(name))
+            .find(|path| path.is_file())
+            .map(|path| read(&path))
+            .transpose()?;
+        Ok(Some(Self {
+            name: root
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "vx-project".to_string()),
+            config: read(&config_path)?,
+            lock,
+        }))
+    }
+}
+
+/// Render the bootstrap script
+///
+/// Without a project only vx is installed. `dir` is where the project
+/// files are written, relative to the remote working directory (default:
+/// the project's name); `VX_BOOTSTRAP_DIR` overrides it when the script runs.
+pub fn render_script(
+    project: Option<&BootstrapProject>,
+    dir: Option<&str>,
+    vx_version: &str,
+) -> String {
+    let mut out = format!(
+        r#"#!/bin/sh
+# Generated by `vx bootstrap` (vx {version})
+#
+# Installs vx{project_note}.
+set -e
+
+VX_BOOTSTRAP_VERSION="{version}"
+
+if command -v vx >/dev/null 2>&1; then
+    VX=vx
+else
+    VX_INSTALL_DIR="${{VX_INSTALL_DIR:-$HOME/.local/bin}}"
+    echo "Installing vx $VX_BOOTSTRAP_VERSION to $VX_INSTALL_DIR" >&2
+    if command -v curl >/dev/null 2>&1; then
+        fetch="curl -fsSL"
+    else
+        fetch="wget -qO-"
+    fi
+    $fetch {install_sh} \
+        | VX_VERSION="$VX_BOOTSTRAP_VERSION" VX_INSTALL_DIR="$VX_INSTALL_DIR" bash >&2
+    VX="$VX_INSTALL_DIR/vx"
+fi
+"#,
+        version = vx_version,
+        project_note = if project.is_some() {
+            " and the locked tools of the project"
+        } else {
+            ""
+        },
+        install_sh = INSTALL_SH_URL,
+    );
+
+    let Some(project) = project else {
+        out.push_str("\n\"$VX\" --version\n");
+        return out;
+    };
+
+    out.push_str(&format!(
+        r#"
+if [ -z "${{VX_BOOTSTRAP_DIR:-}}" ]; then
+    VX_BOOTSTRAP_DIR={dir}
+fi
+mkdir -p "$VX_BOOTSTRAP_DIR"
+cd "$VX_BOOTSTRAP_DIR"
+"#,
+        dir = sh_quote(dir.unwrap_or(&project.name)),
+    ));
+    for (name, content) in std::iter::once(&project.config).chain(project.lock.as_ref()) {
+        out.push_str(&heredoc(name, content));
+    }
+    out.push_str("\n\"$VX\" sync\n");
+    out
+}
+
+/// `cat > <name>` with `content` as a quoted heredoc
+fn heredoc(name: &str, content: &str) -> String {
+    let mut delimiter = "VX_BOOTSTRAP_EOF".to_string();
+    while content.lines().any(|line| line == delimiter) {
+        delimiter.push('_');
+    }
+    let mut out = format!("\ncat > {} <<'{}'\n{}", sh_quote(name), delimiter, content);
+    if !content.ends_with('\n') {
+        out.push('\n');
+    }
+    out.push_str(&delimiter);
+    out.push('\n');
+    out
+}
+
+/// Quote `s` for a POSIX shell
+pub fn sh_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
+
+/// Arguments of the `ssh` call running the script on `target`
+pub fn ssh_args(target: &str, port: Option<u16>, identity: Option<&Path>) -> Vec<String> {
+    let mut args = Vec::new();
+    if let Some(port) = port {
+        args.extend(["-p".to_string(), port.to_string()]);
+    }
+    if let Some(identity) = identity {
+        args.extend(["-i".to_string(), identity.display().to_string()]);
+    }
+    args.extend([target.to_string(), "sh -s".to_string()]);
+    args
+}
+
+fn current_project(no_project: bool) -> Result<Option<BootstrapProject>> {
+    if no_project {
+        return Ok(None);
+    }
+    let cwd = std::env::current_dir().context("Failed to get current directory")?;
+    let project = BootstrapProject::find(&cwd)?;
+    match &project {
+        None => UI::warn_stderr("No vx.toml found; only vx will be installed"),
+        Some(project) if project.lock.is_none() => UI::warn_stderr(
+            "The project has no vx.lock; the remote machine resolves tool versions itself (run `vx lock` first)",
+        ),
+        Some(_) => {}
+    }
+    Ok(project)
+}
+
+/// Handle `vx bootstrap script`
+pub async fn handle_script(
+    dir: Option<&str>,
+    no_project: bool,
+    output: Option<&Path>,
+) -> Result<()> {
+    let project = current_project(no_project)?;
+    let script = render_script(project.as_ref(), dir, env!("CARGO_PKG_VERSION"));
+    match output {
+        Some(path) => {
+            std::fs::write(path, script)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))?;
+            }
+            UI::success(&format!("Wrote bootstrap script to {}", path.display()));
+        }
+        None => print!("{}", script),
+    }
+    Ok(())
+}
+
+/// Handle `vx bootstrap ssh`
+pub async fn handle_ssh(
+    target: &str,
+    dir: Option<&str>,
+    port: Option<u16>,
+    identity: Option<PathBuf>,
+    no_project: bool,
+) -> Result<()> {
+    let project = current_project(no_project)?;
+    let script = render_script(project.as_ref(), dir, env!("CARGO_PKG_VERSION"));
+
+    UI::info(&format!("Bootstrapping {} over ssh...", target));
+    let mut child = Command::new("ssh")
+        .args(ssh_args(target, port, identity.as_deref()))
+        .stdin(Stdio::piped())
+        .spawn()
+        .context("Failed to run ssh (is OpenSSH installed?)")?;
+    child
+        .stdin
+        .take()
+        .context("Failed to open ssh stdin")?
+        .write_all(script.as_bytes())?;
+    let status = child.wait()?;
+    if !status.success() {
+        bail!(
+            "Bootstrap of {} failed (ssh exited with {})",
+            target,
+            status.code().unwrap_or(-1)
+        );
+    }
+
+    match &project {
+        Some(project) => UI::success(&format!(
+            "Bootstrapped {}: vx and the tools of {} are installed in {}",
+            target,
+            project.name,
+            dir.unwrap_or(&project.name)
+        )),
+        None => UI::success(&format!("Installed vx on {}", target)),
+    }
+    Ok(())
+}
@@ -1,11 +1,15 @@
 //! Cache management command implementation
 //!
 //! This module consolidates all cache-related operations:
-//! - `info`: Show cache statistics and disk usage
-//! - `list`: List cached items
-//! - `prune`: Safely remove expired/orphaned cache entries
+//! - `info`: Show cache statistics, disk usage and HTTP cache hit rates
+//! - `list`: List cached version lists, downloads and installer temp files
+//! - `prune`: Safely remove expired/orphaned cache entries, bounded by age and
+//!   size; defaults come from the cache policy in `~/.vx/config/cache.toml`
 //! - `purge`: Forcefully remove all cache data
 //! - `dir`: Show cache directory path
+//! - `serve`: Share the download cache with peers on the LAN and expose
+//!   Prometheus metrics of this machine at `/metrics`
+//! - `peers`: List peer caches on the LAN
 //!
 //! ## Design Philosophy
 //!
@@ -15,10 +19,16 @@
 //! This avoids the confusing `clear` vs `clean` naming.
 
 use super::common::format_size;
+use crate::cache_policy::{CACHE_CONFIG_FILE, CachePolicy, TEMP_MAX_AGE};
 use crate::cli::CacheCommand;
 use crate::ui::UI;
-use anyhow::Result;
-use vx_cache::DownloadCache;
+use anyhow::{Context, Result};
+use std::net::Ipv4Addr;
+use std::time::{Duration, SystemTime};
+use vx_cache::peer::{METRICS_PATH, PeerServer, PeerServerStats};
+use vx_cache::{DownloadCache, HttpCache, NetworkStats, TempArea, UsageCounters, usage};
+use vx_metrics::CommandLatencies;
+use vx_metrics::prometheus::{MetricKind, PrometheusText};
 use vx_paths::VxPaths;
 use vx_resolver::{RESOLUTION_CACHE_DIR_NAME, ResolutionCache};
 use vx_runtime::VersionCache;
@@ -33,29 +43,34 @@ pub async fn handle(command: CacheCommand) -> Result<()> {
             versions,
             downloads,
             resolutions,
+            http,
             orphaned,
+            temp,
             older_than,
+            max_size,
             verbose,
         } => {
-            handle_prune(
-                dry_run,
+            let selection = PruneSelection {
                 versions,
                 downloads,
                 resolutions,
+                http,
                 orphaned,
-                older_than,
-                verbose,
-            )
-            .await
+                temp,
+            };
+            handle_prune(dry_run, selection, older_than, max_size, verbose).await
         }
         CacheCommand::Purge {
             versions,
             downloads,
             resolutions,
+            http,
             tool,
             yes,
-        } => handle_purge(versions, downloads, resolutions, tool, yes).await,
+        } => handle_purge(versions, downloads, resolutions, http, tool, yes).await,
         CacheCommand::Dir => handle_dir().await,
+        CacheCommand::Serve { port, no_announce } => handle_serve(port, no_announce).await,
+        CacheCommand::Peers => handle_peers().await,
     }
 }
 
@@ -97,6 +112,41 @@ async fn handle_info() -> Result<()> {
     UI::info("Download Cache:");
     println!("  Cached files: {}", download_stats.file_count);
     println!("  Total size:   {}", download_stats.formatted_size());
+    match vx_proxy::RemoteCacheConfig::load().store() {
+        Ok(Some(store)) => println!(
+            "  Remote:       {}{}",
+            store.display_url(),
+            if store.upload { "" } else { " (read-only)" }
+        ),
+        Ok(None) => {}
+        Err(e) => UI::warn(&format!("Remote cache: {}", e)),
+    }
+
+    // HTTP response cache stats
+    let http_stats = HttpCache::new(paths.cache_dir.clone()).stats();
+    println!();
+    UI::info("HTTP Cache:");
+    println!(
+        "  Entries:         {} ({} fresh)",
+        http_stats.entries, http_stats.fresh_entries
+    );
+    println!("  Total size:      {}", http_stats.formatted_size());
+    println!("  Requests:        {}", http_stats.requests());
+    println!("  Hits:            {}", http_stats.hits);
+    println!("  Revalidated:     {}", http_stats.revalidated);
+    println!("  Offline reuse:   {}", http_stats.stale_fallbacks);
+    println!("  Hit rate:        {:.1}%", http_stats.hit_rate() * 100.0);
+
+    // Installer temp area
+    let temp_entries = TempArea::new(paths.tmp_dir.clone()).entries();
+    println!();
+    UI::info("Temporary Files:");
+    println!("  Location:        {}", paths.tmp_dir.display());
+    println!("  Entries:         {}", temp_entries.len());
+    println!(
+        "  Total size:      {}",
+        format_size(temp_entries.iter().map(|e| e.size).sum())
+    );
 
     // Store directory stats
     if paths.store_dir.exists() {
@@ -107,6 +157,31 @@ async fn handle_info() -> Result<()> {
         println!("  Total size: {}", format_size(store_size));
     }
 
+    // Pruning policy
+    let policy = CachePolicy::load();
+    println!();
+    UI::info("Prune Policy:");
+    println!(
+        "  Max size:        {}",
+        policy.max_size.map_or("unlimited".to_string(), format_size)
+    );
+    println!(
+        "  Older than:      {}",
+        policy.older_than.map_or("never".to_string(), format_age)
+    );
+    println!(
+        "  Automatic:       {}",
+        if policy.auto_prune && policy.is_limited() {
+            format!("every {}", format_age(policy.prune_interval))
+        } else {
+            "off".to_string()
+        }
+    );
+    println!(
+        "  Config:          {}",
+        paths.config_dir.join(CACHE_CONFIG_FILE).display()
+    );
+
     println!();
     UI::hint("Run 'vx cache prune' to remove expired entries");
     UI::hint("Run 'vx cache purge' to remove all cache (destructive)");
@@ -114,9 +189,57 @@ async fn handle_info() -> Result<()> {
     Ok(())
 }
 
-/// List cached items
+/// List cached version lists, downloads and temp files
 async fn handle_list(verbose: bool) -> Result<()> {
     let paths = VxPaths::new()?;
+    list_version_cache(&paths, verbose);
+
+    println!();
+    UI::header("Cached Downloads");
+    let downloads = DownloadCache::new(paths.cache_dir.clone()).entries();
+    if downloads.is_empty() {
+        UI::info("No cached downloads");
+    }
+    // Most recently used first
+    for entry in downloads.iter().rev() {
+        let name = entry
+            .metadata
+            .as_ref()
+            .map_or(entry.key.as_str(), |m| m.filename.as_str());
+        if verbose {
+            println!(
+                "  {} ({}, used {} ago)",
+                name,
+                format_size(entry.size),
+                format_age(age_of(entry.last_used))
+            );
+            if let Some(metadata) = &entry.metadata {
+                println!("    {}", metadata.url);
+            }
+        } else {
+            println!("  {} ({})", name, format_size(entry.size));
+        }
+    }
+
+    let temp = TempArea::new(paths.tmp_dir.clone()).entries();
+    if !temp.is_empty() {
+        println!();
+        UI::header("Temporary Files");
+        for entry in temp.iter().rev() {
+            let name = entry.path.file_name().unwrap_or_default().to_string_lossy();
+            println!(
+                "  {} ({}, modified {} ago)",
+                name,
+                format_size(entry.size),
+                format_age(age_of(entry.modified))
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn list_version_cache(paths: &VxPaths, verbose: bool) {
     // VersionCache::new expects the base cache dir and appends "versions_v2" internally
     let version_cache = VersionCache::new(paths.cache_dir.clone());
     let cache_dir = paths.cache_dir.join("versions_v2");
@@ -125,38 +248,31 @@ async fn handle_list(verbose: bool) -> Result<()> {
 
     if !cache_dir.exists() {
         UI::info("No version cache found");
-        return Ok(());
+        return;
     }
 
     if let Ok(entries) = std::fs::read_dir(&cache_dir) {
         let mut found = false;
         for entry in entries.filter_map(|e| e.ok()) {
             let path = entry.path();
-            // Check for data files (.data or .jsonval) or metadata files (.meta)
-            let is_cache_file = path
-                .extension()
-                .is_some_and(|ext| ext == "data" || ext == "jsonval" || ext == "meta");
-
-            if is_cache_file {
-                // Only show each tool once (use .meta files)
-                if path.extension().is_some_and(|ext| ext == "meta") {
-                    found = true;
-                    let tool_name = path.file_stem().unwrap_or_default().to_string_lossy();
-
-                    if verbose {
-                        // Show detailed info
-                        if let Ok(meta) = path.metadata() {
-                            let size = format_size(meta.len());
-                            // Use get_entry to check if cache is valid
-                            let is_valid = version_cache.get_entry(&tool_name).is_some();
-                            let status = if is_valid { "valid" } else { "expired" };
-                            println!("  {} ({}, {})", tool_name, size, status);
-                        } else {
-                            println!("  {}", tool_name);
-                        }
+            // Only show each tool once (use .meta files)
+            if path.extension().is_some_and(|ext| ext == "meta") {
+                found = true;
+                let tool_name = path.file_stem().unwrap_or_default().to_string_lossy();
+
+                if verbose {
+                    // Show detailed info
+                    if let Ok(meta) = path.metadata() {
+                        let size = format_size(meta.len());
+                        // Use get_entry to check if cache is valid
+                        let is_valid = version_cache.get_entry(&tool_name).is_some();
+                        let status = if is_valid { "valid" } else { "expired" };
+                        println!("  {} ({}, {})", tool_name, size, status);
                     } else {
                         println!("  {}", tool_name);
                     }
+                } else {
+                    println!("  {}", tool_name);
                 }
             }
         }
@@ -165,21 +281,43 @@ async fn handle_list(verbose: bool) -> Result<()> {
             UI::info("No cached version lists");
         }
     }
+}
 
-    Ok(())
+/// Which cache areas `prune` touches; all of them when nothing is selected
+struct PruneSelection {
+    versions: bool,
+    downloads: bool,
+    resolutions: bool,
+    http: bool,
+    orphaned: bool,
+    temp: bool,
+}
+
+impl PruneSelection {
+    fn any(&self) -> bool {
+        self.versions
+            || self.downloads
+            || self.resolutions
+            || self.http
+            || self.orphaned
+            || self.temp
+    }
 }
 
 /// Prune expired and orphaned cache entries (safe cleanup)
+///
+/// `older_than` and `max_size` default to the cache policy.
 async fn handle_prune(
     dry_run: bool,
-    versions_only: bool,
-    downloads_only: bool,
-    resolutions_only: bool,
-    orphaned_only: bool,
-    older_than: Option<u32>,
+    selection: PruneSelection,
+    older_than: Option<Duration>,
+    max_size: Option<u64>,
     verbose: bool,
 ) -> Result<()> {
     let paths = VxPaths::new()?;
+    let policy = CachePolicy::load();
+    let older_than = older_than.or(policy.older_than);
+    let max_size = max_size.or(policy.max_size);
 
     if dry_run {
         UI::header("Prune Preview (Dry Run)");
@@ -189,11 +327,13 @@ async fn handle_prune(
 
     // Determine what to prune
     // If no selector flag is provided, prune all categories
-    let any_selector = versions_only || downloads_only || resolutions_only || orphaned_only;
-    let prune_versions = if any_selector { versions_only } else { true };
-    let prune_downloads = if any_selector { downloads_only } else { true };
-    let prune_resolutions = if any_selector { resolutions_only } else { true };
-    let prune_orphaned = if any_selector { orphaned_only } else { true };
+    let any_selector = selection.any();
+    let prune_versions = !any_selector || selection.versions;
+    let prune_downloads = !any_selector || selection.downloads;
+    let prune_resolutions = !any_selector || selection.resolutions;
+    let prune_http = !any_selector || selection.http;
+    let prune_orphaned = !any_selector || selection.orphaned;
+    let prune_temp = !any_selector || selection.temp;
 
     let mut total_pruned = 0;
 
@@ -265,7 +405,7 @@ async fn handle_prune(
         }
     }
 
-    // Prune download cache (old files based on older_than)
+    // Prune download cache (unused for too long, then least recently used beyond max_size)
     if prune_downloads {
         let download_cache = DownloadCache::new(paths.cache_dir.clone());
         let stats = download_cache.stats();
@@ -278,21 +418,82 @@ async fn handle_prune(
             ));
         }
 
-        if let Some(days) = older_than {
+        if older_than.is_some() || max_size.is_some() {
+            let pruned = download_cache.prune(older_than, max_size, dry_run);
             if dry_run {
-                UI::hint(&format!("  Would prune files older than {} days", days));
-            } else {
-                // Prune old download files
-                let pruned = prune_old_downloads(&paths.cache_dir, days)?;
-                if pruned > 0 {
-                    UI::success(&format!("Pruned {} old download cache files", pruned));
-                    total_pruned += pruned;
-                } else if verbose {
-                    UI::info("No old download cache files to prune");
+                if pruned.removed > 0 {
+                    UI::hint(&format!(
+                        "  Would prune {} download cache files ({})",
+                        pruned.removed,
+                        format_size(pruned.freed_bytes)
+                    ));
                 }
+            } else if pruned.removed > 0 {
+                UI::success(&format!(
+                    "Pruned {} download cache files ({})",
+                    pruned.removed,
+                    format_size(pruned.freed_bytes)
+                ));
+                total_pruned += pruned.removed;
+            } else if verbose {
+                UI::info("No download cache files to prune");
+            }
+        } else if verbose {
+            UI::hint(&format!(
+                "Use --older-than or --max-size, or set them in ~/.vx/config/{}",
+                CACHE_CONFIG_FILE
+            ));
+        }
+    }
+
+    // Prune leftovers of interrupted installs
+    if prune_temp {
+        let temp_area = TempArea::new(paths.tmp_dir.clone());
+        let pruned = temp_area.prune(TEMP_MAX_AGE, dry_run);
+        if dry_run {
+            if pruned.removed > 0 {
+                UI::hint(&format!(
+                    "  Would prune {} temporary files ({})",
+                    pruned.removed,
+                    format_size(pruned.freed_bytes)
+                ));
+            }
+        } else if pruned.removed > 0 {
+            UI::success(&format!(
+                "Pruned {} temporary files ({})",
+                pruned.removed,
+                format_size(pruned.freed_bytes)
+            ));
+            total_pruned += pruned.removed;
+        } else if verbose {
+            UI::info("No temporary files to prune");
+        }
+    }
+
+    // Prune HTTP response cache (unusable entries, plus old ones with --older-than)
+    if prune_http {
+        let http_cache = HttpCache::new(paths.cache_dir.clone());
+        let older_than_secs = older_than.map(|age| age.as_secs());
+        let pruned = http_cache.prune(older_than_secs, dry_run);
+
+        if verbose || dry_run {
+            let stats = http_cache.stats();
+            UI::info(&format!(
+                "HTTP cache: {} entries ({})",
+                stats.entries,
+                stats.formatted_size()
+            ));
+        }
+
+        if dry_run {
+            if pruned > 0 {
+                UI::hint(&format!("  Would prune {} HTTP cache entries", pruned));
             }
+        } else if pruned > 0 {
+            UI::success(&format!("Pruned {} HTTP cache entries", pruned));
+            total_pruned += pruned;
         } else if verbose {
-            UI::hint("Use --older-than to prune old download files");
+            UI::info("No HTTP cache entries to prune");
         }
     }
 
@@ -323,6 +524,7 @@ async fn handle_purge(
     versions_only: bool,
     downloads_only: bool,
     resolutions_only: bool,
+    http_only: bool,
     tool: Option<String>,
     yes: bool,
 ) -> Result<()> {
@@ -341,10 +543,11 @@ async fn handle_purge(
     }
 
     // Determine what to purge
-    let any_selector = versions_only || downloads_only || resolutions_only;
+    let any_selector = versions_only || downloads_only || resolutions_only || http_only;
     let purge_versions = if any_selector { versions_only } else { true };
     let purge_downloads = if any_selector { downloads_only } else { true };
     let purge_resolutions = if any_selector { resolutions_only } else { true };
+    let purge_http = if any_selector { http_only } else { true };
 
     // Confirmation
     if !yes {
@@ -359,6 +562,9 @@ async fn handle_purge(
         if purge_resolutions {
             targets.push("resolution cache");
         }
+        if purge_http {
+            targets.push("HTTP cache");
+        }
         UI::info(&format!("Targets: {}", targets.join(", ")));
 
         if !confirm_action()? {
@@ -411,11 +617,32 @@ async fn handle_purge(
         }
     }
 
+    // Purge HTTP response cache
+    if purge_http {
+        let http_cache = HttpCache::new(paths.cache_dir.clone());
+        let entries = http_cache.stats().entries;
+        http_cache.clear()?;
+        if entries > 0 {
+            UI::success(&format!("HTTP cache purged: {} entries", entries));
+        } else {
+            UI::info("HTTP cache: (already empty)");
+        }
+    }
+
     // Always clear exec path cache on full purge
     if !any_selector {
         let _ = vx_cache::ExecPathCache::remove_file(&paths.cache_dir);
         vx_resolver::clear_bin_dir_cache();
         UI::success("Exec path cache cleared");
+
+        let analysis_cache = vx_starlark::AnalysisDiskCache::new(
+            paths.cache_dir.join(vx_starlark::ANALYSIS_CACHE_DIR_NAME),
+        );
+        analysis_cache.clear();
+        UI::success("Starlark analysis cache cleared");
+
+        let _ = vx_cache::RegistryIndex::remove_file(&paths.cache_dir);
+        UI::success("Registry index cleared");
     }
 
     UI::success("Purge completed");
@@ -429,28 +656,237 @@ async fn handle_dir() -> Result<()> {
     Ok(())
 }
 
-/// Prune old download files
-fn prune_old_downloads(cache_dir: &std::path::Path, days: u32) -> Result<usize> {
-    let mut count = 0;
-    let threshold = std::time::Duration::from_secs(days as u64 * 24 * 60 * 60);
+/// Serve the download cache to peers until interrupted
+async fn handle_serve(port: Option<u16>, no_announce: bool) -> Result<()> {
+    let paths = VxPaths::new()?;
+    let port = port.unwrap_or_else(|| vx_proxy::PeerConfig::load().port);
+    let cache = DownloadCache::new(paths.cache_dir.clone());
+    let stats = cache.stats();
+
+    let listener = std::net::TcpListener::bind((Ipv4Addr::UNSPECIFIED, port))
+        .with_context(|| format!("Failed to listen on port {}", port))?;
+
+    UI::header("Peer Cache");
+    UI::info(&format!(
+        "Sharing {} cached downloads ({}) on port {}",
+        stats.file_count,
+        stats.formatted_size(),
+        port
+    ));
+
+    if !no_announce {
+        let host = std::env::var("HOSTNAME")
+            .or_else(|_| std::env::var("COMPUTERNAME"))
+            .unwrap_or_else(|_| "host".to_string());
+        let instance = format!("vx-{}", host);
+        std::thread::spawn(move || {
+            if let Err(e) = vx_cache::peer::mdns::respond(&instance, port) {
+                UI::warn(&format!(
+                    "mDNS announcement stopped: {}. Peers can still list this machine in peers.toml",
+                    e
+                ));
+            }
+        });
+    }
 
-    if let Ok(entries) = std::fs::read_dir(cache_dir) {
-        for entry in entries.filter_map(|e| e.ok()) {
-            let path = entry.path();
-            if path.is_file()
-                && let Ok(meta) = path.metadata()
-                && let Ok(modified) = meta.modified()
-            {
-                let age = modified.elapsed().unwrap_or_default();
-                if age > threshold {
-                    std::fs::remove_file(&path)?;
-                    count += 1;
-                }
+    UI::hint("Other machines use it with VX_PEER_CACHE=on (or enabled = true in peers.toml)");
+    UI::hint(&format!(
+        "Prometheus metrics: http://localhost:{}{}",
+        port, METRICS_PATH
+    ));
+    UI::hint("Press Ctrl+C to stop");
+
+    let server = PeerServer::new(cache).with_metrics(move |server| render_metrics(&paths, server));
+    tokio::task::spawn_blocking(move || server.serve(listener))
+        .await?
+        .context("Peer cache server stopped")?;
+    Ok(())
+}
+
+/// Prometheus metrics of this machine's vx usage and of the peer server
+///
+/// Counters are read from disk on every scrape, so they include every vx
+/// process on the machine, not only this server.
+pub fn render_metrics(paths: &VxPaths, server: &PeerServerStats) -> String {
+    let mut out = PrometheusText::new();
+    out.header(
+        "vx_build_info",
+        MetricKind::Gauge,
+        "Version of the vx serving the metrics",
+    );
+    out.sample(
+        "vx_build_info",
+        &[("version", env!("CARGO_PKG_VERSION"))],
+        1,
+    );
+
+    let counters = UsageCounters::new(&paths.cache_dir);
+    out.single(
+        "vx_installs_total",
+        MetricKind::Counter,
+        "Runtimes installed into the store",
+        counters.get(usage::INSTALLS),
+    );
+
+    out.header(
+        "vx_download_cache_requests_total",
+        MetricKind::Counter,
+        "Download cache lookups by result",
+    );
+    let name = "vx_download_cache_requests_total";
+    out.sample(
+        name,
+        &[("result", "hit")],
+        counters.get(usage::DOWNLOAD_CACHE_HITS),
+    );
+    out.sample(
+        name,
+        &[("result", "miss")],
+        counters.get(usage::DOWNLOAD_CACHE_MISSES),
+    );
+
+    let downloads = DownloadCache::new(paths.cache_dir.clone()).stats();
+    out.single(
+        "vx_download_cache_files",
+        MetricKind::Gauge,
+        "Files in the download cache",
+        downloads.file_count,
+    );
+    out.single(
+        "vx_download_cache_size_bytes",
+        MetricKind::Gauge,
+        "Size of the download cache",
+        downloads.total_size,
+    );
+
+    let sources = NetworkStats::new(paths.cache_dir.clone()).sources();
+    out.header(
+        "vx_downloads_total",
+        MetricKind::Counter,
+        "Download attempts by source host and result",
+    );
+    for source in &sources {
+        let name = "vx_downloads_total";
+        out.sample(
+            name,
+            &[("host", &source.host), ("result", "success")],
+            source.successes,
+        );
+        out.sample(
+            name,
+            &[("host", &source.host), ("result", "failure")],
+            source.failures,
+        );
+    }
+    out.header(
+        "vx_download_bytes_total",
+        MetricKind::Counter,
+        "Bytes downloaded by source host",
+    );
+    for source in &sources {
+        out.sample(
+            "vx_download_bytes_total",
+            &[("host", &source.host)],
+            source.bytes,
+        );
+    }
+
+    let http = HttpCache::new(paths.cache_dir.clone()).stats();
+    out.header(
+        "vx_http_cache_requests_total",
+        MetricKind::Counter,
+        "HTTP response cache requests by result",
+    );
+    let name = "vx_http_cache_requests_total";
+    out.sample(name, &[("result", "hit")], http.hits);
+    out.sample(name, &[("result", "revalidated")], http.revalidated);
+    out.sample(name, &[("result", "stale_fallback")], http.stale_fallbacks);
+    out.sample(name, &[("result", "miss")], http.misses);
+
+    CommandLatencies::load(&paths.base_dir.join("metrics")).render(&mut out);
+
+    out.header(
+        "vx_peer_requests_total",
+        MetricKind::Counter,
+        "Cache entries requested from this server by result",
+    );
+    out.sample("vx_peer_requests_total", &[("result", "hit")], server.hits);
+    out.sample(
+        "vx_peer_requests_total",
+        &[("result", "miss")],
+        server.misses,
+    );
+    out.single(
+        "vx_peer_bytes_served_total",
+        MetricKind::Counter,
+        "Bytes of cache entries sent to peers",
+        server.bytes_served,
+    );
+
+    out.finish()
+}
+
+/// List configured and discovered peer caches
+async fn handle_peers() -> Result<()> {
+    let config = vx_proxy::PeerConfig::load();
+
+    let lookup = config.clone();
+    let (configured, discovered) = tokio::task::spawn_blocking(move || {
+        let discovered = if lookup.discovery {
+            vx_cache::peer::discover(lookup.discovery_timeout())
+        } else {
+            Ok(Vec::new())
+        };
+        (lookup.static_peers(), discovered)
+    })
+    .await?;
+
+    UI::header("Peer Caches");
+    if !config.enabled {
+        UI::warn(
+            "Peer cache is disabled. Enable it with VX_PEER_CACHE=on or enabled = true in peers.toml",
+        );
+    }
+
+    println!();
+    UI::info("Configured:");
+    if configured.is_empty() {
+        println!("  (none)");
+    }
+    for peer in &configured {
+        UI::item(&peer.to_string());
+    }
+
+    println!();
+    UI::info("Discovered (mDNS):");
+    match discovered {
+        Ok(peers) if !peers.is_empty() => {
+            for peer in peers {
+                UI::item(&peer.to_string());
             }
         }
+        Ok(_) if !config.discovery => println!("  (discovery disabled)"),
+        Ok(_) => println!("  (none)"),
+        Err(e) => UI::warn(&format!("Discovery failed: {}", e)),
     }
 
-    Ok(count)
+    Ok(())
+}
+
+/// Time elapsed since `time`
+fn age_of(time: SystemTime) -> Duration {
+    time.elapsed().unwrap_or_default()
+}
+
+/// Coarse human-readable age, e.g. `3d`, `5h`, `12m`
+fn format_age(age: Duration) -> String {
+    let secs = age.as_secs();
+    match secs {
+        0..60 => format!("{}s", secs),
+        60..3600 => format!("{}m", secs / 60),
+        3600..86400 => format!("{}h", secs / 3600),
+        _ => format!("{}d", secs / 86400),
+    }
 }
 
 /// Ask for user confirmation
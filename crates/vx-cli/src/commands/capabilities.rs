@@ -2,12 +2,18 @@
 //!
 //! Provides AI-friendly capability discovery for vx.
 //! Returns structured information about available runtimes, system tools, and features.
+//!
+//! `vx capabilities` describes what this vx build supports, independent of
+//! what is installed: every registered runtime with its aliases, executables,
+//! platforms and install strategies, for docs generators and MCP clients.
 
+use crate::cli::OutputFormat;
+use crate::output::{CommandOutput, OutputRenderer};
 use anyhow::Result;
 use serde::Serialize;
 use std::collections::HashMap;
 use vx_paths::{PathManager, PathResolver};
-use vx_runtime::{Ecosystem, Platform, ProviderRegistry};
+use vx_runtime::{Ecosystem, GpuInfo, InstallKind, Libc, Platform, ProviderRegistry, Runtime};
 
 /// Output format for capabilities command
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -120,6 +126,67 @@ pub async fn handle(registry: &ProviderRegistry, json: bool) -> Result<()> {
     Ok(())
 }
 
+/// Machine information for `vx info --system`
+#[derive(Debug, Serialize)]
+pub struct SystemInfo {
+    pub platform: PlatformInfo,
+    /// C library on Linux ("gnu" or "musl")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub libc: Option<String>,
+    pub cpus: usize,
+    pub gpu: GpuStatus,
+}
+
+/// GPU section of [`SystemInfo`]
+#[derive(Debug, Serialize)]
+pub struct GpuStatus {
+    pub available: bool,
+    #[serde(flatten)]
+    pub info: GpuInfo,
+}
+
+impl SystemInfo {
+    /// Collect information about this machine
+    pub fn current() -> Self {
+        let gpu = GpuInfo::current().clone();
+        Self {
+            platform: PlatformInfo::current(),
+            libc: cfg!(target_os = "linux").then(|| Libc::current().to_string()),
+            cpus: std::thread::available_parallelism().map_or(1, |n| n.get()),
+            gpu: GpuStatus {
+                available: gpu.is_available(),
+                info: gpu,
+            },
+        }
+    }
+}
+
+/// Handle the `vx info --system` command
+pub fn handle_system(json: bool) -> Result<()> {
+    let info = SystemInfo::current();
+    if json {
+        println!("{}", serde_json::to_string_pretty(&info)?);
+        return Ok(());
+    }
+
+    println!("Platform: {} ({})", info.platform.os, info.platform.arch);
+    if let Some(libc) = &info.libc {
+        println!("libc:     {}", libc);
+    }
+    println!("CPUs:     {}", info.cpus);
+    println!("GPU:      {}", info.gpu.info.summary());
+    for device in &info.gpu.info.devices {
+        match device.memory_mib {
+            Some(mib) => println!("          {} ({} MiB)", device.name, mib),
+            None => println!("          {}", device.name),
+        }
+    }
+    if let Some(toolkit) = &info.gpu.info.toolkit_version {
+        println!("CUDA toolkit: {}", toolkit);
+    }
+    Ok(())
+}
+
 /// Handle the `vx info --warnings` command
 ///
 /// Displays build diagnostics from the provider registry construction,
@@ -439,3 +506,198 @@ fn print_text_format(capabilities: &Capabilities) {
         capabilities.features.virtual_environments
     );
 }
+
+// ============================================================================
+// vx capabilities
+// ============================================================================
+
+/// Output for `vx capabilities`
+#[derive(Debug, Serialize)]
+pub struct BuildCapabilities {
+    /// vx version
+    pub version: String,
+    /// Platform vx is running on (e.g. `linux-x64`)
+    pub platform: String,
+    /// Registered runtimes, sorted by name
+    pub runtimes: Vec<RuntimeCapabilities>,
+}
+
+/// What vx can do with one runtime
+#[derive(Debug, Serialize)]
+pub struct RuntimeCapabilities {
+    pub name: String,
+    pub provider: String,
+    pub description: String,
+    pub ecosystem: String,
+    pub aliases: Vec<String>,
+    /// Executables available through `vx <name>`: the runtime's own and
+    /// those of runtimes bundled with it
+    pub executables: Vec<String>,
+    /// Supported platforms (e.g. `windows-x64`)
+    pub platforms: Vec<String>,
+    /// Install strategies, most preferred first; empty for tools vx only
+    /// detects on the system
+    pub install_strategies: Vec<InstallKind>,
+    /// Whether the runtime can be installed without network access, from the
+    /// download cache or an offline bundle
+    pub offline_install: bool,
+}
+
+impl BuildCapabilities {
+    /// Describe every runtime in `registry`
+    pub fn collect(registry: &ProviderRegistry) -> Self {
+        let mut runtimes: Vec<(String, std::sync::Arc<dyn Runtime>)> = Vec::new();
+        for provider in registry.providers() {
+            for runtime in provider.runtimes() {
+                if !runtimes.iter().any(|(_, r)| r.name() == runtime.name()) {
+                    runtimes.push((provider.name().to_string(), runtime));
+                }
+            }
+        }
+        runtimes.sort_by(|(_, a), (_, b)| a.name().cmp(b.name()));
+
+        let offline_capable = |name: &str| {
+            runtimes
+                .iter()
+                .find(|(_, r)| r.name() == name)
+                .is_some_and(|(_, r)| {
+                    r.install_kinds()
+                        .iter()
+                        .any(InstallKind::is_offline_capable)
+                })
+        };
+
+        let capabilities = runtimes
+            .iter()
+            .map(|(provider, runtime)| {
+                let install_strategies = runtime.install_kinds();
+                let offline_install = install_strategies.iter().any(|kind| match kind {
+                    InstallKind::ProvidedBy { runtime } => offline_capable(runtime),
+                    kind => kind.is_offline_capable(),
+                });
+
+                let mut executables = vec![runtime.executable_name().to_string()];
+                for (_, bundled) in &runtimes {
+                    if bundled.metadata().get("bundled_with").map(String::as_str)
+                        == Some(runtime.name())
+                        && !executables.iter().any(|e| e == bundled.executable_name())
+                    {
+                        executables.push(bundled.executable_name().to_string());
+                    }
+                }
+
+                let mut platforms: Vec<String> = runtime
+                    .supported_platforms()
+                    .iter()
+                    .map(Platform::as_str)
+                    .collect();
+                platforms.dedup();
+
+                RuntimeCapabilities {
+                    name: runtime.name().to_string(),
+                    provider: provider.clone(),
+                    description: runtime.description().to_string(),
+                    ecosystem: runtime.ecosystem().to_string(),
+                    aliases: runtime.aliases().iter().map(|a| a.to_string()).collect(),
+                    executables,
+                    platforms,
+                    install_strategies,
+                    offline_install,
+                }
+            })
+            .collect();
+
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            platform: Platform::current().as_str(),
+            runtimes: capabilities,
+        }
+    }
+}
+
+fn install_kind_label(kind: &InstallKind) -> String {
+    match kind {
+        InstallKind::Download => "download".to_string(),
+        InstallKind::Pip { package } => format!("pip:{}", package),
+        InstallKind::PackageManager { manager } => manager.clone(),
+        InstallKind::SystemManaged { manager } => format!("{} (managed)", manager),
+        InstallKind::Script => "script".to_string(),
+        InstallKind::ProvidedBy { runtime } => format!("with {}", runtime),
+    }
+}
+
+impl CommandOutput for BuildCapabilities {
+    fn render_text(&self, writer: &mut dyn std::io::Write) -> Result<()> {
+        writeln!(
+            writer,
+            "vx {} ({}): {} runtimes",
+            self.version,
+            self.platform,
+            self.runtimes.len()
+        )?;
+        for rt in &self.runtimes {
+            writeln!(writer)?;
+            writeln!(
+                writer,
+                "{} [{}] - {}",
+                rt.name, rt.ecosystem, rt.description
+            )?;
+            if !rt.aliases.is_empty() {
+                writeln!(writer, "  aliases:     {}", rt.aliases.join(", "))?;
+            }
+            writeln!(writer, "  executables: {}", rt.executables.join(", "))?;
+            writeln!(writer, "  platforms:   {}", rt.platforms.join(", "))?;
+            let strategies: Vec<String> = rt
+                .install_strategies
+                .iter()
+                .map(install_kind_label)
+                .collect();
+            writeln!(
+                writer,
+                "  install:     {}{}",
+                if strategies.is_empty() {
+                    "system only".to_string()
+                } else {
+                    strategies.join(", ")
+                },
+                if rt.offline_install { " (offline)" } else { "" }
+            )?;
+        }
+        Ok(())
+    }
+
+    fn render_compact(&self, writer: &mut dyn std::io::Write) -> Result<()> {
+        for rt in &self.runtimes {
+            let strategies: Vec<String> = rt
+                .install_strategies
+                .iter()
+                .map(install_kind_label)
+                .collect();
+            writeln!(
+                writer,
+                "{} {} [{}]{}",
+                rt.name,
+                rt.ecosystem,
+                strategies.join(","),
+                if rt.offline_install { " offline" } else { "" }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Handle `vx capabilities`
+///
+/// An explicit `--format` is used as given; otherwise the global output
+/// format applies.
+pub async fn handle_build(
+    registry: &ProviderRegistry,
+    format: Option<OutputFormat>,
+    global_format: OutputFormat,
+) -> Result<()> {
+    let output = BuildCapabilities::collect(registry);
+    match format {
+        Some(format) => OutputRenderer::new_exact(format).render(&output),
+        None => OutputRenderer::new(global_format).render(&output),
+    }
+}
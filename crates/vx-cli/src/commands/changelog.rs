@@ -0,0 +1,311 @@
+//! Changelog command implementation
+//!
+//! `vx changelog` renders the release notes between two versions of a tool,
+//! so reviewing a version bump doesn't require leaving the terminal.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! # Notes for the latest release
+//! vx changelog node
+//!
+//! # Everything after 20.11.0 up to and including 20.12.0
+//! vx changelog node 20.11.0..20.12.0
+//!
+//! # Everything newer than the pinned version
+//! vx changelog node 20.11.0..
+//! ```
+//!
+//! Notes come from the GitHub releases of the provider's `repository`. When a
+//! release has no notes there, the provider's `changelog` URL template is
+//! printed instead.
+
+use crate::registry::find_release_sources;
+use crate::ui::UI;
+use anyhow::Result;
+use std::collections::HashMap;
+use vx_resolver::Version;
+use vx_runtime::{ProviderRegistry, RuntimeContext, VersionInfo};
+use vx_version_fetcher::{GitHubReleaseInfo, GitHubReleasesFetcher};
+
+/// Release pages (100 releases each) searched before giving up
+const MAX_RELEASE_PAGES: usize = 10;
+
+/// Which releases to show
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangelogRange {
+    /// The newest stable release
+    Latest,
+    /// A single release
+    Exact(String),
+    /// Releases after `from` (exclusive) up to `to` (inclusive)
+    Between {
+        from: Option<String>,
+        to: Option<String>,
+    },
+}
+
+impl ChangelogRange {
+    /// Parse `FROM..TO`, `FROM..`, `..TO` or a single version.
+    pub fn parse(spec: Option<&str>) -> Result<Self> {
+        let Some(spec) = spec.map(str::trim).filter(|s| !s.is_empty()) else {
+            return Ok(Self::Latest);
+        };
+
+        let Some((from, to)) = spec.split_once("..") else {
+            return Ok(Self::Exact(spec.to_string()));
+        };
+        let endpoint = |s: &str| -> Result<Option<String>> {
+            let s = s.trim();
+            if s.is_empty() {
+                return Ok(None);
+            }
+            if Version::parse(s).is_none() {
+                anyhow::bail!("Invalid version '{}' in range '{}'", s, spec);
+            }
+            Ok(Some(s.to_string()))
+        };
+        let (from, to) = (endpoint(from)?, endpoint(to)?);
+        if from.is_none() && to.is_none() {
+            anyhow::bail!("Range '{}' needs at least one version", spec);
+        }
+        if let (Some(f), Some(t)) = (&from, &to)
+            && Version::parse(f) > Version::parse(t)
+        {
+            anyhow::bail!("Range '{}' is reversed, did you mean {}..{}?", spec, t, f);
+        }
+        Ok(Self::Between { from, to })
+    }
+}
+
+/// Release notes for a single version
+#[derive(Debug, Clone, Default)]
+pub struct ChangelogEntry {
+    pub version: String,
+    /// Release date (`YYYY-MM-DD`)
+    pub date: Option<String>,
+    pub url: Option<String>,
+    pub body: Option<String>,
+}
+
+/// Pick the versions a range covers, newest first, at most `limit` of them.
+///
+/// Prereleases are only included when named explicitly.
+pub fn select_versions(
+    range: &ChangelogRange,
+    versions: &[VersionInfo],
+    limit: usize,
+) -> Vec<String> {
+    let mut candidates: Vec<(Version, &VersionInfo)> = versions
+        .iter()
+        .filter_map(|v| Version::parse(&v.version).map(|parsed| (parsed, v)))
+        .collect();
+    candidates.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+    match range {
+        ChangelogRange::Latest => candidates
+            .iter()
+            .find(|(_, v)| !v.prerelease)
+            .map(|(_, v)| vec![v.version.clone()])
+            .unwrap_or_default(),
+        ChangelogRange::Exact(version) => versions
+            .iter()
+            .find(|v| v.version == *version || v.version == version.trim_start_matches('v'))
+            .map(|v| vec![v.version.clone()])
+            .unwrap_or_default(),
+        ChangelogRange::Between { from, to } => {
+            let from = from.as_deref().and_then(Version::parse);
+            let to_raw = to.as_deref();
+            let to = to_raw.and_then(Version::parse);
+            candidates
+                .iter()
+                .filter(|(parsed, v)| {
+                    from.as_ref().is_none_or(|f| parsed > f)
+                        && to.as_ref().is_none_or(|t| parsed <= t)
+                        && (!v.prerelease || Some(v.version.as_str()) == to_raw)
+                })
+                .take(limit)
+                .map(|(_, v)| v.version.clone())
+                .collect()
+        }
+    }
+}
+
+/// Extract `owner/repo` from a GitHub repository URL.
+pub fn github_repo(url: &str) -> Option<(String, String)> {
+    let path = url
+        .strip_prefix("https://github.com/")
+        .or_else(|| url.strip_prefix("http://github.com/"))
+        .or_else(|| url.strip_prefix("git@github.com:"))?;
+    let mut parts = path.trim_end_matches('/').splitn(3, '/');
+    let owner = parts.next().filter(|s| !s.is_empty())?;
+    let repo = parts.next()?.trim_end_matches(".git");
+    if repo.is_empty() {
+        return None;
+    }
+    Some((owner.to_string(), repo.to_string()))
+}
+
+/// Whether a release tag names `version` (`v1.2.0`, `bun-v1.2.0`, `jq-1.7`, ...).
+pub fn tag_matches(tag: &str, version: &str) -> bool {
+    tag == version
+        || tag
+            .find(|c: char| c.is_ascii_digit())
+            .is_some_and(|i| &tag[i..] == version)
+}
+
+/// Fill a changelog URL template for a version.
+///
+/// Supports `{version}`, `{major}` and `{minor}` placeholders.
+pub fn render_changelog_url(template: &str, version: &str) -> String {
+    let mut parts = version.split('.');
+    let major = parts.next().unwrap_or(version);
+    let minor = parts.next().unwrap_or("0");
+    template
+        .replace("{version}", version)
+        .replace("{major}", major)
+        .replace("{minor}", minor)
+}
+
+/// Render entries as Markdown, newest first.
+pub fn render_changelog(tool: &str, entries: &[ChangelogEntry]) -> String {
+    let mut out = String::new();
+    for entry in entries {
+        out.push_str(&format!("## {} {}", tool, entry.version));
+        if let Some(ref date) = entry.date {
+            out.push_str(&format!(" ({})", date));
+        }
+        out.push('\n');
+
+        match (&entry.body, &entry.url) {
+            (Some(body), url) => {
+                if let Some(url) = url {
+                    out.push_str(&format!("{}\n", url));
+                }
+                out.push('\n');
+                out.push_str(body.replace("\r\n", "\n").trim());
+                out.push('\n');
+            }
+            (None, Some(url)) => out.push_str(&format!("\nRelease notes: {}\n", url)),
+            (None, None) => out.push_str("\nNo release notes found.\n"),
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Handle `vx changelog <tool> [range]`
+pub async fn handle(
+    registry: &ProviderRegistry,
+    ctx: &RuntimeContext,
+    tool: &str,
+    range: Option<&str>,
+    limit: usize,
+) -> Result<()> {
+    let spec = range.unwrap_or("latest");
+    let range = ChangelogRange::parse(range)?;
+
+    let Some(runtime) = registry.get_runtime(tool) else {
+        anyhow::bail!("Unknown tool: {}", tool);
+    };
+    let versions = runtime.fetch_versions(ctx).await?;
+    let selected = select_versions(&range, &versions, limit);
+    if selected.is_empty() {
+        anyhow::bail!("No {} releases match '{}'", tool, spec);
+    }
+
+    let sources = find_release_sources(tool)
+        .or_else(|| find_release_sources(runtime.name()))
+        .unwrap_or_default();
+
+    let releases = match sources.repository.as_deref().and_then(github_repo) {
+        Some((owner, repo)) => fetch_matching_releases(&owner, &repo, &selected, ctx).await,
+        None => HashMap::new(),
+    };
+
+    let entries: Vec<ChangelogEntry> = selected
+        .iter()
+        .map(|version| {
+            let info = versions.iter().find(|v| v.version == *version);
+            let release = releases.get(version);
+            let date = release
+                .and_then(|r| r.published_at.as_deref())
+                .or_else(|| info.and_then(|i| i.metadata.get("release_date").map(String::as_str)))
+                .map(|d| d.chars().take(10).collect())
+                .or_else(|| {
+                    info.and_then(|i| i.released_at)
+                        .map(|dt| dt.format("%Y-%m-%d").to_string())
+                });
+            let changelog_url = sources
+                .changelog
+                .as_deref()
+                .map(|template| render_changelog_url(template, version));
+            let url = release
+                .and_then(|r| r.html_url.clone())
+                .or(changelog_url)
+                .or_else(|| info.and_then(|i| i.release_url()).map(str::to_string));
+
+            ChangelogEntry {
+                version: version.clone(),
+                date,
+                url,
+                body: release.and_then(|r| r.body.clone()),
+            }
+        })
+        .collect();
+
+    print!("{}", render_changelog(tool, &entries));
+    if selected.len() == limit && matches!(range, ChangelogRange::Between { .. }) {
+        UI::hint(&format!(
+            "Showing the newest {} releases, use --limit to see more",
+            limit
+        ));
+    }
+    Ok(())
+}
+
+/// Page through GitHub releases until every wanted version is found.
+async fn fetch_matching_releases(
+    owner: &str,
+    repo: &str,
+    wanted: &[String],
+    ctx: &RuntimeContext,
+) -> HashMap<String, GitHubReleaseInfo> {
+    let oldest = wanted.iter().filter_map(|v| Version::parse(v)).min();
+    let mut found = HashMap::new();
+
+    for page in 1..=MAX_RELEASE_PAGES {
+        let releases = match GitHubReleasesFetcher::fetch_releases(owner, repo, page, ctx).await {
+            Ok(releases) => releases,
+            Err(e) => {
+                UI::warn(&format!("Failed to fetch GitHub releases: {}", e));
+                break;
+            }
+        };
+        if releases.is_empty() {
+            break;
+        }
+
+        let mut reaches_range = false;
+        for release in releases {
+            let tag_version = release
+                .tag_name
+                .find(|c: char| c.is_ascii_digit())
+                .and_then(|i| Version::parse(&release.tag_name[i..]));
+            if tag_version.is_none_or(|tag| oldest.as_ref().is_none_or(|o| tag >= *o)) {
+                reaches_range = true;
+            }
+            if let Some(version) = wanted.iter().find(|v| tag_matches(&release.tag_name, v)) {
+                found.insert(version.clone(), release);
+            }
+        }
+
+        // Releases are listed newest first, so once a whole page is older
+        // than the range nothing further can match
+        if found.len() == wanted.len() || !reaches_range {
+            break;
+        }
+    }
+
+    found
+}
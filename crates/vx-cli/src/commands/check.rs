@@ -10,6 +10,9 @@
 //! - Detect version conflicts between tools
 //! - Check if tools are installed
 //! - Validate against provider version ranges
+//! - Validate machine requirements from `[requirements]`: OS/arch, free
+//!   disk space, system packages, network reachability of registries
+//!   and mirrors, and a CUDA-capable GPU
 //!
 //! ## Usage
 //!
@@ -28,21 +31,34 @@
 //!
 //! # Quiet mode (exit code only)
 //! vx check --quiet
+//!
+//! # Skip network reachability checks
+//! vx check --no-network
 //! ```
 
 use crate::cli::OutputFormat;
-use crate::commands::common::{ToolStatus, check_tools_status};
-use crate::commands::setup::{find_vx_config, parse_vx_config};
-use crate::output::{CheckOutput, OutputRenderer, RequirementStatus, RequirementStatusType};
+use crate::commands::common::{ToolStatus, check_tools_status, format_size};
+use crate::commands::setup::{ConfigView, find_vx_config, parse_vx_config_full};
+use crate::output::{
+    CheckOutput, OutputRenderer, RequirementStatus, RequirementStatusType, SystemCheck,
+    SystemCheckKind,
+};
 use crate::ui::UI;
 use anyhow::{Context, Result};
 use std::collections::HashMap;
 use std::env;
+use std::path::Path;
+use std::time::Duration;
+use vx_config::{RequirementsConfig, VxConfig};
 use vx_paths::project::LOCK_FILE_NAME;
 use vx_resolver::{
     ConflictDetector, LockFile, Version, VersionRangeConfig, VersionRangeResolver, VersionRequest,
 };
-use vx_runtime::ProviderRegistry;
+use vx_runtime::user_mirrors::UserMirrors;
+use vx_runtime::{GpuInfo, ProviderRegistry};
+
+/// Timeout for each network reachability probe
+const NETWORK_TIMEOUT: Duration = Duration::from_secs(10);
 
 /// Handle the check command
 pub async fn handle(
@@ -50,6 +66,7 @@ pub async fn handle(
     tool: Option<String>,
     detailed: bool,
     quiet: bool,
+    no_network: bool,
     format: OutputFormat,
 ) -> Result<()> {
     let current_dir = env::current_dir().context("Failed to get current directory")?;
@@ -65,6 +82,7 @@ pub async fn handle(
                 missing_tools: vec![],
                 warnings: vec!["No vx.toml found".to_string()],
                 errors: vec!["No vx.toml found in current directory or parents".to_string()],
+                system: vec![],
             };
 
             if !quiet {
@@ -80,9 +98,15 @@ pub async fn handle(
         }
     };
 
-    let config = parse_vx_config(&config_path)?;
+    let full_config = parse_vx_config_full(&config_path)?;
+    // Machine requirements apply to the whole project, not a single tool
+    let machine_requirements = full_config
+        .requirements
+        .clone()
+        .filter(|r| !r.is_empty() && tool.is_none());
+    let config = ConfigView::from(full_config);
 
-    if config.tools.is_empty() {
+    if config.tools.is_empty() && machine_requirements.is_none() {
         let output = CheckOutput {
             project_file: Some(config_path.display().to_string()),
             requirements: vec![],
@@ -90,6 +114,7 @@ pub async fn handle(
             missing_tools: vec![],
             warnings: vec!["No tools configured in vx.toml".to_string()],
             errors: vec![],
+            system: vec![],
         };
 
         if !quiet {
@@ -239,6 +264,27 @@ pub async fn handle(
         }
     }
 
+    // Check machine requirements
+    let system = match machine_requirements {
+        Some(ref req) => {
+            let mut tools: Vec<&String> = tools_to_check.keys().collect();
+            tools.sort();
+            let mut urls = req.network.clone();
+            urls.extend(user_mirror_urls(&tools));
+            check_machine_requirements(req, project_root, &urls, !no_network).await
+        }
+        None => vec![],
+    };
+    for check in system.iter().filter(|c| !c.passed) {
+        all_ok = false;
+        errors.push(format!(
+            "{} requirement not met: {} ({})",
+            check.kind.label(),
+            check.name,
+            check.detail
+        ));
+    }
+
     // Build output
     let output = CheckOutput {
         project_file: Some(config_path.display().to_string()),
@@ -247,6 +293,7 @@ pub async fn handle(
         missing_tools,
         warnings,
         errors,
+        system,
     };
 
     // Render output
@@ -305,10 +352,31 @@ fn render_text_output(output: &CheckOutput, detailed: bool) -> Result<()> {
         );
     }
 
+    if !output.system.is_empty() {
+        if !output.requirements.is_empty() {
+            println!();
+        }
+        println!("Machine requirements:");
+        for check in &output.system {
+            let icon = if check.passed { "✓" } else { "✗" };
+            println!(
+                "{} {} {} ({})",
+                icon,
+                check.kind.label(),
+                check.name,
+                check.detail
+            );
+        }
+    }
+
     println!();
 
     if output.all_satisfied && output.warnings.is_empty() {
-        UI::success("✓ All version constraints satisfied");
+        if output.system.is_empty() {
+            UI::success("✓ All version constraints satisfied");
+        } else {
+            UI::success("✓ All requirements satisfied, this machine is ready");
+        }
     } else if output.all_satisfied {
         UI::warn(&format!(
             "⚠ All constraints satisfied with {} warning(s)",
@@ -359,3 +427,185 @@ fn get_provider_version_range_config(
 ) -> VersionRangeConfig {
     VersionRangeConfig::default()
 }
+
+/// Run the `[requirements]` checks against this machine
+async fn check_machine_requirements(
+    req: &RequirementsConfig,
+    project_root: &Path,
+    urls: &[String],
+    network: bool,
+) -> Vec<SystemCheck> {
+    let mut checks = check_platform(req, VxConfig::current_os_name(), env::consts::ARCH);
+
+    if let Some(check) = check_disk_space(req, available_disk_space(project_root)) {
+        checks.push(check);
+    }
+
+    if let Some(check) = check_gpu(req, GpuInfo::current()) {
+        checks.push(check);
+    }
+
+    for package in &req.system_packages {
+        let (passed, detail) = match which::which(package) {
+            Ok(path) => (true, path.display().to_string()),
+            Err(_) => (false, "not found on PATH".to_string()),
+        };
+        checks.push(SystemCheck {
+            kind: SystemCheckKind::SystemPackage,
+            name: package.clone(),
+            passed,
+            detail,
+        });
+    }
+
+    if network {
+        let client = vx_proxy::client_builder()
+            .timeout(NETWORK_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        let mut seen = std::collections::HashSet::new();
+        for url in urls {
+            let Some(origin) = url_origin(url) else {
+                continue;
+            };
+            if !seen.insert(origin.clone()) {
+                continue;
+            }
+            // Any HTTP response proves the host is reachable
+            let (passed, detail) = match client.head(&origin).send().await {
+                Ok(response) => (true, format!("HTTP {}", response.status().as_u16())),
+                Err(e) if e.is_timeout() => (false, "timed out".to_string()),
+                Err(e) => (false, e.to_string()),
+            };
+            checks.push(SystemCheck {
+                kind: SystemCheckKind::Network,
+                name: origin,
+                passed,
+                detail,
+            });
+        }
+    }
+
+    checks
+}
+
+/// Check OS and architecture constraints
+pub fn check_platform(req: &RequirementsConfig, os: &str, arch: &str) -> Vec<SystemCheck> {
+    let mut checks = Vec::new();
+    if !req.os.is_empty() {
+        checks.push(SystemCheck {
+            kind: SystemCheckKind::Os,
+            name: os.to_string(),
+            passed: req.os_supported(os),
+            detail: format!("allowed: {}", req.os.join(", ")),
+        });
+    }
+    if !req.arch.is_empty() {
+        checks.push(SystemCheck {
+            kind: SystemCheckKind::Arch,
+            name: arch.to_string(),
+            passed: req.arch_supported(arch),
+            detail: format!("allowed: {}", req.arch.join(", ")),
+        });
+    }
+    checks
+}
+
+/// Check free disk space against `min_disk_space`
+pub fn check_disk_space(req: &RequirementsConfig, available: Option<u64>) -> Option<SystemCheck> {
+    let required = req.min_disk_space.clone()?;
+    let (passed, detail) = match (req.min_disk_space_bytes(), available) {
+        (Err(e), _) => (false, e),
+        (Ok(_), None) => (false, "could not determine free space".to_string()),
+        (Ok(min), Some(free)) => (
+            min.is_none_or(|min| free >= min),
+            format!("{} free", format_size(free)),
+        ),
+    };
+    Some(SystemCheck {
+        kind: SystemCheckKind::DiskSpace,
+        name: format!(">= {}", required),
+        passed,
+        detail,
+    })
+}
+
+/// Check `requires_gpu` against the detected GPU
+pub fn check_gpu(req: &RequirementsConfig, gpu: &GpuInfo) -> Option<SystemCheck> {
+    req.requires_gpu.then(|| SystemCheck {
+        kind: SystemCheckKind::Gpu,
+        name: "CUDA GPU".to_string(),
+        passed: gpu.is_available(),
+        detail: gpu.summary(),
+    })
+}
+
+/// `scheme://host[:port]` of a URL or URL template
+pub fn url_origin(url: &str) -> Option<String> {
+    let (scheme, rest) = url.split_once("://")?;
+    if !matches!(scheme, "http" | "https") {
+        return None;
+    }
+    let host = rest.split(['/', '?', '#']).next()?;
+    if host.is_empty() || host.contains('{') {
+        return None;
+    }
+    Some(format!("{}://{}", scheme, host))
+}
+
+/// Mirrors from `~/.vx/config/mirrors.toml` used by the project's tools
+fn user_mirror_urls(tools: &[&String]) -> Vec<String> {
+    let mirrors = UserMirrors::load();
+    let region = vx_runtime::region::detect_region();
+    tools
+        .iter()
+        .flat_map(|tool| mirrors.for_tool(tool, region.as_str()))
+        .map(|m| m.url.clone())
+        .collect()
+}
+
+/// Free space available to the current user on the filesystem holding `path`
+#[cfg(unix)]
+fn available_disk_space(path: &Path) -> Option<u64> {
+    use std::os::unix::ffi::OsStrExt;
+
+    let c_path = std::ffi::CString::new(path.as_os_str().as_bytes()).ok()?;
+    // Safety: `statvfs` is plain old data, so an all-zero value is valid.
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    // Safety: `c_path` is NUL-terminated and `stat` is a valid out pointer.
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) } != 0 {
+        return None;
+    }
+    // Field widths differ between platforms
+    #[allow(clippy::unnecessary_cast)]
+    Some(stat.f_bavail as u64 * stat.f_frsize as u64)
+}
+
+/// Free space available to the current user on the volume holding `path`
+#[cfg(windows)]
+fn available_disk_space(path: &Path) -> Option<u64> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows_sys::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+    let wide: Vec<u16> = path
+        .as_os_str()
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect();
+    let mut free: u64 = 0;
+    // Safety: `wide` is NUL-terminated and `free` is a valid out pointer.
+    let ok = unsafe {
+        GetDiskFreeSpaceExW(
+            wide.as_ptr(),
+            &mut free,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+    };
+    (ok != 0).then_some(free)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn available_disk_space(_path: &Path) -> Option<u64> {
+    None
+}
@@ -0,0 +1,380 @@
+//! CI command implementation
+//!
+//! `vx ci generate` writes CI configuration that installs the vx release
+//! the project was set up with, caches `~/.vx`, installs the tools locked in
+//! `vx.lock`, verifies them with `vx sync --check` and runs the project's
+//! scripts through `vx run`. CI then builds with the same pinned toolchain as
+//! developers.
+//!
+//! | Provider | Files |
+//! |----------|-------|
+//! | GitHub Actions | `.github/workflows/vx.yml`, `.github/actions/vx-setup/action.yml` |
+//! | GitLab CI | `.gitlab-ci.yml` |
+//! | CircleCI | `.circleci/config.yml` |
+//!
+//! The GitHub workflow uses a composite action wrapping the official
+//! `loonghao/vx` action pinned to the generating vx release, so other
+//! workflows of the repository can share it with `uses: ./.github/actions/vx-setup`.
+
+use crate::cli::CiProvider;
+use crate::commands::common;
+use crate::commands::wrap::INSTALL_SH_URL;
+use crate::ui::UI;
+use anyhow::{Context, Result, bail};
+use std::path::{Path, PathBuf};
+use vx_paths::project::LOCK_FILE_NAMES;
+
+/// Marker identifying files written by `vx ci generate`, which may be overwritten
+pub const CI_MARKER: &str = "Generated by `vx ci generate`";
+
+/// Scripts run by default, in this order, when the project defines them
+pub const DEFAULT_SCRIPTS: &[&str] = &["lint", "check", "test", "build"];
+
+/// Path of the composite setup action, relative to the project root
+pub const SETUP_ACTION_DIR: &str = ".github/actions/vx-setup";
+
+/// Project facts the CI configuration depends on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CiProject {
+    /// File name of the project configuration (e.g. `vx.toml`)
+    pub config_file: String,
+    /// File name of the lock file, if the project has one
+    pub lock_file: Option<String>,
+    /// Scripts to run, in order
+    pub scripts: Vec<String>,
+}
+
+impl CiProject {
+    /// Files whose content determines the cached toolchain
+    fn cache_files(&self) -> Vec<&str> {
+        std::iter::once(self.config_file.as_str())
+            .chain(self.lock_file.as_deref())
+            .collect()
+    }
+}
+
+/// A CI configuration file to write
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CiFile {
+    /// Path relative to the project root
+    pub path: PathBuf,
+    pub content: String,
+}
+
+/// The files of `provider` for `project`
+///
+/// `vx_version` is the vx release CI installs.
+pub fn ci_files(provider: CiProvider, project: &CiProject, vx_version: &str) -> Vec<CiFile> {
+    match provider {
+        CiProvider::Github => vec![
+            CiFile {
+                path: PathBuf::from(".github/workflows/vx.yml"),
+                content: render_github_workflow(project, vx_version),
+            },
+            CiFile {
+                path: Path::new(SETUP_ACTION_DIR).join("action.yml"),
+                content: render_github_action(vx_version),
+            },
+        ],
+        CiProvider::Gitlab => vec![CiFile {
+            path: PathBuf::from(".gitlab-ci.yml"),
+            content: render_gitlab(project, vx_version),
+        }],
+        CiProvider::Circleci => vec![CiFile {
+            path: PathBuf::from(".circleci/config.yml"),
+            content: render_circleci(project, vx_version),
+        }],
+    }
+}
+
+/// The scripts to run: `requested`, or the [`DEFAULT_SCRIPTS`] the project defines
+///
+/// Requested scripts must exist in `defined`.
+pub fn select_scripts(requested: &[String], defined: &[String]) -> Result<Vec<String>> {
+    if requested.is_empty() {
+        return Ok(DEFAULT_SCRIPTS
+            .iter()
+            .filter(|name| defined.iter().any(|d| d == *name))
+            .map(|name| name.to_string())
+            .collect());
+    }
+    for name in requested {
+        if !defined.contains(name) {
+            bail!("Script '{}' is not defined in vx.toml", name);
+        }
+        if !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':' | '.'))
+        {
+            bail!(
+                "Script name '{}' can't be used in CI configuration; use letters, digits, '-', '_', ':' and '.'",
+                name
+            );
+        }
+    }
+    Ok(requested.to_vec())
+}
+
+fn header(vx_version: &str, provider: &str) -> String {
+    format!(
+        "# {marker} (vx {version}). Regenerate with: vx ci generate --provider {provider}\n",
+        marker = CI_MARKER,
+        version = vx_version,
+        provider = provider,
+    )
+}
+
+/// Render the GitHub Actions workflow
+pub fn render_github_workflow(project: &CiProject, vx_version: &str) -> String {
+    let mut out = header(vx_version, "github");
+    out.push_str(&format!(
+        r#"name: vx
+
+on:
+  push:
+    branches: [main]
+  pull_request:
+
+jobs:
+  ci:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v6
+
+      # Installs vx {version}, restores ~/.vx and installs the locked tools
+      - uses: ./{action}
+
+      - name: Check toolchain
+        run: vx sync --check
+"#,
+        version = vx_version,
+        action = SETUP_ACTION_DIR,
+    ));
+    for script in &project.scripts {
+        out.push_str(&format!(
+            "\n      - name: {script}\n        run: vx run {script}\n",
+            script = script
+        ));
+    }
+    out
+}
+
+/// Render the composite action installing the pinned vx and the project's tools
+pub fn render_github_action(vx_version: &str) -> String {
+    let mut out = header(vx_version, "github");
+    out.push_str(&format!(
+        r#"name: "Setup vx toolchain"
+description: "Install vx {version}, restore the ~/.vx cache and install the tools pinned in vx.toml and vx.lock"
+
+inputs:
+  github-token:
+    description: "GitHub token for API requests (avoids rate limiting)"
+    required: false
+    default: ${{{{ github.token }}}}
+
+runs:
+  using: "composite"
+  steps:
+    - uses: loonghao/vx@vx-v{version}
+      with:
+        version: "{version}"
+        github-token: ${{{{ inputs.github-token }}}}
+        cache: "true"
+        setup: "true"
+"#,
+        version = vx_version,
+    ));
+    out
+}
+
+/// Render the GitLab CI configuration
+///
+/// GitLab only caches paths inside the project directory, so `VX_HOME`
+/// points there.
+pub fn render_gitlab(project: &CiProject, vx_version: &str) -> String {
+    let mut out = header(vx_version, "gitlab");
+    out.push_str(&format!(
+        r#"vx:
+  image: buildpack-deps:bookworm-curl
+  variables:
+    VX_VERSION: "{version}"
+    VX_HOME: "$CI_PROJECT_DIR/.vx"
+    VX_INSTALL_DIR: "$CI_PROJECT_DIR/.vx-bin"
+  cache:
+    key:
+      prefix: vx-{version}
+      files:
+{files}    paths:
+      - .vx/
+      - .vx-bin/
+  before_script:
+    - export PATH="$VX_INSTALL_DIR:$VX_HOME/bin:$PATH"
+    - command -v vx >/dev/null || curl -fsSL {install_sh} | bash
+    - vx sync
+    - vx sync --check
+  script:
+"#,
+        version = vx_version,
+        files = project
+            .cache_files()
+            .iter()
+            .map(|file| format!("        - {}\n", file))
+            .collect::<String>(),
+        install_sh = INSTALL_SH_URL,
+    ));
+    if project.scripts.is_empty() {
+        out.push_str("    - vx list --status\n");
+    }
+    for script in &project.scripts {
+        out.push_str(&format!("    - vx run {}\n", script));
+    }
+    out
+}
+
+/// Render the CircleCI configuration
+pub fn render_circleci(project: &CiProject, vx_version: &str) -> String {
+    let cache_key = std::iter::once(format!("vx-{}", vx_version))
+        .chain(
+            project
+                .cache_files()
+                .iter()
+                .map(|file| format!("{{{{ checksum \"{}\" }}}}", file)),
+        )
+        .collect::<Vec<_>>()
+        .join("-");
+    let mut out = header(vx_version, "circleci");
+    out.push_str(&format!(
+        r#"version: 2.1
+
+jobs:
+  vx:
+    docker:
+      - image: cimg/base:current
+    environment:
+      VX_VERSION: "{version}"
+    steps:
+      - checkout
+      - restore_cache:
+          keys:
+            - {cache_key}
+            - vx-{version}-
+      - run:
+          name: Install vx
+          command: |
+            echo 'export PATH="$HOME/.local/bin:$HOME/.vx/bin:$PATH"' >> "$BASH_ENV"
+            command -v "$HOME/.local/bin/vx" >/dev/null || curl -fsSL {install_sh} | bash
+      - run:
+          name: Install tools
+          command: vx sync
+      - run:
+          name: Check toolchain
+          command: vx sync --check
+      - save_cache:
+          key: {cache_key}
+          paths:
+            - ~/.vx
+            - ~/.local/bin/vx
+"#,
+        version = vx_version,
+        cache_key = cache_key,
+        install_sh = INSTALL_SH_URL,
+    ));
+    for script in &project.scripts {
+        out.push_str(&format!(
+            "      - run:\n          name: {script}\n          command: vx run {script}\n",
+            script = script
+        ));
+    }
+    out.push_str("\nworkflows:\n  vx:\n    jobs:\n      - vx\n");
+    out
+}
+
+/// Write `files` below `root`
+///
+/// Existing files are only replaced when they were generated by
+/// `vx ci generate`, or with `force`. Returns the written paths.
+pub fn write_ci_files(root: &Path, files: &[CiFile], force: bool) -> Result<Vec<PathBuf>> {
+    for file in files {
+        let path = root.join(&file.path);
+        if !force && path.exists() && !is_generated(&path) {
+            bail!(
+                "{} exists and was not generated by `vx ci generate` (use --force to overwrite)",
+                path.display()
+            );
+        }
+    }
+
+    let mut written = Vec::new();
+    for file in files {
+        let path = root.join(&file.path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        std::fs::write(&path, &file.content)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+fn is_generated(path: &Path) -> bool {
+    std::fs::read_to_string(path).is_ok_and(|content| content.contains(CI_MARKER))
+}
+
+/// Handle `vx ci generate`
+pub async fn handle_generate(
+    provider: CiProvider,
+    scripts: &[String],
+    dry_run: bool,
+    force: bool,
+) -> Result<()> {
+    let cwd = std::env::current_dir().context("Failed to get current directory")?;
+    let (config_path, config) = common::load_config_view_cwd()?;
+    let root = config_path.parent().unwrap_or(&cwd).to_path_buf();
+
+    let mut defined: Vec<String> = config.scripts.keys().cloned().collect();
+    defined.sort();
+    let project = CiProject {
+        config_file: config_path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "vx.toml".to_string()),
+        lock_file: LOCK_FILE_NAMES
+            .iter()
+            .find(|name| root.join(name).is_file())
+            .map(|name| name.to_string()),
+        scripts: select_scripts(scripts, &defined)?,
+    };
+    if project.lock_file.is_none() {
+        UI::warn_stderr(
+            "The project has no vx.lock; CI resolves tool versions itself (run `vx lock` first)",
+        );
+    }
+    if project.scripts.is_empty() {
+        UI::warn_stderr(&format!(
+            "No scripts to run: define {} in vx.toml or pass --script",
+            DEFAULT_SCRIPTS.join(", ")
+        ));
+    }
+
+    let files = ci_files(provider, &project, env!("CARGO_PKG_VERSION"));
+    if dry_run {
+        for file in &files {
+            println!("# {}", file.path.display());
+            print!("{}", file.content);
+            println!();
+        }
+        return Ok(());
+    }
+
+    let written = write_ci_files(&root, &files, force)?;
+    for path in &written {
+        UI::detail(&path.display().to_string());
+    }
+    UI::success(&format!(
+        "Wrote {} CI file(s); commit them so CI uses the toolchain pinned in vx.lock",
+        written.len()
+    ));
+    Ok(())
+}
@@ -14,8 +14,15 @@ use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
-use vx_config::{VxConfig, parse_config};
+use std::sync::{Arc, OnceLock};
+use vx_config::{
+    HookCommand, InheritanceManager, PolicyConfig, VxConfig, parse_config, tool_placeholders,
+};
+use vx_paths::project::LOCK_FILE_NAME;
 use vx_paths::{PathManager, find_vx_config as find_vx_config_path};
+use vx_resolver::LockFile;
+
+use super::lock::get_version_string;
 
 // =============================================================================
 // Configuration Loading
@@ -39,9 +46,178 @@ pub fn find_project_config_cwd() -> Result<PathBuf> {
 ///
 /// This is the recommended way to load configuration for new code.
 /// For backward compatibility, see load_config_view().
+///
+/// The `extends` chain is merged in and the machine policy applied. Remote
+/// presets that are not cached yet are skipped with a warning; see
+/// [`fetch_remote_presets`].
 pub fn load_full_config(path: &Path) -> Result<VxConfig> {
-    parse_config(path)
-        .with_context(|| format!("Failed to parse configuration file: {}", path.display()))
+    let config = parse_config(path)
+        .with_context(|| format!("Failed to parse configuration file: {}", path.display()))?;
+    let chain = preset_manager()
+        .resolve_extends(path, config)
+        .with_context(|| format!("Failed to resolve extends of {}", path.display()))?;
+    for source in &chain.missing {
+        tracing::warn!(
+            "Remote preset {} is not cached yet, run 'vx setup' to fetch it",
+            source.url
+        );
+    }
+    Ok(apply_machine_policy(chain.merged()))
+}
+
+/// Inheritance manager caching remote presets in `~/.vx/cache/presets`
+pub fn preset_manager() -> InheritanceManager {
+    InheritanceManager::new(vx_paths::VxPaths::default().cache_dir.join("presets"))
+}
+
+/// Download the remote presets in the `extends` chain of `path` into the cache
+pub async fn fetch_remote_presets(path: &Path) -> Result<()> {
+    let manager = preset_manager();
+    let mut client = None;
+
+    // Each pass fetches one more level of the chain
+    loop {
+        let config = parse_config(path)
+            .with_context(|| format!("Failed to parse configuration file: {}", path.display()))?;
+        let chain = manager.resolve_extends(path, config)?;
+        if chain.missing.is_empty() {
+            return Ok(());
+        }
+        if client.is_none() {
+            client = Some(
+                vx_proxy::client_builder()
+                    .user_agent(format!("vx/{}", env!("CARGO_PKG_VERSION")))
+                    .build()?,
+            );
+        }
+        let http = client.as_ref().expect("client initialized above");
+        for source in &chain.missing {
+            tracing::info!("Fetching preset {}", source.url);
+            let content = http
+                .get(&source.url)
+                .send()
+                .await
+                .and_then(|r| r.error_for_status())
+                .with_context(|| format!("Failed to fetch preset {}", source.url))?
+                .text()
+                .await?;
+            manager.store_preset(source, &content)?;
+        }
+    }
+}
+
+/// Fail before installing anything when the project sets
+/// `[requirements] requires_gpu` and no CUDA-capable GPU is usable
+pub fn ensure_gpu_requirement(config: &VxConfig) -> Result<()> {
+    let required = config.requirements.as_ref().is_some_and(|r| r.requires_gpu);
+    let gpu = vx_runtime::GpuInfo::current();
+    if !required || gpu.is_available() {
+        return Ok(());
+    }
+    anyhow::bail!(
+        "This project requires a CUDA-capable NVIDIA GPU ([requirements] requires_gpu), \
+         but none is usable here ({}).\n\
+         Install the NVIDIA driver so that `nvidia-smi` works, or set {}=<cuda version> \
+         to override detection",
+        gpu.summary(),
+        vx_runtime::GpuInfo::ENV_VAR
+    )
+}
+
+/// Machine-wide policy ([`vx_paths::policy_file`]), loaded once per process
+///
+/// An unreadable or invalid policy is reported and ignored.
+pub fn machine_policy() -> Option<&'static PolicyConfig> {
+    static POLICY: OnceLock<Option<PolicyConfig>> = OnceLock::new();
+    POLICY
+        .get_or_init(|| {
+            let path = vx_paths::policy_file();
+            PolicyConfig::load(&path).unwrap_or_else(|e| {
+                tracing::warn!("Ignoring machine policy {}: {}", path.display(), e);
+                None
+            })
+        })
+        .as_ref()
+}
+
+/// Merge the machine policy into a loaded project configuration
+pub fn apply_machine_policy(config: VxConfig) -> VxConfig {
+    match machine_policy() {
+        Some(policy) => InheritanceManager::apply_policy(&config, policy),
+        None => config,
+    }
+}
+
+/// Check an install against the machine policy
+///
+/// Fails when the tool is not allowlisted, the version does not satisfy the
+/// pinned constraint, or the version is a prerelease and prereleases are
+/// forbidden.
+pub fn check_install_policy(tool: &str, version: &str) -> Result<()> {
+    let Some(policy) = machine_policy() else {
+        return Ok(());
+    };
+    let source = policy
+        .path
+        .as_deref()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|| "machine policy".to_string());
+
+    if !policy.allows_provider(tool) {
+        anyhow::bail!("{} is not in the allowed tools of {}", tool, source);
+    }
+    if let Some(pin) = policy.pin(tool)
+        && pin != version
+        && !vx_versions::VersionRequest::parse(pin).satisfies(version)
+    {
+        anyhow::bail!(
+            "{} {} does not satisfy the version pinned by {} ({})",
+            tool,
+            version,
+            source,
+            pin
+        );
+    }
+    if !policy.allows_prerelease()
+        && vx_versions::Version::parse(version).is_some_and(|v| v.is_prerelease())
+    {
+        anyhow::bail!(
+            "{} {} is a prerelease, which {} does not allow",
+            tool,
+            version,
+            source
+        );
+    }
+    Ok(())
+}
+
+/// Vulnerability-aware resolution from the project's `[security.resolution]`
+///
+/// Returns `None` outside a project or when neither vulnerable versions are
+/// skipped nor floors are set.
+pub fn project_security_policy(
+    http: Arc<dyn vx_runtime::HttpClient>,
+) -> Option<Arc<vx_runtime::SecurityPolicy>> {
+    use vx_runtime::{OsvClient, SecurityPolicy, Severity};
+
+    let (_, config) = load_full_config_cwd().ok()?;
+    let resolution = config.security?.resolution?;
+    let skip_severity = if resolution.skips_vulnerable() {
+        Some(Severity::parse(resolution.severity()).unwrap_or(Severity::Critical))
+    } else {
+        None
+    };
+    if skip_severity.is_none() && resolution.floor.is_empty() {
+        return None;
+    }
+    Some(Arc::new(SecurityPolicy {
+        skip_severity,
+        floors: resolution.floor,
+        advisories: Arc::new(OsvClient::new(
+            http,
+            Arc::new(crate::registry::find_osv_package),
+        )),
+    }))
 }
 
 /// Find and load VxConfig from current directory
@@ -53,6 +229,67 @@ pub fn load_full_config_cwd() -> Result<(PathBuf, VxConfig)> {
     Ok((path, config))
 }
 
+/// Versions the project in the current directory pins its tools to
+///
+/// A tool's `vx.lock` version takes precedence over its vx.toml version.
+/// Returns nothing outside a project.
+pub fn project_tool_versions() -> Vec<(String, String)> {
+    let Ok((config_path, config)) = load_full_config_cwd() else {
+        return Vec::new();
+    };
+    let lock_path = config_path.with_file_name(LOCK_FILE_NAME);
+    let lock = lock_path
+        .exists()
+        .then(|| LockFile::load(&lock_path).ok())
+        .flatten();
+
+    config
+        .tools
+        .iter()
+        .map(|(name, version)| {
+            let version = lock
+                .as_ref()
+                .and_then(|l| l.get_tool(name))
+                .map(|t| t.version.clone())
+                .unwrap_or_else(|| get_version_string(version));
+            (name.clone(), version)
+        })
+        .collect()
+}
+
+/// Executables for the `{tool:<name>}` placeholders used in `commands`
+///
+/// Only the referenced tools are resolved, so commands without placeholders
+/// do not touch the provider registry.
+pub fn resolve_placeholder_tools(
+    config: &VxConfig,
+    commands: &[&str],
+) -> Result<HashMap<String, PathBuf>> {
+    let mut names: Vec<String> = Vec::new();
+    for name in commands.iter().flat_map(|cmd| tool_placeholders(cmd)) {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    if names.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let view = crate::commands::setup::ConfigView::from(config.clone());
+    crate::commands::dev::resolve_tool_executables(&view, &names)
+}
+
+/// Executables for the `{tool:<name>}` placeholders of a hook
+pub fn resolve_hook_tools(
+    config: &VxConfig,
+    hook: &HookCommand,
+) -> Result<HashMap<String, PathBuf>> {
+    let commands: Vec<&str> = match hook {
+        HookCommand::Single(cmd) => vec![cmd.as_str()],
+        HookCommand::Multiple(cmds) => cmds.iter().map(String::as_str).collect(),
+    };
+    resolve_placeholder_tools(config, &commands)
+}
+
 /// Find and parse VxConfig, then convert to ConfigView (backward-compatible)
 ///
 /// This provides backward compatibility with code that uses ConfigView.
@@ -111,6 +111,8 @@ pub async fn handle(format: OutputFormat) -> Result<()> {
         UI::info(&format!("Tools: {}", config.tools.len()));
         UI::info(&format!("Scripts: {}", config.scripts.len()));
         UI::info(&format!("Services: {}", config.services.len()));
+        println!();
+        UI::hint("Run 'vx config show --origin' to see where each value comes from");
     } else {
         renderer.render(&output)?;
     }
@@ -320,3 +322,393 @@ fn generate_template_config(template: &str, additional_tools: &[String]) -> Resu
 
     Ok(config)
 }
+
+#[derive(Serialize)]
+struct ConfigOriginOutput {
+    layers: Vec<String>,
+    settings: Vec<OriginEntry>,
+}
+
+#[derive(Serialize)]
+struct OriginEntry {
+    key: String,
+    value: String,
+    origin: String,
+    locked: bool,
+    overridden: Vec<OverriddenEntry>,
+}
+
+#[derive(Serialize)]
+struct OverriddenEntry {
+    origin: String,
+    value: String,
+}
+
+impl From<vx_config::ResolvedSetting> for OriginEntry {
+    fn from(setting: vx_config::ResolvedSetting) -> Self {
+        Self {
+            key: setting.key,
+            value: setting.value,
+            origin: setting.origin.to_string(),
+            locked: setting.locked,
+            overridden: setting
+                .overridden
+                .into_iter()
+                .map(|(origin, value)| OverriddenEntry {
+                    origin: origin.to_string(),
+                    value,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl CommandOutput for ConfigOriginOutput {
+    fn render_text(&self, writer: &mut dyn std::io::Write) -> Result<()> {
+        for entry in &self.settings {
+            let lock = if entry.locked { " [locked]" } else { "" };
+            writeln!(
+                writer,
+                "{} = {}  # {}{}",
+                entry.key, entry.value, entry.origin, lock
+            )?;
+            for overridden in &entry.overridden {
+                writeln!(
+                    writer,
+                    "    overrides {} from {}",
+                    overridden.value, overridden.origin
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn render_compact(&self, writer: &mut dyn std::io::Write) -> Result<()> {
+        for entry in &self.settings {
+            let lock = if entry.locked { " locked" } else { "" };
+            writeln!(
+                writer,
+                "{}={} {}{}",
+                entry.key, entry.value, entry.origin, lock
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Standard environment variables read below user configuration
+const STANDARD_ENV_KEYS: &[(&str, &str)] = &[
+    ("HTTP_PROXY", "proxy.http"),
+    ("HTTPS_PROXY", "proxy.https"),
+    ("ALL_PROXY", "proxy.all"),
+    ("NO_PROXY", "proxy.no_proxy"),
+];
+
+/// `VX_*` environment variables, which override every configuration file
+const VX_ENV_KEYS: &[(&str, &str)] = &[
+    ("VX_HOME", "paths.home"),
+    ("VX_POLICY_FILE", "paths.policy_file"),
+    ("VX_PROVIDERS_PATH", "providers.path"),
+    ("VX_HTTP_PROXY", "proxy.http"),
+    ("VX_HTTPS_PROXY", "proxy.https"),
+    ("VX_ALL_PROXY", "proxy.all"),
+    ("VX_NO_PROXY", "proxy.no_proxy"),
+    ("VX_PROXY_USERNAME", "proxy.username"),
+    ("VX_PROXY_PASSWORD", "proxy.password"),
+    ("VX_IP_FAMILY", "network.ip_family"),
+    ("VX_CONNECT_TIMEOUT", "network.connect_timeout"),
+    ("VX_PEER_CACHE", "peers.enabled"),
+    ("VX_REMOTE_CACHE", "remote_cache.url"),
+    ("VX_MIRROR_REGION", "mirrors.region"),
+    ("VX_CDN", "mirrors.cdn"),
+    ("VX_TEMPLATE_INDEX", "templates.index"),
+    ("VX_OUTPUT", "output.format"),
+    ("VX_ARCH_FALLBACK", "settings.arch_fallback"),
+    ("VX_LIBC", "settings.libc"),
+];
+
+/// Shown instead of secret values
+const MASKED: &str = "********";
+
+/// Handle `vx config show --origin`
+///
+/// Resolves every configuration layer in precedence order and reports where
+/// each effective value comes from, like `git config --show-origin`.
+pub async fn handle_origin(format: OutputFormat) -> Result<()> {
+    use vx_config::InheritanceManager;
+
+    let renderer = OutputRenderer::new(format);
+    let layers = origin_layers()?;
+
+    let output = ConfigOriginOutput {
+        layers: layers.iter().map(|l| l.origin.to_string()).collect(),
+        settings: InheritanceManager::resolve_origins(&layers)
+            .into_iter()
+            .map(OriginEntry::from)
+            .collect(),
+    };
+
+    if renderer.is_text() {
+        UI::header("📋 Configuration Origins");
+        println!();
+        if output.layers.is_empty() {
+            UI::warning("No policy, user or project configuration found");
+            return Ok(());
+        }
+        UI::info("Precedence (lowest first):");
+        for layer in &output.layers {
+            UI::item(layer);
+        }
+        println!();
+        output.render_text(&mut std::io::stdout())?;
+    } else {
+        renderer.render(&output)?;
+    }
+
+    Ok(())
+}
+
+/// Configuration layers, lowest precedence first:
+/// policy, standard env vars, user config, version files (`.nvmrc`,
+/// `.tool-versions`, ...), presets, project, `VX_*` env vars
+fn origin_layers() -> Result<Vec<vx_config::ConfigLayer>> {
+    use vx_config::{ConfigLayer, ConfigOrigin, PolicyConfig, parse_config};
+
+    let env_layers = |table: &[(&str, &str)], lowercase: bool| {
+        table
+            .iter()
+            .filter_map(|(var, key)| {
+                let value = env::var(var)
+                    .ok()
+                    .or_else(|| {
+                        lowercase
+                            .then(|| env::var(var.to_lowercase()).ok())
+                            .flatten()
+                    })
+                    .filter(|v| !v.trim().is_empty())?;
+                let value = if key.ends_with("password") {
+                    MASKED.to_string()
+                } else {
+                    value
+                };
+                Some(ConfigLayer::from_env(var, key, value))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut layers = Vec::new();
+
+    let policy_path = vx_paths::policy_file();
+    if let Some(policy) = PolicyConfig::load(&policy_path)? {
+        layers.push(ConfigLayer::from_policy(policy_path, &policy));
+    }
+
+    layers.extend(env_layers(STANDARD_ENV_KEYS, true));
+
+    if let Ok(paths) = vx_paths::VxPaths::new() {
+        let mirrors_path = paths.mirrors_config();
+        if mirrors_path.exists() {
+            let mirrors = vx_runtime::user_mirrors::UserMirrors::from_file(&mirrors_path)?;
+            let mut layer = ConfigLayer::new(ConfigOrigin::User(mirrors_path));
+            for (tool, list) in &mirrors.mirrors {
+                let urls: Vec<&str> = list.iter().map(|m| m.url.as_str()).collect();
+                layer
+                    .values
+                    .insert(format!("mirrors.{}", tool), urls.join(", "));
+            }
+            layers.push(layer);
+        }
+
+        let proxy_path = paths.config_dir.join(vx_proxy::PROXY_CONFIG_FILE);
+        if proxy_path.exists() {
+            let proxy = vx_proxy::ProxyConfig::from_file(&proxy_path)?;
+            let mut layer = ConfigLayer::new(ConfigOrigin::User(proxy_path));
+            let entries = [
+                ("proxy.http", proxy.http),
+                ("proxy.https", proxy.https),
+                ("proxy.all", proxy.all),
+                ("proxy.username", proxy.username),
+                ("proxy.password", proxy.password.map(|_| MASKED.to_string())),
+                (
+                    "proxy.no_proxy",
+                    (!proxy.no_proxy.is_empty()).then(|| proxy.no_proxy.join(", ")),
+                ),
+            ];
+            for (key, value) in entries {
+                if let Some(value) = value {
+                    layer.values.insert(key.to_string(), value);
+                }
+            }
+            layers.push(layer);
+        }
+
+        let network_path = paths.config_dir.join(vx_proxy::NETWORK_CONFIG_FILE);
+        if network_path.exists() {
+            let network = vx_proxy::NetworkConfig::from_file(&network_path)?;
+            let mut layer = ConfigLayer::new(ConfigOrigin::User(network_path));
+            if network.ip_family != vx_proxy::IpFamily::Auto {
+                layer.values.insert(
+                    "network.ip_family".to_string(),
+                    network.ip_family.to_string(),
+                );
+            }
+            if let Some(secs) = network.connect_timeout {
+                layer
+                    .values
+                    .insert("network.connect_timeout".to_string(), secs.to_string());
+            }
+            for (host, ip) in &network.hosts {
+                layer
+                    .values
+                    .insert(format!("network.hosts.{}", host), ip.to_string());
+            }
+            layers.push(layer);
+        }
+
+        let peers_path = paths.config_dir.join(vx_proxy::PEERS_CONFIG_FILE);
+        if peers_path.exists() {
+            let peers = vx_proxy::PeerConfig::from_file(&peers_path)?;
+            let mut layer = ConfigLayer::new(ConfigOrigin::User(peers_path));
+            layer
+                .values
+                .insert("peers.enabled".to_string(), peers.enabled.to_string());
+            if !peers.peers.is_empty() {
+                layer
+                    .values
+                    .insert("peers.peers".to_string(), peers.peers.join(", "));
+            }
+            layers.push(layer);
+        }
+
+        let remote_path = paths.config_dir.join(vx_proxy::REMOTE_CACHE_CONFIG_FILE);
+        if remote_path.exists() {
+            let remote = vx_proxy::RemoteCacheConfig::from_file(&remote_path)?;
+            let mut layer = ConfigLayer::new(ConfigOrigin::User(remote_path));
+            let entries = [
+                ("remote_cache.url", remote.url),
+                ("remote_cache.region", remote.region),
+                ("remote_cache.endpoint", remote.endpoint),
+                ("remote_cache.upload", Some(remote.upload.to_string())),
+                (
+                    "remote_cache.secret_access_key",
+                    remote.secret_access_key.map(|_| MASKED.to_string()),
+                ),
+            ];
+            for (key, value) in entries {
+                if let Some(value) = value {
+                    layer.values.insert(key.to_string(), value);
+                }
+            }
+            layers.push(layer);
+        }
+
+        let templates_path = paths.templates_config();
+        if templates_path.exists() {
+            let content = std::fs::read_to_string(&templates_path)?;
+            let templates: crate::template_registry::TemplatesConfig = toml::from_str(&content)?;
+            let mut layer = ConfigLayer::new(ConfigOrigin::User(templates_path));
+            if let Some(index) = templates.registry.index {
+                layer.values.insert("templates.index".to_string(), index);
+            }
+            layers.push(layer);
+        }
+
+        if let Some(setup) = super::global_setup::GlobalSetup::load(&paths) {
+            let mut layer = ConfigLayer::new(ConfigOrigin::User(
+                super::global_setup::GlobalSetup::path(&paths),
+            ));
+            if let Some(region) = setup.mirror_region {
+                layer.values.insert("mirrors.region".to_string(), region);
+            }
+            if let Some(telemetry) = setup.telemetry {
+                layer
+                    .values
+                    .insert("telemetry.enabled".to_string(), telemetry.to_string());
+            }
+            layers.push(layer);
+        }
+    }
+
+    // .nvmrc, .tool-versions, ... only apply where vx.toml doesn't pin a tool
+    let cwd = env::current_dir()?;
+    layers.extend(ConfigLayer::from_version_files(
+        &vx_config::find_version_file_pins(&cwd),
+    ));
+
+    if let Some(config_path) = find_config_file(&cwd) {
+        let config = parse_config(&config_path)?;
+        let chain = super::common::preset_manager().resolve_extends(&config_path, config)?;
+        for source in &chain.missing {
+            UI::warn(&format!(
+                "Remote preset {} is not cached yet, run 'vx setup' to fetch it",
+                source.url
+            ));
+        }
+        layers.extend(chain.config_layers());
+    }
+
+    // Output preferences belong to the user, so ui.toml overrides vx.toml
+    if let Ok(paths) = vx_paths::VxPaths::new() {
+        let ui_path = paths.config_dir.join(vx_config::UI_CONFIG_FILE);
+        let ui = vx_config::UiConfig::from_user_file(&ui_path)?;
+        let mut layer = ConfigLayer::new(ConfigOrigin::User(ui_path));
+        layer.values.extend(ui.entries());
+        layers.push(layer);
+    }
+
+    layers.extend(env_layers(VX_ENV_KEYS, false));
+
+    // Skip empty layers (e.g. a templates.toml without an index)
+    layers.retain(|layer| !layer.values.is_empty());
+    Ok(layers)
+}
+
+/// Handle `vx config explain <key>`
+///
+/// Shows which layer sets a value and every value it overrides. A section
+/// name (`tools`, `settings`, ...) explains every key in it.
+pub async fn handle_explain(key: &str, format: OutputFormat) -> Result<()> {
+    use vx_config::InheritanceManager;
+
+    if let Some(config_path) = find_config_file(&env::current_dir()?) {
+        super::common::fetch_remote_presets(&config_path).await?;
+    }
+
+    let renderer = OutputRenderer::new(format);
+    let layers = origin_layers()?;
+    let resolved = InheritanceManager::resolve_origins(&layers);
+    let prefix = format!("{}.", key);
+    let matches: Vec<_> = resolved
+        .into_iter()
+        .filter(|r| r.key == key || r.key.starts_with(&prefix))
+        .collect();
+
+    if matches.is_empty() {
+        anyhow::bail!(
+            "'{}' is not set by any layer (run 'vx config show --origin' to list all values)",
+            key
+        );
+    }
+
+    if renderer.is_text() {
+        for setting in &matches {
+            UI::header(&format!("{} = {}", setting.key, setting.value));
+            let lock = if setting.locked {
+                " (locked by policy)"
+            } else {
+                ""
+            };
+            UI::info(&format!("Set by {}{}", setting.origin, lock));
+            for (origin, value) in setting.overridden.iter().rev() {
+                UI::detail(&format!("overrides {} from {}", value, origin));
+            }
+        }
+        return Ok(());
+    }
+
+    let output = ConfigOriginOutput {
+        layers: layers.iter().map(|l| l.origin.to_string()).collect(),
+        settings: matches.into_iter().map(OriginEntry::from).collect(),
+    };
+    renderer.render(&output)
+}
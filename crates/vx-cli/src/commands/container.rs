@@ -2,17 +2,27 @@
 //!
 //! Handles container-related commands:
 //! - Dockerfile generation
+//! - Devcontainer generation with a feature that installs vx
 //! - Image building
 //! - Registry push
 //! - Container status
+//!
+//! `vx container generate --devcontainer` writes `.devcontainer/` with a
+//! local `vx` feature: the feature installs vx (and the project's tools) into
+//! the image, `postCreateCommand` runs `vx setup` in the workspace.
+//! `--publish-feature <DIR>` writes the same feature as a standalone
+//! collection, ready for `devcontainer features publish`.
 
+use crate::commands::wrap::INSTALL_SH_URL;
 use crate::ui::UI;
+use anyhow::Context;
+use serde_json::json;
 use std::env;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use vx_config::{
     ContainerManager, DockerfileGenerator, GitInfo, GoDockerConfig, NodejsDockerConfig,
-    PythonDockerConfig, RustDockerConfig, parse_config,
+    PythonDockerConfig, RemoteGenerator, RustDockerConfig, VX_FEATURE_ID, VxConfig, parse_config,
 };
 use vx_paths::find_config_file;
 
@@ -112,6 +122,165 @@ pub async fn handle_generate(
     Ok(())
 }
 
+/// Files of a devcontainer using the local vx feature, relative to the
+/// `.devcontainer` directory
+///
+/// The feature installs `vx_version`, so the container gets the vx that
+/// generated it.
+pub fn devcontainer_files(config: &VxConfig, vx_version: &str) -> Vec<(PathBuf, String)> {
+    let devcontainer = config
+        .remote
+        .as_ref()
+        .and_then(|r| r.devcontainer.clone())
+        .unwrap_or_default();
+    let content = RemoteGenerator::generate_vx_devcontainer(
+        config,
+        &devcontainer,
+        &format!("./{}", VX_FEATURE_ID),
+        json!({ "version": vx_version }),
+    );
+
+    let mut files = vec![(PathBuf::from("devcontainer.json"), to_json(&content))];
+    files.extend(
+        feature_files(config, vx_version)
+            .into_iter()
+            .map(|(path, content)| (Path::new(VX_FEATURE_ID).join(path), content)),
+    );
+    files
+}
+
+/// Files of the vx feature, relative to the feature directory
+pub fn feature_files(config: &VxConfig, vx_version: &str) -> Vec<(PathBuf, String)> {
+    vec![
+        (
+            PathBuf::from("devcontainer-feature.json"),
+            to_json(&RemoteGenerator::generate_vx_feature(config, vx_version)),
+        ),
+        (
+            PathBuf::from("install.sh"),
+            render_feature_install_script(vx_version),
+        ),
+    ]
+}
+
+/// Render the `install.sh` of the vx feature
+///
+/// Feature options arrive as upper-case environment variables. The tools are
+/// installed as the container's remote user, so they land in that user's
+/// vx store rather than root's.
+pub fn render_feature_install_script(vx_version: &str) -> String {
+    format!(
+        r#"#!/bin/sh
+# Generated by `vx container generate --devcontainer` (vx {version})
+#
+# Devcontainer feature: installs vx and the tools of the `tools` option.
+set -e
+
+VERSION="${{VERSION:-latest}}"
+TOOLS="${{TOOLS:-}}"
+
+if command -v curl >/dev/null 2>&1; then
+    fetch="curl -fsSL"
+else
+    fetch="wget -qO-"
+fi
+echo "Installing vx $VERSION to /usr/local/bin"
+$fetch {install_sh} \
+    | VX_VERSION="$VERSION" VX_INSTALL_DIR=/usr/local/bin bash
+
+if [ -n "$TOOLS" ]; then
+    REMOTE_USER="${{_REMOTE_USER:-root}}"
+    echo "Installing $TOOLS for $REMOTE_USER"
+    if [ "$REMOTE_USER" = root ]; then
+        vx install $TOOLS
+    else
+        su "$REMOTE_USER" -c "vx install $TOOLS"
+    fi
+fi
+"#,
+        version = vx_version,
+        install_sh = INSTALL_SH_URL,
+    )
+}
+
+fn to_json(value: &serde_json::Value) -> String {
+    let mut json = serde_json::to_string_pretty(value).unwrap_or_default();
+    json.push('\n');
+    json
+}
+
+/// Handle `vx container generate --devcontainer` command
+pub async fn handle_generate_devcontainer(
+    output: Option<String>,
+    dry_run: bool,
+    publish_feature: Option<&Path>,
+) -> anyhow::Result<()> {
+    let current_dir = env::current_dir()?;
+    let config_path = find_config_file(&current_dir)
+        .ok_or_else(|| anyhow::anyhow!("No vx.toml found. Run 'vx init' first."))?;
+    let config = parse_config(&config_path)?;
+    let vx_version = env!("CARGO_PKG_VERSION");
+
+    let devcontainer_dir = output.map(PathBuf::from).unwrap_or_else(|| {
+        config_path
+            .parent()
+            .unwrap_or(&current_dir)
+            .join(".devcontainer")
+    });
+    let mut outputs = vec![(devcontainer_dir, devcontainer_files(&config, vx_version))];
+    if let Some(dir) = publish_feature {
+        outputs.push((dir.join(VX_FEATURE_ID), feature_files(&config, vx_version)));
+    }
+
+    if dry_run {
+        UI::info("Preview (dry-run mode):");
+        for (dir, files) in &outputs {
+            for (path, content) in files {
+                println!("# {}", dir.join(path).display());
+                println!("{}", content);
+            }
+        }
+        return Ok(());
+    }
+
+    for (dir, files) in &outputs {
+        for (path, content) in files {
+            write_file(&dir.join(path), content)?;
+        }
+    }
+    UI::success(&format!("Generated {}", outputs[0].0.display()));
+    UI::hint("Open the folder in a dev container; it runs 'vx setup' after creation");
+
+    if let Some(dir) = publish_feature {
+        UI::success(&format!(
+            "Generated the {} feature in {}",
+            VX_FEATURE_ID,
+            dir.join(VX_FEATURE_ID).display()
+        ));
+        UI::hint(&format!(
+            "Publish it with 'devcontainer features publish --namespace <owner>/<repo> {}'",
+            dir.display()
+        ));
+    }
+    Ok(())
+}
+
+/// Write `content` to `path`, creating parent directories; shell scripts
+/// are made executable
+fn write_file(path: &Path, content: &str) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    std::fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+    #[cfg(unix)]
+    if path.extension().is_some_and(|ext| ext == "sh") {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))?;
+    }
+    Ok(())
+}
+
 /// Generate default .dockerignore
 fn generate_default_dockerignore(project_root: &Path) -> anyhow::Result<()> {
     let content = r#"# Generated by vx
@@ -0,0 +1,343 @@
+//! Terminal driver and data loading for the dashboard
+
+use super::state::{Action, Dashboard, DiskEntry, InstalledVersion, KeyInput, ServiceEntry};
+use crate::commands::CommandContext;
+use crate::commands::add::{self, AddOptions};
+use crate::commands::common::{
+    calculate_directory_size, load_full_config_cwd, project_tool_versions,
+};
+use crate::commands::services::{ContainerRuntime, get_project_name, get_service_status};
+use crate::commands::update::{ToolUpdate, plan_project_updates};
+use crate::commands::{install, remove};
+use crate::ui::UI;
+use anyhow::{Context, Result};
+use dialoguer::console::{Key, Term};
+use std::collections::BTreeMap;
+use std::io::Write;
+use vx_paths::project::LOCK_FILE_NAME;
+use vx_paths::{PathManager, VxPaths};
+use vx_resolver::{LockFile, Version};
+
+/// Switch to the terminal's alternate screen, like other full-screen programs
+const ENTER_ALT_SCREEN: &str = "\x1b[?1049h";
+const LEAVE_ALT_SCREEN: &str = "\x1b[?1049l";
+
+/// Handle `vx ui`
+pub async fn handle(ctx: &CommandContext) -> Result<()> {
+    let term = Term::stdout();
+    if !term.is_term() {
+        return Err(anyhow::anyhow!(
+            "vx ui needs an interactive terminal; use 'vx list', 'vx outdated' or \
+             'vx services status' instead"
+        ));
+    }
+
+    let installed = load_installed();
+    let mut dashboard = Dashboard::new(installed.clone(), load_disk(&installed), load_services());
+    let mut screen = Screen::enter(&term)?;
+
+    loop {
+        screen.draw(&dashboard)?;
+        let Some(key) = map_key(term.read_key()?) else {
+            continue;
+        };
+        let Some(action) = dashboard.handle_key(key) else {
+            continue;
+        };
+
+        match action {
+            Action::Quit => break,
+            Action::Refresh => {
+                reload(&mut dashboard);
+                dashboard.status = Some("Refreshed".to_string());
+            }
+            Action::CheckUpdates => {
+                dashboard.status = Some("Checking for updates…".to_string());
+                screen.draw(&dashboard)?;
+                dashboard.updates = Some(check_updates(ctx).await.map_err(|e| e.to_string()));
+                dashboard.status = None;
+            }
+            action => {
+                screen.leave()?;
+                if let Err(e) = run_action(ctx, &action).await {
+                    UI::error(&format!("{:#}", e));
+                }
+                println!();
+                println!("Press any key to return to the dashboard");
+                let _ = term.read_key();
+                screen.resume()?;
+
+                reload(&mut dashboard);
+                if dashboard.updates.is_some() {
+                    dashboard.updates = Some(check_updates(ctx).await.map_err(|e| e.to_string()));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Run an install, uninstall or switch outside the dashboard
+async fn run_action(ctx: &CommandContext, action: &Action) -> Result<()> {
+    match action {
+        Action::Install(spec) => {
+            install::handle_install(
+                ctx.registry(),
+                ctx.runtime_context(),
+                std::slice::from_ref(spec),
+                false,
+            )
+            .await
+        }
+        Action::PromptInstall { default } => {
+            let spec: String = dialoguer::Input::new()
+                .with_prompt("Install (tool[@version], empty to cancel)")
+                .with_initial_text(default)
+                .allow_empty(true)
+                .interact_text()?;
+            let spec = spec.trim();
+            if spec.is_empty() {
+                return Ok(());
+            }
+            install::handle_install(
+                ctx.registry(),
+                ctx.runtime_context(),
+                &[spec.to_string()],
+                false,
+            )
+            .await
+        }
+        Action::Uninstall { tool, version } => {
+            let confirmed = dialoguer::Confirm::new()
+                .with_prompt(format!("Uninstall {} {}?", tool, version))
+                .default(false)
+                .interact()?;
+            if !confirmed {
+                return Ok(());
+            }
+            remove::handle(
+                ctx.registry(),
+                ctx.runtime_context(),
+                tool,
+                Some(version),
+                true,
+            )
+            .await
+        }
+        Action::Switch { tool, version } => {
+            let options = AddOptions {
+                no_install: true,
+                force: true,
+                ..Default::default()
+            };
+            add::handle(ctx.registry(), &[format!("{}@{}", tool, version)], options).await
+        }
+        Action::Quit | Action::Refresh | Action::CheckUpdates => Ok(()),
+    }
+}
+
+fn reload(dashboard: &mut Dashboard) {
+    dashboard.installed = load_installed();
+    dashboard.disk = load_disk(&dashboard.installed);
+    dashboard.services = load_services();
+}
+
+/// Installed versions from the store, newest first per tool
+fn load_installed() -> Vec<InstalledVersion> {
+    let Ok(path_manager) = PathManager::new() else {
+        return Vec::new();
+    };
+    let pinned = project_tool_versions();
+    let mut runtimes = path_manager.list_store_runtimes().unwrap_or_default();
+    runtimes.sort();
+
+    let mut installed = Vec::new();
+    for tool in runtimes {
+        let normalizer = crate::registry::find_version_normalizer(&tool);
+        let mut versions = path_manager.list_store_versions(&tool).unwrap_or_default();
+        versions.sort_by(|a, b| match (Version::parse(a), Version::parse(b)) {
+            (Some(a), Some(b)) => b.cmp(&a),
+            _ => b.cmp(a),
+        });
+        for version in versions {
+            let size = calculate_directory_size(&path_manager.version_store_dir(&tool, &version))
+                .unwrap_or(0);
+            let pinned = pinned
+                .iter()
+                .any(|(t, v)| *t == tool && normalizer.same_version(v, &version));
+            installed.push(InstalledVersion {
+                tool: tool.clone(),
+                version,
+                size,
+                pinned,
+            });
+        }
+    }
+    installed
+}
+
+/// Totals for the vx home, then each tool's share of the store, largest first
+fn load_disk(installed: &[InstalledVersion]) -> Vec<DiskEntry> {
+    let Ok(paths) = VxPaths::new() else {
+        return Vec::new();
+    };
+    let mut entries: Vec<DiskEntry> = [
+        ("Tool store", &paths.store_dir),
+        ("Download cache", &paths.cache_dir),
+        ("Environments", &paths.envs_dir),
+        ("Global packages", &paths.packages_dir),
+        ("Temporary files", &paths.tmp_dir),
+    ]
+    .into_iter()
+    .map(|(label, dir)| DiskEntry {
+        label: label.to_string(),
+        size: calculate_directory_size(dir).unwrap_or(0),
+    })
+    .collect();
+
+    let mut per_tool: BTreeMap<&str, u64> = BTreeMap::new();
+    for version in installed {
+        *per_tool.entry(&version.tool).or_default() += version.size;
+    }
+    let mut tools: Vec<DiskEntry> = per_tool
+        .into_iter()
+        .map(|(tool, size)| DiskEntry {
+            label: format!("  {}", tool),
+            size,
+        })
+        .collect();
+    tools.sort_by_key(|t| std::cmp::Reverse(t.size));
+    entries.extend(tools);
+    entries
+}
+
+fn load_services() -> Result<Vec<ServiceEntry>, String> {
+    let (config_path, config) =
+        load_full_config_cwd().map_err(|_| "No vx.toml found in this directory".to_string())?;
+    if config.services.is_empty() {
+        return Ok(Vec::new());
+    }
+    let runtime = ContainerRuntime::detect()
+        .ok_or_else(|| "No container runtime found. Please install Podman.".to_string())?;
+    let project_name = get_project_name(&config_path);
+
+    let mut services = Vec::new();
+    for (name, service) in &config.services {
+        let status =
+            get_service_status(&runtime, &project_name, name).map_err(|e| e.to_string())?;
+        services.push(ServiceEntry {
+            name: name.clone(),
+            image: service
+                .image
+                .clone()
+                .unwrap_or_else(|| "(command)".to_string()),
+            running: status.running,
+            health: status.health,
+        });
+    }
+    Ok(services)
+}
+
+/// Updates for every tool in the project's vx.toml, by tool name
+async fn check_updates(ctx: &CommandContext) -> Result<Vec<ToolUpdate>> {
+    let (config_path, config) = load_full_config_cwd()
+        .map_err(|_| anyhow::anyhow!("No vx.toml found in this directory"))?;
+    let lock_path = config_path.with_file_name(LOCK_FILE_NAME);
+    let lock = if lock_path.exists() {
+        Some(
+            LockFile::load(&lock_path)
+                .with_context(|| format!("Failed to load lock file: {}", lock_path.display()))?,
+        )
+    } else {
+        None
+    };
+
+    let mut updates = plan_project_updates(
+        ctx.registry(),
+        ctx.runtime_context(),
+        &config,
+        lock.as_ref(),
+        |_| true,
+    )
+    .await?;
+    updates.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(updates)
+}
+
+fn map_key(key: Key) -> Option<KeyInput> {
+    Some(match key {
+        Key::ArrowUp => KeyInput::Up,
+        Key::ArrowDown => KeyInput::Down,
+        Key::ArrowLeft => KeyInput::Left,
+        Key::ArrowRight => KeyInput::Right,
+        Key::Home => KeyInput::Home,
+        Key::End => KeyInput::End,
+        Key::PageUp => KeyInput::PageUp,
+        Key::PageDown => KeyInput::PageDown,
+        Key::Tab => KeyInput::Tab,
+        Key::BackTab => KeyInput::BackTab,
+        Key::Enter => KeyInput::Enter,
+        Key::Escape | Key::CtrlC => KeyInput::Esc,
+        Key::Char(c) => KeyInput::Char(c),
+        _ => return None,
+    })
+}
+
+/// The alternate screen; restores the terminal when dropped
+struct Screen<'a> {
+    term: &'a Term,
+    active: bool,
+}
+
+impl<'a> Screen<'a> {
+    fn enter(term: &'a Term) -> Result<Self> {
+        let mut screen = Self {
+            term,
+            active: false,
+        };
+        screen.resume()?;
+        Ok(screen)
+    }
+
+    fn resume(&mut self) -> Result<()> {
+        let mut term = self.term;
+        term.write_all(ENTER_ALT_SCREEN.as_bytes())?;
+        term.hide_cursor()?;
+        self.active = true;
+        Ok(())
+    }
+
+    fn leave(&mut self) -> Result<()> {
+        let mut term = self.term;
+        term.show_cursor()?;
+        term.write_all(LEAVE_ALT_SCREEN.as_bytes())?;
+        term.flush()?;
+        self.active = false;
+        Ok(())
+    }
+
+    fn draw(&self, dashboard: &Dashboard) -> Result<()> {
+        let (rows, cols) = self.term.size();
+        let lines = dashboard.render(cols as usize, rows as usize);
+        self.term.move_cursor_to(0, 0)?;
+        for (i, line) in lines.iter().enumerate() {
+            self.term.clear_line()?;
+            if i + 1 < lines.len() {
+                self.term.write_line(line)?;
+            } else {
+                // No newline after the last row, so the screen doesn't scroll
+                self.term.write_str(line)?;
+            }
+        }
+        self.term.flush()?;
+        Ok(())
+    }
+}
+
+impl Drop for Screen<'_> {
+    fn drop(&mut self) {
+        if self.active {
+            let _ = self.leave();
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! Interactive dashboard (`vx ui`)
+//!
+//! A full-screen front end over the provider registry and the vx store. It
+//! has four tabs:
+//!
+//! - **Tools**: every installed tool version, its size and whether the
+//!   project pins it
+//! - **Updates**: updates for the project's tools, as `vx update --all`
+//!   reports them (checked when the tab is first opened)
+//! - **Disk**: space used by the store, caches and each tool
+//! - **Services**: the project's services and whether they are running
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `←` `→` / `Tab` / `1`-`4` | Switch tab |
+//! | `↑` `↓` / `j` `k` | Select |
+//! | `i` | Install a tool (on Updates: install the selected update) |
+//! | `u` | Uninstall the selected version |
+//! | `s` | Pin the project to the selected version |
+//! | `r` | Refresh |
+//! | `q` / `Esc` | Quit |
+//!
+//! Actions leave the dashboard to run with their usual output, then return
+//! to it. [`Dashboard`] holds the state and rendering so they work without a
+//! terminal; [`handle`] drives it.
+
+mod handler;
+mod state;
+
+pub use handler::handle;
+pub use state::{Action, Dashboard, DiskEntry, InstalledVersion, KeyInput, ServiceEntry, Tab};
@@ -0,0 +1,441 @@
+//! Dashboard state, key handling and rendering
+
+use crate::commands::common::format_size;
+use crate::commands::update::ToolUpdate;
+use dialoguer::console::{Alignment, pad_str, style, truncate_str};
+
+/// Rows moved by PageUp / PageDown
+const PAGE: isize = 10;
+
+/// Width of the usage bars on the Disk tab
+const BAR_WIDTH: u64 = 24;
+
+/// Dashboard tabs, in display order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tab {
+    Tools,
+    Updates,
+    Disk,
+    Services,
+}
+
+impl Tab {
+    pub const ALL: [Tab; 4] = [Tab::Tools, Tab::Updates, Tab::Disk, Tab::Services];
+
+    /// Title shown in the tab bar
+    pub fn title(self) -> &'static str {
+        match self {
+            Tab::Tools => "Tools",
+            Tab::Updates => "Updates",
+            Tab::Disk => "Disk",
+            Tab::Services => "Services",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// An installed tool version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledVersion {
+    pub tool: String,
+    pub version: String,
+    /// Size on disk in bytes
+    pub size: u64,
+    /// Whether the project in the current directory uses this version
+    pub pinned: bool,
+}
+
+/// Space used by one part of the vx home
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskEntry {
+    pub label: String,
+    pub size: u64,
+}
+
+/// A service from vx.toml
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceEntry {
+    pub name: String,
+    /// Container image, or `(command)` for command services
+    pub image: String,
+    pub running: bool,
+    /// Health check status of a running service
+    pub health: Option<String>,
+}
+
+/// A key press, independent of the terminal backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyInput {
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Tab,
+    BackTab,
+    Enter,
+    Esc,
+    Char(char),
+}
+
+/// Work the dashboard asks its driver to do
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    /// Reload installed tools, disk usage and services
+    Refresh,
+    /// Check the project's tools for updates
+    CheckUpdates,
+    /// Install a tool spec (`name[@version]`)
+    Install(String),
+    /// Ask which tool to install, suggesting `default`
+    PromptInstall {
+        default: String,
+    },
+    /// Uninstall one installed version
+    Uninstall {
+        tool: String,
+        version: String,
+    },
+    /// Pin the project to an installed version
+    Switch {
+        tool: String,
+        version: String,
+    },
+}
+
+/// State of the dashboard
+#[derive(Debug, Clone)]
+pub struct Dashboard {
+    pub tab: Tab,
+    pub installed: Vec<InstalledVersion>,
+    /// Project updates; `None` until checked, `Err` when they cannot be checked
+    pub updates: Option<Result<Vec<ToolUpdate>, String>>,
+    pub disk: Vec<DiskEntry>,
+    /// Project services, or why they are unavailable
+    pub services: Result<Vec<ServiceEntry>, String>,
+    /// Message shown in the footer until the next key press
+    pub status: Option<String>,
+    selected: [usize; 4],
+}
+
+impl Dashboard {
+    /// Create the dashboard on the Tools tab
+    pub fn new(
+        installed: Vec<InstalledVersion>,
+        disk: Vec<DiskEntry>,
+        services: Result<Vec<ServiceEntry>, String>,
+    ) -> Self {
+        Self {
+            tab: Tab::Tools,
+            installed,
+            updates: None,
+            disk,
+            services,
+            status: None,
+            selected: [0; 4],
+        }
+    }
+
+    /// Index of the selected row on the current tab
+    pub fn selected(&self) -> usize {
+        self.selected[self.tab.index()].min(self.row_count().saturating_sub(1))
+    }
+
+    /// The installed version selected on the Tools tab
+    pub fn selected_version(&self) -> Option<&InstalledVersion> {
+        match self.tab {
+            Tab::Tools => self.installed.get(self.selected()),
+            _ => None,
+        }
+    }
+
+    /// The update selected on the Updates tab
+    pub fn selected_update(&self) -> Option<&ToolUpdate> {
+        match (self.tab, &self.updates) {
+            (Tab::Updates, Some(Ok(updates))) => updates.get(self.selected()),
+            _ => None,
+        }
+    }
+
+    /// Apply a key press, returning the work it requests
+    pub fn handle_key(&mut self, key: KeyInput) -> Option<Action> {
+        self.status = None;
+        let tab = self.tab.index();
+        match key {
+            KeyInput::Esc | KeyInput::Char('q') => Some(Action::Quit),
+            KeyInput::Right | KeyInput::Tab | KeyInput::Char('l') => self.switch_tab((tab + 1) % 4),
+            KeyInput::Left | KeyInput::BackTab | KeyInput::Char('h') => {
+                self.switch_tab((tab + 3) % 4)
+            }
+            KeyInput::Char(c @ '1'..='4') => self.switch_tab(c as usize - '1' as usize),
+            KeyInput::Up | KeyInput::Char('k') => self.move_selection(-1),
+            KeyInput::Down | KeyInput::Char('j') => self.move_selection(1),
+            KeyInput::PageUp => self.move_selection(-PAGE),
+            KeyInput::PageDown => self.move_selection(PAGE),
+            KeyInput::Home | KeyInput::Char('g') => self.move_selection(isize::MIN),
+            KeyInput::End | KeyInput::Char('G') => self.move_selection(isize::MAX),
+            KeyInput::Char('r') if self.tab == Tab::Updates => Some(Action::CheckUpdates),
+            KeyInput::Char('r') => Some(Action::Refresh),
+            KeyInput::Char('i') => self.install(),
+            KeyInput::Char('u') => self.uninstall(),
+            KeyInput::Char('s') => self.switch(),
+            _ => None,
+        }
+    }
+
+    fn switch_tab(&mut self, index: usize) -> Option<Action> {
+        self.tab = Tab::ALL[index];
+        (self.tab == Tab::Updates && self.updates.is_none()).then_some(Action::CheckUpdates)
+    }
+
+    fn move_selection(&mut self, delta: isize) -> Option<Action> {
+        let last = self.row_count().saturating_sub(1);
+        let selected = self.selected().saturating_add_signed(delta).min(last);
+        self.selected[self.tab.index()] = selected;
+        None
+    }
+
+    fn install(&mut self) -> Option<Action> {
+        match self.tab {
+            Tab::Updates => match self.selected_update() {
+                Some(update) if update.is_outdated() => Some(Action::Install(format!(
+                    "{}@{}",
+                    update.name,
+                    update.target.as_deref().unwrap_or_default()
+                ))),
+                Some(update) => {
+                    self.status = Some(format!("{} has no update to install", update.name));
+                    None
+                }
+                None => Some(Action::PromptInstall {
+                    default: String::new(),
+                }),
+            },
+            _ => Some(Action::PromptInstall {
+                default: self
+                    .selected_version()
+                    .map(|v| v.tool.clone())
+                    .unwrap_or_default(),
+            }),
+        }
+    }
+
+    fn uninstall(&mut self) -> Option<Action> {
+        let Some(version) = self.selected_version() else {
+            self.status = Some("Select a version on the Tools tab to uninstall it".to_string());
+            return None;
+        };
+        Some(Action::Uninstall {
+            tool: version.tool.clone(),
+            version: version.version.clone(),
+        })
+    }
+
+    fn switch(&mut self) -> Option<Action> {
+        let Some(version) = self.selected_version() else {
+            self.status = Some("Select a version on the Tools tab to use it".to_string());
+            return None;
+        };
+        if version.pinned {
+            self.status = Some(format!(
+                "The project already uses {} {}",
+                version.tool, version.version
+            ));
+            return None;
+        }
+        Some(Action::Switch {
+            tool: version.tool.clone(),
+            version: version.version.clone(),
+        })
+    }
+
+    fn row_count(&self) -> usize {
+        match self.tab {
+            Tab::Tools => self.installed.len(),
+            Tab::Updates => match &self.updates {
+                Some(Ok(updates)) => updates.len(),
+                _ => 0,
+            },
+            Tab::Disk => self.disk.len(),
+            Tab::Services => self.services.as_ref().map_or(0, Vec::len),
+        }
+    }
+
+    /// Render the dashboard as exactly `height` lines of at most `width` columns
+    pub fn render(&self, width: usize, height: usize) -> Vec<String> {
+        let mut lines = vec![truncate_str(&self.tab_bar(), width, "…").into_owned()];
+        lines.push("─".repeat(width));
+
+        let (header, rows, empty) = self.rows();
+        if rows.is_empty() {
+            lines.push(style(empty).dim().to_string());
+        } else {
+            lines.push(
+                style(truncate_str(&format!("  {}", header), width, "…"))
+                    .bold()
+                    .to_string(),
+            );
+            let body_height = height.saturating_sub(5).max(1);
+            let selected = self.selected();
+            let offset = (selected + 1).saturating_sub(body_height);
+            for (i, row) in rows.iter().enumerate().skip(offset).take(body_height) {
+                if i == selected {
+                    let row = format!("› {}", row);
+                    let row = truncate_str(&row, width, "…");
+                    lines.push(
+                        style(pad_str(&row, width, Alignment::Left, None))
+                            .reverse()
+                            .to_string(),
+                    );
+                } else {
+                    lines.push(truncate_str(&format!("  {}", row), width, "…").into_owned());
+                }
+            }
+        }
+
+        lines.resize(height.saturating_sub(2), String::new());
+        lines.push("─".repeat(width));
+        let footer = self.status.clone().unwrap_or_else(|| self.key_help());
+        lines.push(truncate_str(&footer, width, "…").into_owned());
+        lines.truncate(height);
+        lines
+    }
+
+    fn tab_bar(&self) -> String {
+        let tabs: Vec<String> = Tab::ALL
+            .iter()
+            .enumerate()
+            .map(|(i, tab)| {
+                let label = format!(" {} {} ", i + 1, tab.title());
+                if *tab == self.tab {
+                    style(label).reverse().bold().to_string()
+                } else {
+                    label
+                }
+            })
+            .collect();
+        format!("{}  {}", style("vx").bold(), tabs.join(" "))
+    }
+
+    fn key_help(&self) -> String {
+        let actions = match self.tab {
+            Tab::Tools => "i install  u uninstall  s use in project  r refresh",
+            Tab::Updates => "i install update  r check again",
+            Tab::Disk | Tab::Services => "i install  r refresh",
+        };
+        format!("↑↓ select  ←→ tabs  {}  q quit", actions)
+    }
+
+    /// Column header, rows and the message shown when there are no rows
+    fn rows(&self) -> (String, Vec<String>, String) {
+        match self.tab {
+            Tab::Tools => (
+                format!("{:<20} {:<18} {:>10}", "TOOL", "VERSION", "SIZE"),
+                self.installed
+                    .iter()
+                    .map(|v| {
+                        format!(
+                            "{:<20} {:<18} {:>10}  {}",
+                            v.tool,
+                            v.version,
+                            format_size(v.size),
+                            if v.pinned { "project" } else { "" }
+                        )
+                    })
+                    .collect(),
+                "No tools installed. Press i to install one.".to_string(),
+            ),
+            Tab::Updates => {
+                let header = format!("{:<20} {:<18} {}", "TOOL", "CURRENT", "UPDATE");
+                match &self.updates {
+                    None => (
+                        header,
+                        Vec::new(),
+                        "Press r to check the project's tools for updates".to_string(),
+                    ),
+                    Some(Err(e)) => (header, Vec::new(), e.clone()),
+                    Some(Ok(updates)) => (
+                        header,
+                        updates.iter().map(update_row).collect(),
+                        "No tools configured in vx.toml".to_string(),
+                    ),
+                }
+            }
+            Tab::Disk => {
+                let max = self.disk.iter().map(|d| d.size).max().unwrap_or(0).max(1);
+                (
+                    format!("{:<28} {:>10}", "LOCATION", "SIZE"),
+                    self.disk
+                        .iter()
+                        .map(|d| {
+                            let bar = (d.size * BAR_WIDTH).div_ceil(max) as usize;
+                            format!(
+                                "{:<28} {:>10}  {}",
+                                d.label,
+                                format_size(d.size),
+                                "█".repeat(bar)
+                            )
+                        })
+                        .collect(),
+                    "Nothing stored yet".to_string(),
+                )
+            }
+            Tab::Services => {
+                let header = format!("  {:<20} {:<28} {}", "SERVICE", "IMAGE", "STATUS");
+                match &self.services {
+                    Err(e) => (header, Vec::new(), e.clone()),
+                    Ok(services) => (
+                        header,
+                        services
+                            .iter()
+                            .map(|s| {
+                                let status = match (&s.health, s.running) {
+                                    (Some(health), true) => health.as_str(),
+                                    (None, true) => "running",
+                                    (_, false) => "stopped",
+                                };
+                                format!(
+                                    "{} {:<20} {:<28} {}",
+                                    if s.running { "●" } else { "○" },
+                                    s.name,
+                                    s.image,
+                                    status
+                                )
+                            })
+                            .collect(),
+                        "No services defined in vx.toml".to_string(),
+                    ),
+                }
+            }
+        }
+    }
+}
+
+fn update_row(update: &ToolUpdate) -> String {
+    let status = if let Some(error) = &update.error {
+        error.clone()
+    } else if update.is_outdated() {
+        format!("→ {}", update.target.as_deref().unwrap_or_default())
+    } else if update.is_held_back() {
+        format!(
+            "up to date (latest {} is outside '{}')",
+            update.latest.as_deref().unwrap_or_default(),
+            update.constraint
+        )
+    } else {
+        "up to date".to_string()
+    };
+    format!(
+        "{:<20} {:<18} {}",
+        update.name,
+        update.current.as_deref().unwrap_or("-"),
+        status
+    )
+}
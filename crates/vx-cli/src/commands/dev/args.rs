@@ -2,42 +2,39 @@
 
 use clap::Args as ClapArgs;
 
-/// Enter development environment
+/// Enter development environment with all project tools
 #[derive(ClapArgs, Clone, Debug)]
 pub struct Args {
     /// Shell to use (auto-detected if not specified)
-    #[arg(long, short)]
+    #[arg(long)]
     pub shell: Option<String>,
-
-    /// Execute a command instead of entering shell
-    #[arg(last = true)]
+    /// Run a command in the dev environment instead of spawning a shell
+    #[arg(short, long, num_args = 1..)]
     pub command: Option<Vec<String>>,
-
-    /// Don't auto-install missing tools
+    /// Don't install missing tools
     #[arg(long)]
     pub no_install: bool,
-
     /// Show verbose output
-    #[arg(long, short)]
+    #[arg(short, long)]
     pub verbose: bool,
-
-    /// Export environment variables instead of spawning shell
-    #[arg(long)]
+    /// Export environment variables for shell activation
+    #[arg(long, short = 'e')]
     pub export: bool,
-
-    /// Export format (shell, powershell, batch, github)
-    #[arg(long)]
+    /// Output format for --export: shell, powershell, batch, github
+    #[arg(long, short = 'f')]
     pub format: Option<String>,
-
-    /// Show environment info without entering shell
-    #[arg(long, short)]
+    /// Show detailed environment information (tools, paths, conflicts)
+    #[arg(long, short = 'i')]
     pub info: bool,
-
-    /// Inherit system PATH (disable isolation)
+    /// Inherit system PATH (disable isolation mode)
+    ///
+    /// By default, vx dev uses isolation mode where only vx-managed tools
+    /// are available. Use this flag to include system tools in PATH.
     #[arg(long)]
     pub inherit_system: bool,
-
-    /// Additional patterns for passenv (can be specified multiple times)
-    #[arg(long = "passenv", value_name = "PATTERN")]
+    /// Additional environment variables to pass through (can be specified multiple times)
+    ///
+    /// Supports glob patterns like SSH_*, GITHUB_*
+    #[arg(long = "passenv", short = 'p', action = clap::ArgAction::Append)]
     pub passenv: Vec<String>,
 }
@@ -11,6 +11,7 @@ use crate::ui::UI;
 use anyhow::{Context, Result};
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::env;
+use std::path::PathBuf;
 use std::process::Command;
 use vx_env::{RuntimeSpec, ToolEnvironment};
 use vx_starlark::handle::global_registry;
@@ -440,10 +441,60 @@ fn execute_command_in_env(cmd: &[String], env_vars: &HashMap<String, String>) ->
 /// (e.g. unit tests). When called outside a Tokio runtime it creates a
 /// temporary single-threaded runtime for the async provider lookups.
 pub fn build_script_environment(config: &ConfigView) -> Result<HashMap<String, String>> {
-    // Merge env from vx.toml with setenv from settings
-    let mut env_vars = config.env.clone();
-    env_vars.extend(config.setenv.clone());
+    let tool_specs = resolve_tool_specs(config)?;
+    build_script_environment_from_specs(config, tool_specs)
+}
 
+/// Resolve the bin directories of the project's pinned tools
+///
+/// Tools that are not installed keep the default `bin` guess and no
+/// resolved directory.
+pub fn resolve_tool_specs(config: &ConfigView) -> Result<Vec<RuntimeSpec>> {
+    let resolved = resolve_pinned_executables(config, |_| true)?;
+    Ok(resolved
+        .into_iter()
+        .map(|tool| {
+            let mut spec = RuntimeSpec::with_bin_dirs(tool.name, tool.version, tool.bin_dirs);
+            if let Some(bin_dir) = tool.executable.as_deref().and_then(|exe| exe.parent()) {
+                spec = spec.set_resolved_bin_dir(bin_dir.to_path_buf());
+            }
+            spec
+        })
+        .collect())
+}
+
+/// Resolve the executables of the pinned tools named in `names`
+///
+/// Used for `{tool:<name>}` placeholders; tools that are not pinned or not
+/// installed are left out.
+pub fn resolve_tool_executables(
+    config: &ConfigView,
+    names: &[String],
+) -> Result<HashMap<String, PathBuf>> {
+    if names.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let resolved = resolve_pinned_executables(config, |tool| names.iter().any(|n| n == tool))?;
+    Ok(resolved
+        .into_iter()
+        .filter_map(|tool| tool.executable.map(|exe| (tool.name, exe)))
+        .collect())
+}
+
+/// A pinned tool with its installed executable, if any
+struct PinnedTool {
+    name: String,
+    version: String,
+    /// Possible bin directories relative to the install root
+    bin_dirs: Vec<String>,
+    executable: Option<PathBuf>,
+}
+
+/// Look up the installed executable of each pinned tool accepted by `filter`
+fn resolve_pinned_executables(
+    config: &ConfigView,
+    filter: impl Fn(&str) -> bool,
+) -> Result<Vec<PinnedTool>> {
     // Get registry to query runtime bin directories
     let (registry, context) = get_registry()?;
 
@@ -458,10 +509,12 @@ pub fn build_script_environment(config: &ConfigView) -> Result<HashMap<String, S
                 .expect("Failed to build local Tokio runtime for build_script_environment")
         });
 
-    // Create RuntimeSpecs with proper bin directories from runtime providers
-    let mut tool_specs = Vec::new();
+    let mut resolved = Vec::new();
     for (tool_name, version) in &config.tools {
-        let (bin_dirs, resolved_bin_dir) = if let Some(provider) =
+        if !filter(tool_name) {
+            continue;
+        }
+        let (bin_dirs, executable) = if let Some(provider) =
             registry.providers().iter().find(|p| p.supports(tool_name))
         {
             if let Some(runtime) = provider.get_runtime(tool_name) {
@@ -481,7 +534,7 @@ pub fn build_script_environment(config: &ConfigView) -> Result<HashMap<String, S
                         .into_iter()
                         .map(|s| s.to_string())
                         .collect();
-                    (dirs, exe_path.parent().map(|p| p.to_path_buf()))
+                    (dirs, Some(exe_path))
                 } else {
                     (vec!["bin".to_string()], None)
                 }
@@ -491,14 +544,26 @@ pub fn build_script_environment(config: &ConfigView) -> Result<HashMap<String, S
         } else {
             (vec!["bin".to_string()], None)
         };
-
-        let mut spec = RuntimeSpec::with_bin_dirs(tool_name.clone(), version.clone(), bin_dirs);
-        if let Some(bin_dir) = resolved_bin_dir {
-            spec = spec.set_resolved_bin_dir(bin_dir);
-        }
-        tool_specs.push(spec);
+        resolved.push(PinnedTool {
+            name: tool_name.clone(),
+            version: version.clone(),
+            bin_dirs,
+            executable,
+        });
     }
 
+    Ok(resolved)
+}
+
+/// Build the script environment from already resolved tool specs
+pub fn build_script_environment_from_specs(
+    config: &ConfigView,
+    tool_specs: Vec<RuntimeSpec>,
+) -> Result<HashMap<String, String>> {
+    // Merge env from vx.toml with setenv from settings
+    let mut env_vars = config.env.clone();
+    env_vars.extend(config.setenv.clone());
+
     let mut builder = ToolEnvironment::new()
         .tools_from_specs(tool_specs)
         .env_vars(&env_vars)
@@ -32,6 +32,9 @@ mod tools;
 
 pub use args::Args;
 pub use export::{ExportFormat, generate_env_export};
-pub use handler::build_script_environment;
 pub use handler::handle;
+pub use handler::{
+    build_script_environment, build_script_environment_from_specs, resolve_tool_executables,
+    resolve_tool_specs,
+};
 pub use tools::get_registry;
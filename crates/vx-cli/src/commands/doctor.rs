@@ -0,0 +1,502 @@
+//! `vx doctor` — Diagnose what slows vx down on this machine.
+//!
+//! The providers check lists providers that failed to initialize — a
+//! `provider.star` that does not parse, a user script that fails validation,
+//! a provider that panics while loading — with the underlying error. vx keeps
+//! working without them.
+//!
+//! The antivirus check looks for real-time scanning of the vx store.
+//! Scanners inspect every file written while a tool is extracted, which
+//! makes installing `node_modules`-heavy tools 5-10x slower. Three
+//! heuristics are combined: known scanner processes, Microsoft Defender's
+//! own status on Windows, and a small-file write benchmark in the store.
+//!
+//! `--exclude-store` registers the store as a scanner exclusion. It always
+//! asks before changing anything and needs administrator rights, requested
+//! through UAC on Windows and `sudo` elsewhere.
+
+use crate::ui::UI;
+use anyhow::{Context, Result, bail};
+use serde::Serialize;
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+use vx_paths::VxPaths;
+use vx_runtime::{ProviderFailure, ProviderRegistry};
+
+/// Average time per small file above which writes count as slow
+///
+/// Unscanned SSDs need well under a millisecond to create, read back and
+/// delete a 4 KiB file; on-access scanning typically takes several.
+pub const SLOW_FILE_THRESHOLD: Duration = Duration::from_millis(2);
+
+/// Files written by the benchmark
+const BENCHMARK_FILES: usize = 200;
+
+/// Process names of real-time scanners and the product they belong to
+const KNOWN_SCANNERS: &[(&str, &str)] = &[
+    ("msmpeng.exe", "Microsoft Defender"),
+    ("mssense.exe", "Microsoft Defender for Endpoint"),
+    ("wdavdaemon", "Microsoft Defender for Endpoint"),
+    ("csfalconservice.exe", "CrowdStrike Falcon"),
+    ("falcon-sensor", "CrowdStrike Falcon"),
+    ("falcond", "CrowdStrike Falcon"),
+    ("sentinelagent.exe", "SentinelOne"),
+    ("sentinelagent", "SentinelOne"),
+    ("sentineld", "SentinelOne"),
+    ("savservice.exe", "Sophos"),
+    ("sophosscand", "Sophos"),
+    ("mcshield.exe", "McAfee"),
+    ("ccsvchst.exe", "Symantec"),
+    ("avp.exe", "Kaspersky"),
+    ("ekrn.exe", "ESET"),
+    ("avastsvc.exe", "Avast"),
+    ("clamd", "ClamAV"),
+];
+
+/// How antivirus scanning affects the vx store
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AntivirusVerdict {
+    /// No scanner found and file writes are fast
+    Clear,
+    /// The store is excluded from scanning
+    Excluded,
+    /// A scanner runs, but it does not measurably slow down the store
+    Scanning,
+    /// A scanner runs and file writes in the store are slow
+    Interfering,
+    /// File writes are slow, but no known scanner was found
+    SlowDisk,
+}
+
+/// Result of the antivirus check
+#[derive(Debug, Clone, Serialize)]
+pub struct AntivirusReport {
+    /// The directory tools are extracted to
+    pub store: PathBuf,
+    /// Scanner products recognized from running processes
+    pub scanners: Vec<String>,
+    /// Whether Defender real-time protection is on (Windows only)
+    pub realtime_protection: Option<bool>,
+    /// Whether the store is a scanner exclusion, if that can be determined
+    pub store_excluded: Option<bool>,
+    /// Average time to write, read back and delete one small file in the store
+    pub small_file_micros: Option<u64>,
+}
+
+impl AntivirusReport {
+    /// Whether the benchmark found slow small-file writes
+    pub fn is_slow(&self) -> bool {
+        self.small_file_micros
+            .is_some_and(|us| us >= SLOW_FILE_THRESHOLD.as_micros() as u64)
+    }
+
+    /// Combine the heuristics into one verdict
+    pub fn verdict(&self) -> AntivirusVerdict {
+        if self.store_excluded == Some(true) {
+            return AntivirusVerdict::Excluded;
+        }
+        let scanning = !self.scanners.is_empty() || self.realtime_protection == Some(true);
+        match (scanning, self.is_slow()) {
+            (true, true) => AntivirusVerdict::Interfering,
+            (true, false) => AntivirusVerdict::Scanning,
+            (false, true) => AntivirusVerdict::SlowDisk,
+            (false, false) => AntivirusVerdict::Clear,
+        }
+    }
+}
+
+/// Handle `vx doctor`
+pub async fn handle(
+    registry: &ProviderRegistry,
+    json: bool,
+    exclude_store: bool,
+    yes: bool,
+) -> Result<()> {
+    let paths = VxPaths::new()?;
+    let report = check_antivirus(&paths.store_dir);
+
+    if exclude_store {
+        return add_store_exclusion(&report, yes);
+    }
+
+    let degraded = crate::registry::degraded_providers(registry).await;
+
+    if json {
+        let value = serde_json::json!({
+            "antivirus": report,
+            "verdict": report.verdict(),
+            "degraded_providers": degraded,
+        });
+        println!("{}", serde_json::to_string_pretty(&value)?);
+        return Ok(());
+    }
+
+    UI::header("vx doctor");
+    println!();
+    print_antivirus(&report);
+    println!();
+    print_providers(&degraded);
+    Ok(())
+}
+
+fn print_providers(degraded: &[ProviderFailure]) {
+    println!("Providers:");
+    if degraded.is_empty() {
+        UI::success("All providers loaded");
+        return;
+    }
+    UI::warn(&format!(
+        "{} provider(s) failed to initialize and are disabled",
+        degraded.len()
+    ));
+    print_degraded_providers(degraded);
+    UI::hint("Other providers are unaffected; fix or remove the failing provider.star");
+}
+
+/// One line per degraded provider with its error
+pub fn print_degraded_providers(degraded: &[ProviderFailure]) {
+    for failure in degraded {
+        let mut lines = failure.error.lines();
+        UI::detail(&format!(
+            "✗ {}: {}",
+            failure.provider,
+            lines.next().unwrap_or_default()
+        ));
+        for line in lines {
+            UI::detail(&format!("  {}", line));
+        }
+    }
+}
+
+/// Run all antivirus heuristics against `store`
+pub fn check_antivirus(store: &Path) -> AntivirusReport {
+    let scanners = detect_scanners(&running_processes());
+    let (realtime_protection, store_excluded) = if cfg!(windows) {
+        defender_status()
+            .map(|output| parse_defender_status(&output, store))
+            .unwrap_or((None, None))
+    } else {
+        (None, None)
+    };
+    let small_file_micros = benchmark_small_files(store)
+        .map(|d| d.as_micros() as u64)
+        .ok();
+
+    AntivirusReport {
+        store: store.to_path_buf(),
+        scanners,
+        realtime_protection,
+        store_excluded,
+        small_file_micros,
+    }
+}
+
+fn print_antivirus(report: &AntivirusReport) {
+    println!("Antivirus:");
+    let scanners = if report.scanners.is_empty() {
+        "none recognized".to_string()
+    } else {
+        report.scanners.join(", ")
+    };
+    UI::detail(&format!("Scanners: {}", scanners));
+    if let Some(on) = report.realtime_protection {
+        UI::detail(&format!(
+            "Defender real-time protection: {}",
+            if on { "on" } else { "off" }
+        ));
+    }
+    let excluded = match report.store_excluded {
+        Some(true) => "yes",
+        Some(false) => "no",
+        None => "unknown",
+    };
+    UI::detail(&format!(
+        "Store excluded: {} ({})",
+        excluded,
+        report.store.display()
+    ));
+    if let Some(us) = report.small_file_micros {
+        UI::detail(&format!(
+            "Small-file writes: {:.2} ms/file",
+            us as f64 / 1000.0
+        ));
+    }
+    println!();
+
+    match report.verdict() {
+        AntivirusVerdict::Clear => UI::success("No antivirus interference detected"),
+        AntivirusVerdict::Excluded => UI::success("The vx store is excluded from scanning"),
+        AntivirusVerdict::Scanning => {
+            UI::info("A real-time scanner is running but does not measurably slow down the store")
+        }
+        AntivirusVerdict::Interfering => {
+            UI::warn("Real-time scanning is slowing down tool extraction in the vx store");
+            print_exclusion_help(report);
+        }
+        AntivirusVerdict::SlowDisk => {
+            UI::warn("File writes in the vx store are slow");
+            UI::hint(
+                "An unrecognized scanner or a network/encrypted drive may be the cause; \
+                 set VX_HOME to a faster local disk or exclude the store from scanning",
+            );
+        }
+    }
+}
+
+fn print_exclusion_help(report: &AntivirusReport) {
+    if exclusion_command(&report.scanners, &report.store).is_some() {
+        UI::hint("Run `vx doctor --exclude-store` to add the store as an exclusion");
+        UI::hint("It asks for confirmation and administrator rights before changing anything");
+    } else {
+        UI::hint(&format!(
+            "Ask your administrator to exclude {} from real-time scanning",
+            report.store.display()
+        ));
+    }
+    UI::hint("Only files vx downloads and verifies are written to the store");
+}
+
+/// Scanner products recognized in a list of process names or paths
+pub fn detect_scanners(processes: &[String]) -> Vec<String> {
+    let mut found: Vec<String> = Vec::new();
+    for process in processes {
+        let name = process
+            .rsplit(['/', '\\'])
+            .next()
+            .unwrap_or(process)
+            .trim()
+            .to_lowercase();
+        for (binary, product) in KNOWN_SCANNERS {
+            if name == *binary && !found.iter().any(|f| f == product) {
+                found.push(product.to_string());
+            }
+        }
+    }
+    found
+}
+
+/// Parse the output of `DEFENDER_STATUS_SCRIPT`
+///
+/// Returns whether real-time protection is on and whether `store` is
+/// inside one of the excluded paths. Exclusions are hidden from
+/// non-administrators, in which case the second value is `None`.
+pub fn parse_defender_status(output: &str, store: &Path) -> (Option<bool>, Option<bool>) {
+    let mut realtime = None;
+    let mut exclusions = Vec::new();
+    let mut hidden = false;
+    for line in output.lines().map(str::trim) {
+        if let Some(value) = line.strip_prefix("realtime=") {
+            realtime = match value.to_lowercase().as_str() {
+                "true" => Some(true),
+                "false" => Some(false),
+                _ => None,
+            };
+        } else if let Some(path) = line.strip_prefix("exclusion=") {
+            if path.starts_with("N/A") {
+                hidden = true;
+            } else {
+                exclusions.push(path);
+            }
+        }
+    }
+    if realtime.is_none() {
+        return (None, None);
+    }
+
+    let store = normalize_windows_path(&store.to_string_lossy());
+    let excluded = exclusions.iter().any(|dir| {
+        let dir = normalize_windows_path(dir);
+        store == dir || store.starts_with(&format!("{}\\", dir))
+    });
+    let excluded = if excluded {
+        Some(true)
+    } else if hidden {
+        None
+    } else {
+        Some(false)
+    };
+    (realtime, excluded)
+}
+
+fn normalize_windows_path(path: &str) -> String {
+    path.replace('/', "\\")
+        .trim_end_matches('\\')
+        .to_lowercase()
+}
+
+/// Prints Defender's real-time protection state and excluded paths
+const DEFENDER_STATUS_SCRIPT: &str = "$s = Get-MpComputerStatus; $p = Get-MpPreference; \
+     \"realtime=$($s.RealTimeProtectionEnabled)\"; \
+     $p.ExclusionPath | ForEach-Object { \"exclusion=$_\" }";
+
+fn defender_status() -> Option<String> {
+    let output = Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-NonInteractive",
+            "-Command",
+            DEFENDER_STATUS_SCRIPT,
+        ])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Names of the running processes, empty if they cannot be listed
+fn running_processes() -> Vec<String> {
+    let output = if cfg!(windows) {
+        Command::new("tasklist")
+            .args(["/fo", "csv", "/nh"])
+            .output()
+    } else {
+        Command::new("ps").args(["-axo", "comm"]).output()
+    };
+    let Ok(output) = output else {
+        return Vec::new();
+    };
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            // tasklist: "Image Name","PID",...
+            let name = line.split(',').next()?.trim().trim_matches('"');
+            (!name.is_empty()).then(|| name.to_string())
+        })
+        .collect()
+}
+
+/// Average time to write, read back and delete one small file in `dir`
+///
+/// Files get a `.js` extension, as scanners inspect scripts more closely
+/// than data files and `node_modules` is mostly scripts.
+pub fn benchmark_small_files(dir: &Path) -> std::io::Result<Duration> {
+    let probe = dir.join(format!(".vx-doctor-{}", std::process::id()));
+    std::fs::create_dir_all(&probe)?;
+    let content = "module.exports = {};\n".repeat(200);
+
+    let start = Instant::now();
+    let result = (|| {
+        for i in 0..BENCHMARK_FILES {
+            let path = probe.join(format!("file{}.js", i));
+            std::fs::write(&path, &content)?;
+            std::fs::read(&path)?;
+        }
+        std::io::Result::Ok(())
+    })();
+    let _ = std::fs::remove_dir_all(&probe);
+    result?;
+    Ok(start.elapsed() / BENCHMARK_FILES as u32)
+}
+
+/// Command that adds `store` as an exclusion of one of `scanners`, if vx knows one
+///
+/// The command runs with administrator rights: through UAC for Defender on
+/// Windows and `sudo` for Defender for Endpoint on macOS and Linux.
+pub fn exclusion_command(scanners: &[String], store: &Path) -> Option<Vec<String>> {
+    let store = store.display().to_string();
+    if cfg!(windows) {
+        if !scanners.iter().any(|s| s == "Microsoft Defender") {
+            return None;
+        }
+        let inner = format!(
+            "-NoProfile -Command Add-MpPreference -ExclusionPath '{}'",
+            store.replace('\'', "''")
+        );
+        Some(vec![
+            "powershell".to_string(),
+            "-NoProfile".to_string(),
+            "-Command".to_string(),
+            format!(
+                "Start-Process powershell -Verb RunAs -Wait -ArgumentList \"{}\"",
+                inner.replace('"', "`\"")
+            ),
+        ])
+    } else if scanners
+        .iter()
+        .any(|s| s == "Microsoft Defender for Endpoint")
+    {
+        Some(
+            [
+                "sudo",
+                "mdatp",
+                "exclusion",
+                "folder",
+                "add",
+                "--path",
+                &store,
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+        )
+    } else {
+        None
+    }
+}
+
+fn add_store_exclusion(report: &AntivirusReport, yes: bool) -> Result<()> {
+    if report.store_excluded == Some(true) {
+        UI::success("The vx store is already excluded from scanning");
+        return Ok(());
+    }
+    let scanners = if report.scanners.is_empty() && report.realtime_protection == Some(true) {
+        vec!["Microsoft Defender".to_string()]
+    } else {
+        report.scanners.clone()
+    };
+    if scanners.is_empty() {
+        UI::info("No antivirus scanner recognized on this machine; nothing to exclude");
+        return Ok(());
+    }
+    let Some(command) = exclusion_command(&scanners, &report.store) else {
+        bail!(
+            "vx cannot register exclusions for {}; exclude {} in its settings instead",
+            scanners.join(", "),
+            report.store.display()
+        );
+    };
+
+    UI::info(&format!(
+        "This adds {} as an antivirus exclusion, so files in it are no longer scanned.",
+        report.store.display()
+    ));
+    UI::detail(&format!("Command: {}", command.join(" ")));
+    UI::detail("Administrator rights are required; you will be asked to approve them.");
+    if !yes && !confirm()? {
+        UI::info("No changes made");
+        return Ok(());
+    }
+
+    let status = Command::new(&command[0])
+        .args(&command[1..])
+        .status()
+        .with_context(|| format!("Failed to run {}", command[0]))?;
+    if !status.success() {
+        bail!(
+            "Adding the exclusion failed (exit code {:?})",
+            status.code()
+        );
+    }
+    UI::success(&format!(
+        "Excluded {} from scanning",
+        report.store.display()
+    ));
+    UI::hint("Undo it at any time in your antivirus settings");
+    Ok(())
+}
+
+fn confirm() -> Result<bool> {
+    if !std::io::stdin().is_terminal() {
+        bail!("Refusing to change antivirus settings without confirmation; pass --yes");
+    }
+    print!("Add the exclusion? [y/N] ");
+    std::io::stdout().flush()?;
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input)?;
+    Ok(input.trim().eq_ignore_ascii_case("y") || input.trim().eq_ignore_ascii_case("yes"))
+}
@@ -69,6 +69,18 @@ pub enum EnvCommand {
         name: Option<String>,
     },
 
+    /// Compare the tools of two environments
+    ///
+    /// Each side is a global environment name (or `env:<name>`), `project`
+    /// for the project's `.vx/env/`, `config` for the versions in vx.toml, or
+    /// `lock` for the versions pinned in vx.lock.
+    Diff {
+        /// Base environment
+        from: String,
+        /// Environment to compare with (defaults to the current environment)
+        to: Option<String>,
+    },
+
     /// Add a runtime to an environment
     Add {
         /// Runtime and version (e.g., node@20.0.0)
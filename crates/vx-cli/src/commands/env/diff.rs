@@ -0,0 +1,244 @@
+//! `vx env diff` - compare the tools of two environments
+//!
+//! Each side is one of:
+//! - a global environment name (`default`, `project-abc`, ...), or `env:<name>`
+//! - `project` / `local`: the project environment in `.vx/env/`
+//! - `config`: versions requested in `vx.toml`
+//! - `lock`: versions pinned in `vx.lock`
+
+use super::helpers::{get_default_env, get_project_env_dir};
+use crate::cli::OutputFormat;
+use crate::commands::common::load_full_config_cwd;
+use crate::output::{CommandOutput, OutputRenderer};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use vx_paths::{LOCK_FILE_NAMES, PathManager};
+use vx_resolver::LockFile;
+
+/// A tool whose version differs between the two sides
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiffEntry {
+    /// Tool name
+    pub name: String,
+    /// Version on the `from` side (`None` when added)
+    pub from: Option<String>,
+    /// Version on the `to` side (`None` when removed)
+    pub to: Option<String>,
+}
+
+/// Differences between two environments
+#[derive(Debug, Clone, Serialize)]
+pub struct EnvDiff {
+    /// Label of the base side
+    pub from: String,
+    /// Label of the compared side
+    pub to: String,
+    /// Tools only present in `to`
+    pub added: Vec<DiffEntry>,
+    /// Tools only present in `from`
+    pub removed: Vec<DiffEntry>,
+    /// Tools present in both with different versions
+    pub changed: Vec<DiffEntry>,
+    /// Number of tools with the same version on both sides
+    pub unchanged: usize,
+}
+
+impl EnvDiff {
+    /// Compare two `tool -> version` maps
+    pub fn between(
+        from: impl Into<String>,
+        from_tools: &BTreeMap<String, String>,
+        to: impl Into<String>,
+        to_tools: &BTreeMap<String, String>,
+    ) -> Self {
+        let mut diff = Self {
+            from: from.into(),
+            to: to.into(),
+            added: Vec::new(),
+            removed: Vec::new(),
+            changed: Vec::new(),
+            unchanged: 0,
+        };
+
+        for (name, version) in from_tools {
+            match to_tools.get(name) {
+                None => diff.removed.push(DiffEntry {
+                    name: name.clone(),
+                    from: Some(version.clone()),
+                    to: None,
+                }),
+                Some(other) if other != version => diff.changed.push(DiffEntry {
+                    name: name.clone(),
+                    from: Some(version.clone()),
+                    to: Some(other.clone()),
+                }),
+                Some(_) => diff.unchanged += 1,
+            }
+        }
+        for (name, version) in to_tools {
+            if !from_tools.contains_key(name) {
+                diff.added.push(DiffEntry {
+                    name: name.clone(),
+                    from: None,
+                    to: Some(version.clone()),
+                });
+            }
+        }
+
+        diff
+    }
+
+    /// Whether both sides have the same tools and versions
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl CommandOutput for EnvDiff {
+    fn render_text(&self, writer: &mut dyn std::io::Write) -> Result<()> {
+        writeln!(writer, "Comparing {} -> {}", self.from, self.to)?;
+        if self.is_empty() {
+            writeln!(
+                writer,
+                "No differences ({} tools identical)",
+                self.unchanged
+            )?;
+            return Ok(());
+        }
+
+        writeln!(writer)?;
+        for entry in &self.added {
+            writeln!(writer, "  + {} {}", entry.name, version(&entry.to))?;
+        }
+        for entry in &self.removed {
+            writeln!(writer, "  - {} {}", entry.name, version(&entry.from))?;
+        }
+        for entry in &self.changed {
+            writeln!(
+                writer,
+                "  ~ {} {} -> {}",
+                entry.name,
+                version(&entry.from),
+                version(&entry.to)
+            )?;
+        }
+        writeln!(writer)?;
+        writeln!(
+            writer,
+            "{} added, {} removed, {} changed, {} unchanged",
+            self.added.len(),
+            self.removed.len(),
+            self.changed.len(),
+            self.unchanged
+        )?;
+        Ok(())
+    }
+
+    fn render_compact(&self, writer: &mut dyn std::io::Write) -> Result<()> {
+        for entry in &self.added {
+            writeln!(writer, "+{}@{}", entry.name, version(&entry.to))?;
+        }
+        for entry in &self.removed {
+            writeln!(writer, "-{}@{}", entry.name, version(&entry.from))?;
+        }
+        for entry in &self.changed {
+            writeln!(
+                writer,
+                "~{}@{}->{}",
+                entry.name,
+                version(&entry.from),
+                version(&entry.to)
+            )?;
+        }
+        if self.is_empty() {
+            writeln!(
+                writer,
+                "ok {}={} ({} tools)",
+                self.from, self.to, self.unchanged
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn version(version: &Option<String>) -> &str {
+    version.as_deref().unwrap_or("-")
+}
+
+/// Compare two environments (`to` defaults to the current environment)
+pub async fn diff_envs(from: &str, to: Option<&str>, format: OutputFormat) -> Result<()> {
+    let path_manager = PathManager::new()?;
+    let (from_label, from_tools) = load_env_tools(from, &path_manager)?;
+    let (to_label, to_tools) = match to {
+        Some(spec) => load_env_tools(spec, &path_manager)?,
+        None => current_env_tools(&path_manager)?,
+    };
+
+    let diff = EnvDiff::between(from_label, &from_tools, to_label, &to_tools);
+    OutputRenderer::new(format).render(&diff)
+}
+
+/// Resolve an environment spec to a label and its tool versions
+fn load_env_tools(
+    spec: &str,
+    path_manager: &PathManager,
+) -> Result<(String, BTreeMap<String, String>)> {
+    match spec {
+        "project" | "local" => {
+            let env_dir = get_project_env_dir()
+                .ok_or_else(|| anyhow::anyhow!("No vx.toml found in current directory"))?;
+            Ok(("project".to_string(), path_manager.env_tools(&env_dir)?))
+        }
+        "config" => {
+            let (config_path, config) = load_full_config_cwd()?;
+            Ok((file_label(&config_path), config.tools_as_btreemap()))
+        }
+        "lock" => {
+            let (config_path, _) = load_full_config_cwd()?;
+            let root = config_path.parent().unwrap_or(&config_path);
+            let lock_path = LOCK_FILE_NAMES
+                .iter()
+                .map(|name| root.join(name))
+                .find(|path| path.exists())
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "No vx.lock found in {}. Run 'vx lock' to create one",
+                        root.display()
+                    )
+                })?;
+            let lockfile = LockFile::load(&lock_path)
+                .with_context(|| format!("Failed to read {}", lock_path.display()))?;
+            Ok((file_label(&lock_path), lockfile.tool_versions()))
+        }
+        _ => {
+            let name = spec.strip_prefix("env:").unwrap_or(spec);
+            if !path_manager.env_exists(name) {
+                anyhow::bail!(
+                    "Environment '{}' does not exist. Use a global environment name, 'project', 'config' or 'lock'",
+                    name
+                );
+            }
+            let tools = path_manager.env_tools(&path_manager.env_dir(name))?;
+            Ok((name.to_string(), tools))
+        }
+    }
+}
+
+fn file_label(path: &std::path::Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+/// The project environment when present, otherwise the default global one
+fn current_env_tools(path_manager: &PathManager) -> Result<(String, BTreeMap<String, String>)> {
+    if let Some(project_env) = get_project_env_dir()
+        && project_env.exists()
+    {
+        return Ok(("project".to_string(), path_manager.env_tools(&project_env)?));
+    }
+    let default_env = get_default_env()?;
+    let tools = path_manager.env_tools(&path_manager.env_dir(&default_env))?;
+    Ok((default_env, tools))
+}
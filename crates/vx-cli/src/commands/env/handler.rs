@@ -2,10 +2,12 @@
 
 use super::Args;
 use super::args::EnvCommand;
+use super::diff::diff_envs;
 use super::helpers::{
     build_tools_from_env_dir, clone_env_contents, get_default_env, get_project_env_dir,
     list_env_runtimes, parse_runtime_version, resolve_env_for_shell, set_default_env,
 };
+use crate::cli::OutputFormat;
 use crate::commands::common::load_config_view_cwd;
 use crate::commands::setup::find_vx_config as find_config_file;
 use crate::ui::UI;
@@ -13,10 +15,10 @@ use anyhow::{Context, Result};
 use std::env;
 use std::io::Write;
 use vx_env::{ExportFormat, SessionContext, SessionSource, ShellSpawner};
-use vx_paths::{LinkStrategy, PROJECT_ENV_DIR, PathManager, link};
+use vx_paths::{PROJECT_ENV_DIR, PathManager, link};
 
 /// Handle env command with Args
-pub async fn handle(args: &Args) -> Result<()> {
+pub async fn handle(args: &Args, format: OutputFormat) -> Result<()> {
     match &args.command {
         EnvCommand::Create {
             name,
@@ -32,6 +34,7 @@ pub async fn handle(args: &Args) -> Result<()> {
             global,
         } => delete_env(name.as_deref(), *force, *global).await,
         EnvCommand::Show { name } => show_env(name.as_deref()).await,
+        EnvCommand::Diff { from, to } => diff_envs(from, to.as_deref(), format).await,
         EnvCommand::Add {
             runtime_version,
             env,
@@ -484,9 +487,9 @@ async fn add_runtime(runtime_version: &str, env_name: Option<&str>, global: bool
             .context("Failed to remove existing runtime link")?;
     }
 
-    // Create symlink
-    link::create_link(&store_dir, &env_runtime_path, LinkStrategy::SymLink)
-        .context("Failed to create symlink to runtime")?;
+    // Symlink, or clone where symlinks aren't available
+    link::materialize(&store_dir, &env_runtime_path)
+        .context("Failed to link runtime into environment")?;
 
     UI::success(&format!(
         "Added {}@{} to environment '{}'",
@@ -588,9 +591,9 @@ async fn sync_env() -> Result<()> {
                 .ok();
         }
 
-        // Create symlink
-        link::create_link(&store_dir, &env_tool_path, LinkStrategy::SymLink)
-            .with_context(|| format!("Failed to create symlink for {}", tool_name))?;
+        // Symlink, or clone where symlinks aren't available
+        link::materialize(&store_dir, &env_tool_path)
+            .with_context(|| format!("Failed to link {} into environment", tool_name))?;
 
         synced += 1;
     }
@@ -96,7 +96,7 @@ pub fn parse_runtime_version(s: &str) -> Result<(String, String)> {
     Ok((parts[0].to_string(), parts[1].to_string()))
 }
 
-/// Clone environment contents (symlinks)
+/// Clone environment contents (symlinks, and copy-on-write clones of files)
 pub fn clone_env_contents(source: &Path, target: &Path) -> Result<()> {
     if !source.exists() {
         return Ok(());
@@ -113,7 +113,7 @@ pub fn clone_env_contents(source: &Path, target: &Path) -> Result<()> {
             link::create_link(&link_target, &target_path, LinkStrategy::SymLink)
                 .context("Failed to create symlink")?;
         } else if source_path.is_file() {
-            std::fs::copy(&source_path, &target_path)?;
+            link::create_link(&source_path, &target_path, LinkStrategy::CopyOnWrite)?;
         } else if source_path.is_dir() {
             std::fs::create_dir_all(&target_path)?;
             clone_env_contents(&source_path, &target_path)?;
@@ -180,57 +180,7 @@ pub fn resolve_env_for_shell(
 /// Build tools map from environment directory symlinks
 pub fn build_tools_from_env_dir(
     env_dir: &Path,
-    _path_manager: &PathManager,
+    path_manager: &PathManager,
 ) -> Result<HashMap<String, String>> {
-    let mut tools = HashMap::new();
-
-    if !env_dir.exists() {
-        return Ok(tools);
-    }
-
-    for entry in std::fs::read_dir(env_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-
-        if !path.is_symlink() && !path.is_dir() {
-            continue;
-        }
-
-        let tool_name = entry.file_name().to_string_lossy().to_string();
-
-        // Try to extract version from symlink target or directory name
-        let version = if path.is_symlink() {
-            if let Ok(target) = std::fs::read_link(&path) {
-                // Extract version from store path: ~/.vx/store/<tool>/<version>/...
-                extract_version_from_store_path(&target, &tool_name)
-            } else {
-                "latest".to_string()
-            }
-        } else {
-            // For directories, try to detect version
-            "latest".to_string()
-        };
-
-        tools.insert(tool_name, version);
-    }
-
-    Ok(tools)
-}
-
-/// Extract version from store path
-fn extract_version_from_store_path(store_path: &Path, tool_name: &str) -> String {
-    // Store path format: ~/.vx/store/<tool>/<version>/...
-    let path_str = store_path.to_string_lossy();
-
-    if let Some(store_idx) = path_str.find("store") {
-        let after_store = &path_str[store_idx + 6..]; // Skip "store/"
-        let parts: Vec<&str> = after_store.split(['/', '\\']).collect();
-
-        // Expected: [<tool>, <version>, ...]
-        if parts.len() >= 2 && parts[0] == tool_name {
-            return parts[1].to_string();
-        }
-    }
-
-    "latest".to_string()
+    Ok(path_manager.env_tools(env_dir)?.into_iter().collect())
 }
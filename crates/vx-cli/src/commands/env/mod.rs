@@ -8,6 +8,7 @@
 //! - list: List all environments
 //! - delete: Remove an environment
 //! - show: Show current environment details
+//! - diff: Compare the tools of two environments
 //! - shell: Enter an interactive shell with environment tools
 //!
 //! ## Environment Types
@@ -21,8 +22,10 @@
 //! Environments contain symlinks to the global store, saving disk space.
 
 mod args;
+pub mod diff;
 mod handler;
 mod helpers;
 
 pub use args::{Args, EnvCommand};
 pub use handler::handle;
+pub use helpers::{build_tools_from_env_dir, resolve_env_for_shell};
@@ -0,0 +1,135 @@
+//! Exec command implementation
+//!
+//! `vx exec --with node@18 --with python@3.11 -- <command>` runs a command in
+//! a temporary environment built from the requested tool versions. Missing
+//! versions are installed on demand; `vx.toml` is never read or modified.
+
+use crate::commands::CommandContext;
+use crate::ui::UI;
+use anyhow::{Context, Result, bail};
+use vx_env::{RuntimeSpec, ToolEnvironment};
+use vx_runtime_core::WithDependency;
+
+/// Parse the `--with` specs for `vx exec`
+///
+/// At least one tool is required; otherwise `vx exec` would just be a plain
+/// command invocation.
+pub fn parse_exec_tools(specs: &[String]) -> Result<Vec<WithDependency>> {
+    let tools = WithDependency::parse_many(specs);
+    if tools.is_empty() {
+        bail!("vx exec requires at least one --with <tool>[@version]");
+    }
+    Ok(tools)
+}
+
+/// Handle `vx exec`
+pub async fn handle(ctx: &CommandContext, command: &[String]) -> Result<()> {
+    let tools = parse_exec_tools(&ctx.options().with_deps)?;
+    let Some((program, args)) = command.split_first() else {
+        bail!("No command specified. Usage: vx exec --with <tool>[@version] -- <command>");
+    };
+
+    let mut specs = Vec::with_capacity(tools.len());
+    for tool in &tools {
+        specs.push(resolve_tool(ctx, tool).await?);
+    }
+
+    let env = ToolEnvironment::new()
+        .tools_from_specs(specs)
+        .include_vx_bin(true)
+        .inherit_path(true)
+        .warn_missing(false)
+        .build()
+        .map_err(|e| anyhow::anyhow!("Failed to build exec environment: {}", e))?;
+
+    // Look the program up on the new PATH so the requested versions win
+    let cwd = std::env::current_dir()?;
+    let program_path = which::which_in(program, env.get("PATH"), &cwd)
+        .with_context(|| format!("Command '{}' not found in exec environment", program))?;
+
+    UI::debug(&format!(
+        "Running {} with args: {:?}",
+        program_path.display(),
+        args
+    ));
+
+    let status = std::process::Command::new(&program_path)
+        .args(args)
+        .envs(&env)
+        .stdin(std::process::Stdio::inherit())
+        .stdout(std::process::Stdio::inherit())
+        .stderr(std::process::Stdio::inherit())
+        .status()
+        .with_context(|| format!("Failed to run '{}'", program))?;
+
+    let exit_code = status.code().unwrap_or(1);
+    if exit_code != 0 {
+        std::process::exit(exit_code);
+    }
+
+    Ok(())
+}
+
+/// Resolve a `--with` tool to an installed version, installing it if needed
+pub(crate) async fn resolve_tool(
+    ctx: &CommandContext,
+    tool: &WithDependency,
+) -> Result<RuntimeSpec> {
+    let runtime = ctx
+        .registry()
+        .get_runtime(&tool.runtime)
+        .ok_or_else(|| anyhow::anyhow!("Unknown tool: {}", tool.runtime))?;
+    let requested = tool.version.as_deref().unwrap_or("latest");
+    let rt_ctx = ctx.runtime_context();
+
+    // Without a version, the newest installed version is used
+    let installed = runtime.resolve_installed_version(requested, rt_ctx).await?;
+    let version = match installed {
+        Some(version) => version,
+        None => {
+            if ctx.options().no_auto_install {
+                bail!(
+                    "{}@{} is not installed (auto-install disabled). Run: vx install {}@{}",
+                    tool.runtime,
+                    requested,
+                    tool.runtime,
+                    requested
+                );
+            }
+            UI::info(&format!(
+                "{}@{} is not installed. Installing...",
+                tool.runtime, requested
+            ));
+            let spec = match &tool.version {
+                Some(v) => format!("{}@{}", tool.runtime, v),
+                None => tool.runtime.clone(),
+            };
+            crate::commands::install::handle_install(
+                ctx.registry(),
+                rt_ctx,
+                std::slice::from_ref(&spec),
+                false,
+            )
+            .await?;
+            runtime
+                .resolve_installed_version(requested, rt_ctx)
+                .await?
+                .ok_or_else(|| {
+                    anyhow::anyhow!("{}@{} was not found after install", tool.runtime, requested)
+                })?
+        }
+    };
+
+    UI::debug(&format!("exec: using {}@{}", tool.runtime, version));
+
+    let bin_dir = runtime
+        .get_executable_path_for_version(&version, rt_ctx)
+        .await?
+        .and_then(|exe| exe.parent().map(|p| p.to_path_buf()));
+    Ok(match bin_dir {
+        Some(dir) => RuntimeSpec::with_resolved_bin_dir(runtime.store_name(), version, dir),
+        None => {
+            RuntimeSpec::with_bin_dirs(runtime.store_name(), version, runtime.possible_bin_dirs())
+        }
+    })
+}
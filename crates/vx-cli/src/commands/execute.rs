@@ -264,9 +264,20 @@ pub async fn execute_runtime_with_options(
     // Create the executor with runtime map from provider.star handles (RFC-0037)
     crate::registry::ensure_provider_metadata_initialized().await;
     let runtime_map = crate::registry::build_runtime_map();
-    let executor =
+    let mut executor =
         Executor::new(config, registry, context, runtime_map)?.with_compact_mode(compact_active);
 
+    // Warn (once a day) when the project pins an end-of-life version
+    if opts.version.is_none() {
+        crate::eol::warn_project_pins(Some(runtime_name), UI::warn_stderr);
+    }
+
+    // Confine the tool to the project's egress policy, if any
+    let egress = crate::egress::EgressSession::start_for_cwd()?;
+    if let Some(egress) = &egress {
+        executor = executor.with_env(egress.env_vars());
+    }
+
     executor
         .execute_with_with_deps(
             runtime_name,
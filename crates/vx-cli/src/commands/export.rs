@@ -0,0 +1,126 @@
+//! Export command implementation
+//!
+//! `vx export brewfile` and `vx export scoop` translate the project toolchain
+//! from `vx.toml` into a Homebrew `Brewfile` or a Scoop import manifest, for
+//! consumers who install tools without vx. `vx.toml` stays the source of
+//! truth; the generated files should be regenerated rather than edited.
+//!
+//! Package names come from the `brew_install(...)` / `scoop_install(...)`
+//! strategies declared by each provider, falling back to the tool name.
+
+use crate::commands::common;
+use crate::registry::find_system_package;
+use crate::ui::UI;
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Scoop bucket used for packages without an explicit bucket
+const SCOOP_DEFAULT_BUCKET: &str = "main";
+const SCOOP_DEFAULT_BUCKET_URL: &str = "https://github.com/ScoopInstaller/Main";
+
+/// A project tool mapped to a system package
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportEntry {
+    /// Tool name in vx.toml
+    pub tool: String,
+    /// Package name for the target package manager
+    pub package: String,
+    /// Version requested in vx.toml
+    pub version: String,
+}
+
+/// Map project tools to packages for the given package manager
+pub fn export_entries(tools: &BTreeMap<String, String>, manager: &str) -> Vec<ExportEntry> {
+    tools
+        .iter()
+        .map(|(tool, version)| ExportEntry {
+            tool: tool.clone(),
+            package: find_system_package(tool, manager).unwrap_or_else(|| tool.clone()),
+            version: version.clone(),
+        })
+        .collect()
+}
+
+/// Render a Homebrew `Brewfile`
+///
+/// Brewfiles cannot pin versions, so the version from vx.toml is kept as a
+/// trailing comment on each line.
+pub fn render_brewfile(project_name: &str, entries: &[ExportEntry]) -> String {
+    let mut out = String::new();
+    out.push_str(&format!(
+        "# Generated by `vx export brewfile` from vx.toml ({})\n",
+        project_name
+    ));
+    out.push_str("# vx.toml is the source of truth: regenerate instead of editing.\n");
+    out.push_str("# Install with: brew bundle\n\n");
+
+    let mut packages: Vec<&str> = Vec::new();
+    for entry in entries {
+        if packages.contains(&entry.package.as_str()) {
+            continue;
+        }
+        packages.push(&entry.package);
+        out.push_str(&format!(
+            "brew \"{}\" # vx: {} = \"{}\"\n",
+            entry.package, entry.tool, entry.version
+        ));
+    }
+
+    out
+}
+
+/// Render a Scoop manifest in the `scoop export` format, usable with `scoop import`
+pub fn render_scoop(entries: &[ExportEntry]) -> Result<String> {
+    let mut apps: Vec<serde_json::Value> = Vec::new();
+    for entry in entries {
+        if apps.iter().any(|a| a["Name"] == entry.package.as_str()) {
+            continue;
+        }
+        apps.push(serde_json::json!({
+            "Name": entry.package,
+            "Version": entry.version,
+            "Source": SCOOP_DEFAULT_BUCKET,
+            "Info": format!("vx: {}", entry.tool),
+        }));
+    }
+
+    let manifest = serde_json::json!({
+        "buckets": [{
+            "Name": SCOOP_DEFAULT_BUCKET,
+            "Source": SCOOP_DEFAULT_BUCKET_URL,
+        }],
+        "apps": apps,
+    });
+
+    let mut json = serde_json::to_string_pretty(&manifest)?;
+    json.push('\n');
+    Ok(json)
+}
+
+/// Handle `vx export brewfile`
+pub async fn handle_brewfile(output: Option<&Path>) -> Result<()> {
+    let (_, config) = common::load_config_view_cwd()?;
+    let entries = export_entries(&config.tools_as_btreemap(), "brew");
+    write_output(&render_brewfile(&config.project_name, &entries), output)
+}
+
+/// Handle `vx export scoop`
+pub async fn handle_scoop(output: Option<&Path>) -> Result<()> {
+    let (_, config) = common::load_config_view_cwd()?;
+    let entries = export_entries(&config.tools_as_btreemap(), "scoop");
+    write_output(&render_scoop(&entries)?, output)
+}
+
+/// Write to the output file, or stdout when none is given
+fn write_output(content: &str, output: Option<&Path>) -> Result<()> {
+    match output {
+        Some(path) => {
+            std::fs::write(path, content)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            UI::success(&format!("Exported to {}", path.display()));
+        }
+        None => print!("{}", content),
+    }
+    Ok(())
+}
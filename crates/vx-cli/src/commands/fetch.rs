@@ -24,7 +24,7 @@ pub async fn handle(
         Some(r) => r,
         None => {
             // Show friendly error with suggestions
-            let available_tools = registry.runtime_names();
+            let available_tools = crate::registry::registry_index(registry).runtime_names();
             UI::tool_not_found(tool_name, &available_tools);
             return Err(anyhow::anyhow!("Tool not found: {}", tool_name));
         }
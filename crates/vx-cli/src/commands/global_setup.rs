@@ -0,0 +1,588 @@
+//! Global setup (`vx setup --global`) and the first-run offer
+//!
+//! On a clean machine vx usually lacks some of:
+//!
+//! 1. **Bin directory** – `~/.vx/bin`, where tool shims live
+//! 2. **PATH** – the directory holding `vx` on PATH in new terminals
+//! 3. **Shell hook** – `vx shell init` loaded from the shell profile
+//!
+//! `vx setup --global` fixes these in one pass and records two machine-wide
+//! choices in `~/.vx/config/setup.toml`: whether vx keeps local usage
+//! metrics, and the default download mirror region.
+//!
+//! The first time another command runs in an interactive terminal with any of
+//! these missing, vx offers to run the setup. The answer is recorded either
+//! way, so the offer is made only once.
+
+use crate::cli::Commands;
+use crate::commands::shell::detect_shell;
+use crate::ui::UI;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use vx_paths::VxPaths;
+
+/// File name of the setup record inside `~/.vx/config`
+pub const SETUP_CONFIG_FILE: &str = "setup.toml";
+
+/// Disables the first-run offer when set to `0` or `false`
+pub const FIRST_RUN_ENV: &str = "VX_FIRST_RUN";
+
+/// Start of the block vx manages in shell profiles
+pub const PROFILE_BLOCK_START: &str = "# >>> vx >>>";
+
+/// End of the block vx manages in shell profiles
+pub const PROFILE_BLOCK_END: &str = "# <<< vx <<<";
+
+/// Machine-wide choices made by `vx setup --global`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GlobalSetup {
+    /// When the setup last ran (ISO 8601)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+    /// The first-run offer was declined
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub declined: bool,
+    /// Shell the hook was installed for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shell: Option<String>,
+    /// Profile the hook was written to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<PathBuf>,
+    /// Keep local usage metrics in `~/.vx/metrics` (default: true)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub telemetry: Option<bool>,
+    /// Download mirror region (`cn`, `global`); unset detects it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mirror_region: Option<String>,
+}
+
+impl GlobalSetup {
+    /// Path of the setup record
+    pub fn path(paths: &VxPaths) -> PathBuf {
+        paths.config_dir.join(SETUP_CONFIG_FILE)
+    }
+
+    /// Load the setup record; `None` when setup never ran or was never offered
+    pub fn load(paths: &VxPaths) -> Option<Self> {
+        let content = std::fs::read_to_string(Self::path(paths)).ok()?;
+        match toml::from_str(&content) {
+            Ok(setup) => Some(setup),
+            Err(e) => {
+                tracing::warn!("Ignoring invalid {}: {}", SETUP_CONFIG_FILE, e);
+                Some(Self::default())
+            }
+        }
+    }
+
+    /// Write the setup record to `~/.vx/config`
+    pub fn save(&self, paths: &VxPaths) -> Result<()> {
+        let path = Self::path(paths);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let content = format!(
+            "# Written by 'vx setup --global'\n{}",
+            toml::to_string_pretty(self)?
+        );
+        std::fs::write(&path, content)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Whether local usage metrics are kept
+    pub fn telemetry_enabled(&self) -> bool {
+        self.telemetry.unwrap_or(true)
+    }
+}
+
+/// Something global setup would fix
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SetupGap {
+    /// The shim directory doesn't exist
+    BinDirMissing(PathBuf),
+    /// A directory vx needs isn't on PATH
+    NotOnPath(PathBuf),
+    /// The shell profile doesn't load `vx shell init`
+    ShellHookMissing { shell: String },
+}
+
+impl SetupGap {
+    /// One-line description
+    pub fn describe(&self) -> String {
+        match self {
+            SetupGap::BinDirMissing(dir) => format!("{} does not exist", dir.display()),
+            SetupGap::NotOnPath(dir) => format!("{} is not on PATH", dir.display()),
+            SetupGap::ShellHookMissing { shell } => {
+                format!("vx shell integration is not loaded by {}", shell)
+            }
+        }
+    }
+}
+
+/// What global setup would change, given the machine's current state
+///
+/// `path_dirs` are the directories that must be on PATH (the one holding
+/// `vx`, then the bin directory). The shell hook counts as installed when it is
+/// active in this shell (`hook_active`) or `profile` already contains it.
+pub fn detect_gaps(
+    bin_dir: &Path,
+    path_dirs: &[PathBuf],
+    path_var: &std::ffi::OsStr,
+    shell: &str,
+    profile: Option<&str>,
+    hook_active: bool,
+) -> Vec<SetupGap> {
+    let mut gaps = Vec::new();
+    if !bin_dir.exists() {
+        gaps.push(SetupGap::BinDirMissing(bin_dir.to_path_buf()));
+    }
+
+    let on_path: Vec<PathBuf> = std::env::split_paths(path_var).collect();
+    for dir in path_dirs {
+        if !on_path.iter().any(|p| same_dir(p, dir)) {
+            gaps.push(SetupGap::NotOnPath(dir.clone()));
+        }
+    }
+
+    let hook_in_profile = profile.is_some_and(|p| p.contains("vx shell init"));
+    if profile_block(shell, &[]).is_some() && !hook_active && !hook_in_profile {
+        gaps.push(SetupGap::ShellHookMissing {
+            shell: shell.to_string(),
+        });
+    }
+    gaps
+}
+
+fn same_dir(a: &Path, b: &Path) -> bool {
+    let trim = |p: &Path| {
+        p.to_string_lossy()
+            .trim_end_matches(['/', '\\'])
+            .to_string()
+    };
+    if cfg!(windows) {
+        trim(a).eq_ignore_ascii_case(&trim(b))
+    } else {
+        trim(a) == trim(b)
+    }
+}
+
+/// Profile file the shell hook goes in, relative to `home`
+///
+/// Shells without a profile vx can edit (`cmd`, `nu`) return `None`.
+pub fn profile_path(shell: &str, home: &Path) -> Option<PathBuf> {
+    match shell {
+        "bash" => Some(home.join(".bashrc")),
+        "zsh" => Some(
+            std::env::var_os("ZDOTDIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| home.to_path_buf())
+                .join(".zshrc"),
+        ),
+        "fish" => Some(home.join(".config").join("fish").join("config.fish")),
+        "powershell" if cfg!(windows) => Some(
+            home.join("Documents")
+                .join("PowerShell")
+                .join("Microsoft.PowerShell_profile.ps1"),
+        ),
+        "powershell" => Some(
+            home.join(".config")
+                .join("powershell")
+                .join("Microsoft.PowerShell_profile.ps1"),
+        ),
+        _ => None,
+    }
+}
+
+/// The managed profile block: `path_dirs` prepended to PATH, then the hook
+///
+/// Returns `None` for shells vx has no hook line for.
+pub fn profile_block(shell: &str, path_dirs: &[PathBuf]) -> Option<String> {
+    let (path_line, hook): (fn(&str) -> String, &str) = match shell {
+        "bash" => (
+            |d| format!("export PATH=\"{}:$PATH\"", d),
+            "eval \"$(vx shell init bash)\"",
+        ),
+        "zsh" => (
+            |d| format!("export PATH=\"{}:$PATH\"", d),
+            "eval \"$(vx shell init zsh)\"",
+        ),
+        "fish" => (
+            |d| format!("set -gx PATH \"{}\" $PATH", d),
+            "vx shell init fish | source",
+        ),
+        "powershell" => (
+            |d| format!("$env:PATH = \"{};$env:PATH\"", d),
+            "Invoke-Expression (& vx shell init powershell | Out-String)",
+        ),
+        _ => return None,
+    };
+
+    let mut lines = vec![PROFILE_BLOCK_START.to_string()];
+    // Prepend in reverse so the first directory ends up first on PATH
+    for dir in path_dirs.iter().rev() {
+        lines.push(path_line(&dir.display().to_string()));
+    }
+    lines.push(hook.to_string());
+    lines.push(PROFILE_BLOCK_END.to_string());
+    Some(lines.join("\n") + "\n")
+}
+
+/// Put `block` into a profile, replacing an earlier vx block if there is one
+pub fn apply_profile_block(existing: &str, block: &str) -> String {
+    if let Some(start) = existing.find(PROFILE_BLOCK_START)
+        && let Some(end) = existing[start..].find(PROFILE_BLOCK_END)
+    {
+        let mut end = start + end + PROFILE_BLOCK_END.len();
+        if existing[end..].starts_with('\n') {
+            end += 1;
+        }
+        return format!("{}{}{}", &existing[..start], block, &existing[end..]);
+    }
+
+    let mut content = existing.to_string();
+    if !content.is_empty() {
+        if !content.ends_with('\n') {
+            content.push('\n');
+        }
+        content.push('\n');
+    }
+    content.push_str(block);
+    content
+}
+
+/// Directories that must be on PATH: the one holding `vx`, then the bin dir
+fn required_path_dirs(paths: &VxPaths) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(exe_dir) = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf))
+    {
+        dirs.push(exe_dir);
+    }
+    if !dirs.iter().any(|d| same_dir(d, &paths.bin_dir)) {
+        dirs.push(paths.bin_dir.clone());
+    }
+    dirs
+}
+
+/// Current gaps for this machine and shell
+fn current_gaps(paths: &VxPaths, shell: &str) -> Vec<SetupGap> {
+    let profile = dirs::home_dir()
+        .and_then(|home| profile_path(shell, &home))
+        .and_then(|p| std::fs::read_to_string(p).ok());
+    detect_gaps(
+        &paths.bin_dir,
+        &required_path_dirs(paths),
+        &std::env::var_os("PATH").unwrap_or_default(),
+        shell,
+        profile.as_deref(),
+        std::env::var_os("VX_SHELL").is_some(),
+    )
+}
+
+/// Whether a command may be interrupted by the first-run offer
+///
+/// Commands that set vx up themselves, print shell code to evaluate, or
+/// report on vx itself are left alone.
+pub fn offers_first_run(command: Option<&Commands>) -> bool {
+    !matches!(
+        command,
+        Some(
+            Commands::Setup(_)
+                | Commands::Onboard { .. }
+                | Commands::Shell { .. }
+                | Commands::Activate { .. }
+                | Commands::Deactivate { .. }
+                | Commands::Hook { .. }
+                | Commands::SelfUpdate(_)
+                | Commands::Version
+                | Commands::Schema { .. }
+        )
+    )
+}
+
+/// Whether the environment rules out the first-run offer
+///
+/// True with `VX_FIRST_RUN=0`, in CI, in portable mode, and for `isolated`
+/// runs, whose VX_HOME is removed when the command finishes.
+pub fn first_run_suppressed(isolated: bool) -> bool {
+    isolated
+        || std::env::var(FIRST_RUN_ENV).is_ok_and(|v| matches!(v.as_str(), "0" | "false"))
+        || vx_runtime::region::is_ci_environment()
+        // Portable copies must not touch shell profiles on the host
+        || vx_paths::portable::is_active()
+}
+
+/// Offer global setup the first time vx runs on a machine that needs it
+///
+/// Only asks in an interactive terminal outside CI and isolated runs, and
+/// never again once answered.
+pub async fn offer_first_run(command: Option<&Commands>, isolated: bool) -> Result<()> {
+    if !offers_first_run(command)
+        || first_run_suppressed(isolated)
+        || !(std::io::stdin().is_terminal()
+            && std::io::stdout().is_terminal()
+            && std::io::stderr().is_terminal())
+    {
+        return Ok(());
+    }
+    let Ok(paths) = VxPaths::new() else {
+        return Ok(());
+    };
+    if GlobalSetup::load(&paths).is_some() {
+        return Ok(());
+    }
+
+    let shell = detect_shell();
+    let gaps = current_gaps(&paths, &shell);
+    if gaps.is_empty() {
+        return Ok(());
+    }
+
+    UI::info("vx is not fully set up on this machine yet:");
+    for gap in &gaps {
+        UI::detail(&gap.describe());
+    }
+    // Ctrl-C or Esc counts as "not now"
+    let accepted = vx_console::confirm("Run the one-time global setup now?", true).unwrap_or(false);
+    if accepted {
+        handle(false, false).await?;
+        println!();
+    } else {
+        GlobalSetup {
+            declined: true,
+            ..Default::default()
+        }
+        .save(&paths)?;
+        UI::hint("Run 'vx setup --global' any time to set it up");
+    }
+    Ok(())
+}
+
+/// Handle `vx setup --global`
+pub async fn handle(yes: bool, dry_run: bool) -> Result<()> {
+    if !yes && !std::io::stdin().is_terminal() {
+        anyhow::bail!("vx setup --global is interactive; use --yes to accept the defaults");
+    }
+
+    let paths = VxPaths::new()?;
+    let home = dirs::home_dir().context("Could not determine home directory")?;
+    let shell = detect_shell();
+    let gaps = current_gaps(&paths, &shell);
+    let mut setup = GlobalSetup::load(&paths).unwrap_or_default();
+
+    UI::header("🌍 vx global setup");
+
+    // 1. Bin directory
+    UI::section("[1/4] Bin directory");
+    if gaps.contains(&SetupGap::BinDirMissing(paths.bin_dir.clone())) {
+        if dry_run {
+            UI::detail(&format!("Would create {}", paths.bin_dir.display()));
+        } else {
+            std::fs::create_dir_all(&paths.bin_dir)
+                .with_context(|| format!("Failed to create {}", paths.bin_dir.display()))?;
+            UI::success(&format!("Created {}", paths.bin_dir.display()));
+        }
+    } else {
+        UI::detail(&format!("✓ {}", paths.bin_dir.display()));
+    }
+
+    // 2 + 3. PATH and shell hook
+    let missing_path: Vec<PathBuf> = gaps
+        .iter()
+        .filter_map(|gap| match gap {
+            SetupGap::NotOnPath(dir) => Some(dir.clone()),
+            _ => None,
+        })
+        .collect();
+    UI::section("[2/4] PATH");
+    if missing_path.is_empty() {
+        UI::detail("✓ vx is on PATH");
+    } else if cfg!(windows) {
+        // New terminals of every kind read the user PATH from the registry
+        if ask("Add vx to your user PATH?", true, yes)? {
+            if dry_run {
+                for dir in &missing_path {
+                    UI::detail(&format!("Would add {} to the user PATH", dir.display()));
+                }
+            } else {
+                persist_windows_path(&missing_path)?;
+                UI::success("Updated the user PATH");
+            }
+        }
+    } else {
+        for dir in &missing_path {
+            UI::detail(&format!(
+                "{} will be added by the shell profile",
+                dir.display()
+            ));
+        }
+    }
+
+    UI::section("[3/4] Shell integration");
+    let profile_dirs: &[PathBuf] = if cfg!(windows) { &[] } else { &missing_path };
+    let hook_missing = gaps
+        .iter()
+        .any(|g| matches!(g, SetupGap::ShellHookMissing { .. }));
+    match (
+        profile_path(&shell, &home),
+        profile_block(&shell, profile_dirs),
+    ) {
+        _ if !hook_missing && profile_dirs.is_empty() => {
+            UI::detail(&format!("✓ vx shell integration is set up for {}", shell));
+        }
+        (Some(profile), Some(block)) => {
+            if ask(
+                &format!("Load vx shell integration from {}?", profile.display()),
+                true,
+                yes,
+            )? {
+                if dry_run {
+                    UI::detail(&format!("Would add to {}:", profile.display()));
+                    for line in block.lines() {
+                        UI::detail(&format!("  {}", line));
+                    }
+                } else {
+                    write_profile_block(&profile, &block)?;
+                    UI::success(&format!("Updated {}", profile.display()));
+                    setup.shell = Some(shell.clone());
+                    setup.profile = Some(profile);
+                }
+            }
+        }
+        _ => {
+            UI::warn(&format!(
+                "vx can't edit the {} profile; add the output of 'vx shell init {}' to it",
+                shell, shell
+            ));
+        }
+    }
+
+    // 4. Preferences
+    UI::section("[4/4] Preferences");
+    let telemetry = ask(
+        "Keep local usage metrics in ~/.vx/metrics for 'vx metrics'? (never uploaded)",
+        setup.telemetry_enabled(),
+        yes,
+    )?;
+    setup.telemetry = Some(telemetry);
+    setup.mirror_region = choose_mirror_region(setup.mirror_region.take(), yes)?;
+    UI::detail(&format!(
+        "Local usage metrics: {}",
+        if telemetry { "kept" } else { "off" }
+    ));
+    UI::detail(&format!(
+        "Download mirrors: {}",
+        setup
+            .mirror_region
+            .as_deref()
+            .unwrap_or("detect automatically")
+    ));
+
+    if dry_run {
+        UI::hint(&format!(
+            "Dry run: nothing was changed; choices would be saved to {}",
+            GlobalSetup::path(&paths).display()
+        ));
+        return Ok(());
+    }
+
+    setup.declined = false;
+    setup.completed_at = Some(chrono::Utc::now().to_rfc3339());
+    setup.save(&paths)?;
+
+    println!();
+    UI::success("Global setup complete");
+    if let Some(profile) = &setup.profile {
+        UI::hint(&format!(
+            "Open a new terminal (or source {}) to pick up the changes",
+            profile.display()
+        ));
+    }
+    Ok(())
+}
+
+/// Ask a yes/no question, or take the default with `--yes`
+fn ask(prompt: &str, default: bool, yes: bool) -> Result<bool> {
+    if yes {
+        return Ok(default);
+    }
+    vx_console::confirm(prompt, default).map_err(|e| match e {
+        vx_console::ConsoleError::Cancelled => {
+            anyhow::anyhow!("Setup interrupted, run 'vx setup --global' again to finish")
+        }
+        other => other.into(),
+    })
+}
+
+fn choose_mirror_region(current: Option<String>, yes: bool) -> Result<Option<String>> {
+    if yes {
+        return Ok(current);
+    }
+    let detected = vx_runtime::region::detect_region();
+    let auto = format!("Detect automatically (currently: {})", detected.as_str());
+    let options = [
+        auto.as_str(),
+        "Global (upstream download hosts)",
+        "China mainland mirrors (cn)",
+    ];
+    let choice = vx_console::select("Default download mirrors", &options).map_err(|e| match e {
+        vx_console::ConsoleError::Cancelled => {
+            anyhow::anyhow!("Setup interrupted, run 'vx setup --global' again to finish")
+        }
+        other => other.into(),
+    })?;
+    Ok(match choice {
+        1 => Some("global".to_string()),
+        2 => Some("cn".to_string()),
+        _ => None,
+    })
+}
+
+fn write_profile_block(profile: &Path, block: &str) -> Result<()> {
+    let existing = std::fs::read_to_string(profile).unwrap_or_default();
+    if let Some(parent) = profile.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    std::fs::write(profile, apply_profile_block(&existing, block))
+        .with_context(|| format!("Failed to write {}", profile.display()))
+}
+
+/// Prepend directories to the user PATH in the Windows registry
+fn persist_windows_path(dirs: &[PathBuf]) -> Result<()> {
+    let prepend = dirs
+        .iter()
+        .map(|d| d.display().to_string().replace('\'', "''"))
+        .collect::<Vec<_>>()
+        .join(";");
+    let script = format!(
+        "$p = [Environment]::GetEnvironmentVariable('PATH', 'User'); \
+         [Environment]::SetEnvironmentVariable('PATH', '{};' + $p, 'User')",
+        prepend
+    );
+    let status = std::process::Command::new("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", &script])
+        .status()
+        .context("Failed to run powershell")?;
+    if !status.success() {
+        anyhow::bail!("Failed to update the user PATH");
+    }
+    Ok(())
+}
+
+/// Apply the recorded mirror region, unless VX_MIRROR_REGION is already set
+pub fn apply_mirror_region_setting(setup: Option<&GlobalSetup>) {
+    if std::env::var_os("VX_MIRROR_REGION").is_some() {
+        return;
+    }
+    if let Some(region) = setup.and_then(|s| s.mirror_region.as_deref()) {
+        // Safety: called before any threads are spawned by this process.
+        #[allow(clippy::disallowed_methods)]
+        unsafe {
+            std::env::set_var("VX_MIRROR_REGION", region);
+        }
+    }
+}
@@ -44,6 +44,8 @@ pub struct GlobalOptions {
     ///
     /// Empty = return all fields. Controlled by `--fields name,version,...`.
     pub fields: Vec<String>,
+    /// Running inside a throwaway VX_HOME (`--isolated` / `VX_ISOLATED=1`).
+    pub isolated: bool,
 }
 
 impl GlobalOptions {
@@ -106,6 +108,12 @@ impl GlobalOptions {
         self
     }
 
+    /// Builder method: set isolated
+    pub fn with_isolated(mut self, value: bool) -> Self {
+        self.isolated = value;
+        self
+    }
+
     /// Check if JSON output is requested
     pub fn is_json(&self) -> bool {
         self.output_format == OutputFormat::Json
@@ -189,6 +197,7 @@ impl CommandContext {
                 output_format: OutputFormat::default(),
                 no_auto_install: false,
                 fields: Vec::new(),
+                isolated: false,
             },
         )
     }
@@ -5,6 +5,7 @@
 //! - enter hook execution for directory changes
 //! - hook installation and status
 
+use crate::commands::common::resolve_hook_tools;
 use crate::ui::UI;
 use anyhow::Result;
 use std::env;
@@ -26,7 +27,9 @@ pub async fn handle_pre_commit() -> Result<()> {
     {
         UI::info("Running pre-commit hook...");
 
-        let executor = HookExecutor::new(&current_dir).verbose(true);
+        let executor = HookExecutor::new(&current_dir)
+            .verbose(true)
+            .tools(resolve_hook_tools(&config, pre_commit)?);
         let result = executor.execute_pre_commit(pre_commit)?;
 
         if !result.success {
@@ -64,7 +67,7 @@ pub async fn handle_enter() -> Result<()> {
     if let Some(hooks) = &config.hooks
         && let Some(enter) = &hooks.enter
     {
-        let executor = HookExecutor::new(&current_dir);
+        let executor = HookExecutor::new(&current_dir).tools(resolve_hook_tools(&config, enter)?);
         let result = executor.execute_enter(enter)?;
 
         if !result.success {
@@ -233,7 +236,9 @@ pub async fn handle_run(name: &str) -> Result<()> {
 
     UI::info(&format!("Running hook '{}'...", name));
 
-    let executor = HookExecutor::new(&current_dir).verbose(true);
+    let executor = HookExecutor::new(&current_dir)
+        .verbose(true)
+        .tools(resolve_hook_tools(&config, hook)?);
     let result = executor.execute(name, hook)?;
 
     if result.success {
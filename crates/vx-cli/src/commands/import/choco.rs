@@ -0,0 +1,265 @@
+//! Chocolatey packages from the community repository
+//!
+//! A `.nupkg` is a zip holding the `.nuspec` metadata and
+//! `tools/chocolateyinstall.ps1`. Packages that download a zip or MSI via
+//! `$url`/`$url64` and packages embedding their executables under `tools/`
+//! are supported; the install script itself is never run.
+
+use super::provider::{ImportSource, ImportedPackage, Installer, InstallerKind, provider_name};
+use anyhow::{Context, Result, bail};
+use std::io::{Cursor, Read};
+
+/// Package download endpoint of the Chocolatey community repository
+pub const CHOCO_PACKAGE_URL: &str = "https://community.chocolatey.org/api/v2/package";
+
+/// URL of the `.nupkg` of `id`, the latest version when `version` is `None`
+pub fn package_url(id: &str, version: Option<&str>) -> String {
+    match version {
+        Some(version) => format!("{}/{}/{}", CHOCO_PACKAGE_URL, id, version),
+        None => format!("{}/{}", CHOCO_PACKAGE_URL, id),
+    }
+}
+
+/// Files of a `.nupkg` relevant to the import
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NupkgContents {
+    pub nuspec: String,
+    /// Content of `tools/chocolateyinstall.ps1`, if present
+    pub install_script: Option<String>,
+    /// Paths of executables under `tools/`
+    pub tool_executables: Vec<String>,
+}
+
+/// Read the relevant files out of a `.nupkg`
+pub fn read_nupkg(bytes: &[u8]) -> Result<NupkgContents> {
+    let mut archive =
+        zip::ZipArchive::new(Cursor::new(bytes)).context("Package is not a valid .nupkg")?;
+    let mut contents = NupkgContents::default();
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i)?;
+        let name = file.name().replace('\\', "/");
+        let lower = name.to_ascii_lowercase();
+        if lower.ends_with(".nuspec") && !lower.contains('/') {
+            file.read_to_string(&mut contents.nuspec)?;
+        } else if lower == "tools/chocolateyinstall.ps1" {
+            let mut script = String::new();
+            file.read_to_string(&mut script)?;
+            contents.install_script = Some(script);
+        } else if lower.starts_with("tools/") && lower.ends_with(".exe") {
+            contents.tool_executables.push(name);
+        }
+    }
+    if contents.nuspec.is_empty() {
+        bail!("Package has no .nuspec");
+    }
+    Ok(contents)
+}
+
+/// Text of the first `<tag>` element of a nuspec, unescaped
+pub fn nuspec_field(nuspec: &str, tag: &str) -> Option<String> {
+    let open = format!("<{}", tag);
+    let mut rest = nuspec;
+    loop {
+        let start = rest.find(&open)?;
+        rest = &rest[start + open.len()..];
+        // Skip longer tags sharing the prefix (`<id` vs `<idx`)
+        if rest.starts_with(['>', ' ', '\t', '\r', '\n']) {
+            break;
+        }
+    }
+    let body = &rest[rest.find('>')? + 1..];
+    let text = body[..body.find(&format!("</{}>", tag))?].trim();
+    (!text.is_empty()).then(|| unescape_xml(text))
+}
+
+fn unescape_xml(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Download URLs and file type declared by `chocolateyinstall.ps1`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InstallScript {
+    pub url: Option<String>,
+    pub url64: Option<String>,
+    pub file_type: Option<String>,
+}
+
+/// Extract `$url`, `$url64`, `fileType` and the values of a package args
+/// hashtable (`url = '...'`) from an install script
+///
+/// `$version`, `${version}` and `$env:ChocolateyPackageVersion` are replaced
+/// by `version`; URLs with other variables are rejected.
+pub fn parse_install_script(script: &str, version: &str) -> Result<InstallScript> {
+    let mut parsed = InstallScript::default();
+    for line in script.lines() {
+        let line = line.trim();
+        if line.starts_with('#') {
+            continue;
+        }
+        let Some((name, value)) = line.split_once('=') else {
+            continue;
+        };
+        let name = name
+            .trim()
+            .trim_start_matches('$')
+            .trim_start_matches("env:")
+            .to_ascii_lowercase();
+        let Some(value) = quoted(value.trim()) else {
+            continue;
+        };
+        let slot = match name.as_str() {
+            "url" => &mut parsed.url,
+            "url64" | "url64bit" => &mut parsed.url64,
+            "filetype" => &mut parsed.file_type,
+            _ => continue,
+        };
+        if slot.is_none() {
+            *slot = Some(value);
+        }
+    }
+
+    for url in [&mut parsed.url, &mut parsed.url64].into_iter().flatten() {
+        let expanded = expand_version(url, version);
+        if expanded.contains('$') {
+            bail!(
+                "Install script builds the download URL from variables vx cannot resolve: {}",
+                url
+            );
+        }
+        *url = expanded;
+    }
+    parsed.file_type = parsed.file_type.map(|t| t.to_ascii_lowercase());
+    Ok(parsed)
+}
+
+/// The content of a leading quoted string, `'a'` or `"a"`
+fn quoted(value: &str) -> Option<String> {
+    let quote = value.chars().next().filter(|c| *c == '\'' || *c == '"')?;
+    let inner = &value[1..];
+    Some(inner[..inner.find(quote)?].to_string())
+}
+
+fn expand_version(value: &str, version: &str) -> String {
+    let mut out = value.to_string();
+    for var in [
+        "$env:ChocolateyPackageVersion",
+        "${env:ChocolateyPackageVersion}",
+        "${version}",
+        "$version",
+    ] {
+        // PowerShell variables are case-insensitive
+        while let Some(pos) = out.to_ascii_lowercase().find(&var.to_ascii_lowercase()) {
+            out.replace_range(pos..pos + var.len(), version);
+        }
+    }
+    out
+}
+
+/// Convert a downloaded package; `nupkg_url` is where it was downloaded from
+pub fn convert(nupkg_url: &str, contents: &NupkgContents) -> Result<ImportedPackage> {
+    let nuspec = &contents.nuspec;
+    let id = nuspec_field(nuspec, "id").context("nuspec has no <id>")?;
+    let version = nuspec_field(nuspec, "version").context("nuspec has no <version>")?;
+    // `git.install` and `git.portable` are variants of `git`
+    let base_id = [".install", ".portable", ".commandline"]
+        .iter()
+        .find_map(|suffix| id.strip_suffix(suffix))
+        .unwrap_or(&id);
+    let name = provider_name(&base_id.replace('.', "-"));
+
+    let script = contents
+        .install_script
+        .as_deref()
+        .map(|s| parse_install_script(s, &version))
+        .transpose()?
+        .unwrap_or_default();
+
+    let mut installers = Vec::new();
+    for (arch, url) in [("x64", &script.url64), ("x86", &script.url)] {
+        let Some(url) = url else { continue };
+        let extension = url
+            .split(['?', '#'])
+            .next()
+            .and_then(|u| u.rsplit_once('.'))
+            .map(|(_, ext)| ext.to_ascii_lowercase());
+        let kind = match script.file_type.as_deref().or(extension.as_deref()) {
+            Some("zip") => InstallerKind::Archive {
+                strip_prefix: None,
+                executable_paths: Vec::new(),
+            },
+            Some("msi") => InstallerKind::Msi,
+            Some(other) => bail!(
+                "Chocolatey package {} downloads a '{}' installer; only zip and msi can be imported",
+                id,
+                other
+            ),
+            None => bail!(
+                "Cannot tell the installer type of {} from its URL {}",
+                id,
+                url
+            ),
+        };
+        installers.push(Installer {
+            arch: arch.to_string(),
+            url: url.clone(),
+            kind,
+        });
+    }
+
+    let mut executable = None;
+    if installers.is_empty() {
+        if contents.tool_executables.is_empty() {
+            bail!(
+                "Chocolatey package {} neither downloads a zip or msi nor embeds executables",
+                id
+            );
+        }
+        // The executables ship inside the package: install the .nupkg itself
+        let paths: Vec<String> = contents
+            .tool_executables
+            .iter()
+            .map(|p| p.trim_start_matches("tools/").to_string())
+            .collect();
+        executable = paths
+            .iter()
+            .map(|p| p.rsplit('/').next().unwrap_or(p).trim_end_matches(".exe"))
+            .find(|stem| stem.eq_ignore_ascii_case(&name))
+            .or_else(|| {
+                paths
+                    .first()
+                    .map(|p| p.rsplit('/').next().unwrap_or(p).trim_end_matches(".exe"))
+            })
+            .map(str::to_string);
+        installers.push(Installer {
+            arch: "neutral".to_string(),
+            url: format!("{}#.zip", nupkg_url),
+            kind: InstallerKind::Archive {
+                strip_prefix: Some("tools".to_string()),
+                executable_paths: paths,
+            },
+        });
+    }
+
+    Ok(ImportedPackage {
+        source: ImportSource::Choco,
+        executable: executable.unwrap_or_else(|| name.clone()),
+        name,
+        version,
+        description: nuspec_field(nuspec, "summary")
+            .or_else(|| nuspec_field(nuspec, "title"))
+            .or_else(|| nuspec_field(nuspec, "description").map(first_line))
+            .unwrap_or_else(|| format!("{} (imported from choco)", id)),
+        homepage: nuspec_field(nuspec, "projectUrl"),
+        license: nuspec_field(nuspec, "licenseUrl"),
+        installers,
+        id,
+    })
+}
+
+fn first_line(text: String) -> String {
+    text.lines().next().unwrap_or_default().trim().to_string()
+}
@@ -0,0 +1,155 @@
+//! Import command implementation
+//!
+//! `vx import winget <id>` and `vx import choco <id>` read a package's
+//! upstream manifest, convert it into a provider.star under
+//! `~/.vx/providers/<name>/` and install the tool through the normal vx
+//! pipeline. Only installers vx can unpack itself are converted (portable
+//! executables, zip archives and MSI packages); setup programs are rejected.
+
+pub mod choco;
+pub mod provider;
+pub mod winget;
+
+pub use provider::{
+    ImportSource, ImportedPackage, Installer, InstallerKind, provider_name, render_provider_star,
+};
+
+use crate::commands::provider::install_star_content;
+use crate::ui::UI;
+use anyhow::{Context, Result, bail};
+use serde_json::Value;
+use std::process::Command;
+
+fn client() -> Result<reqwest::Client> {
+    Ok(vx_proxy::client_builder()
+        .user_agent(format!("vx/{}", env!("CARGO_PKG_VERSION")))
+        .build()?)
+}
+
+async fn fetch(client: &reqwest::Client, url: &str) -> Result<reqwest::Response> {
+    vx_proxy::credentials::authorize(client.get(url), url)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .with_context(|| format!("Failed to fetch {}", url))
+}
+
+/// Split `id@version`
+fn split_spec(spec: &str) -> (&str, Option<&str>) {
+    match spec.split_once('@') {
+        Some((id, version)) if !version.is_empty() => (id, Some(version)),
+        _ => (spec.trim_end_matches('@'), None),
+    }
+}
+
+/// Fetch and convert the winget package `id`
+async fn import_winget(id: &str, version: Option<&str>) -> Result<ImportedPackage> {
+    let client = client()?;
+    let version = match version {
+        Some(version) => version.to_string(),
+        None => {
+            let url = winget::versions_url(id);
+            let entries: Vec<Value> = fetch(&client, &url)
+                .await
+                .with_context(|| format!("winget package '{}' not found", id))?
+                .json()
+                .await?;
+            let versions: Vec<&str> = entries
+                .iter()
+                .filter(|e| e.get("type").and_then(Value::as_str) == Some("dir"))
+                .filter_map(|e| e.get("name").and_then(Value::as_str))
+                .collect();
+            vx_runtime_core::find_latest_version(&versions, true)
+                .or_else(|| vx_runtime_core::find_latest_version(&versions, false))
+                .with_context(|| format!("winget package '{}' has no versions", id))?
+                .to_string()
+        }
+    };
+
+    let installer = fetch(&client, &winget::installer_manifest_url(id, &version))
+        .await
+        .with_context(|| format!("winget package '{}' {} not found", id, version))?
+        .text()
+        .await?;
+    let installer = winget::parse_yaml(&installer).context("Invalid installer manifest")?;
+    // Descriptions are optional, the import works without them
+    let locale = match fetch(&client, &winget::locale_manifest_url(id, &version)).await {
+        Ok(response) => response
+            .text()
+            .await
+            .ok()
+            .and_then(|text| winget::parse_yaml(&text).ok()),
+        Err(_) => None,
+    };
+    winget::convert(id, &installer, locale.as_ref())
+}
+
+/// Fetch and convert the Chocolatey package `id`
+async fn import_choco(id: &str, version: Option<&str>) -> Result<ImportedPackage> {
+    let url = choco::package_url(id, version);
+    let bytes = fetch(&client()?, &url)
+        .await
+        .with_context(|| format!("Chocolatey package '{}' not found", id))?
+        .bytes()
+        .await?;
+    let contents = choco::read_nupkg(&bytes)?;
+    // Pin the package URL to the version actually downloaded
+    let version = choco::nuspec_field(&contents.nuspec, "version").or(version.map(String::from));
+    choco::convert(&choco::package_url(id, version.as_deref()), &contents)
+}
+
+/// Handle `vx import winget` and `vx import choco`
+pub async fn handle(
+    source: ImportSource,
+    spec: &str,
+    name: Option<&str>,
+    force: bool,
+    no_install: bool,
+) -> Result<()> {
+    let (id, version) = split_spec(spec);
+    if id.is_empty() {
+        bail!("Missing package id");
+    }
+
+    UI::info(&format!("Reading {} package {}...", source, id));
+    let mut package = match source {
+        ImportSource::Winget => import_winget(id, version).await?,
+        ImportSource::Choco => import_choco(id, version).await?,
+    };
+    if let Some(name) = name {
+        crate::commands::input_validation::validate_runtime_name(name)?;
+        package.name = name.to_string();
+    }
+
+    let content = render_provider_star(&package);
+    install_star_content(&package.name, &content, force)?;
+    UI::success(&format!(
+        "Imported {} {} as provider '{}'",
+        package.id, package.version, package.name
+    ));
+    if package
+        .installers
+        .iter()
+        .all(|i| i.kind == InstallerKind::Msi)
+    {
+        UI::hint("The package ships as MSI; vx extracts it without running the installer");
+    }
+
+    if no_install {
+        UI::hint(&format!(
+            "Run `vx install {}@{}` to install it",
+            package.name, package.version
+        ));
+        return Ok(());
+    }
+
+    let exe = std::env::current_exe().context("failed to get current exe")?;
+    let status = Command::new(&exe)
+        .args(["install", &format!("{}@{}", package.name, package.version)])
+        .status()
+        .context("failed to run `vx install`")?;
+    if !status.success() {
+        bail!("`vx install` failed with exit code {:?}", status.code());
+    }
+    Ok(())
+}
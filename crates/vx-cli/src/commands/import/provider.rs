@@ -0,0 +1,223 @@
+//! Imported package model and provider.star rendering
+
+use std::fmt;
+
+/// Package manager a package was imported from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportSource {
+    Winget,
+    Choco,
+}
+
+impl fmt::Display for ImportSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ImportSource::Winget => "winget",
+            ImportSource::Choco => "choco",
+        })
+    }
+}
+
+/// How an installer file is installed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstallerKind {
+    /// The download is the executable itself
+    Binary,
+    /// A zip archive; empty `executable_paths` lets vx find the executables
+    Archive {
+        strip_prefix: Option<String>,
+        executable_paths: Vec<String>,
+    },
+    /// An MSI package, extracted with `msiexec /a`
+    Msi,
+}
+
+/// The installer of one architecture
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Installer {
+    /// vx architecture (`x64`, `x86`, `arm64`) or `neutral`
+    pub arch: String,
+    pub url: String,
+    pub kind: InstallerKind,
+}
+
+/// A package converted from a package manager manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedPackage {
+    pub source: ImportSource,
+    /// Package identifier in the source (e.g. `jqlang.jq`)
+    pub id: String,
+    /// Provider and runtime name
+    pub name: String,
+    pub version: String,
+    /// Executable name without `.exe`
+    pub executable: String,
+    pub description: String,
+    pub homepage: Option<String>,
+    pub license: Option<String>,
+    pub installers: Vec<Installer>,
+}
+
+/// Provider name for a package identifier: the last `.` segment, lowercased,
+/// with characters other than letters, digits and `-` replaced by `-`
+pub fn provider_name(id: &str) -> String {
+    let last = id.rsplit('.').next().unwrap_or(id);
+    let name: String = last
+        .to_ascii_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    let name = name.trim_matches('-').to_string();
+    if name.starts_with(|c: char| c.is_ascii_lowercase()) {
+        name
+    } else {
+        format!("pkg-{}", name)
+    }
+}
+
+/// A Starlark string literal
+fn star_str(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| format!("{:?}", value))
+}
+
+/// The generated provider.star
+pub fn render_provider_star(package: &ImportedPackage) -> String {
+    let runtime = if package.executable == package.name {
+        format!("runtime_def({})", star_str(&package.name))
+    } else {
+        format!(
+            "runtime_def({}, executable = {})",
+            star_str(&package.name),
+            star_str(&package.executable)
+        )
+    };
+
+    let mut metadata = vec![
+        format!("name        = {}", star_str(&package.name)),
+        format!("description = {}", star_str(&package.description)),
+    ];
+    if let Some(homepage) = &package.homepage {
+        metadata.push(format!("homepage    = {}", star_str(homepage)));
+    }
+    if let Some(license) = &package.license {
+        metadata.push(format!("license     = {}", star_str(license)));
+    }
+    metadata.push("ecosystem   = \"system\"".to_string());
+
+    let mut installers = String::new();
+    for installer in &package.installers {
+        installers.push_str(&format!("    {}: {{\n", star_str(&installer.arch)));
+        let (kind, extra) = match &installer.kind {
+            InstallerKind::Binary => ("binary", Vec::new()),
+            InstallerKind::Msi => ("msi", Vec::new()),
+            InstallerKind::Archive {
+                strip_prefix,
+                executable_paths,
+            } => {
+                let mut extra = Vec::new();
+                if let Some(prefix) = strip_prefix {
+                    extra.push(format!("\"strip_prefix\": {}", star_str(prefix)));
+                }
+                if !executable_paths.is_empty() {
+                    let paths: Vec<String> = executable_paths.iter().map(|p| star_str(p)).collect();
+                    extra.push(format!("\"executable_paths\": [{}]", paths.join(", ")));
+                }
+                ("archive", extra)
+            }
+        };
+        installers.push_str(&format!("        \"type\": \"{}\",\n", kind));
+        installers.push_str(&format!("        \"url\": {},\n", star_str(&installer.url)));
+        for line in extra {
+            installers.push_str(&format!("        {},\n", line));
+        }
+        installers.push_str("    },\n");
+    }
+
+    PROVIDER_STAR_TEMPLATE
+        .replace("{{name}}", &package.name)
+        .replace("{{source}}", &package.source.to_string())
+        .replace("{{id}}", &package.id)
+        .replace("{{version}}", &package.version)
+        .replace("{{version_str}}", &star_str(&package.version))
+        .replace("{{metadata}}", &metadata.join("\n"))
+        .replace("{{runtime}}", &runtime)
+        .replace("{{installers}}", &installers)
+        .replace(
+            "{{exe_str}}",
+            &star_str(&format!("{}.exe", package.executable)),
+        )
+        .replace(
+            "{{bin_exe_str}}",
+            &star_str(&format!("bin/{}.exe", package.executable)),
+        )
+}
+
+const PROVIDER_STAR_TEMPLATE: &str = r#"# provider.star - {{name}}
+#
+# Imported from {{source}} package {{id}} {{version}} by `vx import {{source}} {{id}}`.
+# Import again to switch versions; changes to this file are overwritten.
+
+load("@vx//stdlib:provider.star", "runtime_def")
+load("@vx//stdlib:install.star", "archive_install", "msi_install")
+
+# ---------------------------------------------------------------------------
+# Provider metadata
+# ---------------------------------------------------------------------------
+{{metadata}}
+
+platforms = {"os": ["windows"]}
+
+runtimes = [{{runtime}}]
+
+# ---------------------------------------------------------------------------
+# Installers by architecture
+# ---------------------------------------------------------------------------
+
+_VERSION = {{version_str}}
+
+_INSTALLERS = {
+{{installers}}}
+
+# Architectures that also run the binaries of others
+_FALLBACK = {
+    "arm64": ["x64", "x86"],
+    "x64":   ["x86"],
+}
+
+def _installer(ctx, version):
+    if ctx.platform.os != "windows" or version != _VERSION:
+        return None
+    for arch in [ctx.platform.arch] + _FALLBACK.get(ctx.platform.arch, []) + ["neutral"]:
+        if arch in _INSTALLERS:
+            return _INSTALLERS[arch]
+    return None
+
+# ---------------------------------------------------------------------------
+# Provider functions
+# ---------------------------------------------------------------------------
+
+def fetch_versions(_ctx):
+    return [{"version": _VERSION, "lts": False, "prerelease": False}]
+
+def download_url(ctx, version):
+    installer = _installer(ctx, version)
+    return installer["url"] if installer else None
+
+def install_layout(ctx, version):
+    installer = _installer(ctx, version)
+    if not installer:
+        return None
+    if installer["type"] == "msi":
+        return msi_install(installer["url"])
+    if installer["type"] == "archive":
+        return archive_install(installer["url"],
+                               strip_prefix     = installer.get("strip_prefix"),
+                               executable_paths = installer.get("executable_paths"))
+    return {
+        "__type":           "binary_install",
+        "url":              installer["url"],
+        "target_name":      {{exe_str}},
+        "target_dir":       "bin",
+        "executable_paths": [{{bin_exe_str}}],
+    }
+"#;
@@ -0,0 +1,379 @@
+//! winget manifests from microsoft/winget-pkgs
+//!
+//! Manifests live at `manifests/<first letter>/<Publisher>/<Package>/<version>/`
+//! with one YAML file for the installers and one per locale. Only the YAML
+//! subset these manifests use is understood: block mappings and sequences,
+//! plain and quoted scalars, flow sequences and block scalars.
+
+use super::provider::{ImportSource, ImportedPackage, Installer, InstallerKind, provider_name};
+use anyhow::{Result, bail};
+use serde_json::{Map, Value};
+
+/// Raw manifest files of the winget-pkgs repository
+pub const WINGET_MANIFESTS_URL: &str =
+    "https://raw.githubusercontent.com/microsoft/winget-pkgs/master/manifests";
+
+/// GitHub contents API of the manifests directory, used to list versions
+pub const WINGET_CONTENTS_URL: &str =
+    "https://api.github.com/repos/microsoft/winget-pkgs/contents/manifests";
+
+/// Manifest directory of a package, e.g. `j/jqlang/jq` for `jqlang.jq`
+pub fn manifest_dir(id: &str) -> String {
+    let first = id
+        .chars()
+        .next()
+        .map(|c| c.to_ascii_lowercase())
+        .unwrap_or('_');
+    format!("{}/{}", first, id.replace('.', "/"))
+}
+
+/// URL of the installer manifest of `id` at `version`
+pub fn installer_manifest_url(id: &str, version: &str) -> String {
+    format!(
+        "{}/{}/{}/{}.installer.yaml",
+        WINGET_MANIFESTS_URL,
+        manifest_dir(id),
+        version,
+        id
+    )
+}
+
+/// URL of the `en-US` locale manifest of `id` at `version`
+pub fn locale_manifest_url(id: &str, version: &str) -> String {
+    format!(
+        "{}/{}/{}/{}.locale.en-US.yaml",
+        WINGET_MANIFESTS_URL,
+        manifest_dir(id),
+        version,
+        id
+    )
+}
+
+/// URL listing the published versions of `id`
+pub fn versions_url(id: &str) -> String {
+    format!("{}/{}", WINGET_CONTENTS_URL, manifest_dir(id))
+}
+
+/// Convert an installer manifest (and optionally its locale manifest)
+pub fn convert(id: &str, installer: &Value, locale: Option<&Value>) -> Result<ImportedPackage> {
+    let version = str_field(installer, "PackageVersion")
+        .ok_or_else(|| anyhow::anyhow!("winget manifest of {} has no PackageVersion", id))?;
+    let name = provider_name(id);
+
+    let mut installers: Vec<Installer> = Vec::new();
+    let mut unsupported: Vec<String> = Vec::new();
+    let mut executable = None;
+    for entry in installer
+        .get("Installers")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        // Fields at the root are defaults for every installer
+        let field = |key: &str| entry.get(key).or_else(|| installer.get(key));
+        let (Some(arch), Some(url)) = (
+            field("Architecture").and_then(Value::as_str),
+            field("InstallerUrl").and_then(Value::as_str),
+        ) else {
+            continue;
+        };
+        let installer_type = field("InstallerType")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let nested_type = field("NestedInstallerType")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let nested_files: Vec<&Value> = field("NestedInstallerFiles")
+            .and_then(Value::as_array)
+            .map(|files| files.iter().collect())
+            .unwrap_or_default();
+        let command = field("Commands")
+            .and_then(Value::as_array)
+            .and_then(|c| c.first())
+            .and_then(Value::as_str);
+
+        let kind = match (installer_type.as_str(), nested_type.as_str()) {
+            ("portable", _) => InstallerKind::Binary,
+            ("zip", "portable") => InstallerKind::Archive {
+                strip_prefix: None,
+                executable_paths: nested_files
+                    .iter()
+                    .filter_map(|f| f.get("RelativeFilePath").and_then(Value::as_str))
+                    .map(|p| p.replace('\\', "/"))
+                    .collect(),
+            },
+            ("msi" | "wix", _) => InstallerKind::Msi,
+            (other, nested) => {
+                unsupported.push(if nested.is_empty() {
+                    other.to_string()
+                } else {
+                    format!("{}/{}", other, nested)
+                });
+                continue;
+            }
+        };
+
+        if executable.is_none() {
+            executable = command
+                .or_else(|| {
+                    nested_files.iter().find_map(|f| {
+                        f.get("PortableCommandAlias")
+                            .and_then(Value::as_str)
+                            .or_else(|| f.get("RelativeFilePath").and_then(Value::as_str))
+                    })
+                })
+                .map(executable_stem)
+                .or_else(|| matches!(kind, InstallerKind::Binary).then(|| executable_stem(url)));
+        }
+
+        let arch = match arch {
+            "x64" | "x86" | "arm64" | "neutral" => arch.to_string(),
+            _ => continue,
+        };
+        match installers.iter().position(|i| i.arch == arch) {
+            // Prefer portable and zip installers over MSI packages
+            Some(pos)
+                if installers[pos].kind == InstallerKind::Msi && kind != InstallerKind::Msi =>
+            {
+                installers[pos] = Installer {
+                    arch,
+                    url: url.to_string(),
+                    kind,
+                };
+            }
+            Some(_) => {}
+            None => installers.push(Installer {
+                arch,
+                url: url.to_string(),
+                kind,
+            }),
+        }
+    }
+
+    if installers.is_empty() {
+        unsupported.sort();
+        unsupported.dedup();
+        bail!(
+            "winget package {} has no installer vx can use (found: {}); supported are portable, zip with portable files, and msi",
+            id,
+            if unsupported.is_empty() {
+                "none".to_string()
+            } else {
+                unsupported.join(", ")
+            }
+        );
+    }
+
+    let locale_field = |key: &str| locale.and_then(|l| str_field(l, key));
+    Ok(ImportedPackage {
+        source: ImportSource::Winget,
+        id: id.to_string(),
+        executable: executable.unwrap_or_else(|| name.clone()),
+        name,
+        version,
+        description: locale_field("ShortDescription")
+            .or_else(|| locale_field("PackageName"))
+            .unwrap_or_else(|| format!("{} (imported from winget)", id)),
+        homepage: locale_field("PackageUrl"),
+        license: locale_field("License"),
+        installers,
+    })
+}
+
+fn str_field(value: &Value, key: &str) -> Option<String> {
+    value.get(key).and_then(Value::as_str).map(str::to_string)
+}
+
+/// `tools\jq.exe` → `jq`
+fn executable_stem(path: &str) -> String {
+    let file = path
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or(path)
+        .split(['?', '#'])
+        .next()
+        .unwrap_or_default();
+    let stem = file
+        .strip_suffix(".exe")
+        .or_else(|| file.strip_suffix(".EXE"))
+        .unwrap_or(file);
+    stem.to_string()
+}
+
+/// Parse a winget manifest into JSON; every scalar becomes a string
+pub fn parse_yaml(source: &str) -> Result<Value> {
+    let mut lines: Vec<(usize, String)> = source
+        .lines()
+        .map(|line| {
+            let content = line.trim_start();
+            (line.len() - content.len(), content.trim_end().to_string())
+        })
+        .collect();
+    let mut pos = 0;
+    skip_blank(&lines, &mut pos);
+    if pos >= lines.len() {
+        return Ok(Value::Object(Map::new()));
+    }
+    let indent = lines[pos].0;
+    parse_block(&mut lines, &mut pos, indent)
+}
+
+fn is_blank(content: &str) -> bool {
+    content.is_empty() || content.starts_with('#') || content == "---"
+}
+
+fn skip_blank(lines: &[(usize, String)], pos: &mut usize) {
+    while *pos < lines.len() && is_blank(&lines[*pos].1) {
+        *pos += 1;
+    }
+}
+
+fn is_sequence_item(content: &str) -> bool {
+    content == "-" || content.starts_with("- ")
+}
+
+fn parse_block(lines: &mut [(usize, String)], pos: &mut usize, indent: usize) -> Result<Value> {
+    skip_blank(lines, pos);
+    if *pos < lines.len() && is_sequence_item(&lines[*pos].1) {
+        parse_sequence(lines, pos, indent)
+    } else {
+        parse_mapping(lines, pos, indent)
+    }
+}
+
+fn parse_sequence(lines: &mut [(usize, String)], pos: &mut usize, indent: usize) -> Result<Value> {
+    let mut items = Vec::new();
+    loop {
+        skip_blank(lines, pos);
+        if *pos >= lines.len() || lines[*pos].0 != indent || !is_sequence_item(&lines[*pos].1) {
+            break;
+        }
+        let rest = lines[*pos].1[1..].trim_start().to_string();
+        if rest.is_empty() {
+            *pos += 1;
+            skip_blank(lines, pos);
+            if *pos < lines.len() && lines[*pos].0 > indent {
+                let child = lines[*pos].0;
+                items.push(parse_block(lines, pos, child)?);
+            } else {
+                items.push(Value::Null);
+            }
+        } else if split_key(&rest).is_some() || is_sequence_item(&rest) {
+            // `- Key: value` starts a mapping indented past the dash
+            let child = indent + (lines[*pos].1.len() - rest.len());
+            lines[*pos] = (child, rest);
+            items.push(parse_block(lines, pos, child)?);
+        } else {
+            items.push(parse_scalar(&rest));
+            *pos += 1;
+        }
+    }
+    Ok(Value::Array(items))
+}
+
+fn parse_mapping(lines: &mut [(usize, String)], pos: &mut usize, indent: usize) -> Result<Value> {
+    let mut map = Map::new();
+    loop {
+        skip_blank(lines, pos);
+        if *pos >= lines.len() || lines[*pos].0 != indent || is_sequence_item(&lines[*pos].1) {
+            break;
+        }
+        let line = lines[*pos].1.clone();
+        let Some((key, value)) = split_key(&line) else {
+            bail!("Unsupported YAML line: {}", line);
+        };
+        *pos += 1;
+
+        let value = if value.starts_with(['|', '>']) {
+            parse_block_scalar(lines, pos, indent, value.starts_with('>'))
+        } else if value.is_empty() {
+            skip_blank(lines, pos);
+            match lines.get(*pos) {
+                Some((child, content))
+                    if *child > indent || (*child == indent && is_sequence_item(content)) =>
+                {
+                    let child = *child;
+                    parse_block(lines, pos, child)?
+                }
+                _ => Value::Null,
+            }
+        } else {
+            parse_scalar(&value)
+        };
+        map.insert(key, value);
+    }
+    Ok(Value::Object(map))
+}
+
+/// The lines of a `|` or `>` scalar below a key at `indent`
+fn parse_block_scalar(
+    lines: &[(usize, String)],
+    pos: &mut usize,
+    indent: usize,
+    folded: bool,
+) -> Value {
+    let mut parts = Vec::new();
+    while *pos < lines.len() && (lines[*pos].1.is_empty() || lines[*pos].0 > indent) {
+        parts.push(lines[*pos].1.clone());
+        *pos += 1;
+    }
+    while parts.last().is_some_and(String::is_empty) {
+        parts.pop();
+    }
+    Value::String(parts.join(if folded { " " } else { "\n" }))
+}
+
+/// Split `Key: value` (the key may be quoted)
+fn split_key(line: &str) -> Option<(String, String)> {
+    let (key, rest) = if let Some(quoted) = line.strip_prefix(['"', '\'']) {
+        let end = quoted.find(line.chars().next()?)?;
+        (quoted[..end].to_string(), &quoted[end + 1..])
+    } else {
+        let end = line
+            .find(": ")
+            .or_else(|| line.ends_with(':').then(|| line.len() - 1))?;
+        let key = &line[..end];
+        if key.contains(' ') || key.starts_with(['[', '{']) {
+            return None;
+        }
+        (key.to_string(), &line[end..])
+    };
+    let value = rest.strip_prefix(':')?;
+    if key.is_empty() || !(value.is_empty() || value.starts_with(' ')) {
+        return None;
+    }
+    Some((key, value.trim().to_string()))
+}
+
+fn parse_scalar(value: &str) -> Value {
+    if let Some(inner) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+        return Value::Array(
+            inner
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(parse_scalar)
+                .collect(),
+        );
+    }
+    if let Some(inner) = value.strip_prefix('\'') {
+        let end = inner.rfind('\'').unwrap_or(inner.len());
+        return Value::String(inner[..end].replace("''", "'"));
+    }
+    if let Some(inner) = value.strip_prefix('"') {
+        let end = inner.rfind('"').unwrap_or(inner.len());
+        return Value::String(
+            inner[..end]
+                .replace("\\\"", "\"")
+                .replace("\\n", "\n")
+                .replace("\\\\", "\\"),
+        );
+    }
+    let plain = value.split(" #").next().unwrap_or(value).trim();
+    if plain.is_empty() || plain == "~" || plain == "null" {
+        return Value::Null;
+    }
+    Value::String(plain.to_string())
+}
@@ -2,6 +2,7 @@
 //
 // Detects project type and generates appropriate vx configuration
 
+use crate::template_registry::{self, TemplateRegistry};
 use crate::ui::UI;
 use anyhow::{Context, Result};
 use std::collections::HashMap;
@@ -91,9 +92,10 @@ pub async fn handle(
     force: bool,
     dry_run: bool,
     list_templates: bool,
+    vars: &[String],
 ) -> Result<()> {
     if list_templates {
-        return list_available_templates();
+        return list_available_templates().await;
     }
 
     let current_dir = std::env::current_dir()
@@ -124,7 +126,10 @@ pub async fn handle(
     let config_content = if interactive {
         generate_interactive_config(existing_vx_config.as_ref()).await?
     } else if let Some(template_name) = template {
-        generate_template_config(&template_name, existing_vx_config.as_ref())?
+        match generate_registry_template(&template_name, vars, &current_dir).await? {
+            Some(content) => content,
+            None => generate_template_config(&template_name, existing_vx_config.as_ref())?,
+        }
     } else if let Some(tools_str) = tools {
         generate_tools_config(&tools_str, existing_vx_config.as_ref())?
     } else {
@@ -195,7 +200,7 @@ fn find_or_create_config_path(dir: &Path) -> (PathBuf, Option<PathBuf>) {
     }
 }
 
-fn list_available_templates() -> Result<()> {
+async fn list_available_templates() -> Result<()> {
     UI::info("Available templates:");
     println!();
     println!("  node           - Node.js project with npm");
@@ -211,11 +216,61 @@ fn list_available_templates() -> Result<()> {
     println!("  fullstack      - Full-stack project (Node.js + Python)");
     println!("  openclaw       - OpenClaw AI agent project with ClawHub skills");
     println!("  minimal        - Minimal configuration");
+
+    match TemplateRegistry::load_configured().await {
+        Ok(Some(registry)) if !registry.index.templates.is_empty() => {
+            println!();
+            UI::info(&format!("Registry templates ({}):", registry.location()));
+            println!();
+            for entry in &registry.index.templates {
+                println!("  {:<14} - {}", entry.name, entry.description);
+            }
+        }
+        Ok(_) => {}
+        Err(e) => UI::warn(&format!("Failed to load template registry: {:#}", e)),
+    }
+
     println!();
-    println!("Usage: vx init --template <template>");
+    println!("Usage: vx init --template <template> [--var NAME=VALUE]");
     Ok(())
 }
 
+/// Render a template from the configured registry.
+///
+/// Returns `None` when no registry is configured or it has no template with
+/// this name, so built-in templates are used instead.
+async fn generate_registry_template(
+    template_name: &str,
+    vars: &[String],
+    project_dir: &Path,
+) -> Result<Option<String>> {
+    let overrides = template_registry::parse_vars(vars)?;
+    let Some(registry) = TemplateRegistry::load_configured().await? else {
+        return Ok(None);
+    };
+    let Some(entry) = registry.index.get(template_name) else {
+        return Ok(None);
+    };
+
+    let mut defaults = entry.variables.clone();
+    if let Some(name) = project_dir.file_name() {
+        defaults
+            .entry("project_name".to_string())
+            .or_insert_with(|| name.to_string_lossy().to_string());
+    }
+
+    let content = registry.fetch_template(entry).await?;
+    let rendered = template_registry::render_template(&content, &defaults, &overrides)?;
+    vx_config::parse_config_str(&rendered).with_context(|| {
+        format!(
+            "Template '{}' from {} is not a valid vx.toml",
+            template_name,
+            registry.location()
+        )
+    })?;
+    Ok(Some(rendered))
+}
+
 async fn generate_interactive_config(existing: Option<&VxConfig>) -> Result<String> {
     UI::header("🚀 VX Project Initialization");
 
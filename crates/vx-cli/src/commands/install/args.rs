@@ -4,14 +4,21 @@ use clap::Args as ClapArgs;
 
 /// Install tool(s) - supports multiple tools at once
 #[derive(ClapArgs, Clone, Debug)]
-#[command(alias = "i")]
 pub struct Args {
     /// Tools to install (e.g., uv, node@22, go@1.22, rust)
-    /// Format: tool or tool@version
-    #[arg(required = true, num_args = 1..)]
+    #[arg(required_unless_present = "from_oci", num_args = 1..)]
     pub tools: Vec<String>,
-
     /// Force reinstallation even if already installed
     #[arg(short, long)]
     pub force: bool,
+    /// Install missing system dependencies without asking
+    #[arg(short = 'y', long)]
+    pub yes: bool,
+    /// Print the install plan (downloads, version switches, vx.lock) without installing
+    #[arg(long, conflicts_with = "from_oci")]
+    pub dry_run: bool,
+    /// Install a pre-built toolchain from an OCI artifact
+    /// (e.g. ghcr.io/org/toolchains:node-20, or oci:<dir>:<tag> for a local layout)
+    #[arg(long, value_name = "REF", conflicts_with = "tools")]
+    pub from_oci: Option<String>,
 }
@@ -1,13 +1,17 @@
 //! Install command handler
 
 use super::Args;
+use super::system_deps::{SystemDepsAction, ensure_system_dependencies};
 use crate::commands::CommandContext;
 use crate::commands::global::{GlobalCommand, InstallGlobalArgs};
+use crate::commands::plan::{ActionPlan, PlannedAction, plan_download};
+use crate::commands::rollback::{Transaction, newest_version};
 use crate::ui::{ProgressSpinner, UI};
 use anyhow::Result;
 use std::env;
 use std::path::PathBuf;
 use vx_paths::project::{LOCK_FILE_NAME, find_vx_config};
+use vx_paths::{ToolChange, TransactionStatus};
 use vx_resolver::{LockFile, LockedTool};
 use vx_runtime::{InstallResult, ProviderRegistry, RuntimeContext};
 use vx_starlark::provider::types::PackageAlias;
@@ -45,12 +49,25 @@ fn find_runtime_on_path(runtime: &dyn vx_runtime::Runtime, tool_name: &str) -> O
 
 /// Handle install command with Args
 pub async fn handle(ctx: &CommandContext, args: &Args) -> Result<()> {
+    let tools = expand_tool_bundles(&args.tools, crate::registry::find_tool_bundle)?;
+    if args.dry_run {
+        return plan(ctx.registry(), ctx.runtime_context(), &tools, args.force)
+            .await?
+            .render(ctx.output_format());
+    }
     let mut success_count = 0;
     let mut fail_count = 0;
-    let total = args.tools.len();
+    let total = tools.len();
     let is_multi = total > 1;
+    let system_deps = if args.yes {
+        SystemDepsAction::Install
+    } else {
+        SystemDepsAction::Prompt
+    };
 
-    for (idx, tool_spec) in args.tools.iter().enumerate() {
+    let mut transaction = Transaction::begin("install");
+
+    for (idx, tool_spec) in tools.iter().enumerate() {
         let (tool_name, version) = parse_tool_spec(tool_spec);
 
         if is_multi {
@@ -58,7 +75,9 @@ pub async fn handle(ctx: &CommandContext, args: &Args) -> Result<()> {
         }
 
         let result = if let Some(alias) = get_package_alias(&tool_name) {
-            install_package_alias(ctx, &tool_name, version.as_deref(), args.force, alias).await
+            install_package_alias(ctx, &tool_name, version.as_deref(), args.force, alias)
+                .await
+                .map(|()| None)
         } else {
             install_single(
                 ctx.registry(),
@@ -66,13 +85,19 @@ pub async fn handle(ctx: &CommandContext, args: &Args) -> Result<()> {
                 &tool_name,
                 version.as_deref(),
                 args.force,
+                system_deps,
                 is_multi,
             )
             .await
         };
 
         match result {
-            Ok(()) => success_count += 1,
+            Ok(change) => {
+                success_count += 1;
+                if let Some(change) = change {
+                    transaction.record(change);
+                }
+            }
             Err(e) => {
                 UI::error(&format!("Failed to install {}: {}", tool_spec, e));
                 fail_count += 1;
@@ -80,6 +105,16 @@ pub async fn handle(ctx: &CommandContext, args: &Args) -> Result<()> {
         }
     }
 
+    transaction.finish(if fail_count == 0 {
+        TransactionStatus::Committed
+    } else {
+        TransactionStatus::Failed
+    });
+
+    if success_count > 0 {
+        crate::cache_policy::auto_prune();
+    }
+
     if is_multi {
         println!();
         if fail_count == 0 {
@@ -99,12 +134,147 @@ pub async fn handle(ctx: &CommandContext, args: &Args) -> Result<()> {
     }
 }
 
+/// Plan what installing `tools` would download, switch and lock
+///
+/// Versions are resolved exactly as a real install resolves them, including
+/// machine policy pins, but nothing is downloaded or written.
+pub async fn plan(
+    registry: &ProviderRegistry,
+    context: &RuntimeContext,
+    tools: &[String],
+    force: bool,
+) -> Result<ActionPlan> {
+    let mut plan = ActionPlan::new("install");
+    let lock_path = find_lock_path().filter(|p| p.exists());
+
+    for tool_spec in tools {
+        let (tool_name, version) = parse_tool_spec(tool_spec);
+        if let Some(alias) = get_package_alias(&tool_name) {
+            plan.push(PlannedAction::Skip {
+                tool: tool_name,
+                version,
+                reason: format!(
+                    "global package; installed with vx global install {}:{}",
+                    alias.ecosystem, alias.package
+                ),
+            });
+            continue;
+        }
+
+        let Some(mut runtime) = registry.get_runtime(&tool_name) else {
+            anyhow::bail!("Tool not found: {}", tool_name);
+        };
+        // Bundled runtimes install their parent
+        if let Some(parent) = runtime.metadata().get("bundled_with").cloned()
+            && parent != tool_name
+        {
+            runtime = registry
+                .get_runtime(&parent)
+                .ok_or_else(|| anyhow::anyhow!("Tool not found: {}", parent))?;
+        }
+        let name = runtime.name().to_string();
+
+        let pinned = crate::commands::common::machine_policy().and_then(|p| p.pin(&name));
+        let requested = version.as_deref().or(pinned).unwrap_or("latest");
+        let target = runtime.resolve_version(requested, context).await?;
+        crate::commands::common::check_install_policy(&name, &target)?;
+
+        if !force && runtime.is_installed(&target, context).await? {
+            plan.push(PlannedAction::Skip {
+                tool: name,
+                version: Some(target),
+                reason: "already installed".to_string(),
+            });
+            continue;
+        }
+
+        plan.push(plan_download(runtime.as_ref(), context, &target).await);
+        let installed = runtime
+            .installed_versions(context)
+            .await
+            .unwrap_or_default();
+        let previous = newest_version(&installed);
+        let mut with_target = installed;
+        with_target.push(target.clone());
+        let normalizer = crate::registry::find_version_normalizer(&name);
+        let is_target = |v: Option<&str>| v.is_some_and(|v| normalizer.same_version(v, &target));
+        if !is_target(previous.as_deref()) && is_target(newest_version(&with_target).as_deref()) {
+            plan.push(PlannedAction::Switch {
+                tool: name.clone(),
+                from: previous,
+                to: target.clone(),
+            });
+        }
+        if let Some(lock_path) = &lock_path {
+            plan.push(PlannedAction::UpdateFile {
+                path: lock_path.clone(),
+                change: format!("lock {} = {}", name, target),
+            });
+        }
+    }
+    Ok(plan)
+}
+
+/// Replace tool bundles (`tool_bundle = [...]` providers such as `devtools`)
+/// with their member tools, keeping the order and dropping duplicates
+///
+/// A bundle has no version of its own: members are pinned individually
+/// (`vx install jq@1.7.1`), so `bundle@version` is rejected.
+pub fn expand_tool_bundles(
+    specs: &[String],
+    lookup: impl Fn(&str) -> Option<Vec<String>>,
+) -> Result<Vec<String>> {
+    let mut tools: Vec<String> = Vec::new();
+    for spec in specs {
+        let (name, version) = parse_tool_spec(spec);
+        let Some(members) = lookup(&name) else {
+            if !tools.contains(spec) {
+                tools.push(spec.clone());
+            }
+            continue;
+        };
+        if let Some(version) = version {
+            anyhow::bail!(
+                "'{}' is a tool bundle and has no version '{}'; pin its tools instead (e.g. vx install {}@<version>)",
+                name,
+                version,
+                members.first().map(String::as_str).unwrap_or("<tool>")
+            );
+        }
+        UI::info(&format!("'{}' bundles: {}", name, members.join(", ")));
+        for member in members {
+            if !tools.contains(&member) {
+                tools.push(member);
+            }
+        }
+    }
+    Ok(tools)
+}
+
 /// Legacy handle function for backwards compatibility
 pub async fn handle_install(
     registry: &ProviderRegistry,
     context: &RuntimeContext,
     tools: &[String],
     force: bool,
+) -> Result<()> {
+    let mut transaction = Transaction::begin("install");
+    let result = install_recorded(registry, context, tools, force, &mut transaction).await;
+    transaction.finish(if result.is_ok() {
+        TransactionStatus::Committed
+    } else {
+        TransactionStatus::Failed
+    });
+    result
+}
+
+/// Install tools, recording every newly installed version in `transaction`
+pub(crate) async fn install_recorded(
+    registry: &ProviderRegistry,
+    context: &RuntimeContext,
+    tools: &[String],
+    force: bool,
+    transaction: &mut Transaction,
 ) -> Result<()> {
     let mut success_count = 0;
     let mut fail_count = 0;
@@ -124,11 +294,17 @@ pub async fn handle_install(
             &tool_name,
             version.as_deref(),
             force,
+            SystemDepsAction::Report,
             is_multi,
         )
         .await
         {
-            Ok(()) => success_count += 1,
+            Ok(change) => {
+                success_count += 1;
+                if let Some(change) = change {
+                    transaction.record(change);
+                }
+            }
             Err(e) => {
                 UI::error(&format!("Failed to install {}: {}", tool_spec, e));
                 fail_count += 1;
@@ -189,20 +365,25 @@ async fn install_package_alias(
     .await
 }
 
+/// Install a single tool.
+///
+/// Returns the journal entry for a newly installed version, `None` when the
+/// version was already installed.
 async fn install_single(
     registry: &ProviderRegistry,
     context: &RuntimeContext,
     tool_name: &str,
     version: Option<&str>,
     force: bool,
+    system_deps: SystemDepsAction,
     is_multi: bool,
-) -> Result<()> {
+) -> Result<Option<ToolChange>> {
     // Get the runtime from registry
     let runtime = match registry.get_runtime(tool_name) {
         Some(r) => r,
         None => {
             // Show friendly error with suggestions
-            let available_tools = registry.runtime_names();
+            let available_tools = crate::registry::registry_index(registry).runtime_names();
             UI::tool_not_found(tool_name, &available_tools);
             return Err(anyhow::anyhow!("Tool not found: {}", tool_name));
         }
@@ -224,13 +405,15 @@ async fn install_single(
             bundled_with,
             version,
             force,
+            system_deps,
             is_multi,
         ))
         .await;
     }
 
-    // Determine version to install
-    let requested_version = version.unwrap_or("latest");
+    // Determine version to install (a machine policy pin replaces "latest")
+    let pinned = crate::commands::common::machine_policy().and_then(|p| p.pin(tool_name));
+    let requested_version = version.or(pinned).unwrap_or("latest");
 
     // Try to load lock file and get download URL
     let mut context_with_cache = context.clone();
@@ -267,6 +450,8 @@ async fn install_single(
         .await?;
     spinner.finish_and_clear();
 
+    crate::commands::common::check_install_policy(tool_name, &target_version)?;
+
     if requested_version != target_version {
         UI::detail(&format!(
             "Resolved {} → {}",
@@ -275,19 +460,37 @@ async fn install_single(
     }
 
     // Check if already installed
-    if !force
-        && runtime
-            .is_installed(&target_version, &context_with_cache)
-            .await?
-    {
+    let was_installed = runtime
+        .is_installed(&target_version, &context_with_cache)
+        .await?;
+    if !force && was_installed {
         UI::success(&format!(
             "{} {} is already installed",
             tool_name, target_version
         ));
-        UI::hint("Use --force to reinstall");
-        return Ok(());
+        // Installing a dependency by name makes it a leaf
+        record_explicit_install(runtime.name(), context);
+        // `--yes` on an installed tool still installs missing system dependencies
+        if system_deps == SystemDepsAction::Install {
+            ensure_system_dependencies(runtime.as_ref(), tool_name, system_deps).await;
+        } else {
+            UI::hint("Use --force to reinstall");
+        }
+        return Ok(None);
     }
 
+    // The version that stays active if this install fails or is rolled back
+    let previous = newest_version(
+        &runtime
+            .installed_versions(&context_with_cache)
+            .await
+            .unwrap_or_default(),
+    )
+    .filter(|v| *v != target_version);
+
+    // Check system prerequisites (VC++ redistributable, system packages, ...)
+    ensure_system_dependencies(runtime.as_ref(), tool_name, system_deps).await;
+
     // Run pre-install hook
     runtime
         .pre_install(&target_version, &context_with_cache)
@@ -323,9 +526,21 @@ async fn install_single(
             }
 
             // Run post-install hook
-            runtime
+            if let Err(e) = runtime
                 .post_install(&target_version, &context_with_cache)
-                .await?;
+                .await
+            {
+                if !was_installed {
+                    discard_failed_install(
+                        runtime.as_ref(),
+                        &target_version,
+                        previous.as_deref(),
+                        context,
+                    )
+                    .await;
+                }
+                return Err(e);
+            }
 
             // Invalidate exec path caches so stale entries are not used
             invalidate_caches_for_runtime(tool_name, context);
@@ -340,6 +555,7 @@ async fn install_single(
                 requested_version,
                 runtime.ecosystem(),
             );
+            record_explicit_install(runtime.name(), context);
 
             // Show usage hint
             if !is_multi {
@@ -356,11 +572,66 @@ async fn install_single(
                     tool_name, target_version, e
                 ));
             }
+            if !was_installed {
+                discard_failed_install(
+                    runtime.as_ref(),
+                    &target_version,
+                    previous.as_deref(),
+                    context,
+                )
+                .await;
+            }
             return Err(e);
         }
     }
 
-    Ok(())
+    Ok((!was_installed).then(|| ToolChange::new(runtime.name(), previous, target_version)))
+}
+
+/// Record that `tool_name` was installed on request (see `vx list --leaves`)
+fn record_explicit_install(tool_name: &str, context: &RuntimeContext) {
+    let state_dir = vx_paths::VxPaths::with_base_dir(context.paths.vx_home()).state_dir;
+    vx_paths::record_install_reason(&state_dir, tool_name, None);
+}
+
+/// Remove whatever a failed install left in the store, so the previously
+/// active version stays in use.
+async fn discard_failed_install(
+    runtime: &dyn vx_runtime::Runtime,
+    version: &str,
+    previous: Option<&str>,
+    context: &RuntimeContext,
+) {
+    let version_dir = context
+        .paths
+        .version_store_dir(runtime.store_name(), version);
+    if !context.fs.exists(&version_dir) {
+        return;
+    }
+    match runtime.uninstall(version, context).await {
+        Ok(()) => {
+            invalidate_caches_for_runtime(runtime.name(), context);
+            match previous {
+                Some(previous) => UI::detail(&format!(
+                    "Removed incomplete {} {}, {} is still active",
+                    runtime.name(),
+                    version,
+                    previous
+                )),
+                None => UI::detail(&format!(
+                    "Removed incomplete {} {}",
+                    runtime.name(),
+                    version
+                )),
+            }
+        }
+        Err(e) => UI::warn(&format!(
+            "Failed to remove incomplete {} {}: {}",
+            runtime.name(),
+            version,
+            e
+        )),
+    }
 }
 
 /// Find the lock file path for the current project, if any.
@@ -407,7 +678,8 @@ fn update_lockfile_if_exists(
     };
 
     // Create locked tool entry
-    let locked_tool = LockedTool::new(version, "vx install")
+    let version = crate::registry::find_version_normalizer(tool_name).normalize(version);
+    let locked_tool = LockedTool::new(&version, "vx install")
         .with_resolved_from(resolved_from)
         .with_ecosystem(resolver_ecosystem);
 
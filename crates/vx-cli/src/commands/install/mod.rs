@@ -4,8 +4,13 @@
 
 mod args;
 mod handler;
+mod system_deps;
 
 pub use args::Args;
+pub use handler::expand_tool_bundles;
 pub use handler::handle;
 pub use handler::handle_install;
 pub use handler::install_quiet;
+pub(crate) use handler::install_recorded;
+pub use handler::plan;
+pub use system_deps::{SystemDepsAction, SystemDepsOutcome, ensure_system_dependencies};
@@ -0,0 +1,187 @@
+//! System dependency checks for `vx install`
+//!
+//! Runtimes can declare system-level prerequisites that vx does not manage
+//! itself, such as the VC++ redistributable or `libssl-dev`. Before a runtime
+//! is installed they are checked with [`SystemDependencyResolver`]; missing
+//! ones are reported and, after confirmation (or with `--yes`), installed via
+//! the preferred system package manager.
+//!
+//! Problems here never fail the tool install: vx warns and continues, since
+//! the tool may still work (or the user may install the dependency later).
+
+use crate::ui::UI;
+use std::io::{IsTerminal, Write};
+use vx_runtime::Runtime;
+use vx_system_pm::{
+    InstallStrategy, ResolvedDependency, SystemDependency, SystemDependencyResolver,
+};
+
+/// What to do with missing system dependencies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemDepsAction {
+    /// Only report missing dependencies
+    Report,
+    /// Ask before installing (reports only when stdin is not a terminal)
+    Prompt,
+    /// Install without asking
+    Install,
+}
+
+/// Result of checking a runtime's system dependencies
+#[derive(Debug, Default)]
+pub struct SystemDepsOutcome {
+    /// Dependencies that were already present
+    pub satisfied: Vec<String>,
+    /// Dependencies installed during this run
+    pub installed: Vec<String>,
+    /// Dependencies that are still missing
+    pub missing: Vec<String>,
+}
+
+/// Check the system dependencies of `runtime`, installing missing ones according to `action`
+pub async fn ensure_system_dependencies(
+    runtime: &dyn Runtime,
+    tool_name: &str,
+    action: SystemDepsAction,
+) -> SystemDepsOutcome {
+    let mut outcome = SystemDepsOutcome::default();
+    let deps = runtime.system_dependencies();
+    if deps.is_empty() {
+        return outcome;
+    }
+
+    let mut resolver = SystemDependencyResolver::new();
+    let resolution = match resolver.resolve(&deps).await {
+        Ok(resolution) => resolution,
+        Err(e) => {
+            UI::warn(&format!(
+                "Could not check system dependencies for {}: {}",
+                tool_name, e
+            ));
+            return outcome;
+        }
+    };
+
+    outcome.satisfied = resolution
+        .satisfied
+        .iter()
+        .map(|r| r.dep.id.clone())
+        .collect();
+
+    if resolution.to_install.is_empty() && resolution.unresolved.is_empty() {
+        return outcome;
+    }
+
+    UI::warn(&format!(
+        "{} needs system dependencies that are not installed:",
+        tool_name
+    ));
+    for unresolved in &resolution.unresolved {
+        UI::item(&describe_dependency(&unresolved.dep));
+        UI::detail(&unresolved.reason);
+        outcome.missing.push(unresolved.dep.id.clone());
+    }
+    for resolved in &resolution.to_install {
+        UI::item(&describe_dependency(&resolved.dep));
+        UI::detail(&describe_strategy(&resolved.strategy));
+    }
+
+    // Only package manager strategies can be run unattended
+    let (installable, manual): (Vec<&ResolvedDependency>, Vec<&ResolvedDependency>) = resolution
+        .to_install
+        .iter()
+        .partition(|r| matches!(r.strategy, InstallStrategy::PackageManager { .. }));
+    outcome
+        .missing
+        .extend(manual.iter().map(|r| r.dep.id.clone()));
+
+    if installable.is_empty() {
+        return outcome;
+    }
+
+    if !confirm_install(installable.len(), action) {
+        UI::hint(&format!(
+            "Install them with 'vx install {} --yes', or manually",
+            tool_name
+        ));
+        outcome
+            .missing
+            .extend(installable.iter().map(|r| r.dep.id.clone()));
+        return outcome;
+    }
+
+    for resolved in installable {
+        match resolver.install(resolved).await {
+            Ok(result) if result.success => {
+                UI::success(&format!("Installed {}", resolved.dep.id));
+                outcome.installed.push(resolved.dep.id.clone());
+            }
+            Ok(result) => {
+                UI::warn(&format!(
+                    "Failed to install {}: {}",
+                    resolved.dep.id,
+                    result.message.as_deref().unwrap_or("unknown error")
+                ));
+                outcome.missing.push(resolved.dep.id.clone());
+            }
+            Err(e) => {
+                UI::warn(&format!("Failed to install {}: {}", resolved.dep.id, e));
+                outcome.missing.push(resolved.dep.id.clone());
+            }
+        }
+    }
+
+    outcome
+}
+
+/// One-line description of a dependency, e.g. `VC++ Redistributable vcredist140 (C++ runtime)`
+fn describe_dependency(dep: &SystemDependency) -> String {
+    let mut text = format!("{} {}", dep.dep_type, dep.id);
+    if let Some(version) = &dep.version {
+        text.push_str(&format!(" {}", version));
+    }
+    if let Some(reason) = &dep.reason {
+        text.push_str(&format!(" ({})", reason));
+    }
+    if dep.optional {
+        text.push_str(" [optional]");
+    }
+    text
+}
+
+/// How a dependency would be installed
+fn describe_strategy(strategy: &InstallStrategy) -> String {
+    match strategy {
+        InstallStrategy::PackageManager {
+            manager, package, ..
+        } => format!("install with {}: {}", manager, package),
+        InstallStrategy::DirectDownload { url, .. } => format!("download manually: {}", url),
+        InstallStrategy::Script { url, .. } => format!("run the installer script: {}", url),
+        InstallStrategy::ProvidedBy { provider, .. } => format!("provided by {}", provider),
+    }
+}
+
+fn confirm_install(count: usize, action: SystemDepsAction) -> bool {
+    match action {
+        SystemDepsAction::Report => false,
+        SystemDepsAction::Install => true,
+        SystemDepsAction::Prompt => {
+            if !std::io::stdin().is_terminal() {
+                return false;
+            }
+            print!(
+                "Install {} system dependenc{} now? [y/N] ",
+                count,
+                if count == 1 { "y" } else { "ies" }
+            );
+            if std::io::stdout().flush().is_err() {
+                return false;
+            }
+            let mut input = String::new();
+            if std::io::stdin().read_line(&mut input).is_err() {
+                return false;
+            }
+            input.trim().eq_ignore_ascii_case("y") || input.trim().eq_ignore_ascii_case("yes")
+        }
+    }
+}
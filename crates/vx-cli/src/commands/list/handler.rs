@@ -22,6 +22,9 @@ fn is_platform_supported(runtime: &Arc<dyn Runtime>, platform: &Platform) -> boo
 
 /// Handle list command with Args
 pub async fn handle(ctx: &CommandContext, args: &Args) -> Result<()> {
+    if args.leaves {
+        return list_leaves(ctx.registry(), ctx.runtime_context(), ctx.output_format()).await;
+    }
     handle_list(
         ctx.registry(),
         ctx.runtime_context(),
@@ -65,6 +68,9 @@ pub async fn handle_list(
             list_all_tools(registry, &resolver, show_status, show_all, format).await?;
         }
     }
+
+    // On stderr, so JSON and TOON output stay parseable
+    crate::eol::warn_project_pins(tool, UI::warn_stderr);
     Ok(())
 }
 
@@ -225,7 +231,7 @@ async fn list_tool_versions(
     // Check if tool is supported
     let runtime = registry.get_runtime(tool_name);
     let Some(runtime) = runtime else {
-        let available_tools = registry.runtime_names();
+        let available_tools = crate::registry::registry_index(registry).runtime_names();
         UI::tool_not_found(tool_name, &available_tools);
         return Ok(());
     };
@@ -254,18 +260,18 @@ async fn list_tool_versions(
             (vec![], None)
         };
 
-    // Get executable paths for each version (for --status display)
-    let version_paths: Vec<(String, Option<std::path::PathBuf>)> = if show_status {
-        versions
-            .iter()
-            .map(|v| {
-                let path = reg.get(canonical_name).and_then(|h| h.get_execute_path(v));
-                (v.clone(), path)
-            })
-            .collect()
-    } else {
-        versions.iter().map(|v| (v.clone(), None)).collect()
-    };
+    // Get executable paths for each version (for --status display); the
+    // store directory keeps the raw name, the listing shows the normalized one
+    let normalizer = crate::registry::find_version_normalizer(canonical_name);
+    let version_paths: Vec<(String, Option<std::path::PathBuf>)> = versions
+        .iter()
+        .map(|v| {
+            let path = show_status
+                .then(|| reg.get(canonical_name).and_then(|h| h.get_execute_path(v)))
+                .flatten();
+            (normalizer.normalize(v), path)
+        })
+        .collect();
 
     drop(reg);
 
@@ -375,7 +381,9 @@ async fn list_all_tools(
     format: OutputFormat,
 ) -> Result<()> {
     let current_platform = Platform::current();
-    let supported_tools = registry.runtime_names();
+    // Names, descriptions and platform support come from the registry index,
+    // so listing doesn't load every provider
+    let index = crate::registry::registry_index(registry);
 
     // ── RFC-0037: ProviderHandle is the single source of truth ───────────
     let reg = global_registry().await;
@@ -383,30 +391,18 @@ async fn list_all_tools(
     let mut installed_count = 0;
     let mut runtimes = Vec::new();
 
-    for tool_name in &supported_tools {
-        let platform_supported = if let Some(ref runtime) = registry.get_runtime(tool_name) {
-            is_platform_supported(runtime, &current_platform)
-        } else {
-            true
-        };
+    for runtime in index.runtimes() {
+        let platform_supported = runtime.platform_supported;
 
         // Skip unsupported platforms unless --all
         if !platform_supported && !show_all {
             continue;
         }
 
-        let runtime = match registry.get_runtime(tool_name) {
-            Some(r) => r,
-            None => continue,
-        };
-
-        let canonical_name = runtime.name();
-        let bundled_with = runtime.metadata().get("bundled_with").cloned();
-
         // Get installed versions from ProviderHandle
-        let versions: Vec<String> = if let Some(handle) = reg.get(canonical_name) {
+        let mut versions: Vec<String> = if let Some(handle) = reg.get(&runtime.name) {
             handle.installed_versions()
-        } else if let Some(parent) = &bundled_with
+        } else if let Some(parent) = &runtime.bundled_with
             && let Some(parent_handle) = reg.get(parent)
         {
             // Bundled tool: show parent's versions
@@ -414,22 +410,25 @@ async fn list_all_tools(
         } else {
             vec![]
         };
+        let normalizer = crate::registry::find_version_normalizer(&runtime.name);
+        for version in &mut versions {
+            *version = normalizer.normalize(version);
+        }
 
         let is_available = !versions.is_empty();
         if is_available {
             installed_count += 1;
         }
 
-        let platform_label = get_runtime_platform_label(tool_name);
-        let ecosystem = runtime.metadata().get("ecosystem").cloned();
+        let platform_label = get_runtime_platform_label(&runtime.name);
 
         runtimes.push(RuntimeEntry {
-            name: tool_name.clone(),
+            name: runtime.name.clone(),
             versions,
             installed: is_available,
-            description: runtime.description().to_string(),
+            description: runtime.description.clone(),
             platform_supported,
-            ecosystem,
+            ecosystem: runtime.ecosystem.clone(),
             platform_label: if !platform_supported || show_all {
                 platform_label
             } else {
@@ -466,3 +465,71 @@ async fn list_all_tools(
 
     Ok(())
 }
+
+/// List installed tools that were not installed as dependencies of other tools
+async fn list_leaves(
+    registry: &ProviderRegistry,
+    context: &RuntimeContext,
+    format: OutputFormat,
+) -> Result<()> {
+    let state_dir = vx_paths::VxPaths::with_base_dir(context.paths.vx_home()).state_dir;
+    let reasons = vx_paths::InstallReasons::load(&state_dir);
+    let current_platform = Platform::current();
+
+    let mut runtimes = Vec::new();
+    for (name, mut versions) in crate::commands::rdeps::installed_tools(registry).await {
+        let Some(runtime) = registry.get_runtime(&name) else {
+            continue;
+        };
+        // Bundled tools come and go with their parent
+        if runtime.metadata().contains_key("bundled_with") || reasons.is_dependency(&name) {
+            continue;
+        }
+        let normalizer = crate::registry::find_version_normalizer(&name);
+        for version in &mut versions {
+            *version = normalizer.normalize(version);
+        }
+        runtimes.push(RuntimeEntry {
+            name,
+            versions,
+            installed: true,
+            description: runtime.description().to_string(),
+            platform_supported: true,
+            ecosystem: runtime.metadata().get("ecosystem").cloned(),
+            platform_label: None,
+        });
+    }
+
+    let renderer = OutputRenderer::new(format);
+    if !renderer.is_text() {
+        let count = runtimes.len();
+        return renderer.render(&ListOutput {
+            runtimes,
+            total: count,
+            installed_count: count,
+            platform: current_platform.as_str().to_string(),
+        });
+    }
+
+    if runtimes.is_empty() {
+        UI::info("No tools installed");
+        return Ok(());
+    }
+    UI::info(&format!(
+        "🌿 Tools installed on request ({})",
+        runtimes.len()
+    ));
+    let width = runtimes.iter().map(|rt| rt.name.len()).max().unwrap_or(0);
+    for rt in &runtimes {
+        println!(
+            "  {:<width$}  {}",
+            rt.name,
+            rt.versions.join(", "),
+            width = width
+        );
+    }
+    UI::hint(
+        "Tools installed as dependencies are hidden; see what needs one with `vx rdeps <tool>`",
+    );
+    Ok(())
+}
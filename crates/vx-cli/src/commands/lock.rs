@@ -5,7 +5,7 @@
 
 use anyhow::{Context, Result};
 use std::collections::{BTreeMap, HashSet};
-use vx_config::{ToolVersion, VxConfig, parse_config};
+use vx_config::{ToolVersion, VxConfig};
 use vx_paths::PathManager;
 use vx_paths::project::{LOCK_FILE_NAME, find_vx_config};
 use vx_resolver::{
@@ -43,7 +43,8 @@ pub async fn handle(
     let lock_path = project_root.join(LOCK_FILE_NAME);
 
     // Load vx.toml
-    let config = parse_config(&config_path)
+    super::common::fetch_remote_presets(&config_path).await?;
+    let config = super::common::load_full_config(&config_path)
         .with_context(|| format!("Failed to load {}", config_path.display()))?;
 
     // Load existing lock file if present
@@ -202,7 +203,7 @@ pub async fn handle(
 }
 
 /// Get version string from ToolVersion
-fn get_version_string(version: &ToolVersion) -> String {
+pub(crate) fn get_version_string(version: &ToolVersion) -> String {
     match version {
         ToolVersion::Simple(s) => s.clone(),
         ToolVersion::Detailed(d) => d.version.clone(),
@@ -356,7 +357,24 @@ fn get_tools_to_resolve(
 }
 
 /// Resolve a single tool's version
-async fn resolve_tool_version(
+///
+/// The locked version is normalized with the provider's `version_format`
+/// rules, so `vx.lock` never records tag prefixes such as `bun-v`.
+pub(crate) async fn resolve_tool_version(
+    registry: &ProviderRegistry,
+    ctx: &RuntimeContext,
+    solver: &VersionSolver,
+    tool_name: &str,
+    version_str: &str,
+    verbose: bool,
+) -> Result<LockedTool> {
+    let mut locked =
+        resolve_locked_tool(registry, ctx, solver, tool_name, version_str, verbose).await?;
+    locked.version = crate::registry::find_version_normalizer(tool_name).normalize(&locked.version);
+    Ok(locked)
+}
+
+async fn resolve_locked_tool(
     registry: &ProviderRegistry,
     ctx: &RuntimeContext,
     solver: &VersionSolver,
@@ -439,14 +457,7 @@ async fn resolve_tool_version(
     }
 
     // Get ecosystem from runtime and convert to vx_resolver::Ecosystem
-    let runtime_ecosystem = runtime.ecosystem();
-    let ecosystem = match runtime_ecosystem {
-        vx_runtime::Ecosystem::NodeJs => Ecosystem::NodeJs,
-        vx_runtime::Ecosystem::Python => Ecosystem::Python,
-        vx_runtime::Ecosystem::Rust => Ecosystem::Rust,
-        vx_runtime::Ecosystem::Go => Ecosystem::Go,
-        _ => Ecosystem::Generic,
-    };
+    let ecosystem = resolver_ecosystem(runtime.ecosystem());
 
     // Check if this tool supports passthrough versions via metadata flag.
     // Note: Rust ecosystem passthrough is now handled by version_info() above.
@@ -543,6 +554,17 @@ async fn resolve_tool_version(
     Ok(locked)
 }
 
+/// Map a runtime ecosystem onto the resolver's version-strategy ecosystem
+pub(crate) fn resolver_ecosystem(ecosystem: vx_runtime::Ecosystem) -> Ecosystem {
+    match ecosystem {
+        vx_runtime::Ecosystem::NodeJs => Ecosystem::NodeJs,
+        vx_runtime::Ecosystem::Python => Ecosystem::Python,
+        vx_runtime::Ecosystem::Rust => Ecosystem::Rust,
+        vx_runtime::Ecosystem::Go => Ecosystem::Go,
+        _ => Ecosystem::Generic,
+    }
+}
+
 /// Try to create a LockedTool entry from a version already installed in the vx store.
 ///
 /// This is a fallback mechanism for when remote version resolution fails but the tool
@@ -665,7 +687,7 @@ pub async fn handle_check(verbose: bool) -> Result<()> {
     let lock_path = project_root.join(LOCK_FILE_NAME);
 
     // Load vx.toml
-    let config = parse_config(&config_path)
+    let config = super::common::load_full_config(&config_path)
         .with_context(|| format!("Failed to load {}", config_path.display()))?;
 
     // Check if lock file exists
@@ -0,0 +1,447 @@
+//! Matrix command implementation
+//!
+//! `vx matrix --tool node=18,20,22 -- npm test` runs a command once per tool
+//! version, like a CI version matrix on the local machine. Several `--tool`
+//! axes expand to every combination. Each run gets an environment built the
+//! same way as `vx exec`: missing versions are installed on demand and
+//! `vx.toml` is never read or modified.
+//!
+//! Runs are sequential by default with the command's output streamed as it
+//! happens; `--parallel` captures each run's output and prints it when the
+//! run finishes. The summary lists exit codes and durations per combination.
+//! With JSON/TOON output the command's stdout goes to stderr, so stdout only
+//! carries the summary.
+
+use crate::commands::CommandContext;
+use crate::commands::exec::resolve_tool;
+use crate::output::{CommandOutput, OutputRenderer};
+use crate::ui::UI;
+use anyhow::{Context, Result, bail};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::time::Instant;
+use vx_env::ToolEnvironment;
+use vx_runtime_core::WithDependency;
+
+/// One `--tool name=v1,v2` axis of the matrix
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatrixAxis {
+    /// Tool name
+    pub tool: String,
+    /// Requested versions, in the order given
+    pub versions: Vec<String>,
+}
+
+/// Parse the `--tool` specs of `vx matrix` (`node=18,20,22` or `node@18,20`)
+pub fn parse_matrix_axes(specs: &[String]) -> Result<Vec<MatrixAxis>> {
+    if specs.is_empty() {
+        bail!("vx matrix requires at least one --tool <tool>=<version>[,<version>...]");
+    }
+    let mut axes: Vec<MatrixAxis> = Vec::with_capacity(specs.len());
+    for spec in specs {
+        let (tool, versions) = spec
+            .split_once('=')
+            .or_else(|| spec.split_once('@'))
+            .with_context(|| {
+                format!(
+                    "Invalid --tool '{}': expected <tool>=<version>[,<version>...]",
+                    spec
+                )
+            })?;
+        let tool = tool.trim();
+        let versions: Vec<String> = versions
+            .split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+            .collect();
+        if tool.is_empty() || versions.is_empty() {
+            bail!(
+                "Invalid --tool '{}': expected <tool>=<version>[,<version>...]",
+                spec
+            );
+        }
+        if axes.iter().any(|axis| axis.tool == tool) {
+            bail!("Tool '{}' is listed more than once in --tool", tool);
+        }
+        axes.push(MatrixAxis {
+            tool: tool.to_string(),
+            versions,
+        });
+    }
+    Ok(axes)
+}
+
+/// Every combination of the axes, first axis varying slowest
+pub fn expand_matrix(axes: &[MatrixAxis]) -> Vec<Vec<WithDependency>> {
+    let mut combinations: Vec<Vec<WithDependency>> = vec![Vec::new()];
+    for axis in axes {
+        combinations = combinations
+            .into_iter()
+            .flat_map(|combination| {
+                axis.versions.iter().map(move |version| {
+                    let mut next = combination.clone();
+                    next.push(WithDependency::new(&axis.tool, Some(version.clone())));
+                    next
+                })
+            })
+            .collect();
+    }
+    combinations
+}
+
+/// Result of one matrix combination
+#[derive(Debug, Clone, Serialize)]
+pub struct MatrixRun {
+    /// Combination label (`node@18 python@3.12`)
+    pub label: String,
+    /// Resolved versions, by tool
+    pub tools: Vec<MatrixTool>,
+    /// Exit code of the command (`None` when it could not be started)
+    pub exit_code: Option<i32>,
+    /// Wall-clock duration in milliseconds
+    pub duration_ms: u64,
+    /// Why the run could not be started
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl MatrixRun {
+    /// Whether the command exited with code 0
+    pub fn passed(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+}
+
+/// A tool version used by a matrix run
+#[derive(Debug, Clone, Serialize)]
+pub struct MatrixTool {
+    /// Tool name
+    pub name: String,
+    /// Requested version (`18`)
+    pub requested: String,
+    /// Installed version it resolved to (`18.20.4`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+/// Summary of `vx matrix`
+#[derive(Debug, Clone, Serialize)]
+pub struct MatrixOutput {
+    /// The command that was run
+    pub command: Vec<String>,
+    /// One entry per combination, in matrix order
+    pub runs: Vec<MatrixRun>,
+    /// Number of passing runs
+    pub passed: usize,
+    /// Number of failing runs
+    pub failed: usize,
+    /// Combinations not run because of `--fail-fast`
+    pub skipped: usize,
+}
+
+impl MatrixOutput {
+    /// Summarize `runs` out of `total` combinations
+    pub fn new(command: &[String], runs: Vec<MatrixRun>, total: usize) -> Self {
+        let passed = runs.iter().filter(|run| run.passed()).count();
+        Self {
+            command: command.to_vec(),
+            failed: runs.len() - passed,
+            skipped: total - runs.len(),
+            passed,
+            runs,
+        }
+    }
+}
+
+impl CommandOutput for MatrixOutput {
+    fn render_text(&self, writer: &mut dyn Write) -> Result<()> {
+        let width = self
+            .runs
+            .iter()
+            .map(|run| run.label.len())
+            .max()
+            .unwrap_or(0)
+            .max("COMBINATION".len());
+        writeln!(writer)?;
+        writeln!(writer, "Matrix: {}", self.command.join(" "))?;
+        writeln!(
+            writer,
+            "  {:<width$}  {:<8}  {:>6}  {:>10}",
+            "COMBINATION", "RESULT", "EXIT", "DURATION"
+        )?;
+        for run in &self.runs {
+            let result = if run.passed() { "✓ pass" } else { "✗ fail" };
+            let exit = run
+                .exit_code
+                .map(|code| code.to_string())
+                .unwrap_or_else(|| "-".to_string());
+            writeln!(
+                writer,
+                "  {:<width$}  {:<8}  {:>6}  {:>10}",
+                run.label,
+                result,
+                exit,
+                format_duration(run.duration_ms)
+            )?;
+            if let Some(error) = &run.error {
+                writeln!(writer, "  {:<width$}  {}", "", error)?;
+            }
+        }
+        writeln!(writer)?;
+        write!(writer, "{} passed, {} failed", self.passed, self.failed)?;
+        if self.skipped > 0 {
+            write!(writer, ", {} skipped", self.skipped)?;
+        }
+        writeln!(writer)?;
+        Ok(())
+    }
+
+    fn render_compact(&self, writer: &mut dyn Write) -> Result<()> {
+        for run in &self.runs {
+            let status = if run.passed() { "ok" } else { "err" };
+            writeln!(
+                writer,
+                "{} {} exit={} {}ms",
+                status,
+                run.label.replace(' ', ","),
+                run.exit_code
+                    .map(|code| code.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                run.duration_ms
+            )?;
+        }
+        writeln!(
+            writer,
+            "{} passed {} failed {} skipped",
+            self.passed, self.failed, self.skipped
+        )?;
+        Ok(())
+    }
+}
+
+fn format_duration(ms: u64) -> String {
+    if ms < 1000 {
+        format!("{}ms", ms)
+    } else if ms < 60_000 {
+        format!("{:.1}s", ms as f64 / 1000.0)
+    } else {
+        format!("{}m{:02}s", ms / 60_000, (ms % 60_000) / 1000)
+    }
+}
+
+fn combination_label(combination: &[WithDependency]) -> String {
+    combination
+        .iter()
+        .map(|tool| format!("{}@{}", tool.runtime, tool.version.as_deref().unwrap_or("")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A combination whose environment is ready to run
+struct PreparedRun {
+    label: String,
+    tools: Vec<MatrixTool>,
+    env: Result<HashMap<String, String>, String>,
+}
+
+/// Handle `vx matrix`
+pub async fn handle(
+    ctx: &CommandContext,
+    tool_specs: &[String],
+    parallel: bool,
+    fail_fast: bool,
+    command: &[String],
+) -> Result<()> {
+    let axes = parse_matrix_axes(tool_specs)?;
+    if command.is_empty() {
+        bail!("No command specified. Usage: vx matrix --tool <tool>=<versions> -- <command>");
+    }
+    let combinations = expand_matrix(&axes);
+    let total = combinations.len();
+
+    // Resolve (and install) every version up front, one at a time, so that
+    // parallel runs never race on the same install
+    let mut prepared = Vec::with_capacity(total);
+    for combination in &combinations {
+        prepared.push(prepare_run(ctx, combination).await);
+    }
+
+    let renderer = OutputRenderer::new(ctx.output_format());
+    let quiet_stdout = renderer.is_structured();
+    let runs = if parallel {
+        run_parallel(prepared, command, quiet_stdout)
+    } else {
+        run_sequential(prepared, command, fail_fast, quiet_stdout)
+    };
+
+    let output = MatrixOutput::new(command, runs, total);
+    renderer.render(&output)?;
+    if output.failed > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+async fn prepare_run(ctx: &CommandContext, combination: &[WithDependency]) -> PreparedRun {
+    let label = combination_label(combination);
+    let mut tools = Vec::with_capacity(combination.len());
+    let mut specs = Vec::with_capacity(combination.len());
+    let mut error = None;
+    for tool in combination {
+        let requested = tool.version.clone().unwrap_or_default();
+        match resolve_tool(ctx, tool).await {
+            Ok(spec) => {
+                tools.push(MatrixTool {
+                    name: tool.runtime.clone(),
+                    requested,
+                    version: Some(spec.version.clone()),
+                });
+                specs.push(spec);
+            }
+            Err(e) => {
+                tools.push(MatrixTool {
+                    name: tool.runtime.clone(),
+                    requested,
+                    version: None,
+                });
+                error.get_or_insert_with(|| e.to_string());
+            }
+        }
+    }
+
+    let env = match error {
+        Some(error) => Err(error),
+        None => ToolEnvironment::new()
+            .tools_from_specs(specs)
+            .include_vx_bin(true)
+            .inherit_path(true)
+            .warn_missing(false)
+            .build()
+            .map_err(|e| format!("Failed to build environment: {}", e)),
+    };
+    PreparedRun { label, tools, env }
+}
+
+/// Build the process for one run, looking the program up on the run's PATH
+fn build_command(command: &[String], env: &HashMap<String, String>) -> Result<Command, String> {
+    let (program, args) = command.split_first().ok_or("No command specified")?;
+    let cwd = std::env::current_dir().map_err(|e| e.to_string())?;
+    let program_path = which::which_in(program, env.get("PATH"), &cwd)
+        .map_err(|_| format!("Command '{}' not found in matrix environment", program))?;
+    let mut cmd = Command::new(program_path);
+    cmd.args(args).envs(env);
+    Ok(cmd)
+}
+
+fn failed_run(label: String, tools: Vec<MatrixTool>, error: String) -> MatrixRun {
+    MatrixRun {
+        label,
+        tools,
+        exit_code: None,
+        duration_ms: 0,
+        error: Some(error),
+    }
+}
+
+fn run_sequential(
+    prepared: Vec<PreparedRun>,
+    command: &[String],
+    fail_fast: bool,
+    quiet_stdout: bool,
+) -> Vec<MatrixRun> {
+    let mut runs = Vec::with_capacity(prepared.len());
+    for PreparedRun { label, tools, env } in prepared {
+        UI::section(&label);
+        let run = match env.and_then(|env| build_command(command, &env)) {
+            Err(error) => failed_run(label, tools, error),
+            Ok(mut cmd) => {
+                let started = Instant::now();
+                let status = cmd
+                    .stdin(Stdio::inherit())
+                    .stdout(if quiet_stdout {
+                        Stdio::from(std::io::stderr())
+                    } else {
+                        Stdio::inherit()
+                    })
+                    .stderr(Stdio::inherit())
+                    .status();
+                let duration_ms = started.elapsed().as_millis() as u64;
+                match status {
+                    Ok(status) => MatrixRun {
+                        label,
+                        tools,
+                        exit_code: Some(status.code().unwrap_or(1)),
+                        duration_ms,
+                        error: None,
+                    },
+                    Err(e) => failed_run(label, tools, e.to_string()),
+                }
+            }
+        };
+        let stop = fail_fast && !run.passed();
+        runs.push(run);
+        if stop {
+            break;
+        }
+    }
+    runs
+}
+
+fn run_parallel(
+    prepared: Vec<PreparedRun>,
+    command: &[String],
+    quiet_stdout: bool,
+) -> Vec<MatrixRun> {
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = prepared
+            .into_iter()
+            .map(|PreparedRun { label, tools, env }| {
+                scope.spawn(move || {
+                    let mut cmd = match env.and_then(|env| build_command(command, &env)) {
+                        Ok(cmd) => cmd,
+                        Err(error) => return failed_run(label, tools, error),
+                    };
+                    let started = Instant::now();
+                    let output = cmd.stdin(Stdio::null()).output();
+                    let duration_ms = started.elapsed().as_millis() as u64;
+                    match output {
+                        Ok(output) => {
+                            print_captured(&label, &output.stdout, &output.stderr, quiet_stdout);
+                            MatrixRun {
+                                label,
+                                tools,
+                                exit_code: Some(output.status.code().unwrap_or(1)),
+                                duration_ms,
+                                error: None,
+                            }
+                        }
+                        Err(e) => failed_run(label, tools, e.to_string()),
+                    }
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("matrix run panicked"))
+            .collect()
+    })
+}
+
+/// Print a finished parallel run's output as one block
+fn print_captured(label: &str, stdout: &[u8], stderr: &[u8], quiet_stdout: bool) {
+    // Hold both locks so blocks of runs finishing together do not interleave
+    let mut out = std::io::stdout().lock();
+    let mut err = std::io::stderr().lock();
+    let _ = writeln!(err, "── {} ──", label);
+    if quiet_stdout {
+        let _ = err.write_all(stdout);
+    } else {
+        let _ = out.write_all(stdout);
+        let _ = out.flush();
+    }
+    let _ = err.write_all(stderr);
+    let _ = err.flush();
+}
@@ -78,6 +78,73 @@ pub async fn handle(last: usize, json: bool, html: Option<String>, clean: bool)
     Ok(())
 }
 
+/// Handle `vx metrics insights`.
+pub async fn handle_insights(last: usize, json: bool) -> Result<()> {
+    let metrics_dir = vx_paths::VxPaths::default().base_dir.join("metrics");
+    let runs = if metrics_dir.exists() {
+        vx_metrics::load_metrics(&metrics_dir, last)?
+    } else {
+        Vec::new()
+    };
+    let budgets = project_stage_budgets();
+    let warnings = vx_metrics::check_budgets(&runs, &budgets);
+
+    if json {
+        let output = serde_json::json!({
+            "runs": runs.len(),
+            "budgets": {
+                "resolve_ms": budgets.resolve_ms,
+                "ensure_ms": budgets.ensure_ms,
+                "prepare_ms": budgets.prepare_ms,
+            },
+            "warnings": warnings,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    if runs.is_empty() {
+        println!("No metrics data found. Run a command to generate metrics.");
+        return Ok(());
+    }
+
+    print!("{}", vx_metrics::render_insights(&runs));
+    if warnings.is_empty() {
+        println!(
+            "\n  All stages within budget over the last {} run(s) (resolve {:.0}ms, ensure {:.0}ms, prepare {:.0}ms)",
+            runs.len(),
+            budgets.resolve_ms,
+            budgets.ensure_ms,
+            budgets.prepare_ms
+        );
+    } else {
+        print!("{}", vx_metrics::render_budget_warnings(&warnings));
+    }
+
+    Ok(())
+}
+
+/// Stage budgets from `[telemetry.budgets]` in vx.toml, over the defaults
+pub fn project_stage_budgets() -> vx_metrics::StageBudgets {
+    let mut budgets = vx_metrics::StageBudgets::default();
+    let Some(config) = crate::commands::common::load_full_config_cwd()
+        .ok()
+        .and_then(|(_, config)| config.telemetry?.budgets)
+    else {
+        return budgets;
+    };
+    if let Some(ms) = config.resolve {
+        budgets.resolve_ms = ms as f64;
+    }
+    if let Some(ms) = config.ensure {
+        budgets.ensure_ms = ms as f64;
+    }
+    if let Some(ms) = config.prepare {
+        budgets.prepare_ms = ms as f64;
+    }
+    budgets
+}
+
 /// Handle `vx metrics tokens`.
 pub async fn handle_tokens(last: usize, json: bool) -> Result<()> {
     let metrics_dir = vx_paths::VxPaths::default().base_dir.join("metrics");
@@ -102,6 +169,21 @@ pub async fn handle_tokens(last: usize, json: bool) -> Result<()> {
     Ok(())
 }
 
+/// Handle `vx metrics shim`.
+pub async fn handle_shim(last: usize, json: bool) -> Result<()> {
+    let metrics_dir = vx_paths::VxPaths::default().base_dir.join("metrics");
+    let invocations = vx_metrics::load_shim_invocations(&metrics_dir, last);
+    let summary = vx_metrics::summarize_shim_latency(&invocations);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&summary)?);
+    } else {
+        print!("{}", vx_metrics::render_shim_latency(&summary));
+    }
+
+    Ok(())
+}
+
 async fn handle_clean(metrics_dir: &std::path::Path) -> Result<()> {
     if !metrics_dir.exists() {
         println!("No metrics directory to clean.");
@@ -36,6 +36,9 @@ pub mod schema;
 /// Global package management - RFC 0025
 pub mod global;
 
+/// Import command - winget and Chocolatey packages as providers
+pub mod import;
+
 /// Install command - modular structure
 pub mod install;
 
@@ -49,39 +52,63 @@ pub mod test;
 // Core Commands
 // =============================================================================
 
+pub mod activate;
 pub mod add;
 pub mod ai;
 pub mod analyze;
+pub mod android;
 pub mod auth;
+pub mod bootstrap;
 pub mod bundle;
 pub mod cache;
 pub mod capabilities;
+pub mod changelog;
 pub mod check;
+pub mod ci;
 pub mod config;
 pub mod container;
+pub mod dashboard;
 pub mod dev;
+pub mod doctor;
 pub mod env;
+pub mod exec;
 pub mod execute;
 #[cfg(test)]
 mod execute_tests;
+pub mod export;
 pub mod ext;
 pub mod fetch;
+pub mod global_setup;
 pub mod hook;
 pub mod init;
 pub mod lock;
+pub mod matrix;
 pub mod metrics;
 pub mod migrate;
+pub mod onboard;
+pub mod outdated;
+pub mod plan;
+pub mod preset;
 pub mod provider;
+pub mod provider_scaffold;
+pub mod rdeps;
 pub mod remove;
+pub mod rollback;
+pub mod rpc;
 pub mod run;
+pub mod sbom;
 pub mod search;
 pub mod self_update;
 pub mod services;
 pub mod setup;
 pub mod shell;
+pub mod stats;
+pub mod store;
 pub mod sync;
+pub mod update;
 pub mod version;
 pub mod where_cmd;
+pub mod wrap;
 
 // Re-export vx_env functions for backwards compatibility
 pub use vx_env::{execute_with_env, generate_wrapper_script};
@@ -0,0 +1,381 @@
+//! Onboard command implementation
+//!
+//! `vx onboard` walks a new contributor through getting a project running:
+//!
+//! 1. **Trust** – review the commands vx.toml will run (hooks, services, scripts)
+//! 2. **Tools** – install the toolchain (`vx setup`)
+//! 3. **Hooks** – install the git pre-commit hook (`vx hook install`)
+//! 4. **Services** – start the declared services (`vx services start`)
+//! 5. **Verify** – check that the machine is ready (`vx check`)
+//!
+//! Progress is saved to `.vx/onboard.json` after every step, so an
+//! interrupted run resumes where it stopped. Editing vx.toml invalidates the
+//! saved progress, because the trusted configuration changed.
+
+use crate::cli::OutputFormat;
+use crate::commands::setup::{ConfigView, find_vx_config, parse_vx_config_full};
+use crate::ui::UI;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use vx_config::{GitHookInstaller, HookCommand, VxConfig};
+use vx_runtime::ProviderRegistry;
+
+/// File name of the progress record inside the project's `.vx` directory
+pub const ONBOARD_PROGRESS_FILE: &str = "onboard.json";
+
+/// A step of the onboarding wizard
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OnboardStep {
+    Trust,
+    Tools,
+    Hooks,
+    Services,
+    Verify,
+}
+
+impl OnboardStep {
+    /// All steps, in the order they run
+    pub const ALL: [OnboardStep; 5] = [
+        OnboardStep::Trust,
+        OnboardStep::Tools,
+        OnboardStep::Hooks,
+        OnboardStep::Services,
+        OnboardStep::Verify,
+    ];
+
+    /// Human-readable step title
+    pub fn title(&self) -> &'static str {
+        match self {
+            OnboardStep::Trust => "Trust the project",
+            OnboardStep::Tools => "Install the toolchain",
+            OnboardStep::Hooks => "Set up git hooks",
+            OnboardStep::Services => "Start services",
+            OnboardStep::Verify => "Verify the environment",
+        }
+    }
+}
+
+/// Saved onboarding progress for a project
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OnboardProgress {
+    /// Hash of the vx.toml the progress applies to
+    #[serde(default)]
+    pub config_hash: String,
+    /// Steps that finished successfully
+    #[serde(default)]
+    pub completed: Vec<OnboardStep>,
+    /// Steps that were skipped
+    #[serde(default)]
+    pub skipped: Vec<OnboardStep>,
+    /// Last update timestamp (ISO 8601)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl OnboardProgress {
+    /// Path of the progress file for a project root
+    pub fn path(project_root: &Path) -> PathBuf {
+        project_root.join(".vx").join(ONBOARD_PROGRESS_FILE)
+    }
+
+    /// Load saved progress; a missing or invalid file starts over.
+    pub fn load(project_root: &Path) -> Self {
+        std::fs::read_to_string(Self::path(project_root))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write progress back to the project's `.vx` directory
+    pub fn save(&self, project_root: &Path) -> Result<()> {
+        let path = Self::path(project_root);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(&path, content)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Tie progress to a vx.toml, discarding it when the file changed since.
+    ///
+    /// Returns `true` when earlier progress was discarded.
+    pub fn sync_config(&mut self, config_hash: &str) -> bool {
+        if self.config_hash == config_hash {
+            return false;
+        }
+        let had_progress = !self.completed.is_empty() || !self.skipped.is_empty();
+        *self = Self {
+            config_hash: config_hash.to_string(),
+            ..Self::default()
+        };
+        had_progress
+    }
+
+    /// Whether a step is completed or skipped
+    pub fn is_finished(&self, step: OnboardStep) -> bool {
+        self.completed.contains(&step) || self.skipped.contains(&step)
+    }
+
+    /// The first step that still has to run
+    pub fn next_step(&self) -> Option<OnboardStep> {
+        OnboardStep::ALL.into_iter().find(|s| !self.is_finished(*s))
+    }
+
+    /// Record a step outcome
+    pub fn record(&mut self, step: OnboardStep, outcome: StepOutcome) {
+        self.completed.retain(|s| *s != step);
+        self.skipped.retain(|s| *s != step);
+        match outcome {
+            StepOutcome::Done => self.completed.push(step),
+            StepOutcome::Skipped => self.skipped.push(step),
+        }
+        self.updated_at = Some(chrono::Utc::now().to_rfc3339());
+    }
+}
+
+/// Result of running a step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepOutcome {
+    Done,
+    Skipped,
+}
+
+/// Hash of a vx.toml's content
+pub fn config_hash(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .fold(String::with_capacity(64), |mut acc, b| {
+            use std::fmt::Write;
+            let _ = write!(acc, "{b:02x}");
+            acc
+        })
+}
+
+/// Commands a project runs on this machine, for the trust prompt
+pub fn trust_summary(config: &VxConfig) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    if let Some(ref hooks) = config.hooks {
+        let named = [
+            ("pre_setup", &hooks.pre_setup),
+            ("post_setup", &hooks.post_setup),
+            ("pre_commit", &hooks.pre_commit),
+            ("enter", &hooks.enter),
+        ];
+        for (name, hook) in named {
+            if let Some(hook) = hook {
+                lines.push(format!("{} hook: {}", name, hook_commands(hook)));
+            }
+        }
+        let mut custom: Vec<_> = hooks.custom.iter().collect();
+        custom.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, hook) in custom {
+            lines.push(format!("{} hook: {}", name, hook_commands(hook)));
+        }
+    }
+
+    let mut services: Vec<_> = config.services.iter().collect();
+    services.sort_by(|a, b| a.0.cmp(b.0));
+    for (name, service) in services {
+        let what = match (&service.image, &service.command) {
+            (Some(image), _) => format!("image {}", image),
+            (None, Some(command)) => command.clone(),
+            (None, None) => "no image or command".to_string(),
+        };
+        lines.push(format!("service {}: {}", name, what));
+    }
+
+    if !config.scripts.is_empty() {
+        lines.push(format!(
+            "{} script{} runnable with `vx run`",
+            config.scripts.len(),
+            if config.scripts.len() == 1 { "" } else { "s" }
+        ));
+    }
+
+    lines
+}
+
+fn hook_commands(hook: &HookCommand) -> String {
+    match hook {
+        HookCommand::Single(command) => command.clone(),
+        HookCommand::Multiple(commands) => commands.join(" && "),
+    }
+}
+
+/// Handle `vx onboard`
+pub async fn handle(registry: &ProviderRegistry, yes: bool, restart: bool) -> Result<()> {
+    if !yes && !std::io::stdin().is_terminal() {
+        anyhow::bail!("vx onboard is interactive; use --yes to accept every step");
+    }
+
+    let current_dir = std::env::current_dir().context("Failed to get current directory")?;
+    let config_path = find_vx_config(&current_dir)?;
+    let project_root = config_path.parent().unwrap_or(&current_dir).to_path_buf();
+    let content = std::fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read {}", config_path.display()))?;
+    let config = parse_vx_config_full(&config_path)?;
+    let view = ConfigView::from(config.clone());
+
+    let mut progress = if restart {
+        OnboardProgress::default()
+    } else {
+        OnboardProgress::load(&project_root)
+    };
+    if progress.sync_config(&config_hash(&content)) {
+        UI::warn("vx.toml changed since the last run, starting over");
+    }
+
+    UI::header(&format!("👋 Welcome to {}", view.project_name));
+    if progress.next_step().is_none() {
+        UI::success("Onboarding is already complete");
+        UI::hint("Run 'vx onboard --restart' to go through it again");
+        return Ok(());
+    }
+    if progress.next_step() != Some(OnboardStep::Trust) {
+        UI::info("Resuming where you left off");
+    }
+
+    let total = OnboardStep::ALL.len();
+    for (i, step) in OnboardStep::ALL.into_iter().enumerate() {
+        let label = format!("[{}/{}] {}", i + 1, total, step.title());
+        if progress.is_finished(step) {
+            UI::detail(&format!("✓ {}", label));
+            continue;
+        }
+
+        println!();
+        UI::section(&label);
+        let outcome = match step {
+            OnboardStep::Trust => trust(&config, yes)?,
+            OnboardStep::Tools => install_tools(registry, &view, yes).await?,
+            OnboardStep::Hooks => install_hooks(&config, &project_root, yes).await?,
+            OnboardStep::Services => start_services(&config, yes).await?,
+            OnboardStep::Verify => {
+                // `vx check` exits with a non-zero status when something is
+                // missing, leaving this step to run again on the next attempt
+                super::check::handle(registry, None, false, false, false, OutputFormat::Text)
+                    .await?;
+                StepOutcome::Done
+            }
+        };
+
+        progress.record(step, outcome);
+        progress.save(&project_root)?;
+    }
+
+    println!();
+    UI::success(&format!("{} is ready for development", view.project_name));
+    if !progress.skipped.is_empty() {
+        let skipped: Vec<&str> = progress.skipped.iter().map(|s| s.title()).collect();
+        UI::hint(&format!(
+            "Skipped: {} (run 'vx onboard --restart' to revisit)",
+            skipped.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+/// Ask a yes/no question, or accept it with `--yes`
+fn ask(prompt: &str, default: bool, yes: bool) -> Result<bool> {
+    if yes {
+        return Ok(true);
+    }
+    vx_console::confirm(prompt, default).map_err(|e| match e {
+        vx_console::ConsoleError::Cancelled => {
+            anyhow::anyhow!("Onboarding interrupted, run 'vx onboard' again to resume")
+        }
+        other => other.into(),
+    })
+}
+
+fn trust(config: &VxConfig, yes: bool) -> Result<StepOutcome> {
+    let summary = trust_summary(config);
+    if summary.is_empty() {
+        UI::info("This project declares no hooks, services or scripts");
+    } else {
+        UI::info("This project runs the following on your machine:");
+        for line in &summary {
+            UI::item(line);
+        }
+    }
+
+    if !ask("Trust this project and continue?", false, yes)? {
+        anyhow::bail!("Onboarding stopped: the project is not trusted");
+    }
+    Ok(StepOutcome::Done)
+}
+
+async fn install_tools(
+    registry: &ProviderRegistry,
+    view: &ConfigView,
+    yes: bool,
+) -> Result<StepOutcome> {
+    if view.tools.is_empty() {
+        UI::detail("No tools declared in vx.toml");
+        return Ok(StepOutcome::Skipped);
+    }
+    let tools: Vec<String> = view
+        .tools_as_btreemap()
+        .into_iter()
+        .map(|(name, version)| format!("{}@{}", name, version))
+        .collect();
+    UI::info(&format!("Tools: {}", tools.join(", ")));
+
+    if !ask(&format!("Install {} tools?", tools.len()), true, yes)? {
+        return Ok(StepOutcome::Skipped);
+    }
+    super::setup::handle(registry, false, false, false, false, false, false).await?;
+    Ok(StepOutcome::Done)
+}
+
+async fn install_hooks(config: &VxConfig, project_root: &Path, yes: bool) -> Result<StepOutcome> {
+    if config
+        .hooks
+        .as_ref()
+        .and_then(|h| h.pre_commit.as_ref())
+        .is_none()
+    {
+        UI::detail("No pre_commit hook declared in vx.toml");
+        return Ok(StepOutcome::Skipped);
+    }
+    let Some(repo_root) = GitHookInstaller::find_repo_root(project_root) else {
+        UI::detail("Not a git repository");
+        return Ok(StepOutcome::Skipped);
+    };
+    if GitHookInstaller::new(&repo_root).is_installed() {
+        UI::detail("Git hooks are already installed");
+        return Ok(StepOutcome::Done);
+    }
+
+    if !ask("Install the git pre-commit hook?", true, yes)? {
+        return Ok(StepOutcome::Skipped);
+    }
+    super::hook::handle_install(false).await?;
+    Ok(StepOutcome::Done)
+}
+
+async fn start_services(config: &VxConfig, yes: bool) -> Result<StepOutcome> {
+    if config.services.is_empty() {
+        UI::detail("No services declared in vx.toml");
+        return Ok(StepOutcome::Skipped);
+    }
+    let mut names: Vec<&str> = config.services.keys().map(String::as_str).collect();
+    names.sort();
+
+    if !ask(
+        &format!("Start {} services ({})?", names.len(), names.join(", ")),
+        true,
+        yes,
+    )? {
+        return Ok(StepOutcome::Skipped);
+    }
+    super::services::handle_start(None, true, false, false, false).await?;
+    Ok(StepOutcome::Done)
+}
@@ -0,0 +1,58 @@
+//! Outdated command implementation
+//!
+//! `vx outdated` reports the project tools that have newer versions within
+//! their vx.toml constraints, like `vx update --all`. With `--eol` it lists
+//! the tools pinned to an end-of-life version instead, using the schedules
+//! providers declare, so no network access is needed.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! # Report available updates for every tool in vx.toml
+//! vx outdated
+//!
+//! # Only tools pinned to an end-of-life version
+//! vx outdated --eol
+//! ```
+
+use super::update::UpdateOptions;
+use crate::ui::UI;
+use anyhow::Result;
+use vx_paths::project::{LOCK_FILE_NAME, find_vx_config};
+use vx_runtime::{ProviderRegistry, RuntimeContext};
+
+/// Handle the outdated command
+pub async fn handle(registry: &ProviderRegistry, ctx: &RuntimeContext, eol: bool) -> Result<()> {
+    if !eol {
+        let options = UpdateOptions {
+            all: true,
+            ..Default::default()
+        };
+        return super::update::handle(registry, ctx, &options).await;
+    }
+
+    let current_dir = std::env::current_dir()?;
+    find_vx_config(&current_dir).map_err(|e| anyhow::anyhow!("No vx.toml found: {}", e))?;
+
+    let pins = crate::eol::project_eol_pins(None);
+    if pins.is_empty() {
+        UI::success("No tools are pinned to an end-of-life version");
+        return Ok(());
+    }
+
+    UI::header(&format!(
+        "{} tool(s) pinned to an end-of-life version",
+        pins.len()
+    ));
+    for pin in &pins {
+        UI::item(&format!(
+            "{} {} (end-of-life since {})",
+            pin.tool, pin.version, pin.eol
+        ));
+    }
+    UI::hint(&format!(
+        "Raise these versions in vx.toml, then run 'vx lock' to refresh {}",
+        LOCK_FILE_NAME
+    ));
+    Ok(())
+}
@@ -0,0 +1,264 @@
+//! Dry-run action plans
+//!
+//! `--dry-run` on the commands that change the vx store (`install`,
+//! `uninstall`, `update`, `upgrade` and `rollback`) builds an [`ActionPlan`]
+//! instead of running: the downloads it would make with their sizes, the
+//! store directories it would delete, the active versions that would change
+//! and the project files it would rewrite.
+//!
+//! The plan is rendered through [`OutputRenderer`], so `--json` emits it as
+//! JSON for automation to review before applying:
+//!
+//! ```json
+//! {"command":"install","actions":[
+//!   {"action":"download","tool":"node","version":"22.1.0","url":"https://...","size":29810688,"cached":false},
+//!   {"action":"switch","tool":"node","from":"20.11.0","to":"22.1.0"}]}
+//! ```
+
+use super::common::{calculate_directory_size, format_size};
+use crate::cli::OutputFormat;
+use crate::output::{CommandOutput, OutputRenderer};
+use anyhow::Result;
+use serde::Serialize;
+use std::path::PathBuf;
+use vx_runtime::{Platform, Runtime, RuntimeContext};
+
+/// Actions a mutating command would take
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ActionPlan {
+    /// Command that was planned (e.g., "install")
+    pub command: String,
+    /// Actions in the order the command would run them
+    pub actions: Vec<PlannedAction>,
+}
+
+/// A single planned action
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum PlannedAction {
+    /// Download and install a tool version
+    Download {
+        tool: String,
+        version: String,
+        /// Archive URL, when the provider downloads one
+        url: Option<String>,
+        /// Download size in bytes, when the server reports it
+        size: Option<u64>,
+        /// Whether the archive is already in the download cache
+        cached: bool,
+    },
+    /// Delete an installed version from the store
+    Delete {
+        tool: String,
+        version: String,
+        path: PathBuf,
+        /// Bytes freed
+        size: u64,
+    },
+    /// Change the version of a tool that is in use
+    Switch {
+        tool: String,
+        from: Option<String>,
+        to: String,
+    },
+    /// Rewrite a project file (e.g., vx.lock)
+    UpdateFile { path: PathBuf, change: String },
+    /// Leave a tool untouched
+    Skip {
+        tool: String,
+        version: Option<String>,
+        reason: String,
+    },
+}
+
+impl ActionPlan {
+    /// Empty plan for `command`
+    pub fn new(command: &str) -> Self {
+        Self {
+            command: command.to_string(),
+            actions: Vec::new(),
+        }
+    }
+
+    /// Append an action
+    pub fn push(&mut self, action: PlannedAction) {
+        self.actions.push(action);
+    }
+
+    /// Whether the plan changes anything
+    pub fn is_noop(&self) -> bool {
+        self.actions
+            .iter()
+            .all(|a| matches!(a, PlannedAction::Skip { .. }))
+    }
+
+    /// Number of downloads and their known total size
+    ///
+    /// Cached archives are not downloaded again, so they count as zero bytes.
+    pub fn download_bytes(&self) -> (usize, u64) {
+        self.actions
+            .iter()
+            .filter_map(|a| match a {
+                PlannedAction::Download { size, cached, .. } => {
+                    Some(if *cached { 0 } else { size.unwrap_or(0) })
+                }
+                _ => None,
+            })
+            .fold((0, 0), |(count, total), size| (count + 1, total + size))
+    }
+
+    /// Number of deletions and the bytes they free
+    pub fn freed_bytes(&self) -> (usize, u64) {
+        self.actions
+            .iter()
+            .filter_map(|a| match a {
+                PlannedAction::Delete { size, .. } => Some(*size),
+                _ => None,
+            })
+            .fold((0, 0), |(count, total), size| (count + 1, total + size))
+    }
+
+    /// Print the plan in the requested output format
+    pub fn render(&self, format: OutputFormat) -> Result<()> {
+        OutputRenderer::new(format).render(self)
+    }
+}
+
+impl CommandOutput for ActionPlan {
+    fn render_text(&self, writer: &mut dyn std::io::Write) -> Result<()> {
+        writeln!(
+            writer,
+            "Dry run: vx {} (nothing will be changed)",
+            self.command
+        )?;
+        for action in &self.actions {
+            match action {
+                PlannedAction::Download {
+                    tool,
+                    version,
+                    url,
+                    size,
+                    cached,
+                } => {
+                    let size = match (cached, size) {
+                        (true, _) => " (cached)".to_string(),
+                        (false, Some(size)) => format!(" ({})", format_size(*size)),
+                        (false, None) => String::new(),
+                    };
+                    writeln!(writer, "  download  {}@{}{}", tool, version, size)?;
+                    if let Some(url) = url {
+                        writeln!(writer, "            {}", url)?;
+                    }
+                }
+                PlannedAction::Delete {
+                    tool,
+                    version,
+                    path,
+                    size,
+                } => writeln!(
+                    writer,
+                    "  delete    {}@{} ({})\n            {}",
+                    tool,
+                    version,
+                    format_size(*size),
+                    path.display()
+                )?,
+                PlannedAction::Switch { tool, from, to } => writeln!(
+                    writer,
+                    "  switch    {} {} → {}",
+                    tool,
+                    from.as_deref().unwrap_or("-"),
+                    to
+                )?,
+                PlannedAction::UpdateFile { path, change } => {
+                    writeln!(writer, "  update    {}: {}", path.display(), change)?
+                }
+                PlannedAction::Skip {
+                    tool,
+                    version,
+                    reason,
+                } => writeln!(
+                    writer,
+                    "  skip      {}{}: {}",
+                    tool,
+                    version
+                        .as_deref()
+                        .map(|v| format!("@{}", v))
+                        .unwrap_or_default(),
+                    reason
+                )?,
+            }
+        }
+
+        if self.is_noop() {
+            writeln!(writer, "Nothing to do")?;
+            return Ok(());
+        }
+        let (downloads, download_size) = self.download_bytes();
+        let (deletions, freed) = self.freed_bytes();
+        writeln!(
+            writer,
+            "Total: {} download(s) ({}), {} deletion(s) ({} freed)",
+            downloads,
+            format_size(download_size),
+            deletions,
+            format_size(freed)
+        )?;
+        Ok(())
+    }
+
+    fn render_compact(&self, writer: &mut dyn std::io::Write) -> Result<()> {
+        let mut parts = Vec::new();
+        for action in &self.actions {
+            parts.push(match action {
+                PlannedAction::Download { tool, version, .. } => format!("+{}@{}", tool, version),
+                PlannedAction::Delete { tool, version, .. } => format!("-{}@{}", tool, version),
+                PlannedAction::Switch { tool, to, .. } => format!("{}->{}", tool, to),
+                PlannedAction::UpdateFile { path, .. } => format!("~{}", path.display()),
+                PlannedAction::Skip { tool, .. } => format!("skip:{}", tool),
+            });
+        }
+        writeln!(writer, "plan {}: {}", self.command, parts.join(" "))?;
+        Ok(())
+    }
+}
+
+/// Download action for installing `version` of `runtime`
+///
+/// The size comes from a HEAD request and is left out when the server does
+/// not report it or cannot be reached; planning never fails on it.
+pub async fn plan_download(
+    runtime: &dyn Runtime,
+    ctx: &RuntimeContext,
+    version: &str,
+) -> PlannedAction {
+    let url = runtime
+        .download_url(version, &Platform::current())
+        .await
+        .ok()
+        .flatten();
+    let cached = url.as_deref().is_some_and(|u| ctx.http.is_cached(u));
+    let size = match url.as_deref() {
+        Some(url) if !cached => ctx.http.content_length(url).await.ok().flatten(),
+        _ => None,
+    };
+    PlannedAction::Download {
+        tool: runtime.name().to_string(),
+        version: version.to_string(),
+        url,
+        size,
+        cached,
+    }
+}
+
+/// Delete action for the store directory of `tool` `version`
+pub fn plan_delete(ctx: &RuntimeContext, tool: &str, version: &str) -> PlannedAction {
+    let path = ctx.paths.version_store_dir(tool, version);
+    let size = calculate_directory_size(&path).unwrap_or(0);
+    PlannedAction::Delete {
+        tool: tool.to_string(),
+        version: version.to_string(),
+        path,
+        size,
+    }
+}
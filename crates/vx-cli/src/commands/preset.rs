@@ -0,0 +1,226 @@
+//! Preset command implementation
+//!
+//! - `vx preset search [query]`: list presets in a preset index
+//! - `vx preset apply <name>`: extend the project vx.toml with a preset,
+//!   pinned to the index sha256 and recorded under `[presets]` in vx.lock
+//! - `vx preset publish <name>`: write the shareable sections of the project
+//!   vx.toml to `presets/<name>.toml` in a local index checkout and update
+//!   its `index.json`
+//!
+//! See [`vx_config::PresetIndex`] for the index format.
+
+use crate::cli::PresetCommand;
+use crate::ui::UI;
+use anyhow::{Context, Result, bail};
+use std::path::{Path, PathBuf};
+use vx_config::config_manager::TomlDocument;
+use vx_config::{
+    PRESET_DIR, PRESET_INDEX_FILE, PresetIndex, PresetSource, extract_preset, index_location,
+    is_url, preset_entry, validate_preset_name,
+};
+use vx_paths::project::{LOCK_FILE_NAME, find_vx_config};
+use vx_resolver::{LockFile, LockedPreset};
+
+/// Environment variable naming the default preset index
+pub const PRESET_INDEX_ENV: &str = "VX_PRESET_INDEX";
+
+/// Handle preset subcommands
+pub async fn handle(command: &PresetCommand) -> Result<()> {
+    match command {
+        PresetCommand::Search { query, index } => {
+            handle_search(query.as_deref().unwrap_or(""), index.as_deref()).await
+        }
+        PresetCommand::Apply { name, index } => handle_apply(name, index.as_deref()).await,
+        PresetCommand::Publish {
+            name,
+            index,
+            description,
+            tags,
+        } => handle_publish(name, index.as_deref(), description.as_deref(), tags),
+    }
+}
+
+/// The `--index` value, falling back to `VX_PRESET_INDEX`
+fn index_arg(index: Option<&str>) -> Result<String> {
+    match index {
+        Some(index) => Ok(index.to_string()),
+        None => match std::env::var(PRESET_INDEX_ENV) {
+            Ok(index) if !index.trim().is_empty() => Ok(index),
+            _ => bail!("No preset index configured: pass --index or set {PRESET_INDEX_ENV}"),
+        },
+    }
+}
+
+/// Read a preset index or preset file from a URL or local path
+async fn read_location(location: &str) -> Result<String> {
+    if !is_url(location) {
+        return std::fs::read_to_string(location)
+            .with_context(|| format!("Failed to read {location}"));
+    }
+    vx_proxy::client_builder()
+        .user_agent(format!("vx/{}", env!("CARGO_PKG_VERSION")))
+        .build()?
+        .get(location)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .with_context(|| format!("Failed to fetch {location}"))?
+        .text()
+        .await
+        .with_context(|| format!("Failed to read {location}"))
+}
+
+async fn load_index(index: &str) -> Result<(String, PresetIndex)> {
+    let location = index_location(index);
+    let content = read_location(&location).await?;
+    let parsed =
+        PresetIndex::parse(&content).with_context(|| format!("Invalid index {location}"))?;
+    Ok((location, parsed))
+}
+
+fn project_config() -> Result<PathBuf> {
+    let current_dir = std::env::current_dir()?;
+    find_vx_config(&current_dir).map_err(|e| anyhow::anyhow!("No vx.toml found: {}", e))
+}
+
+async fn handle_search(query: &str, index: Option<&str>) -> Result<()> {
+    let index = index_arg(index)?;
+    let (_, presets) = load_index(&index).await?;
+    let matches = presets.search(query);
+    if matches.is_empty() {
+        UI::info(&format!("No presets matching '{query}' in {index}"));
+        return Ok(());
+    }
+
+    let width = matches.iter().map(|p| p.name.len()).max().unwrap_or(0);
+    for preset in matches {
+        let mut line = format!("{:width$}  {}", preset.name, preset.description);
+        if !preset.tools.is_empty() {
+            line.push_str(&format!(" [{}]", preset.tools.join(", ")));
+        }
+        println!("{}", line.trim_end());
+    }
+    Ok(())
+}
+
+async fn handle_apply(name: &str, index: Option<&str>) -> Result<()> {
+    let index = index_arg(index)?;
+    let config_path = project_config()?;
+    let (location, presets) = load_index(&index).await?;
+    let entry = presets
+        .get(name)
+        .with_context(|| format!("Preset '{name}' not found in {location}"))?;
+
+    let url = entry.location(&location);
+    let content = read_location(&url).await?;
+    let source = PresetSource {
+        url: url.clone(),
+        version: None,
+        sha256: Some(entry.sha256.clone()),
+    };
+    source
+        .verify_content(&content)
+        .with_context(|| format!("Preset '{name}' does not match the index"))?;
+
+    // Remote presets are pinned with `#<sha256>` and read from the preset
+    // cache; local ones are referenced by path
+    let extends = if is_url(&url) {
+        super::common::preset_manager().store_preset(&source, &content)?;
+        format!("{}#{}", url, entry.sha256)
+    } else {
+        std::fs::canonicalize(&url)
+            .with_context(|| format!("Failed to resolve {url}"))?
+            .display()
+            .to_string()
+    };
+
+    let original = std::fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read {}", config_path.display()))?;
+    let mut doc = TomlDocument::parse(&original)
+        .with_context(|| format!("Failed to parse {}", config_path.display()))?;
+    if let Some(previous) = doc.get_string("extends").filter(|p| *p != extends) {
+        UI::warn(&format!("Replacing extends = \"{previous}\""));
+    }
+    doc.set_string("extends", &extends);
+    std::fs::write(&config_path, doc.to_toml_string())
+        .with_context(|| format!("Failed to write {}", config_path.display()))?;
+
+    let lock_path = config_path.with_file_name(LOCK_FILE_NAME);
+    let mut lock = if lock_path.exists() {
+        LockFile::load(&lock_path)?
+    } else {
+        LockFile::new()
+    };
+    lock.lock_preset(name, LockedPreset::new(&index, &url, &entry.sha256));
+    lock.save(&lock_path)?;
+
+    UI::success(&format!(
+        "Applied preset {name} ({}) to {}",
+        &entry.sha256[..entry.sha256.len().min(12)],
+        config_path.display()
+    ));
+    UI::hint("Run 'vx sync' to install the preset tools");
+    Ok(())
+}
+
+fn handle_publish(
+    name: &str,
+    index: Option<&Path>,
+    description: Option<&str>,
+    tags: &[String],
+) -> Result<()> {
+    validate_preset_name(name)?;
+    let index_dir = match index {
+        Some(dir) => dir.to_path_buf(),
+        None => PathBuf::from(index_arg(None)?),
+    };
+    if !index_dir.is_dir() {
+        bail!(
+            "Publishing needs a local index directory (a checkout of the preset repository), got {}",
+            index_dir.display()
+        );
+    }
+
+    let config_path = project_config()?;
+    let vx_toml = std::fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read {}", config_path.display()))?;
+    let content = extract_preset(name, &vx_toml)?;
+
+    let index_path = index_dir.join(PRESET_INDEX_FILE);
+    let mut presets = if index_path.exists() {
+        PresetIndex::parse(&std::fs::read_to_string(&index_path)?)
+            .with_context(|| format!("Invalid index {}", index_path.display()))?
+    } else {
+        PresetIndex::default()
+    };
+
+    // Keep the previous description and tags when republishing
+    let previous = presets.get(name).cloned();
+    let description = description
+        .map(str::to_string)
+        .or_else(|| previous.as_ref().map(|p| p.description.clone()))
+        .unwrap_or_default();
+    let tags = if tags.is_empty() {
+        previous.map(|p| p.tags).unwrap_or_default()
+    } else {
+        tags.to_vec()
+    };
+    let entry = preset_entry(name, description, tags, &content)?;
+
+    let preset_path = index_dir.join(PRESET_DIR).join(format!("{name}.toml"));
+    std::fs::create_dir_all(index_dir.join(PRESET_DIR))?;
+    std::fs::write(&preset_path, &content)
+        .with_context(|| format!("Failed to write {}", preset_path.display()))?;
+    let sha256 = entry.sha256.clone();
+    presets.upsert(entry);
+    std::fs::write(&index_path, presets.to_json())
+        .with_context(|| format!("Failed to write {}", index_path.display()))?;
+
+    UI::success(&format!(
+        "Published preset {name} ({}) to {}",
+        &sha256[..12],
+        index_dir.display()
+    ));
+    UI::hint("Commit and push the index repository to share it");
+    Ok(())
+}
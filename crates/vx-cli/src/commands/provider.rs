@@ -1,16 +1,25 @@
 //! Provider command implementation
 //!
 //! Manages user-defined providers loaded from provider.star files.
-//! Supports adding, removing, listing, and inspecting providers.
+//! Supports adding, removing, listing, and inspecting providers, and
+//! developing a local provider with hot reload (`vx provider dev`),
+//! scaffolding new ones (`vx provider new`), and adding asdf plugins
+//! (`vx provider asdf`).
 
 use crate::cli::ProviderCommand;
-use crate::registry::load_star_overrides;
+use crate::commands::doctor::print_degraded_providers;
+use crate::commands::provider_scaffold::{self, ScaffoldOptions};
+use crate::commands::run::watch::Watcher;
+use crate::registry::{
+    degraded_providers, dev_provider_links_dir, load_asdf_plugins, load_star_overrides,
+};
 use crate::ui::UI;
 use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
+use vx_config::ScriptWatch;
 use vx_paths::VxPaths;
-use vx_runtime::ProviderRegistry;
-use vx_starlark::StarMetadata;
+use vx_runtime::{ProviderFailure, ProviderRegistry};
+use vx_starlark::{ProviderLint, StarMetadata, StarlarkEngine};
 
 pub async fn handle(registry: &ProviderRegistry, command: ProviderCommand) -> Result<()> {
     match command {
@@ -20,15 +29,21 @@ pub async fn handle(registry: &ProviderRegistry, command: ProviderCommand) -> Re
         } => {
             // Only show locally installed providers (from ~/.vx/providers/ and .vx/providers/)
             let local_providers = load_star_overrides();
+            let asdf_plugins = load_asdf_plugins();
+            let degraded = degraded_providers(registry).await;
 
-            if local_providers.is_empty() {
+            if local_providers.is_empty() && asdf_plugins.is_empty() {
                 UI::header("Installed Providers");
                 UI::info("No local providers installed.");
                 UI::hint("Use `vx provider add <path>` to add a provider.");
+                print_degraded(&degraded);
                 return Ok(());
             }
 
-            UI::header(&format!("Installed Providers  ({})", local_providers.len()));
+            UI::header(&format!(
+                "Installed Providers  ({})",
+                local_providers.len() + asdf_plugins.len()
+            ));
 
             for (name, content) in &local_providers {
                 // Extract description from star content if available
@@ -49,6 +64,15 @@ pub async fn handle(registry: &ProviderRegistry, command: ProviderCommand) -> Re
                     UI::detail(&format!("{} {}", prefix, runtime_name));
                 }
             }
+            for plugin in &asdf_plugins {
+                let source = plugin
+                    .config()
+                    .repository
+                    .clone()
+                    .unwrap_or_else(|| plugin.dir().display().to_string());
+                UI::item(&format!("🔌 {} - asdf plugin ({})", plugin.name(), source));
+            }
+            print_degraded(&degraded);
         }
 
         ProviderCommand::Info { name } => {
@@ -127,9 +151,51 @@ pub async fn handle(registry: &ProviderRegistry, command: ProviderCommand) -> Re
             handle_add(&path, name.as_deref(), force).await?;
         }
 
+        ProviderCommand::Asdf {
+            name,
+            repository,
+            executable,
+            force,
+        } => {
+            handle_asdf(&name, repository.as_deref(), executable, force).await?;
+        }
+
         ProviderCommand::Remove { name } => {
             handle_remove(&name)?;
         }
+
+        ProviderCommand::Dev { path } => {
+            handle_dev(&path).await?;
+        }
+
+        ProviderCommand::New {
+            name,
+            repo,
+            template,
+            asset,
+            executable,
+            description,
+            tag_prefix,
+            strip_prefix,
+            builtin,
+            output,
+            force,
+        } => {
+            let mut opts = ScaffoldOptions::new(&name, &repo, template)?;
+            if let Some(asset) = asset {
+                opts.asset = asset;
+            }
+            if let Some(executable) = executable {
+                opts.executable = executable;
+            }
+            if let Some(description) = description {
+                opts.description = description;
+            }
+            opts.tag_prefix = tag_prefix;
+            opts.strip_prefix = strip_prefix;
+            opts.builtin = builtin;
+            handle_new(&opts, output, force)?;
+        }
     }
 
     Ok(())
@@ -332,7 +398,7 @@ fn handle_add_file(src: &Path, name_override: Option<&str>, force: bool) -> Resu
 // ---------------------------------------------------------------------------
 
 /// Write `content` to `~/.vx/providers/<name>/provider.star`.
-fn install_star_content(provider_name: &str, content: &str, force: bool) -> Result<()> {
+pub(crate) fn install_star_content(provider_name: &str, content: &str, force: bool) -> Result<()> {
     let vx_paths = VxPaths::new().context("Failed to resolve VX home directory")?;
     let dest_dir = vx_paths.base_dir.join("providers").join(provider_name);
     let dest_file = dest_dir.join("provider.star");
@@ -345,6 +411,10 @@ fn install_star_content(provider_name: &str, content: &str, force: bool) -> Resu
         );
     }
 
+    // Reject scripts that would be skipped at startup anyway
+    vx_starlark::validate_user_provider(provider_name, &dest_file, content)
+        .with_context(|| format!("'{}' is not a valid provider.star", provider_name))?;
+
     std::fs::create_dir_all(&dest_dir)
         .with_context(|| format!("Failed to create directory {}", dest_dir.display()))?;
 
@@ -414,15 +484,181 @@ fn extract_runtime_names_from_star(source: &str) -> Vec<String> {
         .collect()
 }
 
+// ---------------------------------------------------------------------------
+// provider asdf
+// ---------------------------------------------------------------------------
+
+/// Plugin index of asdf, one `plugins/<name>` file per short name
+const ASDF_PLUGIN_INDEX: &str =
+    "https://raw.githubusercontent.com/asdf-vm/asdf-plugins/master/plugins";
+
+/// Add an asdf plugin to `~/.vx/asdf-plugins/<name>/`.
+async fn handle_asdf(
+    name: &str,
+    repository: Option<&str>,
+    executable: Option<String>,
+    force: bool,
+) -> Result<()> {
+    vx_runtime::asdf::validate_plugin_name(name)?;
+    let vx_paths = VxPaths::new().context("Failed to resolve VX home directory")?;
+    let dest = vx_paths.asdf_plugins_dir.join(name);
+
+    if dest.exists() {
+        if !force {
+            anyhow::bail!(
+                "asdf plugin '{}' already exists at {}\nUse --force to replace it.",
+                name,
+                dest.display()
+            );
+        }
+        std::fs::remove_dir_all(&dest)
+            .with_context(|| format!("Failed to remove {}", dest.display()))?;
+    }
+    std::fs::create_dir_all(&vx_paths.asdf_plugins_dir).with_context(|| {
+        format!(
+            "Failed to create directory {}",
+            vx_paths.asdf_plugins_dir.display()
+        )
+    })?;
+
+    let repository = match repository {
+        Some(repo) => repo.to_string(),
+        None => asdf_index_repository(name).await?,
+    };
+    let local = Path::new(&repository);
+    if local.is_dir() {
+        copy_plugin_dir(local, &dest)
+            .with_context(|| format!("Failed to copy {}", local.display()))?;
+    } else {
+        UI::info(&format!("Cloning {repository} …"));
+        let status = std::process::Command::new("git")
+            .args(["clone", "--depth", "1", "--quiet", &repository])
+            .arg(&dest)
+            .status()
+            .context("Failed to run git")?;
+        if !status.success() {
+            let _ = std::fs::remove_dir_all(&dest);
+            anyhow::bail!("git clone of {} exited with {}", repository, status);
+        }
+    }
+
+    let config = vx_runtime::AsdfPluginConfig {
+        executable,
+        description: None,
+        repository: Some(repository),
+    };
+    let plugin = config
+        .save(&dest)
+        .and_then(|_| vx_runtime::AsdfPlugin::load(&dest));
+    if let Err(e) = plugin {
+        let _ = std::fs::remove_dir_all(&dest);
+        return Err(e.context(format!("'{}' is not a valid asdf plugin", name)));
+    }
+
+    UI::success(&format!(
+        "asdf plugin '{}' added → {}",
+        name,
+        dest.display()
+    ));
+    if load_star_overrides().iter().any(|(n, _)| n == name)
+        || crate::registry::available_runtime_names()
+            .iter()
+            .any(|n| n == name)
+    {
+        UI::warn(&format!(
+            "A provider named '{}' already exists and takes precedence over the plugin",
+            name
+        ));
+    } else {
+        UI::hint(&format!("Run `vx {name}` to use it"));
+    }
+    Ok(())
+}
+
+/// Look up the repository of an asdf plugin short name in the asdf index
+async fn asdf_index_repository(name: &str) -> Result<String> {
+    let url = format!("{ASDF_PLUGIN_INDEX}/{name}");
+    let client = vx_proxy::client_builder()
+        .user_agent(format!("vx/{}", env!("CARGO_PKG_VERSION")))
+        .build()?;
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .with_context(|| format!("Failed to fetch {url}"))?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        anyhow::bail!(
+            "'{}' is not in the asdf plugin index; pass the plugin repository",
+            name
+        );
+    }
+    let body = response.error_for_status()?.text().await?;
+    parse_asdf_index_entry(&body)
+        .ok_or_else(|| anyhow::anyhow!("No repository in the asdf plugin index entry {url}"))
+}
+
+/// The `repository = <url>` line of an asdf plugin index entry
+pub fn parse_asdf_index_entry(entry: &str) -> Option<String> {
+    entry.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        (key.trim() == "repository")
+            .then(|| value.trim().to_string())
+            .filter(|v| !v.is_empty())
+    })
+}
+
+/// Copy a local plugin directory, without its `.git`
+fn copy_plugin_dir(src: &Path, dst: &Path) -> Result<()> {
+    std::fs::create_dir_all(dst)?;
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        if entry.file_name() == ".git" {
+            continue;
+        }
+        let target = dst.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_plugin_dir(&entry.path(), &target)?;
+        } else {
+            // fs::copy keeps the permission bits, so scripts stay executable
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // provider remove
 // ---------------------------------------------------------------------------
 
 /// Remove a user provider from `~/.vx/providers/<name>/`.
+///
+/// Also removes a `vx provider dev` link left behind by a session that was
+/// killed, or an asdf plugin from `~/.vx/asdf-plugins/<name>/`.
 fn handle_remove(name: &str) -> Result<()> {
     let vx_paths = VxPaths::new().context("Failed to resolve VX home directory")?;
     let dest_dir = vx_paths.base_dir.join("providers").join(name);
 
+    let asdf_dir = vx_paths.asdf_plugins_dir.join(name);
+    if !dest_dir.exists()
+        && vx_runtime::asdf::validate_plugin_name(name).is_ok()
+        && asdf_dir.is_dir()
+    {
+        std::fs::remove_dir_all(&asdf_dir)
+            .with_context(|| format!("Failed to remove {}", asdf_dir.display()))?;
+        UI::success(&format!("asdf plugin '{}' removed.", name));
+        return Ok(());
+    }
+
+    if let Some(links_dir) = dev_provider_links_dir() {
+        let link = vx_starlark::dev_link_file(&links_dir, name);
+        if link.exists() && !dest_dir.exists() {
+            std::fs::remove_file(&link)
+                .with_context(|| format!("Failed to remove {}", link.display()))?;
+            UI::success(&format!("Dev provider link '{}' removed.", name));
+            return Ok(());
+        }
+    }
+
     if !dest_dir.exists() {
         anyhow::bail!("Provider '{}' not found in user providers directory.", name);
     }
@@ -433,3 +669,224 @@ fn handle_remove(name: &str) -> Result<()> {
     UI::success(&format!("Provider '{}' removed.", name));
     Ok(())
 }
+
+// ---------------------------------------------------------------------------
+// provider dev
+// ---------------------------------------------------------------------------
+
+/// Lints that don't apply to providers: vx fixes the hook signatures, so
+/// e.g. an unused `ctx` argument is expected
+const IGNORED_LINTS: &[&str] = &["unused-argument"];
+
+/// Result of analyzing a provider under development
+#[derive(Debug, Default)]
+pub struct DevAnalysis {
+    /// Runtimes the script defines
+    pub runtimes: Vec<String>,
+    /// Lint warnings
+    pub lints: Vec<ProviderLint>,
+    /// Why the script would be rejected, if it would be
+    pub error: Option<String>,
+}
+
+/// Validate and lint a provider.star the way startup discovery would
+pub fn analyze_dev_provider(name: &str, script: &Path, content: &str) -> DevAnalysis {
+    if let Err(e) = vx_starlark::validate_user_provider(name, script, content) {
+        return DevAnalysis {
+            error: Some(e.to_string()),
+            ..Default::default()
+        };
+    }
+    let script_name = script
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| vx_starlark::PROVIDER_FILENAME.to_string());
+    DevAnalysis {
+        runtimes: extract_runtime_names_from_star(content),
+        lints: StarlarkEngine::new()
+            .lint_script(&script_name, content)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|lint| !IGNORED_LINTS.contains(&lint.rule.as_str()))
+            .collect(),
+        error: None,
+    }
+}
+
+/// The provider.star of a `vx provider dev` path (a directory or the file itself)
+fn dev_script_path(path: &Path) -> Result<PathBuf> {
+    let script = if path.is_dir() {
+        path.join(vx_starlark::PROVIDER_FILENAME)
+    } else {
+        path.to_path_buf()
+    };
+    if !script.is_file() {
+        anyhow::bail!("No provider.star found at {}", script.display());
+    }
+    script
+        .canonicalize()
+        .with_context(|| format!("Failed to resolve {}", script.display()))
+}
+
+/// Removes the dev link when the session ends
+struct DevLink(PathBuf);
+
+impl Drop for DevLink {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+/// Watch a local provider.star, re-analyzing it on every save, and make it
+/// available to other vx invocations until interrupted.
+async fn handle_dev(path: &Path) -> Result<()> {
+    let script = dev_script_path(path)?;
+    let content = std::fs::read_to_string(&script)
+        .with_context(|| format!("Failed to read {}", script.display()))?;
+    let name = extract_name_from_star(&content)
+        .or_else(|| {
+            script
+                .parent()
+                .and_then(|p| p.file_name())
+                .map(|n| n.to_string_lossy().to_string())
+        })
+        .filter(|n| !n.is_empty())
+        .context("Could not determine the provider name; set `name` in provider.star")?;
+
+    let links_dir =
+        dev_provider_links_dir().context("User providers are disabled by the machine policy")?;
+    std::fs::create_dir_all(&links_dir)
+        .with_context(|| format!("Failed to create directory {}", links_dir.display()))?;
+    let link = DevLink(vx_starlark::dev_link_file(&links_dir, &name));
+    std::fs::write(&link.0, script.to_string_lossy().as_bytes())
+        .with_context(|| format!("Failed to write {}", link.0.display()))?;
+
+    UI::header(&format!("Provider dev: {}", name));
+    UI::info(&format!(
+        "Other vx invocations now use {}",
+        script.display()
+    ));
+    report_dev_analysis(&name, &script, &content);
+
+    let root = script.parent().unwrap_or(Path::new(".")).to_path_buf();
+    let file_name = script
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let settings = ScriptWatch {
+        paths: vec![glob::Pattern::escape(&file_name)],
+        ..Default::default()
+    };
+    let mut watcher = Watcher::new(&root, Some(&settings))?;
+
+    // The watcher blocks, so it gets its own thread; a detached thread also
+    // doesn't hold up the exit on Ctrl+C
+    let (tx, mut changes) = tokio::sync::mpsc::unbounded_channel();
+    std::thread::spawn(move || while tx.send(watcher.wait()).is_ok() {});
+
+    loop {
+        UI::info("Watching for changes (Ctrl+C to stop)");
+        tokio::select! {
+            Some(_) = changes.recv() => {}
+            _ = tokio::signal::ctrl_c() => break,
+        }
+
+        match std::fs::read_to_string(&script) {
+            Ok(content) => report_dev_analysis(&name, &script, &content),
+            Err(e) => UI::error(&format!("Failed to read {}: {}", script.display(), e)),
+        }
+    }
+
+    drop(link);
+    UI::info(&format!("Provider '{}' unregistered", name));
+    Ok(())
+}
+
+fn report_dev_analysis(name: &str, script: &Path, content: &str) {
+    let analysis = analyze_dev_provider(name, script, content);
+    if let Some(error) = &analysis.error {
+        UI::error(&format!("{}: {}", script.display(), error));
+        return;
+    }
+    for lint in &analysis.lints {
+        UI::warning(&format!(
+            "{} [{}] {}",
+            lint.location, lint.rule, lint.problem
+        ));
+    }
+    if analysis.runtimes.is_empty() {
+        UI::success(&format!("Provider '{}' is valid", name));
+    } else {
+        UI::success(&format!(
+            "Provider '{}' is valid (runtimes: {})",
+            name,
+            analysis.runtimes.join(", ")
+        ));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// provider new
+// ---------------------------------------------------------------------------
+
+/// Scaffold a provider and print how to try and register it.
+fn handle_new(opts: &ScaffoldOptions, output: Option<PathBuf>, force: bool) -> Result<()> {
+    let dir = match output {
+        Some(dir) => dir,
+        None if opts.builtin => {
+            let cwd = std::env::current_dir().context("Failed to get current directory")?;
+            provider_scaffold::find_builtin_providers_dir(&cwd)
+                .context("Not inside a vx checkout (no crates/vx-providers found); use --output")?
+                .join(&opts.name)
+        }
+        None => PathBuf::from(&opts.name),
+    };
+
+    // Refuse to write a provider vx could not load
+    let star = provider_scaffold::render_provider_star(opts);
+    vx_starlark::validate_user_provider(
+        opts.name.as_str(),
+        dir.join(vx_starlark::PROVIDER_FILENAME),
+        star,
+    )
+    .map_err(|e| anyhow::anyhow!("Generated provider.star is invalid: {}", e))?;
+
+    let written = provider_scaffold::write_scaffold(&dir, opts, force)?;
+    UI::success(&format!("Created provider '{}'", opts.name));
+    for path in &written {
+        println!("  {}", path.display());
+    }
+
+    println!();
+    println!("Next steps:");
+    println!(
+        "  1. Check the asset pattern \"{}\" against https://github.com/{}/{}/releases",
+        opts.asset, opts.owner, opts.repo
+    );
+    if opts.builtin {
+        println!("  2. Rebuild vx; build.rs embeds every crates/vx-providers/*/provider.star");
+        println!(
+            "  3. Try it: vx versions {} && vx test {}",
+            opts.name, opts.name
+        );
+    } else {
+        println!(
+            "  2. Try it while editing: vx provider dev {}",
+            dir.display()
+        );
+        println!("  3. Register it: vx provider add {}", dir.display());
+        println!("  4. Check it: vx test {}", opts.name);
+    }
+    Ok(())
+}
+
+/// Providers that failed to initialize, listed after the installed ones
+fn print_degraded(degraded: &[ProviderFailure]) {
+    if degraded.is_empty() {
+        return;
+    }
+    println!();
+    UI::header(&format!("Degraded Providers  ({})", degraded.len()));
+    print_degraded_providers(degraded);
+    UI::hint("Other providers are unaffected; `vx doctor` reports these too.");
+}
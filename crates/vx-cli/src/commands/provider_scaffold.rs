@@ -0,0 +1,414 @@
+//! Provider scaffolding for `vx provider new`
+//!
+//! Renders a provider.star on top of the GitHub release templates in
+//! `@vx//stdlib:provider_templates.star`, so `fetch_versions` and
+//! `download_url` work as generated once the asset pattern matches the
+//! tool's releases. Built-in providers (`--builtin`) also get the two test
+//! files every provider under `crates/vx-providers/` has.
+
+use crate::cli::ProviderTemplate;
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+impl ProviderTemplate {
+    /// The `provider_templates.star` function generating the provider
+    fn function(self) -> &'static str {
+        match self {
+            ProviderTemplate::Rust => "github_rust_provider",
+            ProviderTemplate::Go => "github_go_provider",
+            ProviderTemplate::Binary => "github_binary_provider",
+        }
+    }
+
+    /// Asset pattern used when `--asset` is not given
+    pub fn default_asset(self, name: &str) -> String {
+        match self {
+            ProviderTemplate::Rust => format!("{}-{{vversion}}-{{triple}}.{{ext}}", name),
+            ProviderTemplate::Go => format!("{}_{{version}}_{{os}}_{{arch}}.{{ext}}", name),
+            ProviderTemplate::Binary => format!("{}-{{os}}-{{arch}}{{exe}}", name),
+        }
+    }
+
+    /// `type` of the install layout the template returns
+    pub fn layout(self) -> &'static str {
+        match self {
+            ProviderTemplate::Rust | ProviderTemplate::Go => "archive",
+            ProviderTemplate::Binary => "binary",
+        }
+    }
+}
+
+/// What `vx provider new` generates
+#[derive(Debug, Clone)]
+pub struct ScaffoldOptions {
+    pub name: String,
+    pub owner: String,
+    pub repo: String,
+    pub template: ProviderTemplate,
+    pub asset: String,
+    pub executable: String,
+    pub description: String,
+    pub tag_prefix: String,
+    pub strip_prefix: Option<String>,
+    /// Also generate the Rust tests of a built-in provider
+    pub builtin: bool,
+}
+
+impl ScaffoldOptions {
+    /// Options for `name` released in the GitHub repository `repo`, with the
+    /// defaults of `vx provider new`
+    pub fn new(name: &str, repo: &str, template: ProviderTemplate) -> Result<Self> {
+        validate_name(name)?;
+        let (owner, repo) = parse_github_repo(repo)?;
+        Ok(Self {
+            name: name.to_string(),
+            description: format!("{} - https://github.com/{}/{}", name, owner, repo),
+            owner,
+            repo,
+            template,
+            asset: template.default_asset(name),
+            executable: name.to_string(),
+            tag_prefix: "v".to_string(),
+            strip_prefix: None,
+            builtin: false,
+        })
+    }
+
+    /// Crate name the test files refer to (`vx_provider_<name>`)
+    fn crate_ident(&self) -> String {
+        format!("vx_provider_{}", self.name.replace('-', "_"))
+    }
+}
+
+/// Provider names become directory, runtime and crate names
+pub fn validate_name(name: &str) -> Result<()> {
+    let valid = name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if !valid {
+        anyhow::bail!(
+            "Invalid provider name '{}': use lowercase letters, digits and '-', starting with a letter",
+            name
+        );
+    }
+    Ok(())
+}
+
+/// Split `owner/repo` or `https://github.com/owner/repo[.git]`
+pub fn parse_github_repo(repo: &str) -> Result<(String, String)> {
+    let path = repo
+        .trim()
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_start_matches("github.com/")
+        .trim_end_matches('/')
+        .trim_end_matches(".git");
+    match path.split('/').collect::<Vec<_>>().as_slice() {
+        [owner, name] if !owner.is_empty() && !name.is_empty() => {
+            Ok((owner.to_string(), name.to_string()))
+        }
+        _ => anyhow::bail!("Expected a GitHub repository as owner/repo, got '{}'", repo),
+    }
+}
+
+/// A Starlark string literal
+fn star_str(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| format!("{:?}", value))
+}
+
+/// The generated provider.star
+pub fn render_provider_star(opts: &ScaffoldOptions) -> String {
+    let runtime = if opts.executable == opts.name {
+        format!("runtime_def({})", star_str(&opts.name))
+    } else {
+        format!(
+            "runtime_def({}, executable = {})",
+            star_str(&opts.name),
+            star_str(&opts.executable)
+        )
+    };
+
+    let mut template_args = vec![
+        format!("    {}, {},", star_str(&opts.owner), star_str(&opts.repo)),
+        format!("    asset      = {},", star_str(&opts.asset)),
+    ];
+    // The templates default both to the repository name
+    if opts.executable != opts.repo {
+        template_args.push(format!("    executable = {},", star_str(&opts.executable)));
+    }
+    if opts.name != opts.repo {
+        template_args.push(format!("    store      = {},", star_str(&opts.name)));
+    }
+    if opts.tag_prefix != "v" {
+        template_args.push(format!("    tag_prefix = {},", star_str(&opts.tag_prefix)));
+    }
+    if let Some(strip_prefix) = &opts.strip_prefix
+        && opts.template != ProviderTemplate::Binary
+    {
+        template_args.push(format!("    strip_prefix = {},", star_str(strip_prefix)));
+    }
+
+    PROVIDER_STAR_TEMPLATE
+        .replace("{{name}}", &opts.name)
+        .replace("{{name_str}}", &star_str(&opts.name))
+        .replace("{{description}}", &star_str(&opts.description))
+        .replace(
+            "{{homepage}}",
+            &star_str(&format!("https://github.com/{}/{}", opts.owner, opts.repo)),
+        )
+        .replace("{{owner}}", &opts.owner)
+        .replace("{{repo}}", &opts.repo)
+        .replace("{{asset}}", &opts.asset)
+        .replace("{{tag_prefix}}", &opts.tag_prefix)
+        .replace("{{function}}", opts.template.function())
+        .replace("{{runtime}}", &runtime)
+        .replace("{{template_args}}", &template_args.join("\n"))
+}
+
+/// Every file to write, relative to the provider directory
+pub fn scaffold_files(opts: &ScaffoldOptions) -> Vec<(PathBuf, String)> {
+    let mut files = vec![(
+        PathBuf::from(vx_starlark::PROVIDER_FILENAME),
+        render_provider_star(opts),
+    )];
+    if opts.builtin {
+        let render = |template: &str| {
+            template
+                .replace("{{name}}", &opts.name)
+                .replace("{{crate}}", &opts.crate_ident())
+                .replace("{{ident}}", &opts.name.replace('-', "_"))
+                .replace("{{layout}}", opts.template.layout())
+        };
+        files.push((
+            PathBuf::from("tests/runtime_tests.rs"),
+            render(RUNTIME_TESTS_TEMPLATE),
+        ));
+        files.push((
+            PathBuf::from("tests/starlark_logic_tests.rs"),
+            render(LOGIC_TESTS_TEMPLATE),
+        ));
+    }
+    files
+}
+
+/// Write the scaffold into `dir`, returning the written paths
+///
+/// Nothing is written if any file exists and `force` is not set.
+pub fn write_scaffold(dir: &Path, opts: &ScaffoldOptions, force: bool) -> Result<Vec<PathBuf>> {
+    let files = scaffold_files(opts);
+    if !force && let Some((existing, _)) = files.iter().find(|(path, _)| dir.join(path).exists()) {
+        anyhow::bail!(
+            "{} already exists\nUse --force to overwrite.",
+            dir.join(existing).display()
+        );
+    }
+
+    let mut written = Vec::with_capacity(files.len());
+    for (relative, content) in files {
+        let path = dir.join(relative);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+        }
+        std::fs::write(&path, content)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// `crates/vx-providers` of the vx checkout containing `start`
+pub fn find_builtin_providers_dir(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join("crates").join("vx-providers"))
+        .find(|dir| dir.is_dir())
+}
+
+const PROVIDER_STAR_TEMPLATE: &str = r#"# provider.star - {{name}}
+#
+# Releases:     https://github.com/{{owner}}/{{repo}}/releases
+# Asset format: {{asset}}
+# Tag format:   {{tag_prefix}}{version}
+#
+# Generated by `vx provider new`. Compare the asset format with a published
+# release before relying on it.
+
+load("@vx//stdlib:provider.star",
+     "{{function}}", "runtime_def", "github_permissions")
+
+# ---------------------------------------------------------------------------
+# Provider metadata
+# ---------------------------------------------------------------------------
+name        = {{name_str}}
+description = {{description}}
+homepage    = {{homepage}}
+repository  = {{homepage}}
+ecosystem   = "devtools"
+
+# ---------------------------------------------------------------------------
+# Runtime definitions
+# ---------------------------------------------------------------------------
+
+runtimes = [{{runtime}}]
+
+# ---------------------------------------------------------------------------
+# Permissions
+# ---------------------------------------------------------------------------
+
+permissions = github_permissions()
+
+# ---------------------------------------------------------------------------
+# Provider functions — all generated by {{function}}
+# ---------------------------------------------------------------------------
+
+_p = {{function}}(
+{{template_args}}
+)
+
+fetch_versions   = _p["fetch_versions"]
+download_url     = _p["download_url"]
+install_layout   = _p["install_layout"]
+store_root       = _p["store_root"]
+get_execute_path = _p["get_execute_path"]
+post_install     = _p["post_install"]
+environment      = _p["environment"]
+deps             = _p["deps"]
+"#;
+
+const RUNTIME_TESTS_TEMPLATE: &str = r#"//! {{name}} provider tests
+
+use rstest::rstest;
+use vx_runtime::Runtime;
+
+fn create_provider() -> std::sync::Arc<dyn vx_runtime::Provider> {
+    let meta = vx_starlark::StarMetadata::parse({{crate}}::PROVIDER_STAR);
+    let name = meta.name.unwrap_or_else(|| "unknown".to_string());
+    vx_starlark::create_provider(name, {{crate}}::PROVIDER_STAR)
+}
+
+#[test]
+fn test_provider_name() {
+    let provider = create_provider();
+    assert_eq!(provider.name(), "{{name}}");
+}
+
+#[test]
+fn test_provider_runtimes() {
+    let provider = create_provider();
+    let names: Vec<&str> = provider
+        .runtimes()
+        .iter()
+        .map(|r: &std::sync::Arc<dyn Runtime>| r.name())
+        .collect();
+    assert!(names.contains(&"{{name}}"));
+}
+
+#[rstest]
+#[case("{{name}}", true)]
+#[case("node", false)]
+fn test_provider_supports(#[case] name: &str, #[case] expected: bool) {
+    let provider = create_provider();
+    assert_eq!(provider.supports(name), expected);
+}
+"#;
+
+const LOGIC_TESTS_TEMPLATE: &str = r##"//! Pure Starlark logic tests for {{name}} provider.star
+
+use starlark::assert::Assert;
+use starlark::syntax::Dialect;
+use vx_starlark::test_mocks::setup_provider_test_mocks;
+
+fn make_assert() -> Assert<'static> {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    setup_provider_test_mocks(&mut a);
+    a.module("provider.star", {{crate}}::PROVIDER_STAR);
+    a
+}
+
+fn provider_star_prefix() -> String {
+    use vx_starlark::test_mocks::prepare_provider_source;
+    prepare_provider_source({{crate}}::PROVIDER_STAR)
+}
+
+fn assert_with_provider(check: &str) {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!("{}\n{}", provider_star_prefix(), check));
+}
+
+// ── provider metadata ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_name() {
+    make_assert().eq(r#"load("provider.star", "name"); name"#, r#""{{name}}""#);
+}
+
+#[test]
+fn test_runtimes_has_{{ident}}() {
+    make_assert().is_true(
+        r#"
+load("provider.star", "runtimes")
+"{{name}}" in [r["name"] for r in runtimes]
+"#,
+    );
+}
+
+// ── download_url logic ────────────────────────────────────────────────────────
+
+#[test]
+fn test_download_url_linux_x64() {
+    assert_with_provider(
+        r#"
+ctx = struct(platform = struct(os = "linux", arch = "x64", target = "x86_64-unknown-linux-musl"))
+url = download_url(ctx, "1.0.0")
+url != None and "github.com" in url and "1.0.0" in url
+"#,
+    );
+}
+
+#[test]
+fn test_download_url_windows_x64() {
+    assert_with_provider(
+        r#"
+ctx = struct(platform = struct(os = "windows", arch = "x64", target = "x86_64-pc-windows-msvc"))
+url = download_url(ctx, "1.0.0")
+url != None and "github.com" in url
+"#,
+    );
+}
+
+#[test]
+fn test_download_url_macos_arm64() {
+    assert_with_provider(
+        r#"
+ctx = struct(platform = struct(os = "macos", arch = "arm64", target = "aarch64-apple-darwin"))
+url = download_url(ctx, "1.0.0")
+url != None and "github.com" in url
+"#,
+    );
+}
+
+// ── install_layout logic ──────────────────────────────────────────────────────
+
+#[test]
+fn test_install_layout_linux() {
+    assert_with_provider(
+        r#"
+ctx = struct(platform = struct(os = "linux", arch = "x64", target = "x86_64-unknown-linux-musl"))
+install_layout(ctx, "1.0.0")["type"] == "{{layout}}"
+"#,
+    );
+}
+
+// ── lint check ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_star_lint_clean() {
+    vx_starlark::provider_test_support::assert_provider_star_lint_clean(
+        {{crate}}::PROVIDER_STAR,
+    );
+}
+"##;
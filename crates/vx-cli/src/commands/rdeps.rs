@@ -0,0 +1,232 @@
+//! Reverse dependency command implementation
+//!
+//! `vx rdeps node` lists the tools that depend on node, to judge the impact
+//! of removing it. A tool depends on another when
+//!
+//! - it is bundled with it (npm with node),
+//! - its provider declares it as a dependency (pnpm needs node), or
+//! - installing it pulled the other tool in, as recorded in the install
+//!   reasons (see [`vx_paths::InstallReasons`]).
+//!
+//! Only installed dependents are shown unless `--all` is given.
+
+use crate::ui::UI;
+use anyhow::Result;
+use std::collections::{BTreeMap, BTreeSet};
+use vx_paths::InstallReasons;
+use vx_runtime::{ProviderRegistry, RuntimeContext};
+use vx_starlark::handle::global_registry;
+
+/// How one tool depends on another
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DependencyEdges {
+    /// Tools it is bundled with
+    pub bundled_with: Vec<String>,
+    /// Tools its provider declares as required
+    pub requires: Vec<String>,
+}
+
+/// A tool depending on the queried tool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReverseDependency {
+    pub tool: String,
+    /// Whether a version of the tool is installed
+    pub installed: bool,
+    /// Bundled with the queried tool
+    pub bundled: bool,
+    /// Declared as a dependency by its provider
+    pub declared: bool,
+    /// Recorded as the reason the queried tool was installed
+    pub recorded: bool,
+}
+
+impl ReverseDependency {
+    /// Human-readable description of the relationship
+    pub fn describe(&self, target: &str) -> String {
+        let mut parts = Vec::new();
+        if self.bundled {
+            parts.push(format!("bundled with {}", target));
+        }
+        if self.declared {
+            parts.push(format!("requires {}", target));
+        }
+        if self.recorded {
+            parts.push(format!("installed {} as its dependency", target));
+        }
+        parts.join(", ")
+    }
+}
+
+/// The tools depending on `target`, sorted by name
+///
+/// `edges` maps each tool to the tools it depends on; recorded dependents
+/// come from `reasons`. `installed` holds the installed tools.
+pub fn reverse_dependencies(
+    target: &str,
+    edges: &BTreeMap<String, DependencyEdges>,
+    reasons: &InstallReasons,
+    installed: &BTreeSet<String>,
+) -> Vec<ReverseDependency> {
+    let mut dependents: BTreeMap<String, ReverseDependency> = BTreeMap::new();
+    for (tool, deps) in edges {
+        if tool == target {
+            continue;
+        }
+        if deps.bundled_with.iter().any(|d| d == target) {
+            dependent(&mut dependents, installed, tool).bundled = true;
+        }
+        if deps.requires.iter().any(|d| d == target) {
+            dependent(&mut dependents, installed, tool).declared = true;
+        }
+    }
+    for tool in reasons.required_by(target) {
+        if tool != target {
+            dependent(&mut dependents, installed, tool).recorded = true;
+        }
+    }
+    dependents.into_values().collect()
+}
+
+fn dependent<'a>(
+    dependents: &'a mut BTreeMap<String, ReverseDependency>,
+    installed: &BTreeSet<String>,
+    tool: &str,
+) -> &'a mut ReverseDependency {
+    dependents
+        .entry(tool.to_string())
+        .or_insert_with(|| ReverseDependency {
+            tool: tool.to_string(),
+            installed: installed.contains(tool),
+            bundled: false,
+            declared: false,
+            recorded: false,
+        })
+}
+
+/// Dependency edges of every tool in the registry
+///
+/// Providers may declare dependencies per version (pnpm 9 needs node 18);
+/// those are queried for the installed versions in `installed`.
+pub(crate) async fn dependency_edges(
+    registry: &ProviderRegistry,
+    context: &RuntimeContext,
+    installed: &BTreeMap<String, Vec<String>>,
+) -> BTreeMap<String, DependencyEdges> {
+    let mut edges = BTreeMap::new();
+    for name in registry.runtime_names() {
+        let Some(runtime) = registry.get_runtime(&name) else {
+            continue;
+        };
+        let mut requires: Vec<String> = runtime
+            .dependencies()
+            .iter()
+            .filter(|d| !d.optional)
+            .map(|d| d.name.clone())
+            .collect();
+        for version in installed.get(&name).into_iter().flatten() {
+            if let Ok(deps) = runtime.versioned_dependencies(version, context).await {
+                requires.extend(deps.into_iter().filter(|d| !d.optional).map(|d| d.name));
+            }
+        }
+        requires.sort();
+        requires.dedup();
+        edges.insert(
+            name,
+            DependencyEdges {
+                bundled_with: runtime
+                    .metadata()
+                    .get("bundled_with")
+                    .cloned()
+                    .into_iter()
+                    .collect(),
+                requires,
+            },
+        );
+    }
+    edges
+}
+
+/// Installed versions of every installed tool, by tool name
+///
+/// Bundled tools count as installed with their parent's versions.
+pub(crate) async fn installed_tools(registry: &ProviderRegistry) -> BTreeMap<String, Vec<String>> {
+    let reg = global_registry().await;
+    let mut installed = BTreeMap::new();
+    for name in registry.runtime_names() {
+        let Some(runtime) = registry.get_runtime(&name) else {
+            continue;
+        };
+        let versions = match reg.get(runtime.name()) {
+            Some(handle) => handle.installed_versions(),
+            None => match runtime.metadata().get("bundled_with") {
+                Some(parent) => reg
+                    .get(parent)
+                    .map(|handle| handle.installed_versions())
+                    .unwrap_or_default(),
+                None => Vec::new(),
+            },
+        };
+        if !versions.is_empty() {
+            installed.insert(name, versions);
+        }
+    }
+    installed
+}
+
+/// Handle `vx rdeps`
+pub async fn handle(
+    registry: &ProviderRegistry,
+    context: &RuntimeContext,
+    tool: &str,
+    all: bool,
+) -> Result<()> {
+    let Some(runtime) = registry.get_runtime(tool) else {
+        UI::tool_not_found(
+            tool,
+            &crate::registry::registry_index(registry).runtime_names(),
+        );
+        anyhow::bail!("Tool not found: {}", tool);
+    };
+    let target = runtime.name().to_string();
+
+    let state_dir = vx_paths::VxPaths::with_base_dir(context.paths.vx_home()).state_dir;
+    let reasons = InstallReasons::load(&state_dir);
+    let installed_versions = installed_tools(registry).await;
+    let edges = dependency_edges(registry, context, &installed_versions).await;
+    let installed: BTreeSet<String> = installed_versions.into_keys().collect();
+    let dependents: Vec<ReverseDependency> =
+        reverse_dependencies(&target, &edges, &reasons, &installed)
+            .into_iter()
+            .filter(|d| all || d.installed)
+            .collect();
+
+    if dependents.is_empty() {
+        if all {
+            UI::info(&format!("No tool depends on {}", target));
+        } else {
+            UI::info(&format!("No installed tool depends on {}", target));
+            UI::hint("Use --all to include tools that are not installed");
+        }
+        return Ok(());
+    }
+
+    UI::info(&format!("Tools depending on {}:", target));
+    let width = dependents.iter().map(|d| d.tool.len()).max().unwrap_or(0);
+    for dependent in &dependents {
+        let marker = if dependent.installed { "✓" } else { "-" };
+        println!(
+            "  {} {:<width$}  {}",
+            marker,
+            dependent.tool,
+            dependent.describe(&target),
+            width = width
+        );
+    }
+    if installed.contains(&target) && dependents.iter().any(|d| d.installed) {
+        UI::hint(&format!(
+            "Removing {} breaks the installed tools above",
+            target
+        ));
+    }
+    Ok(())
+}
@@ -4,8 +4,11 @@
 //! and uninstall operations (RFC-0037). Falls back to the legacy `Runtime`
 //! trait path when no ProviderHandle is registered for the tool.
 
+use super::plan::{ActionPlan, PlannedAction, plan_delete};
+use super::rollback::newest_version;
 use crate::ui::UI;
 use anyhow::Result;
+use vx_paths::InstallReasons;
 use vx_runtime::{ProviderRegistry, RuntimeContext};
 use vx_starlark::handle::global_registry;
 
@@ -17,18 +20,109 @@ pub async fn handle(
     force: bool,
 ) -> Result<()> {
     // ── Step 1: Try ProviderHandle (RFC-0037) ─────────────────────────────
-    {
-        let reg = global_registry().await;
-        if let Some(handle) = reg.get(tool_name) {
-            return handle_via_provider_handle(
-                &handle, tool_name, version, force, registry, context,
-            )
-            .await;
+    let handle = global_registry().await.get(tool_name);
+    let remaining = if let Some(handle) = &handle {
+        handle_via_provider_handle(handle, tool_name, version, force, registry, context).await?;
+        handle.installed_versions()
+    } else {
+        // ── Step 2: Fallback to legacy Runtime trait ──────────────────────
+        handle_via_runtime(registry, context, tool_name, version, force).await?;
+        match registry.get_runtime(tool_name) {
+            Some(runtime) => runtime
+                .installed_versions(context)
+                .await
+                .unwrap_or_default(),
+            None => Vec::new(),
         }
+    };
+
+    // A tool without versions is neither a leaf nor a dependency any more
+    if remaining.is_empty() {
+        forget_install_reason(registry, context, tool_name);
     }
+    Ok(())
+}
 
-    // ── Step 2: Fallback to legacy Runtime trait ──────────────────────────
-    handle_via_runtime(registry, context, tool_name, version, force).await
+fn forget_install_reason(registry: &ProviderRegistry, context: &RuntimeContext, tool_name: &str) {
+    let name = registry
+        .get_runtime(tool_name)
+        .map_or_else(|| tool_name.to_string(), |r| r.name().to_string());
+    let state_dir = vx_paths::VxPaths::with_base_dir(context.paths.vx_home()).state_dir;
+    let mut reasons = InstallReasons::load(&state_dir);
+    if reasons.reason(&name).is_some() {
+        reasons.remove(&name);
+        if let Err(e) = reasons.save() {
+            tracing::warn!(tool = %name, error = %e, "Failed to forget install reason");
+        }
+    }
+}
+
+/// Plan what `vx uninstall` would delete, without running hooks or removing
+/// anything
+pub async fn plan(
+    registry: &ProviderRegistry,
+    context: &RuntimeContext,
+    tool_name: &str,
+    version: Option<&str>,
+    force: bool,
+) -> Result<ActionPlan> {
+    let handle = global_registry().await.get(tool_name);
+    let installed = match &handle {
+        Some(handle) => handle.installed_versions(),
+        None => match registry.get_runtime(tool_name) {
+            Some(runtime) => runtime.installed_versions(context).await?,
+            None => anyhow::bail!("Tool not found: {}", tool_name),
+        },
+    };
+
+    let mut plan = ActionPlan::new("uninstall");
+    if installed.is_empty() {
+        plan.push(PlannedAction::Skip {
+            tool: tool_name.to_string(),
+            version: version.map(str::to_string),
+            reason: "not installed".to_string(),
+        });
+        return Ok(plan);
+    }
+    let targets = match version {
+        Some(requested) => vec![match &handle {
+            Some(handle) => handle
+                .resolve_installed_version(requested)
+                .map_err(|e| anyhow::anyhow!("{}", e))?,
+            None => resolve_version_from_installed(tool_name, requested, &installed)?,
+        }],
+        None if !force => {
+            plan.push(PlannedAction::Skip {
+                tool: tool_name.to_string(),
+                version: None,
+                reason: "removing all versions requires --force".to_string(),
+            });
+            return Ok(plan);
+        }
+        None => installed.clone(),
+    };
+
+    for target in &targets {
+        plan.push(plan_delete(context, tool_name, target));
+    }
+
+    // Without a project pin the newest installed version is the active one
+    let remaining: Vec<String> = installed
+        .iter()
+        .filter(|v| !targets.contains(v))
+        .cloned()
+        .collect();
+    let active = newest_version(&installed);
+    if let (Some(from), Some(to)) = (active, newest_version(&remaining))
+        && from != to
+    {
+        plan.push(PlannedAction::Switch {
+            tool: tool_name.to_string(),
+            from: Some(from),
+            to,
+        });
+    }
+    Ok(plan)
 }
 
 // ---------------------------------------------------------------------------
@@ -143,7 +237,7 @@ async fn handle_via_runtime(
     let runtime = match registry.get_runtime(tool_name) {
         Some(r) => r,
         None => {
-            let available_tools = registry.runtime_names();
+            let available_tools = crate::registry::registry_index(registry).runtime_names();
             UI::tool_not_found(tool_name, &available_tools);
             return Err(anyhow::anyhow!("Tool not found: {}", tool_name));
         }
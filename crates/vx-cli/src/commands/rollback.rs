@@ -0,0 +1,232 @@
+//! Rollback command implementation
+//!
+//! `vx install` and `vx upgrade` record the versions they install in the
+//! install journal (`~/.vx/state/install-journal.json`). `vx rollback <tool>`
+//! undoes the most recent recorded install of a tool: the previously active
+//! version is reinstalled if it was removed since, and the newer version is
+//! uninstalled so the previous one becomes active again.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! vx upgrade node        # 20.11.0 → 20.12.0
+//! vx rollback node       # back to 20.11.0
+//! vx rollback node --dry-run   # only print what would change
+//! ```
+
+use super::plan::{ActionPlan, PlannedAction, plan_delete, plan_download};
+use crate::ui::UI;
+use anyhow::Result;
+use std::sync::Arc;
+use vx_paths::{InstallJournal, PathManager, ToolChange, TransactionStatus};
+use vx_resolver::Version;
+use vx_runtime::{ProviderRegistry, Runtime, RuntimeContext};
+
+/// Install transaction recorded in the journal as it runs.
+///
+/// Journaling is best effort: failing to read or write the journal never
+/// fails the install itself.
+pub(crate) struct Transaction {
+    command: &'static str,
+    journal: Option<InstallJournal>,
+    id: Option<u64>,
+    changes: Vec<ToolChange>,
+}
+
+impl Transaction {
+    /// Start a transaction for `command` (`install`, `upgrade`, ...)
+    pub(crate) fn begin(command: &'static str) -> Self {
+        let journal = PathManager::new()
+            .map(|paths| InstallJournal::load(paths.state_dir()))
+            .map_err(|e| tracing::debug!("Install journal unavailable: {}", e))
+            .ok();
+        Self {
+            command,
+            journal,
+            id: None,
+            changes: Vec::new(),
+        }
+    }
+
+    /// A transaction that is not written to the journal
+    pub(crate) fn untracked() -> Self {
+        Self {
+            command: "",
+            journal: None,
+            id: None,
+            changes: Vec::new(),
+        }
+    }
+
+    /// Record an installed version.
+    ///
+    /// The journal entry is created with the first change, so runs that
+    /// install nothing leave no trace.
+    pub(crate) fn record(&mut self, change: ToolChange) {
+        if let Some(journal) = self.journal.as_mut() {
+            let id = *self.id.get_or_insert_with(|| journal.begin(self.command));
+            journal.record(id, change.clone());
+            save(journal);
+        }
+        self.changes.push(change);
+    }
+
+    /// Versions installed so far, in install order
+    pub(crate) fn changes(&self) -> &[ToolChange] {
+        &self.changes
+    }
+
+    /// Store the final status of the transaction
+    pub(crate) fn finish(mut self, status: TransactionStatus) {
+        if let (Some(journal), Some(id)) = (self.journal.as_mut(), self.id) {
+            journal.finish(id, status);
+            save(journal);
+        }
+    }
+}
+
+fn save(journal: &InstallJournal) {
+    if let Err(e) = journal.save() {
+        tracing::warn!("Failed to write install journal: {}", e);
+    }
+}
+
+/// Newest version in a list of installed versions
+pub(crate) fn newest_version(versions: &[String]) -> Option<String> {
+    versions
+        .iter()
+        .filter_map(|v| Version::parse(v).map(|parsed| (parsed, v)))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, v)| v.clone())
+}
+
+/// Remove the versions installed by a failed transaction, newest first
+pub(crate) async fn undo_changes(
+    registry: &ProviderRegistry,
+    ctx: &RuntimeContext,
+    changes: &[ToolChange],
+) {
+    for change in changes.iter().rev() {
+        match super::remove::handle(registry, ctx, &change.tool, Some(&change.installed), true)
+            .await
+        {
+            Ok(()) => UI::detail(&format!(
+                "Rolled back {} {}{}",
+                change.tool,
+                change.installed,
+                change
+                    .previous
+                    .as_deref()
+                    .map(|p| format!(" → {}", p))
+                    .unwrap_or_default()
+            )),
+            Err(e) => UI::warn(&format!(
+                "Failed to roll back {} {}: {}",
+                change.tool, change.installed, e
+            )),
+        }
+    }
+}
+
+/// The runtime `tool` is installed through, the journal and the last
+/// recorded install of it
+fn last_install(
+    registry: &ProviderRegistry,
+    tool: &str,
+) -> Result<(Arc<dyn Runtime>, InstallJournal, u64, ToolChange)> {
+    let Some(runtime) = registry.get_runtime(tool) else {
+        anyhow::bail!("Unknown tool: {}", tool);
+    };
+    // Bundled tools (npm, npx, ...) are installed through their parent
+    let name = runtime
+        .metadata()
+        .get("bundled_with")
+        .cloned()
+        .unwrap_or_else(|| runtime.name().to_string());
+    let Some(runtime) = registry.get_runtime(&name) else {
+        anyhow::bail!("Unknown tool: {}", name);
+    };
+
+    let path_manager = PathManager::new()?;
+    let journal = InstallJournal::load(path_manager.state_dir());
+    let Some((id, change)) = journal.last_change(&name) else {
+        anyhow::bail!("No recorded install of {} to roll back", name);
+    };
+    let change = change.clone();
+    Ok((runtime, journal, id, change))
+}
+
+/// Plan what `vx rollback <tool>` would do
+pub async fn plan(
+    registry: &ProviderRegistry,
+    ctx: &RuntimeContext,
+    tool: &str,
+) -> Result<ActionPlan> {
+    let (runtime, _, _, change) = last_install(registry, tool)?;
+    let name = change.tool.clone();
+    let mut plan = ActionPlan::new("rollback");
+    let Some(previous) = change.previous.clone() else {
+        plan.push(PlannedAction::Skip {
+            tool: name,
+            version: Some(change.installed),
+            reason: "first version installed, nothing to roll back to".to_string(),
+        });
+        return Ok(plan);
+    };
+
+    if !runtime.is_installed(&previous, ctx).await? {
+        plan.push(plan_download(runtime.as_ref(), ctx, &previous).await);
+    }
+    if runtime.is_installed(&change.installed, ctx).await? {
+        plan.push(plan_delete(ctx, &name, &change.installed));
+    }
+    plan.push(PlannedAction::Switch {
+        tool: name,
+        from: Some(change.installed),
+        to: previous,
+    });
+    Ok(plan)
+}
+
+/// Handle `vx rollback <tool>`
+pub async fn handle(registry: &ProviderRegistry, ctx: &RuntimeContext, tool: &str) -> Result<()> {
+    let (runtime, mut journal, id, change) = last_install(registry, tool)?;
+    let name = change.tool.clone();
+    let Some(previous) = change.previous.clone() else {
+        UI::hint(&format!(
+            "Use 'vx uninstall {}@{}' to remove it",
+            name, change.installed
+        ));
+        anyhow::bail!(
+            "{} {} was the first version installed, there is nothing to roll back to",
+            name,
+            change.installed
+        );
+    };
+
+    if !runtime.is_installed(&previous, ctx).await? {
+        UI::info(&format!("Reinstalling {} {}...", name, previous));
+        let mut untracked = Transaction::untracked();
+        super::install::install_recorded(
+            registry,
+            ctx,
+            &[format!("{}@{}", name, previous)],
+            false,
+            &mut untracked,
+        )
+        .await?;
+    }
+
+    if runtime.is_installed(&change.installed, ctx).await? {
+        super::remove::handle(registry, ctx, &name, Some(&change.installed), true).await?;
+    }
+
+    journal.mark_rolled_back(id, &name);
+    journal.save()?;
+
+    UI::success(&format!(
+        "Rolled back {} {} → {}",
+        name, change.installed, previous
+    ));
+    Ok(())
+}
@@ -0,0 +1,250 @@
+//! Request loop and method handlers for `vx rpc`
+
+use super::protocol::{self, ExecuteParams, ListParams, Method, OutputLine, RpcError, ToolParams};
+use crate::commands::CommandContext;
+use crate::commands::common::project_tool_versions;
+use anyhow::{Context, Result};
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::Stdio;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWriteExt, BufReader};
+use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
+use tokio::task::JoinSet;
+use vx_runtime::{ProviderRegistry, RuntimeContext};
+
+/// Handle `vx rpc`
+pub async fn handle(ctx: &CommandContext) -> Result<()> {
+    // Every message goes through one writer so lines never interleave
+    let (tx, mut rx) = unbounded_channel::<Value>();
+    let writer = tokio::spawn(async move {
+        let mut stdout = tokio::io::stdout();
+        while let Some(message) = rx.recv().await {
+            let line = format!("{}\n", message);
+            if stdout.write_all(line.as_bytes()).await.is_err() || stdout.flush().await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    let mut tasks = JoinSet::new();
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let request = match protocol::parse_request(&line) {
+            Ok(request) => request,
+            Err((id, error)) => {
+                let _ = tx.send(protocol::error_response(id, &error));
+                continue;
+            }
+        };
+
+        if request.method == Method::Shutdown {
+            while tasks.join_next().await.is_some() {}
+            if let Some(id) = request.id {
+                let _ = tx.send(protocol::response(id, Value::Null));
+            }
+            break;
+        }
+
+        let registry = ctx.registry.clone();
+        let context = ctx.runtime_context.clone();
+        let tx = tx.clone();
+        tasks.spawn(async move {
+            let id = request.id.unwrap_or(Value::Null);
+            let result = dispatch(&registry, &context, request.method, &id, &tx).await;
+            // Requests without an id are notifications and get no response
+            if !id.is_null() {
+                let message = match result {
+                    Ok(result) => protocol::response(id, result),
+                    Err(error) => protocol::error_response(Some(id), &error),
+                };
+                let _ = tx.send(message);
+            }
+        });
+    }
+
+    while tasks.join_next().await.is_some() {}
+    drop(tx);
+    let _ = writer.await;
+    Ok(())
+}
+
+async fn dispatch(
+    registry: &ProviderRegistry,
+    context: &RuntimeContext,
+    method: Method,
+    id: &Value,
+    tx: &UnboundedSender<Value>,
+) -> Result<Value, RpcError> {
+    let result = match method {
+        Method::List(params) => list(registry, context, &params).await,
+        Method::Resolve(params) => resolve(registry, context, &params).await,
+        Method::Install(params) => install(registry, context, &params, id, tx).await,
+        Method::Execute(params) => execute(&params, id, tx).await,
+        Method::Shutdown => Ok(Value::Null),
+    };
+    result.map_err(|e| RpcError::failed(&e))
+}
+
+/// Supported runtimes and their installed versions
+async fn list(
+    registry: &ProviderRegistry,
+    context: &RuntimeContext,
+    params: &ListParams,
+) -> Result<Value> {
+    let mut runtimes = Vec::new();
+    for name in registry.runtime_names() {
+        let Some(runtime) = registry.get_runtime(&name) else {
+            continue;
+        };
+        let installed = runtime
+            .installed_versions(context)
+            .await
+            .unwrap_or_default();
+        if params.installed && installed.is_empty() {
+            continue;
+        }
+        runtimes.push(json!({
+            "name": name,
+            "description": runtime.description(),
+            "installed": installed,
+        }));
+    }
+    Ok(json!({ "runtimes": runtimes }))
+}
+
+/// The version a tool resolves to and, when installed, its executable
+async fn resolve(
+    registry: &ProviderRegistry,
+    context: &RuntimeContext,
+    params: &ToolParams,
+) -> Result<Value> {
+    let runtime = registry
+        .get_runtime(&params.tool)
+        .ok_or_else(|| anyhow::anyhow!("Unknown tool: {}", params.tool))?;
+    let requested = params
+        .version
+        .clone()
+        .or_else(|| {
+            project_tool_versions()
+                .into_iter()
+                .find(|(tool, _)| tool == &params.tool || tool == runtime.name())
+                .map(|(_, version)| version)
+        })
+        .unwrap_or_else(|| "latest".to_string());
+
+    let version = runtime
+        .resolve_version(&requested, context)
+        .await
+        .with_context(|| format!("Failed to resolve {}@{}", params.tool, requested))?;
+    let installed = runtime
+        .is_installed(&version, context)
+        .await
+        .unwrap_or(false);
+    let executable = if installed {
+        runtime
+            .get_executable_path_for_version(&version, context)
+            .await
+            .ok()
+            .flatten()
+    } else {
+        None
+    };
+
+    Ok(json!({
+        "tool": runtime.name(),
+        "requested": requested,
+        "version": version,
+        "installed": installed,
+        "executable": executable,
+    }))
+}
+
+/// Install a tool, streaming `vx install` output as `progress` notifications
+async fn install(
+    registry: &ProviderRegistry,
+    context: &RuntimeContext,
+    params: &ToolParams,
+    id: &Value,
+    tx: &UnboundedSender<Value>,
+) -> Result<Value> {
+    let args = ["install".to_string(), params.spec()];
+    let code = run_child(&args, None, &BTreeMap::new(), "progress", id, tx).await?;
+    if code != 0 {
+        anyhow::bail!("vx install {} exited with code {}", params.spec(), code);
+    }
+    resolve(registry, context, params).await
+}
+
+/// Run a tool, streaming its output as `output` notifications
+async fn execute(params: &ExecuteParams, id: &Value, tx: &UnboundedSender<Value>) -> Result<Value> {
+    let code = run_child(
+        &params.child_args(),
+        params.cwd.as_deref(),
+        &params.env,
+        "output",
+        id,
+        tx,
+    )
+    .await?;
+    Ok(json!({ "exit_code": code }))
+}
+
+/// Run `vx <args>`, sending each line it writes as a `method` notification
+///
+/// Installs and tool runs happen in a child so nothing they print can reach
+/// the protocol stream on our stdout.
+async fn run_child(
+    args: &[String],
+    cwd: Option<&Path>,
+    env: &BTreeMap<String, String>,
+    method: &'static str,
+    request: &Value,
+    tx: &UnboundedSender<Value>,
+) -> Result<i32> {
+    let exe = std::env::current_exe().context("Failed to locate the vx executable")?;
+    let mut cmd = tokio::process::Command::new(exe);
+    cmd.args(args)
+        .env("NO_COLOR", "1")
+        .envs(env)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    if let Some(cwd) = cwd {
+        cmd.current_dir(cwd);
+    }
+    let mut child = cmd
+        .spawn()
+        .with_context(|| format!("Failed to run vx {}", args.join(" ")))?;
+
+    let stdout = child.stdout.take().context("Child stdout not captured")?;
+    let stderr = child.stderr.take().context("Child stderr not captured")?;
+    let (_, _, status) = tokio::join!(
+        forward_lines(stdout, "stdout", method, request, tx),
+        forward_lines(stderr, "stderr", method, request, tx),
+        child.wait(),
+    );
+    Ok(vx_runtime_core::exit_code_from_status(&status?))
+}
+
+async fn forward_lines(
+    reader: impl AsyncRead + Unpin,
+    stream: &'static str,
+    method: &'static str,
+    request: &Value,
+    tx: &UnboundedSender<Value>,
+) {
+    let mut lines = BufReader::new(reader).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        let params = OutputLine {
+            request: request.clone(),
+            stream,
+            line,
+        };
+        let _ = tx.send(protocol::notification(method, params));
+    }
+}
@@ -0,0 +1,30 @@
+//! JSON-RPC over stdio (`vx rpc`)
+//!
+//! Lets build systems and editors keep one vx running as a child process
+//! instead of paying a cold start per call. Each line on stdin is a JSON-RPC
+//! 2.0 request; each line on stdout is a response or a notification.
+//! Requests run concurrently and may complete out of order.
+//!
+//! | Method | Params | Result |
+//! |--------|--------|--------|
+//! | `list` | `{"installed"?: bool}` | `{"runtimes": [{name, description, installed}]}` |
+//! | `resolve` | `{"tool", "version"?}` | `{tool, requested, version, installed, executable}` |
+//! | `install` | `{"tool", "version"?}` | as `resolve`, once installed |
+//! | `execute` | `{"tool", "version"?, "args"?, "cwd"?, "env"?}` | `{"exit_code"}` |
+//! | `shutdown` | | `null`, after in-flight requests finish |
+//!
+//! `install` streams `progress` notifications and `execute` streams `output`
+//! notifications, one per line the child wrote:
+//! `{"request": <id>, "stream": "stdout" | "stderr", "line": "..."}`.
+//! Both run in a child `vx` so their output can't corrupt the protocol;
+//! `list` and `resolve` are answered in-process.
+
+mod handler;
+mod protocol;
+
+pub use handler::handle;
+pub use protocol::{
+    ExecuteParams, INVALID_PARAMS, INVALID_REQUEST, ListParams, METHOD_NOT_FOUND, Method,
+    OutputLine, PARSE_ERROR, REQUEST_FAILED, Request, RpcError, ToolParams, error_response,
+    notification, parse_request, response,
+};
@@ -0,0 +1,205 @@
+//! JSON-RPC 2.0 messages and method parameters for `vx rpc`
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Invalid JSON was received
+pub const PARSE_ERROR: i64 = -32700;
+/// The JSON sent is not a valid request object
+pub const INVALID_REQUEST: i64 = -32600;
+/// The method does not exist
+pub const METHOD_NOT_FOUND: i64 = -32601;
+/// Invalid method parameters
+pub const INVALID_PARAMS: i64 = -32602;
+/// The request failed (unknown tool, resolution or install error, ...)
+pub const REQUEST_FAILED: i64 = -32000;
+
+/// A JSON-RPC error: code and message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl RpcError {
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    /// A failed request, with the full error chain as the message
+    pub fn failed(error: &anyhow::Error) -> Self {
+        Self::new(REQUEST_FAILED, format!("{:#}", error))
+    }
+}
+
+/// A parsed request; notifications (no `id`) have `id == None`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    pub id: Option<Value>,
+    pub method: Method,
+}
+
+/// The methods `vx rpc` answers
+#[derive(Debug, Clone, PartialEq)]
+pub enum Method {
+    List(ListParams),
+    Resolve(ToolParams),
+    Install(ToolParams),
+    Execute(ExecuteParams),
+    /// Finish in-flight requests and exit
+    Shutdown,
+}
+
+/// `list` parameters
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ListParams {
+    /// Only runtimes with at least one installed version
+    pub installed: bool,
+}
+
+/// `resolve` and `install` parameters
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolParams {
+    pub tool: String,
+    /// Version or range; defaults to the project's pin, then `latest`
+    #[serde(default)]
+    pub version: Option<String>,
+}
+
+impl ToolParams {
+    /// `tool` or `tool@version`, as the CLI takes it
+    pub fn spec(&self) -> String {
+        match &self.version {
+            Some(version) => format!("{}@{}", self.tool, version),
+            None => self.tool.clone(),
+        }
+    }
+}
+
+/// `execute` parameters
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExecuteParams {
+    pub tool: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Working directory (defaults to the directory `vx rpc` started in)
+    #[serde(default)]
+    pub cwd: Option<PathBuf>,
+    /// Extra environment variables
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+}
+
+impl ExecuteParams {
+    /// Arguments for the `vx` child that runs the tool
+    pub fn child_args(&self) -> Vec<String> {
+        let tool = ToolParams {
+            tool: self.tool.clone(),
+            version: self.version.clone(),
+        };
+        std::iter::once(tool.spec())
+            .chain(self.args.iter().cloned())
+            .collect()
+    }
+}
+
+/// Parse one line of input
+///
+/// On failure the error carries the request `id` when one could be read, so
+/// the caller can answer it.
+pub fn parse_request(line: &str) -> Result<Request, (Option<Value>, RpcError)> {
+    let value: Value = serde_json::from_str(line).map_err(|e| {
+        (
+            None,
+            RpcError::new(PARSE_ERROR, format!("Parse error: {}", e)),
+        )
+    })?;
+    let Some(object) = value.as_object() else {
+        return Err((
+            None,
+            RpcError::new(INVALID_REQUEST, "Request must be a JSON object"),
+        ));
+    };
+    let id = object.get("id").cloned();
+    if object.get("jsonrpc").and_then(Value::as_str) != Some("2.0") {
+        return Err((
+            id,
+            RpcError::new(INVALID_REQUEST, "Missing \"jsonrpc\": \"2.0\""),
+        ));
+    }
+    let Some(method) = object.get("method").and_then(Value::as_str) else {
+        return Err((id, RpcError::new(INVALID_REQUEST, "Missing method")));
+    };
+    let params = object.get("params").cloned().unwrap_or(Value::Null);
+    match parse_method(method, params) {
+        Ok(method) => Ok(Request { id, method }),
+        Err(e) => Err((id, e)),
+    }
+}
+
+fn parse_method(method: &str, params: Value) -> Result<Method, RpcError> {
+    fn params_of<T: for<'de> Deserialize<'de> + Default>(params: Value) -> Result<T, RpcError> {
+        if params.is_null() {
+            return Ok(T::default());
+        }
+        serde_json::from_value(params)
+            .map_err(|e| RpcError::new(INVALID_PARAMS, format!("Invalid params: {}", e)))
+    }
+    fn required<T: for<'de> Deserialize<'de>>(params: Value) -> Result<T, RpcError> {
+        serde_json::from_value(params)
+            .map_err(|e| RpcError::new(INVALID_PARAMS, format!("Invalid params: {}", e)))
+    }
+
+    Ok(match method {
+        "list" => Method::List(params_of(params)?),
+        "resolve" => Method::Resolve(required(params)?),
+        "install" => Method::Install(required(params)?),
+        "execute" => Method::Execute(required(params)?),
+        "shutdown" => Method::Shutdown,
+        other => {
+            return Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Method not found: {}", other),
+            ));
+        }
+    })
+}
+
+/// A successful response
+pub fn response(id: Value, result: impl Serialize) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+/// An error response (`id` is `null` when the request's id is unknown)
+pub fn error_response(id: Option<Value>, error: &RpcError) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id.unwrap_or(Value::Null),
+        "error": { "code": error.code, "message": error.message },
+    })
+}
+
+/// A notification from vx, e.g. `progress` or `output`
+pub fn notification(method: &str, params: impl Serialize) -> Value {
+    json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
+
+/// Params of `progress` and `output` notifications: one line a child wrote
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OutputLine {
+    /// Id of the request the line belongs to
+    pub request: Value,
+    /// `stdout` or `stderr`
+    pub stream: &'static str,
+    pub line: String,
+}
@@ -0,0 +1,634 @@
+//! Run command implementation
+//!
+//! Executes scripts defined in vx.toml configuration file.
+//! Scripts are executed with the proper vx-managed tool environment.
+//!
+//! ## Features
+//!
+//! - **Dynamic Arguments**: Scripts can define arguments with types, defaults, and validation
+//! - **Variable Interpolation**: Use `{{var}}` syntax for dynamic values
+//! - **Environment Variables**: Automatic loading from `.env` files and config
+//! - **Passthrough Arguments**: Arguments after `--` are passed directly to the script
+//! - **Dependencies**: `depends`/`depends_on` scripts run first, independent ones in parallel
+//! - **Tool Variables**: `{{tools.<name>.bin}}` and `{{tools.<name>.version}}` for pinned tools
+//! - **Cross-Platform Scripts**: `windows`/`linux`/`macos` variants and a `portable` interpreter
+//! - **Egress Policy**: `[security.egress]` confines scripts to allowed domains
+//! - **Watch Mode**: `--watch` re-runs the script when its `[watch]` files change
+
+mod tasks;
+pub(crate) mod watch;
+
+use tasks::TaskGraph;
+use watch::Watcher;
+
+use anyhow::Result;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::commands::common::load_config_view_cwd;
+use crate::commands::dev::{build_script_environment_from_specs, resolve_tool_specs};
+use crate::commands::setup::ConfigView;
+use crate::egress::EgressSession;
+use crate::ui::UI;
+use vx_args::Interpolator;
+use vx_config::{ScriptConfig, ScriptDetails};
+use vx_env::{execute_portable, execute_with_env_in};
+
+/// Handle the run command - execute a script from vx.toml
+///
+/// This function:
+/// 1. Finds and parses vx.toml configuration
+/// 2. Handles --list to show available scripts
+/// 3. Handles -H/--script-help for script-specific help
+/// 4. Separates script args from passthrough args (after --)
+/// 5. Builds the environment with vx-managed tools in PATH
+/// 6. Runs the script's dependencies, in parallel where independent
+/// 7. Interpolates variables in the script command and executes it
+/// 8. With `watch`, repeats 6 and 7 whenever watched files change
+pub async fn handle(
+    script_name: Option<&str>,
+    list: bool,
+    script_help: bool,
+    jobs: Option<usize>,
+    watch: bool,
+    args: &[String],
+) -> Result<()> {
+    // Use common configuration loading
+    let (config_path, config) = load_config_view_cwd()?;
+
+    // Handle --list flag
+    if list {
+        print_available_scripts(&config)?;
+        return Ok(());
+    }
+
+    // Handle -H/--script-help flag
+    if script_help {
+        if let Some(name) = script_name {
+            print_script_help(name, &config)?;
+        } else {
+            print_run_help(&config)?;
+        }
+        return Ok(());
+    }
+
+    // If no script name provided, show usage
+    let script_name = match script_name {
+        Some(name) => name,
+        None => {
+            print_run_help(&config)?;
+            return Ok(());
+        }
+    };
+
+    // Get the script config
+    let script_config = config.scripts.get(script_name).ok_or_else(|| {
+        let available: Vec<_> = config.scripts.keys().collect();
+        if available.is_empty() {
+            anyhow::anyhow!("No scripts defined in vx.toml")
+        } else {
+            anyhow::anyhow!(
+                "Script '{}' not found. Available scripts: {}",
+                script_name,
+                available
+                    .iter()
+                    .map(|s| s.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        }
+    })?;
+    let script = script_config.for_current_os();
+
+    // `vx run build --watch` puts the flag among the script arguments. Take
+    // it for vx when the script has `[watch]` settings; otherwise it is left
+    // for the script (e.g. `jest --watch`).
+    let (watch, args) = match args.iter().position(|a| a == "--watch" || a == "--") {
+        Some(index) if args[index] == "--watch" && script.watch.is_some() => {
+            let mut args = args.to_vec();
+            args.remove(index);
+            (true, args)
+        }
+        _ => (watch, args.to_vec()),
+    };
+    let args = args.as_slice();
+
+    // Resolve the dependency graph before doing any work
+    let graph = TaskGraph::for_script(script_name, &config.scripts)?;
+
+    let project_dir = config_path.parent().ok_or_else(|| {
+        anyhow::anyhow!(
+            "config path has no parent directory: {}",
+            config_path.display()
+        )
+    })?;
+    let egress = EgressSession::start(config.egress.as_ref())?;
+    let mut context = TaskContext::new(&config, project_dir)?;
+    if let Some(egress) = &egress {
+        context.env.extend(egress.env_vars());
+    }
+
+    let jobs = jobs.unwrap_or_else(default_jobs);
+
+    if watch {
+        let mut watcher = Watcher::new(project_dir, script.watch.as_deref())?;
+        loop {
+            let run = ScriptRun {
+                name: script_name,
+                script: &script,
+                graph: &graph,
+                jobs,
+            };
+            match run.execute(&config, &context, args).await {
+                Ok(status) if status.success() => {
+                    UI::success(&format!("Script '{}' finished", script_name))
+                }
+                Ok(status) => UI::error(&format!(
+                    "Script '{}' failed with exit code {}",
+                    script_name,
+                    vx_resolver::exit_code_from_status(&status)
+                )),
+                Err(e) => UI::error(&format!("{:#}", e)),
+            }
+
+            // Changes made by the script itself do not trigger another run
+            watcher.changes();
+            UI::info(&format!(
+                "Watching {} file(s) for changes (Ctrl+C to stop)",
+                watcher.file_count()
+            ));
+            let (returned, changed) = tokio::task::spawn_blocking(move || {
+                let changed = watcher.wait();
+                (watcher, changed)
+            })
+            .await?;
+            watcher = returned;
+            UI::info(&format!(
+                "{} changed, re-running '{}'",
+                describe_changes(&changed),
+                script_name
+            ));
+        }
+    }
+
+    let run = ScriptRun {
+        name: script_name,
+        script: &script,
+        graph: &graph,
+        jobs,
+    };
+    let status = run.execute(&config, &context, args).await?;
+
+    if !status.success() {
+        // `exit` skips destructors, so report blocked destinations first
+        if let Some(egress) = &egress {
+            egress.report();
+        }
+        // Use exit_code_from_status to handle Ctrl+C gracefully
+        std::process::exit(vx_resolver::exit_code_from_status(&status));
+    }
+
+    Ok(())
+}
+
+/// One run of a script and its dependencies
+struct ScriptRun<'a> {
+    name: &'a str,
+    /// The script, resolved for the current platform
+    script: &'a ScriptDetails,
+    graph: &'a TaskGraph,
+    /// Dependency scripts run at the same time
+    jobs: usize,
+}
+
+impl ScriptRun<'_> {
+    /// Run the dependencies, then the script itself with `args`
+    async fn execute(
+        &self,
+        config: &ConfigView,
+        context: &TaskContext,
+        args: &[String],
+    ) -> Result<std::process::ExitStatus> {
+        let ScriptRun {
+            name: script_name,
+            script,
+            graph,
+            jobs,
+        } = *self;
+
+        // Split args at -- separator
+        let (script_args, passthrough_args) = split_args_at_separator(args);
+
+        // -------------------------
+        // Execute dependency scripts first, in parallel where independent
+        // -------------------------
+        if !graph.is_empty() {
+            let tasks: HashMap<String, Task> = graph
+                .order()
+                .iter()
+                .map(|name| {
+                    let script = config.scripts[name].for_current_os();
+                    let task = context.task(name, &script, &HashMap::new())?;
+                    Ok((name.clone(), task))
+                })
+                .collect::<Result<_>>()?;
+
+            graph
+                .run(jobs, move |name| {
+                    let task = &tasks[name];
+                    UI::info(&format!("Running dependency '{}': {}", name, task.command));
+                    let status = task.execute()?;
+                    if !status.success() {
+                        anyhow::bail!(
+                            "Dependency script '{}' failed with exit code {}",
+                            name,
+                            vx_resolver::exit_code_from_status(&status)
+                        );
+                    }
+                    Ok(())
+                })
+                .await?;
+        }
+
+        // Script arguments as variables (before --)
+        let mut arg_vars: HashMap<String, String> = HashMap::new();
+        for (i, arg) in script_args.iter().enumerate() {
+            arg_vars.insert(format!("arg{}", i + 1), arg.clone());
+            arg_vars.insert(i.to_string(), arg.clone());
+        }
+        arg_vars.insert("@".to_string(), script_args.join(" "));
+        arg_vars.insert("#".to_string(), script_args.len().to_string());
+
+        // Add passthrough arguments as {{args}} variable (after --)
+
+        // If no -- separator, use all args as passthrough for backward compatibility
+        let effective_passthrough = if args.contains(&"--".to_string()) {
+            passthrough_args
+        } else {
+            args.to_vec()
+        };
+        arg_vars.insert("args".to_string(), effective_passthrough.join(" "));
+
+        let mut task = context.task(script_name, script, &arg_vars)?;
+        let script_cmd = &script.command;
+
+        // Build the full command
+        if !script_cmd.contains("{{args}}") && !script_args.is_empty() {
+            // Legacy behavior: append script args if no {{args}} placeholder
+            let uses_placeholders = script_cmd.contains("{{") && script_cmd.contains("}}");
+            if !uses_placeholders {
+                // Append script arguments directly
+                task.command = format!("{} {}", task.command, script_args.join(" "));
+            }
+        }
+
+        UI::info(&format!(
+            "Running script '{}': {}",
+            script_name, task.command
+        ));
+
+        // Add parsed args as env vars (VX_ARG_*)
+        let mut var_source = task.env.clone();
+        var_source.extend(arg_vars);
+        for (key, value) in &var_source {
+            if !key.starts_with("VX_")
+                && !key.contains('.')
+                && key.chars().all(|c| c.is_alphanumeric() || c == '_')
+            {
+                task.env
+                    .insert(format!("VX_ARG_{}", key.to_uppercase()), value.clone());
+            }
+        }
+
+        // Execute the script with the proper environment
+        task.execute()
+    }
+}
+
+/// Short summary of changed files for the re-run message
+fn describe_changes(changed: &[PathBuf]) -> String {
+    match changed {
+        [file] => file.display().to_string(),
+        [first, rest @ ..] => format!("{} and {} more", first.display(), rest.len()),
+        [] => "Nothing".to_string(),
+    }
+}
+
+/// Default number of dependency scripts run at the same time
+fn default_jobs() -> usize {
+    std::thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Environment and variables shared by all scripts of one `vx run`
+struct TaskContext {
+    /// Project environment: vx-managed tools, `.env` files and `[env]`
+    env: HashMap<String, String>,
+    /// `{{tools.<name>.bin}}` and `{{tools.<name>.version}}`
+    tool_vars: HashMap<String, String>,
+    project_dir: PathBuf,
+    interpolator: Interpolator,
+}
+
+impl TaskContext {
+    fn new(config: &ConfigView, project_dir: &Path) -> Result<Self> {
+        // Build environment with vx-managed tools in PATH
+        let tool_specs = resolve_tool_specs(config)?;
+        let mut tool_vars = HashMap::new();
+        for spec in &tool_specs {
+            let bin = spec
+                .resolved_bin_dir
+                .as_ref()
+                .map(|dir| dir.display().to_string())
+                .unwrap_or_default();
+            tool_vars.insert(format!("tools.{}.bin", spec.name), bin);
+            tool_vars.insert(format!("tools.{}.version", spec.name), spec.version.clone());
+        }
+        let mut env = build_script_environment_from_specs(config, tool_specs)?;
+
+        // Load .env files
+        load_dotenv_files(project_dir, &mut env);
+
+        // Add config env vars
+        for (key, value) in &config.env {
+            env.insert(key.clone(), value.clone());
+        }
+
+        Ok(Self {
+            env,
+            tool_vars,
+            project_dir: project_dir.to_path_buf(),
+            interpolator: Interpolator::new().allow_missing(true),
+        })
+    }
+
+    /// Variables visible to interpolation for the given environment
+    fn variables(
+        &self,
+        env: &HashMap<String, String>,
+        extra: &HashMap<String, String>,
+    ) -> HashMap<String, String> {
+        let mut vars = env.clone();
+        for (key, value) in env {
+            vars.insert(format!("env.{}", key), value.clone());
+        }
+        vars.extend(self.tool_vars.clone());
+        vars.extend(extra.clone());
+        vars
+    }
+
+    /// Prepare one script for execution, interpolating its command, env and cwd
+    ///
+    /// `script` is already resolved for the current platform.
+    fn task(
+        &self,
+        name: &str,
+        script: &ScriptDetails,
+        extra: &HashMap<String, String>,
+    ) -> Result<Task> {
+        if script.command.trim().is_empty() {
+            anyhow::bail!(
+                "Script '{}' has no command for {}",
+                name,
+                std::env::consts::OS
+            );
+        }
+
+        // Script-level env vars override config-level ones and may refer to them
+        let mut env = self.env.clone();
+        let vars = self.variables(&self.env, extra);
+        for (key, value) in &script.env {
+            let value = self
+                .interpolator
+                .interpolate(value, &vars)
+                .map_err(|e| anyhow::anyhow!("Script '{}' env {}: {}", name, key, e))?;
+            env.insert(key.clone(), value);
+        }
+
+        let vars = self.variables(&env, extra);
+        let command = self
+            .interpolator
+            .interpolate(&script.command, &vars)
+            .map_err(|e| anyhow::anyhow!("Script '{}': {}", name, e))?;
+        let cwd = script
+            .cwd
+            .as_deref()
+            .map(|cwd| self.interpolator.interpolate(cwd, &vars))
+            .transpose()
+            .map_err(|e| anyhow::anyhow!("Script '{}' cwd: {}", name, e))?
+            .map(|cwd| self.project_dir.join(cwd));
+
+        Ok(Task {
+            command,
+            env,
+            cwd,
+            portable: script.portable,
+        })
+    }
+}
+
+/// A script ready to execute
+struct Task {
+    command: String,
+    env: HashMap<String, String>,
+    cwd: Option<PathBuf>,
+    /// Use vx's cross-platform interpreter instead of the system shell
+    portable: bool,
+}
+
+impl Task {
+    /// Run the command through vx-env's wrapper script or portable interpreter
+    fn execute(&self) -> Result<std::process::ExitStatus> {
+        if let Some(cwd) = &self.cwd
+            && !cwd.is_dir()
+        {
+            anyhow::bail!("Script working directory '{}' not found", cwd.display());
+        }
+        let status = if self.portable {
+            execute_portable(&self.command, &self.env, self.cwd.as_deref())?
+        } else {
+            execute_with_env_in(&self.command, &self.env, self.cwd.as_deref())?
+        };
+        Ok(status)
+    }
+}
+
+/// Print general run command help
+fn print_run_help(config: &ConfigView) -> Result<()> {
+    println!("Run a script defined in vx.toml");
+    println!();
+    println!("Usage: vx run [--jobs N] <SCRIPT> [ARGS...]");
+    println!("       vx run --list");
+    println!("       vx run <SCRIPT> -H");
+    println!();
+    println!("Options:");
+    println!("  -l, --list         List available scripts");
+    println!("  -H, --script-help  Show script-specific help");
+    println!("      --jobs <N>     Dependency scripts to run in parallel");
+    println!("  -h, --help         Show this help message");
+    println!();
+    println!("Arguments after the script name are passed to the script.");
+    println!("Use {{{{args}}}} in script command to receive all arguments.");
+    println!();
+    print_available_scripts(config)?;
+    Ok(())
+}
+
+/// Print available scripts
+fn print_available_scripts(config: &ConfigView) -> Result<()> {
+    if config.scripts.is_empty() {
+        println!("No scripts defined in vx.toml");
+        println!();
+        println!("Add scripts to your vx.toml:");
+        println!();
+        println!("  [scripts]");
+        println!("  test = \"cargo test\"");
+        println!("  build = \"cargo build --release\"");
+    } else {
+        println!("Available scripts:");
+        for (name, script) in &config.scripts {
+            let script = script.for_current_os();
+            let (cmd, desc) = (script.command.as_str(), script.description.as_deref());
+            // Truncate long commands
+            let display_cmd = if cmd.len() > 50 {
+                format!("{}...", &cmd[..47])
+            } else {
+                cmd.to_string()
+            };
+            if let Some(desc) = desc {
+                println!("  {:<15} {} ({})", name, display_cmd, desc);
+            } else {
+                println!("  {:<15} {}", name, display_cmd);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Split arguments at -- separator
+/// Returns (script_args, passthrough_args)
+fn split_args_at_separator(args: &[String]) -> (Vec<String>, Vec<String>) {
+    if let Some(pos) = args.iter().position(|arg| arg == "--") {
+        let script_args = args[..pos].to_vec();
+        let passthrough_args = args[pos + 1..].to_vec();
+        (script_args, passthrough_args)
+    } else {
+        (args.to_vec(), Vec::new())
+    }
+}
+
+/// Load .env files from the current directory
+fn load_dotenv_files(dir: &Path, env_vars: &mut HashMap<String, String>) {
+    // Load .env file
+    let dotenv_path = dir.join(".env");
+    if dotenv_path.exists()
+        && let Ok(iter) = dotenvy::from_path_iter(&dotenv_path)
+    {
+        for item in iter.flatten() {
+            env_vars.insert(item.0, item.1);
+        }
+    }
+
+    // Load .env.local file (higher priority)
+    let dotenv_local = dir.join(".env.local");
+    if dotenv_local.exists()
+        && let Ok(iter) = dotenvy::from_path_iter(&dotenv_local)
+    {
+        for item in iter.flatten() {
+            env_vars.insert(item.0, item.1);
+        }
+    }
+}
+
+/// Print help for a script
+fn print_script_help(script_name: &str, config: &ConfigView) -> Result<()> {
+    if let Some(script_config) = config.scripts.get(script_name) {
+        let resolved = script_config.for_current_os();
+        let cmd = resolved.command.as_str();
+        let details = match script_config {
+            ScriptConfig::Simple(_) => None,
+            ScriptConfig::Detailed(_) => Some(&resolved),
+        };
+
+        println!("Script: {}", script_name);
+        if let Some(d) = &details
+            && let Some(ref desc) = d.description
+        {
+            println!("Description: {}", desc);
+        }
+        println!("Command: {}", cmd);
+
+        if let Some(d) = &details {
+            if !d.depends.is_empty() {
+                println!("Dependencies: {}", d.depends.join(", "));
+            }
+            if let Some(ref cwd) = d.cwd {
+                println!("Working directory: {}", cwd);
+            }
+            if d.portable {
+                println!("Interpreter: portable (built-in)");
+            }
+            if !d.env.is_empty() {
+                println!("Environment:");
+                for (k, v) in &d.env {
+                    println!("  {} = {}", k, v);
+                }
+            }
+        }
+
+        println!();
+        println!(
+            "Usage: vx run {} [script-args...] [-- passthrough-args...]",
+            script_name
+        );
+
+        println!();
+        println!("Arguments:");
+        println!("  script-args       Arguments for script interpolation");
+        println!("  --                Separator for passthrough arguments");
+        println!("  passthrough-args  Arguments passed directly to the command");
+        println!();
+        println!("Variable Interpolation:");
+        println!("  {{{{arg1}}}}          First script argument");
+        println!("  {{{{arg2}}}}          Second script argument");
+        println!("  {{{{@}}}}             All script arguments");
+        println!("  {{{{#}}}}             Number of script arguments");
+        println!("  {{{{args}}}}          All passthrough arguments (after --)");
+        println!("  {{{{env.VAR}}}}       Environment variable VAR (including vx.toml env)");
+        println!("  {{{{tools.NAME.bin}}}}     Bin directory of pinned tool NAME");
+        println!("  {{{{tools.NAME.version}}}} Pinned version of tool NAME");
+        println!("  {{{{project.root}}}}  Project root directory");
+        println!("  {{{{project.name}}}}  Project name");
+        println!("  {{{{os.name}}}}       Operating system");
+        println!("  {{{{vx.version}}}}    VX version");
+        println!();
+        println!("Examples:");
+        println!("  vx run {} arg1 arg2", script_name);
+        println!("  vx run {} -- --flag value", script_name);
+        println!("  vx run {} script-arg -- --tool-flag", script_name);
+    } else {
+        println!("Script '{}' not found.", script_name);
+        println!();
+        println!("Run 'vx run --list' to see available scripts.");
+    }
+    Ok(())
+}
+
+/// List all available scripts in vx.toml
+pub async fn handle_list() -> Result<()> {
+    let (_config_path, config) = load_config_view_cwd()?;
+
+    if config.scripts.is_empty() {
+        UI::info("No scripts defined in vx.toml");
+        UI::hint(
+            "Add scripts to your vx.toml:\n\n[scripts]\nbuild = \"cargo build\"\ntest = \"cargo test\"",
+        );
+        return Ok(());
+    }
+
+    UI::info("Available scripts:");
+    for (name, script) in &config.scripts {
+        println!("  {} = \"{}\"", name, script.for_current_os().command);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,256 @@
+//! Script dependency graph and parallel scheduling
+//!
+//! A script's `depends` (or `depends_on`) form a DAG. Every script in it runs
+//! at most once, only after all of its own dependencies succeeded, and
+//! independent scripts run at the same time up to a job limit. After the
+//! first failure no new scripts start; the ones already running finish.
+
+use anyhow::{Result, bail};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::task::JoinSet;
+use vx_config::ScriptConfig;
+
+/// The dependencies of one script, resolved transitively
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskGraph {
+    /// Dependency scripts in a valid sequential order
+    order: Vec<String>,
+    /// Direct dependencies of each script in `order`
+    deps: HashMap<String, Vec<String>>,
+}
+
+impl TaskGraph {
+    /// Collect everything `target` depends on
+    ///
+    /// Fails on unknown scripts and on circular dependencies. The target
+    /// itself is not part of the graph.
+    pub fn for_script(target: &str, scripts: &HashMap<String, ScriptConfig>) -> Result<Self> {
+        let mut graph = Self {
+            order: Vec::new(),
+            deps: HashMap::new(),
+        };
+        let mut stack = vec![target.to_string()];
+        for dep in dependencies_of(target, scripts) {
+            graph.visit(dep, scripts, &mut stack)?;
+        }
+        Ok(graph)
+    }
+
+    fn visit(
+        &mut self,
+        name: &str,
+        scripts: &HashMap<String, ScriptConfig>,
+        stack: &mut Vec<String>,
+    ) -> Result<()> {
+        if self.deps.contains_key(name) {
+            return Ok(());
+        }
+        if let Some(start) = stack.iter().position(|s| s == name) {
+            bail!(
+                "Circular dependency detected: {} -> {}",
+                stack[start..].join(" -> "),
+                name
+            );
+        }
+        if !scripts.contains_key(name) {
+            bail!(
+                "Dependency script '{}' of '{}' not found in vx.toml",
+                name,
+                stack.last().map(String::as_str).unwrap_or_default()
+            );
+        }
+
+        stack.push(name.to_string());
+        let deps = dependencies_of(name, scripts);
+        for dep in deps {
+            self.visit(dep, scripts, stack)?;
+        }
+        stack.pop();
+
+        self.deps.insert(name.to_string(), deps.to_vec());
+        self.order.push(name.to_string());
+        Ok(())
+    }
+
+    /// Dependency scripts in a valid sequential order
+    pub fn order(&self) -> &[String] {
+        &self.order
+    }
+
+    /// Whether there is nothing to run before the target
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Run every script in the graph with `run`, at most `jobs` at a time
+    ///
+    /// `run` is called on a blocking thread and returns the script's result.
+    /// Returns the first failure after the scripts already started finished.
+    pub async fn run<F>(&self, jobs: usize, run: F) -> Result<()>
+    where
+        F: Fn(&str) -> Result<()> + Send + Sync + 'static,
+    {
+        let run = Arc::new(run);
+        let jobs = jobs.max(1);
+        let mut pending: Vec<&String> = self.order.iter().collect();
+        let mut done: HashSet<String> = HashSet::new();
+        let mut running = JoinSet::new();
+        let mut failure = None;
+
+        loop {
+            if failure.is_none() {
+                let mut index = 0;
+                while index < pending.len() && running.len() < jobs {
+                    let ready = self.deps[pending[index]].iter().all(|d| done.contains(d));
+                    if !ready {
+                        index += 1;
+                        continue;
+                    }
+                    let name = pending.remove(index).clone();
+                    let run = run.clone();
+                    running.spawn_blocking(move || {
+                        let result = run(&name);
+                        (name, result)
+                    });
+                }
+            }
+
+            let Some(joined) = running.join_next().await else {
+                break;
+            };
+            match joined {
+                Ok((name, Ok(()))) => {
+                    done.insert(name);
+                }
+                Ok((_, Err(e))) => {
+                    failure.get_or_insert(e);
+                }
+                Err(e) => {
+                    failure.get_or_insert(e.into());
+                }
+            }
+        }
+
+        match failure {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+fn dependencies_of<'a>(name: &str, scripts: &'a HashMap<String, ScriptConfig>) -> &'a [String] {
+    match scripts.get(name) {
+        Some(ScriptConfig::Detailed(details)) => &details.depends,
+        _ => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+    use vx_config::ScriptDetails;
+
+    fn scripts(entries: &[(&str, &[&str])]) -> HashMap<String, ScriptConfig> {
+        entries
+            .iter()
+            .map(|(name, deps)| {
+                let config = ScriptConfig::Detailed(ScriptDetails {
+                    command: format!("echo {}", name),
+                    depends: deps.iter().map(|d| d.to_string()).collect(),
+                    ..Default::default()
+                });
+                (name.to_string(), config)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_graph_order() {
+        let scripts = scripts(&[
+            ("ci", &["lint", "test"]),
+            ("test", &["build"]),
+            ("lint", &[]),
+            ("build", &[]),
+        ]);
+        let graph = TaskGraph::for_script("ci", &scripts).unwrap();
+        assert_eq!(graph.order(), ["lint", "build", "test"]);
+        assert!(TaskGraph::for_script("lint", &scripts).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_graph_errors() {
+        let cyclic = scripts(&[("a", &["b"]), ("b", &["a"])]);
+        let err = TaskGraph::for_script("a", &cyclic).unwrap_err();
+        assert!(err.to_string().contains("a -> b -> a"));
+
+        let missing = scripts(&[("a", &["b"])]);
+        let err = TaskGraph::for_script("a", &missing).unwrap_err();
+        assert!(err.to_string().contains("'b' of 'a' not found"));
+    }
+
+    #[tokio::test]
+    async fn test_independent_tasks_run_in_parallel() {
+        let scripts = scripts(&[
+            ("ci", &["lint", "test", "build"]),
+            ("lint", &[]),
+            ("test", &[]),
+            ("build", &[]),
+        ]);
+        let graph = TaskGraph::for_script("ci", &scripts).unwrap();
+
+        let active = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let (a, p) = (active.clone(), peak.clone());
+        graph
+            .run(3, move |_| {
+                let now = a.fetch_add(1, Ordering::SeqCst) + 1;
+                p.fetch_max(now, Ordering::SeqCst);
+                std::thread::sleep(Duration::from_millis(100));
+                a.fetch_sub(1, Ordering::SeqCst);
+                Ok(())
+            })
+            .await
+            .unwrap();
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_dependencies_finish_first_and_failures_stop_scheduling() {
+        let scripts = scripts(&[
+            ("ci", &["test", "deploy"]),
+            ("test", &["build"]),
+            ("deploy", &["test"]),
+            ("build", &[]),
+        ]);
+        let graph = TaskGraph::for_script("ci", &scripts).unwrap();
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let seen = log.clone();
+        graph
+            .run(4, move |name| {
+                seen.lock().unwrap().push(name.to_string());
+                Ok(())
+            })
+            .await
+            .unwrap();
+        assert_eq!(*log.lock().unwrap(), ["build", "test", "deploy"]);
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let seen = log.clone();
+        let result = graph
+            .run(4, move |name| {
+                seen.lock().unwrap().push(name.to_string());
+                if name == "test" {
+                    bail!("test failed");
+                }
+                Ok(())
+            })
+            .await;
+        assert_eq!(result.unwrap_err().to_string(), "test failed");
+        assert_eq!(*log.lock().unwrap(), ["build", "test"]);
+    }
+}
@@ -0,0 +1,421 @@
+//! File watching for `vx run --watch`
+//!
+//! Changes arrive as native filesystem events through the `notify` crate
+//! (inotify, FSEvents, ReadDirectoryChangesW). Where native watching is not
+//! available, for example when the inotify watch limit is reached, the tree is
+//! polled for modification times instead. A change only triggers a re-run once the watched files
+//! stayed quiet for the debounce period, so a burst of saves or a
+//! `git checkout` results in a single run.
+
+use anyhow::{Context, Result};
+use glob::{MatchOptions, Pattern};
+use notify::{Event, EventKind, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher as _};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, Instant};
+use vx_config::ScriptWatch;
+use walkdir::WalkDir;
+
+/// Directories that are never watched: VCS metadata and build output
+const IGNORED_DIRS: &[&str] = &[
+    ".git",
+    ".hg",
+    ".svn",
+    ".vx",
+    ".venv",
+    "__pycache__",
+    "node_modules",
+    "target",
+];
+
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Poll interval of the fallback watcher
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How long [`Watcher::changes`] waits for events still in flight
+const SETTLE: Duration = Duration::from_millis(50);
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// The `notify` watcher feeding the event channel; dropping it stops watching
+#[derive(Debug)]
+#[allow(dead_code)]
+enum Backend {
+    Native(RecommendedWatcher),
+    Poll(PollWatcher),
+}
+
+/// Watches the files of one script for changes
+#[derive(Debug)]
+pub struct Watcher {
+    root: PathBuf,
+    paths: Vec<Pattern>,
+    ignore: Vec<Pattern>,
+    debounce: Duration,
+    files: BTreeSet<PathBuf>,
+    events: Receiver<notify::Result<Event>>,
+    _backend: Backend,
+}
+
+impl Watcher {
+    /// Start watching `root` with the script's `[watch]` settings
+    ///
+    /// Without settings every file below `root` is watched.
+    pub fn new(root: &Path, settings: Option<&ScriptWatch>) -> Result<Self> {
+        Self::with_backend(root, settings, false)
+    }
+
+    fn with_backend(root: &Path, settings: Option<&ScriptWatch>, poll: bool) -> Result<Self> {
+        let settings = settings.cloned().unwrap_or_default();
+        let compile = |patterns: &[String]| {
+            patterns
+                .iter()
+                .map(|p| {
+                    Pattern::new(p.trim_start_matches("./"))
+                        .with_context(|| format!("Invalid watch pattern '{}'", p))
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+
+        // Events report paths below the watched path as the OS resolves it
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        let (tx, events) = mpsc::channel();
+        let native = if poll {
+            None
+        } else {
+            RecommendedWatcher::new(tx.clone(), notify::Config::default())
+                .and_then(|mut watcher| {
+                    watcher.watch(&root, RecursiveMode::Recursive)?;
+                    Ok(watcher)
+                })
+                .inspect_err(|e| {
+                    tracing::debug!("Native file watching unavailable, polling instead: {}", e)
+                })
+                .ok()
+        };
+        let backend = match native {
+            Some(watcher) => Backend::Native(watcher),
+            None => {
+                let config = notify::Config::default().with_poll_interval(POLL_INTERVAL);
+                let mut watcher =
+                    PollWatcher::new(tx, config).context("Failed to start the file watcher")?;
+                watcher
+                    .watch(&root, RecursiveMode::Recursive)
+                    .with_context(|| format!("Failed to watch {}", root.display()))?;
+                Backend::Poll(watcher)
+            }
+        };
+
+        let mut watcher = Self {
+            root,
+            paths: compile(&settings.paths)?,
+            ignore: compile(&settings.ignore)?,
+            debounce: settings
+                .debounce
+                .map_or(DEFAULT_DEBOUNCE, Duration::from_millis),
+            files: BTreeSet::new(),
+            events,
+            _backend: backend,
+        };
+        watcher.files = watcher.scan(&watcher.root);
+        Ok(watcher)
+    }
+
+    /// Number of files currently watched
+    pub fn file_count(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Files added, modified or removed since the last call, relative to the root
+    pub fn changes(&mut self) -> Vec<PathBuf> {
+        std::thread::sleep(SETTLE);
+        let mut changed = BTreeSet::new();
+        while let Ok(event) = self.events.try_recv() {
+            self.apply(event, &mut changed);
+        }
+        changed.into_iter().collect()
+    }
+
+    /// Block until files change, then until they stay quiet for the debounce period
+    ///
+    /// Returns every file that changed in the meantime.
+    pub fn wait(&mut self) -> Vec<PathBuf> {
+        self.wait_timeout(None)
+    }
+
+    /// [`Watcher::wait`], giving up with no changes after `timeout`
+    fn wait_timeout(&mut self, timeout: Option<Duration>) -> Vec<PathBuf> {
+        let give_up = timeout.map(|t| Instant::now() + t);
+        let mut changed = BTreeSet::new();
+        while changed.is_empty() {
+            let event = match give_up {
+                Some(at) => self
+                    .events
+                    .recv_timeout(at.saturating_duration_since(Instant::now()))
+                    .ok(),
+                None => self.events.recv().ok(),
+            };
+            // Timed out, or the backend hung up
+            let Some(event) = event else {
+                return Vec::new();
+            };
+            self.apply(event, &mut changed);
+        }
+
+        // Events outside the watched files (build output) don't extend the wait
+        let mut quiet_at = Instant::now() + self.debounce;
+        while let Ok(event) = self
+            .events
+            .recv_timeout(quiet_at.saturating_duration_since(Instant::now()))
+        {
+            if self.apply(event, &mut changed) {
+                quiet_at = Instant::now() + self.debounce;
+            }
+        }
+        changed.into_iter().collect()
+    }
+
+    /// Record the watched files touched by `event` in `changed`
+    ///
+    /// Returns whether any watched file was touched.
+    fn apply(&mut self, event: notify::Result<Event>, changed: &mut BTreeSet<PathBuf>) -> bool {
+        let event = match event {
+            Ok(event) => event,
+            Err(e) => {
+                tracing::debug!("File watcher error: {}", e);
+                return false;
+            }
+        };
+        // The backend lost events: compare against a fresh scan
+        if event.need_rescan() {
+            let current = self.scan(&self.root);
+            let before = changed.len();
+            changed.extend(current.symmetric_difference(&self.files).cloned());
+            self.files = current;
+            return changed.len() > before;
+        }
+        // Reading a file is not a change
+        if matches!(event.kind, EventKind::Access(_)) {
+            return false;
+        }
+
+        let mut touched = false;
+        for path in &event.paths {
+            let Some(relative) = self.relative(path) else {
+                continue;
+            };
+            if self.in_skipped_dir(&relative) {
+                continue;
+            }
+            let relative_path = PathBuf::from(&relative);
+            match std::fs::metadata(path) {
+                // A directory that appeared (mkdir, rename, checkout) may hold
+                // files created before its own watch was added
+                Ok(metadata) if metadata.is_dir() => {
+                    for file in self.scan(path) {
+                        if self.files.insert(file.clone()) {
+                            changed.insert(file);
+                            touched = true;
+                        }
+                    }
+                }
+                Ok(_) => {
+                    if self.matches(&relative) {
+                        self.files.insert(relative_path.clone());
+                        changed.insert(relative_path);
+                        touched = true;
+                    }
+                }
+                // Removed: the file itself, or every file below a directory
+                Err(_) => {
+                    let removed: Vec<PathBuf> = self
+                        .files
+                        .iter()
+                        .filter(|file| file.starts_with(&relative_path))
+                        .cloned()
+                        .collect();
+                    for file in removed {
+                        self.files.remove(&file);
+                        changed.insert(file);
+                        touched = true;
+                    }
+                }
+            }
+        }
+        touched
+    }
+
+    /// Watched files below `dir`, relative to the root
+    fn scan(&self, dir: &Path) -> BTreeSet<PathBuf> {
+        let walker = WalkDir::new(dir).into_iter().filter_entry(|entry| {
+            let Some(relative) = self.relative(entry.path()) else {
+                return true;
+            };
+            !entry.file_type().is_dir() || !self.skips_dir(&relative)
+        });
+
+        walker
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file())
+            .filter_map(|entry| {
+                let relative = self.relative(entry.path())?;
+                self.matches(&relative).then(|| PathBuf::from(relative))
+            })
+            .collect()
+    }
+
+    /// `path` relative to the root with `/` separators; `None` for the root itself
+    fn relative(&self, path: &Path) -> Option<String> {
+        let relative = path.strip_prefix(&self.root).ok()?;
+        if relative.as_os_str().is_empty() {
+            return None;
+        }
+        Some(relative.to_string_lossy().replace('\\', "/"))
+    }
+
+    /// Whether the directory at `relative` is never watched
+    fn skips_dir(&self, relative: &str) -> bool {
+        let name = relative.rsplit('/').next().unwrap_or(relative);
+        IGNORED_DIRS.contains(&name) || self.is_ignored(relative)
+    }
+
+    /// Whether `relative` lies below a directory that is never watched
+    fn in_skipped_dir(&self, relative: &str) -> bool {
+        relative
+            .match_indices('/')
+            .any(|(i, _)| self.skips_dir(&relative[..i]))
+    }
+
+    fn is_ignored(&self, relative: &str) -> bool {
+        self.ignore.iter().any(|p| {
+            p.matches_with(relative, MATCH_OPTIONS)
+                || p.matches_with(&format!("{}/", relative), MATCH_OPTIONS)
+        })
+    }
+
+    fn matches(&self, relative: &str) -> bool {
+        if self.is_ignored(relative) {
+            return false;
+        }
+        self.paths.is_empty()
+            || self
+                .paths
+                .iter()
+                .any(|p| p.matches_with(relative, MATCH_OPTIONS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const TIMEOUT: Option<Duration> = Some(Duration::from_secs(10));
+
+    fn watch(paths: &[&str], ignore: &[&str]) -> ScriptWatch {
+        ScriptWatch {
+            paths: paths.iter().map(|p| p.to_string()).collect(),
+            ignore: ignore.iter().map(|p| p.to_string()).collect(),
+            debounce: Some(50),
+        }
+    }
+
+    #[rstest]
+    #[case::native(false)]
+    #[case::poll(true)]
+    fn test_changes(#[case] poll: bool) {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::write(root.join("a.txt"), "a").unwrap();
+        fs::write(root.join("b.txt"), "b").unwrap();
+
+        let mut watcher = Watcher::with_backend(root, Some(&watch(&[], &[])), poll).unwrap();
+        assert_eq!(watcher.file_count(), 2);
+        assert!(watcher.changes().is_empty());
+
+        // The poll watcher compares modification times in whole seconds
+        if poll {
+            std::thread::sleep(Duration::from_millis(1100));
+        }
+        fs::write(root.join("a.txt"), "changed").unwrap();
+        fs::remove_file(root.join("b.txt")).unwrap();
+        fs::create_dir(root.join("src")).unwrap();
+        fs::write(root.join("src/c.txt"), "c").unwrap();
+        assert_eq!(
+            watcher.wait_timeout(TIMEOUT),
+            [
+                PathBuf::from("a.txt"),
+                PathBuf::from("b.txt"),
+                PathBuf::from("src/c.txt")
+            ]
+        );
+        assert_eq!(watcher.file_count(), 2);
+    }
+
+    #[test]
+    fn test_paths_and_ignore_patterns() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        for file in [
+            "Cargo.toml",
+            "README.md",
+            "src/main.rs",
+            "src/gen/out.rs",
+            "target/debug/app.rs",
+            "node_modules/x/index.rs",
+        ] {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "x").unwrap();
+        }
+
+        let settings = watch(&["src/**/*.rs", "Cargo.toml"], &["src/gen"]);
+        let mut watcher = Watcher::new(root, Some(&settings)).unwrap();
+        assert_eq!(watcher.file_count(), 2);
+
+        fs::write(root.join("README.md"), "changed").unwrap();
+        fs::write(root.join("src/gen/out.rs"), "changed").unwrap();
+        fs::write(root.join("target/debug/app.rs"), "changed").unwrap();
+        fs::create_dir_all(root.join("target/release")).unwrap();
+        fs::write(root.join("target/release/app.rs"), "x").unwrap();
+        assert!(
+            watcher
+                .wait_timeout(Some(Duration::from_millis(300)))
+                .is_empty()
+        );
+
+        fs::write(root.join("src/main.rs"), "changed").unwrap();
+        assert_eq!(
+            watcher.wait_timeout(TIMEOUT),
+            [PathBuf::from("src/main.rs")]
+        );
+
+        let err = Watcher::new(root, Some(&watch(&["src/[.rs"], &[]))).unwrap_err();
+        assert!(err.to_string().contains("Invalid watch pattern 'src/[.rs'"));
+    }
+
+    #[test]
+    fn test_wait_collects_a_burst_of_changes() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().to_path_buf();
+        let mut watcher = Watcher::new(&root, Some(&watch(&[], &[]))).unwrap();
+
+        let writer = std::thread::spawn(move || {
+            for name in ["one", "two", "three"] {
+                std::thread::sleep(Duration::from_millis(20));
+                fs::write(root.join(name), name).unwrap();
+            }
+        });
+        let changed = watcher.wait();
+        writer.join().unwrap();
+        assert_eq!(changed.len(), 3);
+    }
+}
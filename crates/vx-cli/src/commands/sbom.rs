@@ -0,0 +1,396 @@
+//! SBOM command implementation
+//!
+//! `vx sbom` describes every tool version installed in the vx store as a
+//! CycloneDX 1.5 or SPDX 2.3 JSON document, so security teams can audit the
+//! developer toolchain with their usual SBOM tooling.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! vx sbom                              # CycloneDX to stdout
+//! vx sbom --format spdx -o vx.spdx.json
+//! ```
+//!
+//! Each component carries the SHA-256 of its installed executable. Inside a
+//! project, the download source and artifact checksum recorded in `vx.lock`
+//! are included for the locked versions.
+
+use crate::cli::SbomFormat;
+use crate::registry::find_release_sources;
+use crate::ui::UI;
+use anyhow::{Context, Result};
+use std::path::Path;
+use vx_paths::project::{LOCK_FILE_NAME, find_vx_config};
+use vx_paths::{PathManager, PathResolver, SystemManagedRecord};
+use vx_resolver::LockFile;
+use vx_runtime::{Platform, ProviderRegistry};
+
+/// An installed tool version
+#[derive(Debug, Clone, Default)]
+pub struct SbomComponent {
+    pub name: String,
+    pub version: String,
+    pub description: Option<String>,
+    pub homepage: Option<String>,
+    pub repository: Option<String>,
+    pub license: Option<String>,
+    /// URL the artifact is downloaded from
+    pub download_url: Option<String>,
+    /// Artifact checksum recorded in vx.lock (`sha256:<hex>`)
+    pub artifact_checksum: Option<String>,
+    /// SHA-256 of the installed executable
+    pub executable_sha256: Option<String>,
+    /// Package manager owning a system-managed install (winget, choco)
+    pub system_manager: Option<String>,
+    /// Whether the version is pinned in vx.lock
+    pub locked: bool,
+}
+
+impl SbomComponent {
+    /// Reference used to link the component inside the document
+    pub fn bom_ref(&self) -> String {
+        format!("{}@{}", self.name, self.version)
+    }
+
+    /// Package URL (`pkg:generic/<name>@<version>`)
+    pub fn purl(&self) -> String {
+        let mut purl = format!("pkg:generic/{}@{}", self.name, self.version);
+        if let Some(ref url) = self.download_url {
+            purl.push_str("?download_url=");
+            purl.push_str(&percent_encode(url));
+        }
+        purl
+    }
+}
+
+/// Split a checksum like `sha256:<hex>` into algorithm and digest.
+///
+/// Checksums without an algorithm prefix are taken as SHA-256.
+pub fn split_checksum(checksum: &str) -> (String, String) {
+    match checksum.split_once(':') {
+        Some((alg, digest)) => (alg.to_ascii_lowercase(), digest.to_string()),
+        None => ("sha256".to_string(), checksum.to_string()),
+    }
+}
+
+/// Render a CycloneDX 1.5 JSON document
+pub fn render_cyclonedx(
+    components: &[SbomComponent],
+    serial: &str,
+    timestamp: &str,
+) -> Result<String> {
+    let components: Vec<serde_json::Value> = components
+        .iter()
+        .map(|c| {
+            let mut component = serde_json::json!({
+                "type": "application",
+                "bom-ref": c.bom_ref(),
+                "name": c.name,
+                "version": c.version,
+                "purl": c.purl(),
+            });
+            if let Some(ref description) = c.description {
+                component["description"] = description.as_str().into();
+            }
+            if let Some(ref sha) = c.executable_sha256 {
+                component["hashes"] = serde_json::json!([{ "alg": "SHA-256", "content": sha }]);
+            }
+            if let Some(ref license) = c.license {
+                component["licenses"] = serde_json::json!([{ "expression": license }]);
+            }
+
+            let mut references = Vec::new();
+            if let Some(ref url) = c.download_url {
+                let mut reference = serde_json::json!({ "type": "distribution", "url": url });
+                if let Some((alg, digest)) = c.artifact_checksum.as_deref().map(split_checksum) {
+                    reference["hashes"] =
+                        serde_json::json!([{ "alg": cyclonedx_alg(&alg), "content": digest }]);
+                }
+                references.push(reference);
+            }
+            if let Some(ref url) = c.homepage {
+                references.push(serde_json::json!({ "type": "website", "url": url }));
+            }
+            if let Some(ref url) = c.repository {
+                references.push(serde_json::json!({ "type": "vcs", "url": url }));
+            }
+            if !references.is_empty() {
+                component["externalReferences"] = references.into();
+            }
+
+            let mut properties = vec![serde_json::json!({
+                "name": "vx:locked",
+                "value": c.locked.to_string(),
+            })];
+            if let Some(ref manager) = c.system_manager {
+                properties
+                    .push(serde_json::json!({ "name": "vx:system-manager", "value": manager }));
+            }
+            component["properties"] = properties.into();
+            component
+        })
+        .collect();
+
+    let document = serde_json::json!({
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "serialNumber": format!("urn:uuid:{}", serial),
+        "version": 1,
+        "metadata": {
+            "timestamp": timestamp,
+            "tools": {
+                "components": [{
+                    "type": "application",
+                    "name": "vx",
+                    "version": env!("CARGO_PKG_VERSION"),
+                }],
+            },
+        },
+        "components": components,
+    });
+
+    let mut json = serde_json::to_string_pretty(&document)?;
+    json.push('\n');
+    Ok(json)
+}
+
+/// Render an SPDX 2.3 JSON document
+pub fn render_spdx(
+    components: &[SbomComponent],
+    namespace_id: &str,
+    timestamp: &str,
+) -> Result<String> {
+    let spdx_id = |c: &SbomComponent| {
+        let id: String = c
+            .bom_ref()
+            .chars()
+            .map(|ch| {
+                if ch.is_ascii_alphanumeric() || ch == '.' {
+                    ch
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        format!("SPDXRef-Package-{}", id)
+    };
+
+    let packages: Vec<serde_json::Value> = components
+        .iter()
+        .map(|c| {
+            let mut package = serde_json::json!({
+                "SPDXID": spdx_id(c),
+                "name": c.name,
+                "versionInfo": c.version,
+                "downloadLocation": c.download_url.as_deref().unwrap_or("NOASSERTION"),
+                "filesAnalyzed": false,
+                "licenseConcluded": "NOASSERTION",
+                "licenseDeclared": c.license.as_deref().unwrap_or("NOASSERTION"),
+                "copyrightText": "NOASSERTION",
+                "externalRefs": [{
+                    "referenceCategory": "PACKAGE-MANAGER",
+                    "referenceType": "purl",
+                    "referenceLocator": c.purl(),
+                }],
+            });
+            if let Some(ref description) = c.description {
+                package["summary"] = description.as_str().into();
+            }
+            if let Some(ref homepage) = c.homepage {
+                package["homepage"] = homepage.as_str().into();
+            }
+
+            // Package checksums cover the downloaded artifact; the installed
+            // executable's hash is kept in a comment
+            if let Some((alg, digest)) = c.artifact_checksum.as_deref().map(split_checksum) {
+                package["checksums"] = serde_json::json!([{
+                    "algorithm": alg.to_ascii_uppercase(),
+                    "checksumValue": digest,
+                }]);
+            }
+            let mut comment = Vec::new();
+            if let Some(ref sha) = c.executable_sha256 {
+                comment.push(format!("Installed executable SHA-256: {}", sha));
+            }
+            if let Some(ref manager) = c.system_manager {
+                comment.push(format!("Installed by {}", manager));
+            }
+            if !comment.is_empty() {
+                package["comment"] = comment.join("; ").into();
+            }
+            package
+        })
+        .collect();
+
+    let relationships: Vec<serde_json::Value> = components
+        .iter()
+        .map(|c| {
+            serde_json::json!({
+                "spdxElementId": "SPDXRef-DOCUMENT",
+                "relationshipType": "DESCRIBES",
+                "relatedSpdxElement": spdx_id(c),
+            })
+        })
+        .collect();
+
+    let document = serde_json::json!({
+        "spdxVersion": "SPDX-2.3",
+        "dataLicense": "CC0-1.0",
+        "SPDXID": "SPDXRef-DOCUMENT",
+        "name": "vx-toolchain",
+        "documentNamespace": format!("https://vx.dev/spdx/vx-toolchain-{}", namespace_id),
+        "creationInfo": {
+            "created": timestamp,
+            "creators": [format!("Tool: vx-{}", env!("CARGO_PKG_VERSION"))],
+        },
+        "packages": packages,
+        "relationships": relationships,
+    });
+
+    let mut json = serde_json::to_string_pretty(&document)?;
+    json.push('\n');
+    Ok(json)
+}
+
+/// Handle `vx sbom`
+pub async fn handle(
+    registry: &ProviderRegistry,
+    format: SbomFormat,
+    output: Option<&Path>,
+) -> Result<()> {
+    let components = collect_components(registry).await?;
+    if components.is_empty() {
+        UI::warn("No tools are installed in the vx store");
+    }
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let timestamp = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+    let document = match format {
+        SbomFormat::Cyclonedx => render_cyclonedx(&components, &id, &timestamp)?,
+        SbomFormat::Spdx => render_spdx(&components, &id, &timestamp)?,
+    };
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, document)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            UI::success(&format!(
+                "Wrote SBOM with {} components to {}",
+                components.len(),
+                path.display()
+            ));
+        }
+        None => print!("{}", document),
+    }
+    Ok(())
+}
+
+/// Describe every installed store version, sorted by tool name
+async fn collect_components(registry: &ProviderRegistry) -> Result<Vec<SbomComponent>> {
+    let paths = PathManager::new()?;
+    let resolver = PathResolver::new(paths.clone());
+    let lock = project_lock_file();
+    let platform = Platform::current();
+    let platform_key = platform.as_str();
+
+    let mut components = Vec::new();
+    for tool in paths.list_store_runtimes()? {
+        let runtime = registry.get_runtime(&tool);
+        let sources = find_release_sources(&tool).unwrap_or_default();
+        let exe_name = runtime
+            .as_ref()
+            .map(|r| r.executable_name().to_string())
+            .unwrap_or_else(|| tool.clone());
+
+        for version in paths.list_store_versions(&tool)? {
+            let locked = lock
+                .as_ref()
+                .and_then(|lock| lock.get_tool(&tool))
+                .filter(|locked| locked.version == version);
+            let record = SystemManagedRecord::read(&paths.version_store_dir(&tool, &version));
+
+            let download_url = match locked.and_then(|l| l.download_url_for_platform(&platform_key))
+            {
+                Some(url) => Some(url.clone()),
+                None => match runtime {
+                    Some(ref runtime) => runtime
+                        .download_url(&version, &platform)
+                        .await
+                        .ok()
+                        .flatten(),
+                    None => None,
+                },
+            };
+            let executable = record
+                .as_ref()
+                .and_then(|r| r.existing_executable().map(Path::to_path_buf))
+                .or_else(|| {
+                    resolver
+                        .find_tool_version_with_executable(&tool, &version, &exe_name)
+                        .map(|loc| loc.path)
+                });
+
+            components.push(SbomComponent {
+                name: tool.clone(),
+                version: version.clone(),
+                description: runtime
+                    .as_ref()
+                    .map(|r| r.description().to_string())
+                    .filter(|d| !d.is_empty()),
+                homepage: sources.homepage.clone(),
+                repository: sources.repository.clone(),
+                license: sources.license.clone(),
+                download_url,
+                artifact_checksum: locked.and_then(|l| l.checksum.clone()),
+                executable_sha256: executable.and_then(|exe| sha256_file(&exe).ok()),
+                system_manager: record.map(|r| r.manager),
+                locked: locked.is_some(),
+            });
+        }
+    }
+    Ok(components)
+}
+
+/// The `vx.lock` of the project in the current directory, if any
+fn project_lock_file() -> Option<LockFile> {
+    let cwd = std::env::current_dir().ok()?;
+    let config = find_vx_config(&cwd).ok()?;
+    let path = config.parent()?.join(LOCK_FILE_NAME);
+    if !path.exists() {
+        return None;
+    }
+    LockFile::load(&path)
+        .map_err(|e| UI::warn(&format!("Ignoring {}: {}", path.display(), e)))
+        .ok()
+}
+
+fn sha256_file(path: &Path) -> Result<String> {
+    let bytes = std::fs::read(path)?;
+    let digest = crate::oci::sha256_digest(&bytes);
+    Ok(digest.trim_start_matches("sha256:").to_string())
+}
+
+/// CycloneDX hash algorithm name (`SHA-256`, `SHA-512`, ...)
+fn cyclonedx_alg(alg: &str) -> String {
+    match alg {
+        "sha256" => "SHA-256".to_string(),
+        "sha384" => "SHA-384".to_string(),
+        "sha512" => "SHA-512".to_string(),
+        "sha1" => "SHA-1".to_string(),
+        "md5" => "MD5".to_string(),
+        other => other.to_ascii_uppercase(),
+    }
+}
+
+/// Percent-encode a purl qualifier value
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
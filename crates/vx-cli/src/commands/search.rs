@@ -48,21 +48,21 @@ pub async fn handle(
     let query = query.unwrap_or_default();
     let query_lower = query.to_lowercase();
 
-    // Collect results
-    let mut results = Vec::new();
-    for name in registry.runtime_names() {
-        if (query.is_empty() || name.to_lowercase().contains(&query_lower))
-            && let Some(runtime) = registry.get_runtime(&name)
-        {
-            let description = runtime.description().to_string();
-            if query.is_empty()
-                || description.to_lowercase().contains(&query_lower)
-                || name.to_lowercase().contains(&query_lower)
-            {
-                results.push(SearchResult { name, description });
-            }
-        }
-    }
+    // Collect results from the registry index, without loading every provider
+    let index = crate::registry::registry_index(registry);
+    let results: Vec<SearchResult> = index
+        .runtimes()
+        .iter()
+        .filter(|runtime| {
+            query.is_empty()
+                || runtime.name.to_lowercase().contains(&query_lower)
+                || runtime.description.to_lowercase().contains(&query_lower)
+        })
+        .map(|runtime| SearchResult {
+            name: runtime.name.clone(),
+            description: runtime.description.clone(),
+        })
+        .collect();
 
     let total = results.len();
     let output = SearchOutput {
@@ -139,7 +139,7 @@ async fn try_axoupdater(
         updater.set_github_token(token);
     } else if let Ok(token) = env::var("VX_GITHUB_TOKEN") {
         updater.set_github_token(&token);
-    } else if let Ok(token) = env::var("GITHUB_TOKEN") {
+    } else if let Some(token) = vx_proxy::credentials::github_token() {
         updater.set_github_token(&token);
     }
 
@@ -472,7 +472,7 @@ fn create_authenticated_client(token: Option<&str>) -> Result<reqwest::Client> {
         UI::detail("No GitHub token provided, preferring CDN downloads");
     }
 
-    let client = reqwest::Client::builder()
+    let client = vx_proxy::client_builder()
         .default_headers(headers)
         .timeout(std::time::Duration::from_secs(30))
         .build()?;
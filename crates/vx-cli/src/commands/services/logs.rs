@@ -0,0 +1,674 @@
+//! Service log streaming and archiving
+//!
+//! `vx services logs` reads `podman logs --timestamps` of one or more
+//! services concurrently and prints the lines with a color-coded service
+//! prefix, or as JSON lines with `--json`.
+//!
+//! Every line read is also appended to a log archive so logs outlive their
+//! containers:
+//!
+//! ```text
+//! ~/.vx/services/logs/<project>/
+//! ├── postgres.log      # JSON lines, newest
+//! ├── postgres.log.1    # rotated, older
+//! └── postgres.log.5    # oldest kept
+//! ```
+//!
+//! Containers are archived before they are removed (`stop`, forced `start`),
+//! and services without a container are shown from the archive. A file is
+//! rotated once it exceeds `log_max_size_mb` (default 10) or its first line
+//! is older than `log_max_age_days` (default 7).
+
+use super::ContainerRuntime;
+use crate::ui::UI;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use colored::{Color, Colorize};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use vx_config::ServiceConfig;
+
+/// Default size at which a log file is rotated
+pub const DEFAULT_MAX_SIZE_MB: u64 = 10;
+
+/// Default age at which a log file is rotated
+pub const DEFAULT_MAX_AGE_DAYS: u64 = 7;
+
+/// Rotated files kept per service
+const KEEP_ROTATED: usize = 5;
+
+/// Prefix colors, assigned to services in order
+const COLORS: [Color; 6] = [
+    Color::Cyan,
+    Color::Green,
+    Color::Yellow,
+    Color::Magenta,
+    Color::Blue,
+    Color::Red,
+];
+
+/// Output stream a log line was written to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogStream {
+    Stdout,
+    Stderr,
+}
+
+/// One line of service output
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogLine {
+    pub service: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<DateTime<Utc>>,
+    pub stream: LogStream,
+    pub message: String,
+}
+
+impl LogLine {
+    /// Parse a line of `podman logs --timestamps` output
+    ///
+    /// Lines without a leading RFC 3339 timestamp are kept whole.
+    pub fn parse(service: &str, stream: LogStream, raw: &str) -> Self {
+        let raw = raw.trim_end_matches(['\r', '\n']);
+        let (timestamp, message) = match raw.split_once(' ') {
+            Some((first, rest)) => match DateTime::parse_from_rfc3339(first) {
+                Ok(ts) => (Some(ts.to_utc()), rest),
+                Err(_) => (None, raw),
+            },
+            None => match DateTime::parse_from_rfc3339(raw) {
+                Ok(ts) => (Some(ts.to_utc()), ""),
+                Err(_) => (None, raw),
+            },
+        };
+        Self {
+            service: service.to_string(),
+            timestamp,
+            stream,
+            message: message.to_string(),
+        }
+    }
+}
+
+/// Parse `--since`: a duration ago (`30s`, `10m`, `2h`, `1d`), an RFC 3339
+/// timestamp or a date (`2024-05-01`)
+pub fn parse_since(value: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(ts) = DateTime::parse_from_rfc3339(value) {
+        return Ok(ts.to_utc());
+    }
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_time(chrono::NaiveTime::MIN).and_utc());
+    }
+
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+    let amount: i64 = amount
+        .parse()
+        .with_context(|| format!("Invalid --since '{}'", value))?;
+    let duration = match unit {
+        "s" => chrono::Duration::seconds(amount),
+        "m" => chrono::Duration::minutes(amount),
+        "h" => chrono::Duration::hours(amount),
+        "d" => chrono::Duration::days(amount),
+        _ => anyhow::bail!(
+            "Invalid --since '{}' (use e.g. 30s, 10m, 2h, 1d, 2024-05-01 or an RFC 3339 time)",
+            value
+        ),
+    };
+    Ok(now - duration)
+}
+
+/// `--since` and `--grep` filters
+#[derive(Debug, Default)]
+pub struct LogFilter {
+    pub since: Option<DateTime<Utc>>,
+    pub grep: Option<Regex>,
+}
+
+impl LogFilter {
+    /// Whether `line` passes the filters
+    ///
+    /// Lines without a timestamp pass `since`.
+    pub fn matches(&self, line: &LogLine) -> bool {
+        let recent = match (self.since, line.timestamp) {
+            (Some(since), Some(ts)) => ts >= since,
+            _ => true,
+        };
+        recent
+            && self
+                .grep
+                .as_ref()
+                .is_none_or(|re| re.is_match(&line.message))
+    }
+}
+
+/// When a service's log file is rotated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    pub max_size: u64,
+    pub max_age: chrono::Duration,
+}
+
+impl Default for Rotation {
+    fn default() -> Self {
+        Self {
+            max_size: DEFAULT_MAX_SIZE_MB * 1024 * 1024,
+            max_age: chrono::Duration::days(DEFAULT_MAX_AGE_DAYS as i64),
+        }
+    }
+}
+
+impl Rotation {
+    /// Rotation limits configured for a service
+    pub fn for_service(config: &ServiceConfig) -> Self {
+        Self {
+            max_size: config.log_max_size_mb.unwrap_or(DEFAULT_MAX_SIZE_MB) * 1024 * 1024,
+            max_age: chrono::Duration::days(
+                config.log_max_age_days.unwrap_or(DEFAULT_MAX_AGE_DAYS) as i64,
+            ),
+        }
+    }
+}
+
+/// Archived logs of one project
+#[derive(Debug, Clone)]
+pub struct LogArchive {
+    dir: PathBuf,
+}
+
+impl LogArchive {
+    /// Archive of `project` under `~/.vx/services/logs`
+    pub fn for_project(project: &str) -> Result<Self> {
+        let paths = vx_paths::VxPaths::new()?;
+        Ok(Self::at(
+            paths.base_dir.join("services").join("logs").join(project),
+        ))
+    }
+
+    /// Archive in `dir`
+    pub fn at(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Current log file of `service`
+    pub fn path(&self, service: &str) -> PathBuf {
+        self.dir.join(format!("{}.log", service))
+    }
+
+    fn rotated_path(&self, service: &str, index: usize) -> PathBuf {
+        self.dir.join(format!("{}.log.{}", service, index))
+    }
+
+    /// Archived lines of `service`, oldest first
+    pub fn read(&self, service: &str) -> Vec<LogLine> {
+        (1..=KEEP_ROTATED)
+            .rev()
+            .map(|i| self.rotated_path(service, i))
+            .chain(std::iter::once(self.path(service)))
+            .flat_map(|path| read_file(&path))
+            .collect()
+    }
+
+    /// A writer appending to the log file of `service`
+    pub fn writer(&self, service: &str, rotation: Rotation) -> Result<LogWriter> {
+        std::fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+        let mut writer = LogWriter {
+            archive: self.clone(),
+            service: service.to_string(),
+            rotation,
+            file: None,
+            size: 0,
+            first: None,
+            last: None,
+        };
+        writer.open()?;
+        Ok(writer)
+    }
+
+    fn rotate(&self, service: &str) -> Result<()> {
+        let _ = std::fs::remove_file(self.rotated_path(service, KEEP_ROTATED));
+        for index in (1..KEEP_ROTATED).rev() {
+            let from = self.rotated_path(service, index);
+            if from.exists() {
+                std::fs::rename(&from, self.rotated_path(service, index + 1))?;
+            }
+        }
+        std::fs::rename(self.path(service), self.rotated_path(service, 1))?;
+        Ok(())
+    }
+}
+
+/// Appends lines of one service to its archive, rotating as needed
+///
+/// Lines not newer than the last archived one are skipped, so the same
+/// container output can be archived repeatedly.
+#[derive(Debug)]
+pub struct LogWriter {
+    archive: LogArchive,
+    service: String,
+    rotation: Rotation,
+    file: Option<File>,
+    size: u64,
+    first: Option<DateTime<Utc>>,
+    last: Option<DateTime<Utc>>,
+}
+
+impl LogWriter {
+    fn open(&mut self) -> Result<()> {
+        let path = self.archive.path(&self.service);
+        let existing = read_file(&path);
+        self.first = existing.iter().find_map(|l| l.timestamp);
+        self.last = existing
+            .iter()
+            .rev()
+            .find_map(|l| l.timestamp)
+            .or(self.last);
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        self.size = file.metadata().map(|m| m.len()).unwrap_or(0);
+        self.file = Some(file);
+        Ok(())
+    }
+
+    /// Timestamp of the newest archived line
+    pub fn last_timestamp(&self) -> Option<DateTime<Utc>> {
+        self.last
+    }
+
+    /// Append `line` unless it is already archived
+    pub fn write(&mut self, line: &LogLine) -> Result<()> {
+        if let (Some(ts), Some(last)) = (line.timestamp, self.last)
+            && ts <= last
+        {
+            return Ok(());
+        }
+
+        let expired = match (self.first, line.timestamp) {
+            (Some(first), Some(ts)) => ts - first >= self.rotation.max_age,
+            _ => false,
+        };
+        if self.size > 0 && (self.size >= self.rotation.max_size || expired) {
+            self.file = None;
+            self.archive.rotate(&self.service)?;
+            self.first = None;
+            self.open()?;
+        }
+
+        let mut json = serde_json::to_string(line)?;
+        json.push('\n');
+        let file = self.file.as_mut().context("Log file is not open")?;
+        file.write_all(json.as_bytes())?;
+
+        self.size += json.len() as u64;
+        if line.timestamp.is_some() {
+            self.first = self.first.or(line.timestamp);
+            self.last = line.timestamp;
+        }
+        Ok(())
+    }
+}
+
+/// Lines of one archive file; lines cut short by a crash are skipped
+fn read_file(path: &Path) -> Vec<LogLine> {
+    std::fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// Archive the output of a container that is about to be removed
+///
+/// Failures are logged; losing logs must not block stopping a service.
+pub fn archive_container(
+    runtime: &ContainerRuntime,
+    project_name: &str,
+    name: &str,
+    config: &ServiceConfig,
+) {
+    let result = (|| -> Result<usize> {
+        let archive = LogArchive::for_project(project_name)?;
+        let mut writer = archive.writer(name, Rotation::for_service(config))?;
+
+        let mut args = vec!["logs".to_string(), "--timestamps".to_string()];
+        if let Some(last) = writer.last_timestamp() {
+            args.push("--since".to_string());
+            args.push(last.to_rfc3339());
+        }
+        args.push(format!("vx-{}-{}", project_name, name));
+
+        let output = Command::new(runtime.command()).args(&args).output()?;
+        if !output.status.success() {
+            return Ok(0);
+        }
+
+        let mut lines: Vec<_> = [
+            (LogStream::Stdout, &output.stdout),
+            (LogStream::Stderr, &output.stderr),
+        ]
+        .into_iter()
+        .flat_map(|(stream, bytes)| {
+            String::from_utf8_lossy(bytes)
+                .lines()
+                .map(|raw| LogLine::parse(name, stream, raw))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+        lines.sort_by_key(|line| line.timestamp);
+
+        for line in &lines {
+            writer.write(line)?;
+        }
+        Ok(lines.len())
+    })();
+
+    match result {
+        Ok(count) => tracing::debug!(service = name, count, "Archived service logs"),
+        Err(e) => tracing::warn!(service = name, error = %e, "Failed to archive service logs"),
+    }
+}
+
+/// Options of `vx services logs`
+#[derive(Debug, Default)]
+pub struct LogOptions {
+    pub follow: bool,
+    pub tail: Option<usize>,
+    pub json: bool,
+    pub filter: LogFilter,
+}
+
+/// Show logs of `services`, archiving every line read from a container
+///
+/// Services without a container are shown from the archive.
+pub fn stream(
+    runtime: &ContainerRuntime,
+    project_name: &str,
+    services: &[(String, ServiceConfig)],
+    options: &LogOptions,
+) -> Result<()> {
+    let archive = LogArchive::for_project(project_name)?;
+    let printer = Printer {
+        json: options.json,
+        prefix: services.len() > 1,
+        width: services
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or(0),
+    };
+    let print = |line: &LogLine| printer.print(line, color_for(services, &line.service));
+
+    let (tx, rx) = mpsc::channel::<LogLine>();
+    let mut children = Vec::new();
+    // Without --follow, everything is merged by time before printing
+    let mut buffered = Vec::new();
+
+    for (name, _) in services {
+        let container = format!("vx-{}-{}", project_name, name);
+        let exists = Command::new(runtime.command())
+            .args(["container", "exists", &container])
+            .status()
+            .is_ok_and(|s| s.success());
+
+        if !exists {
+            let mut lines: Vec<_> = archive
+                .read(name)
+                .into_iter()
+                .filter(|line| options.filter.matches(line))
+                .collect();
+            if let Some(n) = options.tail {
+                lines.drain(..lines.len().saturating_sub(n));
+            }
+            if lines.is_empty() {
+                UI::warn(&format!(
+                    "No logs for '{}': it is not running and has no archived logs",
+                    name
+                ));
+            } else if options.follow {
+                UI::warn(&format!("'{}' is not running; showing archived logs", name));
+                lines.iter().for_each(print);
+            } else {
+                buffered.extend(lines);
+            }
+            continue;
+        }
+
+        let mut args = vec!["logs".to_string(), "--timestamps".to_string()];
+        if options.follow {
+            args.push("-f".to_string());
+        }
+        if let Some(n) = options.tail {
+            args.push("--tail".to_string());
+            args.push(n.to_string());
+        }
+        if let Some(since) = options.filter.since {
+            args.push("--since".to_string());
+            args.push(since.to_rfc3339());
+        }
+        args.push(container);
+
+        let mut child = Command::new(runtime.command())
+            .args(&args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("Failed to get logs")?;
+        if let Some(stdout) = child.stdout.take() {
+            forward(stdout, name, LogStream::Stdout, tx.clone());
+        }
+        if let Some(stderr) = child.stderr.take() {
+            forward(stderr, name, LogStream::Stderr, tx.clone());
+        }
+        children.push(child);
+    }
+    drop(tx);
+
+    let mut writers: HashMap<String, Option<LogWriter>> = HashMap::new();
+    for line in rx {
+        let writer = writers.entry(line.service.clone()).or_insert_with(|| {
+            let config = services
+                .iter()
+                .find(|(name, _)| *name == line.service)
+                .map(|(_, config)| Rotation::for_service(config))
+                .unwrap_or_default();
+            archive
+                .writer(&line.service, config)
+                .inspect_err(|e| tracing::warn!(error = %e, "Failed to open service log archive"))
+                .ok()
+        });
+        if let Some(writer) = writer
+            && let Err(e) = writer.write(&line)
+        {
+            tracing::warn!(error = %e, "Failed to archive service log line");
+        }
+
+        if !options.filter.matches(&line) {
+            continue;
+        }
+        if options.follow {
+            print(&line);
+        } else {
+            buffered.push(line);
+        }
+    }
+
+    for mut child in children {
+        child.wait().context("Failed to get logs")?;
+    }
+    buffered.sort_by_key(|line| line.timestamp);
+    buffered.iter().for_each(print);
+    Ok(())
+}
+
+/// Send the lines of a `podman logs` output stream to `tx`
+fn forward(
+    reader: impl Read + Send + 'static,
+    service: &str,
+    stream: LogStream,
+    tx: mpsc::Sender<LogLine>,
+) {
+    let service = service.to_string();
+    std::thread::spawn(move || {
+        for raw in BufReader::new(reader).lines().map_while(Result::ok) {
+            if tx.send(LogLine::parse(&service, stream, &raw)).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+fn color_for(services: &[(String, ServiceConfig)], service: &str) -> Color {
+    let index = services
+        .iter()
+        .position(|(name, _)| name == service)
+        .unwrap_or(0);
+    COLORS[index % COLORS.len()]
+}
+
+struct Printer {
+    json: bool,
+    prefix: bool,
+    width: usize,
+}
+
+impl Printer {
+    fn print(&self, line: &LogLine, color: Color) {
+        if self.json {
+            if let Ok(json) = serde_json::to_string(line) {
+                println!("{}", json);
+            }
+        } else if self.prefix {
+            let prefix = format!("{:<width$} |", line.service, width = self.width);
+            println!("{} {}", prefix.color(color), line.message);
+        } else {
+            println!("{}", line.message);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(ts: &str, message: &str) -> LogLine {
+        LogLine::parse("db", LogStream::Stdout, &format!("{} {}", ts, message))
+    }
+
+    #[test]
+    fn test_parse_podman_line() {
+        let parsed = line("2024-05-01T10:00:00.123456789+02:00", "ready to accept");
+        assert_eq!(
+            parsed.timestamp.unwrap().to_rfc3339(),
+            "2024-05-01T08:00:00.123456789+00:00"
+        );
+        assert_eq!(parsed.message, "ready to accept");
+
+        let plain = LogLine::parse("db", LogStream::Stderr, "no timestamp here\n");
+        assert_eq!(plain.timestamp, None);
+        assert_eq!(plain.message, "no timestamp here");
+    }
+
+    #[test]
+    fn test_parse_since() {
+        let now = DateTime::parse_from_rfc3339("2024-05-02T00:00:00Z")
+            .unwrap()
+            .to_utc();
+        assert_eq!(
+            parse_since("90m", now).unwrap().to_rfc3339(),
+            "2024-05-01T22:30:00+00:00"
+        );
+        assert_eq!(
+            parse_since("1d", now).unwrap(),
+            parse_since("2024-05-01", now).unwrap()
+        );
+        assert!(parse_since("2024-05-01T12:00:00Z", now).is_ok());
+        assert!(parse_since("10y", now).is_err());
+        assert!(parse_since("soon", now).is_err());
+    }
+
+    #[test]
+    fn test_filter() {
+        let filter = LogFilter {
+            since: Some(line("2024-05-01T10:00:00Z", "").timestamp.unwrap()),
+            grep: Some(Regex::new("ERROR|FATAL").unwrap()),
+        };
+        assert!(filter.matches(&line("2024-05-01T10:00:01Z", "ERROR boom")));
+        assert!(!filter.matches(&line("2024-05-01T09:59:59Z", "ERROR old")));
+        assert!(!filter.matches(&line("2024-05-01T10:00:01Z", "all good")));
+    }
+
+    #[test]
+    fn test_writer_skips_archived_lines() {
+        let temp = tempfile::tempdir().unwrap();
+        let archive = LogArchive::at(temp.path());
+
+        let mut writer = archive.writer("db", Rotation::default()).unwrap();
+        writer.write(&line("2024-05-01T10:00:00Z", "one")).unwrap();
+        writer.write(&line("2024-05-01T10:00:01Z", "two")).unwrap();
+        drop(writer);
+
+        // Reading the same container output again only appends new lines
+        let mut writer = archive.writer("db", Rotation::default()).unwrap();
+        writer.write(&line("2024-05-01T10:00:01Z", "two")).unwrap();
+        writer
+            .write(&line("2024-05-01T10:00:02Z", "three"))
+            .unwrap();
+
+        let messages: Vec<_> = archive.read("db").into_iter().map(|l| l.message).collect();
+        assert_eq!(messages, ["one", "two", "three"]);
+    }
+
+    #[test]
+    fn test_writer_rotates_by_size_and_age() {
+        let temp = tempfile::tempdir().unwrap();
+        let archive = LogArchive::at(temp.path());
+
+        let by_size = Rotation {
+            max_size: 1,
+            ..Default::default()
+        };
+        let mut writer = archive.writer("db", by_size).unwrap();
+        for second in 0..8 {
+            let ts = format!("2024-05-01T10:00:0{}Z", second);
+            writer.write(&line(&ts, &second.to_string())).unwrap();
+        }
+        // One line per file; only the newest KEEP_ROTATED rotated files remain
+        assert!(archive.rotated_path("db", KEEP_ROTATED).exists());
+        assert!(!archive.rotated_path("db", KEEP_ROTATED + 1).exists());
+        let messages: Vec<_> = archive.read("db").into_iter().map(|l| l.message).collect();
+        assert_eq!(messages, ["2", "3", "4", "5", "6", "7"]);
+
+        let by_age = Rotation {
+            max_age: chrono::Duration::days(1),
+            ..Default::default()
+        };
+        let mut writer = archive.writer("api", by_age).unwrap();
+        writer
+            .write(&line("2024-05-01T10:00:00Z", "monday"))
+            .unwrap();
+        writer
+            .write(&line("2024-05-01T20:00:00Z", "evening"))
+            .unwrap();
+        assert!(!archive.rotated_path("api", 1).exists());
+        writer
+            .write(&line("2024-05-02T10:00:00Z", "tuesday"))
+            .unwrap();
+        assert!(archive.rotated_path("api", 1).exists());
+        assert_eq!(read_file(&archive.path("api")).len(), 1);
+    }
+}
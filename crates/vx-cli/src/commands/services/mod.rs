@@ -0,0 +1,895 @@
+//! Services command - Manage development services
+//!
+//! This command manages services defined in `vx.toml` using Podman.
+//!
+//! ## Configuration Example
+//!
+//! ```toml
+//! [services.postgres]
+//! image = "postgres:15"
+//! ports = ["5432:5432"]
+//! env = { POSTGRES_PASSWORD = "dev" }
+//! healthcheck = "pg_isready -U postgres"
+//!
+//! [services.redis]
+//! image = "redis:7"
+//! ports = ["6379:6379"]
+//!
+//! [services.api]
+//! image = "my-api:dev"
+//! depends_on = ["postgres", "redis"]
+//! healthcheck_url = "http://localhost:8080/health"
+//! startup_timeout = 120
+//! restart = "on-failure"
+//! ```
+//!
+//! Services start in dependency order. A service with `healthcheck` or
+//! `healthcheck_url` must report healthy before its dependents start.
+//!
+//! The `command` of a command-based service may use `{tool:<name>}` to refer
+//! to the pinned version of a project tool, e.g.
+//! `command = "{tool:node} server.js"`. The vx store holding the tool is
+//! mounted read-only into the container at the same path.
+//!
+//! ## Commands
+//!
+//! - `vx services start` - Start all services
+//! - `vx services stop` - Stop all services
+//! - `vx services status` - Show service status
+//! - `vx services logs [services]` - Show and archive service logs (see [`logs`])
+//! - `vx services supervise` - Restart crashed services (see [`supervisor`])
+
+pub mod logs;
+pub mod supervisor;
+
+use crate::commands::common::{load_full_config_cwd, resolve_placeholder_tools};
+use crate::ui::UI;
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use supervisor::{ServiceState, SupervisorState};
+use vx_config::{RestartPolicy, ServiceConfig};
+
+/// Default time to wait for a service to become healthy
+const DEFAULT_STARTUP_TIMEOUT: u64 = 60;
+
+/// Delay between health probes
+const HEALTH_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Container runtime (Podman)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContainerRuntime {
+    Podman,
+}
+
+impl ContainerRuntime {
+    /// Detect available container runtime
+    pub fn detect() -> Option<Self> {
+        if Command::new("podman")
+            .arg("--version")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false)
+        {
+            return Some(ContainerRuntime::Podman);
+        }
+
+        None
+    }
+
+    /// Get the command name
+    pub fn command(&self) -> &str {
+        match self {
+            ContainerRuntime::Podman => "podman",
+        }
+    }
+}
+
+/// Service status
+#[derive(Debug, Clone)]
+pub struct ServiceStatus {
+    pub name: String,
+    pub running: bool,
+    pub container_id: Option<String>,
+    pub ports: Vec<String>,
+    pub health: Option<String>,
+}
+
+/// Handle services start command
+pub async fn handle_start(
+    services: Option<Vec<String>>,
+    detach: bool,
+    force: bool,
+    supervise: bool,
+    verbose: bool,
+) -> Result<()> {
+    let (config_path, config) = load_full_config_cwd()?;
+
+    if config.services.is_empty() {
+        UI::warn("No services defined in vx.toml");
+        println!();
+        println!("Add services to your vx.toml:");
+        println!();
+        println!("  [services.postgres]");
+        println!("  image = \"postgres:15\"");
+        println!("  ports = [\"5432:5432\"]");
+        println!("  env = {{ POSTGRES_PASSWORD = \"dev\" }}");
+        return Ok(());
+    }
+
+    let runtime = ContainerRuntime::detect()
+        .ok_or_else(|| anyhow::anyhow!("No container runtime found. Please install Podman."))?;
+
+    UI::header("🚀 Starting Services");
+    println!();
+
+    // Selected services pull in their dependencies
+    let ordered = order_by_dependencies(&config.services, services.as_deref())?;
+
+    let project_name = get_project_name(&config_path);
+
+    let commands: Vec<&str> = ordered
+        .iter()
+        .filter_map(|name| config.services[name].command.as_deref())
+        .collect();
+    let tools = resolve_placeholder_tools(&config, &commands)?;
+
+    // A manual start resets the supervisor's restart counters
+    SupervisorState::update(&config_path, &ordered, ServiceState::started)?;
+
+    for name in &ordered {
+        let name = name.as_str();
+        let service_config = &config.services[name];
+        start_service(
+            &runtime,
+            &project_name,
+            name,
+            service_config,
+            &tools,
+            detach,
+            force,
+            verbose,
+        )?;
+        // Foreground containers only return once they exit
+        if detach {
+            wait_for_health(&runtime, &project_name, name, service_config).await?;
+        }
+    }
+
+    println!();
+    UI::success("All services started");
+
+    let has_restart_policy = ordered
+        .iter()
+        .any(|name| config.services[name].restart.unwrap_or_default() != RestartPolicy::No);
+    if supervise && detach {
+        println!();
+        supervisor::handle_supervise(Some(ordered), supervisor::DEFAULT_INTERVAL, verbose).await?;
+    } else if has_restart_policy && detach {
+        UI::hint("Run 'vx services supervise' to restart crashed services automatically");
+    }
+
+    Ok(())
+}
+
+/// Handle services stop command
+pub async fn handle_stop(services: Option<Vec<String>>, verbose: bool) -> Result<()> {
+    let (config_path, config) = load_full_config_cwd()?;
+
+    if config.services.is_empty() {
+        UI::warn("No services defined in vx.toml");
+        return Ok(());
+    }
+
+    let runtime = ContainerRuntime::detect()
+        .ok_or_else(|| anyhow::anyhow!("No container runtime found. Please install Podman."))?;
+
+    UI::header("🛑 Stopping Services");
+    println!();
+
+    let project_name = get_project_name(&config_path);
+
+    // Stop in reverse dependency order, leaving unselected dependencies running
+    let ordered: Vec<_> = order_by_dependencies(&config.services, None)?
+        .into_iter()
+        .rev()
+        .filter(|name| services.as_ref().is_none_or(|names| names.contains(name)))
+        .collect();
+
+    // Mark first so a running supervisor does not restart them
+    SupervisorState::update(&config_path, &ordered, |state| state.stopped = true)?;
+
+    for name in ordered {
+        stop_service(
+            &runtime,
+            &project_name,
+            &name,
+            &config.services[&name],
+            verbose,
+        )?;
+    }
+
+    println!();
+    UI::success("All services stopped");
+
+    Ok(())
+}
+
+/// Handle services status command
+pub async fn handle_status(verbose: bool) -> Result<()> {
+    let (config_path, config) = load_full_config_cwd()?;
+
+    if config.services.is_empty() {
+        UI::warn("No services defined in vx.toml");
+        return Ok(());
+    }
+
+    let runtime = ContainerRuntime::detect()
+        .ok_or_else(|| anyhow::anyhow!("No container runtime found. Please install Podman."))?;
+
+    UI::header("📊 Service Status");
+
+    println!();
+
+    let project_name = get_project_name(&config_path);
+
+    let mut any_running = false;
+    let supervisor_state = if verbose {
+        SupervisorState::load(&SupervisorState::path(&config_path))?
+    } else {
+        SupervisorState::default()
+    };
+
+    for (name, service_config) in &config.services {
+        let status = get_service_status(&runtime, &project_name, name)?;
+
+        let status_icon = if status.running {
+            any_running = true;
+            "🟢"
+        } else {
+            "⚪"
+        };
+
+        let image = service_config.image.as_deref().unwrap_or("(command)");
+
+        if status.running {
+            println!("  {} {} ({})", status_icon, name, image);
+            if !status.ports.is_empty() {
+                println!("     Ports: {}", status.ports.join(", "));
+            }
+            if let Some(health) = &status.health {
+                println!("     Health: {}", health);
+            }
+            if verbose && let Some(id) = &status.container_id {
+                println!("     Container: {}", id);
+            }
+        } else {
+            println!("  {} {} ({})", status_icon, name, image);
+        }
+
+        if verbose {
+            print_restart_history(service_config, supervisor_state.services.get(name));
+        }
+    }
+
+    println!();
+    if any_running {
+        UI::info("Use 'vx services logs <service>' to view logs");
+    } else {
+        UI::info("Use 'vx services start' to start services");
+    }
+
+    Ok(())
+}
+
+/// Handle services logs command
+pub async fn handle_logs(
+    services: &[String],
+    follow: bool,
+    tail: Option<usize>,
+    since: Option<&str>,
+    grep: Option<&str>,
+    json: bool,
+) -> Result<()> {
+    let (config_path, config) = load_full_config_cwd()?;
+
+    let mut names: Vec<String> = if services.is_empty() {
+        config.services.keys().cloned().collect()
+    } else {
+        services.to_vec()
+    };
+    names.sort();
+    names.dedup();
+    if names.is_empty() {
+        UI::warn("No services defined in vx.toml");
+        return Ok(());
+    }
+
+    let mut selected = Vec::new();
+    for name in names {
+        let Some(service) = config.services.get(&name) else {
+            let mut available: Vec<_> = config.services.keys().map(String::as_str).collect();
+            available.sort_unstable();
+            anyhow::bail!(
+                "Service '{}' not found. Available: {}",
+                name,
+                available.join(", ")
+            );
+        };
+        selected.push((name, service.clone()));
+    }
+
+    let options = logs::LogOptions {
+        follow,
+        tail,
+        json,
+        filter: logs::LogFilter {
+            since: since
+                .map(|s| logs::parse_since(s, chrono::Utc::now()))
+                .transpose()?,
+            grep: grep
+                .map(regex::Regex::new)
+                .transpose()
+                .context("Invalid --grep pattern")?,
+        },
+    };
+
+    let runtime = ContainerRuntime::detect()
+        .ok_or_else(|| anyhow::anyhow!("No container runtime found. Please install Podman."))?;
+    let project_name = get_project_name(&config_path);
+
+    logs::stream(&runtime, &project_name, &selected, &options)
+}
+
+/// Handle services restart command
+pub async fn handle_restart(services: Option<Vec<String>>, verbose: bool) -> Result<()> {
+    handle_stop(services.clone(), verbose).await?;
+    handle_start(services, true, false, false, verbose).await?;
+    Ok(())
+}
+
+// ============================================
+// Helper functions
+// ============================================
+
+/// Print the restart policy and recent restarts of a service
+fn print_restart_history(config: &ServiceConfig, state: Option<&ServiceState>) {
+    let policy = config.restart.unwrap_or_default();
+    let restarts = state.map(|s| s.restarts).unwrap_or(0);
+    if policy != RestartPolicy::No {
+        println!(
+            "     Restart: {} ({}/{} restarts)",
+            policy.as_str(),
+            restarts,
+            config
+                .max_restarts
+                .unwrap_or(supervisor::DEFAULT_MAX_RESTARTS)
+        );
+    }
+
+    let Some(state) = state else {
+        return;
+    };
+    if state.gave_up {
+        println!("     Supervisor gave up after {} restarts", restarts);
+    }
+    for record in state.history.iter().rev().take(5) {
+        let action = if record.attempt == 0 {
+            "gave up".to_string()
+        } else {
+            format!("restart #{} after {}s", record.attempt, record.delay_secs)
+        };
+        println!(
+            "     {}  {}, {}",
+            record.at.format("%Y-%m-%d %H:%M:%S UTC"),
+            supervisor::exit_label(record.exit_code),
+            action
+        );
+    }
+}
+
+pub(crate) fn get_project_name(config_path: &Path) -> String {
+    config_path
+        .parent()
+        .and_then(|p| p.file_name())
+        .and_then(|n| n.to_str())
+        .unwrap_or("vx")
+        .to_string()
+        .replace(|c: char| !c.is_alphanumeric() && c != '-' && c != '_', "-")
+        .to_lowercase()
+}
+
+/// Order services so that dependencies start first
+///
+/// `selected` services are returned together with everything they depend
+/// on; `None` selects all services. Unknown services and dependency cycles
+/// are errors.
+fn order_by_dependencies(
+    services: &HashMap<String, ServiceConfig>,
+    selected: Option<&[String]>,
+) -> Result<Vec<String>> {
+    // Sort roots so the order is stable across runs
+    let mut roots: Vec<&str> = match selected {
+        Some(names) => names.iter().map(String::as_str).collect(),
+        None => services.keys().map(String::as_str).collect(),
+    };
+    roots.sort_unstable();
+
+    fn visit(
+        name: &str,
+        services: &HashMap<String, ServiceConfig>,
+        stack: &mut Vec<String>,
+        result: &mut Vec<String>,
+    ) -> Result<()> {
+        if result.iter().any(|n| n == name) {
+            return Ok(());
+        }
+        if let Some(pos) = stack.iter().position(|n| n == name) {
+            let mut cycle = stack[pos..].to_vec();
+            cycle.push(name.to_string());
+            anyhow::bail!("Circular service dependency: {}", cycle.join(" -> "));
+        }
+
+        let config = match (services.get(name), stack.last()) {
+            (Some(config), _) => config,
+            (None, Some(parent)) => {
+                anyhow::bail!("Service '{}' depends on unknown service '{}'", parent, name)
+            }
+            (None, None) => {
+                let mut available: Vec<_> = services.keys().map(String::as_str).collect();
+                available.sort_unstable();
+                anyhow::bail!(
+                    "Service '{}' not found. Available: {}",
+                    name,
+                    available.join(", ")
+                )
+            }
+        };
+
+        stack.push(name.to_string());
+        for dep in &config.depends_on {
+            visit(dep, services, stack, result)?;
+        }
+        stack.pop();
+
+        result.push(name.to_string());
+        Ok(())
+    }
+
+    let mut result = Vec::new();
+    let mut stack = Vec::new();
+    for name in roots {
+        visit(name, services, &mut stack, &mut result)?;
+    }
+
+    Ok(result)
+}
+
+/// Host directories to mount so the executables in `tools` resolve inside
+/// a container
+///
+/// Executables in the vx store share one mount of the whole store, since
+/// tools often need files next to their bin directory; others get their
+/// own directory mounted.
+pub(crate) fn tool_mounts<'a>(
+    tools: impl IntoIterator<Item = &'a PathBuf>,
+    store_dir: &Path,
+) -> Vec<PathBuf> {
+    let mut mounts: Vec<PathBuf> = Vec::new();
+    for exe in tools {
+        let mount = if exe.starts_with(store_dir) {
+            store_dir.to_path_buf()
+        } else if let Some(parent) = exe.parent() {
+            parent.to_path_buf()
+        } else {
+            continue;
+        };
+        if !mounts.contains(&mount) {
+            mounts.push(mount);
+        }
+    }
+    mounts
+}
+
+#[allow(clippy::too_many_arguments)]
+fn start_service(
+    runtime: &ContainerRuntime,
+    project_name: &str,
+    name: &str,
+    config: &ServiceConfig,
+    tools: &HashMap<String, PathBuf>,
+    detach: bool,
+    force: bool,
+    verbose: bool,
+) -> Result<()> {
+    let container_name = format!("vx-{}-{}", project_name, name);
+
+    // Check if already running
+    let status = get_service_status(runtime, project_name, name)?;
+    if status.running && !force {
+        UI::info(&format!("{} is already running", name));
+        return Ok(());
+    }
+
+    // Stop existing container if force; keep the logs of an exited one
+    if status.running && force {
+        stop_service(runtime, project_name, name, config, verbose)?;
+    } else if status.container_id.is_some() {
+        logs::archive_container(runtime, project_name, name, config);
+    }
+
+    // Remove existing container
+    let _ = Command::new(runtime.command())
+        .args(["rm", "-f", &container_name])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+
+    // Build run command
+    let mut args = vec!["run".to_string()];
+
+    if detach {
+        args.push("-d".to_string());
+    }
+
+    args.push("--name".to_string());
+    args.push(container_name);
+
+    // Add ports
+    for port in &config.ports {
+        args.push("-p".to_string());
+        args.push(port.clone());
+    }
+
+    // Add environment variables
+    for (key, value) in &config.env {
+        args.push("-e".to_string());
+        args.push(format!("{}={}", key, value));
+    }
+
+    // Add env file
+    if let Some(env_file) = &config.env_file {
+        args.push("--env-file".to_string());
+        args.push(env_file.clone());
+    }
+
+    // Add volumes
+    for volume in &config.volumes {
+        args.push("-v".to_string());
+        args.push(volume.clone());
+    }
+
+    // Add working directory
+    if let Some(working_dir) = &config.working_dir {
+        args.push("-w".to_string());
+        args.push(working_dir.clone());
+    }
+
+    // Add healthcheck
+    if let Some(healthcheck) = &config.healthcheck {
+        args.push("--health-cmd".to_string());
+        args.push(healthcheck.clone());
+        args.push("--health-interval".to_string());
+        args.push("10s".to_string());
+        args.push("--health-timeout".to_string());
+        args.push("5s".to_string());
+        args.push("--health-retries".to_string());
+        args.push("3".to_string());
+    }
+
+    // Add image or command
+    if let Some(image) = &config.image {
+        args.push(image.clone());
+    } else if let Some(command) = &config.command {
+        let used: Vec<&PathBuf> = vx_config::tool_placeholders(command)
+            .iter()
+            .filter_map(|tool| tools.get(tool))
+            .collect();
+        let command = vx_config::expand_tool_placeholders(command, tools)
+            .with_context(|| format!("Failed to expand tool placeholders of service '{}'", name))?;
+        if !used.is_empty() {
+            let store_dir = vx_paths::VxPaths::new()?.store_dir;
+            for mount in tool_mounts(used, &store_dir) {
+                args.push("-v".to_string());
+                args.push(format!("{}:{}:ro", mount.display(), mount.display()));
+            }
+        }
+        // For command-based services, we need a base image
+        args.push("alpine:latest".to_string());
+        args.push("sh".to_string());
+        args.push("-c".to_string());
+        args.push(command);
+    } else {
+        return Err(anyhow::anyhow!(
+            "Service '{}' must have either 'image' or 'command'",
+            name
+        ));
+    }
+
+    if verbose {
+        UI::info(&format!(
+            "Running: {} {}",
+            runtime.command(),
+            args.join(" ")
+        ));
+    }
+
+    UI::info(&format!("Starting {}...", name));
+
+    let output = Command::new(runtime.command())
+        .args(&args)
+        .output()
+        .context("Failed to start container")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow::anyhow!("Failed to start {}: {}", name, stderr));
+    }
+
+    UI::success(&format!("{} started", name));
+
+    Ok(())
+}
+
+/// Wait until a started service passes its health checks
+///
+/// Services without `healthcheck` or `healthcheck_url` are considered ready
+/// as soon as their container is running.
+async fn wait_for_health(
+    runtime: &ContainerRuntime,
+    project_name: &str,
+    name: &str,
+    config: &ServiceConfig,
+) -> Result<()> {
+    if config.healthcheck.is_none() && config.healthcheck_url.is_none() {
+        return Ok(());
+    }
+
+    let container_name = format!("vx-{}-{}", project_name, name);
+    let timeout = config.startup_timeout.unwrap_or(DEFAULT_STARTUP_TIMEOUT);
+    let deadline = Instant::now() + Duration::from_secs(timeout);
+    // Probes target local ports, so they must not go through a proxy
+    let client = match &config.healthcheck_url {
+        Some(_) => Some(
+            reqwest::Client::builder()
+                .no_proxy()
+                .timeout(Duration::from_secs(5))
+                .build()
+                .context("Failed to create HTTP client")?,
+        ),
+        None => None,
+    };
+
+    UI::info(&format!("Waiting for {} to become healthy...", name));
+
+    loop {
+        if is_healthy(runtime, &container_name, config, client.as_ref()).await {
+            UI::success(&format!("{} is healthy", name));
+            return Ok(());
+        }
+
+        if !get_service_status(runtime, project_name, name)?.running {
+            anyhow::bail!(
+                "{} exited before becoming healthy. Run 'vx services logs {}' for details",
+                name,
+                name
+            );
+        }
+
+        if Instant::now() >= deadline {
+            anyhow::bail!(
+                "{} did not become healthy within {}s (set startup_timeout to wait longer)",
+                name,
+                timeout
+            );
+        }
+
+        tokio::time::sleep(HEALTH_POLL_INTERVAL).await;
+    }
+}
+
+/// Run every configured health check once
+async fn is_healthy(
+    runtime: &ContainerRuntime,
+    container_name: &str,
+    config: &ServiceConfig,
+    client: Option<&reqwest::Client>,
+) -> bool {
+    if config.healthcheck.is_some() {
+        let passed = Command::new(runtime.command())
+            .args(["healthcheck", "run", container_name])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|s| s.success());
+        if !passed {
+            return false;
+        }
+    }
+
+    match (&config.healthcheck_url, client) {
+        (Some(url), Some(client)) => client.get(url).send().await.is_ok_and(|response| {
+            response.status().is_success() || response.status().is_redirection()
+        }),
+        _ => true,
+    }
+}
+
+fn stop_service(
+    runtime: &ContainerRuntime,
+    project_name: &str,
+    name: &str,
+    config: &ServiceConfig,
+    verbose: bool,
+) -> Result<()> {
+    let container_name = format!("vx-{}-{}", project_name, name);
+
+    if verbose {
+        UI::info(&format!("Stopping container: {}", container_name));
+    }
+
+    UI::info(&format!("Stopping {}...", name));
+
+    let status = Command::new(runtime.command())
+        .args(["stop", &container_name])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+
+    match status {
+        Ok(s) if s.success() => {
+            UI::success(&format!("{} stopped", name));
+        }
+        _ => {
+            if verbose {
+                UI::warn(&format!("{} was not running", name));
+            }
+        }
+    }
+
+    // Keep its logs, then remove the container
+    logs::archive_container(runtime, project_name, name, config);
+    let _ = Command::new(runtime.command())
+        .args(["rm", "-f", &container_name])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+
+    Ok(())
+}
+
+pub(crate) fn get_service_status(
+    runtime: &ContainerRuntime,
+    project_name: &str,
+    name: &str,
+) -> Result<ServiceStatus> {
+    let container_name = format!("vx-{}-{}", project_name, name);
+
+    let output = Command::new(runtime.command())
+        .args([
+            "inspect",
+            "--format",
+            "{{.State.Running}}|{{.Id}}|{{range .NetworkSettings.Ports}}{{.}}{{end}}|{{.State.Health.Status}}",
+            &container_name,
+        ])
+        .output();
+
+    match output {
+        Ok(o) if o.status.success() => {
+            let stdout = String::from_utf8_lossy(&o.stdout);
+            let parts: Vec<_> = stdout.trim().split('|').collect();
+
+            let running = parts.first().map(|s| *s == "true").unwrap_or(false);
+            let container_id = parts.get(1).map(|s| s[..12.min(s.len())].to_string());
+            let health = parts.get(3).and_then(|s| {
+                if s.is_empty() {
+                    None
+                } else {
+                    Some(s.to_string())
+                }
+            });
+
+            Ok(ServiceStatus {
+                name: name.to_string(),
+                running,
+                container_id,
+                ports: vec![], // TODO: parse ports
+                health,
+            })
+        }
+        _ => Ok(ServiceStatus {
+            name: name.to_string(),
+            running: false,
+            container_id: None,
+            ports: vec![],
+            health: None,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_container_runtime_command() {
+        assert_eq!(ContainerRuntime::Podman.command(), "podman");
+    }
+
+    #[test]
+    fn test_get_project_name() {
+        let path = PathBuf::from("/home/user/my-project/vx.toml");
+        assert_eq!(get_project_name(&path), "my-project");
+
+        let path = PathBuf::from("/home/user/My Project/vx.toml");
+        assert_eq!(get_project_name(&path), "my-project");
+    }
+
+    #[test]
+    fn test_tool_mounts_share_store() {
+        let store = PathBuf::from("/home/u/.vx/store");
+        let node = store.join("node/22.1.0/linux-x64/bin/node");
+        let uv = store.join("uv/0.5.0/linux-x64/uv");
+        let system = PathBuf::from("/opt/tools/bin/deno");
+
+        let mounts = tool_mounts([&node, &uv, &system], &store);
+        assert_eq!(mounts, vec![store, PathBuf::from("/opt/tools/bin")]);
+    }
+
+    fn service(depends_on: &[&str]) -> ServiceConfig {
+        ServiceConfig {
+            image: Some("app:latest".to_string()),
+            depends_on: depends_on.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_order_by_dependencies() {
+        let services = HashMap::from([
+            ("app".to_string(), service(&["postgres", "redis"])),
+            ("postgres".to_string(), service(&[])),
+            ("redis".to_string(), service(&["postgres"])),
+        ]);
+
+        let ordered = order_by_dependencies(&services, None).unwrap();
+        assert_eq!(ordered, vec!["postgres", "redis", "app"]);
+    }
+
+    #[test]
+    fn test_order_by_dependencies_includes_dependencies() {
+        let services = HashMap::from([
+            ("app".to_string(), service(&["redis"])),
+            ("redis".to_string(), service(&["postgres"])),
+            ("postgres".to_string(), service(&[])),
+            ("mail".to_string(), service(&[])),
+        ]);
+
+        let selected = vec!["app".to_string()];
+        let ordered = order_by_dependencies(&services, Some(&selected)).unwrap();
+        assert_eq!(ordered, vec!["postgres", "redis", "app"]);
+    }
+
+    #[test]
+    fn test_order_by_dependencies_errors() {
+        let services = HashMap::from([
+            ("a".to_string(), service(&["b"])),
+            ("b".to_string(), service(&["a"])),
+            ("c".to_string(), service(&["missing"])),
+        ]);
+
+        let err = order_by_dependencies(&services, Some(&["a".to_string()])).unwrap_err();
+        assert!(err.to_string().contains("a -> b -> a"));
+
+        let err = order_by_dependencies(&services, Some(&["c".to_string()])).unwrap_err();
+        assert!(err.to_string().contains("unknown service 'missing'"));
+
+        let err = order_by_dependencies(&services, Some(&["nope".to_string()])).unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+}
@@ -0,0 +1,479 @@
+//! Crash supervision for background services
+//!
+//! `vx services supervise` polls the containers of services that set a
+//! `restart` policy and starts them again when they exit. Consecutive
+//! restarts back off exponentially (1s, 2s, 4s, ... up to 60s) and stop once
+//! `max_restarts` is reached. A service that stays up for a minute starts
+//! over with a clean backoff.
+//!
+//! Restart history is kept in `.vx/services/state.json` next to `vx.toml`, so
+//! `vx services status --verbose` can show it and `vx services stop` can tell
+//! the supervisor that a service was stopped on purpose.
+
+use super::{ContainerRuntime, get_project_name};
+use crate::commands::common::load_full_config_cwd;
+use crate::ui::UI;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use vx_config::{RestartPolicy, ServiceConfig};
+
+/// Default seconds between container checks
+pub const DEFAULT_INTERVAL: u64 = 2;
+
+/// Default number of consecutive restarts before giving up
+pub const DEFAULT_MAX_RESTARTS: u32 = 5;
+
+/// Delay before the first restart
+const BASE_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Longest delay between restarts
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Uptime after which a restarted service counts as stable again
+const STABLE_AFTER: Duration = Duration::from_secs(60);
+
+/// Restart records kept per service
+const HISTORY_LIMIT: usize = 20;
+
+/// Persisted supervisor state for one project
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SupervisorState {
+    #[serde(default)]
+    pub services: BTreeMap<String, ServiceState>,
+}
+
+/// Supervisor state of a single service
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ServiceState {
+    /// Stopped with `vx services stop`; never restarted
+    #[serde(default)]
+    pub stopped: bool,
+    /// Consecutive restarts since the service was last stable
+    #[serde(default)]
+    pub restarts: u32,
+    /// Set once `max_restarts` was reached
+    #[serde(default)]
+    pub gave_up: bool,
+    /// Most recent restarts, oldest first
+    #[serde(default)]
+    pub history: Vec<RestartRecord>,
+}
+
+/// One exit observed by the supervisor
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RestartRecord {
+    /// When the exit was observed
+    pub at: DateTime<Utc>,
+    /// Container exit code, when known
+    pub exit_code: Option<i32>,
+    /// Consecutive restart number (0 when the supervisor gave up)
+    pub attempt: u32,
+    /// Delay before the restart, in seconds
+    pub delay_secs: u64,
+}
+
+impl SupervisorState {
+    /// Path of the state file for the project owning `config_path`
+    pub fn path(config_path: &Path) -> PathBuf {
+        config_path
+            .parent()
+            .unwrap_or(Path::new("."))
+            .join(".vx")
+            .join("services")
+            .join("state.json")
+    }
+
+    /// Load the state file, or an empty state when it does not exist
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Write the state file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Update the state of `names` in the state file of `config_path`
+    pub fn update(
+        config_path: &Path,
+        names: &[String],
+        f: impl Fn(&mut ServiceState),
+    ) -> Result<()> {
+        let path = Self::path(config_path);
+        let mut state = Self::load(&path)?;
+        for name in names {
+            f(state.services.entry(name.clone()).or_default());
+        }
+        state.save(&path)
+    }
+}
+
+impl ServiceState {
+    /// Clear the restart counters after a manual start
+    pub fn started(&mut self) {
+        self.stopped = false;
+        self.gave_up = false;
+        self.restarts = 0;
+    }
+
+    fn record(&mut self, record: RestartRecord) {
+        self.history.push(record);
+        if self.history.len() > HISTORY_LIMIT {
+            self.history.drain(..self.history.len() - HISTORY_LIMIT);
+        }
+    }
+}
+
+/// Delay before the `attempt`-th consecutive restart (1-based)
+pub fn backoff(attempt: u32) -> Duration {
+    let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+    BASE_BACKOFF.saturating_mul(factor).min(MAX_BACKOFF)
+}
+
+/// What the supervisor does with a service whose container exited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    /// Leave the service alone
+    Ignore,
+    /// Restart after `delay`
+    Restart { attempt: u32, delay: Duration },
+    /// The restart limit is reached
+    GiveUp,
+}
+
+/// Decide how to handle an exited container
+pub fn decide(
+    policy: RestartPolicy,
+    max_restarts: u32,
+    exit_code: Option<i32>,
+    state: &ServiceState,
+) -> Decision {
+    let crashed = exit_code != Some(0);
+    let wants_restart = match policy {
+        RestartPolicy::No => false,
+        RestartPolicy::OnFailure => crashed,
+        RestartPolicy::Always => true,
+    };
+    if !wants_restart || state.stopped || state.gave_up {
+        return Decision::Ignore;
+    }
+    if state.restarts >= max_restarts {
+        return Decision::GiveUp;
+    }
+    let attempt = state.restarts + 1;
+    Decision::Restart {
+        attempt,
+        delay: backoff(attempt),
+    }
+}
+
+/// Container state as seen by the supervisor
+struct ContainerState {
+    running: bool,
+    exit_code: Option<i32>,
+}
+
+fn container_state(runtime: &ContainerRuntime, container_name: &str) -> Option<ContainerState> {
+    let output = Command::new(runtime.command())
+        .args([
+            "inspect",
+            "--format",
+            "{{.State.Running}}|{{.State.ExitCode}}",
+            container_name,
+        ])
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|o| o.status.success())?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (running, exit_code) = stdout.trim().split_once('|')?;
+    Some(ContainerState {
+        running: running == "true",
+        exit_code: exit_code.trim().parse().ok(),
+    })
+}
+
+/// Supervised services: those with a restart policy other than `no`
+fn supervised<'a>(
+    services: &'a HashMap<String, ServiceConfig>,
+    selected: Option<&[String]>,
+) -> Vec<(&'a String, &'a ServiceConfig)> {
+    let mut supervised: Vec<_> = services
+        .iter()
+        .filter(|(name, _)| selected.is_none_or(|names| names.contains(name)))
+        .filter(|(_, config)| config.restart.unwrap_or_default() != RestartPolicy::No)
+        .collect();
+    supervised.sort_by_key(|(name, _)| *name);
+    supervised
+}
+
+/// Handle services supervise command
+///
+/// Runs until every supervised service was stopped or gave up.
+pub async fn handle_supervise(
+    services: Option<Vec<String>>,
+    interval: u64,
+    verbose: bool,
+) -> Result<()> {
+    let (config_path, config) = load_full_config_cwd()?;
+
+    let supervised = supervised(&config.services, services.as_deref());
+    if supervised.is_empty() {
+        UI::warn("No services with a restart policy");
+        UI::hint("Set restart = \"on-failure\" or restart = \"always\" on a service in vx.toml");
+        return Ok(());
+    }
+
+    let runtime = ContainerRuntime::detect()
+        .ok_or_else(|| anyhow::anyhow!("No container runtime found. Please install Podman."))?;
+
+    let project_name = get_project_name(&config_path);
+    let state_path = SupervisorState::path(&config_path);
+    let interval = Duration::from_secs(interval.max(1));
+
+    UI::header("🩺 Supervising Services");
+    for (name, service) in &supervised {
+        UI::item(&format!(
+            "{} (restart: {}, max {} restarts)",
+            name,
+            service.restart.unwrap_or_default().as_str(),
+            service.max_restarts.unwrap_or(DEFAULT_MAX_RESTARTS)
+        ));
+    }
+    println!();
+
+    let mut running_since: HashMap<&str, Instant> = HashMap::new();
+    let mut pending: HashMap<&str, Instant> = HashMap::new();
+
+    loop {
+        // Re-read every tick: `vx services stop` marks services in the same file
+        let mut state = SupervisorState::load(&state_path)?;
+        let mut changed = false;
+        let mut active = 0;
+
+        for (name, service) in &supervised {
+            let name = name.as_str();
+            let container_name = format!("vx-{}-{}", project_name, name);
+            let entry = state.services.entry(name.to_string()).or_default();
+            if entry.stopped || entry.gave_up {
+                pending.remove(name);
+                continue;
+            }
+            active += 1;
+
+            if let Some(due) = pending.get(name) {
+                if Instant::now() < *due {
+                    continue;
+                }
+                pending.remove(name);
+                UI::info(&format!("Restarting {}...", name));
+                let started = Command::new(runtime.command())
+                    .args(["start", &container_name])
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .status()
+                    .is_ok_and(|s| s.success());
+                if !started {
+                    UI::warn(&format!("Failed to restart {}", name));
+                }
+                continue;
+            }
+
+            // Missing containers were never started or were removed by hand
+            let Some(container) = container_state(&runtime, &container_name) else {
+                continue;
+            };
+
+            if container.running {
+                let since = *running_since.entry(name).or_insert_with(Instant::now);
+                if entry.restarts > 0 && since.elapsed() >= STABLE_AFTER {
+                    if verbose {
+                        UI::detail(&format!("{} is stable again", name));
+                    }
+                    entry.restarts = 0;
+                    changed = true;
+                }
+                continue;
+            }
+            running_since.remove(name);
+
+            let policy = service.restart.unwrap_or_default();
+            let max_restarts = service.max_restarts.unwrap_or(DEFAULT_MAX_RESTARTS);
+            match decide(policy, max_restarts, container.exit_code, entry) {
+                Decision::Ignore => {}
+                Decision::Restart { attempt, delay } => {
+                    UI::warn(&format!(
+                        "{} exited ({}), restarting in {}s (attempt {}/{})",
+                        name,
+                        exit_label(container.exit_code),
+                        delay.as_secs(),
+                        attempt,
+                        max_restarts
+                    ));
+                    entry.restarts = attempt;
+                    entry.record(RestartRecord {
+                        at: Utc::now(),
+                        exit_code: container.exit_code,
+                        attempt,
+                        delay_secs: delay.as_secs(),
+                    });
+                    pending.insert(name, Instant::now() + delay);
+                    changed = true;
+                }
+                Decision::GiveUp => {
+                    UI::error(&format!(
+                        "{} exited ({}) after {} restarts, giving up. Run 'vx services logs {}' for details",
+                        name,
+                        exit_label(container.exit_code),
+                        entry.restarts,
+                        name
+                    ));
+                    entry.gave_up = true;
+                    entry.record(RestartRecord {
+                        at: Utc::now(),
+                        exit_code: container.exit_code,
+                        attempt: 0,
+                        delay_secs: 0,
+                    });
+                    changed = true;
+                }
+            }
+        }
+
+        if changed {
+            state.save(&state_path)?;
+        }
+
+        if active == 0 {
+            UI::info("No supervised services left running");
+            return Ok(());
+        }
+
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Describe an exit code for messages
+pub fn exit_label(exit_code: Option<i32>) -> String {
+    match exit_code {
+        Some(code) => format!("exit code {}", code),
+        None => "unknown exit code".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_and_caps() {
+        assert_eq!(backoff(1), Duration::from_secs(1));
+        assert_eq!(backoff(2), Duration::from_secs(2));
+        assert_eq!(backoff(4), Duration::from_secs(8));
+        assert_eq!(backoff(7), MAX_BACKOFF);
+        assert_eq!(backoff(100), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn test_decide_follows_policy() {
+        let state = ServiceState::default();
+        assert_eq!(
+            decide(RestartPolicy::No, 5, Some(1), &state),
+            Decision::Ignore
+        );
+        assert_eq!(
+            decide(RestartPolicy::OnFailure, 5, Some(0), &state),
+            Decision::Ignore
+        );
+        assert_eq!(
+            decide(RestartPolicy::OnFailure, 5, Some(137), &state),
+            Decision::Restart {
+                attempt: 1,
+                delay: Duration::from_secs(1)
+            }
+        );
+        assert!(matches!(
+            decide(RestartPolicy::Always, 5, Some(0), &state),
+            Decision::Restart { attempt: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn test_decide_caps_restarts() {
+        let mut state = ServiceState {
+            restarts: 2,
+            ..Default::default()
+        };
+        assert_eq!(
+            decide(RestartPolicy::Always, 3, Some(1), &state),
+            Decision::Restart {
+                attempt: 3,
+                delay: Duration::from_secs(4)
+            }
+        );
+
+        state.restarts = 3;
+        assert_eq!(
+            decide(RestartPolicy::Always, 3, Some(1), &state),
+            Decision::GiveUp
+        );
+
+        state.gave_up = true;
+        assert_eq!(
+            decide(RestartPolicy::Always, 3, Some(1), &state),
+            Decision::Ignore
+        );
+        state.started();
+        state.stopped = true;
+        assert_eq!(
+            decide(RestartPolicy::Always, 3, Some(1), &state),
+            Decision::Ignore
+        );
+    }
+
+    #[test]
+    fn test_state_round_trip_trims_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("vx.toml");
+        let path = SupervisorState::path(&config_path);
+        assert_eq!(
+            SupervisorState::load(&path).unwrap(),
+            SupervisorState::default()
+        );
+
+        let mut state = SupervisorState::default();
+        let service = state.services.entry("db".to_string()).or_default();
+        for attempt in 0..(HISTORY_LIMIT as u32 + 5) {
+            service.record(RestartRecord {
+                at: Utc::now(),
+                exit_code: Some(1),
+                attempt,
+                delay_secs: 1,
+            });
+        }
+        state.save(&path).unwrap();
+
+        SupervisorState::update(&config_path, &["db".to_string()], |s| s.stopped = true).unwrap();
+        let loaded = SupervisorState::load(&path).unwrap();
+        let db = &loaded.services["db"];
+        assert!(db.stopped);
+        assert_eq!(db.history.len(), HISTORY_LIMIT);
+        assert_eq!(db.history[0].attempt, 5);
+    }
+}
@@ -53,6 +53,8 @@ pub struct ConfigView {
     pub passenv: Vec<String>,
     /// Environment variables to explicitly set (setenv)
     pub setenv: HashMap<String, String>,
+    /// Network egress policy (`[security.egress]`)
+    pub egress: Option<vx_config::EgressConfig>,
 }
 
 impl ConfigView {
@@ -66,23 +68,14 @@ impl ConfigView {
 
     /// Get the command string for a script
     pub fn get_script_command(&self, name: &str) -> Option<String> {
-        self.scripts.get(name).map(|s| match s {
-            ScriptConfig::Simple(cmd) => cmd.clone(),
-            ScriptConfig::Detailed(d) => d.command.clone(),
-        })
+        self.scripts.get(name).map(|s| s.for_current_os().command)
     }
 
     /// Get scripts as simple HashMap<String, String> (for backward-compatible operations)
     pub fn scripts_as_simple_hashmap(&self) -> HashMap<String, String> {
         self.scripts
             .iter()
-            .map(|(k, v)| {
-                let cmd = match v {
-                    ScriptConfig::Simple(s) => s.clone(),
-                    ScriptConfig::Detailed(d) => d.command.clone(),
-                };
-                (k.clone(), cmd)
-            })
+            .map(|(k, v)| (k.clone(), v.for_current_os().command))
             .collect()
     }
 }
@@ -121,6 +114,7 @@ impl From<VxConfig> for ConfigView {
             isolation: config.is_isolation_mode(),
             passenv: config.get_passenv(),
             setenv: config.get_setenv(),
+            egress: config.security.and_then(|s| s.egress),
         }
     }
 }
@@ -152,7 +146,9 @@ pub async fn handle(
 
     // Find and parse vx.toml
     let config_path = find_vx_config(&current_dir)?;
+    super::common::fetch_remote_presets(&config_path).await?;
     let config = parse_vx_config_full(&config_path)?;
+    super::common::ensure_gpu_requirement(&config)?;
     let view = ConfigView::from(config.clone());
 
     UI::header("🚀 VX Development Environment Setup");
@@ -165,7 +161,9 @@ pub async fn handle(
         && let Some(pre_setup) = &hooks.pre_setup
     {
         UI::info("Running pre_setup hook...");
-        let executor = HookExecutor::new(&current_dir).verbose(verbose);
+        let executor = HookExecutor::new(&current_dir)
+            .verbose(verbose)
+            .tools(super::common::resolve_hook_tools(&config, pre_setup)?);
         let result = executor.execute_pre_setup(pre_setup)?;
         if !result.success {
             if let Some(err) = result.error {
@@ -197,7 +195,9 @@ pub async fn handle(
     {
         println!();
         UI::info("Running post_setup hook...");
-        let executor = HookExecutor::new(&current_dir).verbose(verbose);
+        let executor = HookExecutor::new(&current_dir)
+            .verbose(verbose)
+            .tools(super::common::resolve_hook_tools(&config, post_setup)?);
         let result = executor.execute_post_setup(post_setup)?;
         if !result.success {
             if let Some(err) = result.error {
@@ -238,17 +238,24 @@ fn find_config_in_current_dir(current_dir: &Path) -> Result<PathBuf> {
 }
 
 /// Parse vx.toml configuration and return a flattened view
+///
+/// Presets from `extends` and the machine policy are applied.
 pub fn parse_vx_config(path: &Path) -> Result<ConfigView> {
-    let config = parse_config(path)
-        .with_context(|| format!("Failed to parse configuration file: {}", path.display()))?;
-
-    Ok(ConfigView::from(config))
+    super::common::load_full_config(path).map(ConfigView::from)
 }
 
 /// Parse vx.toml configuration and return the full typed config
+///
+/// Presets from `extends` and the machine policy are applied.
 pub fn parse_vx_config_full(path: &Path) -> Result<VxConfig> {
-    parse_config(path)
-        .with_context(|| format!("Failed to parse configuration file: {}", path.display()))
+    super::common::load_full_config(path)
+}
+
+/// Parse vx.toml as written, for edits that are saved back to the file
+fn parse_vx_config_for_edit(path: &Path) -> Result<ConfigView> {
+    let config = parse_config(path)
+        .with_context(|| format!("Failed to parse configuration file: {}", path.display()))?;
+    Ok(ConfigView::from(config))
 }
 
 /// Show next steps after setup
@@ -262,11 +269,7 @@ fn show_next_steps(config: &ConfigView) {
         println!();
         println!("Available scripts:");
         for (name, script) in &config.scripts {
-            let cmd = match script {
-                ScriptConfig::Simple(s) => s.as_str(),
-                ScriptConfig::Detailed(d) => d.command.as_str(),
-            };
-            println!("  vx run {} -> {}", name, cmd);
+            println!("  vx run {} -> {}", name, script.for_current_os().command);
         }
     }
 }
@@ -387,7 +390,7 @@ pub async fn add_tool(tool: &str, version: Option<&str>) -> Result<()> {
         (tool, version.unwrap_or("latest"))
     };
 
-    let mut config = parse_vx_config(&config_path)?;
+    let mut config = parse_vx_config_for_edit(&config_path)?;
 
     if config.tools.contains_key(tool) {
         UI::warn(&format!("Tool '{}' already configured", tool));
@@ -426,7 +429,7 @@ pub async fn remove_tool(tool: &str) -> Result<()> {
     let current_dir = env::current_dir().context("Failed to get current directory")?;
     let config_path = find_config_in_current_dir(&current_dir)?;
 
-    let mut config = parse_vx_config(&config_path)?;
+    let mut config = parse_vx_config_for_edit(&config_path)?;
 
     if !config.tools.contains_key(tool) {
         UI::warn(&format!("Tool '{}' not found in configuration", tool));
@@ -578,7 +581,7 @@ pub async fn update_tool(tool: &str, version: &str) -> Result<()> {
     let current_dir = env::current_dir().context("Failed to get current directory")?;
     let config_path = find_config_in_current_dir(&current_dir)?;
 
-    let mut config = parse_vx_config(&config_path)?;
+    let mut config = parse_vx_config_for_edit(&config_path)?;
 
     let old_version = config.tools.get(tool).cloned();
     config.tools.insert(tool.to_string(), version.to_string());
@@ -1,6 +1,7 @@
 // Shell integration commands
 
 use anyhow::Result;
+use clap::{Arg, ArgAction, Command, CommandFactory};
 use std::env;
 
 pub async fn handle_shell_init(shell: Option<String>) -> Result<()> {
@@ -10,6 +11,7 @@ pub async fn handle_shell_init(shell: Option<String>) -> Result<()> {
         "bash" => print_bash_init(),
         "zsh" => print_zsh_init(),
         "fish" => print_fish_init(),
+        "nu" | "nushell" => print_nushell_init(),
         "powershell" | "pwsh" => print_powershell_init(),
         "cmd" => print_cmd_init(),
         _ => {
@@ -21,10 +23,33 @@ pub async fn handle_shell_init(shell: Option<String>) -> Result<()> {
 }
 
 pub async fn handle_completion(shell: String) -> Result<()> {
-    match shell.as_str() {
+    print_completion(&shell, &crate::cli::Cli::command())
+}
+
+/// Print completions that also cover provider-contributed subcommands and
+/// extension commands
+pub async fn handle_completion_with_contributions(
+    shell: String,
+    registry: &vx_runtime::ProviderRegistry,
+) -> Result<()> {
+    let extensions = match vx_extension::ExtensionManager::new() {
+        Ok(manager) => manager.list_extensions().await.unwrap_or_default(),
+        Err(_) => Vec::new(),
+    };
+    let command = crate::provider_commands::with_contributions(
+        crate::cli::Cli::command(),
+        registry,
+        &extensions,
+    );
+    print_completion(&shell, &command)
+}
+
+fn print_completion(shell: &str, command: &Command) -> Result<()> {
+    match shell {
         "bash" => print_bash_completion(),
         "zsh" => print_zsh_completion(),
-        "fish" => print_fish_completion(),
+        "fish" => print!("{}", generate_fish_completion(command)),
+        "nu" | "nushell" => print!("{}", generate_nushell_completion(command)),
         "powershell" | "pwsh" => print_powershell_completion(),
         _ => {
             return Err(anyhow::anyhow!("Unsupported shell: {}", shell));
@@ -34,7 +59,8 @@ pub async fn handle_completion(shell: String) -> Result<()> {
     Ok(())
 }
 
-fn detect_shell() -> String {
+/// Shell vx is running under (`bash`, `zsh`, `fish`, `nu`, `powershell`, `cmd`)
+pub fn detect_shell() -> String {
     // Try to detect shell from environment variables
     if let Ok(shell) = env::var("SHELL") {
         if shell.contains("bash") {
@@ -43,9 +69,16 @@ fn detect_shell() -> String {
             return "zsh".to_string();
         } else if shell.contains("fish") {
             return "fish".to_string();
+        } else if shell.ends_with("/nu") || shell.ends_with("nu.exe") {
+            return "nu".to_string();
         }
     }
 
+    // Nushell sets NU_VERSION for its child processes
+    if env::var("NU_VERSION").is_ok() {
+        return "nu".to_string();
+    }
+
     // Check for PowerShell
     if env::var("PSModulePath").is_ok() {
         return "powershell".to_string();
@@ -60,8 +93,9 @@ fn detect_shell() -> String {
 }
 
 fn print_bash_init() {
-    let vx_home = dirs::home_dir()
-        .map(|p| p.join(".vx").display().to_string())
+    let vx_home = vx_paths::VxPaths::new()
+        .ok()
+        .map(|p| p.base_dir.display().to_string())
         .unwrap_or_else(|| "$HOME/.vx".to_string());
 
     println!(
@@ -125,8 +159,9 @@ __vx_prompt() {{
 }
 
 fn print_zsh_init() {
-    let vx_home = dirs::home_dir()
-        .map(|p| p.join(".vx").display().to_string())
+    let vx_home = vx_paths::VxPaths::new()
+        .ok()
+        .map(|p| p.base_dir.display().to_string())
         .unwrap_or_else(|| "$HOME/.vx".to_string());
 
     println!(
@@ -187,8 +222,9 @@ __vx_prompt() {{
 }
 
 fn print_fish_init() {
-    let vx_home = dirs::home_dir()
-        .map(|p| p.join(".vx").display().to_string())
+    let vx_home = vx_paths::VxPaths::new()
+        .ok()
+        .map(|p| p.base_dir.display().to_string())
         .unwrap_or_else(|| "$HOME/.vx".to_string());
 
     println!(
@@ -251,9 +287,92 @@ end
     );
 }
 
+fn print_nushell_init() {
+    let vx_home = vx_paths::VxPaths::new()
+        .ok()
+        .map(|p| p.base_dir.display().to_string())
+        .unwrap_or_else(|| "~/.vx".to_string());
+
+    println!(
+        r#"# VX Shell Integration for Nushell
+# Nushell cannot eval generated code, so save this file and source it from config.nu:
+#   vx shell init nu | save -f ($nu.default-config-dir | path join "vx.nu")
+#   source ($nu.default-config-dir | path join "vx.nu")
+
+# Set VX environment variables
+$env.VX_HOME = "{vx_home}"
+$env.VX_SHELL = "nu"
+
+# Add VX bin directory to PATH if not already present
+let vx_bin = ($env.VX_HOME | path join "bin")
+if not ($vx_bin in $env.PATH) {{
+    $env.PATH = ($env.PATH | prepend $vx_bin)
+}}
+
+# VX project detection function
+def --env __vx_detect_project [] {{
+    mut dir = (pwd)
+    loop {{
+        if ($dir | path join "vx.toml" | path exists) {{
+            $env.VX_PROJECT_ROOT = $dir
+            return
+        }}
+        let parent = ($dir | path dirname)
+        if $parent == $dir {{
+            break
+        }}
+        $dir = $parent
+    }}
+    hide-env -i VX_PROJECT_ROOT
+}}
+
+# Auto-sync on directory change
+def --env __vx_auto_sync [] {{
+    __vx_detect_project
+    if ($env.VX_PROJECT_ROOT? | is-not-empty) and (which vx | is-not-empty) {{
+        ^vx sync --check --quiet | complete | ignore
+    }}
+}}
+
+# Hook into directory change
+$env.config = ($env.config | upsert hooks.env_change.PWD {{|config|
+    ($config.hooks.env_change.PWD? | default []) | append {{|before, after| __vx_auto_sync }}
+}})
+
+# Activate project tools in this shell (the Nushell form of `eval "$(vx activate)"`)
+def --env vx-activate [env_name?: string] {{
+    if ($env.VX_ACTIVE? | is-not-empty) {{
+        print -e "Warning: vx environment is already active. Run 'vx-deactivate' first."
+        return
+    }}
+    let args = if $env_name == null {{ [] }} else {{ [$env_name] }}
+    let activation = (^vx activate --shell nu ...$args | from nuon)
+    $env._OLD_VX_PATH = $env.PATH
+    $env.PATH = ($env.PATH | prepend $activation.path)
+    load-env $activation.env
+}}
+
+# Restore the shell after vx-activate
+def --env vx-deactivate [] {{
+    if ($env.VX_ACTIVE? | is-empty) {{
+        print -e "No vx environment is currently active."
+        return
+    }}
+    $env.PATH = $env._OLD_VX_PATH
+    for key in ($env.VX_ACTIVATED_VARS | split row " ") {{
+        hide-env -i $key
+    }}
+    hide-env -i _OLD_VX_PATH VX_ACTIVATED_VARS
+}}
+"#,
+        vx_home = vx_home
+    );
+}
+
 fn print_powershell_init() {
-    let vx_home = dirs::home_dir()
-        .map(|p| p.join(".vx").display().to_string())
+    let vx_home = vx_paths::VxPaths::new()
+        .ok()
+        .map(|p| p.base_dir.display().to_string())
         .unwrap_or_else(|| "$env:USERPROFILE\\.vx".to_string());
 
     println!(
@@ -489,59 +608,6 @@ _vx "$@"
     );
 }
 
-fn print_fish_completion() {
-    println!(
-        r#"# VX Fish Completion
-
-# Main commands
-complete -c vx -f -n '__fish_use_subcommand' -a 'install' -d 'Install a tool'
-complete -c vx -f -n '__fish_use_subcommand' -a 'remove' -d 'Remove a tool'
-complete -c vx -f -n '__fish_use_subcommand' -a 'list' -d 'List installed tools'
-complete -c vx -f -n '__fish_use_subcommand' -a 'update' -d 'Update tools'
-complete -c vx -f -n '__fish_use_subcommand' -a 'search' -d 'Search available tools'
-complete -c vx -f -n '__fish_use_subcommand' -a 'sync' -d 'Sync project tools'
-complete -c vx -f -n '__fish_use_subcommand' -a 'init' -d 'Initialize project'
-complete -c vx -f -n '__fish_use_subcommand' -a 'cleanup' -d 'Clean up cache and orphaned files'
-complete -c vx -f -n '__fish_use_subcommand' -a 'stats' -d 'Show statistics'
-complete -c vx -f -n '__fish_use_subcommand' -a 'venv' -d 'Virtual environment management'
-complete -c vx -f -n '__fish_use_subcommand' -a 'config' -d 'Configuration management'
-complete -c vx -f -n '__fish_use_subcommand' -a 'global' -d 'Global tool management'
-complete -c vx -f -n '__fish_use_subcommand' -a 'plugin' -d 'Plugin management'
-complete -c vx -f -n '__fish_use_subcommand' -a 'shell-init' -d 'Generate shell initialization script'
-complete -c vx -f -n '__fish_use_subcommand' -a 'completion' -d 'Generate shell completion script'
-complete -c vx -f -n '__fish_use_subcommand' -a 'version' -d 'Show version information'
-complete -c vx -f -n '__fish_use_subcommand' -a 'help' -d 'Show help'
-
-# Tool names for install/remove/switch/fetch
-complete -c vx -f -n '__fish_seen_subcommand_from install remove switch fetch' -a 'node npm npx go cargo uv uvx python'
-
-# Venv subcommands
-complete -c vx -f -n '__fish_seen_subcommand_from venv; and not __fish_seen_subcommand_from create list activate remove current' -a 'create' -d 'Create virtual environment'
-complete -c vx -f -n '__fish_seen_subcommand_from venv; and not __fish_seen_subcommand_from create list activate remove current' -a 'list' -d 'List virtual environments'
-complete -c vx -f -n '__fish_seen_subcommand_from venv; and not __fish_seen_subcommand_from create list activate remove current' -a 'activate' -d 'Activate virtual environment'
-complete -c vx -f -n '__fish_seen_subcommand_from venv; and not __fish_seen_subcommand_from create list activate remove current' -a 'remove' -d 'Remove virtual environment'
-complete -c vx -f -n '__fish_seen_subcommand_from venv; and not __fish_seen_subcommand_from create list activate remove current' -a 'current' -d 'Show current virtual environment'
-
-# Config subcommands
-complete -c vx -f -n '__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show set get reset edit' -a 'show' -d 'Show configuration'
-complete -c vx -f -n '__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show set get reset edit' -a 'set' -d 'Set configuration value'
-complete -c vx -f -n '__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show set get reset edit' -a 'get' -d 'Get configuration value'
-complete -c vx -f -n '__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show set get reset edit' -a 'reset' -d 'Reset configuration'
-complete -c vx -f -n '__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show set get reset edit' -a 'edit' -d 'Edit configuration'
-
-# Shell types for completion and shell-init
-complete -c vx -f -n '__fish_seen_subcommand_from completion shell-init' -a 'bash zsh fish powershell'
-
-# Common options
-complete -c vx -l help -d 'Show help'
-complete -c vx -l version -d 'Show version'
-complete -c vx -l verbose -d 'Verbose output'
-complete -c vx -l dry-run -d 'Preview operations'
-complete -c vx -l force -d 'Force operation'
-"#
-    );
-}
-
 fn print_powershell_completion() {
     println!(
         r#"# VX PowerShell Completion
@@ -589,3 +655,320 @@ Register-ArgumentCompleter -Native -CommandName vx -ScriptBlock {{
 "#
     );
 }
+
+// =============================================================================
+// Generated completions
+// =============================================================================
+
+/// Shells offered for `shell`/`--shell` arguments
+const SHELL_NAMES: &[&str] = &["bash", "zsh", "fish", "nu", "powershell", "cmd"];
+
+/// First line of an argument's or command's help text
+fn summary(text: Option<&clap::builder::StyledStr>) -> String {
+    text.map(|t| {
+        t.to_string()
+            .lines()
+            .next()
+            .unwrap_or("")
+            .trim()
+            .to_string()
+    })
+    .unwrap_or_default()
+}
+
+fn visible_subcommands(cmd: &Command) -> Vec<&Command> {
+    cmd.get_subcommands().filter(|c| !c.is_hide_set()).collect()
+}
+
+fn visible_options(cmd: &Command) -> Vec<&Arg> {
+    cmd.get_arguments()
+        .filter(|a| !a.is_hide_set() && !a.is_positional())
+        .collect()
+}
+
+fn takes_value(arg: &Arg) -> bool {
+    arg.get_action().takes_values()
+}
+
+/// Values to offer for an argument (value enums and shell names)
+fn arg_values(arg: &Arg) -> Vec<String> {
+    if arg.get_id() == "shell" {
+        return SHELL_NAMES.iter().map(|s| s.to_string()).collect();
+    }
+    arg.get_possible_values()
+        .iter()
+        .filter(|v| !v.is_hide_set())
+        .map(|v| v.get_name().to_string())
+        .collect()
+}
+
+/// Generate fish completions from the clap command tree
+///
+/// Install with: `vx shell completions fish > ~/.config/fish/completions/vx.fish`
+pub fn generate_fish_completion(cmd: &Command) -> String {
+    let mut out = String::new();
+    out.push_str("# VX Fish Completion (generated from the vx command definitions)\n");
+    out.push_str("# Install: vx shell completions fish > ~/.config/fish/completions/vx.fish\n\n");
+
+    for arg in visible_options(cmd) {
+        out.push_str(&fish_option_line("", arg));
+    }
+    out.push_str("complete -c vx -s h -l help -d 'Print help'\n");
+    out.push_str("complete -c vx -s V -l version -d 'Print version'\n\n");
+
+    fish_subcommands(cmd, &[], &mut out);
+    out
+}
+
+fn fish_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+/// `__fish_seen_subcommand_from` condition for a command path
+fn fish_path_condition(path: &[&str]) -> String {
+    path.iter()
+        .map(|name| format!("__fish_seen_subcommand_from {}", name))
+        .collect::<Vec<_>>()
+        .join("; and ")
+}
+
+fn fish_option_line(condition: &str, arg: &Arg) -> String {
+    let mut line = String::from("complete -c vx");
+    if !condition.is_empty() {
+        line.push_str(&format!(" -n '{}'", condition));
+    }
+    if let Some(short) = arg.get_short() {
+        line.push_str(&format!(" -s {}", short));
+    }
+    if let Some(long) = arg.get_long() {
+        line.push_str(&format!(" -l {}", long));
+    }
+    if takes_value(arg) {
+        line.push_str(" -r");
+        let values = arg_values(arg);
+        if !values.is_empty() {
+            line.push_str(&format!(" -f -a '{}'", values.join(" ")));
+        }
+    }
+    let help = summary(arg.get_help());
+    if !help.is_empty() {
+        line.push_str(&format!(" -d '{}'", fish_escape(&help)));
+    }
+    line.push('\n');
+    line
+}
+
+fn fish_subcommands(cmd: &Command, path: &[&str], out: &mut String) {
+    let subcommands = visible_subcommands(cmd);
+    if subcommands.is_empty() {
+        return;
+    }
+
+    let names: Vec<&str> = subcommands.iter().map(|c| c.get_name()).collect();
+    let condition = if path.is_empty() {
+        "__fish_use_subcommand".to_string()
+    } else {
+        format!(
+            "{}; and not __fish_seen_subcommand_from {}",
+            fish_path_condition(path),
+            names.join(" ")
+        )
+    };
+
+    for sub in &subcommands {
+        out.push_str(&format!(
+            "complete -c vx -f -n '{}' -a '{}' -d '{}'\n",
+            condition,
+            sub.get_name(),
+            fish_escape(&summary(sub.get_about()))
+        ));
+    }
+    out.push('\n');
+
+    for sub in subcommands {
+        let mut sub_path = path.to_vec();
+        sub_path.push(sub.get_name());
+        let sub_condition = fish_path_condition(&sub_path);
+
+        for arg in sub.get_arguments().filter(|a| !a.is_hide_set()) {
+            if arg.is_positional() {
+                let values = arg_values(arg);
+                if !values.is_empty() {
+                    out.push_str(&format!(
+                        "complete -c vx -f -n '{}' -a '{}'\n",
+                        sub_condition,
+                        values.join(" ")
+                    ));
+                }
+            } else {
+                out.push_str(&fish_option_line(&sub_condition, arg));
+            }
+        }
+        fish_subcommands(sub, &sub_path, out);
+    }
+}
+
+/// Generate Nushell completions (`extern` signatures) from the clap command tree
+///
+/// Install with:
+/// `vx shell completions nu | save -f ($nu.default-config-dir | path join "vx-completions.nu")`
+/// and `source vx-completions.nu` from config.nu.
+pub fn generate_nushell_completion(cmd: &Command) -> String {
+    let mut out = String::new();
+    out.push_str("# VX Nushell Completion (generated from the vx command definitions)\n");
+    out.push_str(
+        "# Install: vx shell completions nu | save -f ($nu.default-config-dir | path join \"vx-completions.nu\")\n",
+    );
+    out.push_str("#          then add `source vx-completions.nu` to config.nu\n\n");
+
+    out.push_str(&format!(
+        "def \"nu-complete vx shells\" [] {{ [{}] }}\n\n",
+        SHELL_NAMES
+            .iter()
+            .map(|s| format!("\"{}\"", s))
+            .collect::<Vec<_>>()
+            .join(" ")
+    ));
+
+    let globals: Vec<&Arg> = visible_options(cmd)
+        .into_iter()
+        .filter(|a| a.is_global_set())
+        .collect();
+    nushell_externs(cmd, "vx", &globals, &mut out);
+    out
+}
+
+fn nushell_quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// `nu-complete` helper for subcommand names, returned as its command name
+fn nushell_subcommand_completer(name: &str, subcommands: &[&Command], out: &mut String) -> String {
+    let completer = format!("nu-complete {} commands", name);
+    let items: Vec<String> = subcommands
+        .iter()
+        .map(|c| {
+            format!(
+                "{{value: {}, description: {}}}",
+                nushell_quote(c.get_name()),
+                nushell_quote(&summary(c.get_about()))
+            )
+        })
+        .collect();
+    out.push_str(&format!(
+        "def {} [] {{\n    [\n        {}\n    ]\n}}\n\n",
+        nushell_quote(&completer),
+        items.join("\n        ")
+    ));
+    completer
+}
+
+fn nushell_flag(arg: &Arg) -> Option<String> {
+    let long = arg.get_long()?;
+    let mut flag = format!("--{}", long);
+    if let Some(short) = arg.get_short() {
+        flag.push_str(&format!("(-{})", short));
+    }
+    if takes_value(arg) {
+        flag.push_str(": string");
+        if arg.get_id() == "shell" {
+            flag.push_str("@\"nu-complete vx shells\"");
+        }
+    }
+    Some(flag)
+}
+
+fn nushell_externs(cmd: &Command, name: &str, globals: &[&Arg], out: &mut String) {
+    let subcommands = visible_subcommands(cmd);
+    let completer =
+        (!subcommands.is_empty()).then(|| nushell_subcommand_completer(name, &subcommands, out));
+
+    let mut params: Vec<String> = Vec::new();
+    let mut longs: Vec<&str> = Vec::new();
+    let mut shorts: Vec<char> = Vec::new();
+
+    // Own options first, then inherited globals that don't clash
+    let own = cmd
+        .get_arguments()
+        .filter(|a| !a.is_hide_set() && !a.is_positional());
+    for arg in own.chain(globals.iter().copied()) {
+        let Some(long) = arg.get_long() else {
+            continue;
+        };
+        if longs.contains(&long) || arg.get_short().is_some_and(|s| shorts.contains(&s)) {
+            continue;
+        }
+        longs.push(long);
+        shorts.extend(arg.get_short());
+        if let Some(flag) = nushell_flag(arg) {
+            let help = summary(arg.get_help());
+            params.push(if help.is_empty() {
+                flag
+            } else {
+                format!("{}  # {}", flag, help)
+            });
+        }
+    }
+    if !longs.contains(&"help") {
+        let help = if shorts.contains(&'h') {
+            "--help"
+        } else {
+            "--help(-h)"
+        };
+        params.push(format!("{}  # Print help", help));
+    }
+
+    if let Some(completer) = &completer {
+        params.push(format!("command?: string@{}", nushell_quote(completer)));
+        // Anything after the subcommand (or an implicit tool invocation)
+        params.push("...args: string".to_string());
+    } else {
+        let mut optional = false;
+        let mut rest = false;
+        for arg in cmd
+            .get_arguments()
+            .filter(|a| !a.is_hide_set() && a.is_positional())
+        {
+            if rest {
+                break;
+            }
+            let param = arg.get_id().as_str().replace('-', "_");
+            let many = matches!(arg.get_action(), ArgAction::Append)
+                || arg.get_num_args().is_some_and(|n| n.max_values() > 1);
+            let completion = if arg.get_id() == "shell" {
+                "@\"nu-complete vx shells\""
+            } else {
+                ""
+            };
+            let text = if many {
+                rest = true;
+                format!("...{}: string{}", param, completion)
+            } else if arg.is_required_set() && !optional {
+                format!("{}: string{}", param, completion)
+            } else {
+                optional = true;
+                format!("{}?: string{}", param, completion)
+            };
+            let help = summary(arg.get_help());
+            params.push(if help.is_empty() {
+                text
+            } else {
+                format!("{}  # {}", text, help)
+            });
+        }
+    }
+
+    let about = summary(cmd.get_about());
+    if !about.is_empty() {
+        out.push_str(&format!("# {}\n", about));
+    }
+    out.push_str(&format!("extern {} [\n", nushell_quote(name)));
+    for param in params {
+        out.push_str(&format!("    {}\n", param));
+    }
+    out.push_str("]\n\n");
+
+    for sub in subcommands {
+        nushell_externs(sub, &format!("{} {}", name, sub.get_name()), globals, out);
+    }
+}
@@ -0,0 +1,92 @@
+//! `vx stats` — Local usage statistics.
+//!
+//! `--network` lists every download source seen on this machine with its
+//! recent failure rate, latency and throughput, so slow or failing mirrors
+//! stand out.
+
+use crate::ui::UI;
+use anyhow::Result;
+use colored::Colorize;
+use vx_cache::{NetworkStats, SourceStats};
+use vx_paths::VxPaths;
+
+/// Handle `vx stats`.
+///
+/// Network statistics are currently the only section, so they are shown
+/// with or without `--network`.
+pub async fn handle(_network: bool, json: bool, clear: bool) -> Result<()> {
+    let stats = NetworkStats::new(VxPaths::new()?.cache_dir);
+
+    if clear {
+        stats.clear()?;
+        UI::success("Cleared download statistics");
+        return Ok(());
+    }
+
+    let sources = stats.sources();
+    if json {
+        println!("{}", serde_json::to_string_pretty(&sources)?);
+        return Ok(());
+    }
+
+    UI::header("Download Sources");
+    if sources.is_empty() {
+        UI::info("No downloads recorded yet");
+        return Ok(());
+    }
+
+    let width = sources
+        .iter()
+        .map(|s| s.host.len())
+        .max()
+        .unwrap_or(0)
+        .max(6);
+    println!();
+    println!(
+        "  {:<width$}  {:>9}  {:>8}  {:>10}  {:>10}  {:>11}",
+        "SOURCE", "DOWNLOADS", "FAILURES", "LATENCY", "P95", "THROUGHPUT"
+    );
+    for source in &sources {
+        print_source(source, width);
+    }
+
+    let failing: Vec<_> = sources.iter().filter(|s| s.is_failing()).collect();
+    let slow = sources.iter().filter(|s| s.is_slow()).count();
+    println!();
+    for source in &failing {
+        if let Some(error) = &source.last_error {
+            UI::warn(&format!("{}: {}", source.host, error));
+        }
+    }
+    if !failing.is_empty() || slow > 0 {
+        UI::hint(
+            "Slow or failing sources can be replaced with a mirror in ~/.vx/config/mirrors.toml",
+        );
+    }
+    Ok(())
+}
+
+fn print_source(source: &SourceStats, width: usize) {
+    let millis = |ms: Option<u64>| ms.map_or("-".to_string(), |ms| format!("{} ms", ms));
+    let failures = format!("{:.0}%", source.recent_failure_rate * 100.0);
+    let throughput = source
+        .formatted_throughput()
+        .unwrap_or_else(|| "-".to_string());
+
+    let line = format!(
+        "  {:<width$}  {:>9}  {:>8}  {:>10}  {:>10}  {:>11}",
+        source.host,
+        source.successes,
+        failures,
+        millis(source.median_latency_ms),
+        millis(source.p95_latency_ms),
+        throughput,
+    );
+    if source.is_failing() {
+        println!("{}", line.red());
+    } else if source.is_slow() {
+        println!("{}", line.yellow());
+    } else {
+        println!("{}", line);
+    }
+}
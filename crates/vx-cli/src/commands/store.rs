@@ -0,0 +1,124 @@
+//! Store command implementation
+//!
+//! - `vx store export-oci <tool>@<version> --ref <ref>`: package a store entry
+//!   as an OCI artifact and push it to a registry or local OCI layout
+//! - `vx install --from-oci <ref>`: pull such an artifact into the store
+//!
+//! See [`crate::oci`] for the artifact format.
+
+use crate::cli::StoreCommand;
+use crate::oci::{self, OciTarget, ToolchainArtifact, ToolchainInfo};
+use crate::ui::UI;
+use anyhow::{Context, Result, bail};
+use vx_paths::PathManager;
+
+/// Handle store subcommands
+pub async fn handle(command: &StoreCommand) -> Result<()> {
+    match command {
+        StoreCommand::ExportOci { tool, reference } => handle_export_oci(tool, reference).await,
+    }
+}
+
+/// Resolve `tool[@version]` to an installed store version
+///
+/// Without a version, the tool must have exactly one installed version.
+fn resolve_installed(paths: &PathManager, spec: &str) -> Result<(String, String)> {
+    let request = vx_resolver::RuntimeRequest::parse(spec);
+    let installed = paths.list_store_versions(&request.name)?;
+
+    let version = match request.version {
+        Some(v) if installed.contains(&v) => v,
+        Some(v) => bail!(
+            "{}@{} is not installed (installed: {})",
+            request.name,
+            v,
+            if installed.is_empty() {
+                "none".to_string()
+            } else {
+                installed.join(", ")
+            }
+        ),
+        None => match installed.as_slice() {
+            [only] => only.clone(),
+            [] => bail!("{} is not installed", request.name),
+            _ => bail!(
+                "Multiple versions of {} are installed ({}); specify one with {}@<version>",
+                request.name,
+                installed.join(", "),
+                request.name
+            ),
+        },
+    };
+    Ok((request.name, version))
+}
+
+async fn handle_export_oci(spec: &str, reference: &str) -> Result<()> {
+    let target = OciTarget::parse(reference)?;
+    let paths = PathManager::new()?;
+    let (tool, version) = resolve_installed(&paths, spec)?;
+    let platform = paths.platform_dir_name();
+    let store_dir = paths.version_store_dir(&tool, &version);
+
+    UI::info(&format!("Packaging {tool}@{version} ({platform}) …"));
+    let artifact = ToolchainArtifact::pack(
+        ToolchainInfo {
+            tool: tool.clone(),
+            version: version.clone(),
+            platform,
+        },
+        &store_dir,
+    )?;
+
+    UI::info(&format!("Pushing to {target} …"));
+    let digest = oci::publish(&artifact, &target)
+        .await
+        .with_context(|| format!("Failed to push {target}"))?;
+
+    UI::success(&format!(
+        "Exported {tool}@{version} to {target} ({})",
+        super::common::format_size(artifact.layer.len() as u64)
+    ));
+    UI::detail(&format!("digest: {digest}"));
+    UI::hint(&format!(
+        "Install elsewhere with: vx install --from-oci {target}"
+    ));
+    Ok(())
+}
+
+/// Pull a toolchain artifact into the store (`vx install --from-oci <ref>`)
+pub async fn handle_install_from_oci(reference: &str, force: bool) -> Result<()> {
+    let target = OciTarget::parse(reference)?;
+    let paths = PathManager::new()?;
+
+    UI::info(&format!("Pulling {target} …"));
+    let (manifest, layer) = oci::fetch(&target)
+        .await
+        .with_context(|| format!("Failed to pull {target}"))?;
+    let info = manifest.toolchain()?;
+
+    let current = paths.platform_dir_name();
+    if info.platform != current {
+        bail!(
+            "{target} contains a {} build of {}@{}, but this machine is {current}",
+            info.platform,
+            info.tool,
+            info.version
+        );
+    }
+
+    let store_dir = paths.version_store_dir(&info.tool, &info.version);
+    if store_dir.exists() && !force {
+        UI::success(&format!(
+            "{}@{} is already installed (use --force to replace it)",
+            info.tool, info.version
+        ));
+        return Ok(());
+    }
+
+    oci::install_layer(&layer, &store_dir)?;
+    UI::success(&format!(
+        "Installed {}@{} from {target}",
+        info.tool, info.version
+    ));
+    Ok(())
+}
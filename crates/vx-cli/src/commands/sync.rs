@@ -12,6 +12,13 @@
 //! - If `vx.lock` exists but is inconsistent: warn and suggest `vx lock`
 //! - If `vx.lock` doesn't exist and `--auto-lock` is set: generate it automatically
 //! - If `vx.lock` doesn't exist: use versions from vx.toml
+//!
+//! ## Retries
+//!
+//! Tools whose installation fails are retried with a growing delay
+//! (`--retries`, `[settings] install_retries`, default 2). Sync ends with a
+//! [`SyncReport`] of installed, reused and failed tools and fails only when
+//! a tool still could not be installed.
 
 use crate::commands::common::{ToolStatus, check_tools_status_ordered};
 use crate::commands::setup::{find_vx_config, parse_vx_config, parse_vx_config_full};
@@ -19,15 +26,20 @@ use crate::ui::{InstallProgress, UI};
 use anyhow::{Context, Result};
 use std::collections::{BTreeMap, HashMap};
 use std::env;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 use std::process::Command;
+use std::time::Duration;
 use vx_paths::project::LOCK_FILE_NAME;
 use vx_project_analyzer::{AnalyzerConfig, ProjectAnalyzer};
 use vx_resolver::{LockFile, LockFileInconsistency};
 use vx_runtime::ProviderRegistry;
 
-/// Type alias for complex tool tuple reference
-type ToolInfoRef<'a> = &'a (String, String, ToolStatus, Option<PathBuf>, Option<String>);
+/// Retries of a failed installation when neither `--retries` nor
+/// `[settings] install_retries` is set
+pub const DEFAULT_INSTALL_RETRIES: u32 = 2;
+
+/// Longest delay before a retry
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
 
 /// Install result with optional error message
 type InstallResult = (String, bool, Option<String>);
@@ -88,6 +100,7 @@ pub async fn handle(
             no_parallel,
             auto_lock: false, // Default behavior
             analyze: true,    // Enable project analysis by default
+            retries: None,
         },
     )
     .await
@@ -109,6 +122,111 @@ pub struct SyncOptions {
     pub auto_lock: bool,
     /// Analyze project files for additional tools (e.g., detect just from Justfile)
     pub analyze: bool,
+    /// Retries of a failed installation (defaults to `[settings] install_retries`)
+    pub retries: Option<u32>,
+}
+
+/// Outcome of one tool in a sync
+#[derive(Debug, Clone, PartialEq)]
+pub enum ToolOutcome {
+    /// Installed by this sync after `attempts` tries
+    Installed { attempts: u32 },
+    /// Already installed (or provided by the system)
+    Reused,
+    /// Still failing after `attempts` tries
+    Failed { attempts: u32, reason: String },
+}
+
+/// Per-tool outcomes of a sync
+#[derive(Debug, Clone, Default)]
+pub struct SyncReport {
+    pub tools: Vec<(String, String, ToolOutcome)>,
+}
+
+impl SyncReport {
+    /// Record the outcome of `name@version`, replacing an earlier one
+    pub fn record(&mut self, name: &str, version: &str, outcome: ToolOutcome) {
+        match self.tools.iter_mut().find(|(n, _, _)| n == name) {
+            Some(entry) => entry.2 = outcome,
+            None => self
+                .tools
+                .push((name.to_string(), version.to_string(), outcome)),
+        }
+    }
+
+    /// Tools that failed, with their versions
+    pub fn failed(&self) -> Vec<(&str, &str)> {
+        self.tools
+            .iter()
+            .filter(|(_, _, outcome)| matches!(outcome, ToolOutcome::Failed { .. }))
+            .map(|(name, version, _)| (name.as_str(), version.as_str()))
+            .collect()
+    }
+
+    /// Grouped summary: installed, reused, then failed tools with reasons
+    pub fn render(&self) -> String {
+        let mut installed = Vec::new();
+        let mut reused = Vec::new();
+        let mut failed = Vec::new();
+        for (name, version, outcome) in &self.tools {
+            match outcome {
+                ToolOutcome::Installed { attempts } if *attempts > 1 => {
+                    installed.push(format!("{}@{} (attempt {})", name, version, attempts))
+                }
+                ToolOutcome::Installed { .. } => installed.push(format!("{}@{}", name, version)),
+                ToolOutcome::Reused => reused.push(format!("{}@{}", name, version)),
+                ToolOutcome::Failed { attempts, reason } => failed.push(format!(
+                    "{}@{} ({} attempt{}): {}",
+                    name,
+                    version,
+                    attempts,
+                    if *attempts == 1 { "" } else { "s" },
+                    reason
+                )),
+            }
+        }
+
+        let mut out = String::from("Sync summary:\n");
+        if !installed.is_empty() {
+            out.push_str(&format!(
+                "  Installed ({}): {}\n",
+                installed.len(),
+                installed.join(", ")
+            ));
+        }
+        if !reused.is_empty() {
+            out.push_str(&format!(
+                "  Reused ({}): {}\n",
+                reused.len(),
+                reused.join(", ")
+            ));
+        }
+        if !failed.is_empty() {
+            out.push_str(&format!("  Failed ({}):\n", failed.len()));
+            for line in failed {
+                out.push_str(&format!("    ✗ {}\n", line));
+            }
+        }
+        out
+    }
+}
+
+/// Delay before retry number `retry` (1-based): 2s, 4s, 8s, ... up to 30s
+pub fn retry_delay(retry: u32) -> Duration {
+    Duration::from_secs(2u64.saturating_pow(retry.min(16))).min(MAX_RETRY_DELAY)
+}
+
+/// The most useful line of an install error: the first non-empty one that
+/// is not spinner output, without the `✗` marker of `vx install`
+pub fn failure_reason(error: Option<&str>) -> String {
+    error
+        .and_then(|err| {
+            err.lines()
+                .map(|line| line.trim().trim_start_matches('✗').trim_start())
+                .find(|line| !line.is_empty() && !line.starts_with('�'))
+        })
+        .unwrap_or("unknown error")
+        .to_string()
 }
 
 /// Handle the sync command with options
@@ -127,6 +245,7 @@ pub async fn handle_with_options(_registry: &ProviderRegistry, options: SyncOpti
     // Load full config (unfiltered) for lock file consistency check
     // The lock file should cover ALL platforms, not just the current one
     let full_config = parse_vx_config_full(&config_path)?;
+    super::common::ensure_gpu_requirement(&full_config)?;
     let config_tools = full_config.tools_as_btreemap();
 
     // Check lock file status
@@ -223,6 +342,8 @@ pub async fn handle_with_options(_registry: &ProviderRegistry, options: SyncOpti
         }
     }
 
+    crate::eol::warn_project_pins(None, UI::warn);
+
     // Check tool status
     let statuses = check_tools_status_ordered(&effective_tools)?;
 
@@ -248,9 +369,10 @@ pub async fn handle_with_options(_registry: &ProviderRegistry, options: SyncOpti
     }
 
     // Count missing tools
-    let missing: Vec<_> = statuses
+    let missing: Vec<(String, String)> = statuses
         .iter()
         .filter(|(_, _, status, _, _)| matches!(status, ToolStatus::NotInstalled) || options.force)
+        .map(|(name, version, _, _, _)| (name.clone(), version.clone()))
         .collect();
 
     if missing.is_empty() {
@@ -266,74 +388,126 @@ pub async fn handle_with_options(_registry: &ProviderRegistry, options: SyncOpti
 
     if options.dry_run {
         UI::info(&format!("Would install {} tool(s):", missing.len()));
-        for (name, version, _, _, _) in &missing {
+        for (name, version) in &missing {
             println!("  - {}@{}", name, version);
         }
         return Ok(());
     }
 
+    let mut report = SyncReport::default();
+    for (name, version, _, _, _) in &statuses {
+        if !missing.iter().any(|(n, _)| n == name) {
+            report.record(name, version, ToolOutcome::Reused);
+        }
+    }
+
     // Build install-time env vars from ToolConfig metadata (e.g., MSVC components)
     let install_env_vars = build_install_env_vars(&full_config);
 
-    // Install missing tools using InstallProgress for unified progress display
-    let mut progress = InstallProgress::new(
-        missing.len(),
-        &format!("Installing {} tool(s)", missing.len()),
-    );
-
-    let results = if options.no_parallel {
-        install_sequential_with_progress(
-            &missing,
-            options.verbose,
-            &mut progress,
-            &install_env_vars,
-        )
-        .await?
-    } else {
-        install_parallel_with_progress(&missing, options.verbose, &mut progress, &install_env_vars)
-            .await?
-    };
+    let retries = options
+        .retries
+        .or_else(|| {
+            full_config
+                .settings
+                .as_ref()
+                .and_then(|s| s.install_retries)
+        })
+        .unwrap_or(DEFAULT_INSTALL_RETRIES);
+
+    let mut pending = missing;
+    for attempt in 1..=retries + 1 {
+        if attempt > 1 {
+            let delay = retry_delay(attempt - 1);
+            println!();
+            UI::warn(&format!(
+                "Retrying {} failed tool(s) in {}s (attempt {}/{})",
+                pending.len(),
+                delay.as_secs(),
+                attempt,
+                retries + 1
+            ));
+            tokio::time::sleep(delay).await;
+        }
 
-    // Finish progress
-    let successful = results.iter().filter(|(_, ok, _)| *ok).count();
-    let failed = results.len() - successful;
+        // Install pending tools using InstallProgress for unified progress display
+        let mut progress = InstallProgress::new(
+            pending.len(),
+            &format!("Installing {} tool(s)", pending.len()),
+        );
+
+        let results = if options.no_parallel {
+            install_sequential_with_progress(
+                &pending,
+                options.verbose,
+                &mut progress,
+                &install_env_vars,
+            )
+            .await?
+        } else {
+            install_parallel_with_progress(
+                &pending,
+                options.verbose,
+                &mut progress,
+                &install_env_vars,
+            )
+            .await?
+        };
 
-    if failed == 0 {
-        progress.finish(&format!(
-            "✓ Successfully synchronized {} tool(s)",
-            successful
-        ));
-    } else {
-        progress.finish(&format!(
-            "⚠ Synchronized {}/{} tools ({} failed)",
-            successful,
-            results.len(),
-            failed
-        ));
-    }
+        let successful = results.iter().filter(|(_, ok, _)| *ok).count();
+        if successful == results.len() {
+            progress.finish(&format!("✓ Successfully installed {} tool(s)", successful));
+        } else {
+            progress.finish(&format!(
+                "⚠ Installed {}/{} tools ({} failed)",
+                successful,
+                results.len(),
+                results.len() - successful
+            ));
+        }
 
-    // Show detailed error information for failed tools
-    if failed > 0 {
-        println!();
-        UI::error("Failed installations:");
-        for (name, success, error) in &results {
-            if !success {
-                println!("  ✗ {}", name);
-                if let Some(err) = error {
-                    // Show error details, indented
-                    for line in err.lines().take(5) {
-                        // Skip empty lines and spinner characters
-                        let trimmed = line.trim();
-                        if !trimmed.is_empty() && !trimmed.starts_with('�') {
-                            println!("    {}", trimmed);
-                        }
-                    }
+        let mut still_failing = Vec::new();
+        for (name, version) in pending {
+            match results.iter().find(|(n, _, _)| *n == name) {
+                Some((_, true, _)) => report.record(
+                    &name,
+                    &version,
+                    ToolOutcome::Installed { attempts: attempt },
+                ),
+                result => {
+                    let reason = failure_reason(result.and_then(|(_, _, error)| error.as_deref()));
+                    report.record(
+                        &name,
+                        &version,
+                        ToolOutcome::Failed {
+                            attempts: attempt,
+                            reason,
+                        },
+                    );
+                    still_failing.push((name, version));
                 }
             }
         }
+        pending = still_failing;
+        if pending.is_empty() {
+            break;
+        }
+    }
+
+    println!();
+    print!("{}", report.render());
 
+    let failed = report.failed();
+    if !failed.is_empty() {
         println!();
-        UI::hint("Run 'vx install <tool> <version>' for more details on specific failures");
+        UI::hint("Run 'vx install <tool>@<version>' for more details on specific failures");
+        anyhow::bail!(
+            "Failed to install {}",
+            failed
+                .iter()
+                .map(|(name, version)| format!("{}@{}", name, version))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
     }
 
     Ok(())
@@ -360,14 +534,17 @@ fn run_lock_command() -> Result<()> {
 
 /// Build environment variables from a single ToolConfig for install subprocesses.
 ///
-/// Extracts VX_MSVC_COMPONENTS, VX_MSVC_EXCLUDE_PATTERNS, and any custom install_env vars.
-fn build_env_vars_for_tool(tool_config: &vx_config::ToolConfig) -> InstallEnvVars {
+/// Extracts VX_<TOOL>_COMPONENTS, VX_MSVC_EXCLUDE_PATTERNS, and any custom install_env vars.
+fn build_env_vars_for_tool(name: &str, tool_config: &vx_config::ToolConfig) -> InstallEnvVars {
     let mut env_vars = HashMap::new();
 
     if let Some(components) = &tool_config.components
         && !components.is_empty()
     {
-        env_vars.insert("VX_MSVC_COMPONENTS".to_string(), components.join(","));
+        env_vars.insert(
+            vx_runtime::RuntimeContext::components_option_key(name),
+            components.join(","),
+        );
     }
 
     if let Some(patterns) = &tool_config.exclude_patterns
@@ -394,7 +571,7 @@ fn build_install_env_vars(config: &vx_config::VxConfig) -> HashMap<String, Insta
     // Check [tools] section first (higher priority)
     for name in config.tools.keys() {
         if let Some(tool_config) = config.get_tool_config(name) {
-            let env_vars = build_env_vars_for_tool(tool_config);
+            let env_vars = build_env_vars_for_tool(name, tool_config);
             if !env_vars.is_empty() {
                 tool_envs.insert(name.clone(), env_vars);
             }
@@ -407,7 +584,7 @@ fn build_install_env_vars(config: &vx_config::VxConfig) -> HashMap<String, Insta
             continue; // tools section takes precedence
         }
         if let Some(tool_config) = config.get_tool_config(name) {
-            let env_vars = build_env_vars_for_tool(tool_config);
+            let env_vars = build_env_vars_for_tool(name, tool_config);
             if !env_vars.is_empty() {
                 tool_envs.insert(name.clone(), env_vars);
             }
@@ -446,14 +623,14 @@ fn resolve_effective_versions(
 
 /// Install tools sequentially with progress display
 async fn install_sequential_with_progress(
-    tools: &[ToolInfoRef<'_>],
+    tools: &[(String, String)],
     verbose: bool,
     progress: &mut InstallProgress,
     install_env_vars: &HashMap<String, InstallEnvVars>,
 ) -> Result<Vec<InstallResult>> {
     let mut results = Vec::new();
 
-    for (name, version, _, _, _) in tools {
+    for (name, version) in tools {
         progress.start_tool(name, version);
 
         let env_vars = install_env_vars.get(name.as_str()).cloned();
@@ -478,7 +655,7 @@ async fn install_sequential_with_progress(
 
 /// Install tools in parallel with progress display
 async fn install_parallel_with_progress(
-    tools: &[ToolInfoRef<'_>],
+    tools: &[(String, String)],
     _verbose: bool,
     progress: &mut InstallProgress,
     install_env_vars: &HashMap<String, InstallEnvVars>,
@@ -488,7 +665,7 @@ async fn install_parallel_with_progress(
     let mut join_set = JoinSet::new();
 
     // Start all installations
-    for (name, version, _, _, _) in tools {
+    for (name, version) in tools {
         let name = name.clone();
         let version = version.clone();
         let env_vars = install_env_vars.get(name.as_str()).cloned();
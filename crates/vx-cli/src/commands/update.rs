@@ -0,0 +1,849 @@
+//! Update command implementation
+//!
+//! `vx update` compares the version each project tool is locked (or installed)
+//! at with the newest version its `vx.toml` constraint allows, and reports the
+//! release notes in between.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! # Report available updates for every tool in vx.toml
+//! vx update --all
+//!
+//! # Install them and refresh vx.lock
+//! vx update --all --apply
+//!
+//! # Write a Markdown summary for a PR description
+//! vx update --all --apply --summary UPDATES.md
+//!
+//! # Upgrade every installed tool, not just the project's
+//! vx upgrade --all
+//!
+//! # Print what --apply would download and switch, as JSON
+//! vx update --all --dry-run --json
+//! ```
+//!
+//! `vx upgrade` works on the vx store instead of vx.toml: each installed tool
+//! moves to the newest release on its channel (LTS stays LTS), upgrades run in
+//! dependency order, and a failure rolls back the versions installed so far.
+//! Each run is recorded in the install journal, so `vx rollback <tool>` can
+//! undo a single upgrade later.
+//!
+//! Release notes come from the version metadata providers already fetch
+//! (GitHub releases carry the release URL and a body summary), so no extra
+//! API calls are made.
+
+use super::lock::{get_version_string, resolve_tool_version, resolver_ecosystem};
+use super::plan::{ActionPlan, PlannedAction, plan_download};
+use super::rollback::{Transaction, undo_changes};
+use crate::ui::UI;
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use vx_config::{VxConfig, parse_config};
+use vx_paths::project::{LOCK_FILE_NAME, find_vx_config};
+use vx_paths::{PathManager, TransactionStatus};
+use vx_resolver::{Ecosystem, LockFile, SolverConfig, Version, VersionRequest, VersionSolver};
+use vx_runtime::{ProviderRegistry, RuntimeContext, VersionInfo};
+
+/// Release notes shown per tool before collapsing the rest into a count
+const MAX_NOTES_PER_TOOL: usize = 5;
+
+/// Options for the update command
+#[derive(Debug, Clone, Default)]
+pub struct UpdateOptions {
+    /// Tools to update (empty with `all` means every tool in vx.toml)
+    pub tools: Vec<String>,
+    /// Update every tool in vx.toml
+    pub all: bool,
+    /// Install the upgrades instead of only reporting them
+    pub apply: bool,
+    /// Where to write the Markdown summary (`-` for stdout)
+    pub summary: Option<PathBuf>,
+}
+
+/// Options for the upgrade command
+#[derive(Debug, Clone, Default)]
+pub struct UpgradeOptions {
+    /// Installed tools to upgrade (empty with `all` means every installed tool)
+    pub tools: Vec<String>,
+    /// Upgrade every installed tool
+    pub all: bool,
+    /// Only show the upgrade preview
+    pub dry_run: bool,
+}
+
+/// Release notes for one version between the current and target version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseNote {
+    /// Released version
+    pub version: String,
+    /// Release page URL
+    pub url: Option<String>,
+    /// Short release notes summary
+    pub summary: Option<String>,
+}
+
+/// Update plan for a single tool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolUpdate {
+    /// Tool name
+    pub name: String,
+    /// Version constraint from vx.toml
+    pub constraint: String,
+    /// Locked or installed version, if any
+    pub current: Option<String>,
+    /// Newest version the constraint allows
+    pub target: Option<String>,
+    /// Release date of the target version (YYYY-MM-DD)
+    pub released: Option<String>,
+    /// Whether the target version is an LTS release
+    pub lts: bool,
+    /// Newest stable version regardless of the constraint
+    pub latest: Option<String>,
+    /// Release notes for versions in `(current, target]`, newest first
+    pub notes: Vec<ReleaseNote>,
+    /// Why the tool could not be checked or updated
+    pub error: Option<String>,
+}
+
+impl ToolUpdate {
+    /// Whether a newer version is allowed by the constraint
+    pub fn is_outdated(&self) -> bool {
+        match (&self.current, &self.target) {
+            (Some(current), Some(target)) => is_newer(target, current),
+            _ => false,
+        }
+    }
+
+    /// Whether the constraint keeps the tool below the newest release
+    pub fn is_held_back(&self) -> bool {
+        match (&self.target, &self.latest) {
+            (Some(target), Some(latest)) => is_newer(latest, target),
+            _ => false,
+        }
+    }
+}
+
+/// Build the update plan for one tool from its available versions
+pub fn plan_update(
+    name: &str,
+    constraint: &str,
+    current: Option<&str>,
+    versions: &[VersionInfo],
+    ecosystem: &Ecosystem,
+) -> ToolUpdate {
+    plan_with_solver(
+        &VersionSolver::new(),
+        name,
+        constraint,
+        current,
+        versions,
+        ecosystem,
+    )
+}
+
+/// Build the upgrade plan for an installed tool
+///
+/// A tool installed at an LTS release is kept on the LTS line; anything else
+/// moves to the newest stable release.
+pub fn plan_installed_upgrade(
+    name: &str,
+    current: &str,
+    versions: &[VersionInfo],
+    ecosystem: &Ecosystem,
+) -> ToolUpdate {
+    let on_lts = versions.iter().any(|v| v.version == current && v.lts);
+    let solver = VersionSolver::with_config(SolverConfig {
+        prefer_lts: on_lts,
+        ..Default::default()
+    });
+    let constraint = if on_lts { "lts" } else { "latest" };
+    plan_with_solver(
+        &solver,
+        name,
+        constraint,
+        Some(current),
+        versions,
+        ecosystem,
+    )
+}
+
+fn plan_with_solver(
+    solver: &VersionSolver,
+    name: &str,
+    constraint: &str,
+    current: Option<&str>,
+    versions: &[VersionInfo],
+    ecosystem: &Ecosystem,
+) -> ToolUpdate {
+    let mut update = ToolUpdate {
+        name: name.to_string(),
+        constraint: constraint.to_string(),
+        current: current.map(str::to_string),
+        target: None,
+        released: None,
+        lts: false,
+        latest: None,
+        notes: Vec::new(),
+        error: None,
+    };
+
+    match solver.resolve(
+        name,
+        &VersionRequest::parse(constraint),
+        versions,
+        ecosystem,
+    ) {
+        Ok(resolved) => update.target = Some(resolved.version_string()),
+        Err(e) => {
+            update.error = Some(e.to_string());
+            return update;
+        }
+    }
+    if let Some(info) = versions
+        .iter()
+        .find(|v| Some(v.version.as_str()) == update.target.as_deref())
+    {
+        update.lts = info.lts;
+        update.released = info
+            .released_at
+            .map(|dt| dt.format("%Y-%m-%d").to_string())
+            .or_else(|| {
+                info.metadata
+                    .get("release_date")
+                    .map(|d| d.chars().take(10).collect())
+            });
+    }
+    update.latest = solver
+        .resolve(name, &VersionRequest::latest(), versions, ecosystem)
+        .ok()
+        .map(|resolved| resolved.version_string());
+
+    if update.is_outdated()
+        && let (Some(current), Some(target)) = (&update.current, &update.target)
+    {
+        let mut notes: Vec<(Version, ReleaseNote)> = versions
+            .iter()
+            .filter(|v| !v.prerelease)
+            .filter(|v| is_newer(&v.version, current) && !is_newer(&v.version, target))
+            .filter(|v| v.release_url().is_some() || v.release_notes().is_some())
+            .filter_map(|v| {
+                let note = ReleaseNote {
+                    version: v.version.clone(),
+                    url: v.release_url().map(str::to_string),
+                    summary: v.release_notes().map(str::to_string),
+                };
+                Version::parse(&v.version).map(|parsed| (parsed, note))
+            })
+            .collect();
+        notes.sort_by(|(a, _), (b, _)| b.cmp(a));
+        notes.dedup_by(|(a, _), (b, _)| a == b);
+        update.notes = notes.into_iter().map(|(_, note)| note).collect();
+    }
+
+    update
+}
+
+/// Render the updates as Markdown suitable for a PR description
+///
+/// Tools whose update failed are listed under "Not updated" instead of the
+/// update table.
+pub fn render_summary(updates: &[ToolUpdate], applied: bool) -> String {
+    let mut out = String::new();
+    let outdated: Vec<_> = updates
+        .iter()
+        .filter(|u| u.is_outdated() && u.error.is_none())
+        .collect();
+
+    out.push_str(if applied {
+        "## Tool updates\n\n"
+    } else {
+        "## Available tool updates\n\n"
+    });
+
+    if outdated.is_empty() {
+        out.push_str("All tools are up to date.\n");
+    } else {
+        out.push_str("| Tool | From | To | Constraint |\n");
+        out.push_str("| --- | --- | --- | --- |\n");
+        for update in &outdated {
+            out.push_str(&format!(
+                "| {} | {} | {} | `{}` |\n",
+                update.name,
+                update.current.as_deref().unwrap_or("-"),
+                update.target.as_deref().unwrap_or("-"),
+                update.constraint
+            ));
+        }
+
+        for update in outdated.iter().filter(|u| !u.notes.is_empty()) {
+            out.push_str(&format!(
+                "\n### {} {} → {}\n\n",
+                update.name,
+                update.current.as_deref().unwrap_or("-"),
+                update.target.as_deref().unwrap_or("-")
+            ));
+            for note in update.notes.iter().take(MAX_NOTES_PER_TOOL) {
+                match &note.url {
+                    Some(url) => out.push_str(&format!("- [{}]({})\n", note.version, url)),
+                    None => out.push_str(&format!("- {}\n", note.version)),
+                }
+                if let Some(summary) = &note.summary {
+                    for line in summary.lines() {
+                        out.push_str(&format!("  > {}\n", line));
+                    }
+                }
+            }
+            let hidden = update.notes.len().saturating_sub(MAX_NOTES_PER_TOOL);
+            if hidden > 0 {
+                out.push_str(&format!("- …and {} earlier release(s)\n", hidden));
+            }
+        }
+    }
+
+    let held_back: Vec<_> = updates.iter().filter(|u| u.is_held_back()).collect();
+    if !held_back.is_empty() {
+        out.push_str("\n### Held back by vx.toml\n\n");
+        for update in held_back {
+            out.push_str(&format!(
+                "- {} stays on {} (`{}`), latest is {}\n",
+                update.name,
+                update.target.as_deref().unwrap_or("-"),
+                update.constraint,
+                update.latest.as_deref().unwrap_or("-")
+            ));
+        }
+    }
+
+    let failed: Vec<_> = updates.iter().filter(|u| u.error.is_some()).collect();
+    if !failed.is_empty() {
+        out.push_str("\n### Not updated\n\n");
+        for update in failed {
+            out.push_str(&format!(
+                "- {}: {}\n",
+                update.name,
+                update.error.as_deref().unwrap_or_default()
+            ));
+        }
+    }
+
+    out
+}
+
+/// Render the `vx upgrade` preview table
+pub fn render_upgrade_table(updates: &[ToolUpdate]) -> String {
+    let mut out = format!(
+        "{:<16} {:<14} {:<14} {:<12} LTS\n",
+        "TOOL", "CURRENT", "LATEST", "RELEASED"
+    );
+    for update in updates
+        .iter()
+        .filter(|u| u.is_outdated() && u.error.is_none())
+    {
+        out.push_str(&format!(
+            "{:<16} {:<14} {:<14} {:<12} {}\n",
+            update.name,
+            update.current.as_deref().unwrap_or("-"),
+            update.target.as_deref().unwrap_or("-"),
+            update.released.as_deref().unwrap_or("-"),
+            if update.lts { "yes" } else { "-" }
+        ));
+    }
+    out
+}
+
+/// Order tools so that each comes after the tools it depends on
+///
+/// Only dependencies within `names` are considered; cycles are broken at the
+/// first repeated tool. Tools without dependencies keep their input order.
+pub fn dependency_order(names: &[String], deps_of: impl Fn(&str) -> Vec<String>) -> Vec<String> {
+    fn visit(
+        name: &str,
+        names: &[String],
+        deps_of: &dyn Fn(&str) -> Vec<String>,
+        visited: &mut HashSet<String>,
+        order: &mut Vec<String>,
+    ) {
+        if !visited.insert(name.to_string()) {
+            return;
+        }
+        for dep in deps_of(name) {
+            if names.contains(&dep) {
+                visit(&dep, names, deps_of, visited, order);
+            }
+        }
+        order.push(name.to_string());
+    }
+
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+    for name in names {
+        visit(name, names, &deps_of, &mut visited, &mut order);
+    }
+    order
+}
+
+/// Project updates selected by `options`, with the project's lock file
+/// (when present) and its path
+async fn load_project_updates(
+    registry: &ProviderRegistry,
+    ctx: &RuntimeContext,
+    options: &UpdateOptions,
+) -> Result<(Vec<ToolUpdate>, Option<LockFile>, PathBuf)> {
+    if !options.all && options.tools.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Specify the tools to update, or use --all to update every tool in vx.toml"
+        ));
+    }
+
+    let current_dir = std::env::current_dir()?;
+    let config_path =
+        find_vx_config(&current_dir).map_err(|e| anyhow::anyhow!("No vx.toml found: {}", e))?;
+    let project_root = config_path.parent().unwrap_or(&current_dir);
+    let lock_path = project_root.join(LOCK_FILE_NAME);
+
+    let config = parse_config(&config_path)
+        .with_context(|| format!("Failed to load {}", config_path.display()))?;
+    let lock = if lock_path.exists() {
+        Some(
+            LockFile::load(&lock_path)
+                .with_context(|| format!("Failed to load lock file: {}", lock_path.display()))?,
+        )
+    } else {
+        None
+    };
+
+    for tool in &options.tools {
+        if !config.tools.contains_key(tool) {
+            return Err(anyhow::anyhow!("{} is not configured in vx.toml", tool));
+        }
+    }
+
+    let updates = plan_project_updates(registry, ctx, &config, lock.as_ref(), |name| {
+        options.all || options.tools.iter().any(|t| t == name)
+    })
+    .await?;
+    Ok((updates, lock, lock_path))
+}
+
+/// Handle the update command
+pub async fn handle(
+    registry: &ProviderRegistry,
+    ctx: &RuntimeContext,
+    options: &UpdateOptions,
+) -> Result<()> {
+    let (mut updates, mut lock, lock_path) = load_project_updates(registry, ctx, options).await?;
+    let solver = VersionSolver::new();
+
+    print_report(&updates);
+
+    let outdated = updates.iter().filter(|u| u.is_outdated()).count();
+    if options.apply && outdated > 0 {
+        for update in updates.iter_mut().filter(|u| u.is_outdated()) {
+            let target = update.target.clone().unwrap_or_default();
+            UI::section(&format!("Updating {} to {}", update.name, target));
+
+            let spec = format!("{}@{}", update.name, target);
+            if let Err(e) = super::install::handle_install(registry, ctx, &[spec], false).await {
+                update.error = Some(format!("install failed: {}", e));
+                continue;
+            }
+
+            if let Some(lock) = lock.as_mut() {
+                match resolve_tool_version(
+                    registry,
+                    ctx,
+                    &solver,
+                    &update.name,
+                    &update.constraint,
+                    false,
+                )
+                .await
+                {
+                    Ok(locked) => lock.lock_tool(update.name.clone(), locked),
+                    Err(e) => UI::warn(&format!(
+                        "Failed to refresh {} entry for {}: {}",
+                        LOCK_FILE_NAME, update.name, e
+                    )),
+                }
+            }
+        }
+
+        if let Some(lock) = &lock {
+            lock.save(&lock_path)?;
+            UI::detail(&format!("Updated {}", lock_path.display()));
+        }
+    } else if outdated > 0 {
+        UI::hint("Run 'vx update --all --apply' to install these updates");
+    }
+
+    if let Some(path) = &options.summary {
+        write_summary(path, &render_summary(&updates, options.apply))?;
+    }
+
+    let failed = updates.iter().filter(|u| u.error.is_some()).count();
+    if options.apply && failed > 0 {
+        return Err(anyhow::anyhow!("{} tool(s) could not be updated", failed));
+    }
+    Ok(())
+}
+
+/// Plan updates for the vx.toml tools `selected` accepts
+///
+/// The current version of each tool is its locked version, else the newest
+/// installed version in range. Tools that cannot be checked are returned with
+/// an error instead of failing the whole plan.
+pub async fn plan_project_updates(
+    registry: &ProviderRegistry,
+    ctx: &RuntimeContext,
+    config: &VxConfig,
+    lock: Option<&LockFile>,
+    selected: impl Fn(&str) -> bool,
+) -> Result<Vec<ToolUpdate>> {
+    let path_manager = PathManager::new()?;
+    let solver = VersionSolver::new();
+    let mut updates = Vec::new();
+
+    for (name, version) in &config.tools {
+        if !selected(name) {
+            continue;
+        }
+        let constraint = get_version_string(version);
+
+        let Some(runtime) = registry.get_runtime(name) else {
+            updates.push(failed_update(name, &constraint, None, "unknown tool"));
+            continue;
+        };
+        let ecosystem = resolver_ecosystem(runtime.ecosystem());
+
+        // Locked version first, then the newest installed version in range
+        let current = lock
+            .and_then(|l| l.get_tool(name))
+            .map(|t| t.version.clone())
+            .or_else(|| {
+                let mut installed: Vec<(Version, String)> = path_manager
+                    .list_store_versions(name)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|v| solver.version_satisfies(v, &constraint, &ecosystem))
+                    .filter_map(|v| Version::parse(&v).map(|parsed| (parsed, v)))
+                    .collect();
+                installed.sort_by(|(a, _), (b, _)| b.cmp(a));
+                installed.into_iter().next().map(|(_, v)| v)
+            })
+            .map(|v| crate::registry::find_version_normalizer(name).normalize(&v));
+
+        let Some(current) = current else {
+            updates.push(failed_update(
+                name,
+                &constraint,
+                None,
+                "not installed (run 'vx sync' first)",
+            ));
+            continue;
+        };
+
+        match runtime.fetch_versions(ctx).await {
+            Ok(versions) => updates.push(plan_update(
+                name,
+                &constraint,
+                Some(&current),
+                &versions,
+                &ecosystem,
+            )),
+            Err(e) => updates.push(failed_update(
+                name,
+                &constraint,
+                Some(&current),
+                &format!("failed to fetch versions: {}", e),
+            )),
+        }
+    }
+
+    Ok(updates)
+}
+
+/// Plan what `vx update --apply` would do
+pub async fn plan(
+    registry: &ProviderRegistry,
+    ctx: &RuntimeContext,
+    options: &UpdateOptions,
+) -> Result<ActionPlan> {
+    let (updates, lock, lock_path) = load_project_updates(registry, ctx, options).await?;
+    let mut plan = plan_tool_updates("update", registry, ctx, &updates).await;
+    if lock.is_some() && updates.iter().any(|u| u.is_outdated() && u.error.is_none()) {
+        plan.push(PlannedAction::UpdateFile {
+            path: lock_path,
+            change: "lock the updated versions".to_string(),
+        });
+    }
+    Ok(plan)
+}
+
+/// Plan what `vx upgrade` would do
+pub async fn plan_upgrade(
+    registry: &ProviderRegistry,
+    ctx: &RuntimeContext,
+    options: &UpgradeOptions,
+) -> Result<ActionPlan> {
+    let updates = collect_upgrades(registry, ctx, options).await?;
+    Ok(plan_tool_updates("upgrade", registry, ctx, &updates).await)
+}
+
+/// Download and switch every outdated tool; skip the ones that cannot be updated
+async fn plan_tool_updates(
+    command: &str,
+    registry: &ProviderRegistry,
+    ctx: &RuntimeContext,
+    updates: &[ToolUpdate],
+) -> ActionPlan {
+    let mut plan = ActionPlan::new(command);
+    for update in updates {
+        if let Some(error) = &update.error {
+            plan.push(PlannedAction::Skip {
+                tool: update.name.clone(),
+                version: update.current.clone(),
+                reason: error.clone(),
+            });
+            continue;
+        }
+        if !update.is_outdated() {
+            continue;
+        }
+        let (Some(target), Some(runtime)) = (&update.target, registry.get_runtime(&update.name))
+        else {
+            continue;
+        };
+        plan.push(plan_download(runtime.as_ref(), ctx, target).await);
+        plan.push(PlannedAction::Switch {
+            tool: update.name.clone(),
+            from: update.current.clone(),
+            to: target.clone(),
+        });
+    }
+    plan
+}
+
+/// Upgrade plans for the installed tools `options` selects
+async fn collect_upgrades(
+    registry: &ProviderRegistry,
+    ctx: &RuntimeContext,
+    options: &UpgradeOptions,
+) -> Result<Vec<ToolUpdate>> {
+    if !options.all && options.tools.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Specify the tools to upgrade, or use --all to upgrade every installed tool"
+        ));
+    }
+
+    let path_manager = PathManager::new()?;
+    let names = if options.all {
+        path_manager.list_store_runtimes()?
+    } else {
+        options.tools.clone()
+    };
+
+    let mut updates = Vec::new();
+    for name in &names {
+        let Some(runtime) = registry.get_runtime(name) else {
+            // The store may hold directories of tools no provider knows anymore
+            if !options.all {
+                updates.push(failed_update(name, "latest", None, "unknown tool"));
+            }
+            continue;
+        };
+        let ecosystem = resolver_ecosystem(runtime.ecosystem());
+
+        let normalizer = crate::registry::find_version_normalizer(name);
+        let mut installed: Vec<(Version, String)> = path_manager
+            .list_store_versions(name)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|v| normalizer.normalize(&v))
+            .filter_map(|v| Version::parse(&v).map(|parsed| (parsed, v)))
+            .collect();
+        installed.sort_by(|(a, _), (b, _)| b.cmp(a));
+        let Some((_, current)) = installed.into_iter().next() else {
+            if !options.all {
+                updates.push(failed_update(name, "latest", None, "not installed"));
+            }
+            continue;
+        };
+
+        match runtime.fetch_versions(ctx).await {
+            Ok(versions) => updates.push(plan_installed_upgrade(
+                name, &current, &versions, &ecosystem,
+            )),
+            Err(e) => updates.push(failed_update(
+                name,
+                "latest",
+                Some(&current),
+                &format!("failed to fetch versions: {}", e),
+            )),
+        }
+    }
+    Ok(updates)
+}
+
+/// Handle the upgrade command
+pub async fn handle_upgrade(
+    registry: &ProviderRegistry,
+    ctx: &RuntimeContext,
+    options: &UpgradeOptions,
+) -> Result<()> {
+    let updates = collect_upgrades(registry, ctx, options).await?;
+
+    for update in updates.iter().filter(|u| u.error.is_some()) {
+        UI::warn(&format!(
+            "{}: {}",
+            update.name,
+            update.error.as_deref().unwrap_or_default()
+        ));
+    }
+
+    let outdated: Vec<&ToolUpdate> = updates
+        .iter()
+        .filter(|u| u.is_outdated() && u.error.is_none())
+        .collect();
+    if outdated.is_empty() {
+        UI::success("All installed tools are up to date");
+        return Ok(());
+    }
+
+    UI::header(&format!("{} upgrade(s) available", outdated.len()));
+    print!("{}", render_upgrade_table(&updates));
+    for update in &outdated {
+        if !update.notes.is_empty() {
+            UI::item(&update.name);
+            print_notes(update);
+        }
+    }
+
+    if options.dry_run {
+        UI::hint("Run without --dry-run to install these upgrades");
+        return Ok(());
+    }
+
+    let names: Vec<String> = outdated.iter().map(|u| u.name.clone()).collect();
+    let order = dependency_order(&names, |name| {
+        registry
+            .get_runtime(name)
+            .map(|r| r.dependencies().iter().map(|d| d.name.clone()).collect())
+            .unwrap_or_default()
+    });
+
+    let mut transaction = Transaction::begin("upgrade");
+    for name in &order {
+        let Some(update) = outdated.iter().find(|u| &u.name == name) else {
+            continue;
+        };
+        let target = update.target.clone().unwrap_or_default();
+        UI::section(&format!(
+            "Upgrading {} {} → {}",
+            name,
+            update.current.as_deref().unwrap_or("-"),
+            target
+        ));
+
+        let spec = format!("{}@{}", name, target);
+        if let Err(e) =
+            super::install::install_recorded(registry, ctx, &[spec], false, &mut transaction).await
+        {
+            UI::error(&format!("Failed to upgrade {}: {}", name, e));
+            let upgraded = transaction.changes().len();
+            undo_changes(registry, ctx, transaction.changes()).await;
+            transaction.finish(TransactionStatus::RolledBack);
+            return Err(anyhow::anyhow!(
+                "Upgrade of {} failed; rolled back {} upgraded tool(s)",
+                name,
+                upgraded
+            ));
+        }
+    }
+
+    UI::success(&format!("Upgraded {} tool(s)", transaction.changes().len()));
+    transaction.finish(TransactionStatus::Committed);
+    UI::hint("Use 'vx rollback <tool>' to return to the previous version");
+    Ok(())
+}
+
+fn failed_update(name: &str, constraint: &str, current: Option<&str>, error: &str) -> ToolUpdate {
+    ToolUpdate {
+        name: name.to_string(),
+        constraint: constraint.to_string(),
+        current: current.map(str::to_string),
+        target: None,
+        released: None,
+        lts: false,
+        latest: None,
+        notes: Vec::new(),
+        error: Some(error.to_string()),
+    }
+}
+
+fn print_report(updates: &[ToolUpdate]) {
+    let outdated: Vec<_> = updates.iter().filter(|u| u.is_outdated()).collect();
+    if outdated.is_empty() {
+        UI::success("All tools are up to date");
+    } else {
+        UI::header(&format!("{} update(s) available", outdated.len()));
+        for update in &outdated {
+            UI::item(&format!(
+                "{} {} → {} ({})",
+                update.name,
+                update.current.as_deref().unwrap_or("-"),
+                update.target.as_deref().unwrap_or("-"),
+                update.constraint
+            ));
+            print_notes(update);
+        }
+    }
+
+    for update in updates.iter().filter(|u| u.is_held_back()) {
+        UI::detail(&format!(
+            "{} {} is held back by '{}' (latest {})",
+            update.name,
+            update.target.as_deref().unwrap_or("-"),
+            update.constraint,
+            update.latest.as_deref().unwrap_or("-")
+        ));
+    }
+    for update in updates.iter().filter(|u| u.error.is_some()) {
+        UI::warn(&format!(
+            "{}: {}",
+            update.name,
+            update.error.as_deref().unwrap_or_default()
+        ));
+    }
+}
+
+fn print_notes(update: &ToolUpdate) {
+    for note in update.notes.iter().take(MAX_NOTES_PER_TOOL) {
+        let first_line = note.summary.as_deref().and_then(|s| s.lines().next());
+        match (&note.url, first_line) {
+            (Some(url), _) => UI::detail(&format!("  {} {}", note.version, url)),
+            (None, Some(line)) => UI::detail(&format!("  {} {}", note.version, line)),
+            (None, None) => {}
+        }
+    }
+}
+
+fn write_summary(path: &std::path::Path, summary: &str) -> Result<()> {
+    if path.as_os_str() == "-" {
+        println!("{}", summary);
+        return Ok(());
+    }
+    std::fs::write(path, summary)
+        .with_context(|| format!("Failed to write summary to {}", path.display()))?;
+    UI::success(&format!("Wrote update summary to {}", path.display()));
+    Ok(())
+}
+
+/// Whether `a` is a strictly newer version than `b`
+fn is_newer(a: &str, b: &str) -> bool {
+    match (Version::parse(a), Version::parse(b)) {
+        (Some(a), Some(b)) => a > b,
+        _ => false,
+    }
+}
@@ -0,0 +1,223 @@
+//! Wrap command implementation
+//!
+//! `vx wrap node npm python` writes small wrapper scripts (`tools/node`,
+//! `tools/node.cmd`, ...) meant to be checked into the project, like
+//! `gradlew`. Each wrapper runs the tool through vx; when vx is not on PATH
+//! it first installs the vx version that generated the wrapper into
+//! `$VX_HOME/wrapper/<version>/`, without touching PATH or shell profiles.
+//! Contributors without vx can then run `./tools/node` and get the version
+//! pinned in vx.toml.
+
+use crate::commands::common;
+use crate::commands::input_validation::validate_runtime_name;
+use crate::ui::UI;
+use anyhow::{Context, Result, bail};
+use std::path::{Path, PathBuf};
+use vx_runtime::ProviderRegistry;
+
+/// Marker identifying files written by `vx wrap`, which may be overwritten
+pub const WRAPPER_MARKER: &str = "Generated by `vx wrap`";
+
+pub(crate) const INSTALL_SH_URL: &str =
+    "https://raw.githubusercontent.com/loonghao/vx/main/install.sh";
+const INSTALL_PS1_URL: &str = "https://raw.githubusercontent.com/loonghao/vx/main/install.ps1";
+
+/// A wrapper file to write
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WrapperFile {
+    /// Path relative to the wrapper directory
+    pub name: String,
+    pub content: String,
+    /// Whether the file needs the executable bit
+    pub executable: bool,
+}
+
+/// The POSIX shell and Windows batch wrappers of `tool`
+///
+/// `vx_version` is the vx release installed when vx is missing.
+pub fn render_wrappers(tool: &str, vx_version: &str) -> Vec<WrapperFile> {
+    vec![
+        WrapperFile {
+            name: tool.to_string(),
+            content: render_sh(tool, vx_version),
+            executable: true,
+        },
+        WrapperFile {
+            name: format!("{}.cmd", tool),
+            content: render_cmd(tool, vx_version),
+            executable: false,
+        },
+    ]
+}
+
+/// Render the POSIX shell wrapper
+pub fn render_sh(tool: &str, vx_version: &str) -> String {
+    format!(
+        r#"#!/bin/sh
+# {marker} (vx {version}). Regenerate with: vx wrap {tool}
+#
+# Runs `{tool}` through vx, installing vx first when it is not on PATH.
+set -e
+
+VX_WRAPPER_VERSION="{version}"
+
+if command -v vx >/dev/null 2>&1; then
+    VX=vx
+else
+    VX_WRAPPER_DIR="${{VX_HOME:-$HOME/.vx}}/wrapper/$VX_WRAPPER_VERSION"
+    VX="$VX_WRAPPER_DIR/vx"
+    if [ ! -x "$VX" ]; then
+        echo "vx not found, installing vx $VX_WRAPPER_VERSION to $VX_WRAPPER_DIR" >&2
+        if command -v curl >/dev/null 2>&1; then
+            fetch="curl -fsSL"
+        else
+            fetch="wget -qO-"
+        fi
+        $fetch {install_sh} \
+            | VX_VERSION="$VX_WRAPPER_VERSION" VX_INSTALL_DIR="$VX_WRAPPER_DIR" VX_NO_MODIFY_PATH=1 bash >&2
+    fi
+fi
+
+exec "$VX" {tool} "$@"
+"#,
+        marker = WRAPPER_MARKER,
+        version = vx_version,
+        tool = tool,
+        install_sh = INSTALL_SH_URL,
+    )
+}
+
+/// Render the Windows batch wrapper (CRLF line endings)
+pub fn render_cmd(tool: &str, vx_version: &str) -> String {
+    let script = format!(
+        r#"@echo off
+rem {marker} (vx {version}). Regenerate with: vx wrap {tool}
+rem
+rem Runs `{tool}` through vx, installing vx first when it is not on PATH.
+setlocal
+
+set "VX_WRAPPER_VERSION={version}"
+set "VX=vx"
+where vx >nul 2>nul && goto run
+
+set "VX_WRAPPER_HOME=%VX_HOME%"
+if not defined VX_WRAPPER_HOME set "VX_WRAPPER_HOME=%USERPROFILE%\.vx"
+set "VX_WRAPPER_DIR=%VX_WRAPPER_HOME%\wrapper\%VX_WRAPPER_VERSION%"
+set "VX=%VX_WRAPPER_DIR%\vx.exe"
+if exist "%VX%" goto run
+
+echo vx not found, installing vx %VX_WRAPPER_VERSION% to %VX_WRAPPER_DIR% 1>&2
+set "VX_VERSION=%VX_WRAPPER_VERSION%"
+set "VX_INSTALL_DIR=%VX_WRAPPER_DIR%"
+set "VX_NO_MODIFY_PATH=1"
+powershell -NoProfile -ExecutionPolicy Bypass -Command "irm {install_ps1} | iex" 1>&2
+if not exist "%VX%" exit /b 1
+
+:run
+"%VX%" {tool} %*
+exit /b %ERRORLEVEL%
+"#,
+        marker = WRAPPER_MARKER,
+        version = vx_version,
+        tool = tool,
+        install_ps1 = INSTALL_PS1_URL,
+    );
+    script.replace('\n', "\r\n")
+}
+
+/// Write the wrappers of `tools` into `dir`
+///
+/// Existing files are only replaced when they were generated by `vx wrap`,
+/// or with `force`. Returns the written paths.
+pub fn write_wrappers(
+    dir: &Path,
+    tools: &[String],
+    vx_version: &str,
+    force: bool,
+) -> Result<Vec<PathBuf>> {
+    let files: Vec<WrapperFile> = tools
+        .iter()
+        .flat_map(|tool| render_wrappers(tool, vx_version))
+        .collect();
+
+    for file in &files {
+        let path = dir.join(&file.name);
+        if !force && path.exists() && !is_generated(&path) {
+            bail!(
+                "{} exists and was not generated by `vx wrap` (use --force to overwrite)",
+                path.display()
+            );
+        }
+    }
+
+    std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let mut written = Vec::new();
+    for file in files {
+        let path = dir.join(&file.name);
+        std::fs::write(&path, &file.content)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        #[cfg(unix)]
+        if file.executable {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))?;
+        }
+        written.push(path);
+    }
+    Ok(written)
+}
+
+fn is_generated(path: &Path) -> bool {
+    std::fs::read_to_string(path).is_ok_and(|content| content.contains(WRAPPER_MARKER))
+}
+
+/// Handle `vx wrap`
+///
+/// Without tools, every tool in vx.toml is wrapped. `dir` is relative to the
+/// project root (the directory of vx.toml), or the current directory outside
+/// a project.
+pub async fn handle(
+    registry: &ProviderRegistry,
+    tools: &[String],
+    dir: &Path,
+    force: bool,
+) -> Result<()> {
+    let cwd = std::env::current_dir().context("Failed to get current directory")?;
+    let project_root = vx_paths::project::find_project_root(&cwd);
+
+    let tools: Vec<String> = if tools.is_empty() {
+        if project_root.is_none() {
+            bail!("No tools given and no vx.toml found; run `vx wrap <tool>...`");
+        }
+        let (_, config) = common::load_config_view_cwd()?;
+        config.tools_as_btreemap().into_keys().collect()
+    } else {
+        tools.to_vec()
+    };
+    if tools.is_empty() {
+        bail!("vx.toml has no tools to wrap");
+    }
+    for tool in &tools {
+        validate_runtime_name(tool)?;
+        if tool.contains(':') || tool.contains('@') {
+            bail!(
+                "Cannot wrap '{}': pin versions in vx.toml and wrap the tool name",
+                tool
+            );
+        }
+        if !registry.supports(tool) {
+            bail!("Unknown tool '{}'", tool);
+        }
+    }
+
+    let dir = project_root.unwrap_or(cwd).join(dir);
+    let written = write_wrappers(&dir, &tools, env!("CARGO_PKG_VERSION"), force)?;
+    for path in &written {
+        UI::detail(&path.display().to_string());
+    }
+    UI::success(&format!(
+        "Wrapped {} tool(s) in {}; commit the directory so contributors can run them without vx",
+        tools.len(),
+        dir.display()
+    ));
+    Ok(())
+}
@@ -0,0 +1,92 @@
+//! Network egress policy for scripts and tool runs (`[security.egress]`)
+//!
+//! When the project's `vx.toml` enables the policy, vx starts a local
+//! [`EgressProxy`] for the duration of the command and hands its proxy
+//! variables to the child process. Blocked destinations are logged to
+//! `~/.vx/state/egress.log` and summarized when the command finishes.
+
+use crate::ui::UI;
+use anyhow::{Context, Result};
+use vx_config::EgressConfig;
+use vx_paths::VxPaths;
+use vx_proxy::{EgressPolicy, EgressProxy, ProxyConfig};
+
+/// An active egress policy; reports blocked destinations when dropped
+#[derive(Debug)]
+pub struct EgressSession {
+    proxy: EgressProxy,
+}
+
+impl EgressSession {
+    /// Start the policy from `config`, if it is enabled
+    ///
+    /// An `http://` upstream proxy from the user's proxy settings is chained
+    /// so corporate proxies keep working.
+    pub fn start(config: Option<&EgressConfig>) -> Result<Option<Self>> {
+        let Some(config) = config.filter(|c| c.is_enabled()) else {
+            return Ok(None);
+        };
+
+        let mut policy = EgressPolicy::new(config.allow.iter().cloned());
+        if let Ok(paths) = VxPaths::new() {
+            policy = policy.with_log_file(paths.state_dir.join("egress.log"));
+        }
+        let upstream = ProxyConfig::load();
+        match upstream.https.as_deref().or(upstream.all.as_deref()) {
+            Some(url) if url.starts_with("http://") && upstream.username.is_none() => {
+                policy = policy.with_upstream(url);
+            }
+            Some(url) => UI::warn(&format!(
+                "Egress policy connects directly; upstream proxy {} is not supported",
+                url
+            )),
+            None => {}
+        }
+
+        let proxy = EgressProxy::start(policy).context("Failed to start the egress proxy")?;
+        UI::debug(&format!(
+            "Network egress restricted to [{}] via {}",
+            config.allow.join(", "),
+            proxy.url()
+        ));
+        for hint in proxy.firewall_hints() {
+            UI::debug(&hint);
+        }
+        Ok(Some(Self { proxy }))
+    }
+
+    /// Start the policy of the project in the current directory, if any
+    pub fn start_for_cwd() -> Result<Option<Self>> {
+        let Ok((_, config)) = crate::commands::common::load_full_config_cwd() else {
+            return Ok(None);
+        };
+        Self::start(config.security.as_ref().and_then(|s| s.egress.as_ref()))
+    }
+
+    /// Proxy variables for child processes
+    pub fn env_vars(&self) -> Vec<(String, String)> {
+        self.proxy.env_vars()
+    }
+
+    /// Warn about destinations blocked so far
+    pub fn report(&self) {
+        let blocked = self.proxy.blocked();
+        if blocked.is_empty() {
+            return;
+        }
+        UI::warn(&format!(
+            "Egress policy blocked {} destination(s):",
+            blocked.len()
+        ));
+        for (destination, count) in &blocked {
+            UI::detail(&format!("{} ({}x)", destination, count));
+        }
+        UI::hint("Allow them in [security.egress] allow = [...] in vx.toml if they are expected");
+    }
+}
+
+impl Drop for EgressSession {
+    fn drop(&mut self) {
+        self.report();
+    }
+}
@@ -0,0 +1,189 @@
+//! End-of-life warnings for project-pinned runtime versions
+//!
+//! Providers declare when their version lines stop receiving fixes with a
+//! top-level `eol` dict in `provider.star`:
+//!
+//! ```python
+//! eol = {"18": "2025-04-30", "20": "2026-04-30"}
+//! ```
+//!
+//! When `vx.lock` (or, without a lock entry, `vx.toml`) pins a version past
+//! its date, `vx list`, `vx sync` and running the tool warn about it, at most
+//! once per day for each tool version. `vx outdated --eol` lists every such
+//! pin.
+
+use crate::commands::common::project_tool_versions;
+use chrono::NaiveDate;
+use std::path::Path;
+use vx_paths::VxPaths;
+
+/// Records when each EOL pin was last warned about (`<tool>@<version> <epoch secs>`)
+const WARNINGS_FILE: &str = "eol-warnings";
+
+/// Minimum time between two warnings about the same pin
+const WARN_INTERVAL_SECS: u64 = 24 * 60 * 60;
+
+/// End-of-life dates of a runtime's version lines
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EolSchedule {
+    lines: Vec<(String, NaiveDate)>,
+}
+
+impl EolSchedule {
+    /// Build from `(version prefix, YYYY-MM-DD)` pairs; invalid dates are skipped
+    pub fn from_pairs(pairs: &[(String, String)]) -> Self {
+        let lines = pairs
+            .iter()
+            .filter_map(
+                |(line, date)| match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+                    Ok(date) => Some((line.clone(), date)),
+                    Err(_) => {
+                        tracing::debug!(line = %line, date = %date, "Ignoring invalid EOL date");
+                        None
+                    }
+                },
+            )
+            .collect();
+        Self { lines }
+    }
+
+    /// Whether no version line has a date
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// End-of-life date of the line `version` belongs to
+    ///
+    /// The most specific line wins, so `3.8.10` matches `3.8` rather than `3`.
+    /// Leading `v`, `^`, `~` and `=` are ignored; ranges and channels such as
+    /// `latest` match nothing.
+    pub fn eol_date(&self, version: &str) -> Option<NaiveDate> {
+        let version = version.trim().trim_start_matches(['v', '^', '~', '=']);
+        self.lines
+            .iter()
+            .filter(|(line, _)| {
+                version == line
+                    || version
+                        .strip_prefix(line.as_str())
+                        .is_some_and(|rest| rest.starts_with('.'))
+            })
+            .max_by_key(|(line, _)| line.split('.').count())
+            .map(|(_, date)| *date)
+    }
+}
+
+/// A project tool pinned to a version past its end-of-life date
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EolPin {
+    /// Tool name as written in vx.toml
+    pub tool: String,
+    /// Locked version, or the vx.toml version when the tool is not locked
+    pub version: String,
+    /// When the version line reached end-of-life
+    pub eol: NaiveDate,
+}
+
+impl EolPin {
+    /// One-line warning for this pin
+    pub fn message(&self) -> String {
+        format!(
+            "{} {} reached end-of-life on {}; consider upgrading to a supported version",
+            self.tool, self.version, self.eol
+        )
+    }
+
+    fn key(&self) -> String {
+        format!("{}@{}", self.tool, self.version)
+    }
+}
+
+/// Pins among `pins` (`(tool, version)`) that are end-of-life on `today`
+pub fn find_eol_pins(
+    pins: impl IntoIterator<Item = (String, String)>,
+    today: NaiveDate,
+    schedule_of: impl Fn(&str) -> Option<EolSchedule>,
+) -> Vec<EolPin> {
+    let mut found: Vec<EolPin> = pins
+        .into_iter()
+        .filter_map(|(tool, version)| {
+            let eol = schedule_of(&tool)?.eol_date(&version)?;
+            (eol <= today).then_some(EolPin { tool, version, eol })
+        })
+        .collect();
+    found.sort_by(|a, b| a.tool.cmp(&b.tool));
+    found
+}
+
+/// End-of-life pins of the project in the current directory
+///
+/// `tool` limits the check to one tool. Returns nothing outside a project.
+pub fn project_eol_pins(tool: Option<&str>) -> Vec<EolPin> {
+    let pins = project_tool_versions()
+        .into_iter()
+        .filter(|(name, _)| tool.is_none_or(|tool| tool == name));
+    find_eol_pins(
+        pins,
+        chrono::Local::now().date_naive(),
+        crate::registry::find_eol_schedule,
+    )
+}
+
+/// Pins from `pins` not warned about in the last day, recorded as warned now
+///
+/// `state_file` keeps the last warning time per pin; failing to read or
+/// write it only means warning again.
+pub fn take_due_warnings(state_file: &Path, pins: Vec<EolPin>, now: u64) -> Vec<EolPin> {
+    if pins.is_empty() {
+        return pins;
+    }
+    let mut warned: Vec<(String, u64)> = std::fs::read_to_string(state_file)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| {
+            let (key, secs) = line.rsplit_once(' ')?;
+            Some((key.to_string(), secs.parse().ok()?))
+        })
+        .collect();
+
+    let due: Vec<EolPin> = pins
+        .into_iter()
+        .filter(|pin| {
+            let key = pin.key();
+            !warned
+                .iter()
+                .any(|(k, secs)| *k == key && now.saturating_sub(*secs) < WARN_INTERVAL_SECS)
+        })
+        .collect();
+    if due.is_empty() {
+        return due;
+    }
+
+    for pin in &due {
+        let key = pin.key();
+        warned.retain(|(k, _)| *k != key);
+        warned.push((key, now));
+    }
+    let content: String = warned
+        .iter()
+        .map(|(key, secs)| format!("{} {}\n", key, secs))
+        .collect();
+    let _ = vx_cache::atomic_write_string(state_file, &content);
+    due
+}
+
+/// Warn about the project's end-of-life pins that are due today
+///
+/// `tool` limits the check to one tool; `emit` prints each message.
+pub fn warn_project_pins(tool: Option<&str>, emit: impl Fn(&str)) {
+    let pins = project_eol_pins(tool);
+    if pins.is_empty() {
+        return;
+    }
+    let Ok(paths) = VxPaths::new() else {
+        return;
+    };
+    let state_file = paths.state_dir.join(WARNINGS_FILE);
+    for pin in take_due_warnings(&state_file, pins, vx_cache::now_epoch_secs()) {
+        emit(&pin.message());
+    }
+}
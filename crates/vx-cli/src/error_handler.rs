@@ -8,50 +8,154 @@
 //! ```text
 //! ✗ error[resolve]: runtime not found: xyz
 //!
-//!   💡 Use 'vx list' to see all supported runtimes
+//!   💡 See all supported runtimes: vx list
 //! ```
+//!
+//! With `--format json` the same error is printed to stdout as an
+//! [`ErrorReport`], with the fixes as structured [`Suggestion`]s.
 
+use crate::cli::OutputFormat;
+use crate::suggestions::{self, Suggestion};
 use colored::*;
 use core::hint::cold_path;
+use serde::Serialize;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
 use vx_resolver::{EnsureError, ExecuteError, PipelineError, PrepareError, ResolveError};
 
+/// Output format chosen on the command line, set once in `main`
+static OUTPUT_FORMAT: OnceLock<OutputFormat> = OnceLock::new();
+
+/// Whether an error report was already printed in JSON mode
+static REPORTED: AtomicBool = AtomicBool::new(false);
+
+/// Record the output format errors should be reported in
+pub fn set_output_format(format: OutputFormat) {
+    let _ = OUTPUT_FORMAT.set(format);
+}
+
+/// Whether errors are reported as JSON
+pub fn is_json() -> bool {
+    OUTPUT_FORMAT.get() == Some(&OutputFormat::Json)
+}
+
+/// Machine-readable description of a failed command
+///
+/// Printed as `{"error": {...}}` on stdout in JSON mode.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorReport {
+    /// Pipeline stage or area that failed (`resolve`, `install`, ...)
+    pub category: String,
+    /// The error message
+    pub message: String,
+    /// Underlying causes, outermost first
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub causes: Vec<String>,
+    /// Actionable fixes
+    pub suggestions: Vec<Suggestion>,
+}
+
+impl ErrorReport {
+    /// Create a report without causes
+    pub fn new(
+        category: impl Into<String>,
+        message: impl Into<String>,
+        suggestions: Vec<Suggestion>,
+    ) -> Self {
+        Self {
+            category: category.into(),
+            message: message.into(),
+            causes: Vec::new(),
+            suggestions,
+        }
+    }
+
+    /// Build a report for a pipeline error
+    pub fn from_pipeline(err: &PipelineError) -> Self {
+        // The stage is the category; keep it out of the message
+        let message = match err {
+            PipelineError::Resolve(e) => e.to_string(),
+            PipelineError::Ensure(e) => e.to_string(),
+            PipelineError::Prepare(e) => e.to_string(),
+            PipelineError::Execute(e) => e.to_string(),
+            _ => err.to_string(),
+        };
+        Self::new(pipeline_category(err), message, pipeline_suggestions(err))
+    }
+
+    /// Build a report for any error, with suggestions for pipeline errors
+    pub fn from_error(err: &anyhow::Error) -> Self {
+        if let Some(e) = err.downcast_ref::<PipelineError>() {
+            return Self::from_pipeline(e);
+        }
+        if let Some(e) = err.downcast_ref::<ResolveError>() {
+            return Self::new("resolve", e.to_string(), resolve_suggestions(e));
+        }
+        if let Some(e) = err.downcast_ref::<EnsureError>() {
+            return Self::new("install", e.to_string(), ensure_suggestions(e));
+        }
+        if let Some(e) = err.downcast_ref::<PrepareError>() {
+            return Self::new("prepare", e.to_string(), prepare_suggestions(e));
+        }
+        if let Some(e) = err.downcast_ref::<ExecuteError>() {
+            return Self::new("execute", e.to_string(), execute_suggestions(e));
+        }
+
+        Self {
+            category: "error".to_string(),
+            message: err.to_string(),
+            causes: err.chain().skip(1).map(|c| c.to_string()).collect(),
+            suggestions: Vec::new(),
+        }
+    }
+
+    /// Print the report as JSON on stdout
+    ///
+    /// Only the first report of a process is printed, so a handler that
+    /// already reported an error (e.g. an unknown tool) is not followed by a
+    /// second, less specific report when the error reaches `main`.
+    pub fn emit(&self) {
+        if REPORTED.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let payload = serde_json::json!({ "error": self });
+        let json = if crate::output::stdout_is_tty() {
+            serde_json::to_string_pretty(&payload)
+        } else {
+            serde_json::to_string(&payload)
+        };
+        if let Ok(json) = json {
+            println!("{json}");
+        }
+    }
+}
+
 /// Format and display a pipeline error with structured output.
 ///
 /// Returns the appropriate exit code for the error type.
 pub fn handle_pipeline_error(err: &PipelineError) -> i32 {
     cold_path();
+    if is_json() {
+        ErrorReport::from_pipeline(err).emit();
+        return 1;
+    }
+
+    print_error_header(pipeline_category(err));
     match err {
-        PipelineError::Resolve(e) => {
-            print_error_header("resolve");
-            format_resolve_error(e);
-        }
-        PipelineError::Ensure(e) => {
-            print_error_header("install");
-            format_ensure_error(e);
-        }
-        PipelineError::Prepare(e) => {
-            print_error_header("prepare");
-            format_prepare_error(e);
-        }
-        PipelineError::Execute(e) => {
-            print_error_header("execute");
-            format_execute_error(e);
-        }
+        PipelineError::Resolve(e) => format_resolve_error(e),
+        PipelineError::Ensure(e) => format_ensure_error(e),
+        PipelineError::Prepare(e) => format_prepare_error(e),
+        PipelineError::Execute(e) => format_execute_error(e),
         PipelineError::PlatformUnsupported { reasons } => {
-            print_error_header("platform");
             eprintln!("  {}", "Platform not supported for this runtime:".red());
             for reason in reasons {
                 eprintln!("    {} {}", "•".dimmed(), reason);
             }
-            eprintln!();
-            print_hint("This runtime is not available for your current platform.");
         }
         PipelineError::IncompatibleDependencies { details } => {
-            print_error_header("dependencies");
             eprintln!("  {}", details.red());
         }
         PipelineError::PlatformCheckFailed { runtime, reason } => {
-            print_error_header("platform");
             eprintln!(
                 "  Platform check failed for {}: {}",
                 runtime.cyan().bold(),
@@ -59,13 +163,10 @@ pub fn handle_pipeline_error(err: &PipelineError) -> i32 {
             );
         }
         PipelineError::Offline(msg) => {
-            print_error_header("network");
             eprintln!("  {}", msg);
-            eprintln!();
-            print_hint("Check your internet connection and try again.");
-            print_hint("Use 'vx --offline' to work with locally installed runtimes only.");
         }
     }
+    print_suggestions(&pipeline_suggestions(err));
 
     1
 }
@@ -83,44 +184,202 @@ pub fn try_handle_error(err: &anyhow::Error) -> bool {
 
     // Also try the individual error types (in case they weren't wrapped in PipelineError)
     if let Some(e) = err.downcast_ref::<ResolveError>() {
-        print_error_header("resolve");
-        format_resolve_error(e);
-        std::process::exit(1);
+        report_stage_error(
+            err,
+            "resolve",
+            || format_resolve_error(e),
+            resolve_suggestions(e),
+        );
+    } else if let Some(e) = err.downcast_ref::<EnsureError>() {
+        report_stage_error(
+            err,
+            "install",
+            || format_ensure_error(e),
+            ensure_suggestions(e),
+        );
+    } else if let Some(e) = err.downcast_ref::<PrepareError>() {
+        report_stage_error(
+            err,
+            "prepare",
+            || format_prepare_error(e),
+            prepare_suggestions(e),
+        );
+    } else if let Some(e) = err.downcast_ref::<ExecuteError>() {
+        report_stage_error(
+            err,
+            "execute",
+            || format_execute_error(e),
+            execute_suggestions(e),
+        );
+    } else {
+        return false;
     }
-    if let Some(e) = err.downcast_ref::<EnsureError>() {
-        print_error_header("install");
-        format_ensure_error(e);
-        std::process::exit(1);
+    std::process::exit(1);
+}
+
+fn report_stage_error(
+    err: &anyhow::Error,
+    category: &str,
+    print_text: impl FnOnce(),
+    suggestions: Vec<Suggestion>,
+) {
+    if is_json() {
+        ErrorReport::from_error(err).emit();
+    } else {
+        print_error_header(category);
+        print_text();
+        print_suggestions(&suggestions);
     }
-    if let Some(e) = err.downcast_ref::<PrepareError>() {
-        print_error_header("prepare");
-        format_prepare_error(e);
-        std::process::exit(1);
+}
+
+/// Category shown in `error[...]` for a pipeline error
+fn pipeline_category(err: &PipelineError) -> &'static str {
+    match err {
+        PipelineError::Resolve(_) => "resolve",
+        PipelineError::Ensure(_) => "install",
+        PipelineError::Prepare(_) => "prepare",
+        PipelineError::Execute(_) => "execute",
+        PipelineError::PlatformUnsupported { .. } | PipelineError::PlatformCheckFailed { .. } => {
+            "platform"
+        }
+        PipelineError::IncompatibleDependencies { .. } => "dependencies",
+        PipelineError::Offline(_) => "network",
     }
-    if let Some(e) = err.downcast_ref::<ExecuteError>() {
-        print_error_header("execute");
-        format_execute_error(e);
-        std::process::exit(1);
+}
+
+/// Suggested fixes for a pipeline error
+pub fn pipeline_suggestions(err: &PipelineError) -> Vec<Suggestion> {
+    match err {
+        PipelineError::Resolve(e) => resolve_suggestions(e),
+        PipelineError::Ensure(e) => ensure_suggestions(e),
+        PipelineError::Prepare(e) => prepare_suggestions(e),
+        PipelineError::Execute(e) => execute_suggestions(e),
+        PipelineError::PlatformUnsupported { .. } => vec![Suggestion::hint(
+            "This runtime is not available for your current platform.",
+        )],
+        PipelineError::IncompatibleDependencies { .. }
+        | PipelineError::PlatformCheckFailed { .. } => Vec::new(),
+        PipelineError::Offline(_) => vec![
+            Suggestion::hint("Check your internet connection and try again."),
+            Suggestion::hint("Use 'vx --offline' to work with locally installed runtimes only."),
+        ],
     }
+}
 
-    false
+fn resolve_suggestions(err: &ResolveError) -> Vec<Suggestion> {
+    match err {
+        ResolveError::RuntimeNotFound { name } => vec![
+            Suggestion::command("vx list", "See all supported runtimes"),
+            Suggestion::command(
+                format!("vx list | grep {}", name),
+                format!("Search for '{}'", name),
+            ),
+        ],
+        ResolveError::VersionNotFound { runtime, .. } => vec![Suggestion::command(
+            format!("vx list {}", runtime),
+            "See available versions",
+        )],
+        ResolveError::NoLockedVersion { runtime } => vec![
+            Suggestion::run(format!("vx lock {}", runtime)),
+            Suggestion::hint("Or specify a version explicitly: vx <runtime>@<version>"),
+        ],
+        ResolveError::DependencyCycle { .. } => vec![Suggestion::hint(
+            "Check your project configuration for circular dependencies.",
+        )],
+        ResolveError::IncompatibleDependencies { .. } => vec![Suggestion::hint(
+            "Adjust requested runtime versions to satisfy dependency constraints.",
+        )],
+        ResolveError::PlatformNotSupported { .. }
+        | ResolveError::ResolutionFailed { .. }
+        | ResolveError::UnknownWithDependency { .. }
+        | ResolveError::Other(_) => Vec::new(),
+    }
+}
+
+fn ensure_suggestions(err: &EnsureError) -> Vec<Suggestion> {
+    match err {
+        EnsureError::InstallFailed {
+            runtime, version, ..
+        } => vec![Suggestion::run(format!(
+            "vx install {}@{}",
+            runtime, version
+        ))],
+        EnsureError::DependencyInstallFailed { dep, .. } => {
+            vec![Suggestion::run(format!("vx install {}", dep))]
+        }
+        EnsureError::DownloadFailed { .. } => vec![Suggestion::hint(
+            "Check your internet connection and try again.",
+        )],
+        EnsureError::AutoInstallDisabled { runtime, version } => vec![
+            Suggestion::run(format!("vx install {}@{}", runtime, version)),
+            auto_install_suggestion("Or enable auto-install in your configuration"),
+            Suggestion::docs(
+                suggestions::docs_url("guide/configuration.md"),
+                "Configuration reference",
+            ),
+        ],
+        EnsureError::Timeout { .. } => vec![Suggestion::hint(
+            "Try again with a better network connection.",
+        )],
+        EnsureError::PostInstallVerificationFailed { runtime, .. } => vec![
+            Suggestion::hint("The installation may be corrupted. Try reinstalling:"),
+            Suggestion::run(format!("vx install {} --force", runtime)),
+        ],
+        EnsureError::NoVersionsFound { .. } => vec![Suggestion::hint(
+            "Check your internet connection or try again later.",
+        )],
+        EnsureError::PlatformNotSupported { .. }
+        | EnsureError::CommandFailed { .. }
+        | EnsureError::NotInstalled { .. }
+        | EnsureError::Other(_) => Vec::new(),
+    }
+}
+
+fn prepare_suggestions(err: &PrepareError) -> Vec<Suggestion> {
+    match err {
+        PrepareError::UnknownRuntime { .. } => {
+            vec![Suggestion::command("vx list", "See all supported runtimes")]
+        }
+        PrepareError::NoExecutable { runtime } => {
+            vec![Suggestion::run(format!("vx install {} --force", runtime))]
+        }
+        PrepareError::ProxyNotAvailable { proxy, .. } => {
+            vec![Suggestion::run(format!("vx install {}", proxy))]
+        }
+        PrepareError::DependencyRequired { dependency, .. } => vec![
+            Suggestion::run(format!("vx install {}", dependency)),
+            auto_install_suggestion("Or enable auto-install to install dependencies automatically"),
+        ],
+        PrepareError::ExecutableNotFound { .. }
+        | PrepareError::EnvironmentFailed { .. }
+        | PrepareError::ProxyRetryFailed { .. }
+        | PrepareError::Other(_) => Vec::new(),
+    }
 }
 
-/// Format a ResolveError with context and suggestions
+fn execute_suggestions(err: &ExecuteError) -> Vec<Suggestion> {
+    match err {
+        ExecuteError::SpawnFailed { .. } => vec![Suggestion::hint(
+            "Check that the file exists and has execute permissions.",
+        )],
+        ExecuteError::Timeout { .. } => {
+            vec![Suggestion::hint("The process took too long to complete.")]
+        }
+        ExecuteError::Killed
+        | ExecuteError::BundleExecutionFailed { .. }
+        | ExecuteError::Other(_) => Vec::new(),
+    }
+}
+
+fn auto_install_suggestion(description: &str) -> Suggestion {
+    Suggestion::config("defaults.auto_install", "true", description)
+}
+
+/// Format a ResolveError with context
 fn format_resolve_error(err: &ResolveError) {
     match err {
         ResolveError::RuntimeNotFound { name } => {
             eprintln!("  Runtime '{}' not found", name.cyan().bold());
-            eprintln!();
-            print_hint(&format!(
-                "Use '{}' to see all supported runtimes",
-                "vx list".cyan()
-            ));
-            print_hint(&format!(
-                "Use '{}' to search for '{}'",
-                format!("vx list | grep {}", name).cyan(),
-                name
-            ));
         }
         ResolveError::VersionNotFound { runtime, version } => {
             eprintln!(
@@ -128,24 +387,14 @@ fn format_resolve_error(err: &ResolveError) {
                 version.yellow(),
                 runtime.cyan().bold()
             );
-            eprintln!();
-            print_hint(&format!(
-                "Use '{}' to see available versions",
-                format!("vx list {}", runtime).cyan()
-            ));
         }
         ResolveError::NoLockedVersion { runtime } => {
             eprintln!("  No locked version for '{}'", runtime.cyan().bold());
-            eprintln!();
-            print_fix(&format!("vx lock {}", runtime));
-            print_hint("Or specify a version explicitly: vx <runtime>@<version>");
         }
         ResolveError::DependencyCycle { cycle } => {
             eprintln!("  Dependency cycle detected:");
             eprintln!();
             eprintln!("    {}", cycle.join(" → ").yellow());
-            eprintln!();
-            print_hint("Check your project configuration for circular dependencies.");
         }
         ResolveError::PlatformNotSupported {
             runtime,
@@ -176,8 +425,6 @@ fn format_resolve_error(err: &ResolveError) {
         }
         ResolveError::IncompatibleDependencies { details } => {
             eprintln!("  {}", details.red());
-            eprintln!();
-            print_hint("Adjust requested runtime versions to satisfy dependency constraints.");
         }
         ResolveError::Other(e) => {
             eprintln!("  {}", e);
@@ -185,7 +432,7 @@ fn format_resolve_error(err: &ResolveError) {
     }
 }
 
-/// Format an EnsureError with context and suggestions
+/// Format an EnsureError with context
 fn format_ensure_error(err: &EnsureError) {
     match err {
         EnsureError::InstallFailed {
@@ -199,8 +446,6 @@ fn format_ensure_error(err: &EnsureError) {
                 version.yellow()
             );
             eprintln!("  {}", reason.dimmed());
-            eprintln!();
-            print_fix(&format!("vx install {}@{}", runtime, version));
         }
         EnsureError::DependencyInstallFailed {
             runtime,
@@ -213,8 +458,6 @@ fn format_ensure_error(err: &EnsureError) {
                 runtime.cyan()
             );
             eprintln!("  {}", reason.dimmed());
-            eprintln!();
-            print_fix(&format!("vx install {}", dep));
         }
         EnsureError::DownloadFailed {
             runtime,
@@ -229,8 +472,6 @@ fn format_ensure_error(err: &EnsureError) {
             );
             eprintln!("  URL: {}", url.dimmed());
             eprintln!("  {}", reason.dimmed());
-            eprintln!();
-            print_hint("Check your internet connection and try again.");
         }
         EnsureError::AutoInstallDisabled { runtime, version } => {
             eprintln!(
@@ -238,9 +479,6 @@ fn format_ensure_error(err: &EnsureError) {
                 runtime.cyan().bold(),
                 version.yellow()
             );
-            eprintln!();
-            print_fix(&format!("vx install {}@{}", runtime, version));
-            print_hint("Or enable auto-install in your configuration.");
         }
         EnsureError::Timeout {
             runtime,
@@ -253,8 +491,6 @@ fn format_ensure_error(err: &EnsureError) {
                 version.yellow(),
                 seconds
             );
-            eprintln!();
-            print_hint("Try again with a better network connection.");
         }
         EnsureError::PlatformNotSupported { runtime, reason } => {
             eprintln!(
@@ -269,14 +505,9 @@ fn format_ensure_error(err: &EnsureError) {
                 runtime.cyan().bold()
             );
             eprintln!("  Expected at: {}", path.display().to_string().dimmed());
-            eprintln!();
-            print_hint("The installation may be corrupted. Try reinstalling:");
-            print_fix(&format!("vx install {} --force", runtime));
         }
         EnsureError::NoVersionsFound { runtime } => {
             eprintln!("  No versions found for {}", runtime.cyan().bold());
-            eprintln!();
-            print_hint("Check your internet connection or try again later.");
         }
         EnsureError::CommandFailed { exit_code } => {
             if let Some(code) = exit_code {
@@ -300,7 +531,7 @@ fn format_ensure_error(err: &EnsureError) {
     }
 }
 
-/// Format a PrepareError with context and suggestions
+/// Format a PrepareError with context
 fn format_prepare_error(err: &PrepareError) {
     match err {
         PrepareError::UnknownRuntime { runtime } => {
@@ -308,19 +539,12 @@ fn format_prepare_error(err: &PrepareError) {
                 "  Unknown runtime '{}', cannot auto-install",
                 runtime.cyan().bold()
             );
-            eprintln!();
-            print_hint(&format!(
-                "Use '{}' to see all supported runtimes",
-                "vx list".cyan()
-            ));
         }
         PrepareError::NoExecutable { runtime } => {
             eprintln!(
                 "  No executable found for {} after installation",
                 runtime.cyan().bold()
             );
-            eprintln!();
-            print_fix(&format!("vx install {} --force", runtime));
         }
         PrepareError::ExecutableNotFound { path } => {
             eprintln!(
@@ -346,8 +570,6 @@ fn format_prepare_error(err: &PrepareError) {
                 runtime.cyan()
             );
             eprintln!("  {}", reason.dimmed());
-            eprintln!();
-            print_fix(&format!("vx install {}", proxy));
         }
         PrepareError::DependencyRequired {
             runtime,
@@ -359,9 +581,6 @@ fn format_prepare_error(err: &PrepareError) {
                 runtime.cyan().bold(),
                 dependency.cyan().bold()
             );
-            eprintln!();
-            print_fix(&format!("vx install {}", dependency));
-            print_hint("Or enable auto-install to install dependencies automatically.");
         }
         PrepareError::ProxyRetryFailed {
             runtime,
@@ -381,7 +600,7 @@ fn format_prepare_error(err: &PrepareError) {
     }
 }
 
-/// Format an ExecuteError with context and suggestions
+/// Format an ExecuteError with context
 fn format_execute_error(err: &ExecuteError) {
     match err {
         ExecuteError::SpawnFailed { executable, reason } => {
@@ -390,16 +609,12 @@ fn format_execute_error(err: &ExecuteError) {
                 executable.display().to_string().yellow()
             );
             eprintln!("  {}", reason.dimmed());
-            eprintln!();
-            print_hint("Check that the file exists and has execute permissions.");
         }
         ExecuteError::Timeout { seconds } => {
             eprintln!(
                 "  Execution timed out after {}s",
                 seconds.to_string().yellow()
             );
-            eprintln!();
-            print_hint("The process took too long to complete.");
         }
         ExecuteError::Killed => {
             eprintln!("  Process was killed by signal");
@@ -423,18 +638,52 @@ fn print_error_header(category: &str) {
     );
 }
 
-/// Print a hint line
-fn print_hint(msg: &str) {
-    eprintln!("  {} {}", "💡".cyan(), msg.dimmed());
+/// Print suggestions as hint lines, after a blank line
+pub fn print_suggestions(suggestions: &[Suggestion]) {
+    if suggestions.is_empty() {
+        return;
+    }
+    eprintln!();
+    for suggestion in suggestions {
+        print_suggestion(suggestion);
+    }
 }
 
-/// Print a fix suggestion line
-fn print_fix(cmd: &str) {
-    eprintln!("  {} To fix, run: {}", "💡".cyan(), cmd.cyan().bold());
+fn print_suggestion(suggestion: &Suggestion) {
+    let icon = "💡".cyan();
+    match suggestion {
+        Suggestion::Command {
+            command,
+            description: Some(description),
+        } => eprintln!("  {} {}: {}", icon, description.dimmed(), command.cyan()),
+        Suggestion::Command {
+            command,
+            description: None,
+        } => eprintln!("  {} To fix, run: {}", icon, command.cyan().bold()),
+        Suggestion::Config {
+            key,
+            value,
+            description,
+        } => eprintln!(
+            "  {} {}: {}",
+            icon,
+            description.dimmed(),
+            format!("vx config set {} {}", key, value).cyan()
+        ),
+        Suggestion::Docs { url, description } => {
+            eprintln!("  {} {}: {}", icon, description, url.dimmed())
+        }
+        Suggestion::Hint { message } => eprintln!("  {} {}", icon, message.dimmed()),
+    }
 }
 
 /// Format a generic anyhow error with basic styling
 pub fn format_generic_error(err: &anyhow::Error) {
+    if is_json() {
+        ErrorReport::from_error(err).emit();
+        return;
+    }
+
     eprintln!("\n{} {}", "✗".red().bold(), "error".red().bold());
     eprintln!("  {}", err);
 
@@ -448,3 +697,20 @@ pub fn format_generic_error(err: &anyhow::Error) {
         }
     }
 }
+
+/// Report an unknown tool, with similar tools and a link to request support
+///
+/// Prints an [`ErrorReport`] in JSON mode; text output is left to the caller.
+/// Returns `true` when the report was printed.
+pub fn report_tool_not_found(tool_name: &str, available_tools: &[String]) -> bool {
+    if !is_json() {
+        return false;
+    }
+    ErrorReport::new(
+        "not_found",
+        format!("Tool '{}' is not supported by vx", tool_name),
+        suggestions::tool_not_found_suggestions(tool_name, available_tools),
+    )
+    .emit();
+    true
+}
@@ -0,0 +1,107 @@
+//! Isolated VX_HOME sandbox (`vx --isolated`)
+//!
+//! Runs vx against a throwaway `VX_HOME` so that the user's real store,
+//! config, caches and metrics are never touched. Used by `vx --isolated <cmd>`
+//! for trialing risky operations, and by the test harness in
+//! [`crate::test_utils`] for integration tests.
+//!
+//! Every vx path (store, cache, config, providers, shims, metrics…) is derived
+//! from `VX_HOME` via [`VxPaths`], so redirecting that single variable is
+//! enough to sandbox the whole process — including nested `vx` calls made by
+//! child processes, which inherit the variable.
+
+use anyhow::{Context, Result};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+use vx_paths::VxPaths;
+
+/// Environment variable that requests isolated mode (equivalent to `--isolated`)
+pub const VX_ISOLATED_ENV: &str = "VX_ISOLATED";
+
+/// Environment variable that points vx at its home directory
+pub const VX_HOME_ENV: &str = "VX_HOME";
+
+/// Returns true if isolated mode is requested by flag or `VX_ISOLATED=1`.
+pub fn isolation_requested(flag: bool) -> bool {
+    flag || matches!(
+        std::env::var(VX_ISOLATED_ENV).as_deref(),
+        Ok("1") | Ok("true") | Ok("yes")
+    )
+}
+
+/// A temporary VX_HOME that is removed when dropped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use vx_cli::isolation::IsolatedHome;
+///
+/// let home = IsolatedHome::new().unwrap();
+/// let mut cmd = std::process::Command::new("vx");
+/// cmd.envs(home.env_vars()).args(["list", "--installed"]);
+/// ```
+#[derive(Debug)]
+pub struct IsolatedHome {
+    dir: TempDir,
+}
+
+impl IsolatedHome {
+    /// Create a fresh, empty VX_HOME in the system temp directory
+    pub fn new() -> Result<Self> {
+        let dir = tempfile::Builder::new()
+            .prefix("vx-isolated-")
+            .tempdir()
+            .context("Failed to create isolated VX_HOME")?;
+        Ok(Self { dir })
+    }
+
+    /// Create a fresh VX_HOME inside the given parent directory
+    pub fn new_in(parent: impl AsRef<Path>) -> Result<Self> {
+        let dir = tempfile::Builder::new()
+            .prefix("vx-isolated-")
+            .tempdir_in(parent)
+            .context("Failed to create isolated VX_HOME")?;
+        Ok(Self { dir })
+    }
+
+    /// Path of the isolated VX_HOME
+    pub fn path(&self) -> &Path {
+        self.dir.path()
+    }
+
+    /// Standard vx directory layout rooted at the isolated VX_HOME
+    pub fn paths(&self) -> VxPaths {
+        VxPaths::with_base_dir(self.path())
+    }
+
+    /// Environment variables a child process needs to run inside this sandbox
+    ///
+    /// `VX_ISOLATED` is cleared so that a nested vx reuses this home instead
+    /// of creating yet another sandbox.
+    pub fn env_vars(&self) -> Vec<(&'static str, OsString)> {
+        vec![
+            (VX_HOME_ENV, self.path().as_os_str().to_owned()),
+            (VX_ISOLATED_ENV, OsString::new()),
+        ]
+    }
+
+    /// Point the current process at this sandbox by setting `VX_HOME`.
+    ///
+    /// Must be called before any vx paths are resolved (i.e. before the
+    /// registry, runtime context or metrics are initialized).
+    pub fn activate(&self) {
+        // Safety: called at startup before vx spawns its own worker tasks.
+        #[allow(clippy::disallowed_methods)]
+        unsafe {
+            std::env::set_var(VX_HOME_ENV, self.path());
+            std::env::remove_var(VX_ISOLATED_ENV);
+        }
+        tracing::debug!("Isolated mode: VX_HOME={}", self.path().display());
+    }
+
+    /// Keep the directory on disk instead of deleting it on drop
+    pub fn keep(self) -> PathBuf {
+        self.dir.keep()
+    }
+}
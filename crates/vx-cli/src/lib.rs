@@ -10,20 +10,27 @@ use vx_runtime::ProviderRegistry;
 use vx_runtime_core::WithDependency;
 use vx_shim::{PackageRequest, ShimExecutor};
 
+pub mod cache_policy;
 pub mod cli;
 pub mod commands;
 pub mod config;
+pub mod egress;
+pub mod eol;
 pub mod error_handler;
+pub mod isolation;
 pub mod npm_global_bridge;
+pub mod oci;
 pub mod output;
+pub mod provider_commands;
 pub mod registry;
 pub mod suggestions;
 pub mod system_tools;
+pub mod template_registry;
 pub mod tracing_setup;
 pub mod ui;
 pub mod update_checker;
 
-#[cfg(test)]
+#[cfg(any(feature = "testing", test))]
 pub mod test_utils;
 
 // Re-export for convenience
@@ -39,6 +46,40 @@ pub async fn main() -> anyhow::Result<()> {
     // Parse CLI first to check for --debug flag
     let cli = Cli::parse();
 
+    // With `--output-format json`, errors are printed as JSON reports
+    error_handler::set_output_format(GlobalOptions::from(&cli).output_format);
+
+    // `--isolated`: redirect VX_HOME to a throwaway directory before anything
+    // resolves vx paths (metrics, registry, runtime context). The guard removes
+    // the directory when the command finishes.
+    let isolated_home = if isolation::isolation_requested(cli.isolated) {
+        let home = isolation::IsolatedHome::new()?;
+        home.activate();
+        Some(home)
+    } else {
+        None
+    };
+
+    // Portable mode: keep VX_HOME next to the vx binary. `--isolated` and an
+    // explicit VX_HOME take precedence.
+    apply_portable_setting();
+
+    // `VX_RECORD` / `VX_REPLAY`: fail early on an unreadable cassette
+    vx_cache::cassette::init_from_env()?;
+
+    // `[ui]` theme and accessibility options, before anything is printed
+    apply_ui_setting();
+
+    // On a machine vx hasn't been set up on, offer the one-time global setup
+    if let Err(e) =
+        commands::global_setup::offer_first_run(cli.command.as_ref(), isolated_home.is_some()).await
+    {
+        ui::UI::warn_stderr(&format!("Global setup did not finish: {:#}", e));
+    }
+    let global_setup = vx_paths::VxPaths::new()
+        .ok()
+        .and_then(|p| commands::global_setup::GlobalSetup::load(&p));
+
     // Build command string from raw args for metrics
     let command_str = std::env::args().collect::<Vec<_>>().join(" ");
 
@@ -48,6 +89,11 @@ pub async fn main() -> anyhow::Result<()> {
         debug: cli.debug,
         verbose: cli.verbose,
         command: command_str,
+        disabled: global_setup
+            .as_ref()
+            .is_some_and(|s| !s.telemetry_enabled()),
+        // With --verbose, warn when stages go over their budgets
+        budgets: cli.verbose.then(commands::metrics::project_stage_budgets),
         ..Default::default()
     });
 
@@ -83,6 +129,16 @@ pub async fn main() -> anyhow::Result<()> {
         }
     }
 
+    // On Windows ARM64, hand the project's `arch_fallback` setting to the
+    // providers, which read VX_ARCH_FALLBACK.
+    apply_arch_fallback_setting();
+
+    // On Linux, a `libc` override picks glibc or musl builds (VX_LIBC)
+    apply_libc_setting();
+
+    // Mirror region chosen in `vx setup --global`, read from VX_MIRROR_REGION
+    commands::global_setup::apply_mirror_region_setting(global_setup.as_ref());
+
     // Fast-path for lightweight commands that do not require provider registry
     // or runtime context initialization. This significantly reduces fixed startup
     // overhead for config/script read-only operations used in benchmarks.
@@ -95,7 +151,7 @@ pub async fn main() -> anyhow::Result<()> {
 
         // Check for vx updates (non-blocking, cached)
         // Skip notification for self-update command to avoid confusion
-        if !matches!(&cli.command, Some(Commands::SelfUpdate { .. })) {
+        if !matches!(&cli.command, Some(Commands::SelfUpdate(_))) {
             // Wait for update check to complete (with timeout)
             let timeout_duration = std::time::Duration::from_secs(10);
             let handle = update_checker::notify_if_update_available();
@@ -113,6 +169,11 @@ pub async fn main() -> anyhow::Result<()> {
         return result;
     }
 
+    // Remove temp workspaces left behind by killed installs
+    if let Ok(paths) = vx_paths::VxPaths::new() {
+        vx_paths::sweep_orphans(&paths.tmp_dir, vx_paths::ORPHAN_MAX_AGE);
+    }
+
     // Register embedded bridge binaries (e.g., MSBuild.exe on Windows)
     // This must happen before any provider tries to deploy bridges.
     registry::register_embedded_bridges();
@@ -124,7 +185,15 @@ pub async fn main() -> anyhow::Result<()> {
     let options = GlobalOptions::from(&cli);
 
     // Create runtime context (apply global cache mode)
-    let context = create_context()?.with_cache_mode(options.cache_mode);
+    // A cassette has to see every version list, so skip the version cache
+    let cache_mode = if vx_cache::cassette::active().is_some() {
+        vx_runtime::CacheMode::NoCache
+    } else {
+        options.cache_mode
+    };
+    let context = create_context()?.with_cache_mode(cache_mode);
+    let security = commands::common::project_security_policy(context.http.clone());
+    let context = context.with_security_policy(security);
 
     // Create command context
     let cmd_ctx = CommandContext::new(registry, context, options);
@@ -138,6 +207,10 @@ pub async fn main() -> anyhow::Result<()> {
                 // Show help if no arguments
                 Cli::parse_from(["vx", "--help"]);
                 Ok(())
+            } else if let Some(result) = provider_commands::try_dispatch(&cmd_ctx, &cli.args).await
+            {
+                // `vx <provider> <subcommand>` contributed by the provider
+                result
             } else {
                 // Execute tool with --with dependencies
                 execute_tool(&cmd_ctx, &cli.args, &cli.with_deps).await
@@ -150,9 +223,12 @@ pub async fn main() -> anyhow::Result<()> {
         _metrics_guard.set_exit_code(1);
     }
 
+    // Let background uploads to the remote download cache finish
+    vx_runtime_http::wait_for_remote_uploads(std::time::Duration::from_secs(120)).await;
+
     // Check for vx updates (non-blocking, cached)
     // Skip notification for self-update command to avoid confusion
-    if !matches!(&cli.command, Some(Commands::SelfUpdate { .. })) {
+    if !matches!(&cli.command, Some(Commands::SelfUpdate(_))) {
         // Wait for update check to complete (with timeout to avoid blocking)
         let timeout_duration = std::time::Duration::from_secs(10);
         let handle = update_checker::notify_if_update_available();
@@ -170,6 +246,115 @@ pub async fn main() -> anyhow::Result<()> {
     result
 }
 
+/// Export the portable VX_HOME and `VX_PORTABLE=1` unless VX_HOME is set
+///
+/// Child processes, including nested vx calls from shims, inherit both.
+fn apply_portable_setting() {
+    use vx_paths::portable::{PORTABLE_ENV, portable_home};
+
+    if std::env::var_os(isolation::VX_HOME_ENV).is_some() {
+        return;
+    }
+    let Some(home) = portable_home() else {
+        return;
+    };
+    // Safety: called before any threads are spawned by this process.
+    #[allow(clippy::disallowed_methods)]
+    unsafe {
+        std::env::set_var(isolation::VX_HOME_ENV, &home);
+        std::env::set_var(PORTABLE_ENV, "1");
+    }
+}
+
+/// Export `[settings] arch_fallback` as `VX_ARCH_FALLBACK` unless it is set
+///
+/// Only Windows ARM64 has a fallback, so other hosts skip reading vx.toml.
+fn apply_arch_fallback_setting() {
+    use vx_runtime::{ArchFallback, Platform};
+
+    let platform = Platform::current();
+    if platform.emulation_fallback(ArchFallback::Emulate).is_none()
+        || std::env::var_os(ArchFallback::ENV_VAR).is_some()
+    {
+        return;
+    }
+    let Some(value) = commands::common::load_full_config_cwd()
+        .ok()
+        .and_then(|(_, config)| config.settings?.arch_fallback)
+    else {
+        return;
+    };
+    match ArchFallback::parse(&value) {
+        Some(policy) => {
+            // Safety: called before any threads are spawned by this process.
+            #[allow(clippy::disallowed_methods)]
+            unsafe {
+                std::env::set_var(ArchFallback::ENV_VAR, policy.as_str());
+            }
+        }
+        None => ui::UI::warn_stderr(&format!(
+            "Ignoring settings.arch_fallback = \"{}\" in vx.toml; expected \"emulate\" or \"never\"",
+            value
+        )),
+    }
+}
+
+/// Install the output theme from the `[ui]` options
+///
+/// `~/.vx/config/ui.toml` takes precedence over the project's vx.toml, one
+/// option at a time.
+fn apply_ui_setting() {
+    let project = commands::common::load_full_config_cwd()
+        .ok()
+        .and_then(|(_, config)| config.ui)
+        .unwrap_or_default();
+    let user = match vx_paths::VxPaths::new() {
+        Ok(paths) => {
+            vx_config::UiConfig::from_user_file(&paths.config_dir.join(vx_config::UI_CONFIG_FILE))
+                .unwrap_or_else(|e| {
+                    ui::UI::warn_stderr(&format!("Ignoring {}", e));
+                    Default::default()
+                })
+        }
+        Err(_) => Default::default(),
+    };
+    let config = user.or(&project);
+    if !config.is_empty() {
+        ui::apply_ui_config(&config);
+    }
+}
+
+/// Export `[settings] libc` as `VX_LIBC` unless it is set
+///
+/// Only Linux builds come in glibc and musl flavours, so other hosts skip
+/// reading vx.toml.
+fn apply_libc_setting() {
+    use vx_runtime::Libc;
+
+    if !cfg!(target_os = "linux") || std::env::var_os(Libc::ENV_VAR).is_some() {
+        return;
+    }
+    let Some(value) = commands::common::load_full_config_cwd()
+        .ok()
+        .and_then(|(_, config)| config.settings?.libc)
+    else {
+        return;
+    };
+    match Libc::parse(&value) {
+        Some(libc) => {
+            // Safety: called before any threads are spawned by this process.
+            #[allow(clippy::disallowed_methods)]
+            unsafe {
+                std::env::set_var(Libc::ENV_VAR, libc.as_str());
+            }
+        }
+        None => ui::UI::warn_stderr(&format!(
+            "Ignoring settings.libc = \"{}\" in vx.toml; expected \"gnu\" or \"musl\"",
+            value
+        )),
+    }
+}
+
 /// Execute lightweight commands without initializing provider registry/runtime context.
 ///
 /// Returns Some(result) when command was handled via fast-path, None otherwise.
@@ -184,9 +369,19 @@ async fn try_execute_lightweight_command(cli: &Cli) -> Option<Result<()>> {
 
         // `vx config show` is used in benchmark parse tests and only needs local config I/O.
         Some(Commands::Config {
-            command: Some(ConfigCommand::Show) | None,
+            command: Some(ConfigCommand::Show { origin: false }) | None,
         }) => Some(commands::config::handle(output_format).await),
 
+        // `vx config show --origin` only reads the policy, user and project files.
+        Some(Commands::Config {
+            command: Some(ConfigCommand::Show { origin: true }),
+        }) => Some(commands::config::handle_origin(output_format).await),
+
+        // `vx config explain` reads the same layers as `show --origin`.
+        Some(Commands::Config {
+            command: Some(ConfigCommand::Explain { key }),
+        }) => Some(commands::config::handle_explain(key, output_format).await),
+
         // `vx config validate` is also benchmarked and does not require runtime/provider init.
         Some(Commands::Config {
             command: Some(ConfigCommand::Validate { path, verbose }),
@@ -198,26 +393,35 @@ async fn try_execute_lightweight_command(cli: &Cli) -> Option<Result<()>> {
         }) => Some(commands::config::handle_dir().await),
 
         // `vx run --list` benchmark path: read/print scripts from vx.toml only.
-        Some(Commands::Run {
+        Some(Commands::Run(cli::RunArgs {
             script: _,
             list: true,
             script_help: false,
+            jobs: _,
+            watch: _,
             args: _,
-        }) => Some(commands::run::handle(None, true, false, &[]).await),
+        })) => Some(commands::run::handle(None, true, false, None, false, &[]).await),
 
         // `vx metrics` reads JSON files from disk, no registry needed.
-        Some(Commands::Metrics {
+        Some(Commands::Metrics(cli::MetricsArgs {
             command: None,
             last,
             json,
             html,
             clean,
-        }) => Some(commands::metrics::handle(*last, *json, html.clone(), *clean).await),
+        })) => Some(commands::metrics::handle(*last, *json, html.clone(), *clean).await),
 
-        Some(Commands::Metrics {
+        Some(Commands::Metrics(cli::MetricsArgs {
             command: Some(crate::cli::MetricsCommand::Tokens { last, json }),
             ..
-        }) => Some(commands::metrics::handle_tokens(*last, *json).await),
+        })) => Some(commands::metrics::handle_tokens(*last, *json).await),
+
+        // `vx stats` reads the statistics file, no registry needed.
+        Some(Commands::Stats {
+            network,
+            json,
+            clear,
+        }) => Some(commands::stats::handle(*network, *json, *clear).await),
 
         _ => None,
     }
@@ -390,6 +594,16 @@ async fn execute_tool(
         return npm_global_bridge::bridge_npm_global_install(ctx, &global_install_req).await;
     }
 
+    // Tool bundles (e.g. `devtools`) only group other tools for installation
+    if is_known_runtime && let Some(members) = registry::find_tool_bundle(&request.name) {
+        anyhow::bail!(
+            "'{}' is a tool bundle ({}); install it with `vx install {}` and run its tools directly",
+            request.name,
+            members.join(", "),
+            request.name
+        );
+    }
+
     // RFC 0033: If the runtime has a package_alias, route to package execution path
     // This makes `vx vite@5.0` equivalent to `vx npm:vite@5.0`
     if is_known_runtime && let Some(alias) = ctx.get_package_alias(&request.name) {
@@ -0,0 +1,777 @@
+//! OCI artifact packaging for store entries
+//!
+//! A store entry (`~/.vx/store/<tool>/<version>`) is packaged as a
+//! single-layer OCI artifact so that pre-verified toolchains can be shared
+//! through any container registry (ghcr.io, Harbor, Artifactory, …):
+//!
+//! - config: the OCI empty descriptor (`{}`)
+//! - layer 0: gzip-compressed tar of the version directory
+//! - annotations: `dev.vx.tool`, `dev.vx.version`, `dev.vx.platform` (the
+//!   platform the entry was installed on; pulls refuse other platforms)
+//!
+//! Artifacts can be pushed to a registry (OCI distribution API) or written to
+//! a local OCI image layout directory (`oci:<dir>[:<tag>]`) for air-gapped
+//! transfer.
+
+use anyhow::{Context, Result, anyhow, bail};
+use reqwest::StatusCode;
+use reqwest::header::{ACCEPT, CONTENT_TYPE, LOCATION, WWW_AUTHENTICATE};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
+
+/// Artifact type of a vx toolchain artifact
+pub const ARTIFACT_TYPE: &str = "application/vnd.vx.toolchain.v1";
+/// Media type of the toolchain layer (tar+gzip of a store version dir)
+pub const LAYER_MEDIA_TYPE: &str = "application/vnd.vx.toolchain.layer.v1.tar+gzip";
+/// OCI image manifest media type
+pub const MANIFEST_MEDIA_TYPE: &str = "application/vnd.oci.image.manifest.v1+json";
+/// OCI empty config media type
+pub const EMPTY_CONFIG_MEDIA_TYPE: &str = "application/vnd.oci.empty.v1+json";
+/// Content of the OCI empty config blob
+pub const EMPTY_CONFIG: &[u8] = b"{}";
+
+/// Annotation holding the tool name
+pub const ANNOTATION_TOOL: &str = "dev.vx.tool";
+/// Annotation holding the tool version
+pub const ANNOTATION_VERSION: &str = "dev.vx.version";
+/// Annotation holding the store platform (e.g. `linux-x64`)
+pub const ANNOTATION_PLATFORM: &str = "dev.vx.platform";
+const ANNOTATION_TITLE: &str = "org.opencontainers.image.title";
+const ANNOTATION_CREATED: &str = "org.opencontainers.image.created";
+const ANNOTATION_REF_NAME: &str = "org.opencontainers.image.ref.name";
+
+/// Prefix selecting a local OCI image layout instead of a registry
+pub const LAYOUT_PREFIX: &str = "oci:";
+
+// ============================================================================
+// References
+// ============================================================================
+
+/// A registry reference: `registry/repository[:tag|@digest]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OciReference {
+    /// Registry host (with optional port), e.g. `ghcr.io`
+    pub registry: String,
+    /// Repository path, e.g. `org/toolchains`
+    pub repository: String,
+    /// Tag or digest (`sha256:…`)
+    pub reference: String,
+}
+
+impl OciReference {
+    /// Base URL of the distribution API (`http` for localhost registries)
+    pub fn api_base(&self) -> String {
+        let host = self.registry.split(':').next().unwrap_or_default();
+        let scheme = if host == "localhost" || host == "127.0.0.1" {
+            "http"
+        } else {
+            "https"
+        };
+        format!("{scheme}://{}/v2/{}", self.registry, self.repository)
+    }
+
+    /// Whether the reference is a digest rather than a tag
+    pub fn is_digest(&self) -> bool {
+        self.reference.starts_with("sha256:")
+    }
+}
+
+impl FromStr for OciReference {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (registry, rest) = s
+            .split_once('/')
+            .filter(|(host, _)| host.contains('.') || host.contains(':') || *host == "localhost")
+            .ok_or_else(|| {
+                anyhow!("Invalid OCI reference '{s}': expected <registry>/<repository>[:<tag>]")
+            })?;
+
+        let (repository, reference) = if let Some((repo, digest)) = rest.split_once('@') {
+            (repo, digest.to_string())
+        } else {
+            match rest.rsplit_once(':') {
+                Some((repo, tag)) if !tag.contains('/') => (repo, tag.to_string()),
+                _ => (rest, "latest".to_string()),
+            }
+        };
+
+        if repository.is_empty() || reference.is_empty() {
+            bail!("Invalid OCI reference '{s}'");
+        }
+
+        Ok(Self {
+            registry: registry.to_string(),
+            repository: repository.to_string(),
+            reference,
+        })
+    }
+}
+
+impl std::fmt::Display for OciReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sep = if self.is_digest() { '@' } else { ':' };
+        write!(
+            f,
+            "{}/{}{}{}",
+            self.registry, self.repository, sep, self.reference
+        )
+    }
+}
+
+/// Where an artifact lives: a registry or a local OCI image layout
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OciTarget {
+    /// Remote registry reference
+    Registry(OciReference),
+    /// Local OCI image layout directory and tag
+    Layout { dir: PathBuf, tag: String },
+}
+
+impl OciTarget {
+    /// Parse `oci:<dir>[:<tag>]` or a registry reference
+    pub fn parse(s: &str) -> Result<Self> {
+        if let Some(rest) = s.strip_prefix(LAYOUT_PREFIX) {
+            // Only split on the last ':' when it is not part of a Windows drive (C:\...)
+            let (dir, tag) = match rest.rsplit_once(':') {
+                Some((dir, tag)) if dir.len() > 1 && !tag.contains(['/', '\\']) => (dir, tag),
+                _ => (rest, "latest"),
+            };
+            return Ok(Self::Layout {
+                dir: PathBuf::from(dir),
+                tag: tag.to_string(),
+            });
+        }
+        Ok(Self::Registry(s.parse()?))
+    }
+}
+
+impl std::fmt::Display for OciTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Registry(r) => write!(f, "{r}"),
+            Self::Layout { dir, tag } => write!(f, "{LAYOUT_PREFIX}{}:{tag}", dir.display()),
+        }
+    }
+}
+
+// ============================================================================
+// Manifest
+// ============================================================================
+
+/// OCI content descriptor
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Descriptor {
+    pub media_type: String,
+    pub digest: String,
+    pub size: u64,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub annotations: BTreeMap<String, String>,
+}
+
+impl Descriptor {
+    /// Describe a blob
+    pub fn for_blob(media_type: &str, bytes: &[u8]) -> Self {
+        Self {
+            media_type: media_type.to_string(),
+            digest: sha256_digest(bytes),
+            size: bytes.len() as u64,
+            annotations: BTreeMap::new(),
+        }
+    }
+}
+
+/// OCI image manifest (the subset used for toolchain artifacts)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Manifest {
+    pub schema_version: u32,
+    #[serde(default)]
+    pub media_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artifact_type: Option<String>,
+    pub config: Descriptor,
+    pub layers: Vec<Descriptor>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub annotations: BTreeMap<String, String>,
+}
+
+/// Identity of the toolchain stored in an artifact
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolchainInfo {
+    pub tool: String,
+    pub version: String,
+    pub platform: String,
+}
+
+impl Manifest {
+    /// The toolchain identity recorded in the manifest annotations
+    pub fn toolchain(&self) -> Result<ToolchainInfo> {
+        let get = |key: &str| {
+            self.annotations
+                .get(key)
+                .cloned()
+                .ok_or_else(|| anyhow!("Not a vx toolchain artifact (missing '{key}' annotation)"))
+        };
+        let info = ToolchainInfo {
+            tool: get(ANNOTATION_TOOL)?,
+            version: get(ANNOTATION_VERSION)?,
+            platform: get(ANNOTATION_PLATFORM)?,
+        };
+        // tool/version become store path components
+        for part in [&info.tool, &info.version] {
+            let mut components = Path::new(part.as_str()).components();
+            let single_normal = matches!(components.next(), Some(Component::Normal(_)))
+                && components.next().is_none();
+            if !single_normal || part.contains(['/', '\\']) {
+                bail!("Invalid toolchain name '{part}' in artifact annotations");
+            }
+        }
+        Ok(info)
+    }
+
+    /// The toolchain layer descriptor
+    pub fn toolchain_layer(&self) -> Result<&Descriptor> {
+        self.layers
+            .iter()
+            .find(|l| l.media_type == LAYER_MEDIA_TYPE)
+            .ok_or_else(|| anyhow!("Artifact has no '{LAYER_MEDIA_TYPE}' layer"))
+    }
+}
+
+/// A packaged store entry ready to be pushed or written to a layout
+#[derive(Debug, Clone)]
+pub struct ToolchainArtifact {
+    pub info: ToolchainInfo,
+    pub manifest: Manifest,
+    /// Serialized manifest (its digest identifies the artifact)
+    pub manifest_bytes: Vec<u8>,
+    /// Compressed layer
+    pub layer: Vec<u8>,
+}
+
+impl ToolchainArtifact {
+    /// Package a store version directory
+    pub fn pack(info: ToolchainInfo, store_dir: &Path) -> Result<Self> {
+        let layer = pack_dir(store_dir)?;
+
+        let mut layer_desc = Descriptor::for_blob(LAYER_MEDIA_TYPE, &layer);
+        layer_desc.annotations.insert(
+            ANNOTATION_TITLE.to_string(),
+            format!("{}-{}-{}.tar.gz", info.tool, info.version, info.platform),
+        );
+
+        let mut annotations = BTreeMap::new();
+        annotations.insert(ANNOTATION_TOOL.to_string(), info.tool.clone());
+        annotations.insert(ANNOTATION_VERSION.to_string(), info.version.clone());
+        annotations.insert(ANNOTATION_PLATFORM.to_string(), info.platform.clone());
+        annotations.insert(
+            ANNOTATION_CREATED.to_string(),
+            chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        );
+
+        let manifest = Manifest {
+            schema_version: 2,
+            media_type: MANIFEST_MEDIA_TYPE.to_string(),
+            artifact_type: Some(ARTIFACT_TYPE.to_string()),
+            config: Descriptor::for_blob(EMPTY_CONFIG_MEDIA_TYPE, EMPTY_CONFIG),
+            layers: vec![layer_desc],
+            annotations,
+        };
+        let manifest_bytes = serde_json::to_vec_pretty(&manifest)?;
+
+        Ok(Self {
+            info,
+            manifest,
+            manifest_bytes,
+            layer,
+        })
+    }
+
+    /// Digest of the serialized manifest
+    pub fn digest(&self) -> String {
+        sha256_digest(&self.manifest_bytes)
+    }
+}
+
+// ============================================================================
+// Layer packing
+// ============================================================================
+
+/// `sha256:<hex>` digest of a blob
+pub fn sha256_digest(bytes: &[u8]) -> String {
+    let hash = Sha256::digest(bytes);
+    let hex = hash.iter().fold(String::with_capacity(64), |mut acc, b| {
+        use std::fmt::Write;
+        let _ = write!(acc, "{b:02x}");
+        acc
+    });
+    format!("sha256:{hex}")
+}
+
+/// Verify that a blob matches its descriptor
+pub fn verify_blob(desc: &Descriptor, bytes: &[u8]) -> Result<()> {
+    let actual = sha256_digest(bytes);
+    if actual != desc.digest || bytes.len() as u64 != desc.size {
+        bail!(
+            "Blob digest mismatch: expected {} ({} bytes), got {} ({} bytes)",
+            desc.digest,
+            desc.size,
+            actual,
+            bytes.len()
+        );
+    }
+    Ok(())
+}
+
+/// Create a gzip-compressed tar of the contents of `dir`
+pub fn pack_dir(dir: &Path) -> Result<Vec<u8>> {
+    if !dir.is_dir() {
+        bail!("Store entry not found: {}", dir.display());
+    }
+    let enc = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    let mut tar = tar::Builder::new(enc);
+    tar.follow_symlinks(false);
+    tar.append_dir_all(".", dir)
+        .with_context(|| format!("Failed to archive {}", dir.display()))?;
+    let enc = tar.into_inner()?;
+    Ok(enc.finish()?)
+}
+
+/// Extract a layer produced by [`pack_dir`] into `dest`
+pub fn unpack_layer(layer: &[u8], dest: &Path) -> Result<()> {
+    std::fs::create_dir_all(dest)?;
+    let dec = flate2::read::GzDecoder::new(layer);
+    let mut archive = tar::Archive::new(dec);
+    archive.set_preserve_permissions(true);
+    archive
+        .unpack(dest)
+        .with_context(|| format!("Failed to extract layer into {}", dest.display()))
+}
+
+// ============================================================================
+// OCI image layout (local directory)
+// ============================================================================
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ImageIndex {
+    schema_version: u32,
+    #[serde(default)]
+    manifests: Vec<Descriptor>,
+}
+
+fn blob_path(dir: &Path, digest: &str) -> Result<PathBuf> {
+    let hex = digest
+        .strip_prefix("sha256:")
+        .filter(|h| h.len() == 64 && h.chars().all(|c| c.is_ascii_hexdigit()))
+        .ok_or_else(|| anyhow!("Unsupported digest '{digest}'"))?;
+    Ok(dir.join("blobs").join("sha256").join(hex))
+}
+
+/// Write an artifact into an OCI image layout directory under `tag`
+pub fn write_layout(dir: &Path, tag: &str, artifact: &ToolchainArtifact) -> Result<()> {
+    std::fs::create_dir_all(dir.join("blobs").join("sha256"))?;
+    std::fs::write(dir.join("oci-layout"), br#"{"imageLayoutVersion":"1.0.0"}"#)?;
+
+    for (digest, bytes) in [
+        (artifact.manifest.config.digest.as_str(), EMPTY_CONFIG),
+        (
+            artifact.manifest.layers[0].digest.as_str(),
+            artifact.layer.as_slice(),
+        ),
+    ] {
+        std::fs::write(blob_path(dir, digest)?, bytes)?;
+    }
+    let digest = artifact.digest();
+    std::fs::write(blob_path(dir, &digest)?, &artifact.manifest_bytes)?;
+
+    let index_path = dir.join("index.json");
+    let mut index: ImageIndex = match std::fs::read(&index_path) {
+        Ok(bytes) => serde_json::from_slice(&bytes)
+            .with_context(|| format!("Invalid {}", index_path.display()))?,
+        Err(_) => ImageIndex {
+            schema_version: 2,
+            manifests: Vec::new(),
+        },
+    };
+    index
+        .manifests
+        .retain(|m| m.annotations.get(ANNOTATION_REF_NAME).map(String::as_str) != Some(tag));
+    let mut desc = Descriptor::for_blob(MANIFEST_MEDIA_TYPE, &artifact.manifest_bytes);
+    desc.annotations
+        .insert(ANNOTATION_REF_NAME.to_string(), tag.to_string());
+    index.manifests.push(desc);
+    std::fs::write(&index_path, serde_json::to_vec_pretty(&index)?)?;
+    Ok(())
+}
+
+/// Read the manifest tagged `tag` and its toolchain layer from a layout
+pub fn read_layout(dir: &Path, tag: &str) -> Result<(Manifest, Vec<u8>)> {
+    let index_path = dir.join("index.json");
+    let index: ImageIndex = serde_json::from_slice(
+        &std::fs::read(&index_path)
+            .with_context(|| format!("Not an OCI image layout: {}", dir.display()))?,
+    )
+    .with_context(|| format!("Invalid {}", index_path.display()))?;
+
+    let desc = index
+        .manifests
+        .iter()
+        .find(|m| m.annotations.get(ANNOTATION_REF_NAME).map(String::as_str) == Some(tag))
+        .ok_or_else(|| anyhow!("Tag '{tag}' not found in {}", dir.display()))?;
+
+    let manifest_bytes = std::fs::read(blob_path(dir, &desc.digest)?)?;
+    verify_blob(desc, &manifest_bytes)?;
+    let manifest: Manifest = serde_json::from_slice(&manifest_bytes)?;
+
+    let layer_desc = manifest.toolchain_layer()?;
+    let layer = std::fs::read(blob_path(dir, &layer_desc.digest)?)?;
+    verify_blob(layer_desc, &layer)?;
+    Ok((manifest, layer))
+}
+
+// ============================================================================
+// Registry client (OCI distribution API)
+// ============================================================================
+
+/// Parsed `WWW-Authenticate` challenge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthChallenge {
+    Basic,
+    Bearer {
+        realm: String,
+        service: Option<String>,
+        scope: Option<String>,
+    },
+}
+
+impl AuthChallenge {
+    /// Parse a `WWW-Authenticate` header value
+    pub fn parse(header: &str) -> Option<Self> {
+        let (scheme, params) = header.trim().split_once(' ').unwrap_or((header.trim(), ""));
+        if scheme.eq_ignore_ascii_case("basic") {
+            return Some(Self::Basic);
+        }
+        if !scheme.eq_ignore_ascii_case("bearer") {
+            return None;
+        }
+
+        let mut fields = BTreeMap::new();
+        let mut rest = params.trim();
+        while let Some((key, after)) = rest.split_once('=') {
+            let key = key
+                .trim()
+                .trim_start_matches(',')
+                .trim()
+                .to_ascii_lowercase();
+            let (value, remaining) = if let Some(quoted) = after.strip_prefix('"') {
+                let end = quoted.find('"').unwrap_or(quoted.len());
+                (&quoted[..end], quoted.get(end + 1..).unwrap_or(""))
+            } else {
+                let end = after.find(',').unwrap_or(after.len());
+                (&after[..end], &after[end..])
+            };
+            fields.insert(key, value.to_string());
+            rest = remaining.trim_start_matches(',').trim();
+        }
+
+        Some(Self::Bearer {
+            realm: fields.remove("realm")?,
+            service: fields.remove("service"),
+            scope: fields.remove("scope"),
+        })
+    }
+}
+
+/// Registry credentials: `VX_OCI_USERNAME`/`VX_OCI_PASSWORD`, then
+/// `vx auth login registry`, falling back to the GitHub token for `ghcr.io`
+fn registry_credentials(registry: &str) -> Option<(String, String)> {
+    if let (Ok(user), Ok(pass)) = (
+        std::env::var("VX_OCI_USERNAME"),
+        std::env::var("VX_OCI_PASSWORD"),
+    ) {
+        return Some((user, pass));
+    }
+    if let Some(credential) = vx_proxy::credentials::registry_credential(registry) {
+        let user = credential.username.unwrap_or_else(|| "vx".to_string());
+        return Some((user, credential.secret));
+    }
+    if registry == "ghcr.io" {
+        let token = vx_proxy::credentials::github_token()?;
+        let user = std::env::var("GITHUB_ACTOR").unwrap_or_else(|_| "vx".to_string());
+        return Some((user, token));
+    }
+    None
+}
+
+/// Credentials attached to registry requests after a challenge
+#[derive(Debug, Clone)]
+enum Authorization {
+    Basic(String, String),
+    Bearer(String),
+}
+
+impl Authorization {
+    fn apply(&self, req: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match self {
+            Self::Basic(user, pass) => req.basic_auth(user, Some(pass)),
+            Self::Bearer(token) => req.bearer_auth(token),
+        }
+    }
+}
+
+/// Minimal OCI distribution client for pushing and pulling toolchain artifacts
+pub struct RegistryClient {
+    http: reqwest::Client,
+    reference: OciReference,
+    credentials: Option<(String, String)>,
+    authorization: Option<Authorization>,
+}
+
+impl RegistryClient {
+    /// Create a client for the repository of `reference`
+    pub fn new(reference: OciReference) -> Result<Self> {
+        let http = vx_proxy::client_builder()
+            .user_agent(format!("vx/{}", env!("CARGO_PKG_VERSION")))
+            .build()?;
+        let credentials = registry_credentials(&reference.registry);
+        Ok(Self {
+            http,
+            reference,
+            credentials,
+            authorization: None,
+        })
+    }
+
+    /// Send a request, answering one authentication challenge if needed
+    async fn send(
+        &mut self,
+        build: impl Fn(&reqwest::Client) -> reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response> {
+        let with_auth = |req: reqwest::RequestBuilder, auth: &Option<Authorization>| match auth {
+            Some(auth) => auth.apply(req),
+            None => req,
+        };
+
+        let response = with_auth(build(&self.http), &self.authorization)
+            .send()
+            .await?;
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+
+        let challenge = response
+            .headers()
+            .get(WWW_AUTHENTICATE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(AuthChallenge::parse)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Registry {} requires authentication",
+                    self.reference.registry
+                )
+            })?;
+        self.authorization = Some(self.authorize(challenge).await?);
+
+        Ok(with_auth(build(&self.http), &self.authorization)
+            .send()
+            .await?)
+    }
+
+    async fn authorize(&self, challenge: AuthChallenge) -> Result<Authorization> {
+        match challenge {
+            AuthChallenge::Basic => self
+                .credentials
+                .clone()
+                .map(|(user, pass)| Authorization::Basic(user, pass))
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Registry {0} requires credentials (run `vx auth login registry {0}` or set VX_OCI_USERNAME and VX_OCI_PASSWORD)",
+                        self.reference.registry
+                    )
+                }),
+            AuthChallenge::Bearer {
+                realm,
+                service,
+                scope,
+            } => {
+                let scope = scope.unwrap_or_else(|| {
+                    format!("repository:{}:pull,push", self.reference.repository)
+                });
+                let mut params = vec![("scope", scope.as_str())];
+                if let Some(service) = &service {
+                    params.push(("service", service.as_str()));
+                }
+                let url = reqwest::Url::parse_with_params(&realm, &params)
+                    .with_context(|| format!("Invalid token realm '{realm}'"))?;
+                let mut req = self.http.get(url);
+                if let Some((user, pass)) = &self.credentials {
+                    req = req.basic_auth(user, Some(pass));
+                }
+
+                #[derive(Deserialize)]
+                struct TokenResponse {
+                    token: Option<String>,
+                    access_token: Option<String>,
+                }
+                let resp = req.send().await?.error_for_status().with_context(|| {
+                    format!("Failed to obtain a token for {}", self.reference.registry)
+                })?;
+                let body: TokenResponse = resp.json().await?;
+                body.token
+                    .or(body.access_token)
+                    .map(Authorization::Bearer)
+                    .ok_or_else(|| anyhow!("Token endpoint returned no token"))
+            }
+        }
+    }
+
+    /// Upload a blob unless the registry already has it
+    async fn push_blob(&mut self, digest: &str, bytes: &[u8]) -> Result<()> {
+        let base = self.reference.api_base();
+        let url = format!("{base}/blobs/{digest}");
+        if self.send(|c| c.head(&url)).await?.status().is_success() {
+            tracing::debug!(%digest, "Blob already present, skipping upload");
+            return Ok(());
+        }
+
+        let start = self
+            .send(|c| c.post(format!("{base}/blobs/uploads/")))
+            .await?
+            .error_for_status()
+            .context("Failed to start blob upload")?;
+        let location = start
+            .headers()
+            .get(LOCATION)
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| anyhow!("Registry did not return an upload location"))?;
+        let mut upload_url = if location.starts_with("http") {
+            location.to_string()
+        } else {
+            let origin = base.split("/v2/").next().unwrap_or_default();
+            format!("{origin}{location}")
+        };
+        upload_url.push(if upload_url.contains('?') { '&' } else { '?' });
+        upload_url.push_str(&format!("digest={digest}"));
+
+        let body = bytes.to_vec();
+        self.send(|c| {
+            c.put(&upload_url)
+                .header(CONTENT_TYPE, "application/octet-stream")
+                .body(body.clone())
+        })
+        .await?
+        .error_for_status()
+        .with_context(|| format!("Failed to upload blob {digest}"))?;
+        Ok(())
+    }
+
+    /// Push an artifact (blobs, then manifest) to the client's reference
+    pub async fn push(&mut self, artifact: &ToolchainArtifact) -> Result<String> {
+        self.push_blob(&artifact.manifest.config.digest, EMPTY_CONFIG)
+            .await?;
+        let layer_digest = artifact.manifest.layers[0].digest.clone();
+        self.push_blob(&layer_digest, &artifact.layer).await?;
+
+        let url = format!(
+            "{}/manifests/{}",
+            self.reference.api_base(),
+            self.reference.reference
+        );
+        let body = artifact.manifest_bytes.clone();
+        self.send(|c| {
+            c.put(&url)
+                .header(CONTENT_TYPE, MANIFEST_MEDIA_TYPE)
+                .body(body.clone())
+        })
+        .await?
+        .error_for_status()
+        .context("Failed to upload manifest")?;
+        Ok(artifact.digest())
+    }
+
+    /// Pull the manifest and toolchain layer of the client's reference
+    pub async fn pull(&mut self) -> Result<(Manifest, Vec<u8>)> {
+        let base = self.reference.api_base();
+        let url = format!("{base}/manifests/{}", self.reference.reference);
+        let manifest_bytes = self
+            .send(|c| c.get(&url).header(ACCEPT, MANIFEST_MEDIA_TYPE))
+            .await?
+            .error_for_status()
+            .with_context(|| format!("Failed to fetch manifest for {}", self.reference))?
+            .bytes()
+            .await?;
+        if self.reference.is_digest() && sha256_digest(&manifest_bytes) != self.reference.reference
+        {
+            bail!("Manifest digest does not match {}", self.reference);
+        }
+        let manifest: Manifest = serde_json::from_slice(&manifest_bytes)
+            .with_context(|| format!("{} is not an OCI image manifest", self.reference))?;
+
+        let layer_desc = manifest.toolchain_layer()?.clone();
+        let blob_url = format!("{base}/blobs/{}", layer_desc.digest);
+        let layer = self
+            .send(|c| c.get(&blob_url))
+            .await?
+            .error_for_status()
+            .context("Failed to download toolchain layer")?
+            .bytes()
+            .await?
+            .to_vec();
+        verify_blob(&layer_desc, &layer)?;
+        Ok((manifest, layer))
+    }
+}
+
+// ============================================================================
+// High-level operations
+// ============================================================================
+
+/// Push an artifact to `target`, returning the manifest digest
+pub async fn publish(artifact: &ToolchainArtifact, target: &OciTarget) -> Result<String> {
+    match target {
+        OciTarget::Layout { dir, tag } => {
+            write_layout(dir, tag, artifact)?;
+            Ok(artifact.digest())
+        }
+        OciTarget::Registry(reference) => {
+            RegistryClient::new(reference.clone())?.push(artifact).await
+        }
+    }
+}
+
+/// Fetch the manifest and verified layer of the artifact at `target`
+pub async fn fetch(target: &OciTarget) -> Result<(Manifest, Vec<u8>)> {
+    match target {
+        OciTarget::Layout { dir, tag } => read_layout(dir, tag),
+        OciTarget::Registry(reference) => RegistryClient::new(reference.clone())?.pull().await,
+    }
+}
+
+/// Extract a pulled layer into `store_dir`, replacing it atomically
+///
+/// The layer is unpacked into a sibling staging directory first so that a
+/// failed extraction never leaves a half-populated store entry behind.
+pub fn install_layer(layer: &[u8], store_dir: &Path) -> Result<()> {
+    let parent = store_dir
+        .parent()
+        .ok_or_else(|| anyhow!("Invalid store path {}", store_dir.display()))?;
+    std::fs::create_dir_all(parent)?;
+    let staging = tempfile::Builder::new()
+        .prefix(".oci-")
+        .tempdir_in(parent)?;
+    unpack_layer(layer, staging.path())?;
+
+    if store_dir.exists() {
+        std::fs::remove_dir_all(store_dir)
+            .with_context(|| format!("Failed to replace {}", store_dir.display()))?;
+    }
+    std::fs::rename(staging.path(), store_dir)
+        .with_context(|| format!("Failed to move toolchain into {}", store_dir.display()))?;
+    Ok(())
+}
@@ -622,6 +622,48 @@ pub struct CheckOutput {
     pub warnings: Vec<String>,
     /// List of errors
     pub errors: Vec<String>,
+    /// Machine requirement checks (`[requirements]` in vx.toml)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub system: Vec<SystemCheck>,
+}
+
+/// Result of a machine requirement check
+#[derive(Serialize, Clone, Debug)]
+pub struct SystemCheck {
+    /// What kind of requirement was checked
+    pub kind: SystemCheckKind,
+    /// Checked item (OS name, package, URL, ...)
+    pub name: String,
+    /// Whether the machine meets the requirement
+    pub passed: bool,
+    /// Observed value or failure reason
+    pub detail: String,
+}
+
+/// Kind of machine requirement
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SystemCheckKind {
+    Os,
+    Arch,
+    DiskSpace,
+    SystemPackage,
+    Network,
+    Gpu,
+}
+
+impl SystemCheckKind {
+    /// Short label used in text output
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Os => "os",
+            Self::Arch => "arch",
+            Self::DiskSpace => "disk",
+            Self::SystemPackage => "package",
+            Self::Network => "network",
+            Self::Gpu => "gpu",
+        }
+    }
 }
 
 /// Status of a tool requirement
@@ -693,6 +735,21 @@ impl CommandOutput for CheckOutput {
             )?;
         }
 
+        if !self.system.is_empty() {
+            writeln!(writer)?;
+            writeln!(writer, "Machine requirements:")?;
+            for check in &self.system {
+                writeln!(
+                    writer,
+                    "{} {} {} ({})",
+                    if check.passed { "✓" } else { "✗" },
+                    check.kind.label(),
+                    check.name,
+                    check.detail
+                )?;
+            }
+        }
+
         writeln!(writer)?;
 
         if self.all_satisfied && self.warnings.is_empty() {
@@ -0,0 +1,191 @@
+//! Subcommands contributed by providers and extensions
+//!
+//! Providers declare subcommands through
+//! [`Provider::commands`](vx_runtime::Provider::commands), invoked as
+//! `vx <provider> <subcommand>`. Their [`CommandSpec`]s are turned into clap
+//! commands at runtime, which parse the arguments and render `--help`.
+//! The same commands, and those of installed extensions (`vx x <extension>
+//! <command>`), are merged into the command tree used for shell completions.
+
+use crate::commands::CommandContext;
+use anyhow::Result;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use std::sync::Arc;
+use vx_extension::Extension;
+use vx_extension::config::CommandConfig;
+use vx_runtime::{
+    CommandArg, CommandArgKind, CommandContribution, CommandInput, CommandSpec, ProviderRegistry,
+};
+
+/// Build the clap command for a contributed subcommand
+pub fn to_clap(spec: &CommandSpec) -> Command {
+    let mut command = Command::new(spec.name.clone()).about(spec.about.clone());
+    if let Some(long_about) = &spec.long_about {
+        command = command.long_about(long_about.clone());
+    }
+    command.args(spec.args.iter().map(to_clap_arg))
+}
+
+fn to_clap_arg(arg: &CommandArg) -> Arg {
+    let mut clap_arg = Arg::new(arg.name.clone()).help(arg.help.clone());
+    if arg.kind != CommandArgKind::Positional {
+        clap_arg = clap_arg.long(arg.name.clone());
+        if let Some(short) = arg.short {
+            clap_arg = clap_arg.short(short);
+        }
+    }
+    clap_arg = match (arg.kind, arg.multiple) {
+        (CommandArgKind::Flag, _) => clap_arg.action(ArgAction::SetTrue),
+        // Repeated options take one value each: `--exclude a --exclude b`
+        (CommandArgKind::Option, true) => clap_arg.action(ArgAction::Append),
+        (_, true) => clap_arg.action(ArgAction::Append).num_args(1..),
+        (_, false) => clap_arg.action(ArgAction::Set),
+    };
+    if arg.kind != CommandArgKind::Flag {
+        clap_arg = clap_arg.required(arg.required);
+        if let Some(default) = &arg.default {
+            clap_arg = clap_arg.default_value(default.clone());
+        }
+        if !arg.choices.is_empty() {
+            clap_arg = clap_arg.value_parser(arg.choices.clone());
+        }
+    }
+    clap_arg
+}
+
+/// Parse `args` (everything after the subcommand name) against `spec`
+///
+/// `provider` only appears in usage and error messages.
+pub fn parse_input(
+    spec: &CommandSpec,
+    provider: &str,
+    args: &[String],
+) -> Result<CommandInput, clap::Error> {
+    let command = to_clap(spec).bin_name(format!("vx {} {}", provider, spec.name));
+    let matches = command
+        .try_get_matches_from(std::iter::once(spec.name.clone()).chain(args.iter().cloned()))?;
+    Ok(input_from_matches(spec, &matches))
+}
+
+fn input_from_matches(spec: &CommandSpec, matches: &ArgMatches) -> CommandInput {
+    let mut input = CommandInput::new();
+    for arg in &spec.args {
+        if arg.kind == CommandArgKind::Flag {
+            input.insert_flag(arg.name.clone(), matches.get_flag(&arg.name));
+        } else if let Some(values) = matches.get_many::<String>(&arg.name) {
+            input.insert_values(arg.name.clone(), values.cloned().collect());
+        }
+    }
+    input
+}
+
+/// The command tree of a provider's contributions, as `vx <provider>`
+pub fn provider_command(provider: &str, about: &str, specs: &[CommandSpec]) -> Command {
+    Command::new(provider.to_string())
+        .about(about.to_string())
+        .subcommands(specs.iter().map(to_clap))
+}
+
+/// Describe an extension command with the same schema as provider commands
+pub fn extension_command_spec(name: &str, config: &CommandConfig) -> CommandSpec {
+    let mut spec = CommandSpec::new(name, config.description.clone());
+    for def in &config.arguments {
+        let help = def.help.clone().unwrap_or_default();
+        let mut arg = match def.arg_type.as_str() {
+            _ if def.positional => CommandArg::positional(def.name.clone(), help),
+            "flag" | "bool" | "boolean" => CommandArg::flag(def.name.clone(), help),
+            _ => CommandArg::option(def.name.clone(), help),
+        };
+        if let Some(short) = def.short.as_deref().and_then(|s| s.chars().next()) {
+            arg = arg.short(short);
+        }
+        if def.required {
+            arg = arg.required();
+        }
+        if matches!(def.arg_type.as_str(), "array" | "list") {
+            arg = arg.multiple();
+        }
+        if let Some(default) = &def.default {
+            arg = arg.default_value(default.clone());
+        }
+        spec = spec.arg(arg.choices(def.choices.clone()));
+    }
+    spec
+}
+
+/// Add provider and extension commands to the vx command tree for completions
+///
+/// Materializes every provider, so only use this where that cost is fine.
+pub fn with_contributions(
+    cli: Command,
+    registry: &ProviderRegistry,
+    extensions: &[Extension],
+) -> Command {
+    let mut cli = cli;
+    for provider in registry.providers() {
+        let specs: Vec<CommandSpec> = provider.commands().iter().map(|c| c.spec()).collect();
+        if specs.is_empty() || cli.find_subcommand(provider.name()).is_some() {
+            continue;
+        }
+        cli = cli.subcommand(provider_command(
+            provider.name(),
+            provider.description(),
+            &specs,
+        ));
+    }
+
+    if extensions.is_empty() {
+        return cli;
+    }
+    cli.mut_subcommand("x", |x| {
+        x.subcommands(extensions.iter().map(|ext| {
+            Command::new(ext.name.clone())
+                .about(ext.config.extension.description.clone())
+                .subcommands(
+                    ext.config
+                        .commands
+                        .iter()
+                        .map(|(name, config)| to_clap(&extension_command_spec(name, config))),
+                )
+        }))
+    })
+}
+
+/// Find a provider's contributed subcommand by name
+pub fn find_command(
+    registry: &ProviderRegistry,
+    provider: &str,
+    subcommand: &str,
+) -> Option<Arc<dyn CommandContribution>> {
+    registry
+        .get_provider(provider)?
+        .commands()
+        .into_iter()
+        .find(|command| command.spec().name == subcommand)
+}
+
+/// Run `vx <provider> <subcommand> [args]` if the provider contributes it
+///
+/// Returns `None` when `args` don't name a contributed subcommand, so they are
+/// passed to the tool as usual. `vx <provider> -- <args>` always goes to the tool.
+pub async fn try_dispatch(ctx: &CommandContext, args: &[String]) -> Option<Result<()>> {
+    let [provider, subcommand, rest @ ..] = args else {
+        return None;
+    };
+    let command = find_command(ctx.registry(), provider, subcommand)?;
+    let spec = command.spec();
+
+    let input = match parse_input(&spec, provider, rest) {
+        Ok(input) => input,
+        // Prints help, or the usage error, and exits like any vx command
+        Err(e) => e.exit(),
+    };
+    tracing::debug!(provider = %provider, command = %spec.name, "Running provider command");
+
+    let result = command.run(&input, ctx.runtime_context()).await;
+    Some(result.map(|code| {
+        if code != 0 {
+            std::process::exit(code);
+        }
+    }))
+}
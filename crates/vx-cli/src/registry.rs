@@ -4,28 +4,25 @@
 //! All providers are loaded from `provider.star` files (RFC-0037).
 //! Static Rust providers are registered directly into the ProviderRegistry.
 
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
-use std::path::Path;
 use std::sync::{Arc, OnceLock};
 use tracing::trace;
-use vx_paths::{PROJECT_VX_DIR, VxPaths, find_project_root};
+use vx_api::providers::{
+    self as wiring, BUILTIN_PROVIDERS, BUILTIN_PROVIDERS_DIGEST, builtin_provider_stars,
+};
+use vx_cache::{IndexedRuntime, RegistryIndex};
+use vx_paths::VxPaths;
 use vx_runtime::{
-    ConstraintRule, DependencyConstraint, ManifestVersionPattern, Provider, ProviderRegistry,
-    Runtime, RuntimeContext, init_constraints_from_star,
+    AsdfPlugin, Platform, Provider, ProviderFailure, ProviderRegistry, Runtime, RuntimeContext,
 };
 use vx_runtime_http::create_runtime_context;
+use vx_starlark::StarMetadata;
 use vx_starlark::provider::types::PackageAlias;
-use vx_starlark::{StarMetadata, StarlarkEngine};
-use vx_versions::{RangeOp, VersionConstraint, VersionRequest};
-
-// ---------------------------------------------------------------------------
-// Compile-time embedded provider.star contents (RFC-0037)
-//
-// build.rs writes provider_stars.rs:
-//   - PROVIDER_STAR_<NAME> : &str  (individual star content, via include_str!)
-//   - ALL_PROVIDER_STARS   : &[(&str, &str)]  (name, star_content pairs)
-// ---------------------------------------------------------------------------
-include!(concat!(env!("OUT_DIR"), "/provider_stars.rs"));
+
+// The provider.star files of crates/vx-providers are embedded at compile
+// time by vx-api (RFC-0037), which also owns the registration wiring shared
+// with embedders: `vx_api::providers`.
 
 // Include the compile-time generated embedded bridge binaries
 mod embedded_bridges {
@@ -41,8 +38,8 @@ pub fn register_embedded_bridges() {
     vx_bridge::register_embedded_bridge("MSBuild", embedded_bridges::MSBUILD_BRIDGE_BYTES);
 }
 
-// All builtin providers are registered automatically from ALL_PROVIDER_STARS,
-// which is generated by build.rs at compile time by scanning the vx-providers/
+// All builtin providers are registered automatically from BUILTIN_PROVIDERS,
+// which vx-api generates at compile time by scanning the vx-providers/
 // directory. No manual list is needed — adding a provider.star file is enough.
 
 /// Create and initialize the provider registry with all available providers.
@@ -55,83 +52,18 @@ pub fn create_registry() -> ProviderRegistry {
 
 /// Build a registry using static registration only.
 ///
-/// All builtin providers are loaded from `ALL_PROVIDER_STARS`, which is
-/// generated at compile time by `build.rs` scanning the `vx-providers/`
-/// directory. Any provider name is supported — no Rust identifier constraints.
+/// Built-in providers, user/project providers added via `vx provider add`
+/// and asdf plugins added via `vx provider asdf` are registered lazily:
+/// providers are only materialized when a runtime is actually requested.
 fn create_static_registry() -> ProviderRegistry {
     let registry = ProviderRegistry::new();
-
-    // Register all builtin providers lazily from the compile-time generated array.
-    // build.rs scans vx-providers/*/provider.star and embeds them all here.
-    // Providers are only materialized when a runtime is actually requested.
-    for (name, star_content) in ALL_PROVIDER_STARS {
-        register_builtin_provider_lazy(&registry, name, star_content);
-    }
-
-    // User / project-level providers added via `vx provider add`
-    for (name, star_content) in load_star_overrides() {
-        register_dynamic_provider_lazy(&registry, name, star_content);
-    }
-
-    registry
-}
-
-fn register_builtin_provider_lazy(
-    registry: &ProviderRegistry,
-    name: &'static str,
-    star_content: &'static str,
-) {
-    let runtime_names = builtin_runtime_lookup_names(name);
-    registry.register_lazy(
-        name.to_string(),
-        runtime_names,
-        Box::new(move || vx_starlark::create_provider(name, star_content)),
+    wiring::register(
+        &registry,
+        true,
+        &load_star_overrides(),
+        &load_asdf_plugins(),
     );
-}
-
-/// Look up pre-computed runtime names for a builtin provider.
-///
-/// Uses the `PROVIDER_RUNTIME_NAMES` table generated at build time,
-/// avoiding a `StarMetadata::parse()` call per provider at startup.
-fn builtin_runtime_lookup_names(provider_name: &str) -> Vec<String> {
-    for (name, names) in PROVIDER_RUNTIME_NAMES {
-        if *name == provider_name {
-            return names.iter().map(|s| s.to_string()).collect();
-        }
-    }
-    // Fallback: parse at runtime (handles dynamic/user providers)
-    vec![provider_name.to_string()]
-}
-
-fn register_dynamic_provider_lazy(registry: &ProviderRegistry, name: String, star_content: String) {
-    let runtime_names = extract_runtime_lookup_names(&name, &star_content);
-    let provider_name = name.clone();
-
-    registry.register_lazy(
-        provider_name,
-        runtime_names,
-        Box::new(move || vx_starlark::create_provider(&name, &star_content)),
-    );
-}
-
-fn extract_runtime_lookup_names(provider_name: &str, star_content: &str) -> Vec<String> {
-    let meta = StarMetadata::parse(star_content);
-    let mut names = Vec::new();
-
-    for runtime in meta.runtimes {
-        if let Some(name) = runtime.name {
-            names.push(name);
-        }
-        names.extend(runtime.aliases);
-    }
-
-    if names.is_empty() {
-        names.push(provider_name.to_string());
-    }
-
-    names.sort();
-    names.dedup();
-    names
+    registry
 }
 
 /// Load user and project-level `provider.star` overrides.
@@ -139,42 +71,68 @@ fn extract_runtime_lookup_names(provider_name: &str, star_content: &str) -> Vec<
 /// Returns a list of `(name, star_content)` pairs from:
 /// 1. `~/.vx/providers/*/provider.star` (user-level)
 /// 2. `<project>/.vx/providers/*/provider.star` (project-level)
+/// 3. Scripts linked by a running `vx provider dev`
+///
+/// Scripts are validated and sandboxed by
+/// [`vx_starlark::discover_user_providers`]; invalid ones are skipped with a
+/// warning and reported by [`degraded_providers`]. Discovery runs once per
+/// process.
 pub fn load_star_overrides() -> Vec<(String, String)> {
-    let mut overrides = Vec::new();
+    static OVERRIDES: OnceLock<Vec<(String, String)>> = OnceLock::new();
+    OVERRIDES
+        .get_or_init(|| {
+            wiring::discover_user_providers(
+                &user_provider_roots(),
+                dev_provider_links_dir().as_deref(),
+            )
+        })
+        .clone()
+}
 
-    // User-level: ~/.vx/providers
-    if let Ok(paths) = VxPaths::new() {
-        let user_dir = paths.base_dir.join("providers");
-        collect_star_files(&user_dir, &mut overrides);
-    }
+/// asdf plugins from `~/.vx/asdf-plugins`
+///
+/// Plugins named like a built-in or `provider.star` runtime are skipped, so
+/// they never shadow a native provider. Empty when the machine policy
+/// disables user providers. Discovery runs once per process.
+pub fn load_asdf_plugins() -> Vec<AsdfPlugin> {
+    static PLUGINS: OnceLock<Vec<AsdfPlugin>> = OnceLock::new();
+    PLUGINS
+        .get_or_init(|| {
+            if crate::commands::common::machine_policy().is_some_and(|p| !p.allows_user_providers())
+            {
+                return Vec::new();
+            }
+            let Ok(paths) = VxPaths::new() else {
+                return Vec::new();
+            };
+            wiring::asdf_plugins(&paths.asdf_plugins_dir, true, &load_star_overrides())
+        })
+        .clone()
+}
 
-    // Project-level: <project>/.vx/providers
-    if let Ok(cwd) = std::env::current_dir()
-        && let Some(project_root) = find_project_root(&cwd)
-    {
-        let project_dir = project_root.join(PROJECT_VX_DIR).join("providers");
-        collect_star_files(&project_dir, &mut overrides);
+/// Directories scanned for user providers, lowest precedence first
+///
+/// Empty when the machine policy sets `providers.user_providers = false`.
+pub fn user_provider_roots() -> Vec<std::path::PathBuf> {
+    if crate::commands::common::machine_policy().is_some_and(|p| !p.allows_user_providers()) {
+        tracing::debug!("User providers disabled by machine policy");
+        return Vec::new();
     }
-
-    overrides
+    VxPaths::new()
+        .map(|paths| wiring::user_provider_roots(&paths.base_dir))
+        .unwrap_or_default()
 }
 
-fn collect_star_files(dir: &std::path::Path, out: &mut Vec<(String, String)>) {
-    if !dir.exists() {
-        return;
-    }
-    let Ok(entries) = std::fs::read_dir(dir) else {
-        return;
-    };
-    for entry in entries.flatten() {
-        let star_path = entry.path().join("provider.star");
-        if star_path.exists()
-            && let Ok(content) = std::fs::read_to_string(&star_path)
-        {
-            let name = entry.file_name().to_string_lossy().to_string();
-            out.push((name, content));
-        }
-    }
+/// Directory holding the links of `vx provider dev` sessions
+///
+/// `None` when the machine policy disables user providers.
+pub fn dev_provider_links_dir() -> Option<std::path::PathBuf> {
+    if crate::commands::common::machine_policy().is_some_and(|p| !p.allows_user_providers()) {
+        return None;
+    }
+    VxPaths::new()
+        .ok()
+        .map(|paths| wiring::dev_provider_links_dir(&paths.base_dir))
 }
 
 /// Initialize the global ProviderHandle registry with all built-in providers (RFC-0037)
@@ -189,62 +147,23 @@ fn collect_star_files(dir: &std::path::Path, out: &mut Vec<(String, String)>) {
 ///
 /// Should be called once at CLI startup, before any command is dispatched.
 async fn init_provider_handles_inner() {
-    use vx_starlark::handle::{ProviderHandle, global_registry_mut};
-
-    // Build all ProviderHandles concurrently before acquiring the write lock.
-    // This moves the Starlark parsing work (CPU-bound) out of the critical section.
-    let mut futures: Vec<tokio::task::JoinHandle<(&str, Result<ProviderHandle, _>)>> = Vec::new();
-
-    for (name, star_content) in ALL_PROVIDER_STARS {
-        let name: &'static str = name;
-        let star_content: &'static str = star_content;
-        futures.push(tokio::task::spawn(async move {
-            let result = ProviderHandle::from_content(name, star_content).await;
-            (name, result)
-        }));
-    }
-
-    // Collect all built handles (parallel resolution)
-    let mut built_handles = Vec::with_capacity(ALL_PROVIDER_STARS.len());
-    for fut in futures {
-        if let Ok((name, result)) = fut.await {
-            built_handles.push((name, result));
-        }
-    }
-
-    // Acquire write lock once and batch-insert all handles
-    let mut reg = global_registry_mut().await;
-    for (name, result) in built_handles {
-        match result {
-            Ok(handle) => {
-                reg.insert_handle(handle);
-                trace!(provider = %name, "Registered ProviderHandle");
-            }
-            Err(e) => {
-                tracing::warn!(
-                    provider = %name,
-                    error = %e,
-                    "Failed to register ProviderHandle — provider.star may have errors"
-                );
-            }
-        }
-    }
+    wiring::init_handles(true, &load_star_overrides()).await;
+}
 
-    // 2. User / project-level overrides (vx provider add) — serial, few entries
-    for (name, star_content) in load_star_overrides() {
-        match reg.register_dynamic(&name, star_content).await {
-            Ok(()) => {
-                trace!(provider = %name, "Registered user ProviderHandle");
-            }
-            Err(e) => {
-                tracing::warn!(
-                    provider = %name,
-                    error = %e,
-                    "Failed to register user ProviderHandle — provider.star may have errors"
-                );
-            }
-        }
+/// Providers that failed to initialize, with the underlying error
+///
+/// Covers factories that panicked in `registry`, `provider.star` files whose
+/// `ProviderHandle` could not be built, and user providers skipped during
+/// discovery. The rest of the registry keeps working without them; this is
+/// what `vx doctor` and `vx provider list` report as degraded.
+pub async fn degraded_providers(registry: &ProviderRegistry) -> Vec<ProviderFailure> {
+    load_star_overrides();
+    load_asdf_plugins();
+    ensure_provider_handles_initialized().await;
+    for failure in wiring::load_failures() {
+        registry.record_failure(failure);
     }
+    registry.failed_providers()
 }
 
 pub async fn init_provider_handles() {
@@ -265,19 +184,7 @@ pub async fn ensure_provider_handles_initialized() {
 /// Constraints are applied to the runtime whose name matches the provider,
 /// or the first runtime if no name matches.
 pub fn init_constraints_registry() {
-    let mut rules: HashMap<String, Vec<ConstraintRule>> = HashMap::new();
-
-    for (name, star_content) in ALL_PROVIDER_STARS {
-        collect_constraints_from_star(name, star_content, &mut rules);
-    }
-
-    for (name, star_content) in load_star_overrides() {
-        collect_constraints_from_star(&name, &star_content, &mut rules);
-    }
-
-    if let Err(e) = init_constraints_from_star(rules.into_iter().collect()) {
-        tracing::warn!("Failed to initialize constraints registry: {}", e);
-    }
+    wiring::init_constraints(true, &load_star_overrides());
 }
 
 pub fn ensure_constraints_registry_initialized() {
@@ -298,7 +205,7 @@ static PACKAGE_ALIAS_CACHE: OnceLock<HashMap<String, PackageAlias>> = OnceLock::
 fn build_package_alias_cache() -> HashMap<String, PackageAlias> {
     let mut cache = HashMap::new();
 
-    for (name, star_content) in ALL_PROVIDER_STARS {
+    for (name, star_content) in builtin_provider_stars() {
         let meta = StarMetadata::parse(star_content);
         if let Some((ecosystem, package)) = &meta.package_alias {
             let alias = PackageAlias {
@@ -355,207 +262,378 @@ pub fn find_package_alias(runtime_name: &str) -> Option<PackageAlias> {
     cache.get(runtime_name).cloned()
 }
 
-/// Cached runtime names list.
-static RUNTIME_NAMES_CACHE: OnceLock<Vec<String>> = OnceLock::new();
+/// Cached tool bundle lookup table (`tool_bundle = [...]`), keyed by provider
+/// and runtime names.
+static TOOL_BUNDLE_CACHE: OnceLock<HashMap<String, Vec<String>>> = OnceLock::new();
 
-pub fn available_runtime_names() -> Vec<String> {
-    RUNTIME_NAMES_CACHE
-        .get_or_init(|| {
-            // Use pre-computed table from build.rs — no StarMetadata::parse() needed
-            let mut names: Vec<String> = PROVIDER_RUNTIME_NAMES
-                .iter()
-                .flat_map(|(_, runtime_names)| runtime_names.iter().map(|s| s.to_string()))
-                .collect();
-
-            // Include names from dynamic (user/project) overrides
-            for (name, star_content) in load_star_overrides() {
-                names.extend(extract_runtime_lookup_names(&name, &star_content));
-            }
+fn build_tool_bundle_cache() -> HashMap<String, Vec<String>> {
+    let mut cache = HashMap::new();
 
-            names.sort();
-            names.dedup();
-            names
-        })
-        .clone()
-}
+    let builtin = builtin_provider_stars()
+        .map(|(name, content)| (name.to_string(), StarMetadata::parse(content)));
+    let overrides = load_star_overrides()
+        .into_iter()
+        .map(|(name, content)| (name, StarMetadata::parse(&content)));
 
-fn collect_constraints_from_star(
-    provider_name: &str,
-    content: &str,
-    out: &mut HashMap<String, Vec<ConstraintRule>>,
-) {
-    // Fast pre-filter: skip providers that don't mention "constraints" at all.
-    // This avoids creating a Starlark engine + full AST evaluation for the ~90%
-    // of providers that have no constraints, saving significant startup time.
-    if !content.contains("constraints") {
-        return;
+    for (name, meta) in builtin.chain(overrides) {
+        if meta.tool_bundle.is_empty() {
+            continue;
+        }
+        cache.insert(meta.name.clone().unwrap_or(name), meta.tool_bundle.clone());
+        for runtime in &meta.runtimes {
+            if let Some(ref runtime_name) = runtime.name {
+                cache.insert(runtime_name.clone(), meta.tool_bundle.clone());
+            }
+            for a in &runtime.aliases {
+                cache.insert(a.clone(), meta.tool_bundle.clone());
+            }
+        }
     }
 
-    let engine = StarlarkEngine::new();
-    let script_path = Path::new(provider_name);
+    cache
+}
 
-    let Ok(Some(value)) = engine.get_variable(script_path, content, "constraints") else {
-        return;
-    };
+/// Member tools of a bundle provider, when `name` is one
+pub fn find_tool_bundle(name: &str) -> Option<Vec<String>> {
+    let cache = TOOL_BUNDLE_CACHE.get_or_init(build_tool_bundle_cache);
+    cache.get(name).cloned()
+}
 
-    let meta = StarMetadata::parse(content);
-    let runtime_names: Vec<String> = meta
-        .runtimes
-        .iter()
-        .filter_map(|r| r.name.clone())
-        .collect();
+/// Cached system package lookup table (`brew_install(...)`, `scoop_install(...)`, ...).
+static SYSTEM_PACKAGE_CACHE: OnceLock<HashMap<String, Vec<(String, String)>>> = OnceLock::new();
 
-    let default_runtime = if runtime_names.iter().any(|n| n == provider_name) {
-        Some(provider_name.to_string())
-    } else {
-        runtime_names.first().cloned()
-    };
+fn build_system_package_cache() -> HashMap<String, Vec<(String, String)>> {
+    let mut cache = HashMap::new();
 
-    let Some(default_runtime) = default_runtime else {
-        return;
-    };
+    let builtin = builtin_provider_stars()
+        .map(|(name, content)| (name.to_string(), StarMetadata::parse(content)));
+    let overrides = load_star_overrides()
+        .into_iter()
+        .map(|(name, content)| (name, StarMetadata::parse(&content)));
 
-    for (runtime, rules) in parse_constraints_value(&value, &default_runtime) {
-        out.entry(runtime).or_default().extend(rules);
+    for (name, meta) in builtin.chain(overrides) {
+        if meta.system_packages.is_empty() {
+            continue;
+        }
+        cache.insert(
+            meta.name.clone().unwrap_or(name),
+            meta.system_packages.clone(),
+        );
+        for runtime in &meta.runtimes {
+            if let Some(ref runtime_name) = runtime.name {
+                cache.insert(runtime_name.clone(), meta.system_packages.clone());
+            }
+            for a in &runtime.aliases {
+                cache.insert(a.clone(), meta.system_packages.clone());
+            }
+        }
     }
+
+    cache
 }
 
-fn parse_constraints_value(
-    value: &serde_json::Value,
-    default_runtime: &str,
-) -> Vec<(String, Vec<ConstraintRule>)> {
-    let Some(entries) = value.as_array() else {
-        return Vec::new();
-    };
+/// Find the package a provider declares for a system package manager
+/// (`"brew"`, `"scoop"`, `"winget"`, ...).
+pub fn find_system_package(runtime_name: &str, manager: &str) -> Option<String> {
+    let cache = SYSTEM_PACKAGE_CACHE.get_or_init(build_system_package_cache);
+    cache
+        .get(runtime_name)?
+        .iter()
+        .find(|(m, _)| m == manager)
+        .map(|(_, p)| p.clone())
+}
 
-    let mut rules_by_runtime: HashMap<String, Vec<ConstraintRule>> = HashMap::new();
+/// Where a provider publishes its sources and release notes.
+#[derive(Debug, Clone, Default)]
+pub struct ReleaseSources {
+    /// Project homepage (`homepage = "..."`)
+    pub homepage: Option<String>,
+    /// Source repository URL (`repository = "..."`)
+    pub repository: Option<String>,
+    /// Changelog URL template (`changelog = "..."`)
+    pub changelog: Option<String>,
+    /// License of the tool (`license = "..."`)
+    pub license: Option<String>,
+}
 
-    for entry in entries {
-        let Some(obj) = entry.as_object() else {
-            continue;
+/// Cached release-notes sources, keyed by provider, runtime and alias names.
+static RELEASE_SOURCES_CACHE: OnceLock<HashMap<String, ReleaseSources>> = OnceLock::new();
+
+fn build_release_sources_cache() -> HashMap<String, ReleaseSources> {
+    let mut cache = HashMap::new();
+
+    let builtin = builtin_provider_stars()
+        .map(|(name, content)| (name.to_string(), StarMetadata::parse(content)));
+    let overrides = load_star_overrides()
+        .into_iter()
+        .map(|(name, content)| (name, StarMetadata::parse(&content)));
+
+    for (name, meta) in builtin.chain(overrides) {
+        let sources = ReleaseSources {
+            homepage: meta.homepage.clone(),
+            repository: meta.repository.clone(),
+            changelog: meta.changelog.clone(),
+            license: meta.license.clone(),
         };
+        cache.insert(meta.name.clone().unwrap_or(name), sources.clone());
+        for runtime in &meta.runtimes {
+            if let Some(ref runtime_name) = runtime.name {
+                cache.insert(runtime_name.clone(), sources.clone());
+            }
+            for a in &runtime.aliases {
+                cache.insert(a.clone(), sources.clone());
+            }
+        }
+    }
 
-        let when = obj.get("when").and_then(|v| v.as_str()).unwrap_or("*");
+    cache
+}
 
-        let runtime_name = obj
-            .get("runtime")
-            .and_then(|v| v.as_str())
-            .unwrap_or(default_runtime)
-            .to_string();
+/// Find the homepage, repository, changelog URL and license a provider declares for a runtime.
+pub fn find_release_sources(runtime_name: &str) -> Option<ReleaseSources> {
+    let cache = RELEASE_SOURCES_CACHE.get_or_init(build_release_sources_cache);
+    cache.get(runtime_name).cloned()
+}
 
-        let mut constraints: Vec<DependencyConstraint> = Vec::new();
+/// Cached end-of-life schedules, keyed by provider, runtime and alias names.
+static EOL_SCHEDULE_CACHE: OnceLock<HashMap<String, Vec<(String, String)>>> = OnceLock::new();
 
-        if let Some(reqs) = obj.get("requires").and_then(|v| v.as_array()) {
-            for req in reqs {
-                if let Some(dep) = parse_constraint_dep(req, false) {
-                    constraints.push(dep);
-                }
+fn build_eol_schedule_cache() -> HashMap<String, Vec<(String, String)>> {
+    let mut cache = HashMap::new();
+
+    let builtin = builtin_provider_stars()
+        .map(|(name, content)| (name.to_string(), StarMetadata::parse(content)));
+    let overrides = load_star_overrides()
+        .into_iter()
+        .map(|(name, content)| (name, StarMetadata::parse(&content)));
+
+    for (name, meta) in builtin.chain(overrides) {
+        if meta.eol.is_empty() {
+            continue;
+        }
+        let provider = meta.name.clone().unwrap_or(name);
+        // The schedule is for the provider's own runtime, not the ones it
+        // bundles (npm versions are not Node.js versions)
+        for runtime in &meta.runtimes {
+            if runtime.name.as_deref() != Some(provider.as_str()) {
+                continue;
+            }
+            for a in &runtime.aliases {
+                cache.insert(a.clone(), meta.eol.clone());
             }
         }
+        cache.insert(provider, meta.eol.clone());
+    }
 
-        if let Some(reqs) = obj.get("recommends").and_then(|v| v.as_array()) {
-            for req in reqs {
-                if let Some(dep) = parse_constraint_dep(req, true) {
-                    constraints.push(dep);
-                }
+    cache
+}
+
+/// Find the end-of-life schedule a provider declares for a runtime (`eol = {...}`).
+pub fn find_eol_schedule(runtime_name: &str) -> Option<crate::eol::EolSchedule> {
+    let cache = EOL_SCHEDULE_CACHE.get_or_init(build_eol_schedule_cache);
+    cache
+        .get(runtime_name)
+        .map(|pairs| crate::eol::EolSchedule::from_pairs(pairs))
+}
+
+/// Cached runtime name → OSV package `(ecosystem, name)`.
+static OSV_PACKAGE_CACHE: OnceLock<HashMap<String, (String, String)>> = OnceLock::new();
+
+fn build_osv_package_cache() -> HashMap<String, (String, String)> {
+    let mut cache = HashMap::new();
+
+    let builtin = builtin_provider_stars()
+        .map(|(name, content)| (name.to_string(), StarMetadata::parse(content)));
+    let overrides = load_star_overrides()
+        .into_iter()
+        .map(|(name, content)| (name, StarMetadata::parse(&content)));
+
+    for (name, meta) in builtin.chain(overrides) {
+        let Some(package) = meta.osv else {
+            continue;
+        };
+        let provider = meta.name.clone().unwrap_or(name);
+        // Like EOL schedules, the package describes the provider's own runtime
+        for runtime in &meta.runtimes {
+            if runtime.name.as_deref() != Some(provider.as_str()) {
+                continue;
+            }
+            for a in &runtime.aliases {
+                cache.insert(a.clone(), package.clone());
             }
         }
+        cache.insert(provider, package);
+    }
+
+    cache
+}
+
+/// OSV package of a runtime, from `osv = {...}` in its provider.star.
+pub fn find_osv_package(runtime_name: &str) -> Option<vx_runtime::OsvPackage> {
+    let cache = OSV_PACKAGE_CACHE.get_or_init(build_osv_package_cache);
+    cache
+        .get(runtime_name)
+        .map(|(ecosystem, name)| vx_runtime::OsvPackage {
+            ecosystem: ecosystem.clone(),
+            name: name.clone(),
+        })
+}
+
+/// Cached version normalization rules, keyed by provider, runtime and alias names.
+static VERSION_FORMAT_CACHE: OnceLock<HashMap<String, vx_versions::VersionNormalizer>> =
+    OnceLock::new();
+
+fn build_version_format_cache() -> HashMap<String, vx_versions::VersionNormalizer> {
+    let mut cache = HashMap::new();
+
+    let builtin = builtin_provider_stars()
+        .map(|(name, content)| (name.to_string(), StarMetadata::parse(content)));
+    let overrides = load_star_overrides()
+        .into_iter()
+        .map(|(name, content)| (name, StarMetadata::parse(&content)));
 
-        if constraints.is_empty() {
+    for (name, meta) in builtin.chain(overrides) {
+        if meta.version_format.is_empty() {
             continue;
         }
-
-        let mut rule = ConstraintRule::with_manifest_pattern(ManifestVersionPattern::new(when));
-        for constraint in constraints {
-            rule = rule.with_constraint(constraint);
+        let rules = vx_versions::VersionNormalizer::from_pairs(&meta.version_format);
+        // Bundled runtimes (bunx) share the versions of the provider's runtime
+        for runtime in &meta.runtimes {
+            if let Some(ref runtime_name) = runtime.name {
+                cache.insert(runtime_name.clone(), rules.clone());
+            }
+            for a in &runtime.aliases {
+                cache.insert(a.clone(), rules.clone());
+            }
         }
-
-        rules_by_runtime.entry(runtime_name).or_default().push(rule);
+        cache.insert(meta.name.clone().unwrap_or(name), rules);
     }
 
-    rules_by_runtime.into_iter().collect()
+    cache
 }
 
-fn parse_constraint_dep(
-    value: &serde_json::Value,
-    force_optional: bool,
-) -> Option<DependencyConstraint> {
-    let obj = value.as_object()?;
-    let runtime = obj.get("runtime")?.as_str()?.to_string();
-    let version = obj.get("version").and_then(|v| v.as_str()).unwrap_or("*");
+/// Version normalization rules of a runtime, from `version_format = {...}` in
+/// its provider.star; runtimes without rules only strip the `v` prefix.
+pub fn find_version_normalizer(runtime_name: &str) -> vx_versions::VersionNormalizer {
+    let cache = VERSION_FORMAT_CACHE.get_or_init(build_version_format_cache);
+    cache.get(runtime_name).cloned().unwrap_or_default()
+}
 
-    let (min, max) = parse_version_min_max(version);
-    let mut constraint = DependencyConstraint::required(runtime);
+/// Cached runtime names list.
+static RUNTIME_NAMES_CACHE: OnceLock<Vec<String>> = OnceLock::new();
 
-    if let Some(min) = min {
-        constraint = constraint.min(min);
-    }
-    if let Some(max) = max {
-        constraint = constraint.max(max);
-    }
+pub fn available_runtime_names() -> Vec<String> {
+    RUNTIME_NAMES_CACHE
+        .get_or_init(|| {
+            let mut names = native_runtime_names();
+            names.extend(load_asdf_plugins().iter().map(|p| p.name().to_string()));
+            names.sort();
+            names.dedup();
+            names
+        })
+        .clone()
+}
 
-    if let Some(rec) = obj.get("recommended").and_then(|v| v.as_str()) {
-        constraint = constraint.recommended(rec);
-    }
+/// Runtime names of built-in and `provider.star` providers
+fn native_runtime_names() -> Vec<String> {
+    // Use pre-computed table from build.rs — no StarMetadata::parse() needed
+    let mut names: Vec<String> = BUILTIN_PROVIDERS
+        .iter()
+        .flat_map(|(_, runtime_names, _)| runtime_names.iter().map(|s| s.to_string()))
+        .collect();
 
-    if let Some(reason) = obj.get("reason").and_then(|v| v.as_str()) {
-        constraint = constraint.reason(reason);
+    // Include names from dynamic (user/project) overrides
+    for (name, star_content) in load_star_overrides() {
+        names.extend(wiring::runtime_lookup_names(&name, &star_content));
     }
 
-    let optional = obj
-        .get("optional")
-        .and_then(|v| v.as_bool())
-        .unwrap_or(false)
-        || force_optional;
+    names.sort();
+    names.dedup();
+    names
+}
 
-    if optional {
-        constraint.optional = true;
-    }
+static REGISTRY_INDEX: OnceLock<Arc<RegistryIndex>> = OnceLock::new();
 
-    Some(constraint)
-}
-
-fn parse_version_min_max(version_req: &str) -> (Option<String>, Option<String>) {
-    let req = VersionRequest::parse(version_req);
-    match &req.constraint {
-        VersionConstraint::Range(constraints) => {
-            let mut min = None;
-            let mut max = None;
-            for c in constraints {
-                match c.op {
-                    RangeOp::Gte | RangeOp::Gt => {
-                        min = Some(c.version.to_string());
-                    }
-                    RangeOp::Lte | RangeOp::Lt => {
-                        max = Some(c.version.to_string());
-                    }
-                    _ => {}
-                }
+/// Indexed metadata of all runtimes, for `vx list`, `vx search` and suggestions.
+///
+/// Read from `~/.vx/cache/registry.idx`, so these commands don't parse every
+/// `provider.star`. The index is built from `registry` on first run, and
+/// rebuilt when vx, the user/project providers or the asdf plugins change.
+pub fn registry_index(registry: &ProviderRegistry) -> Arc<RegistryIndex> {
+    REGISTRY_INDEX
+        .get_or_init(|| {
+            let key = registry_index_key();
+            let platform = Platform::current().as_str().to_string();
+            let cache_dir = VxPaths::new().ok().map(|paths| paths.cache_dir);
+
+            if let Some(index) = cache_dir
+                .as_deref()
+                .and_then(|dir| RegistryIndex::load(dir, &key, &platform))
+            {
+                trace!(runtimes = index.len(), "Loaded registry index");
+                return Arc::new(index);
             }
-            (min, max)
-        }
-        VersionConstraint::Caret(v) => {
-            let min = v.to_string();
-            let max = if v.major > 0 {
-                format!("{}.0.0", v.major + 1)
-            } else if v.minor > 0 {
-                format!("0.{}.0", v.minor + 1)
-            } else {
-                format!("0.0.{}", v.patch + 1)
-            };
-            (Some(min), Some(max))
-        }
-        VersionConstraint::Tilde(v) => {
-            let min = v.to_string();
-            let max = format!("{}.{}.0", v.major, v.minor + 1);
-            (Some(min), Some(max))
-        }
-        VersionConstraint::Exact(v) => {
-            let ver = v.to_string();
-            (Some(ver.clone()), Some(ver))
-        }
-        _ => (None, None),
+
+            let index = build_registry_index(registry, key, platform);
+            if let Some(dir) = cache_dir
+                && let Err(e) = index.save(&dir)
+            {
+                tracing::debug!(error = %e, "Failed to save registry index");
+            }
+            Arc::new(index)
+        })
+        .clone()
+}
+
+/// Build the registry index by loading every provider
+pub fn build_registry_index(
+    registry: &ProviderRegistry,
+    key: String,
+    platform: String,
+) -> RegistryIndex {
+    let current_platform = Platform::current();
+    let runtimes = registry
+        .runtime_names()
+        .into_iter()
+        .filter_map(|name| {
+            let runtime = registry.get_runtime(&name)?;
+            let metadata = runtime.metadata();
+            Some(IndexedRuntime {
+                aliases: runtime.aliases().iter().map(|a| a.to_string()).collect(),
+                description: runtime.description().to_string(),
+                ecosystem: metadata.get("ecosystem").cloned(),
+                bundled_with: metadata.get("bundled_with").cloned(),
+                platform_supported: runtime.is_platform_supported(&current_platform),
+                name,
+            })
+        })
+        .collect();
+    RegistryIndex::new(key, platform, runtimes)
+}
+
+/// Key identifying the providers the registry index is built from
+fn registry_index_key() -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(env!("CARGO_PKG_VERSION"));
+    hasher.update(BUILTIN_PROVIDERS_DIGEST);
+    for (name, star_content) in load_star_overrides() {
+        hasher.update([0]);
+        hasher.update(&name);
+        hasher.update([0]);
+        hasher.update(&star_content);
+    }
+    for plugin in load_asdf_plugins() {
+        hasher.update([0]);
+        hasher.update(plugin.name());
     }
+    hasher
+        .finalize()
+        .iter()
+        .fold(String::with_capacity(64), |mut acc, b| {
+            use std::fmt::Write;
+            let _ = write!(acc, "{b:02x}");
+            acc
+        })
 }
 
 /// Build a RuntimeMap from the global ProviderHandle registry (RFC-0037)
@@ -566,48 +644,7 @@ fn parse_version_min_max(version_req: &str) -> (Option<String>, Option<String>)
 ///
 /// Falls back to an empty RuntimeMap if the registry is not yet initialized.
 pub fn build_runtime_map() -> vx_resolver::RuntimeMap {
-    use vx_resolver::{RuntimeDependency, RuntimeMap, RuntimeSpec};
-
-    let Ok(registry) = vx_starlark::handle::GLOBAL_REGISTRY.try_read() else {
-        return RuntimeMap::empty();
-    };
-
-    let mut map = RuntimeMap::empty();
-
-    for (_name, handle) in registry.iter() {
-        for runtime_meta in handle.runtime_metas() {
-            let mut spec = RuntimeSpec::new(&runtime_meta.name, &runtime_meta.description);
-            spec.executable = Some(runtime_meta.executable.clone());
-            spec.aliases = runtime_meta.aliases.clone();
-            spec.priority = runtime_meta.priority as i32;
-            spec.command_prefix = runtime_meta.command_prefix.clone();
-
-            // Handle bundled_with: add a provided_by dependency so the resolver
-            // looks in the parent runtime's store directory for this executable.
-            // e.g., uvx is bundled_with uv → look in ~/.vx/store/uv/ for uvx
-            if let Some(ref bundled_with) = runtime_meta.bundled_with {
-                let dep = RuntimeDependency::required(
-                    bundled_with.clone(),
-                    format!("{} is bundled with {}", runtime_meta.name, bundled_with),
-                )
-                .provided_by(bundled_with.clone());
-                spec.dependencies.push(dep);
-            }
-
-            // Register system_paths glob patterns for detection
-            // (e.g., MSVC cl.exe paths that are not on system PATH)
-            if !runtime_meta.system_paths.is_empty() {
-                map.register_system_paths(
-                    runtime_meta.name.clone(),
-                    runtime_meta.system_paths.clone(),
-                );
-            }
-
-            map.register(spec);
-        }
-    }
-
-    map
+    wiring::runtime_map(&load_asdf_plugins())
 }
 
 /// Get platform label for a runtime from the ProviderHandle registry.
@@ -617,7 +654,7 @@ pub fn build_runtime_map() -> vx_resolver::RuntimeMap {
 /// This is a best-effort synchronous lookup; for full accuracy use the async API.
 pub fn get_runtime_platform_label(runtime_name: &str) -> Option<String> {
     // Try to get from ProviderHandle registry (provider.star)
-    // This is a sync wrapper — for now we check ALL_PROVIDER_STARS metadata
+    // This is a sync wrapper — for now we check BUILTIN_PROVIDERS metadata
     // A full implementation would query ProviderMeta.platforms from the handle
     let _ = runtime_name;
     None
@@ -691,7 +728,7 @@ mod tests {
     #[test]
     fn test_embedded_stars_exist() {
         assert!(
-            !ALL_PROVIDER_STARS.is_empty(),
+            !BUILTIN_PROVIDERS.is_empty(),
             "Expected embedded provider.star files, found none"
         );
     }
@@ -704,4 +741,25 @@ mod tests {
             "Registry should have providers"
         );
     }
+
+    #[test]
+    fn test_build_registry_index() {
+        let registry = create_registry();
+        let index = build_registry_index(&registry, "key".to_string(), "linux-x64".to_string());
+        assert_eq!(index.len(), registry.runtime_names().len());
+
+        let npm = index.get("npm").expect("npm should be indexed");
+        assert_eq!(npm.bundled_with.as_deref(), Some("node"));
+        assert!(
+            index
+                .get("node")
+                .is_some_and(|node| !node.description.is_empty())
+        );
+    }
+
+    #[test]
+    fn test_registry_index_key_is_stable() {
+        assert_eq!(registry_index_key(), registry_index_key());
+        assert_eq!(registry_index_key().len(), 64);
+    }
 }
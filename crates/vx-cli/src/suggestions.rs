@@ -4,7 +4,10 @@
 //! - Tool name aliases (e.g., "rust" -> "cargo", "python" -> "uv")
 //! - Fuzzy matching using Levenshtein distance for typo suggestions
 //! - GitHub issue links for unsupported tool requests
+//! - Structured [`Suggestion`]s attached to errors, rendered as hints in text
+//!   output and as objects in `--format json` error payloads
 
+use serde::Serialize;
 use strsim::levenshtein;
 
 /// GitHub repository for issue creation
@@ -49,6 +52,82 @@ const TOOL_ALIASES: &[(&str, &str, &str)] = &[
     ("golang", "go", "Go programming language"),
 ];
 
+/// An actionable fix attached to an error
+///
+/// Serialized with a `kind` tag so wrappers (IDE plugins, MCP/REST surfaces)
+/// can offer the fix without parsing hint text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Suggestion {
+    /// A command to run
+    Command {
+        command: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+    },
+    /// A configuration key to set (`vx config set <key> <value>`)
+    Config {
+        key: String,
+        value: String,
+        description: String,
+    },
+    /// A documentation or issue page
+    Docs { url: String, description: String },
+    /// Advice without a concrete action
+    Hint { message: String },
+}
+
+impl Suggestion {
+    /// A command that fixes the error on its own
+    pub fn run(command: impl Into<String>) -> Self {
+        Self::Command {
+            command: command.into(),
+            description: None,
+        }
+    }
+
+    /// A command with a short explanation of what it does
+    pub fn command(command: impl Into<String>, description: impl Into<String>) -> Self {
+        Self::Command {
+            command: command.into(),
+            description: Some(description.into()),
+        }
+    }
+
+    /// A configuration key to set
+    pub fn config(
+        key: impl Into<String>,
+        value: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self::Config {
+            key: key.into(),
+            value: value.into(),
+            description: description.into(),
+        }
+    }
+
+    /// A page to read
+    pub fn docs(url: impl Into<String>, description: impl Into<String>) -> Self {
+        Self::Docs {
+            url: url.into(),
+            description: description.into(),
+        }
+    }
+
+    /// Free-text advice
+    pub fn hint(message: impl Into<String>) -> Self {
+        Self::Hint {
+            message: message.into(),
+        }
+    }
+}
+
+/// URL of a page in the repository's `docs/` directory
+pub fn docs_url(page: &str) -> String {
+    format!("{}/blob/main/docs/{}", GITHUB_REPO, page)
+}
+
 /// Represents a tool suggestion with context
 #[derive(Debug, Clone)]
 pub struct ToolSuggestion {
@@ -60,6 +139,18 @@ pub struct ToolSuggestion {
     pub is_alias: bool,
 }
 
+impl ToolSuggestion {
+    /// The suggestion as a command to run instead
+    pub fn to_suggestion(&self) -> Suggestion {
+        let description = if self.description.is_empty() {
+            format!("Did you mean '{}'?", self.suggested_tool)
+        } else {
+            self.description.clone()
+        };
+        Suggestion::command(format!("vx {}", self.suggested_tool), description)
+    }
+}
+
 /// Get suggestions for an unknown tool name
 ///
 /// Returns suggestions based on:
@@ -113,6 +204,25 @@ pub fn get_tool_suggestions(unknown_tool: &str, available_tools: &[String]) -> V
     suggestions
 }
 
+/// Structured suggestions for an unknown tool name
+///
+/// Similar tools first, then `vx list` and a link to request support.
+pub fn tool_not_found_suggestions(
+    unknown_tool: &str,
+    available_tools: &[String],
+) -> Vec<Suggestion> {
+    let mut suggestions: Vec<Suggestion> = get_tool_suggestions(unknown_tool, available_tools)
+        .iter()
+        .map(ToolSuggestion::to_suggestion)
+        .collect();
+    suggestions.push(Suggestion::command("vx list", "See all supported tools"));
+    suggestions.push(Suggestion::docs(
+        get_feature_request_url(unknown_tool),
+        format!("Request support for '{}'", unknown_tool),
+    ));
+    suggestions
+}
+
 /// Generate a GitHub issue URL for requesting a new tool
 pub fn get_feature_request_url(tool_name: &str) -> String {
     let title = format!("Feature Request: Support for '{}'", tool_name);
@@ -225,6 +335,38 @@ mod tests {
         assert!(suggestions.is_empty());
     }
 
+    #[test]
+    fn test_tool_not_found_suggestions() {
+        let available = vec!["cargo".to_string(), "node".to_string()];
+        let suggestions = tool_not_found_suggestions("nod", &available);
+
+        assert_eq!(
+            suggestions[0],
+            Suggestion::command("vx node", "Did you mean 'node'?")
+        );
+        assert_eq!(
+            suggestions[1],
+            Suggestion::command("vx list", "See all supported tools")
+        );
+        assert!(matches!(&suggestions[2], Suggestion::Docs { url, .. } if url.contains("nod")));
+    }
+
+    #[test]
+    fn test_suggestion_serializes_with_kind() {
+        let json = serde_json::to_value(Suggestion::config(
+            "defaults.auto_install",
+            "true",
+            "Enable auto-install",
+        ))
+        .unwrap();
+        assert_eq!(json["kind"], "config");
+        assert_eq!(json["key"], "defaults.auto_install");
+
+        let json = serde_json::to_value(Suggestion::run("vx install node")).unwrap();
+        assert_eq!(json["kind"], "command");
+        assert!(json.get("description").is_none());
+    }
+
     #[test]
     fn test_feature_request_url() {
         let url = get_feature_request_url("mytool");
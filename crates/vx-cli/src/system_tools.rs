@@ -402,9 +402,8 @@ fn get_tool_category(runtime_name: &str) -> String {
         // System
         "systemctl" | "journalctl" | "launchctl" | "loginctl" | "systemd-analyze" => "system",
         // Package managers
-        "choco" | "brew" | "apt" | "apt-get" | "yum" | "dnf" | "pacman" | "winget" | "scoop" => {
-            "package"
-        }
+        "choco" | "brew" | "apt" | "apt-get" | "yum" | "dnf" | "pacman" | "zypper" | "apk"
+        | "nix" | "winget" | "scoop" => "package",
         // Version control
         "git" | "svn" | "hg" | "git-lfs" => "vcs",
         // Container
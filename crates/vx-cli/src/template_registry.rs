@@ -0,0 +1,371 @@
+//! Remote project template registry for `vx init --template`
+//!
+//! Organizations can publish project templates in a template index and point
+//! vx at it in `~/.vx/config/templates.toml` (or with `VX_TEMPLATE_INDEX`):
+//!
+//! ```toml
+//! [registry]
+//! # HTTPS index file
+//! index = "https://templates.corp.example/vx/index.toml"
+//! # or a git repository with index.toml at its root
+//! # index = "git+https://github.com/acme/vx-templates.git"
+//! ```
+//!
+//! The index lists templates; each template is a `vx.toml` with `{{name}}`
+//! placeholders, located relative to the index:
+//!
+//! ```toml
+//! [[templates]]
+//! name = "acme-service"
+//! description = "ACME backend service"
+//! path = "acme-service/vx.toml"
+//!
+//! [templates.variables]
+//! node_version = "22"
+//! ```
+//!
+//! Indexes and templates are cached under `~/.vx/templates` and refreshed
+//! once a day; when the registry cannot be reached the cached copy is used.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+use vx_paths::VxPaths;
+
+/// Environment variable overriding the configured template index
+pub const TEMPLATE_INDEX_ENV: &str = "VX_TEMPLATE_INDEX";
+
+/// Name of the index file inside a git template repository
+pub const INDEX_FILE_NAME: &str = "index.toml";
+
+/// How long a cached index is used before it is refreshed
+const CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// `~/.vx/config/templates.toml`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TemplatesConfig {
+    #[serde(default)]
+    pub registry: RegistryConfig,
+}
+
+/// `[registry]` section of `templates.toml`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RegistryConfig {
+    /// Index location: an HTTPS URL or `git+<repo-url>`
+    pub index: Option<String>,
+}
+
+/// Template index published by a registry
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TemplateIndex {
+    #[serde(default)]
+    pub templates: Vec<TemplateEntry>,
+}
+
+/// One template listed in the index
+#[derive(Debug, Clone, Deserialize)]
+pub struct TemplateEntry {
+    /// Template name used with `vx init --template`
+    pub name: String,
+    /// One-line description shown by `--list-templates`
+    #[serde(default)]
+    pub description: String,
+    /// Template file, relative to the index (or an absolute URL)
+    pub path: String,
+    /// Variable defaults
+    #[serde(default)]
+    pub variables: BTreeMap<String, String>,
+}
+
+impl TemplateIndex {
+    /// Parse an index file
+    pub fn parse(content: &str) -> Result<Self> {
+        Ok(toml::from_str(content)?)
+    }
+
+    /// Find a template by name
+    pub fn get(&self, name: &str) -> Option<&TemplateEntry> {
+        self.templates.iter().find(|t| t.name == name)
+    }
+}
+
+/// Where a template index is published
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IndexSource {
+    /// Index file served over HTTP(S)
+    Http(String),
+    /// Git repository with `index.toml` at its root
+    Git(String),
+}
+
+impl IndexSource {
+    /// Parse an index location from configuration
+    pub fn parse(location: &str) -> Self {
+        let location = location.trim();
+        if let Some(repo) = location.strip_prefix("git+") {
+            Self::Git(repo.to_string())
+        } else if location.ends_with(".git") || location.starts_with("git@") {
+            Self::Git(location.to_string())
+        } else {
+            Self::Http(location.to_string())
+        }
+    }
+
+    /// Configured index: `VX_TEMPLATE_INDEX`, then `~/.vx/config/templates.toml`
+    pub fn configured(paths: &VxPaths) -> Result<Option<Self>> {
+        if let Ok(location) = std::env::var(TEMPLATE_INDEX_ENV)
+            && !location.trim().is_empty()
+        {
+            return Ok(Some(Self::parse(&location)));
+        }
+        let path = paths.templates_config();
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let config: TemplatesConfig = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(config.registry.index.as_deref().map(Self::parse))
+    }
+
+    fn location(&self) -> &str {
+        match self {
+            Self::Http(url) | Self::Git(url) => url,
+        }
+    }
+
+    /// Cache directory for this index under `~/.vx/templates`
+    pub fn cache_dir(&self, templates_dir: &Path) -> PathBuf {
+        let digest = Sha256::digest(self.location().as_bytes());
+        let key = digest.iter().take(8).fold(String::new(), |mut acc, b| {
+            use std::fmt::Write;
+            let _ = write!(acc, "{b:02x}");
+            acc
+        });
+        templates_dir.join(key)
+    }
+}
+
+/// A template index fetched into the local cache
+pub struct TemplateRegistry {
+    source: IndexSource,
+    cache_dir: PathBuf,
+    /// Parsed index
+    pub index: TemplateIndex,
+}
+
+impl TemplateRegistry {
+    /// Load the configured registry, if any
+    pub async fn load_configured() -> Result<Option<Self>> {
+        let paths = VxPaths::new()?;
+        match IndexSource::configured(&paths)? {
+            Some(source) => Ok(Some(Self::load(source, &paths.templates_dir()).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetch an index into `templates_dir`, falling back to a stale cache
+    pub async fn load(source: IndexSource, templates_dir: &Path) -> Result<Self> {
+        let cache_dir = source.cache_dir(templates_dir);
+        std::fs::create_dir_all(&cache_dir)?;
+
+        let index_path = match &source {
+            IndexSource::Http(url) => {
+                let path = cache_dir.join(INDEX_FILE_NAME);
+                if !is_fresh(&path)
+                    && let Err(e) = download(url, &path).await
+                {
+                    if !path.exists() {
+                        return Err(e.context(format!("Failed to fetch template index {}", url)));
+                    }
+                    tracing::warn!("Using cached template index, refresh failed: {}", e);
+                }
+                path
+            }
+            IndexSource::Git(repo) => {
+                let checkout = cache_dir.join("repo");
+                if let Err(e) = sync_git(repo, &checkout) {
+                    if !checkout.exists() {
+                        return Err(e.context(format!("Failed to clone template index {}", repo)));
+                    }
+                    tracing::warn!("Using cached template repository, refresh failed: {}", e);
+                }
+                checkout.join(INDEX_FILE_NAME)
+            }
+        };
+
+        let content = std::fs::read_to_string(&index_path)
+            .with_context(|| format!("Failed to read {}", index_path.display()))?;
+        let index = TemplateIndex::parse(&content)
+            .with_context(|| format!("Invalid template index {}", source.location()))?;
+
+        Ok(Self {
+            source,
+            cache_dir,
+            index,
+        })
+    }
+
+    /// Index location, for display
+    pub fn location(&self) -> &str {
+        self.source.location()
+    }
+
+    /// Fetch a template file (cached next to the index)
+    pub async fn fetch_template(&self, entry: &TemplateEntry) -> Result<String> {
+        let escapes = |p: &str| {
+            Path::new(p)
+                .components()
+                .any(|c| !matches!(c, std::path::Component::Normal(_)))
+        };
+        if escapes(&entry.name) || (!entry.path.contains("://") && escapes(&entry.path)) {
+            anyhow::bail!("Template '{}' has an invalid path", entry.name);
+        }
+
+        let path = match &self.source {
+            IndexSource::Git(_) => self.cache_dir.join("repo").join(&entry.path),
+            IndexSource::Http(index_url) => {
+                let url = resolve_url(index_url, &entry.path);
+                let path = self
+                    .cache_dir
+                    .join("files")
+                    .join(&entry.name)
+                    .join("vx.toml");
+                if !is_fresh(&path)
+                    && let Err(e) = download(&url, &path).await
+                {
+                    if !path.exists() {
+                        return Err(e.context(format!("Failed to fetch template {}", url)));
+                    }
+                    tracing::warn!(
+                        "Using cached template {}, refresh failed: {}",
+                        entry.name,
+                        e
+                    );
+                }
+                path
+            }
+        };
+        std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read template {}", path.display()))
+    }
+}
+
+/// Resolve a template path against the index URL
+pub fn resolve_url(index_url: &str, path: &str) -> String {
+    if path.contains("://") {
+        return path.to_string();
+    }
+    match index_url.rfind('/') {
+        Some(i) => format!("{}/{}", &index_url[..i], path.trim_start_matches('/')),
+        None => path.to_string(),
+    }
+}
+
+/// Substitute `{{name}}` placeholders.
+///
+/// `overrides` win over the template's defaults. Fails when a placeholder
+/// has no value, listing every missing variable.
+pub fn render_template(
+    content: &str,
+    defaults: &BTreeMap<String, String>,
+    overrides: &BTreeMap<String, String>,
+) -> Result<String> {
+    let mut output = String::with_capacity(content.len());
+    let mut missing = Vec::new();
+    let mut rest = content;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        output.push_str(&rest[..start]);
+        let name = rest[start + 2..start + 2 + len].trim();
+        match overrides.get(name).or_else(|| defaults.get(name)) {
+            Some(value) => output.push_str(value),
+            None => {
+                if !missing.iter().any(|m| m == name) {
+                    missing.push(name.to_string());
+                }
+            }
+        }
+        rest = &rest[start + 2 + len + 2..];
+    }
+    output.push_str(rest);
+
+    if !missing.is_empty() {
+        anyhow::bail!(
+            "Missing template variables: {} (pass them with --var NAME=VALUE)",
+            missing.join(", ")
+        );
+    }
+    Ok(output)
+}
+
+/// Parse `--var NAME=VALUE` arguments
+pub fn parse_vars(vars: &[String]) -> Result<BTreeMap<String, String>> {
+    vars.iter()
+        .map(|var| {
+            let (name, value) = var
+                .split_once('=')
+                .with_context(|| format!("Invalid --var '{}', expected NAME=VALUE", var))?;
+            Ok((name.trim().to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+fn is_fresh(path: &Path) -> bool {
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < CACHE_TTL)
+}
+
+async fn download(url: &str, dest: &Path) -> Result<()> {
+    let client = vx_proxy::client_builder()
+        .user_agent(format!("vx/{}", env!("CARGO_PKG_VERSION")))
+        .build()?;
+    let body = client
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(dest, body)?;
+    Ok(())
+}
+
+fn sync_git(repo: &str, checkout: &Path) -> Result<()> {
+    let fetch_head = checkout.join(".git").join("FETCH_HEAD");
+    let status = if checkout.join(".git").exists() {
+        if is_fresh(&fetch_head) || is_fresh(&checkout.join(".git").join("HEAD")) {
+            return Ok(());
+        }
+        Command::new("git")
+            .arg("-C")
+            .arg(checkout)
+            .args(["pull", "--ff-only", "--quiet"])
+            .status()
+    } else {
+        Command::new("git")
+            .args(["clone", "--depth", "1", "--quiet", repo])
+            .arg(checkout)
+            .status()
+    }
+    .context("Failed to run git")?;
+
+    if !status.success() {
+        anyhow::bail!("git exited with {}", status);
+    }
+    Ok(())
+}
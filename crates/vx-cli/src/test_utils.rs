@@ -2,10 +2,14 @@
 //!
 //! This module provides common testing utilities, mocks, and helpers
 //! for testing vx-cli functionality.
+//!
+//! Available to downstream crates and integration tests via the `testing`
+//! feature. [`IsolatedVx`] runs a vx binary against a throwaway VX_HOME so
+//! tests never touch the developer's real store or config.
 
 use std::collections::HashMap;
-use std::path::PathBuf;
-use std::process::Output;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
 use std::sync::Arc;
 use tempfile::TempDir;
 use vx_runtime::{
@@ -154,6 +158,49 @@ impl TestEnvironment {
     }
 }
 
+pub use crate::isolation::IsolatedHome;
+
+/// Runs a vx binary inside an isolated VX_HOME
+///
+/// Each harness owns its own sandbox, so tests can run in parallel without
+/// sharing store, cache or config state.
+pub struct IsolatedVx {
+    binary: PathBuf,
+    home: IsolatedHome,
+}
+
+impl IsolatedVx {
+    /// Create a harness for the given vx binary with a fresh VX_HOME
+    pub fn new(binary: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        Ok(Self {
+            binary: binary.into(),
+            home: IsolatedHome::new()?,
+        })
+    }
+
+    /// The sandboxed VX_HOME
+    pub fn home(&self) -> &IsolatedHome {
+        &self.home
+    }
+
+    /// Build a command for `vx <args>` that runs inside the sandbox
+    pub fn command(&self, args: &[&str]) -> Command {
+        let mut cmd = Command::new(&self.binary);
+        cmd.args(args).envs(self.home.env_vars());
+        cmd
+    }
+
+    /// Run `vx <args>` inside the sandbox and collect its output
+    pub fn run(&self, args: &[&str]) -> std::io::Result<Output> {
+        self.command(args).output()
+    }
+
+    /// Run `vx <args>` in `dir` inside the sandbox and collect its output
+    pub fn run_in(&self, dir: &Path, args: &[&str]) -> std::io::Result<Output> {
+        self.command(args).current_dir(dir).output()
+    }
+}
+
 /// Mock command execution for testing
 pub struct MockCommandExecutor {
     pub expected_commands: Vec<(String, Vec<String>)>,
@@ -4,11 +4,15 @@
 //! - Consistent output formatting (UI)
 //! - Re-exports from vx-console for unified progress management
 //! - Tool suggestion display for friendly error messages
+//!
+//! Message prefixes and symbols come from the global output theme, set from
+//! the `[ui]` options at startup (see [`apply_ui_config`]).
 
 use crate::suggestions::{self, ToolSuggestion};
 use colored::*;
 use std::sync::atomic::{AtomicBool, Ordering};
-use vx_console::global_progress_manager;
+use vx_config::UiConfig;
+use vx_console::{Style, Theme, global_progress_manager, global_theme};
 
 static VERBOSE: AtomicBool = AtomicBool::new(false);
 
@@ -19,6 +23,66 @@ pub fn progress_manager() -> std::sync::Arc<vx_console::ProgressManager> {
     global_progress_manager()
 }
 
+/// Build the output theme for `[ui]` options
+///
+/// Returns an error naming the valid themes when `theme` is unknown.
+pub fn theme_for(config: &UiConfig) -> Result<Theme, String> {
+    let mut theme = match config.theme.as_deref() {
+        Some(name) => Theme::named(name).ok_or_else(|| {
+            format!(
+                "Unknown ui.theme \"{}\"; expected one of: {}",
+                name,
+                Theme::NAMES.join(", ")
+            )
+        })?,
+        None => Theme::detect(),
+    };
+    if config.emoji == Some(false) {
+        theme = theme.without_emoji();
+    }
+    if config.ascii_only == Some(true) {
+        theme = theme.ascii_only();
+    }
+    Ok(theme)
+}
+
+/// Make the theme for `[ui]` options the global one
+///
+/// An unknown theme is reported and the detected theme used instead.
+pub fn apply_ui_config(config: &UiConfig) {
+    let theme = theme_for(config).unwrap_or_else(|e| {
+        UI::warn_stderr(&e);
+        theme_for(&UiConfig {
+            theme: None,
+            ..config.clone()
+        })
+        .unwrap_or_else(|_| Theme::detect())
+    });
+    vx_console::Console::builder().theme(theme).init_global();
+}
+
+/// `text` in `style`, when colors are enabled
+fn paint(text: &str, style: &Style) -> String {
+    if colored::control::SHOULD_COLORIZE.should_colorize() {
+        style.apply(text)
+    } else {
+        text.to_string()
+    }
+}
+
+/// `prefix` in `style`, then `message`
+fn prefixed(prefix: &str, style: &Style, message: &str) -> String {
+    if prefix.is_empty() {
+        return message.to_string();
+    }
+    format!("{} {}", paint(prefix, style), message)
+}
+
+/// `symbol`, or `fallback` when the output theme doesn't allow it
+fn symbol(symbol: &'static str, fallback: &'static str) -> &'static str {
+    global_theme().symbol(symbol, fallback)
+}
+
 /// UI utilities for consistent output formatting
 pub struct UI;
 
@@ -35,52 +99,93 @@ impl UI {
 
     /// Print an info message
     pub fn info(message: &str) {
-        global_progress_manager().println(&format!("{} {}", "ℹ".blue(), message));
+        let theme = global_theme();
+        global_progress_manager().println(&prefixed(
+            theme.info_prefix(),
+            &theme.info,
+            &theme.text(message),
+        ));
     }
 
     /// Print a success message
     pub fn success(message: &str) {
-        global_progress_manager().println(&format!("{} {}", "✓".green(), message));
+        let theme = global_theme();
+        global_progress_manager().println(&prefixed(
+            theme.success_prefix(),
+            &theme.success,
+            &theme.text(message),
+        ));
     }
 
     /// Print a warning message
     pub fn warn(message: &str) {
-        global_progress_manager().println(&format!("{} {}", "⚠".yellow(), message.yellow()));
+        let theme = global_theme();
+        global_progress_manager().println(&prefixed(
+            theme.warn_prefix(),
+            &theme.warn,
+            &theme.text(message).yellow().to_string(),
+        ));
+    }
+
+    /// Print a warning to stderr, leaving stdout to the tool being run
+    pub fn warn_stderr(message: &str) {
+        let theme = global_theme();
+        let line = prefixed(
+            theme.warn_prefix(),
+            &theme.warn,
+            &theme.text(message).yellow().to_string(),
+        );
+        global_progress_manager().println_status(&line);
     }
 
     /// Print an error message
     pub fn error(message: &str) {
-        // errors go to stderr; use eprintln inside suspend to avoid glitches
-        global_progress_manager().suspend(|| {
-            eprintln!("{} {}", "✗".red(), message.red());
-        });
+        let theme = global_theme();
+        let line = prefixed(
+            theme.error_prefix(),
+            &theme.error,
+            &theme.text(message).red().to_string(),
+        );
+        // errors go to stderr, above any progress bars
+        global_progress_manager().println_status(&line);
     }
 
     /// Print a debug message (only in verbose mode)
     pub fn debug(message: &str) {
         if Self::is_verbose() {
-            global_progress_manager().println(&format!("{} {}", "→".purple(), message.dimmed()));
+            let theme = global_theme();
+            global_progress_manager().println(&prefixed(
+                theme.debug_prefix(),
+                &theme.debug,
+                &theme.text(message).dimmed().to_string(),
+            ));
         }
     }
 
     /// Print a hint message
     pub fn hint(message: &str) {
-        global_progress_manager().println(&format!("{} {}", "💡".cyan(), message.dimmed()));
+        let theme = global_theme();
+        global_progress_manager().println(&prefixed(
+            theme.hint_prefix(),
+            &theme.hint,
+            &theme.text(message).dimmed().to_string(),
+        ));
     }
 
     /// Print a list item
     pub fn item(message: &str) {
-        global_progress_manager().println(&format!("  {}", message));
+        global_progress_manager().println(&format!("  {}", global_theme().text(message)));
     }
 
     /// Print a detail line (indented)
     pub fn detail(message: &str) {
-        global_progress_manager().println(&format!("    {}", message.dimmed()));
+        global_progress_manager()
+            .println(&format!("    {}", global_theme().text(message).dimmed()));
     }
 
     /// Print a separator line
     pub fn separator() {
-        global_progress_manager().println(&"─".repeat(50).dimmed().to_string());
+        global_progress_manager().println(&symbol("─", "-").repeat(50).dimmed().to_string());
     }
 
     /// Print a header
@@ -90,12 +195,24 @@ impl UI {
 
     /// Print a section header (for multi-step operations)
     pub fn section(message: &str) {
-        global_progress_manager().println(&format!("\n{} {}", "▸".cyan().bold(), message.bold()));
+        global_progress_manager().println(&format!(
+            "\n{} {}",
+            symbol("▸", ">").cyan().bold(),
+            message.bold()
+        ));
     }
 
     /// Print a progress message
     pub fn progress(message: &str) {
-        global_progress_manager().println(&format!("{} {}...", "⏳".yellow(), message));
+        global_progress_manager().println(&Self::pending_line(&format!("{}...", message)));
+    }
+
+    /// A pending operation: `message` after an hourglass where the theme allows one
+    fn pending_line(message: &str) -> String {
+        match symbol("⏳", "") {
+            "" => message.to_string(),
+            glyph => format!("{} {}", glyph.yellow(), message),
+        }
     }
 
     /// Complete a progress message
@@ -105,12 +222,12 @@ impl UI {
 
     /// Print a spinner (placeholder for now)
     pub fn spinner(message: &str) {
-        global_progress_manager().println(&format!("{} {}", "⏳".yellow(), message));
+        global_progress_manager().println(&Self::pending_line(message));
     }
 
     /// Print a step message
     pub fn step(message: &str) {
-        global_progress_manager().println(&format!("{} {}", "▶".blue(), message));
+        global_progress_manager().println(&format!("{} {}", symbol("▶", ">").blue(), message));
     }
 
     /// Alias for warn method (for backward compatibility)
@@ -152,88 +269,85 @@ impl UI {
     /// 3. Suggests similar tool names using fuzzy matching
     /// 4. Provides a link to request new tool support
     pub fn tool_not_found(tool_name: &str, available_tools: &[String]) {
-        // Use global progress manager to avoid interleaving with progress bars
-        let pm = global_progress_manager();
-        pm.suspend(|| {
-            eprintln!(
-                "{} {}",
-                "✗".red(),
-                format!("Tool '{}' is not supported by vx", tool_name).red()
-            );
-
-            // Get suggestions
-            let suggestions_list = suggestions::get_tool_suggestions(tool_name, available_tools);
-
-            if !suggestions_list.is_empty() {
-                eprintln!();
-                for suggestion in &suggestions_list {
-                    if suggestion.is_alias {
-                        eprintln!(
-                            "{} Did you mean: {} ({})",
-                            "💡".cyan(),
-                            suggestion.suggested_tool.cyan().bold(),
-                            suggestion.description.dimmed()
-                        );
-                    } else {
-                        eprintln!(
-                            "{} Did you mean: {}",
-                            "💡".cyan(),
-                            suggestion.suggested_tool.cyan().bold()
-                        );
-                    }
-                }
-            }
+        if crate::error_handler::report_tool_not_found(tool_name, available_tools) {
+            return;
+        }
 
-            eprintln!();
-            eprintln!(
-                "{} {}",
-                "💡".cyan(),
-                "Use 'vx list' to see all supported tools".dimmed()
-            );
+        let theme = global_theme();
+        let error_prefix = paint(theme.error_prefix(), &theme.error);
+        let hint_prefix = paint(theme.hint_prefix(), &theme.hint);
+
+        let mut lines = vec![format!(
+            "{} {}",
+            error_prefix,
+            format!("Tool '{}' is not supported by vx", tool_name).red()
+        )];
+
+        let suggestions_list = suggestions::get_tool_suggestions(tool_name, available_tools);
+        if !suggestions_list.is_empty() {
+            lines.push(String::new());
+            for suggestion in &suggestions_list {
+                lines.push(did_you_mean(&hint_prefix, suggestion));
+            }
+        }
 
-            let issue_url = suggestions::get_feature_request_url(tool_name);
-            eprintln!(
-                "{} Request support for '{}': {}",
-                "💡".cyan(),
-                tool_name,
-                issue_url.dimmed()
-            );
-        });
+        lines.push(String::new());
+        lines.push(format!(
+            "{} {}",
+            hint_prefix,
+            "Use 'vx list' to see all supported tools".dimmed()
+        ));
+        lines.push(format!(
+            "{} Request support for '{}': {}",
+            hint_prefix,
+            tool_name,
+            suggestions::get_feature_request_url(tool_name).dimmed()
+        ));
+
+        // One block, so progress bars don't interleave with it
+        global_progress_manager().println_status(&lines.join("\n"));
     }
 
     /// Display a friendly "tool not found" error with suggestions (simpler version)
     pub fn tool_not_found_simple(tool_name: &str, suggestion: Option<&ToolSuggestion>) {
-        global_progress_manager().suspend(|| {
-            eprintln!(
-                "{} {}",
-                "✗".red(),
-                format!("Tool '{}' is not supported", tool_name).red()
-            );
-
-            if let Some(s) = suggestion {
-                eprintln!();
-                if s.is_alias {
-                    eprintln!(
-                        "{} Did you mean: {} ({})",
-                        "💡".cyan(),
-                        s.suggested_tool.cyan().bold(),
-                        s.description.dimmed()
-                    );
-                } else {
-                    eprintln!(
-                        "{} Did you mean: {}",
-                        "💡".cyan(),
-                        s.suggested_tool.cyan().bold()
-                    );
-                }
-            }
+        let theme = global_theme();
+        let error_prefix = paint(theme.error_prefix(), &theme.error);
+        let hint_prefix = paint(theme.hint_prefix(), &theme.hint);
+
+        let mut lines = vec![format!(
+            "{} {}",
+            error_prefix,
+            format!("Tool '{}' is not supported", tool_name).red()
+        )];
+        if let Some(suggestion) = suggestion {
+            lines.push(String::new());
+            lines.push(did_you_mean(&hint_prefix, suggestion));
+        }
+        lines.push(format!(
+            "{} {}",
+            hint_prefix,
+            "Use 'vx list' to see all supported tools".dimmed()
+        ));
+
+        global_progress_manager().println_status(&lines.join("\n"));
+    }
+}
 
-            eprintln!(
-                "{} {}",
-                "💡".cyan(),
-                "Use 'vx list' to see all supported tools".dimmed()
-            );
-        });
+/// "Did you mean" line of a tool suggestion
+fn did_you_mean(hint_prefix: &str, suggestion: &ToolSuggestion) -> String {
+    if suggestion.is_alias {
+        format!(
+            "{} Did you mean: {} ({})",
+            hint_prefix,
+            suggestion.suggested_tool.cyan().bold(),
+            suggestion.description.dimmed()
+        )
+    } else {
+        format!(
+            "{} Did you mean: {}",
+            hint_prefix,
+            suggestion.suggested_tool.cyan().bold()
+        )
     }
 }
 
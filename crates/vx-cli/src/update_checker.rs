@@ -95,9 +95,9 @@ impl Default for UpdateCheckCache {
 
 /// Get the cache file path
 fn get_cache_path() -> Result<PathBuf> {
-    let vx_dir = dirs::home_dir()
-        .map(|h| h.join(".vx").join("cache"))
-        .ok_or_else(|| anyhow!("Failed to determine home directory"))?;
+    let vx_dir = vx_paths::VxPaths::new()
+        .map(|p| p.cache_dir)
+        .map_err(|e| anyhow!("Failed to determine vx cache directory: {}", e))?;
 
     // Create cache directory if it doesn't exist
     if !vx_dir.exists() {
@@ -182,7 +182,7 @@ fn format_time(t: SystemTime) -> String {
 async fn fetch_latest_version() -> Result<String> {
     use reqwest::header::{AUTHORIZATION, USER_AGENT};
 
-    let client = reqwest::Client::builder()
+    let client = vx_proxy::client_builder()
         .timeout(Duration::from_secs(5)) // Short timeout to avoid blocking
         .build()
         .context("Failed to create HTTP client")?;
@@ -216,7 +216,9 @@ async fn fetch_latest_version() -> Result<String> {
     let github_url = "https://api.github.com/repos/loonghao/vx/releases/latest";
 
     // Add authorization if token is available
-    if let Ok(token) = env::var("GITHUB_TOKEN").or_else(|_| env::var("VX_GITHUB_TOKEN"))
+    if let Some(token) = env::var("VX_GITHUB_TOKEN")
+        .ok()
+        .or_else(vx_proxy::credentials::github_token)
         && let Ok(header_value) =
             reqwest::header::HeaderValue::from_str(&format!("Bearer {}", token))
     {
@@ -304,6 +306,10 @@ async fn perform_update_check(cache: &mut UpdateCheckCache) -> Result<()> {
 /// This function creates its own runtime and performs the update check with timeout.
 /// It's designed to be called from `tokio::task::spawn_blocking()`.
 fn do_update_check_sync() -> Option<String> {
+    // Keep recorded and replayed runs to the command's own traffic
+    if vx_cache::cassette::active().is_some() {
+        return None;
+    }
     let current_version = env!("CARGO_PKG_VERSION").to_string();
     let mut cache = load_cache();
 
@@ -0,0 +1,44 @@
+//! Tests for the `vx android setup` sdkmanager invocations
+
+use std::path::Path;
+use vx_cli::commands::android::{default_sdk_root, install_args, license_args};
+
+#[test]
+fn test_default_sdk_root_is_under_vx_home() {
+    assert_eq!(
+        default_sdk_root(Path::new("/home/user/.vx")),
+        Path::new("/home/user/.vx").join("android").join("sdk")
+    );
+}
+
+#[test]
+fn test_license_args_pass_sdk_root() {
+    assert_eq!(
+        license_args(Path::new("/sdk")),
+        vec!["--sdk_root=/sdk", "--licenses"]
+    );
+}
+
+#[test]
+fn test_install_args_default_to_platform_tools() {
+    assert_eq!(
+        install_args(Path::new("/sdk"), &[]),
+        vec!["--sdk_root=/sdk", "platform-tools"]
+    );
+}
+
+#[test]
+fn test_install_args_keep_requested_packages() {
+    let packages = vec![
+        "platforms;android-35".to_string(),
+        "build-tools;35.0.0".to_string(),
+    ];
+    assert_eq!(
+        install_args(Path::new("/sdk"), &packages),
+        vec![
+            "--sdk_root=/sdk",
+            "platforms;android-35",
+            "build-tools;35.0.0"
+        ]
+    );
+}
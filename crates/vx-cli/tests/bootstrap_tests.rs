@@ -0,0 +1,118 @@
+//! Tests for `vx bootstrap` script rendering
+
+use std::path::Path;
+use vx_cli::commands::bootstrap::{BootstrapProject, render_script, sh_quote, ssh_args};
+
+fn project() -> BootstrapProject {
+    BootstrapProject {
+        name: "demo".to_string(),
+        config: (
+            "vx.toml".to_string(),
+            "[tools]\nnode = \"22\"\n".to_string(),
+        ),
+        lock: Some((
+            "vx.lock".to_string(),
+            "version = 1\n\n[tools.node]\nversion = \"22.11.0\"\n".to_string(),
+        )),
+    }
+}
+
+#[test]
+fn test_script_without_project_only_installs_vx() {
+    let script = render_script(None, None, "0.9.0");
+    assert!(script.starts_with("#!/bin/sh\n"));
+    assert!(script.contains("VX_BOOTSTRAP_VERSION=\"0.9.0\""));
+    assert!(script.contains("install.sh"));
+    assert!(!script.contains("sync"));
+    assert!(!script.contains("cat >"));
+}
+
+#[test]
+fn test_script_embeds_project_files_and_syncs() {
+    let script = render_script(Some(&project()), None, "0.9.0");
+    assert!(script.contains("    VX_BOOTSTRAP_DIR='demo'\n"));
+    assert!(script.contains(
+        "cat > 'vx.toml' <<'VX_BOOTSTRAP_EOF'\n[tools]\nnode = \"22\"\nVX_BOOTSTRAP_EOF\n"
+    ));
+    assert!(script.contains("cat > 'vx.lock' <<'VX_BOOTSTRAP_EOF'\n"));
+    assert!(script.trim_end().ends_with("\"$VX\" sync"));
+
+    let script = render_script(Some(&project()), Some("builds/it's"), "0.9.0");
+    assert!(script.contains(r"VX_BOOTSTRAP_DIR='builds/it'\''s'"));
+}
+
+#[test]
+fn test_heredoc_delimiter_avoids_file_content() {
+    let mut project = project();
+    project.config.1 = "# VX_BOOTSTRAP_EOF\nVX_BOOTSTRAP_EOF\n".to_string();
+    project.lock = None;
+    let script = render_script(Some(&project), None, "0.9.0");
+    assert!(script.contains("<<'VX_BOOTSTRAP_EOF_'\n"));
+    assert!(script.contains("\nVX_BOOTSTRAP_EOF_\n"));
+}
+
+#[test]
+fn test_ssh_args() {
+    assert_eq!(ssh_args("ci@agent", None, None), ["ci@agent", "sh -s"]);
+    assert_eq!(
+        ssh_args("ci@agent", Some(2222), Some(Path::new("id_ed25519"))),
+        ["-p", "2222", "-i", "id_ed25519", "ci@agent", "sh -s"]
+    );
+    assert_eq!(sh_quote("a b"), "'a b'");
+}
+
+#[test]
+fn test_find_project_reads_config_and_lock() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path().join("my-app");
+    std::fs::create_dir_all(root.join("src")).unwrap();
+    std::fs::write(root.join("vx.toml"), "[tools]\ngo = \"1.23\"\n").unwrap();
+
+    let found = BootstrapProject::find(&root.join("src")).unwrap().unwrap();
+    assert_eq!(found.name, "my-app");
+    assert_eq!(found.config.0, "vx.toml");
+    assert!(found.lock.is_none());
+
+    std::fs::write(root.join("vx.lock"), "version = 1\n").unwrap();
+    let found = BootstrapProject::find(&root).unwrap().unwrap();
+    assert_eq!(
+        found.lock,
+        Some(("vx.lock".to_string(), "version = 1\n".to_string()))
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_script_runs_with_existing_vx() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let bin = dir.path().join("bin");
+    std::fs::create_dir(&bin).unwrap();
+    let fake_vx = bin.join("vx");
+    std::fs::write(&fake_vx, "#!/bin/sh\necho \"vx $*\" > vx-called\n").unwrap();
+    std::fs::set_permissions(&fake_vx, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let script = render_script(Some(&project()), None, "0.9.0");
+    let status = std::process::Command::new("sh")
+        .arg("-c")
+        .arg(&script)
+        .current_dir(dir.path())
+        .env("PATH", format!("{}:/usr/bin:/bin", bin.display()))
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let project_dir = dir.path().join("demo");
+    assert_eq!(
+        std::fs::read_to_string(project_dir.join("vx.toml")).unwrap(),
+        "[tools]\nnode = \"22\"\n"
+    );
+    assert!(project_dir.join("vx.lock").is_file());
+    assert_eq!(
+        std::fs::read_to_string(project_dir.join("vx-called"))
+            .unwrap()
+            .trim(),
+        "vx sync"
+    );
+}
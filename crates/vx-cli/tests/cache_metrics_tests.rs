@@ -0,0 +1,75 @@
+//! Tests for the `/metrics` endpoint of `vx cache serve`
+
+use std::time::Duration;
+use vx_cache::peer::PeerServerStats;
+use vx_cache::{DownloadAttempt, NetworkStats, UsageCounters, usage};
+use vx_cli::commands::cache::render_metrics;
+use vx_metrics::CommandLatencies;
+use vx_paths::VxPaths;
+
+#[test]
+fn test_render_metrics_of_empty_home() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let text = render_metrics(
+        &VxPaths::with_base_dir(temp.path()),
+        &PeerServerStats::default(),
+    );
+
+    assert!(text.contains(&format!(
+        "vx_build_info{{version=\"{}\"}} 1\n",
+        env!("CARGO_PKG_VERSION")
+    )));
+    assert!(text.contains("vx_installs_total 0\n"));
+    assert!(text.contains("vx_download_cache_requests_total{result=\"hit\"} 0\n"));
+    assert!(text.contains("# TYPE vx_command_duration_seconds histogram\n"));
+    assert!(text.contains("vx_peer_bytes_served_total 0\n"));
+    // Every sample belongs to a declared family
+    let families: Vec<&str> = text
+        .lines()
+        .filter_map(|l| l.strip_prefix("# TYPE "))
+        .map(|l| l.split(' ').next().unwrap())
+        .collect();
+    for line in text.lines().filter(|l| !l.starts_with('#')) {
+        assert!(families.iter().any(|f| line.starts_with(f)), "{}", line);
+    }
+}
+
+#[test]
+fn test_render_metrics_reads_recorded_usage() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let paths = VxPaths::with_base_dir(temp.path());
+
+    let counters = UsageCounters::new(&paths.cache_dir);
+    counters.increment(usage::INSTALLS);
+    counters.increment(usage::DOWNLOAD_CACHE_MISSES);
+    NetworkStats::new(paths.cache_dir.clone()).record(
+        "https://nodejs.org/dist/v22.0.0/node.tar.gz",
+        &DownloadAttempt::Success {
+            latency: Duration::from_millis(50),
+            elapsed: Duration::from_millis(500),
+            bytes: 4096,
+        },
+    );
+    CommandLatencies::record(
+        &paths.base_dir.join("metrics"),
+        "vx install node",
+        2.0,
+        true,
+    )
+    .unwrap();
+
+    let server = PeerServerStats {
+        hits: 3,
+        misses: 1,
+        bytes_served: 1024,
+    };
+    let text = render_metrics(&paths, &server);
+
+    assert!(text.contains("vx_installs_total 1\n"));
+    assert!(text.contains("vx_download_cache_requests_total{result=\"miss\"} 1\n"));
+    assert!(text.contains("vx_downloads_total{host=\"nodejs.org\",result=\"success\"} 1\n"));
+    assert!(text.contains("vx_download_bytes_total{host=\"nodejs.org\"} 4096\n"));
+    assert!(text.contains("vx_command_duration_seconds_count{command=\"install\"} 1\n"));
+    assert!(text.contains("vx_peer_requests_total{result=\"hit\"} 3\n"));
+    assert!(text.contains("vx_peer_bytes_served_total 1024\n"));
+}
@@ -0,0 +1,65 @@
+//! Tests for the cache pruning policy and `vx cache prune` limits
+
+use std::time::Duration;
+use vx_cli::cache_policy::{CachePolicy, parse_age_arg, parse_size_arg};
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[test]
+fn test_parse_age_arg() {
+    assert_eq!(parse_age_arg("30d").unwrap(), DAY * 30);
+    assert_eq!(parse_age_arg("30").unwrap(), DAY * 30);
+    assert_eq!(parse_age_arg("2w").unwrap(), DAY * 14);
+    assert_eq!(
+        parse_age_arg("12h").unwrap(),
+        Duration::from_secs(12 * 3600)
+    );
+    assert_eq!(parse_age_arg("90m").unwrap(), Duration::from_secs(90 * 60));
+    assert_eq!(parse_age_arg("45s").unwrap(), Duration::from_secs(45));
+
+    assert!(parse_age_arg("").is_err());
+    assert!(parse_age_arg("d").is_err());
+    assert!(parse_age_arg("3y").is_err());
+}
+
+#[test]
+fn test_parse_size_arg() {
+    assert_eq!(parse_size_arg("5GB").unwrap(), 5 << 30);
+    assert!(parse_size_arg("lots").is_err());
+}
+
+#[test]
+fn test_cache_policy_defaults() {
+    let policy = CachePolicy::parse("").unwrap();
+    assert_eq!(policy, CachePolicy::default());
+    assert!(!policy.auto_prune);
+    assert!(!policy.is_limited());
+    assert_eq!(policy.prune_interval, DAY);
+}
+
+#[test]
+fn test_cache_policy_parse() {
+    let policy = CachePolicy::parse(
+        r#"
+max_size = "5GB"
+older_than = "30d"
+auto_prune = true
+prune_interval = "12h"
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(policy.max_size, Some(5 << 30));
+    assert_eq!(policy.older_than, Some(DAY * 30));
+    assert!(policy.auto_prune);
+    assert!(policy.is_limited());
+    assert_eq!(policy.prune_interval, Duration::from_secs(12 * 3600));
+}
+
+#[test]
+fn test_cache_policy_rejects_invalid() {
+    let err = CachePolicy::parse("max_size = \"huge\"").unwrap_err();
+    assert!(err.to_string().contains("max_size"));
+    assert!(CachePolicy::parse("older_than = \"soon\"").is_err());
+    assert!(CachePolicy::parse("max_bytes = 10").is_err());
+}
@@ -0,0 +1,148 @@
+//! Tests for `vx changelog` range selection and rendering
+
+use vx_cli::commands::changelog::{
+    ChangelogEntry, ChangelogRange, github_repo, render_changelog, render_changelog_url,
+    select_versions, tag_matches,
+};
+use vx_versions::VersionInfo;
+
+fn versions() -> Vec<VersionInfo> {
+    vec![
+        VersionInfo::new("21.0.0-rc.1").with_prerelease(true),
+        VersionInfo::new("20.12.1"),
+        VersionInfo::new("20.12.0"),
+        VersionInfo::new("20.11.1"),
+        VersionInfo::new("20.11.0"),
+        VersionInfo::new("18.19.1"),
+    ]
+}
+
+#[test]
+fn test_parse_range() {
+    assert_eq!(ChangelogRange::parse(None).unwrap(), ChangelogRange::Latest);
+    assert_eq!(
+        ChangelogRange::parse(Some("20.11.0")).unwrap(),
+        ChangelogRange::Exact("20.11.0".to_string())
+    );
+    assert_eq!(
+        ChangelogRange::parse(Some("20.11.0..")).unwrap(),
+        ChangelogRange::Between {
+            from: Some("20.11.0".to_string()),
+            to: None,
+        }
+    );
+    assert!(ChangelogRange::parse(Some("..")).is_err());
+    assert!(ChangelogRange::parse(Some("20.12.0..20.11.0")).is_err());
+    assert!(ChangelogRange::parse(Some("foo..20.11.0")).is_err());
+}
+
+#[test]
+fn test_select_between_excludes_from() {
+    let range = ChangelogRange::parse(Some("20.11.0..20.12.0")).unwrap();
+    assert_eq!(
+        select_versions(&range, &versions(), 20),
+        vec!["20.12.0", "20.11.1"]
+    );
+}
+
+#[test]
+fn test_select_open_ranges() {
+    let newer = ChangelogRange::parse(Some("20.11.1..")).unwrap();
+    assert_eq!(
+        select_versions(&newer, &versions(), 20),
+        vec!["20.12.1", "20.12.0"]
+    );
+
+    let older = ChangelogRange::parse(Some("..20.12.0")).unwrap();
+    assert_eq!(
+        select_versions(&older, &versions(), 2),
+        vec!["20.12.0", "20.11.1"]
+    );
+}
+
+#[test]
+fn test_select_latest_and_exact() {
+    assert_eq!(
+        select_versions(&ChangelogRange::Latest, &versions(), 20),
+        vec!["20.12.1"]
+    );
+    let exact = ChangelogRange::parse(Some("v21.0.0-rc.1")).unwrap();
+    assert_eq!(
+        select_versions(&exact, &versions(), 20),
+        vec!["21.0.0-rc.1"]
+    );
+}
+
+#[test]
+fn test_github_repo() {
+    assert_eq!(
+        github_repo("https://github.com/nodejs/node"),
+        Some(("nodejs".to_string(), "node".to_string()))
+    );
+    assert_eq!(
+        github_repo("https://github.com/astral-sh/uv.git/"),
+        Some(("astral-sh".to_string(), "uv".to_string()))
+    );
+    assert_eq!(github_repo("https://gitlab.com/owner/repo"), None);
+    assert_eq!(github_repo("https://github.com/owner"), None);
+}
+
+#[test]
+fn test_tag_matches() {
+    assert!(tag_matches("v20.12.0", "20.12.0"));
+    assert!(tag_matches("bun-v1.1.0", "1.1.0"));
+    assert!(tag_matches("1.7", "1.7"));
+    assert!(!tag_matches("v20.12.0", "20.1.0"));
+}
+
+#[test]
+fn test_render_changelog_url() {
+    assert_eq!(
+        render_changelog_url("https://go.dev/doc/devel/release#go{version}", "1.22.1"),
+        "https://go.dev/doc/devel/release#go1.22.1"
+    );
+    assert_eq!(
+        render_changelog_url("https://example.com/{major}.{minor}/notes", "3.12.4"),
+        "https://example.com/3.12/notes"
+    );
+}
+
+#[test]
+fn test_render_changelog() {
+    let entries = vec![
+        ChangelogEntry {
+            version: "20.12.0".to_string(),
+            date: Some("2024-03-26".to_string()),
+            url: Some("https://github.com/nodejs/node/releases/tag/v20.12.0".to_string()),
+            body: Some("### Notable changes\r\n\r\n* crypto: ...\r\n".to_string()),
+        },
+        ChangelogEntry {
+            version: "20.11.1".to_string(),
+            url: Some("https://example.com/20.11.1".to_string()),
+            ..Default::default()
+        },
+        ChangelogEntry {
+            version: "20.11.0".to_string(),
+            ..Default::default()
+        },
+    ];
+
+    assert_eq!(
+        render_changelog("node", &entries),
+        "## node 20.12.0 (2024-03-26)\n\
+         https://github.com/nodejs/node/releases/tag/v20.12.0\n\
+         \n\
+         ### Notable changes\n\
+         \n\
+         * crypto: ...\n\
+         \n\
+         ## node 20.11.1\n\
+         \n\
+         Release notes: https://example.com/20.11.1\n\
+         \n\
+         ## node 20.11.0\n\
+         \n\
+         No release notes found.\n\
+         \n"
+    );
+}
@@ -0,0 +1,81 @@
+//! Tests for the machine requirement checks of `vx check`
+
+use vx_cli::commands::check::{check_disk_space, check_gpu, check_platform, url_origin};
+use vx_config::RequirementsConfig;
+use vx_runtime::GpuInfo;
+
+fn requirements() -> RequirementsConfig {
+    RequirementsConfig {
+        os: vec!["linux".to_string(), "macos".to_string()],
+        arch: vec!["arm64".to_string()],
+        min_disk_space: Some("1GB".to_string()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_check_platform() {
+    let checks = check_platform(&requirements(), "darwin", "x86_64");
+
+    assert_eq!(checks.len(), 2);
+    assert!(checks[0].passed, "darwin matches macos");
+    assert!(!checks[1].passed, "x86_64 is not arm64");
+    assert_eq!(checks[1].detail, "allowed: arm64");
+}
+
+#[test]
+fn test_check_platform_without_constraints() {
+    assert!(check_platform(&RequirementsConfig::default(), "windows", "x86_64").is_empty());
+}
+
+#[test]
+fn test_check_disk_space() {
+    let req = requirements();
+
+    assert!(check_disk_space(&req, Some(2 << 30)).unwrap().passed);
+    assert!(!check_disk_space(&req, Some(1 << 20)).unwrap().passed);
+    assert!(!check_disk_space(&req, None).unwrap().passed);
+    assert!(check_disk_space(&RequirementsConfig::default(), Some(0)).is_none());
+
+    let invalid = RequirementsConfig {
+        min_disk_space: Some("lots".to_string()),
+        ..Default::default()
+    };
+    let check = check_disk_space(&invalid, Some(1 << 30)).unwrap();
+    assert!(!check.passed);
+    assert_eq!(check.detail, "Invalid disk size 'lots'");
+}
+
+#[test]
+fn test_check_gpu() {
+    let req = RequirementsConfig {
+        requires_gpu: true,
+        ..Default::default()
+    };
+    let cuda = GpuInfo::from_override("12.4").unwrap();
+
+    let check = check_gpu(&req, &cuda).unwrap();
+    assert!(check.passed);
+    assert_eq!(check.detail, "CUDA 12.4");
+
+    let check = check_gpu(&req, &GpuInfo::default()).unwrap();
+    assert!(!check.passed);
+    assert_eq!(check.detail, "no NVIDIA GPU detected");
+
+    assert!(check_gpu(&RequirementsConfig::default(), &cuda).is_none());
+}
+
+#[test]
+fn test_url_origin() {
+    assert_eq!(
+        url_origin("https://registry.npmjs.org/some/package"),
+        Some("https://registry.npmjs.org".to_string())
+    );
+    assert_eq!(
+        url_origin("http://mirror.corp:8080?x=1"),
+        Some("http://mirror.corp:8080".to_string())
+    );
+    assert_eq!(url_origin("https://{host}/node"), None);
+    assert_eq!(url_origin("ftp://example.com"), None);
+    assert_eq!(url_origin("registry.npmjs.org"), None);
+}
@@ -0,0 +1,116 @@
+//! Tests for `vx ci generate`
+
+use std::path::PathBuf;
+use vx_cli::cli::CiProvider;
+use vx_cli::commands::ci::{CI_MARKER, CiProject, ci_files, select_scripts, write_ci_files};
+
+fn project() -> CiProject {
+    CiProject {
+        config_file: "vx.toml".to_string(),
+        lock_file: Some("vx.lock".to_string()),
+        scripts: vec!["lint".to_string(), "test".to_string()],
+    }
+}
+
+fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+#[test]
+fn test_select_scripts() {
+    let defined = names(&["build", "dev", "lint", "test"]);
+    assert_eq!(
+        select_scripts(&[], &defined).unwrap(),
+        names(&["lint", "test", "build"])
+    );
+    assert_eq!(
+        select_scripts(&names(&["dev"]), &defined).unwrap(),
+        names(&["dev"])
+    );
+    assert!(select_scripts(&names(&["deploy"]), &defined).is_err());
+    assert!(select_scripts(&names(&["a b"]), &names(&["a b"])).is_err());
+}
+
+#[test]
+fn test_github_files_use_pinned_setup_action() {
+    let files = ci_files(CiProvider::Github, &project(), "0.9.0");
+    let paths: Vec<PathBuf> = files.iter().map(|file| file.path.clone()).collect();
+    assert_eq!(
+        paths,
+        [
+            PathBuf::from(".github/workflows/vx.yml"),
+            PathBuf::from(".github/actions/vx-setup/action.yml"),
+        ]
+    );
+
+    let workflow = &files[0].content;
+    assert!(workflow.starts_with(&format!("# {}", CI_MARKER)));
+    assert!(workflow.contains("uses: ./.github/actions/vx-setup"));
+    assert!(workflow.contains("run: vx sync --check"));
+    let lint = workflow.find("run: vx run lint").unwrap();
+    let test = workflow.find("run: vx run test").unwrap();
+    assert!(workflow.find("vx sync --check").unwrap() < lint && lint < test);
+
+    let action = &files[1].content;
+    assert!(action.contains("using: \"composite\""));
+    assert!(action.contains("uses: loonghao/vx@vx-v0.9.0"));
+    assert!(action.contains("version: \"0.9.0\""));
+    assert!(action.contains("cache: \"true\""));
+    assert!(action.contains("setup: \"true\""));
+    assert!(action.contains("default: ${{ github.token }}"));
+}
+
+#[test]
+fn test_gitlab_caches_vx_home_inside_project() {
+    let files = ci_files(CiProvider::Gitlab, &project(), "0.9.0");
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].path, PathBuf::from(".gitlab-ci.yml"));
+
+    let config = &files[0].content;
+    assert!(config.contains("VX_VERSION: \"0.9.0\""));
+    assert!(config.contains("VX_HOME: \"$CI_PROJECT_DIR/.vx\""));
+    assert!(config.contains("        - vx.toml\n        - vx.lock\n"));
+    assert!(config.contains("- vx sync --check\n"));
+    assert!(config.ends_with("  script:\n    - vx run lint\n    - vx run test\n"));
+}
+
+#[test]
+fn test_circleci_cache_key_checksums_project_files() {
+    let mut project = project();
+    project.lock_file = None;
+    let files = ci_files(CiProvider::Circleci, &project, "0.9.0");
+    assert_eq!(files[0].path, PathBuf::from(".circleci/config.yml"));
+
+    let config = &files[0].content;
+    assert!(config.contains("version: 2.1"));
+    assert!(config.contains("key: vx-0.9.0-{{ checksum \"vx.toml\" }}\n"));
+    assert!(!config.contains("vx.lock"));
+    assert!(config.contains("- ~/.vx\n"));
+    assert!(config.contains("command: vx sync --check"));
+    assert!(config.contains("command: vx run test"));
+    assert!(config.ends_with("workflows:\n  vx:\n    jobs:\n      - vx\n"));
+}
+
+#[test]
+fn test_write_ci_files_keeps_foreign_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let files = ci_files(CiProvider::Gitlab, &project(), "0.9.0");
+    let path = dir.path().join(".gitlab-ci.yml");
+
+    std::fs::write(&path, "stages: [build]\n").unwrap();
+    assert!(write_ci_files(dir.path(), &files, false).is_err());
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "stages: [build]\n");
+
+    write_ci_files(dir.path(), &files, true).unwrap();
+    // Generated files are regenerated without --force
+    let updated = ci_files(CiProvider::Gitlab, &project(), "0.9.1");
+    assert_eq!(
+        write_ci_files(dir.path(), &updated, false).unwrap(),
+        vec![path.clone()]
+    );
+    assert!(
+        std::fs::read_to_string(&path)
+            .unwrap()
+            .contains("VX_VERSION: \"0.9.1\"")
+    );
+}
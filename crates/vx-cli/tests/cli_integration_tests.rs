@@ -348,6 +348,7 @@ mod init_tests {
             false, // force
             false, // dry_run
             true,  // list_templates
+            &[],   // vars
         )
         .await;
         assert!(result.is_ok(), "List templates should succeed");
@@ -368,6 +369,7 @@ mod init_tests {
             false,                       // force
             true,                        // dry_run
             false,                       // list_templates
+            &[],                         // vars
         )
         .await;
         // Dry run should succeed without creating files
@@ -396,6 +398,7 @@ mod init_tests {
             false,
             true, // dry_run to avoid file creation
             false,
+            &[],
         )
         .await;
 
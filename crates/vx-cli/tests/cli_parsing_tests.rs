@@ -5,6 +5,7 @@
 use clap::Parser;
 use vx_cli::GlobalOptions;
 use vx_cli::cli::*;
+use vx_cli::commands::{dev, install, list};
 use vx_runtime::CacheMode;
 
 // ============================================
@@ -21,6 +22,126 @@ fn test_cli_version_command() {
     assert!(matches!(cli.command, Some(Commands::Version)));
 }
 
+#[test]
+fn test_cli_outdated_command() {
+    let cli = Cli::try_parse_from(["vx", "outdated", "--eol"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Outdated { eol: true })
+    ));
+
+    let cli = Cli::try_parse_from(["vx", "outdated"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Outdated { eol: false })
+    ));
+}
+
+#[test]
+fn test_cli_ui_command() {
+    let cli = Cli::try_parse_from(["vx", "ui"]).unwrap();
+    assert!(matches!(cli.command, Some(Commands::Ui)));
+}
+
+#[test]
+fn test_cli_rpc_command() {
+    let cli = Cli::try_parse_from(["vx", "rpc"]).unwrap();
+    assert!(matches!(cli.command, Some(Commands::Rpc)));
+}
+
+#[test]
+fn test_cli_provider_dev_command() {
+    let cli = Cli::try_parse_from(["vx", "provider", "dev", "./my-provider/"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Provider {
+            command: ProviderCommand::Dev { ref path }
+        }) if path == std::path::Path::new("./my-provider/")
+    ));
+}
+
+#[test]
+fn test_cli_plugin_is_provider_alias() {
+    let cli = Cli::try_parse_from(["vx", "plugin", "list"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Provider {
+            command: ProviderCommand::List { .. }
+        })
+    ));
+}
+
+#[test]
+fn test_cli_provider_new_command() {
+    let cli = Cli::try_parse_from([
+        "vx",
+        "provider",
+        "new",
+        "mytool",
+        "--repo",
+        "owner/mytool",
+        "--template",
+        "go",
+        "--tag-prefix",
+        "",
+    ])
+    .unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Provider {
+            command: ProviderCommand::New {
+                ref name,
+                template: ProviderTemplate::Go,
+                ref tag_prefix,
+                builtin: false,
+                ..
+            }
+        }) if name == "mytool" && tag_prefix.is_empty()
+    ));
+}
+
+#[test]
+fn test_cli_provider_asdf_command() {
+    let cli = Cli::try_parse_from([
+        "vx",
+        "provider",
+        "asdf",
+        "nodejs",
+        "https://github.com/asdf-vm/asdf-nodejs",
+        "--executable",
+        "node",
+    ])
+    .unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Provider {
+            command: ProviderCommand::Asdf {
+                ref name,
+                repository: Some(_),
+                executable: Some(ref executable),
+                force: false,
+            }
+        }) if name == "nodejs" && executable == "node"
+    ));
+}
+
+#[test]
+fn test_cli_capabilities_command() {
+    let cli = Cli::try_parse_from(["vx", "capabilities", "--format", "json"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Capabilities {
+            format: Some(OutputFormat::Json)
+        })
+    ));
+
+    let cli = Cli::try_parse_from(["vx", "capabilities"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Capabilities { format: None })
+    ));
+}
+
 // Note: Stats command has been removed from vx-cli
 /*
 #[test]
@@ -73,6 +194,17 @@ fn test_global_options_inherits_cli_cache_mode() {
     assert_eq!(options.cache_mode, CacheMode::Offline);
 }
 
+#[test]
+fn test_cli_isolated_flag() {
+    let cli = Cli::try_parse_from(["vx", "--isolated", "list"]).unwrap();
+    assert!(cli.isolated);
+    assert!(GlobalOptions::from(&cli).isolated);
+
+    // Global flag: also accepted after the subcommand
+    let cli = Cli::try_parse_from(["vx", "install", "node", "--isolated"]).unwrap();
+    assert!(cli.isolated);
+}
+
 // ============================================
 // List Command Tests
 // ============================================
@@ -83,7 +215,7 @@ fn test_cli_list_command() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::List {
+        Some(Commands::List(list::Args {
             tool,
             status,
             installed,
@@ -91,7 +223,8 @@ fn test_cli_list_command() {
             all,
             system,
             version_check,
-        }) => {
+            leaves,
+        })) => {
             assert!(tool.is_none());
             assert!(!status);
             assert!(!installed);
@@ -99,17 +232,39 @@ fn test_cli_list_command() {
             assert!(!all);
             assert!(!system);
             assert!(!version_check);
+            assert!(!leaves);
         }
         _ => panic!("Expected List command"),
     }
 }
 
+#[test]
+fn test_cli_list_leaves_and_rdeps() {
+    let cli = Cli::try_parse_from(["vx", "list", "--leaves"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::List(list::Args { leaves: true, .. }))
+    ));
+    assert!(Cli::try_parse_from(["vx", "list", "node", "--leaves"]).is_err());
+    assert!(Cli::try_parse_from(["vx", "list", "--system", "--leaves"]).is_err());
+
+    let cli = Cli::try_parse_from(["vx", "rdeps", "node", "--all"]).unwrap();
+    match cli.command {
+        Some(Commands::Rdeps { tool, all }) => {
+            assert_eq!(tool, "node");
+            assert!(all);
+        }
+        _ => panic!("Expected Rdeps command"),
+    }
+    assert!(Cli::try_parse_from(["vx", "rdeps"]).is_err());
+}
+
 #[test]
 fn test_cli_list_alias() {
     let args = vec!["vx", "ls"];
     let cli = Cli::try_parse_from(args).unwrap();
 
-    assert!(matches!(cli.command, Some(Commands::List { .. })));
+    assert!(matches!(cli.command, Some(Commands::List(_))));
 }
 
 #[test]
@@ -118,7 +273,7 @@ fn test_cli_list_with_options() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::List {
+        Some(Commands::List(list::Args {
             tool,
             status,
             installed,
@@ -126,7 +281,8 @@ fn test_cli_list_with_options() {
             all,
             system: _,
             version_check: _,
-        }) => {
+            leaves: _,
+        })) => {
             assert_eq!(tool, Some("node".to_string()));
             assert!(status);
             assert!(installed);
@@ -143,7 +299,7 @@ fn test_cli_list_all_flag() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::List { all, .. }) => {
+        Some(Commands::List(list::Args { all, .. })) => {
             assert!(all);
         }
         _ => panic!("Expected List command"),
@@ -160,7 +316,7 @@ fn test_cli_install_command() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Install { tools, force }) => {
+        Some(Commands::Install(install::Args { tools, force, .. })) => {
             assert_eq!(tools, vec!["node@18.0.0"]);
             assert!(!force);
         }
@@ -174,7 +330,7 @@ fn test_cli_install_multiple_tools() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Install { tools, force }) => {
+        Some(Commands::Install(install::Args { tools, force, .. })) => {
             assert_eq!(tools, vec!["node", "uv", "go@1.22"]);
             assert!(!force);
         }
@@ -187,7 +343,7 @@ fn test_cli_install_alias() {
     let args = vec!["vx", "i", "node"];
     let cli = Cli::try_parse_from(args).unwrap();
 
-    assert!(matches!(cli.command, Some(Commands::Install { .. })));
+    assert!(matches!(cli.command, Some(Commands::Install(_))));
 }
 
 #[test]
@@ -196,7 +352,7 @@ fn test_cli_install_with_force() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Install { tools, force }) => {
+        Some(Commands::Install(install::Args { tools, force, .. })) => {
             assert_eq!(tools, vec!["node"]);
             assert!(force);
         }
@@ -204,13 +360,114 @@ fn test_cli_install_with_force() {
     }
 }
 
+#[test]
+fn test_cli_install_with_yes() {
+    let cli = Cli::try_parse_from(["vx", "install", "node", "-y"]).unwrap();
+
+    match cli.command {
+        Some(Commands::Install(install::Args { tools, yes, .. })) => {
+            assert_eq!(tools, vec!["node"]);
+            assert!(yes);
+        }
+        _ => panic!("Expected Install command"),
+    }
+}
+
+#[test]
+fn test_cli_install_from_oci() {
+    let cli =
+        Cli::try_parse_from(["vx", "install", "--from-oci", "ghcr.io/org/tc:node-20"]).unwrap();
+
+    match cli.command {
+        Some(Commands::Install(install::Args {
+            tools, from_oci, ..
+        })) => {
+            assert!(tools.is_empty());
+            assert_eq!(from_oci.as_deref(), Some("ghcr.io/org/tc:node-20"));
+        }
+        _ => panic!("Expected Install command"),
+    }
+
+    // Tools are required without --from-oci, and conflict with it
+    assert!(Cli::try_parse_from(["vx", "install"]).is_err());
+    assert!(Cli::try_parse_from(["vx", "install", "node", "--from-oci", "ghcr.io/a/b"]).is_err());
+}
+
+#[test]
+fn test_cli_store_export_oci() {
+    let cli = Cli::try_parse_from([
+        "vx",
+        "store",
+        "export-oci",
+        "node@20.11.0",
+        "--ref",
+        "ghcr.io/org/tc:node-20",
+    ])
+    .unwrap();
+
+    match cli.command {
+        Some(Commands::Store {
+            command: StoreCommand::ExportOci { tool, reference },
+        }) => {
+            assert_eq!(tool, "node@20.11.0");
+            assert_eq!(reference, "ghcr.io/org/tc:node-20");
+        }
+        _ => panic!("Expected Store export-oci command"),
+    }
+}
+
+#[test]
+fn test_cli_preset_commands() {
+    let cli = Cli::try_parse_from([
+        "vx",
+        "preset",
+        "apply",
+        "python-ml",
+        "--index",
+        "github:acme/vx-presets",
+    ])
+    .unwrap();
+    match cli.command {
+        Some(Commands::Preset {
+            command: PresetCommand::Apply { name, index },
+        }) => {
+            assert_eq!(name, "python-ml");
+            assert_eq!(index.as_deref(), Some("github:acme/vx-presets"));
+        }
+        _ => panic!("Expected Preset apply command"),
+    }
+
+    let cli = Cli::try_parse_from([
+        "vx",
+        "preset",
+        "publish",
+        "node-fullstack",
+        "-d",
+        "Node",
+        "-t",
+        "node",
+        "-t",
+        "web",
+    ])
+    .unwrap();
+    match cli.command {
+        Some(Commands::Preset {
+            command: PresetCommand::Publish { tags, index, .. },
+        }) => {
+            assert_eq!(tags, ["node", "web"]);
+            assert!(index.is_none());
+        }
+        _ => panic!("Expected Preset publish command"),
+    }
+}
+
 #[test]
 fn test_cli_install_force_short() {
     let args = vec!["vx", "install", "go", "-f"];
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Install { force, .. }) => {
+        Some(Commands::Install(install::Args { force, .. })) => {
             assert!(force);
         }
         _ => panic!("Expected Install command"),
@@ -227,19 +484,53 @@ fn test_cli_uninstall_command() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Uninstall {
+        Some(Commands::Uninstall(UninstallArgs {
             tool,
             version,
             force,
-        }) => {
+            dry_run,
+        })) => {
             assert_eq!(tool, "node");
             assert_eq!(version, Some("18.0.0".to_string()));
             assert!(!force);
+            assert!(!dry_run);
         }
         _ => panic!("Expected Uninstall command"),
     }
 }
 
+#[test]
+fn test_cli_dry_run_on_mutating_commands() {
+    for args in [
+        vec!["vx", "install", "node@22", "--dry-run"],
+        vec!["vx", "uninstall", "node", "--dry-run"],
+        vec!["vx", "update", "--all", "--dry-run"],
+        vec!["vx", "rollback", "node", "--dry-run"],
+    ] {
+        let cli = Cli::try_parse_from(&args).unwrap();
+        let dry_run = match cli.command {
+            Some(Commands::Install(install::Args { dry_run, .. }))
+            | Some(Commands::Uninstall(UninstallArgs { dry_run, .. }))
+            | Some(Commands::Update(UpdateArgs { dry_run, .. }))
+            | Some(Commands::Rollback { dry_run, .. }) => dry_run,
+            _ => panic!("Unexpected command for {:?}", args),
+        };
+        assert!(dry_run, "{:?}", args);
+    }
+}
+
+#[test]
+fn test_cli_install_dry_run_conflicts_with_from_oci() {
+    let args = vec![
+        "vx",
+        "install",
+        "--from-oci",
+        "ghcr.io/org/tc:1",
+        "--dry-run",
+    ];
+    assert!(Cli::try_parse_from(args).is_err());
+}
+
 #[test]
 fn test_cli_uninstall_no_alias() {
     // After refactoring, `rm` should NOT be an alias for uninstall
@@ -263,7 +554,7 @@ fn test_cli_update_command() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Update { tool, apply }) => {
+        Some(Commands::Update(UpdateArgs { tool, apply })) => {
             assert!(tool.is_none());
             assert!(!apply);
         }
@@ -277,7 +568,7 @@ fn test_cli_update_alias() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Update { tool, .. }) => {
+        Some(Commands::Update(UpdateArgs { tool, .. })) => {
             assert_eq!(tool, Some("node".to_string()));
         }
         _ => panic!("Expected Update command"),
@@ -290,7 +581,7 @@ fn test_cli_update_with_apply() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Update { apply, .. }) => {
+        Some(Commands::Update(UpdateArgs { apply, .. })) => {
             assert!(apply);
         }
         _ => panic!("Expected Update command"),
@@ -302,20 +593,258 @@ fn test_cli_update_with_apply() {
 // Self-Update Command Tests
 // ============================================
 
+#[test]
+fn test_cli_update_all_apply_command() {
+    let args = vec![
+        "vx",
+        "update",
+        "--all",
+        "--apply",
+        "--summary",
+        "UPDATES.md",
+    ];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        Some(Commands::Update(UpdateArgs {
+            tools,
+            all,
+            apply,
+            summary,
+            ..
+        })) => {
+            assert!(tools.is_empty());
+            assert!(all);
+            assert!(apply);
+            assert_eq!(summary, Some(std::path::PathBuf::from("UPDATES.md")));
+        }
+        _ => panic!("Expected Update command"),
+    }
+}
+
+#[test]
+fn test_cli_update_specific_tools() {
+    let args = vec!["vx", "update", "node", "go"];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        Some(Commands::Update(UpdateArgs {
+            tools, all, apply, ..
+        })) => {
+            assert_eq!(tools, vec!["node", "go"]);
+            assert!(!all);
+            assert!(!apply);
+        }
+        _ => panic!("Expected Update command"),
+    }
+}
+
+#[test]
+fn test_cli_upgrade_all_dry_run_command() {
+    let args = vec!["vx", "upgrade", "--all", "--dry-run"];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        Some(Commands::Upgrade {
+            tools,
+            all,
+            dry_run,
+        }) => {
+            assert!(tools.is_empty());
+            assert!(all);
+            assert!(dry_run);
+        }
+        _ => panic!("Expected Upgrade command"),
+    }
+}
+
+#[test]
+fn test_cli_check_no_network() {
+    let args = vec!["vx", "check", "--no-network"];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        Some(Commands::Check(CheckArgs {
+            tool, no_network, ..
+        })) => {
+            assert!(tool.is_none());
+            assert!(no_network);
+        }
+        _ => panic!("Expected Check command"),
+    }
+}
+
+#[test]
+fn test_cli_rollback_command() {
+    let args = vec!["vx", "rollback", "node"];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        Some(Commands::Rollback { tool, dry_run }) => {
+            assert_eq!(tool, "node");
+            assert!(!dry_run);
+        }
+        _ => panic!("Expected Rollback command"),
+    }
+}
+
+#[test]
+fn test_cli_changelog_command() {
+    let args = vec!["vx", "changelog", "node", "20.11.0..20.12.0"];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        Some(Commands::Changelog { tool, range, limit }) => {
+            assert_eq!(tool, "node");
+            assert_eq!(range.as_deref(), Some("20.11.0..20.12.0"));
+            assert_eq!(limit, 20);
+        }
+        _ => panic!("Expected Changelog command"),
+    }
+}
+
+#[test]
+fn test_cli_sbom_command() {
+    let cli = Cli::try_parse_from(["vx", "sbom"]).unwrap();
+    match cli.command {
+        Some(Commands::Sbom { format, output }) => {
+            assert_eq!(format, SbomFormat::Cyclonedx);
+            assert!(output.is_none());
+        }
+        _ => panic!("Expected Sbom command"),
+    }
+
+    let cli =
+        Cli::try_parse_from(["vx", "sbom", "--format", "spdx", "-o", "vx.spdx.json"]).unwrap();
+    match cli.command {
+        Some(Commands::Sbom { format, output }) => {
+            assert_eq!(format, SbomFormat::Spdx);
+            assert_eq!(
+                output.as_deref(),
+                Some(std::path::Path::new("vx.spdx.json"))
+            );
+        }
+        _ => panic!("Expected Sbom command"),
+    }
+}
+
+#[test]
+fn test_cli_import_command() {
+    let cli =
+        Cli::try_parse_from(["vx", "import", "winget", "jqlang.jq@1.7.1", "--no-install"]).unwrap();
+    match cli.command {
+        Some(Commands::Import {
+            command:
+                ImportCommand::Winget {
+                    id,
+                    name,
+                    force,
+                    no_install,
+                },
+        }) => {
+            assert_eq!(id, "jqlang.jq@1.7.1");
+            assert!(name.is_none());
+            assert!(!force);
+            assert!(no_install);
+        }
+        _ => panic!("Expected Import winget command"),
+    }
+
+    let cli = Cli::try_parse_from(["vx", "import", "choco", "ripgrep", "--name", "rg"]).unwrap();
+    match cli.command {
+        Some(Commands::Import {
+            command: ImportCommand::Choco { id, name, .. },
+        }) => {
+            assert_eq!(id, "ripgrep");
+            assert_eq!(name.as_deref(), Some("rg"));
+        }
+        _ => panic!("Expected Import choco command"),
+    }
+}
+
+#[test]
+fn test_cli_bootstrap_command() {
+    let cli = Cli::try_parse_from(["vx", "bootstrap", "ssh", "ci@agent", "-p", "2222"]).unwrap();
+    match cli.command {
+        Some(Commands::Bootstrap {
+            command:
+                BootstrapCommand::Ssh {
+                    target,
+                    port,
+                    identity,
+                    no_project,
+                    ..
+                },
+        }) => {
+            assert_eq!(target, "ci@agent");
+            assert_eq!(port, Some(2222));
+            assert!(identity.is_none());
+            assert!(!no_project);
+        }
+        _ => panic!("Expected Bootstrap ssh command"),
+    }
+
+    let cli = Cli::try_parse_from(["vx", "bootstrap", "script", "--dir", "app"]).unwrap();
+    match cli.command {
+        Some(Commands::Bootstrap {
+            command: BootstrapCommand::Script { dir, output, .. },
+        }) => {
+            assert_eq!(dir.as_deref(), Some("app"));
+            assert!(output.is_none());
+        }
+        _ => panic!("Expected Bootstrap script command"),
+    }
+}
+
+#[test]
+fn test_cli_wrap_command() {
+    let cli = Cli::try_parse_from(["vx", "wrap", "node", "npm"]).unwrap();
+    match cli.command {
+        Some(Commands::Wrap { tools, dir, force }) => {
+            assert_eq!(tools, vec!["node", "npm"]);
+            assert_eq!(dir, std::path::PathBuf::from("tools"));
+            assert!(!force);
+        }
+        _ => panic!("Expected Wrap command"),
+    }
+
+    let cli = Cli::try_parse_from(["vx", "wrap", "--dir", "bin", "--force"]).unwrap();
+    match cli.command {
+        Some(Commands::Wrap { tools, dir, force }) => {
+            assert!(tools.is_empty());
+            assert_eq!(dir, std::path::PathBuf::from("bin"));
+            assert!(force);
+        }
+        _ => panic!("Expected Wrap command"),
+    }
+}
+
+#[test]
+fn test_cli_onboard_command() {
+    let cli = Cli::try_parse_from(["vx", "onboard", "--yes", "--restart"]).unwrap();
+    match cli.command {
+        Some(Commands::Onboard { yes, restart }) => {
+            assert!(yes);
+            assert!(restart);
+        }
+        _ => panic!("Expected Onboard command"),
+    }
+}
+
 #[test]
 fn test_cli_self_update_command() {
     let args = vec!["vx", "self-update"];
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::SelfUpdate {
+        Some(Commands::SelfUpdate(SelfUpdateArgs {
             check,
             version,
             token,
             channel,
             force,
             ..
-        }) => {
+        })) => {
             assert!(!check);
             assert!(version.is_none());
             assert!(token.is_none());
@@ -332,7 +861,7 @@ fn test_cli_self_update_check() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::SelfUpdate { check, .. }) => {
+        Some(Commands::SelfUpdate(SelfUpdateArgs { check, .. })) => {
             assert!(check);
         }
         _ => panic!("Expected SelfUpdate command"),
@@ -345,7 +874,7 @@ fn test_cli_self_update_with_version() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::SelfUpdate { version, .. }) => {
+        Some(Commands::SelfUpdate(SelfUpdateArgs { version, .. })) => {
             assert_eq!(version, Some("0.5.0".to_string()));
         }
         _ => panic!("Expected SelfUpdate command"),
@@ -388,13 +917,13 @@ fn test_cli_versions_command() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Versions {
+        Some(Commands::Versions(VersionsArgs {
             tool,
             latest,
             prerelease,
             detailed,
             interactive,
-        }) => {
+        })) => {
             assert_eq!(tool, "node");
             assert_eq!(latest, Some(5));
             assert!(prerelease);
@@ -411,7 +940,7 @@ fn test_cli_versions_interactive() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Versions { interactive, .. }) => {
+        Some(Commands::Versions(VersionsArgs { interactive, .. })) => {
             assert!(interactive);
         }
         _ => panic!("Expected Versions command"),
@@ -428,13 +957,13 @@ fn test_cli_search_command() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Search {
+        Some(Commands::Search(SearchArgs {
             query,
             category,
             installed_only,
             available_only,
             verbose,
-        }) => {
+        })) => {
             assert_eq!(query, Some("python".to_string()));
             assert_eq!(category, Some("language".to_string()));
             assert!(!installed_only);
@@ -453,7 +982,7 @@ fn test_cli_search_json_format() {
 
     assert!(cli.json);
     match cli.command {
-        Some(Commands::Search { .. }) => {}
+        Some(Commands::Search(_)) => {}
         _ => panic!("Expected Search command"),
     }
 }
@@ -468,7 +997,7 @@ fn test_cli_sync_command() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Sync {
+        Some(Commands::Sync(SyncArgs {
             check,
             force,
             dry_run,
@@ -476,7 +1005,8 @@ fn test_cli_sync_command() {
             no_parallel,
             no_auto_install,
             auto_lock,
-        }) => {
+            retries,
+        })) => {
             assert!(!check);
             assert!(!force);
             assert!(dry_run);
@@ -484,6 +1014,7 @@ fn test_cli_sync_command() {
             assert!(!no_parallel);
             assert!(!no_auto_install);
             assert!(!auto_lock);
+            assert!(retries.is_none());
         }
         _ => panic!("Expected Sync command"),
     }
@@ -495,7 +1026,7 @@ fn test_cli_sync_check_only() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Sync { check, .. }) => {
+        Some(Commands::Sync(SyncArgs { check, .. })) => {
             assert!(check);
         }
         _ => panic!("Expected Sync command"),
@@ -512,20 +1043,45 @@ fn test_cli_init_command() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Init {
+        Some(Commands::Init(InitArgs {
             interactive,
             template,
             tools,
             force,
             dry_run,
             list_templates,
-        }) => {
+            vars,
+        })) => {
             assert!(interactive);
             assert_eq!(template, Some("node".to_string()));
             assert!(tools.is_none());
             assert!(!force);
             assert!(!dry_run);
             assert!(!list_templates);
+            assert!(vars.is_empty());
+        }
+        _ => panic!("Expected Init command"),
+    }
+}
+
+#[test]
+fn test_cli_init_template_vars() {
+    let args = vec![
+        "vx",
+        "init",
+        "--template",
+        "acme-service",
+        "--var",
+        "node_version=22",
+        "--var",
+        "team=platform",
+    ];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        Some(Commands::Init(InitArgs { template, vars, .. })) => {
+            assert_eq!(template, Some("acme-service".to_string()));
+            assert_eq!(vars, vec!["node_version=22", "team=platform"]);
         }
         _ => panic!("Expected Init command"),
     }
@@ -537,7 +1093,7 @@ fn test_cli_init_list_templates() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Init { list_templates, .. }) => {
+        Some(Commands::Init(InitArgs { list_templates, .. })) => {
             assert!(list_templates);
         }
         _ => panic!("Expected Init command"),
@@ -615,37 +1171,57 @@ fn test_cli_setup_command() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Setup {
+        Some(Commands::Setup(SetupArgs {
             force,
             dry_run,
             verbose,
             no_parallel,
             no_hooks,
             ci,
-        }) => {
+            global,
+            yes,
+        })) => {
             assert!(!force);
             assert!(!dry_run);
             assert!(!verbose);
             assert!(!no_parallel);
             assert!(!no_hooks);
             assert!(!ci);
+            assert!(!global);
+            assert!(!yes);
         }
         _ => panic!("Expected Setup command"),
     }
 }
 
+#[test]
+fn test_cli_setup_global() {
+    let cli = Cli::try_parse_from(["vx", "setup", "--global", "--yes"]).unwrap();
+    match cli.command {
+        Some(Commands::Setup(SetupArgs { global, yes, .. })) => {
+            assert!(global);
+            assert!(yes);
+        }
+        _ => panic!("Expected Setup command"),
+    }
+
+    // --yes only applies to the global setup
+    assert!(Cli::try_parse_from(["vx", "setup", "--yes"]).is_err());
+    assert!(Cli::try_parse_from(["vx", "setup", "--global", "--ci"]).is_err());
+}
+
 #[test]
 fn test_cli_setup_with_options() {
     let args = vec!["vx", "setup", "--force", "--dry-run", "--no-hooks"];
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Setup {
+        Some(Commands::Setup(SetupArgs {
             force,
             dry_run,
             no_hooks,
             ..
-        }) => {
+        })) => {
             assert!(force);
             assert!(dry_run);
             assert!(no_hooks);
@@ -664,7 +1240,7 @@ fn test_cli_dev_command() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Dev {
+        Some(Commands::Dev(dev::Args {
             shell,
             command,
             no_install,
@@ -672,7 +1248,7 @@ fn test_cli_dev_command() {
             export,
             format,
             ..
-        }) => {
+        })) => {
             assert!(shell.is_none());
             assert!(command.is_none());
             assert!(!no_install);
@@ -690,7 +1266,7 @@ fn test_cli_dev_with_shell() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Dev { shell, .. }) => {
+        Some(Commands::Dev(dev::Args { shell, .. })) => {
             assert_eq!(shell, Some("bash".to_string()));
         }
         _ => panic!("Expected Dev command"),
@@ -703,7 +1279,7 @@ fn test_cli_dev_export() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Dev { export, format, .. }) => {
+        Some(Commands::Dev(dev::Args { export, format, .. })) => {
             assert!(export);
             assert_eq!(format, Some("powershell".to_string()));
         }
@@ -717,7 +1293,7 @@ fn test_cli_dev_with_command() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Dev { command, .. }) => {
+        Some(Commands::Dev(dev::Args { command, .. })) => {
             assert_eq!(
                 command,
                 Some(vec![
@@ -741,7 +1317,7 @@ fn test_cli_add_command_with_version_spec() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Add { tools, .. }) => {
+        Some(Commands::Add(AddArgs { tools, .. })) => {
             assert_eq!(tools, vec!["node@18.0.0".to_string()]);
         }
         _ => panic!("Expected Add command"),
@@ -754,9 +1330,9 @@ fn test_cli_add_without_version() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Add {
+        Some(Commands::Add(AddArgs {
             tools, no_install, ..
-        }) => {
+        })) => {
             assert_eq!(tools, vec!["python".to_string()]);
             assert!(!no_install);
         }
@@ -770,7 +1346,7 @@ fn test_cli_add_multiple_tools() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Add { tools, .. }) => {
+        Some(Commands::Add(AddArgs { tools, .. })) => {
             assert_eq!(
                 tools,
                 vec![
@@ -800,7 +1376,7 @@ fn test_cli_add_with_flags() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Add {
+        Some(Commands::Add(AddArgs {
             tools,
             no_install,
             no_lock,
@@ -808,7 +1384,7 @@ fn test_cli_add_with_flags() {
             force,
             os,
             ..
-        }) => {
+        })) => {
             assert_eq!(tools, vec!["node@22".to_string()]);
             assert!(no_install);
             assert!(no_lock);
@@ -872,7 +1448,7 @@ fn test_cli_run_command() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Run { script, args, .. }) => {
+        Some(Commands::Run(RunArgs { script, args, .. })) => {
             assert_eq!(script.as_deref(), Some("build"));
             assert!(args.is_empty());
         }
@@ -887,7 +1463,7 @@ fn test_cli_run_with_args() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Run { script, args, .. }) => {
+        Some(Commands::Run(RunArgs { script, args, .. })) => {
             assert_eq!(script.as_deref(), Some("test"));
             assert_eq!(args, vec!["--coverage"]);
         }
@@ -901,7 +1477,7 @@ fn test_cli_run_with_positional_args() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Run { script, args, .. }) => {
+        Some(Commands::Run(RunArgs { script, args, .. })) => {
             assert_eq!(script.as_deref(), Some("build"));
             assert_eq!(args, vec!["arg1", "arg2"]);
         }
@@ -909,6 +1485,44 @@ fn test_cli_run_with_positional_args() {
     }
 }
 
+#[test]
+fn test_cli_run_with_jobs() {
+    let args = vec!["vx", "run", "--jobs", "2", "ci", "-j", "4"];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        Some(Commands::Run(RunArgs {
+            script, jobs, args, ..
+        })) => {
+            assert_eq!(script.as_deref(), Some("ci"));
+            assert_eq!(jobs, Some(2));
+            // `-j` is not a vx flag, so it reaches the script
+            assert_eq!(args, vec!["-j", "4"]);
+        }
+        _ => panic!("Expected Run command"),
+    }
+}
+
+#[test]
+fn test_cli_run_with_watch() {
+    let args = vec!["vx", "run", "--watch", "build", "--release"];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        Some(Commands::Run(RunArgs {
+            script,
+            watch,
+            args,
+            ..
+        })) => {
+            assert_eq!(script.as_deref(), Some("build"));
+            assert!(watch);
+            assert_eq!(args, vec!["--release"]);
+        }
+        _ => panic!("Expected Run command"),
+    }
+}
+
 // ============================================
 // Global Flags Tests
 // ============================================
@@ -1009,10 +1623,10 @@ fn test_cli_metrics_tokens_subcommand() {
         Cli::try_parse_from(vec!["vx", "metrics", "tokens", "--last", "20", "--json"]).unwrap();
 
     match cli.command {
-        Some(Commands::Metrics {
+        Some(Commands::Metrics(MetricsArgs {
             command: Some(MetricsCommand::Tokens { last, json }),
             ..
-        }) => {
+        })) => {
             assert_eq!(last, 20);
             assert!(json);
         }
@@ -1020,6 +1634,56 @@ fn test_cli_metrics_tokens_subcommand() {
     }
 }
 
+#[test]
+fn test_cli_metrics_insights_subcommand() {
+    let cli = Cli::try_parse_from(vec!["vx", "metrics", "insights", "-n", "5"]).unwrap();
+
+    match cli.command {
+        Some(Commands::Metrics(MetricsArgs {
+            command: Some(MetricsCommand::Insights { last, json }),
+            ..
+        })) => {
+            assert_eq!(last, 5);
+            assert!(!json);
+        }
+        _ => panic!("Expected metrics insights command"),
+    }
+}
+
+#[test]
+fn test_cli_metrics_shim_subcommand() {
+    let cli = Cli::try_parse_from(vec!["vx", "metrics", "shim", "--json"]).unwrap();
+
+    match cli.command {
+        Some(Commands::Metrics(MetricsArgs {
+            command: Some(MetricsCommand::Shim { last, json }),
+            ..
+        })) => {
+            assert_eq!(last, 500);
+            assert!(json);
+        }
+        _ => panic!("Expected metrics shim command"),
+    }
+}
+
+#[test]
+fn test_cli_stats_network() {
+    let cli = Cli::try_parse_from(vec!["vx", "stats", "--network", "--json"]).unwrap();
+
+    match cli.command {
+        Some(Commands::Stats {
+            network,
+            json,
+            clear,
+        }) => {
+            assert!(network);
+            assert!(json);
+            assert!(!clear);
+        }
+        _ => panic!("Expected stats command"),
+    }
+}
+
 // ============================================
 // Help Tests
 // ============================================
@@ -1042,12 +1706,38 @@ fn test_cli_config_show() {
 
     match cli.command {
         Some(Commands::Config {
-            command: Some(ConfigCommand::Show),
+            command: Some(ConfigCommand::Show { origin: false }),
         }) => {}
         _ => panic!("Expected Config Show command"),
     }
 }
 
+#[test]
+fn test_cli_config_show_origin() {
+    let args = vec!["vx", "config", "show", "--origin"];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        Some(Commands::Config {
+            command: Some(ConfigCommand::Show { origin: true }),
+        }) => {}
+        _ => panic!("Expected Config Show --origin command"),
+    }
+}
+
+#[test]
+fn test_cli_config_explain() {
+    let args = vec!["vx", "config", "explain", "tools.node"];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        Some(Commands::Config {
+            command: Some(ConfigCommand::Explain { key }),
+        }) => assert_eq!(key, "tools.node"),
+        _ => panic!("Expected Config Explain command"),
+    }
+}
+
 #[test]
 fn test_cli_config_set() {
     let args = vec!["vx", "config", "set", "defaults.auto_install", "true"];
@@ -1104,6 +1794,81 @@ fn test_cli_services_start() {
     }
 }
 
+#[test]
+fn test_cli_services_supervise() {
+    let args = vec!["vx", "services", "supervise", "db", "--interval", "5"];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        Some(Commands::Services {
+            command:
+                ServicesCommand::Supervise {
+                    services, interval, ..
+                },
+        }) => {
+            assert_eq!(services, vec!["db"]);
+            assert_eq!(interval, 5);
+        }
+        _ => panic!("Expected Services Supervise command"),
+    }
+
+    let args = vec!["vx", "services", "start", "--supervise", "--foreground"];
+    assert!(Cli::try_parse_from(args).is_err());
+}
+
+#[test]
+fn test_cli_cache_serve() {
+    let args = vec!["vx", "cache", "serve", "--port", "9000", "--no-announce"];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        Some(Commands::Cache {
+            command: CacheCommand::Serve { port, no_announce },
+        }) => {
+            assert_eq!(port, Some(9000));
+            assert!(no_announce);
+        }
+        _ => panic!("Expected Cache Serve command"),
+    }
+}
+
+#[test]
+fn test_cli_cache_prune_limits() {
+    let args = vec![
+        "vx",
+        "cache",
+        "prune",
+        "--max-size",
+        "5GB",
+        "--older-than",
+        "30d",
+    ];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        Some(Commands::Cache {
+            command:
+                CacheCommand::Prune {
+                    max_size,
+                    older_than,
+                    temp,
+                    ..
+                },
+        }) => {
+            assert_eq!(max_size, Some(5 << 30));
+            assert_eq!(
+                older_than,
+                Some(std::time::Duration::from_secs(30 * 24 * 60 * 60))
+            );
+            assert!(!temp);
+        }
+        _ => panic!("Expected Cache Prune command"),
+    }
+
+    let args = vec!["vx", "cache", "prune", "--older-than", "soon"];
+    assert!(Cli::try_parse_from(args).is_err());
+}
+
 #[test]
 fn test_cli_services_stop() {
     let args = vec!["vx", "services", "stop"];
@@ -1128,17 +1893,42 @@ fn test_cli_services_logs() {
         Some(Commands::Services {
             command:
                 ServicesCommand::Logs {
-                    service,
+                    services,
                     follow,
                     tail,
+                    ..
                 },
         }) => {
-            assert_eq!(service, "redis");
+            assert_eq!(services, vec!["redis"]);
             assert!(follow);
             assert_eq!(tail, Some(100));
         }
         _ => panic!("Expected Services Logs command"),
     }
+
+    let args = vec![
+        "vx", "services", "logs", "api", "db", "--since", "10m", "--grep", "ERROR", "--json",
+    ];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        Some(Commands::Services {
+            command:
+                ServicesCommand::Logs {
+                    services,
+                    since,
+                    grep,
+                    json,
+                    ..
+                },
+        }) => {
+            assert_eq!(services, vec!["api", "db"]);
+            assert_eq!(since.as_deref(), Some("10m"));
+            assert_eq!(grep.as_deref(), Some("ERROR"));
+            assert!(json);
+        }
+        _ => panic!("Expected Services Logs command"),
+    }
 }
 
 // ============================================
@@ -1256,7 +2046,7 @@ fn test_cli_migrate_check() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Migrate { check, dry_run, .. }) => {
+        Some(Commands::Migrate(MigrateArgs { check, dry_run, .. })) => {
             assert!(check);
             assert!(!dry_run);
         }
@@ -1270,7 +2060,7 @@ fn test_cli_migrate_dry_run() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Migrate { dry_run, check, .. }) => {
+        Some(Commands::Migrate(MigrateArgs { dry_run, check, .. })) => {
             assert!(dry_run);
             assert!(!check);
         }
@@ -1284,7 +2074,7 @@ fn test_cli_migrate_with_path() {
     let cli = Cli::try_parse_from(args).unwrap();
 
     match cli.command {
-        Some(Commands::Migrate { path, verbose, .. }) => {
+        Some(Commands::Migrate(MigrateArgs { path, verbose, .. })) => {
             assert_eq!(path, Some("/some/path".to_string()));
             assert!(verbose);
         }
@@ -1324,6 +2114,86 @@ fn test_cli_container_generate() {
     }
 }
 
+#[test]
+fn test_cli_ci_generate() {
+    let cli = Cli::try_parse_from([
+        "vx",
+        "ci",
+        "generate",
+        "--provider",
+        "gitlab",
+        "-s",
+        "lint",
+        "--script",
+        "test",
+    ])
+    .unwrap();
+
+    match cli.command {
+        Some(Commands::Ci {
+            command:
+                CiCommand::Generate {
+                    provider,
+                    scripts,
+                    dry_run,
+                    force,
+                },
+        }) => {
+            assert_eq!(provider, CiProvider::Gitlab);
+            assert_eq!(scripts, vec!["lint".to_string(), "test".to_string()]);
+            assert!(!dry_run);
+            assert!(!force);
+        }
+        _ => panic!("Expected Ci Generate command"),
+    }
+
+    assert!(Cli::try_parse_from(["vx", "ci", "generate", "--provider", "jenkins"]).is_err());
+}
+
+#[test]
+fn test_cli_container_generate_devcontainer() {
+    let args = vec![
+        "vx",
+        "container",
+        "generate",
+        "--devcontainer",
+        "--publish-feature",
+        "features",
+    ];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        Some(Commands::Container {
+            command:
+                ContainerCommand::Generate {
+                    devcontainer,
+                    publish_feature,
+                    ..
+                },
+        }) => {
+            assert!(devcontainer);
+            assert_eq!(publish_feature, Some("features".to_string()));
+        }
+        _ => panic!("Expected Container Generate command"),
+    }
+
+    // A feature is only published alongside a devcontainer
+    assert!(
+        Cli::try_parse_from(["vx", "container", "generate", "--publish-feature", "f"]).is_err()
+    );
+    assert!(
+        Cli::try_parse_from([
+            "vx",
+            "container",
+            "generate",
+            "--devcontainer",
+            "--template",
+            "node"
+        ])
+        .is_err()
+    );
+}
+
 #[test]
 fn test_cli_container_build() {
     let args = vec![
@@ -1346,3 +2216,234 @@ fn test_cli_container_build() {
         _ => panic!("Expected Container Build command"),
     }
 }
+
+#[test]
+fn test_cli_exec_with_tools() {
+    let args = vec![
+        "vx",
+        "exec",
+        "--with",
+        "node@18",
+        "--with",
+        "python@3.11",
+        "--",
+        "node",
+        "-v",
+    ];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    assert_eq!(cli.with_deps, vec!["node@18", "python@3.11"]);
+    match cli.command {
+        Some(Commands::Exec { command }) => assert_eq!(command, vec!["node", "-v"]),
+        _ => panic!("Expected Exec command"),
+    }
+}
+
+#[test]
+fn test_cli_exec_requires_command() {
+    assert!(Cli::try_parse_from(["vx", "exec", "--with", "node@18"]).is_err());
+}
+
+#[test]
+fn test_cli_matrix_command() {
+    let cli = Cli::try_parse_from([
+        "vx",
+        "matrix",
+        "--tool",
+        "node=18,20",
+        "-t",
+        "pnpm=9",
+        "--parallel",
+        "--",
+        "pnpm",
+        "test",
+    ])
+    .unwrap();
+    match cli.command {
+        Some(Commands::Matrix(MatrixArgs {
+            tools,
+            parallel,
+            fail_fast,
+            command,
+        })) => {
+            assert_eq!(tools, vec!["node=18,20", "pnpm=9"]);
+            assert!(parallel);
+            assert!(!fail_fast);
+            assert_eq!(command, vec!["pnpm", "test"]);
+        }
+        _ => panic!("Expected Matrix command"),
+    }
+}
+
+#[test]
+fn test_cli_matrix_fail_fast_conflicts_with_parallel() {
+    assert!(
+        Cli::try_parse_from([
+            "vx",
+            "matrix",
+            "--tool",
+            "node=18",
+            "--parallel",
+            "--fail-fast",
+            "--",
+            "node",
+            "-v",
+        ])
+        .is_err()
+    );
+}
+
+#[test]
+fn test_cli_android_setup_command() {
+    let cli = Cli::try_parse_from([
+        "vx",
+        "android",
+        "setup",
+        "platforms;android-35",
+        "--sdk-root",
+        "./sdk",
+    ])
+    .unwrap();
+    match cli.command {
+        Some(Commands::Android {
+            command:
+                AndroidCommand::Setup {
+                    packages,
+                    sdk_root,
+                    version,
+                },
+        }) => {
+            assert_eq!(packages, vec!["platforms;android-35"]);
+            assert_eq!(sdk_root, Some(std::path::PathBuf::from("./sdk")));
+            assert_eq!(version, None);
+        }
+        _ => panic!("Expected Android setup command"),
+    }
+}
+
+#[test]
+fn test_exec_requires_with_tools() {
+    use vx_cli::commands::exec::parse_exec_tools;
+
+    assert!(parse_exec_tools(&[]).is_err());
+    let tools = parse_exec_tools(&["node@18".to_string(), "uv".to_string()]).unwrap();
+    assert_eq!(tools[0].runtime, "node");
+    assert_eq!(tools[0].version.as_deref(), Some("18"));
+    assert_eq!(tools[1].runtime, "uv");
+    assert_eq!(tools[1].version, None);
+}
+
+#[test]
+fn test_cli_activate_command() {
+    let cli = Cli::try_parse_from(["vx", "activate"]).unwrap();
+    match cli.command {
+        Some(Commands::Activate { env, shell }) => {
+            assert!(env.is_none());
+            assert!(shell.is_none());
+        }
+        _ => panic!("Expected Activate command"),
+    }
+
+    let cli = Cli::try_parse_from(["vx", "activate", "my-env", "--shell", "fish"]).unwrap();
+    match cli.command {
+        Some(Commands::Activate { env, shell }) => {
+            assert_eq!(env.as_deref(), Some("my-env"));
+            assert_eq!(shell.as_deref(), Some("fish"));
+        }
+        _ => panic!("Expected Activate command"),
+    }
+}
+
+#[test]
+fn test_cli_deactivate_command() {
+    let cli = Cli::try_parse_from(["vx", "deactivate", "--shell", "powershell"]).unwrap();
+    match cli.command {
+        Some(Commands::Deactivate { shell }) => assert_eq!(shell.as_deref(), Some("powershell")),
+        _ => panic!("Expected Deactivate command"),
+    }
+}
+
+#[test]
+fn test_activate_resolve_shell() {
+    use vx_cli::commands::activate::resolve_shell;
+    use vx_env::shell::Shell;
+
+    assert_eq!(resolve_shell(Some("zsh")).unwrap(), Shell::Zsh);
+    assert_eq!(resolve_shell(Some("/usr/bin/fish")).unwrap(), Shell::Fish);
+    assert!(resolve_shell(Some("tcsh")).is_err());
+}
+
+#[test]
+fn test_cli_export_command() {
+    let cli = Cli::try_parse_from(["vx", "export", "brewfile"]).unwrap();
+    match cli.command {
+        Some(Commands::Export {
+            command: ExportCommand::Brewfile { output },
+        }) => assert!(output.is_none()),
+        _ => panic!("Expected Export brewfile command"),
+    }
+
+    let cli = Cli::try_parse_from(["vx", "export", "scoop", "-o", "scoop.json"]).unwrap();
+    match cli.command {
+        Some(Commands::Export {
+            command: ExportCommand::Scoop { output },
+        }) => assert_eq!(output, Some(std::path::PathBuf::from("scoop.json"))),
+        _ => panic!("Expected Export scoop command"),
+    }
+}
+
+#[test]
+fn test_cli_auth_login_registry() {
+    let cli = Cli::try_parse_from([
+        "vx",
+        "auth",
+        "login",
+        "registry",
+        "https://registry.corp.example",
+        "--username",
+        "alice",
+        "--with-token",
+    ])
+    .unwrap();
+    match cli.command {
+        Some(Commands::Auth {
+            command:
+                AuthCommand::Login {
+                    service,
+                    url,
+                    username,
+                    with_token,
+                    token,
+                },
+        }) => {
+            assert_eq!(service, "registry");
+            assert_eq!(url.as_deref(), Some("https://registry.corp.example"));
+            assert_eq!(username.as_deref(), Some("alice"));
+            assert!(with_token);
+            assert!(token.is_none());
+        }
+        _ => panic!("Expected Auth login command"),
+    }
+
+    let cli = Cli::try_parse_from(["vx", "auth", "status"]).unwrap();
+    match cli.command {
+        Some(Commands::Auth {
+            command: AuthCommand::Status { service },
+        }) => assert_eq!(service, "all"),
+        _ => panic!("Expected Auth status command"),
+    }
+}
+
+#[test]
+fn test_cli_env_diff() {
+    let cli = Cli::try_parse_from(["vx", "env", "diff", "default", "lock"]).unwrap();
+    match cli.command {
+        Some(Commands::Env {
+            command: vx_cli::commands::env::EnvCommand::Diff { from, to },
+        }) => {
+            assert_eq!(from, "default");
+            assert_eq!(to.as_deref(), Some("lock"));
+        }
+        _ => panic!("Expected Env diff command"),
+    }
+}
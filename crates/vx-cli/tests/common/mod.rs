@@ -2,7 +2,7 @@
 
 #![allow(dead_code)]
 
-use std::io::{self, ErrorKind};
+use std::io::{self, ErrorKind, Read};
 use std::path::{Path, PathBuf};
 use std::process::{Command, Output, Stdio};
 use std::sync::Once;
@@ -137,9 +137,18 @@ pub fn run_command_with_timeout(mut cmd: Command, timeout: Duration) -> io::Resu
     let child_id = child.id();
     let start = Instant::now();
 
+    // Drain the pipes while waiting: a child whose output exceeds the pipe
+    // buffer would otherwise block forever on write.
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
     loop {
-        if child.try_wait()?.is_some() {
-            return child.wait_with_output();
+        if let Some(status) = child.try_wait()? {
+            return Ok(Output {
+                status,
+                stdout: stdout.join().unwrap_or_default(),
+                stderr: stderr.join().unwrap_or_default(),
+            });
         }
 
         if start.elapsed() >= timeout {
@@ -160,6 +169,16 @@ pub fn run_command_with_timeout(mut cmd: Command, timeout: Duration) -> io::Resu
     }
 }
 
+fn drain(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        buf
+    })
+}
+
 fn e2e_timeout() -> Duration {
     std::env::var("VX_E2E_TIMEOUT_SECS")
         .ok()
@@ -0,0 +1,49 @@
+//! Tests for devcontainer generation
+
+use std::path::PathBuf;
+use vx_cli::commands::container::{
+    devcontainer_files, feature_files, render_feature_install_script,
+};
+use vx_config::parse_config_str;
+
+#[test]
+fn test_devcontainer_files() {
+    let config = parse_config_str("[tools]\nnode = \"20\"\n").unwrap();
+
+    let files = devcontainer_files(&config, "0.9.0");
+    let paths: Vec<PathBuf> = files.iter().map(|(path, _)| path.clone()).collect();
+    assert_eq!(
+        paths,
+        [
+            PathBuf::from("devcontainer.json"),
+            PathBuf::from("vx").join("devcontainer-feature.json"),
+            PathBuf::from("vx").join("install.sh"),
+        ]
+    );
+
+    let devcontainer: serde_json::Value = serde_json::from_str(&files[0].1).unwrap();
+    assert_eq!(devcontainer["features"]["./vx"]["version"], "0.9.0");
+    assert_eq!(devcontainer["postCreateCommand"], "vx setup");
+
+    let feature: serde_json::Value = serde_json::from_str(&files[1].1).unwrap();
+    assert_eq!(feature["options"]["tools"]["default"], "node@20");
+}
+
+#[test]
+fn test_feature_files_match_local_feature() {
+    let config = parse_config_str("[tools]\ngo = \"1.22\"\n").unwrap();
+    let local = devcontainer_files(&config, "0.9.0");
+    let standalone = feature_files(&config, "0.9.0");
+    assert_eq!(standalone.len(), 2);
+    assert_eq!(standalone[0].1, local[1].1);
+    assert_eq!(standalone[1].1, local[2].1);
+}
+
+#[test]
+fn test_feature_install_script() {
+    let script = render_feature_install_script("0.9.0");
+    assert!(script.starts_with("#!/bin/sh\n"));
+    assert!(script.contains("(vx 0.9.0)"));
+    assert!(script.contains("VX_VERSION=\"$VERSION\" VX_INSTALL_DIR=/usr/local/bin bash"));
+    assert!(script.contains("su \"$REMOTE_USER\" -c \"vx install $TOOLS\""));
+}
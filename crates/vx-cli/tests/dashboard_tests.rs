@@ -0,0 +1,170 @@
+//! Tests for the `vx ui` dashboard state and rendering
+
+use dialoguer::console::measure_text_width;
+use vx_cli::commands::dashboard::{
+    Action, Dashboard, DiskEntry, InstalledVersion, KeyInput, ServiceEntry, Tab,
+};
+use vx_cli::commands::update::ToolUpdate;
+
+fn installed(tool: &str, version: &str, pinned: bool) -> InstalledVersion {
+    InstalledVersion {
+        tool: tool.to_string(),
+        version: version.to_string(),
+        size: 40 * 1024 * 1024,
+        pinned,
+    }
+}
+
+fn update(name: &str, current: &str, target: &str) -> ToolUpdate {
+    ToolUpdate {
+        name: name.to_string(),
+        constraint: "^1".to_string(),
+        current: Some(current.to_string()),
+        target: Some(target.to_string()),
+        released: None,
+        lts: false,
+        latest: Some(target.to_string()),
+        notes: Vec::new(),
+        error: None,
+    }
+}
+
+fn dashboard() -> Dashboard {
+    Dashboard::new(
+        vec![
+            installed("node", "22.11.0", true),
+            installed("node", "20.18.0", false),
+            installed("uv", "0.5.1", false),
+        ],
+        vec![DiskEntry {
+            label: "Tool store".to_string(),
+            size: 120 * 1024 * 1024,
+        }],
+        Ok(vec![ServiceEntry {
+            name: "postgres".to_string(),
+            image: "postgres:16".to_string(),
+            running: true,
+            health: Some("healthy".to_string()),
+        }]),
+    )
+}
+
+#[test]
+fn test_navigation() {
+    let mut dash = dashboard();
+    assert_eq!(dash.handle_key(KeyInput::Down), None);
+    assert_eq!(dash.selected_version().unwrap().version, "20.18.0");
+    dash.handle_key(KeyInput::End);
+    assert_eq!(dash.selected(), 2);
+    dash.handle_key(KeyInput::Down);
+    assert_eq!(dash.selected(), 2);
+    dash.handle_key(KeyInput::Char('g'));
+    assert_eq!(dash.selected(), 0);
+
+    // Opening the Updates tab checks for updates once
+    assert_eq!(dash.handle_key(KeyInput::Right), Some(Action::CheckUpdates));
+    assert_eq!(dash.tab, Tab::Updates);
+    dash.updates = Some(Ok(vec![update("uv", "0.5.1", "0.6.0")]));
+    dash.handle_key(KeyInput::Char('4'));
+    assert_eq!(dash.tab, Tab::Services);
+    assert_eq!(dash.handle_key(KeyInput::Char('2')), None);
+    dash.handle_key(KeyInput::BackTab);
+    assert_eq!(dash.tab, Tab::Tools);
+    assert_eq!(dash.handle_key(KeyInput::Char('q')), Some(Action::Quit));
+}
+
+#[test]
+fn test_actions() {
+    let mut dash = dashboard();
+    assert_eq!(
+        dash.handle_key(KeyInput::Char('s')),
+        None,
+        "the pinned version is already in use"
+    );
+    assert!(
+        dash.status
+            .as_deref()
+            .unwrap()
+            .contains("already uses node 22.11.0")
+    );
+
+    dash.handle_key(KeyInput::Down);
+    assert_eq!(dash.status, None);
+    assert_eq!(
+        dash.handle_key(KeyInput::Char('s')),
+        Some(Action::Switch {
+            tool: "node".to_string(),
+            version: "20.18.0".to_string()
+        })
+    );
+    assert_eq!(
+        dash.handle_key(KeyInput::Char('u')),
+        Some(Action::Uninstall {
+            tool: "node".to_string(),
+            version: "20.18.0".to_string()
+        })
+    );
+    assert_eq!(
+        dash.handle_key(KeyInput::Char('i')),
+        Some(Action::PromptInstall {
+            default: "node".to_string()
+        })
+    );
+    assert_eq!(dash.handle_key(KeyInput::Char('r')), Some(Action::Refresh));
+
+    dash.tab = Tab::Updates;
+    dash.updates = Some(Ok(vec![
+        update("go", "1.23.0", "1.23.0"),
+        update("uv", "0.5.1", "0.6.0"),
+    ]));
+    assert_eq!(dash.handle_key(KeyInput::Char('i')), None);
+    assert!(dash.status.as_deref().unwrap().contains("go has no update"));
+    dash.handle_key(KeyInput::Down);
+    assert_eq!(
+        dash.handle_key(KeyInput::Char('i')),
+        Some(Action::Install("uv@0.6.0".to_string()))
+    );
+    assert_eq!(dash.handle_key(KeyInput::Char('u')), None);
+    assert_eq!(
+        dash.handle_key(KeyInput::Char('r')),
+        Some(Action::CheckUpdates)
+    );
+}
+
+#[test]
+fn test_render() {
+    let mut dash = dashboard();
+    let lines = dash.render(80, 12);
+    assert_eq!(lines.len(), 12);
+    assert!(lines.iter().all(|line| measure_text_width(line) <= 80));
+    assert!(lines[0].contains("1 Tools") && lines[0].contains("4 Services"));
+    assert!(lines[3].contains("› node") && lines[3].contains("22.11.0"));
+    assert!(lines[3].contains("40.0 MB") && lines[3].contains("project"));
+    assert!(lines[11].contains("u uninstall"));
+    // Narrow terminals truncate rows instead of wrapping them
+    assert!(
+        dash.render(30, 12)
+            .iter()
+            .all(|line| measure_text_width(line) <= 30)
+    );
+
+    // The selection stays visible in a short window
+    dash.handle_key(KeyInput::End);
+    let lines = dash.render(60, 6);
+    assert_eq!(lines.len(), 6);
+    assert!(lines[3].contains("› uv"));
+
+    dash.handle_key(KeyInput::Right);
+    let lines = dash.render(60, 12);
+    assert!(lines[2].contains("Press r to check"));
+    dash.updates = Some(Err("No vx.toml found in this directory".to_string()));
+    assert!(dash.render(60, 12)[2].contains("No vx.toml found"));
+    dash.updates = Some(Ok(vec![update("uv", "0.5.1", "0.6.0")]));
+    assert!(dash.render(80, 12)[3].contains("→ 0.6.0"));
+
+    dash.handle_key(KeyInput::Right);
+    assert!(dash.render(80, 12)[3].contains("Tool store"));
+    dash.handle_key(KeyInput::Right);
+    let row = &dash.render(80, 12)[3];
+    assert!(row.contains("postgres:16") && row.contains("healthy"));
+}
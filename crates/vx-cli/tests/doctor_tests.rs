@@ -0,0 +1,130 @@
+//! Tests for the antivirus heuristics of `vx doctor`
+
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+use vx_cli::commands::doctor::{
+    AntivirusReport, AntivirusVerdict, benchmark_small_files, detect_scanners, exclusion_command,
+    parse_defender_status,
+};
+
+fn report(scanners: &[&str], store_excluded: Option<bool>, micros: u64) -> AntivirusReport {
+    AntivirusReport {
+        store: PathBuf::from("/home/dev/.vx/store"),
+        scanners: scanners.iter().map(|s| s.to_string()).collect(),
+        realtime_protection: None,
+        store_excluded,
+        small_file_micros: Some(micros),
+    }
+}
+
+#[test]
+fn test_detect_scanners() {
+    let processes: Vec<String> = [
+        "System",
+        "MsMpEng.exe",
+        "/usr/bin/ssh",
+        "/Library/Application Support/Microsoft/MAU/wdavdaemon",
+        "C:\\Program Files\\CrowdStrike\\CSFalconService.exe",
+        "falcond",
+        "clamd-helper",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+
+    assert_eq!(
+        detect_scanners(&processes),
+        [
+            "Microsoft Defender",
+            "Microsoft Defender for Endpoint",
+            "CrowdStrike Falcon"
+        ]
+    );
+    assert!(detect_scanners(&["bash".to_string()]).is_empty());
+}
+
+#[test]
+fn test_parse_defender_status() {
+    let store = Path::new("C:/Users/dev/.vx/store");
+
+    let output = "realtime=True\r\nexclusion=C:\\Users\\dev\\.vx\\\r\nexclusion=D:\\build\r\n";
+    assert_eq!(
+        parse_defender_status(output, store),
+        (Some(true), Some(true))
+    );
+
+    let output = "realtime=True\nexclusion=C:\\Users\\dev\\.vx-old\n";
+    assert_eq!(
+        parse_defender_status(output, store),
+        (Some(true), Some(false))
+    );
+
+    // Exclusions are hidden from non-administrators
+    let output = "realtime=True\nexclusion=N/A: Must be an administrator to view exclusions\n";
+    assert_eq!(parse_defender_status(output, store), (Some(true), None));
+
+    assert_eq!(
+        parse_defender_status("realtime=False\n", store),
+        (Some(false), Some(false))
+    );
+    assert_eq!(parse_defender_status("", store), (None, None));
+}
+
+#[test]
+fn test_antivirus_verdict() {
+    let slow = 5_000;
+    let fast = 200;
+
+    assert_eq!(
+        report(&["Microsoft Defender"], Some(false), slow).verdict(),
+        AntivirusVerdict::Interfering
+    );
+    assert_eq!(
+        report(&["Microsoft Defender"], None, fast).verdict(),
+        AntivirusVerdict::Scanning
+    );
+    assert_eq!(
+        report(&["Microsoft Defender"], Some(true), slow).verdict(),
+        AntivirusVerdict::Excluded
+    );
+    assert_eq!(
+        report(&[], None, slow).verdict(),
+        AntivirusVerdict::SlowDisk
+    );
+    assert_eq!(report(&[], None, fast).verdict(), AntivirusVerdict::Clear);
+
+    let realtime = AntivirusReport {
+        realtime_protection: Some(true),
+        ..report(&[], Some(false), slow)
+    };
+    assert_eq!(realtime.verdict(), AntivirusVerdict::Interfering);
+}
+
+#[test]
+fn test_exclusion_command() {
+    let store = Path::new("/home/dev/.vx/store");
+    assert!(exclusion_command(&["ClamAV".to_string()], store).is_none());
+
+    let defender = if cfg!(windows) {
+        "Microsoft Defender"
+    } else {
+        "Microsoft Defender for Endpoint"
+    };
+    let command = exclusion_command(&[defender.to_string()], store).unwrap();
+    let line = command.join(" ");
+    assert!(line.contains("/home/dev/.vx/store"));
+    if cfg!(windows) {
+        assert!(line.contains("-Verb RunAs") && line.contains("Add-MpPreference"));
+    } else {
+        assert_eq!(command[..3], ["sudo", "mdatp", "exclusion"]);
+    }
+}
+
+#[test]
+fn test_benchmark_small_files_cleans_up() {
+    let temp = TempDir::new().unwrap();
+    let per_file = benchmark_small_files(temp.path()).unwrap();
+
+    assert!(per_file.as_secs() < 1);
+    assert_eq!(std::fs::read_dir(temp.path()).unwrap().count(), 0);
+}
@@ -0,0 +1,43 @@
+//! Tests for `vx env diff` comparisons
+
+use std::collections::BTreeMap;
+use vx_cli::commands::env::diff::{DiffEntry, EnvDiff};
+
+fn tools(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+    entries
+        .iter()
+        .map(|(name, version)| (name.to_string(), version.to_string()))
+        .collect()
+}
+
+fn entry(name: &str, from: Option<&str>, to: Option<&str>) -> DiffEntry {
+    DiffEntry {
+        name: name.to_string(),
+        from: from.map(str::to_string),
+        to: to.map(str::to_string),
+    }
+}
+
+#[test]
+fn test_diff_reports_added_removed_and_changed() {
+    let from = tools(&[("go", "1.22.0"), ("node", "18.19.0"), ("uv", "0.5.0")]);
+    let to = tools(&[("node", "20.10.0"), ("pnpm", "9.0.0"), ("uv", "0.5.0")]);
+
+    let diff = EnvDiff::between("default", &from, "project", &to);
+    assert!(!diff.is_empty());
+    assert_eq!(diff.added, vec![entry("pnpm", None, Some("9.0.0"))]);
+    assert_eq!(diff.removed, vec![entry("go", Some("1.22.0"), None)]);
+    assert_eq!(
+        diff.changed,
+        vec![entry("node", Some("18.19.0"), Some("20.10.0"))]
+    );
+    assert_eq!(diff.unchanged, 1);
+}
+
+#[test]
+fn test_diff_identical_environments() {
+    let side = tools(&[("node", "20.10.0")]);
+    let diff = EnvDiff::between("a", &side, "b", &side);
+    assert!(diff.is_empty());
+    assert_eq!(diff.unchanged, 1);
+}
@@ -0,0 +1,110 @@
+//! Tests for end-of-life schedules and warnings
+
+use chrono::NaiveDate;
+use vx_cli::eol::{EolPin, EolSchedule, find_eol_pins, take_due_warnings};
+use vx_cli::registry::find_eol_schedule;
+
+fn date(s: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+}
+
+fn schedule(pairs: &[(&str, &str)]) -> EolSchedule {
+    let pairs: Vec<(String, String)> = pairs
+        .iter()
+        .map(|(line, d)| (line.to_string(), d.to_string()))
+        .collect();
+    EolSchedule::from_pairs(&pairs)
+}
+
+fn pin(tool: &str, version: &str, eol: &str) -> EolPin {
+    EolPin {
+        tool: tool.to_string(),
+        version: version.to_string(),
+        eol: date(eol),
+    }
+}
+
+#[test]
+fn test_eol_date_matches_most_specific_line() {
+    let python = schedule(&[
+        ("3", "2099-01-01"),
+        ("3.8", "2024-10-07"),
+        ("3.1", "2012-04-09"),
+    ]);
+    assert_eq!(python.eol_date("3.8.10"), Some(date("2024-10-07")));
+    assert_eq!(python.eol_date("3.8"), Some(date("2024-10-07")));
+    assert_eq!(python.eol_date("v3.8.1"), Some(date("2024-10-07")));
+    assert_eq!(python.eol_date("~3.8"), Some(date("2024-10-07")));
+    // `3.10` is not on the `3.1` line
+    assert_eq!(python.eol_date("3.10.4"), Some(date("2099-01-01")));
+    assert_eq!(python.eol_date("latest"), None);
+
+    let node = schedule(&[("18", "2025-04-30"), ("20", "not a date")]);
+    assert_eq!(node.eol_date("18.20.1"), Some(date("2025-04-30")));
+    assert_eq!(node.eol_date("180.1"), None);
+    assert_eq!(node.eol_date("20.11.1"), None);
+    assert!(EolSchedule::default().is_empty());
+}
+
+#[test]
+fn test_find_eol_pins() {
+    let node = schedule(&[("18", "2025-04-30"), ("20", "2026-04-30")]);
+    let pins = vec![
+        ("node".to_string(), "20.11.1".to_string()),
+        ("uv".to_string(), "0.5.0".to_string()),
+        ("node-legacy".to_string(), "18.20.1".to_string()),
+    ];
+    let found = find_eol_pins(pins.clone(), date("2025-10-14"), |tool| {
+        tool.starts_with("node").then(|| node.clone())
+    });
+    assert_eq!(found, [pin("node-legacy", "18.20.1", "2025-04-30")]);
+
+    let found = find_eol_pins(pins, date("2026-04-30"), |tool| {
+        tool.starts_with("node").then(|| node.clone())
+    });
+    assert_eq!(
+        found,
+        [
+            pin("node", "20.11.1", "2026-04-30"),
+            pin("node-legacy", "18.20.1", "2025-04-30")
+        ]
+    );
+    assert!(
+        found[0]
+            .message()
+            .contains("node 20.11.1 reached end-of-life on 2026-04-30")
+    );
+}
+
+#[test]
+fn test_take_due_warnings_once_per_day() {
+    let temp = tempfile::tempdir().unwrap();
+    let state = temp.path().join("state").join("eol-warnings");
+    let node = pin("node", "18.20.1", "2025-04-30");
+    let python = pin("python", "3.8.10", "2024-10-07");
+    let now = 1_760_000_000;
+
+    assert_eq!(
+        take_due_warnings(&state, vec![node.clone()], now),
+        vec![node.clone()]
+    );
+    assert!(take_due_warnings(&state, vec![node.clone()], now + 3600).is_empty());
+    assert_eq!(
+        take_due_warnings(&state, vec![node.clone(), python.clone()], now + 7200),
+        vec![python.clone()]
+    );
+    assert_eq!(
+        take_due_warnings(&state, vec![node.clone(), python], now + 24 * 3600),
+        [node]
+    );
+}
+
+#[test]
+fn test_builtin_schedules() {
+    let node = find_eol_schedule("node").unwrap();
+    assert_eq!(node.eol_date("16.20.2"), Some(date("2023-09-11")));
+    assert!(find_eol_schedule("npm").is_none());
+
+    let python = find_eol_schedule("python3").unwrap();
+    assert_eq!(python.eol_date("3.8.10"), Some(date("2024-10-07")));
+}
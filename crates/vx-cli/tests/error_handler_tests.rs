@@ -4,6 +4,8 @@
 //! without panicking and produce expected output patterns.
 
 use std::path::PathBuf;
+use vx_cli::error_handler::ErrorReport;
+use vx_cli::suggestions::Suggestion;
 use vx_resolver::{EnsureError, ExecuteError, PipelineError, PrepareError, ResolveError};
 
 /// Helper: call handle_pipeline_error without triggering process::exit
@@ -200,3 +202,46 @@ fn test_execute_error_bundle_failed() {
     let code = vx_cli::error_handler::handle_pipeline_error(&err);
     assert_eq!(code, 1);
 }
+
+// Test structured error reports
+#[test]
+fn test_error_report_for_pipeline_error() {
+    let err = anyhow::Error::new(PipelineError::Ensure(EnsureError::AutoInstallDisabled {
+        runtime: "node".to_string(),
+        version: "20.0.0".to_string(),
+    }));
+    let report = ErrorReport::from_error(&err);
+    assert_eq!(report.category, "install");
+    assert!(!report.message.starts_with("install:"));
+    assert_eq!(
+        report.suggestions[0],
+        Suggestion::run("vx install node@20.0.0")
+    );
+    assert!(report.suggestions.iter().any(|s| matches!(
+        s,
+        Suggestion::Config { key, value, .. } if key == "defaults.auto_install" && value == "true"
+    )));
+}
+
+#[test]
+fn test_error_report_for_stage_error() {
+    let err = anyhow::Error::new(ResolveError::RuntimeNotFound {
+        name: "nodee".to_string(),
+    });
+    let report = ErrorReport::from_error(&err);
+    assert_eq!(report.category, "resolve");
+    assert_eq!(
+        report.suggestions[0],
+        Suggestion::command("vx list", "See all supported runtimes")
+    );
+}
+
+#[test]
+fn test_error_report_for_generic_error_lists_causes() {
+    let err = anyhow::anyhow!("disk full").context("failed to write lockfile");
+    let json = serde_json::to_value(ErrorReport::from_error(&err)).unwrap();
+    assert_eq!(json["category"], "error");
+    assert_eq!(json["message"], "failed to write lockfile");
+    assert_eq!(json["causes"], serde_json::json!(["disk full"]));
+    assert_eq!(json["suggestions"], serde_json::json!([]));
+}
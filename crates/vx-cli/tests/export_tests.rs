@@ -0,0 +1,65 @@
+//! Tests for `vx export brewfile` / `vx export scoop` manifest rendering
+
+use std::collections::BTreeMap;
+use vx_cli::commands::export::{ExportEntry, export_entries, render_brewfile, render_scoop};
+
+fn tools(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_export_entries_use_provider_packages() {
+    let entries = export_entries(&tools(&[("7zip", "latest"), ("node", "20")]), "brew");
+
+    assert_eq!(entries[0].package, "sevenzip");
+    // Tools without a declared formula fall back to their own name
+    assert_eq!(entries[1].package, "node");
+    assert_eq!(entries[1].version, "20");
+}
+
+#[test]
+fn test_render_brewfile() {
+    let entries = export_entries(&tools(&[("bash", "5"), ("node", "20")]), "brew");
+    let brewfile = render_brewfile("demo", &entries);
+
+    assert!(brewfile.starts_with("# Generated by `vx export brewfile` from vx.toml (demo)"));
+    assert!(brewfile.contains("brew \"bash\" # vx: bash = \"5\"\n"));
+    assert!(brewfile.contains("brew \"node\" # vx: node = \"20\"\n"));
+}
+
+#[test]
+fn test_render_brewfile_dedupes_packages() {
+    let entries = vec![
+        ExportEntry {
+            tool: "node".to_string(),
+            package: "node".to_string(),
+            version: "20".to_string(),
+        },
+        ExportEntry {
+            tool: "npm".to_string(),
+            package: "node".to_string(),
+            version: "20".to_string(),
+        },
+    ];
+    let brewfile = render_brewfile("demo", &entries);
+    assert_eq!(brewfile.matches("brew \"node\"").count(), 1);
+}
+
+#[test]
+fn test_render_scoop_manifest() {
+    let entries = export_entries(&tools(&[("bash", "latest"), ("xmake", "2.9")]), "scoop");
+    let json: serde_json::Value = serde_json::from_str(&render_scoop(&entries).unwrap()).unwrap();
+
+    assert_eq!(json["buckets"][0]["Name"], "main");
+    let apps = json["apps"].as_array().unwrap();
+    assert_eq!(apps.len(), 2);
+    // bash is provided by Git for Windows on Scoop
+    assert_eq!(apps[0]["Name"], "git");
+    assert_eq!(apps[0]["Info"], "vx: bash");
+    assert_eq!(apps[1]["Name"], "xmake");
+    assert_eq!(apps[1]["Version"], "2.9");
+    assert_eq!(apps[1]["Source"], "main");
+}
@@ -0,0 +1,182 @@
+//! Tests for `vx setup --global` detection, profile editing and the setup record
+
+use serial_test::serial;
+use std::ffi::OsString;
+use std::path::PathBuf;
+use tempfile::TempDir;
+use vx_cli::Cli;
+use vx_cli::commands::global_setup::{
+    GlobalSetup, SetupGap, apply_profile_block, detect_gaps, first_run_suppressed, offer_first_run,
+    offers_first_run, profile_block,
+};
+use vx_cli::isolation::{IsolatedHome, VX_HOME_ENV};
+use vx_paths::VxPaths;
+
+use clap::Parser;
+
+fn path_var(dirs: &[PathBuf]) -> OsString {
+    std::env::join_paths(dirs).unwrap()
+}
+
+#[test]
+fn test_detect_gaps_on_clean_machine() {
+    let home = TempDir::new().unwrap();
+    let bin_dir = home.path().join(".vx").join("bin");
+    let exe_dir = home.path().join(".local").join("bin");
+
+    let gaps = detect_gaps(
+        &bin_dir,
+        &[exe_dir.clone(), bin_dir.clone()],
+        &path_var(&[PathBuf::from("/usr/bin")]),
+        "bash",
+        None,
+        false,
+    );
+
+    assert_eq!(
+        gaps,
+        vec![
+            SetupGap::BinDirMissing(bin_dir.clone()),
+            SetupGap::NotOnPath(exe_dir),
+            SetupGap::NotOnPath(bin_dir),
+            SetupGap::ShellHookMissing {
+                shell: "bash".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_detect_gaps_when_set_up() {
+    let home = TempDir::new().unwrap();
+    let bin_dir = home.path().join("bin");
+    std::fs::create_dir_all(&bin_dir).unwrap();
+
+    let path = path_var(&[PathBuf::from("/usr/bin"), bin_dir.clone()]);
+    let required = [bin_dir.clone()];
+    let profile = "alias ll='ls -l'\neval \"$(vx shell init zsh)\"\n";
+    assert!(detect_gaps(&bin_dir, &required, &path, "zsh", Some(profile), false).is_empty());
+
+    // The hook exported VX_SHELL in this shell, even without a known profile
+    assert!(detect_gaps(&bin_dir, &required, &path, "bash", None, true).is_empty());
+
+    // No hook line exists for cmd, so only PATH matters there
+    assert!(detect_gaps(&bin_dir, &required, &path, "cmd", None, false).is_empty());
+}
+
+#[test]
+fn test_profile_block_per_shell() {
+    let dirs = [PathBuf::from("/opt/vx"), PathBuf::from("/home/u/.vx/bin")];
+
+    let bash = profile_block("bash", &dirs).unwrap();
+    assert_eq!(
+        bash,
+        "# >>> vx >>>\n\
+         export PATH=\"/home/u/.vx/bin:$PATH\"\n\
+         export PATH=\"/opt/vx:$PATH\"\n\
+         eval \"$(vx shell init bash)\"\n\
+         # <<< vx <<<\n"
+    );
+
+    let fish = profile_block("fish", &[]).unwrap();
+    assert!(fish.contains("vx shell init fish | source"));
+    assert!(!fish.contains("PATH"));
+
+    let pwsh = profile_block("powershell", &dirs[..1]).unwrap();
+    assert!(pwsh.contains("$env:PATH = \"/opt/vx;$env:PATH\""));
+    assert!(pwsh.contains("vx shell init powershell"));
+
+    assert!(profile_block("cmd", &dirs).is_none());
+}
+
+#[test]
+fn test_apply_profile_block_appends_then_replaces() {
+    let first = profile_block("bash", &[PathBuf::from("/opt/vx")]).unwrap();
+    let profile = apply_profile_block("export EDITOR=vim", &first);
+    assert_eq!(profile, format!("export EDITOR=vim\n\n{}", first));
+
+    let second = profile_block("bash", &[]).unwrap();
+    let updated = apply_profile_block(&format!("{}alias g=git\n", profile), &second);
+    assert_eq!(
+        updated,
+        format!("export EDITOR=vim\n\n{}alias g=git\n", second)
+    );
+    assert_eq!(updated.matches("# >>> vx >>>").count(), 1);
+
+    assert_eq!(apply_profile_block("", &second), second);
+}
+
+#[test]
+fn test_global_setup_roundtrip() {
+    let home = TempDir::new().unwrap();
+    let paths = VxPaths::with_base_dir(home.path());
+    assert!(GlobalSetup::load(&paths).is_none());
+
+    let setup = GlobalSetup {
+        shell: Some("zsh".to_string()),
+        telemetry: Some(false),
+        mirror_region: Some("cn".to_string()),
+        ..Default::default()
+    };
+    setup.save(&paths).unwrap();
+    assert!(GlobalSetup::path(&paths).ends_with("config/setup.toml"));
+
+    let loaded = GlobalSetup::load(&paths).unwrap();
+    assert_eq!(loaded, setup);
+    assert!(!loaded.telemetry_enabled());
+    assert!(GlobalSetup::default().telemetry_enabled());
+
+    // A declined offer is still a record, so it isn't made again
+    GlobalSetup {
+        declined: true,
+        ..Default::default()
+    }
+    .save(&paths)
+    .unwrap();
+    assert!(GlobalSetup::load(&paths).unwrap().declined);
+}
+
+#[test]
+fn test_first_run_offer_skips_setup_and_shell_commands() {
+    let parse = |args: &[&str]| Cli::try_parse_from(args).unwrap().command;
+
+    assert!(offers_first_run(parse(&["vx", "list"]).as_ref()));
+    assert!(offers_first_run(None));
+    assert!(!offers_first_run(
+        parse(&["vx", "setup", "--global"]).as_ref()
+    ));
+    assert!(!offers_first_run(
+        parse(&["vx", "shell", "init", "bash"]).as_ref()
+    ));
+    assert!(!offers_first_run(parse(&["vx", "self-update"]).as_ref()));
+}
+
+#[tokio::test]
+#[serial]
+async fn test_isolated_run_never_offers_first_run() {
+    assert!(first_run_suppressed(true));
+
+    let user_home = TempDir::new().unwrap();
+    let original_home = std::env::var_os("HOME");
+    let original_vx_home = std::env::var_os(VX_HOME_ENV);
+    unsafe {
+        std::env::set_var("HOME", user_home.path());
+    }
+    let isolated = IsolatedHome::new().unwrap();
+    isolated.activate();
+
+    offer_first_run(None, true).await.unwrap();
+
+    // Neither the user's shell profiles nor the sandbox setup record are written
+    assert_eq!(std::fs::read_dir(user_home.path()).unwrap().count(), 0);
+    assert!(GlobalSetup::load(&isolated.paths()).is_none());
+
+    unsafe {
+        for (key, value) in [("HOME", original_home), (VX_HOME_ENV, original_vx_home)] {
+            match value {
+                Some(v) => std::env::set_var(key, v),
+                None => std::env::remove_var(key),
+            }
+        }
+    }
+}
@@ -0,0 +1,278 @@
+//! Tests for `vx import` manifest conversion and provider rendering
+
+use std::io::Write;
+use vx_cli::commands::import::{
+    ImportSource, InstallerKind, choco, provider_name, render_provider_star, winget,
+};
+
+const JQ_INSTALLER: &str = r#"# Created with WinGet Releaser
+# yaml-language-server: $schema=https://aka.ms/winget-manifest.installer.1.6.0.schema.json
+
+PackageIdentifier: jqlang.jq
+PackageVersion: 1.7.1
+InstallerType: portable
+Commands:
+- jq
+ReleaseDate: 2023-12-13
+Installers:
+- Architecture: x64
+  InstallerUrl: https://github.com/jqlang/jq/releases/download/jq-1.7.1/jq-windows-amd64.exe
+  InstallerSha256: 7451FBBF37FEFFB9BF262BD97C54F0DA558C63F0748E64152DD87B0A07B6D6AB
+- Architecture: x86
+  InstallerUrl: https://github.com/jqlang/jq/releases/download/jq-1.7.1/jq-windows-i386.exe
+  InstallerSha256: 3DDA3E4D7F9E27A25D2E4B05A8C6E2C0C3D5C3B57B0C0F3C4C2E1A1B1C1D1E1F
+ManifestType: installer
+ManifestVersion: 1.6.0
+"#;
+
+const JQ_LOCALE: &str = r#"PackageIdentifier: jqlang.jq
+PackageVersion: 1.7.1
+PackageLocale: en-US
+PackageName: jq
+PackageUrl: https://jqlang.github.io/jq/
+License: MIT
+ShortDescription: "Command-line JSON processor"
+Description: |-
+  jq is like sed for JSON data.
+  It slices, filters and maps structured data.
+Tags: [json, cli]
+"#;
+
+const RIPGREP_INSTALLER: &str = r#"PackageIdentifier: BurntSushi.ripgrep.MSVC
+PackageVersion: 14.1.0
+InstallerType: zip
+NestedInstallerType: portable
+Installers:
+- Architecture: x64
+  NestedInstallerFiles:
+  - RelativeFilePath: ripgrep-14.1.0-x86_64-pc-windows-msvc\rg.exe
+    PortableCommandAlias: rg
+  InstallerUrl: https://github.com/BurntSushi/ripgrep/releases/download/14.1.0/ripgrep-14.1.0-x86_64-pc-windows-msvc.zip
+- Architecture: x64
+  InstallerType: msi
+  InstallerUrl: https://example.com/ripgrep-14.1.0-x64.msi
+"#;
+
+#[test]
+fn test_provider_name() {
+    assert_eq!(provider_name("jqlang.jq"), "jq");
+    assert_eq!(provider_name("Microsoft.PowerToys"), "powertoys");
+    assert_eq!(provider_name("Vendor.7zip"), "pkg-7zip");
+    assert_eq!(provider_name("Some.Tool_Name"), "tool-name");
+}
+
+#[test]
+fn test_parse_yaml_subset() {
+    let value = winget::parse_yaml(JQ_LOCALE).unwrap();
+    assert_eq!(value["ShortDescription"], "Command-line JSON processor");
+    assert_eq!(
+        value["Description"],
+        "jq is like sed for JSON data.\nIt slices, filters and maps structured data."
+    );
+    assert_eq!(value["Tags"], serde_json::json!(["json", "cli"]));
+
+    let value = winget::parse_yaml(RIPGREP_INSTALLER).unwrap();
+    let installers = value["Installers"].as_array().unwrap();
+    assert_eq!(installers.len(), 2);
+    assert_eq!(
+        installers[0]["NestedInstallerFiles"][0]["PortableCommandAlias"],
+        "rg"
+    );
+    assert_eq!(installers[1]["InstallerType"], "msi");
+}
+
+#[test]
+fn test_winget_urls() {
+    assert_eq!(
+        winget::installer_manifest_url("jqlang.jq", "1.7.1"),
+        "https://raw.githubusercontent.com/microsoft/winget-pkgs/master/manifests/j/jqlang/jq/1.7.1/jqlang.jq.installer.yaml"
+    );
+    assert!(
+        winget::versions_url("BurntSushi.ripgrep.MSVC").ends_with("/b/BurntSushi/ripgrep/MSVC")
+    );
+}
+
+#[test]
+fn test_winget_portable_package() {
+    let installer = winget::parse_yaml(JQ_INSTALLER).unwrap();
+    let locale = winget::parse_yaml(JQ_LOCALE).unwrap();
+    let package = winget::convert("jqlang.jq", &installer, Some(&locale)).unwrap();
+
+    assert_eq!(package.source, ImportSource::Winget);
+    assert_eq!(package.name, "jq");
+    assert_eq!(package.executable, "jq");
+    assert_eq!(package.version, "1.7.1");
+    assert_eq!(package.description, "Command-line JSON processor");
+    assert_eq!(package.license.as_deref(), Some("MIT"));
+    assert_eq!(package.installers.len(), 2);
+    assert_eq!(package.installers[0].arch, "x64");
+    assert_eq!(package.installers[0].kind, InstallerKind::Binary);
+}
+
+#[test]
+fn test_winget_zip_preferred_over_msi() {
+    let installer = winget::parse_yaml(RIPGREP_INSTALLER).unwrap();
+    let package = winget::convert("BurntSushi.ripgrep.MSVC", &installer, None).unwrap();
+
+    assert_eq!(package.name, "msvc");
+    assert_eq!(package.executable, "rg");
+    assert_eq!(package.installers.len(), 1);
+    assert_eq!(
+        package.installers[0].kind,
+        InstallerKind::Archive {
+            strip_prefix: None,
+            executable_paths: vec!["ripgrep-14.1.0-x86_64-pc-windows-msvc/rg.exe".to_string()],
+        }
+    );
+}
+
+#[test]
+fn test_winget_rejects_setup_programs() {
+    let installer = winget::parse_yaml(
+        "PackageIdentifier: Vendor.App\nPackageVersion: 1.0\nInstallers:\n- Architecture: x64\n  InstallerType: inno\n  InstallerUrl: https://example.com/setup.exe\n",
+    )
+    .unwrap();
+    let err = winget::convert("Vendor.App", &installer, None).unwrap_err();
+    assert!(err.to_string().contains("found: inno"), "{err}");
+}
+
+#[test]
+fn test_rendered_provider_is_valid() {
+    for (id, manifest) in [
+        ("jqlang.jq", JQ_INSTALLER),
+        ("BurntSushi.ripgrep.MSVC", RIPGREP_INSTALLER),
+    ] {
+        let installer = winget::parse_yaml(manifest).unwrap();
+        let package = winget::convert(id, &installer, None).unwrap();
+        let star = render_provider_star(&package);
+        assert!(star.contains(&format!("_VERSION = \"{}\"", package.version)));
+        vx_starlark::validate_user_provider(
+            package.name.clone(),
+            format!("/tmp/{}/provider.star", package.name),
+            star,
+        )
+        .unwrap();
+    }
+}
+
+const NUSPEC: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<package xmlns="http://schemas.microsoft.com/packaging/2015/06/nuspec.xsd">
+  <metadata>
+    <id>ripgrep</id>
+    <version>14.1.0</version>
+    <title>ripgrep</title>
+    <projectUrl>https://github.com/BurntSushi/ripgrep</projectUrl>
+    <licenseUrl>https://github.com/BurntSushi/ripgrep/blob/master/LICENSE-MIT</licenseUrl>
+    <summary>Search tool &amp; grep replacement</summary>
+    <description>ripgrep recursively searches directories.</description>
+  </metadata>
+</package>
+"#;
+
+const INSTALL_SCRIPT: &str = r#"$ErrorActionPreference = 'Stop'
+$toolsDir = "$(Split-Path -parent $MyInvocation.MyCommand.Definition)"
+$version = '14.1.0'
+
+$packageArgs = @{
+  packageName   = $env:ChocolateyPackageName
+  unzipLocation = $toolsDir
+  url           = "https://github.com/BurntSushi/ripgrep/releases/download/$version/ripgrep-$version-i686-pc-windows-msvc.zip"
+  url64bit      = "https://github.com/BurntSushi/ripgrep/releases/download/${version}/ripgrep-${version}-x86_64-pc-windows-msvc.zip"
+}
+Install-ChocolateyZipPackage @packageArgs
+"#;
+
+fn nupkg(files: &[(&str, &str)]) -> Vec<u8> {
+    let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for (name, content) in files {
+        writer
+            .start_file(*name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(content.as_bytes()).unwrap();
+    }
+    writer.finish().unwrap().into_inner()
+}
+
+#[test]
+fn test_nuspec_fields() {
+    assert_eq!(
+        choco::nuspec_field(NUSPEC, "summary").as_deref(),
+        Some("Search tool & grep replacement")
+    );
+    assert_eq!(
+        choco::nuspec_field(NUSPEC, "id").as_deref(),
+        Some("ripgrep")
+    );
+    assert_eq!(choco::nuspec_field(NUSPEC, "tags"), None);
+}
+
+#[test]
+fn test_choco_zip_package() {
+    let bytes = nupkg(&[
+        ("ripgrep.nuspec", NUSPEC),
+        ("tools/chocolateyInstall.ps1", INSTALL_SCRIPT),
+    ]);
+    let contents = choco::read_nupkg(&bytes).unwrap();
+    let package =
+        choco::convert(&choco::package_url("ripgrep", Some("14.1.0")), &contents).unwrap();
+
+    assert_eq!(package.source, ImportSource::Choco);
+    assert_eq!(package.name, "ripgrep");
+    assert_eq!(package.description, "Search tool & grep replacement");
+    assert_eq!(package.installers.len(), 2);
+    assert_eq!(package.installers[0].arch, "x64");
+    assert_eq!(
+        package.installers[0].url,
+        "https://github.com/BurntSushi/ripgrep/releases/download/14.1.0/ripgrep-14.1.0-x86_64-pc-windows-msvc.zip"
+    );
+    assert_eq!(package.installers[1].arch, "x86");
+    assert!(matches!(
+        package.installers[1].kind,
+        InstallerKind::Archive { .. }
+    ));
+}
+
+#[test]
+fn test_choco_embedded_executables() {
+    let nuspec = NUSPEC.replace("<id>ripgrep</id>", "<id>rg.portable</id>");
+    let bytes = nupkg(&[("rg.portable.nuspec", &nuspec), ("tools/bin/rg.exe", "MZ")]);
+    let contents = choco::read_nupkg(&bytes).unwrap();
+    let url = choco::package_url("rg.portable", Some("14.1.0"));
+    let package = choco::convert(&url, &contents).unwrap();
+
+    assert_eq!(package.name, "rg");
+    assert_eq!(package.executable, "rg");
+    assert_eq!(package.installers[0].arch, "neutral");
+    assert_eq!(package.installers[0].url, format!("{}#.zip", url));
+    assert_eq!(
+        package.installers[0].kind,
+        InstallerKind::Archive {
+            strip_prefix: Some("tools".to_string()),
+            executable_paths: vec!["bin/rg.exe".to_string()],
+        }
+    );
+    vx_starlark::validate_user_provider(
+        "rg",
+        "/tmp/rg/provider.star",
+        render_provider_star(&package),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_choco_rejects_unresolved_urls_and_setup_programs() {
+    let err = choco::parse_install_script("$url = \"https://example.com/$arch/tool.zip\"", "1.0")
+        .unwrap_err();
+    assert!(err.to_string().contains("cannot resolve"), "{err}");
+
+    let bytes = nupkg(&[
+        ("tool.nuspec", &NUSPEC.replace("ripgrep</id>", "tool</id>")),
+        (
+            "tools/chocolateyinstall.ps1",
+            "$url = 'https://example.com/setup.exe'\nInstall-ChocolateyPackage $packageName 'exe' '/S' $url",
+        ),
+    ]);
+    let contents = choco::read_nupkg(&bytes).unwrap();
+    let err = choco::convert("https://example.com/tool.nupkg", &contents).unwrap_err();
+    assert!(err.to_string().contains("'exe' installer"), "{err}");
+}
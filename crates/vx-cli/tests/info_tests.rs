@@ -133,6 +133,43 @@ async fn test_list_system_tools_all() {
 ///
 /// In CI, stdout is not a TTY so vx auto-switches to NDJSON.
 /// Tests that check for human-readable text headers must force text mode.
+/// Test that `vx capabilities --format json` describes every runtime
+#[rstest]
+#[tokio::test]
+async fn test_capabilities_json_output() {
+    common::init_test_env();
+
+    let output = run_vx_command(&["capabilities", "--format", "json"]).await;
+    assert!(output.status.success(), "capabilities should succeed");
+
+    let json: serde_json::Value =
+        serde_json::from_str(&output.stdout).expect("capabilities should return valid JSON");
+    let runtimes = json["runtimes"].as_array().expect("should list runtimes");
+    assert!(!runtimes.is_empty());
+
+    let node = runtimes
+        .iter()
+        .find(|rt| rt["name"] == "node")
+        .expect("node should be registered");
+    for field in [
+        "provider",
+        "ecosystem",
+        "aliases",
+        "executables",
+        "platforms",
+        "install_strategies",
+        "offline_install",
+    ] {
+        assert!(node.get(field).is_some(), "node should have {}", field);
+    }
+    assert_eq!(node["install_strategies"][0]["kind"], "download");
+    assert_eq!(node["offline_install"], true);
+
+    let npm = runtimes.iter().find(|rt| rt["name"] == "npm").unwrap();
+    assert_eq!(npm["install_strategies"][0]["kind"], "provided_by");
+    assert_eq!(npm["offline_install"], true);
+}
+
 async fn run_vx_command(args: &[&str]) -> CommandOutput {
     let output =
         common::run_vx_with_env(args, &[("VX_OUTPUT", "text")]).expect("Failed to execute vx");
@@ -0,0 +1,46 @@
+//! Tests for tool bundle expansion in `vx install` (e.g. `vx install devtools`)
+
+use vx_cli::commands::install::expand_tool_bundles;
+
+fn specs(items: &[&str]) -> Vec<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+fn lookup(name: &str) -> Option<Vec<String>> {
+    (name == "devtools").then(|| specs(&["jq", "fd", "bat"]))
+}
+
+#[test]
+fn test_bundle_expands_to_members() {
+    let tools = expand_tool_bundles(&specs(&["node@22", "devtools"]), lookup).unwrap();
+    assert_eq!(tools, specs(&["node@22", "jq", "fd", "bat"]));
+}
+
+#[test]
+fn test_bundle_members_are_deduplicated() {
+    let tools = expand_tool_bundles(&specs(&["jq", "devtools", "devtools"]), lookup).unwrap();
+    assert_eq!(tools, specs(&["jq", "fd", "bat"]));
+}
+
+#[test]
+fn test_bundle_with_version_is_rejected() {
+    let err = expand_tool_bundles(&specs(&["devtools@1.0"]), lookup).unwrap_err();
+    assert!(err.to_string().contains("tool bundle"));
+}
+
+#[test]
+fn test_builtin_devtools_bundle() {
+    let members = vx_cli::registry::find_tool_bundle("devtools").expect("devtools bundle");
+    assert_eq!(members, specs(&["jq", "yq", "fzf", "ripgrep", "fd", "bat"]));
+    assert!(vx_cli::registry::find_tool_bundle("jq").is_none());
+}
+
+#[test]
+fn test_builtin_kubernetes_bundle() {
+    let tools =
+        expand_tool_bundles(&specs(&["kubernetes"]), vx_cli::registry::find_tool_bundle).unwrap();
+    assert_eq!(
+        tools,
+        specs(&["kubectl", "helm", "k9s", "kind", "kustomize"])
+    );
+}
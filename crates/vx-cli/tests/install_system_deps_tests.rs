@@ -0,0 +1,66 @@
+//! Tests for system dependency checks during `vx install`
+
+use anyhow::Result;
+use async_trait::async_trait;
+use vx_cli::commands::install::{SystemDepsAction, ensure_system_dependencies};
+use vx_runtime::{Runtime, RuntimeContext, VersionInfo};
+use vx_system_pm::{SystemDepType, SystemDependency};
+
+struct DepsRuntime {
+    deps: Vec<SystemDependency>,
+}
+
+#[async_trait]
+impl Runtime for DepsRuntime {
+    fn name(&self) -> &str {
+        "deps-tool"
+    }
+
+    async fn fetch_versions(&self, _ctx: &RuntimeContext) -> Result<Vec<VersionInfo>> {
+        Ok(vec![])
+    }
+
+    fn system_dependencies(&self) -> Vec<SystemDependency> {
+        self.deps.clone()
+    }
+}
+
+#[tokio::test]
+async fn test_no_system_dependencies() {
+    let runtime = DepsRuntime { deps: vec![] };
+
+    let outcome =
+        ensure_system_dependencies(&runtime, "deps-tool", SystemDepsAction::Install).await;
+    assert!(outcome.satisfied.is_empty());
+    assert!(outcome.installed.is_empty());
+    assert!(outcome.missing.is_empty());
+}
+
+#[tokio::test]
+async fn test_other_platform_dependencies_are_skipped() {
+    let runtime = DepsRuntime {
+        deps: vec![
+            SystemDependency::new(SystemDepType::Package, "libfoo-dev")
+                .with_platforms(vec!["nonexistent-platform".to_string()]),
+        ],
+    };
+
+    let outcome =
+        ensure_system_dependencies(&runtime, "deps-tool", SystemDepsAction::Install).await;
+    assert!(outcome.missing.is_empty());
+    assert!(outcome.installed.is_empty());
+}
+
+#[tokio::test]
+async fn test_report_leaves_missing_dependencies() {
+    let runtime = DepsRuntime {
+        deps: vec![
+            SystemDependency::new(SystemDepType::DotNet, "Vx.NoSuchRuntime.App")
+                .with_reason("test dependency"),
+        ],
+    };
+
+    let outcome = ensure_system_dependencies(&runtime, "deps-tool", SystemDepsAction::Report).await;
+    assert_eq!(outcome.missing, vec!["Vx.NoSuchRuntime.App".to_string()]);
+    assert!(outcome.installed.is_empty());
+}
@@ -0,0 +1,101 @@
+//! Tests for the isolated VX_HOME sandbox (`vx --isolated`)
+
+use rstest::rstest;
+use serial_test::serial;
+use vx_cli::isolation::{IsolatedHome, VX_HOME_ENV, VX_ISOLATED_ENV, isolation_requested};
+
+#[test]
+fn test_isolated_home_is_fresh_and_removed_on_drop() {
+    let home = IsolatedHome::new().unwrap();
+    let path = home.path().to_path_buf();
+
+    assert!(path.is_dir());
+    assert_eq!(std::fs::read_dir(&path).unwrap().count(), 0);
+    assert!(
+        path.file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("vx-isolated-")
+    );
+
+    drop(home);
+    assert!(!path.exists());
+}
+
+#[test]
+fn test_isolated_home_paths_are_rooted_in_sandbox() {
+    let home = IsolatedHome::new().unwrap();
+    let paths = home.paths();
+
+    assert_eq!(paths.base_dir, home.path());
+    assert!(paths.store_dir.starts_with(home.path()));
+    assert!(paths.config_dir.starts_with(home.path()));
+    assert!(paths.cache_dir.starts_with(home.path()));
+}
+
+#[test]
+fn test_isolated_home_env_vars() {
+    let home = IsolatedHome::new().unwrap();
+    let vars = home.env_vars();
+
+    let vx_home = vars.iter().find(|(k, _)| *k == VX_HOME_ENV).unwrap();
+    assert_eq!(vx_home.1, home.path().as_os_str());
+
+    // Nested vx invocations must reuse the sandbox rather than create another
+    let isolated = vars.iter().find(|(k, _)| *k == VX_ISOLATED_ENV).unwrap();
+    assert!(isolated.1.is_empty());
+}
+
+#[test]
+fn test_isolated_home_keep_persists_directory() {
+    let home = IsolatedHome::new().unwrap();
+    let path = home.keep();
+    assert!(path.is_dir());
+    std::fs::remove_dir_all(&path).unwrap();
+}
+
+#[rstest]
+#[case(Some("1"), true)]
+#[case(Some("true"), true)]
+#[case(Some("yes"), true)]
+#[case(Some("0"), false)]
+#[case(Some(""), false)]
+#[case(None, false)]
+#[serial]
+fn test_isolation_requested_from_env(#[case] value: Option<&str>, #[case] expected: bool) {
+    unsafe {
+        match value {
+            Some(v) => std::env::set_var(VX_ISOLATED_ENV, v),
+            None => std::env::remove_var(VX_ISOLATED_ENV),
+        }
+    }
+    assert_eq!(isolation_requested(false), expected);
+    assert!(isolation_requested(true));
+    unsafe {
+        std::env::remove_var(VX_ISOLATED_ENV);
+    }
+}
+
+#[test]
+#[serial]
+fn test_activate_sets_vx_home() {
+    let original = std::env::var_os(VX_HOME_ENV);
+    let home = IsolatedHome::new().unwrap();
+    home.activate();
+
+    assert_eq!(
+        std::env::var_os(VX_HOME_ENV).as_deref(),
+        Some(home.path().as_os_str())
+    );
+    assert_eq!(
+        vx_paths::VxPaths::new().unwrap().base_dir,
+        home.path().to_path_buf()
+    );
+
+    unsafe {
+        match original {
+            Some(v) => std::env::set_var(VX_HOME_ENV, v),
+            None => std::env::remove_var(VX_HOME_ENV),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Tests for `vx matrix` axis parsing, expansion and the summary
+
+use vx_cli::commands::matrix::{
+    MatrixAxis, MatrixOutput, MatrixRun, expand_matrix, parse_matrix_axes,
+};
+use vx_cli::output::CommandOutput;
+
+fn specs(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+fn run(label: &str, exit_code: Option<i32>, duration_ms: u64) -> MatrixRun {
+    MatrixRun {
+        label: label.to_string(),
+        tools: Vec::new(),
+        exit_code,
+        duration_ms,
+        error: exit_code.is_none().then(|| "not installed".to_string()),
+    }
+}
+
+#[test]
+fn test_parse_axes_accepts_equals_and_at() {
+    let axes = parse_matrix_axes(&specs(&["node=18, 20,22", "python@3.11,3.12"])).unwrap();
+    assert_eq!(
+        axes,
+        vec![
+            MatrixAxis {
+                tool: "node".to_string(),
+                versions: specs(&["18", "20", "22"]),
+            },
+            MatrixAxis {
+                tool: "python".to_string(),
+                versions: specs(&["3.11", "3.12"]),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_parse_axes_rejects_invalid_specs() {
+    assert!(parse_matrix_axes(&[]).is_err());
+    assert!(parse_matrix_axes(&specs(&["node"])).is_err());
+    assert!(parse_matrix_axes(&specs(&["node="])).is_err());
+    assert!(parse_matrix_axes(&specs(&["=18"])).is_err());
+    assert!(parse_matrix_axes(&specs(&["node=18", "node=20"])).is_err());
+}
+
+#[test]
+fn test_expand_single_axis_keeps_order() {
+    let axes = parse_matrix_axes(&specs(&["node=22,18,20"])).unwrap();
+    let versions: Vec<String> = expand_matrix(&axes)
+        .iter()
+        .map(|combination| combination[0].version.clone().unwrap())
+        .collect();
+    assert_eq!(versions, specs(&["22", "18", "20"]));
+}
+
+#[test]
+fn test_expand_multiple_axes_is_cartesian_product() {
+    let axes = parse_matrix_axes(&specs(&["node=18,20", "pnpm=8,9"])).unwrap();
+    let labels: Vec<String> = expand_matrix(&axes)
+        .iter()
+        .map(|combination| {
+            combination
+                .iter()
+                .map(|tool| format!("{}@{}", tool.runtime, tool.version.as_deref().unwrap()))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect();
+    assert_eq!(
+        labels,
+        specs(&[
+            "node@18 pnpm@8",
+            "node@18 pnpm@9",
+            "node@20 pnpm@8",
+            "node@20 pnpm@9",
+        ])
+    );
+}
+
+#[test]
+fn test_summary_counts_passed_failed_and_skipped() {
+    let output = MatrixOutput::new(
+        &specs(&["npm", "test"]),
+        vec![run("node@18", Some(0), 1200), run("node@20", Some(1), 800)],
+        3,
+    );
+    assert_eq!(output.passed, 1);
+    assert_eq!(output.failed, 1);
+    assert_eq!(output.skipped, 1);
+
+    let json = serde_json::to_value(&output).unwrap();
+    assert_eq!(json["runs"][0]["exit_code"], 0);
+    assert_eq!(json["runs"][1]["duration_ms"], 800);
+    assert!(json["runs"][0].get("error").is_none());
+}
+
+#[test]
+fn test_summary_text_lists_every_run() {
+    let output = MatrixOutput::new(
+        &specs(&["npm", "test"]),
+        vec![run("node@18", Some(0), 1500), run("node@22", None, 0)],
+        2,
+    );
+    let mut text = Vec::new();
+    output.render_text(&mut text).unwrap();
+    let text = String::from_utf8(text).unwrap();
+
+    assert!(text.contains("Matrix: npm test"));
+    assert!(text.contains("node@18"));
+    assert!(text.contains("1.5s"));
+    assert!(text.contains("not installed"));
+    assert!(text.contains("1 passed, 1 failed"));
+}
@@ -0,0 +1,244 @@
+//! Tests for OCI toolchain artifacts (`vx store export-oci`, `vx install --from-oci`)
+
+use rstest::rstest;
+use std::path::PathBuf;
+use vx_cli::oci::{
+    ANNOTATION_TOOL, AuthChallenge, LAYER_MEDIA_TYPE, OciReference, OciTarget, ToolchainArtifact,
+    ToolchainInfo, install_layer, read_layout, sha256_digest, write_layout,
+};
+
+fn sample_info() -> ToolchainInfo {
+    ToolchainInfo {
+        tool: "node".to_string(),
+        version: "20.11.0".to_string(),
+        platform: "linux-x64".to_string(),
+    }
+}
+
+fn sample_store_entry() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("bin")).unwrap();
+    std::fs::write(
+        dir.path().join("bin").join("node"),
+        b"#!/bin/sh\necho node\n",
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("LICENSE"), b"MIT").unwrap();
+    dir
+}
+
+// ============================================================================
+// References
+// ============================================================================
+
+#[rstest]
+#[case(
+    "ghcr.io/org/toolchains:node-20",
+    "ghcr.io",
+    "org/toolchains",
+    "node-20"
+)]
+#[case("ghcr.io/org/toolchains", "ghcr.io", "org/toolchains", "latest")]
+#[case("localhost:5000/tools:v1", "localhost:5000", "tools", "v1")]
+#[case(
+    "registry.example.com/a/b/c@sha256:abc",
+    "registry.example.com",
+    "a/b/c",
+    "sha256:abc"
+)]
+fn test_parse_reference(
+    #[case] input: &str,
+    #[case] registry: &str,
+    #[case] repository: &str,
+    #[case] reference: &str,
+) {
+    let parsed: OciReference = input.parse().unwrap();
+    assert_eq!(parsed.registry, registry);
+    assert_eq!(parsed.repository, repository);
+    assert_eq!(parsed.reference, reference);
+}
+
+#[rstest]
+#[case("node:20")]
+#[case("toolchains/node")]
+#[case("ghcr.io/")]
+fn test_parse_reference_rejects_missing_registry(#[case] input: &str) {
+    assert!(input.parse::<OciReference>().is_err());
+}
+
+#[test]
+fn test_reference_api_base_uses_http_for_localhost() {
+    let local: OciReference = "localhost:5000/tools:v1".parse().unwrap();
+    assert_eq!(local.api_base(), "http://localhost:5000/v2/tools");
+    let remote: OciReference = "ghcr.io/org/tools:v1".parse().unwrap();
+    assert_eq!(remote.api_base(), "https://ghcr.io/v2/org/tools");
+}
+
+#[test]
+fn test_target_parses_layout_prefix() {
+    assert_eq!(
+        OciTarget::parse("oci:./artifacts:node-20").unwrap(),
+        OciTarget::Layout {
+            dir: PathBuf::from("./artifacts"),
+            tag: "node-20".to_string()
+        }
+    );
+    assert_eq!(
+        OciTarget::parse("oci:/tmp/layout").unwrap(),
+        OciTarget::Layout {
+            dir: PathBuf::from("/tmp/layout"),
+            tag: "latest".to_string()
+        }
+    );
+    assert!(matches!(
+        OciTarget::parse("ghcr.io/org/tools:v1").unwrap(),
+        OciTarget::Registry(_)
+    ));
+}
+
+// ============================================================================
+// Auth challenges
+// ============================================================================
+
+#[test]
+fn test_parse_bearer_challenge() {
+    let header = r#"Bearer realm="https://ghcr.io/token",service="ghcr.io",scope="repository:org/tools:pull""#;
+    assert_eq!(
+        AuthChallenge::parse(header),
+        Some(AuthChallenge::Bearer {
+            realm: "https://ghcr.io/token".to_string(),
+            service: Some("ghcr.io".to_string()),
+            scope: Some("repository:org/tools:pull".to_string()),
+        })
+    );
+}
+
+#[test]
+fn test_parse_basic_and_unknown_challenges() {
+    assert_eq!(
+        AuthChallenge::parse(r#"Basic realm="Registry""#),
+        Some(AuthChallenge::Basic)
+    );
+    assert_eq!(AuthChallenge::parse("Negotiate"), None);
+}
+
+// ============================================================================
+// Packaging round trip
+// ============================================================================
+
+#[test]
+fn test_pack_builds_annotated_manifest() {
+    let entry = sample_store_entry();
+    let artifact = ToolchainArtifact::pack(sample_info(), entry.path()).unwrap();
+
+    assert_eq!(artifact.manifest.schema_version, 2);
+    assert_eq!(artifact.manifest.layers.len(), 1);
+    assert_eq!(artifact.manifest.layers[0].media_type, LAYER_MEDIA_TYPE);
+    assert_eq!(
+        artifact.manifest.layers[0].digest,
+        sha256_digest(&artifact.layer)
+    );
+    assert_eq!(artifact.manifest.annotations[ANNOTATION_TOOL], "node");
+    assert_eq!(artifact.manifest.toolchain().unwrap(), sample_info());
+    assert_eq!(artifact.digest(), sha256_digest(&artifact.manifest_bytes));
+}
+
+#[test]
+fn test_pack_missing_store_entry_fails() {
+    let result = ToolchainArtifact::pack(sample_info(), std::path::Path::new("/nonexistent/vx"));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_layout_round_trip_installs_store_entry() {
+    let entry = sample_store_entry();
+    let artifact = ToolchainArtifact::pack(sample_info(), entry.path()).unwrap();
+
+    let layout = tempfile::tempdir().unwrap();
+    write_layout(layout.path(), "node-20", &artifact).unwrap();
+    assert!(layout.path().join("oci-layout").exists());
+
+    let (manifest, layer) = read_layout(layout.path(), "node-20").unwrap();
+    assert_eq!(manifest, artifact.manifest);
+
+    let store = tempfile::tempdir().unwrap();
+    let dest = store.path().join("node").join("20.11.0");
+    install_layer(&layer, &dest).unwrap();
+    assert_eq!(
+        std::fs::read(dest.join("bin").join("node")).unwrap(),
+        b"#!/bin/sh\necho node\n"
+    );
+    assert_eq!(std::fs::read(dest.join("LICENSE")).unwrap(), b"MIT");
+}
+
+#[test]
+fn test_layout_retags_and_rejects_unknown_tags() {
+    let entry = sample_store_entry();
+    let artifact = ToolchainArtifact::pack(sample_info(), entry.path()).unwrap();
+    let layout = tempfile::tempdir().unwrap();
+
+    write_layout(layout.path(), "node-20", &artifact).unwrap();
+    write_layout(layout.path(), "node-20", &artifact).unwrap();
+    let index: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(layout.path().join("index.json")).unwrap()).unwrap();
+    assert_eq!(index["manifests"].as_array().unwrap().len(), 1);
+
+    assert!(read_layout(layout.path(), "missing").is_err());
+}
+
+#[test]
+fn test_tampered_layer_is_rejected() {
+    let entry = sample_store_entry();
+    let artifact = ToolchainArtifact::pack(sample_info(), entry.path()).unwrap();
+    let layout = tempfile::tempdir().unwrap();
+    write_layout(layout.path(), "v1", &artifact).unwrap();
+
+    let hex = artifact.manifest.layers[0]
+        .digest
+        .trim_start_matches("sha256:");
+    std::fs::write(
+        layout.path().join("blobs").join("sha256").join(hex),
+        b"tampered",
+    )
+    .unwrap();
+
+    let err = read_layout(layout.path(), "v1").unwrap_err();
+    assert!(err.to_string().contains("digest mismatch"));
+}
+
+#[test]
+fn test_toolchain_rejects_path_traversal() {
+    let entry = sample_store_entry();
+    let mut info = sample_info();
+    info.tool = "../../etc".to_string();
+    let artifact = ToolchainArtifact::pack(info, entry.path()).unwrap();
+    assert!(artifact.manifest.toolchain().is_err());
+}
+
+#[rstest]
+#[case(".")]
+#[case("..")]
+#[case("")]
+#[case("20/11")]
+fn test_toolchain_rejects_non_normal_version(#[case] version: &str) {
+    let entry = sample_store_entry();
+    let mut info = sample_info();
+    info.version = version.to_string();
+    let artifact = ToolchainArtifact::pack(info, entry.path()).unwrap();
+    assert!(artifact.manifest.toolchain().is_err(), "{version:?}");
+}
+
+#[test]
+fn test_install_layer_replaces_existing_entry() {
+    let entry = sample_store_entry();
+    let artifact = ToolchainArtifact::pack(sample_info(), entry.path()).unwrap();
+
+    let store = tempfile::tempdir().unwrap();
+    let dest = store.path().join("node").join("20.11.0");
+    std::fs::create_dir_all(&dest).unwrap();
+    std::fs::write(dest.join("stale"), b"old").unwrap();
+
+    install_layer(&artifact.layer, &dest).unwrap();
+    assert!(!dest.join("stale").exists());
+    assert!(dest.join("LICENSE").exists());
+}
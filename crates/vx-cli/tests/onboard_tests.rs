@@ -0,0 +1,80 @@
+//! Tests for `vx onboard` progress tracking and trust summary
+
+use tempfile::TempDir;
+use vx_cli::commands::onboard::{
+    OnboardProgress, OnboardStep, StepOutcome, config_hash, trust_summary,
+};
+
+#[test]
+fn test_progress_resumes_after_save() {
+    let dir = TempDir::new().unwrap();
+    let hash = config_hash("[tools]\nnode = \"20\"\n");
+
+    let mut progress = OnboardProgress::load(dir.path());
+    assert!(!progress.sync_config(&hash));
+    assert_eq!(progress.next_step(), Some(OnboardStep::Trust));
+
+    progress.record(OnboardStep::Trust, StepOutcome::Done);
+    progress.record(OnboardStep::Tools, StepOutcome::Done);
+    progress.record(OnboardStep::Hooks, StepOutcome::Skipped);
+    progress.save(dir.path()).unwrap();
+    assert!(OnboardProgress::path(dir.path()).ends_with(".vx/onboard.json"));
+
+    let mut reloaded = OnboardProgress::load(dir.path());
+    assert!(!reloaded.sync_config(&hash));
+    assert!(reloaded.is_finished(OnboardStep::Hooks));
+    assert_eq!(reloaded.next_step(), Some(OnboardStep::Services));
+}
+
+#[test]
+fn test_config_change_discards_progress() {
+    let mut progress = OnboardProgress::default();
+    progress.sync_config(&config_hash("a"));
+    progress.record(OnboardStep::Trust, StepOutcome::Done);
+
+    assert!(progress.sync_config(&config_hash("b")));
+    assert_eq!(progress.next_step(), Some(OnboardStep::Trust));
+    assert_eq!(progress.config_hash, config_hash("b"));
+}
+
+#[test]
+fn test_record_replaces_previous_outcome() {
+    let mut progress = OnboardProgress::default();
+    progress.record(OnboardStep::Services, StepOutcome::Skipped);
+    progress.record(OnboardStep::Services, StepOutcome::Done);
+
+    assert_eq!(progress.completed, vec![OnboardStep::Services]);
+    assert!(progress.skipped.is_empty());
+}
+
+#[test]
+fn test_trust_summary() {
+    let config = vx_config::parse_config_str(
+        r#"
+[tools]
+node = "20"
+
+[hooks]
+pre_setup = "npm ci"
+pre_commit = ["vx run lint", "vx run test"]
+
+[services.db]
+image = "postgres:16"
+
+[scripts]
+test = "npm test"
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        trust_summary(&config),
+        vec![
+            "pre_setup hook: npm ci",
+            "pre_commit hook: vx run lint && vx run test",
+            "service db: image postgres:16",
+            "1 script runnable with `vx run`",
+        ]
+    );
+    assert!(trust_summary(&vx_config::VxConfig::default()).is_empty());
+}
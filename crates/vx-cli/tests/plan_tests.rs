@@ -0,0 +1,107 @@
+//! Tests for dry-run action plans
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use vx_cli::commands::plan::{ActionPlan, PlannedAction, plan_delete};
+use vx_cli::output::OutputRenderer;
+use vx_runtime::{MockPathProvider, mock_context};
+
+fn sample_plan() -> ActionPlan {
+    let mut plan = ActionPlan::new("install");
+    plan.push(PlannedAction::Download {
+        tool: "node".to_string(),
+        version: "22.1.0".to_string(),
+        url: Some("https://nodejs.org/dist/v22.1.0/node-v22.1.0-linux-x64.tar.xz".to_string()),
+        size: Some(2 * 1024 * 1024),
+        cached: false,
+    });
+    plan.push(PlannedAction::Download {
+        tool: "uv".to_string(),
+        version: "0.5.0".to_string(),
+        url: None,
+        size: Some(4096),
+        cached: true,
+    });
+    plan.push(PlannedAction::Switch {
+        tool: "node".to_string(),
+        from: Some("20.11.0".to_string()),
+        to: "22.1.0".to_string(),
+    });
+    plan.push(PlannedAction::UpdateFile {
+        path: PathBuf::from("vx.lock"),
+        change: "lock node = 22.1.0".to_string(),
+    });
+    plan
+}
+
+#[test]
+fn test_plan_totals_skip_cached_downloads() {
+    let plan = sample_plan();
+    assert_eq!(plan.download_bytes(), (2, 2 * 1024 * 1024));
+    assert_eq!(plan.freed_bytes(), (0, 0));
+    assert!(!plan.is_noop());
+}
+
+#[test]
+fn test_plan_text_output() {
+    let text = OutputRenderer::text()
+        .render_to_string(&sample_plan())
+        .unwrap();
+    assert!(text.starts_with("Dry run: vx install (nothing will be changed)"));
+    assert!(text.contains("download  node@22.1.0 (2.0 MB)"));
+    assert!(text.contains("download  uv@0.5.0 (cached)"));
+    assert!(text.contains("switch    node 20.11.0 → 22.1.0"));
+    assert!(text.contains("update    vx.lock: lock node = 22.1.0"));
+    assert!(text.contains("Total: 2 download(s) (2.0 MB), 0 deletion(s) (0 B freed)"));
+}
+
+#[test]
+fn test_plan_json_output() {
+    let json = OutputRenderer::json()
+        .render_to_string(&sample_plan())
+        .unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["command"], "install");
+    let actions = value["actions"].as_array().unwrap();
+    assert_eq!(actions[0]["action"], "download");
+    assert_eq!(actions[0]["size"], 2 * 1024 * 1024);
+    assert_eq!(actions[0]["cached"], false);
+    assert_eq!(actions[2]["action"], "switch");
+    assert_eq!(actions[2]["from"], "20.11.0");
+    assert_eq!(actions[3]["action"], "update_file");
+}
+
+#[test]
+fn test_plan_of_skips_is_noop() {
+    let mut plan = ActionPlan::new("uninstall");
+    plan.push(PlannedAction::Skip {
+        tool: "node".to_string(),
+        version: None,
+        reason: "removing all versions requires --force".to_string(),
+    });
+    assert!(plan.is_noop());
+    let text = OutputRenderer::text().render_to_string(&plan).unwrap();
+    assert!(text.contains("skip      node: removing all versions requires --force"));
+    assert!(text.contains("Nothing to do"));
+}
+
+#[test]
+fn test_plan_delete_measures_store_dir() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut ctx = mock_context();
+    ctx.paths = Arc::new(MockPathProvider::new(temp.path()));
+    let dir = ctx.paths.version_store_dir("node", "20.11.0");
+    std::fs::create_dir_all(dir.join("bin")).unwrap();
+    std::fs::write(dir.join("bin").join("node"), vec![0u8; 1000]).unwrap();
+
+    let action = plan_delete(&ctx, "node", "20.11.0");
+    assert_eq!(
+        action,
+        PlannedAction::Delete {
+            tool: "node".to_string(),
+            version: "20.11.0".to_string(),
+            path: dir,
+            size: 1000,
+        }
+    );
+}
@@ -0,0 +1,18 @@
+//! Tests for `vx provider asdf`
+
+use vx_cli::commands::provider::parse_asdf_index_entry;
+
+#[test]
+fn test_parse_asdf_index_entry() {
+    assert_eq!(
+        parse_asdf_index_entry("repository = https://github.com/asdf-vm/asdf-nodejs.git\n")
+            .as_deref(),
+        Some("https://github.com/asdf-vm/asdf-nodejs.git")
+    );
+    assert_eq!(
+        parse_asdf_index_entry("# comment\nrepository=https://example.com/p\n").as_deref(),
+        Some("https://example.com/p")
+    );
+    assert_eq!(parse_asdf_index_entry("repository =\n"), None);
+    assert_eq!(parse_asdf_index_entry(""), None);
+}
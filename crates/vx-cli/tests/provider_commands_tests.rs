@@ -0,0 +1,197 @@
+//! Tests for provider-contributed subcommands
+
+use anyhow::Result;
+use async_trait::async_trait;
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser};
+use std::sync::Arc;
+use vx_cli::cli::Cli;
+use vx_cli::commands::shell::generate_fish_completion;
+use vx_cli::provider_commands::{find_command, parse_input, to_clap, with_contributions};
+use vx_runtime::{
+    CommandArg, CommandContribution, CommandInput, CommandSpec, Provider, ProviderRegistry,
+    Runtime, RuntimeContext, mock_context,
+};
+
+struct Clean;
+
+#[async_trait]
+impl CommandContribution for Clean {
+    fn spec(&self) -> CommandSpec {
+        CommandSpec::new("clean", "Remove build caches")
+            .long_about("Remove build caches of the selected profiles.")
+            .arg(CommandArg::flag("dry-run", "Only show what would be removed").short('n'))
+            .arg(
+                CommandArg::option("profile", "Profile to clean")
+                    .short('p')
+                    .choices(["debug", "release"])
+                    .default_value("debug"),
+            )
+            .arg(CommandArg::option("exclude", "Skip these paths").multiple())
+            .arg(CommandArg::positional("targets", "Targets to clean").multiple())
+    }
+
+    async fn run(&self, _input: &CommandInput, _ctx: &RuntimeContext) -> Result<i32> {
+        Ok(0)
+    }
+}
+
+struct MockProvider;
+
+impl Provider for MockProvider {
+    fn name(&self) -> &str {
+        "mocktool"
+    }
+
+    fn description(&self) -> &str {
+        "Mock tool"
+    }
+
+    fn runtimes(&self) -> Vec<Arc<dyn Runtime>> {
+        Vec::new()
+    }
+
+    fn commands(&self) -> Vec<Arc<dyn CommandContribution>> {
+        vec![Arc::new(Clean)]
+    }
+}
+
+fn args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn test_parse_input() {
+    let spec = Clean.spec();
+    let input = parse_input(
+        &spec,
+        "mocktool",
+        &args(&["-n", "--exclude", "a", "--exclude", "b", "x", "y"]),
+    )
+    .unwrap();
+
+    assert!(input.flag("dry-run"));
+    assert_eq!(input.get("profile"), Some("debug"));
+    assert_eq!(input.get_all("exclude"), ["a", "b"]);
+    assert_eq!(input.get_all("targets"), ["x", "y"]);
+
+    let input = parse_input(&spec, "mocktool", &args(&["-p", "release"])).unwrap();
+    assert!(!input.flag("dry-run"));
+    assert_eq!(input.get("profile"), Some("release"));
+    assert!(input.get_all("targets").is_empty());
+}
+
+#[test]
+fn test_parse_input_rejects_invalid_values() {
+    let spec = Clean.spec();
+    let err = parse_input(&spec, "mocktool", &args(&["--profile", "fast"])).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidValue);
+
+    let err = parse_input(&spec, "mocktool", &args(&["--unknown"])).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnknownArgument);
+
+    let err = parse_input(&spec, "mocktool", &args(&["--help"])).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::DisplayHelp);
+    let help = err.to_string();
+    assert!(help.contains("vx mocktool clean"));
+    assert!(help.contains("Remove build caches of the selected profiles."));
+    assert!(help.contains("[possible values: debug, release]"));
+}
+
+#[test]
+fn test_required_arguments() {
+    let spec = CommandSpec::new("deploy", "Deploy")
+        .arg(CommandArg::positional("target", "Where to deploy").required());
+    let err = parse_input(&spec, "mocktool", &[]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+    let input = parse_input(&spec, "mocktool", &args(&["prod"])).unwrap();
+    assert_eq!(input.get("target"), Some("prod"));
+    to_clap(&spec).debug_assert();
+}
+
+#[test]
+fn test_find_command() {
+    let registry = ProviderRegistry::new();
+    registry.register(Arc::new(MockProvider));
+
+    assert!(find_command(&registry, "mocktool", "clean").is_some());
+    assert!(find_command(&registry, "mocktool", "build").is_none());
+    assert!(find_command(&registry, "mocktool@1.0", "clean").is_none());
+    assert!(find_command(&registry, "other", "clean").is_none());
+}
+
+#[test]
+fn test_completions_include_contributed_commands() {
+    let registry = ProviderRegistry::new();
+    registry.register(Arc::new(MockProvider));
+
+    let script = generate_fish_completion(&with_contributions(Cli::command(), &registry, &[]));
+    assert!(script.contains("-a 'mocktool' -d 'Mock tool'"));
+    assert!(script.contains(
+        "complete -c vx -f -n '__fish_seen_subcommand_from mocktool; and not __fish_seen_subcommand_from clean' -a 'clean' -d 'Remove build caches'"
+    ));
+    assert!(script.contains("-s p -l profile -r -f -a 'debug release'"));
+}
+
+#[test]
+fn test_double_dash_bypasses_contributed_commands() {
+    let cli = Cli::try_parse_from(["vx", "mocktool", "--", "clean"]).unwrap();
+    assert_eq!(cli.args, ["mocktool", "--", "clean"]);
+}
+
+/// provider.star declaring `vx startool <subcommand>` commands; steps run cargo,
+/// which is always available to tests.
+fn star_provider() -> String {
+    format!(
+        r#"
+load("@vx//stdlib:provider.star", "runtime_def", "command_def", "command_arg")
+load("@vx//stdlib:install.star", "run_command")
+
+name = "startool"
+description = "Star tool"
+runtimes = [runtime_def("startool")]
+
+commands = [
+    command_def("cargo-version", "Print the cargo version", run = "cargo_version",
+                args = [command_arg("broken", kind = "flag", short = "b",
+                                    help = "Pass an unknown flag")]),
+    command_def("exit", "Exit with a code", run = "exit_with",
+                args = [command_arg("code", required = True, help = "Exit code")]),
+]
+
+def cargo_version(ctx, args):
+    extra = ["--no-such-flag"] if args["broken"] else []
+    return [run_command({cargo:?}, ["--version"] + extra)]
+
+def exit_with(ctx, args):
+    return int(args["code"])
+"#,
+        cargo = env!("CARGO"),
+    )
+}
+
+#[tokio::test]
+async fn test_star_provider_commands() {
+    let registry = ProviderRegistry::new();
+    registry.register(vx_starlark::create_provider("startool", star_provider()));
+    let ctx = mock_context();
+
+    let command = find_command(&registry, "startool", "cargo-version").unwrap();
+    let spec = command.spec();
+    assert_eq!(spec.about, "Print the cargo version");
+    assert_eq!(spec.args[0].short, Some('b'));
+
+    let input = parse_input(&spec, "startool", &[]).unwrap();
+    assert_eq!(command.run(&input, &ctx).await.unwrap(), 0);
+    let input = parse_input(&spec, "startool", &args(&["-b"])).unwrap();
+    assert_ne!(command.run(&input, &ctx).await.unwrap(), 0);
+
+    let command = find_command(&registry, "startool", "exit").unwrap();
+    let err = parse_input(&command.spec(), "startool", &[]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    let input = parse_input(&command.spec(), "startool", &args(&["3"])).unwrap();
+    assert_eq!(command.run(&input, &ctx).await.unwrap(), 3);
+
+    assert!(find_command(&registry, "startool", "startool").is_none());
+}
@@ -0,0 +1,60 @@
+//! Tests for `vx provider dev` analysis
+
+use std::path::Path;
+use vx_cli::commands::provider::analyze_dev_provider;
+
+const SCRIPT: &str = r#"
+name = "devtool"
+description = "Provider under development"
+
+runtimes = [
+    {"name": "devtool", "executable": "devtool"},
+    {"name": "devtool-helper", "executable": "devtool-helper"},
+]
+
+def fetch_versions(ctx):
+    return [{"version": "1.0.0"}]
+
+def download_url(ctx, version):
+    return "https://dl.example.com/devtool-" + version + ".tar.gz"
+"#;
+
+#[test]
+fn test_valid_provider_lists_runtimes() {
+    let analysis = analyze_dev_provider("devtool", Path::new("provider.star"), SCRIPT);
+    assert!(analysis.error.is_none(), "{:?}", analysis.error);
+    assert_eq!(analysis.runtimes, ["devtool", "devtool-helper"]);
+    assert!(analysis.lints.is_empty(), "{:?}", analysis.lints);
+}
+
+#[test]
+fn test_missing_function_is_reported() {
+    let script = SCRIPT.replace("def download_url", "def other_url");
+    let analysis = analyze_dev_provider("devtool", Path::new("provider.star"), &script);
+    let error = analysis
+        .error
+        .expect("script without download_url is invalid");
+    assert!(error.contains("download_url"), "{}", error);
+}
+
+#[test]
+fn test_syntax_error_is_reported() {
+    let script = format!("{}\ndef broken(:\n    pass\n", SCRIPT);
+    let analysis = analyze_dev_provider("devtool", Path::new("provider.star"), &script);
+    assert!(analysis.error.is_some());
+    assert!(analysis.runtimes.is_empty());
+}
+
+#[test]
+fn test_lint_warnings_are_collected() {
+    let script = format!(
+        "{}\ndef _unused(ctx):\n    x = 1\n    return None\n",
+        SCRIPT
+    );
+    let analysis = analyze_dev_provider("devtool", Path::new("provider.star"), &script);
+    assert!(analysis.error.is_none(), "{:?}", analysis.error);
+    assert!(
+        !analysis.lints.is_empty(),
+        "expected a lint for the unused assignment"
+    );
+}
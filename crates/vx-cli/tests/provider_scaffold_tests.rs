@@ -0,0 +1,105 @@
+//! Tests for `vx provider new` scaffolding
+
+use rstest::rstest;
+use vx_cli::cli::ProviderTemplate;
+use vx_cli::commands::provider_scaffold::{
+    ScaffoldOptions, parse_github_repo, render_provider_star, validate_name, write_scaffold,
+};
+use vx_starlark::StarlarkProvider;
+
+#[rstest]
+#[case(ProviderTemplate::Rust)]
+#[case(ProviderTemplate::Go)]
+#[case(ProviderTemplate::Binary)]
+#[tokio::test]
+async fn test_rendered_provider_downloads_from_github(#[case] template: ProviderTemplate) {
+    let opts = ScaffoldOptions::new("mytool", "owner/mytool", template).unwrap();
+    let star = render_provider_star(&opts);
+    vx_starlark::validate_user_provider("mytool", "provider.star", star.as_str()).unwrap();
+
+    let name = format!("scaffold-{:?}", template).to_lowercase();
+    let provider = StarlarkProvider::from_content(name, star).await.unwrap();
+    let url = provider.download_url("1.2.3").await.unwrap().unwrap();
+    assert!(
+        url.starts_with("https://github.com/owner/mytool/releases/download/v1.2.3/mytool"),
+        "{}",
+        url
+    );
+}
+
+#[tokio::test]
+async fn test_rendered_provider_uses_overrides() {
+    let mut opts = ScaffoldOptions::new(
+        "my-tool",
+        "https://github.com/acme/tool.git",
+        ProviderTemplate::Go,
+    )
+    .unwrap();
+    opts.asset = "tool-{version}-{os}-{arch}.{ext}".to_string();
+    opts.executable = "tool".to_string();
+    opts.tag_prefix = String::new();
+    let star = render_provider_star(&opts);
+    assert!(star.contains(r#"runtimes = [runtime_def("my-tool", executable = "tool")]"#));
+    assert!(star.contains(r#"store      = "my-tool","#));
+    assert!(star.contains(r#"tag_prefix = "","#));
+    assert!(!star.contains("executable = \"tool\",\n"));
+
+    let provider = StarlarkProvider::from_content("scaffold-overrides", star)
+        .await
+        .unwrap();
+    let url = provider.download_url("2.0.0").await.unwrap().unwrap();
+    assert!(
+        url.starts_with("https://github.com/acme/tool/releases/download/2.0.0/tool-2.0.0-"),
+        "{}",
+        url
+    );
+}
+
+#[test]
+fn test_write_scaffold_standalone_and_builtin() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut opts = ScaffoldOptions::new("mytool", "owner/mytool", ProviderTemplate::Rust).unwrap();
+
+    let written = write_scaffold(dir.path(), &opts, false).unwrap();
+    assert_eq!(written, [dir.path().join("provider.star")]);
+
+    // Existing files are kept unless forced
+    let err = write_scaffold(dir.path(), &opts, false).unwrap_err();
+    assert!(err.to_string().contains("--force"), "{}", err);
+
+    opts.builtin = true;
+    write_scaffold(dir.path(), &opts, true).unwrap();
+    let logic = std::fs::read_to_string(dir.path().join("tests/starlark_logic_tests.rs")).unwrap();
+    assert!(logic.contains("vx_provider_mytool::PROVIDER_STAR"));
+    assert!(logic.contains(r#"install_layout(ctx, "1.0.0")["type"] == "archive""#));
+    assert!(dir.path().join("tests/runtime_tests.rs").exists());
+}
+
+#[rstest]
+#[case("mytool", true)]
+#[case("my-tool2", true)]
+#[case("MyTool", false)]
+#[case("2tool", false)]
+#[case("my_tool", false)]
+#[case("", false)]
+fn test_validate_name(#[case] name: &str, #[case] valid: bool) {
+    assert_eq!(validate_name(name).is_ok(), valid);
+}
+
+#[rstest]
+#[case("owner/repo")]
+#[case("github.com/owner/repo")]
+#[case("https://github.com/owner/repo/")]
+#[case("https://github.com/owner/repo.git")]
+fn test_parse_github_repo(#[case] repo: &str) {
+    assert_eq!(
+        parse_github_repo(repo).unwrap(),
+        ("owner".to_string(), "repo".to_string())
+    );
+}
+
+#[test]
+fn test_parse_github_repo_rejects_other_shapes() {
+    assert!(parse_github_repo("repo").is_err());
+    assert!(parse_github_repo("owner/repo/tree/main").is_err());
+}
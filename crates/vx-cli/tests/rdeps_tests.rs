@@ -0,0 +1,93 @@
+//! Tests for `vx rdeps`
+
+use std::collections::{BTreeMap, BTreeSet};
+use vx_cli::commands::rdeps::{DependencyEdges, ReverseDependency, reverse_dependencies};
+use vx_paths::InstallReasons;
+
+fn edges() -> BTreeMap<String, DependencyEdges> {
+    let mut edges = BTreeMap::new();
+    edges.insert(
+        "npm".to_string(),
+        DependencyEdges {
+            bundled_with: vec!["node".to_string()],
+            requires: vec![],
+        },
+    );
+    edges.insert(
+        "pnpm".to_string(),
+        DependencyEdges {
+            bundled_with: vec![],
+            requires: vec!["node".to_string()],
+        },
+    );
+    edges.insert("node".to_string(), DependencyEdges::default());
+    edges.insert(
+        "uv".to_string(),
+        DependencyEdges {
+            bundled_with: vec![],
+            requires: vec!["python".to_string()],
+        },
+    );
+    edges
+}
+
+fn installed(tools: &[&str]) -> BTreeSet<String> {
+    tools.iter().map(|t| t.to_string()).collect()
+}
+
+#[test]
+fn test_reverse_dependencies_combine_declared_and_recorded() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut reasons = InstallReasons::load(temp.path());
+    reasons.record_dependency("node", "pnpm");
+    reasons.record_dependency("node", "yarn");
+
+    let dependents = reverse_dependencies(
+        "node",
+        &edges(),
+        &reasons,
+        &installed(&["node", "npm", "pnpm"]),
+    );
+    assert_eq!(
+        dependents,
+        vec![
+            ReverseDependency {
+                tool: "npm".to_string(),
+                installed: true,
+                bundled: true,
+                declared: false,
+                recorded: false,
+            },
+            ReverseDependency {
+                tool: "pnpm".to_string(),
+                installed: true,
+                bundled: false,
+                declared: true,
+                recorded: true,
+            },
+            ReverseDependency {
+                tool: "yarn".to_string(),
+                installed: false,
+                bundled: false,
+                declared: false,
+                recorded: true,
+            },
+        ]
+    );
+    assert_eq!(
+        dependents[1].describe("node"),
+        "requires node, installed node as its dependency"
+    );
+    assert_eq!(dependents[0].describe("node"), "bundled with node");
+}
+
+#[test]
+fn test_reverse_dependencies_of_leaf() {
+    let temp = tempfile::tempdir().unwrap();
+    let reasons = InstallReasons::load(temp.path());
+    assert!(reverse_dependencies("pnpm", &edges(), &reasons, &installed(&["pnpm"])).is_empty());
+    assert_eq!(
+        reverse_dependencies("python", &edges(), &reasons, &installed(&[]))[0].tool,
+        "uv"
+    );
+}
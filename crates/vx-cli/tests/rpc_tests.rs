@@ -0,0 +1,120 @@
+//! Tests for the `vx rpc` JSON-RPC protocol
+
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+use vx_cli::commands::rpc::{
+    ExecuteParams, INVALID_PARAMS, INVALID_REQUEST, ListParams, METHOD_NOT_FOUND, Method,
+    OutputLine, PARSE_ERROR, RpcError, ToolParams, error_response, notification, parse_request,
+    response,
+};
+
+#[test]
+fn test_parse_resolve_request() {
+    let request = parse_request(
+        r#"{"jsonrpc":"2.0","id":1,"method":"resolve","params":{"tool":"node","version":"20"}}"#,
+    )
+    .unwrap();
+    assert_eq!(request.id, Some(json!(1)));
+    assert_eq!(
+        request.method,
+        Method::Resolve(ToolParams {
+            tool: "node".to_string(),
+            version: Some("20".to_string()),
+        })
+    );
+}
+
+#[test]
+fn test_parse_list_without_params() {
+    let request = parse_request(r#"{"jsonrpc":"2.0","id":"a","method":"list"}"#).unwrap();
+    assert_eq!(request.id, Some(json!("a")));
+    assert_eq!(request.method, Method::List(ListParams::default()));
+
+    let request =
+        parse_request(r#"{"jsonrpc":"2.0","id":2,"method":"list","params":{"installed":true}}"#)
+            .unwrap();
+    assert_eq!(request.method, Method::List(ListParams { installed: true }));
+}
+
+#[test]
+fn test_parse_execute_request() {
+    let request = parse_request(
+        r#"{"jsonrpc":"2.0","id":3,"method":"execute","params":{"tool":"node","version":"22","args":["--version"],"env":{"CI":"1"}}}"#,
+    )
+    .unwrap();
+    let Method::Execute(params) = request.method else {
+        panic!("expected execute");
+    };
+    assert_eq!(params.child_args(), ["node@22", "--version"]);
+    assert_eq!(
+        params.env,
+        BTreeMap::from([("CI".to_string(), "1".to_string())])
+    );
+    assert_eq!(params.cwd, None);
+
+    let params = ExecuteParams {
+        tool: "uv".to_string(),
+        version: None,
+        args: vec!["pip".to_string(), "list".to_string()],
+        cwd: None,
+        env: BTreeMap::new(),
+    };
+    assert_eq!(params.child_args(), ["uv", "pip", "list"]);
+}
+
+#[test]
+fn test_parse_notification_has_no_id() {
+    let request = parse_request(r#"{"jsonrpc":"2.0","method":"shutdown"}"#).unwrap();
+    assert_eq!(request.id, None);
+    assert_eq!(request.method, Method::Shutdown);
+}
+
+#[test]
+fn test_parse_errors() {
+    let code = |line: &str| parse_request(line).unwrap_err();
+
+    assert_eq!(code("{not json").1.code, PARSE_ERROR);
+    assert_eq!(code("[1, 2]").1.code, INVALID_REQUEST);
+    assert_eq!(
+        code(r#"{"id":1,"method":"list"}"#),
+        (
+            Some(json!(1)),
+            RpcError::new(INVALID_REQUEST, "Missing \"jsonrpc\": \"2.0\"")
+        )
+    );
+
+    let (id, error) = code(r#"{"jsonrpc":"2.0","id":4,"method":"frobnicate"}"#);
+    assert_eq!(id, Some(json!(4)));
+    assert_eq!(error.code, METHOD_NOT_FOUND);
+    assert_eq!(error.message, "Method not found: frobnicate");
+
+    let (id, error) = code(r#"{"jsonrpc":"2.0","id":5,"method":"resolve","params":{}}"#);
+    assert_eq!(id, Some(json!(5)));
+    assert_eq!(error.code, INVALID_PARAMS);
+    assert!(error.message.contains("tool"), "{}", error.message);
+}
+
+#[test]
+fn test_messages() {
+    assert_eq!(
+        response(json!(1), json!({"exit_code": 0})),
+        json!({"jsonrpc": "2.0", "id": 1, "result": {"exit_code": 0}})
+    );
+    assert_eq!(
+        error_response(None, &RpcError::new(PARSE_ERROR, "Parse error")),
+        json!({"jsonrpc": "2.0", "id": Value::Null, "error": {"code": -32700, "message": "Parse error"}})
+    );
+    let line = OutputLine {
+        request: json!(7),
+        stream: "stderr",
+        line: "Downloading node 22.1.0".to_string(),
+    };
+    assert_eq!(
+        notification("progress", line),
+        json!({
+            "jsonrpc": "2.0",
+            "method": "progress",
+            "params": {"request": 7, "stream": "stderr", "line": "Downloading node 22.1.0"},
+        })
+    );
+}
@@ -0,0 +1,100 @@
+//! Tests for `vx sbom` document rendering
+
+use vx_cli::commands::sbom::{SbomComponent, render_cyclonedx, render_spdx, split_checksum};
+
+fn components() -> Vec<SbomComponent> {
+    vec![
+        SbomComponent {
+            name: "node".to_string(),
+            version: "20.11.0".to_string(),
+            homepage: Some("https://nodejs.org".to_string()),
+            license: Some("MIT".to_string()),
+            download_url: Some("https://nodejs.org/dist/v20.11.0/node.tar.gz".to_string()),
+            artifact_checksum: Some("sha256:abc123".to_string()),
+            executable_sha256: Some("def456".to_string()),
+            locked: true,
+            ..Default::default()
+        },
+        SbomComponent {
+            name: "terraform".to_string(),
+            version: "1.7.0".to_string(),
+            system_manager: Some("winget".to_string()),
+            ..Default::default()
+        },
+    ]
+}
+
+#[test]
+fn test_split_checksum() {
+    assert_eq!(
+        split_checksum("SHA512:ff00"),
+        ("sha512".to_string(), "ff00".to_string())
+    );
+    assert_eq!(
+        split_checksum("ff00"),
+        ("sha256".to_string(), "ff00".to_string())
+    );
+}
+
+#[test]
+fn test_purl() {
+    let components = components();
+    assert_eq!(
+        components[0].purl(),
+        "pkg:generic/node@20.11.0?download_url=https%3A//nodejs.org/dist/v20.11.0/node.tar.gz"
+    );
+    assert_eq!(components[1].purl(), "pkg:generic/terraform@1.7.0");
+}
+
+#[test]
+fn test_render_cyclonedx() {
+    let json = render_cyclonedx(&components(), "1234", "2026-01-01T00:00:00Z").unwrap();
+    let doc: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(doc["bomFormat"], "CycloneDX");
+    assert_eq!(doc["specVersion"], "1.5");
+    assert_eq!(doc["serialNumber"], "urn:uuid:1234");
+
+    let node = &doc["components"][0];
+    assert_eq!(node["bom-ref"], "node@20.11.0");
+    assert_eq!(node["hashes"][0]["alg"], "SHA-256");
+    assert_eq!(node["hashes"][0]["content"], "def456");
+    assert_eq!(node["licenses"][0]["expression"], "MIT");
+    assert_eq!(node["externalReferences"][0]["type"], "distribution");
+    assert_eq!(
+        node["externalReferences"][0]["hashes"][0]["content"],
+        "abc123"
+    );
+    assert_eq!(node["externalReferences"][1]["type"], "website");
+
+    let terraform = &doc["components"][1];
+    assert!(terraform.get("hashes").is_none());
+    assert!(terraform.get("externalReferences").is_none());
+    assert_eq!(terraform["properties"][1]["value"], "winget");
+}
+
+#[test]
+fn test_render_spdx() {
+    let json = render_spdx(&components(), "1234", "2026-01-01T00:00:00Z").unwrap();
+    let doc: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(doc["spdxVersion"], "SPDX-2.3");
+    assert_eq!(doc["creationInfo"]["created"], "2026-01-01T00:00:00Z");
+
+    let node = &doc["packages"][0];
+    assert_eq!(node["SPDXID"], "SPDXRef-Package-node-20.11.0");
+    assert_eq!(node["checksums"][0]["algorithm"], "SHA256");
+    assert_eq!(node["checksums"][0]["checksumValue"], "abc123");
+    assert_eq!(node["licenseDeclared"], "MIT");
+
+    let terraform = &doc["packages"][1];
+    assert_eq!(terraform["downloadLocation"], "NOASSERTION");
+    assert_eq!(terraform["licenseDeclared"], "NOASSERTION");
+    assert_eq!(terraform["comment"], "Installed by winget");
+
+    assert_eq!(doc["relationships"].as_array().unwrap().len(), 2);
+    assert_eq!(
+        doc["relationships"][1]["relatedSpdxElement"],
+        "SPDXRef-Package-terraform-1.7.0"
+    );
+}
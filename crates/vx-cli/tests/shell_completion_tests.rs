@@ -0,0 +1,73 @@
+//! Tests for fish and Nushell completions generated from the CLI definition
+
+use clap::CommandFactory;
+use vx_cli::cli::Cli;
+use vx_cli::commands::shell::{generate_fish_completion, generate_nushell_completion};
+
+#[test]
+fn test_fish_completion_lists_current_commands() {
+    let script = generate_fish_completion(&Cli::command());
+
+    assert!(script.contains("complete -c vx -f -n '__fish_use_subcommand' -a 'install'"));
+    assert!(script.contains("-a 'activate'"));
+    assert!(script.contains("complete -c vx -s w -l with -r"));
+    assert!(script.contains(
+        "complete -c vx -f -n '__fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from"
+    ));
+    // Shell names are offered for `vx shell completions <shell>`
+    assert!(script.contains(
+        "complete -c vx -f -n '__fish_seen_subcommand_from shell; and __fish_seen_subcommand_from completions' -a 'bash zsh fish nu powershell cmd'"
+    ));
+}
+
+#[test]
+fn test_fish_completion_escapes_quotes() {
+    let script = generate_fish_completion(&Cli::command());
+    for line in script.lines().filter(|l| l.starts_with("complete")) {
+        let unescaped = line.replace("\\'", "");
+        assert_eq!(
+            unescaped.matches('\'').count() % 2,
+            0,
+            "unbalanced quotes: {line}"
+        );
+    }
+}
+
+#[test]
+fn test_nushell_completion_defines_externs() {
+    let script = generate_nushell_completion(&Cli::command());
+
+    assert!(script.contains("def \"nu-complete vx shells\" []"));
+    assert!(script.contains("extern \"vx\" ["));
+    assert!(script.contains("extern \"vx install\" ["));
+    assert!(script.contains("extern \"vx shell completions\" ["));
+    assert!(script.contains("    shell: string@\"nu-complete vx shells\""));
+    assert!(script.contains("--with(-w): string"));
+}
+
+#[test]
+fn test_nushell_externs_have_unique_flags() {
+    let script = generate_nushell_completion(&Cli::command());
+
+    for block in script.split("extern ").skip(1) {
+        let body = block.split("\n]\n").next().unwrap();
+        let mut longs = Vec::new();
+        let mut shorts = Vec::new();
+        for param in body.lines().skip(1).map(str::trim) {
+            let Some(flag) = param.strip_prefix("--") else {
+                continue;
+            };
+            let flag = flag.split_whitespace().next().unwrap();
+            let name: String = flag
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '-' || *c == '_')
+                .collect();
+            assert!(!longs.contains(&name), "duplicate --{name} in {block}");
+            longs.push(name);
+            if let Some(short) = flag.split_once('(').map(|(_, s)| &s[1..2]) {
+                assert!(!shorts.contains(&short), "duplicate -{short} in {block}");
+                shorts.push(short);
+            }
+        }
+    }
+}
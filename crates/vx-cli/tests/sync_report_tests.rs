@@ -0,0 +1,76 @@
+//! Tests for the `vx sync` retry policy and summary report
+
+use std::time::Duration;
+use vx_cli::commands::sync::{SyncReport, ToolOutcome, failure_reason, retry_delay};
+
+#[test]
+fn test_retry_delay_grows_and_caps() {
+    assert_eq!(retry_delay(1), Duration::from_secs(2));
+    assert_eq!(retry_delay(2), Duration::from_secs(4));
+    assert_eq!(retry_delay(3), Duration::from_secs(8));
+    assert_eq!(retry_delay(10), Duration::from_secs(30));
+    assert_eq!(retry_delay(u32::MAX), Duration::from_secs(30));
+}
+
+#[test]
+fn test_failure_reason_skips_blank_and_spinner_lines() {
+    let error = "\n   \n� Downloading\nError: connection reset by peer\nmore";
+    assert_eq!(
+        failure_reason(Some(error)),
+        "Error: connection reset by peer"
+    );
+    assert_eq!(
+        failure_reason(Some("✗ Failed to install just@1.36.0")),
+        "Failed to install just@1.36.0"
+    );
+    assert_eq!(failure_reason(Some("  \n✗\n")), "unknown error");
+    assert_eq!(failure_reason(None), "unknown error");
+}
+
+#[test]
+fn test_record_replaces_earlier_outcome() {
+    let mut report = SyncReport::default();
+    report.record(
+        "node",
+        "22",
+        ToolOutcome::Failed {
+            attempts: 1,
+            reason: "timeout".to_string(),
+        },
+    );
+    report.record("node", "22", ToolOutcome::Installed { attempts: 2 });
+
+    assert_eq!(report.tools.len(), 1);
+    assert_eq!(report.tools[0].2, ToolOutcome::Installed { attempts: 2 });
+    assert!(report.failed().is_empty());
+}
+
+#[test]
+fn test_render_groups_outcomes() {
+    let mut report = SyncReport::default();
+    report.record("just", "latest", ToolOutcome::Reused);
+    report.record("node", "22", ToolOutcome::Installed { attempts: 1 });
+    report.record("uv", "0.5", ToolOutcome::Installed { attempts: 2 });
+    report.record(
+        "go",
+        "1.22",
+        ToolOutcome::Failed {
+            attempts: 3,
+            reason: "HTTP 503".to_string(),
+        },
+    );
+
+    let rendered = report.render();
+    assert!(rendered.contains("Installed (2): node@22, uv@0.5 (attempt 2)"));
+    assert!(rendered.contains("Reused (1): just@latest"));
+    assert!(rendered.contains("Failed (1):"));
+    assert!(rendered.contains("✗ go@1.22 (3 attempts): HTTP 503"));
+    assert_eq!(report.failed(), vec![("go", "1.22")]);
+}
+
+#[test]
+fn test_render_without_failures_has_no_failed_group() {
+    let mut report = SyncReport::default();
+    report.record("node", "22", ToolOutcome::Reused);
+    assert!(!report.render().contains("Failed"));
+}
@@ -0,0 +1,137 @@
+//! Tests for the remote `vx init` template registry
+
+use std::collections::BTreeMap;
+use std::process::Command;
+use vx_cli::template_registry::{
+    INDEX_FILE_NAME, IndexSource, TemplateIndex, TemplateRegistry, parse_vars, render_template,
+    resolve_url,
+};
+
+const INDEX: &str = r#"
+[[templates]]
+name = "acme-service"
+description = "ACME backend service"
+path = "acme-service/vx.toml"
+
+[templates.variables]
+node_version = "22"
+"#;
+
+fn vars(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_index_source_parse() {
+    assert_eq!(
+        IndexSource::parse("https://example.com/index.toml"),
+        IndexSource::Http("https://example.com/index.toml".to_string())
+    );
+    assert_eq!(
+        IndexSource::parse("git+https://example.com/templates"),
+        IndexSource::Git("https://example.com/templates".to_string())
+    );
+    assert_eq!(
+        IndexSource::parse("https://github.com/acme/templates.git"),
+        IndexSource::Git("https://github.com/acme/templates.git".to_string())
+    );
+}
+
+#[test]
+fn test_parse_index() {
+    let index = TemplateIndex::parse(INDEX).unwrap();
+    let entry = index.get("acme-service").unwrap();
+    assert_eq!(entry.path, "acme-service/vx.toml");
+    assert_eq!(entry.variables["node_version"], "22");
+    assert!(index.get("missing").is_none());
+}
+
+#[test]
+fn test_resolve_url() {
+    assert_eq!(
+        resolve_url("https://example.com/vx/index.toml", "node/vx.toml"),
+        "https://example.com/vx/node/vx.toml"
+    );
+    assert_eq!(
+        resolve_url(
+            "https://example.com/vx/index.toml",
+            "https://cdn.example.com/t.toml"
+        ),
+        "https://cdn.example.com/t.toml"
+    );
+}
+
+#[test]
+fn test_render_template_overrides_defaults() {
+    let rendered = render_template(
+        "[project]\nname = \"{{ project_name }}\"\n\n[tools]\nnode = \"{{node_version}}\"\n",
+        &vars(&[("node_version", "22"), ("project_name", "demo")]),
+        &vars(&[("node_version", "20")]),
+    )
+    .unwrap();
+
+    assert!(rendered.contains("name = \"demo\""));
+    assert!(rendered.contains("node = \"20\""));
+}
+
+#[test]
+fn test_render_template_reports_missing_variables() {
+    let err = render_template("{{a}} {{b}} {{a}}", &BTreeMap::new(), &BTreeMap::new())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("a, b"), "{}", err);
+}
+
+#[test]
+fn test_parse_vars() {
+    let parsed = parse_vars(&["team=platform".to_string(), "url=a=b".to_string()]).unwrap();
+    assert_eq!(parsed["team"], "platform");
+    assert_eq!(parsed["url"], "a=b");
+    assert!(parse_vars(&["novalue".to_string()]).is_err());
+}
+
+#[tokio::test]
+async fn test_load_git_registry() {
+    if which::which("git").is_err() {
+        return;
+    }
+    let repo = tempfile::tempdir().unwrap();
+    std::fs::write(repo.path().join(INDEX_FILE_NAME), INDEX).unwrap();
+    std::fs::create_dir_all(repo.path().join("acme-service")).unwrap();
+    std::fs::write(
+        repo.path().join("acme-service").join("vx.toml"),
+        "[tools]\nnode = \"{{node_version}}\"\n",
+    )
+    .unwrap();
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .arg("-C")
+            .arg(repo.path())
+            .args(args)
+            .output()
+            .unwrap()
+    };
+    git(&["init", "--quiet"]);
+    git(&["add", "."]);
+    git(&[
+        "-c",
+        "user.name=vx",
+        "-c",
+        "user.email=vx@example.com",
+        "commit",
+        "--quiet",
+        "-m",
+        "templates",
+    ]);
+
+    let cache = tempfile::tempdir().unwrap();
+    let source = IndexSource::Git(repo.path().display().to_string());
+    let registry = TemplateRegistry::load(source, cache.path()).await.unwrap();
+    let entry = registry.index.get("acme-service").unwrap();
+    let content = registry.fetch_template(entry).await.unwrap();
+
+    assert_eq!(content, "[tools]\nnode = \"{{node_version}}\"\n");
+}
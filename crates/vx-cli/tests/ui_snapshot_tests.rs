@@ -0,0 +1,115 @@
+//! Snapshot tests of UI messages and text output
+//!
+//! Each snapshot holds the output of every capture mode (plain, color,
+//! verbose, quiet, CI). Run with `VX_UPDATE_SNAPSHOTS=1` after an intended
+//! output change and review the diff of `tests/snapshots/`.
+
+use vx_cli::cli::OutputFormat;
+use vx_cli::output::{ListOutput, OutputRenderer, RuntimeEntry};
+use vx_cli::suggestions::ToolSuggestion;
+use vx_cli::ui::UI;
+use vx_console::{Capture, CaptureMode, Verbosity, assert_snapshot, capture_all};
+
+/// Capture `f` in every mode, with `colored` and UI verbosity following the
+/// mode
+fn capture_ui(mut f: impl FnMut()) -> String {
+    capture_all(&CaptureMode::all(), |capture: &Capture| {
+        let mode = capture.mode();
+        colored::control::set_override(mode.color);
+        UI::set_verbose(mode.verbosity == Verbosity::Verbose);
+        f();
+        UI::set_verbose(false);
+        colored::control::unset_override();
+    })
+}
+
+#[test]
+fn test_ui_messages_snapshot() {
+    let output = capture_ui(|| {
+        UI::header("Installing tools");
+        UI::info("Resolving node@20");
+        UI::debug("Using cached index for node");
+        UI::success("Installed node@20.10.0");
+        UI::warn("node@18 reaches end of life on 2025-04-30");
+        UI::error("Failed to install go@1.23: checksum mismatch");
+        UI::hint("Run 'vx cache clean' and retry");
+        UI::item("node 20.10.0");
+        UI::detail("~/.vx/store/node/20.10.0");
+        UI::separator();
+    });
+    assert_snapshot!("ui_messages", output);
+}
+
+#[test]
+fn test_ui_progress_snapshot() {
+    let output = capture_ui(|| {
+        UI::section("Syncing project tools");
+        UI::step("node@20");
+        let spinner = UI::new_spinner("Downloading node@20.10.0");
+        spinner.finish_and_clear();
+        let spinner = vx_cli::ui::progress_manager().add_spinner("Installing uv@0.5.0");
+        spinner.set_message("Linking uv@0.5.0");
+        spinner.finish_success("Installed uv@0.5.0");
+    });
+    assert_snapshot!("ui_progress", output);
+}
+
+#[test]
+fn test_tool_not_found_snapshot() {
+    let output = capture_ui(|| {
+        let suggestion = ToolSuggestion {
+            suggested_tool: "cargo".to_string(),
+            description: "Rust toolchain is managed through cargo".to_string(),
+            is_alias: true,
+        };
+        UI::tool_not_found_simple("rust", Some(&suggestion));
+    });
+    assert_snapshot!("tool_not_found", output);
+}
+
+#[test]
+fn test_list_output_snapshot() {
+    let list = ListOutput {
+        runtimes: vec![
+            RuntimeEntry {
+                name: "node".to_string(),
+                versions: vec!["20.10.0".to_string(), "18.19.0".to_string()],
+                installed: true,
+                description: "Node.js JavaScript runtime".to_string(),
+                platform_supported: true,
+                ecosystem: Some("nodejs".to_string()),
+                platform_label: None,
+            },
+            RuntimeEntry {
+                name: "go".to_string(),
+                versions: Vec::new(),
+                installed: false,
+                description: "Go programming language".to_string(),
+                platform_supported: true,
+                ecosystem: Some("go".to_string()),
+                platform_label: None,
+            },
+            RuntimeEntry {
+                name: "msbuild".to_string(),
+                versions: Vec::new(),
+                installed: false,
+                description: "Microsoft Build Engine".to_string(),
+                platform_supported: false,
+                ecosystem: None,
+                platform_label: Some("Windows only".to_string()),
+            },
+        ],
+        total: 3,
+        installed_count: 1,
+        platform: "linux-x64".to_string(),
+    };
+
+    let text = OutputRenderer::new_exact(OutputFormat::Text)
+        .render_to_string(&list)
+        .unwrap();
+    let compact = OutputRenderer::compact().render_to_string(&list).unwrap();
+    assert_snapshot!(
+        "list_output",
+        format!("== text ==\n{}== compact ==\n{}", text, compact)
+    );
+}
@@ -0,0 +1,46 @@
+//! Tests for building the output theme from `[ui]` options
+
+use vx_cli::ui::theme_for;
+use vx_config::UiConfig;
+
+#[test]
+fn test_theme_for_named_theme() {
+    let theme = theme_for(&UiConfig {
+        theme: Some("screen-reader".to_string()),
+        ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(theme.success_prefix(), "Success:");
+    assert!(!theme.is_animated());
+}
+
+#[test]
+fn test_theme_for_applies_emoji_and_ascii_options() {
+    let theme = theme_for(&UiConfig {
+        theme: Some("default".to_string()),
+        emoji: Some(false),
+        ascii_only: None,
+    })
+    .unwrap();
+    assert_eq!(theme.hint_prefix(), "→");
+    assert!(!theme.allows_emoji());
+
+    let theme = theme_for(&UiConfig {
+        theme: Some("default".to_string()),
+        emoji: None,
+        ascii_only: Some(true),
+    })
+    .unwrap();
+    assert_eq!(theme.success_prefix(), "[OK]");
+}
+
+#[test]
+fn test_theme_for_unknown_theme() {
+    let err = theme_for(&UiConfig {
+        theme: Some("sparkly".to_string()),
+        ..Default::default()
+    })
+    .unwrap_err();
+    assert!(err.contains("sparkly"));
+    assert!(err.contains("screen-reader"));
+}
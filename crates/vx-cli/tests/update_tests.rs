@@ -0,0 +1,226 @@
+//! Tests for `vx update` planning and summary rendering
+
+use vx_cli::commands::update::{
+    ToolUpdate, dependency_order, plan_installed_upgrade, plan_update, render_summary,
+    render_upgrade_table,
+};
+use vx_cli::registry::find_version_normalizer;
+use vx_resolver::Ecosystem;
+use vx_versions::VersionInfo;
+
+fn release(version: &str) -> VersionInfo {
+    VersionInfo::new(version)
+        .with_release_url(format!(
+            "https://github.com/owner/tool/releases/tag/v{}",
+            version
+        ))
+        .with_release_notes(format!("* Changes in {}", version))
+}
+
+fn available() -> Vec<VersionInfo> {
+    vec![
+        release("2.0.0"),
+        release("1.3.0"),
+        VersionInfo::new("1.2.1"),
+        release("1.2.0"),
+        release("1.1.0"),
+    ]
+}
+
+#[test]
+fn test_plan_respects_constraint() {
+    let update = plan_update(
+        "tool",
+        "^1.1",
+        Some("1.1.0"),
+        &available(),
+        &Ecosystem::Generic,
+    );
+
+    assert_eq!(update.target.as_deref(), Some("1.3.0"));
+    assert_eq!(update.latest.as_deref(), Some("2.0.0"));
+    assert!(update.is_outdated());
+    assert!(update.is_held_back());
+
+    // Only releases in (current, target] that carry notes, newest first
+    let versions: Vec<_> = update.notes.iter().map(|n| n.version.as_str()).collect();
+    assert_eq!(versions, vec!["1.3.0", "1.2.0"]);
+    assert_eq!(
+        update.notes[0].url.as_deref(),
+        Some("https://github.com/owner/tool/releases/tag/v1.3.0")
+    );
+}
+
+#[test]
+fn test_plan_up_to_date() {
+    let update = plan_update(
+        "tool",
+        "latest",
+        Some("2.0.0"),
+        &available(),
+        &Ecosystem::Generic,
+    );
+
+    assert!(!update.is_outdated());
+    assert!(!update.is_held_back());
+    assert!(update.notes.is_empty());
+}
+
+#[test]
+fn test_plan_unresolvable_constraint() {
+    let update = plan_update(
+        "tool",
+        "^3",
+        Some("2.0.0"),
+        &available(),
+        &Ecosystem::Generic,
+    );
+
+    assert!(update.error.is_some());
+    assert!(!update.is_outdated());
+}
+
+#[test]
+fn test_render_summary() {
+    let outdated = plan_update(
+        "tool",
+        "^1.1",
+        Some("1.1.0"),
+        &available(),
+        &Ecosystem::Generic,
+    );
+    let current = plan_update(
+        "other",
+        "latest",
+        Some("2.0.0"),
+        &available(),
+        &Ecosystem::Generic,
+    );
+    let failed = ToolUpdate {
+        error: Some("install failed".to_string()),
+        ..plan_update(
+            "broken",
+            "latest",
+            Some("1.0.0"),
+            &available(),
+            &Ecosystem::Generic,
+        )
+    };
+
+    let summary = render_summary(&[outdated, current, failed], true);
+
+    assert!(summary.starts_with("## Tool updates\n"));
+    assert!(summary.contains("| tool | 1.1.0 | 1.3.0 | `^1.1` |"));
+    assert!(!summary.contains("| other |"));
+    assert!(!summary.contains("| broken |"));
+    assert!(summary.contains("### tool 1.1.0 → 1.3.0"));
+    assert!(summary.contains(
+        "- [1.3.0](https://github.com/owner/tool/releases/tag/v1.3.0)\n  > * Changes in 1.3.0"
+    ));
+    assert!(summary.contains("- tool stays on 1.3.0 (`^1.1`), latest is 2.0.0"));
+    assert!(summary.contains("### Not updated\n\n- broken: install failed"));
+}
+
+#[test]
+fn test_render_summary_nothing_to_update() {
+    let current = plan_update(
+        "tool",
+        "latest",
+        Some("2.0.0"),
+        &available(),
+        &Ecosystem::Generic,
+    );
+
+    let summary = render_summary(&[current], false);
+    assert_eq!(
+        summary,
+        "## Available tool updates\n\nAll tools are up to date.\n"
+    );
+}
+
+fn node_versions() -> Vec<VersionInfo> {
+    vec![
+        VersionInfo::new("23.1.0").with_release_date("2024-10-24T00:00:00Z"),
+        VersionInfo::new("22.11.0")
+            .with_lts(true)
+            .with_release_date("2024-10-29T00:00:00Z"),
+        VersionInfo::new("22.10.0").with_lts(true),
+        VersionInfo::new("21.7.3"),
+    ]
+}
+
+#[test]
+fn test_installed_upgrade_stays_on_lts() {
+    let update = plan_installed_upgrade("node", "22.10.0", &node_versions(), &Ecosystem::NodeJs);
+
+    assert_eq!(update.target.as_deref(), Some("22.11.0"));
+    assert!(update.lts);
+    assert_eq!(update.released.as_deref(), Some("2024-10-29"));
+    assert!(update.is_outdated());
+}
+
+#[test]
+fn test_installed_upgrade_moves_to_newest_stable() {
+    let update = plan_installed_upgrade("node", "21.7.3", &node_versions(), &Ecosystem::NodeJs);
+
+    assert_eq!(update.target.as_deref(), Some("23.1.0"));
+    assert!(!update.lts);
+    assert_eq!(update.released.as_deref(), Some("2024-10-24"));
+}
+
+#[test]
+fn test_render_upgrade_table() {
+    let outdated = plan_installed_upgrade("node", "22.10.0", &node_versions(), &Ecosystem::NodeJs);
+    let current = plan_installed_upgrade("go", "23.1.0", &node_versions(), &Ecosystem::Generic);
+
+    let table = render_upgrade_table(&[outdated, current]);
+    let lines: Vec<_> = table.lines().collect();
+
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("TOOL"));
+    assert!(lines[0].ends_with("LTS"));
+    let cells: Vec<_> = lines[1].split_whitespace().collect();
+    assert_eq!(
+        cells,
+        vec!["node", "22.10.0", "22.11.0", "2024-10-29", "yes"]
+    );
+}
+
+#[test]
+fn test_dependency_order() {
+    let names: Vec<String> = ["pnpm", "node", "uv", "python"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let order = dependency_order(&names, |name| match name {
+        "pnpm" => vec!["node".to_string()],
+        "uv" => vec!["python".to_string(), "rust".to_string()],
+        "python" => vec!["uv".to_string()],
+        _ => vec![],
+    });
+
+    // Dependencies first, unrelated tools untouched, cycles do not loop
+    assert_eq!(order, vec!["node", "pnpm", "python", "uv"]);
+}
+
+#[test]
+fn test_provider_version_formats() {
+    let bun = find_version_normalizer("bun");
+    assert_eq!(bun.normalize("bun-v1.1.0"), "1.1.0");
+    assert!(find_version_normalizer("bunx").same_version("bun-v1.1.0", "1.1.0"));
+    assert_eq!(find_version_normalizer("jq").normalize("jq-1.7.1"), "1.7.1");
+    assert_eq!(
+        find_version_normalizer("node").normalize("v20.10.0"),
+        "20.10.0"
+    );
+
+    // A prefixed installed version is not behind the same available version
+    let update = plan_update(
+        "bun",
+        "latest",
+        Some(&bun.normalize("bun-v2.0.0")),
+        &available(),
+        &Ecosystem::Generic,
+    );
+    assert!(!update.is_outdated());
+}
@@ -0,0 +1,85 @@
+//! Tests for `vx wrap` wrapper script generation
+
+use vx_cli::commands::wrap::{
+    WRAPPER_MARKER, render_cmd, render_sh, render_wrappers, write_wrappers,
+};
+
+#[test]
+fn test_render_wrappers_for_both_shells() {
+    let files = render_wrappers("node", "0.9.0");
+    let names: Vec<&str> = files.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, ["node", "node.cmd"]);
+    assert!(files[0].executable);
+    assert!(!files[1].executable);
+}
+
+#[test]
+fn test_sh_wrapper_pins_vx_and_dispatches() {
+    let script = render_sh("node", "0.9.0");
+    assert!(script.starts_with("#!/bin/sh\n"));
+    assert!(script.contains(WRAPPER_MARKER));
+    assert!(script.contains("VX_WRAPPER_VERSION=\"0.9.0\""));
+    assert!(script.contains("${VX_HOME:-$HOME/.vx}/wrapper/$VX_WRAPPER_VERSION"));
+    assert!(script.contains("VX_NO_MODIFY_PATH=1"));
+    assert!(script.trim_end().ends_with("exec \"$VX\" node \"$@\""));
+}
+
+#[test]
+fn test_cmd_wrapper_uses_crlf_and_install_ps1() {
+    let script = render_cmd("python", "0.9.0");
+    assert!(script.starts_with("@echo off\r\n"));
+    assert!(!script.replace("\r\n", "").contains('\n'));
+    assert!(script.contains("set \"VX_WRAPPER_VERSION=0.9.0\""));
+    assert!(script.contains("install.ps1 | iex"));
+    assert!(script.contains("\"%VX%\" python %*\r\n"));
+}
+
+#[test]
+fn test_write_wrappers_refuses_foreign_files() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("node"), "#!/bin/sh\necho mine\n").unwrap();
+
+    let tools = vec!["node".to_string()];
+    let err = write_wrappers(dir.path(), &tools, "0.9.0", false).unwrap_err();
+    assert!(err.to_string().contains("--force"));
+    assert!(!dir.path().join("node.cmd").exists());
+
+    let written = write_wrappers(dir.path(), &tools, "0.9.0", true).unwrap();
+    assert_eq!(written.len(), 2);
+    // Regenerating over generated files needs no --force
+    write_wrappers(dir.path(), &tools, "0.9.1", false).unwrap();
+    let script = std::fs::read_to_string(dir.path().join("node")).unwrap();
+    assert!(script.contains("VX_WRAPPER_VERSION=\"0.9.1\""));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_sh_wrapper_runs_vx_from_path() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let bin = dir.path().join("bin");
+    std::fs::create_dir(&bin).unwrap();
+    let fake_vx = bin.join("vx");
+    std::fs::write(&fake_vx, "#!/bin/sh\necho \"vx $*\"\n").unwrap();
+    std::fs::set_permissions(&fake_vx, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let tools_dir = dir.path().join("tools");
+    write_wrappers(&tools_dir, &["node".to_string()], "0.9.0", false).unwrap();
+    let mode = std::fs::metadata(tools_dir.join("node"))
+        .unwrap()
+        .permissions()
+        .mode();
+    assert_eq!(mode & 0o111, 0o111);
+
+    let output = std::process::Command::new(tools_dir.join("node"))
+        .args(["--version", "a b"])
+        .env("PATH", format!("{}:/usr/bin:/bin", bin.display()))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        "vx node --version a b"
+    );
+}
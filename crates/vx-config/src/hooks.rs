@@ -7,12 +7,96 @@
 //! - `pre_setup` / `post_setup` - Run before/after `vx setup`
 //! - `pre_commit` - Run before git commit (integrates with git hooks)
 //! - `enter` - Run when entering a directory (shell integration)
+//!
+//! # Tool Placeholders
+//!
+//! Hook commands may refer to a project tool as `{tool:<name>}` (e.g.
+//! `{tool:node} scripts/check.js`). The placeholder expands to the
+//! executable of the version pinned in `vx.toml`, so hooks do not depend on
+//! PATH being set up; see [`expand_tool_placeholders`].
 
 use crate::types::HookCommand;
 use anyhow::{Context, Result};
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 
+/// Prefix of a tool placeholder, e.g. `{tool:node}`
+const TOOL_PLACEHOLDER_PREFIX: &str = "{tool:";
+
+/// Part of a command split at its tool placeholders
+enum Segment<'a> {
+    Text(&'a str),
+    Tool(&'a str),
+}
+
+/// Split `cmd` into literal text and the names of well-formed
+/// `{tool:<name>}` placeholders; malformed ones are kept as text
+fn segments(cmd: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+    let mut rest = cmd;
+    while let Some(start) = rest.find(TOOL_PLACEHOLDER_PREFIX) {
+        let after = &rest[start + TOOL_PLACEHOLDER_PREFIX.len()..];
+        let name = after.find('}').map(|end| &after[..end]).filter(|name| {
+            !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        });
+        match name {
+            Some(name) => {
+                segments.push(Segment::Text(&rest[..start]));
+                segments.push(Segment::Tool(name));
+                rest = &after[name.len() + 1..];
+            }
+            None => {
+                let keep = start + TOOL_PLACEHOLDER_PREFIX.len();
+                segments.push(Segment::Text(&rest[..keep]));
+                rest = &rest[keep..];
+            }
+        }
+    }
+    segments.push(Segment::Text(rest));
+    segments
+}
+
+/// Names of the tools referenced by `{tool:<name>}` placeholders in `cmd`,
+/// in order of first appearance
+pub fn tool_placeholders(cmd: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for segment in segments(cmd) {
+        if let Segment::Tool(name) = segment
+            && !names.iter().any(|n| n == name)
+        {
+            names.push(name.to_string());
+        }
+    }
+    names
+}
+
+/// Replace every `{tool:<name>}` in `cmd` with the executable from `tools`
+///
+/// Fails when a referenced tool has no resolved executable, i.e. it is not
+/// pinned in `[tools]` or not installed yet.
+pub fn expand_tool_placeholders(cmd: &str, tools: &HashMap<String, PathBuf>) -> Result<String> {
+    let mut expanded = String::with_capacity(cmd.len());
+    for segment in segments(cmd) {
+        match segment {
+            Segment::Text(text) => expanded.push_str(text),
+            Segment::Tool(name) => {
+                let path = tools.get(name).ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "{{tool:{}}} is not pinned in [tools] or not installed (run 'vx sync')",
+                        name
+                    )
+                })?;
+                expanded.push_str(&path.display().to_string());
+            }
+        }
+    }
+    Ok(expanded)
+}
+
 /// Hook execution result
 #[derive(Debug, Clone)]
 pub struct HookResult {
@@ -38,6 +122,8 @@ pub struct HookExecutor {
     shell: String,
     /// Environment variables to set
     env_vars: std::collections::HashMap<String, String>,
+    /// Executables substituted for `{tool:<name>}` placeholders
+    tools: HashMap<String, PathBuf>,
 }
 
 impl HookExecutor {
@@ -54,6 +140,7 @@ impl HookExecutor {
             verbose: false,
             shell,
             env_vars: std::collections::HashMap::new(),
+            tools: HashMap::new(),
         }
     }
 
@@ -121,8 +208,16 @@ impl HookExecutor {
         })
     }
 
+    /// Set the executables substituted for `{tool:<name>}` placeholders
+    pub fn tools(mut self, tools: HashMap<String, PathBuf>) -> Self {
+        self.tools = tools;
+        self
+    }
+
     /// Run a single command
     fn run_command(&self, name: &str, cmd: &str) -> Result<HookResult> {
+        let cmd = &expand_tool_placeholders(cmd, &self.tools)
+            .with_context(|| format!("Failed to expand tool placeholders of hook '{}'", name))?;
         let (shell_cmd, shell_arg) = if cfg!(windows) {
             if self.shell.contains("powershell") || self.shell.contains("pwsh") {
                 (&self.shell as &str, "-Command")
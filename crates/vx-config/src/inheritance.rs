@@ -8,22 +8,87 @@
 //! Remote presets SHOULD include a `sha256` hash for verification.
 //! When a preset is loaded without hash verification, a security warning
 //! is emitted to alert users of potential supply chain risks.
+//!
+//! ## Extends
+//!
+//! A `vx.toml` can extend a preset with `extends = "…"`: a built-in preset
+//! (`vx:node`), a local file relative to the config, or a remote URL. Presets
+//! can extend other presets. Layers are merged base first with
+//! [`MergeStrategy::Merge`]:
+//!
+//! - `tools`, `scripts` and `services` are merged by name, the child wins
+//! - `[env.vars]` are merged by variable name, the child wins
+//! - every other section is replaced as a whole when the child sets it
+//!
+//! Remote presets are read from the preset cache; callers with network
+//! access fetch the [`ExtendsChain::missing`] ones and store them with
+//! [`InheritanceManager::store_preset`].
+//!
+//! ## Machine policy
+//!
+//! A machine-wide [`PolicyConfig`] sits below user and project configuration.
+//! Its `[settings]` only provide defaults, while version pins, mirrors and
+//! provider restrictions are enforced and win over every other layer.
 
-use crate::{ConfigError, ConfigResult, VxConfig};
+use crate::{
+    ConfigError, ConfigResult, PolicyConfig, SettingsConfig, ToolVersion, VersionFilePin, VxConfig,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::path::{Path, PathBuf};
 use tracing::warn;
 
+/// Maximum `extends` depth, guards against runaway preset chains
+const MAX_EXTENDS_DEPTH: usize = 8;
+
 /// Configuration inheritance manager
 pub struct InheritanceManager {
     /// Cache directory for remote presets
-    #[allow(dead_code)]
     cache_dir: std::path::PathBuf,
 }
 
+/// Where an `extends` reference points
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PresetLocation {
+    /// Built-in preset shipped with vx
+    Builtin(String),
+    /// Preset file on disk
+    Local(PathBuf),
+    /// Remote preset fetched into the cache
+    Remote(PresetSource),
+}
+
+/// Configurations along an `extends` chain
+#[derive(Debug, Clone, Default)]
+pub struct ExtendsChain {
+    /// Layers, base preset first and the extending configuration last
+    pub layers: Vec<(ConfigOrigin, VxConfig)>,
+    /// Remote presets that are not in the cache yet
+    pub missing: Vec<PresetSource>,
+}
+
+impl ExtendsChain {
+    /// Merge every layer into the effective configuration
+    pub fn merged(&self) -> VxConfig {
+        let mut layers = self.layers.iter().map(|(_, config)| config);
+        let base = layers.next().cloned().unwrap_or_default();
+        layers.fold(base, |parent, child| {
+            InheritanceManager::merge_configs(&parent, child, MergeStrategy::Merge)
+        })
+    }
+
+    /// Flattened layers for [`InheritanceManager::resolve_origins`]
+    pub fn config_layers(&self) -> Vec<ConfigLayer> {
+        self.layers
+            .iter()
+            .map(|(origin, config)| ConfigLayer::from_config(origin.clone(), config))
+            .collect()
+    }
+}
+
 /// Remote preset source
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PresetSource {
     /// Preset URL or identifier
     pub url: String,
@@ -192,6 +257,9 @@ impl InheritanceManager {
             result.services.insert(name.clone(), service.clone());
         }
 
+        // A parent's extends is already resolved
+        result.extends = child.extends.clone();
+
         // Override simple fields if set in child
         if child.min_version.is_some() {
             result.min_version = child.min_version.clone();
@@ -208,10 +276,19 @@ impl InheritanceManager {
                     if let (Some(parent_env), Some(child_env)) = (&result.env, &child.env) {
                         let mut merged = parent_env.vars.clone();
                         merged.extend(child_env.vars.clone());
+                        let mut allow = parent_env.allow.clone();
+                        allow.extend(child_env.allow.iter().cloned());
+                        let mut deny = parent_env.deny.clone();
+                        deny.extend(child_env.deny.iter().cloned());
                         result.env = Some(crate::EnvConfig {
                             vars: merged,
+                            inherit: child_env.inherit.or(parent_env.inherit),
+                            allow,
+                            deny,
                             ..child_env.clone()
                         });
+                    } else {
+                        result.env = child.env.clone();
                     }
                 }
                 _ => {
@@ -263,10 +340,208 @@ impl InheritanceManager {
         if child.versioning.is_some() {
             result.versioning = child.versioning.clone();
         }
+        if child.requirements.is_some() {
+            result.requirements = child.requirements.clone();
+        }
+        if child.ui.is_some() {
+            result.ui = child.ui.clone();
+        }
+
+        result
+    }
+
+    /// Apply a machine policy to a merged configuration.
+    ///
+    /// Policy settings fill in whatever the configuration leaves unset, and
+    /// pinned versions replace the versions requested for those tools.
+    pub fn apply_policy(config: &VxConfig, policy: &PolicyConfig) -> VxConfig {
+        let mut result = config.clone();
+
+        if let Some(defaults) = &policy.settings {
+            let settings = result.settings.get_or_insert_with(SettingsConfig::default);
+            let defaults = defaults.clone();
+            settings.auto_install = settings.auto_install.or(defaults.auto_install);
+            settings.parallel_install = settings.parallel_install.or(defaults.parallel_install);
+            settings.cache_duration = settings.cache_duration.take().or(defaults.cache_duration);
+            settings.shell = settings.shell.take().or(defaults.shell);
+            settings.log_level = settings.log_level.take().or(defaults.log_level);
+            settings.isolation = settings.isolation.or(defaults.isolation);
+            settings.passenv = settings.passenv.take().or(defaults.passenv);
+            settings.setenv = settings.setenv.take().or(defaults.setenv);
+            settings.experimental = settings.experimental.take().or(defaults.experimental);
+        }
+
+        for (name, version) in result.tools.iter_mut() {
+            if let Some(pin) = policy.pin(name) {
+                match version {
+                    ToolVersion::Simple(v) => *v = pin.to_string(),
+                    ToolVersion::Detailed(d) => d.version = pin.to_string(),
+                }
+            }
+        }
 
         result
     }
 
+    /// Resolve flattened configuration layers, lowest precedence first.
+    ///
+    /// Later layers override earlier ones, except for keys locked by an
+    /// earlier layer (machine policy), which keep their value.
+    pub fn resolve_origins(layers: &[ConfigLayer]) -> Vec<ResolvedSetting> {
+        let mut resolved: BTreeMap<String, ResolvedSetting> = BTreeMap::new();
+
+        for layer in layers {
+            for (key, value) in &layer.values {
+                let locked = layer.locked.contains(key);
+                match resolved.get_mut(key) {
+                    Some(current) if current.locked => {
+                        current
+                            .overridden
+                            .push((layer.origin.clone(), value.clone()));
+                    }
+                    Some(current) => {
+                        let previous = std::mem::replace(&mut current.value, value.clone());
+                        let previous_origin =
+                            std::mem::replace(&mut current.origin, layer.origin.clone());
+                        current.overridden.push((previous_origin, previous));
+                        current.locked = locked;
+                    }
+                    None => {
+                        resolved.insert(
+                            key.clone(),
+                            ResolvedSetting {
+                                key: key.clone(),
+                                value: value.clone(),
+                                origin: layer.origin.clone(),
+                                locked,
+                                overridden: Vec::new(),
+                            },
+                        );
+                    }
+                }
+            }
+        }
+
+        resolved.into_values().collect()
+    }
+
+    /// Resolve a built-in preset reference (`vx:node`, `node@1`, ...)
+    ///
+    /// Returns `None` for anything that is not a built-in preset.
+    pub fn builtin_preset(extends: &str) -> Option<VxConfig> {
+        let name = extends.split(['@', '#']).next().unwrap_or(extends);
+        let name = name.strip_prefix("vx:").unwrap_or(name);
+        presets::get_builtin(name).and_then(|content| crate::parse_config_str(content).ok())
+    }
+
+    /// The preset a configuration extends (`extends`, or legacy `team.extends`)
+    pub fn extends_of(config: &VxConfig) -> Option<&str> {
+        config
+            .extends
+            .as_deref()
+            .or_else(|| config.team.as_ref().and_then(|t| t.extends.as_deref()))
+            .filter(|e| !e.trim().is_empty())
+    }
+
+    /// Locate an `extends` reference made from a file in `base_dir`
+    pub fn locate_preset(extends: &str, base_dir: &Path) -> PresetLocation {
+        let extends = extends.trim();
+        if extends.starts_with("http://")
+            || extends.starts_with("https://")
+            || extends.starts_with("github:")
+        {
+            return PresetLocation::Remote(Self::parse_extends(extends));
+        }
+        if let Some(name) = extends.strip_prefix("vx:") {
+            let name = name.split(['@', '#']).next().unwrap_or(name);
+            return PresetLocation::Builtin(name.to_string());
+        }
+        if presets::get_builtin(extends).is_some() {
+            return PresetLocation::Builtin(extends.to_string());
+        }
+        PresetLocation::Local(base_dir.join(extends))
+    }
+
+    /// Cache file for a remote preset
+    pub fn preset_cache_path(&self, source: &PresetSource) -> PathBuf {
+        let key = Self::calculate_hash(&source.url);
+        self.cache_dir.join(format!("{}.toml", &key[..16]))
+    }
+
+    /// Verify and cache a fetched remote preset
+    pub fn store_preset(&self, source: &PresetSource, content: &str) -> ConfigResult<()> {
+        source.verify_content(content)?;
+        crate::parse_config_str(content).map_err(|e| {
+            ConfigError::ParseError(format!("Invalid preset {}: {}", source.url, e))
+        })?;
+        std::fs::create_dir_all(&self.cache_dir)?;
+        std::fs::write(self.preset_cache_path(source), content)?;
+        Ok(())
+    }
+
+    /// Follow the `extends` chain of a configuration loaded from `config_path`
+    pub fn resolve_extends(
+        &self,
+        config_path: &Path,
+        config: VxConfig,
+    ) -> ConfigResult<ExtendsChain> {
+        let mut chain = ExtendsChain::default();
+        let mut visited = Vec::new();
+        let mut base_dir = config_path.parent().unwrap_or(Path::new(".")).to_path_buf();
+        let mut next = Self::extends_of(&config).map(str::to_string);
+        chain
+            .layers
+            .push((ConfigOrigin::Project(config_path.to_path_buf()), config));
+
+        while let Some(extends) = next.take() {
+            if visited.len() >= MAX_EXTENDS_DEPTH {
+                return Err(ConfigError::Validation {
+                    message: format!("extends chain is deeper than {} presets", MAX_EXTENDS_DEPTH),
+                });
+            }
+            let location = Self::locate_preset(&extends, &base_dir);
+            if visited.contains(&location) {
+                return Err(ConfigError::Validation {
+                    message: format!("Circular extends: {}", extends),
+                });
+            }
+            visited.push(location.clone());
+
+            let preset = match &location {
+                PresetLocation::Builtin(name) => {
+                    let content =
+                        presets::get_builtin(name).ok_or_else(|| ConfigError::NotFound {
+                            path: format!("vx:{}", name),
+                        })?;
+                    crate::parse_config_str(content)?
+                }
+                PresetLocation::Local(path) => {
+                    if let Some(parent) = path.parent() {
+                        base_dir = parent.to_path_buf();
+                    }
+                    crate::parse_config(path)?
+                }
+                PresetLocation::Remote(source) => {
+                    let cached = self.preset_cache_path(source);
+                    if !cached.exists() {
+                        chain.missing.push(source.clone());
+                        break;
+                    }
+                    let content = std::fs::read_to_string(&cached)?;
+                    source.verify_content(&content)?;
+                    crate::parse_config_str(&content)?
+                }
+            };
+
+            next = Self::extends_of(&preset).map(str::to_string);
+            chain
+                .layers
+                .insert(0, (ConfigOrigin::Preset(extends), preset));
+        }
+
+        Ok(chain)
+    }
+
     /// Load lock file
     pub fn load_lock_file(path: impl AsRef<Path>) -> ConfigResult<LockFile> {
         let content = std::fs::read_to_string(path.as_ref())
@@ -307,6 +582,144 @@ impl InheritanceManager {
     }
 }
 
+/// Where a configuration value comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigOrigin {
+    /// Machine-wide policy file
+    Policy(PathBuf),
+    /// User configuration (`~/.vx/config`)
+    User(PathBuf),
+    /// Preset referenced by `team.extends`
+    Preset(String),
+    /// Project `vx.toml`
+    Project(PathBuf),
+    /// Version file of another version manager (`.nvmrc`, `.tool-versions`, ...)
+    VersionFile(PathBuf),
+    /// Environment variable
+    Env(String),
+}
+
+impl fmt::Display for ConfigOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Policy(path) => write!(f, "policy ({})", path.display()),
+            Self::User(path) => write!(f, "user ({})", path.display()),
+            Self::Preset(name) => write!(f, "preset ({})", name),
+            Self::Project(path) => write!(f, "project ({})", path.display()),
+            Self::VersionFile(path) => write!(f, "version file ({})", path.display()),
+            Self::Env(name) => write!(f, "env ({})", name),
+        }
+    }
+}
+
+/// One flattened configuration layer
+#[derive(Debug, Clone)]
+pub struct ConfigLayer {
+    /// Where the values come from
+    pub origin: ConfigOrigin,
+    /// Flattened `key → value` pairs
+    pub values: BTreeMap<String, String>,
+    /// Keys that later layers cannot override
+    pub locked: BTreeSet<String>,
+}
+
+impl ConfigLayer {
+    /// Create an empty layer
+    pub fn new(origin: ConfigOrigin) -> Self {
+        Self {
+            origin,
+            values: BTreeMap::new(),
+            locked: BTreeSet::new(),
+        }
+    }
+
+    /// Layer for a machine policy: settings are defaults, everything else is locked
+    pub fn from_policy(path: PathBuf, policy: &PolicyConfig) -> Self {
+        let mut layer = Self::new(ConfigOrigin::Policy(path));
+        if let Some(settings) = &policy.settings {
+            let config = VxConfig {
+                settings: Some(settings.clone()),
+                ..Default::default()
+            };
+            for (key, value) in config.settings_as_hashmap() {
+                layer.values.insert(format!("settings.{}", key), value);
+            }
+        }
+        for (key, value) in policy.enforced_values() {
+            layer.locked.insert(key.clone());
+            layer.values.insert(key, value);
+        }
+        layer
+    }
+
+    /// Layer holding a single value set by an environment variable
+    pub fn from_env(var: &str, key: &str, value: impl Into<String>) -> Self {
+        let mut layer = Self::new(ConfigOrigin::Env(var.to_string()));
+        layer.values.insert(key.to_string(), value.into());
+        layer
+    }
+
+    /// Layer for a project, preset or user `vx.toml`: tools, settings, scripts, env and ui
+    pub fn from_config(origin: ConfigOrigin, config: &VxConfig) -> Self {
+        let mut layer = Self::new(origin);
+        for (name, version) in config.tools_as_hashmap() {
+            layer.values.insert(format!("tools.{}", name), version);
+        }
+        for (key, value) in config.settings_as_hashmap() {
+            layer.values.insert(format!("settings.{}", key), value);
+        }
+        for (name, script) in &config.scripts {
+            let command = match script {
+                crate::ScriptConfig::Simple(command) => command.clone(),
+                crate::ScriptConfig::Detailed(details) => details.command.clone(),
+            };
+            layer.values.insert(format!("scripts.{}", name), command);
+        }
+        for (name, value) in config.env_as_hashmap() {
+            layer.values.insert(format!("env.{}", name), value);
+        }
+        if let Some(ui) = &config.ui {
+            layer.values.extend(ui.entries());
+        }
+        layer
+    }
+
+    /// Layers for version file pins, lowest precedence first
+    ///
+    /// Each file becomes a layer of `tools.<name>` values; `pins` are in the
+    /// order of [`find_version_file_pins`](crate::find_version_file_pins).
+    pub fn from_version_files(pins: &[VersionFilePin]) -> Vec<Self> {
+        let mut layers: Vec<Self> = Vec::new();
+        for pin in pins.iter().rev() {
+            let origin = ConfigOrigin::VersionFile(pin.path.clone());
+            if layers.last().is_none_or(|layer| layer.origin != origin) {
+                layers.push(Self::new(origin));
+            }
+            if let Some(layer) = layers.last_mut() {
+                layer
+                    .values
+                    .insert(format!("tools.{}", pin.tool), pin.version.clone());
+            }
+        }
+        layers
+    }
+}
+
+/// A configuration value after precedence resolution
+#[derive(Debug, Clone)]
+pub struct ResolvedSetting {
+    /// Flattened key (`tools.node`, `settings.auto_install`, ...)
+    pub key: String,
+    /// Effective value
+    pub value: String,
+    /// Layer the effective value comes from
+    pub origin: ConfigOrigin,
+    /// Whether the value is enforced by policy
+    pub locked: bool,
+    /// Values from other layers that lost, in precedence order
+    pub overridden: Vec<(ConfigOrigin, String)>,
+}
+
 /// Built-in presets
 #[allow(dead_code)]
 pub mod presets {
@@ -32,6 +32,8 @@ mod hooks;
 mod inheritance;
 mod migration;
 mod parser;
+mod policy;
+mod preset_registry;
 mod remote;
 mod security;
 mod setup_pipeline;
@@ -40,6 +42,7 @@ mod telemetry;
 mod testing;
 mod types;
 mod validation;
+mod version_files;
 
 pub use container::{
     ContainerManager, DockerfileGenerator, GitInfo, GoDockerConfig, NodejsDockerConfig,
@@ -47,11 +50,25 @@ pub use container::{
 };
 pub use dependencies::{AuditResult, AutoUpdateStrategy, DependencyManager, RegistryPresets};
 pub use error::{ConfigError, ConfigResult};
-pub use hooks::{EnterHookManager, GitHookInstaller, HookExecutor, HookResult};
-pub use inheritance::{InheritanceManager, LockEntry, LockFile, MergeStrategy, PresetSource};
+pub use hooks::{
+    EnterHookManager, GitHookInstaller, HookExecutor, HookResult, expand_tool_placeholders,
+    tool_placeholders,
+};
+pub use inheritance::{
+    ConfigLayer, ConfigOrigin, ExtendsChain, InheritanceManager, LockEntry, LockFile,
+    MergeStrategy, PresetLocation, PresetSource, ResolvedSetting,
+};
 pub use migration::{ConfigMigrator, ConfigVersion, MigrationOptions, MigrationResult};
 pub use parser::{parse_config, parse_config_str};
-pub use remote::{RemoteGenerator, generate_devcontainer_json, generate_gitpod_yml};
+pub use policy::{PolicyConfig, PolicyMirror, ProviderPolicy, VersionPolicy};
+pub use preset_registry::{
+    PRESET_DIR, PRESET_INDEX_FILE, PRESET_INDEX_VERSION, PRESET_SECTIONS, PresetEntry, PresetIndex,
+    extract_preset, index_location, is_url, preset_entry, validate_preset_name,
+};
+pub use remote::{
+    DEFAULT_DEVCONTAINER_IMAGE, RemoteGenerator, VX_FEATURE_ID, generate_devcontainer_json,
+    generate_gitpod_yml,
+};
 pub use security::{
     LicenseViolation, ScanStatus, SecretFinding, SecurityScanResult, SecurityScanner, Severity,
     Vulnerability, generate_report as generate_security_report, patterns,
@@ -64,6 +81,9 @@ pub use telemetry::{
 pub use testing::{CoverageReporter, TestFramework, TestResult, TestRunner};
 pub use types::*;
 pub use validation::{ValidationResult, validate_config};
+pub use version_files::{
+    VERSION_FILES, VersionFilePin, find_version_file_pins, parse_version_file, version_file_pins,
+};
 
 /// Re-export for convenience (only available with "schema" feature)
 #[cfg(feature = "schema")]
@@ -256,6 +256,7 @@ impl ConfigMigrator {
                         install_env: None,
                         components: None,
                         exclude_patterns: None,
+                        exec: None,
                     })
                 } else {
                     warnings.push(format!("tools.{}: invalid value, skipped", name));
@@ -320,9 +321,7 @@ impl ConfigMigrator {
             if !vars.is_empty() {
                 v2_config.env = Some(EnvConfig {
                     vars,
-                    required: None,
-                    optional: None,
-                    secrets: None,
+                    ..Default::default()
                 });
                 changes.push("env: migrated environment variables".to_string());
             }
@@ -356,6 +355,7 @@ impl ConfigMigrator {
                         args: vec![],
                         env: HashMap::new(),
                         depends: vec![],
+                        ..Default::default()
                     })
                 } else {
                     warnings.push(format!("scripts.{}: invalid value, skipped", name));
@@ -0,0 +1,165 @@
+//! Machine-wide policy
+//!
+//! Administrators can restrict what vx does on a machine with a policy file
+//! (`/etc/vx/policy.toml`, or `%ProgramData%\vx\policy.toml` on Windows):
+//!
+//! ```toml
+//! # Machine defaults, overridable by user and project configuration
+//! [settings]
+//! auto_install = false
+//!
+//! # Enforced: no prerelease versions, pinned tool versions
+//! [versions]
+//! allow_prerelease = false
+//!
+//! [versions.pin]
+//! node = "20.11.0"
+//! python = "3.11"
+//!
+//! # Enforced: downloads for these tools only use the listed mirrors
+//! [[mirrors.node]]
+//! name = "corp"
+//! url = "https://artifactory.corp.example/node/{filename}"
+//!
+//! # Enforced: tool allowlist, no user or project provider.star files
+//! [providers]
+//! allow = ["node", "python", "uv"]
+//! user_providers = false
+//! ```
+//!
+//! `[settings]` is merged below user and project configuration. Everything
+//! else is enforced and cannot be overridden; see
+//! [`InheritanceManager::apply_policy`](crate::InheritanceManager::apply_policy).
+
+use crate::{ConfigError, ConfigResult, SettingsConfig};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Parsed machine policy
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PolicyConfig {
+    /// Machine defaults for `[settings]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<SettingsConfig>,
+    /// Version restrictions
+    pub versions: VersionPolicy,
+    /// Pinned download mirrors, keyed by tool name
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub mirrors: BTreeMap<String, Vec<PolicyMirror>>,
+    /// Provider restrictions
+    pub providers: ProviderPolicy,
+    /// File the policy was loaded from
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+}
+
+/// `[versions]` section of the policy
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VersionPolicy {
+    /// Whether prerelease versions may be installed (default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_prerelease: Option<bool>,
+    /// Tool name → version constraint every install must satisfy
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub pin: BTreeMap<String, String>,
+}
+
+/// A mirror pinned by the policy
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PolicyMirror {
+    /// Mirror name
+    pub name: String,
+    /// Mirror URL or URL template
+    pub url: String,
+}
+
+/// `[providers]` section of the policy
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProviderPolicy {
+    /// Tools that may be installed (empty = any)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+    /// Whether user and project `provider.star` files are loaded (default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_providers: Option<bool>,
+}
+
+impl PolicyConfig {
+    /// Load the policy file, if it exists
+    pub fn load(path: &Path) -> ConfigResult<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(path)?;
+        let mut policy = Self::parse(&content).map_err(|e| {
+            ConfigError::ParseError(format!("Invalid policy {}: {}", path.display(), e))
+        })?;
+        policy.path = Some(path.to_path_buf());
+        Ok(Some(policy))
+    }
+
+    /// Parse policy TOML
+    pub fn parse(content: &str) -> ConfigResult<Self> {
+        Ok(toml::from_str(content)?)
+    }
+
+    /// Whether the policy allows installing a tool
+    pub fn allows_provider(&self, name: &str) -> bool {
+        self.providers.allow.is_empty() || self.providers.allow.iter().any(|p| p == name)
+    }
+
+    /// Whether user and project provider overrides may be loaded
+    pub fn allows_user_providers(&self) -> bool {
+        self.providers.user_providers.unwrap_or(true)
+    }
+
+    /// Whether prerelease versions may be installed
+    pub fn allows_prerelease(&self) -> bool {
+        self.versions.allow_prerelease.unwrap_or(true)
+    }
+
+    /// Version constraint pinned for a tool
+    pub fn pin(&self, tool: &str) -> Option<&str> {
+        self.versions.pin.get(tool).map(String::as_str)
+    }
+
+    /// Mirrors pinned for a tool
+    pub fn mirrors_for(&self, tool: &str) -> &[PolicyMirror] {
+        self.mirrors
+            .get(tool)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Flatten enforced entries into `key → value` pairs (`tools.node`, `mirrors.node`, ...)
+    pub fn enforced_values(&self) -> BTreeMap<String, String> {
+        let mut values = BTreeMap::new();
+        if let Some(allow) = self.versions.allow_prerelease {
+            values.insert("versions.allow_prerelease".to_string(), allow.to_string());
+        }
+        for (tool, pin) in &self.versions.pin {
+            values.insert(format!("tools.{}", tool), pin.clone());
+        }
+        for (tool, mirrors) in &self.mirrors {
+            let urls: Vec<&str> = mirrors.iter().map(|m| m.url.as_str()).collect();
+            values.insert(format!("mirrors.{}", tool), urls.join(", "));
+        }
+        if !self.providers.allow.is_empty() {
+            values.insert(
+                "providers.allow".to_string(),
+                self.providers.allow.join(", "),
+            );
+        }
+        if let Some(user_providers) = self.providers.user_providers {
+            values.insert(
+                "providers.user_providers".to_string(),
+                user_providers.to_string(),
+            );
+        }
+        values
+    }
+}
@@ -0,0 +1,236 @@
+//! Shareable preset registry
+//!
+//! A preset index is a static `index.json`, usually kept in a git repository,
+//! that lists presets such as `python-ml` or `node-fullstack`:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "presets": [
+//!     {
+//!       "name": "python-ml",
+//!       "description": "Python with uv, jupyter and a training service",
+//!       "path": "presets/python-ml.toml",
+//!       "sha256": "…",
+//!       "tags": ["python", "ml"],
+//!       "tools": ["python", "uv"]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Each preset is a `vx.toml` fragment holding the shareable sections
+//! ([`PRESET_SECTIONS`]). Projects use a preset through `extends`, pinned to
+//! the `sha256` recorded in the index.
+
+use crate::{ConfigError, ConfigResult, InheritanceManager};
+use serde::{Deserialize, Serialize};
+
+/// File name of a preset index
+pub const PRESET_INDEX_FILE: &str = "index.json";
+
+/// Directory of preset files next to the index
+pub const PRESET_DIR: &str = "presets";
+
+/// `vx.toml` sections a published preset carries
+pub const PRESET_SECTIONS: &[&str] = &["tools", "scripts", "hooks", "services"];
+
+/// Current preset index format version
+pub const PRESET_INDEX_VERSION: u32 = 1;
+
+/// Static index of shareable presets
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PresetIndex {
+    /// Index format version
+    #[serde(default = "default_index_version")]
+    pub version: u32,
+    /// Presets, sorted by name
+    #[serde(default)]
+    pub presets: Vec<PresetEntry>,
+}
+
+fn default_index_version() -> u32 {
+    PRESET_INDEX_VERSION
+}
+
+impl Default for PresetIndex {
+    fn default() -> Self {
+        Self {
+            version: PRESET_INDEX_VERSION,
+            presets: Vec::new(),
+        }
+    }
+}
+
+/// A preset listed in a [`PresetIndex`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PresetEntry {
+    /// Preset name (e.g. `python-ml`)
+    pub name: String,
+    /// One-line description
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    /// Preset file, relative to the index or an absolute URL
+    pub path: String,
+    /// SHA256 of the preset file
+    pub sha256: String,
+    /// Search tags
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Tools the preset pins
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tools: Vec<String>,
+}
+
+impl PresetEntry {
+    /// Whether the entry matches a case-insensitive search query
+    ///
+    /// The query is matched against the name, description, tags and tools.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return true;
+        }
+        self.name.to_lowercase().contains(&query)
+            || self.description.to_lowercase().contains(&query)
+            || self
+                .tags
+                .iter()
+                .chain(&self.tools)
+                .any(|t| t.to_lowercase() == query)
+    }
+
+    /// Location of the preset file for an index loaded from `index_location`
+    pub fn location(&self, index_location: &str) -> String {
+        if is_url(&self.path) {
+            return self.path.clone();
+        }
+        match index_location.rsplit_once('/') {
+            Some((base, _)) => format!("{}/{}", base, self.path),
+            None => self.path.clone(),
+        }
+    }
+}
+
+impl PresetIndex {
+    /// Parse an `index.json`
+    pub fn parse(content: &str) -> ConfigResult<Self> {
+        serde_json::from_str(content)
+            .map_err(|e| ConfigError::ParseError(format!("Invalid preset index: {}", e)))
+    }
+
+    /// Serialize to pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        let mut json = serde_json::to_string_pretty(self).unwrap_or_default();
+        json.push('\n');
+        json
+    }
+
+    /// Find a preset by name
+    pub fn get(&self, name: &str) -> Option<&PresetEntry> {
+        self.presets.iter().find(|p| p.name == name)
+    }
+
+    /// Presets matching a search query (all of them for an empty query)
+    pub fn search(&self, query: &str) -> Vec<&PresetEntry> {
+        self.presets.iter().filter(|p| p.matches(query)).collect()
+    }
+
+    /// Add or replace a preset, keeping the list sorted by name
+    pub fn upsert(&mut self, entry: PresetEntry) {
+        self.presets.retain(|p| p.name != entry.name);
+        self.presets.push(entry);
+        self.presets.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+}
+
+/// Location of the `index.json` for a `--index` value
+///
+/// - `github:owner/repo` reads `index.json` from the `main` branch
+/// - URLs and paths ending in `.json` are used as-is
+/// - any other URL or path is a directory holding `index.json`
+pub fn index_location(index: &str) -> String {
+    let index = index.trim().trim_end_matches('/');
+    if let Some(repo) = index.strip_prefix("github:") {
+        return format!(
+            "https://raw.githubusercontent.com/{}/main/{}",
+            repo, PRESET_INDEX_FILE
+        );
+    }
+    if index.ends_with(".json") {
+        return index.to_string();
+    }
+    format!("{}/{}", index, PRESET_INDEX_FILE)
+}
+
+/// Whether a location is fetched over HTTP
+pub fn is_url(location: &str) -> bool {
+    location.starts_with("http://") || location.starts_with("https://")
+}
+
+/// Validate a preset name (lowercase letters, digits and dashes)
+pub fn validate_preset_name(name: &str) -> ConfigResult<()> {
+    let valid = !name.is_empty()
+        && !name.starts_with('-')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if valid {
+        Ok(())
+    } else {
+        Err(ConfigError::Validation {
+            message: format!(
+                "Invalid preset name '{}': use lowercase letters, digits and dashes",
+                name
+            ),
+        })
+    }
+}
+
+/// Preset file content for a project `vx.toml`
+///
+/// Keeps the [`PRESET_SECTIONS`] and drops everything project-specific.
+pub fn extract_preset(name: &str, vx_toml: &str) -> ConfigResult<String> {
+    let table: toml::Table = toml::from_str(vx_toml)
+        .map_err(|e| ConfigError::ParseError(format!("Invalid vx.toml: {}", e)))?;
+    let preset: toml::Table = PRESET_SECTIONS
+        .iter()
+        .filter_map(|section| {
+            table
+                .get(*section)
+                .map(|value| (section.to_string(), value.clone()))
+        })
+        .collect();
+    if preset.is_empty() {
+        return Err(ConfigError::Validation {
+            message: format!(
+                "Nothing to publish: vx.toml has none of [{}]",
+                PRESET_SECTIONS.join("], [")
+            ),
+        });
+    }
+    let body = toml::to_string_pretty(&preset).map_err(|e| {
+        ConfigError::ParseError(format!("Failed to serialize preset {}: {}", name, e))
+    })?;
+    Ok(format!("# vx preset: {}\n\n{}", name, body))
+}
+
+/// Index entry for published preset content
+pub fn preset_entry(
+    name: &str,
+    description: impl Into<String>,
+    tags: Vec<String>,
+    content: &str,
+) -> ConfigResult<PresetEntry> {
+    let config = crate::parse_config_str(content)?;
+    let mut tools: Vec<String> = config.tools.into_keys().collect();
+    tools.sort();
+    Ok(PresetEntry {
+        name: name.to_string(),
+        description: description.into(),
+        path: format!("{}/{}.toml", PRESET_DIR, name),
+        sha256: InheritanceManager::calculate_hash(content),
+        tags,
+        tools,
+    })
+}
@@ -4,6 +4,7 @@
 //! - GitHub Codespaces (devcontainer.json)
 //! - GitPod (.gitpod.yml)
 //! - DevContainer (devcontainer.json)
+//! - A devcontainer feature that installs vx and the project's tools
 
 use crate::{CodespacesConfig, DevContainerConfig, GitpodConfig, RemoteConfig, VxConfig};
 use serde_json::{Value, json};
@@ -109,6 +110,12 @@ fn write_yaml_value(out: &mut String, value: &Value, indent: usize, inline: bool
     }
 }
 
+/// Image used by generated devcontainers that don't configure one
+pub const DEFAULT_DEVCONTAINER_IMAGE: &str = "mcr.microsoft.com/devcontainers/base:ubuntu";
+
+/// Id of the vx devcontainer feature
+pub const VX_FEATURE_ID: &str = "vx";
+
 /// Remote development configuration generator
 pub struct RemoteGenerator;
 
@@ -199,6 +206,71 @@ impl RemoteGenerator {
         result
     }
 
+    /// Generate devcontainer.json that installs vx through the feature at
+    /// `feature_ref` (e.g. `./vx`) and runs `vx setup` after creation
+    ///
+    /// The project's tools come from vx, so language features are not
+    /// detected; features configured in `[remote.devcontainer]` are kept.
+    pub fn generate_vx_devcontainer(
+        config: &VxConfig,
+        devcontainer: &DevContainerConfig,
+        feature_ref: &str,
+        feature_options: Value,
+    ) -> Value {
+        let mut devcontainer = devcontainer.clone();
+        if devcontainer.image.is_none() && devcontainer.dockerfile.is_none() {
+            devcontainer.image = Some(DEFAULT_DEVCONTAINER_IMAGE.to_string());
+        }
+        devcontainer
+            .features
+            .insert(feature_ref.to_string(), feature_options);
+        Self::generate_devcontainer(config, &devcontainer)
+    }
+
+    /// Generate the `devcontainer-feature.json` of the vx feature
+    ///
+    /// The `tools` option defaults to the project's Linux tools as
+    /// `tool@version`, so an image built with the feature already has them.
+    pub fn generate_vx_feature(config: &VxConfig, feature_version: &str) -> Value {
+        let project = config.project.as_ref().and_then(|p| p.name.as_deref());
+        let description = match project {
+            Some(name) => format!("Installs vx and the tools of {}", name),
+            None => "Installs vx and the project's tools".to_string(),
+        };
+
+        json!({
+            "id": VX_FEATURE_ID,
+            "version": feature_version,
+            "name": "vx",
+            "description": description,
+            "documentationURL": "https://github.com/loonghao/vx",
+            "options": {
+                "version": {
+                    "type": "string",
+                    "default": "latest",
+                    "description": "vx version to install"
+                },
+                "tools": {
+                    "type": "string",
+                    "default": Self::feature_tools(config),
+                    "description": "Tools to install into the image, as space-separated tool@version"
+                }
+            },
+            "installsAfter": ["ghcr.io/devcontainers/features/common-utils"]
+        })
+    }
+
+    /// The project's Linux tools as space-separated `tool@version`, sorted
+    fn feature_tools(config: &VxConfig) -> String {
+        let (tools, _) = config.tools_for_platform("linux");
+        let mut tools: Vec<String> = tools
+            .into_iter()
+            .map(|(name, version)| format!("{}@{}", name, version))
+            .collect();
+        tools.sort();
+        tools.join(" ")
+    }
+
     /// Detect features from vx config
     fn detect_features(config: &VxConfig) -> HashMap<String, Value> {
         let mut features = HashMap::new();
@@ -7,9 +7,9 @@ use std::collections::{BTreeMap, HashMap};
 
 use super::{
     AiConfig, ContainerConfig, DependenciesConfig, DocsConfig, EnvConfig, HooksConfig,
-    ProjectConfig, PythonConfig, RemoteConfig, ScriptConfig, SecurityConfig, ServiceConfig,
-    SettingsConfig, SetupConfig, TeamConfig, TelemetryConfig, TestConfig, ToolConfig, ToolVersion,
-    VersioningConfig,
+    ProjectConfig, PythonConfig, RemoteConfig, RequirementsConfig, ScriptConfig, SecurityConfig,
+    ServiceConfig, SettingsConfig, SetupConfig, TeamConfig, TelemetryConfig, TestConfig,
+    ToolConfig, ToolVersion, UiConfig, VersioningConfig,
 };
 
 /// Tools included/skipped for a platform, with skip reasons.
@@ -26,6 +26,11 @@ pub struct VxConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub min_version: Option<String>,
 
+    /// Preset this configuration extends: a built-in preset (`vx:node`),
+    /// a path relative to this file, or a URL (`https://…#<sha256>`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+
     /// Project metadata
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<ProjectConfig>,
@@ -111,6 +116,14 @@ pub struct VxConfig {
     /// Versioning strategy
     #[serde(skip_serializing_if = "Option::is_none")]
     pub versioning: Option<VersioningConfig>,
+
+    /// Machine requirements checked by `vx check`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requirements: Option<RequirementsConfig>,
+
+    /// Terminal output theme and accessibility options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ui: Option<UiConfig>,
 }
 
 // ============================================
@@ -295,25 +308,16 @@ impl VxConfig {
         }
     }
 
-    /// Get script command
+    /// Get script command for the current platform
     pub fn get_script_command(&self, name: &str) -> Option<String> {
-        self.scripts.get(name).map(|s| match s {
-            ScriptConfig::Simple(cmd) => cmd.clone(),
-            ScriptConfig::Detailed(d) => d.command.clone(),
-        })
+        self.scripts.get(name).map(|s| s.for_current_os().command)
     }
 
     /// Get all scripts as simple HashMap (for backward compatibility)
     pub fn scripts_as_hashmap(&self) -> HashMap<String, String> {
         self.scripts
             .iter()
-            .map(|(k, v)| {
-                let cmd = match v {
-                    ScriptConfig::Simple(s) => s.clone(),
-                    ScriptConfig::Detailed(d) => d.command.clone(),
-                };
-                (k.clone(), cmd)
-            })
+            .map(|(k, v)| (k.clone(), v.for_current_os().command))
             .collect()
     }
 
@@ -341,6 +345,15 @@ impl VxConfig {
             if let Some(isolation) = settings.isolation {
                 map.insert("isolation".to_string(), isolation.to_string());
             }
+            if let Some(fallback) = &settings.arch_fallback {
+                map.insert("arch_fallback".to_string(), fallback.clone());
+            }
+            if let Some(libc) = &settings.libc {
+                map.insert("libc".to_string(), libc.clone());
+            }
+            if let Some(retries) = settings.install_retries {
+                map.insert("install_retries".to_string(), retries.to_string());
+            }
         }
         map
     }
@@ -25,6 +25,39 @@ pub struct EnvConfig {
     /// Secret variables (loaded from secure storage)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub secrets: Option<SecretsConfig>,
+
+    /// Which host variables tools run through vx inherit (`all` by default)
+    ///
+    /// Example:
+    /// ```toml
+    /// [env]
+    /// inherit = "filtered"
+    /// allow = ["SSH_AUTH_SOCK", "GITHUB_*"]
+    /// deny = ["LC_ALL"]
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inherit: Option<EnvInherit>,
+
+    /// Host variables passed through in `filtered` mode (glob patterns)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+
+    /// Host variables never passed through (glob patterns)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deny: Vec<String>,
+}
+
+/// How host environment variables are inherited by tools
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum EnvInherit {
+    /// Every host variable except the `deny` patterns
+    #[default]
+    All,
+    /// Only essential system variables (HOME, LANG, TEMP, ...) and the
+    /// `allow` patterns, minus the `deny` patterns
+    Filtered,
 }
 
 /// Secrets configuration
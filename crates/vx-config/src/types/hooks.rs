@@ -26,6 +26,14 @@ pub struct HooksConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub enter: Option<HookCommand>,
 
+    /// Runs before every tool executed through vx; a failure aborts the run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_execute: Option<HookCommand>,
+
+    /// Runs after every tool executed through vx, with its exit code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_execute: Option<HookCommand>,
+
     /// Custom hooks
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub custom: HashMap<String, HookCommand>,
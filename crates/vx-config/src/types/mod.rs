@@ -25,6 +25,8 @@
 //! - `telemetry`: Telemetry configuration
 //! - `container`: Container deployment
 //! - `versioning`: Versioning strategy
+//! - `requirements`: Machine requirements
+//! - `ui`: Terminal output preferences
 
 mod ai;
 mod config;
@@ -36,6 +38,7 @@ mod hooks;
 mod project;
 mod python;
 mod remote;
+mod requirements;
 mod script;
 mod security;
 mod service;
@@ -45,6 +48,7 @@ mod team;
 mod telemetry;
 mod test;
 mod tool;
+mod ui;
 mod versioning;
 
 // Re-export all types
@@ -58,6 +62,7 @@ pub use hooks::*;
 pub use project::*;
 pub use python::*;
 pub use remote::*;
+pub use requirements::*;
 pub use script::*;
 pub use security::*;
 pub use service::*;
@@ -67,4 +72,5 @@ pub use team::*;
 pub use telemetry::*;
 pub use test::*;
 pub use tool::*;
+pub use ui::*;
 pub use versioning::*;
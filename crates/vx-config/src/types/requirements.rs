@@ -0,0 +1,114 @@
+//! Machine requirements configuration
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Machine requirements checked by `vx check`
+///
+/// ```toml
+/// [requirements]
+/// os = ["linux", "macos"]
+/// arch = ["x86_64", "aarch64"]
+/// min_disk_space = "10GB"
+/// system_packages = ["git", "docker"]
+/// network = ["https://registry.npmjs.org"]
+/// requires_gpu = true
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+pub struct RequirementsConfig {
+    /// Supported operating systems (`windows`, `macos`/`darwin`, `linux`)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub os: Vec<String>,
+
+    /// Supported CPU architectures (`x86_64`/`x64`, `aarch64`/`arm64`)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub arch: Vec<String>,
+
+    /// Minimum free disk space for the project directory (e.g. `"10GB"`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_disk_space: Option<String>,
+
+    /// Executables that must be available on PATH
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub system_packages: Vec<String>,
+
+    /// URLs that must be reachable (registries, internal mirrors, ...)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub network: Vec<String>,
+
+    /// Whether the project needs a CUDA-capable NVIDIA GPU
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub requires_gpu: bool,
+}
+
+impl RequirementsConfig {
+    /// Whether no requirement is declared
+    pub fn is_empty(&self) -> bool {
+        self.os.is_empty()
+            && self.arch.is_empty()
+            && self.min_disk_space.is_none()
+            && self.system_packages.is_empty()
+            && self.network.is_empty()
+            && !self.requires_gpu
+    }
+
+    /// Whether `os` (as returned by [`crate::VxConfig::current_os_name`]) is allowed
+    pub fn os_supported(&self, os: &str) -> bool {
+        self.os.is_empty() || self.os.iter().any(|o| normalize_os(o) == normalize_os(os))
+    }
+
+    /// Whether `arch` (e.g. `std::env::consts::ARCH`) is allowed
+    pub fn arch_supported(&self, arch: &str) -> bool {
+        self.arch.is_empty()
+            || self
+                .arch
+                .iter()
+                .any(|a| normalize_arch(a) == normalize_arch(arch))
+    }
+
+    /// Minimum free disk space in bytes
+    pub fn min_disk_space_bytes(&self) -> Result<Option<u64>, String> {
+        self.min_disk_space
+            .as_deref()
+            .map(|s| parse_size(s).ok_or_else(|| format!("Invalid disk size '{}'", s)))
+            .transpose()
+    }
+}
+
+/// Parse a human-readable size (`"10GB"`, `"512 MiB"`, `"2048"`) into bytes
+pub fn parse_size(size: &str) -> Option<u64> {
+    let size = size.trim();
+    let split = size
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(size.len());
+    let (number, unit) = size.split_at(split);
+    let number: f64 = number.parse().ok()?;
+    let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" | "kib" => 1 << 10,
+        "m" | "mb" | "mib" => 1 << 20,
+        "g" | "gb" | "gib" => 1 << 30,
+        "t" | "tb" | "tib" => 1 << 40,
+        _ => return None,
+    };
+    Some((number * multiplier as f64) as u64)
+}
+
+fn normalize_os(os: &str) -> String {
+    match os.to_ascii_lowercase().as_str() {
+        "macos" | "darwin" | "osx" => "darwin".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn normalize_arch(arch: &str) -> String {
+    match arch.to_ascii_lowercase().as_str() {
+        "x64" | "amd64" | "x86_64" => "x86_64".to_string(),
+        "arm64" | "aarch64" => "aarch64".to_string(),
+        "x86" | "i386" | "i686" => "x86".to_string(),
+        other => other.to_string(),
+    }
+}
@@ -22,6 +22,27 @@ impl Default for ScriptConfig {
     }
 }
 
+impl ScriptConfig {
+    /// The script as it applies to the current platform
+    pub fn for_current_os(&self) -> ScriptDetails {
+        self.for_os(std::env::consts::OS)
+    }
+
+    /// The script as it applies to `os` (`windows`, `linux` or `macos`)
+    ///
+    /// The OS variant, if any, is merged over the base definition. The
+    /// returned details have no variants left.
+    pub fn for_os(&self, os: &str) -> ScriptDetails {
+        match self {
+            ScriptConfig::Simple(command) => ScriptDetails {
+                command: command.clone(),
+                ..Default::default()
+            },
+            ScriptConfig::Detailed(details) => details.for_os(os),
+        }
+    }
+}
+
 /// Detailed script configuration
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -47,6 +68,123 @@ pub struct ScriptDetails {
     pub env: HashMap<String, String>,
 
     /// Dependencies (other scripts to run first)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    ///
+    /// Independent dependencies run in parallel. Also accepted as `depends_on`.
+    #[serde(default, alias = "depends_on", skip_serializing_if = "Vec::is_empty")]
     pub depends: Vec<String>,
+
+    /// Run with vx's built-in cross-platform interpreter instead of the system shell
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub portable: bool,
+
+    /// Override for Windows
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub windows: Option<Box<ScriptVariant>>,
+
+    /// Override for Linux
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linux: Option<Box<ScriptVariant>>,
+
+    /// Override for macOS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub macos: Option<Box<ScriptVariant>>,
+
+    /// Files that re-run the script under `vx run --watch`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watch: Option<Box<ScriptWatch>>,
+}
+
+impl ScriptDetails {
+    /// The variant for `os`, if one is defined
+    pub fn variant(&self, os: &str) -> Option<&ScriptVariant> {
+        match os {
+            "windows" => self.windows.as_deref(),
+            "linux" => self.linux.as_deref(),
+            "macos" => self.macos.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Whether any OS variant is defined
+    pub fn has_variants(&self) -> bool {
+        self.windows.is_some() || self.linux.is_some() || self.macos.is_some()
+    }
+
+    /// These details with the variant for `os` merged in
+    pub fn for_os(&self, os: &str) -> ScriptDetails {
+        let mut details = ScriptDetails {
+            windows: None,
+            linux: None,
+            macos: None,
+            ..self.clone()
+        };
+        match self.variant(os) {
+            Some(ScriptVariant::Simple(command)) => details.command = command.clone(),
+            Some(ScriptVariant::Detailed(variant)) => {
+                if let Some(command) = &variant.command {
+                    details.command = command.clone();
+                }
+                if let Some(cwd) = &variant.cwd {
+                    details.cwd = Some(cwd.clone());
+                }
+                if let Some(portable) = variant.portable {
+                    details.portable = portable;
+                }
+                details.env.extend(variant.env.clone());
+            }
+            None => {}
+        }
+        details
+    }
+}
+
+/// Watch settings of a script (`[scripts.<name>.watch]`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+pub struct ScriptWatch {
+    /// Globs, relative to the project root, of files to watch (default: all)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+
+    /// Globs of files to ignore, in addition to VCS and build directories
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<String>,
+
+    /// Quiet period in milliseconds before re-running (default: 300)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub debounce: Option<u64>,
+}
+
+/// Platform-specific override of a script
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(untagged)]
+pub enum ScriptVariant {
+    /// Replacement command
+    Simple(String),
+    /// Replacement command, extra env vars, cwd or interpreter
+    Detailed(ScriptVariantDetails),
+}
+
+/// Detailed platform-specific override of a script
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+pub struct ScriptVariantDetails {
+    /// Command to run instead of the base command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+
+    /// Working directory instead of the base one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+
+    /// Environment variables added to the base ones
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub env: HashMap<String, String>,
+
+    /// Whether to use the portable interpreter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub portable: Option<bool>,
 }
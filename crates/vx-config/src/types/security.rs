@@ -3,6 +3,7 @@
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Security configuration (Phase 4)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -36,6 +37,72 @@ pub struct SecurityConfig {
     /// Denied licenses
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub denied_licenses: Vec<String>,
+
+    /// Network egress policy for scripts and tool runs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub egress: Option<EgressConfig>,
+
+    /// Vulnerability-aware version resolution
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<ResolutionSecurityConfig>,
+}
+
+/// Vulnerability-aware version resolution (`[security.resolution]`)
+///
+/// Applies when resolving `latest` or a range; exact versions are installed
+/// as requested.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+pub struct ResolutionSecurityConfig {
+    /// Skip versions with known advisories (from OSV)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_vulnerable: Option<bool>,
+
+    /// Lowest advisory severity that makes a version skipped
+    /// (low, medium, high, critical; default: critical)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+
+    /// Security floor: tool name → lowest version resolution may pick
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub floor: HashMap<String, String>,
+}
+
+impl ResolutionSecurityConfig {
+    /// Whether vulnerable versions are skipped
+    pub fn skips_vulnerable(&self) -> bool {
+        self.skip_vulnerable.unwrap_or(false)
+    }
+
+    /// Severity threshold for skipping, as written (default: `critical`)
+    pub fn severity(&self) -> &str {
+        self.severity.as_deref().unwrap_or("critical")
+    }
+}
+
+/// Network egress policy (`[security.egress]`)
+///
+/// Child processes are pointed at a local proxy that only allows these
+/// destinations.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+pub struct EgressConfig {
+    /// Enable the policy (default: true when the section is present)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Allowed hosts: `pypi.org`, `*.github.com` (subdomains), IPs, or `*`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+}
+
+impl EgressConfig {
+    /// Whether the policy applies
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or(true)
+    }
 }
 
 /// Security audit configuration
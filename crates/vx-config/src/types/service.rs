@@ -38,11 +38,60 @@ pub struct ServiceConfig {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub depends_on: Vec<String>,
 
-    /// Health check command
+    /// Health check command, run inside the container
     #[serde(skip_serializing_if = "Option::is_none")]
     pub healthcheck: Option<String>,
 
+    /// HTTP health probe, checked from the host (healthy on 2xx/3xx)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub healthcheck_url: Option<String>,
+
+    /// Seconds to wait for the service to become healthy (default: 60)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub startup_timeout: Option<u64>,
+
+    /// Restart policy applied by `vx services supervise`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restart: Option<RestartPolicy>,
+
+    /// Consecutive restarts before the supervisor gives up (default: 5)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_restarts: Option<u32>,
+
+    /// Rotate the archived log once it exceeds this many megabytes (default: 10)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_max_size_mb: Option<u64>,
+
+    /// Rotate the archived log once its oldest line is this many days old (default: 7)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_max_age_days: Option<u64>,
+
     /// Working directory
     #[serde(skip_serializing_if = "Option::is_none")]
     pub working_dir: Option<String>,
 }
+
+/// When a supervised service is restarted after its container exits
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum RestartPolicy {
+    /// Never restart
+    #[default]
+    No,
+    /// Restart when the container exits with a non-zero code
+    OnFailure,
+    /// Restart whenever the container exits
+    Always,
+}
+
+impl RestartPolicy {
+    /// Config value for this policy
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::No => "no",
+            Self::OnFailure => "on-failure",
+            Self::Always => "always",
+        }
+    }
+}
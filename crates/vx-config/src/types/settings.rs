@@ -70,6 +70,45 @@ pub struct SettingsConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub setenv: Option<std::collections::HashMap<String, String>>,
 
+    /// What to install when a tool has no build for the host architecture
+    ///
+    /// On Windows ARM64, `"emulate"` (default) installs the x64 build, which
+    /// Windows runs under emulation; `"never"` only installs native builds.
+    /// `VX_ARCH_FALLBACK` takes precedence.
+    ///
+    /// Example:
+    /// ```toml
+    /// [settings]
+    /// arch_fallback = "never"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arch_fallback: Option<String>,
+
+    /// C library of Linux builds to install: `"gnu"` or `"musl"`
+    ///
+    /// Detected from the host by default, so Alpine gets musl builds.
+    /// `VX_LIBC` takes precedence.
+    ///
+    /// Example:
+    /// ```toml
+    /// [settings]
+    /// libc = "musl"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub libc: Option<String>,
+
+    /// How often `vx sync` retries a tool whose installation failed
+    ///
+    /// Defaults to 2 retries; `vx sync --retries` takes precedence.
+    ///
+    /// Example:
+    /// ```toml
+    /// [settings]
+    /// install_retries = 3
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub install_retries: Option<u32>,
+
     /// Experimental features
     #[serde(skip_serializing_if = "Option::is_none")]
     pub experimental: Option<ExperimentalConfig>,
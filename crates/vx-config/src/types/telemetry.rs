@@ -29,6 +29,35 @@ pub struct TelemetryConfig {
     /// Metrics to collect
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub metrics: Vec<String>,
+
+    /// Stage timing budgets for `vx metrics insights` and `--verbose`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budgets: Option<StageBudgetsConfig>,
+}
+
+/// Pipeline stage timing budgets, in milliseconds
+///
+/// Example:
+/// ```toml
+/// [telemetry.budgets]
+/// resolve = 50
+/// ensure = 1000
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+pub struct StageBudgetsConfig {
+    /// Version resolution (default: 100)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolve: Option<u64>,
+
+    /// Checking or installing the runtime (default: 500)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ensure: Option<u64>,
+
+    /// Building the environment and PATH (default: 100)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prepare: Option<u64>,
 }
 
 /// Build time tracking configuration
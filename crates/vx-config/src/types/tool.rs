@@ -43,8 +43,10 @@ pub struct ToolConfig {
     pub install_env: Option<HashMap<String, String>>,
 
     /// Optional components to include (e.g., ["spectre", "mfc", "atl", "asan", "cli"])
-    /// Used by MSVC provider to select additional msvc-kit components.
-    /// See msvc-kit MsvcComponent for valid values.
+    /// Used by MSVC provider to select additional msvc-kit components, and by
+    /// archive providers that declare components (e.g., LLVM's
+    /// ["clang-format", "clang-tidy"]) to extract only part of the archive.
+    /// Passed to the install as `VX_<TOOL>_COMPONENTS`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub components: Option<Vec<String>>,
 
@@ -52,4 +54,36 @@ pub struct ToolConfig {
     /// Used by MSVC provider for fine-grained control over package selection.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub exclude_patterns: Option<Vec<String>>,
+
+    /// Default arguments and environment applied whenever the tool runs through vx
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exec: Option<Box<ToolExecConfig>>,
+}
+
+/// Execution wrapper for a tool (`[tools.<name>.exec]`)
+///
+/// Replaces wrapper scripts that only exist to add flags or env vars, e.g.
+/// `args = ["--max-old-space-size=4096"]` for node.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+pub struct ToolExecConfig {
+    /// Arguments inserted before the user's arguments
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+
+    /// Environment variables set for the tool process
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub env: HashMap<String, String>,
+
+    /// Command the tool is launched through, e.g. `["sccache"]` for a compiler
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub wrapper: Vec<String>,
+}
+
+impl ToolExecConfig {
+    /// Whether the wrapper changes nothing
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty() && self.env.is_empty() && self.wrapper.is_empty()
+    }
 }
@@ -0,0 +1,87 @@
+//! Terminal output configuration
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::{ConfigError, ConfigResult};
+
+/// File name of the user's output preferences inside `~/.vx/config`
+pub const UI_CONFIG_FILE: &str = "ui.toml";
+
+/// How vx draws its own output
+///
+/// ```toml
+/// [ui]
+/// theme = "screen-reader"   # or "high-contrast", "minimal", "auto", ...
+/// emoji = false
+/// ascii_only = true
+/// ```
+///
+/// The same `[ui]` table in `~/.vx/config/ui.toml` takes precedence over
+/// the project's, since these are preferences of the person at the
+/// terminal.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+pub struct UiConfig {
+    /// Output theme (default: `auto`, picked from the terminal)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+
+    /// Allow emoji in output (default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<bool>,
+
+    /// Only print ASCII characters (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ascii_only: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct UiFile {
+    #[serde(default)]
+    ui: UiConfig,
+}
+
+impl UiConfig {
+    /// Load the `[ui]` table of a user preferences file
+    ///
+    /// A missing file gives the defaults.
+    pub fn from_user_file(path: &Path) -> ConfigResult<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        let file: UiFile = toml::from_str(&content)
+            .map_err(|e| ConfigError::ParseError(format!("Invalid {}: {}", path.display(), e)))?;
+        Ok(file.ui)
+    }
+
+    /// Fill the options not set here from `fallback`
+    pub fn or(self, fallback: &Self) -> Self {
+        Self {
+            theme: self.theme.or_else(|| fallback.theme.clone()),
+            emoji: self.emoji.or(fallback.emoji),
+            ascii_only: self.ascii_only.or(fallback.ascii_only),
+        }
+    }
+
+    /// Flattened `ui.*` keys and values of the options that are set
+    pub fn entries(&self) -> Vec<(String, String)> {
+        [
+            ("theme", self.theme.clone()),
+            ("emoji", self.emoji.map(|v| v.to_string())),
+            ("ascii_only", self.ascii_only.map(|v| v.to_string())),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| Some((format!("ui.{}", key), value?)))
+        .collect()
+    }
+
+    /// Whether no option is set
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
@@ -1,6 +1,6 @@
 //! Configuration validation
 
-use crate::types::VxConfig;
+use crate::types::{ScriptConfig, VxConfig};
 
 /// Validation result
 #[derive(Debug, Default)]
@@ -43,13 +43,81 @@ pub fn validate_config(config: &VxConfig) -> ValidationResult {
     }
 
     // Validate scripts
-    for name in config.scripts.keys() {
+    for (name, script) in &config.scripts {
         validate_script_name(name, &mut result);
+        let ScriptConfig::Detailed(details) = script else {
+            continue;
+        };
+        if details.command.trim().is_empty() {
+            let missing: Vec<_> = ["windows", "linux", "macos"]
+                .into_iter()
+                .filter(|os| details.for_os(os).command.trim().is_empty())
+                .collect();
+            if missing.len() == 3 {
+                result.warn(format!("Script '{}' has no command", name));
+            } else if !missing.is_empty() {
+                result.warn(format!(
+                    "Script '{}' has no command for {}",
+                    name,
+                    missing.join(", ")
+                ));
+            }
+        }
+        for dep in &details.depends {
+            if dep == name {
+                result.warn(format!("Script '{}' depends on itself", name));
+            } else if !config.scripts.contains_key(dep) {
+                result.warn(format!(
+                    "Script '{}' depends on unknown script '{}'",
+                    name, dep
+                ));
+            }
+        }
+    }
+
+    // Validate the egress policy
+    if let Some(egress) = config.security.as_ref().and_then(|s| s.egress.as_ref())
+        && egress.is_enabled()
+    {
+        if egress.allow.is_empty() {
+            result.warn(
+                "security.egress allows no hosts; scripts and tools will have no network access",
+            );
+        }
+        for entry in &egress.allow {
+            if entry.contains("://") || entry.contains('/') {
+                result.warn(format!(
+                    "security.egress.allow entry '{}' should be a host name, not a URL",
+                    entry
+                ));
+            }
+        }
+    }
+
+    // Validate vulnerability-aware resolution
+    if let Some(resolution) = config.security.as_ref().and_then(|s| s.resolution.as_ref()) {
+        let severity = resolution.severity().to_ascii_lowercase();
+        if !["low", "medium", "moderate", "high", "critical"].contains(&severity.as_str()) {
+            result.warn(format!(
+                "security.resolution.severity '{}' should be one of low, medium, high, critical",
+                resolution.severity()
+            ));
+        }
     }
 
     // Validate services
     for (name, service) in &config.services {
         validate_service(name, service, &mut result);
+        for dep in &service.depends_on {
+            if dep == name {
+                result.warn(format!("Service '{}' depends on itself", name));
+            } else if !config.services.contains_key(dep) {
+                result.warn(format!(
+                    "Service '{}' depends on unknown service '{}'",
+                    name, dep
+                ));
+            }
+        }
     }
 
     result
@@ -112,6 +180,15 @@ fn validate_service(
         ));
     }
 
+    if let Some(url) = &service.healthcheck_url
+        && !(url.starts_with("http://") || url.starts_with("https://"))
+    {
+        result.warn(format!(
+            "Service '{}' has invalid healthcheck_url: {}",
+            name, url
+        ));
+    }
+
     // Validate port format
     for port in &service.ports {
         if !is_valid_port_mapping(port) {
@@ -175,4 +252,129 @@ min_version = "invalid"
         let result = validate_config(&config);
         assert!(!result.warnings.is_empty());
     }
+
+    #[test]
+    fn test_validate_service_dependencies() {
+        let content = r#"
+[services.api]
+image = "api:dev"
+depends_on = ["api", "db"]
+healthcheck_url = "localhost:8080/health"
+startup_timeout = 30
+"#;
+        let config = parse_config_str(content).unwrap();
+        assert_eq!(config.services["api"].startup_timeout, Some(30));
+        let result = validate_config(&config);
+        assert!(
+            result
+                .warnings
+                .iter()
+                .any(|w| w.contains("depends on itself"))
+        );
+        assert!(
+            result
+                .warnings
+                .iter()
+                .any(|w| w.contains("unknown service 'db'"))
+        );
+        assert!(
+            result
+                .warnings
+                .iter()
+                .any(|w| w.contains("healthcheck_url"))
+        );
+    }
+
+    #[test]
+    fn test_validate_script_dependencies() {
+        let content = r#"
+[scripts]
+lint = "cargo clippy"
+
+[scripts.ci]
+command = "echo done"
+depends_on = ["lint", "test"]
+"#;
+        let config = parse_config_str(content).unwrap();
+        let result = validate_config(&config);
+        assert!(
+            result
+                .warnings
+                .iter()
+                .any(|w| w.contains("unknown script 'test'"))
+        );
+        assert!(!result.warnings.iter().any(|w| w.contains("'lint'")));
+    }
+
+    #[test]
+    fn test_validate_script_os_variants() {
+        let content = r#"
+[scripts.open]
+windows = "start index.html"
+macos = "open index.html"
+
+[scripts.all.windows]
+command = "dir"
+[scripts.all.linux]
+command = "ls"
+[scripts.all.macos]
+command = "ls"
+"#;
+        let config = parse_config_str(content).unwrap();
+        let result = validate_config(&config);
+        assert!(
+            result
+                .warnings
+                .iter()
+                .any(|w| w == "Script 'open' has no command for linux")
+        );
+        assert!(!result.warnings.iter().any(|w| w.contains("'all'")));
+    }
+
+    #[test]
+    fn test_validate_egress_policy() {
+        let content = r#"
+[security.egress]
+allow = ["pypi.org", "https://registry.npmjs.org/"]
+"#;
+        let config = parse_config_str(content).unwrap();
+        let result = validate_config(&config);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("'https://registry.npmjs.org/'"));
+
+        let config = parse_config_str("[security.egress]\n").unwrap();
+        let result = validate_config(&config);
+        assert!(result.warnings[0].contains("no network access"));
+
+        let config = parse_config_str("[security.egress]\nenabled = false\n").unwrap();
+        assert!(validate_config(&config).warnings.is_empty());
+    }
+
+    #[test]
+    fn test_validate_resolution_severity() {
+        let content = r#"
+[security.resolution]
+skip_vulnerable = true
+severity = "severe"
+
+[security.resolution.floor]
+node = "20.11.1"
+"#;
+        let config = parse_config_str(content).unwrap();
+        let resolution = config
+            .security
+            .as_ref()
+            .unwrap()
+            .resolution
+            .as_ref()
+            .unwrap();
+        assert!(resolution.skips_vulnerable());
+        assert_eq!(resolution.floor["node"], "20.11.1");
+        let result = validate_config(&config);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("'severe'"));
+
+        let config = parse_config_str("[security.resolution]\nskip_vulnerable = true\n").unwrap();
+        assert!(validate_config(&config).warnings.is_empty());
+    }
 }
@@ -0,0 +1,165 @@
+//! Version files of other version managers
+//!
+//! Projects set up for nvm, pyenv, rustup or asdf already pin their tools in
+//! files of their own. vx reads them for tools `vx.toml` and `vx.lock` don't
+//! pin:
+//!
+//! | File | Tools | Used by |
+//! |------|-------|---------|
+//! | `.nvmrc` | node | nvm |
+//! | `.node-version` | node | fnm, nodenv, volta |
+//! | `.python-version` | python | pyenv, uv |
+//! | `rust-toolchain.toml`, `rust-toolchain` | rust | rustup |
+//! | `.tool-versions` | any | asdf, mise |
+//!
+//! Files are searched from the working directory up to the filesystem root.
+//! The nearest directory pinning a tool wins; within one directory the order
+//! of [`VERSION_FILES`] applies, so a tool-specific file beats
+//! `.tool-versions`.
+
+use std::path::{Path, PathBuf};
+
+/// Recognized version files, highest precedence first
+pub const VERSION_FILES: &[&str] = &[
+    ".nvmrc",
+    ".node-version",
+    ".python-version",
+    "rust-toolchain.toml",
+    "rust-toolchain",
+    ".tool-versions",
+];
+
+/// asdf plugin names that differ from the vx tool name
+const ASDF_TOOL_NAMES: &[(&str, &str)] = &[("nodejs", "node"), ("golang", "go")];
+
+/// A tool version pinned by a version file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionFilePin {
+    /// vx tool name
+    pub tool: String,
+    /// Version as vx understands it
+    pub version: String,
+    /// File the pin comes from
+    pub path: PathBuf,
+}
+
+impl VersionFilePin {
+    /// File name of the pin's file, e.g. `.nvmrc`
+    pub fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+}
+
+/// Parse the content of the version file `file_name` into `(tool, version)`
+/// pins
+///
+/// Values vx can't resolve (`system`, `ref:...`, `path:...`, pyenv virtualenv
+/// names) are skipped. An unknown file name yields no pins.
+pub fn parse_version_file(file_name: &str, content: &str) -> Vec<(String, String)> {
+    match file_name {
+        ".nvmrc" | ".node-version" => first_value(content)
+            .and_then(node_version)
+            .map(|v| vec![("node".to_string(), v)])
+            .unwrap_or_default(),
+        ".python-version" => first_value(content)
+            .filter(|v| v.starts_with(|c: char| c.is_ascii_digit()))
+            .map(|v| vec![("python".to_string(), v.to_string())])
+            .unwrap_or_default(),
+        "rust-toolchain.toml" => rust_toolchain_channel(content)
+            .map(|v| vec![("rust".to_string(), v)])
+            .unwrap_or_default(),
+        // The legacy file holds a bare channel, or TOML like rust-toolchain.toml
+        "rust-toolchain" => rust_toolchain_channel(content)
+            .or_else(|| first_value(content).map(str::to_string))
+            .map(|v| vec![("rust".to_string(), v)])
+            .unwrap_or_default(),
+        ".tool-versions" => parse_tool_versions(content),
+        _ => Vec::new(),
+    }
+}
+
+/// All pins from version files in `start_dir` and its ancestors, highest
+/// precedence first
+///
+/// The first pin of a tool is the one that applies; later ones are shadowed.
+/// Unreadable files are skipped.
+pub fn find_version_file_pins(start_dir: &Path) -> Vec<VersionFilePin> {
+    let mut pins = Vec::new();
+    for dir in start_dir.ancestors() {
+        for file_name in VERSION_FILES {
+            let path = dir.join(file_name);
+            let Ok(content) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            for (tool, version) in parse_version_file(file_name, &content) {
+                pins.push(VersionFilePin {
+                    tool,
+                    version,
+                    path: path.clone(),
+                });
+            }
+        }
+    }
+    pins
+}
+
+/// The pin that applies for each tool, from [`find_version_file_pins`]
+pub fn version_file_pins(start_dir: &Path) -> Vec<VersionFilePin> {
+    let mut pins: Vec<VersionFilePin> = Vec::new();
+    for pin in find_version_file_pins(start_dir) {
+        if !pins.iter().any(|p| p.tool == pin.tool) {
+            pins.push(pin);
+        }
+    }
+    pins
+}
+
+/// First line that is neither empty nor a comment
+fn first_value(content: &str) -> Option<&str> {
+    content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .find(|line| !line.is_empty())
+}
+
+/// nvm version: `node` and `stable` mean the latest release
+fn node_version(value: &str) -> Option<String> {
+    match value {
+        "node" | "stable" => Some("latest".to_string()),
+        "system" | "iojs" => None,
+        _ => Some(value.to_string()),
+    }
+}
+
+/// `toolchain.channel` of a rust-toolchain.toml
+fn rust_toolchain_channel(content: &str) -> Option<String> {
+    let value: toml::Value = toml::from_str(content).ok()?;
+    let channel = value.get("toolchain")?.get("channel")?.as_str()?.trim();
+    (!channel.is_empty()).then(|| channel.to_string())
+}
+
+/// `<tool> <version> [<fallback>...]` lines of an asdf `.tool-versions`
+fn parse_tool_versions(content: &str) -> Vec<(String, String)> {
+    let mut pins: Vec<(String, String)> = Vec::new();
+    for line in content.lines() {
+        let line = line.split('#').next().unwrap_or_default();
+        let mut fields = line.split_whitespace();
+        let (Some(tool), Some(version)) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        if version == "system" || version.starts_with("ref:") || version.starts_with("path:") {
+            continue;
+        }
+        let tool = ASDF_TOOL_NAMES
+            .iter()
+            .find(|(asdf, _)| *asdf == tool)
+            .map_or(tool, |(_, vx)| vx);
+        if !pins.iter().any(|(t, _)| t == tool) {
+            pins.push((tool.to_string(), version.to_string()));
+        }
+    }
+    pins
+}
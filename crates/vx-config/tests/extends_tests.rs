@@ -0,0 +1,165 @@
+//! Tests for `extends` preset inheritance
+
+use std::path::Path;
+use vx_config::{
+    ConfigOrigin, InheritanceManager, PresetLocation, ToolVersion, VxConfig, parse_config,
+};
+
+fn write(dir: &Path, name: &str, content: &str) -> std::path::PathBuf {
+    let path = dir.join(name);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).unwrap();
+    }
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+fn version(config: &VxConfig, tool: &str) -> Option<String> {
+    match config.tools.get(tool)? {
+        ToolVersion::Simple(v) => Some(v.clone()),
+        ToolVersion::Detailed(d) => Some(d.version.clone()),
+    }
+}
+
+#[test]
+fn test_locate_preset() {
+    let base = Path::new("/work/project");
+    assert_eq!(
+        InheritanceManager::locate_preset("vx:node@1", base),
+        PresetLocation::Builtin("node".to_string())
+    );
+    assert_eq!(
+        InheritanceManager::locate_preset("python", base),
+        PresetLocation::Builtin("python".to_string())
+    );
+    assert_eq!(
+        InheritanceManager::locate_preset("../team/vx.toml", base),
+        PresetLocation::Local(base.join("../team/vx.toml"))
+    );
+    assert!(matches!(
+        InheritanceManager::locate_preset("https://example.com/team.toml#abc", base),
+        PresetLocation::Remote(source) if source.sha256.as_deref() == Some("abc")
+    ));
+}
+
+#[test]
+fn test_extends_local_chain() {
+    let dir = tempfile::tempdir().unwrap();
+    write(
+        dir.path(),
+        "shared/base.toml",
+        "[tools]\ngo = \"1.22\"\nnode = \"18\"\n\n[env]\nBASE = \"1\"\nSHARED = \"base\"\n",
+    );
+    write(
+        dir.path(),
+        "shared/team.toml",
+        "extends = \"base.toml\"\n\n[tools]\nnode = \"20\"\n\n[scripts]\nlint = \"eslint .\"\n",
+    );
+    let project = write(
+        dir.path(),
+        "app/vx.toml",
+        "extends = \"../shared/team.toml\"\n\n[tools]\nuv = \"latest\"\n\n[env]\nSHARED = \"app\"\n",
+    );
+
+    let manager = InheritanceManager::new(dir.path().join("cache"));
+    let chain = manager
+        .resolve_extends(&project, parse_config(&project).unwrap())
+        .unwrap();
+
+    assert_eq!(chain.layers.len(), 3);
+    assert!(chain.missing.is_empty());
+    assert!(matches!(&chain.layers[0].0, ConfigOrigin::Preset(p) if p == "base.toml"));
+    assert!(matches!(chain.layers[2].0, ConfigOrigin::Project(_)));
+
+    let merged = chain.merged();
+    assert_eq!(version(&merged, "go").as_deref(), Some("1.22"));
+    assert_eq!(version(&merged, "node").as_deref(), Some("20"));
+    assert_eq!(version(&merged, "uv").as_deref(), Some("latest"));
+    assert!(merged.scripts.contains_key("lint"));
+
+    let env = merged.env_as_hashmap();
+    assert_eq!(env["BASE"], "1");
+    assert_eq!(env["SHARED"], "app");
+}
+
+#[test]
+fn test_extends_builtin_preset() {
+    let dir = tempfile::tempdir().unwrap();
+    let project = write(
+        dir.path(),
+        "vx.toml",
+        "extends = \"vx:node\"\n\n[tools]\nnode = \"22\"\n",
+    );
+
+    let manager = InheritanceManager::new(dir.path().join("cache"));
+    let chain = manager
+        .resolve_extends(&project, parse_config(&project).unwrap())
+        .unwrap();
+    let merged = chain.merged();
+
+    assert_eq!(version(&merged, "node").as_deref(), Some("22"));
+    assert_eq!(version(&merged, "pnpm").as_deref(), Some("latest"));
+}
+
+#[test]
+fn test_extends_cycle_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "a.toml", "extends = \"b.toml\"\n");
+    write(dir.path(), "b.toml", "extends = \"a.toml\"\n");
+    let project = write(dir.path(), "vx.toml", "extends = \"a.toml\"\n");
+
+    let manager = InheritanceManager::new(dir.path().join("cache"));
+    let err = manager
+        .resolve_extends(&project, parse_config(&project).unwrap())
+        .unwrap_err();
+    assert!(err.to_string().contains("Circular extends"), "{}", err);
+}
+
+#[test]
+fn test_extends_remote_preset_from_cache() {
+    let dir = tempfile::tempdir().unwrap();
+    let preset = "[tools]\njust = \"latest\"\n";
+    let hash = InheritanceManager::calculate_hash(preset);
+    let url = format!("https://example.com/team.toml#{}", hash);
+    let project = write(
+        dir.path(),
+        "vx.toml",
+        &format!("extends = \"{}\"\n\n[tools]\nnode = \"22\"\n", url),
+    );
+    let manager = InheritanceManager::new(dir.path().join("cache"));
+
+    let chain = manager
+        .resolve_extends(&project, parse_config(&project).unwrap())
+        .unwrap();
+    assert_eq!(chain.missing.len(), 1);
+    assert_eq!(chain.layers.len(), 1);
+
+    let source = chain.missing[0].clone();
+    assert!(
+        manager
+            .store_preset(&source, "[tools]\nother = \"1\"\n")
+            .is_err()
+    );
+    manager.store_preset(&source, preset).unwrap();
+
+    let chain = manager
+        .resolve_extends(&project, parse_config(&project).unwrap())
+        .unwrap();
+    assert!(chain.missing.is_empty());
+    let merged = chain.merged();
+    assert_eq!(version(&merged, "just").as_deref(), Some("latest"));
+    assert_eq!(version(&merged, "node").as_deref(), Some("22"));
+}
+
+#[test]
+fn test_team_extends_is_still_supported() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "base.toml", "[tools]\ngo = \"1.22\"\n");
+    let project = write(dir.path(), "vx.toml", "[team]\nextends = \"base.toml\"\n");
+
+    let manager = InheritanceManager::new(dir.path().join("cache"));
+    let chain = manager
+        .resolve_extends(&project, parse_config(&project).unwrap())
+        .unwrap();
+    assert_eq!(version(&chain.merged(), "go").as_deref(), Some("1.22"));
+}
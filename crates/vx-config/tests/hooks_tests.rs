@@ -3,8 +3,13 @@
 //! Tests for lifecycle hook execution.
 
 use rstest::rstest;
+use std::collections::HashMap;
+use std::path::PathBuf;
 use tempfile::TempDir;
-use vx_config::{EnterHookManager, GitHookInstaller, HookCommand, HookExecutor};
+use vx_config::{
+    EnterHookManager, GitHookInstaller, HookCommand, HookExecutor, expand_tool_placeholders,
+    tool_placeholders,
+};
 
 // ============================================
 // HookExecutor Basic Tests
@@ -170,6 +175,66 @@ fn test_execute_with_environment_variable() {
     }
 }
 
+// ============================================
+// Tool Placeholder Tests
+// ============================================
+
+#[test]
+fn test_tool_placeholders_lists_referenced_tools() {
+    let names = tool_placeholders("{tool:node} a.js && {tool:uv} run x && {tool:node} b.js");
+    assert_eq!(names, vec!["node", "uv"]);
+}
+
+#[test]
+fn test_tool_placeholders_ignores_other_braces() {
+    assert!(tool_placeholders("echo ${HOME} {a,b} {{tools.node.bin}}").is_empty());
+    assert!(tool_placeholders("echo {tool:} {tool:bad name} {tool:open").is_empty());
+}
+
+#[test]
+fn test_expand_tool_placeholders() {
+    let tools = HashMap::from([(
+        "node".to_string(),
+        PathBuf::from("/vx/store/node/22.1.0/bin/node"),
+    )]);
+
+    let expanded = expand_tool_placeholders("{tool:node} --version", &tools).unwrap();
+    assert_eq!(expanded, "/vx/store/node/22.1.0/bin/node --version");
+
+    let unchanged = expand_tool_placeholders("echo {tool:}", &tools).unwrap();
+    assert_eq!(unchanged, "echo {tool:}");
+}
+
+#[test]
+fn test_expand_tool_placeholders_unknown_tool() {
+    let err = expand_tool_placeholders("{tool:go} build", &HashMap::new()).unwrap_err();
+    assert!(err.to_string().contains("{tool:go}"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_execute_expands_tool_placeholders() {
+    let temp_dir = TempDir::new().unwrap();
+    let tools = HashMap::from([("echo".to_string(), PathBuf::from("/bin/echo"))]);
+    let executor = HookExecutor::new(temp_dir.path()).tools(tools);
+
+    let hook = HookCommand::Single("{tool:echo} from-tool".to_string());
+    let result = executor.execute("test_hook", &hook).unwrap();
+
+    assert!(result.success);
+    assert!(result.output.unwrap().contains("from-tool"));
+}
+
+#[test]
+fn test_execute_fails_on_unresolved_placeholder() {
+    let temp_dir = TempDir::new().unwrap();
+    let executor = HookExecutor::new(temp_dir.path());
+
+    let hook = HookCommand::Single("{tool:node} -v".to_string());
+    let err = executor.execute("post_setup", &hook).unwrap_err();
+    assert!(format!("{:#}", err).contains("hook 'post_setup'"));
+}
+
 // ============================================
 // Named Hook Execution Tests
 // ============================================
@@ -0,0 +1,57 @@
+//! Tests for configuration origin resolution
+
+use std::path::PathBuf;
+use vx_config::{ConfigLayer, ConfigOrigin, InheritanceManager, parse_config_str};
+
+#[test]
+fn test_builtin_preset() {
+    let preset = InheritanceManager::builtin_preset("vx:node").unwrap();
+    assert!(preset.tools.contains_key("node"));
+
+    assert!(InheritanceManager::builtin_preset("python@1.0").is_some());
+    assert!(InheritanceManager::builtin_preset("github:acme/presets").is_none());
+}
+
+#[test]
+fn test_origin_display() {
+    assert_eq!(
+        ConfigOrigin::Env("VX_CDN".to_string()).to_string(),
+        "env (VX_CDN)"
+    );
+    assert_eq!(
+        ConfigOrigin::Preset("vx:node".to_string()).to_string(),
+        "preset (vx:node)"
+    );
+}
+
+#[test]
+fn test_resolve_preset_project_and_env() {
+    let preset = InheritanceManager::builtin_preset("vx:node").unwrap();
+    let project = parse_config_str("[tools]\nnode = \"22\"\n").unwrap();
+
+    let layers = vec![
+        ConfigLayer::from_config(ConfigOrigin::Preset("vx:node".to_string()), &preset),
+        ConfigLayer::from_config(ConfigOrigin::Project(PathBuf::from("vx.toml")), &project),
+        ConfigLayer::from_env("VX_CDN", "mirrors.cdn", "1"),
+    ];
+    let resolved = InheritanceManager::resolve_origins(&layers);
+    let get = |key: &str| resolved.iter().find(|r| r.key == key).unwrap();
+
+    let node = get("tools.node");
+    assert_eq!(node.value, "22");
+    assert!(matches!(node.origin, ConfigOrigin::Project(_)));
+    assert_eq!(
+        node.overridden,
+        vec![(
+            ConfigOrigin::Preset("vx:node".to_string()),
+            "lts".to_string()
+        )]
+    );
+
+    // Only set by the preset
+    assert!(matches!(get("tools.pnpm").origin, ConfigOrigin::Preset(_)));
+
+    let cdn = get("mirrors.cdn");
+    assert_eq!(cdn.origin, ConfigOrigin::Env("VX_CDN".to_string()));
+    assert!(!cdn.locked);
+}
@@ -116,6 +116,28 @@ install_env = { NODE_OPTIONS = "--max-old-space-size=4096" }
     }
 }
 
+#[test]
+fn test_parse_tool_with_exec_config() {
+    let content = r#"
+[tools.node]
+version = "20"
+
+[tools.node.exec]
+args = ["--max-old-space-size=4096"]
+env = { NODE_ENV = "production" }
+"#;
+    let config = parse_config_str(content).unwrap();
+    let exec = config
+        .get_tool_config("node")
+        .unwrap()
+        .exec
+        .as_ref()
+        .unwrap();
+    assert_eq!(exec.args, vec!["--max-old-space-size=4096".to_string()]);
+    assert_eq!(exec.env.get("NODE_ENV"), Some(&"production".to_string()));
+    assert!(!exec.is_empty());
+}
+
 // ============================================
 // Script Configuration Tests
 // ============================================
@@ -188,6 +210,64 @@ env = { NODE_ENV = "development", DEBUG = "true" }
     }
 }
 
+#[test]
+fn test_parse_script_os_variants() {
+    let content = r#"
+[scripts.build]
+command = "make"
+env = { MODE = "release" }
+
+[scripts.build.windows]
+command = "nmake"
+env = { CC = "cl" }
+
+[scripts.build.macos]
+portable = true
+
+[scripts.clean]
+portable = true
+command = "rm -rf dist"
+linux = "rm -rf dist target"
+"#;
+    let config = parse_config_str(content).unwrap();
+    let build = &config.scripts["build"];
+
+    let windows = build.for_os("windows");
+    assert_eq!(windows.command, "nmake");
+    assert_eq!(windows.env.get("MODE"), Some(&"release".to_string()));
+    assert_eq!(windows.env.get("CC"), Some(&"cl".to_string()));
+    assert!(windows.windows.is_none());
+
+    let linux = build.for_os("linux");
+    assert_eq!(linux.command, "make");
+    assert!(!linux.portable);
+    assert!(build.for_os("macos").portable);
+
+    let clean = &config.scripts["clean"];
+    assert_eq!(clean.for_os("linux").command, "rm -rf dist target");
+    assert_eq!(clean.for_os("windows").command, "rm -rf dist");
+    assert!(clean.for_os("windows").portable);
+}
+
+#[test]
+fn test_parse_script_watch() {
+    let content = r#"
+[scripts.build]
+command = "cargo build"
+
+[scripts.build.watch]
+paths = ["src/**/*.rs", "Cargo.toml"]
+ignore = ["src/generated/**"]
+debounce = 500
+"#;
+    let config = parse_config_str(content).unwrap();
+    let build = config.scripts["build"].for_current_os();
+    let watch = build.watch.expect("watch settings");
+    assert_eq!(watch.paths, ["src/**/*.rs", "Cargo.toml"]);
+    assert_eq!(watch.ignore, ["src/generated/**"]);
+    assert_eq!(watch.debounce, Some(500));
+}
+
 // ============================================
 // Environment Variables Tests
 // ============================================
@@ -223,6 +303,21 @@ cache_duration = "7d"
     assert_eq!(settings.cache_duration, Some("7d".to_string()));
 }
 
+#[test]
+fn test_parse_install_retries() {
+    let content = r#"
+[settings]
+install_retries = 4
+"#;
+    let config = parse_config_str(content).unwrap();
+    let settings = config.settings.as_ref().unwrap();
+    assert_eq!(settings.install_retries, Some(4));
+    assert_eq!(
+        config.settings_as_hashmap().get("install_retries"),
+        Some(&"4".to_string())
+    );
+}
+
 // ============================================
 // Hooks Configuration Tests
 // ============================================
@@ -0,0 +1,142 @@
+//! Tests for the machine-wide policy
+
+use std::path::PathBuf;
+use vx_config::{
+    ConfigLayer, ConfigOrigin, InheritanceManager, PolicyConfig, ToolVersion, parse_config_str,
+};
+
+const POLICY: &str = r#"
+[settings]
+auto_install = false
+isolation = false
+
+[versions]
+allow_prerelease = false
+
+[versions.pin]
+node = "20.11.0"
+
+[[mirrors.node]]
+name = "corp"
+url = "https://mirror.corp.example/node/{filename}"
+
+[providers]
+allow = ["node", "uv"]
+user_providers = false
+"#;
+
+#[test]
+fn test_parse_policy() {
+    let policy = PolicyConfig::parse(POLICY).unwrap();
+
+    assert!(!policy.allows_prerelease());
+    assert!(!policy.allows_user_providers());
+    assert!(policy.allows_provider("node"));
+    assert!(!policy.allows_provider("go"));
+    assert_eq!(policy.pin("node"), Some("20.11.0"));
+    assert_eq!(policy.pin("uv"), None);
+    assert_eq!(policy.mirrors_for("node").len(), 1);
+    assert_eq!(policy.mirrors_for("node")[0].name, "corp");
+    assert!(policy.mirrors_for("uv").is_empty());
+}
+
+#[test]
+fn test_empty_policy_allows_everything() {
+    let policy = PolicyConfig::parse("").unwrap();
+
+    assert!(policy.allows_prerelease());
+    assert!(policy.allows_user_providers());
+    assert!(policy.allows_provider("anything"));
+    assert!(policy.enforced_values().is_empty());
+}
+
+#[test]
+fn test_load_missing_policy() {
+    let dir = tempfile::tempdir().unwrap();
+    let loaded = PolicyConfig::load(&dir.path().join("policy.toml")).unwrap();
+    assert!(loaded.is_none());
+}
+
+#[test]
+fn test_load_invalid_policy_names_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("policy.toml");
+    std::fs::write(&path, "[versions]\nallow_prerelease = \"nope\"\n").unwrap();
+
+    let err = PolicyConfig::load(&path).unwrap_err().to_string();
+    assert!(err.contains("policy.toml"), "{}", err);
+}
+
+#[test]
+fn test_apply_policy_settings_are_defaults() {
+    let policy = PolicyConfig::parse(POLICY).unwrap();
+    let config = parse_config_str(
+        r#"
+[tools]
+node = "18"
+uv = "latest"
+
+[settings]
+auto_install = true
+"#,
+    )
+    .unwrap();
+
+    let applied = InheritanceManager::apply_policy(&config, &policy);
+    let settings = applied.settings.unwrap();
+
+    // Project value wins over the policy default
+    assert_eq!(settings.auto_install, Some(true));
+    // Unset values fall back to the policy
+    assert_eq!(settings.isolation, Some(false));
+    // Pins replace the requested version
+    assert!(matches!(
+        applied.tools.get("node"),
+        Some(ToolVersion::Simple(v)) if v == "20.11.0"
+    ));
+    assert!(matches!(
+        applied.tools.get("uv"),
+        Some(ToolVersion::Simple(v)) if v == "latest"
+    ));
+}
+
+#[test]
+fn test_resolve_origins_precedence() {
+    let policy = PolicyConfig::parse(POLICY).unwrap();
+    let config = parse_config_str(
+        r#"
+[tools]
+node = "18"
+
+[settings]
+auto_install = true
+"#,
+    )
+    .unwrap();
+
+    let layers = vec![
+        ConfigLayer::from_policy(PathBuf::from("/etc/vx/policy.toml"), &policy),
+        ConfigLayer::from_config(ConfigOrigin::Project(PathBuf::from("vx.toml")), &config),
+    ];
+    let resolved = InheritanceManager::resolve_origins(&layers);
+    let get = |key: &str| resolved.iter().find(|r| r.key == key).unwrap();
+
+    // Locked by policy: project value is reported as overridden
+    let node = get("tools.node");
+    assert_eq!(node.value, "20.11.0");
+    assert!(node.locked);
+    assert!(matches!(node.origin, ConfigOrigin::Policy(_)));
+    assert_eq!(node.overridden.len(), 1);
+    assert_eq!(node.overridden[0].1, "18");
+
+    // Policy default: project wins
+    let auto_install = get("settings.auto_install");
+    assert_eq!(auto_install.value, "true");
+    assert!(!auto_install.locked);
+    assert!(matches!(auto_install.origin, ConfigOrigin::Project(_)));
+    assert_eq!(auto_install.overridden[0].1, "false");
+
+    // Only set by policy
+    assert_eq!(get("settings.isolation").value, "false");
+    assert!(get("providers.user_providers").locked);
+}
@@ -0,0 +1,130 @@
+//! Tests for the shareable preset registry
+
+use vx_config::{
+    InheritanceManager, PresetEntry, PresetIndex, extract_preset, index_location, preset_entry,
+    validate_preset_name,
+};
+
+const PROJECT: &str = r#"
+extends = "vx:python"
+
+[project]
+name = "trainer"
+
+[tools]
+python = "3.12"
+uv = "latest"
+
+[scripts]
+train = "python train.py"
+
+[services.mlflow]
+image = "ghcr.io/mlflow/mlflow:v2.17.0"
+ports = ["5000:5000"]
+"#;
+
+fn entry(name: &str, description: &str, tags: &[&str]) -> PresetEntry {
+    PresetEntry {
+        name: name.to_string(),
+        description: description.to_string(),
+        path: format!("presets/{}.toml", name),
+        sha256: "00".to_string(),
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        tools: Vec::new(),
+    }
+}
+
+#[test]
+fn test_index_location() {
+    assert_eq!(
+        index_location("github:acme/vx-presets"),
+        "https://raw.githubusercontent.com/acme/vx-presets/main/index.json"
+    );
+    assert_eq!(
+        index_location("https://presets.example.com/"),
+        "https://presets.example.com/index.json"
+    );
+    assert_eq!(
+        index_location("https://example.com/vx/presets.json"),
+        "https://example.com/vx/presets.json"
+    );
+    assert_eq!(index_location("../vx-presets"), "../vx-presets/index.json");
+}
+
+#[test]
+fn test_entry_location_is_relative_to_index() {
+    let e = entry("python-ml", "", &[]);
+    assert_eq!(
+        e.location("https://raw.githubusercontent.com/acme/vx-presets/main/index.json"),
+        "https://raw.githubusercontent.com/acme/vx-presets/main/presets/python-ml.toml"
+    );
+
+    let absolute = PresetEntry {
+        path: "https://cdn.example.com/python-ml.toml".to_string(),
+        ..e
+    };
+    assert_eq!(
+        absolute.location("/srv/presets/index.json"),
+        "https://cdn.example.com/python-ml.toml"
+    );
+}
+
+#[test]
+fn test_search_and_upsert() {
+    let mut index = PresetIndex::default();
+    index.upsert(entry(
+        "node-fullstack",
+        "Node with postgres",
+        &["node", "web"],
+    ));
+    index.upsert(entry("python-ml", "Python for ML", &["python", "ml"]));
+    index.upsert(entry(
+        "node-fullstack",
+        "Node, pnpm and postgres",
+        &["node"],
+    ));
+
+    assert_eq!(index.presets.len(), 2);
+    assert_eq!(index.presets[0].name, "node-fullstack");
+    assert_eq!(index.presets[0].description, "Node, pnpm and postgres");
+
+    let names =
+        |q: &str| -> Vec<String> { index.search(q).iter().map(|p| p.name.clone()).collect() };
+    assert_eq!(names(""), ["node-fullstack", "python-ml"]);
+    assert_eq!(names("ML"), ["python-ml"]);
+    assert_eq!(names("postgres"), ["node-fullstack"]);
+    assert!(names("rust").is_empty());
+
+    let parsed = PresetIndex::parse(&index.to_json()).unwrap();
+    assert_eq!(parsed, index);
+}
+
+#[test]
+fn test_extract_preset_keeps_shareable_sections() {
+    let content = extract_preset("python-ml", PROJECT).unwrap();
+    assert!(content.starts_with("# vx preset: python-ml"));
+    assert!(content.contains("[tools]"));
+    assert!(content.contains("[scripts]"));
+    assert!(content.contains("[services.mlflow]"));
+    assert!(!content.contains("[project]"));
+    assert!(!content.contains("extends"));
+
+    let entry = preset_entry("python-ml", "Python for ML", vec!["ml".into()], &content).unwrap();
+    assert_eq!(entry.path, "presets/python-ml.toml");
+    assert_eq!(entry.tools, ["python", "uv"]);
+    assert!(InheritanceManager::verify_hash(&content, &entry.sha256));
+}
+
+#[test]
+fn test_extract_preset_requires_content() {
+    assert!(extract_preset("empty", "[project]\nname = \"x\"\n").is_err());
+}
+
+#[test]
+fn test_validate_preset_name() {
+    assert!(validate_preset_name("python-ml").is_ok());
+    assert!(validate_preset_name("node22").is_ok());
+    assert!(validate_preset_name("").is_err());
+    assert!(validate_preset_name("Python").is_err());
+    assert!(validate_preset_name("../escape").is_err());
+}
@@ -3,7 +3,8 @@
 //! Tests for remote development environment configuration parsing.
 
 use rstest::rstest;
-use vx_config::parse_config_str;
+use serde_json::json;
+use vx_config::{DEFAULT_DEVCONTAINER_IMAGE, RemoteGenerator, parse_config_str};
 
 // ============================================
 // Remote Config Parsing Tests
@@ -254,3 +255,74 @@ machine = "{}"
 
     assert_eq!(codespaces.machine, Some(machine.to_string()));
 }
+
+// ============================================
+// vx Devcontainer Feature Tests
+// ============================================
+
+#[test]
+fn test_vx_devcontainer_uses_feature_and_setup() {
+    let config = parse_config_str(
+        r#"
+[project]
+name = "demo"
+
+[tools]
+node = "20"
+
+[remote.devcontainer]
+features = { "ghcr.io/devcontainers/features/docker-in-docker:2" = {} }
+"#,
+    )
+    .unwrap();
+    let devcontainer = config
+        .remote
+        .as_ref()
+        .unwrap()
+        .devcontainer
+        .clone()
+        .unwrap();
+
+    let result = RemoteGenerator::generate_vx_devcontainer(
+        &config,
+        &devcontainer,
+        "./vx",
+        json!({ "version": "0.9.0" }),
+    );
+    assert_eq!(result["image"], DEFAULT_DEVCONTAINER_IMAGE);
+    assert_eq!(result["features"]["./vx"], json!({ "version": "0.9.0" }));
+    assert!(
+        result["features"]
+            .get("ghcr.io/devcontainers/features/docker-in-docker:2")
+            .is_some()
+    );
+    // Tools come from vx, not from language features
+    assert!(
+        result["features"]
+            .get("ghcr.io/devcontainers/features/node:1")
+            .is_none()
+    );
+    assert_eq!(result["postCreateCommand"], "vx setup");
+}
+
+#[test]
+fn test_vx_feature_defaults_to_linux_tools() {
+    let config = parse_config_str(
+        r#"
+[tools]
+uv = "latest"
+node = "20"
+
+[tools.msvc]
+version = "14.42"
+os = ["windows"]
+"#,
+    )
+    .unwrap();
+
+    let feature = RemoteGenerator::generate_vx_feature(&config, "1.2.0");
+    assert_eq!(feature["id"], "vx");
+    assert_eq!(feature["version"], "1.2.0");
+    assert_eq!(feature["options"]["tools"]["default"], "node@20 uv@latest");
+    assert_eq!(feature["options"]["version"]["default"], "latest");
+}
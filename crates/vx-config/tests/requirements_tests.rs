@@ -0,0 +1,60 @@
+//! Tests for the `[requirements]` section
+
+use vx_config::{parse_config_str, parse_size};
+
+#[test]
+fn test_parse_requirements_section() {
+    let config = parse_config_str(
+        r#"
+[requirements]
+os = ["linux", "macos"]
+arch = ["x64"]
+min_disk_space = "10GB"
+system_packages = ["git"]
+network = ["https://registry.npmjs.org"]
+"#,
+    )
+    .unwrap();
+
+    let req = config.requirements.unwrap();
+    assert!(!req.is_empty());
+    assert!(req.os_supported("linux"));
+    assert!(req.os_supported("darwin"));
+    assert!(!req.os_supported("windows"));
+    assert!(req.arch_supported("x86_64"));
+    assert!(!req.arch_supported("aarch64"));
+    assert_eq!(
+        req.min_disk_space_bytes(),
+        Ok(Some(10 * 1024 * 1024 * 1024))
+    );
+    assert_eq!(req.system_packages, vec!["git"]);
+}
+
+#[test]
+fn test_empty_requirements_allow_everything() {
+    let config = parse_config_str("[requirements]\n").unwrap();
+    let req = config.requirements.unwrap();
+
+    assert!(req.is_empty());
+    assert!(req.os_supported("windows"));
+    assert!(req.arch_supported("aarch64"));
+    assert_eq!(req.min_disk_space_bytes(), Ok(None));
+}
+
+#[test]
+fn test_requires_gpu() {
+    let config = parse_config_str("[requirements]\nrequires_gpu = true\n").unwrap();
+    let req = config.requirements.unwrap();
+
+    assert!(req.requires_gpu);
+    assert!(!req.is_empty());
+}
+
+#[test]
+fn test_parse_size() {
+    assert_eq!(parse_size("2048"), Some(2048));
+    assert_eq!(parse_size("512 MiB"), Some(512 * 1024 * 1024));
+    assert_eq!(parse_size("1.5gb"), Some(1024 * 1024 * 1024 * 3 / 2));
+    assert_eq!(parse_size("10 parsecs"), None);
+    assert_eq!(parse_size("GB"), None);
+}
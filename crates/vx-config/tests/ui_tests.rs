@@ -0,0 +1,60 @@
+//! Tests for the `[ui]` section and `~/.vx/config/ui.toml`
+
+use vx_config::{ConfigLayer, ConfigOrigin, UiConfig, parse_config_str};
+
+#[test]
+fn test_parse_ui_section() {
+    let config = parse_config_str(
+        r#"
+[ui]
+theme = "screen-reader"
+emoji = false
+ascii_only = true
+"#,
+    )
+    .unwrap();
+
+    let ui = config.ui.clone().unwrap();
+    assert_eq!(ui.theme.as_deref(), Some("screen-reader"));
+    assert_eq!(ui.emoji, Some(false));
+    assert_eq!(ui.ascii_only, Some(true));
+
+    let layer = ConfigLayer::from_config(ConfigOrigin::Project("vx.toml".into()), &config);
+    assert_eq!(layer.values["ui.theme"], "screen-reader");
+    assert_eq!(layer.values["ui.emoji"], "false");
+}
+
+#[test]
+fn test_user_file_overrides_project_per_option() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let path = temp.path().join("ui.toml");
+    std::fs::write(&path, "[ui]\nemoji = false\n").unwrap();
+
+    let user = UiConfig::from_user_file(&path).unwrap();
+    let project = UiConfig {
+        theme: Some("high-contrast".to_string()),
+        emoji: Some(true),
+        ascii_only: None,
+    };
+    let merged = user.or(&project);
+    assert_eq!(merged.theme.as_deref(), Some("high-contrast"));
+    assert_eq!(merged.emoji, Some(false));
+    assert_eq!(merged.ascii_only, None);
+    assert_eq!(
+        merged.entries(),
+        [
+            ("ui.theme".to_string(), "high-contrast".to_string()),
+            ("ui.emoji".to_string(), "false".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_user_file_missing_or_invalid() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let path = temp.path().join("ui.toml");
+    assert!(UiConfig::from_user_file(&path).unwrap().is_empty());
+
+    std::fs::write(&path, "[ui]\nemoji = \"no\"\n").unwrap();
+    assert!(UiConfig::from_user_file(&path).is_err());
+}
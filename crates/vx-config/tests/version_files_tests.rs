@@ -0,0 +1,133 @@
+//! Tests for version files of other version managers
+
+use rstest::rstest;
+use vx_config::{
+    ConfigLayer, ConfigOrigin, InheritanceManager, find_version_file_pins, parse_version_file,
+    version_file_pins,
+};
+
+fn pins(file_name: &str, content: &str) -> Vec<(String, String)> {
+    parse_version_file(file_name, content)
+}
+
+fn pin(tool: &str, version: &str) -> Vec<(String, String)> {
+    vec![(tool.to_string(), version.to_string())]
+}
+
+#[rstest]
+#[case(".nvmrc", "v20.11.0\n", pin("node", "v20.11.0"))]
+#[case(".nvmrc", "# project node\nlts/iron\n", pin("node", "lts/iron"))]
+#[case(".nvmrc", "node\n", pin("node", "latest"))]
+#[case(".nvmrc", "system\n", vec![])]
+#[case(".node-version", "20\n", pin("node", "20"))]
+#[case(".python-version", "3.12.1\n3.11.7\n", pin("python", "3.12.1"))]
+#[case(".python-version", "my-virtualenv\n", vec![])]
+#[case(
+    "rust-toolchain.toml",
+    "[toolchain]\nchannel = \"1.83.0\"\ncomponents = [\"clippy\"]\n",
+    pin("rust", "1.83.0")
+)]
+#[case(
+    "rust-toolchain",
+    "nightly-2024-05-01\n",
+    pin("rust", "nightly-2024-05-01")
+)]
+#[case(
+    "rust-toolchain",
+    "[toolchain]\nchannel = \"stable\"\n",
+    pin("rust", "stable")
+)]
+#[case("Cargo.toml", "[package]\n", vec![])]
+fn test_parse_version_file(
+    #[case] file_name: &str,
+    #[case] content: &str,
+    #[case] expected: Vec<(String, String)>,
+) {
+    assert_eq!(pins(file_name, content), expected);
+}
+
+#[rstest]
+fn test_parse_tool_versions() {
+    let content = "\
+# asdf
+nodejs 20.11.0 18.19.0
+golang 1.22.0
+python system
+ruby ref:v3_3_0
+uv   0.5.0  # inline comment
+nodejs 16.0.0
+";
+    assert_eq!(
+        pins(".tool-versions", content),
+        vec![
+            ("node".to_string(), "20.11.0".to_string()),
+            ("go".to_string(), "1.22.0".to_string()),
+            ("uv".to_string(), "0.5.0".to_string()),
+        ]
+    );
+}
+
+#[rstest]
+fn test_nearest_directory_and_file_order_win() {
+    let root = tempfile::tempdir().unwrap();
+    let app = root.path().join("app");
+    std::fs::create_dir_all(&app).unwrap();
+    std::fs::write(root.path().join(".nvmrc"), "18\n").unwrap();
+    std::fs::write(root.path().join(".python-version"), "3.11\n").unwrap();
+    std::fs::write(app.join(".tool-versions"), "nodejs 22.1.0\n").unwrap();
+    std::fs::write(app.join(".node-version"), "20.11.0\n").unwrap();
+
+    let all = find_version_file_pins(&app);
+    let order: Vec<(String, String)> = all
+        .iter()
+        .map(|p| (p.file_name(), p.version.clone()))
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            (".node-version".to_string(), "20.11.0".to_string()),
+            (".tool-versions".to_string(), "22.1.0".to_string()),
+            (".nvmrc".to_string(), "18".to_string()),
+            (".python-version".to_string(), "3.11".to_string()),
+        ]
+    );
+
+    let applied = version_file_pins(&app);
+    assert_eq!(applied.len(), 2);
+    assert_eq!(applied[0].tool, "node");
+    assert_eq!(applied[0].version, "20.11.0");
+    assert_eq!(applied[1].tool, "python");
+}
+
+#[rstest]
+fn test_version_file_layers_explain_shadowed_pins() {
+    let root = tempfile::tempdir().unwrap();
+    std::fs::write(root.path().join(".nvmrc"), "20.11.0\n").unwrap();
+    std::fs::write(
+        root.path().join(".tool-versions"),
+        "nodejs 18.0.0\ngo 1.22.0\n",
+    )
+    .unwrap();
+
+    let layers = ConfigLayer::from_version_files(&find_version_file_pins(root.path()));
+    assert_eq!(layers.len(), 2);
+
+    let resolved = InheritanceManager::resolve_origins(&layers);
+    let node = resolved.iter().find(|r| r.key == "tools.node").unwrap();
+    assert_eq!(node.value, "20.11.0");
+    assert_eq!(
+        node.origin,
+        ConfigOrigin::VersionFile(root.path().join(".nvmrc"))
+    );
+    assert_eq!(
+        node.overridden,
+        vec![(
+            ConfigOrigin::VersionFile(root.path().join(".tool-versions")),
+            "18.0.0".to_string()
+        )]
+    );
+    assert_eq!(
+        node.origin.to_string(),
+        format!("version file ({})", root.path().join(".nvmrc").display())
+    );
+}
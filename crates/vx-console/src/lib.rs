@@ -11,8 +11,10 @@
 //! - **Progress**: Unified progress bars and spinners
 //! - **Terminal**: Cross-platform terminal detection and adaptation
 //! - **Interact**: Interactive input (confirm, password, select)
-//! - **Theme**: Customizable output themes
-//! - **Test**: Testing utilities for capturing output
+//! - **Theme**: Customizable output themes, including high-contrast and
+//!   screen-reader presets
+//! - **Test**: Testing utilities for capturing output, and snapshot tests
+//!   of it across verbosity, color and CI modes
 //! - **Task**: Task execution with timing statistics
 //!
 //! ## Quick Start
@@ -67,6 +69,7 @@
 mod format;
 mod output;
 mod shell;
+mod snapshot;
 mod style;
 mod task;
 mod term;
@@ -82,7 +85,10 @@ mod interact;
 pub use format::{CiOutput, JsonOutput, OutputMode};
 pub use output::{ColorChoice, ShellOut};
 pub use shell::{Shell, ShellBuilder, Verbosity};
-pub use style::{Color, Style, Theme, ThemeBuilder};
+pub use snapshot::{
+    Capture, CaptureMode, UPDATE_SNAPSHOTS_ENV, assert_snapshot_at, capture_all, normalize,
+};
+pub use style::{Color, Style, Theme, ThemeBuilder, global_theme, set_global_theme};
 pub use task::{TaskResult, TimedTask, format_bytes, format_duration, format_speed};
 pub use term::{CiEnvironment, Term, TermCapabilities, TerminalType};
 pub use test_support::{TestOutput, TestWriter};
@@ -402,6 +408,18 @@ impl ConsoleBuilder {
         self
     }
 
+    /// Build the console and make it the global one.
+    ///
+    /// Its theme also becomes the global theme used by progress bars and
+    /// by shells created afterwards.
+    pub fn init_global(self) {
+        let console = self.build();
+        set_global_theme(console.shell().theme().clone());
+        if let Ok(mut global) = GLOBAL_CONSOLE.write() {
+            *global = console;
+        }
+    }
+
     /// Build the console.
     pub fn build(self) -> Console {
         let mut shell_builder = Shell::builder();
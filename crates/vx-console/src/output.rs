@@ -47,7 +47,6 @@ fn is_ci_no_color() -> bool {
     false
 }
 
-/// Output destination for Shell.
 /// Output destination for Shell.
 pub enum ShellOut {
     /// Output to stdout/stderr streams.
@@ -59,6 +58,13 @@ pub enum ShellOut {
     },
     /// Output to a custom writer (for testing).
     Write(Mutex<Box<dyn Write + Send>>),
+    /// Output to a custom writer that honors the color choice (for snapshots).
+    ///
+    /// The writer is never a terminal, so `Auto` means no color.
+    Capture {
+        writer: Mutex<Box<dyn Write + Send>>,
+        color_choice: ColorChoice,
+    },
 }
 
 impl std::fmt::Debug for ShellOut {
@@ -74,6 +80,10 @@ impl std::fmt::Debug for ShellOut {
                 .field("color_choice", color_choice)
                 .finish(),
             ShellOut::Write(_) => f.debug_struct("Write").finish(),
+            ShellOut::Capture { color_choice, .. } => f
+                .debug_struct("Capture")
+                .field("color_choice", color_choice)
+                .finish(),
         }
     }
 }
@@ -90,17 +100,28 @@ impl ShellOut {
         }
     }
 
+    /// Create an output capturing into `writer`.
+    pub fn capture(writer: Box<dyn Write + Send>, color_choice: ColorChoice) -> Self {
+        ShellOut::Capture {
+            writer: Mutex::new(writer),
+            color_choice,
+        }
+    }
+
     /// Get the current color choice.
     pub fn color_choice(&self) -> ColorChoice {
         match self {
-            ShellOut::Stream { color_choice, .. } => *color_choice,
+            ShellOut::Stream { color_choice, .. } | ShellOut::Capture { color_choice, .. } => {
+                *color_choice
+            }
             ShellOut::Write(_) => ColorChoice::Never,
         }
     }
 
     /// Set the color choice.
     pub fn set_color_choice(&mut self, choice: ColorChoice) {
-        if let ShellOut::Stream { color_choice, .. } = self {
+        if let ShellOut::Stream { color_choice, .. } | ShellOut::Capture { color_choice, .. } = self
+        {
             *color_choice = choice;
         }
     }
@@ -113,6 +134,7 @@ impl ShellOut {
                 color_choice,
                 ..
             } => color_choice.should_use_color(*stderr_tty),
+            ShellOut::Capture { color_choice, .. } => *color_choice == ColorChoice::Always,
             ShellOut::Write(_) => false,
         }
     }
@@ -121,7 +143,7 @@ impl ShellOut {
     pub fn is_tty(&self) -> bool {
         match self {
             ShellOut::Stream { stderr_tty, .. } => *stderr_tty,
-            ShellOut::Write(_) => false,
+            ShellOut::Write(_) | ShellOut::Capture { .. } => false,
         }
     }
 
@@ -135,8 +157,8 @@ impl ShellOut {
                 write!(stderr, "{}", text)?;
                 stderr.flush()?;
             }
-            ShellOut::Write(w) => {
-                if let Ok(mut writer) = w.lock() {
+            ShellOut::Write(writer) | ShellOut::Capture { writer, .. } => {
+                if let Ok(mut writer) = writer.lock() {
                     write!(writer, "{}", text)?;
                     writer.flush()?;
                 }
@@ -155,8 +177,8 @@ impl ShellOut {
                 write!(stdout, "{}", text)?;
                 stdout.flush()?;
             }
-            ShellOut::Write(w) => {
-                if let Ok(mut writer) = w.lock() {
+            ShellOut::Write(writer) | ShellOut::Capture { writer, .. } => {
+                if let Ok(mut writer) = writer.lock() {
                     write!(writer, "{}", text)?;
                     writer.flush()?;
                 }
@@ -1,12 +1,24 @@
 //! Progress bars and spinners.
 //!
 //! This module provides unified progress reporting using indicatif.
-
-use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+//!
+//! Bars are drawn with the global theme's spinner and progress characters.
+//! When the theme isn't animated (see [`Theme::screen_reader`]), nothing is
+//! redrawn in place: each spinner or bar prints one status line to stderr
+//! when it starts and one when it finishes.
+
+use crate::snapshot::capture_line;
+use crate::style::{Theme, global_theme};
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
 use once_cell::sync::Lazy;
+use std::ops::Deref;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+/// Template of download bars
+const DOWNLOAD_TEMPLATE: &str =
+    "  {spinner:.green} {msg} {wide_bar:.cyan/blue} {bytes}/{total_bytes} ({bytes_per_sec}, {eta})";
+
 /// Global progress manager singleton.
 ///
 /// All progress bars and text output should go through this instance to avoid
@@ -48,6 +60,128 @@ pub fn eprintln_status_above_bars(message: impl AsRef<str>) {
     GLOBAL_PROGRESS_MANAGER.println_status(message.as_ref());
 }
 
+/// A progress bar, or the status lines standing in for it
+#[derive(Debug, Clone)]
+struct Bar {
+    bar: ProgressBar,
+    linear: bool,
+}
+
+impl Bar {
+    /// Show `bar` with `template`, under `multi` when given
+    ///
+    /// With a non-animated theme the bar stays hidden and `line` is printed
+    /// instead.
+    fn new(
+        bar: ProgressBar,
+        multi: Option<&MultiProgress>,
+        template: &str,
+        tick: Option<Duration>,
+        message: String,
+        line: String,
+    ) -> Self {
+        let theme = global_theme();
+        let linear = !theme.is_animated();
+        let bar = if linear {
+            bar.set_draw_target(ProgressDrawTarget::hidden());
+            eprint_line(&format!("  {}", line));
+            bar
+        } else {
+            let bar = match multi {
+                Some(multi) => multi.add(bar),
+                None => bar,
+            };
+            bar.set_style(progress_style(template, &theme));
+            if let Some(tick) = tick {
+                bar.enable_steady_tick(tick);
+            }
+            bar
+        };
+        bar.set_message(message);
+        Self { bar, linear }
+    }
+
+    /// A spinner showing `label` before its message
+    fn spinner(multi: Option<&MultiProgress>, label: &str, message: String) -> Self {
+        let line = format!("{}{}", label, message);
+        let template = format!("  {{spinner:.green}} {}{{msg}}", label);
+        Self::new(
+            ProgressBar::new_spinner(),
+            multi,
+            &template,
+            Some(Duration::from_millis(80)),
+            message,
+            line,
+        )
+    }
+
+    /// Print `message` as a status line when not animated
+    fn status(&self, message: &str) {
+        if self.linear {
+            eprint_line(&format!("  {}", message));
+        }
+    }
+
+    fn finish_with_message(&self, message: String) {
+        if self.linear {
+            self.bar.finish();
+            eprint_line(&format!("  {}", message));
+        } else {
+            self.bar.finish_with_message(message);
+        }
+    }
+
+    /// Finish with the theme's success or error prefix before `message`
+    fn finish_outcome(&self, success: bool, message: &str) {
+        let theme = global_theme();
+        let (style, prefix) = if success {
+            (theme.success_style(), theme.success_prefix())
+        } else {
+            (theme.error_style(), theme.error_prefix())
+        };
+        if !self.linear {
+            self.bar.set_style(
+                ProgressStyle::with_template("  {msg}").expect("invalid progress template"),
+            );
+        }
+        let prefix = if console::colors_enabled_stderr() {
+            style.apply(prefix)
+        } else {
+            prefix.to_string()
+        };
+        self.finish_with_message(format!("{} {}", prefix, message));
+    }
+}
+
+impl Deref for Bar {
+    type Target = ProgressBar;
+
+    fn deref(&self) -> &ProgressBar {
+        &self.bar
+    }
+}
+
+/// Print a status line to stderr, or into the active capture
+fn eprint_line(line: &str) {
+    if !capture_line(line) {
+        eprintln!("{}", line);
+    }
+}
+
+/// Style for `template` with the theme's spinner and progress characters
+fn progress_style(template: &str, theme: &Theme) -> ProgressStyle {
+    let mut ticks: Vec<&str> = theme.spinner_chars.iter().map(String::as_str).collect();
+    // Shown once the bar has finished
+    ticks.push(theme.symbol("✓", "*"));
+    if ticks.len() < 2 {
+        ticks.insert(0, " ");
+    }
+    ProgressStyle::with_template(template)
+        .expect("invalid progress template")
+        .tick_strings(&ticks)
+        .progress_chars(&theme.progress_chars)
+}
+
 /// Progress manager for handling multiple progress bars.
 #[derive(Debug)]
 pub struct ProgressManager {
@@ -72,17 +206,10 @@ impl ProgressManager {
 
     /// Add a spinner with the given message.
     pub fn add_spinner(&self, message: &str) -> ManagedSpinner {
-        let bar = self.multi.add(ProgressBar::new_spinner());
-        bar.set_style(
-            ProgressStyle::with_template("  {spinner:.green} {msg}")
-                .expect("invalid progress template")
-                .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏", "✓"]),
-        );
-        bar.set_message(message.to_string());
-        bar.enable_steady_tick(Duration::from_millis(80));
+        let bar = Bar::spinner(Some(&self.multi), "", message.to_string());
 
         if let Ok(mut bars) = self.active_bars.lock() {
-            bars.push(bar.clone());
+            bars.push(bar.bar.clone());
         }
 
         ManagedSpinner {
@@ -93,19 +220,17 @@ impl ProgressManager {
 
     /// Add a download progress bar.
     pub fn add_download(&self, total_size: u64, message: &str) -> ManagedDownload {
-        let bar = self.multi.add(ProgressBar::new(total_size));
-        bar.set_style(
-            ProgressStyle::with_template(
-                "  {spinner:.green} {msg} {wide_bar:.cyan/blue} {bytes}/{total_bytes} ({bytes_per_sec}, {eta})"
-            )
-            .expect("invalid progress template")
-            .progress_chars("━━╺"),
+        let bar = Bar::new(
+            ProgressBar::new(total_size),
+            Some(&self.multi),
+            DOWNLOAD_TEMPLATE,
+            Some(Duration::from_millis(100)),
+            message.to_string(),
+            message.to_string(),
         );
-        bar.set_message(message.to_string());
-        bar.enable_steady_tick(Duration::from_millis(100));
 
         if let Ok(mut bars) = self.active_bars.lock() {
-            bars.push(bar.clone());
+            bars.push(bar.bar.clone());
         }
 
         ManagedDownload { bar }
@@ -113,19 +238,17 @@ impl ProgressManager {
 
     /// Add a task progress bar.
     pub fn add_task(&self, total: u64, message: &str) -> ManagedTask {
-        let bar = self.multi.add(ProgressBar::new(total));
-        bar.set_style(
-            ProgressStyle::with_template(
-                "  {spinner:.green} {msg} [{bar:40.cyan/blue}] {pos}/{len}",
-            )
-            .expect("invalid progress template")
-            .progress_chars("━━╺"),
+        let bar = Bar::new(
+            ProgressBar::new(total),
+            Some(&self.multi),
+            "  {spinner:.green} {msg} [{bar:40.cyan/blue}] {pos}/{len}",
+            Some(Duration::from_millis(100)),
+            message.to_string(),
+            message.to_string(),
         );
-        bar.set_message(message.to_string());
-        bar.enable_steady_tick(Duration::from_millis(100));
 
         if let Ok(mut bars) = self.active_bars.lock() {
-            bars.push(bar.clone());
+            bars.push(bar.bar.clone());
         }
 
         ManagedTask { bar }
@@ -161,6 +284,9 @@ impl ProgressManager {
         // Use suspend to ensure the message is printed correctly on all terminals,
         // especially Windows where MultiProgress::println can have issues with
         // cursor positioning and message interleaving.
+        if capture_line(message) {
+            return;
+        }
         self.multi.suspend(|| {
             println!("{}", message);
         });
@@ -172,6 +298,9 @@ impl ProgressManager {
     /// that should NOT appear in the stdout of the proxied command.
     /// Normal UI messages (version info, listings) should use `println` instead.
     pub fn println_status(&self, message: &str) {
+        if capture_line(message) {
+            return;
+        }
         self.multi.suspend(|| {
             eprintln!("{}", message);
         });
@@ -181,7 +310,7 @@ impl ProgressManager {
 /// A managed spinner that auto-removes from the manager.
 #[derive(Debug)]
 pub struct ManagedSpinner {
-    bar: ProgressBar,
+    bar: Bar,
     message: String,
 }
 
@@ -194,6 +323,7 @@ impl ManagedSpinner {
     /// Set the spinner message.
     pub fn set_message(&self, message: &str) {
         self.bar.set_message(message.to_string());
+        self.bar.status(message);
     }
 
     /// Finish with a message.
@@ -208,31 +338,19 @@ impl ManagedSpinner {
 
     /// Finish with a success message.
     pub fn finish_success(&self, message: &str) {
-        self.bar.set_style(
-            ProgressStyle::with_template("  {spinner:.green} {msg}")
-                .expect("invalid progress template")
-                .tick_strings(&["✓"]),
-        );
-        self.bar
-            .finish_with_message(format!("\x1b[32m✓\x1b[0m {}", message));
+        self.bar.finish_outcome(true, message);
     }
 
     /// Finish with an error message.
     pub fn finish_error(&self, message: &str) {
-        self.bar.set_style(
-            ProgressStyle::with_template("  {spinner:.red} {msg}")
-                .expect("invalid progress template")
-                .tick_strings(&["✗"]),
-        );
-        self.bar
-            .finish_with_message(format!("\x1b[31m✗\x1b[0m {}", message));
+        self.bar.finish_outcome(false, message);
     }
 }
 
 /// A managed download progress bar.
 #[derive(Debug)]
 pub struct ManagedDownload {
-    bar: ProgressBar,
+    bar: Bar,
 }
 
 impl ManagedDownload {
@@ -270,7 +388,7 @@ impl ManagedDownload {
 /// A managed task progress bar.
 #[derive(Debug)]
 pub struct ManagedTask {
-    bar: ProgressBar,
+    bar: Bar,
 }
 
 impl ManagedTask {
@@ -303,52 +421,35 @@ impl ManagedTask {
 /// Simple spinner (standalone, not managed).
 #[derive(Debug)]
 pub struct ProgressSpinner {
-    bar: ProgressBar,
+    bar: Bar,
 }
 
 impl ProgressSpinner {
     /// Create a new spinner.
     pub fn new(message: &str) -> Self {
-        let bar = ProgressBar::new_spinner();
-        bar.set_style(
-            ProgressStyle::with_template("  {spinner:.green} {msg}")
-                .expect("invalid progress template")
-                .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏", "✓"]),
-        );
-        bar.set_message(message.to_string());
-        bar.enable_steady_tick(Duration::from_millis(80));
-        Self { bar }
+        Self {
+            bar: Bar::spinner(None, "", message.to_string()),
+        }
     }
 
     /// Create a download spinner.
     pub fn new_download(message: &str) -> Self {
-        let bar = ProgressBar::new_spinner();
-        bar.set_style(
-            ProgressStyle::with_template("  {spinner:.green} Downloading {msg}")
-                .expect("invalid progress template")
-                .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏", "✓"]),
-        );
-        bar.set_message(message.to_string());
-        bar.enable_steady_tick(Duration::from_millis(80));
-        Self { bar }
+        Self {
+            bar: Bar::spinner(None, "Downloading ", message.to_string()),
+        }
     }
 
     /// Create an install spinner.
     pub fn new_install(message: &str) -> Self {
-        let bar = ProgressBar::new_spinner();
-        bar.set_style(
-            ProgressStyle::with_template("  {spinner:.green} Installing {msg}")
-                .expect("invalid progress template")
-                .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏", "✓"]),
-        );
-        bar.set_message(message.to_string());
-        bar.enable_steady_tick(Duration::from_millis(80));
-        Self { bar }
+        Self {
+            bar: Bar::spinner(None, "Installing ", message.to_string()),
+        }
     }
 
     /// Set the message.
     pub fn set_message(&self, message: &str) {
         self.bar.set_message(message.to_string());
+        self.bar.status(message);
     }
 
     /// Finish with a message.
@@ -363,48 +464,43 @@ impl ProgressSpinner {
 
     /// Finish with an error.
     pub fn finish_with_error(&self, message: &str) {
-        self.bar.set_style(
-            ProgressStyle::with_template("  {spinner:.red} {msg}")
-                .expect("invalid progress template")
-                .tick_strings(&["✗"]),
-        );
-        self.bar
-            .finish_with_message(format!("\x1b[31m✗\x1b[0m {}", message));
+        self.bar.finish_outcome(false, message);
     }
 }
 
 /// Download progress bar (standalone).
 #[derive(Debug)]
 pub struct DownloadProgress {
-    bar: ProgressBar,
+    bar: Bar,
 }
 
 impl DownloadProgress {
     /// Create a new download progress bar.
     pub fn new(total_size: u64, message: &str) -> Self {
-        let bar = ProgressBar::new(total_size);
-        bar.set_style(
-            ProgressStyle::with_template(
-                "  {spinner:.green} {msg} {wide_bar:.cyan/blue} {bytes}/{total_bytes} ({bytes_per_sec}, {eta})"
-            )
-            .expect("static progress template")
-            .progress_chars("━━╺"),
-        );
-        bar.set_message(message.to_string());
-        bar.enable_steady_tick(Duration::from_millis(100));
-        Self { bar }
+        Self {
+            bar: Bar::new(
+                ProgressBar::new(total_size),
+                None,
+                DOWNLOAD_TEMPLATE,
+                Some(Duration::from_millis(100)),
+                message.to_string(),
+                message.to_string(),
+            ),
+        }
     }
 
     /// Create a download progress bar with unknown size.
     pub fn new_unknown(message: &str) -> Self {
-        let bar = ProgressBar::new_spinner();
-        bar.set_style(
-            ProgressStyle::with_template("  {spinner:.green} {msg} {bytes} ({bytes_per_sec})")
-                .expect("invalid progress template"),
-        );
-        bar.set_message(message.to_string());
-        bar.enable_steady_tick(Duration::from_millis(100));
-        Self { bar }
+        Self {
+            bar: Bar::new(
+                ProgressBar::new_spinner(),
+                None,
+                "  {spinner:.green} {msg} {bytes} ({bytes_per_sec})",
+                Some(Duration::from_millis(100)),
+                message.to_string(),
+                message.to_string(),
+            ),
+        }
     }
 
     /// Set the total size.
@@ -443,25 +539,23 @@ impl DownloadProgress {
 pub struct MultiStepProgress {
     steps: Vec<String>,
     current: usize,
-    bar: ProgressBar,
+    bar: Bar,
 }
 
 impl MultiStepProgress {
     /// Create a new multi-step progress.
     pub fn new(steps: Vec<String>) -> Self {
         let total = steps.len() as u64;
-        let bar = ProgressBar::new(total);
-        bar.set_style(
-            ProgressStyle::with_template("  {spinner:.green} [{pos}/{len}] {msg}")
-                .expect("invalid progress template")
-                .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏", "✓"]),
+        let first = steps.first().cloned().unwrap_or_default();
+        let bar = Bar::new(
+            ProgressBar::new(total),
+            None,
+            "  {spinner:.green} [{pos}/{len}] {msg}",
+            Some(Duration::from_millis(80)),
+            first.clone(),
+            format!("[1/{}] {}", total, first),
         );
 
-        if let Some(first) = steps.first() {
-            bar.set_message(first.clone());
-        }
-        bar.enable_steady_tick(Duration::from_millis(80));
-
         Self {
             steps,
             current: 0,
@@ -475,6 +569,12 @@ impl MultiStepProgress {
         self.bar.inc(1);
         if let Some(step) = self.steps.get(self.current) {
             self.bar.set_message(step.clone());
+            self.bar.status(&format!(
+                "[{}/{}] {}",
+                self.current + 1,
+                self.steps.len(),
+                step
+            ));
         }
     }
 
@@ -488,8 +588,8 @@ impl MultiStepProgress {
 #[derive(Debug)]
 pub struct InstallProgress {
     multi: MultiProgress,
-    main_bar: ProgressBar,
-    current_bar: Option<ProgressBar>,
+    main_bar: Bar,
+    current_bar: Option<Bar>,
     completed: u64,
 }
 
@@ -497,13 +597,14 @@ impl InstallProgress {
     /// Create a new install progress.
     pub fn new(total_tools: usize, title: &str) -> Self {
         let multi = MultiProgress::new();
-        let main_bar = multi.add(ProgressBar::new(total_tools as u64));
-        main_bar.set_style(
-            ProgressStyle::with_template("{msg} [{bar:40.cyan/blue}] {pos}/{len}")
-                .expect("invalid progress template")
-                .progress_chars("━━╺"),
+        let main_bar = Bar::new(
+            ProgressBar::new(total_tools as u64),
+            Some(&multi),
+            "{msg} [{bar:40.cyan/blue}] {pos}/{len}",
+            None,
+            title.to_string(),
+            title.to_string(),
         );
-        main_bar.set_message(title.to_string());
 
         Self {
             multi,
@@ -522,14 +623,11 @@ impl InstallProgress {
             bar.finish_and_clear();
         }
 
-        let bar = self.multi.add(ProgressBar::new_spinner());
-        bar.set_style(
-            ProgressStyle::with_template("  {spinner:.green} Installing {msg}")
-                .expect("invalid progress template")
-                .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏", "✓"]),
+        let bar = Bar::spinner(
+            Some(&self.multi),
+            "Installing ",
+            format!("{}@{}", tool_name, version),
         );
-        bar.set_message(format!("{}@{}", tool_name, version));
-        bar.enable_steady_tick(Duration::from_millis(80));
         self.current_bar = Some(bar);
     }
 
@@ -538,11 +636,7 @@ impl InstallProgress {
     /// The `tool_name` and `version` are shown in the finished bar message.
     pub fn complete_tool(&mut self, success: bool, tool_name: &str, version: &str) {
         if let Some(bar) = self.current_bar.take() {
-            if success {
-                bar.finish_with_message(format!("\x1b[32m✓\x1b[0m {}@{}", tool_name, version));
-            } else {
-                bar.finish_with_message(format!("\x1b[31m✗\x1b[0m {}@{}", tool_name, version));
-            }
+            bar.finish_outcome(success, &format!("{}@{}", tool_name, version));
         }
         self.completed += 1;
         self.main_bar.set_position(self.completed);
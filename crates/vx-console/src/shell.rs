@@ -6,7 +6,7 @@
 use crate::Result;
 use crate::format::OutputMode;
 use crate::output::{ColorChoice, ShellOut};
-use crate::style::{Color, Style, Theme};
+use crate::style::{Color, Style, Theme, global_theme};
 use crate::term::Term;
 
 #[cfg(feature = "progress")]
@@ -55,7 +55,7 @@ impl Shell {
         Self {
             output: ShellOut::stream(),
             verbosity: Verbosity::Normal,
-            theme: Theme::default(),
+            theme: global_theme(),
             needs_clear: false,
             output_mode: OutputMode::Standard,
             #[cfg(feature = "progress")]
@@ -438,7 +438,7 @@ impl ShellBuilder {
         Shell {
             output,
             verbosity: self.verbosity.unwrap_or_default(),
-            theme: self.theme.unwrap_or_default(),
+            theme: self.theme.unwrap_or_else(global_theme),
             needs_clear: false,
             output_mode,
             #[cfg(feature = "progress")]
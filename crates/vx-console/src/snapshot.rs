@@ -0,0 +1,412 @@
+//! Snapshot testing of console output.
+//!
+//! A [`Capture`] records what vx-console prints on the current thread: the
+//! lines of its [`Shell`](Capture::shell), [`ProgressManager::println`] and
+//! `println_status`, and the status lines of spinners and progress bars.
+//! [`capture_all`] runs the same code once per [`CaptureMode`] and
+//! [`assert_snapshot!`](crate::assert_snapshot) compares the result with a
+//! file under `tests/snapshots/`.
+//!
+//! ```rust,no_run
+//! use vx_console::{CaptureMode, assert_snapshot, capture_all};
+//!
+//! let output = capture_all(&CaptureMode::all(), |capture| {
+//!     let shell = capture.shell();
+//!     shell.info("Installing node@20.10.0").unwrap();
+//!     shell.success("Installed node@20.10.0").unwrap();
+//! });
+//! assert_snapshot!("install_node", output);
+//! ```
+//!
+//! Set `VX_UPDATE_SNAPSHOTS=1` to rewrite the snapshot files after an
+//! intended change. A missing snapshot is written on first run, except in
+//! CI (`CI` is set), where it fails the test.
+//!
+//! [`ProgressManager::println`]: crate::ProgressManager::println
+
+use crate::format::OutputMode;
+use crate::output::{ColorChoice, ShellOut};
+use crate::shell::{Shell, Verbosity};
+use crate::style::{Theme, global_theme, set_global_theme};
+use crate::test_support::{TestOutput, TestWriter};
+
+use std::cell::RefCell;
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+
+/// Environment variable that makes snapshot assertions rewrite the files.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "VX_UPDATE_SNAPSHOTS";
+
+/// Serializes captures, which change the global theme.
+static CAPTURE_LOCK: Mutex<()> = Mutex::new(());
+
+thread_local! {
+    /// Output of the active capture on this thread.
+    static ACTIVE: RefCell<Option<TestOutput>> = const { RefCell::new(None) };
+}
+
+/// Send `line` to the active capture of this thread.
+///
+/// Returns `false` when nothing is capturing, so the caller prints it.
+#[cfg_attr(not(feature = "progress"), allow(dead_code))]
+pub(crate) fn capture_line(line: &str) -> bool {
+    ACTIVE.with(|active| match active.borrow().as_ref() {
+        Some(output) => {
+            for line in line.split('\n') {
+                output.write(line);
+            }
+            true
+        }
+        None => false,
+    })
+}
+
+/// Output settings a capture runs under.
+#[derive(Debug, Clone)]
+pub struct CaptureMode {
+    /// Name of the mode, used as section header by [`capture_all`].
+    pub name: String,
+    /// Verbosity of the capture shell.
+    pub verbosity: Verbosity,
+    /// Whether ANSI colors are written.
+    pub color: bool,
+    /// Output mode of the capture shell.
+    pub output_mode: OutputMode,
+    /// Theme, also made the global theme while capturing.
+    ///
+    /// Captures never animate, so spinners and progress bars show up as
+    /// their status lines.
+    pub theme: Theme,
+}
+
+impl CaptureMode {
+    /// Create a mode with normal verbosity, no color and the default theme.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            verbosity: Verbosity::Normal,
+            color: false,
+            output_mode: OutputMode::Standard,
+            theme: Theme::default(),
+        }
+    }
+
+    /// Plain output, as seen when piped.
+    pub fn plain() -> Self {
+        Self::new("plain")
+    }
+
+    /// Colored output, as seen in a terminal.
+    pub fn color() -> Self {
+        Self {
+            color: true,
+            ..Self::new("color")
+        }
+    }
+
+    /// Verbose output (`--verbose`).
+    pub fn verbose() -> Self {
+        Self {
+            verbosity: Verbosity::Verbose,
+            output_mode: OutputMode::Verbose,
+            ..Self::new("verbose")
+        }
+    }
+
+    /// Quiet output (`--quiet`).
+    pub fn quiet() -> Self {
+        Self {
+            verbosity: Verbosity::Quiet,
+            output_mode: OutputMode::Quiet,
+            ..Self::new("quiet")
+        }
+    }
+
+    /// CI output with the GitHub Actions theme.
+    pub fn ci() -> Self {
+        Self {
+            output_mode: OutputMode::Ci,
+            theme: Theme::github(),
+            ..Self::new("ci")
+        }
+    }
+
+    /// All preset modes: plain, color, verbose, quiet and CI.
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::plain(),
+            Self::color(),
+            Self::verbose(),
+            Self::quiet(),
+            Self::ci(),
+        ]
+    }
+
+    /// Set the theme.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    fn color_choice(&self) -> ColorChoice {
+        if self.color {
+            ColorChoice::Always
+        } else {
+            ColorChoice::Never
+        }
+    }
+}
+
+/// Records console output on the current thread until dropped.
+///
+/// Only one capture runs at a time per process: starting one waits for the
+/// others to finish, and starting a second one on the same thread
+/// deadlocks.
+pub struct Capture {
+    mode: CaptureMode,
+    output: TestOutput,
+    previous_theme: Theme,
+    previous_colors: (bool, bool),
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl std::fmt::Debug for Capture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Capture")
+            .field("mode", &self.mode.name)
+            .field("lines", &self.output.len())
+            .finish()
+    }
+}
+
+impl Capture {
+    /// Start capturing under `mode`.
+    pub fn start(mode: CaptureMode) -> Self {
+        let lock = CAPTURE_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let previous_theme = global_theme();
+        let mut theme = mode.theme.clone();
+        theme.animated = false;
+        set_global_theme(theme);
+
+        let previous_colors = (console::colors_enabled(), console::colors_enabled_stderr());
+        console::set_colors_enabled(mode.color);
+        console::set_colors_enabled_stderr(mode.color);
+
+        let output = TestOutput::new();
+        ACTIVE.with(|active| *active.borrow_mut() = Some(output.clone()));
+
+        Self {
+            mode,
+            output,
+            previous_theme,
+            previous_colors,
+            _lock: lock,
+        }
+    }
+
+    /// The mode of this capture.
+    pub fn mode(&self) -> &CaptureMode {
+        &self.mode
+    }
+
+    /// The captured lines so far.
+    pub fn output(&self) -> &TestOutput {
+        &self.output
+    }
+
+    /// A shell configured by the mode, writing into this capture.
+    pub fn shell(&self) -> Shell {
+        let mut theme = self.mode.theme.clone();
+        theme.animated = false;
+        Shell::builder()
+            .output(ShellOut::capture(
+                Box::new(TestWriter::new(self.output.clone())),
+                self.mode.color_choice(),
+            ))
+            .verbosity(self.mode.verbosity)
+            .output_mode(self.mode.output_mode)
+            .theme(theme)
+            .build()
+    }
+
+    /// Stop capturing and return the output, normalized by [`normalize`].
+    pub fn finish(self) -> String {
+        normalize(&self.output.output())
+    }
+}
+
+impl Drop for Capture {
+    fn drop(&mut self) {
+        ACTIVE.with(|active| *active.borrow_mut() = None);
+        console::set_colors_enabled(self.previous_colors.0);
+        console::set_colors_enabled_stderr(self.previous_colors.1);
+        set_global_theme(self.previous_theme.clone());
+    }
+}
+
+/// Run `f` once per mode and join the outputs under `== <mode> ==` headers.
+pub fn capture_all(modes: &[CaptureMode], mut f: impl FnMut(&Capture)) -> String {
+    let mut sections = Vec::with_capacity(modes.len());
+    for mode in modes {
+        let header = format!("== {} ==", mode.name);
+        let capture = Capture::start(mode.clone());
+        f(&capture);
+        let output = capture.finish();
+        sections.push(if output.is_empty() {
+            header
+        } else {
+            format!("{}\n{}", header, output)
+        });
+    }
+    sections.join("\n")
+}
+
+/// Make terminal control sequences visible.
+///
+/// SGR sequences become `<1;32>` and `</>` (reset), other escape sequences
+/// `<ESC[K>`, and carriage returns `<CR>`.
+pub fn normalize(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' if chars.peek() == Some(&'[') => {
+                chars.next();
+                let mut params = String::new();
+                let mut terminator = None;
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        terminator = Some(c);
+                        break;
+                    }
+                    params.push(c);
+                }
+                match terminator {
+                    Some('m') if params.is_empty() || params == "0" => result.push_str("</>"),
+                    Some('m') => {
+                        result.push('<');
+                        result.push_str(&params);
+                        result.push('>');
+                    }
+                    other => {
+                        result.push_str("<ESC[");
+                        result.push_str(&params);
+                        result.extend(other);
+                        result.push('>');
+                    }
+                }
+            }
+            '\x1b' => result.push_str("<ESC>"),
+            '\r' => result.push_str("<CR>"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// Compare `actual` with the snapshot file at `path`.
+///
+/// Used by [`assert_snapshot!`](crate::assert_snapshot).
+///
+/// # Panics
+///
+/// Panics with a line diff when they differ, or when the file is missing
+/// in CI. With `VX_UPDATE_SNAPSHOTS=1` the file is rewritten instead.
+#[track_caller]
+pub fn assert_snapshot_at(path: &Path, actual: &str) {
+    let actual = actual.trim_end_matches('\n');
+    let update = std::env::var(UPDATE_SNAPSHOTS_ENV).is_ok_and(|v| v == "1" || v == "true");
+
+    let expected = match std::fs::read_to_string(path) {
+        Ok(content) => content.replace("\r\n", "\n"),
+        Err(_) if !update && std::env::var_os("CI").is_some() => panic!(
+            "Snapshot {} is missing; run the test locally and commit it",
+            path.display()
+        ),
+        Err(_) => {
+            write_snapshot(path, actual);
+            eprintln!("Created snapshot {}", path.display());
+            return;
+        }
+    };
+    let expected = expected.trim_end_matches('\n');
+    if expected == actual {
+        return;
+    }
+    if update {
+        write_snapshot(path, actual);
+        eprintln!("Updated snapshot {}", path.display());
+        return;
+    }
+    panic!(
+        "Snapshot {} does not match (- expected, + actual):\n{}\nRun with {}=1 to update it.",
+        path.display(),
+        diff_lines(expected, actual),
+        UPDATE_SNAPSHOTS_ENV
+    );
+}
+
+fn write_snapshot(path: &Path, content: &str) {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .unwrap_or_else(|e| panic!("Failed to create {}: {}", parent.display(), e));
+    }
+    std::fs::write(path, format!("{}\n", content))
+        .unwrap_or_else(|e| panic!("Failed to write {}: {}", path.display(), e));
+}
+
+/// Line diff of `expected` and `actual`, based on their longest common
+/// subsequence.
+fn diff_lines(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // lcs[i][j]: common lines of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            diff.push(format!("  {}", old[i]));
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] > lcs[i + 1][j]) {
+            diff.push(format!("+ {}", new[j]));
+            j += 1;
+        } else {
+            diff.push(format!("- {}", old[i]));
+            i += 1;
+        }
+    }
+    diff.join("\n")
+}
+
+/// Assert that a string matches the snapshot `tests/snapshots/<name>.snap`
+/// of the calling crate.
+///
+/// See [`assert_snapshot_at`] for how snapshots are created and updated.
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $actual:expr $(,)?) => {
+        $crate::assert_snapshot_at(
+            &::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("snapshots")
+                .join(format!("{}.snap", $name)),
+            ::std::convert::AsRef::<str>::as_ref(&$actual),
+        )
+    };
+}
@@ -2,8 +2,32 @@
 //!
 //! This module provides styling primitives using anstyle for zero-overhead
 //! style definitions.
+//!
+//! Output helpers that don't own a [`Shell`](crate::Shell), such as the
+//! progress bars, draw with the global theme; set it with
+//! [`set_global_theme`] or [`ConsoleBuilder::init_global`](crate::ConsoleBuilder::init_global).
 
+use once_cell::sync::Lazy;
 use std::fmt;
+use std::sync::RwLock;
+
+/// Theme used by shells and progress bars created without an explicit one.
+static GLOBAL_THEME: Lazy<RwLock<Theme>> = Lazy::new(|| RwLock::new(Theme::default()));
+
+/// Get a copy of the global theme.
+pub fn global_theme() -> Theme {
+    GLOBAL_THEME
+        .read()
+        .map(|theme| theme.clone())
+        .unwrap_or_default()
+}
+
+/// Replace the global theme.
+pub fn set_global_theme(theme: Theme) {
+    if let Ok(mut global) = GLOBAL_THEME.write() {
+        *global = theme;
+    }
+}
 
 /// ANSI colors.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -205,6 +229,13 @@ pub struct Theme {
     pub spinner_chars: Vec<String>,
     /// Progress bar characters.
     pub progress_chars: String,
+    /// Whether emoji may appear in output.
+    pub emoji: bool,
+    /// Whether output is restricted to ASCII characters.
+    pub ascii_only: bool,
+    /// Whether spinners and progress bars are animated; otherwise they are
+    /// printed as one status line when they start and finish.
+    pub animated: bool,
 }
 
 impl Default for Theme {
@@ -235,11 +266,29 @@ impl Default for Theme {
                 "⠏".to_string(),
             ],
             progress_chars: "━━╺".to_string(),
+            emoji: true,
+            ascii_only: false,
+            animated: true,
         }
     }
 }
 
 impl Theme {
+    /// Names accepted by [`Theme::named`].
+    pub const NAMES: &'static [&'static str] = &[
+        "auto",
+        "default",
+        "minimal",
+        "colorful",
+        "github",
+        "cargo",
+        "npm",
+        "emoji",
+        "monochrome",
+        "high-contrast",
+        "screen-reader",
+    ];
+
     /// Create a new theme builder.
     pub fn builder() -> ThemeBuilder {
         ThemeBuilder::default()
@@ -267,6 +316,9 @@ impl Theme {
                 "\\".to_string(),
             ],
             progress_chars: "=>-".to_string(),
+            emoji: false,
+            ascii_only: true,
+            animated: true,
         }
     }
 
@@ -324,6 +376,9 @@ impl Theme {
                 "⠏".to_string(),
             ],
             progress_chars: "━━╺".to_string(),
+            emoji: true,
+            ascii_only: false,
+            animated: true,
         }
     }
 
@@ -355,6 +410,9 @@ impl Theme {
                 "⠏".to_string(),
             ],
             progress_chars: "█░░".to_string(),
+            emoji: true,
+            ascii_only: false,
+            animated: true,
         }
     }
 
@@ -388,6 +446,9 @@ impl Theme {
                 "🕛".to_string(),
             ],
             progress_chars: "🟩🟩⬜".to_string(),
+            emoji: true,
+            ascii_only: false,
+            animated: true,
         }
     }
 
@@ -419,7 +480,163 @@ impl Theme {
                 "⠏".to_string(),
             ],
             progress_chars: "━━╺".to_string(),
+            emoji: true,
+            ascii_only: false,
+            animated: true,
+        }
+    }
+
+    /// Create a high-contrast theme (bright bold colors, no dimmed text or emoji).
+    pub fn high_contrast() -> Self {
+        Self {
+            success: Style::new().fg(Color::BrightGreen).bold(),
+            error: Style::new().fg(Color::BrightRed).bold(),
+            warn: Style::new().fg(Color::BrightYellow).bold(),
+            info: Style::new().fg(Color::BrightCyan).bold(),
+            hint: Style::new().fg(Color::BrightWhite).bold(),
+            debug: Style::new().fg(Color::BrightWhite),
+            success_prefix: "✓".to_string(),
+            error_prefix: "✗".to_string(),
+            warn_prefix: "!".to_string(),
+            info_prefix: "i".to_string(),
+            hint_prefix: "→".to_string(),
+            debug_prefix: "…".to_string(),
+            spinner_chars: vec![
+                "◐".to_string(),
+                "◓".to_string(),
+                "◑".to_string(),
+                "◒".to_string(),
+            ],
+            progress_chars: "██░".to_string(),
+            emoji: false,
+            ascii_only: false,
+            animated: true,
+        }
+    }
+
+    /// Create a screen-reader friendly theme.
+    ///
+    /// Messages start with a word instead of a symbol, and spinners and
+    /// progress bars are replaced by one plain status line when they start
+    /// and finish, so nothing is redrawn in place.
+    pub fn screen_reader() -> Self {
+        Self {
+            success_prefix: "Success:".to_string(),
+            error_prefix: "Error:".to_string(),
+            warn_prefix: "Warning:".to_string(),
+            info_prefix: "Info:".to_string(),
+            hint_prefix: "Hint:".to_string(),
+            debug_prefix: "Debug:".to_string(),
+            animated: false,
+            ..Self::minimal()
+        }
+    }
+
+    /// Look up a preset by name (see [`Theme::NAMES`]).
+    ///
+    /// Matching ignores case, and `_` may be used for `-`. `auto` picks a
+    /// theme with [`Theme::detect`].
+    pub fn named(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase().replace('_', "-");
+        Some(match name.as_str() {
+            "auto" => Self::detect(),
+            "default" => Self::default(),
+            "minimal" => Self::minimal(),
+            "colorful" => Self::colorful(),
+            "github" => Self::github(),
+            "cargo" => Self::cargo(),
+            "npm" => Self::npm(),
+            "emoji" => Self::emoji(),
+            "monochrome" => Self::monochrome(),
+            "high-contrast" => Self::high_contrast(),
+            "screen-reader" => Self::screen_reader(),
+            _ => return None,
+        })
+    }
+
+    /// Replace emoji prefixes, spinners and progress characters with the
+    /// plain Unicode ones of [`Theme::monochrome`].
+    pub fn without_emoji(mut self) -> Self {
+        let fallback = Self::monochrome();
+        self.replace_symbols(&fallback, contains_emoji);
+        self.emoji = false;
+        self
+    }
+
+    /// Replace non-ASCII prefixes, spinners and progress characters with the
+    /// ones of [`Theme::minimal`].
+    pub fn ascii_only(mut self) -> Self {
+        let fallback = Self::minimal();
+        self.replace_symbols(&fallback, |s| !s.is_ascii());
+        self.emoji = false;
+        self.ascii_only = true;
+        self
+    }
+
+    fn replace_symbols(&mut self, fallback: &Self, replace: impl Fn(&str) -> bool) {
+        let prefixes = [
+            (&mut self.success_prefix, &fallback.success_prefix),
+            (&mut self.error_prefix, &fallback.error_prefix),
+            (&mut self.warn_prefix, &fallback.warn_prefix),
+            (&mut self.info_prefix, &fallback.info_prefix),
+            (&mut self.hint_prefix, &fallback.hint_prefix),
+            (&mut self.debug_prefix, &fallback.debug_prefix),
+            (&mut self.progress_chars, &fallback.progress_chars),
+        ];
+        for (value, fallback) in prefixes {
+            if replace(value) {
+                *value = fallback.clone();
+            }
+        }
+        if self.spinner_chars.iter().any(|c| replace(c)) {
+            self.spinner_chars = fallback.spinner_chars.clone();
+        }
+    }
+
+    /// Pick `symbol`, or `fallback` when the theme doesn't allow it.
+    ///
+    /// ```rust
+    /// use vx_console::Theme;
+    ///
+    /// assert_eq!(Theme::default().symbol("→", "->"), "→");
+    /// assert_eq!(Theme::minimal().symbol("→", "->"), "->");
+    /// ```
+    pub fn symbol<'a>(&self, symbol: &'a str, fallback: &'a str) -> &'a str {
+        if (self.ascii_only && !symbol.is_ascii()) || (!self.emoji && contains_emoji(symbol)) {
+            fallback
+        } else {
+            symbol
+        }
+    }
+
+    /// Strip emoji (and the space after them) from `text` when the theme
+    /// doesn't allow them.
+    pub fn text(&self, text: &str) -> String {
+        if self.emoji || !contains_emoji(text) {
+            return text.to_string();
         }
+        let mut result = String::with_capacity(text.len());
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '\u{FE0F}' {
+                // Variation selector after a plain symbol, e.g. `⚠️`
+                continue;
+            }
+            if is_emoji(c) {
+                while chars
+                    .peek()
+                    .is_some_and(|&c| is_emoji(c) || c == '\u{200D}')
+                {
+                    chars.next();
+                }
+                if chars.peek() == Some(&' ') {
+                    chars.next();
+                }
+            } else {
+                result.push(c);
+            }
+        }
+        result
     }
 
     /// Detect the best theme based on terminal capabilities.
@@ -454,6 +671,21 @@ impl Theme {
         }
     }
 
+    /// Whether emoji may appear in output.
+    pub fn allows_emoji(&self) -> bool {
+        self.emoji
+    }
+
+    /// Whether output is restricted to ASCII characters.
+    pub fn is_ascii_only(&self) -> bool {
+        self.ascii_only
+    }
+
+    /// Whether spinners and progress bars are animated.
+    pub fn is_animated(&self) -> bool {
+        self.animated
+    }
+
     /// Get the success prefix.
     pub fn success_prefix(&self) -> &str {
         &self.success_prefix
@@ -515,6 +747,26 @@ impl Theme {
     }
 }
 
+/// Whether `c` is drawn as an emoji (pictographs, and the symbols that
+/// terminals render in color). Plain symbols such as `✓` or `⚠` are not.
+fn is_emoji(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1F000..=0x1FAFF
+            | 0x231A..=0x231B
+            | 0x23E9..=0x23F3
+            | 0x2705
+            | 0x2728
+            | 0x274C
+            | 0x2B1C
+            | 0xFE0F
+    )
+}
+
+fn contains_emoji(text: &str) -> bool {
+    text.chars().any(is_emoji)
+}
+
 impl fmt::Display for Style {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -580,6 +832,24 @@ impl ThemeBuilder {
         self
     }
 
+    /// Allow or forbid emoji.
+    pub fn emoji(mut self, emoji: bool) -> Self {
+        self.theme.emoji = emoji;
+        self
+    }
+
+    /// Restrict output to ASCII characters.
+    pub fn ascii_only(mut self, ascii_only: bool) -> Self {
+        self.theme.ascii_only = ascii_only;
+        self
+    }
+
+    /// Animate spinners and progress bars, or print them as status lines.
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.theme.animated = animated;
+        self
+    }
+
     /// Build the theme.
     pub fn build(self) -> Theme {
         self.theme
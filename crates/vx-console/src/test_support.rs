@@ -24,6 +24,16 @@ impl TestOutput {
         }
     }
 
+    /// Append text to the last line, starting one if the buffer is empty.
+    pub(crate) fn append(&self, text: &str) {
+        if let Ok(mut buffer) = self.buffer.lock() {
+            match buffer.last_mut() {
+                Some(last) => last.push_str(text),
+                None => buffer.push(text.to_string()),
+            }
+        }
+    }
+
     /// Get all lines in the buffer.
     pub fn lines(&self) -> Vec<String> {
         self.buffer.lock().map(|b| b.clone()).unwrap_or_default()
@@ -214,14 +224,21 @@ impl TestOutput {
 }
 
 /// A writer that captures output to a TestOutput.
+///
+/// Text is split into lines at `\n`; a write that doesn't end with one
+/// leaves the line open, so the next write continues it.
 pub struct TestWriter {
     output: TestOutput,
+    open_line: bool,
 }
 
 impl TestWriter {
     /// Create a new test writer.
     pub fn new(output: TestOutput) -> Self {
-        Self { output }
+        Self {
+            output,
+            open_line: false,
+        }
     }
 
     /// Get the underlying TestOutput.
@@ -232,10 +249,20 @@ impl TestWriter {
 
 impl Write for TestWriter {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        if let Ok(s) = std::str::from_utf8(buf) {
-            for line in s.lines() {
+        let text = String::from_utf8_lossy(buf);
+        let mut rest: &str = &text;
+        while !rest.is_empty() {
+            let (line, ended, next) = match rest.split_once('\n') {
+                Some((line, next)) => (line.strip_suffix('\r').unwrap_or(line), true, next),
+                None => (rest, false, ""),
+            };
+            if self.open_line {
+                self.output.append(line);
+            } else {
                 self.output.write(line);
             }
+            self.open_line = !ended;
+            rest = next;
         }
         Ok(buf.len())
     }
@@ -304,4 +331,13 @@ mod tests {
         write!(writer, "test line").unwrap();
         assert!(output.contains("test line"));
     }
+
+    #[test]
+    fn test_test_writer_joins_partial_writes() {
+        let output = TestOutput::new();
+        let mut writer = TestWriter::new(output.clone());
+        write!(writer, "Downloading").unwrap();
+        write!(writer, "... done\r\nnext\n\n").unwrap();
+        assert_eq!(output.lines(), ["Downloading... done", "next", ""]);
+    }
 }
@@ -173,3 +173,23 @@ fn test_install_progress() {
     progress.complete_tool(false, "yarn", "4");
     progress.finish("Installed 2/3 tools");
 }
+
+#[rstest]
+fn test_progress_with_non_animated_theme() {
+    vx_console::set_global_theme(vx_console::Theme::screen_reader());
+
+    let pm = ProgressManager::new();
+    let spinner = pm.add_spinner("Resolving");
+    spinner.set_message("Downloading");
+    spinner.finish_success("Installed node@22");
+    let download = pm.add_download(100, "node.tar.gz");
+    download.inc(50);
+    download.finish_and_clear();
+
+    let mut install = InstallProgress::new(1, "Installing tools");
+    install.start_tool("node", "22");
+    install.complete_tool(false, "node", "22");
+    install.finish("Done");
+
+    vx_console::set_global_theme(vx_console::Theme::default());
+}
@@ -0,0 +1,96 @@
+//! Snapshot harness tests.
+
+use rstest::rstest;
+use vx_console::{
+    Capture, CaptureMode, ProgressManager, Theme, assert_snapshot, assert_snapshot_at, capture_all,
+    global_theme, normalize,
+};
+
+#[rstest]
+fn test_normalize_escapes() {
+    assert_eq!(
+        normalize("\x1b[1;32m✓\x1b[0m done\r\x1b[K"),
+        "<1;32>✓</> done<CR><ESC[K>"
+    );
+    assert_eq!(normalize("plain"), "plain");
+}
+
+#[rstest]
+fn test_capture_mode_presets() {
+    let names: Vec<String> = CaptureMode::all().into_iter().map(|m| m.name).collect();
+    assert_eq!(names, ["plain", "color", "verbose", "quiet", "ci"]);
+}
+
+#[rstest]
+fn test_capture_restores_global_theme() {
+    let before = global_theme().success_prefix().to_string();
+    {
+        let _capture = Capture::start(CaptureMode::plain().with_theme(Theme::minimal()));
+        assert_eq!(global_theme().success_prefix(), "[OK]");
+        assert!(!global_theme().is_animated());
+    }
+    assert_eq!(global_theme().success_prefix(), before);
+}
+
+#[rstest]
+fn test_capture_progress_manager_lines() {
+    let capture = Capture::start(CaptureMode::plain());
+    let pm = ProgressManager::new();
+    pm.println("to stdout");
+    pm.println_status("to stderr");
+    let spinner = pm.add_spinner("Resolving");
+    spinner.finish_success("Resolved");
+    assert_eq!(
+        capture.finish(),
+        "to stdout\nto stderr\n  Resolving\n  ✓ Resolved"
+    );
+}
+
+#[rstest]
+fn test_shell_messages_snapshot() {
+    let output = capture_all(&CaptureMode::all(), |capture| {
+        let shell = capture.shell();
+        shell.status("Resolving", "node@20").unwrap();
+        shell.info("Installing node@20.10.0").unwrap();
+        shell.debug("Cache hit for node-v20.10.0.tar.gz").unwrap();
+        shell.warn("node@18 is deprecated").unwrap();
+        shell.error("Checksum mismatch").unwrap();
+        shell.hint("Run 'vx cache clean' and retry").unwrap();
+        shell.success("Installed node@20.10.0").unwrap();
+    });
+    assert_snapshot!("shell_messages", output);
+}
+
+#[rstest]
+fn test_progress_snapshot() {
+    let output = capture_all(&CaptureMode::all(), |capture| {
+        let shell = capture.shell();
+        let spinner = shell.spinner("Downloading node@20.10.0");
+        spinner.set_message("Extracting node@20.10.0");
+        spinner.finish_success("Installed node@20.10.0");
+        let spinner = shell.spinner("Downloading go@1.23");
+        spinner.finish_error("Download failed");
+    });
+    assert_snapshot!("progress", output);
+}
+
+#[rstest]
+fn test_assert_snapshot_reports_diff() {
+    let dir = std::env::temp_dir().join(format!("vx-console-snapshot-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("diff.snap");
+    std::fs::write(&path, "first\nsecond\nthird\n").unwrap();
+
+    assert_snapshot_at(&path, "first\nsecond\nthird");
+
+    let panic = std::panic::catch_unwind(|| assert_snapshot_at(&path, "first\n2nd\nthird\n"))
+        .expect_err("mismatch must fail");
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert!(
+        message.contains("  first\n- second\n+ 2nd\n  third"),
+        "{message}"
+    );
+    assert!(message.contains("VX_UPDATE_SNAPSHOTS=1"), "{message}");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
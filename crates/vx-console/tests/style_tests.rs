@@ -123,3 +123,88 @@ fn test_theme_builder() {
     assert_eq!(theme.progress_chars, "=>-");
     assert_eq!(theme.spinner_chars.len(), 4);
 }
+
+#[rstest]
+#[case("high-contrast")]
+#[case("High_Contrast")]
+#[case("screen-reader")]
+#[case("auto")]
+fn test_theme_named(#[case] name: &str) {
+    assert!(Theme::named(name).is_some());
+}
+
+#[rstest]
+fn test_theme_named_covers_names() {
+    for name in Theme::NAMES {
+        assert!(Theme::named(name).is_some(), "{}", name);
+    }
+    assert!(Theme::named("sparkly").is_none());
+}
+
+#[rstest]
+fn test_theme_screen_reader() {
+    let theme = Theme::screen_reader();
+    assert_eq!(theme.success_prefix(), "Success:");
+    assert_eq!(theme.error_prefix(), "Error:");
+    assert_eq!(theme.warn_prefix(), "Warning:");
+    assert!(!theme.is_animated());
+    assert!(theme.is_ascii_only());
+    assert!(!theme.allows_emoji());
+    assert_eq!(theme.success_style().apply("ok"), "ok");
+}
+
+#[rstest]
+fn test_theme_high_contrast() {
+    let theme = Theme::high_contrast();
+    assert!(theme.is_animated());
+    assert!(!theme.allows_emoji());
+    assert!(theme.error_style().apply("x").contains("91"));
+    assert_eq!(theme.progress_chars, "██░");
+}
+
+#[rstest]
+fn test_theme_without_emoji() {
+    let theme = Theme::default().without_emoji();
+    assert_eq!(theme.hint_prefix(), "→");
+    // Plain symbols are kept
+    assert_eq!(theme.success_prefix(), "✓");
+    assert_eq!(theme.warn_prefix(), "⚠");
+
+    let theme = Theme::emoji().without_emoji();
+    assert_eq!(theme.success_prefix(), "✓");
+    assert_eq!(theme.warn_prefix(), "!");
+    assert_eq!(theme.spinner_chars, Theme::monochrome().spinner_chars);
+    assert_eq!(theme.progress_chars, "━━╺");
+}
+
+#[rstest]
+fn test_theme_ascii_only() {
+    let theme = Theme::default().ascii_only();
+    assert_eq!(theme.success_prefix(), "[OK]");
+    assert_eq!(theme.debug_prefix(), "[DEBUG]");
+    assert_eq!(theme.progress_chars, "=>-");
+    assert!(theme.spinner_chars.iter().all(|c| c.is_ascii()));
+    assert!(theme.is_ascii_only() && !theme.allows_emoji());
+
+    // ASCII prefixes are kept
+    assert_eq!(Theme::github().ascii_only().error_prefix(), "::error::");
+}
+
+#[rstest]
+fn test_theme_symbol() {
+    assert_eq!(Theme::default().symbol("💡", "->"), "💡");
+    assert_eq!(Theme::default().without_emoji().symbol("💡", "->"), "->");
+    assert_eq!(Theme::default().without_emoji().symbol("▸", ">"), "▸");
+    assert_eq!(Theme::default().ascii_only().symbol("▸", ">"), ">");
+}
+
+#[rstest]
+#[case("💡 Run 'vx sync'", "Run 'vx sync'")]
+#[case("Done ✅", "Done ")]
+#[case("⚠️ Careful", "⚠ Careful")]
+#[case("👩‍💻 node ✓", "node ✓")]
+#[case("plain text", "plain text")]
+fn test_theme_text_strips_emoji(#[case] text: &str, #[case] expected: &str) {
+    assert_eq!(Theme::default().without_emoji().text(text), expected);
+    assert_eq!(Theme::default().text(text), text);
+}
@@ -7,6 +7,11 @@
 use crate::error::EnvError;
 use crate::shell;
 use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Distinguishes wrapper scripts of commands started in the same millisecond
+static SCRIPT_COUNTER: AtomicU64 = AtomicU64::new(0);
 
 /// Execute a command by generating a platform-specific wrapper script
 ///
@@ -35,6 +40,18 @@ use std::collections::HashMap;
 pub fn execute_with_env(
     cmd: &str,
     env_vars: &HashMap<String, String>,
+) -> Result<std::process::ExitStatus, EnvError> {
+    execute_with_env_in(cmd, env_vars, None)
+}
+
+/// Execute a command like [`execute_with_env`], in `cwd` when given
+///
+/// Unlike changing the process's working directory, this is safe when
+/// several commands run at the same time.
+pub fn execute_with_env_in(
+    cmd: &str,
+    env_vars: &HashMap<String, String>,
+    cwd: Option<&Path>,
 ) -> Result<std::process::ExitStatus, EnvError> {
     use std::fs;
     use std::io::Write;
@@ -47,12 +64,19 @@ pub fn execute_with_env(
         .duration_since(std::time::UNIX_EPOCH)
         .map(|d| d.as_millis())
         .unwrap_or(0);
+    let sequence = SCRIPT_COUNTER.fetch_add(1, Ordering::Relaxed);
 
     #[cfg(windows)]
-    let script_path = temp_dir.join(format!("vx_run_{}_{}.ps1", script_id, timestamp));
+    let script_path = temp_dir.join(format!(
+        "vx_run_{}_{}_{}.ps1",
+        script_id, timestamp, sequence
+    ));
 
     #[cfg(not(windows))]
-    let script_path = temp_dir.join(format!("vx_run_{}_{}.sh", script_id, timestamp));
+    let script_path = temp_dir.join(format!(
+        "vx_run_{}_{}_{}.sh",
+        script_id, timestamp, sequence
+    ));
 
     // Generate the script content
     let script_content = generate_wrapper_script(cmd, env_vars);
@@ -60,6 +84,9 @@ pub fn execute_with_env(
     // Write the script
     {
         let mut file = fs::File::create(&script_path)?;
+        #[cfg(windows)]
+        file.write_all(&shell::powershell::encode_script_file(&script_content))?;
+        #[cfg(not(windows))]
         file.write_all(script_content.as_bytes())?;
     }
 
@@ -72,12 +99,20 @@ pub fn execute_with_env(
         fs::set_permissions(&script_path, perms)?;
     }
 
+    let command = |program: &str| {
+        let mut command = Command::new(program);
+        if let Some(cwd) = cwd {
+            command.current_dir(cwd);
+        }
+        command
+    };
+
     // Execute the script using platform-appropriate shell
     #[cfg(windows)]
     let status = {
         // Try pwsh (PowerShell Core) first, fall back to powershell (Windows PowerShell)
         let script_path_str = script_path.to_string_lossy();
-        let pwsh_result = Command::new("pwsh")
+        let pwsh_result = command("pwsh")
             .args([
                 "-NoProfile",
                 "-NonInteractive",
@@ -92,7 +127,7 @@ pub fn execute_with_env(
             Ok(status) => Ok(status),
             Err(_) => {
                 // Fall back to Windows PowerShell
-                Command::new("powershell")
+                command("powershell")
                     .args([
                         "-NoProfile",
                         "-NonInteractive",
@@ -110,13 +145,13 @@ pub fn execute_with_env(
     let status = {
         // Use bash with pipefail for better error handling
         // Fall back to sh if bash is not available
-        let bash_result = Command::new("bash").arg(&script_path).status();
+        let bash_result = command("bash").arg(&script_path).status();
 
         match bash_result {
             Ok(status) => Ok(status),
             Err(_) => {
                 // Fall back to sh
-                Command::new("sh").arg(&script_path).status()
+                command("sh").arg(&script_path).status()
             }
         }
     };
@@ -219,4 +254,20 @@ mod tests {
             assert!(script.contains("It'\\''s working"));
         }
     }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn test_execute_in_cwd() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut env_vars: HashMap<String, String> = HashMap::new();
+        env_vars.insert(
+            "PATH".to_string(),
+            std::env::var("PATH").unwrap_or_default(),
+        );
+
+        let status = execute_with_env_in("touch marker", &env_vars, Some(dir.path())).unwrap();
+
+        assert!(status.success());
+        assert!(dir.path().join("marker").exists());
+    }
 }
@@ -55,6 +55,7 @@
 //! ├── assets.rs       # Embedded shell scripts (rust-embed)
 //! ├── builder.rs      # Environment builder (EnvBuilder)
 //! ├── executor.rs     # Script execution (execute_with_env)
+//! ├── portable.rs     # Portable script interpreter (execute_portable)
 //! ├── tool_env.rs     # Tool environment (ToolEnvironment)
 //! ├── session.rs      # Session context (SessionContext)
 //! ├── spawner.rs      # Shell spawner (ShellSpawner)
@@ -62,6 +63,8 @@
 //! ├── error.rs        # Error types
 //! └── shell/          # Shell-specific implementations
 //!     ├── bash.rs
+//!     ├── fish.rs
+//!     ├── nushell.rs
 //!     ├── powershell.rs
 //!     └── cmd.rs
 //! ```
@@ -72,6 +75,7 @@ pub mod context;
 pub mod env_assembler;
 mod error;
 mod executor;
+mod portable;
 pub mod session;
 pub mod shell;
 pub mod spawner;
@@ -83,7 +87,8 @@ pub use builder::EnvBuilder;
 pub use context::{ContextOverride, EnvContext};
 pub use env_assembler::{EnvAssembler, EnvOperation, EnvVar, priority};
 pub use error::EnvError;
-pub use executor::{execute_with_env, generate_wrapper_script};
+pub use executor::{execute_with_env, execute_with_env_in, generate_wrapper_script};
+pub use portable::execute_portable;
 pub use session::{IsolationConfig, SessionContext, SessionSource};
 pub use spawner::{ExportFormat, ShellSpawner, detect_shell, print_exit, print_welcome};
 pub use tool_env::{RuntimeSpec, ToolEnvironment};
@@ -0,0 +1,885 @@
+//! Portable script interpreter
+//!
+//! Runs a command line the same way on every platform instead of handing it
+//! to bash or PowerShell, in the spirit of `cross-env` and `shx`:
+//!
+//! - `a && b`, `a || b` and `a; b` (a failing list stops the script, like
+//!   `set -e`)
+//! - `NAME=value cmd` sets a variable for one command; a bare `NAME=value`
+//!   sets it for the rest of the script
+//! - `$NAME` and `${NAME}` expand in unquoted words and double quotes
+//!   (without word splitting); single quotes are literal
+//! - unquoted `*`, `?` and `[...]` expand to matching paths
+//! - `>`, `>>`, `2>` and `2>>` redirect to files
+//! - `cd`, `echo`, `pwd`, `cat`, `touch`, `mkdir [-p]`, `rm [-rf]`,
+//!   `cp [-r]`, `mv`, `true`, `false` and `exit` are built in, so they do not
+//!   depend on the platform's shell utilities
+//!
+//! Everything else runs as a program found on the script's `PATH`. Pipes,
+//! background jobs and input redirection are not supported.
+
+use crate::error::EnvError;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+
+/// Execute `cmd` with the portable interpreter
+///
+/// `env_vars` are set on top of the inherited environment, as with
+/// [`crate::execute_with_env`]. Returns the status of the last command run.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::collections::HashMap;
+/// use vx_env::execute_portable;
+///
+/// let status = execute_portable("mkdir -p dist && NODE_ENV=production node build.js", &HashMap::new(), None).unwrap();
+/// assert!(status.success());
+/// ```
+pub fn execute_portable(
+    cmd: &str,
+    env_vars: &HashMap<String, String>,
+    cwd: Option<&Path>,
+) -> Result<ExitStatus, EnvError> {
+    let script = parse(cmd)?;
+    let cwd = match cwd {
+        Some(cwd) => cwd.to_path_buf(),
+        None => std::env::current_dir()?,
+    };
+    let mut shell = Shell {
+        vars: env_vars.clone(),
+        cwd,
+    };
+    shell.run(&script)
+}
+
+// ============================================================================
+// Parsing
+// ============================================================================
+
+/// Part of a word, before expansion
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    /// Literal text; quoted text is never glob-expanded
+    Text { text: String, quoted: bool },
+    /// `$NAME` or `${NAME}`
+    Var(String),
+}
+
+type Word = Vec<Segment>;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(Word),
+    And,
+    Or,
+    Separator,
+    Redirect { fd: u8, append: bool },
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct SimpleCommand {
+    assignments: Vec<(String, Word)>,
+    words: Vec<Word>,
+    redirects: Vec<Redirect>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Redirect {
+    fd: u8,
+    append: bool,
+    target: Word,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Connector {
+    And,
+    Or,
+}
+
+/// Commands joined by `&&` and `||`
+#[derive(Debug, PartialEq)]
+struct AndOrList {
+    first: SimpleCommand,
+    rest: Vec<(Connector, SimpleCommand)>,
+}
+
+fn parse_error(message: impl Into<String>) -> EnvError {
+    EnvError::CommandParse(message.into())
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, EnvError> {
+    let mut tokens = Vec::new();
+    let mut word: Word = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    fn push_text(word: &mut Word, c: char, quoted: bool) {
+        match word.last_mut() {
+            Some(Segment::Text { text, quoted: q }) if *q == quoted => text.push(c),
+            _ => word.push(Segment::Text {
+                text: c.to_string(),
+                quoted,
+            }),
+        }
+    }
+
+    fn finish(word: &mut Word, tokens: &mut Vec<Token>) {
+        if !word.is_empty() {
+            tokens.push(Token::Word(std::mem::take(word)));
+        }
+    }
+
+    fn variable(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> Option<String> {
+        if chars.peek() == Some(&'{') {
+            chars.next();
+            let name: String = chars.by_ref().take_while(|&c| c != '}').collect();
+            return Some(name);
+        }
+        let mut name = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                name.push(c);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        (!name.is_empty()).then_some(name)
+    }
+
+    while let Some(c) = chars.next() {
+        match c {
+            ' ' | '\t' | '\r' => finish(&mut word, &mut tokens),
+            '\n' | ';' => {
+                finish(&mut word, &mut tokens);
+                tokens.push(Token::Separator);
+            }
+            '#' if word.is_empty() => {
+                // Comment until the end of the line
+                while chars.peek().is_some_and(|&c| c != '\n') {
+                    chars.next();
+                }
+            }
+            '&' => {
+                finish(&mut word, &mut tokens);
+                if chars.next_if_eq(&'&').is_none() {
+                    return Err(parse_error("background jobs (&) are not supported"));
+                }
+                tokens.push(Token::And);
+            }
+            '|' => {
+                finish(&mut word, &mut tokens);
+                if chars.next_if_eq(&'|').is_none() {
+                    return Err(parse_error("pipes (|) are not supported"));
+                }
+                tokens.push(Token::Or);
+            }
+            '<' => return Err(parse_error("input redirection (<) is not supported")),
+            '>' => {
+                // `2>` redirects stderr when the 2 stands alone
+                let fd = if word
+                    == [Segment::Text {
+                        text: "2".to_string(),
+                        quoted: false,
+                    }] {
+                    word.clear();
+                    2
+                } else {
+                    finish(&mut word, &mut tokens);
+                    1
+                };
+                let append = chars.next_if_eq(&'>').is_some();
+                tokens.push(Token::Redirect { fd, append });
+            }
+            '\'' => {
+                let mut closed = false;
+                for c in chars.by_ref() {
+                    if c == '\'' {
+                        closed = true;
+                        break;
+                    }
+                    push_text(&mut word, c, true);
+                }
+                if !closed {
+                    return Err(parse_error("unterminated single quote"));
+                }
+                // Keep empty quotes as an (empty) argument
+                word.push(Segment::Text {
+                    text: String::new(),
+                    quoted: true,
+                });
+            }
+            '"' => {
+                let mut closed = false;
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => {
+                            closed = true;
+                            break;
+                        }
+                        '\\' if chars.peek().is_some_and(|c| matches!(c, '"' | '\\' | '$')) => {
+                            push_text(&mut word, chars.next().unwrap_or_default(), true);
+                        }
+                        '$' => match variable(&mut chars) {
+                            Some(name) => word.push(Segment::Var(name)),
+                            None => push_text(&mut word, '$', true),
+                        },
+                        c => push_text(&mut word, c, true),
+                    }
+                }
+                if !closed {
+                    return Err(parse_error("unterminated double quote"));
+                }
+                word.push(Segment::Text {
+                    text: String::new(),
+                    quoted: true,
+                });
+            }
+            '$' => match variable(&mut chars) {
+                Some(name) => word.push(Segment::Var(name)),
+                None => push_text(&mut word, '$', false),
+            },
+            // Backslashes only escape shell syntax, so Windows paths keep working
+            '\\' if chars
+                .peek()
+                .is_some_and(|c| " \t\"'\\$&|;<>#*?[".contains(*c)) =>
+            {
+                push_text(&mut word, chars.next().unwrap_or_default(), true);
+            }
+            c => push_text(&mut word, c, false),
+        }
+    }
+    finish(&mut word, &mut tokens);
+    Ok(tokens)
+}
+
+/// `NAME=value` at the start of a command
+fn assignment(word: &Word) -> Option<(String, Word)> {
+    let Some(Segment::Text {
+        text,
+        quoted: false,
+    }) = word.first()
+    else {
+        return None;
+    };
+    let (name, value) = text.split_once('=')?;
+    let valid = !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return None;
+    }
+
+    let mut rest = vec![Segment::Text {
+        text: value.to_string(),
+        quoted: false,
+    }];
+    rest.extend(word[1..].iter().cloned());
+    Some((name.to_string(), rest))
+}
+
+fn parse(input: &str) -> Result<Vec<AndOrList>, EnvError> {
+    let mut lists = Vec::new();
+    let mut list: Option<AndOrList> = None;
+    let mut command = SimpleCommand::default();
+    let mut connector = None;
+    let mut tokens = tokenize(input)?.into_iter();
+
+    fn close(
+        command: &mut SimpleCommand,
+        list: &mut Option<AndOrList>,
+        connector: Option<Connector>,
+    ) -> Result<(), EnvError> {
+        let command = std::mem::take(command);
+        if command.words.is_empty() && command.assignments.is_empty() {
+            return match (connector, list.is_some()) {
+                (None, _) => Ok(()),
+                _ => Err(parse_error("missing command after && or ||")),
+            };
+        }
+        match (list.as_mut(), connector) {
+            (Some(list), Some(connector)) => list.rest.push((connector, command)),
+            (None, None) => {
+                *list = Some(AndOrList {
+                    first: command,
+                    rest: Vec::new(),
+                })
+            }
+            _ => return Err(parse_error("missing command before && or ||")),
+        }
+        Ok(())
+    }
+
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Word(word) => {
+                if command.words.is_empty()
+                    && let Some(assignment) = assignment(&word)
+                {
+                    command.assignments.push(assignment);
+                } else {
+                    command.words.push(word);
+                }
+            }
+            Token::Redirect { fd, append } => match tokens.next() {
+                Some(Token::Word(target)) => {
+                    command.redirects.push(Redirect { fd, append, target })
+                }
+                _ => return Err(parse_error("missing file name after >")),
+            },
+            Token::And | Token::Or => {
+                if command.words.is_empty() && command.assignments.is_empty() {
+                    return Err(parse_error("missing command before && or ||"));
+                }
+                close(&mut command, &mut list, connector)?;
+                connector = Some(if token == Token::And {
+                    Connector::And
+                } else {
+                    Connector::Or
+                });
+            }
+            Token::Separator => {
+                close(&mut command, &mut list, connector)?;
+                connector = None;
+                lists.extend(list.take());
+            }
+        }
+    }
+    close(&mut command, &mut list, connector)?;
+    lists.extend(list.take());
+    Ok(lists)
+}
+
+// ============================================================================
+// Execution
+// ============================================================================
+
+struct Shell {
+    /// Variables set by the caller and the script, exported to programs
+    vars: HashMap<String, String>,
+    cwd: PathBuf,
+}
+
+/// Stop the script with this status (from `exit`)
+struct Exit(i32);
+
+impl Shell {
+    fn run(&mut self, script: &[AndOrList]) -> Result<ExitStatus, EnvError> {
+        let mut status = exit_status(0);
+        for list in script {
+            match self.run_list(list)? {
+                Ok(s) => status = s,
+                Err(Exit(code)) => return Ok(exit_status(code)),
+            }
+            // Like `set -e`: a failing list ends the script
+            if !status.success() {
+                break;
+            }
+        }
+        Ok(status)
+    }
+
+    fn run_list(&mut self, list: &AndOrList) -> Result<Result<ExitStatus, Exit>, EnvError> {
+        let mut status = match self.run_command(&list.first)? {
+            Ok(status) => status,
+            exit => return Ok(exit),
+        };
+        for (connector, command) in &list.rest {
+            let run = match connector {
+                Connector::And => status.success(),
+                Connector::Or => !status.success(),
+            };
+            if run {
+                status = match self.run_command(command)? {
+                    Ok(status) => status,
+                    exit => return Ok(exit),
+                };
+            }
+        }
+        Ok(Ok(status))
+    }
+
+    fn lookup(&self, name: &str) -> String {
+        self.vars
+            .get(name)
+            .cloned()
+            .or_else(|| std::env::var(name).ok())
+            .unwrap_or_default()
+    }
+
+    fn expand_text(&self, word: &Word) -> String {
+        word.iter()
+            .map(|segment| match segment {
+                Segment::Text { text, .. } => text.clone(),
+                Segment::Var(name) => self.lookup(name),
+            })
+            .collect()
+    }
+
+    /// Expand a word to arguments, applying globs to unquoted text
+    fn expand(&self, word: &Word) -> Vec<String> {
+        let has_glob = word.iter().any(|segment| {
+            matches!(segment, Segment::Text { text, quoted: false } if text.contains(['*', '?', '[']))
+        });
+        let text = self.expand_text(word);
+        if !has_glob {
+            return vec![text];
+        }
+
+        // Quoted parts and variable values must match literally
+        let pattern: String = word
+            .iter()
+            .map(|segment| match segment {
+                Segment::Text {
+                    text,
+                    quoted: false,
+                } => text.clone(),
+                Segment::Text { text, quoted: true } => glob::Pattern::escape(text),
+                Segment::Var(name) => glob::Pattern::escape(&self.lookup(name)),
+            })
+            .collect();
+        let absolute = Path::new(&pattern).is_absolute();
+        let full = if absolute {
+            pattern.clone()
+        } else {
+            self.cwd.join(&pattern).to_string_lossy().into_owned()
+        };
+        let mut matches: Vec<String> = glob::glob(&full)
+            .map(|paths| {
+                paths
+                    .filter_map(Result::ok)
+                    .map(|path| {
+                        let path = if absolute {
+                            path
+                        } else {
+                            path.strip_prefix(&self.cwd)
+                                .map(Path::to_path_buf)
+                                .unwrap_or(path)
+                        };
+                        path.to_string_lossy().into_owned()
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        matches.sort();
+        if matches.is_empty() {
+            // No match keeps the word, as in POSIX shells
+            vec![text]
+        } else {
+            matches
+        }
+    }
+
+    fn run_command(
+        &mut self,
+        command: &SimpleCommand,
+    ) -> Result<Result<ExitStatus, Exit>, EnvError> {
+        let assignments: Vec<(String, String)> = command
+            .assignments
+            .iter()
+            .map(|(name, value)| (name.clone(), self.expand_text(value)))
+            .collect();
+        let args: Vec<String> = command.words.iter().flat_map(|w| self.expand(w)).collect();
+
+        let Some((program, args)) = args.split_first() else {
+            // A bare assignment applies to the rest of the script
+            self.vars.extend(assignments);
+            return Ok(Ok(exit_status(0)));
+        };
+
+        let mut stdout: Option<fs::File> = None;
+        let mut stderr: Option<fs::File> = None;
+        for redirect in &command.redirects {
+            let path = self.cwd.join(self.expand_text(&redirect.target));
+            let file = fs::OpenOptions::new()
+                .create(true)
+                .write(true)
+                .append(redirect.append)
+                .truncate(!redirect.append)
+                .open(&path);
+            let file = match file {
+                Ok(file) => file,
+                Err(e) => {
+                    eprintln!("vx: {}: {}", path.display(), e);
+                    return Ok(Ok(exit_status(1)));
+                }
+            };
+            if redirect.fd == 2 {
+                stderr = Some(file);
+            } else {
+                stdout = Some(file);
+            }
+        }
+
+        if let Some(result) = self.builtin(program, args, stdout.as_mut(), stderr.as_mut()) {
+            return Ok(result.map(exit_status));
+        }
+
+        let mut env = self.vars.clone();
+        env.extend(assignments);
+        let Some(path) = self.resolve_program(program, &env) else {
+            let message = format!("vx: {}: command not found", program);
+            write_line(stderr.as_mut(), &message);
+            return Ok(Ok(exit_status(127)));
+        };
+
+        let mut child = Command::new(path);
+        child.args(args).envs(&env).current_dir(&self.cwd);
+        if let Some(file) = stdout {
+            child.stdout(Stdio::from(file));
+        }
+        if let Some(file) = stderr {
+            child.stderr(Stdio::from(file));
+        }
+        let status = child
+            .status()
+            .map_err(|e| EnvError::Execution(format!("{}: {}", program, e)))?;
+        Ok(Ok(status))
+    }
+
+    /// Find `program` on the script's `PATH`, or relative to the working directory
+    fn resolve_program(&self, program: &str, env: &HashMap<String, String>) -> Option<PathBuf> {
+        if program.contains(['/', '\\']) {
+            let path = self.cwd.join(program);
+            return which::which_in(&path, None::<&str>, &self.cwd).ok();
+        }
+        let path = env
+            .get("PATH")
+            .cloned()
+            .or_else(|| std::env::var("PATH").ok());
+        which::which_in(program, path, &self.cwd).ok()
+    }
+
+    /// Run a built-in command; `None` when `program` is not one
+    fn builtin(
+        &mut self,
+        program: &str,
+        args: &[String],
+        stdout: Option<&mut fs::File>,
+        stderr: Option<&mut fs::File>,
+    ) -> Option<Result<i32, Exit>> {
+        let mut out: Box<dyn Write + '_> = match stdout {
+            Some(file) => Box::new(file),
+            None => Box::new(io::stdout()),
+        };
+        let result = match program {
+            "true" => Ok(()),
+            "false" => return Some(Ok(1)),
+            "exit" => {
+                let code = args.first().and_then(|a| a.parse().ok()).unwrap_or(0);
+                return Some(Err(Exit(code)));
+            }
+            "echo" => {
+                let (newline, args) = match args.split_first() {
+                    Some((flag, rest)) if flag == "-n" => (false, rest),
+                    _ => (true, args),
+                };
+                let mut line = args.join(" ");
+                if newline {
+                    line.push('\n');
+                }
+                out.write_all(line.as_bytes())
+            }
+            "pwd" => writeln!(out, "{}", self.cwd.display()),
+            "cd" => {
+                let target = match args.first() {
+                    Some(dir) => self.cwd.join(dir),
+                    None => dirs::home_dir().unwrap_or_else(|| self.cwd.clone()),
+                };
+                if target.is_dir() {
+                    self.cwd = target;
+                    Ok(())
+                } else {
+                    Err(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!("{}: no such directory", target.display()),
+                    ))
+                }
+            }
+            "cat" => args.iter().try_for_each(|file| {
+                let content = fs::read(self.cwd.join(file))?;
+                out.write_all(&content)
+            }),
+            "touch" => args.iter().try_for_each(|file| {
+                let path = self.cwd.join(file);
+                if path.exists() {
+                    let file = fs::OpenOptions::new().append(true).open(&path)?;
+                    file.set_modified(std::time::SystemTime::now())
+                } else {
+                    fs::File::create(&path).map(drop)
+                }
+            }),
+            "mkdir" => {
+                let (flags, paths) = split_flags(args);
+                paths.iter().try_for_each(|dir| {
+                    let path = self.cwd.join(dir);
+                    if flags.contains('p') {
+                        fs::create_dir_all(path)
+                    } else {
+                        fs::create_dir(path)
+                    }
+                })
+            }
+            "rm" => {
+                let (flags, paths) = split_flags(args);
+                let recursive = flags.contains('r') || flags.contains('R');
+                let force = flags.contains('f');
+                paths.iter().try_for_each(|target| {
+                    let path = self.cwd.join(target);
+                    let result = match fs::symlink_metadata(&path) {
+                        Ok(meta) if meta.is_dir() && recursive => fs::remove_dir_all(&path),
+                        Ok(meta) if meta.is_dir() => {
+                            Err(io::Error::other(format!("{}: is a directory", target)))
+                        }
+                        Ok(_) => fs::remove_file(&path),
+                        Err(e) => Err(e),
+                    };
+                    match result {
+                        Err(e) if force && e.kind() == io::ErrorKind::NotFound => Ok(()),
+                        result => result,
+                    }
+                })
+            }
+            "cp" | "mv" => {
+                let (flags, paths) = split_flags(args);
+                let recursive = flags.contains('r') || flags.contains('R');
+                self.copy_or_move(program == "mv", recursive, &paths)
+            }
+            _ => return None,
+        };
+
+        Some(Ok(match result {
+            Ok(()) => 0,
+            Err(e) => {
+                write_line(stderr, &format!("vx: {}: {}", program, e));
+                1
+            }
+        }))
+    }
+
+    fn copy_or_move(&self, rename: bool, recursive: bool, paths: &[&String]) -> io::Result<()> {
+        let Some((dest, sources)) = paths.split_last() else {
+            return Err(io::Error::other("missing destination"));
+        };
+        if sources.is_empty() {
+            return Err(io::Error::other("missing source"));
+        }
+        let dest = self.cwd.join(dest);
+        if sources.len() > 1 && !dest.is_dir() {
+            return Err(io::Error::other(format!(
+                "{}: not a directory",
+                dest.display()
+            )));
+        }
+
+        for source in sources {
+            let source = self.cwd.join(source);
+            let target = match (dest.is_dir(), source.file_name()) {
+                (true, Some(name)) => dest.join(name),
+                _ => dest.clone(),
+            };
+            if rename {
+                fs::rename(&source, &target)?;
+            } else if source.is_dir() {
+                if !recursive {
+                    return Err(io::Error::other(format!(
+                        "{}: is a directory (use -r)",
+                        source.display()
+                    )));
+                }
+                copy_dir(&source, &target)?;
+            } else {
+                fs::copy(&source, &target)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Split leading `-xyz` flags from operands
+fn split_flags(args: &[String]) -> (String, Vec<&String>) {
+    let mut flags = String::new();
+    let mut operands = Vec::new();
+    let mut in_flags = true;
+    for arg in args {
+        if in_flags && arg == "--" {
+            in_flags = false;
+        } else if in_flags && arg.len() > 1 && arg.starts_with('-') {
+            flags.push_str(&arg[1..]);
+        } else {
+            in_flags = false;
+            operands.push(arg);
+        }
+    }
+    (flags, operands)
+}
+
+fn copy_dir(source: &Path, target: &Path) -> io::Result<()> {
+    fs::create_dir_all(target)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let path = entry.path();
+        let dest = target.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&path, &dest)?;
+        } else {
+            fs::copy(&path, &dest)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_line(file: Option<&mut fs::File>, message: &str) {
+    match file {
+        Some(file) => {
+            let _ = writeln!(file, "{}", message);
+        }
+        None => eprintln!("{}", message),
+    }
+}
+
+#[cfg(unix)]
+fn exit_status(code: i32) -> ExitStatus {
+    use std::os::unix::process::ExitStatusExt;
+    ExitStatus::from_raw((code & 0xff) << 8)
+}
+
+#[cfg(windows)]
+fn exit_status(code: i32) -> ExitStatus {
+    use std::os::windows::process::ExitStatusExt;
+    ExitStatus::from_raw(code as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn run(cmd: &str, dir: &Path) -> ExitStatus {
+        execute_portable(cmd, &HashMap::new(), Some(dir)).unwrap()
+    }
+
+    fn text(s: &str) -> Segment {
+        Segment::Text {
+            text: s.to_string(),
+            quoted: false,
+        }
+    }
+
+    #[test]
+    fn test_tokenize_quotes_and_variables() {
+        let tokens = tokenize(r#"echo 'a b' "x $HOME" ${USER}s C:\dir\file"#).unwrap();
+        let words: Vec<_> = tokens
+            .iter()
+            .map(|t| match t {
+                Token::Word(w) => w.clone(),
+                _ => panic!("unexpected token {:?}", t),
+            })
+            .collect();
+        assert_eq!(words.len(), 5);
+        assert_eq!(words[0], [text("echo")]);
+        assert!(matches!(&words[1][0], Segment::Text { text, quoted: true } if text == "a b"));
+        assert_eq!(words[2][1], Segment::Var("HOME".to_string()));
+        assert_eq!(words[3], [Segment::Var("USER".to_string()), text("s")]);
+        // Backslashes in Windows paths stay literal
+        assert_eq!(words[4], [text(r"C:\dir\file")]);
+    }
+
+    #[test]
+    fn test_parse_lists_and_assignments() {
+        let script = parse("A=1 B=2 node x.js && echo ok || echo no; C=3").unwrap();
+        assert_eq!(script.len(), 2);
+        let first = &script[0];
+        assert_eq!(first.first.assignments.len(), 2);
+        assert_eq!(first.first.words.len(), 2);
+        assert_eq!(first.rest.len(), 2);
+        assert_eq!(first.rest[1].0, Connector::Or);
+        assert_eq!(script[1].first.assignments[0].0, "C");
+        assert!(script[1].first.words.is_empty());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for cmd in [
+            "a | b",
+            "a &",
+            "a < b",
+            "echo 'open",
+            "&& a",
+            "a &&",
+            "echo >",
+        ] {
+            assert!(parse(cmd).is_err(), "{} should not parse", cmd);
+        }
+    }
+
+    #[test]
+    fn test_file_builtins() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+
+        let status = run(
+            "mkdir -p out/nested && echo hello > out/a.txt && echo more >> out/a.txt && cp -r out copy && mv copy/a.txt copy/b.txt",
+            dir,
+        );
+        assert!(status.success());
+        assert_eq!(
+            fs::read_to_string(dir.join("out/a.txt")).unwrap(),
+            "hello\nmore\n"
+        );
+        assert!(dir.join("copy/b.txt").exists());
+        assert!(dir.join("copy/nested").is_dir());
+
+        assert!(run("rm out/*.txt && rm -rf copy missing", dir).success());
+        assert!(!dir.join("out/a.txt").exists());
+        assert!(!dir.join("copy").exists());
+        // Without -f a missing file is an error
+        assert!(!run("rm missing", dir).success());
+    }
+
+    #[test]
+    fn test_variables_and_control_flow() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        let mut env = HashMap::new();
+        env.insert("NAME".to_string(), "vx".to_string());
+
+        let cmd = "GREETING=hi; false || echo \"$GREETING ${NAME}\" > out.txt; false; echo never > never.txt";
+        let status = execute_portable(cmd, &env, Some(dir)).unwrap();
+        assert_eq!(status.code(), Some(1));
+        assert_eq!(fs::read_to_string(dir.join("out.txt")).unwrap(), "hi vx\n");
+        assert!(!dir.join("never.txt").exists());
+
+        let status = execute_portable("exit 3; echo no > no.txt", &env, Some(dir)).unwrap();
+        assert_eq!(status.code(), Some(3));
+        assert!(!dir.join("no.txt").exists());
+
+        assert!(run("mkdir sub && cd sub && touch here", dir).success());
+        assert!(dir.join("sub/here").exists());
+    }
+
+    #[test]
+    fn test_unknown_command() {
+        let temp = TempDir::new().unwrap();
+        let status = run("definitely-not-a-real-program-vx", temp.path());
+        assert_eq!(status.code(), Some(127));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_external_program_gets_assignments() {
+        let temp = TempDir::new().unwrap();
+        let status = run(
+            "MODE=release sh -c 'echo $MODE > mode.txt' && test -f mode.txt",
+            temp.path(),
+        );
+        assert!(status.success());
+        assert_eq!(
+            fs::read_to_string(temp.path().join("mode.txt")).unwrap(),
+            "release\n"
+        );
+    }
+}
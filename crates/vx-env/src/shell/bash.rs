@@ -14,6 +14,18 @@ fn escape_single_quoted(value: &str) -> String {
     value.replace('\'', "'\\''")
 }
 
+/// Escape a value for use in bash double-quoted string
+///
+/// Inside double quotes `\`, `"`, `$` and backticks keep their special
+/// meaning, so paths containing them are escaped with a backslash.
+fn escape_double_quoted(value: &str) -> String {
+    value
+        .replace('\\', "\\\\") // Escape backslashes first
+        .replace('"', "\\\"")
+        .replace('$', "\\$")
+        .replace('`', "\\`")
+}
+
 /// Generate a bash script that sets environment variables and executes a command
 ///
 /// # Features
@@ -156,7 +168,7 @@ export _OLD_VX_PS1="${PS1:-}"
 
     // Update PATH
     if !config.path_entries.is_empty() {
-        let paths = config.path_entries.join(":");
+        let paths = escape_double_quoted(&config.path_entries.join(":"));
         script.push_str(&format!(
             "# Add tool paths\nexport PATH=\"{}:$PATH\"\n\n",
             paths
@@ -209,6 +221,21 @@ export PS1="{} ${{_OLD_VX_PS1:-\\w\\$ }}"
     script
 }
 
+/// Generate a deactivation script for Bash/Zsh
+///
+/// Calls the `vx_deactivate` function defined by the activation script.
+pub fn generate_deactivation_script() -> String {
+    r#"# vx environment deactivation script for Bash/Zsh
+# Usage: eval "$(vx deactivate)"
+if command -v vx_deactivate >/dev/null 2>&1; then
+    vx_deactivate
+else
+    echo "No vx environment is currently active." >&2
+fi
+"#
+    .to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -297,6 +324,14 @@ mod tests {
         assert!(script.contains("export PATH=\"/usr/local/bin:/opt/tools/bin:$PATH\""));
     }
 
+    #[test]
+    fn test_full_activation_script_quotes_special_paths() {
+        let config = ActivationConfig::new("test").with_path("/home/J \"Q\" $x `y`/用户/bin");
+
+        let script = generate_full_activation_script(&config);
+        assert!(script.contains(r#"export PATH="/home/J \"Q\" \$x \`y\`/用户/bin:$PATH""#));
+    }
+
     #[test]
     fn test_full_activation_script_with_env_vars() {
         let config = ActivationConfig::new("test")
@@ -339,4 +374,11 @@ mod tests {
         // Single quotes should be escaped
         assert!(script.contains("It'\\''s a \"test\""));
     }
+
+    #[test]
+    fn test_deactivation_script_calls_function() {
+        let script = generate_deactivation_script();
+        assert!(script.contains("command -v vx_deactivate"));
+        assert!(script.contains("    vx_deactivate"));
+    }
 }
@@ -0,0 +1,304 @@
+//! Fish script generator
+//!
+//! Generates fish scripts for environment activation.
+//! Follows the same design patterns as Python's venv and conda.
+
+use super::ActivationConfig;
+use std::collections::HashMap;
+
+/// Escape a value for use in a fish single-quoted string
+///
+/// In fish single-quoted strings, only backslashes and single quotes
+/// need escaping (with a backslash).
+fn escape_single_quoted(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+/// Generate a fish script that sets environment variables and executes a command
+///
+/// PATH-like values are split on `:` so fish sees them as lists.
+pub fn generate_script(cmd: &str, env_vars: &HashMap<String, String>) -> String {
+    let mut script = String::new();
+
+    script.push_str("#!/usr/bin/env fish\n\n");
+    script.push_str(&set_env_lines(env_vars));
+    script.push_str(&format!("\n{}\n", cmd));
+
+    script
+}
+
+/// Generate an activation script for interactive shell use (legacy API)
+///
+/// **Note**: For full virtual environment support, use `generate_full_activation_script` instead.
+pub fn generate_activation_script(env_vars: &HashMap<String, String>) -> String {
+    let mut script = String::new();
+
+    script.push_str("# vx environment activation script\n");
+    script.push_str("# Source it: vx env activate --shell fish | source\n\n");
+    script.push_str(&set_env_lines(env_vars));
+
+    script
+}
+
+/// `set -gx` lines for the given variables
+fn set_env_lines(env_vars: &HashMap<String, String>) -> String {
+    let mut lines = String::new();
+    for (key, value) in env_vars {
+        if key == "PATH" {
+            let entries: Vec<String> = value
+                .split(':')
+                .filter(|p| !p.is_empty())
+                .map(|p| format!("'{}'", escape_single_quoted(p)))
+                .collect();
+            lines.push_str(&format!("set -gx PATH {}\n", entries.join(" ")));
+        } else {
+            lines.push_str(&format!(
+                "set -gx {} '{}'\n",
+                key,
+                escape_single_quoted(value)
+            ));
+        }
+    }
+    lines
+}
+
+/// Generate a complete activation script for fish
+///
+/// Mirrors [`super::bash::generate_full_activation_script`]:
+/// - Saves the original PATH and prompt
+/// - Provides a `vx_deactivate` function to restore the environment
+/// - Updates the prompt to show the environment name
+/// - Prevents double activation
+///
+/// # Example
+///
+/// ```rust
+/// use vx_env::shell::fish::generate_full_activation_script;
+/// use vx_env::shell::ActivationConfig;
+///
+/// let config = ActivationConfig::new("my-project")
+///     .with_path("/home/user/.vx/store/node/20.0.0/bin");
+///
+/// let script = generate_full_activation_script(&config);
+/// assert!(script.contains("function vx_deactivate"));
+/// ```
+pub fn generate_full_activation_script(config: &ActivationConfig) -> String {
+    let mut script = String::new();
+
+    // Header
+    script.push_str("# vx environment activation script for fish\n");
+    script.push_str("# Source it: vx activate --shell fish | source\n\n");
+
+    // Define deactivate function
+    script.push_str(
+        r#"# Deactivate function to restore previous environment
+function vx_deactivate
+    # Restore old PATH
+    if set -q _OLD_VX_PATH
+        set -gx PATH $_OLD_VX_PATH
+        set -e _OLD_VX_PATH
+    end
+
+    # Restore old prompt
+    if functions -q _old_vx_fish_prompt
+        functions -e fish_prompt
+        functions -c _old_vx_fish_prompt fish_prompt
+        functions -e _old_vx_fish_prompt
+    end
+
+    # Unset VX environment variables
+    set -e VX_ACTIVE
+    set -e VX_PROJECT_NAME
+    set -e VX_PROJECT_ROOT
+
+"#,
+    );
+
+    // Unset custom environment variables in deactivate
+    for key in config.env_vars.keys() {
+        if key != "PATH" && !key.starts_with("VX_") {
+            script.push_str(&format!("    set -e {}\n", key));
+        }
+    }
+
+    // Remove aliases in deactivate
+    for alias_name in config.aliases.keys() {
+        script.push_str(&format!("    functions -e {}\n", alias_name));
+    }
+
+    script.push_str(
+        r#"
+    # Self-destruct
+    functions -e vx_deactivate
+end
+
+"#,
+    );
+
+    // Check for double activation
+    script.push_str(
+        r#"# Prevent double activation
+if set -q VX_ACTIVE
+    echo "Warning: vx environment is already active. Run 'vx_deactivate' first." >&2
+    return 1
+end
+
+"#,
+    );
+
+    // Save current environment
+    script.push_str(
+        r#"# Save current environment
+set -gx _OLD_VX_PATH $PATH
+if functions -q fish_prompt
+    functions -c fish_prompt _old_vx_fish_prompt
+end
+
+"#,
+    );
+
+    // Update PATH (fish treats PATH as a list)
+    if !config.path_entries.is_empty() {
+        let paths: Vec<String> = config
+            .path_entries
+            .iter()
+            .map(|p| format!("'{}'", escape_single_quoted(p)))
+            .collect();
+        script.push_str(&format!(
+            "# Add tool paths\nset -gx PATH {} $PATH\n\n",
+            paths.join(" ")
+        ));
+    }
+
+    // Set VX environment marker
+    script.push_str("# VX environment marker\n");
+    script.push_str("set -gx VX_ACTIVE 1\n");
+
+    // Set project name if available
+    if let Some(name) = &config.name {
+        script.push_str(&format!(
+            "set -gx VX_PROJECT_NAME '{}'\n",
+            escape_single_quoted(name)
+        ));
+    }
+
+    // Set custom environment variables
+    if !config.env_vars.is_empty() {
+        script.push_str("\n# Custom environment variables\n");
+        for (key, value) in &config.env_vars {
+            if key == "PATH" {
+                continue; // PATH is handled separately
+            }
+            script.push_str(&format!(
+                "set -gx {} '{}'\n",
+                key,
+                escape_single_quoted(value)
+            ));
+        }
+    }
+
+    // Define aliases
+    if !config.aliases.is_empty() {
+        script.push_str("\n# Shell aliases\n");
+        for (name, command) in &config.aliases {
+            script.push_str(&format!(
+                "alias {} '{}'\n",
+                name,
+                escape_single_quoted(command)
+            ));
+        }
+    }
+
+    // Update prompt
+    let prompt_prefix = escape_single_quoted(&config.prompt_prefix());
+    script.push_str(&format!(
+        r#"
+# Update prompt
+function fish_prompt
+    echo -n '{} '
+    if functions -q _old_vx_fish_prompt
+        _old_vx_fish_prompt
+    end
+end
+
+# Type 'vx_deactivate' to exit the vx environment
+"#,
+        prompt_prefix
+    ));
+
+    script
+}
+
+/// Generate a deactivation script for fish
+///
+/// Calls the `vx_deactivate` function defined by the activation script.
+pub fn generate_deactivation_script() -> String {
+    r#"# vx environment deactivation script for fish
+# Source it: vx deactivate --shell fish | source
+if functions -q vx_deactivate
+    vx_deactivate
+else
+    echo "No vx environment is currently active." >&2
+end
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_single_quoted() {
+        assert_eq!(escape_single_quoted("test"), "test");
+        assert_eq!(escape_single_quoted("it's"), "it\\'s");
+        assert_eq!(escape_single_quoted("C:\\tools"), "C:\\\\tools");
+    }
+
+    #[test]
+    fn test_generate_script_splits_path() {
+        let mut env = HashMap::new();
+        env.insert("PATH".to_string(), "/a/bin:/b/bin".to_string());
+        env.insert("MSG".to_string(), "it's".to_string());
+
+        let script = generate_script("node -v", &env);
+        assert!(script.starts_with("#!/usr/bin/env fish"));
+        assert!(script.contains("set -gx PATH '/a/bin' '/b/bin'"));
+        assert!(script.contains("set -gx MSG 'it\\'s'"));
+        assert!(script.ends_with("node -v\n"));
+    }
+
+    #[test]
+    fn test_full_activation_script_basic() {
+        let config = ActivationConfig::new("my-project");
+        let script = generate_full_activation_script(&config);
+
+        assert!(script.contains("function vx_deactivate"));
+        assert!(script.contains("set -gx PATH $_OLD_VX_PATH"));
+        assert!(script.contains("if set -q VX_ACTIVE"));
+        assert!(script.contains("set -gx _OLD_VX_PATH $PATH"));
+        assert!(script.contains("set -gx VX_ACTIVE 1"));
+        assert!(script.contains("set -gx VX_PROJECT_NAME 'my-project'"));
+        assert!(script.contains("(my-project[vx])"));
+    }
+
+    #[test]
+    fn test_full_activation_script_with_paths_and_env() {
+        let config = ActivationConfig::new("test")
+            .with_path("/usr/local/bin")
+            .with_path("/opt/tools/bin")
+            .with_env("NODE_ENV", "development");
+
+        let script = generate_full_activation_script(&config);
+        assert!(script.contains("set -gx PATH '/usr/local/bin' '/opt/tools/bin' $PATH"));
+        assert!(script.contains("set -gx NODE_ENV 'development'"));
+        assert!(script.contains("    set -e NODE_ENV"));
+    }
+
+    #[test]
+    fn test_deactivation_script_calls_function() {
+        let script = generate_deactivation_script();
+        assert!(script.contains("functions -q vx_deactivate"));
+        assert!(script.contains("    vx_deactivate"));
+    }
+}
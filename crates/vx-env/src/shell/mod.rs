@@ -15,6 +15,8 @@
 
 pub mod bash;
 pub mod cmd;
+pub mod fish;
+pub mod nushell;
 pub mod powershell;
 
 use std::collections::HashMap;
@@ -114,6 +116,8 @@ pub enum Shell {
     Zsh,
     /// Fish shell
     Fish,
+    /// Nushell
+    Nushell,
 }
 
 impl Shell {
@@ -129,6 +133,8 @@ impl Shell {
                     Shell::Zsh
                 } else if shell.contains("fish") {
                     Shell::Fish
+                } else if Shell::parse(&shell) == Some(Shell::Nushell) {
+                    Shell::Nushell
                 } else if shell.contains("bash") {
                     Shell::Bash
                 } else {
@@ -147,6 +153,7 @@ impl Shell {
             Shell::PowerShell => "ps1",
             Shell::Cmd => "bat",
             Shell::Fish => "fish",
+            Shell::Nushell => "nu",
         }
     }
 
@@ -157,10 +164,49 @@ impl Shell {
             Shell::Sh => "sh",
             Shell::Zsh => "zsh",
             Shell::Fish => "fish",
+            Shell::Nushell => "nu",
             Shell::PowerShell => "pwsh",
             Shell::Cmd => "cmd",
         }
     }
+
+    /// Parse a shell name or path (e.g. `zsh`, `/usr/bin/fish`, `pwsh.exe`)
+    pub fn parse(name: &str) -> Option<Self> {
+        let name = name.rsplit(['/', '\\']).next().unwrap_or(name);
+        let name = name.strip_suffix(".exe").unwrap_or(name).to_lowercase();
+        match name.as_str() {
+            "bash" => Some(Shell::Bash),
+            "sh" | "dash" => Some(Shell::Sh),
+            "zsh" => Some(Shell::Zsh),
+            "fish" => Some(Shell::Fish),
+            "nu" | "nushell" => Some(Shell::Nushell),
+            "powershell" | "pwsh" => Some(Shell::PowerShell),
+            "cmd" => Some(Shell::Cmd),
+            _ => None,
+        }
+    }
+
+    /// Generate a full activation script for this shell
+    pub fn activation_script(&self, config: &ActivationConfig) -> String {
+        match self {
+            Shell::Bash | Shell::Sh | Shell::Zsh => bash::generate_full_activation_script(config),
+            Shell::Fish => fish::generate_full_activation_script(config),
+            Shell::Nushell => nushell::generate_full_activation_script(config),
+            Shell::PowerShell => powershell::generate_full_activation_script(config),
+            Shell::Cmd => cmd::generate_full_activation_script(config),
+        }
+    }
+
+    /// Generate a script that undoes [`Shell::activation_script`]
+    pub fn deactivation_script(&self) -> String {
+        match self {
+            Shell::Bash | Shell::Sh | Shell::Zsh => bash::generate_deactivation_script(),
+            Shell::Fish => fish::generate_deactivation_script(),
+            Shell::Nushell => nushell::generate_deactivation_script(),
+            Shell::PowerShell => powershell::generate_deactivation_script(),
+            Shell::Cmd => cmd::generate_deactivation_script(&ActivationConfig::default()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -172,6 +218,8 @@ mod tests {
         assert_eq!(Shell::Bash.extension(), "sh");
         assert_eq!(Shell::PowerShell.extension(), "ps1");
         assert_eq!(Shell::Cmd.extension(), "bat");
+        assert_eq!(Shell::Fish.extension(), "fish");
+        assert_eq!(Shell::Nushell.extension(), "nu");
     }
 
     #[test]
@@ -180,6 +228,40 @@ mod tests {
         assert_eq!(Shell::PowerShell.executable(), "pwsh");
     }
 
+    #[test]
+    fn test_shell_parse() {
+        assert_eq!(Shell::parse("zsh"), Some(Shell::Zsh));
+        assert_eq!(Shell::parse("/usr/local/bin/fish"), Some(Shell::Fish));
+        assert_eq!(Shell::parse("PowerShell"), Some(Shell::PowerShell));
+        assert_eq!(
+            Shell::parse("C:\\Windows\\System32\\cmd.exe"),
+            Some(Shell::Cmd)
+        );
+        assert_eq!(Shell::parse("/usr/bin/nu"), Some(Shell::Nushell));
+        assert_eq!(Shell::parse("tcsh"), None);
+    }
+
+    #[test]
+    fn test_shell_activation_dispatch() {
+        let config = ActivationConfig::new("demo");
+        assert!(
+            Shell::Fish
+                .activation_script(&config)
+                .contains("set -gx VX_ACTIVE 1")
+        );
+        assert!(
+            Shell::Zsh
+                .activation_script(&config)
+                .contains("export VX_ACTIVE=1")
+        );
+        assert!(Shell::Bash.deactivation_script().contains("vx_deactivate"));
+        assert!(
+            Shell::PowerShell
+                .deactivation_script()
+                .contains("Vx-Deactivate")
+        );
+    }
+
     #[test]
     fn test_activation_config_new() {
         let config = ActivationConfig::new("my-project");
@@ -0,0 +1,187 @@
+//! Nushell script generator
+//!
+//! Nushell cannot `eval` text produced by another program, so activation
+//! works differently from the POSIX shells: [`generate_full_activation_script`]
+//! emits a NUON record that the `vx-activate` command defined by
+//! `vx shell init nu` loads with `from nuon` and applies with `load-env`.
+
+use super::ActivationConfig;
+use std::collections::HashMap;
+
+/// Quote a value as a Nushell double-quoted string
+///
+/// The result is also valid JSON, so records built from it parse as NUON.
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '\\' => quoted.push_str("\\\\"),
+            '"' => quoted.push_str("\\\""),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Render a `{ "KEY": "value", ... }` record with keys in sorted order
+fn record(vars: &HashMap<String, String>) -> String {
+    let mut keys: Vec<&String> = vars.keys().collect();
+    keys.sort();
+    let fields: Vec<String> = keys
+        .into_iter()
+        .map(|k| format!("{}: {}", quote(k), quote(&vars[k])))
+        .collect();
+    format!("{{{}}}", fields.join(", "))
+}
+
+/// Generate a Nushell script that sets environment variables and executes a command
+///
+/// PATH-like values are split with the platform separator so Nushell sees
+/// them as lists.
+pub fn generate_script(cmd: &str, env_vars: &HashMap<String, String>) -> String {
+    let mut script = String::new();
+
+    script.push_str("#!/usr/bin/env nu\n\n");
+    script.push_str(&load_env_lines(env_vars));
+    script.push_str(&format!("\n{}\n", cmd));
+
+    script
+}
+
+/// Generate an activation script for interactive shell use (legacy API)
+///
+/// Must be sourced from a file, since Nushell resolves `source` at parse time.
+pub fn generate_activation_script(env_vars: &HashMap<String, String>) -> String {
+    let mut script = String::new();
+
+    script.push_str("# vx environment activation script\n");
+    script.push_str("# Save to a file and `source` it from config.nu\n\n");
+    script.push_str(&load_env_lines(env_vars));
+
+    script
+}
+
+/// `load-env` (and PATH list) lines for the given variables
+fn load_env_lines(env_vars: &HashMap<String, String>) -> String {
+    let mut vars = env_vars.clone();
+    let mut lines = String::new();
+
+    if let Some(path) = vars.remove("PATH") {
+        lines.push_str(&format!(
+            "$env.PATH = ({} | split row (char esep))\n",
+            quote(&path)
+        ));
+    }
+    if !vars.is_empty() {
+        lines.push_str(&format!("load-env {}\n", record(&vars)));
+    }
+
+    lines
+}
+
+/// Generate the activation record consumed by `vx-activate`
+///
+/// The record has three fields:
+/// - `name`: environment name (shown in the prompt)
+/// - `path`: entries to prepend to `$env.PATH`
+/// - `env`: variables to set, including `VX_ACTIVE` and `VX_ACTIVATED_VARS`
+///   (the space-separated list of variables `vx-deactivate` must hide)
+///
+/// Aliases are not supported, since Nushell cannot define them at runtime.
+///
+/// # Example
+///
+/// ```rust
+/// use vx_env::shell::nushell::generate_full_activation_script;
+/// use vx_env::shell::ActivationConfig;
+///
+/// let config = ActivationConfig::new("my-project").with_path("/opt/node/bin");
+/// let record = generate_full_activation_script(&config);
+/// assert!(record.contains(r#""path": ["/opt/node/bin"]"#));
+/// ```
+pub fn generate_full_activation_script(config: &ActivationConfig) -> String {
+    let mut env: HashMap<String, String> = config
+        .env_vars
+        .iter()
+        .filter(|(k, _)| k.as_str() != "PATH")
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    env.insert("VX_ACTIVE".to_string(), "1".to_string());
+    if let Some(name) = &config.name {
+        env.insert("VX_PROJECT_NAME".to_string(), name.clone());
+    }
+
+    let mut activated: Vec<&String> = env.keys().collect();
+    activated.sort();
+    let activated = activated
+        .into_iter()
+        .map(|k| k.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    env.insert("VX_ACTIVATED_VARS".to_string(), activated);
+
+    let path: Vec<String> = config.path_entries.iter().map(|p| quote(p)).collect();
+
+    format!(
+        "{{\"name\": {}, \"prompt\": {}, \"path\": [{}], \"env\": {}}}\n",
+        quote(config.name.as_deref().unwrap_or("vx")),
+        quote(&config.prompt_prefix()),
+        path.join(", "),
+        record(&env)
+    )
+}
+
+/// Generate a deactivation script for Nushell
+///
+/// Deactivation is done entirely by the `vx-deactivate` command defined by
+/// `vx shell init nu`, so this only documents the usage.
+pub fn generate_deactivation_script() -> String {
+    "# Nushell: run `vx-deactivate` (defined by `vx shell init nu`)\n".to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_escapes() {
+        assert_eq!(quote("plain"), "\"plain\"");
+        assert_eq!(quote("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(quote("C:\\tools"), "\"C:\\\\tools\"");
+        assert_eq!(quote("a\nb"), "\"a\\nb\"");
+    }
+
+    #[test]
+    fn test_generate_script_sets_env_and_runs() {
+        let mut env = HashMap::new();
+        env.insert("PATH".to_string(), "/a/bin".to_string());
+        env.insert("NODE_ENV".to_string(), "test".to_string());
+
+        let script = generate_script("node -v", &env);
+        assert!(script.starts_with("#!/usr/bin/env nu"));
+        assert!(script.contains("$env.PATH = (\"/a/bin\" | split row (char esep))"));
+        assert!(script.contains("load-env {\"NODE_ENV\": \"test\"}"));
+        assert!(script.ends_with("node -v\n"));
+    }
+
+    #[test]
+    fn test_full_activation_record() {
+        let config = ActivationConfig::new("demo")
+            .with_path("/opt/node/bin")
+            .with_path("/opt/uv/bin")
+            .with_env("NODE_ENV", "development");
+
+        let record = generate_full_activation_script(&config);
+        assert!(record.contains("\"name\": \"demo\""));
+        assert!(record.contains("\"prompt\": \"(demo[vx])\""));
+        assert!(record.contains("\"path\": [\"/opt/node/bin\", \"/opt/uv/bin\"]"));
+        assert!(record.contains("\"NODE_ENV\": \"development\""));
+        assert!(record.contains("\"VX_ACTIVE\": \"1\""));
+        assert!(record.contains("\"VX_ACTIVATED_VARS\": \"NODE_ENV VX_ACTIVE VX_PROJECT_NAME\""));
+    }
+}
@@ -29,6 +29,18 @@ fn escape_double_quoted(value: &str) -> String {
         .replace('"', "`\"") // Escape double quotes
 }
 
+/// Encode a script for writing to a `.ps1` file
+///
+/// Windows PowerShell 5.1 reads BOM-less scripts with the ANSI code page,
+/// which mangles non-ASCII paths and values (e.g. a localized user name in
+/// `$env:PATH`); a UTF-8 BOM makes every PowerShell version read it as UTF-8.
+pub fn encode_script_file(script: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(script.len() + 3);
+    bytes.extend_from_slice(b"\xEF\xBB\xBF");
+    bytes.extend_from_slice(script.as_bytes());
+    bytes
+}
+
 /// Generate a PowerShell script that sets environment variables and executes a command
 ///
 /// # Features
@@ -273,10 +285,35 @@ function global:prompt {{
     script
 }
 
+/// Generate a deactivation script for PowerShell
+///
+/// Calls the `Vx-Deactivate` function defined by the activation script.
+pub fn generate_deactivation_script() -> String {
+    r#"# vx environment deactivation script for PowerShell
+# Usage: Invoke-Expression ((vx deactivate --shell powershell) -join "`n")
+if (Test-Path function:global:Vx-Deactivate) {
+    Vx-Deactivate
+} else {
+    Write-Warning "No vx environment is currently active."
+}
+"#
+    .to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_encode_script_file_adds_utf8_bom() {
+        let bytes = encode_script_file("$env:PATH = 'C:\\Users\\用户\\bin'");
+        assert_eq!(&bytes[..3], b"\xEF\xBB\xBF");
+        assert_eq!(
+            std::str::from_utf8(&bytes[3..]).unwrap(),
+            "$env:PATH = 'C:\\Users\\用户\\bin'"
+        );
+    }
+
     #[test]
     fn test_escape_single_quoted() {
         // Single quotes should be doubled
@@ -516,4 +553,11 @@ mod tests {
         // Single quotes should be escaped
         assert!(script.contains("$env:MSG = 'It''s a test'"));
     }
+
+    #[test]
+    fn test_deactivation_script_calls_function() {
+        let script = generate_deactivation_script();
+        assert!(script.contains("Test-Path function:global:Vx-Deactivate"));
+        assert!(script.contains("    Vx-Deactivate"));
+    }
 }
@@ -7,6 +7,7 @@ use crate::ToolEnvironment;
 #[cfg(windows)]
 use crate::assets::ShellScript;
 use crate::session::SessionContext;
+use crate::shell::ActivationConfig;
 use anyhow::{Context, Result};
 use std::collections::HashMap;
 use std::env;
@@ -114,6 +115,10 @@ impl ShellSpawner {
 
     /// Get the vx data directory for storing completion scripts and history
     fn get_data_dir() -> Result<std::path::PathBuf> {
+        // Portable mode keeps everything inside the vx home next to the binary
+        if vx_paths::portable::is_active() {
+            return Ok(vx_paths::VxPaths::new()?.base_dir.join("shell"));
+        }
         if cfg!(windows) {
             Ok(dirs::data_local_dir()
                 .ok_or_else(|| anyhow::anyhow!("Failed to get local data directory"))?
@@ -273,20 +278,24 @@ impl ShellSpawner {
             let init_script = self.create_powershell_init_script()?;
 
             // Use a persistent path in vx directory for the init script
-            let vx_temp = dirs::data_local_dir()
-                .unwrap_or_else(std::env::temp_dir)
-                .join("vx")
-                .join("temp");
+            let vx_temp = if vx_paths::portable::is_active() {
+                vx_paths::VxPaths::new()?.tmp_dir
+            } else {
+                dirs::data_local_dir()
+                    .unwrap_or_else(std::env::temp_dir)
+                    .join("vx")
+                    .join("temp")
+            };
             std::fs::create_dir_all(&vx_temp)?;
             let init_path = vx_temp.join("vx_shell_init.ps1");
-            std::fs::write(&init_path, init_script)?;
-
-            command.args([
-                "-NoLogo",
-                "-NoExit",
-                "-File",
-                init_path.to_str().unwrap_or(""),
-            ]);
+            std::fs::write(
+                &init_path,
+                crate::shell::powershell::encode_script_file(&init_script),
+            )?;
+
+            command
+                .args(["-NoLogo", "-NoExit", "-File"])
+                .arg(&init_path);
         } else if shell_path.contains("cmd") {
             // For cmd.exe, we set the prompt via environment variable
             let prompt = format!("({}[vx]) $P$G", self.session.prompt_name());
@@ -376,6 +385,29 @@ impl ShellSpawner {
 
         Ok(output)
     }
+
+    /// Build an activation config for sourcing into the current shell
+    ///
+    /// Only PATH entries that are not already on the current PATH are
+    /// included, so the generated script prepends just the tool directories.
+    pub fn activation_config(&self) -> ActivationConfig {
+        let path = self.env_vars.get("PATH").cloned().unwrap_or_default();
+        let current_path = env::var_os("PATH").unwrap_or_default();
+        let current: Vec<std::path::PathBuf> = env::split_paths(&current_path).collect();
+
+        let path_entries: Vec<String> = env::split_paths(&path)
+            .filter(|p| !p.as_os_str().is_empty() && !current.contains(p))
+            .map(|p| p.display().to_string())
+            .collect();
+
+        let mut config = ActivationConfig::new(self.session.prompt_name())
+            .with_paths(path_entries)
+            .with_envs(self.session.env_vars.clone());
+        if let Some(root) = &self.session.project_root {
+            config = config.with_env("VX_PROJECT_ROOT", root.display().to_string());
+        }
+        config
+    }
 }
 
 /// Detect the user's preferred shell
@@ -0,0 +1,389 @@
+//! Homebrew bottle support
+//!
+//! Bottles are the prebuilt archives Homebrew publishes for every formula as
+//! OCI artifacts on ghcr.io. vx installs them on macOS without Homebrew:
+//!
+//! 1. Fetch the image index of `<formula>:<version>` ([`index_url`]) and pick
+//!    the bottle built for the host ([`host_tags`], [`select_bottle`])
+//! 2. Download the blob ([`blob_url`]) and extract its `<formula>/<version>/`
+//!    directory into the vx store
+//! 3. [`relocate`] the files, replacing the `@@HOMEBREW_PREFIX@@`-style
+//!    placeholders Homebrew leaves in scripts, `.pc` files and Mach-O load
+//!    commands with the install directory
+//!
+//! Only the formula's own paths can be relocated. References to other
+//! formulae (`@@HOMEBREW_PREFIX@@/opt/<dependency>/...`) are reported in
+//! [`RelocationReport::unresolved`], so bottles are best suited to formulae
+//! without runtime dependencies.
+
+use crate::Result;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+/// OCI registry holding the homebrew/core bottles
+pub const GHCR_REPOSITORY: &str = "https://ghcr.io/v2/homebrew/core";
+
+/// Media type of the image index listing a version's bottles
+pub const INDEX_MEDIA_TYPE: &str = "application/vnd.oci.image.index.v1+json";
+
+/// Tag of bottles that run on every platform
+pub const ALL_TAG: &str = "all";
+
+const PREFIX: &str = "@@HOMEBREW_PREFIX@@";
+const CELLAR: &str = "@@HOMEBREW_CELLAR@@";
+const REPOSITORY: &str = "@@HOMEBREW_REPOSITORY@@";
+const PLACEHOLDER_START: &str = "@@HOMEBREW_";
+
+/// macOS releases bottles are built for, newest first
+const MACOS_CODENAMES: &[(u32, &str)] = &[
+    (26, "tahoe"),
+    (15, "sequoia"),
+    (14, "sonoma"),
+    (13, "ventura"),
+    (12, "monterey"),
+    (11, "big_sur"),
+];
+
+/// Repository name of a formula on ghcr.io (`python@3.12` → `python/3.12`)
+pub fn repository_name(formula: &str) -> String {
+    formula.replace('@', "/").replace('+', "x")
+}
+
+/// URL of the image index listing the bottles of `formula` at `version`
+///
+/// `version` is the Homebrew version, including a `_<revision>` suffix for
+/// rebuilt formulae.
+pub fn index_url(formula: &str, version: &str) -> String {
+    format!(
+        "{}/{}/manifests/{}",
+        GHCR_REPOSITORY,
+        repository_name(formula),
+        version
+    )
+}
+
+/// URL of a bottle archive
+pub fn blob_url(formula: &str, digest: &str) -> String {
+    format!(
+        "{}/{}/blobs/sha256:{}",
+        GHCR_REPOSITORY,
+        repository_name(formula),
+        digest.trim_start_matches("sha256:")
+    )
+}
+
+/// Bottle tags that run on macOS `major` on `arch`, best match first
+///
+/// Bottles built for older releases run on newer ones, so every older
+/// release is listed after the host's own. Returns only [`ALL_TAG`] for
+/// releases older than the oldest supported one.
+pub fn tags_for(arch: &str, major: u32) -> Vec<String> {
+    let arm = matches!(arch, "aarch64" | "arm64");
+    MACOS_CODENAMES
+        .iter()
+        .filter(|(release, _)| *release <= major)
+        .map(|(_, name)| {
+            if arm {
+                format!("arm64_{}", name)
+            } else {
+                name.to_string()
+            }
+        })
+        .chain(std::iter::once(ALL_TAG.to_string()))
+        .collect()
+}
+
+/// Bottle tags for the current machine, or `None` when it is not a Mac
+pub fn host_tags() -> Option<Vec<String>> {
+    if !cfg!(target_os = "macos") {
+        return None;
+    }
+    let output = std::process::Command::new("sw_vers")
+        .arg("-productVersion")
+        .output()
+        .ok()?;
+    let version = String::from_utf8_lossy(&output.stdout);
+    let major = version.trim().split('.').next()?.parse().ok()?;
+    Some(tags_for(std::env::consts::ARCH, major))
+}
+
+/// A bottle picked from an image index
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BottleFile {
+    /// Bottle tag, e.g. `arm64_sonoma`
+    pub tag: String,
+    /// SHA-256 of the archive
+    pub digest: String,
+    /// Download URL of the archive
+    pub url: String,
+}
+
+/// Pick the bottle for the first of `tags` that `index` has
+///
+/// `index` is the image index returned by [`index_url`]; each of its
+/// manifests names the bottle tag in `org.opencontainers.image.ref.name`
+/// (`<version>.<tag>`) and the archive digest in `sh.brew.bottle.digest`.
+pub fn select_bottle(
+    formula: &str,
+    index: &serde_json::Value,
+    tags: &[String],
+) -> Option<BottleFile> {
+    let available: Vec<(&str, &str)> = index
+        .get("manifests")?
+        .as_array()?
+        .iter()
+        .filter_map(|manifest| {
+            let annotations = manifest.get("annotations")?;
+            let reference = annotations
+                .get("org.opencontainers.image.ref.name")?
+                .as_str()?;
+            let digest = annotations.get("sh.brew.bottle.digest")?.as_str()?;
+            Some((reference.rsplit_once('.')?.1, digest))
+        })
+        .collect();
+
+    tags.iter().find_map(|tag| {
+        available
+            .iter()
+            .find(|(available_tag, _)| available_tag == tag)
+            .map(|(_, digest)| BottleFile {
+                tag: tag.clone(),
+                digest: digest.to_string(),
+                url: blob_url(formula, digest),
+            })
+    })
+}
+
+/// What [`relocate`] changed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RelocationReport {
+    /// Text files with placeholders replaced
+    pub text_files: usize,
+    /// Mach-O files with rewritten load commands
+    pub macho_files: usize,
+    /// Other formulae the bottle refers to, which are not relocated
+    pub unresolved: BTreeSet<String>,
+}
+
+/// Replace the Homebrew placeholders in the bottle of `formula` extracted to `dir`
+///
+/// The formula's keg (`@@HOMEBREW_CELLAR@@/<formula>/<version>`), its
+/// `opt` link and the prefix itself all become `dir`. Text files are
+/// rewritten in place. Mach-O load commands are changed with
+/// `install_name_tool` and the files re-signed ad hoc, which is only
+/// possible on macOS; elsewhere they are left unchanged.
+pub fn relocate(dir: &Path, formula: &str) -> Result<RelocationReport> {
+    let prefix = dir.to_string_lossy();
+    let mut report = RelocationReport::default();
+
+    for entry in walkdir::WalkDir::new(dir) {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let path = entry.path();
+        let bytes = std::fs::read(path)?;
+        if !contains(&bytes, PLACEHOLDER_START.as_bytes()) {
+            continue;
+        }
+
+        if is_macho(&bytes) {
+            let paths = macho_placeholder_paths(&bytes);
+            for old in &paths {
+                report.unresolved.extend(foreign_formula(old, formula));
+            }
+            if relocate_macho(path, &paths, formula, &prefix)? {
+                report.macho_files += 1;
+            }
+        } else if !bytes.contains(&0) {
+            let text = String::from_utf8_lossy(&bytes);
+            for (start, _) in text.match_indices(PLACEHOLDER_START) {
+                report
+                    .unresolved
+                    .extend(foreign_formula(&text[start..], formula));
+            }
+            let relocated = relocate_str(&text, formula, &prefix);
+            write_file(path, relocated.as_bytes())?;
+            report.text_files += 1;
+        }
+    }
+    Ok(report)
+}
+
+/// `s` with the placeholders of `formula` replaced by `prefix`
+pub fn relocate_str(s: &str, formula: &str, prefix: &str) -> String {
+    // The keg path includes the version directory, which becomes the prefix
+    let keg = format!("{}/{}/", CELLAR, formula);
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find(&keg) {
+        out.push_str(&rest[..start]);
+        out.push_str(prefix);
+        let after = &rest[start + keg.len()..];
+        let end = after
+            .find(|c: char| c == '/' || !is_path_char(c))
+            .unwrap_or(after.len());
+        rest = &after[end..];
+    }
+    out.push_str(rest);
+
+    let opt = format!("{}/opt/{}", PREFIX, formula);
+    let mut relocated = String::with_capacity(out.len());
+    let mut rest = out.as_str();
+    while let Some(start) = rest.find(&opt) {
+        let after = &rest[start + opt.len()..];
+        relocated.push_str(&rest[..start]);
+        // `opt/jq` must not match `opt/jqx`
+        if after.starts_with(|c: char| is_path_char(c) && c != '/') {
+            relocated.push_str(&opt);
+        } else {
+            relocated.push_str(prefix);
+        }
+        rest = after;
+    }
+    relocated.push_str(rest);
+
+    relocated
+        .replace(PREFIX, prefix)
+        .replace(CELLAR, prefix)
+        .replace(REPOSITORY, prefix)
+}
+
+/// Whether `bytes` start with a Mach-O (thin or universal) header
+pub fn is_macho(bytes: &[u8]) -> bool {
+    matches!(
+        bytes.get(..4),
+        Some(
+            [0xfe, 0xed, 0xfa, 0xce]
+                | [0xfe, 0xed, 0xfa, 0xcf]
+                | [0xce, 0xfa, 0xed, 0xfe]
+                | [0xcf, 0xfa, 0xed, 0xfe]
+                | [0xca, 0xfe, 0xba, 0xbe]
+        )
+    )
+}
+
+/// NUL-terminated placeholder paths in a Mach-O file (install names and rpaths)
+pub fn macho_placeholder_paths(bytes: &[u8]) -> Vec<String> {
+    let needle = PLACEHOLDER_START.as_bytes();
+    let mut paths = Vec::new();
+    let mut offset = 0;
+    while let Some(found) = find(&bytes[offset..], needle) {
+        let start = offset + found;
+        let end = bytes[start..]
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |len| start + len);
+        let path = String::from_utf8_lossy(&bytes[start..end]).into_owned();
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+        offset = end;
+    }
+    paths
+}
+
+/// Rewrite the load commands of a Mach-O file; `false` when not on macOS
+#[cfg(target_os = "macos")]
+fn relocate_macho(path: &Path, paths: &[String], formula: &str, prefix: &str) -> Result<bool> {
+    use std::process::Command;
+
+    let file_name = path.file_name().map(|n| n.to_string_lossy().into_owned());
+    let mut args: Vec<String> = Vec::new();
+    for old in paths {
+        let new = relocate_str(old, formula, prefix);
+        let is_library = old.ends_with(".dylib") || old.contains(".framework/");
+        if !is_library {
+            args.extend(["-rpath".to_string(), old.clone(), new]);
+        } else if file_name
+            .as_deref()
+            .is_some_and(|name| old.ends_with(&format!("/{}", name)))
+        {
+            args.extend(["-id".to_string(), new]);
+        } else {
+            args.extend(["-change".to_string(), old.clone(), new]);
+        }
+    }
+
+    make_writable(path)?;
+    let run = |program: &str, args: &[String]| -> Result<()> {
+        let output = Command::new(program).args(args).arg(path).output()?;
+        if !output.status.success() {
+            return Err(crate::Error::extraction_failed(
+                path,
+                format!(
+                    "{} failed: {}",
+                    program,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ),
+            ));
+        }
+        Ok(())
+    };
+    run("install_name_tool", &args)?;
+    // Changing load commands invalidates the signature, which arm64 requires
+    run(
+        "codesign",
+        &["--force".to_string(), "--sign".to_string(), "-".to_string()],
+    )?;
+    Ok(true)
+}
+
+#[cfg(not(target_os = "macos"))]
+fn relocate_macho(path: &Path, _paths: &[String], _formula: &str, _prefix: &str) -> Result<bool> {
+    tracing::debug!(
+        "Leaving Mach-O load commands of {} unchanged outside macOS",
+        path.display()
+    );
+    Ok(false)
+}
+
+/// The other formula a placeholder path at the start of `s` refers to, if any
+fn foreign_formula(s: &str, formula: &str) -> Option<String> {
+    let rest = s
+        .strip_prefix(&format!("{}/opt/", PREFIX))
+        .or_else(|| s.strip_prefix(&format!("{}/", CELLAR)))?;
+    let name: String = rest
+        .chars()
+        .take_while(|&c| c != '/' && is_path_char(c))
+        .collect();
+    (!name.is_empty() && name != formula).then_some(name)
+}
+
+fn is_path_char(c: char) -> bool {
+    !c.is_whitespace() && !matches!(c, '"' | '\'' | ':' | ';' | '\0' | '`' | ')' | '(')
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    find(haystack, needle).is_some()
+}
+
+/// Bottles ship many files read-only
+fn make_writable(path: &Path) -> Result<()> {
+    let mut permissions = std::fs::metadata(path)?.permissions();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        permissions.set_mode(permissions.mode() | 0o200);
+    }
+    #[cfg(not(unix))]
+    #[allow(clippy::permissions_set_readonly_false)]
+    permissions.set_readonly(false);
+    std::fs::set_permissions(path, permissions)?;
+    Ok(())
+}
+
+/// Overwrite `path`, keeping its permissions
+fn write_file(path: &Path, contents: &[u8]) -> Result<()> {
+    let permissions = std::fs::metadata(path)?.permissions();
+    make_writable(path)?;
+    std::fs::write(path, contents)?;
+    std::fs::set_permissions(path, permissions)?;
+    Ok(())
+}
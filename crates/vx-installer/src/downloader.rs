@@ -7,6 +7,7 @@ use sha2::Digest;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use tracing::{debug, warn};
+use vx_paths::TempWorkspace;
 
 /// HTTP downloader for fetching files from URLs
 pub struct Downloader {
@@ -30,7 +31,7 @@ impl Downloader {
 
     /// Create a new downloader with default configuration
     pub fn new() -> Result<Self> {
-        let client = reqwest::Client::builder()
+        let client = vx_proxy::client_builder()
             .user_agent(USER_AGENT)
             .timeout(std::time::Duration::from_secs(300)) // 5 minutes
             .build()?;
@@ -46,7 +47,7 @@ impl Downloader {
 
     /// Create a downloader with CDN acceleration enabled
     pub fn with_cdn(cdn_enabled: bool) -> Result<Self> {
-        let client = reqwest::Client::builder()
+        let client = vx_proxy::client_builder()
             .user_agent(USER_AGENT)
             .timeout(std::time::Duration::from_secs(300))
             .build()?;
@@ -66,7 +67,7 @@ impl Downloader {
     /// * `timeout` - Download timeout duration
     /// * `cdn_enabled` - Whether to enable CDN acceleration
     pub fn with_timeout(timeout: Duration, cdn_enabled: bool) -> Result<Self> {
-        let client = reqwest::Client::builder()
+        let client = vx_proxy::client_builder()
             .user_agent(USER_AGENT)
             .timeout(timeout)
             .build()?;
@@ -205,7 +206,9 @@ impl Downloader {
             debug!("Downloading from: {}", download_url);
 
             // Start the download request
-            let response = match self.client.get(*download_url).send().await {
+            let request =
+                vx_proxy::credentials::authorize(self.client.get(*download_url), download_url);
+            let response = match request.send().await {
                 Ok(resp) => resp,
                 Err(e) => {
                     let error = if e.is_timeout() {
@@ -345,24 +348,28 @@ impl Downloader {
         }
     }
 
-    /// Download a file to a temporary location and return the path
-    pub async fn download_temp(&self, url: &str, progress: &ProgressContext) -> Result<PathBuf> {
+    /// Download a file into a new temp workspace under `~/.vx/tmp`
+    ///
+    /// The file is removed together with the returned workspace, so it is
+    /// cleaned up even when the install using it fails.
+    pub async fn download_temp(
+        &self,
+        url: &str,
+        progress: &ProgressContext,
+    ) -> Result<(TempWorkspace, PathBuf)> {
         // First, try to get the actual filename from the server
         let filename = self
             .get_filename_from_server(url)
             .await
             .unwrap_or_else(|| self.extract_filename_from_url(url));
 
-        let temp_dir = tempfile::tempdir()?;
-        let temp_path = temp_dir.path().join(filename);
+        let workspace = TempWorkspace::new("download")
+            .map_err(|e| Error::download_failed(url, e.to_string()))?;
+        let temp_path = workspace.join(filename);
 
         self.download(url, &temp_path, progress).await?;
 
-        // Convert to a persistent path (caller is responsible for cleanup)
-        let persistent_path = temp_path.clone();
-        std::mem::forget(temp_dir); // Prevent automatic cleanup
-
-        Ok(persistent_path)
+        Ok((workspace, temp_path))
     }
 
     /// Get filename from server response headers
@@ -508,7 +515,8 @@ impl Downloader {
         sidecar_url: &str,
         asset_filename: &str,
     ) -> Result<Option<String>> {
-        let response = match self.client.get(sidecar_url).send().await {
+        let request = vx_proxy::credentials::authorize(self.client.get(sidecar_url), sidecar_url);
+        let response = match vx_proxy::http_cache::send(request).await {
             Ok(resp) => resp,
             Err(e) => {
                 // If the server returned a 404, the sidecar simply doesn't exist
@@ -533,8 +541,7 @@ impl Downloader {
             return Ok(None);
         }
 
-        let content = response.text().await?;
-        Ok(parse_checksum_content(&content, asset_filename))
+        Ok(parse_checksum_content(&response.text(), asset_filename))
     }
 
     /// Download and verify checksum
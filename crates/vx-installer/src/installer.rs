@@ -124,7 +124,7 @@ impl Installer {
             })?;
 
         // Download the archive
-        let temp_path = self
+        let (workspace, temp_path) = self
             .downloader
             .download_temp(download_url, progress)
             .await?;
@@ -136,10 +136,12 @@ impl Installer {
             .verify_sidecar_checksum(download_url, &temp_path)
             .await?;
 
-        // Extract the archive
+        // Extract inside the workspace, then move the complete tree into
+        // the install directory so a failed extraction leaves nothing there
+        let extract_dir = workspace.join("extract");
         let extracted_files = self
             .extractor
-            .extract(&temp_path, &config.install_dir, progress)
+            .extract(&temp_path, &extract_dir, progress)
             .await?;
 
         // Find the best executable
@@ -147,10 +149,14 @@ impl Installer {
             .extractor
             .find_best_executable(&extracted_files, &config.tool_name)?;
 
-        // Clean up temporary file
-        let _ = std::fs::remove_file(temp_path);
+        workspace
+            .move_contents(&extract_dir, &config.install_dir)
+            .map_err(|e| Error::extraction_failed(&temp_path, format!("{:#}", e)))?;
 
-        Ok(executable_path)
+        let relative = executable_path
+            .strip_prefix(&extract_dir)
+            .unwrap_or(&executable_path);
+        Ok(config.install_dir.join(relative))
     }
 
     /// Install binary file directly
@@ -192,7 +198,7 @@ impl Installer {
         let exe_path = bin_dir.join(target_name);
 
         // Download to temporary location first
-        let temp_path = self
+        let (_workspace, temp_path) = self
             .downloader
             .download_temp(download_url, progress)
             .await?;
@@ -13,6 +13,7 @@
 //! - **Platform Agnostic**: Works across Windows, macOS, and Linux
 //! - **Async Support**: Fully async API for non-blocking operations
 //! - **CDN Acceleration**: Optional CDN optimization via turbo-cdn
+//! - **Homebrew Bottles**: Relocation of Homebrew bottles into the vx store
 //!
 //! ## Example
 //!
@@ -41,6 +42,7 @@
 //! }
 //! ```
 
+pub mod bottle;
 pub mod cdn;
 pub mod downloader;
 pub mod error;
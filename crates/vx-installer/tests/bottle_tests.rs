@@ -0,0 +1,146 @@
+//! Tests for Homebrew bottle selection and relocation
+
+use vx_installer::bottle::{
+    self, blob_url, index_url, is_macho, macho_placeholder_paths, relocate, relocate_str,
+    select_bottle, tags_for,
+};
+
+fn index() -> serde_json::Value {
+    let manifest = |tag: &str, digest: &str| {
+        serde_json::json!({
+            "mediaType": "application/vnd.oci.image.manifest.v1+json",
+            "annotations": {
+                "org.opencontainers.image.ref.name": format!("1.7.1_1.{}", tag),
+                "sh.brew.bottle.digest": digest,
+            },
+        })
+    };
+    serde_json::json!({
+        "schemaVersion": 2,
+        "mediaType": bottle::INDEX_MEDIA_TYPE,
+        "manifests": [
+            manifest("arm64_sequoia", "aaa"),
+            manifest("arm64_sonoma", "bbb"),
+            manifest("sonoma", "ccc"),
+            manifest("x86_64_linux", "ddd"),
+        ],
+    })
+}
+
+#[test]
+fn test_urls_use_ghcr_repository_names() {
+    assert_eq!(
+        index_url("python@3.12", "3.12.7_1"),
+        "https://ghcr.io/v2/homebrew/core/python/3.12/manifests/3.12.7_1"
+    );
+    assert_eq!(
+        blob_url("libxml++", "sha256:abc"),
+        "https://ghcr.io/v2/homebrew/core/libxmlxx/blobs/sha256:abc"
+    );
+}
+
+#[test]
+fn test_tags_fall_back_to_older_releases() {
+    assert_eq!(
+        tags_for("aarch64", 14),
+        vec![
+            "arm64_sonoma",
+            "arm64_ventura",
+            "arm64_monterey",
+            "arm64_big_sur",
+            "all"
+        ]
+    );
+    assert_eq!(tags_for("x86_64", 12), vec!["monterey", "big_sur", "all"]);
+    assert_eq!(tags_for("x86_64", 10), vec!["all"]);
+}
+
+#[test]
+fn test_select_bottle_prefers_host_release() {
+    let index = index();
+    let bottle = select_bottle("jq", &index, &tags_for("aarch64", 15)).unwrap();
+    assert_eq!(bottle.tag, "arm64_sequoia");
+    assert_eq!(bottle.digest, "aaa");
+    assert_eq!(bottle.url, blob_url("jq", "aaa"));
+
+    // Tahoe has no bottle of its own yet and reuses the Sequoia one
+    let bottle = select_bottle("jq", &index, &tags_for("aarch64", 26)).unwrap();
+    assert_eq!(bottle.tag, "arm64_sequoia");
+
+    assert_eq!(
+        select_bottle("jq", &index, &tags_for("x86_64", 15))
+            .unwrap()
+            .tag,
+        "sonoma"
+    );
+    assert!(select_bottle("jq", &index, &tags_for("x86_64", 13)).is_none());
+}
+
+#[test]
+fn test_relocate_str_replaces_own_paths_only() {
+    let prefix = "/home/me/.vx/store/jq/1.7.1_1";
+    assert_eq!(
+        relocate_str("@@HOMEBREW_CELLAR@@/jq/1.7.1_1/lib", "jq", prefix),
+        format!("{}/lib", prefix)
+    );
+    assert_eq!(
+        relocate_str("@@HOMEBREW_PREFIX@@/opt/jq/lib/libjq.1.dylib", "jq", prefix),
+        format!("{}/lib/libjq.1.dylib", prefix)
+    );
+    // Another formula whose name starts with the same letters
+    assert_eq!(
+        relocate_str("@@HOMEBREW_PREFIX@@/opt/jqx/bin", "jq", prefix),
+        format!("{}/opt/jqx/bin", prefix)
+    );
+    assert_eq!(
+        relocate_str("prefix=@@HOMEBREW_PREFIX@@", "jq", prefix),
+        format!("prefix={}", prefix)
+    );
+}
+
+#[test]
+fn test_relocate_rewrites_text_files_and_reports_dependencies() {
+    let dir = tempfile::tempdir().unwrap();
+    let pc = dir.path().join("lib").join("pkgconfig");
+    std::fs::create_dir_all(&pc).unwrap();
+    std::fs::write(
+        pc.join("libjq.pc"),
+        "prefix=@@HOMEBREW_CELLAR@@/jq/1.7.1_1\n\
+         Requires.private: @@HOMEBREW_PREFIX@@/opt/oniguruma/lib\n",
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("README"), "no placeholders").unwrap();
+
+    let report = relocate(dir.path(), "jq").unwrap();
+    assert_eq!(report.text_files, 1);
+    assert_eq!(report.macho_files, 0);
+    assert_eq!(
+        report.unresolved.into_iter().collect::<Vec<_>>(),
+        vec!["oniguruma"]
+    );
+
+    let relocated = std::fs::read_to_string(pc.join("libjq.pc")).unwrap();
+    let prefix = dir.path().to_string_lossy();
+    assert!(relocated.starts_with(&format!("prefix={}\n", prefix)));
+    assert!(relocated.contains(&format!("{}/opt/oniguruma/lib", prefix)));
+    assert!(!relocated.contains("@@HOMEBREW_"));
+}
+
+#[test]
+fn test_macho_placeholder_paths() {
+    let mut bytes = vec![0xcf, 0xfa, 0xed, 0xfe, 0, 0];
+    bytes.extend_from_slice(b"@@HOMEBREW_PREFIX@@/opt/jq/lib/libjq.1.dylib\0");
+    bytes.extend_from_slice(b"/usr/lib/libSystem.B.dylib\0");
+    bytes.extend_from_slice(b"@@HOMEBREW_CELLAR@@/jq/1.7.1_1/lib\0");
+    bytes.extend_from_slice(b"@@HOMEBREW_PREFIX@@/opt/jq/lib/libjq.1.dylib\0");
+
+    assert!(is_macho(&bytes));
+    assert!(!is_macho(b"#!/bin/sh\n"));
+    assert_eq!(
+        macho_placeholder_paths(&bytes),
+        vec![
+            "@@HOMEBREW_PREFIX@@/opt/jq/lib/libjq.1.dylib",
+            "@@HOMEBREW_CELLAR@@/jq/1.7.1_1/lib"
+        ]
+    );
+}
@@ -0,0 +1,143 @@
+//! Stage timing budgets
+//!
+//! Compares pipeline stage timings against budgets (e.g. resolve under
+//! 100ms) and turns overruns into suggestions for speeding vx up.
+
+use serde::Serialize;
+
+use crate::report::CommandMetrics;
+
+/// Time budget per pipeline stage, in milliseconds
+#[derive(Debug, Clone, PartialEq)]
+pub struct StageBudgets {
+    /// Version resolution
+    pub resolve_ms: f64,
+    /// Checking (or installing) the runtime
+    pub ensure_ms: f64,
+    /// Building the environment and PATH
+    pub prepare_ms: f64,
+}
+
+impl Default for StageBudgets {
+    fn default() -> Self {
+        Self {
+            resolve_ms: 100.0,
+            ensure_ms: 500.0,
+            prepare_ms: 100.0,
+        }
+    }
+}
+
+impl StageBudgets {
+    /// Stages that have a budget, in pipeline order
+    pub const STAGES: [&'static str; 3] = ["resolve", "ensure", "prepare"];
+
+    /// Budget for `stage`, if it has one
+    pub fn get(&self, stage: &str) -> Option<f64> {
+        match stage {
+            "resolve" => Some(self.resolve_ms),
+            "ensure" => Some(self.ensure_ms),
+            "prepare" => Some(self.prepare_ms),
+            _ => None,
+        }
+    }
+}
+
+/// A stage that went over its budget
+#[derive(Debug, Clone, Serialize)]
+pub struct BudgetWarning {
+    /// Stage name (`resolve`, `ensure`, `prepare`)
+    pub stage: String,
+    /// The stage's budget
+    pub budget_ms: f64,
+    /// Runs where the stage went over budget
+    pub runs_over: usize,
+    /// Runs that recorded the stage
+    pub runs: usize,
+    /// Slowest recorded duration
+    pub slowest_ms: f64,
+    /// Average duration across the runs that recorded the stage
+    pub average_ms: f64,
+    /// What to try
+    pub suggestions: Vec<String>,
+}
+
+/// Stages of `runs` that went over `budgets`, in pipeline order
+pub fn check_budgets(runs: &[CommandMetrics], budgets: &StageBudgets) -> Vec<BudgetWarning> {
+    StageBudgets::STAGES
+        .iter()
+        .filter_map(|&stage| {
+            let budget = budgets.get(stage)?;
+            let durations: Vec<f64> = runs
+                .iter()
+                .filter_map(|r| r.stages.get(stage).map(|s| s.duration_ms))
+                .collect();
+            let runs_over = durations.iter().filter(|&&d| d > budget).count();
+            if runs_over == 0 {
+                return None;
+            }
+            Some(BudgetWarning {
+                stage: stage.to_string(),
+                budget_ms: budget,
+                runs_over,
+                runs: durations.len(),
+                slowest_ms: durations.iter().copied().fold(0.0, f64::max),
+                average_ms: durations.iter().sum::<f64>() / durations.len() as f64,
+                suggestions: suggestions(stage),
+            })
+        })
+        .collect()
+}
+
+fn suggestions(stage: &str) -> Vec<String> {
+    let suggestions: &[&str] = match stage {
+        "resolve" => &[
+            "Pin exact versions with `vx lock`, so resolve reads vx.lock instead of version lists",
+            "Rebuild stale version caches once with `--cache-mode refresh`, or skip network lookups with `--cache-mode offline`",
+        ],
+        "ensure" => &[
+            "Install project tools ahead of time with `vx sync` (e.g. in CI setup), so commands don't install on first use",
+        ],
+        "prepare" => &[
+            "PATH construction scans installed runtimes; `vx cache prune --orphaned` removes versions no project uses",
+        ],
+        _ => &[],
+    };
+    suggestions.iter().map(|s| s.to_string()).collect()
+}
+
+/// Render budget warnings for the terminal
+///
+/// Empty when nothing went over budget.
+pub fn render_budget_warnings(warnings: &[BudgetWarning]) -> String {
+    if warnings.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::new();
+    out.push_str("\n  Stage Budgets:\n");
+    out.push_str("  ─────────────────────────────────────────────────────────────\n");
+    for warning in warnings {
+        if warning.runs == 1 {
+            out.push_str(&format!(
+                "  ⏱  '{}' took {:.0}ms (budget {:.0}ms)\n",
+                warning.stage, warning.slowest_ms, warning.budget_ms
+            ));
+        } else {
+            out.push_str(&format!(
+                "  ⏱  '{}' went over its {:.0}ms budget in {} of {} runs (avg {:.0}ms, slowest {:.0}ms)\n",
+                warning.stage,
+                warning.budget_ms,
+                warning.runs_over,
+                warning.runs,
+                warning.average_ms,
+                warning.slowest_ms
+            ));
+        }
+        for suggestion in &warning.suggestions {
+            out.push_str(&format!("         → {}\n", suggestion));
+        }
+    }
+    out.push_str("  ─────────────────────────────────────────────────────────────\n");
+    out
+}
@@ -22,7 +22,9 @@ use tracing_subscriber::Layer;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 
+use crate::budget::{StageBudgets, check_budgets, render_budget_warnings};
 use crate::exporter::JsonFileExporter;
+use crate::prometheus::CommandLatencies;
 use crate::report::CommandMetrics;
 
 /// Configuration for metrics initialization.
@@ -36,6 +38,10 @@ pub struct MetricsConfig {
     pub command: String,
     /// Custom metrics output directory (defaults to ~/.vx/metrics)
     pub metrics_dir: Option<PathBuf>,
+    /// Don't write metrics reports (the user opted out)
+    pub disabled: bool,
+    /// Warn on stderr when stages of this run go over these budgets
+    pub budgets: Option<StageBudgets>,
 }
 
 /// Guard that flushes metrics to disk on drop.
@@ -48,6 +54,8 @@ pub struct MetricsGuard {
     start_time: Instant,
     command: String,
     metrics_dir: PathBuf,
+    disabled: bool,
+    budgets: Option<StageBudgets>,
     exit_code: Arc<std::sync::atomic::AtomicI32>,
 }
 
@@ -76,6 +84,9 @@ impl MetricsGuard {
 
         let spans = self.exporter.take_spans();
         let token_savings = crate::drain_token_savings();
+        if self.disabled {
+            return Ok(());
+        }
 
         // Skip writing if no spans or token savings were collected (e.g., --help)
         if spans.is_empty() && token_savings.is_empty() {
@@ -92,6 +103,19 @@ impl MetricsGuard {
         metrics.token_savings = token_savings;
         metrics.extract_stages_from_spans();
 
+        // Latency histograms are best-effort, like the report itself
+        let _ = CommandLatencies::record(
+            &self.metrics_dir,
+            &self.command,
+            elapsed.as_secs_f64(),
+            exit_code == 0,
+        );
+
+        if let Some(budgets) = &self.budgets {
+            let warnings = check_budgets(std::slice::from_ref(&metrics), budgets);
+            eprint!("{}", render_budget_warnings(&warnings));
+        }
+
         // Ensure metrics directory exists
         std::fs::create_dir_all(&self.metrics_dir)?;
 
@@ -196,6 +220,8 @@ pub fn init(config: MetricsConfig) -> MetricsGuard {
         start_time,
         command,
         metrics_dir,
+        disabled: config.disabled,
+        budgets: config.budgets,
         exit_code: Arc::new(std::sync::atomic::AtomicI32::new(0)),
     }
 }
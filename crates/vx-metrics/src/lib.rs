@@ -53,14 +53,23 @@
 //! // On exit, guard flushes spans to JSON file
 //! ```
 
+pub mod budget;
 pub mod exporter;
 pub mod init;
+pub mod prometheus;
 pub mod report;
+pub mod shim;
 pub mod token_savings;
 pub mod visualize;
 
+pub use budget::{BudgetWarning, StageBudgets, check_budgets, render_budget_warnings};
 pub use init::{MetricsConfig, MetricsGuard, fmt_filter_directive, init, otel_filter_directive};
+pub use prometheus::{CommandLatencies, PrometheusText, command_label};
 pub use report::{CommandMetrics, StageMetrics, TokenSavingsRecord};
+pub use shim::{
+    ShimInvocation, ShimLatencySummary, load_shim_invocations, record_shim_invocation,
+    render_shim_latency, summarize_shim_latency,
+};
 pub use token_savings::{
     build_token_savings_record, drain_token_savings, estimate_tokens, record_token_savings,
     render_token_savings, summarize_token_savings,
@@ -0,0 +1,237 @@
+//! Prometheus exposition of command latencies
+//!
+//! Per-run reports in `~/.vx/metrics` are pruned to the latest few, so
+//! [`CommandLatencies`] keeps a running histogram per command next to them
+//! that only grows, as Prometheus counters must. [`PrometheusText`] renders
+//! samples in the text format Prometheus scrapes.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Write as _};
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use vx_cache::with_file_lock;
+
+/// Upper bounds of the latency buckets, in seconds
+pub const LATENCY_BUCKETS: [f64; 11] =
+    [0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 300.0];
+
+/// Running latency histogram of one command
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LatencyHistogram {
+    /// Runs that took at most each of [`LATENCY_BUCKETS`] (cumulative)
+    pub buckets: Vec<u64>,
+    /// All runs
+    pub count: u64,
+    /// Total wall time of all runs
+    pub sum_seconds: f64,
+    /// Runs that exited with a non-zero code
+    pub failures: u64,
+}
+
+impl LatencyHistogram {
+    /// Count one run
+    pub fn observe(&mut self, seconds: f64, success: bool) {
+        self.buckets.resize(LATENCY_BUCKETS.len(), 0);
+        for (bucket, le) in self.buckets.iter_mut().zip(LATENCY_BUCKETS) {
+            if seconds <= le {
+                *bucket += 1;
+            }
+        }
+        self.count += 1;
+        self.sum_seconds += seconds;
+        if !success {
+            self.failures += 1;
+        }
+    }
+}
+
+/// Latency histograms of every command run on this machine
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CommandLatencies {
+    /// Histogram per [`command_label`]
+    pub commands: BTreeMap<String, LatencyHistogram>,
+}
+
+impl CommandLatencies {
+    /// File holding the histograms under `metrics_dir`
+    ///
+    /// It lives in a subdirectory so report listing and pruning, which look
+    /// at the `.json` files of `metrics_dir` itself, never see it.
+    pub fn path(metrics_dir: &Path) -> PathBuf {
+        metrics_dir.join("totals").join("latency.json")
+    }
+
+    /// Histograms recorded under `metrics_dir`, empty when there are none
+    pub fn load(metrics_dir: &Path) -> Self {
+        std::fs::read_to_string(Self::path(metrics_dir))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Count one run of `command` (the full command line)
+    ///
+    /// The update holds a file lock, so concurrent vx processes don't lose runs.
+    pub fn record(metrics_dir: &Path, command: &str, seconds: f64, success: bool) -> Result<()> {
+        let path = Self::path(metrics_dir);
+        with_file_lock(&path, || {
+            let mut latencies = Self::load(metrics_dir);
+            latencies
+                .commands
+                .entry(command_label(command))
+                .or_default()
+                .observe(seconds, success);
+
+            // Write then rename, so a concurrent scrape never reads half a file
+            let temp = path.with_extension("json.tmp");
+            std::fs::write(&temp, serde_json::to_string(&latencies)?)?;
+            std::fs::rename(&temp, &path)?;
+            Ok(())
+        })
+    }
+
+    /// Add `vx_command_duration_seconds` and `vx_command_failures_total`
+    pub fn render(&self, out: &mut PrometheusText) {
+        out.header(
+            "vx_command_duration_seconds",
+            MetricKind::Histogram,
+            "Wall time of vx commands",
+        );
+        for (command, histogram) in &self.commands {
+            let labels = [("command", command.as_str())];
+            for (i, le) in LATENCY_BUCKETS.iter().enumerate() {
+                let count = histogram.buckets.get(i).copied().unwrap_or_default();
+                out.sample(
+                    "vx_command_duration_seconds_bucket",
+                    &[("command", command), ("le", &le.to_string())],
+                    count,
+                );
+            }
+            out.sample(
+                "vx_command_duration_seconds_bucket",
+                &[("command", command), ("le", "+Inf")],
+                histogram.count,
+            );
+            out.sample(
+                "vx_command_duration_seconds_sum",
+                &labels,
+                histogram.sum_seconds,
+            );
+            out.sample(
+                "vx_command_duration_seconds_count",
+                &labels,
+                histogram.count,
+            );
+        }
+
+        out.header(
+            "vx_command_failures_total",
+            MetricKind::Counter,
+            "vx commands that exited with a non-zero code",
+        );
+        for (command, histogram) in &self.commands {
+            out.sample(
+                "vx_command_failures_total",
+                &[("command", command)],
+                histogram.failures,
+            );
+        }
+    }
+}
+
+/// The subcommand or tool a command line ran, e.g. `node` for
+/// `vx --verbose node --version`
+///
+/// Shims run under the tool's own name, which is used as is.
+pub fn command_label(command: &str) -> String {
+    let mut args = command.split_whitespace();
+    let program = args.next().unwrap_or_default();
+    // Windows accepts either separator
+    let file_name = program.rsplit(['/', '\\']).next().unwrap_or_default();
+    let stem = file_name.strip_suffix(".exe").unwrap_or(file_name);
+    if !stem.is_empty() && stem != "vx" {
+        return stem.to_string();
+    }
+    args.find(|arg| !arg.starts_with('-'))
+        .unwrap_or("vx")
+        .to_string()
+}
+
+/// Type of a metric family
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricKind {
+    Counter,
+    Gauge,
+    Histogram,
+}
+
+impl MetricKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Counter => "counter",
+            Self::Gauge => "gauge",
+            Self::Histogram => "histogram",
+        }
+    }
+}
+
+/// Builder of a Prometheus text exposition
+///
+/// ```rust
+/// use vx_metrics::prometheus::{MetricKind, PrometheusText};
+///
+/// let mut out = PrometheusText::new();
+/// out.header("vx_installs_total", MetricKind::Counter, "Runtimes installed");
+/// out.sample("vx_installs_total", &[], 3);
+/// assert!(out.finish().ends_with("vx_installs_total 3\n"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PrometheusText {
+    out: String,
+}
+
+impl PrometheusText {
+    /// Empty exposition
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start the metric family `name`
+    pub fn header(&mut self, name: &str, kind: MetricKind, help: &str) {
+        let _ = writeln!(self.out, "# HELP {} {}", name, help.replace('\n', " "));
+        let _ = writeln!(self.out, "# TYPE {} {}", name, kind.as_str());
+    }
+
+    /// Add one sample of the current family
+    pub fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: impl Display) {
+        self.out.push_str(name);
+        if !labels.is_empty() {
+            let labels: Vec<String> = labels
+                .iter()
+                .map(|(key, value)| format!("{}=\"{}\"", key, escape_label(value)))
+                .collect();
+            let _ = write!(self.out, "{{{}}}", labels.join(","));
+        }
+        let _ = writeln!(self.out, " {}", value);
+    }
+
+    /// Start a family and add its only, unlabelled sample
+    pub fn single(&mut self, name: &str, kind: MetricKind, help: &str, value: impl Display) {
+        self.header(name, kind, help);
+        self.sample(name, &[], value);
+    }
+
+    /// The exposition text
+    pub fn finish(self) -> String {
+        self.out
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
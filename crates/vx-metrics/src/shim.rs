@@ -0,0 +1,217 @@
+//! Shim dispatch telemetry
+//!
+//! With `VX_SHIM_TELEMETRY=1`, vx-shim appends one [`ShimInvocation`] per
+//! executed shim to `~/.vx/metrics/shim/invocations.jsonl`. Each record splits
+//! the time vx spends before the target process starts (target resolution and
+//! environment setup) from the time the target itself runs, so the cost of
+//! going through vx can be compared with direct execution.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// Records kept when the log is compacted
+pub const MAX_SHIM_RECORDS: usize = 2000;
+
+/// Log size that triggers compaction to [`MAX_SHIM_RECORDS`]
+const COMPACT_BYTES: u64 = 1024 * 1024;
+
+/// Timing of one shim execution
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShimInvocation {
+    /// ISO 8601 timestamp when the shim was entered
+    pub timestamp: String,
+    /// Executable name the shim was invoked as (e.g., "tsc")
+    pub executable: String,
+    /// Package key providing the executable (e.g., "npm:typescript")
+    pub package: String,
+    /// Time to find the package and target executable, in microseconds
+    pub resolve_us: u64,
+    /// Time from entering the shim until the target is spawned, in
+    /// microseconds (resolution plus environment setup)
+    pub dispatch_us: u64,
+    /// Wall time of the target process, in milliseconds
+    pub run_ms: u64,
+    /// Exit code of the target process
+    pub exit_code: i32,
+}
+
+/// Path of the shim invocation log under `metrics_dir`
+///
+/// It lives in a subdirectory so report listing and pruning, which look at
+/// the `.json` files of `metrics_dir` itself, never see it.
+pub fn shim_log_path(metrics_dir: &Path) -> PathBuf {
+    metrics_dir.join("shim").join("invocations.jsonl")
+}
+
+/// Append one invocation to the log, compacting it once it grows too large
+pub fn record_shim_invocation(metrics_dir: &Path, invocation: &ShimInvocation) -> Result<()> {
+    let path = shim_log_path(metrics_dir);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)?;
+    writeln!(file, "{}", serde_json::to_string(invocation)?)?;
+    drop(file);
+
+    if std::fs::metadata(&path)?.len() > COMPACT_BYTES {
+        let keep = load_shim_invocations(metrics_dir, MAX_SHIM_RECORDS);
+        let mut content = String::new();
+        for record in &keep {
+            content.push_str(&serde_json::to_string(record)?);
+            content.push('\n');
+        }
+        let temp = path.with_extension("jsonl.tmp");
+        std::fs::write(&temp, content)?;
+        std::fs::rename(&temp, &path)?;
+    }
+    Ok(())
+}
+
+/// The latest `last` invocations, oldest first; unreadable lines are skipped
+pub fn load_shim_invocations(metrics_dir: &Path, last: usize) -> Vec<ShimInvocation> {
+    let Ok(content) = std::fs::read_to_string(shim_log_path(metrics_dir)) else {
+        return Vec::new();
+    };
+    let records: Vec<ShimInvocation> = content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    let skip = records.len().saturating_sub(last);
+    records.into_iter().skip(skip).collect()
+}
+
+/// Dispatch overhead percentiles of a set of invocations
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ShimLatencySummary {
+    /// Number of invocations summarized
+    pub invocations: usize,
+    /// Median target resolution time, in microseconds
+    pub resolve_p50_us: u64,
+    /// 95th percentile target resolution time, in microseconds
+    pub resolve_p95_us: u64,
+    /// Median time until the target is spawned, in microseconds
+    pub dispatch_p50_us: u64,
+    /// 95th percentile time until the target is spawned, in microseconds
+    pub dispatch_p95_us: u64,
+    /// Slowest dispatch, in microseconds
+    pub dispatch_max_us: u64,
+    /// Per-executable breakdown, slowest median dispatch first
+    pub executables: Vec<ExecutableShimLatency>,
+}
+
+/// Dispatch overhead of one executable
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExecutableShimLatency {
+    /// Executable name
+    pub executable: String,
+    /// Number of invocations
+    pub invocations: usize,
+    /// Median time until the target is spawned, in microseconds
+    pub dispatch_p50_us: u64,
+    /// Median wall time of the target, in milliseconds
+    pub run_p50_ms: u64,
+}
+
+/// Nearest-rank percentile of `values` (0 when empty)
+fn percentile(values: &mut [u64], pct: usize) -> u64 {
+    if values.is_empty() {
+        return 0;
+    }
+    values.sort_unstable();
+    let rank = (values.len() * pct).div_ceil(100).max(1);
+    values[rank - 1]
+}
+
+/// Summarize dispatch overhead across `invocations`
+pub fn summarize_shim_latency(invocations: &[ShimInvocation]) -> ShimLatencySummary {
+    let mut resolve: Vec<u64> = invocations.iter().map(|i| i.resolve_us).collect();
+    let mut dispatch: Vec<u64> = invocations.iter().map(|i| i.dispatch_us).collect();
+
+    let mut by_executable: BTreeMap<&str, (Vec<u64>, Vec<u64>)> = BTreeMap::new();
+    for invocation in invocations {
+        let entry = by_executable
+            .entry(invocation.executable.as_str())
+            .or_default();
+        entry.0.push(invocation.dispatch_us);
+        entry.1.push(invocation.run_ms);
+    }
+    let mut executables: Vec<ExecutableShimLatency> = by_executable
+        .into_iter()
+        .map(
+            |(executable, (mut dispatch, mut run))| ExecutableShimLatency {
+                executable: executable.to_string(),
+                invocations: dispatch.len(),
+                dispatch_p50_us: percentile(&mut dispatch, 50),
+                run_p50_ms: percentile(&mut run, 50),
+            },
+        )
+        .collect();
+    executables.sort_by_key(|e| Reverse(e.dispatch_p50_us));
+
+    ShimLatencySummary {
+        invocations: invocations.len(),
+        resolve_p50_us: percentile(&mut resolve, 50),
+        resolve_p95_us: percentile(&mut resolve, 95),
+        dispatch_p50_us: percentile(&mut dispatch, 50),
+        dispatch_p95_us: percentile(&mut dispatch, 95),
+        dispatch_max_us: dispatch.iter().copied().max().unwrap_or_default(),
+        executables,
+    }
+}
+
+fn format_us(us: u64) -> String {
+    if us >= 1000 {
+        format!("{:.1}ms", us as f64 / 1000.0)
+    } else {
+        format!("{}us", us)
+    }
+}
+
+/// Render a shim latency summary for the terminal
+pub fn render_shim_latency(summary: &ShimLatencySummary) -> String {
+    if summary.invocations == 0 {
+        return "No shim telemetry found. Set VX_SHIM_TELEMETRY=1 and run a shimmed tool.\n"
+            .to_string();
+    }
+
+    let mut out = String::new();
+    out.push_str("Shim dispatch overhead\n");
+    out.push_str(&format!(
+        "invocations:{} resolve p50:{} p95:{} dispatch p50:{} p95:{} max:{}\n\n",
+        summary.invocations,
+        format_us(summary.resolve_p50_us),
+        format_us(summary.resolve_p95_us),
+        format_us(summary.dispatch_p50_us),
+        format_us(summary.dispatch_p95_us),
+        format_us(summary.dispatch_max_us),
+    ));
+    out.push_str(&format!(
+        "{:<28} {:>6} {:>14} {:>10}\n",
+        "Executable", "Runs", "Dispatch p50", "Run p50"
+    ));
+    out.push_str(&format!(
+        "{:<28} {:>6} {:>14} {:>10}\n",
+        "-".repeat(28),
+        "----",
+        "------------",
+        "-------"
+    ));
+    for executable in &summary.executables {
+        out.push_str(&format!(
+            "{:<28} {:>6} {:>14} {:>10}\n",
+            executable.executable,
+            executable.invocations,
+            format_us(executable.dispatch_p50_us),
+            format!("{}ms", executable.run_p50_ms),
+        ));
+    }
+    out
+}
@@ -0,0 +1,85 @@
+//! Tests for stage timing budgets.
+
+use std::collections::HashMap;
+use vx_metrics::budget::{StageBudgets, check_budgets, render_budget_warnings};
+use vx_metrics::report::{CommandMetrics, StageMetrics};
+
+fn run_with(stages: &[(&str, f64)]) -> CommandMetrics {
+    let mut metrics = CommandMetrics::new("vx node --version".to_string());
+    metrics.stages = stages
+        .iter()
+        .map(|(name, ms)| {
+            (
+                name.to_string(),
+                StageMetrics {
+                    duration_ms: *ms,
+                    success: true,
+                    error: None,
+                },
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    metrics
+}
+
+#[test]
+fn test_within_budget_has_no_warnings() {
+    let runs = vec![run_with(&[
+        ("resolve", 20.0),
+        ("ensure", 5.0),
+        ("prepare", 30.0),
+    ])];
+    let warnings = check_budgets(&runs, &StageBudgets::default());
+    assert!(warnings.is_empty());
+    assert!(render_budget_warnings(&warnings).is_empty());
+}
+
+#[test]
+fn test_slow_resolve_suggests_lock_and_cache() {
+    let runs = vec![
+        run_with(&[("resolve", 250.0), ("execute", 40.0)]),
+        run_with(&[("resolve", 50.0)]),
+    ];
+    let warnings = check_budgets(&runs, &StageBudgets::default());
+    assert_eq!(warnings.len(), 1);
+
+    let warning = &warnings[0];
+    assert_eq!(warning.stage, "resolve");
+    assert_eq!(warning.runs_over, 1);
+    assert_eq!(warning.runs, 2);
+    assert_eq!(warning.slowest_ms, 250.0);
+    assert_eq!(warning.average_ms, 150.0);
+    assert!(warning.suggestions.iter().any(|s| s.contains("vx lock")));
+    assert!(
+        warning
+            .suggestions
+            .iter()
+            .any(|s| s.contains("--cache-mode"))
+    );
+
+    let output = render_budget_warnings(&warnings);
+    assert!(output.contains("1 of 2 runs"));
+}
+
+#[test]
+fn test_custom_budgets() {
+    let runs = vec![run_with(&[("resolve", 60.0), ("ensure", 800.0)])];
+    let budgets = StageBudgets {
+        resolve_ms: 50.0,
+        ensure_ms: 1000.0,
+        ..Default::default()
+    };
+    let warnings = check_budgets(&runs, &budgets);
+    let stages: Vec<&str> = warnings.iter().map(|w| w.stage.as_str()).collect();
+    assert_eq!(stages, ["resolve"]);
+
+    let output = render_budget_warnings(&warnings);
+    assert!(output.contains("'resolve' took 60ms (budget 50ms)"));
+}
+
+#[test]
+fn test_execute_has_no_budget() {
+    let runs = vec![run_with(&[("execute", 60_000.0)])];
+    assert!(check_budgets(&runs, &StageBudgets::default()).is_empty());
+    assert_eq!(StageBudgets::default().get("execute"), None);
+}
@@ -9,6 +9,8 @@ fn test_metrics_config_default() {
     assert!(!config.verbose);
     assert!(config.command.is_empty());
     assert!(config.metrics_dir.is_none());
+    assert!(!config.disabled);
+    assert!(config.budgets.is_none());
 }
 
 #[test]
@@ -47,6 +49,22 @@ fn test_metrics_guard_exit_code_handle_shared() {
     assert_eq!(handle2.load(std::sync::atomic::Ordering::Relaxed), 7);
 }
 
+#[test]
+fn test_metrics_guard_disabled_writes_nothing() {
+    let temp = TempDir::new().unwrap();
+    let guard = vx_metrics::init(MetricsConfig {
+        command: "test".to_string(),
+        metrics_dir: Some(temp.path().to_path_buf()),
+        disabled: true,
+        ..Default::default()
+    });
+
+    tracing::info_span!("vx_disabled_metrics").in_scope(|| {});
+    guard.flush();
+
+    assert_eq!(std::fs::read_dir(temp.path()).unwrap().count(), 0);
+}
+
 // ============================================================================
 // Per-layer filter directive tests
 // ============================================================================
@@ -0,0 +1,125 @@
+//! Tests for the Prometheus exposition.
+
+use rstest::rstest;
+use tempfile::TempDir;
+use vx_metrics::prometheus::{
+    CommandLatencies, LATENCY_BUCKETS, LatencyHistogram, MetricKind, PrometheusText, command_label,
+};
+
+#[rstest]
+#[case("vx node --version", "node")]
+#[case("/usr/local/bin/vx --verbose install node@22", "install")]
+#[case("C:\\Users\\me\\.vx\\bin\\vx.exe cache serve", "cache")]
+#[case("/home/me/.vx/shims/npm install", "npm")]
+#[case("vx --version", "vx")]
+#[case("vx", "vx")]
+fn test_command_label(#[case] command: &str, #[case] expected: &str) {
+    assert_eq!(command_label(command), expected);
+}
+
+#[test]
+fn test_histogram_buckets_are_cumulative() {
+    let mut histogram = LatencyHistogram::default();
+    histogram.observe(0.08, true);
+    histogram.observe(0.7, false);
+    histogram.observe(1000.0, true);
+
+    assert_eq!(histogram.buckets.len(), LATENCY_BUCKETS.len());
+    assert_eq!(histogram.buckets[0], 0); // <= 0.05
+    assert_eq!(histogram.buckets[1], 1); // <= 0.1
+    assert_eq!(histogram.buckets[4], 2); // <= 1.0
+    assert_eq!(*histogram.buckets.last().unwrap(), 2); // The slowest run only counts in +Inf
+    assert_eq!(histogram.count, 3);
+    assert_eq!(histogram.failures, 1);
+    assert!((histogram.sum_seconds - 1000.78).abs() < 1e-9);
+}
+
+#[test]
+fn test_record_accumulates_per_command() {
+    let temp = TempDir::new().unwrap();
+    assert_eq!(
+        CommandLatencies::load(temp.path()),
+        CommandLatencies::default()
+    );
+
+    CommandLatencies::record(temp.path(), "vx node --version", 0.2, true).unwrap();
+    CommandLatencies::record(temp.path(), "vx node index.js", 3.0, false).unwrap();
+    CommandLatencies::record(temp.path(), "vx install go", 40.0, true).unwrap();
+
+    let latencies = CommandLatencies::load(temp.path());
+    assert_eq!(latencies.commands.len(), 2);
+    assert_eq!(latencies.commands["node"].count, 2);
+    assert_eq!(latencies.commands["node"].failures, 1);
+    assert_eq!(latencies.commands["install"].count, 1);
+
+    // Kept out of the per-run reports
+    assert!(
+        vx_metrics::load_metrics(temp.path(), 10)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_concurrent_records_are_not_lost() {
+    let temp = TempDir::new().unwrap();
+    std::thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| {
+                for _ in 0..25 {
+                    CommandLatencies::record(temp.path(), "vx node --version", 0.2, true).unwrap();
+                }
+            });
+        }
+    });
+    assert_eq!(
+        CommandLatencies::load(temp.path()).commands["node"].count,
+        200
+    );
+}
+
+#[test]
+fn test_render_histogram() {
+    let temp = TempDir::new().unwrap();
+    CommandLatencies::record(temp.path(), "vx node --version", 0.2, true).unwrap();
+    CommandLatencies::record(temp.path(), "vx node index.js", 3.0, false).unwrap();
+
+    let mut out = PrometheusText::new();
+    CommandLatencies::load(temp.path()).render(&mut out);
+    let text = out.finish();
+
+    assert!(text.contains("# TYPE vx_command_duration_seconds histogram\n"));
+    assert!(text.contains("vx_command_duration_seconds_bucket{command=\"node\",le=\"0.1\"} 0\n"));
+    assert!(text.contains("vx_command_duration_seconds_bucket{command=\"node\",le=\"0.25\"} 1\n"));
+    assert!(text.contains("vx_command_duration_seconds_bucket{command=\"node\",le=\"5\"} 2\n"));
+    assert!(text.contains("vx_command_duration_seconds_bucket{command=\"node\",le=\"+Inf\"} 2\n"));
+    assert!(text.contains("vx_command_duration_seconds_sum{command=\"node\"} 3.2\n"));
+    assert!(text.contains("vx_command_duration_seconds_count{command=\"node\"} 2\n"));
+    assert!(text.contains("vx_command_failures_total{command=\"node\"} 1\n"));
+}
+
+#[test]
+fn test_text_format() {
+    let mut out = PrometheusText::new();
+    out.header(
+        "vx_downloads_total",
+        MetricKind::Counter,
+        "Download attempts",
+    );
+    out.sample(
+        "vx_downloads_total",
+        &[("host", "a\"b\\c"), ("result", "success")],
+        3,
+    );
+    out.single("vx_cache_size_bytes", MetricKind::Gauge, "Cache size", 1024);
+
+    assert_eq!(
+        out.finish(),
+        "# HELP vx_downloads_total Download attempts\n\
+         # TYPE vx_downloads_total counter\n\
+         vx_downloads_total{host=\"a\\\"b\\\\c\",result=\"success\"} 3\n\
+         # HELP vx_cache_size_bytes Cache size\n\
+         # TYPE vx_cache_size_bytes gauge\n\
+         vx_cache_size_bytes 1024\n"
+    );
+}
@@ -0,0 +1,90 @@
+//! Tests for shim dispatch telemetry.
+
+use tempfile::TempDir;
+use vx_metrics::shim::{
+    ShimInvocation, load_shim_invocations, record_shim_invocation, render_shim_latency,
+    shim_log_path, summarize_shim_latency,
+};
+
+fn invocation(executable: &str, dispatch_us: u64, run_ms: u64) -> ShimInvocation {
+    ShimInvocation {
+        timestamp: "2026-10-15T10:00:00+00:00".to_string(),
+        executable: executable.to_string(),
+        package: format!("npm:{}", executable),
+        resolve_us: dispatch_us / 2,
+        dispatch_us,
+        run_ms,
+        exit_code: 0,
+    }
+}
+
+#[test]
+fn test_record_and_load_keep_order() {
+    let temp = TempDir::new().unwrap();
+    for dispatch in [100, 200, 300] {
+        record_shim_invocation(temp.path(), &invocation("tsc", dispatch, 5)).unwrap();
+    }
+
+    assert!(shim_log_path(temp.path()).starts_with(temp.path().join("shim")));
+    let all = load_shim_invocations(temp.path(), 10);
+    assert_eq!(
+        all.iter().map(|i| i.dispatch_us).collect::<Vec<_>>(),
+        vec![100, 200, 300]
+    );
+    // `last` keeps the newest records
+    let latest = load_shim_invocations(temp.path(), 2);
+    assert_eq!(latest[0].dispatch_us, 200);
+}
+
+#[test]
+fn test_load_skips_corrupt_lines() {
+    let temp = TempDir::new().unwrap();
+    record_shim_invocation(temp.path(), &invocation("tsc", 100, 5)).unwrap();
+    let path = shim_log_path(temp.path());
+    let mut content = std::fs::read_to_string(&path).unwrap();
+    content.push_str("{not json\n");
+    std::fs::write(&path, content).unwrap();
+    record_shim_invocation(temp.path(), &invocation("tsc", 200, 5)).unwrap();
+
+    assert_eq!(load_shim_invocations(temp.path(), 10).len(), 2);
+}
+
+#[test]
+fn test_load_missing_log_is_empty() {
+    let temp = TempDir::new().unwrap();
+    assert!(load_shim_invocations(temp.path(), 10).is_empty());
+}
+
+#[test]
+fn test_summary_percentiles_and_breakdown() {
+    let mut invocations: Vec<ShimInvocation> =
+        (1..=20).map(|i| invocation("tsc", i * 100, 10)).collect();
+    invocations.push(invocation("http", 50_000, 40));
+
+    let summary = summarize_shim_latency(&invocations);
+    assert_eq!(summary.invocations, 21);
+    assert_eq!(summary.dispatch_p50_us, 1100);
+    assert_eq!(summary.dispatch_p95_us, 2000);
+    assert_eq!(summary.dispatch_max_us, 50_000);
+    assert_eq!(summary.resolve_p50_us, 550);
+
+    // Slowest median dispatch first
+    assert_eq!(summary.executables[0].executable, "http");
+    assert_eq!(summary.executables[0].run_p50_ms, 40);
+    assert_eq!(summary.executables[1].invocations, 20);
+}
+
+#[test]
+fn test_render_shim_latency() {
+    let empty = render_shim_latency(&summarize_shim_latency(&[]));
+    assert!(empty.contains("VX_SHIM_TELEMETRY=1"));
+
+    let text = render_shim_latency(&summarize_shim_latency(&[
+        invocation("tsc", 800, 12),
+        invocation("tsc", 2500, 14),
+    ]));
+    assert!(text.contains("invocations:2"));
+    assert!(text.contains("tsc"));
+    assert!(text.contains("800us"));
+    assert!(text.contains("2.5ms"));
+}
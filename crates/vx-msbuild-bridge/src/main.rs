@@ -8,7 +8,8 @@
 //! 1. **System VS Build Tools MSBuild.exe** — has VCTargets (Microsoft.Cpp.*.props)
 //!    needed for C/C++ compilation (node-gyp, cmake, etc.)
 //! 2. **System VS Community/Professional/Enterprise MSBuild.exe** — same capabilities
-//! 3. **dotnet msbuild** — fallback, but lacks VCTargetsPath for C++ projects
+//! 3. **dotnet msbuild** — fallback, but lacks VCTargetsPath for C++ projects.
+//!    A vx-managed SDK is found through `DOTNET_ROOT`, before the system paths.
 //!
 //! ## Why not just `dotnet msbuild`?
 //!
@@ -121,7 +122,15 @@ fn has_spectre_override(args: &[String]) -> bool {
 }
 
 fn find_dotnet() -> Option<PathBuf> {
-    // Check well-known paths first
+    // vx sets DOTNET_ROOT to the managed SDK it resolved for this project
+    if let Some(root) = std::env::var_os("DOTNET_ROOT") {
+        let candidate = Path::new(&root).join("dotnet.exe");
+        if candidate.exists() {
+            return Some(candidate);
+        }
+    }
+
+    // Check well-known paths
     let dotnet_paths = [
         r"C:\Program Files\dotnet\dotnet.exe",
         r"C:\Program Files (x86)\dotnet\dotnet.exe",
@@ -78,9 +78,11 @@ impl PathConfig {
             config_dir: self.config_dir.clone().unwrap_or(default_paths.config_dir),
             tmp_dir: self.tmp_dir.clone().unwrap_or(default_paths.tmp_dir),
             providers_dir: default_paths.providers_dir,
+            asdf_plugins_dir: default_paths.asdf_plugins_dir,
             // RFC 0025: Global packages CAS
             packages_dir: default_paths.packages_dir,
             shims_dir: default_paths.shims_dir,
+            state_dir: default_paths.state_dir,
         })
     }
 
@@ -0,0 +1,186 @@
+//! Journal of install transactions
+//!
+//! Every `vx install` and `vx upgrade` run is recorded as a transaction in
+//! `~/.vx/state/install-journal.json`. Each transaction lists the tool
+//! versions it installed together with the version that was active before,
+//! so a failed run can be undone and `vx rollback <tool>` can restore the
+//! previously active version later.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// File name of the journal inside the state directory
+pub const INSTALL_JOURNAL_FILE: &str = "install-journal.json";
+
+/// Transactions kept in the journal before the oldest are dropped
+const MAX_TRANSACTIONS: usize = 50;
+
+/// State of an install transaction
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionStatus {
+    /// Still running, or interrupted before it finished
+    Pending,
+    /// Finished successfully
+    Committed,
+    /// Finished with errors; successful changes were kept
+    Failed,
+    /// Failed and every change was undone
+    RolledBack,
+}
+
+/// A tool version installed by a transaction
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ToolChange {
+    /// Runtime name
+    pub tool: String,
+    /// Version that was active before the install (`None` for a first install)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous: Option<String>,
+    /// Version the transaction installed
+    pub installed: String,
+    /// Whether the change has been undone
+    #[serde(default)]
+    pub rolled_back: bool,
+}
+
+impl ToolChange {
+    /// Create a new change record
+    pub fn new(
+        tool: impl Into<String>,
+        previous: Option<String>,
+        installed: impl Into<String>,
+    ) -> Self {
+        Self {
+            tool: tool.into(),
+            previous,
+            installed: installed.into(),
+            rolled_back: false,
+        }
+    }
+}
+
+/// A single `vx install` / `vx upgrade` run
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InstallTransaction {
+    /// Monotonic transaction id
+    pub id: u64,
+    /// Command that started the transaction (`install`, `upgrade`, ...)
+    pub command: String,
+    /// Start timestamp (ISO 8601)
+    pub started_at: String,
+    /// Current state
+    pub status: TransactionStatus,
+    /// Versions installed so far
+    #[serde(default)]
+    pub changes: Vec<ToolChange>,
+}
+
+/// Install transactions, oldest first
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InstallJournal {
+    #[serde(default)]
+    pub transactions: Vec<InstallTransaction>,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl InstallJournal {
+    /// Load the journal from a state directory.
+    ///
+    /// A missing or unreadable journal starts empty.
+    pub fn load(state_dir: &Path) -> Self {
+        let path = state_dir.join(INSTALL_JOURNAL_FILE);
+        let mut journal = match std::fs::read_to_string(&path) {
+            Ok(content) => match serde_json::from_str::<Self>(&content) {
+                Ok(journal) => journal,
+                Err(e) => {
+                    tracing::warn!(
+                        path = %path.display(),
+                        error = %e,
+                        "Ignoring invalid install journal"
+                    );
+                    Self::default()
+                }
+            },
+            Err(_) => Self::default(),
+        };
+        journal.path = path;
+        journal
+    }
+
+    /// Write the journal back to disk
+    pub fn save(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(&self.path, content)
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+
+    /// Start a new pending transaction and return its id
+    pub fn begin(&mut self, command: &str) -> u64 {
+        let id = self.transactions.last().map_or(1, |t| t.id + 1);
+        self.transactions.push(InstallTransaction {
+            id,
+            command: command.to_string(),
+            started_at: chrono::Utc::now().to_rfc3339(),
+            status: TransactionStatus::Pending,
+            changes: Vec::new(),
+        });
+        if self.transactions.len() > MAX_TRANSACTIONS {
+            let excess = self.transactions.len() - MAX_TRANSACTIONS;
+            self.transactions.drain(..excess);
+        }
+        id
+    }
+
+    /// Look up a transaction by id
+    pub fn transaction(&self, id: u64) -> Option<&InstallTransaction> {
+        self.transactions.iter().find(|t| t.id == id)
+    }
+
+    /// Record a version installed by a transaction
+    pub fn record(&mut self, id: u64, change: ToolChange) {
+        if let Some(tx) = self.transactions.iter_mut().find(|t| t.id == id) {
+            tx.changes.push(change);
+        }
+    }
+
+    /// Set the final status of a transaction.
+    ///
+    /// [`TransactionStatus::RolledBack`] also marks every change as undone.
+    pub fn finish(&mut self, id: u64, status: TransactionStatus) {
+        if let Some(tx) = self.transactions.iter_mut().find(|t| t.id == id) {
+            tx.status = status;
+            if status == TransactionStatus::RolledBack {
+                for change in &mut tx.changes {
+                    change.rolled_back = true;
+                }
+            }
+        }
+    }
+
+    /// The most recent change to a tool that has not been rolled back
+    pub fn last_change(&self, tool: &str) -> Option<(u64, &ToolChange)> {
+        self.transactions.iter().rev().find_map(|tx| {
+            tx.changes
+                .iter()
+                .rev()
+                .find(|c| c.tool == tool && !c.rolled_back)
+                .map(|c| (tx.id, c))
+        })
+    }
+
+    /// Mark a transaction's change to `tool` as undone
+    pub fn mark_rolled_back(&mut self, id: u64, tool: &str) {
+        if let Some(tx) = self.transactions.iter_mut().find(|t| t.id == id) {
+            for change in tx.changes.iter_mut().filter(|c| c.tool == tool) {
+                change.rolled_back = true;
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! Why each tool was installed
+//!
+//! Every install records in `~/.vx/state/install-reasons.json` whether the
+//! tool was requested (`vx install node`, `vx node`, a tool in `vx.toml`) or
+//! pulled in as a dependency of another tool (node for `vx pnpm`), together
+//! with the tools that pulled it in. `vx list --leaves` shows the tools
+//! installed on request and `vx rdeps <tool>` the tools depending on one.
+//!
+//! Tools installed before vx recorded reasons have no entry and count as
+//! installed on request.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// File name of the record inside the state directory
+pub const INSTALL_REASONS_FILE: &str = "install-reasons.json";
+
+/// Why a tool was installed
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InstallReason {
+    /// Requested by the user or the project
+    Explicit,
+    /// Installed because another tool needs it
+    Dependency,
+}
+
+/// Install reason of one tool
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InstallRecord {
+    pub reason: InstallReason,
+    /// Tools whose installation pulled this tool in
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required_by: Vec<String>,
+    /// First installation timestamp (ISO 8601)
+    pub installed_at: String,
+}
+
+/// Install reasons of all tools, by tool name
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InstallReasons {
+    #[serde(default)]
+    pub tools: BTreeMap<String, InstallRecord>,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl InstallReasons {
+    /// Load the record from a state directory.
+    ///
+    /// A missing or unreadable record starts empty.
+    pub fn load(state_dir: &Path) -> Self {
+        let path = state_dir.join(INSTALL_REASONS_FILE);
+        let mut reasons = match std::fs::read_to_string(&path) {
+            Ok(content) => match serde_json::from_str::<Self>(&content) {
+                Ok(reasons) => reasons,
+                Err(e) => {
+                    tracing::warn!(
+                        path = %path.display(),
+                        error = %e,
+                        "Ignoring invalid install reasons"
+                    );
+                    Self::default()
+                }
+            },
+            Err(_) => Self::default(),
+        };
+        reasons.path = path;
+        reasons
+    }
+
+    /// Write the record back to disk
+    pub fn save(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(&self.path, content)
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+
+    /// Record that `tool` was installed on request.
+    ///
+    /// A tool installed as a dependency before becomes explicit.
+    pub fn record_explicit(&mut self, tool: &str) {
+        self.entry(tool, InstallReason::Explicit).reason = InstallReason::Explicit;
+    }
+
+    /// Record that `tool` was installed because `dependent` needs it.
+    ///
+    /// A tool installed on request stays explicit.
+    pub fn record_dependency(&mut self, tool: &str, dependent: &str) {
+        let record = self.entry(tool, InstallReason::Dependency);
+        if !record.required_by.iter().any(|d| d == dependent) {
+            record.required_by.push(dependent.to_string());
+            record.required_by.sort();
+        }
+    }
+
+    fn entry(&mut self, tool: &str, reason: InstallReason) -> &mut InstallRecord {
+        self.tools
+            .entry(tool.to_string())
+            .or_insert_with(|| InstallRecord {
+                reason,
+                required_by: Vec::new(),
+                installed_at: chrono::Utc::now().to_rfc3339(),
+            })
+    }
+
+    /// Install reason of `tool`; `None` for tools installed before reasons were recorded
+    pub fn reason(&self, tool: &str) -> Option<InstallReason> {
+        self.tools.get(tool).map(|record| record.reason)
+    }
+
+    /// Whether `tool` was only installed as a dependency of other tools
+    pub fn is_dependency(&self, tool: &str) -> bool {
+        self.reason(tool) == Some(InstallReason::Dependency)
+    }
+
+    /// Tools whose installation pulled `tool` in
+    pub fn required_by(&self, tool: &str) -> &[String] {
+        self.tools
+            .get(tool)
+            .map(|record| record.required_by.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Forget `tool`, once no version of it is installed
+    pub fn remove(&mut self, tool: &str) {
+        self.tools.remove(tool);
+    }
+}
+
+/// Record an install reason in the state directory: on request when
+/// `dependent` is `None`, otherwise as a dependency of `dependent`.
+///
+/// Failures are logged; a missing reason never fails an install.
+pub fn record_install_reason(state_dir: &Path, tool: &str, dependent: Option<&str>) {
+    let mut reasons = InstallReasons::load(state_dir);
+    match dependent {
+        Some(dependent) => reasons.record_dependency(tool, dependent),
+        None => reasons.record_explicit(tool),
+    }
+    if let Err(e) = reasons.save() {
+        tracing::warn!(tool, error = %e, "Failed to record install reason");
+    }
+}
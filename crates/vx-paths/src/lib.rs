@@ -58,7 +58,8 @@
 //! ├── bin/                        # Global shims
 //! ├── cache/                      # Download cache
 //! ├── config/                     # Configuration
-//! └── tmp/                        # Temporary files
+//! ├── state/                      # Install journal and other state
+//! └── tmp/                        # Per-operation temp workspaces
 //! ```
 //!
 //! # Offline Bundle Support
@@ -83,18 +84,29 @@ use std::path::{Path, PathBuf};
 
 pub mod config;
 pub mod global_packages;
+pub mod install_journal;
+pub mod install_reasons;
 pub mod link;
 pub mod manager;
 pub mod package_spec;
 pub mod platform;
+pub mod portable;
 pub mod project;
 pub mod resolver;
 pub mod runtime_root;
 pub mod shims;
+pub mod system_managed;
+pub mod temp_workspace;
 pub mod windows;
 
 pub use config::PathConfig;
 pub use global_packages::{GlobalPackage, PackageRegistry, RuntimeDependency};
+pub use install_journal::{
+    INSTALL_JOURNAL_FILE, InstallJournal, InstallTransaction, ToolChange, TransactionStatus,
+};
+pub use install_reasons::{
+    INSTALL_REASONS_FILE, InstallReason, InstallReasons, InstallRecord, record_install_reason,
+};
 pub use link::{LinkResult, LinkStrategy};
 pub use manager::PathManager;
 pub use package_spec::PackageSpec;
@@ -108,6 +120,8 @@ pub use resolver::{PathResolver, ToolLocation, ToolSource};
 pub use runtime_root::{
     RuntimeRoot, get_bundled_tool_path, get_latest_runtime_root, get_runtime_root,
 };
+pub use system_managed::{SYSTEM_MANAGED_RECORD_FILE, SystemManagedRecord};
+pub use temp_workspace::{ORPHAN_MAX_AGE, TempWorkspace, sweep_orphans};
 
 // Re-export platform module utilities for convenience
 pub use platform::{
@@ -142,22 +156,31 @@ pub struct VxPaths {
     pub tmp_dir: PathBuf,
     /// User providers directory (~/.vx/providers) - Manifest-driven runtimes
     pub providers_dir: PathBuf,
+    /// asdf plugins directory (~/.vx/asdf-plugins)
+    pub asdf_plugins_dir: PathBuf,
     /// Global packages CAS directory (~/.vx/packages) - RFC 0025
     pub packages_dir: PathBuf,
     /// Global shims directory (~/.vx/shims) - RFC 0025
     pub shims_dir: PathBuf,
+    /// Persistent state directory (~/.vx/state)
+    pub state_dir: PathBuf,
 }
 
 impl VxPaths {
     /// Create VxPaths with default locations
     ///
-    /// Uses VX_HOME environment variable if set, otherwise defaults to ~/.vx
+    /// Uses VX_HOME environment variable if set, then the portable home next
+    /// to the vx binary in portable mode, otherwise defaults to ~/.vx
     pub fn new() -> Result<Self> {
         // Check for VX_HOME environment variable first
         if let Ok(vx_home) = std::env::var("VX_HOME") {
             return Ok(Self::with_base_dir(vx_home));
         }
 
+        if let Some(portable_home) = portable::portable_home() {
+            return Ok(Self::with_base_dir(portable_home));
+        }
+
         let home_dir = dirs::home_dir()
             .ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?;
 
@@ -174,8 +197,10 @@ impl VxPaths {
             config_dir: base_dir.join("config"),
             tmp_dir: base_dir.join("tmp"),
             providers_dir: base_dir.join("providers"),
+            asdf_plugins_dir: base_dir.join("asdf-plugins"),
             packages_dir: base_dir.join("packages"),
             shims_dir: base_dir.join("shims"),
+            state_dir: base_dir.join("state"),
             base_dir,
         })
     }
@@ -195,8 +220,10 @@ impl VxPaths {
             config_dir: base_dir.join("config"),
             tmp_dir: base_dir.join("tmp"),
             providers_dir: base_dir.join("providers"),
+            asdf_plugins_dir: base_dir.join("asdf-plugins"),
             packages_dir: base_dir.join("packages"),
             shims_dir: base_dir.join("shims"),
+            state_dir: base_dir.join("state"),
             base_dir,
         }
     }
@@ -216,6 +243,7 @@ impl VxPaths {
         std::fs::create_dir_all(&self.providers_dir)?;
         std::fs::create_dir_all(&self.packages_dir)?;
         std::fs::create_dir_all(&self.shims_dir)?;
+        std::fs::create_dir_all(&self.state_dir)?;
         Ok(())
     }
 
@@ -364,6 +392,27 @@ impl VxPaths {
         self.config_dir.join("global-tools.toml")
     }
 
+    /// Get the user download mirrors configuration file path
+    ///
+    /// Returns: ~/.vx/config/mirrors.toml
+    pub fn mirrors_config(&self) -> PathBuf {
+        self.config_dir.join("mirrors.toml")
+    }
+
+    /// Get the project template registry configuration file path
+    ///
+    /// Returns: ~/.vx/config/templates.toml
+    pub fn templates_config(&self) -> PathBuf {
+        self.config_dir.join("templates.toml")
+    }
+
+    /// Get the project template cache directory
+    ///
+    /// Returns: ~/.vx/templates
+    pub fn templates_dir(&self) -> PathBuf {
+        self.base_dir.join("templates")
+    }
+
     /// Get the global packages registry file path
     ///
     /// Returns: ~/.vx/config/packages-registry.json
@@ -407,3 +456,24 @@ pub fn with_executable_extension_legacy(tool_name: &str) -> String {
 pub fn normalize_package_name(name: &str) -> String {
     platform::normalize_for_comparison(name)
 }
+
+/// Environment variable overriding the machine policy file location
+pub const POLICY_FILE_ENV: &str = "VX_POLICY_FILE";
+
+/// Get the machine-wide policy file path
+///
+/// Returns `VX_POLICY_FILE` if set, otherwise `%ProgramData%\vx\policy.toml`
+/// on Windows and `/etc/vx/policy.toml` elsewhere. The file is managed by
+/// administrators and applies to every user on the machine.
+pub fn policy_file() -> PathBuf {
+    if let Some(path) = std::env::var_os(POLICY_FILE_ENV).filter(|p| !p.is_empty()) {
+        return PathBuf::from(path);
+    }
+    if cfg!(windows) {
+        let program_data =
+            std::env::var_os("ProgramData").unwrap_or_else(|| "C:\\ProgramData".into());
+        PathBuf::from(program_data).join("vx").join("policy.toml")
+    } else {
+        PathBuf::from("/etc/vx/policy.toml")
+    }
+}
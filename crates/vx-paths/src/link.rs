@@ -4,7 +4,7 @@
 //! duplicating files when creating virtual environments.
 
 use anyhow::Result;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Link strategy for creating file references
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -13,7 +13,8 @@ pub enum LinkStrategy {
     HardLink,
     /// Symbolic link (cross-filesystem, Windows needs permissions)
     SymLink,
-    /// Copy-on-Write (macOS APFS, Linux Btrfs/XFS)
+    /// Copy-on-Write (macOS APFS, Linux Btrfs/XFS, Windows ReFS); copies
+    /// where cloning is unsupported
     CopyOnWrite,
     /// Copy (fallback, slowest)
     Copy,
@@ -37,10 +38,23 @@ impl LinkStrategy {
     }
 
     /// Detect the best strategy for a given path
-    pub fn detect(_path: &Path) -> Self {
-        // For now, use auto detection based on platform
-        // TODO: Actually test filesystem capabilities
-        Self::auto()
+    ///
+    /// Prefers copy-on-write when the filesystem holding `path` supports
+    /// cloning, and falls back to the platform default otherwise.
+    pub fn detect(path: &Path) -> Self {
+        let dir = if path.is_dir() {
+            path
+        } else {
+            path.parent().unwrap_or(path)
+        };
+        if supports_cow(dir) {
+            Self::CopyOnWrite
+        } else if cfg!(target_os = "macos") {
+            // Not APFS: CoW would only copy, hard links are cheaper
+            Self::HardLink
+        } else {
+            Self::auto()
+        }
     }
 
     /// Get a human-readable name for the strategy
@@ -150,7 +164,12 @@ fn create_symlink(src: &Path, dst: &Path) -> Result<()> {
     Ok(())
 }
 
-/// Create a copy-on-write link (or fallback to copy)
+/// Create a copy-on-write clone (or fallback to copy)
+///
+/// Clones share data blocks with the source until either side is modified,
+/// so even large trees are copied instantly and take no extra space. Each
+/// file falls back to a regular copy where cloning is unsupported (other
+/// filesystems, or `src` and `dst` on different volumes).
 fn create_cow_link(src: &Path, dst: &Path) -> Result<()> {
     // Ensure parent directory exists
     if let Some(parent) = dst.parent() {
@@ -159,34 +178,94 @@ fn create_cow_link(src: &Path, dst: &Path) -> Result<()> {
 
     #[cfg(target_os = "macos")]
     {
-        // macOS: use clonefile
-        use std::ffi::CString;
-        use std::os::unix::ffi::OsStrExt;
-
-        let src_c = CString::new(src.as_os_str().as_bytes())?;
-        let dst_c = CString::new(dst.as_os_str().as_bytes())?;
-
-        // clonefile is available on macOS 10.12+
-        unsafe extern "C" {
-            fn clonefile(src: *const i8, dst: *const i8, flags: u32) -> i32;
+        // macOS: clonefile clones a whole directory tree in one call
+        if clone_file(src, dst).is_ok() {
+            return Ok(());
         }
+    }
 
-        let result = unsafe { clonefile(src_c.as_ptr(), dst_c.as_ptr(), 0) };
-        if result == 0 {
-            return Ok(());
+    if src.is_dir() {
+        std::fs::create_dir_all(dst)?;
+        for entry in std::fs::read_dir(src)? {
+            let entry = entry?;
+            create_cow_link(&entry.path(), &dst.join(entry.file_name()))?;
         }
-        // If clonefile fails, fall back to copy
+    } else if let Err(e) = clone_file(src, dst) {
+        tracing::trace!(src = %src.display(), error = %e, "Clone failed, copying instead");
+        std::fs::copy(src, dst)?;
     }
+    Ok(())
+}
 
-    #[cfg(target_os = "linux")]
-    {
-        // Linux: try reflink, fall back to copy
-        // This requires the reflink crate or ioctl FICLONE
-        // For now, just copy
+/// Clone `src` to `dst` with APFS `clonefile` (files and directories)
+#[cfg(target_os = "macos")]
+fn clone_file(src: &Path, dst: &Path) -> std::io::Result<()> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let src_c = CString::new(src.as_os_str().as_bytes())?;
+    let dst_c = CString::new(dst.as_os_str().as_bytes())?;
+
+    // clonefile is available on macOS 10.12+
+    unsafe extern "C" {
+        fn clonefile(src: *const i8, dst: *const i8, flags: u32) -> i32;
     }
 
-    // Fallback to regular copy
-    copy_path(src, dst)
+    // Safety: both paths are NUL-terminated C strings that outlive the call.
+    if unsafe { clonefile(src_c.as_ptr(), dst_c.as_ptr(), 0) } == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+/// Clone `src` to `dst` with the `FICLONE` ioctl (Btrfs, XFS, bcachefs)
+#[cfg(target_os = "linux")]
+fn clone_file(src: &Path, dst: &Path) -> std::io::Result<()> {
+    use std::os::unix::io::AsRawFd;
+
+    let source = std::fs::File::open(src)?;
+    let permissions = source.metadata()?.permissions();
+    let target = std::fs::File::options()
+        .write(true)
+        .create_new(true)
+        .open(dst)?;
+    // Safety: both descriptors are open for the duration of the call.
+    let result = unsafe { libc::ioctl(target.as_raw_fd(), libc::FICLONE, source.as_raw_fd()) };
+    if result != 0 {
+        let err = std::io::Error::last_os_error();
+        drop(target);
+        let _ = std::fs::remove_file(dst);
+        return Err(err);
+    }
+    target.set_permissions(permissions)?;
+    Ok(())
+}
+
+/// Clone `src` to `dst` on other platforms
+///
+/// On Windows, `std::fs::copy` goes through `CopyFileExW`, which uses block
+/// cloning on ReFS and Dev Drive volumes by itself (Windows 11 24H2 and
+/// Windows Server 2025), so a plain copy is already the clone there.
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+fn clone_file(src: &Path, dst: &Path) -> std::io::Result<()> {
+    std::fs::copy(src, dst).map(|_| ())
+}
+
+/// Whether files in `dir` can be cloned instead of copied
+///
+/// Probes by cloning a scratch file, so the answer reflects the actual
+/// filesystem rather than the platform.
+pub fn supports_cow(dir: &Path) -> bool {
+    if !cfg!(any(target_os = "macos", target_os = "linux")) {
+        return false;
+    }
+    let src = dir.join(format!(".vx-clone-probe-{}", std::process::id()));
+    let dst = src.with_extension("clone");
+    let supported = std::fs::write(&src, b"vx").is_ok() && clone_file(&src, &dst).is_ok();
+    let _ = std::fs::remove_file(&src);
+    let _ = std::fs::remove_file(&dst);
+    supported
 }
 
 /// Copy a file or directory
@@ -209,9 +288,40 @@ fn copy_path(src: &Path, dst: &Path) -> Result<()> {
     Ok(())
 }
 
+/// File inside a materialized copy recording the store directory it was copied from
+pub const COPY_SOURCE_FILE: &str = ".vx-source";
+
+/// Make the store directory `src` available at `dst`
+///
+/// Symlinks when possible. Where symlinks aren't viable (Windows without
+/// Developer Mode, filesystems without symlink support), clones the tree
+/// copy-on-write and records `src` in [`COPY_SOURCE_FILE`], so
+/// [`copied_from`] can still tell which version `dst` holds.
+pub fn materialize(src: &Path, dst: &Path) -> Result<LinkStrategy> {
+    match create_link(src, dst, LinkStrategy::SymLink) {
+        Ok(()) => Ok(LinkStrategy::SymLink),
+        Err(e) => {
+            tracing::debug!(dst = %dst.display(), error = %e, "Symlink failed, cloning instead");
+            create_cow_link(src, dst)?;
+            std::fs::write(dst.join(COPY_SOURCE_FILE), src.to_string_lossy().as_bytes())?;
+            Ok(LinkStrategy::CopyOnWrite)
+        }
+    }
+}
+
+/// The store directory a [`materialize`]d path points to or was copied from
+pub fn copied_from(path: &Path) -> Option<PathBuf> {
+    std::fs::read_link(path).ok().or_else(|| {
+        std::fs::read_to_string(path.join(COPY_SOURCE_FILE))
+            .ok()
+            .map(|source| PathBuf::from(source.trim()))
+    })
+}
+
 /// Link a directory tree using the best available strategy
 pub fn link_directory(src: &Path, dst: &Path) -> Result<LinkResult> {
-    let strategy = LinkStrategy::detect(src);
+    // Probe where the links will be created, not the (possibly read-only) source
+    let strategy = LinkStrategy::detect(dst);
 
     match create_link(src, dst, strategy) {
         Ok(()) => {
@@ -2,7 +2,8 @@
 
 use crate::{VxPaths, with_executable_extension};
 use anyhow::Result;
-use std::path::{Path, PathBuf};
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
 
 /// Current platform information
 ///
@@ -142,6 +143,11 @@ impl PathManager {
         &self.paths.tmp_dir
     }
 
+    /// Get the state directory (install journal, ...)
+    pub fn state_dir(&self) -> &Path {
+        &self.paths.state_dir
+    }
+
     // ========== Store Paths (Content-Addressable Storage) ==========
 
     /// Get the platform directory name for the current platform
@@ -383,6 +389,34 @@ impl PathManager {
         self.env_dir(env_name).exists()
     }
 
+    /// List the runtimes in an environment directory with their versions
+    ///
+    /// Versions are read from the store link targets
+    /// (`~/.vx/store/<runtime>/<version>`); entries that don't point into the
+    /// store are reported as `latest`.
+    pub fn env_tools(&self, env_dir: &Path) -> Result<BTreeMap<String, String>> {
+        let mut tools = BTreeMap::new();
+        if !env_dir.exists() {
+            return Ok(tools);
+        }
+
+        for entry in std::fs::read_dir(env_dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if !path.is_symlink() && !path.is_dir() {
+                continue;
+            }
+
+            let name = entry.file_name().to_string_lossy().to_string();
+            let version = crate::link::copied_from(&path)
+                .and_then(|target| store_version(&target, &name))
+                .unwrap_or_else(|| "latest".to_string());
+            tools.insert(name, version);
+        }
+
+        Ok(tools)
+    }
+
     /// Create an environment directory
     pub fn create_env(&self, env_name: &str) -> Result<PathBuf> {
         let env_dir = self.env_dir(env_name);
@@ -526,3 +560,19 @@ impl Default for PathManager {
         })
     }
 }
+
+/// Extract `<version>` from a `.../store/<runtime>/<version>/...` link target
+fn store_version(target: &Path, runtime_name: &str) -> Option<String> {
+    let parts: Vec<_> = target
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(part) => part.to_str(),
+            _ => None,
+        })
+        .collect();
+    parts
+        .windows(3)
+        .rev()
+        .find(|w| w[0] == "store" && w[1] == runtime_name)
+        .map(|w| w[2].to_string())
+}
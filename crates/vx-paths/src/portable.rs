@@ -0,0 +1,93 @@
+//! Portable mode (`VX_PORTABLE`)
+//!
+//! In portable mode vx keeps everything next to its own binary, so a copy on
+//! a USB stick or network share works on locked-down machines without
+//! writing to the user's home directory:
+//!
+//! ```text
+//! E:\vx\
+//! ├── vx.exe
+//! ├── vx.portable          # marker file (or set VX_PORTABLE=1)
+//! └── .vx\                 # VX_HOME: store, cache, config, shims, …
+//! ```
+//!
+//! Portable mode is on when `VX_PORTABLE` is `1`/`true`/`yes`, or when a
+//! [`PORTABLE_MARKER`] file sits next to the binary. `VX_PORTABLE=0` turns it
+//! off despite the marker, and an explicit `VX_HOME` always wins.
+//!
+//! Shims written in portable mode refer to their targets relative to the
+//! shim itself (see [`relative_path`]), so they keep working when the drive
+//! is mounted under another letter or path.
+
+use std::path::{Component, Path, PathBuf};
+
+/// Environment variable that turns portable mode on (`1`) or off (`0`)
+pub const PORTABLE_ENV: &str = "VX_PORTABLE";
+
+/// Marker file next to the vx binary that turns portable mode on
+pub const PORTABLE_MARKER: &str = "vx.portable";
+
+/// Directory next to the vx binary used as VX_HOME in portable mode
+pub const PORTABLE_HOME_DIR: &str = ".vx";
+
+/// Portable VX_HOME for the running vx binary, `None` outside portable mode
+pub fn portable_home() -> Option<PathBuf> {
+    let exe = std::env::current_exe().ok()?;
+    portable_home_for(&exe, std::env::var(PORTABLE_ENV).ok().as_deref())
+}
+
+/// Portable VX_HOME for a vx binary at `exe` and a `VX_PORTABLE` value
+pub fn portable_home_for(exe: &Path, env: Option<&str>) -> Option<PathBuf> {
+    let dir = exe.parent()?;
+    let enabled = match env.map(|v| v.trim().to_ascii_lowercase()).as_deref() {
+        Some("1" | "true" | "yes" | "on") => true,
+        Some("0" | "false" | "no" | "off") => false,
+        _ => dir.join(PORTABLE_MARKER).is_file(),
+    };
+    enabled.then(|| dir.join(PORTABLE_HOME_DIR))
+}
+
+/// Whether this process runs in portable mode
+///
+/// True when `VX_HOME` was set from [`portable_home`] (the CLI exports
+/// `VX_PORTABLE=1` alongside it so child processes agree) or when portable
+/// mode is requested without an explicit `VX_HOME`.
+pub fn is_active() -> bool {
+    match std::env::var(PORTABLE_ENV).as_deref() {
+        Ok("1") => true,
+        _ => std::env::var_os("VX_HOME").is_none() && portable_home().is_some(),
+    }
+}
+
+/// Path of `target` relative to the directory `from`
+///
+/// Both paths must be absolute and share a root (same drive on Windows);
+/// returns `None` otherwise. `relative_path("/v/.vx/shims", "/v/.vx/store/x")`
+/// is `../store/x`.
+pub fn relative_path(from: &Path, target: &Path) -> Option<PathBuf> {
+    if !from.is_absolute() || !target.is_absolute() {
+        return None;
+    }
+    let from: Vec<Component> = from.components().collect();
+    let target: Vec<Component> = target.components().collect();
+    // Different drive letters / UNC shares cannot be bridged with `..`
+    if from.first() != target.first() {
+        return None;
+    }
+    let common = from.iter().zip(&target).take_while(|(a, b)| a == b).count();
+    if from[common..]
+        .iter()
+        .any(|c| !matches!(c, Component::Normal(_)))
+    {
+        return None;
+    }
+
+    let mut relative = PathBuf::new();
+    for _ in common..from.len() {
+        relative.push("..");
+    }
+    for component in &target[common..] {
+        relative.push(component.as_os_str());
+    }
+    Some(relative)
+}
@@ -21,9 +21,18 @@
 //! ```sh
 //! #!/bin/sh
 //! export PATH="/home/user/.vx/store/node/20.0.0/linux-x64/node-v20.0.0-linux-x64:$PATH"
-//! exec "/home/user/.vx/packages/npm/opencode-ai/latest/opencode" "$@"
+//! exec '/home/user/.vx/packages/npm/opencode-ai/latest/opencode' "$@"
 //! ```
 //!
+//! Targets are quoted so that homes with spaces, quotes or non-ASCII
+//! characters work: Unix shims single-quote the path, and Windows shims refer
+//! to a non-ASCII target relative to `%~dp0`, because cmd.exe decodes batch
+//! files with the OEM code page and would mangle the absolute path.
+//!
+//! In portable mode (see [`crate::portable`]) shims refer to their target
+//! relative to the shim (`%~dp0..\packages\…` / `$(dirname "$0")/../packages/…`)
+//! so that the vx home can move between machines and drive letters.
+//!
 //! ## Future Enhancement: shimexe-core Integration
 //!
 //! This module can be enhanced to use `shimexe-core` (<https://github.com/loonghao/shimexe>)
@@ -84,17 +93,7 @@ fn create_windows_shim(shim_dir: &Path, exe_name: &str, target_path: &Path) -> R
     let shim_path = shim_dir.join(format!("{}.cmd", exe_name));
     let created = !shim_path.exists();
 
-    // Use forward slashes in the script for better compatibility
-    let target_str = target_path.to_string_lossy();
-
-    // Create batch script content
-    let content = format!(
-        r#"@echo off
-setlocal
-"{}" %*
-"#,
-        target_str
-    );
+    let content = windows_shim_script(shim_dir, target_path);
 
     std::fs::write(&shim_path, content)
         .with_context(|| format!("Failed to write shim: {}", shim_path.display()))?;
@@ -110,13 +109,7 @@ fn create_unix_shim(shim_dir: &Path, exe_name: &str, target_path: &Path) -> Resu
     let shim_path = shim_dir.join(exe_name);
     let created = !shim_path.exists();
 
-    // Create shell script content
-    let content = format!(
-        r#"#!/bin/sh
-exec "{}" "$@"
-"#,
-        target_path.display()
-    );
+    let content = unix_shim_script(shim_dir, target_path);
 
     std::fs::write(&shim_path, &content)
         .with_context(|| format!("Failed to write shim: {}", shim_path.display()))?;
@@ -130,6 +123,69 @@ exec "{}" "$@"
     Ok(ShimResult { shim_path, created })
 }
 
+/// Content of a Windows .cmd shim running `target_path`
+#[cfg_attr(not(windows), allow(dead_code))]
+fn windows_shim_script(shim_dir: &Path, target_path: &Path) -> String {
+    // Portable mode, or a non-ASCII target the OEM code page cannot encode:
+    // resolve the target from the shim's own directory
+    let relative = portable_target(shim_dir, target_path).or_else(|| {
+        if target_path.to_string_lossy().is_ascii() {
+            None
+        } else {
+            crate::portable::relative_path(shim_dir, target_path)
+        }
+    });
+    let target = match relative {
+        Some(relative) => format!("%~dp0{}", escape_batch_percent(&relative)),
+        None => escape_batch_percent(target_path),
+    };
+
+    format!(
+        r#"@echo off
+setlocal
+"{}" %*
+"#,
+        target
+    )
+}
+
+/// `%` starts a variable reference in batch files, even inside quotes
+#[cfg_attr(not(windows), allow(dead_code))]
+fn escape_batch_percent(path: &Path) -> String {
+    path.to_string_lossy().replace('%', "%%")
+}
+
+/// Content of a Unix shell shim running `target_path`
+#[cfg_attr(windows, allow(dead_code))]
+fn unix_shim_script(shim_dir: &Path, target_path: &Path) -> String {
+    // Portable mode: resolve the target from the shim's own directory
+    let target = match portable_target(shim_dir, target_path) {
+        Some(relative) => format!("\"$(dirname \"$0\")\"/{}", sh_quote(&relative)),
+        None => sh_quote(target_path),
+    };
+
+    format!(
+        r#"#!/bin/sh
+exec {} "$@"
+"#,
+        target
+    )
+}
+
+/// Single-quote a path for sh, so spaces, `$`, backticks and quotes are kept
+#[cfg_attr(windows, allow(dead_code))]
+fn sh_quote(path: &Path) -> String {
+    format!("'{}'", path.to_string_lossy().replace('\'', r"'\''"))
+}
+
+/// Target relative to the shim directory, when running in portable mode
+fn portable_target(shim_dir: &Path, target_path: &Path) -> Option<PathBuf> {
+    if !crate::portable::is_active() {
+        return None;
+    }
+    crate::portable::relative_path(shim_dir, target_path)
+}
+
 /// Remove a shim for an executable
 ///
 /// # Arguments
@@ -338,4 +394,45 @@ mod tests {
         let shims = list_shims(&shim_dir).unwrap();
         assert_eq!(shims, vec!["tool1"]);
     }
+
+    #[test]
+    fn test_unix_shim_quotes_special_characters() {
+        let target = Path::new("/home/J O'Brien/$HOME `x`/用户/tool");
+        let script = unix_shim_script(Path::new("/home/J O'Brien/.vx/shims"), target);
+        assert!(script.contains(r#"exec '/home/J O'\''Brien/$HOME `x`/用户/tool' "$@""#));
+    }
+
+    #[test]
+    fn test_windows_shim_non_ascii_target_is_relative() {
+        let shim_dir = Path::new("/Users/Jürgen Müller/.vx/shims");
+        let target = Path::new("/Users/Jürgen Müller/.vx/packages/npm/tsc");
+        let script = windows_shim_script(shim_dir, target);
+        let relative = Path::new("..").join("packages").join("npm").join("tsc");
+        assert!(script.contains(&format!(r#""%~dp0{}" %*"#, relative.display())));
+
+        let script = windows_shim_script(Path::new("/vx/shims"), Path::new("/100% tools/tsc"));
+        assert!(script.contains(r#""/100%% tools/tsc" %*"#));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_shim_runs_from_path_with_spaces_and_unicode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = tempdir().unwrap();
+        let home = temp.path().join("My Folder/用户 ü/it's $here");
+        let shim_dir = home.join("shims");
+        let target = home.join("bin").join("tool");
+        std::fs::create_dir_all(target.parent().unwrap()).unwrap();
+        std::fs::write(&target, "#!/bin/sh\necho \"ran $1\"\n").unwrap();
+        std::fs::set_permissions(&target, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let result = create_shim(&shim_dir, "tool", &target).unwrap();
+        let output = std::process::Command::new(&result.shim_path)
+            .arg("a b")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "ran a b\n");
+    }
 }
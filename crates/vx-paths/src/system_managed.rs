@@ -0,0 +1,84 @@
+//! Store records for system-managed installations
+//!
+//! Tools that only ship Windows installers are installed through winget or
+//! Chocolatey instead of being extracted into the store. vx still creates the
+//! store version directory and writes a `system-managed.json` record into it,
+//! so the version shows up in `vx list` and `vx which` can resolve the
+//! executable the package manager installed.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// File name of the record inside a store version directory
+pub const SYSTEM_MANAGED_RECORD_FILE: &str = "system-managed.json";
+
+/// A store version installed by a system package manager
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SystemManagedRecord {
+    /// Package manager that owns the installation (winget, choco)
+    pub manager: String,
+    /// Package identifier for that manager
+    pub package: String,
+    /// Installed version
+    pub version: String,
+    /// Executable located after installation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub executable: Option<PathBuf>,
+    /// Installation timestamp (ISO 8601)
+    pub installed_at: String,
+}
+
+impl SystemManagedRecord {
+    /// Create a new record
+    pub fn new(
+        manager: impl Into<String>,
+        package: impl Into<String>,
+        version: impl Into<String>,
+        executable: Option<PathBuf>,
+    ) -> Self {
+        Self {
+            manager: manager.into(),
+            package: package.into(),
+            version: version.into(),
+            executable,
+            installed_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Path of the record file for a store version directory
+    pub fn path(version_dir: &Path) -> PathBuf {
+        version_dir.join(SYSTEM_MANAGED_RECORD_FILE)
+    }
+
+    /// Read the record from a store version directory, if present
+    pub fn read(version_dir: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(Self::path(version_dir)).ok()?;
+        match serde_json::from_str(&content) {
+            Ok(record) => Some(record),
+            Err(e) => {
+                tracing::warn!(
+                    dir = %version_dir.display(),
+                    error = %e,
+                    "Ignoring invalid system-managed record"
+                );
+                None
+            }
+        }
+    }
+
+    /// Write the record into a store version directory, creating it if needed
+    pub fn write(&self, version_dir: &Path) -> Result<()> {
+        std::fs::create_dir_all(version_dir)
+            .with_context(|| format!("Failed to create {}", version_dir.display()))?;
+        let path = Self::path(version_dir);
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(&path, content)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Recorded executable, if it still exists on disk
+    pub fn existing_executable(&self) -> Option<&Path> {
+        self.executable.as_deref().filter(|p| p.exists())
+    }
+}
@@ -0,0 +1,193 @@
+//! Per-operation temporary directories under `~/.vx/tmp`
+//!
+//! Downloads and archive extraction work in a [`TempWorkspace`]: a fresh
+//! directory named after the operation, the process id and a counter, so
+//! parallel installs (in one process or several) never share one. The
+//! directory is removed when the workspace is dropped, including when an
+//! install fails halfway.
+//!
+//! A process that is killed cannot clean up, so [`sweep_orphans`] runs at
+//! startup and removes entries nobody has written to for
+//! [`ORPHAN_MAX_AGE`].
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Temp entries untouched for this long belong to a process that died
+pub const ORPHAN_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Workspaces created by this process, for unique names
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A temporary directory owned by one operation, removed on drop
+#[derive(Debug)]
+pub struct TempWorkspace {
+    path: PathBuf,
+}
+
+impl TempWorkspace {
+    /// Create a workspace in the vx temp directory (`~/.vx/tmp`)
+    pub fn new(label: &str) -> Result<Self> {
+        let paths = crate::VxPaths::new()?;
+        Self::new_in(&paths.tmp_dir, label)
+    }
+
+    /// Create a workspace in `root`
+    ///
+    /// `label` names the operation (`download`, `extract`, ...) so leftover
+    /// directories can be told apart.
+    pub fn new_in(root: &Path, label: &str) -> Result<Self> {
+        std::fs::create_dir_all(root)
+            .with_context(|| format!("Failed to create {}", root.display()))?;
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or_default();
+        loop {
+            let name = format!(
+                "{}-{}-{}-{:08x}",
+                label,
+                std::process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed),
+                nanos
+            );
+            let path = root.join(name);
+            // `create_dir` fails if the name is taken, unlike `create_dir_all`
+            match std::fs::create_dir(&path) {
+                Ok(()) => return Ok(Self { path }),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(e) => {
+                    return Err(e).with_context(|| format!("Failed to create {}", path.display()));
+                }
+            }
+        }
+    }
+
+    /// The workspace directory
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// A path inside the workspace
+    pub fn join(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.path.join(path)
+    }
+
+    /// Move every entry of `dir` (inside the workspace) into `dest`
+    ///
+    /// Entries are renamed, falling back to a copy when `dest` is on
+    /// another filesystem (`VX_TMP_DIR` elsewhere). Existing entries in
+    /// `dest` with the same name are replaced.
+    pub fn move_contents(&self, dir: &Path, dest: &Path) -> Result<()> {
+        std::fs::create_dir_all(dest)
+            .with_context(|| format!("Failed to create {}", dest.display()))?;
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            let target = dest.join(entry.file_name());
+            remove_entry(&target);
+            move_entry(&entry.path(), &target).with_context(|| {
+                format!(
+                    "Failed to move {} to {}",
+                    entry.path().display(),
+                    target.display()
+                )
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for TempWorkspace {
+    fn drop(&mut self) {
+        if let Err(e) = std::fs::remove_dir_all(&self.path)
+            && e.kind() != std::io::ErrorKind::NotFound
+        {
+            tracing::debug!(path = %self.path.display(), error = %e, "Failed to remove temp workspace");
+        }
+    }
+}
+
+/// Remove entries of `root` that were not written to for `older_than`
+///
+/// A directory counts as written to when any file inside it is, so a
+/// workspace that is still in use is never removed. Returns the number of
+/// entries removed.
+pub fn sweep_orphans(root: &Path, older_than: Duration) -> usize {
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return 0;
+    };
+    let now = SystemTime::now();
+    let is_old = |time: SystemTime| now.duration_since(time).is_ok_and(|age| age > older_than);
+    let mut removed = 0;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(modified) = entry.metadata().and_then(|m| m.modified()) else {
+            continue;
+        };
+        // Cheap check first; only old directories are walked
+        if !is_old(modified) || newest_modification(&path).is_some_and(|t| !is_old(t)) {
+            continue;
+        }
+        let result = if path.is_dir() {
+            std::fs::remove_dir_all(&path)
+        } else {
+            std::fs::remove_file(&path)
+        };
+        match result {
+            Ok(()) => removed += 1,
+            Err(e) => {
+                tracing::debug!(path = %path.display(), error = %e, "Failed to remove stale temp entry")
+            }
+        }
+    }
+    removed
+}
+
+/// Newest modification time of `path` and everything below it
+fn newest_modification(path: &Path) -> Option<SystemTime> {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.metadata().ok()?.modified().ok())
+        .max()
+}
+
+fn remove_entry(path: &Path) {
+    let Ok(meta) = std::fs::symlink_metadata(path) else {
+        return;
+    };
+    let _ = if meta.is_dir() {
+        std::fs::remove_dir_all(path)
+    } else {
+        std::fs::remove_file(path)
+    };
+}
+
+fn move_entry(source: &Path, target: &Path) -> std::io::Result<()> {
+    if std::fs::rename(source, target).is_ok() {
+        return Ok(());
+    }
+    copy_recursive(source, target)?;
+    remove_entry(source);
+    Ok(())
+}
+
+fn copy_recursive(source: &Path, target: &Path) -> std::io::Result<()> {
+    let meta = std::fs::symlink_metadata(source)?;
+    if meta.is_dir() {
+        std::fs::create_dir_all(target)?;
+        for entry in std::fs::read_dir(source)? {
+            let entry = entry?;
+            copy_recursive(&entry.path(), &target.join(entry.file_name()))?;
+        }
+    } else {
+        #[cfg(unix)]
+        if meta.file_type().is_symlink() {
+            return std::os::unix::fs::symlink(std::fs::read_link(source)?, target);
+        }
+        std::fs::copy(source, target)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,82 @@
+//! Tests for the install transaction journal
+
+use tempfile::TempDir;
+use vx_paths::{INSTALL_JOURNAL_FILE, InstallJournal, ToolChange, TransactionStatus};
+
+#[test]
+fn test_journal_roundtrip() {
+    let temp = TempDir::new().unwrap();
+    let state_dir = temp.path().join("state");
+
+    let mut journal = InstallJournal::load(&state_dir);
+    assert!(journal.transactions.is_empty());
+
+    let id = journal.begin("install");
+    journal.record(
+        id,
+        ToolChange::new("node", Some("20.11.0".to_string()), "20.12.0"),
+    );
+    journal.finish(id, TransactionStatus::Committed);
+    journal.save().unwrap();
+
+    assert!(state_dir.join(INSTALL_JOURNAL_FILE).exists());
+    let loaded = InstallJournal::load(&state_dir);
+    let tx = loaded.transaction(id).unwrap();
+    assert_eq!(tx.command, "install");
+    assert_eq!(tx.status, TransactionStatus::Committed);
+    assert_eq!(tx.changes[0].installed, "20.12.0");
+}
+
+#[test]
+fn test_last_change_skips_rolled_back() {
+    let temp = TempDir::new().unwrap();
+    let mut journal = InstallJournal::load(temp.path());
+
+    let first = journal.begin("install");
+    journal.record(first, ToolChange::new("node", None, "20.11.0"));
+    let second = journal.begin("upgrade");
+    journal.record(
+        second,
+        ToolChange::new("node", Some("20.11.0".to_string()), "20.12.0"),
+    );
+    journal.record(second, ToolChange::new("uv", None, "0.5.0"));
+
+    let (id, change) = journal.last_change("node").unwrap();
+    assert_eq!(id, second);
+    assert_eq!(change.installed, "20.12.0");
+
+    journal.mark_rolled_back(second, "node");
+    let (id, change) = journal.last_change("node").unwrap();
+    assert_eq!(id, first);
+    assert_eq!(change.previous, None);
+
+    // Other tools in the same transaction are untouched
+    assert_eq!(journal.last_change("uv").unwrap().0, second);
+    assert!(journal.last_change("go").is_none());
+}
+
+#[test]
+fn test_invalid_journal_starts_empty() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join(INSTALL_JOURNAL_FILE), "not json").unwrap();
+
+    let mut journal = InstallJournal::load(temp.path());
+    assert!(journal.transactions.is_empty());
+    assert_eq!(journal.begin("install"), 1);
+}
+
+#[test]
+fn test_rolled_back_transaction_undoes_changes() {
+    let temp = TempDir::new().unwrap();
+    let mut journal = InstallJournal::load(temp.path());
+
+    let id = journal.begin("upgrade");
+    journal.record(
+        id,
+        ToolChange::new("node", Some("20.11.0".to_string()), "20.12.0"),
+    );
+    journal.finish(id, TransactionStatus::RolledBack);
+
+    assert!(journal.transaction(id).unwrap().changes[0].rolled_back);
+    assert!(journal.last_change("node").is_none());
+}
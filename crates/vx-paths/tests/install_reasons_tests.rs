@@ -0,0 +1,50 @@
+//! Tests for install reasons
+
+use tempfile::TempDir;
+use vx_paths::{INSTALL_REASONS_FILE, InstallReason, InstallReasons, record_install_reason};
+
+#[test]
+fn test_reasons_roundtrip() {
+    let temp = TempDir::new().unwrap();
+    let state_dir = temp.path().join("state");
+
+    record_install_reason(&state_dir, "pnpm", None);
+    record_install_reason(&state_dir, "node", Some("pnpm"));
+    record_install_reason(&state_dir, "node", Some("yarn"));
+    record_install_reason(&state_dir, "node", Some("pnpm"));
+    assert!(state_dir.join(INSTALL_REASONS_FILE).exists());
+
+    let reasons = InstallReasons::load(&state_dir);
+    assert_eq!(reasons.reason("pnpm"), Some(InstallReason::Explicit));
+    assert!(reasons.is_dependency("node"));
+    assert_eq!(reasons.required_by("node"), ["pnpm", "yarn"]);
+    assert_eq!(reasons.reason("go"), None);
+    assert!(!reasons.is_dependency("go"));
+}
+
+#[test]
+fn test_explicit_install_promotes_dependency() {
+    let temp = TempDir::new().unwrap();
+    let mut reasons = InstallReasons::load(temp.path());
+
+    reasons.record_dependency("node", "pnpm");
+    reasons.record_explicit("node");
+    assert_eq!(reasons.reason("node"), Some(InstallReason::Explicit));
+    // Dependents stay known for `vx rdeps`
+    assert_eq!(reasons.required_by("node"), ["pnpm"]);
+
+    // A dependency install never demotes an explicit tool
+    reasons.record_dependency("node", "yarn");
+    assert_eq!(reasons.reason("node"), Some(InstallReason::Explicit));
+
+    reasons.remove("node");
+    assert_eq!(reasons.reason("node"), None);
+    assert!(reasons.required_by("node").is_empty());
+}
+
+#[test]
+fn test_invalid_reasons_start_empty() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join(INSTALL_REASONS_FILE), "not json").unwrap();
+    assert!(InstallReasons::load(temp.path()).tools.is_empty());
+}
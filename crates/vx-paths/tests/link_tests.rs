@@ -87,3 +87,75 @@ fn test_link_directory() {
     assert!(dst.join("file1.txt").exists());
     assert!(dst.join("subdir/file2.txt").exists());
 }
+
+#[test]
+fn test_create_link_copy_on_write() {
+    let temp_dir = TempDir::new().unwrap();
+    let src = temp_dir.path().join("src_dir");
+    let dst = temp_dir.path().join("dst_dir");
+
+    std::fs::create_dir_all(src.join("bin")).unwrap();
+    std::fs::write(src.join("bin/tool"), "#!/bin/sh\necho tool\n").unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(src.join("bin/tool"), std::fs::Permissions::from_mode(0o755))
+            .unwrap();
+    }
+
+    // Clones where supported, copies otherwise; either way the result is independent
+    link::create_link(&src, &dst, LinkStrategy::CopyOnWrite).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(dst.join("bin/tool")).unwrap(),
+        "#!/bin/sh\necho tool\n"
+    );
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(dst.join("bin/tool"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o755);
+    }
+
+    std::fs::write(dst.join("bin/tool"), "changed").unwrap();
+    assert_eq!(
+        std::fs::read_to_string(src.join("bin/tool")).unwrap(),
+        "#!/bin/sh\necho tool\n"
+    );
+}
+
+#[test]
+fn test_supports_cow_leaves_no_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let _ = link::supports_cow(temp_dir.path());
+    assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn test_materialize_and_copied_from() {
+    let temp_dir = TempDir::new().unwrap();
+    let store = temp_dir.path().join("store/node/20.0.0");
+    std::fs::create_dir_all(store.join("bin")).unwrap();
+    std::fs::write(store.join("bin/node"), "node").unwrap();
+
+    let env_path = temp_dir.path().join("env/node");
+    let strategy = link::materialize(&store, &env_path).unwrap();
+    assert!(env_path.join("bin/node").exists());
+    assert_eq!(link::copied_from(&env_path), Some(store.clone()));
+    if strategy == LinkStrategy::CopyOnWrite {
+        assert!(env_path.join(link::COPY_SOURCE_FILE).exists());
+    }
+
+    // A materialized copy records its source
+    let copy = temp_dir.path().join("env/copied");
+    link::create_link(&store, &copy, LinkStrategy::Copy).unwrap();
+    assert_eq!(link::copied_from(&copy), None);
+    std::fs::write(
+        copy.join(link::COPY_SOURCE_FILE),
+        store.to_string_lossy().as_bytes(),
+    )
+    .unwrap();
+    assert_eq!(link::copied_from(&copy), Some(store));
+}
@@ -103,3 +103,33 @@ fn test_list_store_versions_supports_unified_version_dirs() {
 
     assert_eq!(manager.list_store_versions("uv").unwrap(), vec!["0.11.6"]);
 }
+
+#[test]
+fn test_env_tools_reads_store_links() {
+    let temp_dir = TempDir::new().unwrap();
+    let base_dir = temp_dir.path().join(".vx");
+    let manager = PathManager::with_base_dir(&base_dir).unwrap();
+
+    let env_dir = manager.create_env("dev").unwrap();
+    let node_dir = manager.version_store_dir("node", "20.10.0");
+    std::fs::create_dir_all(&node_dir).unwrap();
+    vx_paths::link::create_link(
+        &node_dir,
+        &env_dir.join("node"),
+        vx_paths::LinkStrategy::SymLink,
+    )
+    .unwrap();
+    std::fs::create_dir_all(env_dir.join("custom")).unwrap();
+    std::fs::write(env_dir.join("notes.txt"), "ignored").unwrap();
+
+    let tools = manager.env_tools(&env_dir).unwrap();
+    assert_eq!(tools.len(), 2);
+    assert_eq!(tools["node"], "20.10.0");
+    assert_eq!(tools["custom"], "latest");
+    assert!(
+        manager
+            .env_tools(&manager.env_dir("missing"))
+            .unwrap()
+            .is_empty()
+    );
+}
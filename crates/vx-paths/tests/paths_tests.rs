@@ -17,6 +17,7 @@ fn test_vx_paths_creation() {
     // RFC 0025: New directories
     assert_eq!(paths.packages_dir, PathBuf::from("/tmp/test-vx/packages"));
     assert_eq!(paths.shims_dir, PathBuf::from("/tmp/test-vx/shims"));
+    assert_eq!(paths.state_dir, PathBuf::from("/tmp/test-vx/state"));
 }
 
 #[test]
@@ -0,0 +1,78 @@
+//! Portable mode tests
+
+use std::path::{Path, PathBuf};
+use vx_paths::portable::{PORTABLE_HOME_DIR, PORTABLE_MARKER, portable_home_for, relative_path};
+
+#[test]
+fn test_portable_home_from_env() {
+    let temp = tempfile::tempdir().unwrap();
+    let exe = temp.path().join("vx.exe");
+
+    assert_eq!(
+        portable_home_for(&exe, Some("1")),
+        Some(temp.path().join(PORTABLE_HOME_DIR))
+    );
+    assert_eq!(
+        portable_home_for(&exe, Some("true")),
+        Some(temp.path().join(PORTABLE_HOME_DIR))
+    );
+    assert_eq!(portable_home_for(&exe, None), None);
+    assert_eq!(portable_home_for(&exe, Some("")), None);
+}
+
+#[test]
+fn test_portable_home_from_marker() {
+    let temp = tempfile::tempdir().unwrap();
+    let exe = temp.path().join("vx");
+    std::fs::write(temp.path().join(PORTABLE_MARKER), "").unwrap();
+
+    assert_eq!(
+        portable_home_for(&exe, None),
+        Some(temp.path().join(PORTABLE_HOME_DIR))
+    );
+    // VX_PORTABLE=0 overrides the marker
+    assert_eq!(portable_home_for(&exe, Some("0")), None);
+}
+
+#[cfg(not(windows))]
+#[test]
+fn test_relative_path() {
+    assert_eq!(
+        relative_path(
+            Path::new("/media/usb/.vx/shims"),
+            Path::new("/media/usb/.vx/packages/npm/tsc/bin/tsc")
+        ),
+        Some(PathBuf::from("../packages/npm/tsc/bin/tsc"))
+    );
+    assert_eq!(
+        relative_path(
+            Path::new("/media/usb/.vx/store/bun/1.1.0"),
+            Path::new("/media/usb/.vx/store/bun/1.1.0/bun")
+        ),
+        Some(PathBuf::from("bun"))
+    );
+    assert_eq!(
+        relative_path(Path::new("shims"), Path::new("/usr/bin/tsc")),
+        None
+    );
+}
+
+#[cfg(windows)]
+#[test]
+fn test_relative_path() {
+    assert_eq!(
+        relative_path(
+            Path::new(r"E:\vx\.vx\shims"),
+            Path::new(r"E:\vx\.vx\packages\npm\tsc\tsc.cmd")
+        ),
+        Some(PathBuf::from(r"..\packages\npm\tsc\tsc.cmd"))
+    );
+    // Another drive cannot be reached relatively
+    assert_eq!(
+        relative_path(
+            Path::new(r"E:\vx\.vx\shims"),
+            Path::new(r"C:\tools\tsc.exe")
+        ),
+        None
+    );
+}
@@ -0,0 +1,41 @@
+//! Tests for system-managed store records
+
+use tempfile::TempDir;
+use vx_paths::{SYSTEM_MANAGED_RECORD_FILE, SystemManagedRecord};
+
+#[test]
+fn test_record_roundtrip() {
+    let temp = TempDir::new().unwrap();
+    let version_dir = temp.path().join("store").join("nuget").join("6.9.1");
+    let exe = temp.path().join("nuget.exe");
+
+    let record = SystemManagedRecord::new("winget", "Microsoft.NuGet", "6.9.1", Some(exe));
+    record.write(&version_dir).unwrap();
+
+    assert!(version_dir.join(SYSTEM_MANAGED_RECORD_FILE).exists());
+    assert_eq!(SystemManagedRecord::read(&version_dir), Some(record));
+}
+
+#[test]
+fn test_read_missing_or_invalid_record() {
+    let temp = TempDir::new().unwrap();
+    assert_eq!(SystemManagedRecord::read(temp.path()), None);
+
+    std::fs::write(temp.path().join(SYSTEM_MANAGED_RECORD_FILE), "not json").unwrap();
+    assert_eq!(SystemManagedRecord::read(temp.path()), None);
+}
+
+#[test]
+fn test_existing_executable() {
+    let temp = TempDir::new().unwrap();
+    let exe = temp.path().join("tool.exe");
+
+    let record = SystemManagedRecord::new("choco", "tool", "1.0.0", Some(exe.clone()));
+    assert_eq!(record.existing_executable(), None);
+
+    std::fs::write(&exe, b"").unwrap();
+    assert_eq!(record.existing_executable(), Some(exe.as_path()));
+
+    let record = SystemManagedRecord::new("choco", "tool", "1.0.0", None);
+    assert_eq!(record.existing_executable(), None);
+}
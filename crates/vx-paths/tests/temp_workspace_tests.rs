@@ -0,0 +1,137 @@
+//! Tests for per-operation temp workspaces
+
+#[cfg(unix)]
+use std::path::Path;
+use std::time::Duration;
+#[cfg(unix)]
+use std::time::SystemTime;
+use tempfile::TempDir;
+use vx_paths::{ORPHAN_MAX_AGE, TempWorkspace, sweep_orphans};
+
+#[cfg(unix)]
+fn set_age(path: &Path, age: Duration) {
+    std::fs::File::open(path)
+        .unwrap()
+        .set_modified(SystemTime::now() - age)
+        .unwrap();
+}
+
+#[test]
+fn test_workspace_is_removed_on_drop() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().join("tmp");
+
+    let workspace = TempWorkspace::new_in(&root, "install").unwrap();
+    let path = workspace.path().to_path_buf();
+    std::fs::write(workspace.join("archive.tar.gz"), b"data").unwrap();
+    assert!(path.starts_with(&root));
+    assert!(
+        path.file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("install-")
+    );
+
+    drop(workspace);
+    assert!(!path.exists());
+    assert!(root.exists());
+}
+
+#[test]
+fn test_workspaces_are_unique_across_threads() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().to_path_buf();
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let root = root.clone();
+            std::thread::spawn(move || {
+                (0..16)
+                    .map(|_| TempWorkspace::new_in(&root, "download").unwrap())
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+    let workspaces: Vec<TempWorkspace> = handles
+        .into_iter()
+        .flat_map(|h| h.join().unwrap())
+        .collect();
+
+    assert_eq!(std::fs::read_dir(&root).unwrap().count(), 8 * 16);
+    drop(workspaces);
+    assert_eq!(std::fs::read_dir(&root).unwrap().count(), 0);
+}
+
+#[test]
+fn test_move_contents_replaces_existing_entries() {
+    let temp = TempDir::new().unwrap();
+    let workspace = TempWorkspace::new_in(&temp.path().join("tmp"), "install").unwrap();
+    let extract = workspace.join("extract");
+    std::fs::create_dir_all(extract.join("bin")).unwrap();
+    std::fs::write(extract.join("bin").join("tool"), b"new").unwrap();
+    std::fs::write(extract.join("LICENSE"), b"MIT").unwrap();
+
+    let dest = temp.path().join("store").join("tool").join("1.0.0");
+    std::fs::create_dir_all(dest.join("bin")).unwrap();
+    std::fs::write(dest.join("bin").join("stale"), b"old").unwrap();
+
+    workspace.move_contents(&extract, &dest).unwrap();
+
+    assert_eq!(
+        std::fs::read(dest.join("bin").join("tool")).unwrap(),
+        b"new"
+    );
+    assert!(!dest.join("bin").join("stale").exists());
+    assert!(dest.join("LICENSE").exists());
+    assert_eq!(std::fs::read_dir(&extract).unwrap().count(), 0);
+}
+
+#[test]
+fn test_sweep_orphans_keeps_recent_entries() {
+    let temp = TempDir::new().unwrap();
+    let workspace = TempWorkspace::new_in(temp.path(), "install").unwrap();
+    std::fs::write(workspace.join("partial"), b"data").unwrap();
+    std::fs::write(temp.path().join("loose-file"), b"data").unwrap();
+
+    assert_eq!(sweep_orphans(temp.path(), ORPHAN_MAX_AGE), 0);
+    assert!(workspace.join("partial").exists());
+
+    // Everything is older than zero
+    std::thread::sleep(Duration::from_millis(20));
+    assert_eq!(sweep_orphans(temp.path(), Duration::ZERO), 2);
+    assert_eq!(std::fs::read_dir(temp.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn test_sweep_orphans_missing_root() {
+    let temp = TempDir::new().unwrap();
+    assert_eq!(
+        sweep_orphans(&temp.path().join("missing"), ORPHAN_MAX_AGE),
+        0
+    );
+}
+
+// Directories can only be opened to set their times on Unix
+#[cfg(unix)]
+#[test]
+fn test_sweep_orphans_removes_stale_and_keeps_active_directories() {
+    let temp = TempDir::new().unwrap();
+    let old = ORPHAN_MAX_AGE * 3;
+
+    let stale = temp.path().join("install-1-0-00000000");
+    std::fs::create_dir_all(stale.join("extract")).unwrap();
+    std::fs::write(stale.join("extract").join("file"), b"data").unwrap();
+    set_age(&stale.join("extract").join("file"), old);
+    set_age(&stale.join("extract"), old);
+    set_age(&stale, old);
+
+    // Old directory, but a file inside is still being written
+    let active = temp.path().join("install-2-0-00000000");
+    std::fs::create_dir_all(&active).unwrap();
+    std::fs::write(active.join("file"), b"data").unwrap();
+    set_age(&active, old);
+
+    assert_eq!(sweep_orphans(temp.path(), ORPHAN_MAX_AGE), 1);
+    assert!(!stale.exists());
+    assert!(active.join("file").exists());
+}
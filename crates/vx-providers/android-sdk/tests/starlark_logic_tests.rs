@@ -0,0 +1,111 @@
+//! Pure Starlark logic tests for android-sdk provider.star
+
+use starlark::assert::Assert;
+use starlark::syntax::Dialect;
+use vx_starlark::test_mocks::setup_provider_test_mocks;
+
+fn make_assert() -> Assert<'static> {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    setup_provider_test_mocks(&mut a);
+    a.module("provider.star", vx_provider_android_sdk::PROVIDER_STAR);
+    a
+}
+
+fn provider_star_prefix() -> String {
+    use vx_starlark::test_mocks::prepare_provider_source;
+    prepare_provider_source(vx_provider_android_sdk::PROVIDER_STAR)
+}
+
+/// Evaluate `expr` against provider.star with a mock `ctx` for `os`/`arch`
+fn check(os: &str, arch: &str, expr: &str) {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "{}", arch = "{}", target = ""), install_dir = "/opt/android-sdk", vx_home = "/home/user/.vx")
+{}
+"#,
+        provider_star_prefix(),
+        os,
+        arch,
+        expr
+    ));
+}
+
+// ── provider metadata ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_name_is_android_sdk() {
+    make_assert().eq(
+        r#"load("provider.star", "name"); name"#,
+        r#""android-sdk""#,
+    );
+}
+
+// ── download_url logic ────────────────────────────────────────────────────────
+
+#[test]
+fn test_download_url_uses_build_number() {
+    check(
+        "linux",
+        "x64",
+        r#"download_url(ctx, "19.0") == "https://dl.google.com/android/repository/commandlinetools-linux-13114758_latest.zip""#,
+    );
+}
+
+#[test]
+fn test_download_url_macos_and_windows() {
+    check(
+        "macos",
+        "arm64",
+        r#"download_url(ctx, "16.0").endswith("/commandlinetools-mac-12266719_latest.zip")"#,
+    );
+    check(
+        "windows",
+        "x64",
+        r#"download_url(ctx, "16.0").endswith("/commandlinetools-win-12266719_latest.zip")"#,
+    );
+}
+
+#[test]
+fn test_download_url_none_for_unknown_version() {
+    check("linux", "x64", r#"download_url(ctx, "1.0") == None"#);
+}
+
+// ── install_layout + environment logic ────────────────────────────────────────
+
+#[test]
+fn test_install_layout_strips_cmdline_tools() {
+    check(
+        "windows",
+        "x64",
+        r#"
+layout = install_layout(ctx, "19.0")
+layout["strip_prefix"] == "cmdline-tools" and "bin/sdkmanager.bat" in layout["executable_paths"]
+"#,
+    );
+}
+
+#[test]
+fn test_environment_exports_shared_sdk_root() {
+    check(
+        "linux",
+        "x64",
+        r#"
+env = environment(ctx, "19.0")
+homes = [e for e in env if e["key"] == "ANDROID_HOME"]
+len(homes) == 1 and homes[0]["value"] == "/home/user/.vx/android/sdk"
+"#,
+    );
+}
+
+// ── lint check ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_star_lint_clean() {
+    vx_starlark::provider_test_support::assert_provider_star_lint_clean(
+        vx_provider_android_sdk::PROVIDER_STAR,
+    );
+}
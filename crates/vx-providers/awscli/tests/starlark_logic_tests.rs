@@ -110,16 +110,15 @@ url == None
 }
 
 #[test]
-fn test_download_url_macos_returns_none() {
-    // macOS uses system_install (PKG not supported)
+fn test_download_url_macos_returns_pkg() {
     let mut a = Assert::new();
     a.dialect(&Dialect::Standard);
     a.is_true(&format!(
         r#"
 {}
-ctx = struct(platform = struct(os = "macos", arch = "x64", target = ""))
+ctx = struct(platform = struct(os = "macos", arch = "arm64", target = ""))
 url = download_url(ctx, "2.15.0")
-url == None
+url == "https://awscli.amazonaws.com/AWSCLIV2-2.15.0.pkg"
 "#,
         provider_star_prefix()
     ));
@@ -151,7 +150,7 @@ fn test_install_layout_is_archive() {
 {}
 ctx = struct(platform = struct(os = "linux", arch = "x64", target = ""))
 layout = install_layout(ctx, "2.15.0")
-layout["type"] == "archive"
+layout["__type"] == "archive"
 "#,
         provider_star_prefix()
     ));
@@ -172,6 +171,35 @@ layout["strip_prefix"] == "aws"
     ));
 }
 
+#[test]
+fn test_install_layout_macos_uses_pkg_payload() {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "macos", arch = "x64", target = ""))
+layout = install_layout(ctx, "2.15.0")
+"strip_prefix" not in layout and layout["executable_paths"][0] == "aws-cli/aws"
+"#,
+        provider_star_prefix()
+    ));
+}
+
+#[test]
+fn test_post_extract_windows_is_empty() {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "windows", arch = "x64", target = ""))
+post_extract(ctx, "2.15.0", "C:/vx/store/awscli/2.15.0") == []
+"#,
+        provider_star_prefix()
+    ));
+}
+
 // ── lint check ────────────────────────────────────────────────────────────────
 
 #[test]
@@ -64,43 +64,43 @@ rt = [r for r in runtimes if r["name"] == "az"][0]
 // ── download_url logic ────────────────────────────────────────────────────────
 
 #[test]
-fn test_download_url_linux_returns_tar_gz() {
+fn test_download_url_windows_returns_zip() {
     let mut a = Assert::new();
     a.dialect(&Dialect::Standard);
     a.is_true(&format!(
         r#"
 {}
-ctx = struct(platform = struct(os = "linux", arch = "x64", target = ""))
+ctx = struct(platform = struct(os = "windows", arch = "x64", target = ""))
 url = download_url(ctx, "2.57.0")
-url != None and url.endswith(".tar.gz")
+url == "https://azcliprod.blob.core.windows.net/zip/azure-cli-2.57.0-x64.zip"
 "#,
         provider_star_prefix()
     ));
 }
 
 #[test]
-fn test_download_url_linux_contains_version() {
+fn test_download_url_windows_before_zip_returns_none() {
     let mut a = Assert::new();
     a.dialect(&Dialect::Standard);
     a.is_true(&format!(
         r#"
 {}
-ctx = struct(platform = struct(os = "linux", arch = "x64", target = ""))
-url = download_url(ctx, "2.57.0")
-"2.57.0" in url
+ctx = struct(platform = struct(os = "windows", arch = "x64", target = ""))
+url = download_url(ctx, "2.50.0")
+url == None
 "#,
         provider_star_prefix()
     ));
 }
 
 #[test]
-fn test_download_url_windows_returns_none() {
+fn test_download_url_linux_returns_none() {
     let mut a = Assert::new();
     a.dialect(&Dialect::Standard);
     a.is_true(&format!(
         r#"
 {}
-ctx = struct(platform = struct(os = "windows", arch = "x64", target = ""))
+ctx = struct(platform = struct(os = "linux", arch = "x64", target = ""))
 url = download_url(ctx, "2.57.0")
 url == None
 "#,
@@ -126,30 +126,29 @@ url == None
 // ── install_layout logic ──────────────────────────────────────────────────────
 
 #[test]
-fn test_install_layout_is_archive() {
+fn test_install_layout_windows_is_archive() {
     let mut a = Assert::new();
     a.dialect(&Dialect::Standard);
     a.is_true(&format!(
         r#"
 {}
-ctx = struct(platform = struct(os = "linux", arch = "x64", target = ""))
+ctx = struct(platform = struct(os = "windows", arch = "x64", target = ""))
 layout = install_layout(ctx, "2.57.0")
-layout["type"] == "archive"
+layout["__type"] == "archive" and layout["executable_paths"] == ["bin/az.cmd"]
 "#,
         provider_star_prefix()
     ));
 }
 
 #[test]
-fn test_install_layout_has_az_executable() {
+fn test_install_layout_linux_is_none() {
     let mut a = Assert::new();
     a.dialect(&Dialect::Standard);
     a.is_true(&format!(
         r#"
 {}
 ctx = struct(platform = struct(os = "linux", arch = "x64", target = ""))
-layout = install_layout(ctx, "2.57.0")
-any(["az" in p for p in layout["executable_paths"]])
+install_layout(ctx, "2.57.0") == None
 "#,
         provider_star_prefix()
     ));
@@ -0,0 +1,104 @@
+//! Pure Starlark logic tests for binaryen provider.star
+
+use starlark::assert::Assert;
+use starlark::syntax::Dialect;
+use vx_starlark::test_mocks::setup_provider_test_mocks;
+
+fn make_assert() -> Assert<'static> {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    setup_provider_test_mocks(&mut a);
+    a.module("provider.star", vx_provider_binaryen::PROVIDER_STAR);
+    a
+}
+
+fn provider_star_prefix() -> String {
+    use vx_starlark::test_mocks::prepare_provider_source;
+    prepare_provider_source(vx_provider_binaryen::PROVIDER_STAR)
+}
+
+/// Evaluate `expr` against provider.star with a mock `ctx` for `os`/`arch`
+fn check(os: &str, arch: &str, expr: &str) {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "{}", arch = "{}", target = ""), install_dir = "/opt/binaryen", vx_home = "/home/user/.vx")
+{}
+"#,
+        provider_star_prefix(),
+        os,
+        arch,
+        expr
+    ));
+}
+
+// ── provider metadata ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_name_is_binaryen() {
+    make_assert().eq(r#"load("provider.star", "name"); name"#, r#""binaryen""#);
+}
+
+// ── download_url logic ────────────────────────────────────────────────────────
+
+#[test]
+fn test_download_url_linux_x64() {
+    check(
+        "linux",
+        "x64",
+        r#"download_url(ctx, "123") == "https://github.com/WebAssembly/binaryen/releases/download/version_123/binaryen-version_123-x86_64-linux.tar.gz""#,
+    );
+}
+
+#[test]
+fn test_download_url_macos_arm64_and_windows() {
+    check(
+        "macos",
+        "arm64",
+        r#"download_url(ctx, "123").endswith("/binaryen-version_123-arm64-macos.tar.gz")"#,
+    );
+    check(
+        "windows",
+        "x64",
+        r#"download_url(ctx, "123").endswith("/binaryen-version_123-x86_64-windows.tar.gz")"#,
+    );
+}
+
+#[test]
+fn test_download_url_none_for_unsupported_platform() {
+    check("windows", "arm64", r#"download_url(ctx, "123") == None"#);
+}
+
+// ── install_layout + environment logic ────────────────────────────────────────
+
+#[test]
+fn test_install_layout_exposes_bin_tools() {
+    check(
+        "windows",
+        "x64",
+        r#"
+layout = install_layout(ctx, "123")
+layout["strip_prefix"] == "binaryen-version_123" and "bin/wasm-opt.exe" in layout["executable_paths"]
+"#,
+    );
+}
+
+#[test]
+fn test_environment_prepends_bin() {
+    check(
+        "linux",
+        "x64",
+        r#"environment(ctx, "123")[0]["value"] == "/opt/binaryen/bin""#,
+    );
+}
+
+// ── lint check ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_star_lint_clean() {
+    vx_starlark::provider_test_support::assert_provider_star_lint_clean(
+        vx_provider_binaryen::PROVIDER_STAR,
+    );
+}
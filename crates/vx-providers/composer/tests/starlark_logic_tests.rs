@@ -0,0 +1,99 @@
+//! Pure Starlark logic tests for composer provider.star
+
+use starlark::assert::Assert;
+use starlark::syntax::Dialect;
+use vx_starlark::test_mocks::setup_provider_test_mocks;
+
+fn make_assert() -> Assert<'static> {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    setup_provider_test_mocks(&mut a);
+    a.module("provider.star", vx_provider_composer::PROVIDER_STAR);
+    a
+}
+
+fn provider_star_prefix() -> String {
+    use vx_starlark::test_mocks::prepare_provider_source;
+    prepare_provider_source(vx_provider_composer::PROVIDER_STAR)
+}
+
+/// Evaluate `expr` against provider.star with a mock `ctx` for `os`
+fn check(os: &str, expr: &str) {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "{}", arch = "x64", target = ""), install_dir = "/opt/composer", vx_home = "/home/user/.vx")
+{}
+"#,
+        provider_star_prefix(),
+        os,
+        expr
+    ));
+}
+
+// ── provider metadata ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_name_is_composer() {
+    make_assert().eq(r#"load("provider.star", "name"); name"#, r#""composer""#);
+}
+
+// ── download_url logic ────────────────────────────────────────────────────────
+
+#[test]
+fn test_download_url_is_phar() {
+    check(
+        "linux",
+        r#"download_url(ctx, "2.8.1") == "https://getcomposer.org/download/2.8.1/composer.phar""#,
+    );
+}
+
+// ── install_layout + post_extract logic ───────────────────────────────────────
+
+#[test]
+fn test_install_layout_unix_installs_bin_composer() {
+    check(
+        "linux",
+        r#"install_layout(ctx, "2.8.1")["executable_paths"] == ["bin/composer"]"#,
+    );
+}
+
+#[test]
+fn test_windows_gets_bat_launcher() {
+    check(
+        "windows",
+        r#"
+actions = post_extract(ctx, "2.8.1", "/opt/composer")
+actions[0]["__type"] == "write_file" and actions[0]["path"] == "bin/composer.bat" and "composer.phar" in actions[0]["content"]
+"#,
+    );
+}
+
+#[test]
+fn test_get_execute_path_windows_is_bat() {
+    check(
+        "windows",
+        r#"get_execute_path(ctx, "2.8.1") == "/opt/composer/bin/composer.bat""#,
+    );
+}
+
+// ── deps logic ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_deps_require_php() {
+    check(
+        "linux",
+        r#"[d["runtime"] for d in deps(ctx, "2.8.1")] == ["php"]"#,
+    );
+}
+
+// ── lint check ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_star_lint_clean() {
+    vx_starlark::provider_test_support::assert_provider_star_lint_clean(
+        vx_provider_composer::PROVIDER_STAR,
+    );
+}
@@ -124,7 +124,7 @@ url = download_url(ctx, "8.0.0")
 }
 
 #[test]
-fn test_download_url_uses_microsoft_cdn() {
+fn test_download_url_uses_release_feed() {
     let mut a = Assert::new();
     a.dialect(&Dialect::Standard);
     a.is_true(&format!(
@@ -132,7 +132,7 @@ fn test_download_url_uses_microsoft_cdn() {
 {}
 ctx = struct(platform = struct(os = "linux", arch = "x64", target = ""))
 url = download_url(ctx, "8.0.0")
-"dotnetcli.azureedge.net" in url or "microsoft.com" in url or "dotnet.microsoft.com" in url or "download.visualstudio.microsoft.com" in url
+url == "https://builds.dotnet.microsoft.com/dotnet/Sdk/8.0.0/dotnet-sdk-8.0.0-linux-x64.tar.gz"
 "#,
         provider_star_prefix()
     ));
@@ -93,6 +93,22 @@ url = download_url(ctx, "7.0")
     ));
 }
 
+#[test]
+fn test_download_url_windows_arm64_is_native() {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "windows", arch = "arm64", target = ""))
+url = download_url(ctx, "7.0")
+layout = install_layout(ctx, "7.0")
+url.endswith("ffmpeg-7.0-winarm64-lgpl.zip") and layout["strip_prefix"] == "ffmpeg-n7.0-latest-winarm64-lgpl-7.0"
+"#,
+        provider_star_prefix()
+    ));
+}
+
 #[test]
 fn test_download_url_linux_x64_uses_johnvansickle() {
     let mut a = Assert::new();
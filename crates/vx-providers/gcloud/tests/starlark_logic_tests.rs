@@ -215,6 +215,39 @@ len(path_ops) > 0 and "/opt/gcloud/bin" in path_ops[0].get("value", "")
     ));
 }
 
+// ── post_extract logic ────────────────────────────────────────────────────────
+
+#[test]
+fn test_post_extract_runs_install_sh_quietly() {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "linux", arch = "x64", target = ""))
+actions = post_extract(ctx, "470.0.0", "/opt/gcloud")
+cmd = actions[0]
+cmd["executable"] == "/opt/gcloud/install.sh" and "--quiet" in cmd["args"] and cmd["working_dir"] == "/opt/gcloud"
+"#,
+        provider_star_prefix()
+    ));
+}
+
+#[test]
+fn test_post_extract_windows_runs_install_bat() {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "windows", arch = "x64", target = ""))
+actions = post_extract(ctx, "470.0.0", "C:/vx/gcloud")
+actions[0]["executable"] == "C:/vx/gcloud/install.bat"
+"#,
+        provider_star_prefix()
+    ));
+}
+
 // ── platform guard ────────────────────────────────────────────────────────────
 
 #[test]
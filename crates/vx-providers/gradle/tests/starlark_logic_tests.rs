@@ -0,0 +1,144 @@
+//! Pure Starlark logic tests for gradle provider.star
+
+use starlark::assert::Assert;
+use starlark::syntax::Dialect;
+use vx_starlark::test_mocks::setup_provider_test_mocks;
+
+fn make_assert() -> Assert<'static> {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    setup_provider_test_mocks(&mut a);
+    a.module("provider.star", vx_provider_gradle::PROVIDER_STAR);
+    a
+}
+
+fn provider_star_prefix() -> String {
+    use vx_starlark::test_mocks::prepare_provider_source;
+    prepare_provider_source(vx_provider_gradle::PROVIDER_STAR)
+}
+
+/// Evaluate `expr` against provider.star with a mock `ctx` for `os`
+fn check(os: &str, expr: &str) {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "{}", arch = "x64", target = ""), install_dir = "/opt/gradle", vx_home = "/home/user/.vx")
+{}
+"#,
+        provider_star_prefix(),
+        os,
+        expr
+    ));
+}
+
+// ── provider metadata ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_name_is_gradle() {
+    make_assert().eq(r#"load("provider.star", "name"); name"#, r#""gradle""#);
+}
+
+#[test]
+fn test_provider_ecosystem_is_java() {
+    make_assert().eq(
+        r#"load("provider.star", "ecosystem"); ecosystem"#,
+        r#""java""#,
+    );
+}
+
+#[test]
+fn test_provider_has_homepage() {
+    make_assert().is_true(r#"load("provider.star", "homepage"); homepage.startswith("https://")"#);
+}
+
+// ── runtimes metadata ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_runtimes_has_gradle() {
+    make_assert().is_true(
+        r#"
+load("provider.star", "runtimes")
+names = [r["name"] for r in runtimes]
+"gradle" in names
+"#,
+    );
+}
+
+// ── download_url logic ────────────────────────────────────────────────────────
+
+#[test]
+fn test_download_url_uses_services_gradle_org() {
+    check(
+        "linux",
+        r#"download_url(ctx, "8.10.2") == "https://services.gradle.org/distributions/gradle-8.10.2-bin.zip""#,
+    );
+}
+
+#[test]
+fn test_download_url_drops_zero_patch_version() {
+    check("linux", r#"download_url(ctx, "8.10.0").endswith("/gradle-8.10-bin.zip")"#);
+}
+
+#[test]
+fn test_download_url_is_zip_on_windows() {
+    check("windows", r#"download_url(ctx, "8.10.2").endswith("-bin.zip")"#);
+}
+
+// ── install_layout logic ──────────────────────────────────────────────────────
+
+#[test]
+fn test_install_layout_strips_distribution_dir() {
+    check(
+        "linux",
+        r#"
+install_layout(ctx, "8.10.2")["strip_prefix"] == "gradle-8.10.2" and install_layout(ctx, "8.10.0")["strip_prefix"] == "gradle-8.10"
+"#,
+    );
+}
+
+#[test]
+fn test_install_layout_windows_uses_bat_script() {
+    check(
+        "windows",
+        r#"install_layout(ctx, "8.10.2")["executable_paths"] == ["bin/gradle.bat"]"#,
+    );
+}
+
+// ── environment + deps logic ──────────────────────────────────────────────────
+
+#[test]
+fn test_environment_sets_gradle_home_and_path() {
+    check(
+        "linux",
+        r#"
+env = environment(ctx, "8.10.2")
+homes = [op for op in env if op.get("key") == "GRADLE_HOME"]
+paths = [op for op in env if op.get("key") == "PATH"]
+homes[0]["value"] == "/opt/gradle" and paths[0]["value"] == "/opt/gradle/bin"
+"#,
+    );
+}
+
+#[test]
+fn test_deps_require_java() {
+    check(
+        "linux",
+        r#"[d["runtime"] for d in deps(ctx, "8.10.2")] == ["java"]"#,
+    );
+}
+
+#[test]
+fn test_gradle_9_requires_java_17() {
+    check("linux", r#"deps(ctx, "9.0.0")[0]["version"] == ">=17""#);
+}
+
+// ── lint check ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_star_lint_clean() {
+    vx_starlark::provider_test_support::assert_provider_star_lint_clean(
+        vx_provider_gradle::PROVIDER_STAR,
+    );
+}
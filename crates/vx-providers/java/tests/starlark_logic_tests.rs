@@ -218,6 +218,40 @@ layout = install_layout(ctx, "21.0.3")
     ));
 }
 
+#[test]
+fn test_install_layout_strips_versioned_dir() {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.eq(
+        &format!(
+            r#"
+{}
+ctx = struct(platform = struct(os = "linux", arch = "x64", target = ""))
+install_layout(ctx, "21.0.3")["strip_prefix"]
+"#,
+            provider_star_prefix()
+        ),
+        r#""jdk-*""#,
+    );
+}
+
+#[test]
+fn test_install_layout_macos_strips_bundle_dirs() {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.eq(
+        &format!(
+            r#"
+{}
+ctx = struct(platform = struct(os = "macos", arch = "arm64", target = ""))
+install_layout(ctx, "21.0.3")["strip_prefix"]
+"#,
+            provider_star_prefix()
+        ),
+        r#""jdk-*/Contents/Home""#,
+    );
+}
+
 // ── environment logic ─────────────────────────────────────────────────────────
 
 #[test]
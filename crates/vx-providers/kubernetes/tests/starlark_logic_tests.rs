@@ -0,0 +1,43 @@
+//! Pure Starlark logic tests for kubernetes provider.star
+
+use starlark::assert::Assert;
+use starlark::syntax::Dialect;
+use vx_starlark::test_mocks::setup_provider_test_mocks;
+
+fn make_assert() -> Assert<'static> {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    setup_provider_test_mocks(&mut a);
+    a.module("provider.star", vx_provider_kubernetes::PROVIDER_STAR);
+    a
+}
+
+#[test]
+fn test_provider_name_is_kubernetes() {
+    make_assert().eq(r#"load("provider.star", "name"); name"#, r#""kubernetes""#);
+}
+
+#[test]
+fn test_tool_bundle_members() {
+    make_assert().eq(
+        r#"load("provider.star", "tool_bundle"); tool_bundle"#,
+        r#"["kubectl", "helm", "k9s", "kind", "kustomize"]"#,
+    );
+}
+
+#[test]
+fn test_download_url_is_none() {
+    make_assert().is_true(
+        r#"
+load("provider.star", "download_url")
+download_url(None, "1.0.0") == None
+"#,
+    );
+}
+
+#[test]
+fn test_provider_star_lint_clean() {
+    vx_starlark::provider_test_support::assert_provider_star_lint_clean(
+        vx_provider_kubernetes::PROVIDER_STAR,
+    );
+}
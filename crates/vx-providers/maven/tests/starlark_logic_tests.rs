@@ -0,0 +1,142 @@
+//! Pure Starlark logic tests for maven provider.star
+
+use starlark::assert::Assert;
+use starlark::syntax::Dialect;
+use vx_starlark::test_mocks::setup_provider_test_mocks;
+
+fn make_assert() -> Assert<'static> {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    setup_provider_test_mocks(&mut a);
+    a.module("provider.star", vx_provider_maven::PROVIDER_STAR);
+    a
+}
+
+fn provider_star_prefix() -> String {
+    use vx_starlark::test_mocks::prepare_provider_source;
+    prepare_provider_source(vx_provider_maven::PROVIDER_STAR)
+}
+
+/// Evaluate `expr` against provider.star with a mock `ctx` for `os`
+fn check(os: &str, expr: &str) {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "{}", arch = "x64", target = ""), install_dir = "/opt/maven", vx_home = "/home/user/.vx")
+{}
+"#,
+        provider_star_prefix(),
+        os,
+        expr
+    ));
+}
+
+// ── provider metadata ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_name_is_maven() {
+    make_assert().eq(r#"load("provider.star", "name"); name"#, r#""maven""#);
+}
+
+#[test]
+fn test_provider_ecosystem_is_java() {
+    make_assert().eq(
+        r#"load("provider.star", "ecosystem"); ecosystem"#,
+        r#""java""#,
+    );
+}
+
+#[test]
+fn test_provider_has_homepage() {
+    make_assert().is_true(r#"load("provider.star", "homepage"); homepage.startswith("https://")"#);
+}
+
+// ── runtimes metadata ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_maven_runs_mvn_with_alias() {
+    make_assert().is_true(
+        r#"
+load("provider.star", "runtimes")
+rt = [r for r in runtimes if r["name"] == "maven"][0]
+rt["executable"] == "mvn" and "mvn" in rt["aliases"]
+"#,
+    );
+}
+
+// ── download_url logic ────────────────────────────────────────────────────────
+
+#[test]
+fn test_download_url_linux_is_tar_gz_from_apache_archive() {
+    check(
+        "linux",
+        r#"download_url(ctx, "3.9.9") == "https://archive.apache.org/dist/maven/maven-3/3.9.9/binaries/apache-maven-3.9.9-bin.tar.gz""#,
+    );
+}
+
+#[test]
+fn test_download_url_windows_is_zip() {
+    check("windows", r#"download_url(ctx, "3.9.9").endswith("-bin.zip")"#);
+}
+
+#[test]
+fn test_download_url_uses_major_version_dir() {
+    check("linux", r#""/maven-4/4.0.0/" in download_url(ctx, "4.0.0")"#);
+}
+
+// ── install_layout logic ──────────────────────────────────────────────────────
+
+#[test]
+fn test_install_layout_strips_versioned_dir() {
+    check(
+        "linux",
+        r#"install_layout(ctx, "3.9.9")["strip_prefix"] == "apache-maven-3.9.9""#,
+    );
+}
+
+#[test]
+fn test_install_layout_windows_uses_cmd_script() {
+    check(
+        "windows",
+        r#"install_layout(ctx, "3.9.9")["executable_paths"] == ["bin/mvn.cmd"]"#,
+    );
+}
+
+// ── environment + deps logic ──────────────────────────────────────────────────
+
+#[test]
+fn test_environment_sets_maven_home_and_path() {
+    check(
+        "linux",
+        r#"
+env = environment(ctx, "3.9.9")
+homes = [op for op in env if op.get("key") == "MAVEN_HOME"]
+paths = [op for op in env if op.get("key") == "PATH"]
+homes[0]["value"] == "/opt/maven" and paths[0]["value"] == "/opt/maven/bin"
+"#,
+    );
+}
+
+#[test]
+fn test_deps_require_java() {
+    check(
+        "linux",
+        r#"[d["runtime"] for d in deps(ctx, "3.9.9")] == ["java"]"#,
+    );
+}
+
+#[test]
+fn test_maven_4_requires_java_17() {
+    check("linux", r#"deps(ctx, "4.0.0")[0]["version"] == ">=17""#);
+}
+
+// ── lint check ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_star_lint_clean() {
+    vx_starlark::provider_test_support::assert_provider_star_lint_clean(
+        vx_provider_maven::PROVIDER_STAR,
+    );
+}
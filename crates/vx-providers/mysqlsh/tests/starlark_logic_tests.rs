@@ -0,0 +1,87 @@
+//! Pure Starlark logic tests for mysqlsh provider.star
+
+use starlark::assert::Assert;
+use starlark::syntax::Dialect;
+use vx_starlark::test_mocks::setup_provider_test_mocks;
+
+fn make_assert() -> Assert<'static> {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    setup_provider_test_mocks(&mut a);
+    a.module("provider.star", vx_provider_mysqlsh::PROVIDER_STAR);
+    a
+}
+
+fn provider_star_prefix() -> String {
+    use vx_starlark::test_mocks::prepare_provider_source;
+    prepare_provider_source(vx_provider_mysqlsh::PROVIDER_STAR)
+}
+
+/// Evaluate `expr` against provider.star with a mock `ctx` for `os`/`arch`
+fn check(os: &str, arch: &str, expr: &str) {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "{}", arch = "{}", target = ""), install_dir = "/opt/mysqlsh", vx_home = "/home/user/.vx")
+{}
+"#,
+        provider_star_prefix(),
+        os,
+        arch,
+        expr
+    ));
+}
+
+// ── provider metadata ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_name_is_mysqlsh() {
+    make_assert().eq(r#"load("provider.star", "name"); name"#, r#""mysqlsh""#);
+}
+
+// ── download_url logic ────────────────────────────────────────────────────────
+
+#[test]
+fn test_download_url_linux_x64() {
+    check(
+        "linux",
+        "x64",
+        r#"download_url(ctx, "8.4.3") == "https://dev.mysql.com/get/Downloads/MySQL-Shell/mysql-shell-8.4.3-linux-glibc2.28-x86-64bit.tar.gz""#,
+    );
+}
+
+#[test]
+fn test_download_url_windows_is_zip() {
+    check(
+        "windows",
+        "x64",
+        r#"download_url(ctx, "9.1.0").endswith("/mysql-shell-9.1.0-windows-x86-64bit.zip")"#,
+    );
+}
+
+#[test]
+fn test_download_url_macos_uses_system_install() {
+    check("macos", "arm64", r#"download_url(ctx, "8.4.3") == None"#);
+}
+
+// ── install_layout logic ──────────────────────────────────────────────────────
+
+#[test]
+fn test_install_layout_strips_bundle_dir() {
+    check(
+        "linux",
+        "arm64",
+        r#"install_layout(ctx, "8.4.3")["strip_prefix"] == "mysql-shell-8.4.3-linux-glibc2.28-arm-64bit""#,
+    );
+}
+
+// ── lint check ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_star_lint_clean() {
+    vx_starlark::provider_test_support::assert_provider_star_lint_clean(
+        vx_provider_mysqlsh::PROVIDER_STAR,
+    );
+}
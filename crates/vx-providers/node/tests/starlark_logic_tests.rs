@@ -199,6 +199,22 @@ url == None
     ));
 }
 
+#[test]
+fn test_download_url_linux_musl_uses_unofficial_builds() {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "linux", arch = "x64", target = "", libc = "musl"))
+url = download_url(ctx, "20.0.0")
+layout = install_layout(ctx, "20.0.0")
+url == "https://unofficial-builds.nodejs.org/download/release/v20.0.0/node-v20.0.0-linux-x64-musl.tar.xz" and layout["strip_prefix"] == "node-v20.0.0-linux-x64-musl"
+"#,
+        provider_star_prefix()
+    ));
+}
+
 // ── install_layout logic ──────────────────────────────────────────────────────
 
 #[test]
@@ -0,0 +1,157 @@
+//! Pure Starlark logic tests for php provider.star
+
+use starlark::assert::Assert;
+use starlark::syntax::Dialect;
+use vx_starlark::test_mocks::setup_provider_test_mocks;
+
+fn make_assert() -> Assert<'static> {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    setup_provider_test_mocks(&mut a);
+    a.module("provider.star", vx_provider_php::PROVIDER_STAR);
+    a
+}
+
+fn provider_star_prefix() -> String {
+    use vx_starlark::test_mocks::prepare_provider_source;
+    prepare_provider_source(vx_provider_php::PROVIDER_STAR)
+}
+
+/// Evaluate `expr` against provider.star with a mock `ctx` for `os`/`arch`
+fn check(os: &str, arch: &str, expr: &str) {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "{}", arch = "{}", target = ""), install_dir = "/opt/php", vx_home = "/home/user/.vx")
+{}
+"#,
+        provider_star_prefix(),
+        os,
+        arch,
+        expr
+    ));
+}
+
+// ── provider metadata ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_name_is_php() {
+    make_assert().eq(r#"load("provider.star", "name"); name"#, r#""php""#);
+}
+
+#[test]
+fn test_provider_ecosystem_is_php() {
+    make_assert().eq(
+        r#"load("provider.star", "ecosystem"); ecosystem"#,
+        r#""php""#,
+    );
+}
+
+// ── download_url logic ────────────────────────────────────────────────────────
+
+#[test]
+fn test_download_url_windows_is_official_nts_build() {
+    check(
+        "windows",
+        "x64",
+        r#"download_url(ctx, "8.3.12") == "https://windows.php.net/downloads/releases/php-8.3.12-nts-Win32-vs16-x64.zip""#,
+    );
+}
+
+#[test]
+fn test_download_url_windows_php_84_uses_vs17() {
+    check(
+        "windows",
+        "x64",
+        r#"download_url(ctx, "8.4.1").endswith("-nts-Win32-vs17-x64.zip")"#,
+    );
+}
+
+#[test]
+fn test_download_url_linux_is_static_build() {
+    check(
+        "linux",
+        "x64",
+        r#"download_url(ctx, "8.3.12") == "https://dl.static-php.dev/static-php-cli/common/php-8.3.12-cli-linux-x86_64.tar.gz""#,
+    );
+}
+
+#[test]
+fn test_download_url_macos_arm64() {
+    check(
+        "macos",
+        "arm64",
+        r#"download_url(ctx, "8.3.12").endswith("-cli-macos-aarch64.tar.gz")"#,
+    );
+}
+
+#[test]
+fn test_download_url_windows_arm64_unsupported() {
+    check("windows", "arm64", r#"download_url(ctx, "8.3.12") == None"#);
+}
+
+// ── install_layout + post_extract logic ───────────────────────────────────────
+
+#[test]
+fn test_install_layout_has_php_at_root() {
+    check(
+        "windows",
+        "x64",
+        r#"install_layout(ctx, "8.3.12")["executable_paths"] == ["php.exe"]"#,
+    );
+}
+
+#[test]
+fn test_php_ini_wires_extension_dir() {
+    check(
+        "linux",
+        "x64",
+        r#"'extension_dir = "/opt/php/ext"' in php_ini(ctx, "8.3.12", "/opt/php")"#,
+    );
+}
+
+#[test]
+fn test_php_ini_enables_windows_extensions() {
+    check(
+        "windows",
+        "x64",
+        r#""extension=openssl" in php_ini(ctx, "8.3.12", "C:/vx/php")"#,
+    );
+}
+
+#[test]
+fn test_post_extract_writes_php_ini() {
+    check(
+        "linux",
+        "x64",
+        r#"
+actions = post_extract(ctx, "8.3.12", "/opt/php")
+[a["path"] for a in actions if a["__type"] == "write_file"] == ["php.ini"]
+"#,
+    );
+}
+
+// ── environment logic ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_environment_points_phprc_at_install_dir() {
+    check(
+        "linux",
+        "x64",
+        r#"
+env = environment(ctx, "8.3.12")
+[op["value"] for op in env if op.get("key") == "PHPRC"] == ["/opt/php"]
+"#,
+    );
+}
+
+// ── lint check ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_star_lint_clean() {
+    vx_starlark::provider_test_support::assert_provider_star_lint_clean(
+        vx_provider_php::PROVIDER_STAR,
+    );
+}
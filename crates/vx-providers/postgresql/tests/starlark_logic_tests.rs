@@ -0,0 +1,100 @@
+//! Pure Starlark logic tests for postgresql provider.star
+
+use starlark::assert::Assert;
+use starlark::syntax::Dialect;
+use vx_starlark::test_mocks::setup_provider_test_mocks;
+
+fn make_assert() -> Assert<'static> {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    setup_provider_test_mocks(&mut a);
+    a.module("provider.star", vx_provider_postgresql::PROVIDER_STAR);
+    a
+}
+
+fn provider_star_prefix() -> String {
+    use vx_starlark::test_mocks::prepare_provider_source;
+    prepare_provider_source(vx_provider_postgresql::PROVIDER_STAR)
+}
+
+/// Evaluate `expr` against provider.star with a mock `ctx` for `os`/`arch`
+fn check(os: &str, arch: &str, expr: &str) {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "{}", arch = "{}", target = ""), install_dir = "/opt/postgresql", vx_home = "/home/user/.vx")
+{}
+"#,
+        provider_star_prefix(),
+        os,
+        arch,
+        expr
+    ));
+}
+
+// ── provider metadata ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_name_is_postgresql() {
+    make_assert().eq(r#"load("provider.star", "name"); name"#, r#""postgresql""#);
+}
+
+// ── download_url logic ────────────────────────────────────────────────────────
+
+#[test]
+fn test_download_url_linux_x64() {
+    check(
+        "linux",
+        "x64",
+        r#"download_url(ctx, "17.2.0") == "https://github.com/theseus-rs/postgresql-binaries/releases/download/17.2.0/postgresql-17.2.0-x86_64-unknown-linux-gnu.tar.gz""#,
+    );
+}
+
+#[test]
+fn test_download_url_windows_is_zip() {
+    check(
+        "windows",
+        "x64",
+        r#"download_url(ctx, "17.2.0").endswith("/postgresql-17.2.0-x86_64-pc-windows-msvc.zip")"#,
+    );
+}
+
+#[test]
+fn test_download_url_macos_arm64() {
+    check(
+        "macos",
+        "arm64",
+        r#"download_url(ctx, "16.6.0").endswith("/postgresql-16.6.0-aarch64-apple-darwin.tar.gz")"#,
+    );
+}
+
+// ── install_layout logic ──────────────────────────────────────────────────────
+
+#[test]
+fn test_install_layout_strips_bundle_dir() {
+    check(
+        "linux",
+        "arm64",
+        r#"install_layout(ctx, "17.2.0")["strip_prefix"] == "postgresql-17.2.0-aarch64-unknown-linux-gnu""#,
+    );
+}
+
+#[test]
+fn test_execute_path_is_psql_in_bin() {
+    check(
+        "windows",
+        "x64",
+        r#"get_execute_path(ctx, "17.2.0") == "/opt/postgresql/bin/psql.exe""#,
+    );
+}
+
+// ── lint check ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_star_lint_clean() {
+    vx_starlark::provider_test_support::assert_provider_star_lint_clean(
+        vx_provider_postgresql::PROVIDER_STAR,
+    );
+}
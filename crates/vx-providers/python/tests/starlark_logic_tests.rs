@@ -353,6 +353,21 @@ url != None and "3.13.4" in url
     ));
 }
 
+#[test]
+fn test_download_url_windows_arm64_is_native() {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "windows", arch = "arm64", target = ""), version_date = "20250610")
+url = download_url(ctx, "3.13.4")
+url != None and "aarch64-pc-windows-msvc" in url
+"#,
+        provider_star_prefix()
+    ));
+}
+
 #[test]
 fn test_download_url_macos_arm64_contains_aarch64() {
     let mut a = Assert::new();
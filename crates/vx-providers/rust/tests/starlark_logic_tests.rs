@@ -152,6 +152,21 @@ url != None and url.endswith(".exe")
     ));
 }
 
+#[test]
+fn test_download_url_windows_arm64_is_native() {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "windows", arch = "arm64", target = ""))
+url = download_url(ctx, "1.76.0")
+url != None and "aarch64-pc-windows-msvc" in url and url.endswith(".exe")
+"#,
+        provider_star_prefix()
+    ));
+}
+
 #[test]
 fn test_download_url_macos_arm64_returns_url() {
     let mut a = Assert::new();
@@ -0,0 +1,129 @@
+//! Pure Starlark logic tests for sqlite provider.star
+
+use starlark::assert::Assert;
+use starlark::syntax::Dialect;
+use vx_starlark::test_mocks::setup_provider_test_mocks;
+
+fn make_assert() -> Assert<'static> {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    setup_provider_test_mocks(&mut a);
+    a.module("provider.star", vx_provider_sqlite::PROVIDER_STAR);
+    a
+}
+
+fn provider_star_prefix() -> String {
+    use vx_starlark::test_mocks::prepare_provider_source;
+    prepare_provider_source(vx_provider_sqlite::PROVIDER_STAR)
+}
+
+/// Evaluate `expr` against provider.star with a mock `ctx` for `os`/`arch`
+/// and the release date `version_date`
+fn check_dated(os: &str, arch: &str, version_date: &str, expr: &str) {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "{}", arch = "{}", target = ""), install_dir = "/opt/sqlite", vx_home = "/home/user/.vx", version_date = "{}")
+{}
+"#,
+        provider_star_prefix(),
+        os,
+        arch,
+        version_date,
+        expr
+    ));
+}
+
+/// Evaluate `expr` against provider.star with a mock `ctx` without a release date
+fn check(os: &str, arch: &str, expr: &str) {
+    check_dated(os, arch, "", expr);
+}
+
+// ── provider metadata ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_name_is_sqlite() {
+    make_assert().eq(r#"load("provider.star", "name"); name"#, r#""sqlite""#);
+}
+
+// ── download_url logic ────────────────────────────────────────────────────────
+
+#[test]
+fn test_version_number_encoding() {
+    check(
+        "linux",
+        "x64",
+        r#"sqlite_version_number("3.46.1") == "3460100" and sqlite_version_number("3.50.10") == "3501000""#,
+    );
+}
+
+#[test]
+fn test_download_url_linux_x64_uses_release_year() {
+    check(
+        "linux",
+        "x64",
+        r#"download_url(ctx, "3.46.1") == "https://www.sqlite.org/2024/sqlite-tools-linux-x64-3460100.zip""#,
+    );
+}
+
+#[test]
+fn test_download_url_year_from_release_date() {
+    check_dated(
+        "linux",
+        "x64",
+        "2027-01-09",
+        r#"download_url(ctx, "3.53.0") == "https://www.sqlite.org/2027/sqlite-tools-linux-x64-3530000.zip""#,
+    );
+    // The release date wins over the fallback table
+    check_dated(
+        "macos",
+        "x64",
+        "2025-01-02",
+        r#"download_url(ctx, "3.47.2").endswith("/2025/sqlite-tools-osx-x64-3470200.zip")"#,
+    );
+}
+
+#[test]
+fn test_download_url_none_for_undated_unknown_release() {
+    check("linux", "x64", r#"download_url(ctx, "3.53.0") == None"#);
+}
+
+#[test]
+fn test_download_url_windows_x64() {
+    check(
+        "windows",
+        "x64",
+        r#"download_url(ctx, "3.50.4").endswith("/2025/sqlite-tools-win-x64-3500400.zip")"#,
+    );
+}
+
+#[test]
+fn test_download_url_none_without_official_build() {
+    check("linux", "arm64", r#"download_url(ctx, "3.46.1") == None"#);
+    check("linux", "x64", r#"download_url(ctx, "3.40.0") == None"#);
+}
+
+// ── install_layout logic ──────────────────────────────────────────────────────
+
+#[test]
+fn test_install_layout_is_flat_archive() {
+    check(
+        "windows",
+        "x64",
+        r#"
+layout = install_layout(ctx, "3.46.1")
+layout["strip_prefix"] == "" and "sqlite3.exe" in layout["executable_paths"]
+"#,
+    );
+}
+
+// ── lint check ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_star_lint_clean() {
+    vx_starlark::provider_test_support::assert_provider_star_lint_clean(
+        vx_provider_sqlite::PROVIDER_STAR,
+    );
+}
@@ -157,7 +157,7 @@ fn test_download_url_arch_before_os_in_asset_name() {
         r#"
 {}
 ctx = struct(platform = struct(os = "linux", arch = "x64", target = ""))
-url = download_url(ctx, "0.13.0")
+url = download_url(ctx, "0.14.1")
 "x86_64-linux" in url
 "#,
         provider_star_prefix()
@@ -179,6 +179,21 @@ url = download_url(ctx, "0.13.0")
     ));
 }
 
+#[test]
+fn test_download_url_dev_build_uses_builds_dir() {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "linux", arch = "x64", target = ""))
+url = download_url(ctx, "0.15.0-dev.847+850655f06")
+url == "https://ziglang.org/builds/zig-x86_64-linux-0.15.0-dev.847+850655f06.tar.xz"
+"#,
+        provider_star_prefix()
+    ));
+}
+
 // ── install_layout logic ──────────────────────────────────────────────────────
 
 #[test]
@@ -211,6 +226,22 @@ layout = install_layout(ctx, "0.13.0")
     ));
 }
 
+#[test]
+fn test_install_layout_strip_prefix_matches_old_asset_name() {
+    // Before 0.14.1 the archive dir is zig-{os}-{arch}-{version}
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "linux", arch = "x64", target = ""))
+layout = install_layout(ctx, "0.13.0")
+layout["strip_prefix"] == "zig-linux-x86_64-0.13.0"
+"#,
+        provider_star_prefix()
+    ));
+}
+
 #[test]
 fn test_install_layout_has_zig_executable() {
     let mut a = Assert::new();
@@ -0,0 +1,116 @@
+//! Pure Starlark logic tests for zls provider.star
+
+use starlark::assert::Assert;
+use starlark::syntax::Dialect;
+use vx_starlark::test_mocks::setup_provider_test_mocks;
+
+fn make_assert() -> Assert<'static> {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    setup_provider_test_mocks(&mut a);
+    a.module("provider.star", vx_provider_zls::PROVIDER_STAR);
+    a
+}
+
+fn provider_star_prefix() -> String {
+    use vx_starlark::test_mocks::prepare_provider_source;
+    prepare_provider_source(vx_provider_zls::PROVIDER_STAR)
+}
+
+/// Evaluate `expr` against provider.star with a mock `ctx` for `os`/`arch`
+fn check(os: &str, arch: &str, expr: &str) {
+    let mut a = Assert::new();
+    a.dialect(&Dialect::Standard);
+    a.is_true(&format!(
+        r#"
+{}
+ctx = struct(platform = struct(os = "{}", arch = "{}", target = ""), install_dir = "/opt/zls", vx_home = "/home/user/.vx")
+{}
+"#,
+        provider_star_prefix(),
+        os,
+        arch,
+        expr
+    ));
+}
+
+// ── provider metadata ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_name_is_zls() {
+    make_assert().eq(r#"load("provider.star", "name"); name"#, r#""zls""#);
+}
+
+#[test]
+fn test_provider_ecosystem_is_zig() {
+    make_assert().eq(
+        r#"load("provider.star", "ecosystem"); ecosystem"#,
+        r#""zig""#,
+    );
+}
+
+// ── download_url logic ────────────────────────────────────────────────────────
+
+#[test]
+fn test_download_url_linux_x64_is_tar_xz() {
+    check(
+        "linux",
+        "x64",
+        r#"download_url(ctx, "0.14.0") == "https://github.com/zigtools/zls/releases/download/0.14.0/zls-x86_64-linux.tar.xz""#,
+    );
+}
+
+#[test]
+fn test_download_url_windows_is_zip() {
+    check(
+        "windows",
+        "x64",
+        r#"download_url(ctx, "0.14.0").endswith("/zls-x86_64-windows.zip")"#,
+    );
+}
+
+#[test]
+fn test_download_url_macos_arm64() {
+    check(
+        "macos",
+        "arm64",
+        r#"download_url(ctx, "0.14.0").endswith("/zls-aarch64-macos.tar.xz")"#,
+    );
+}
+
+// ── install_layout logic ──────────────────────────────────────────────────────
+
+#[test]
+fn test_install_layout_is_flat_archive() {
+    check(
+        "linux",
+        "x64",
+        r#"
+layout = install_layout(ctx, "0.14.0")
+layout["type"] == "archive" and layout["strip_prefix"] == ""
+"#,
+    );
+}
+
+// ── deps logic ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_deps_recommend_matching_zig() {
+    check(
+        "linux",
+        "x64",
+        r#"
+d = deps(ctx, "0.14.0")[0]
+d["runtime"] == "zig" and d["version"] == "~0.14" and d["optional"]
+"#,
+    );
+}
+
+// ── lint check ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_provider_star_lint_clean() {
+    vx_starlark::provider_test_support::assert_provider_star_lint_clean(
+        vx_provider_zls::PROVIDER_STAR,
+    );
+}
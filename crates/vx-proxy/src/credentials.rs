@@ -0,0 +1,479 @@
+//! Credential storage and lookup for vx network clients
+//!
+//! `vx auth login` stores tokens in the OS keyring (macOS Keychain, Windows
+//! Credential Manager, or the Secret Service via `secret-tool` on Linux).
+//! When no keyring is available the secret is written to
+//! `~/.vx/config/credentials.toml` (mode 0600) instead. That file also
+//! indexes every stored credential, so `vx auth status` can list them:
+//!
+//! ```toml
+//! [github]
+//! storage = "keyring"
+//!
+//! ["registry.corp.example"]
+//! username = "alice"
+//! storage = "file"
+//! secret = "..."
+//! ```
+//!
+//! Version fetchers and downloaders call [`authorize`] so requests to GitHub
+//! and to registries with stored credentials are authenticated without the
+//! token ever appearing on a command line. `GITHUB_TOKEN` and `GH_TOKEN`
+//! still take precedence for GitHub.
+
+use anyhow::{Context, Result};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+/// Credential index file name inside the vx config directory
+pub const CREDENTIALS_FILE: &str = "credentials.toml";
+
+/// Set to `file` to skip the OS keyring (headless machines, CI)
+pub const CREDENTIAL_STORE_ENV: &str = "VX_CREDENTIAL_STORE";
+
+/// Entry name for the GitHub token
+pub const GITHUB: &str = "github";
+
+/// Path of the Homebrew bottle repositories on ghcr.io
+const HOMEBREW_BOTTLES_PATH: &str = "/v2/homebrew/";
+
+/// Token ghcr.io accepts for anonymous pulls of public packages
+const ANONYMOUS_GHCR_TOKEN: &str = "QQ==";
+
+/// Service name used for keyring entries
+const KEYRING_SERVICE: &str = "vx";
+
+/// Token file written by older vx versions
+const LEGACY_GITHUB_TOKEN_FILE: &str = "github_token";
+
+/// Where a secret is kept
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Storage {
+    /// OS keyring
+    Keyring,
+    /// `~/.vx/config/credentials.toml`
+    File,
+}
+
+impl std::fmt::Display for Storage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Storage::Keyring => write!(f, "OS keyring"),
+            Storage::File => write!(f, "credentials file"),
+        }
+    }
+}
+
+/// A secret ready to be attached to requests
+#[derive(Clone, PartialEq, Eq)]
+pub struct Credential {
+    /// Username for basic auth; bearer auth when absent
+    pub username: Option<String>,
+    /// Token or password
+    pub secret: String,
+}
+
+impl std::fmt::Debug for Credential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Credential")
+            .field("username", &self.username)
+            .field("secret", &"********")
+            .finish()
+    }
+}
+
+impl Credential {
+    /// Bearer credential
+    pub fn token(secret: impl Into<String>) -> Self {
+        Self {
+            username: None,
+            secret: secret.into(),
+        }
+    }
+
+    /// Attach this credential to a request
+    pub fn apply(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.username {
+            Some(username) => request.basic_auth(username, Some(&self.secret)),
+            None => request.bearer_auth(&self.secret),
+        }
+    }
+}
+
+/// A stored credential as listed by [`CredentialStore::entries`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CredentialEntry {
+    /// `github` or a registry host
+    pub name: String,
+    /// Username for basic auth
+    pub username: Option<String>,
+    /// Where the secret is kept
+    pub storage: Storage,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredCredential {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+    storage: Storage,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    secret: Option<String>,
+}
+
+/// Keyring-backed credential store with a file fallback
+#[derive(Debug, Clone)]
+pub struct CredentialStore {
+    path: PathBuf,
+    use_keyring: bool,
+}
+
+impl CredentialStore {
+    /// The user's store in `~/.vx/config`
+    ///
+    /// The keyring is skipped when `VX_CREDENTIAL_STORE=file`.
+    pub fn open() -> Result<Self> {
+        let paths = vx_paths::VxPaths::new()?;
+        let use_keyring = !std::env::var(CREDENTIAL_STORE_ENV)
+            .is_ok_and(|v| v.trim().eq_ignore_ascii_case("file"));
+        Ok(Self::with_path(
+            paths.config_dir.join(CREDENTIALS_FILE),
+            use_keyring,
+        ))
+    }
+
+    /// A store using `path` as its index
+    pub fn with_path(path: impl Into<PathBuf>, use_keyring: bool) -> Self {
+        Self {
+            path: path.into(),
+            use_keyring,
+        }
+    }
+
+    /// Index file location
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Store a secret, preferring the keyring
+    ///
+    /// Returns where the secret ended up.
+    pub fn save(&self, name: &str, username: Option<&str>, secret: &str) -> Result<Storage> {
+        let mut index = self.read_index()?;
+        let storage = if self.use_keyring {
+            match os_keyring::set(name, secret) {
+                Ok(()) => Storage::Keyring,
+                Err(e) => {
+                    tracing::warn!(error = %e, "OS keyring unavailable, storing credential in file");
+                    Storage::File
+                }
+            }
+        } else {
+            Storage::File
+        };
+
+        index.insert(
+            name.to_string(),
+            StoredCredential {
+                username: username.map(str::to_string),
+                storage,
+                secret: (storage == Storage::File).then(|| secret.to_string()),
+            },
+        );
+        self.write_index(&index)?;
+        forget(name);
+        Ok(storage)
+    }
+
+    /// Look up a stored secret
+    pub fn get(&self, name: &str) -> Result<Option<Credential>> {
+        let index = self.read_index()?;
+        let Some(stored) = index.get(name) else {
+            return Ok(None);
+        };
+        let secret = match stored.storage {
+            Storage::File => stored.secret.clone(),
+            Storage::Keyring => os_keyring::get(name)
+                .with_context(|| format!("Failed to read '{}' from the OS keyring", name))?,
+        };
+        Ok(secret.filter(|s| !s.is_empty()).map(|secret| Credential {
+            username: stored.username.clone(),
+            secret,
+        }))
+    }
+
+    /// Remove a stored secret; returns whether one existed
+    pub fn remove(&self, name: &str) -> Result<bool> {
+        let mut index = self.read_index()?;
+        let Some(stored) = index.remove(name) else {
+            return Ok(false);
+        };
+        if stored.storage == Storage::Keyring {
+            os_keyring::delete(name)
+                .with_context(|| format!("Failed to remove '{}' from the OS keyring", name))?;
+        }
+        self.write_index(&index)?;
+        forget(name);
+        Ok(true)
+    }
+
+    /// All stored credentials, without their secrets
+    pub fn entries(&self) -> Result<Vec<CredentialEntry>> {
+        Ok(self
+            .read_index()?
+            .into_iter()
+            .map(|(name, stored)| CredentialEntry {
+                name,
+                username: stored.username,
+                storage: stored.storage,
+            })
+            .collect())
+    }
+
+    fn read_index(&self) -> Result<BTreeMap<String, StoredCredential>> {
+        if !self.path.exists() {
+            return Ok(BTreeMap::new());
+        }
+        let content = std::fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read {}", self.path.display()))?;
+        toml::from_str(&content).with_context(|| format!("Failed to parse {}", self.path.display()))
+    }
+
+    fn write_index(&self, index: &BTreeMap<String, StoredCredential>) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        std::fs::write(&self.path, toml::to_string(index)?)
+            .with_context(|| format!("Failed to write {}", self.path.display()))?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&self.path, std::fs::Permissions::from_mode(0o600))?;
+        }
+        Ok(())
+    }
+}
+
+/// Entry name for a registry: its `host[:port]`, lowercased
+///
+/// Accepts a URL (`https://registry.corp.example/v2/`) or a bare host.
+pub fn registry_name(url: &str) -> Result<String> {
+    let url = url.trim();
+    let with_scheme = if url.contains("://") {
+        url.to_string()
+    } else {
+        format!("https://{}", url)
+    };
+    let parsed =
+        reqwest::Url::parse(&with_scheme).with_context(|| format!("Invalid URL '{}'", url))?;
+    let host = parsed
+        .host_str()
+        .filter(|h| !h.is_empty())
+        .with_context(|| format!("URL '{}' has no host", url))?
+        .to_lowercase();
+    Ok(match parsed.port() {
+        Some(port) => format!("{}:{}", host, port),
+        None => host,
+    })
+}
+
+/// Whether `host` is served by GitHub and accepts the GitHub token
+pub fn is_github_host(host: &str) -> bool {
+    host == "github.com" || host.ends_with(".github.com")
+}
+
+/// GitHub token: `GITHUB_TOKEN`, `GH_TOKEN`, the credential store, then the
+/// legacy `~/.vx/config/github_token` file
+pub fn github_token() -> Option<String> {
+    for var in ["GITHUB_TOKEN", "GH_TOKEN"] {
+        if let Some(token) = std::env::var(var).ok().filter(|t| !t.is_empty()) {
+            return Some(token);
+        }
+    }
+    if let Some(credential) = stored(GITHUB) {
+        return Some(credential.secret);
+    }
+    legacy_github_token()
+}
+
+/// Token written by `vx auth login` before the credential store existed
+pub fn legacy_github_token() -> Option<String> {
+    let path = vx_paths::VxPaths::new()
+        .ok()?
+        .config_dir
+        .join(LEGACY_GITHUB_TOKEN_FILE);
+    let token = std::fs::read_to_string(path).ok()?;
+    let token = token.trim();
+    (!token.is_empty()).then(|| token.to_string())
+}
+
+/// Remove the legacy `~/.vx/config/github_token` file, if present
+pub fn remove_legacy_github_token() -> Result<bool> {
+    let path = vx_paths::VxPaths::new()?
+        .config_dir
+        .join(LEGACY_GITHUB_TOKEN_FILE);
+    if !path.exists() {
+        return Ok(false);
+    }
+    std::fs::remove_file(&path).with_context(|| format!("Failed to remove {}", path.display()))?;
+    Ok(true)
+}
+
+/// Stored credential for a registry entry name (see [`registry_name`])
+pub fn registry_credential(name: &str) -> Option<Credential> {
+    stored(name)
+}
+
+/// Credential to send with a request to `url`, if any
+pub fn lookup(url: &str) -> Option<Credential> {
+    let parsed = reqwest::Url::parse(url).ok()?;
+    let host = parsed.host_str()?.to_lowercase();
+    if is_github_host(&host) {
+        return github_token().map(Credential::token);
+    }
+    registry_name(url)
+        .ok()
+        .and_then(|name| registry_credential(&name))
+        .or_else(|| {
+            // Homebrew bottles are public but ghcr.io still wants a bearer token
+            (host == "ghcr.io" && parsed.path().starts_with(HOMEBREW_BOTTLES_PATH))
+                .then(|| Credential::token(ANONYMOUS_GHCR_TOKEN))
+        })
+}
+
+/// Attach the stored credential for `url` to `request`
+pub fn authorize(request: RequestBuilder, url: &str) -> RequestBuilder {
+    match lookup(url) {
+        Some(credential) => credential.apply(request),
+        None => request,
+    }
+}
+
+/// Process-wide cache so keyring lookups happen once per entry
+fn cache() -> &'static Mutex<HashMap<String, Option<Credential>>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, Option<Credential>>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn stored(name: &str) -> Option<Credential> {
+    if let Some(hit) = cache().lock().ok()?.get(name) {
+        return hit.clone();
+    }
+    let credential = CredentialStore::open()
+        .and_then(|store| store.get(name))
+        .unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "Failed to read stored credential");
+            None
+        });
+    if let Ok(mut cache) = cache().lock() {
+        cache.insert(name.to_string(), credential.clone());
+    }
+    credential
+}
+
+fn forget(name: &str) {
+    if let Ok(mut cache) = cache().lock() {
+        cache.remove(name);
+    }
+}
+
+/// Native keyring backends
+#[cfg(any(target_os = "macos", windows))]
+mod os_keyring {
+    use super::KEYRING_SERVICE;
+    use anyhow::Result;
+
+    pub fn set(account: &str, secret: &str) -> Result<()> {
+        keyring::Entry::new(KEYRING_SERVICE, account)?.set_password(secret)?;
+        Ok(())
+    }
+
+    pub fn get(account: &str) -> Result<Option<String>> {
+        match keyring::Entry::new(KEYRING_SERVICE, account)?.get_password() {
+            Ok(secret) => Ok(Some(secret)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn delete(account: &str) -> Result<()> {
+        match keyring::Entry::new(KEYRING_SERVICE, account)?.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Secret Service backend through libsecret's `secret-tool`
+#[cfg(all(unix, not(target_os = "macos")))]
+mod os_keyring {
+    use super::KEYRING_SERVICE;
+    use anyhow::{Context, Result};
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    fn secret_tool(args: &[&str], account: &str) -> Command {
+        let mut cmd = Command::new("secret-tool");
+        cmd.args(args)
+            .args(["service", KEYRING_SERVICE, "account", account])
+            .stderr(Stdio::null());
+        cmd
+    }
+
+    pub fn set(account: &str, secret: &str) -> Result<()> {
+        let label = format!("vx: {}", account);
+        let mut child = secret_tool(&["store", "--label", &label], account)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()
+            .context("secret-tool is not available")?;
+        child
+            .stdin
+            .take()
+            .context("Failed to open secret-tool stdin")?
+            .write_all(secret.as_bytes())?;
+        let status = child.wait()?;
+        if !status.success() {
+            anyhow::bail!("secret-tool store exited with {}", status);
+        }
+        Ok(())
+    }
+
+    pub fn get(account: &str) -> Result<Option<String>> {
+        let output = secret_tool(&["lookup"], account)
+            .output()
+            .context("secret-tool is not available")?;
+        // `lookup` exits non-zero when nothing matches
+        let secret = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok((output.status.success() && !secret.is_empty()).then_some(secret))
+    }
+
+    pub fn delete(account: &str) -> Result<()> {
+        secret_tool(&["clear"], account)
+            .status()
+            .context("secret-tool is not available")?;
+        Ok(())
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod os_keyring {
+    use anyhow::Result;
+
+    pub fn set(_account: &str, _secret: &str) -> Result<()> {
+        anyhow::bail!("No OS keyring on this platform")
+    }
+
+    pub fn get(_account: &str) -> Result<Option<String>> {
+        anyhow::bail!("No OS keyring on this platform")
+    }
+
+    pub fn delete(_account: &str) -> Result<()> {
+        anyhow::bail!("No OS keyring on this platform")
+    }
+}
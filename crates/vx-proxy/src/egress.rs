@@ -0,0 +1,472 @@
+//! Restricted network egress for child processes
+//!
+//! [`EgressProxy`] is a local HTTP proxy that only lets connections through
+//! to an allowlist of domains. vx starts one for project scripts and tool
+//! runs when `vx.toml` opts in, and points the child at it through
+//! `HTTP_PROXY`/`HTTPS_PROXY` (see [`EgressProxy::env_vars`]). Everything
+//! else gets `403 Forbidden` and is recorded, so unexpected destinations of
+//! a dependency's install script show up instead of silently succeeding.
+//!
+//! ```toml
+//! # vx.toml
+//! [security.egress]
+//! allow = ["registry.npmjs.org", "*.github.com", "pypi.org", "files.pythonhosted.org"]
+//! ```
+//!
+//! Programs that ignore proxy variables are not confined; pair the policy
+//! with a firewall rule for that ([`EgressProxy::firewall_hints`]).
+//!
+//! HTTPS goes through `CONNECT` tunnels and is never decrypted, so only the
+//! host and port are checked. When the user has an `http://` proxy configured
+//! (see [`crate::ProxyConfig`]), allowed connections are chained through it.
+
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Time allowed for a client to send its request headers
+const HEADER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Domains a child process may connect to
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EgressPolicy {
+    allow: Vec<String>,
+    log_file: Option<PathBuf>,
+    upstream: Option<String>,
+}
+
+impl EgressPolicy {
+    /// Allow connections to `allow`
+    ///
+    /// Entries are host names (`pypi.org`), wildcards for subdomains
+    /// (`*.github.com`, which does not match `github.com` itself), IP
+    /// addresses, or `*` for everything. Matching ignores case.
+    pub fn new<I, S>(allow: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            allow: allow
+                .into_iter()
+                .map(|entry| normalize_host(&entry.into()))
+                .filter(|entry| !entry.is_empty())
+                .collect(),
+            log_file: None,
+            upstream: None,
+        }
+    }
+
+    /// Append every blocked destination to `path`
+    pub fn with_log_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.log_file = Some(path.into());
+        self
+    }
+
+    /// Chain allowed connections through an `http://host:port` proxy
+    pub fn with_upstream(mut self, proxy_url: impl Into<String>) -> Self {
+        self.upstream = Some(proxy_url.into());
+        self
+    }
+
+    /// Allowlist entries, normalized
+    pub fn allowed(&self) -> &[String] {
+        &self.allow
+    }
+
+    /// Whether connections to `host` are allowed
+    pub fn allows(&self, host: &str) -> bool {
+        let host = normalize_host(host);
+        self.allow.iter().any(|entry| {
+            if entry == "*" || *entry == host {
+                return true;
+            }
+            match entry.strip_prefix("*.") {
+                Some(domain) => host
+                    .strip_suffix(domain)
+                    .is_some_and(|prefix| prefix.ends_with('.')),
+                None => false,
+            }
+        })
+    }
+}
+
+/// Lowercase, without brackets or a trailing dot
+fn normalize_host(host: &str) -> String {
+    host.trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .trim_end_matches('.')
+        .to_ascii_lowercase()
+}
+
+/// A running egress proxy on a loopback port
+///
+/// The proxy stops accepting connections when dropped; tunnels that are
+/// already open run until either side closes them.
+#[derive(Debug)]
+pub struct EgressProxy {
+    addr: SocketAddr,
+    blocked: Arc<Mutex<BTreeMap<String, usize>>>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl EgressProxy {
+    /// Start a proxy enforcing `policy` on an ephemeral loopback port
+    pub fn start(policy: EgressPolicy) -> io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+        let addr = listener.local_addr()?;
+        let upstream = match &policy.upstream {
+            Some(url) => Some(parse_upstream(url).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("unsupported upstream proxy for egress policy: {}", url),
+                )
+            })?),
+            None => None,
+        };
+
+        let shared = Arc::new(Shared {
+            policy,
+            upstream,
+            blocked: Arc::new(Mutex::new(BTreeMap::new())),
+        });
+        let stopped = Arc::new(AtomicBool::new(false));
+        let proxy = Self {
+            addr,
+            blocked: shared.blocked.clone(),
+            stopped: stopped.clone(),
+        };
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stopped.load(Ordering::SeqCst) {
+                    break;
+                }
+                let Ok(stream) = stream else { continue };
+                let shared = shared.clone();
+                // Errors only affect one connection, e.g. a client going away
+                std::thread::spawn(move || {
+                    let _ = shared.handle(stream);
+                });
+            }
+        });
+        Ok(proxy)
+    }
+
+    /// Address the proxy listens on
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Proxy URL, e.g. `http://127.0.0.1:54321`
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Environment variables that route a child process through the proxy
+    ///
+    /// Covers the conventional upper- and lowercase variables, npm's own
+    /// settings and vx's, so nested `vx` invocations are confined too.
+    /// Loopback addresses stay direct.
+    pub fn env_vars(&self) -> Vec<(String, String)> {
+        let url = self.url();
+        let no_proxy = "localhost,127.0.0.1,::1".to_string();
+        let mut vars: Vec<(String, String)> = [
+            "HTTP_PROXY",
+            "HTTPS_PROXY",
+            "ALL_PROXY",
+            "http_proxy",
+            "https_proxy",
+            "all_proxy",
+            "npm_config_proxy",
+            "npm_config_https_proxy",
+            "VX_HTTP_PROXY",
+            "VX_HTTPS_PROXY",
+            "VX_ALL_PROXY",
+        ]
+        .into_iter()
+        .map(|name| (name.to_string(), url.clone()))
+        .collect();
+        for name in ["NO_PROXY", "no_proxy", "VX_NO_PROXY"] {
+            vars.push((name.to_string(), no_proxy.clone()));
+        }
+        vars
+    }
+
+    /// Destinations (`host:port`) refused so far, with how often
+    pub fn blocked(&self) -> Vec<(String, usize)> {
+        self.blocked
+            .lock()
+            .map(|blocked| blocked.iter().map(|(k, v)| (k.clone(), *v)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Suggestions for enforcing the policy at the OS level
+    ///
+    /// The proxy only confines programs that honor proxy variables. Running
+    /// builds as a dedicated user whose direct outbound traffic the firewall
+    /// rejects closes that gap.
+    pub fn firewall_hints(&self) -> Vec<String> {
+        let port = self.addr.port();
+        if cfg!(windows) {
+            vec![
+                "Block direct outbound traffic of the tool, e.g.:".to_string(),
+                "  New-NetFirewallRule -DisplayName \"vx egress\" -Direction Outbound -Program <path-to-tool.exe> -Action Block".to_string(),
+                format!("The proxy itself listens on 127.0.0.1:{} and needs no rule.", port),
+            ]
+        } else if cfg!(target_os = "macos") {
+            vec![
+                "Run builds as a dedicated user and let pf reject its direct traffic, e.g. in /etc/pf.conf:".to_string(),
+                "  pass out quick on lo0 all".to_string(),
+                "  block drop out quick proto { tcp, udp } user <build-user>".to_string(),
+                format!("The proxy itself listens on 127.0.0.1:{}.", port),
+            ]
+        } else {
+            vec![
+                "Run builds as a dedicated user and reject its direct traffic, e.g.:".to_string(),
+                "  sudo iptables -A OUTPUT -o lo -m owner --uid-owner <build-user> -j ACCEPT"
+                    .to_string(),
+                "  sudo iptables -A OUTPUT -m owner --uid-owner <build-user> -j REJECT".to_string(),
+                format!("The proxy itself listens on 127.0.0.1:{}.", port),
+            ]
+        }
+    }
+}
+
+impl Drop for EgressProxy {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // Wake the accept loop so it sees the flag
+        let _ = TcpStream::connect_timeout(&self.addr, Duration::from_millis(200));
+    }
+}
+
+/// State shared by all connections of one proxy
+struct Shared {
+    policy: EgressPolicy,
+    upstream: Option<(String, u16)>,
+    blocked: Arc<Mutex<BTreeMap<String, usize>>>,
+}
+
+impl Shared {
+    fn handle(&self, client: TcpStream) -> io::Result<()> {
+        client.set_read_timeout(Some(HEADER_TIMEOUT))?;
+        let mut reader = BufReader::new(client.try_clone()?);
+        let mut writer = client;
+
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        let mut headers = Vec::new();
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line)? == 0 || line == "\r\n" || line == "\n" {
+                break;
+            }
+            headers.push(line);
+        }
+
+        let mut parts = request_line.split_whitespace();
+        let (method, target, version) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(method), Some(target), Some(version)) => (method, target, version),
+            _ => return respond(&mut writer, "400 Bad Request", "Malformed request\n"),
+        };
+
+        let connect = method.eq_ignore_ascii_case("CONNECT");
+        let destination = if connect {
+            split_host_port(target, 443)
+        } else {
+            absolute_target(target)
+        };
+        let Some((host, port, path)) = destination else {
+            return respond(
+                &mut writer,
+                "400 Bad Request",
+                "The vx egress proxy only accepts proxy requests\n",
+            );
+        };
+
+        if !self.policy.allows(&host) {
+            self.record_blocked(&host, port);
+            let body = format!(
+                "Connection to {}:{} blocked by the vx egress policy\n",
+                host, port
+            );
+            return respond(&mut writer, "403 Forbidden", &body);
+        }
+
+        let mut server = match self.connect(&host, port) {
+            Ok(server) => server,
+            Err(e) => {
+                let body = format!("Failed to connect to {}:{}: {}\n", host, port, e);
+                return respond(&mut writer, "502 Bad Gateway", &body);
+            }
+        };
+
+        if connect {
+            writer.write_all(b"HTTP/1.1 200 Connection Established\r\n\r\n")?;
+        } else {
+            // Forward the request in origin form (upstream proxies are tunneled
+            // through as well); one request per connection keeps framing simple
+            let mut head = format!("{} {} {}\r\n", method, path, version);
+            for header in &headers {
+                let name = header.split(':').next().unwrap_or_default().trim();
+                if ["proxy-connection", "proxy-authorization", "connection"]
+                    .iter()
+                    .any(|h| name.eq_ignore_ascii_case(h))
+                {
+                    continue;
+                }
+                head.push_str(header);
+            }
+            head.push_str("Connection: close\r\n\r\n");
+            server.write_all(head.as_bytes())?;
+        }
+
+        // Bytes the client sent after the headers (e.g. TLS ClientHello or a body)
+        let buffered = reader.buffer().to_vec();
+        server.write_all(&buffered)?;
+        drop(reader);
+
+        writer.set_read_timeout(None)?;
+        tunnel(writer, server)
+    }
+
+    /// Open a connection to `host:port`, directly or through the upstream proxy
+    fn connect(&self, host: &str, port: u16) -> io::Result<TcpStream> {
+        let Some((proxy_host, proxy_port)) = &self.upstream else {
+            return TcpStream::connect((host, port));
+        };
+        let mut stream = TcpStream::connect((proxy_host.as_str(), *proxy_port))?;
+        let authority = if host.contains(':') {
+            format!("[{}]:{}", host, port)
+        } else {
+            format!("{}:{}", host, port)
+        };
+        write!(
+            stream,
+            "CONNECT {0} HTTP/1.1\r\nHost: {0}\r\n\r\n",
+            authority
+        )?;
+
+        // Read the upstream response headers byte by byte so no tunnel data is lost
+        stream.set_read_timeout(Some(HEADER_TIMEOUT))?;
+        let mut head = Vec::new();
+        let mut byte = [0u8; 1];
+        while !head.ends_with(b"\r\n\r\n") {
+            if stream.read(&mut byte)? == 0 || head.len() > 16 * 1024 {
+                return Err(io::Error::other("upstream proxy closed the connection"));
+            }
+            head.push(byte[0]);
+        }
+        stream.set_read_timeout(None)?;
+        let status = String::from_utf8_lossy(&head);
+        let code = status.split_whitespace().nth(1).unwrap_or_default();
+        if code != "200" {
+            return Err(io::Error::other(format!(
+                "upstream proxy answered {}",
+                status.lines().next().unwrap_or_default()
+            )));
+        }
+        Ok(stream)
+    }
+
+    fn record_blocked(&self, host: &str, port: u16) {
+        let destination = format!("{}:{}", host, port);
+        tracing::warn!(destination = %destination, "Blocked by egress policy");
+        if let Ok(mut blocked) = self.blocked.lock() {
+            *blocked.entry(destination.clone()).or_default() += 1;
+        }
+        if let Some(path) = &self.policy.log_file {
+            if let Some(parent) = path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            if let Ok(mut file) = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+            {
+                let _ = writeln!(
+                    file,
+                    "{} blocked {}",
+                    chrono::Utc::now().to_rfc3339(),
+                    destination
+                );
+            }
+        }
+    }
+}
+
+fn respond(stream: &mut TcpStream, status: &str, body: &str) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+/// Copy bytes both ways until both sides are done
+fn tunnel(client: TcpStream, server: TcpStream) -> io::Result<()> {
+    let mut client_read = client.try_clone()?;
+    let mut server_write = server.try_clone()?;
+    let upload = std::thread::spawn(move || {
+        let _ = io::copy(&mut client_read, &mut server_write);
+        let _ = server_write.shutdown(Shutdown::Write);
+    });
+
+    let (mut server_read, mut client_write) = (server, client);
+    let _ = io::copy(&mut server_read, &mut client_write);
+    let _ = client_write.shutdown(Shutdown::Write);
+    let _ = upload.join();
+    Ok(())
+}
+
+/// `host:port` (or `[v6]:port`) with a default port
+fn split_host_port(authority: &str, default_port: u16) -> Option<(String, u16, String)> {
+    let (host, port) = if let Some(rest) = authority.strip_prefix('[') {
+        let (host, rest) = rest.split_once(']')?;
+        let port = match rest.strip_prefix(':') {
+            Some(port) => port.parse().ok()?,
+            None => default_port,
+        };
+        (host.to_string(), port)
+    } else {
+        match authority.rsplit_once(':') {
+            Some((host, port)) => (host.to_string(), port.parse().ok()?),
+            None => (authority.to_string(), default_port),
+        }
+    };
+    (!host.is_empty()).then(|| (normalize_host(&host), port, String::new()))
+}
+
+/// Host, port and origin-form path of an absolute `http://` request target
+fn absolute_target(target: &str) -> Option<(String, u16, String)> {
+    let rest = target
+        .strip_prefix("http://")
+        .or_else(|| target.strip_prefix("HTTP://"))?;
+    let (authority, path) = match rest.find('/') {
+        Some(index) => (&rest[..index], &rest[index..]),
+        None => (rest, "/"),
+    };
+    let authority = authority.rsplit('@').next()?;
+    let (host, port, _) = split_host_port(authority, 80)?;
+    Some((host, port, path.to_string()))
+}
+
+/// Host and port of an `http://` proxy URL without credentials
+fn parse_upstream(url: &str) -> Option<(String, u16)> {
+    let rest = url.strip_prefix("http://")?;
+    let authority = rest.split('/').next()?;
+    if authority.contains('@') {
+        return None;
+    }
+    let (host, port, _) = split_host_port(authority, 80)?;
+    Some((host, port))
+}
@@ -0,0 +1,285 @@
+//! Shared HTTP response cache for vx network clients
+//!
+//! [`send`] answers `GET` requests from the on-disk [`HttpCache`] in
+//! `~/.vx/cache/http`, honouring `Cache-Control` and revalidating stale
+//! entries with `ETag` / `Last-Modified`. When the network is unreachable a
+//! stale entry is served instead of failing.
+//!
+//! Set `VX_HTTP_CACHE=off` to bypass the cache. While a record/replay
+//! cassette is active (`VX_RECORD` / `VX_REPLAY`) the cache is bypassed too,
+//! and [`send`] records every response or answers from the cassette.
+
+use reqwest::header::{
+    CACHE_CONTROL, CONTENT_TYPE, ETAG, HeaderMap, HeaderValue, IF_MODIFIED_SINCE, IF_NONE_MATCH,
+    LAST_MODIFIED,
+};
+use reqwest::{Method, RequestBuilder, StatusCode};
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+use vx_cache::cassette::{self, CassetteMode, HttpInteraction};
+use vx_cache::{HttpCache, HttpCacheEntry, HttpCacheHeaders, HttpCacheLookup, HttpCacheOutcome};
+
+/// Set to `off` (or `0`) to bypass the HTTP cache
+pub const HTTP_CACHE_ENV: &str = "VX_HTTP_CACHE";
+
+/// A fully read response, from the network or the cache
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Vec<u8>,
+    from_cache: bool,
+}
+
+impl CachedResponse {
+    fn cached(entry: &HttpCacheEntry, body: Vec<u8>) -> Self {
+        let mut headers = HeaderMap::new();
+        let entries = [
+            (CONTENT_TYPE, &entry.content_type),
+            (ETAG, &entry.etag),
+            (LAST_MODIFIED, &entry.last_modified),
+        ];
+        for (name, value) in entries {
+            if let Some(value) = value.as_deref().and_then(|v| HeaderValue::from_str(v).ok()) {
+                headers.insert(name, value);
+            }
+        }
+        Self {
+            status: StatusCode::OK,
+            headers,
+            body,
+            from_cache: true,
+        }
+    }
+
+    fn replayed(interaction: &HttpInteraction, body: Vec<u8>) -> Self {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &interaction.headers {
+            if let (Ok(name), Ok(value)) = (
+                reqwest::header::HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                headers.insert(name, value);
+            }
+        }
+        Self {
+            status: StatusCode::from_u16(interaction.status).unwrap_or(StatusCode::OK),
+            headers,
+            body,
+            from_cache: true,
+        }
+    }
+
+    /// Stand-in for a request the replayed cassette has no response for
+    fn not_recorded(url: &str, cassette: &std::path::Path) -> Self {
+        Self {
+            status: StatusCode::NOT_FOUND,
+            headers: HeaderMap::new(),
+            body: format!("{} is not recorded in cassette {}", url, cassette.display())
+                .into_bytes(),
+            from_cache: true,
+        }
+    }
+
+    /// HTTP status (`200 OK` for cached responses)
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Response headers
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Response body
+    pub fn bytes(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// Response body as text (invalid UTF-8 is replaced)
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Whether the body came from the cache
+    pub fn from_cache(&self) -> bool {
+        self.from_cache
+    }
+}
+
+/// The user's HTTP cache, unless disabled with `VX_HTTP_CACHE=off`
+pub fn shared() -> Option<&'static HttpCache> {
+    static CACHE: OnceLock<Option<HttpCache>> = OnceLock::new();
+    CACHE
+        .get_or_init(|| {
+            let disabled = std::env::var(HTTP_CACHE_ENV)
+                .is_ok_and(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "off" | "0"));
+            if disabled || cassette::active().is_some() {
+                return None;
+            }
+            vx_paths::VxPaths::new()
+                .ok()
+                .map(|paths| HttpCache::new(paths.cache_dir))
+        })
+        .as_ref()
+}
+
+/// Send a request through the shared cache and read the whole body
+///
+/// Only `GET` requests are cached; anything else goes straight to the
+/// network. A replayed request the cassette has no response for gets a
+/// `404 Not Found` saying so.
+pub async fn send(request: RequestBuilder) -> reqwest::Result<CachedResponse> {
+    let (client, request) = request.build_split();
+    let request = request?;
+
+    let Some(cassette) = cassette::active() else {
+        return send_request(client, request).await;
+    };
+    let method = request.method().to_string();
+    let url = request.url().to_string();
+    match cassette.mode() {
+        CassetteMode::Replay => {
+            let replayed = cassette
+                .replay_http(&method, &url)
+                .and_then(|i| i.body().ok().map(|body| CachedResponse::replayed(&i, body)));
+            Ok(replayed.unwrap_or_else(|| CachedResponse::not_recorded(&url, cassette.path())))
+        }
+        CassetteMode::Record => {
+            let response = send_request(client, request).await?;
+            let interaction = HttpInteraction::new(
+                method,
+                url,
+                response.status.as_u16(),
+                recorded_headers(&response.headers),
+                &response.body,
+            );
+            if let Err(e) = cassette.record_http(interaction) {
+                tracing::warn!(error = %e, "Failed to record HTTP response");
+            }
+            Ok(response)
+        }
+    }
+}
+
+async fn send_request(
+    client: reqwest::Client,
+    mut request: reqwest::Request,
+) -> reqwest::Result<CachedResponse> {
+    let cache = match shared() {
+        Some(cache) if request.method() == Method::GET => cache,
+        _ => {
+            let response = client.execute(request).await?;
+            return read(response).await;
+        }
+    };
+
+    let url = request.url().to_string();
+    let stale = match cache.lookup(&url) {
+        HttpCacheLookup::Fresh { entry, body } => {
+            tracing::debug!(%url, "HTTP cache hit");
+            cache.record(HttpCacheOutcome::Hit);
+            return Ok(CachedResponse::cached(&entry, body));
+        }
+        HttpCacheLookup::Stale { entry, body } => {
+            let headers = request.headers_mut();
+            if let Some(etag) = entry
+                .etag
+                .as_deref()
+                .and_then(|v| HeaderValue::from_str(v).ok())
+            {
+                headers.entry(IF_NONE_MATCH).or_insert(etag);
+            }
+            if let Some(modified) = entry
+                .last_modified
+                .as_deref()
+                .and_then(|v| HeaderValue::from_str(v).ok())
+            {
+                headers.entry(IF_MODIFIED_SINCE).or_insert(modified);
+            }
+            Some((entry, body))
+        }
+        HttpCacheLookup::Miss => None,
+    };
+
+    let response = match client.execute(request).await {
+        Ok(response) => response,
+        Err(e) => match stale {
+            Some((entry, body)) if e.is_connect() || e.is_timeout() => {
+                tracing::debug!(%url, error = %e, "Network unavailable, using stale HTTP cache entry");
+                cache.record(HttpCacheOutcome::StaleFallback);
+                return Ok(CachedResponse::cached(&entry, body));
+            }
+            _ => return Err(e),
+        },
+    };
+
+    let cache_headers = cache_headers(response.headers());
+    if response.status() == StatusCode::NOT_MODIFIED
+        && let Some((entry, body)) = stale
+    {
+        tracing::debug!(%url, "HTTP cache revalidated");
+        let body = match cache.revalidate(&url, &cache_headers) {
+            Ok(Some(body)) => body,
+            Ok(None) => body,
+            Err(e) => {
+                tracing::debug!(%url, error = %e, "Failed to update HTTP cache entry");
+                body
+            }
+        };
+        cache.record(HttpCacheOutcome::Revalidated);
+        return Ok(CachedResponse::cached(&entry, body));
+    }
+
+    let response = read(response).await?;
+    if response.status == StatusCode::OK
+        && let Err(e) = cache.store(&url, &response.body, &cache_headers)
+    {
+        tracing::debug!(%url, error = %e, "Failed to store HTTP cache entry");
+    }
+    cache.record(HttpCacheOutcome::Miss);
+    Ok(response)
+}
+
+async fn read(response: reqwest::Response) -> reqwest::Result<CachedResponse> {
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = response.bytes().await?.to_vec();
+    Ok(CachedResponse {
+        status,
+        headers,
+        body,
+        from_cache: false,
+    })
+}
+
+/// The response headers vx reads, for a cassette
+pub fn recorded_headers(headers: &HeaderMap) -> BTreeMap<String, String> {
+    [
+        CONTENT_TYPE,
+        ETAG,
+        LAST_MODIFIED,
+        reqwest::header::CONTENT_DISPOSITION,
+    ]
+    .into_iter()
+    .filter_map(|name| {
+        let value = headers.get(&name)?.to_str().ok()?;
+        Some((name.to_string(), value.to_string()))
+    })
+    .collect()
+}
+
+fn cache_headers(headers: &HeaderMap) -> HttpCacheHeaders {
+    let get = |name| {
+        headers
+            .get(name)
+            .and_then(|v: &HeaderValue| v.to_str().ok())
+            .map(str::to_string)
+    };
+    HttpCacheHeaders {
+        cache_control: get(CACHE_CONTROL),
+        etag: get(ETAG),
+        last_modified: get(LAST_MODIFIED),
+        content_type: get(CONTENT_TYPE),
+    }
+}
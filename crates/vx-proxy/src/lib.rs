@@ -0,0 +1,220 @@
+//! Unified proxy configuration for vx network clients
+//!
+//! Every HTTP client vx builds (runtime downloads, version fetchers, the
+//! installer's downloader, self-update and auth) goes through
+//! [`client_builder`], so one proxy setting applies everywhere. Connection
+//! settings (IP family, connect timeout, static hosts) live in [`network`],
+//! LAN peer cache settings in [`peers`], the shared bucket cache in
+//! [`remote_cache`]. [`egress`] confines child processes to allowed domains.
+//!
+//! # Sources
+//!
+//! Each setting is taken from the first source that defines it:
+//!
+//! 1. `VX_HTTP_PROXY`, `VX_HTTPS_PROXY`, `VX_ALL_PROXY`, `VX_NO_PROXY`,
+//!    `VX_PROXY_USERNAME`, `VX_PROXY_PASSWORD`
+//! 2. `~/.vx/config/proxy.toml`
+//! 3. `HTTP_PROXY`, `HTTPS_PROXY`, `ALL_PROXY`, `NO_PROXY` (or lowercase)
+//!
+//! ```toml
+//! # ~/.vx/config/proxy.toml
+//! https = "http://proxy.corp.example:8080"
+//! http = "http://proxy.corp.example:8080"
+//! # Fallback for any scheme; socks5:// and socks5h:// are supported
+//! all = "socks5h://127.0.0.1:1080"
+//! # Hosts reached directly: domains (and subdomains), IPs, CIDR ranges, or "*"
+//! no_proxy = ["localhost", "127.0.0.1", ".corp.example", "10.0.0.0/8"]
+//! # Credentials for proxies whose URL has none
+//! username = "alice"
+//! password = "secret"
+//! ```
+
+pub mod credentials;
+pub mod egress;
+pub mod http_cache;
+pub mod network;
+pub mod peers;
+pub mod remote_cache;
+
+pub use egress::{EgressPolicy, EgressProxy};
+pub use network::{IpFamily, NETWORK_CONFIG_FILE, NetworkConfig};
+pub use peers::{PEER_CACHE_ENV, PEERS_CONFIG_FILE, PeerConfig};
+pub use remote_cache::{
+    REMOTE_CACHE_CONFIG_FILE, REMOTE_CACHE_ENV, RemoteCacheConfig, RemoteStore,
+};
+
+use anyhow::{Context, Result};
+use reqwest::{ClientBuilder, NoProxy, Proxy};
+use serde::Deserialize;
+use std::path::Path;
+
+/// Proxy configuration file name inside the vx config directory
+pub const PROXY_CONFIG_FILE: &str = "proxy.toml";
+
+/// Proxy settings for vx network clients
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ProxyConfig {
+    /// Proxy for `http://` URLs
+    #[serde(default)]
+    pub http: Option<String>,
+
+    /// Proxy for `https://` URLs
+    #[serde(default)]
+    pub https: Option<String>,
+
+    /// Proxy for any URL not covered by `http`/`https`
+    #[serde(default)]
+    pub all: Option<String>,
+
+    /// Hosts that bypass the proxy
+    #[serde(default)]
+    pub no_proxy: Vec<String>,
+
+    /// Proxy username (used when the proxy URL carries no credentials)
+    #[serde(default)]
+    pub username: Option<String>,
+
+    /// Proxy password
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+impl ProxyConfig {
+    /// Load proxy settings from the environment and `~/.vx/config/proxy.toml`
+    ///
+    /// An unreadable or invalid config file is logged and ignored.
+    pub fn load() -> Self {
+        let file = vx_paths::VxPaths::new()
+            .ok()
+            .map(|paths| paths.config_dir.join(PROXY_CONFIG_FILE))
+            .filter(|path| path.exists())
+            .and_then(|path| match Self::from_file(&path) {
+                Ok(config) => Some(config),
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), error = %e, "Ignoring invalid proxy config");
+                    None
+                }
+            });
+        Self::resolve(file, |name| std::env::var(name).ok())
+    }
+
+    /// Load proxy settings from a config file only
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Parse proxy settings from TOML content
+    pub fn parse(content: &str) -> Result<Self> {
+        Ok(toml::from_str(content)?)
+    }
+
+    /// Merge a config file with environment variables read through `env`
+    pub fn resolve(file: Option<Self>, env: impl Fn(&str) -> Option<String>) -> Self {
+        let env = |name: &str| env(name).filter(|v| !v.trim().is_empty());
+        let standard = |name: &str| env(name).or_else(|| env(&name.to_lowercase()));
+        let file = file.unwrap_or_default();
+
+        let pick = |vx_var: &str, from_file: Option<String>, std_var: Option<&str>| {
+            env(vx_var)
+                .or(from_file.filter(|v| !v.trim().is_empty()))
+                .or_else(|| std_var.and_then(standard))
+        };
+
+        let no_proxy = match env("VX_NO_PROXY") {
+            Some(list) => split_list(&list),
+            None if !file.no_proxy.is_empty() => file.no_proxy,
+            None => standard("NO_PROXY")
+                .map(|list| split_list(&list))
+                .unwrap_or_default(),
+        };
+
+        Self {
+            http: pick("VX_HTTP_PROXY", file.http, Some("HTTP_PROXY")),
+            https: pick("VX_HTTPS_PROXY", file.https, Some("HTTPS_PROXY")),
+            all: pick("VX_ALL_PROXY", file.all, Some("ALL_PROXY")),
+            no_proxy,
+            username: pick("VX_PROXY_USERNAME", file.username, None),
+            password: pick("VX_PROXY_PASSWORD", file.password, None),
+        }
+    }
+
+    /// Whether any proxy is configured
+    pub fn is_empty(&self) -> bool {
+        self.http.is_none() && self.https.is_none() && self.all.is_none()
+    }
+
+    /// Configure `builder` to use these proxies
+    ///
+    /// When no proxy is configured the builder is returned unchanged.
+    pub fn apply(&self, mut builder: ClientBuilder) -> Result<ClientBuilder> {
+        if self.is_empty() {
+            return Ok(builder);
+        }
+
+        // Scheme-specific proxies first so they win over `all`
+        let entries = [
+            (self.http.as_deref(), "http"),
+            (self.https.as_deref(), "https"),
+            (self.all.as_deref(), "all"),
+        ];
+        for (url, scheme) in entries {
+            let Some(url) = url else { continue };
+            let proxy = match scheme {
+                "http" => Proxy::http(url),
+                "https" => Proxy::https(url),
+                _ => Proxy::all(url),
+            };
+            let mut proxy = proxy.with_context(|| format!("Invalid proxy URL: {}", url))?;
+            if let Some(username) = &self.username
+                && !has_credentials(url)
+            {
+                proxy = proxy.basic_auth(username, self.password.as_deref().unwrap_or(""));
+            }
+            proxy = proxy.no_proxy(self.no_proxy_rules());
+            builder = builder.proxy(proxy);
+        }
+        Ok(builder)
+    }
+
+    fn no_proxy_rules(&self) -> Option<NoProxy> {
+        if self.no_proxy.is_empty() {
+            None
+        } else {
+            NoProxy::from_string(&self.no_proxy.join(","))
+        }
+    }
+}
+
+/// A `reqwest` client builder with the user's proxy and network settings applied
+///
+/// Invalid proxy settings are logged and the builder falls back to a direct
+/// connection, so the error surfaces as a network failure rather than a panic.
+pub fn client_builder() -> ClientBuilder {
+    let network = NetworkConfig::load();
+    let base = || network.apply(reqwest::Client::builder());
+    match ProxyConfig::load().apply(base()) {
+        Ok(builder) => builder,
+        Err(e) => {
+            tracing::warn!(error = %e, "Ignoring invalid proxy configuration");
+            base()
+        }
+    }
+}
+
+/// Split a comma-separated host list
+fn split_list(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Whether a proxy URL embeds `user[:password]@`
+fn has_credentials(url: &str) -> bool {
+    let authority = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+    let authority = authority.split('/').next().unwrap_or(authority);
+    authority.contains('@')
+}
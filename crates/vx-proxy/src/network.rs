@@ -0,0 +1,173 @@
+//! Connection settings for vx network clients
+//!
+//! vx clients race IPv6 and IPv4 connections (happy eyeballs) by default, so a
+//! broken IPv6 route only costs a short delay. Networks where that still
+//! stalls can force one address family, shorten the connect timeout, or pin
+//! host names to addresses when DNS itself is unreliable.
+//!
+//! Each setting is taken from `VX_IP_FAMILY` / `VX_CONNECT_TIMEOUT` first,
+//! then `~/.vx/config/network.toml`:
+//!
+//! ```toml
+//! # ~/.vx/config/network.toml
+//! ip_family = "ipv4"      # auto (default), ipv4 or ipv6
+//! connect_timeout = 10    # seconds
+//!
+//! # Static DNS overrides
+//! [hosts]
+//! "github.com" = "140.82.112.3"
+//! ```
+
+use anyhow::{Context, Result};
+use reqwest::ClientBuilder;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Network configuration file name inside the vx config directory
+pub const NETWORK_CONFIG_FILE: &str = "network.toml";
+
+/// Address family used for outgoing connections
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IpFamily {
+    /// Race IPv6 and IPv4 (happy eyeballs)
+    #[default]
+    Auto,
+    /// Only connect over IPv4
+    Ipv4,
+    /// Only connect over IPv6
+    Ipv6,
+}
+
+impl IpFamily {
+    /// Config value for this family
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Ipv4 => "ipv4",
+            Self::Ipv6 => "ipv6",
+        }
+    }
+}
+
+impl FromStr for IpFamily {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "auto" | "" => Ok(Self::Auto),
+            "ipv4" | "v4" | "4" => Ok(Self::Ipv4),
+            "ipv6" | "v6" | "6" => Ok(Self::Ipv6),
+            other => anyhow::bail!(
+                "Invalid IP family '{}' (expected auto, ipv4 or ipv6)",
+                other
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for IpFamily {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Connection settings for vx network clients
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct NetworkConfig {
+    /// Address family preference
+    #[serde(default)]
+    pub ip_family: IpFamily,
+
+    /// Connect timeout in seconds
+    #[serde(default)]
+    pub connect_timeout: Option<u64>,
+
+    /// Host names resolved to fixed addresses instead of DNS
+    #[serde(default)]
+    pub hosts: BTreeMap<String, IpAddr>,
+}
+
+impl NetworkConfig {
+    /// Load network settings from the environment and `~/.vx/config/network.toml`
+    ///
+    /// An unreadable or invalid config file is logged and ignored.
+    pub fn load() -> Self {
+        let file = vx_paths::VxPaths::new()
+            .ok()
+            .map(|paths| paths.config_dir.join(NETWORK_CONFIG_FILE))
+            .filter(|path| path.exists())
+            .and_then(|path| match Self::from_file(&path) {
+                Ok(config) => Some(config),
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), error = %e, "Ignoring invalid network config");
+                    None
+                }
+            });
+        Self::resolve(file, |name| std::env::var(name).ok())
+    }
+
+    /// Load network settings from a config file only
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Parse network settings from TOML content
+    pub fn parse(content: &str) -> Result<Self> {
+        Ok(toml::from_str(content)?)
+    }
+
+    /// Merge a config file with environment variables read through `env`
+    ///
+    /// Invalid environment values are logged and ignored.
+    pub fn resolve(file: Option<Self>, env: impl Fn(&str) -> Option<String>) -> Self {
+        let mut config = file.unwrap_or_default();
+        let env = |name: &str| env(name).filter(|v| !v.trim().is_empty());
+
+        if let Some(value) = env("VX_IP_FAMILY") {
+            match value.parse() {
+                Ok(family) => config.ip_family = family,
+                Err(e) => tracing::warn!(error = %e, "Ignoring VX_IP_FAMILY"),
+            }
+        }
+        if let Some(value) = env("VX_CONNECT_TIMEOUT") {
+            match value.trim().parse() {
+                Ok(secs) => config.connect_timeout = Some(secs),
+                Err(_) => tracing::warn!(value = %value, "Ignoring invalid VX_CONNECT_TIMEOUT"),
+            }
+        }
+        config
+    }
+
+    /// Configured connect timeout, or `default`
+    pub fn connect_timeout_or(&self, default: Duration) -> Duration {
+        self.connect_timeout
+            .map(Duration::from_secs)
+            .unwrap_or(default)
+    }
+
+    /// Configure `builder` with these settings
+    pub fn apply(&self, mut builder: ClientBuilder) -> ClientBuilder {
+        // Binding to the unspecified address of one family makes the
+        // connector skip addresses of the other family entirely.
+        builder = match self.ip_family {
+            IpFamily::Auto => builder,
+            IpFamily::Ipv4 => builder.local_address(IpAddr::V4(Ipv4Addr::UNSPECIFIED)),
+            IpFamily::Ipv6 => builder.local_address(IpAddr::V6(Ipv6Addr::UNSPECIFIED)),
+        };
+        if let Some(secs) = self.connect_timeout {
+            builder = builder.connect_timeout(Duration::from_secs(secs));
+        }
+        for (host, ip) in &self.hosts {
+            // The port is ignored; requests keep the port from their URL
+            builder = builder.resolve(host, SocketAddr::new(*ip, 0));
+        }
+        builder
+    }
+}
@@ -0,0 +1,139 @@
+//! LAN peer cache settings
+//!
+//! With the peer cache enabled, a download missing from the local cache is
+//! first requested from peers running `vx cache serve` (found through mDNS or
+//! listed explicitly) and only fetched upstream when no peer has a copy whose
+//! SHA-256 matches the upstream checksum.
+//!
+//! `VX_PEER_CACHE=on|off` overrides `enabled` from
+//! `~/.vx/config/peers.toml`:
+//!
+//! ```toml
+//! # ~/.vx/config/peers.toml
+//! enabled = true
+//! peers = ["10.0.0.5:7878"]   # in addition to mDNS discovery
+//! discovery = true            # query the LAN with mDNS
+//! discovery_timeout_ms = 300
+//! port = 7878                 # port used by `vx cache serve`
+//! require_checksum = true     # only use peers when an upstream checksum exists
+//! ```
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::path::Path;
+use std::time::Duration;
+use vx_cache::peer::DEFAULT_PEER_PORT;
+
+/// Peer cache configuration file name inside the vx config directory
+pub const PEERS_CONFIG_FILE: &str = "peers.toml";
+
+/// Set to `on` or `off` to enable or disable the peer cache
+pub const PEER_CACHE_ENV: &str = "VX_PEER_CACHE";
+
+/// LAN peer cache settings
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct PeerConfig {
+    /// Fetch downloads from peers before going upstream
+    pub enabled: bool,
+    /// Discover peers with mDNS
+    pub discovery: bool,
+    /// How long to wait for mDNS answers, in milliseconds
+    pub discovery_timeout_ms: u64,
+    /// Peers to try in addition to discovered ones (`host` or `host:port`)
+    pub peers: Vec<String>,
+    /// Port used by `vx cache serve`
+    pub port: u16,
+    /// Only use peers when the upstream publishes a checksum for the file
+    pub require_checksum: bool,
+}
+
+impl Default for PeerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            discovery: true,
+            discovery_timeout_ms: 300,
+            peers: Vec::new(),
+            port: DEFAULT_PEER_PORT,
+            require_checksum: true,
+        }
+    }
+}
+
+impl PeerConfig {
+    /// Load peer settings from the environment and `~/.vx/config/peers.toml`
+    ///
+    /// An unreadable or invalid config file is logged and ignored.
+    pub fn load() -> Self {
+        let file = vx_paths::VxPaths::new()
+            .ok()
+            .map(|paths| paths.config_dir.join(PEERS_CONFIG_FILE))
+            .filter(|path| path.exists())
+            .and_then(|path| match Self::from_file(&path) {
+                Ok(config) => Some(config),
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), error = %e, "Ignoring invalid peer cache config");
+                    None
+                }
+            });
+        Self::resolve(file, |name| std::env::var(name).ok())
+    }
+
+    /// Load peer settings from a config file only
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Parse peer settings from TOML content
+    pub fn parse(content: &str) -> Result<Self> {
+        Ok(toml::from_str(content)?)
+    }
+
+    /// Merge a config file with environment variables read through `env`
+    ///
+    /// Invalid environment values are logged and ignored.
+    pub fn resolve(file: Option<Self>, env: impl Fn(&str) -> Option<String>) -> Self {
+        let mut config = file.unwrap_or_default();
+        if let Some(value) = env(PEER_CACHE_ENV).filter(|v| !v.trim().is_empty()) {
+            match value.trim().to_ascii_lowercase().as_str() {
+                "1" | "on" | "true" | "yes" => config.enabled = true,
+                "0" | "off" | "false" | "no" => config.enabled = false,
+                _ => tracing::warn!(value = %value, "Ignoring invalid VX_PEER_CACHE"),
+            }
+        }
+        config
+    }
+
+    /// mDNS discovery timeout
+    pub fn discovery_timeout(&self) -> Duration {
+        Duration::from_millis(self.discovery_timeout_ms)
+    }
+
+    /// Resolve the configured peers, using the default port when none is given
+    ///
+    /// Peers that fail to resolve are logged and skipped. Resolution may
+    /// block on DNS.
+    pub fn static_peers(&self) -> Vec<SocketAddr> {
+        self.peers
+            .iter()
+            .filter_map(|peer| {
+                let resolved = if peer.contains(':') {
+                    peer.to_socket_addrs()
+                } else {
+                    (peer.as_str(), DEFAULT_PEER_PORT).to_socket_addrs()
+                };
+                match resolved {
+                    Ok(mut addrs) => addrs.next(),
+                    Err(e) => {
+                        tracing::warn!(peer = %peer, error = %e, "Ignoring unresolvable peer");
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+}
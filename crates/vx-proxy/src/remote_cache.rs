@@ -0,0 +1,418 @@
+//! Remote shared download cache settings
+//!
+//! A remote cache is a bucket on S3, an S3-compatible server (MinIO, R2, ...)
+//! or Google Cloud Storage (through its S3-compatible XML API with HMAC keys)
+//! shared by a team or CI fleet. Downloads missing from the local cache are
+//! looked up there before going upstream, and files downloaded upstream are
+//! uploaded in the background so the next machine finds them warm.
+//!
+//! `VX_REMOTE_CACHE=<url>|off` overrides `url` from
+//! `~/.vx/config/remote-cache.toml`:
+//!
+//! ```toml
+//! # ~/.vx/config/remote-cache.toml
+//! url = "s3://team-vx-cache/downloads"   # or gs://bucket/prefix
+//! region = "eu-west-1"
+//! endpoint = "https://minio.corp.example:9000"  # S3-compatible servers only
+//! upload = true                           # false for a read-only cache
+//! ```
+//!
+//! Credentials, region and endpoint fall back to `AWS_ACCESS_KEY_ID`,
+//! `AWS_SECRET_ACCESS_KEY`, `AWS_SESSION_TOKEN`, `AWS_REGION` and
+//! `AWS_ENDPOINT_URL`. Without credentials, requests are anonymous, which
+//! works for public-read buckets.
+
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+use reqwest::{Client, Method, RequestBuilder};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+/// Remote cache configuration file name inside the vx config directory
+pub const REMOTE_CACHE_CONFIG_FILE: &str = "remote-cache.toml";
+
+/// Set to a bucket URL to use a remote cache, or `off` to disable it
+pub const REMOTE_CACHE_ENV: &str = "VX_REMOTE_CACHE";
+
+/// SHA-256 of an empty payload, signed for requests without a body
+pub const EMPTY_SHA256: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+/// Object metadata header carrying the SHA-256 of an uploaded file
+pub const SHA256_METADATA: &str = "x-amz-meta-vx-sha256";
+
+/// Remote cache settings
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct RemoteCacheConfig {
+    /// Bucket URL, `s3://bucket/prefix` or `gs://bucket/prefix`
+    pub url: Option<String>,
+    /// Bucket region (default `us-east-1`, `auto` for GCS)
+    pub region: Option<String>,
+    /// Endpoint of an S3-compatible server, e.g. `https://minio.local:9000`
+    pub endpoint: Option<String>,
+    /// Address buckets as `<endpoint>/<bucket>` instead of `<bucket>.<endpoint>`
+    /// (default: on for custom endpoints and GCS)
+    pub path_style: Option<bool>,
+    /// Upload files downloaded upstream
+    pub upload: bool,
+    /// Access key id (default: `AWS_ACCESS_KEY_ID`)
+    pub access_key_id: Option<String>,
+    /// Secret access key (default: `AWS_SECRET_ACCESS_KEY`)
+    pub secret_access_key: Option<String>,
+    /// Session token for temporary credentials (default: `AWS_SESSION_TOKEN`)
+    pub session_token: Option<String>,
+}
+
+impl Default for RemoteCacheConfig {
+    fn default() -> Self {
+        Self {
+            url: None,
+            region: None,
+            endpoint: None,
+            path_style: None,
+            upload: true,
+            access_key_id: None,
+            secret_access_key: None,
+            session_token: None,
+        }
+    }
+}
+
+impl RemoteCacheConfig {
+    /// Load remote cache settings from the environment and
+    /// `~/.vx/config/remote-cache.toml`
+    ///
+    /// An unreadable or invalid config file is logged and ignored.
+    pub fn load() -> Self {
+        let file = vx_paths::VxPaths::new()
+            .ok()
+            .map(|paths| paths.config_dir.join(REMOTE_CACHE_CONFIG_FILE))
+            .filter(|path| path.exists())
+            .and_then(|path| match Self::from_file(&path) {
+                Ok(config) => Some(config),
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), error = %e, "Ignoring invalid remote cache config");
+                    None
+                }
+            });
+        Self::resolve(file, |name| std::env::var(name).ok())
+    }
+
+    /// Load remote cache settings from a config file only
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Parse remote cache settings from TOML content
+    pub fn parse(content: &str) -> Result<Self> {
+        Ok(toml::from_str(content)?)
+    }
+
+    /// Merge a config file with environment variables read through `env`
+    ///
+    /// `VX_REMOTE_CACHE` overrides the file; `AWS_*` variables only fill in
+    /// what the file leaves unset.
+    pub fn resolve(file: Option<Self>, env: impl Fn(&str) -> Option<String>) -> Self {
+        let env = |name: &str| env(name).filter(|v| !v.trim().is_empty());
+        let mut config = file.unwrap_or_default();
+
+        if let Some(value) = env(REMOTE_CACHE_ENV) {
+            config.url = match value.trim().to_ascii_lowercase().as_str() {
+                "0" | "off" | "false" | "no" => None,
+                _ => Some(value.trim().to_string()),
+            };
+        }
+        config.region = config
+            .region
+            .or_else(|| env("AWS_REGION"))
+            .or_else(|| env("AWS_DEFAULT_REGION"));
+        config.endpoint = config
+            .endpoint
+            .or_else(|| env("AWS_ENDPOINT_URL_S3"))
+            .or_else(|| env("AWS_ENDPOINT_URL"));
+        if config.access_key_id.is_none() && config.secret_access_key.is_none() {
+            config.access_key_id = env("AWS_ACCESS_KEY_ID");
+            config.secret_access_key = env("AWS_SECRET_ACCESS_KEY");
+            config.session_token = config.session_token.or_else(|| env("AWS_SESSION_TOKEN"));
+        }
+        config
+    }
+
+    /// The configured bucket, or `None` when no remote cache is set up
+    pub fn store(&self) -> Result<Option<RemoteStore>> {
+        let Some(url) = &self.url else {
+            return Ok(None);
+        };
+        let (backend, rest) = if let Some(rest) = url.strip_prefix("s3://") {
+            (RemoteBackend::S3, rest)
+        } else if let Some(rest) = url.strip_prefix("gs://") {
+            (RemoteBackend::Gcs, rest)
+        } else {
+            bail!(
+                "Unsupported remote cache URL '{}' (expected s3:// or gs://)",
+                url
+            );
+        };
+        let (bucket, prefix) = rest.split_once('/').unwrap_or((rest, ""));
+        if bucket.is_empty() {
+            bail!("Remote cache URL '{}' has no bucket", url);
+        }
+
+        let region = match (&self.region, backend) {
+            (Some(region), _) => region.clone(),
+            (None, RemoteBackend::S3) => "us-east-1".to_string(),
+            (None, RemoteBackend::Gcs) => "auto".to_string(),
+        };
+        let endpoint = match (&self.endpoint, backend) {
+            (Some(endpoint), _) => endpoint.trim_end_matches('/').to_string(),
+            (None, RemoteBackend::S3) => format!("https://s3.{}.amazonaws.com", region),
+            (None, RemoteBackend::Gcs) => "https://storage.googleapis.com".to_string(),
+        };
+        let (scheme, host) = endpoint
+            .split_once("://")
+            .filter(|(scheme, host)| matches!(*scheme, "http" | "https") && !host.is_empty())
+            .with_context(|| format!("Invalid remote cache endpoint '{}'", endpoint))?;
+        // reqwest omits default ports from the Host header, so the signature must too
+        let default_port = if scheme == "https" { ":443" } else { ":80" };
+        let host = host.strip_suffix(default_port).unwrap_or(host);
+
+        let path_style = self
+            .path_style
+            .unwrap_or(self.endpoint.is_some() || backend == RemoteBackend::Gcs);
+        let credentials = match (&self.access_key_id, &self.secret_access_key) {
+            (Some(access_key_id), Some(secret_access_key)) => Some(Credentials {
+                access_key_id: access_key_id.clone(),
+                secret_access_key: secret_access_key.clone(),
+                session_token: self.session_token.clone(),
+            }),
+            (None, None) => None,
+            _ => bail!("Remote cache needs both an access key id and a secret access key"),
+        };
+
+        Ok(Some(RemoteStore {
+            backend,
+            scheme: scheme.to_string(),
+            host: if path_style {
+                host.to_string()
+            } else {
+                format!("{}.{}", bucket, host)
+            },
+            bucket: bucket.to_string(),
+            prefix: prefix.trim_matches('/').to_string(),
+            region,
+            path_style,
+            upload: self.upload,
+            credentials,
+        }))
+    }
+}
+
+/// Storage service behind a remote cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteBackend {
+    /// Amazon S3 or an S3-compatible server
+    S3,
+    /// Google Cloud Storage through its S3-compatible XML API
+    Gcs,
+}
+
+/// Access keys for signing remote cache requests
+#[derive(Clone, PartialEq, Eq)]
+pub struct Credentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub session_token: Option<String>,
+}
+
+impl std::fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Credentials")
+            .field("access_key_id", &self.access_key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A bucket used as a remote download cache
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteStore {
+    pub backend: RemoteBackend,
+    scheme: String,
+    host: String,
+    pub bucket: String,
+    pub prefix: String,
+    pub region: String,
+    pub path_style: bool,
+    /// Upload files downloaded upstream
+    pub upload: bool,
+    credentials: Option<Credentials>,
+}
+
+impl RemoteStore {
+    /// Human-readable location, e.g. `s3://bucket/prefix`
+    pub fn display_url(&self) -> String {
+        let scheme = match self.backend {
+            RemoteBackend::S3 => "s3",
+            RemoteBackend::Gcs => "gs",
+        };
+        if self.prefix.is_empty() {
+            format!("{}://{}", scheme, self.bucket)
+        } else {
+            format!("{}://{}/{}", scheme, self.bucket, self.prefix)
+        }
+    }
+
+    /// URL of the object stored under `key`
+    pub fn object_url(&self, key: &str) -> String {
+        format!("{}://{}{}", self.scheme, self.host, self.object_path(key))
+    }
+
+    /// A signed request for the object stored under `key`
+    ///
+    /// `headers` are sent and signed along with the generated ones, and
+    /// `payload_sha256` is the hex SHA-256 of the body (`None` for no body).
+    pub fn request(
+        &self,
+        client: &Client,
+        method: Method,
+        key: &str,
+        headers: &[(&str, &str)],
+        payload_sha256: Option<&str>,
+    ) -> RequestBuilder {
+        let signed = self.signed_headers(method.as_str(), key, headers, payload_sha256, Utc::now());
+        let mut request = client.request(method, self.object_url(key));
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        for (name, value) in signed {
+            request = request.header(name, value);
+        }
+        request
+    }
+
+    /// AWS Signature Version 4 headers for a request
+    ///
+    /// `extra` are additional headers sent with the request that should be
+    /// signed too. Returns nothing for anonymous access.
+    pub fn signed_headers(
+        &self,
+        method: &str,
+        key: &str,
+        extra: &[(&str, &str)],
+        payload_sha256: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> Vec<(String, String)> {
+        let Some(credentials) = &self.credentials else {
+            return Vec::new();
+        };
+        let payload = payload_sha256.unwrap_or(EMPTY_SHA256);
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = &amz_date[..8];
+
+        let mut generated = vec![
+            ("x-amz-content-sha256".to_string(), payload.to_string()),
+            ("x-amz-date".to_string(), amz_date.clone()),
+        ];
+        if let Some(token) = &credentials.session_token {
+            generated.push(("x-amz-security-token".to_string(), token.clone()));
+        }
+
+        let mut headers: Vec<(String, String)> = extra
+            .iter()
+            .map(|(name, value)| (name.to_ascii_lowercase(), value.trim().to_string()))
+            .chain(std::iter::once(("host".to_string(), self.host.clone())))
+            .chain(generated.iter().cloned())
+            .collect();
+        headers.sort();
+        let signed = headers
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>()
+            .join(";");
+        let canonical_headers: String = headers
+            .iter()
+            .map(|(name, value)| format!("{}:{}\n", name, value))
+            .collect();
+
+        let canonical_request = format!(
+            "{}\n{}\n\n{}\n{}\n{}",
+            method,
+            self.object_path(key),
+            canonical_headers,
+            signed,
+            payload
+        );
+        let scope = format!("{}/{}/s3/aws4_request", date, self.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let secret = format!("AWS4{}", credentials.secret_access_key);
+        let signing_key = [date, self.region.as_str(), "s3", "aws4_request"]
+            .iter()
+            .fold(secret.into_bytes(), |key, part| {
+                hmac_sha256(&key, part.as_bytes()).to_vec()
+            });
+        let signature = hex::encode(hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+
+        generated.push((
+            "authorization".to_string(),
+            format!(
+                "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+                credentials.access_key_id, scope, signed, signature
+            ),
+        ));
+        generated
+    }
+
+    fn object_path(&self, key: &str) -> String {
+        let mut path = String::new();
+        if self.path_style {
+            path.push('/');
+            path.push_str(&encode_segment(&self.bucket));
+        }
+        for segment in self.prefix.split('/').filter(|s| !s.is_empty()) {
+            path.push('/');
+            path.push_str(&encode_segment(segment));
+        }
+        path.push('/');
+        path.push_str(&encode_segment(key));
+        path
+    }
+}
+
+/// URI-encode a path segment the way SigV4 expects
+fn encode_segment(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    const BLOCK: usize = 64;
+    let mut block = [0u8; BLOCK];
+    if key.len() > BLOCK {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(block.map(|b| b ^ 0x36));
+    inner.update(message);
+    let mut outer = Sha256::new();
+    outer.update(block.map(|b| b ^ 0x5c));
+    outer.update(inner.finalize());
+    outer.finalize().into()
+}
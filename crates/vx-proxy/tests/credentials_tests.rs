@@ -0,0 +1,130 @@
+//! Tests for the credential store and request authorization
+
+use reqwest::header::AUTHORIZATION;
+use vx_proxy::credentials::{
+    CREDENTIAL_STORE_ENV, Credential, CredentialStore, GITHUB, Storage, is_github_host, lookup,
+    registry_name,
+};
+
+fn store() -> (tempfile::TempDir, CredentialStore) {
+    let dir = tempfile::tempdir().unwrap();
+    let store = CredentialStore::with_path(dir.path().join("credentials.toml"), false);
+    (dir, store)
+}
+
+#[test]
+fn test_save_get_remove() {
+    let (_dir, store) = store();
+    assert_eq!(store.get(GITHUB).unwrap(), None);
+
+    let storage = store.save(GITHUB, None, "ghp_token").unwrap();
+    assert_eq!(storage, Storage::File);
+    store
+        .save("registry.corp.example", Some("alice"), "s3cret")
+        .unwrap();
+
+    assert_eq!(
+        store.get(GITHUB).unwrap(),
+        Some(Credential::token("ghp_token"))
+    );
+    let registry = store.get("registry.corp.example").unwrap().unwrap();
+    assert_eq!(registry.username.as_deref(), Some("alice"));
+    assert_eq!(registry.secret, "s3cret");
+
+    let names: Vec<_> = store
+        .entries()
+        .unwrap()
+        .into_iter()
+        .map(|e| e.name)
+        .collect();
+    assert_eq!(names, ["github", "registry.corp.example"]);
+
+    assert!(store.remove(GITHUB).unwrap());
+    assert!(!store.remove(GITHUB).unwrap());
+    assert_eq!(store.get(GITHUB).unwrap(), None);
+    assert!(store.get("registry.corp.example").unwrap().is_some());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_credentials_file_is_private() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let (_dir, store) = store();
+    store.save(GITHUB, None, "ghp_token").unwrap();
+    let mode = std::fs::metadata(store.path())
+        .unwrap()
+        .permissions()
+        .mode();
+    assert_eq!(mode & 0o777, 0o600);
+}
+
+#[test]
+fn test_registry_name() {
+    assert_eq!(
+        registry_name("https://Registry.Corp.example/v2/").unwrap(),
+        "registry.corp.example"
+    );
+    assert_eq!(registry_name("ghcr.io").unwrap(), "ghcr.io");
+    assert_eq!(
+        registry_name("http://localhost:5000").unwrap(),
+        "localhost:5000"
+    );
+    assert!(registry_name("https://").is_err());
+}
+
+#[test]
+fn test_is_github_host() {
+    assert!(is_github_host("github.com"));
+    assert!(is_github_host("api.github.com"));
+    assert!(!is_github_host("notgithub.com"));
+    assert!(!is_github_host("ghcr.io"));
+}
+
+#[test]
+fn test_credential_apply() {
+    let client = reqwest::Client::new();
+    let bearer = Credential::token("abc")
+        .apply(client.get("https://example.com"))
+        .build()
+        .unwrap();
+    assert_eq!(bearer.headers()[AUTHORIZATION], "Bearer abc");
+
+    let basic = Credential {
+        username: Some("alice".to_string()),
+        secret: "s3cret".to_string(),
+    }
+    .apply(client.get("https://example.com"))
+    .build()
+    .unwrap();
+    assert!(
+        basic.headers()[AUTHORIZATION]
+            .to_str()
+            .unwrap()
+            .starts_with("Basic ")
+    );
+}
+
+#[test]
+fn test_credential_debug_hides_secret() {
+    let debug = format!("{:?}", Credential::token("ghp_secret"));
+    assert!(!debug.contains("ghp_secret"));
+}
+
+#[test]
+fn test_homebrew_bottles_use_anonymous_ghcr_token() {
+    let home = tempfile::tempdir().unwrap();
+    // SAFETY: no other test in this binary reads these variables
+    unsafe {
+        std::env::set_var("VX_HOME", home.path());
+        std::env::set_var(CREDENTIAL_STORE_ENV, "file");
+    }
+    assert_eq!(
+        lookup("https://ghcr.io/v2/homebrew/core/jq/manifests/1.7.1"),
+        Some(Credential::token("QQ=="))
+    );
+    assert_eq!(
+        lookup("https://ghcr.io/v2/some-org/private/manifests/1"),
+        None
+    );
+}
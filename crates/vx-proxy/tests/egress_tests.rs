@@ -0,0 +1,163 @@
+//! Tests for the egress policy and proxy
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use tempfile::TempDir;
+use vx_proxy::{EgressPolicy, EgressProxy};
+
+/// Origin server answering each connection with what it received first
+fn origin() -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut first = String::new();
+            reader.read_line(&mut first).unwrap();
+            let body = format!("origin saw: {}", first.trim());
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+        }
+    });
+    port
+}
+
+fn request(proxy: &EgressProxy, raw: &str) -> String {
+    let mut stream = TcpStream::connect(proxy.addr()).unwrap();
+    stream.write_all(raw.as_bytes()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[test]
+fn test_policy_matching() {
+    let policy = EgressPolicy::new(["Registry.npmjs.org", "*.github.com", "10.0.0.1"]);
+    assert!(policy.allows("registry.npmjs.org"));
+    assert!(policy.allows("REGISTRY.NPMJS.ORG."));
+    assert!(policy.allows("api.github.com"));
+    assert!(policy.allows("a.b.github.com"));
+    assert!(!policy.allows("github.com"));
+    assert!(!policy.allows("evilgithub.com"));
+    assert!(!policy.allows("npmjs.org"));
+    assert!(policy.allows("10.0.0.1"));
+    assert!(EgressPolicy::new(["*"]).allows("anything.example"));
+    assert!(!EgressPolicy::default().allows("example.com"));
+}
+
+#[test]
+fn test_blocked_destinations_are_refused_and_logged() {
+    let temp = TempDir::new().unwrap();
+    let log = temp.path().join("logs").join("egress.log");
+    let proxy = EgressProxy::start(EgressPolicy::new(["pypi.org"]).with_log_file(&log)).unwrap();
+
+    let response = request(
+        &proxy,
+        "CONNECT evil.example:443 HTTP/1.1\r\nHost: evil.example:443\r\n\r\n",
+    );
+    assert!(response.starts_with("HTTP/1.1 403"), "{}", response);
+    let response = request(&proxy, "GET http://evil.example/x HTTP/1.1\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 403"), "{}", response);
+
+    assert_eq!(
+        proxy.blocked(),
+        [
+            ("evil.example:443".to_string(), 1),
+            ("evil.example:80".to_string(), 1)
+        ]
+    );
+    let logged = std::fs::read_to_string(&log).unwrap();
+    assert!(logged.contains("blocked evil.example:443"));
+
+    // Direct (non-proxy) requests are rejected
+    let response = request(&proxy, "GET / HTTP/1.1\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 400"), "{}", response);
+}
+
+#[test]
+fn test_allowed_connections_are_forwarded() {
+    let port = origin();
+    let proxy = EgressProxy::start(EgressPolicy::new(["127.0.0.1"])).unwrap();
+
+    // Plain HTTP is forwarded in origin form
+    let response = request(
+        &proxy,
+        &format!(
+            "GET http://127.0.0.1:{}/pkg?x=1 HTTP/1.1\r\nHost: 127.0.0.1\r\nProxy-Connection: keep-alive\r\n\r\n",
+            port
+        ),
+    );
+    assert!(response.starts_with("HTTP/1.1 200 OK"), "{}", response);
+    assert!(response.ends_with("origin saw: GET /pkg?x=1 HTTP/1.1"));
+
+    // CONNECT opens a tunnel
+    let response = request(
+        &proxy,
+        &format!(
+            "CONNECT 127.0.0.1:{0} HTTP/1.1\r\n\r\nPING tunnel HTTP/1.1\r\n\r\n",
+            port
+        ),
+    );
+    assert!(response.starts_with("HTTP/1.1 200 Connection Established"));
+    assert!(response.ends_with("origin saw: PING tunnel HTTP/1.1"));
+    assert!(proxy.blocked().is_empty());
+}
+
+#[test]
+fn test_chains_through_upstream_proxy() {
+    let port = origin();
+    let upstream = EgressProxy::start(EgressPolicy::new(["127.0.0.1"])).unwrap();
+    let proxy =
+        EgressProxy::start(EgressPolicy::new(["127.0.0.1"]).with_upstream(upstream.url())).unwrap();
+
+    let response = request(
+        &proxy,
+        &format!(
+            "CONNECT 127.0.0.1:{} HTTP/1.1\r\n\r\nHELLO / HTTP/1.1\r\n\r\n",
+            port
+        ),
+    );
+    assert!(
+        response.ends_with("origin saw: HELLO / HTTP/1.1"),
+        "{}",
+        response
+    );
+
+    let response = request(
+        &proxy,
+        &format!("GET http://127.0.0.1:{}/a HTTP/1.1\r\n\r\n", port),
+    );
+    assert!(
+        response.ends_with("origin saw: GET /a HTTP/1.1"),
+        "{}",
+        response
+    );
+
+    assert!(
+        EgressProxy::start(EgressPolicy::new(["*"]).with_upstream("socks5://127.0.0.1:1080"))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_env_vars_point_to_proxy() {
+    let proxy = EgressProxy::start(EgressPolicy::new(["pypi.org"])).unwrap();
+    let vars = proxy.env_vars();
+    let get = |name: &str| {
+        vars.iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    };
+    assert_eq!(get("HTTPS_PROXY"), Some(proxy.url().as_str()));
+    assert_eq!(get("http_proxy"), Some(proxy.url().as_str()));
+    assert_eq!(get("VX_HTTPS_PROXY"), Some(proxy.url().as_str()));
+    assert!(get("NO_PROXY").unwrap().contains("127.0.0.1"));
+    assert!(!proxy.firewall_hints().is_empty());
+}
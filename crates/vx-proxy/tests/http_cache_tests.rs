@@ -0,0 +1,69 @@
+//! Tests for the shared HTTP response cache wrapper
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use vx_proxy::http_cache;
+
+/// Serve one canned response per connection and record each request head
+fn spawn_server(responses: Vec<&'static str>) -> (String, std::thread::JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = format!("http://{}", listener.local_addr().unwrap());
+    let handle = std::thread::spawn(move || {
+        let mut requests = Vec::new();
+        for response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0u8; 4096];
+            let n = stream.read(&mut buf).unwrap();
+            requests.push(String::from_utf8_lossy(&buf[..n]).to_lowercase());
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+        requests
+    });
+    (addr, handle)
+}
+
+#[tokio::test]
+async fn test_send_revalidates_and_falls_back_offline() {
+    let home = tempfile::tempdir().unwrap();
+    // Only test in this binary, so nothing else reads the environment concurrently
+    unsafe { std::env::set_var("VX_HOME", home.path()) };
+    let cache = http_cache::shared().unwrap();
+
+    let (addr, handle) = spawn_server(vec![
+        "HTTP/1.1 200 OK\r\ncache-control: no-cache\r\netag: \"v1\"\r\n\
+         content-type: application/json\r\ncontent-length: 3\r\nconnection: close\r\n\r\n[1]",
+        "HTTP/1.1 304 Not Modified\r\netag: \"v1\"\r\nconnection: close\r\n\r\n",
+    ]);
+    let url = format!("{}/index.json", addr);
+    let client = reqwest::Client::new();
+
+    let first = http_cache::send(client.get(&url)).await.unwrap();
+    assert!(!first.from_cache());
+    assert_eq!(first.bytes(), b"[1]");
+
+    let second = http_cache::send(client.get(&url)).await.unwrap();
+    assert!(second.from_cache());
+    assert_eq!(second.text(), "[1]");
+    assert_eq!(
+        second.headers()[reqwest::header::CONTENT_TYPE],
+        "application/json"
+    );
+
+    let requests = handle.join().unwrap();
+    assert!(!requests[0].contains("if-none-match"));
+    assert!(requests[1].contains("if-none-match: \"v1\""));
+
+    // The server is gone: the stale entry is served instead of an error
+    let offline = http_cache::send(client.get(&url)).await.unwrap();
+    assert!(offline.from_cache());
+    assert_eq!(offline.bytes(), b"[1]");
+
+    // Non-GET requests are never cached
+    assert!(http_cache::send(client.post(&url)).await.is_err());
+
+    let stats = cache.stats();
+    assert_eq!(stats.entries, 1);
+    assert_eq!(stats.misses, 1);
+    assert_eq!(stats.revalidated, 1);
+    assert_eq!(stats.stale_fallbacks, 1);
+}
@@ -328,17 +328,26 @@ pub async fn execute_bundle(bundle: &BundleContext, args: &[String]) -> Result<i
             .to_lowercase();
 
         if ext == "cmd" || ext == "bat" {
+            // Quote the path and arguments the way cmd.exe expects, so
+            // bundles under a home with spaces still run
             let mut c = Command::new("cmd.exe");
-            c.arg("/c").arg(&bundle.executable);
+            c.args(["/s", "/c"])
+                .raw_arg(super::command::build_cmd_string(
+                    &bundle.executable,
+                    &[],
+                    args,
+                ));
             c
         } else {
-            Command::new(&bundle.executable)
+            let mut c = Command::new(&bundle.executable);
+            c.args(args);
+            c
         }
     };
 
     #[cfg(not(windows))]
     let mut cmd = Command::new(&bundle.executable);
-
+    #[cfg(not(windows))]
     cmd.args(args);
 
     // Set up environment with bundle bin directories in PATH
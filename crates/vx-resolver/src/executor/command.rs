@@ -96,7 +96,9 @@ fn build_command_inner(
 
         if ext == "cmd" || ext == "bat" {
             let mut c = Command::new("cmd.exe");
-            c.arg("/c");
+            // /s: always strip exactly the outer quotes, whatever the rest
+            // of the line looks like
+            c.args(["/s", "/c"]);
 
             // Build the complete command string with proper quoting
             // Format: ""path" args..." where outer quotes are stripped by /c
@@ -228,7 +230,7 @@ fn resolve_windows_executable(executable: &std::path::Path) -> Option<std::path:
 /// Format: ""path with spaces" arg1 "arg with spaces" arg2"
 /// The outer quotes are needed because /c strips the first and last quote.
 #[cfg(windows)]
-fn build_cmd_string(
+pub(super) fn build_cmd_string(
     executable: &std::path::Path,
     command_prefix: &[String],
     args: &[String],
@@ -249,8 +251,8 @@ fn build_cmd_string(
         parts.push(quote_if_needed(arg));
     }
 
-    // Join with spaces
-    parts.join(" ")
+    // Join with spaces and wrap in the outer quotes stripped by /c
+    format!("\"{}\"", parts.join(" "))
 }
 
 /// Quote a string if it contains spaces or special characters
@@ -0,0 +1,95 @@
+//! Host environment filtering for tool execution
+//!
+//! `[env] inherit = "filtered"` in vx.toml runs tools with a cleaned
+//! environment: only the essential system variables vx-manifest already
+//! inherits for isolated providers ([`DEFAULT_INHERIT_SYSTEM_VARS`]) and the
+//! project's `allow` patterns come through, so variables like `NODE_OPTIONS`
+//! or `PYTHONPATH` on the host cannot change how a pinned tool behaves.
+//! `deny` patterns drop variables in either mode.
+//!
+//! Filtering only applies to variables inherited from the host. `PATH`,
+//! `VX_*` and everything vx sets for the tool (runtime, exec and project
+//! variables) are always passed.
+
+use std::collections::HashSet;
+use std::ffi::OsString;
+use tokio::process::Command;
+use vx_config::{EnvConfig, EnvInherit};
+use vx_manifest::DEFAULT_INHERIT_SYSTEM_VARS;
+
+/// Which host environment variables a tool process inherits
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HostEnvFilter {
+    /// Inheritance mode
+    pub inherit: EnvInherit,
+    /// Patterns passed through in `filtered` mode
+    pub allow: Vec<String>,
+    /// Patterns never passed through
+    pub deny: Vec<String>,
+}
+
+impl HostEnvFilter {
+    /// Filter configured by a project's `[env]` section
+    ///
+    /// Returns `None` when every host variable is inherited.
+    pub fn from_config(config: &EnvConfig) -> Option<Self> {
+        let filter = Self {
+            inherit: config.inherit.unwrap_or_default(),
+            allow: config.allow.clone(),
+            deny: config.deny.clone(),
+        };
+        (filter.inherit == EnvInherit::Filtered || !filter.deny.is_empty()).then_some(filter)
+    }
+
+    /// Whether the host variable `name` is passed to tools
+    pub fn passes(&self, name: &str) -> bool {
+        if name.eq_ignore_ascii_case("PATH") || name.starts_with("VX_") {
+            return true;
+        }
+        if matches_any(name, &self.deny) {
+            return false;
+        }
+        match self.inherit {
+            EnvInherit::All => true,
+            EnvInherit::Filtered => {
+                matches_any(name, DEFAULT_INHERIT_SYSTEM_VARS) || matches_any(name, &self.allow)
+            }
+        }
+    }
+
+    /// Remove the host variables that do not pass from `cmd`
+    ///
+    /// Variables `cmd` sets explicitly are kept.
+    pub fn apply(&self, cmd: &mut Command) {
+        let explicit: HashSet<OsString> = cmd
+            .as_std()
+            .get_envs()
+            .map(|(key, _)| key.to_os_string())
+            .collect();
+        for (key, _) in std::env::vars_os() {
+            if explicit.contains(&key) {
+                continue;
+            }
+            if !self.passes(&key.to_string_lossy()) {
+                cmd.env_remove(&key);
+            }
+        }
+    }
+}
+
+/// Whether `name` matches one of `patterns` (`PREFIX*` globs or exact names)
+///
+/// Windows variable names are case-insensitive.
+fn matches_any<S: AsRef<str>>(name: &str, patterns: &[S]) -> bool {
+    patterns.iter().any(|pattern| {
+        let pattern = pattern.as_ref();
+        match pattern.strip_suffix('*') {
+            Some(prefix) if cfg!(windows) => name
+                .get(..prefix.len())
+                .is_some_and(|head| head.eq_ignore_ascii_case(prefix)),
+            Some(prefix) => name.starts_with(prefix),
+            None if cfg!(windows) => name.eq_ignore_ascii_case(pattern),
+            None => name == pattern,
+        }
+    })
+}
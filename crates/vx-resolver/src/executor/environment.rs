@@ -12,9 +12,16 @@ use tracing::{debug, trace, warn};
 use vx_runtime::{ProviderRegistry, RuntimeContext};
 
 use super::bin_dir_cache;
+use super::pipeline::plan::PlannedRuntime;
 use super::project_config::ProjectToolsConfig;
 use super::version_utils;
 
+/// Home variables tools expect for a dependency runtime
+///
+/// Maven and Gradle look for the JDK through `JAVA_HOME` rather than `PATH`;
+/// the MSBuild bridge finds the .NET SDK through `DOTNET_ROOT`.
+const DEPENDENCY_HOME_VARS: &[(&str, &str)] = &[("java", "JAVA_HOME"), ("dotnet", "DOTNET_ROOT")];
+
 /// Environment preparation manager
 pub struct EnvironmentManager<'a> {
     #[allow(dead_code)]
@@ -23,6 +30,9 @@ pub struct EnvironmentManager<'a> {
     pub(crate) registry: Option<&'a ProviderRegistry>,
     pub(crate) context: Option<&'a RuntimeContext>,
     pub(crate) project_config: Option<&'a ProjectToolsConfig>,
+    /// Versions chosen for this invocation (e.g. `vx node@18`), preferred
+    /// over vx.toml/vx.lock when building the tools PATH
+    pub(crate) version_overrides: HashMap<String, String>,
 }
 
 impl<'a> EnvironmentManager<'a> {
@@ -40,9 +50,23 @@ impl<'a> EnvironmentManager<'a> {
             registry,
             context,
             project_config,
+            version_overrides: HashMap::new(),
         }
     }
 
+    /// Prefer these runtime versions over the project's pins
+    ///
+    /// Used for `vx node@18 script.js`: child processes spawned by the script
+    /// must find node 18 on PATH even when vx.toml pins node 20. Nothing is
+    /// written back to vx.toml or vx.lock.
+    pub fn with_version_overrides(
+        mut self,
+        overrides: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.version_overrides.extend(overrides);
+        self
+    }
+
     /// Prepare environment variables for a runtime
     pub async fn prepare_runtime_environment(
         &self,
@@ -605,6 +629,42 @@ impl<'a> EnvironmentManager<'a> {
         Ok(env)
     }
 
+    /// Point home variables (`JAVA_HOME`, ...) at the vx-managed dependencies
+    ///
+    /// Uses the planned dependency version when it is installed, otherwise
+    /// the latest installed version. Variables already set are kept.
+    pub fn inject_dependency_homes(
+        &self,
+        env: &mut HashMap<String, String>,
+        dependencies: &[PlannedRuntime],
+    ) {
+        let Some(context) = self.context else {
+            return;
+        };
+        let vx_paths = vx_paths::VxPaths::with_base_dir(context.paths.vx_home());
+
+        for dep in dependencies {
+            let Some((_, var)) = DEPENDENCY_HOME_VARS
+                .iter()
+                .find(|(name, _)| *name == dep.name)
+            else {
+                continue;
+            };
+            if env.contains_key(*var) {
+                continue;
+            }
+
+            let root = dep
+                .version_string()
+                .and_then(|v| vx_paths::RuntimeRoot::find(&dep.name, v, &vx_paths).ok()?)
+                .or_else(|| vx_paths::RuntimeRoot::find_latest(&dep.name, &vx_paths).ok()?);
+            if let Some(root) = root {
+                debug!("Setting {} from {} {}", var, dep.name, root.version);
+                env.insert(var.to_string(), root.root_dir.to_string_lossy().to_string());
+            }
+        }
+    }
+
     /// Get the provider runtime for a bundled tool
     pub fn get_provider_runtime(
         &self,
@@ -862,7 +922,10 @@ impl<'a> EnvironmentManager<'a> {
         }
     }
 
-    /// Select the version to use for a runtime, prioritizing project configuration
+    /// Select the version to use for a runtime
+    ///
+    /// Priority: version chosen for this invocation > vx.lock/vx.toml >
+    /// latest installed.
     pub fn select_version_for_runtime(
         &self,
         runtime_name: &str,
@@ -872,6 +935,17 @@ impl<'a> EnvironmentManager<'a> {
             return None;
         }
 
+        if let Some(requested_version) = self.version_overrides.get(runtime_name)
+            && let Some(version) =
+                self.find_matching_version(runtime_name, requested_version, installed_versions)
+        {
+            trace!(
+                "Using {} version {} chosen for this invocation",
+                runtime_name, version
+            );
+            return Some(version);
+        }
+
         // Check if project configuration specifies a version for this runtime
         if let Some(project_config) = self.project_config
             && let Some(requested_version) = project_config.get_version_with_fallback(runtime_name)
@@ -10,12 +10,18 @@
 
 use super::bundle::{execute_bundle, has_bundle, is_online, try_get_bundle_context};
 use super::environment::EnvironmentManager;
+use super::hooks::{
+    ExecutionHook, ExecutionInvocation, ExecutionOutcome, ProjectExecutionHook, run_after,
+    run_before,
+};
 use super::installation::InstallationManager;
 use super::pipeline::error::PipelineError;
 use super::project_config::ProjectToolsConfig;
 use crate::{ResolutionCache, Resolver, ResolverConfig, Result, RuntimeMap};
 use std::path::PathBuf;
-use tracing::{debug, info, info_span};
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::{debug, info, info_span, warn};
 use vx_paths::project::find_vx_config;
 use vx_runtime::{CacheMode, ProviderRegistry, RuntimeContext};
 
@@ -42,6 +48,12 @@ pub struct Executor<'a> {
     /// When `true`, subprocess output is piped through the compact output filter.
     /// Only takes effect when stdout is **not** a TTY.
     compact_mode: bool,
+
+    /// Extra environment variables set for the executed process
+    extra_env: Vec<(String, String)>,
+
+    /// Hooks called around the executed process
+    execution_hooks: Vec<Arc<dyn ExecutionHook>>,
 }
 
 impl<'a> Executor<'a> {
@@ -77,6 +89,8 @@ impl<'a> Executor<'a> {
             context: Some(context),
             project_config,
             compact_mode: false,
+            extra_env: Vec::new(),
+            execution_hooks: Vec::new(),
         })
     }
 
@@ -90,6 +104,24 @@ impl<'a> Executor<'a> {
         self
     }
 
+    /// Set extra environment variables for the executed process.
+    ///
+    /// They are applied last and override the prepared runtime environment,
+    /// e.g. the proxy variables of an egress policy.
+    pub fn with_env(mut self, env: impl IntoIterator<Item = (String, String)>) -> Self {
+        self.extra_env.extend(env);
+        self
+    }
+
+    /// Register a hook called before and after the tool process runs.
+    ///
+    /// Hooks run in registration order, after the project's own
+    /// `[hooks] before_execute/after_execute` and exec wrappers.
+    pub fn with_execution_hook(mut self, hook: Arc<dyn ExecutionHook>) -> Self {
+        self.execution_hooks.push(hook);
+        self
+    }
+
     /// Set the runtime context
     pub fn set_context(&mut self, context: &'a RuntimeContext) {
         self.context = Some(context);
@@ -312,7 +344,31 @@ impl<'a> Executor<'a> {
         // Add executable's parent directory to PATH
         self.add_executable_dir_to_prepared_path(&mut prepared);
 
+        prepared.env.extend(self.extra_env.iter().cloned());
+
+        // -------------------------
+        // Execution hooks: before_execute
+        // -------------------------
+        let hooks = self.execution_hooks();
+        let mut invocation = ExecutionInvocation::from_prepared(&prepared);
+        run_before(&hooks, &mut invocation).await?;
+        let runtime = self
+            .registry
+            .and_then(|registry| registry.get_runtime(runtime_name));
+        let exec_ctx = vx_runtime::ExecutionContext {
+            working_dir: invocation.working_dir.clone(),
+            env: invocation.env.clone(),
+            capture_output: false,
+            timeout: self.config.execution_timeout,
+            executor: Arc::new(vx_runtime::RealCommandExecutor),
+        };
+        if let Some(runtime) = &runtime {
+            runtime.pre_execute(&invocation.args, &exec_ctx).await?;
+        }
+        invocation.clone().apply_to(&mut prepared)?;
+
         // Stage 4: Execute
+        let started = Instant::now();
         let exit_code = {
             let _span = tracing::info_span!("execute_process", runtime = %runtime_name).entered();
             debug!("[Pipeline] Execute");
@@ -322,6 +378,28 @@ impl<'a> Executor<'a> {
                 .map_err(PipelineError::from)?
         };
 
+        // -------------------------
+        // Execution hooks: after_execute
+        // -------------------------
+        let outcome = ExecutionOutcome {
+            exit_code,
+            duration: started.elapsed(),
+        };
+        if let Some(runtime) = &runtime {
+            let result = vx_runtime::ExecutionResult {
+                exit_code,
+                stdout: None,
+                stderr: None,
+            };
+            if let Err(e) = runtime
+                .post_execute(&invocation.args, &result, &exec_ctx)
+                .await
+            {
+                warn!("post_execute of {} failed: {}", runtime_name, e);
+            }
+        }
+        run_after(&hooks, &invocation, &outcome).await;
+
         // Persist caches (new entries discovered during resolution)
         self.resolver.save_exec_cache();
         if let Some(ctx) = self.context {
@@ -331,6 +409,19 @@ impl<'a> Executor<'a> {
         Ok(exit_code)
     }
 
+    /// The project's execution hook followed by the registered ones
+    fn execution_hooks(&self) -> Vec<Arc<dyn ExecutionHook>> {
+        let project_hook = self
+            .project_config
+            .as_ref()
+            .and_then(ProjectExecutionHook::from_project_config)
+            .map(|hook| Arc::new(hook) as Arc<dyn ExecutionHook>);
+        project_hook
+            .into_iter()
+            .chain(self.execution_hooks.iter().cloned())
+            .collect()
+    }
+
     /// Apply RFC 0028 proxy execution overrides to a PreparedExecution
     async fn apply_proxy_execution(
         &self,
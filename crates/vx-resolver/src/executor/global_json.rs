@@ -0,0 +1,234 @@
+//! .NET `global.json` SDK selection
+//!
+//! A `global.json` in the project (or any parent directory) pins the .NET
+//! SDK with `sdk.version` and says how far the SDK may roll forward with
+//! `sdk.rollForward`, following the rules of the `dotnet` host:
+//!
+//! ```json
+//! { "sdk": { "version": "8.0.100", "rollForward": "latestFeature" } }
+//! ```
+//!
+//! SDK versions are `major.minor.patch` where the hundreds of the patch are
+//! the *feature band* (`8.0.415` is band `8.0.400`, patch level 15).
+//!
+//! Without an explicit version in vx.toml or vx.lock, `vx dotnet` runs the
+//! installed SDK that the rules select, or installs the newest SDK they
+//! allow (see [`GlobalJson::constraint`]).
+
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use tracing::debug;
+
+/// File name looked up from the working directory upward
+pub const GLOBAL_JSON: &str = "global.json";
+
+/// How far the SDK may roll forward from `sdk.version`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RollForward {
+    /// The requested version, else the latest patch of its feature band
+    Patch,
+    /// Like `patch`, then the next higher feature band of the same minor
+    Feature,
+    /// Like `feature`, then the next higher minor of the same major
+    Minor,
+    /// Like `minor`, then the next higher major
+    Major,
+    /// The latest patch of the requested feature band
+    LatestPatch,
+    /// The latest feature band and patch of the requested minor
+    LatestFeature,
+    /// The latest minor, feature band and patch of the requested major
+    LatestMinor,
+    /// The latest installed SDK
+    LatestMajor,
+    /// Exactly the requested version
+    Disable,
+}
+
+#[derive(Debug, Deserialize)]
+struct GlobalJsonFile {
+    sdk: Option<SdkSection>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SdkSection {
+    version: Option<String>,
+    roll_forward: Option<RollForward>,
+    allow_prerelease: Option<bool>,
+}
+
+/// The `sdk` section of a project's `global.json`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlobalJson {
+    /// Path of the file
+    pub path: PathBuf,
+    /// `sdk.version`
+    pub version: Option<String>,
+    /// `sdk.rollForward`, defaulting to `patch` with a version and
+    /// `latestMajor` without one
+    pub roll_forward: RollForward,
+    /// `sdk.allowPrerelease` (default `true`)
+    pub allow_prerelease: bool,
+}
+
+impl GlobalJson {
+    /// Find the nearest `global.json` at or above `start_dir`
+    ///
+    /// Like the `dotnet` host, the search stops at the first file found.
+    /// Returns `None` when there is none, it cannot be parsed or it has
+    /// no `sdk` section.
+    pub fn find(start_dir: &Path) -> Option<Self> {
+        let path = start_dir
+            .ancestors()
+            .map(|dir| dir.join(GLOBAL_JSON))
+            .find(|path| path.is_file())?;
+        let content = std::fs::read_to_string(&path).ok()?;
+        match Self::parse(&content, path.clone()) {
+            Ok(global_json) => global_json,
+            Err(e) => {
+                debug!("Ignoring invalid {}: {}", path.display(), e);
+                None
+            }
+        }
+    }
+
+    /// Parse the content of a `global.json`
+    pub fn parse(content: &str, path: PathBuf) -> serde_json::Result<Option<Self>> {
+        let file: GlobalJsonFile = serde_json::from_str(content)?;
+        Ok(file.sdk.map(|sdk| {
+            let version = sdk.version.filter(|v| !v.trim().is_empty());
+            let roll_forward = sdk.roll_forward.unwrap_or(if version.is_some() {
+                RollForward::Patch
+            } else {
+                RollForward::LatestMajor
+            });
+            Self {
+                path,
+                version,
+                roll_forward,
+                allow_prerelease: sdk.allow_prerelease.unwrap_or(true),
+            }
+        }))
+    }
+
+    /// Pick the installed SDK these rules select, if any
+    pub fn select(&self, installed: &[String]) -> Option<String> {
+        let mut candidates: Vec<(SdkVersion, &String)> = installed
+            .iter()
+            .filter_map(|v| Some((SdkVersion::parse(v)?, v)))
+            .filter(|(v, _)| self.allow_prerelease || !v.prerelease)
+            .collect();
+        candidates.sort_by_key(|(v, s)| (v.key(), (*s).clone()));
+        let Some(requested) = self.version.as_deref() else {
+            return candidates.last().map(|(_, s)| (*s).clone());
+        };
+        let req = SdkVersion::parse(requested)?;
+        candidates.retain(|(v, _)| v.key() >= req.key());
+        let latest = |filter: &dyn Fn(&SdkVersion) -> bool| {
+            candidates
+                .iter()
+                .rev()
+                .find(|(v, _)| filter(v))
+                .map(|(_, s)| (*s).clone())
+        };
+
+        match self.roll_forward {
+            RollForward::Disable => installed.iter().find(|v| *v == requested).cloned(),
+            RollForward::Patch if installed.iter().any(|v| v == requested) => {
+                Some(requested.to_string())
+            }
+            RollForward::Patch | RollForward::LatestPatch => latest(&|v| v.same_band(&req)),
+            RollForward::LatestFeature => latest(&|v| v.same_minor(&req)),
+            RollForward::LatestMinor => latest(&|v| v.major == req.major),
+            RollForward::LatestMajor => latest(&|_| true),
+            RollForward::Feature | RollForward::Minor | RollForward::Major => {
+                if let Some(found) = latest(&|v| v.same_band(&req)) {
+                    return Some(found);
+                }
+                // The lowest higher band in scope, at its latest patch
+                let in_scope = |v: &SdkVersion| match self.roll_forward {
+                    RollForward::Feature => v.same_minor(&req),
+                    RollForward::Minor => v.major == req.major,
+                    _ => true,
+                };
+                let lowest = candidates.iter().find(|(v, _)| in_scope(v))?.0;
+                latest(&|v| v.same_band(&lowest))
+            }
+        }
+    }
+
+    /// The version constraint to install when no installed SDK matches
+    ///
+    /// Installing the newest release the rules allow: the requested feature
+    /// band for the `patch`-to-`major` policies, a wider range for the
+    /// `latest*` ones, and exactly the requested version for `disable`.
+    /// `None` without `sdk.version`, meaning the latest SDK.
+    pub fn constraint(&self) -> Option<String> {
+        let requested = self.version.as_deref()?;
+        let Some(req) = SdkVersion::parse(requested) else {
+            return Some(requested.to_string());
+        };
+        let band = req.patch / 100 * 100;
+        Some(match self.roll_forward {
+            RollForward::Disable => requested.to_string(),
+            RollForward::Patch
+            | RollForward::LatestPatch
+            | RollForward::Feature
+            | RollForward::Minor
+            | RollForward::Major => format!(
+                ">={}, <{}.{}.{}",
+                requested,
+                req.major,
+                req.minor,
+                band + 100
+            ),
+            RollForward::LatestFeature => {
+                format!(">={}, <{}.{}.0", requested, req.major, req.minor + 1)
+            }
+            RollForward::LatestMinor => format!(">={}, <{}.0.0", requested, req.major + 1),
+            RollForward::LatestMajor => format!(">={}", requested),
+        })
+    }
+}
+
+/// `major.minor.patch[-prerelease]` of an SDK version
+#[derive(Debug, Clone, Copy)]
+struct SdkVersion {
+    major: u64,
+    minor: u64,
+    patch: u64,
+    prerelease: bool,
+}
+
+impl SdkVersion {
+    fn parse(version: &str) -> Option<Self> {
+        let (release, prerelease) = match version.split_once('-') {
+            Some((release, _)) => (release, true),
+            None => (version, false),
+        };
+        let mut parts = release.split('.').map(|p| p.parse::<u64>().ok());
+        let version = Self {
+            major: parts.next()??,
+            minor: parts.next()??,
+            patch: parts.next()??,
+            prerelease,
+        };
+        parts.next().is_none().then_some(version)
+    }
+
+    /// Sort key, a prerelease before its release
+    fn key(&self) -> (u64, u64, u64, bool) {
+        (self.major, self.minor, self.patch, !self.prerelease)
+    }
+
+    fn same_minor(&self, other: &Self) -> bool {
+        self.major == other.major && self.minor == other.minor
+    }
+
+    fn same_band(&self, other: &Self) -> bool {
+        self.same_minor(other) && self.patch / 100 == other.patch / 100
+    }
+}
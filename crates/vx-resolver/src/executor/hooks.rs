@@ -0,0 +1,303 @@
+//! Execution hooks around every tool run
+//!
+//! Cross-cutting concerns such as command logging, cost accounting or
+//! launching compilers through `sccache` are implemented once as an
+//! [`ExecutionHook`] instead of in every provider. The executor calls each
+//! registered hook right before the process is spawned, with the final
+//! executable, arguments and environment, and again after it exits.
+//!
+//! Projects configure the same hook points in vx.toml:
+//!
+//! - `[hooks] before_execute` / `after_execute` run shell commands with
+//!   `VX_EXEC_*` variables describing the invocation ([`ProjectExecutionHook`])
+//! - `[tools.<name>.exec] wrapper` launches the tool through another command
+
+use super::pipeline::stages::prepare::PreparedExecution;
+use super::project_config::{ExecHooks, ProjectToolsConfig};
+use crate::Result;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+use tracing::{debug, warn};
+use vx_config::{HookCommand, HookExecutor};
+
+/// Set for hook commands so a `vx` call inside a hook does not run the hooks again
+pub const EXEC_HOOK_ENV: &str = "VX_EXEC_HOOK";
+
+/// A tool invocation about to be executed
+#[derive(Debug, Clone)]
+pub struct ExecutionInvocation {
+    /// Runtime name (e.g., "node")
+    pub runtime: String,
+    /// Resolved version, if known
+    pub version: Option<String>,
+    /// Executable that will be spawned
+    pub executable: PathBuf,
+    /// Arguments inserted before `args` (e.g., a script the executable runs)
+    pub command_prefix: Vec<String>,
+    /// Arguments passed to the tool
+    pub args: Vec<String>,
+    /// Environment variables set for the process
+    pub env: HashMap<String, String>,
+    /// Working directory of the process
+    pub working_dir: Option<PathBuf>,
+    /// Command the executable is launched through, e.g. `["sccache"]`
+    ///
+    /// The first element is looked up on the tool's PATH; the rest are
+    /// arguments placed before the tool's executable.
+    pub wrapper: Vec<String>,
+}
+
+impl ExecutionInvocation {
+    /// Invocation of a prepared execution
+    pub fn from_prepared(prepared: &PreparedExecution) -> Self {
+        Self {
+            runtime: prepared.plan.primary.name.clone(),
+            version: prepared.plan.primary.version_string().map(str::to_string),
+            executable: prepared.executable.clone(),
+            command_prefix: prepared.command_prefix.clone(),
+            args: prepared.args.clone(),
+            env: prepared.env.clone(),
+            working_dir: prepared
+                .working_dir
+                .clone()
+                .or_else(|| std::env::current_dir().ok()),
+            wrapper: Vec::new(),
+        }
+    }
+
+    /// Write the changes of the hooks back to `prepared`
+    ///
+    /// A wrapper becomes the executable, with the tool's executable as its
+    /// first argument after the wrapper's own.
+    pub fn apply_to(self, prepared: &mut PreparedExecution) -> Result<()> {
+        prepared.args = self.args;
+        prepared.env = self.env;
+        let Some((program, wrapper_args)) = self.wrapper.split_first() else {
+            prepared.executable = self.executable;
+            prepared.command_prefix = self.command_prefix;
+            return Ok(());
+        };
+
+        let search_path = prepared
+            .env
+            .get("PATH")
+            .cloned()
+            .or_else(|| std::env::var("PATH").ok())
+            .into_iter()
+            .chain(prepared.vx_tools_path.clone())
+            .collect::<Vec<_>>()
+            .join(&vx_paths::path_separator().to_string());
+        let cwd = self
+            .working_dir
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("."));
+        let wrapper = which::which_in(program, Some(search_path), cwd).map_err(|_| {
+            anyhow::anyhow!(
+                "wrapper '{}' for {} was not found on PATH (add it to [tools] or install it)",
+                program,
+                self.runtime
+            )
+        })?;
+        debug!(
+            "[HOOK] launching {} through {}",
+            self.runtime,
+            wrapper.display()
+        );
+
+        prepared.command_prefix = wrapper_args
+            .iter()
+            .cloned()
+            .chain(std::iter::once(self.executable.display().to_string()))
+            .chain(self.command_prefix)
+            .collect();
+        prepared.executable = wrapper;
+        Ok(())
+    }
+
+    /// The full command line, wrapper included
+    pub fn command_line(&self) -> Vec<String> {
+        self.wrapper
+            .iter()
+            .cloned()
+            .chain(std::iter::once(self.executable.display().to_string()))
+            .chain(self.command_prefix.iter().cloned())
+            .chain(self.args.iter().cloned())
+            .collect()
+    }
+
+    /// Environment variables describing this invocation to hook commands
+    pub fn hook_env(&self) -> HashMap<String, String> {
+        let mut env = HashMap::from([
+            ("VX_EXEC_TOOL".to_string(), self.runtime.clone()),
+            (
+                "VX_EXEC_VERSION".to_string(),
+                self.version.clone().unwrap_or_default(),
+            ),
+            (
+                "VX_EXEC_EXECUTABLE".to_string(),
+                self.executable.display().to_string(),
+            ),
+            ("VX_EXEC_ARGS".to_string(), self.args.join(" ")),
+            ("VX_EXEC_COMMAND".to_string(), self.command_line().join(" ")),
+            (EXEC_HOOK_ENV.to_string(), "1".to_string()),
+        ]);
+        if let Some(dir) = &self.working_dir {
+            env.insert("VX_EXEC_CWD".to_string(), dir.display().to_string());
+        }
+        env
+    }
+}
+
+/// How a tool invocation ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionOutcome {
+    /// Process exit code
+    pub exit_code: i32,
+    /// Wall-clock time of the process
+    pub duration: Duration,
+}
+
+/// Hook points around tool execution
+///
+/// Register hooks with [`Executor::with_execution_hook`](super::Executor::with_execution_hook).
+#[async_trait]
+pub trait ExecutionHook: Send + Sync {
+    /// Hook name used in logs and error messages
+    fn name(&self) -> &str;
+
+    /// Called before the process is spawned
+    ///
+    /// The hook may change arguments, environment or the wrapper. Return
+    /// `Err` to abort the execution.
+    async fn before_execute(&self, invocation: &mut ExecutionInvocation) -> Result<()> {
+        let _ = invocation;
+        Ok(())
+    }
+
+    /// Called after the process exits
+    ///
+    /// Errors are logged and do not change the tool's exit code.
+    async fn after_execute(
+        &self,
+        invocation: &ExecutionInvocation,
+        outcome: &ExecutionOutcome,
+    ) -> Result<()> {
+        let _ = (invocation, outcome);
+        Ok(())
+    }
+}
+
+/// The hooks a project configures in vx.toml
+///
+/// - `[hooks] before_execute` runs before every tool; a failing command
+///   aborts the run
+/// - `[hooks] after_execute` runs after every tool and also gets
+///   `VX_EXEC_EXIT_CODE` and `VX_EXEC_DURATION_MS`
+/// - `[tools.<name>.exec] wrapper` sets the invocation's wrapper
+///
+/// Hook commands are skipped when vx itself runs inside a hook command.
+pub struct ProjectExecutionHook {
+    commands: Option<ExecHooks>,
+    wrappers: HashMap<String, Vec<String>>,
+}
+
+impl ProjectExecutionHook {
+    /// Hook for a project configuration, or `None` when it configures none
+    pub fn from_project_config(config: &ProjectToolsConfig) -> Option<Self> {
+        let commands = config
+            .exec_hooks()
+            .filter(|_| std::env::var_os(EXEC_HOOK_ENV).is_none())
+            .cloned();
+        let wrappers: HashMap<String, Vec<String>> = config
+            .exec_configs()
+            .filter(|(_, exec)| !exec.wrapper.is_empty())
+            .map(|(name, exec)| (name.to_string(), exec.wrapper.clone()))
+            .collect();
+        (commands.is_some() || !wrappers.is_empty()).then_some(Self { commands, wrappers })
+    }
+
+    fn run(&self, name: &str, command: &HookCommand, env: HashMap<String, String>) -> Result<()> {
+        let Some(commands) = &self.commands else {
+            return Ok(());
+        };
+        let result = HookExecutor::new(&commands.root)
+            .envs(env)
+            .execute(name, command)?;
+        if let Some(output) = &result.output {
+            debug!("[{}] {}", name, output.trim_end());
+        }
+        if !result.success {
+            anyhow::bail!(
+                "{} hook failed: {}",
+                name,
+                result.error.unwrap_or_default().trim_end()
+            );
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ExecutionHook for ProjectExecutionHook {
+    fn name(&self) -> &str {
+        "vx.toml"
+    }
+
+    async fn before_execute(&self, invocation: &mut ExecutionInvocation) -> Result<()> {
+        if let Some(command) = self.commands.as_ref().and_then(|c| c.before.as_ref()) {
+            self.run("before_execute", command, invocation.hook_env())?;
+        }
+        if let Some(wrapper) = self.wrappers.get(&invocation.runtime) {
+            invocation.wrapper = wrapper.clone();
+        }
+        Ok(())
+    }
+
+    async fn after_execute(
+        &self,
+        invocation: &ExecutionInvocation,
+        outcome: &ExecutionOutcome,
+    ) -> Result<()> {
+        if let Some(command) = self.commands.as_ref().and_then(|c| c.after.as_ref()) {
+            let mut env = invocation.hook_env();
+            env.insert(
+                "VX_EXEC_EXIT_CODE".to_string(),
+                outcome.exit_code.to_string(),
+            );
+            env.insert(
+                "VX_EXEC_DURATION_MS".to_string(),
+                outcome.duration.as_millis().to_string(),
+            );
+            self.run("after_execute", command, env)?;
+        }
+        Ok(())
+    }
+}
+
+/// Run `before_execute` of every hook in order
+pub(crate) async fn run_before(
+    hooks: &[std::sync::Arc<dyn ExecutionHook>],
+    invocation: &mut ExecutionInvocation,
+) -> Result<()> {
+    for hook in hooks {
+        debug!("[HOOK] before_execute: {}", hook.name());
+        hook.before_execute(invocation).await?;
+    }
+    Ok(())
+}
+
+/// Run `after_execute` of every hook in order, logging failures
+pub(crate) async fn run_after(
+    hooks: &[std::sync::Arc<dyn ExecutionHook>],
+    invocation: &ExecutionInvocation,
+    outcome: &ExecutionOutcome,
+) {
+    for hook in hooks {
+        debug!("[HOOK] after_execute: {}", hook.name());
+        if let Err(e) = hook.after_execute(invocation, outcome).await {
+            warn!("after_execute hook '{}' failed: {}", hook.name(), e);
+        }
+    }
+}
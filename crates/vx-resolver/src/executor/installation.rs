@@ -755,6 +755,7 @@ impl<'a> InstallationManager<'a> {
                 } else {
                     self.install_runtime(dep_runtime).await?;
                 }
+                self.record_install_reason(dep_runtime, Some(runtime_name));
             }
 
             spinner.finish_and_clear();
@@ -763,6 +764,15 @@ impl<'a> InstallationManager<'a> {
         Ok(())
     }
 
+    /// Record why `runtime_name` was installed: on request when `dependent`
+    /// is `None`, otherwise as a dependency of `dependent`
+    pub(crate) fn record_install_reason(&self, runtime_name: &str, dependent: Option<&str>) {
+        if let Some(context) = self.context {
+            let state_dir = vx_paths::VxPaths::with_base_dir(context.paths.vx_home()).state_dir;
+            vx_paths::record_install_reason(&state_dir, runtime_name, dependent);
+        }
+    }
+
     /// Check if the runtime and its dependency belong to the Rust ecosystem
     fn is_rust_ecosystem(&self, runtime_name: &str, dep_runtime: &str) -> bool {
         let rust_runtimes = ["cargo", "rustc", "rust", "rustup"];
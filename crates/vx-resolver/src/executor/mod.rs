@@ -12,16 +12,22 @@
 //! - `fallback` - Fallback installation methods (system installers)
 //! - `environment` - Environment variable preparation and PATH building
 //! - `command` - Command building and execution
+//! - `env_filter` - Host environment filtering from `[env] inherit/allow/deny`
+//! - `hooks` - Execution hooks around every tool run (`before_execute`/`after_execute`)
 //! - `project_config` - Project configuration loading from vx.toml
+//! - `global_json` - .NET SDK selection from global.json
 //! - `bundle` - Offline bundle support for disconnected environments
 
 mod bin_dir_cache;
 mod bundle;
 mod command;
+mod env_filter;
 mod environment;
 #[allow(clippy::module_inception)]
 mod executor;
 mod fallback;
+mod global_json;
+mod hooks;
 mod installation;
 pub mod pipeline;
 mod project_config;
@@ -33,8 +39,13 @@ pub use bundle::{
     BUNDLE_DIR, BUNDLE_MANIFEST, BundleContext, BundleManifest, BundledToolInfo, execute_bundle,
     execute_system_runtime, has_bundle, is_online, try_get_bundle_context,
 };
+pub use env_filter::HostEnvFilter;
 pub use executor::Executor;
-pub use project_config::ProjectToolsConfig;
+pub use global_json::{GlobalJson, RollForward};
+pub use hooks::{
+    EXEC_HOOK_ENV, ExecutionHook, ExecutionInvocation, ExecutionOutcome, ProjectExecutionHook,
+};
+pub use project_config::{ExecHooks, ProjectToolsConfig};
 
 // Re-export from vx_runtime_core for convenience
 pub use vx_runtime_core::{exit_code_from_status, is_ctrl_c_exit};
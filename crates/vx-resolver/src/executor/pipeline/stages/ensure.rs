@@ -117,6 +117,13 @@ impl<'a> Stage<ExecutionPlan, ExecutionPlan> for EnsureStage<'a> {
 
         let install_mgr = self.installation_manager();
 
+        // The parent of a bundled primary (node for `vx npm`) is what the
+        // user asked for, not a dependency
+        let bundled_parent = self
+            .registry
+            .and_then(|r| r.get_runtime(&plan.primary.name))
+            .and_then(|rt| rt.metadata().get("bundled_with").cloned());
+
         // Install dependencies first (they're in topological order)
         for dep in &mut plan.dependencies {
             if dep.status == InstallStatus::NeedsInstall {
@@ -149,6 +156,11 @@ impl<'a> Stage<ExecutionPlan, ExecutionPlan> for EnsureStage<'a> {
                         None
                     };
                     dep.mark_installed_with_version(result.version.clone(), exe);
+                    if bundled_parent.as_deref() == Some(dep.name.as_str()) {
+                        install_mgr.record_install_reason(&dep.name, None);
+                    } else {
+                        install_mgr.record_install_reason(&dep.name, Some(&plan.primary.name));
+                    }
                     info!(
                         "[EnsureStage] Dependency {} installed (version: {})",
                         dep.name, result.version
@@ -244,6 +256,7 @@ impl<'a> Stage<ExecutionPlan, ExecutionPlan> for EnsureStage<'a> {
                     };
                     plan.primary
                         .mark_installed_with_version(result.version.clone(), exe);
+                    install_mgr.record_install_reason(&plan.primary.name, None);
                     info!(
                         "[EnsureStage] Primary {} installed (version: {})",
                         plan.primary.name, result.version
@@ -285,6 +298,7 @@ impl<'a> Stage<ExecutionPlan, ExecutionPlan> for EnsureStage<'a> {
                         None
                     };
                     injected.mark_installed_with_version(result.version.clone(), exe);
+                    install_mgr.record_install_reason(&injected.name, None);
                     info!(
                         "[EnsureStage] --with dep {} installed (version: {})",
                         injected.name, result.version
@@ -97,6 +97,9 @@ impl Stage<PreparedExecution, i32> for ExecuteStage {
                 executable: prepared.executable.clone(),
                 reason: e.to_string(),
             })?;
+            if let Some(filter) = &prepared.env_filter {
+                filter.apply(&mut cmd);
+            }
 
             let child = cmd.spawn().map_err(|e| ExecuteError::SpawnFailed {
                 executable: prepared.executable.clone(),
@@ -127,6 +130,9 @@ impl Stage<PreparedExecution, i32> for ExecuteStage {
             executable: prepared.executable.clone(),
             reason: e.to_string(),
         })?;
+        if let Some(filter) = &prepared.env_filter {
+            filter.apply(&mut cmd);
+        }
 
         debug!(
             "[ExecuteStage] cmd: {} {:?}",
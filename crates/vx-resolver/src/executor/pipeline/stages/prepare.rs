@@ -8,6 +8,7 @@
 //! - `--with` dependency PATH injection
 //! - Proxy execution setup (RFC 0028) for bundled runtimes
 //! - Executable path verification
+//! - Per-tool default args and env from `[tools.<name>.exec]`
 
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -16,9 +17,10 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use tracing::{debug, info};
 
+use crate::executor::env_filter::HostEnvFilter;
 use crate::executor::environment::EnvironmentManager;
 use crate::executor::pipeline::error::PrepareError;
-use crate::executor::pipeline::plan::ExecutionPlan;
+use crate::executor::pipeline::plan::{ExecutionPlan, VersionResolution};
 use crate::executor::pipeline::stage::Stage;
 use crate::executor::project_config::ProjectToolsConfig;
 use crate::{Resolver, ResolverConfig};
@@ -54,6 +56,9 @@ pub struct PreparedExecution {
     /// Optional output filter config (compact mode).
     /// Passed through from `ExecutionConfig::output_filter`.
     pub output_filter: Option<vx_output_filter::OutputFilterConfig>,
+
+    /// Host variables to drop from the subprocess (`[env] inherit/deny`)
+    pub env_filter: Option<HostEnvFilter>,
 }
 
 /// The Prepare stage: `ExecutionPlan` → `PreparedExecution`
@@ -216,6 +221,14 @@ impl<'a> PrepareStage<'a> {
     }
 }
 
+/// Concrete versions of the vx-managed runtimes in a plan, by runtime name
+fn planned_versions(plan: &ExecutionPlan) -> Vec<(String, String)> {
+    plan.all_runtimes()
+        .filter(|rt| !matches!(rt.version, VersionResolution::SystemAvailable { .. }))
+        .filter_map(|rt| Some((rt.name.clone(), rt.version_string()?.to_string())))
+        .collect()
+}
+
 #[async_trait]
 impl<'a> Stage<ExecutionPlan, PreparedExecution> for PrepareStage<'a> {
     type Error = PrepareError;
@@ -229,7 +242,7 @@ impl<'a> Stage<ExecutionPlan, PreparedExecution> for PrepareStage<'a> {
         // Step 1: Prepare environment variables (needed before proxy execution)
         let version = plan.primary.version_string().map(|s| s.to_string());
         let env_mgr = self.environment_manager();
-        let runtime_env = env_mgr
+        let mut runtime_env = env_mgr
             .prepare_runtime_environment(
                 &plan.primary.name,
                 version.as_deref(),
@@ -240,6 +253,7 @@ impl<'a> Stage<ExecutionPlan, PreparedExecution> for PrepareStage<'a> {
                 runtime: plan.primary.name.clone(),
                 reason: e.to_string(),
             })?;
+        env_mgr.inject_dependency_homes(&mut runtime_env, &plan.dependencies);
 
         debug!(
             "[PrepareStage] Environment prepared: {} variables",
@@ -326,9 +340,31 @@ impl<'a> Stage<ExecutionPlan, PreparedExecution> for PrepareStage<'a> {
                 })?
         };
 
-        // Step 3: Build vx tools PATH
+        // Step 3: Apply the project's execution wrapper for this tool
+        let mut args = plan.config.args.clone();
+        if let Some(exec) = self
+            .project_config
+            .and_then(|pc| pc.get_exec_config(&plan.primary.name))
+        {
+            debug!(
+                "[PrepareStage] Applying exec config for {}: {} arg(s), {} env var(s)",
+                plan.primary.name,
+                exec.args.len(),
+                exec.env.len()
+            );
+            args.splice(0..0, exec.args.iter().cloned());
+            runtime_env.extend(exec.env.clone());
+        }
+
+        // Step 4: Build vx tools PATH
+        //
+        // The runtimes planned for this invocation win over the project's pins,
+        // so `vx node@18 script.js` keeps node 18 on PATH for child processes
+        // even when vx.toml pins another version.
         let vx_tools_path = if plan.config.inherit_vx_path {
-            let env_mgr = self.environment_manager();
+            let env_mgr = self
+                .environment_manager()
+                .with_version_overrides(planned_versions(&plan));
             env_mgr.build_vx_tools_path()
         } else {
             None
@@ -337,12 +373,13 @@ impl<'a> Stage<ExecutionPlan, PreparedExecution> for PrepareStage<'a> {
         Ok(PreparedExecution {
             executable,
             command_prefix,
-            args: plan.config.args.clone(),
+            args,
             env: runtime_env,
             inherit_vx_path: plan.config.inherit_vx_path,
             vx_tools_path,
             working_dir: plan.config.working_dir.clone(),
             output_filter: plan.config.output_filter.clone(),
+            env_filter: self.project_config.and_then(|pc| pc.env_filter().cloned()),
             plan,
         })
     }
@@ -364,6 +401,7 @@ mod tests {
             vx_tools_path: None,
             working_dir: None,
             output_filter: None,
+            env_filter: None,
             plan: ExecutionPlan::new(
                 PlannedRuntime::installed(
                     "node",
@@ -396,4 +434,54 @@ mod tests {
             PrepareError::NoExecutable { .. }
         ));
     }
+
+    #[test]
+    fn test_planned_versions_skip_system_runtimes() {
+        let primary = PlannedRuntime::installed(
+            "node",
+            "18.20.4".to_string(),
+            PathBuf::from("/vx/store/node/18.20.4/bin/node"),
+        );
+        let plan = ExecutionPlan::new(primary, ExecutionConfig::default()).with_dependency(
+            PlannedRuntime::new(
+                "git",
+                VersionResolution::SystemAvailable {
+                    path: PathBuf::from("/usr/bin/git"),
+                    version: Some("2.43.0".to_string()),
+                },
+            ),
+        );
+
+        assert_eq!(
+            planned_versions(&plan),
+            vec![("node".to_string(), "18.20.4".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_invocation_version_wins_over_project_pin() {
+        let config = ResolverConfig::default();
+        let resolver = Resolver::new(config.clone(), crate::RuntimeMap::empty()).unwrap();
+        let project =
+            ProjectToolsConfig::from_tools(HashMap::from([("node".to_string(), "20".to_string())]));
+        let installed = vec!["18.20.4".to_string(), "20.18.0".to_string()];
+
+        let pinned = EnvironmentManager::new(&config, &resolver, None, None, Some(&project));
+        assert_eq!(
+            pinned.select_version_for_runtime("node", &installed),
+            Some("20.18.0".to_string())
+        );
+
+        let one_off = EnvironmentManager::new(&config, &resolver, None, None, Some(&project))
+            .with_version_overrides([("node".to_string(), "18".to_string())]);
+        assert_eq!(
+            one_off.select_version_for_runtime("node", &installed),
+            Some("18.20.4".to_string())
+        );
+        // Other runtimes still follow the project pins
+        assert_eq!(
+            one_off.select_version_for_runtime("go", &["1.22.0".to_string()]),
+            Some("1.22.0".to_string())
+        );
+    }
 }
@@ -4,7 +4,8 @@
 //! into an `ExecutionPlan` by:
 //!
 //! 1. Resolving the requested version with priority:
-//!    - explicit (command-line) > vx.lock > vx.toml > latest
+//!    - explicit (command-line) > vx.lock > vx.toml > version files (.nvmrc,
+//!      .tool-versions, ...) > global.json (dotnet) > latest
 //! 2. Calling `Resolver::resolve_with_version()` for dependency analysis
 //! 3. Checking platform support via the provider registry
 //! 4. Mapping `ResolutionResult` into `PlannedRuntime` entries
@@ -18,6 +19,7 @@ use async_trait::async_trait;
 use tracing::{debug, trace};
 use vx_runtime::{ProviderRegistry, RuntimeContext, get_default_constraints};
 
+use crate::executor::global_json::{GLOBAL_JSON, GlobalJson};
 use crate::executor::project_config::ProjectToolsConfig;
 use crate::{ResolutionCache, ResolutionCacheKey, ResolutionResult, Resolver, ResolverConfig};
 
@@ -205,7 +207,7 @@ impl<'a> ResolveStage<'a> {
 
     /// Determine the `VersionSource` based on how the version was obtained
     ///
-    /// Priority: explicit > locked > project config > installed latest
+    /// Priority: explicit > locked > project config > version file > installed latest
     fn determine_source(&self, runtime_name: &str, explicit: Option<&str>) -> VersionSource {
         if explicit.is_some() {
             VersionSource::Explicit
@@ -213,6 +215,10 @@ impl<'a> ResolveStage<'a> {
             // Check if the version comes from vx.lock
             if project_config.is_locked(runtime_name) {
                 VersionSource::Locked
+            } else if let Some(pin) = project_config.version_file(runtime_name) {
+                VersionSource::LegacyConfig {
+                    file: pin.file_name(),
+                }
             } else if project_config
                 .get_version_with_fallback(runtime_name)
                 .is_some()
@@ -226,6 +232,48 @@ impl<'a> ResolveStage<'a> {
         }
     }
 
+    /// Resolve the .NET SDK from the project's `global.json`
+    ///
+    /// Returns the installed SDK its `rollForward` rules select, else the
+    /// range of SDKs they allow installing. Only used for `dotnet` when
+    /// neither the command line nor vx.toml/vx.lock names a version.
+    async fn resolve_global_json_version(&self, request: &ResolveRequest) -> Option<String> {
+        let spec_name = self
+            .resolver
+            .get_spec(&request.runtime_name)
+            .map(|spec| spec.name.as_str());
+        if spec_name.unwrap_or(&request.runtime_name) != "dotnet" {
+            return None;
+        }
+        let start_dir = request
+            .working_dir
+            .clone()
+            .or_else(|| std::env::current_dir().ok())?;
+        let global_json = GlobalJson::find(&start_dir)?;
+
+        let installed = match (self.registry, self.runtime_context) {
+            (Some(registry), Some(runtime_context)) => match registry.get_runtime("dotnet") {
+                Some(runtime) => runtime
+                    .installed_versions(runtime_context)
+                    .await
+                    .unwrap_or_default(),
+                None => Vec::new(),
+            },
+            _ => Vec::new(),
+        };
+        let version = global_json
+            .select(&installed)
+            .or_else(|| global_json.constraint());
+        debug!(
+            "[ResolveStage] {} (sdk.version={:?}, rollForward={:?}) -> {:?}",
+            global_json.path.display(),
+            global_json.version,
+            global_json.roll_forward,
+            version
+        );
+        version
+    }
+
     async fn enrich_with_versioned_dependencies(
         &self,
         runtime_name: &str,
@@ -593,8 +641,17 @@ impl<'a> Stage<ResolveRequest, ExecutionPlan> for ResolveStage<'a> {
         );
 
         // Step 1: Resolve version (explicit → project config → latest installed)
-        let resolved_version = self.resolve_version(&input.runtime_name, input.version.as_deref());
-        let source = self.determine_source(&input.runtime_name, input.version.as_deref());
+        let mut resolved_version =
+            self.resolve_version(&input.runtime_name, input.version.as_deref());
+        let mut source = self.determine_source(&input.runtime_name, input.version.as_deref());
+        if resolved_version.is_none()
+            && let Some(version) = self.resolve_global_json_version(&input).await
+        {
+            resolved_version = Some(version);
+            source = VersionSource::LegacyConfig {
+                file: GLOBAL_JSON.to_string(),
+            };
+        }
 
         debug!(
             "[ResolveStage] resolved_version={:?}, source={:?}",
@@ -604,9 +661,14 @@ impl<'a> Stage<ResolveRequest, ExecutionPlan> for ResolveStage<'a> {
         // Step 2: Check resolution cache (only when no executable override, since overrides
         // are rare and their cache keys would be harder to invalidate correctly)
         let cache_key = if self.resolution_cache.is_some() && input.executable_override.is_none() {
+            // global.json is not part of the key, so key on the SDK it selected
+            let key_version = match source {
+                VersionSource::LegacyConfig { .. } => resolved_version.as_deref(),
+                _ => input.version.as_deref(),
+            };
             Some(ResolutionCacheKey::from_context(
                 &input.runtime_name,
-                input.version.as_deref(),
+                key_version,
                 &input.args,
                 self.config,
             ))
@@ -1044,6 +1106,32 @@ mod tests {
         assert_eq!(plan.config.args, vec!["--version"]);
     }
 
+    #[tokio::test]
+    async fn test_resolve_stage_dotnet_uses_global_json() {
+        let resolver = test_resolver();
+        let config = ResolverConfig::default();
+        let stage = ResolveStage::new(&resolver, &config);
+
+        let project = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            project.path().join("global.json"),
+            r#"{"sdk": {"version": "8.0.100", "rollForward": "latestFeature"}}"#,
+        )
+        .unwrap();
+        let nested = project.path().join("src").join("App");
+        std::fs::create_dir_all(&nested).unwrap();
+
+        let mut request = ResolveRequest::new("dotnet", vec!["build".into()]);
+        request.working_dir = Some(nested);
+        let plan = stage.execute(request).await.unwrap();
+        assert_eq!(plan.primary.version_string(), Some(">=8.0.100, <8.1.0"));
+
+        // An explicit version wins over global.json
+        let request = ResolveRequest::new("dotnet", vec![]).with_version("9.0.100");
+        let plan = stage.execute(request).await.unwrap();
+        assert_eq!(plan.primary.version_string(), Some("9.0.100"));
+    }
+
     #[tokio::test]
     async fn test_resolve_stage_with_injected_deps() {
         let resolver = test_resolver();
@@ -1179,6 +1267,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_determine_source_version_file() {
+        use crate::executor::project_config::ProjectToolsConfig;
+
+        let resolver = test_resolver();
+        let config = ResolverConfig::default();
+        let pin = |tool: &str, version: &str, file: &str| vx_config::VersionFilePin {
+            tool: tool.to_string(),
+            version: version.to_string(),
+            path: PathBuf::from("/project").join(file),
+        };
+
+        // vx.toml pins go; .nvmrc pins node; .tool-versions pins go too
+        let project_config = ProjectToolsConfig::from_tools(std::collections::HashMap::from([(
+            "go".to_string(),
+            "1.22".to_string(),
+        )]))
+        .with_version_files(vec![
+            pin("node", "20.11.0", ".nvmrc"),
+            pin("go", "1.21.0", ".tool-versions"),
+        ]);
+
+        let stage = ResolveStage::new(&resolver, &config).with_project_config(&project_config);
+
+        assert_eq!(
+            stage.resolve_version("node", None),
+            Some("20.11.0".to_string())
+        );
+        assert_eq!(
+            stage.determine_source("node", None),
+            VersionSource::LegacyConfig {
+                file: ".nvmrc".to_string()
+            }
+        );
+        // Bundled tools follow their runtime's version file
+        assert_eq!(
+            stage.determine_source("npm", None),
+            VersionSource::LegacyConfig {
+                file: ".nvmrc".to_string()
+            }
+        );
+
+        // vx.toml wins over version files
+        assert_eq!(stage.resolve_version("go", None), Some("1.22".to_string()));
+        assert_eq!(
+            stage.determine_source("go", None),
+            VersionSource::ProjectConfig
+        );
+    }
+
     // =============================================================================
     // Bundled runtime version propagation tests
     // =============================================================================
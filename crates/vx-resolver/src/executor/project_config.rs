@@ -1,7 +1,8 @@
 //! Project configuration for vx.toml and vx.lock
 //!
 //! This module handles loading and querying project-level tool version
-//! configurations from vx.toml and vx.lock files.
+//! configurations from vx.toml and vx.lock files, and from the version files
+//! of other version managers (`.nvmrc`, `.tool-versions`, ...).
 //!
 //! # Version Priority
 //!
@@ -9,16 +10,20 @@
 //! 1. **Explicit** - Command-line specified (e.g., `vx node@20`)
 //! 2. **vx.lock** - Locked version from vx.lock (highest priority in config)
 //! 3. **vx.toml** - Project configuration version
-//! 4. **Latest** - Default to the latest available version
+//! 4. **Version files** - `.nvmrc`, `.node-version`, `.python-version`,
+//!    `rust-toolchain.toml` and `.tool-versions` (see [`vx_config::VERSION_FILES`])
+//! 5. **Latest** - Default to the latest available version
 //!
 //! This ensures reproducible builds: once a version is locked in vx.lock,
 //! it will be used consistently until the lock file is updated.
 
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use tracing::debug;
-use vx_config::parse_config;
+use vx_config::{HookCommand, VersionFilePin, parse_config, version_file_pins};
 use vx_paths::find_config_file_upward;
 
+use super::env_filter::HostEnvFilter;
 use crate::version::LockFile;
 
 /// Install options for a specific tool (key-value env-style pairs)
@@ -31,9 +36,28 @@ pub struct ProjectToolsConfig {
     tools: HashMap<String, String>,
     /// Locked tool versions from vx.lock (higher priority than vx.toml)
     locked_tools: HashMap<String, String>,
+    /// Versions pinned by version files (lower priority than vx.toml)
+    version_files: HashMap<String, VersionFilePin>,
     /// Per-tool install options extracted from detailed ToolConfig
     /// (e.g., msvc -> {"VX_MSVC_COMPONENTS": "spectre", "VX_MSVC_EXCLUDE_PATTERNS": "..."})
     tool_install_options: HashMap<String, InstallEnvVars>,
+    /// Per-tool execution wrappers from `[tools.<name>.exec]`
+    tool_exec: HashMap<String, vx_config::ToolExecConfig>,
+    /// Host environment filter from `[env] inherit/allow/deny`
+    env_filter: Option<HostEnvFilter>,
+    /// `[hooks] before_execute/after_execute` commands
+    exec_hooks: Option<ExecHooks>,
+}
+
+/// Commands run around every tool execution (`[hooks] before_execute/after_execute`)
+#[derive(Debug, Clone)]
+pub struct ExecHooks {
+    /// Directory the commands run in (the one containing vx.toml)
+    pub root: PathBuf,
+    /// Command run before the tool
+    pub before: Option<HookCommand>,
+    /// Command run after the tool
+    pub after: Option<HookCommand>,
 }
 
 impl ProjectToolsConfig {
@@ -42,7 +66,11 @@ impl ProjectToolsConfig {
         Self {
             tools,
             locked_tools: HashMap::new(),
+            version_files: HashMap::new(),
             tool_install_options: HashMap::new(),
+            tool_exec: HashMap::new(),
+            env_filter: None,
+            exec_hooks: None,
         }
     }
 
@@ -54,7 +82,11 @@ impl ProjectToolsConfig {
         Self {
             tools,
             locked_tools,
+            version_files: HashMap::new(),
             tool_install_options: HashMap::new(),
+            tool_exec: HashMap::new(),
+            env_filter: None,
+            exec_hooks: None,
         }
     }
 
@@ -66,24 +98,86 @@ impl ProjectToolsConfig {
         Self {
             tools,
             locked_tools: HashMap::new(),
+            version_files: HashMap::new(),
             tool_install_options,
+            tool_exec: HashMap::new(),
+            env_filter: None,
+            exec_hooks: None,
+        }
+    }
+
+    /// Create a ProjectToolsConfig with execution wrappers (for testing)
+    pub fn from_tools_with_exec(
+        tools: HashMap<String, String>,
+        tool_exec: HashMap<String, vx_config::ToolExecConfig>,
+    ) -> Self {
+        Self {
+            tools,
+            locked_tools: HashMap::new(),
+            version_files: HashMap::new(),
+            tool_install_options: HashMap::new(),
+            tool_exec,
+            env_filter: None,
+            exec_hooks: None,
         }
     }
 
+    /// Set the version file pins (for testing)
+    pub fn with_version_files(mut self, pins: Vec<VersionFilePin>) -> Self {
+        self.version_files = pins
+            .into_iter()
+            .map(|pin| (pin.tool.clone(), pin))
+            .collect();
+        self
+    }
+
     /// Load project configuration from vx.toml and vx.lock in current directory or parent directories
     ///
     /// This loads both files from the same directory where vx.toml is found.
     /// The vx.lock has higher priority than vx.toml for version resolution.
     pub fn load() -> Option<Self> {
         let cwd = std::env::current_dir().ok()?;
-        let config_path = find_config_file_upward(&cwd)?;
+        Self::load_from(&cwd)
+    }
+
+    /// Load project configuration for `dir`
+    ///
+    /// Without a vx.toml, version files alone still make a configuration.
+    pub fn load_from(dir: &Path) -> Option<Self> {
+        let version_files: HashMap<String, VersionFilePin> = version_file_pins(dir)
+            .into_iter()
+            .map(|pin| (pin.tool.clone(), pin))
+            .collect();
+        if !version_files.is_empty() {
+            debug!("Loaded {} pin(s) from version files", version_files.len());
+        }
+
+        let Some(config_path) = find_config_file_upward(dir) else {
+            return (!version_files.is_empty()).then(|| Self {
+                version_files,
+                ..Self::from_tools(HashMap::new())
+            });
+        };
         let config = parse_config(&config_path).ok()?;
         let tools = config.tools_as_hashmap();
 
         // Load locked versions from vx.lock (same directory as vx.toml)
         let locked_tools = Self::load_locked_versions(&config_path);
+        let env_filter = config.env.as_ref().and_then(HostEnvFilter::from_config);
+        let exec_hooks = config.hooks.as_ref().and_then(|hooks| {
+            (hooks.before_execute.is_some() || hooks.after_execute.is_some()).then(|| ExecHooks {
+                root: config_path.parent().unwrap_or(dir).to_path_buf(),
+                before: hooks.before_execute.clone(),
+                after: hooks.after_execute.clone(),
+            })
+        });
 
-        if tools.is_empty() && locked_tools.is_empty() {
+        if tools.is_empty()
+            && locked_tools.is_empty()
+            && version_files.is_empty()
+            && env_filter.is_none()
+            && exec_hooks.is_none()
+        {
             debug!(
                 "No tools defined in vx.toml or vx.lock at {}",
                 config_path.display()
@@ -99,11 +193,16 @@ impl ProjectToolsConfig {
 
             // Extract install options from detailed tool configs
             let tool_install_options = Self::extract_install_options(&config);
+            let tool_exec = Self::extract_exec_configs(&config);
 
             Some(Self {
                 tools,
                 locked_tools,
+                version_files,
                 tool_install_options,
+                tool_exec,
+                env_filter,
+                exec_hooks,
             })
         }
     }
@@ -146,7 +245,7 @@ impl ProjectToolsConfig {
 
     /// Get the version for a specific tool
     ///
-    /// Priority: vx.lock > vx.toml
+    /// Priority: vx.lock > vx.toml > version files
     ///
     /// This ensures reproducible builds - once a version is locked,
     /// it will be used consistently until the lock file is updated.
@@ -156,7 +255,29 @@ impl ProjectToolsConfig {
             return Some(locked);
         }
         // Then, check vx.toml
-        self.tools.get(tool).map(|s| s.as_str())
+        if let Some(version) = self.tools.get(tool) {
+            return Some(version);
+        }
+        // Finally, version files of other version managers
+        self.version_files.get(tool).map(|pin| pin.version.as_str())
+    }
+
+    /// The version file `get_version_with_fallback` takes the tool's version
+    /// from, when neither vx.lock nor vx.toml pins it
+    pub fn version_file(&self, tool: &str) -> Option<&VersionFilePin> {
+        let pinned =
+            |name: &str| self.locked_tools.contains_key(name) || self.tools.contains_key(name);
+        if pinned(tool) {
+            return None;
+        }
+        if let Some(pin) = self.version_files.get(tool) {
+            return Some(pin);
+        }
+        let primary = self.bundled_tool_runtime(tool)?;
+        if pinned(primary) {
+            return None;
+        }
+        self.version_files.get(primary)
     }
 
     /// Check if a tool has a locked version in vx.lock
@@ -179,7 +300,7 @@ impl ProjectToolsConfig {
     /// yarn, and bun have their own version schemes and should NOT inherit the Node.js
     /// version.
     ///
-    /// Priority: vx.lock > vx.toml (for both direct and fallback lookups)
+    /// Priority: vx.lock > vx.toml > version files (for both direct and fallback lookups)
     ///
     /// Examples of valid fallbacks:
     /// - `cargo` -> checks `cargo` then `rust` (cargo is bundled with Rust)
@@ -260,6 +381,58 @@ impl ProjectToolsConfig {
         self.tool_install_options.get(tool)
     }
 
+    /// Get the execution wrapper (`[tools.<name>.exec]`) for a specific tool.
+    ///
+    /// Its args go before the user's arguments and its env vars are set for the
+    /// tool process whenever the tool runs through vx.
+    pub fn get_exec_config(&self, tool: &str) -> Option<&vx_config::ToolExecConfig> {
+        self.tool_exec.get(tool)
+    }
+
+    /// Host environment filter for tools run in this project, if any
+    pub fn env_filter(&self) -> Option<&HostEnvFilter> {
+        self.env_filter.as_ref()
+    }
+
+    /// Tools with an execution wrapper (`[tools.<name>.exec]`)
+    pub fn exec_configs(&self) -> impl Iterator<Item = (&str, &vx_config::ToolExecConfig)> {
+        self.tool_exec
+            .iter()
+            .map(|(name, exec)| (name.as_str(), exec))
+    }
+
+    /// Commands run around every tool execution, if any
+    pub fn exec_hooks(&self) -> Option<&ExecHooks> {
+        self.exec_hooks.as_ref()
+    }
+
+    /// Set the execution hook commands (for testing)
+    pub fn with_exec_hooks(mut self, hooks: ExecHooks) -> Self {
+        self.exec_hooks = Some(hooks);
+        self
+    }
+
+    /// Set the host environment filter (for testing)
+    pub fn with_env_filter(mut self, filter: HostEnvFilter) -> Self {
+        self.env_filter = Some(filter);
+        self
+    }
+
+    /// Extract non-empty execution wrappers from the tools and runtimes sections.
+    fn extract_exec_configs(
+        config: &vx_config::VxConfig,
+    ) -> HashMap<String, vx_config::ToolExecConfig> {
+        config
+            .tools
+            .keys()
+            .chain(config.runtimes.keys())
+            .filter_map(|name| {
+                let exec = config.get_tool_config(name)?.exec.as_deref()?;
+                (!exec.is_empty()).then(|| (name.clone(), exec.clone()))
+            })
+            .collect()
+    }
+
     /// Extract install options from all detailed ToolConfig entries in VxConfig.
     ///
     /// This mirrors the logic in `sync.rs::build_install_env_vars()` but stores
@@ -303,7 +476,10 @@ impl ProjectToolsConfig {
         if let Some(components) = &tool_config.components
             && !components.is_empty()
         {
-            env_vars.insert("VX_MSVC_COMPONENTS".to_string(), components.join(","));
+            env_vars.insert(
+                vx_runtime::RuntimeContext::components_option_key(name),
+                components.join(","),
+            );
         }
 
         if let Some(patterns) = &tool_config.exclude_patterns
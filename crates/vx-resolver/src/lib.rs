@@ -44,10 +44,11 @@ pub mod version;
 
 pub use config::{DEFAULT_RESOLUTION_CACHE_TTL, ResolverConfig};
 pub use executor::{
-    BUNDLE_DIR, BUNDLE_MANIFEST, BundleContext, BundleManifest, BundledToolInfo, Executor,
-    ProjectToolsConfig, clear_bin_dir_cache, execute_bundle, execute_system_runtime,
-    exit_code_from_status, has_bundle, invalidate_bin_dir_cache, is_ctrl_c_exit, is_online,
-    try_get_bundle_context,
+    BUNDLE_DIR, BUNDLE_MANIFEST, BundleContext, BundleManifest, BundledToolInfo, EXEC_HOOK_ENV,
+    ExecHooks, ExecutionHook, ExecutionInvocation, ExecutionOutcome, Executor, GlobalJson,
+    HostEnvFilter, ProjectExecutionHook, ProjectToolsConfig, RollForward, clear_bin_dir_cache,
+    execute_bundle, execute_system_runtime, exit_code_from_status, has_bundle,
+    invalidate_bin_dir_cache, is_ctrl_c_exit, is_online, try_get_bundle_context,
 };
 
 // Pipeline types (RFC 0029)
@@ -74,9 +75,9 @@ pub use runtime_spec::{Ecosystem, RuntimeDependency, RuntimeSpec};
 // Re-export version types for convenience
 pub use version::{
     ApplyConfigResult, BoundsCheckResult, Conflict, ConflictDetectionError, ConflictDetector,
-    DependencyRequirement, LockFile, LockFileError, LockFileInconsistency, LockedTool,
-    MergedRequirement, RangeConstraint, RangeOp, ResolvedVersion, SolverConfig, SolverError,
-    SolverResult, SolverStatus, Version, VersionConstraint, VersionRangeConfig,
+    DependencyRequirement, LockFile, LockFileError, LockFileInconsistency, LockedPreset,
+    LockedTool, MergedRequirement, RangeConstraint, RangeOp, ResolvedVersion, SolverConfig,
+    SolverError, SolverResult, SolverStatus, Version, VersionConstraint, VersionRangeConfig,
     VersionRangeResolver, VersionRequest, VersionSolver, VersionStrategy,
 };
 
@@ -25,6 +25,12 @@
 //! [dependencies]
 //! npm = ["node"]
 //! npx = ["node"]
+//!
+//! [presets.python-ml]
+//! index = "github:acme/vx-presets"
+//! url = "https://raw.githubusercontent.com/acme/vx-presets/main/presets/python-ml.toml"
+//! sha256 = "9f2c..."
+//! applied_at = "2025-12-30T10:00:00Z"
 //! ```
 
 use super::constraint::Version;
@@ -246,6 +252,35 @@ impl From<&ResolvedVersion> for LockedTool {
     }
 }
 
+/// Provenance of a preset applied with `vx preset apply`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedPreset {
+    /// Preset index the preset came from
+    pub index: String,
+    /// Location of the preset file
+    pub url: String,
+    /// SHA256 of the preset file
+    pub sha256: String,
+    /// When the preset was applied
+    pub applied_at: String,
+}
+
+impl LockedPreset {
+    /// Create a preset entry applied now
+    pub fn new(
+        index: impl Into<String>,
+        url: impl Into<String>,
+        sha256: impl Into<String>,
+    ) -> Self {
+        Self {
+            index: index.into(),
+            url: url.into(),
+            sha256: sha256.into(),
+            applied_at: chrono_now(),
+        }
+    }
+}
+
 /// Lock file for reproducible environments
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LockFile {
@@ -259,6 +294,9 @@ pub struct LockFile {
     /// Tool dependencies (e.g., npm -> `node`)
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub dependencies: BTreeMap<String, Vec<String>>,
+    /// Presets applied from a preset index, by name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub presets: BTreeMap<String, LockedPreset>,
 }
 
 impl Default for LockFile {
@@ -268,6 +306,7 @@ impl Default for LockFile {
             metadata: LockFileMetadata::default(),
             tools: BTreeMap::new(),
             dependencies: BTreeMap::new(),
+            presets: BTreeMap::new(),
         }
     }
 }
@@ -397,6 +436,12 @@ impl LockFile {
         self.metadata.generated_at = chrono_now();
     }
 
+    /// Record the provenance of an applied preset
+    pub fn lock_preset(&mut self, name: impl Into<String>, preset: LockedPreset) {
+        self.presets.insert(name.into(), preset);
+        self.metadata.generated_at = chrono_now();
+    }
+
     /// Get a locked tool by name
     pub fn get_tool(&self, name: &str) -> Option<&LockedTool> {
         self.tools.get(name)
@@ -417,6 +462,14 @@ impl LockFile {
         self.tools.keys().map(|s| s.as_str()).collect()
     }
 
+    /// Get the locked version of every tool, keyed by tool name
+    pub fn tool_versions(&self) -> BTreeMap<String, String> {
+        self.tools
+            .iter()
+            .map(|(name, tool)| (name.clone(), tool.version.clone()))
+            .collect()
+    }
+
     /// Add a dependency relationship
     pub fn add_dependency(&mut self, tool: impl Into<String>, depends_on: Vec<String>) {
         self.dependencies.insert(tool.into(), depends_on);
@@ -477,6 +530,9 @@ impl LockFile {
         for (name, deps) in &other.dependencies {
             self.dependencies.insert(name.clone(), deps.clone());
         }
+        for (name, preset) in &other.presets {
+            self.presets.insert(name.clone(), preset.clone());
+        }
         self.metadata.generated_at = chrono_now();
     }
 
@@ -46,7 +46,7 @@ pub use conflict::{
     Conflict, ConflictDetectionError, ConflictDetector, DependencyRequirement, MergedRequirement,
 };
 pub use constraint::{RangeConstraint, RangeOp, Version, VersionConstraint};
-pub use lockfile::{LockFile, LockFileError, LockFileInconsistency, LockedTool};
+pub use lockfile::{LockFile, LockFileError, LockFileInconsistency, LockedPreset, LockedTool};
 pub use range::{ApplyConfigResult, BoundsCheckResult, VersionRangeConfig, VersionRangeResolver};
 pub use request::VersionRequest;
 pub use resolved::ResolvedVersion;
@@ -0,0 +1,102 @@
+//! Tests for host environment filtering (`[env] inherit/allow/deny`)
+
+use std::ffi::OsStr;
+use tokio::process::Command;
+use vx_config::{EnvInherit, parse_config_str};
+use vx_resolver::HostEnvFilter;
+
+fn filter_from(toml: &str) -> Option<HostEnvFilter> {
+    let config = parse_config_str(toml).unwrap();
+    config.env.as_ref().and_then(HostEnvFilter::from_config)
+}
+
+#[test]
+fn test_no_filter_by_default() {
+    assert!(filter_from("[env]\nNODE_ENV = \"development\"\n").is_none());
+    assert!(filter_from("[env]\ninherit = \"all\"\n").is_none());
+}
+
+#[test]
+fn test_filtered_keeps_essentials_and_allowed() {
+    let filter = filter_from(
+        r#"
+[env]
+inherit = "filtered"
+allow = ["SSH_AUTH_SOCK", "GITHUB_*"]
+"#,
+    )
+    .unwrap();
+    assert_eq!(filter.inherit, EnvInherit::Filtered);
+
+    for name in ["PATH", "HOME", "LANG", "LC_ALL", "VX_HOME", "SSH_AUTH_SOCK"] {
+        assert!(filter.passes(name), "{} should pass", name);
+    }
+    assert!(filter.passes("GITHUB_TOKEN"));
+    for name in ["NODE_OPTIONS", "PYTHONPATH", "GOFLAGS", "SSH_AGENT_PID"] {
+        assert!(!filter.passes(name), "{} should be dropped", name);
+    }
+}
+
+#[test]
+fn test_deny_wins_in_both_modes() {
+    let all = filter_from("[env]\ndeny = [\"NODE_OPTIONS\", \"PYTHON*\"]\n").unwrap();
+    assert_eq!(all.inherit, EnvInherit::All);
+    assert!(all.passes("CUSTOM_VAR"));
+    assert!(!all.passes("NODE_OPTIONS"));
+    assert!(!all.passes("PYTHONPATH"));
+
+    let filtered = filter_from(
+        r#"
+[env]
+inherit = "filtered"
+allow = ["LC_*"]
+deny = ["LC_ALL"]
+"#,
+    )
+    .unwrap();
+    assert!(filtered.passes("LC_CTYPE"));
+    assert!(!filtered.passes("LC_ALL"));
+    // PATH and vx's own variables cannot be denied
+    let locked = filter_from("[env]\ndeny = [\"PATH\", \"VX_*\"]\n").unwrap();
+    assert!(locked.passes("PATH"));
+    assert!(locked.passes("VX_HOME"));
+}
+
+#[test]
+fn test_filter_settings_are_not_env_vars() {
+    let config = parse_config_str(
+        r#"
+[env]
+NODE_ENV = "production"
+inherit = "filtered"
+allow = ["CI"]
+"#,
+    )
+    .unwrap();
+    let env = config.env.unwrap();
+    assert_eq!(env.vars.len(), 1);
+    assert_eq!(env.inherit, Some(EnvInherit::Filtered));
+    assert_eq!(env.allow, vec!["CI"]);
+}
+
+#[test]
+fn test_apply_removes_dropped_host_vars_but_keeps_explicit_ones() {
+    // SAFETY: no other test in this binary reads these variables
+    unsafe {
+        std::env::set_var("NODE_OPTIONS", "--inspect");
+        std::env::set_var("PYTHONPATH", "/host/site-packages");
+    }
+    let filter = HostEnvFilter {
+        inherit: EnvInherit::All,
+        allow: Vec::new(),
+        deny: vec!["NODE_OPTIONS".to_string(), "PYTHONPATH".to_string()],
+    };
+
+    let mut cmd = Command::new("true");
+    cmd.env("PYTHONPATH", "/project/src");
+    filter.apply(&mut cmd);
+
+    let envs: Vec<_> = cmd.as_std().get_envs().collect();
+    assert!(envs.contains(&(OsStr::new("NODE_OPTIONS"), None)));
+    assert!(envs.contains(&(OsStr::new("PYTHONPATH"), Some(OsStr::new("/project/src")))));
+}
@@ -40,6 +40,7 @@ async fn test_execute_stage_repairs_vx_store_execute_permissions() {
         working_dir: None,
         plan,
         output_filter: None,
+        env_filter: None,
     };
 
     let exit_code = ExecuteStage::new()
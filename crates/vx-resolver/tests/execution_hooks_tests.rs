@@ -0,0 +1,202 @@
+//! Tests for execution hooks (`before_execute`/`after_execute`, exec wrappers)
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use vx_config::HookCommand;
+use vx_resolver::{
+    ExecHooks, ExecutionConfig, ExecutionHook, ExecutionInvocation, ExecutionOutcome,
+    ExecutionPlan, PlannedRuntime, PrepareStage, PreparedExecution, ProjectExecutionHook,
+    ProjectToolsConfig, Resolver, ResolverConfig, RuntimeMap, Stage,
+};
+
+async fn prepared(tool: &str, executable: &str, args: &[&str]) -> PreparedExecution {
+    let config = ResolverConfig::default();
+    let resolver = Resolver::new(config.clone(), RuntimeMap::empty()).unwrap();
+    let plan = ExecutionPlan::new(
+        PlannedRuntime::installed(tool, "1.0.0".to_string(), PathBuf::from(executable)),
+        ExecutionConfig::with_args(args.iter().map(|a| a.to_string()).collect()),
+    );
+    PrepareStage::new(&resolver, &config, None, None)
+        .execute(plan)
+        .await
+        .unwrap()
+}
+
+fn project_with_wrapper(tool: &str, wrapper: &[&str]) -> ProjectToolsConfig {
+    let exec = vx_config::ToolExecConfig {
+        wrapper: wrapper.iter().map(|w| w.to_string()).collect(),
+        ..Default::default()
+    };
+    ProjectToolsConfig::from_tools_with_exec(
+        HashMap::from([(tool.to_string(), "1.0.0".to_string())]),
+        HashMap::from([(tool.to_string(), exec)]),
+    )
+}
+
+/// Create an executable named `name` in `dir`
+fn fake_executable(dir: &Path, name: &str) -> PathBuf {
+    let path = dir.join(format!("{}{}", name, std::env::consts::EXE_SUFFIX));
+    std::fs::write(&path, "#!/bin/sh\n").unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    path
+}
+
+#[tokio::test]
+async fn test_invocation_round_trips_without_changes() {
+    let mut prepared = prepared("node", "/opt/node/bin/node", &["app.js"]).await;
+    prepared.command_prefix = vec!["--inspect".to_string()];
+
+    let invocation = ExecutionInvocation::from_prepared(&prepared);
+    assert_eq!(invocation.runtime, "node");
+    assert_eq!(invocation.version.as_deref(), Some("1.0.0"));
+    assert_eq!(
+        invocation.command_line(),
+        vec!["/opt/node/bin/node", "--inspect", "app.js"]
+    );
+
+    invocation.apply_to(&mut prepared).unwrap();
+    assert_eq!(prepared.executable, PathBuf::from("/opt/node/bin/node"));
+    assert_eq!(prepared.command_prefix, vec!["--inspect"]);
+    assert_eq!(prepared.args, vec!["app.js"]);
+}
+
+#[tokio::test]
+async fn test_wrapper_launches_tool_through_command_on_path() {
+    let bin = tempfile::tempdir().unwrap();
+    let sccache = fake_executable(bin.path(), "sccache");
+
+    let mut prepared = prepared("clang", "/opt/llvm/bin/clang", &["-c", "main.c"]).await;
+    prepared
+        .env
+        .insert("PATH".to_string(), bin.path().to_string_lossy().to_string());
+
+    let hook =
+        ProjectExecutionHook::from_project_config(&project_with_wrapper("clang", &["sccache"]))
+            .expect("wrapper configures a hook");
+    let mut invocation = ExecutionInvocation::from_prepared(&prepared);
+    hook.before_execute(&mut invocation).await.unwrap();
+    assert_eq!(invocation.wrapper, vec!["sccache"]);
+    assert_eq!(
+        invocation.command_line(),
+        vec!["sccache", "/opt/llvm/bin/clang", "-c", "main.c"]
+    );
+
+    invocation.apply_to(&mut prepared).unwrap();
+    assert_eq!(prepared.executable, sccache);
+    assert_eq!(prepared.command_prefix, vec!["/opt/llvm/bin/clang"]);
+    assert_eq!(prepared.args, vec!["-c", "main.c"]);
+}
+
+#[tokio::test]
+async fn test_wrapper_only_applies_to_its_tool_and_must_exist() {
+    let project = project_with_wrapper("clang", &["vx-missing-wrapper", "--stats"]);
+    let hook = ProjectExecutionHook::from_project_config(&project).unwrap();
+
+    let prepared_node = prepared("node", "/opt/node/bin/node", &[]).await;
+    let mut invocation = ExecutionInvocation::from_prepared(&prepared_node);
+    hook.before_execute(&mut invocation).await.unwrap();
+    assert!(invocation.wrapper.is_empty());
+
+    let mut prepared_clang = prepared("clang", "/opt/llvm/bin/clang", &[]).await;
+    prepared_clang.env.insert("PATH".to_string(), String::new());
+    let mut invocation = ExecutionInvocation::from_prepared(&prepared_clang);
+    hook.before_execute(&mut invocation).await.unwrap();
+    let err = invocation.apply_to(&mut prepared_clang).unwrap_err();
+    assert!(err.to_string().contains("vx-missing-wrapper"));
+}
+
+#[test]
+fn test_no_project_hook_without_configuration() {
+    let project =
+        ProjectToolsConfig::from_tools(HashMap::from([("node".to_string(), "20".to_string())]));
+    assert!(ProjectExecutionHook::from_project_config(&project).is_none());
+}
+
+#[test]
+fn test_hooks_parse_from_vx_toml() {
+    let config = vx_config::parse_config_str(
+        r#"
+[tools.clang]
+version = "20"
+
+[tools.clang.exec]
+wrapper = ["sccache"]
+
+[hooks]
+before_execute = "echo start"
+after_execute = ["echo done"]
+"#,
+    )
+    .unwrap();
+    let hooks = config.hooks.as_ref().unwrap();
+    assert!(matches!(hooks.before_execute, Some(HookCommand::Single(_))));
+    assert!(matches!(
+        hooks.after_execute,
+        Some(HookCommand::Multiple(_))
+    ));
+    let exec = config
+        .get_tool_config("clang")
+        .and_then(|tool| tool.exec.clone())
+        .unwrap();
+    assert_eq!(exec.wrapper, vec!["sccache"]);
+    assert!(!exec.is_empty());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_hook_commands_see_invocation_and_outcome() {
+    let root = tempfile::tempdir().unwrap();
+    let project = project_with_wrapper("node", &[]).with_exec_hooks(ExecHooks {
+        root: root.path().to_path_buf(),
+        before: Some(HookCommand::Single(
+            "echo \"$VX_EXEC_TOOL $VX_EXEC_VERSION $VX_EXEC_ARGS\" > before.log".to_string(),
+        )),
+        after: Some(HookCommand::Single(
+            "echo \"$VX_EXEC_EXIT_CODE $VX_EXEC_DURATION_MS\" > after.log".to_string(),
+        )),
+    });
+    let hook = ProjectExecutionHook::from_project_config(&project).unwrap();
+
+    let prepared = prepared("node", "/opt/node/bin/node", &["app.js", "--watch"]).await;
+    let mut invocation = ExecutionInvocation::from_prepared(&prepared);
+    hook.before_execute(&mut invocation).await.unwrap();
+    hook.after_execute(
+        &invocation,
+        &ExecutionOutcome {
+            exit_code: 2,
+            duration: Duration::from_millis(1500),
+        },
+    )
+    .await
+    .unwrap();
+
+    let before = std::fs::read_to_string(root.path().join("before.log")).unwrap();
+    assert_eq!(before.trim(), "node 1.0.0 app.js --watch");
+    let after = std::fs::read_to_string(root.path().join("after.log")).unwrap();
+    assert_eq!(after.trim(), "2 1500");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_failing_before_execute_aborts() {
+    let root = tempfile::tempdir().unwrap();
+    let project = ProjectToolsConfig::from_tools(HashMap::new()).with_exec_hooks(ExecHooks {
+        root: root.path().to_path_buf(),
+        before: Some(HookCommand::Single(
+            "echo 'budget exceeded' >&2; exit 3".to_string(),
+        )),
+        after: None,
+    });
+    let hook = ProjectExecutionHook::from_project_config(&project).unwrap();
+
+    let prepared = prepared("node", "/opt/node/bin/node", &[]).await;
+    let mut invocation = ExecutionInvocation::from_prepared(&prepared);
+    let err = hook.before_execute(&mut invocation).await.unwrap_err();
+    assert!(err.to_string().contains("before_execute hook failed"));
+    assert!(err.to_string().contains("budget exceeded"));
+}
@@ -0,0 +1,156 @@
+//! Tests for .NET `global.json` SDK selection
+
+use std::path::PathBuf;
+
+use rstest::rstest;
+use vx_resolver::{GlobalJson, RollForward};
+
+fn global_json(version: &str, roll_forward: RollForward) -> GlobalJson {
+    GlobalJson {
+        path: PathBuf::from("global.json"),
+        version: Some(version.to_string()),
+        roll_forward,
+        allow_prerelease: true,
+    }
+}
+
+fn installed(versions: &[&str]) -> Vec<String> {
+    versions.iter().map(|v| v.to_string()).collect()
+}
+
+#[test]
+fn test_parse_defaults() {
+    let parsed = GlobalJson::parse(r#"{"sdk": {"version": "8.0.100"}}"#, "global.json".into())
+        .unwrap()
+        .unwrap();
+    assert_eq!(parsed.version.as_deref(), Some("8.0.100"));
+    assert_eq!(parsed.roll_forward, RollForward::Patch);
+    assert!(parsed.allow_prerelease);
+
+    let parsed = GlobalJson::parse(
+        r#"{"sdk": {"allowPrerelease": false}}"#,
+        "global.json".into(),
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(parsed.version, None);
+    assert_eq!(parsed.roll_forward, RollForward::LatestMajor);
+    assert!(!parsed.allow_prerelease);
+}
+
+#[test]
+fn test_parse_roll_forward_and_missing_sdk() {
+    let parsed = GlobalJson::parse(
+        r#"{"sdk": {"version": "8.0.100", "rollForward": "latestFeature"}, "msbuild-sdks": {}}"#,
+        "global.json".into(),
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(parsed.roll_forward, RollForward::LatestFeature);
+
+    assert!(
+        GlobalJson::parse(r#"{"msbuild-sdks": {}}"#, "global.json".into())
+            .unwrap()
+            .is_none()
+    );
+    assert!(GlobalJson::parse(r#"{"sdk": {"rollForward": "sideways"}}"#, "g".into()).is_err());
+}
+
+#[test]
+fn test_find_walks_up_to_nearest_file() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let nested = temp.path().join("src").join("App");
+    std::fs::create_dir_all(&nested).unwrap();
+    std::fs::write(
+        temp.path().join("global.json"),
+        r#"{"sdk": {"version": "8.0.100", "rollForward": "disable"}}"#,
+    )
+    .unwrap();
+
+    let found = GlobalJson::find(&nested).unwrap();
+    assert_eq!(found.path, temp.path().join("global.json"));
+    assert_eq!(found.roll_forward, RollForward::Disable);
+
+    // The nearest file wins, even without an sdk section
+    std::fs::write(temp.path().join("src").join("global.json"), "{}").unwrap();
+    assert!(GlobalJson::find(&nested).is_none());
+}
+
+#[rstest]
+#[case::disable_exact(RollForward::Disable, &["8.0.100", "8.0.101"], Some("8.0.100"))]
+#[case::disable_missing(RollForward::Disable, &["8.0.101"], None)]
+#[case::patch_exact(RollForward::Patch, &["8.0.100", "8.0.105"], Some("8.0.100"))]
+#[case::patch_rolls_within_band(RollForward::Patch, &["8.0.105", "8.0.103", "8.0.200"], Some("8.0.105"))]
+#[case::patch_stays_in_band(RollForward::Patch, &["8.0.200"], None)]
+#[case::latest_patch(RollForward::LatestPatch, &["8.0.100", "8.0.105", "8.0.200"], Some("8.0.105"))]
+#[case::feature_in_band(RollForward::Feature, &["8.0.105", "8.0.300"], Some("8.0.105"))]
+#[case::feature_next_band(RollForward::Feature, &["8.0.204", "8.0.201", "8.0.300", "8.1.100"], Some("8.0.204"))]
+#[case::feature_stays_in_minor(RollForward::Feature, &["8.1.100"], None)]
+#[case::minor_next_minor(RollForward::Minor, &["8.1.101", "8.1.100", "8.2.100", "9.0.100"], Some("8.1.101"))]
+#[case::minor_stays_in_major(RollForward::Minor, &["9.0.100"], None)]
+#[case::major_next_major(RollForward::Major, &["9.0.100", "10.0.100"], Some("9.0.100"))]
+#[case::latest_feature(RollForward::LatestFeature, &["8.0.100", "8.0.415", "8.1.100"], Some("8.0.415"))]
+#[case::latest_minor(RollForward::LatestMinor, &["8.0.100", "8.2.100", "9.0.100"], Some("8.2.100"))]
+#[case::latest_major(RollForward::LatestMajor, &["8.0.100", "9.0.306", "7.0.410"], Some("9.0.306"))]
+#[case::never_older(RollForward::LatestMajor, &["7.0.410", "8.0.099"], None)]
+fn test_select(
+    #[case] roll_forward: RollForward,
+    #[case] versions: &[&str],
+    #[case] expected: Option<&str>,
+) {
+    let selected = global_json("8.0.100", roll_forward).select(&installed(versions));
+    assert_eq!(selected.as_deref(), expected);
+}
+
+#[test]
+fn test_select_prerelease() {
+    let versions = installed(&["9.0.100-rc.2.24474.11", "8.0.415"]);
+    let mut config = global_json("8.0.100", RollForward::LatestMajor);
+    assert_eq!(
+        config.select(&versions).as_deref(),
+        Some("9.0.100-rc.2.24474.11")
+    );
+
+    config.allow_prerelease = false;
+    assert_eq!(config.select(&versions).as_deref(), Some("8.0.415"));
+
+    // A release sorts after its prerelease
+    let versions = installed(&["9.0.100", "9.0.100-rc.2.24474.11"]);
+    assert_eq!(
+        global_json("9.0.100-rc.1", RollForward::LatestPatch)
+            .select(&versions)
+            .as_deref(),
+        Some("9.0.100")
+    );
+}
+
+#[test]
+fn test_select_without_version_uses_latest() {
+    let config = GlobalJson {
+        path: PathBuf::from("global.json"),
+        version: None,
+        roll_forward: RollForward::LatestMajor,
+        allow_prerelease: true,
+    };
+    assert_eq!(
+        config
+            .select(&installed(&["8.0.415", "9.0.306"]))
+            .as_deref(),
+        Some("9.0.306")
+    );
+    assert_eq!(config.constraint(), None);
+}
+
+#[rstest]
+#[case(RollForward::Disable, "8.0.301")]
+#[case(RollForward::Patch, ">=8.0.301, <8.0.400")]
+#[case(RollForward::Major, ">=8.0.301, <8.0.400")]
+#[case(RollForward::LatestFeature, ">=8.0.301, <8.1.0")]
+#[case(RollForward::LatestMinor, ">=8.0.301, <9.0.0")]
+#[case(RollForward::LatestMajor, ">=8.0.301")]
+fn test_constraint(#[case] roll_forward: RollForward, #[case] expected: &str) {
+    assert_eq!(
+        global_json("8.0.301", roll_forward).constraint().as_deref(),
+        Some(expected)
+    );
+}
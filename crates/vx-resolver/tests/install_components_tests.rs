@@ -0,0 +1,170 @@
+//! Tests for partial archive installs driven by layout `components`
+
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use vx_runtime::{
+    FileSystem, Installer, ManifestDrivenRuntime, MockFileSystem, ProviderSource, RuntimeContext,
+    mock_context,
+};
+
+/// Installer that records the layout metadata of each download
+#[derive(Default)]
+struct RecordingInstaller {
+    fs: Arc<MockFileSystem>,
+    downloads: Mutex<Vec<HashMap<String, String>>>,
+}
+
+#[async_trait]
+impl Installer for RecordingInstaller {
+    async fn extract(&self, _archive: &Path, dest: &Path) -> Result<()> {
+        self.fs.create_dir_all(dest)
+    }
+
+    async fn download_and_extract(&self, _url: &str, dest: &Path) -> Result<()> {
+        self.fs.create_dir_all(dest)
+    }
+
+    async fn download_with_layout(
+        &self,
+        _url: &str,
+        dest: &Path,
+        metadata: &HashMap<String, String>,
+    ) -> Result<()> {
+        self.downloads.lock().unwrap().push(metadata.clone());
+        self.fs.create_dir_all(dest)
+    }
+}
+
+fn llvm_runtime() -> ManifestDrivenRuntime {
+    ManifestDrivenRuntime::new("llvm", "llvm", ProviderSource::BuiltIn)
+        .with_download_url(|_| {
+            Box::pin(async {
+                Ok(Some(
+                    "https://example.com/LLVM-20.1.0-Linux-X64.tar.xz".into(),
+                ))
+            })
+        })
+        .with_install_layout(|_| {
+            Box::pin(async {
+                Ok(Some(serde_json::json!({
+                    "strip_prefix": "LLVM-20.1.0-Linux-X64",
+                    "executable_paths": ["bin/clang"],
+                    "components": {
+                        "clang": ["bin/clang", "lib/clang/*"],
+                        "clang-format": ["bin/clang-format"],
+                        "clang-tidy": ["bin/clang-tidy", "lib/clang/*"],
+                    },
+                })))
+            })
+        })
+}
+
+fn context(installer: Arc<RecordingInstaller>, components: Option<&str>) -> RuntimeContext {
+    let mut ctx = mock_context();
+    ctx.fs = installer.fs.clone();
+    ctx.installer = installer;
+    if let Some(components) = components {
+        ctx.install_options.insert(
+            RuntimeContext::components_option_key("llvm"),
+            components.to_string(),
+        );
+    }
+    ctx
+}
+
+fn last_include(installer: &RecordingInstaller) -> Option<String> {
+    installer
+        .downloads
+        .lock()
+        .unwrap()
+        .last()
+        .and_then(|meta| meta.get("include").cloned())
+}
+
+#[test]
+fn test_components_option_key() {
+    assert_eq!(
+        RuntimeContext::components_option_key("msvc"),
+        "VX_MSVC_COMPONENTS"
+    );
+    assert_eq!(
+        RuntimeContext::components_option_key("clang-format"),
+        "VX_CLANG_FORMAT_COMPONENTS"
+    );
+}
+
+#[tokio::test]
+async fn test_default_extracts_every_declared_component() {
+    let installer = Arc::new(RecordingInstaller::default());
+    let ctx = context(installer.clone(), None);
+    let result = llvm_runtime().install_impl("20.1.0", &ctx).await.unwrap();
+
+    assert_eq!(
+        last_include(&installer).as_deref(),
+        Some("bin/clang,lib/clang/*,bin/clang-format,bin/clang-tidy")
+    );
+    let marker = ctx
+        .fs
+        .read_to_string(&result.install_path.join(".vx-components"))
+        .unwrap();
+    assert_eq!(marker, "clang\nclang-format\nclang-tidy");
+}
+
+#[tokio::test]
+async fn test_selected_components_reinstall_only_when_missing() {
+    let installer = Arc::new(RecordingInstaller::default());
+    let runtime = llvm_runtime();
+
+    let ctx = context(installer.clone(), Some("clang-format"));
+    runtime.install_impl("20.1.0", &ctx).await.unwrap();
+    assert_eq!(
+        last_include(&installer).as_deref(),
+        Some("bin/clang-format")
+    );
+
+    // Same selection: already installed
+    let result = runtime.install_impl("20.1.0", &ctx).await.unwrap();
+    assert!(result.already_installed);
+    assert_eq!(installer.downloads.lock().unwrap().len(), 1);
+
+    // A component that is not installed yet triggers a reinstall
+    let ctx = context(installer.clone(), Some("clang-format, clang-tidy"));
+    let result = runtime.install_impl("20.1.0", &ctx).await.unwrap();
+    assert!(!result.already_installed);
+    assert_eq!(
+        last_include(&installer).as_deref(),
+        Some("bin/clang-format,bin/clang-tidy,lib/clang/*")
+    );
+}
+
+#[tokio::test]
+async fn test_all_extracts_the_whole_archive() {
+    let installer = Arc::new(RecordingInstaller::default());
+    let ctx = context(installer.clone(), Some("all"));
+    let result = llvm_runtime().install_impl("20.1.0", &ctx).await.unwrap();
+
+    assert_eq!(last_include(&installer), None);
+    assert!(!ctx.fs.exists(&result.install_path.join(".vx-components")));
+}
+
+#[tokio::test]
+async fn test_unknown_component_is_rejected() {
+    let installer = Arc::new(RecordingInstaller::default());
+    let ctx = context(installer.clone(), Some("lldb"));
+    let err = llvm_runtime()
+        .install_impl("20.1.0", &ctx)
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("Unknown llvm component 'lldb'"), "{}", err);
+    assert!(
+        err.contains("clang, clang-format, clang-tidy, all"),
+        "{}",
+        err
+    );
+    assert!(installer.downloads.lock().unwrap().is_empty());
+}
@@ -0,0 +1,46 @@
+//! Tests for preset provenance in vx.lock
+
+use std::collections::HashSet;
+use vx_resolver::{LockFile, LockedPreset, LockedTool};
+
+fn python_ml() -> LockedPreset {
+    LockedPreset::new(
+        "github:acme/vx-presets",
+        "https://raw.githubusercontent.com/acme/vx-presets/main/presets/python-ml.toml",
+        "9f2c",
+    )
+}
+
+#[test]
+fn test_presets_round_trip() {
+    let preset = python_ml();
+    let mut lock = LockFile::new();
+    lock.lock_tool("python", LockedTool::new("3.12.8", "python"));
+    lock.lock_preset("python-ml", preset.clone());
+
+    let content = lock.to_string().unwrap();
+    assert!(content.contains("[presets.python-ml]"), "{}", content);
+
+    let parsed = LockFile::parse(&content).unwrap();
+    assert_eq!(parsed.presets.get("python-ml"), Some(&preset));
+}
+
+#[test]
+fn test_presets_section_is_omitted_when_empty() {
+    let mut lock = LockFile::new();
+    lock.lock_tool("node", LockedTool::new("22.12.0", "nodejs.org"));
+    assert!(!lock.to_string().unwrap().contains("presets"));
+}
+
+#[test]
+fn test_prune_and_merge_keep_presets() {
+    let mut lock = LockFile::new();
+    lock.lock_tool("bun", LockedTool::new("1.3.8", "bun"));
+    lock.lock_preset("python-ml", python_ml());
+    lock.prune(&HashSet::new());
+    assert!(lock.presets.contains_key("python-ml"));
+
+    let mut other = LockFile::new();
+    other.merge(&lock);
+    assert!(other.presets.contains_key("python-ml"));
+}
@@ -17,8 +17,8 @@ use vx_resolver::{
     ResolverConfig, RuntimeMap, Stage,
 };
 use vx_runtime::{
-    ExecutionContext, ExecutionPrep, InstallResult, Provider, ProviderRegistry, Runtime,
-    RuntimeContext, VersionInfo, mock_context,
+    ExecutionContext, ExecutionPrep, InstallResult, MockPathProvider, Provider, ProviderRegistry,
+    Runtime, RuntimeContext, VersionInfo, mock_context,
 };
 
 struct BundledRuntime {
@@ -341,3 +341,75 @@ async fn prepare_stage_skips_broken_companion_with_install_options_without_repai
     assert_eq!(prepare_count.load(Ordering::SeqCst), 0);
     assert!(!prepared.env.contains_key("VX_COMPANION_MARKER"));
 }
+
+#[tokio::test]
+async fn prepare_stage_applies_tool_exec_config() {
+    let config = ResolverConfig::default();
+    let resolver =
+        Resolver::new(config.clone(), RuntimeMap::empty()).expect("resolver should build");
+
+    let exec = vx_config::ToolExecConfig {
+        args: vec!["--max-old-space-size=4096".to_string()],
+        env: HashMap::from([("NODE_ENV".to_string(), "production".to_string())]),
+        ..Default::default()
+    };
+    let project_config = ProjectToolsConfig::from_tools_with_exec(
+        HashMap::from([("node".to_string(), "20.0.0".to_string())]),
+        HashMap::from([("node".to_string(), exec)]),
+    );
+    let stage =
+        PrepareStage::new(&resolver, &config, None, None).with_project_config(&project_config);
+
+    let plan = ExecutionPlan::new(
+        PlannedRuntime::installed("node", "20.0.0".to_string(), PathBuf::from("/usr/bin/node")),
+        ExecutionConfig::with_args(vec!["app.js".to_string()]),
+    );
+    let prepared = stage.execute(plan).await.expect("prepare should succeed");
+    assert_eq!(prepared.args, vec!["--max-old-space-size=4096", "app.js"]);
+    assert_eq!(
+        prepared.env.get("NODE_ENV").map(String::as_str),
+        Some("production")
+    );
+
+    // Other tools are unaffected
+    let plan = ExecutionPlan::new(
+        PlannedRuntime::installed("git", "2.51.0".to_string(), PathBuf::from("/usr/bin/git")),
+        ExecutionConfig::with_args(vec!["status".to_string()]),
+    );
+    let prepared = stage.execute(plan).await.expect("prepare should succeed");
+    assert_eq!(prepared.args, vec!["status"]);
+}
+
+#[tokio::test]
+async fn prepare_stage_sets_java_home_from_installed_jdk_dependency() {
+    let temp_dir = tempdir().expect("temp dir should be created");
+    let jdk_home = temp_dir.path().join("store").join("java").join("21.0.5");
+    fs::create_dir_all(jdk_home.join("bin")).expect("jdk bin dir should be created");
+    fs::write(
+        jdk_home
+            .join("bin")
+            .join(format!("java{}", std::env::consts::EXE_SUFFIX)),
+        "",
+    )
+    .expect("mock java should be created");
+
+    let config = ResolverConfig::default();
+    let resolver =
+        Resolver::new(config.clone(), RuntimeMap::empty()).expect("resolver should build");
+    let mut context = mock_context();
+    context.paths = Arc::new(MockPathProvider::new(temp_dir.path()));
+    let stage = PrepareStage::new(&resolver, &config, None, Some(&context));
+
+    let mut plan = ExecutionPlan::new(
+        PlannedRuntime::installed("maven", "3.9.9".to_string(), PathBuf::from("/usr/bin/mvn")),
+        ExecutionConfig::default(),
+    );
+    plan.dependencies
+        .push(PlannedRuntime::needs_install("java", "21.0.5".to_string()));
+
+    let prepared = stage.execute(plan).await.expect("prepare should succeed");
+    assert_eq!(
+        prepared.env.get("JAVA_HOME").map(PathBuf::from),
+        Some(jdk_home)
+    );
+}
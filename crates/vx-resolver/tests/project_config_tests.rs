@@ -268,3 +268,51 @@ fn test_locked_tool_names() {
 
     assert_eq!(locked, vec!["go", "node"]);
 }
+
+// =============================================================================
+// Version files (.nvmrc, .python-version, .tool-versions, ...)
+// =============================================================================
+
+#[rstest]
+fn test_load_from_version_files_below_vx_toml() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("vx.toml"), "[tools]\nnode = \"22\"\n").unwrap();
+    std::fs::write(dir.path().join(".nvmrc"), "v20.11.0\n").unwrap();
+    std::fs::write(dir.path().join(".python-version"), "3.12.1\n").unwrap();
+    std::fs::write(
+        dir.path().join(".tool-versions"),
+        "python 3.11.0\ngolang 1.22.0\n",
+    )
+    .unwrap();
+
+    let config = ProjectToolsConfig::load_from(dir.path()).unwrap();
+
+    // vx.toml pins node, so .nvmrc is ignored
+    assert_eq!(config.get_version("node"), Some("22"));
+    assert!(config.version_file("node").is_none());
+    // .python-version beats .tool-versions
+    assert_eq!(config.get_version("python"), Some("3.12.1"));
+    assert_eq!(
+        config.version_file("pip").unwrap().file_name(),
+        ".python-version"
+    );
+    assert_eq!(config.get_version("go"), Some("1.22.0"));
+    // Version file pins don't become companion tools
+    assert!(config.get_companion_tools("node").is_empty());
+}
+
+#[rstest]
+fn test_load_from_version_files_without_vx_toml() {
+    let dir = tempfile::tempdir().unwrap();
+    let sub = dir.path().join("packages/web");
+    std::fs::create_dir_all(&sub).unwrap();
+    std::fs::write(dir.path().join(".tool-versions"), "nodejs 18.19.0\n").unwrap();
+    std::fs::write(sub.join(".node-version"), "20\n").unwrap();
+
+    // The nearest file wins
+    let config = ProjectToolsConfig::load_from(&sub).unwrap();
+    assert_eq!(config.get_version_with_fallback("npm"), Some("20"));
+
+    let config = ProjectToolsConfig::load_from(dir.path()).unwrap();
+    assert_eq!(config.get_version("node"), Some("18.19.0"));
+}
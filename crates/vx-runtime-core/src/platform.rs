@@ -13,11 +13,22 @@ pub enum Libc {
 }
 
 impl Libc {
+    /// Environment variable that overrides detection (`musl` or `gnu`)
+    pub const ENV_VAR: &'static str = "VX_LIBC";
+
     /// Detect the current libc implementation at runtime
+    ///
+    /// `VX_LIBC` wins; otherwise a Linux host is musl when `/etc/os-release`
+    /// names Alpine, or when its only dynamic linker is musl's
+    /// (`/lib/ld-musl-*.so.1`). glibc hosts with musl-tools installed have
+    /// both linkers and stay glibc.
     pub fn current() -> Self {
-        // First check if we're in a musl environment via environment variable
-        if std::env::var("VX_LIBC").ok().as_deref() == Some("musl") {
-            return Libc::Musl;
+        // Explicit override, e.g. `settings.libc` in vx.toml or a container
+        if let Some(libc) = std::env::var(Self::ENV_VAR)
+            .ok()
+            .and_then(|v| Self::parse(&v))
+        {
+            return libc;
         }
 
         // Only relevant for Linux
@@ -25,23 +36,42 @@ impl Libc {
             return Libc::Gnu;
         }
 
-        // Check /etc/os-release for Alpine (uses musl)
-        if let Ok(content) = std::fs::read_to_string("/etc/os-release") {
-            let content_lower = content.to_lowercase();
-            if content_lower.contains("alpine") {
-                return Libc::Musl;
-            }
+        let os_release = std::fs::read_to_string("/etc/os-release").ok();
+        let loaders: Vec<String> = ["/lib", "/lib64"]
+            .iter()
+            .filter_map(|dir| std::fs::read_dir(dir).ok())
+            .flat_map(|entries| entries.flatten())
+            .map(|e| e.file_name().to_string_lossy().into_owned())
+            .filter(|name| name.starts_with("ld-"))
+            .collect();
+        let musl_loader_only = loaders.iter().any(|name| name.starts_with("ld-musl-"))
+            && !loaders.iter().any(|name| name.starts_with("ld-linux"));
+        Self::detect(os_release.as_deref(), musl_loader_only)
+    }
+
+    /// Libc of a Linux host from its `/etc/os-release` and whether musl's is
+    /// the only dynamic linker installed
+    pub fn detect(os_release: Option<&str>, musl_loader_only: bool) -> Self {
+        let alpine = os_release.is_some_and(|content| {
+            content.lines().any(|line| {
+                let line = line.trim().to_lowercase();
+                (line.starts_with("id=") || line.starts_with("id_like=")) && line.contains("alpine")
+            })
+        });
+        if alpine || musl_loader_only {
+            Libc::Musl
+        } else {
+            Libc::Gnu
         }
+    }
 
-        // Check if /lib/ld-musl-*.so.1 exists (musl dynamic linker)
-        if std::path::Path::new("/lib/ld-musl-x86_64.so.1").exists()
-            || std::path::Path::new("/lib/ld-musl-aarch64.so.1").exists()
-        {
-            return Libc::Musl;
+    /// Parse `musl`, `gnu` or `glibc`
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "musl" => Some(Libc::Musl),
+            "gnu" | "glibc" => Some(Libc::Gnu),
+            _ => None,
         }
-
-        // Default to GNU libc
-        Libc::Gnu
     }
 
     /// Get the libc suffix for Rust target triples
@@ -0,0 +1,507 @@
+//! Resumable downloads with retry and mirror failover
+//!
+//! Downloads are streamed into a partial file under `~/.vx/cache/partials`
+//! (keyed by the original URL) and only moved to their destination once
+//! complete. When a transfer fails or stalls, the next attempt resumes from
+//! the partial file with an HTTP `Range` request; `If-Range` makes the server
+//! send the whole file again if it changed in between.
+//!
+//! With CDN acceleration enabled the CDN URL is tried first, and a failing or
+//! stalled mirror fails over to the original URL.
+//!
+//! While a cassette is active (`VX_RECORD` / `VX_REPLAY`), completed
+//! downloads are recorded into it, or written from it without a request.
+
+use crate::http_client::{HttpError, RealHttpClient};
+use anyhow::Result;
+use backon::{BackoffBuilder, ExponentialBuilder};
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tokio::io::AsyncWriteExt;
+use vx_cache::DownloadAttempt;
+use vx_cache::cassette::{self, Cassette, CassetteMode, HttpInteraction};
+
+/// Retry policy for downloads
+///
+/// The defaults can be overridden with `VX_DOWNLOAD_RETRIES` (number of
+/// retries) and `VX_DOWNLOAD_STALL_TIMEOUT` (seconds without data before a
+/// transfer is considered stalled).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt
+    pub max_retries: usize,
+    /// Delay before the first retry
+    pub min_delay: Duration,
+    /// Upper bound for the exponential backoff delay
+    pub max_delay: Duration,
+    /// Randomize delays so parallel downloads don't retry in lockstep
+    pub jitter: bool,
+    /// Abort a transfer that receives no data for this long
+    pub stall_timeout: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            min_delay: Duration::from_secs(2),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            stall_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Default policy with overrides from the environment
+    pub fn from_env() -> Self {
+        let mut policy = Self::default();
+        if let Some(retries) = env_u64("VX_DOWNLOAD_RETRIES") {
+            policy.max_retries = retries as usize;
+        }
+        if let Some(secs) = env_u64("VX_DOWNLOAD_STALL_TIMEOUT").filter(|s| *s > 0) {
+            policy.stall_timeout = Duration::from_secs(secs);
+        }
+        policy
+    }
+
+    /// Set the number of retries after the first attempt
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the backoff delay range
+    pub fn with_delays(mut self, min_delay: Duration, max_delay: Duration) -> Self {
+        self.min_delay = min_delay;
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Enable or disable jitter
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Set the stall timeout
+    pub fn with_stall_timeout(mut self, stall_timeout: Duration) -> Self {
+        self.stall_timeout = stall_timeout;
+        self
+    }
+
+    /// Delays to wait before each retry (one per retry)
+    pub fn delays(&self) -> impl Iterator<Item = Duration> + Send + use<> {
+        let builder = ExponentialBuilder::default()
+            .with_min_delay(self.min_delay)
+            .with_max_delay(self.max_delay)
+            .with_max_times(self.max_retries);
+        if self.jitter {
+            builder.with_jitter().build()
+        } else {
+            builder.build()
+        }
+    }
+}
+
+fn env_u64(key: &str) -> Option<u64> {
+    std::env::var(key).ok()?.trim().parse().ok()
+}
+
+/// Progress of a running download
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DownloadProgress {
+    /// Total size in bytes (0 if unknown)
+    pub total: u64,
+    /// Bytes downloaded so far, including resumed bytes
+    pub downloaded: u64,
+    /// Whether the data comes from a CDN mirror
+    pub via_cdn: bool,
+}
+
+/// Details of the response that completed a download
+#[derive(Debug, Clone)]
+pub(crate) struct DownloadOutcome {
+    /// Final URL after redirects
+    pub final_url: String,
+    /// `Content-Disposition` header, if any
+    pub content_disposition: Option<String>,
+    /// Time until the response headers arrived
+    pub latency: Duration,
+    /// Bytes received by the completing attempt
+    pub transferred: u64,
+}
+
+/// Validators recorded next to a partial file
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PartialMeta {
+    /// URL the partial data was downloaded from
+    source: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+/// A partially downloaded file in the partials directory
+#[derive(Debug)]
+pub(crate) struct PartialFile {
+    path: PathBuf,
+    meta_path: PathBuf,
+}
+
+impl PartialFile {
+    /// Partial file for `url` in `dir`
+    pub fn new(dir: &Path, url: &str) -> Self {
+        let key = vx_cache::DownloadCache::cache_key(url);
+        Self {
+            path: dir.join(format!("{}.part", key)),
+            meta_path: dir.join(format!("{}.json", key)),
+        }
+    }
+
+    /// Path of the partial data
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Number of bytes already downloaded
+    pub fn len(&self) -> u64 {
+        std::fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0)
+    }
+
+    fn meta(&self) -> Option<PartialMeta> {
+        let content = std::fs::read_to_string(&self.meta_path).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    fn save_meta(&self, meta: &PartialMeta) -> std::io::Result<()> {
+        if let Some(parent) = self.meta_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.meta_path, serde_json::to_vec(meta)?)
+    }
+
+    /// Remove the partial data and its metadata
+    pub fn discard(&self) {
+        let _ = std::fs::remove_file(&self.path);
+        let _ = std::fs::remove_file(&self.meta_path);
+    }
+
+    /// Move the completed download to `dest`
+    fn finish(&self, dest: &Path) -> Result<()> {
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Rename fails across filesystems; fall back to copying
+        if std::fs::rename(&self.path, dest).is_err() {
+            std::fs::copy(&self.path, dest)?;
+        }
+        self.discard();
+        Ok(())
+    }
+}
+
+impl RealHttpClient {
+    /// Download `url` to `dest`, resuming and retrying according to the retry policy
+    pub(crate) async fn download_resumable(
+        &self,
+        url: &str,
+        dest: &Path,
+        on_progress: &mut (dyn FnMut(&DownloadProgress) + Send),
+    ) -> Result<DownloadOutcome> {
+        let cassette = cassette::active();
+        if let Some(cassette) = cassette.filter(|c| c.mode() == CassetteMode::Replay) {
+            return replay_download(cassette, url, dest, on_progress);
+        }
+        let outcome = self.download_with_retries(url, dest, on_progress).await?;
+        if let Some(cassette) = cassette {
+            record_download(cassette, url, dest, &outcome);
+        }
+        Ok(outcome)
+    }
+
+    async fn download_with_retries(
+        &self,
+        url: &str,
+        dest: &Path,
+        on_progress: &mut (dyn FnMut(&DownloadProgress) + Send),
+    ) -> Result<DownloadOutcome> {
+        let download_url = self.optimize_url(url).await;
+        let mut sources = vec![download_url];
+        if sources[0] != url {
+            tracing::info!(
+                original = url,
+                optimized = %sources[0],
+                "Using CDN accelerated URL"
+            );
+            sources.push(url.to_string());
+        }
+
+        let partial = PartialFile::new(&self.partials_dir(), url);
+        let mut delays = self.retry_policy.delays();
+        let mut source = 0;
+
+        loop {
+            let source_url = &sources[source];
+            let via_cdn = source_url != url;
+            let started = Instant::now();
+            let result = self
+                .fetch_into_partial(source_url, via_cdn, &partial, on_progress)
+                .await;
+            self.record_attempt(source_url, started, &result);
+            match result {
+                Ok(outcome) => {
+                    partial.finish(dest)?;
+                    return Ok(outcome);
+                }
+                Err(err) if source + 1 < sources.len() => {
+                    tracing::warn!(
+                        cdn_url = %source_url,
+                        error = %err,
+                        original_url = url,
+                        "CDN download failed, falling back to original URL"
+                    );
+                    source += 1;
+                }
+                Err(err) if err.is_retryable => match delays.next() {
+                    Some(delay) => {
+                        tracing::warn!(
+                            error = %err,
+                            retry_in = ?delay,
+                            resume_from = partial.len(),
+                            url = url,
+                            "Retrying download after transient error"
+                        );
+                        tokio::time::sleep(delay).await;
+                    }
+                    None => return Err(anyhow::anyhow!("{}", err)),
+                },
+                Err(err) => return Err(anyhow::anyhow!("{}", err)),
+            }
+        }
+    }
+
+    /// Add one attempt to the per-source download statistics
+    fn record_attempt(
+        &self,
+        source: &str,
+        started: Instant,
+        result: &std::result::Result<DownloadOutcome, HttpError>,
+    ) {
+        let Some(stats) = self.network_stats() else {
+            return;
+        };
+        let elapsed = started.elapsed();
+        let attempt = match result {
+            Ok(outcome) => DownloadAttempt::Success {
+                latency: outcome.latency,
+                elapsed,
+                bytes: outcome.transferred,
+            },
+            Err(err) => DownloadAttempt::Failure {
+                elapsed,
+                error: err.to_string(),
+            },
+        };
+        stats.record(source, &attempt);
+    }
+
+    /// Run a single transfer from `source`, appending to the partial file when possible
+    async fn fetch_into_partial(
+        &self,
+        source: &str,
+        via_cdn: bool,
+        partial: &PartialFile,
+        on_progress: &mut (dyn FnMut(&DownloadProgress) + Send),
+    ) -> std::result::Result<DownloadOutcome, HttpError> {
+        use reqwest::StatusCode;
+        use reqwest::header::{CONTENT_DISPOSITION, ETAG, IF_RANGE, LAST_MODIFIED, RANGE};
+
+        let offset = partial.len();
+        let mut request = vx_proxy::credentials::authorize(self.client.get(source), source);
+        if offset > 0 {
+            request = request.header(RANGE, format!("bytes={}-", offset));
+            // Validators only apply to the source they were recorded from
+            if let Some(meta) = partial.meta().filter(|m| m.source == source)
+                && let Some(validator) = meta.etag.or(meta.last_modified)
+            {
+                request = request.header(IF_RANGE, validator);
+            }
+        }
+
+        let started = Instant::now();
+        let response = request
+            .send()
+            .await
+            .map_err(|e| HttpError::retryable(format!("Download failed for {}: {}", source, e)))?;
+        let latency = started.elapsed();
+
+        let status = response.status();
+        let resumed = match status {
+            StatusCode::PARTIAL_CONTENT if offset > 0 => true,
+            s if s.is_success() => false,
+            StatusCode::RANGE_NOT_SATISFIABLE => {
+                partial.discard();
+                return Err(HttpError::retryable(format!(
+                    "Cannot resume download of {}, restarting",
+                    source
+                )));
+            }
+            _ => return Err(HttpError::from_status(status, source)),
+        };
+        if offset > 0 && !resumed {
+            tracing::debug!(
+                url = source,
+                "Server sent the full file, restarting download"
+            );
+        }
+
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        };
+        let meta = PartialMeta {
+            source: source.to_string(),
+            etag: header(ETAG),
+            last_modified: header(LAST_MODIFIED),
+        };
+        let content_disposition = header(CONTENT_DISPOSITION);
+        let final_url = response.url().to_string();
+
+        let io_error = |e: std::io::Error| {
+            HttpError::non_retryable(format!("Failed to write download: {}", e))
+        };
+        partial.save_meta(&meta).map_err(io_error)?;
+
+        let start = if resumed { offset } else { 0 };
+        let total = response.content_length().map_or(0, |len| start + len);
+        let mut file = if resumed {
+            tokio::fs::OpenOptions::new()
+                .append(true)
+                .open(partial.path())
+                .await
+        } else {
+            tokio::fs::File::create(partial.path()).await
+        }
+        .map_err(io_error)?;
+
+        let mut progress = DownloadProgress {
+            total,
+            downloaded: start,
+            via_cdn,
+        };
+        on_progress(&progress);
+
+        let stall_timeout = self.retry_policy.stall_timeout;
+        let mut stream = response.bytes_stream();
+        let transfer = loop {
+            let chunk = match tokio::time::timeout(stall_timeout, stream.next()).await {
+                Err(_) => {
+                    break Err(HttpError::retryable(format!(
+                        "Download stalled: no data from {} for {}s",
+                        source,
+                        stall_timeout.as_secs()
+                    )));
+                }
+                Ok(None) => break Ok(()),
+                Ok(Some(Err(e))) => {
+                    break Err(HttpError::retryable(format!(
+                        "Download interrupted for {}: {}",
+                        source, e
+                    )));
+                }
+                Ok(Some(Ok(chunk))) => chunk,
+            };
+            if let Err(e) = file.write_all(&chunk).await {
+                break Err(io_error(e));
+            }
+            progress.downloaded += chunk.len() as u64;
+            on_progress(&progress);
+        };
+
+        // Flush before returning so the next attempt resumes from the real length
+        file.flush().await.map_err(io_error)?;
+        transfer?;
+
+        if total > 0 && progress.downloaded < total {
+            return Err(HttpError::retryable(format!(
+                "Download of {} ended early ({} of {} bytes)",
+                source, progress.downloaded, total
+            )));
+        }
+
+        Ok(DownloadOutcome {
+            final_url,
+            content_disposition,
+            latency,
+            transferred: progress.downloaded - start,
+        })
+    }
+}
+
+/// Write the recorded response for `url` to `dest`
+fn replay_download(
+    cassette: &Cassette,
+    url: &str,
+    dest: &Path,
+    on_progress: &mut (dyn FnMut(&DownloadProgress) + Send),
+) -> Result<DownloadOutcome> {
+    let interaction = cassette.replay_http("GET", url).ok_or_else(|| {
+        anyhow::anyhow!(
+            "{} is not recorded in cassette {}",
+            url,
+            cassette.path().display()
+        )
+    })?;
+    if !(200..300).contains(&interaction.status) {
+        anyhow::bail!("HTTP {} from {} (replayed)", interaction.status, url);
+    }
+    let body = interaction.body()?;
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(dest, &body)?;
+
+    let size = body.len() as u64;
+    on_progress(&DownloadProgress {
+        total: size,
+        downloaded: size,
+        via_cdn: false,
+    });
+    Ok(DownloadOutcome {
+        final_url: interaction
+            .final_url
+            .clone()
+            .unwrap_or_else(|| url.to_string()),
+        content_disposition: interaction
+            .header("content-disposition")
+            .map(str::to_string),
+        latency: Duration::ZERO,
+        transferred: size,
+    })
+}
+
+/// Add a completed download to the cassette being recorded
+fn record_download(cassette: &Cassette, url: &str, dest: &Path, outcome: &DownloadOutcome) {
+    let body = match std::fs::read(dest) {
+        Ok(body) => body,
+        Err(e) => {
+            tracing::warn!(url, error = %e, "Failed to read download for the cassette");
+            return;
+        }
+    };
+    let headers = outcome
+        .content_disposition
+        .iter()
+        .map(|value| ("content-disposition".to_string(), value.clone()))
+        .collect();
+    let interaction =
+        HttpInteraction::new("GET", url, 200, headers, &body).with_final_url(&outcome.final_url);
+    if let Err(e) = cassette.record_http(interaction) {
+        tracing::warn!(url, error = %e, "Failed to record download");
+    }
+}
@@ -0,0 +1,79 @@
+//! Partial archive extraction
+//!
+//! Toolchain archives such as LLVM's ship gigabytes of static libraries and
+//! headers next to the handful of executables most projects use. A provider
+//! layout can split the archive into named components; the installer then
+//! receives the selected components' path patterns in the `include` layout
+//! key and only writes matching entries.
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+
+/// Selects the archive entries to extract
+#[derive(Debug, Clone)]
+pub struct ExtractFilter {
+    /// Leading path segments to ignore (the `strip_prefix` directory)
+    skip: usize,
+    patterns: Vec<glob::Pattern>,
+}
+
+impl ExtractFilter {
+    /// Build a filter from glob patterns relative to the archive root
+    ///
+    /// `*` also matches `/`, so `lib/clang/*` selects the whole directory.
+    pub fn new<S: AsRef<str>>(patterns: &[S]) -> Result<Self> {
+        let patterns = patterns
+            .iter()
+            .map(|p| {
+                glob::Pattern::new(p.as_ref())
+                    .with_context(|| format!("Invalid include pattern '{}'", p.as_ref()))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { skip: 0, patterns })
+    }
+
+    /// Match patterns below the `strip_prefix` directory instead of the archive root
+    pub fn below(mut self, strip_prefix: &str) -> Self {
+        self.skip = segments(strip_prefix).count();
+        self
+    }
+
+    /// Filter described by download layout metadata
+    ///
+    /// Reads the comma-separated `include` patterns, relative to
+    /// `strip_prefix` when one is set. Returns `None` when every entry is
+    /// extracted.
+    pub fn from_layout(metadata: &HashMap<String, String>) -> Result<Option<Self>> {
+        let Some(include) = metadata.get("include") else {
+            return Ok(None);
+        };
+        let patterns: Vec<&str> = include
+            .split(',')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .collect();
+        if patterns.is_empty() {
+            return Ok(None);
+        }
+        let filter = Self::new(&patterns)?;
+        Ok(Some(match metadata.get("strip_prefix") {
+            Some(prefix) => filter.below(prefix),
+            None => filter,
+        }))
+    }
+
+    /// Whether the archive entry at `path` is extracted
+    pub fn matches(&self, path: &str) -> bool {
+        let rest: Vec<&str> = segments(path).skip(self.skip).collect();
+        if rest.is_empty() {
+            return false;
+        }
+        let rest = rest.join("/");
+        self.patterns.iter().any(|p| p.matches(&rest))
+    }
+}
+
+fn segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split(['/', '\\'])
+        .filter(|s| !s.is_empty() && *s != ".")
+}
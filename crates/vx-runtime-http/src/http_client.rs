@@ -1,9 +1,11 @@
 //! Real HTTP client implementation
 
+use crate::download::{DownloadProgress, RetryPolicy};
 use anyhow::Result;
 use async_trait::async_trait;
 use backon::{ExponentialBuilder, Retryable};
-use std::path::Path;
+use indicatif::{ProgressBar, ProgressStyle};
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 use vx_runtime::HttpClient;
 
@@ -44,6 +46,16 @@ pub struct RealHttpClient {
     cdn_enabled: bool,
     /// Download cache for avoiding re-downloads
     pub(crate) download_cache: Option<vx_cache::DownloadCache>,
+    /// Retry policy for downloads
+    pub(crate) retry_policy: RetryPolicy,
+    /// Directory for partial downloads (default: `~/.vx/cache/partials`)
+    partials_dir: Option<PathBuf>,
+    /// LAN peer cache settings (default: loaded from the user config)
+    peer_config: Option<vx_proxy::PeerConfig>,
+    /// Remote shared cache settings (default: loaded from the user config)
+    remote_cache: Option<vx_proxy::RemoteCacheConfig>,
+    /// Per-source download statistics (default: `~/.vx/cache/network`)
+    network_stats: Option<vx_cache::NetworkStats>,
 }
 
 impl RealHttpClient {
@@ -66,6 +78,11 @@ impl RealHttpClient {
             client: Self::build_client(),
             cdn_enabled,
             download_cache: None,
+            retry_policy: RetryPolicy::from_env(),
+            partials_dir: None,
+            peer_config: None,
+            remote_cache: None,
+            network_stats: None,
         }
     }
 
@@ -75,6 +92,11 @@ impl RealHttpClient {
             client: Self::build_client(),
             cdn_enabled: cdn_enabled && cfg!(feature = "cdn-acceleration"),
             download_cache: None,
+            retry_policy: RetryPolicy::from_env(),
+            partials_dir: None,
+            peer_config: None,
+            remote_cache: None,
+            network_stats: None,
         }
     }
 
@@ -85,7 +107,7 @@ impl RealHttpClient {
         read_timeout: Duration,
     ) -> Self {
         Self {
-            client: reqwest::Client::builder()
+            client: vx_proxy::client_builder()
                 .user_agent(format!("vx/{}", env!("CARGO_PKG_VERSION")))
                 .connect_timeout(connect_timeout)
                 .read_timeout(read_timeout)
@@ -95,14 +117,21 @@ impl RealHttpClient {
                 .expect("Failed to create HTTP client"),
             cdn_enabled: cdn_enabled && cfg!(feature = "cdn-acceleration"),
             download_cache: None,
+            retry_policy: RetryPolicy::from_env(),
+            partials_dir: None,
+            peer_config: None,
+            remote_cache: None,
+            network_stats: None,
         }
     }
 
     /// Build the default reqwest client
     fn build_client() -> reqwest::Client {
-        reqwest::Client::builder()
+        let connect_timeout =
+            vx_proxy::NetworkConfig::load().connect_timeout_or(Duration::from_secs(30));
+        vx_proxy::client_builder()
             .user_agent(format!("vx/{}", env!("CARGO_PKG_VERSION")))
-            .connect_timeout(Duration::from_secs(30))
+            .connect_timeout(connect_timeout)
             .read_timeout(Duration::from_secs(60))
             .pool_idle_timeout(Duration::from_secs(90))
             .pool_max_idle_per_host(10)
@@ -111,11 +140,94 @@ impl RealHttpClient {
     }
 
     /// Enable download caching with the specified cache directory
+    ///
+    /// Partial downloads are kept in `<cache_dir>/partials` and download
+    /// statistics in `<cache_dir>/network`.
+    ///
+    /// While a cassette is recorded or replayed, downloads skip the cache so
+    /// every one of them goes through the cassette.
     pub fn with_download_cache(mut self, cache_dir: std::path::PathBuf) -> Self {
-        self.download_cache = Some(vx_cache::DownloadCache::new(cache_dir));
+        self.partials_dir = Some(cache_dir.join("partials"));
+        self.network_stats = Some(vx_cache::NetworkStats::new(cache_dir.clone()));
+        if vx_cache::cassette::active().is_none() {
+            self.download_cache = Some(vx_cache::DownloadCache::new(cache_dir));
+        }
+        self
+    }
+
+    /// Set the retry policy for downloads
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Set the directory where interrupted downloads are kept for resuming
+    pub fn with_partials_dir(mut self, dir: PathBuf) -> Self {
+        self.partials_dir = Some(dir);
+        self
+    }
+
+    /// Record download statistics under `<cache_dir>/network`
+    pub fn with_network_stats(mut self, cache_dir: PathBuf) -> Self {
+        self.network_stats = Some(vx_cache::NetworkStats::new(cache_dir));
         self
     }
 
+    /// Per-source download statistics, if a cache directory is known
+    pub(crate) fn network_stats(&self) -> Option<vx_cache::NetworkStats> {
+        self.network_stats.clone().or_else(|| {
+            vx_paths::VxPaths::new()
+                .ok()
+                .map(|p| vx_cache::NetworkStats::new(p.cache_dir))
+        })
+    }
+
+    /// Set the LAN peer cache settings instead of loading them from the user config
+    pub fn with_peer_config(mut self, config: vx_proxy::PeerConfig) -> Self {
+        self.peer_config = Some(config);
+        self
+    }
+
+    /// LAN peer cache settings (disabled while a cassette is active)
+    pub(crate) fn peer_config(&self) -> vx_proxy::PeerConfig {
+        if vx_cache::cassette::active().is_some() {
+            return vx_proxy::PeerConfig::default();
+        }
+        self.peer_config
+            .clone()
+            .unwrap_or_else(vx_proxy::PeerConfig::load)
+    }
+
+    /// Set the remote shared cache settings instead of loading them from the user config
+    pub fn with_remote_cache(mut self, config: vx_proxy::RemoteCacheConfig) -> Self {
+        self.remote_cache = Some(config);
+        self
+    }
+
+    /// Remote shared cache settings
+    pub(crate) fn remote_cache_config(&self) -> vx_proxy::RemoteCacheConfig {
+        self.remote_cache
+            .clone()
+            .unwrap_or_else(vx_proxy::RemoteCacheConfig::load)
+    }
+
+    /// Get the retry policy for downloads
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Directory for partial downloads
+    pub(crate) fn partials_dir(&self) -> PathBuf {
+        self.partials_dir
+            .clone()
+            .or_else(|| {
+                vx_paths::VxPaths::new()
+                    .ok()
+                    .map(|p| p.cache_dir.join("partials"))
+            })
+            .unwrap_or_else(|| std::env::temp_dir().join("vx-partials"))
+    }
+
     /// Check if CDN acceleration is enabled
     pub fn is_cdn_enabled(&self) -> bool {
         self.cdn_enabled
@@ -188,18 +300,22 @@ impl RealHttpClient {
                 .header("X-GitHub-Api-Version", "2022-11-28");
         }
 
-        // Add GitHub token for GitHub API requests
-        if (url.contains("api.github.com") || url.contains("github.com"))
-            && let Some(token) = get_github_token()
-        {
-            request = request.header("Authorization", format!("Bearer {}", token));
+        // OCI registries only return image indexes (e.g. Homebrew bottles) on request
+        if url.contains("/v2/") && url.contains("/manifests/") {
+            request = request.header("Accept", vx_installer::bottle::INDEX_MEDIA_TYPE);
         }
 
-        let response = request.send().await.map_err(|e| {
+        // GitHub token or stored registry credentials
+        request = vx_proxy::credentials::authorize(request, url);
+
+        // Answered from the shared HTTP cache when still fresh or unchanged
+        let response = vx_proxy::http_cache::send(request).await.map_err(|e| {
             if e.is_timeout() {
                 HttpError::retryable(format!("Request timed out for {}: {}", url, e))
             } else if e.is_connect() {
                 HttpError::retryable(format!("Connection failed for {}: {}", url, e))
+            } else if e.is_body() || e.is_decode() {
+                HttpError::retryable(format!("Error reading response body from {}: {}", url, e))
             } else {
                 HttpError::non_retryable(format!("Request failed: {}", e))
             }
@@ -225,7 +341,7 @@ impl RealHttpClient {
 
             if remaining == Some(0) {
                 return Err(HttpError::non_retryable(
-                    "GitHub API rate limit exceeded. Set GITHUB_TOKEN or GH_TOKEN environment variable to increase limit (5000 requests/hour with token vs 60/hour without).",
+                    "GitHub API rate limit exceeded. Run `vx auth login github` or set GITHUB_TOKEN to increase the limit (5000 requests/hour with token vs 60/hour without).",
                 ));
             }
         }
@@ -256,13 +372,13 @@ impl RealHttpClient {
                 401 | 403 => {
                     format!(
                         "Access denied (HTTP {}): {}\n\n\
-                        Try setting GITHUB_TOKEN or GH_TOKEN environment variable for authentication.",
+                        Run `vx auth login github` (or `vx auth login registry <url>`) to authenticate.",
                         status.as_u16(),
                         url
                     )
                 }
                 _ => {
-                    let body = response.text().await.unwrap_or_default();
+                    let body = response.text();
                     // Don't show HTML content, it's not useful
                     if body.trim_start().starts_with('<') {
                         format!("HTTP {} for {}", status, url)
@@ -284,24 +400,12 @@ impl RealHttpClient {
             };
         }
 
-        // Be tolerant to broken/missing Content-Type headers (some proxies misbehave).
-        // `reqwest::Response::json()` rejects non-JSON content-types; we parse from bytes instead.
-        let bytes = response.bytes().await.map_err(|e| {
-            // Check if this is a timeout error while reading the response body
-            if e.is_timeout() {
-                HttpError::retryable(format!(
-                    "Timeout while reading response body from {}: {}",
-                    url, e
-                ))
-            } else if e.is_body() || e.is_decode() {
-                HttpError::retryable(format!("Error reading response body from {}: {}", url, e))
-            } else {
-                HttpError::retryable(format!("Failed to read response body from {}: {}", url, e))
-            }
-        })?;
+        // Be tolerant to broken/missing Content-Type headers (some proxies misbehave):
+        // parse from bytes instead of relying on the declared content-type.
+        let bytes = response.bytes();
 
-        serde_json::from_slice::<serde_json::Value>(&bytes).map_err(|e| {
-            let body = String::from_utf8_lossy(&bytes);
+        serde_json::from_slice::<serde_json::Value>(bytes).map_err(|e| {
+            let body = String::from_utf8_lossy(bytes);
             let preview = if body.len() > 200 {
                 format!("{}...", &body[..200])
             } else {
@@ -387,45 +491,52 @@ impl RealHttpClient {
     }
 }
 
-impl Default for RealHttpClient {
-    fn default() -> Self {
-        Self::new()
+impl RealHttpClient {
+    /// Create a download progress bar registered to the global MultiProgress
+    ///
+    /// Registering it keeps it from interleaving with other progress bars or text output:
+    /// cpython-3.10.19-windows-x86_64-none (download) ━━━━━━━━━━━━━━ 1.47 MiB/21.49 MiB
+    pub(crate) fn download_progress_bar(
+        filename: &str,
+        progress: &DownloadProgress,
+    ) -> ProgressBar {
+        let pm = vx_console::global_progress_manager();
+        let cdn_suffix = if progress.via_cdn { " [CDN]" } else { "" };
+        if progress.total > 0 {
+            let pb = pm.multi().add(ProgressBar::new(progress.total));
+            pb.set_style(
+                ProgressStyle::with_template(&format!(
+                    "{filename}{cdn_suffix} (download) {{wide_bar:.cyan/blue}} {{bytes}}/{{total_bytes}}"
+                ))
+                .unwrap_or_else(|_| ProgressStyle::default_bar())
+                .progress_chars("━━╺"),
+            );
+            pb
+        } else {
+            let pb = pm.multi().add(ProgressBar::new_spinner());
+            pb.set_style(
+                ProgressStyle::with_template(&format!(
+                    "{{spinner:.green}} {filename}{cdn_suffix} (download) {{bytes}}"
+                ))
+                .unwrap_or_else(|_| ProgressStyle::default_spinner()),
+            );
+            pb.enable_steady_tick(std::time::Duration::from_millis(100));
+            pb
+        }
     }
 }
 
-/// Get GitHub token from environment variables or stored config
-/// Checks in order: GITHUB_TOKEN, GH_TOKEN, ~/.vx/config/github_token
-fn get_github_token() -> Option<String> {
-    // First check environment variables (highest priority)
-    if let Some(token) = std::env::var("GITHUB_TOKEN").ok().filter(|t| !t.is_empty()) {
-        return Some(token);
-    }
-
-    if let Some(token) = std::env::var("GH_TOKEN").ok().filter(|t| !t.is_empty()) {
-        return Some(token);
-    }
-
-    // Then check stored token file
-    if let Ok(paths) = vx_paths::VxPaths::new() {
-        let token_file = paths.config_dir.join("github_token");
-        if token_file.exists()
-            && let Ok(token) = std::fs::read_to_string(&token_file)
-        {
-            let token = token.trim();
-            if !token.is_empty() {
-                return Some(token.to_string());
-            }
-        }
+impl Default for RealHttpClient {
+    fn default() -> Self {
+        Self::new()
     }
-
-    None
 }
 
 /// HTTP error that can be retried
 #[derive(Debug)]
-struct HttpError {
+pub(crate) struct HttpError {
     message: String,
-    is_retryable: bool,
+    pub(crate) is_retryable: bool,
 }
 
 impl std::fmt::Display for HttpError {
@@ -437,20 +548,34 @@ impl std::fmt::Display for HttpError {
 impl std::error::Error for HttpError {}
 
 impl HttpError {
-    fn retryable(message: impl Into<String>) -> Self {
+    pub(crate) fn retryable(message: impl Into<String>) -> Self {
         Self {
             message: message.into(),
             is_retryable: true,
         }
     }
 
-    fn non_retryable(message: impl Into<String>) -> Self {
+    pub(crate) fn non_retryable(message: impl Into<String>) -> Self {
         Self {
             message: message.into(),
             is_retryable: false,
         }
     }
 
+    /// Download error for an unsuccessful HTTP status
+    pub(crate) fn from_status(status: reqwest::StatusCode, url: &str) -> Self {
+        let message = format!(
+            "Download failed: HTTP {} {} for {}",
+            status.as_u16(),
+            status.canonical_reason().unwrap_or(""),
+            url
+        );
+        Self {
+            message,
+            is_retryable: Self::is_retryable_status(status),
+        }
+    }
+
     /// Check if the HTTP status code indicates a retryable error
     fn is_retryable_status(status: reqwest::StatusCode) -> bool {
         matches!(
@@ -470,29 +595,20 @@ impl HttpClient for RealHttpClient {
         let client = self.client.clone();
 
         let result = (|| async {
-            let mut request = client.get(&url);
-
-            // Add GitHub token for GitHub API requests
-            if (url.contains("api.github.com") || url.contains("github.com"))
-                && let Some(token) = get_github_token()
-            {
-                request = request.header("Authorization", format!("Bearer {}", token));
-            }
+            // GitHub token or stored registry credentials
+            let request = vx_proxy::credentials::authorize(client.get(&url), &url);
 
-            let response = request.send().await.map_err(|e| {
+            let response = vx_proxy::http_cache::send(request).await.map_err(|e| {
                 if e.is_timeout() || e.is_connect() {
                     HttpError::retryable(format!("Network error: {}", e))
+                } else if e.is_body() || e.is_decode() {
+                    HttpError::non_retryable(format!("Failed to read response: {}", e))
                 } else {
                     HttpError::non_retryable(format!("Request failed: {}", e))
                 }
             })?;
 
-            let text = response
-                .text()
-                .await
-                .map_err(|e| HttpError::non_retryable(format!("Failed to read response: {}", e)))?;
-
-            Ok::<_, HttpError>(text)
+            Ok::<_, HttpError>(response.text())
         })
         .retry(Self::build_retry_strategy())
         .notify(|err: &HttpError, dur: Duration| {
@@ -519,122 +635,55 @@ impl HttpClient for RealHttpClient {
         result.map_err(|e| anyhow::anyhow!("{}", e))
     }
 
-    async fn download(&self, url: &str, dest: &Path) -> Result<()> {
-        use futures_util::StreamExt;
-        use indicatif::{ProgressBar, ProgressStyle};
-        use tokio::io::AsyncWriteExt;
-        use vx_console::global_progress_manager;
-
-        // Optimize URL with CDN if enabled
-        let download_url = self.optimize_url(url).await;
-        let using_cdn = download_url != url;
-        if using_cdn {
-            tracing::info!(
-                original = url,
-                optimized = %download_url,
-                "Using CDN accelerated URL"
-            );
-        }
+    async fn post_json(&self, url: &str, body: &serde_json::Value) -> Result<serde_json::Value> {
+        let url = url.to_string();
+        let client = self.client.clone();
 
-        let response = self.client.get(&download_url).send().await;
-
-        // If CDN URL failed, fallback to original URL
-        let (response, actual_using_cdn) = match response {
-            Ok(resp) if resp.status().is_success() => (resp, using_cdn),
-            Ok(resp) if using_cdn => {
-                tracing::warn!(
-                    cdn_url = %download_url,
-                    status = %resp.status(),
-                    original_url = url,
-                    "CDN download failed, falling back to original URL"
-                );
-                let fallback_resp = self.client.get(url).send().await?;
-                if !fallback_resp.status().is_success() {
-                    return Err(anyhow::anyhow!(
-                        "Download failed: HTTP {} for {}",
-                        fallback_resp.status(),
-                        url
-                    ));
-                }
-                (fallback_resp, false)
-            }
-            Ok(resp) => {
-                return Err(anyhow::anyhow!(
-                    "Download failed: HTTP {} for {}",
-                    resp.status(),
-                    url
-                ));
-            }
-            Err(e) if using_cdn => {
-                tracing::warn!(
-                    cdn_url = %download_url,
-                    error = %e,
-                    original_url = url,
-                    "CDN download error, falling back to original URL"
-                );
-                let fallback_resp = self.client.get(url).send().await?;
-                if !fallback_resp.status().is_success() {
-                    return Err(anyhow::anyhow!(
-                        "Download failed: HTTP {} for {}",
-                        fallback_resp.status(),
-                        url
-                    ));
+        let result = (|| async {
+            let response = client.post(&url).json(body).send().await.map_err(|e| {
+                if e.is_timeout() || e.is_connect() {
+                    HttpError::retryable(format!("Network error: {}", e))
+                } else {
+                    HttpError::non_retryable(format!("Request failed: {}", e))
                 }
-                (fallback_resp, false)
+            })?;
+            let status = response.status();
+            if !status.is_success() {
+                return Err(HttpError::from_status(status, &url));
             }
-            Err(e) => return Err(e.into()),
-        };
-
-        let total_size = response.content_length().unwrap_or(0);
-
-        if let Some(parent) = dest.parent() {
-            std::fs::create_dir_all(parent)?;
-        }
+            response
+                .json::<serde_json::Value>()
+                .await
+                .map_err(|e| HttpError::non_retryable(format!("Invalid JSON from {}: {}", url, e)))
+        })
+        .retry(Self::build_retry_strategy())
+        .notify(|err: &HttpError, dur: Duration| {
+            tracing::debug!(error = %err, retry_in = ?dur, url = %url, "Retrying POST request");
+        })
+        .when(|e: &HttpError| e.is_retryable)
+        .await;
 
-        let mut file = tokio::fs::File::create(dest).await?;
-        let mut stream = response.bytes_stream();
+        result.map_err(|e| anyhow::anyhow!("{}", e))
+    }
 
+    async fn download(&self, url: &str, dest: &Path) -> Result<()> {
         // Extract filename from URL for display (uv-style)
         let filename = Self::extract_display_name_from_url(url);
-        let cdn_suffix = if actual_using_cdn { " [CDN]" } else { "" };
-
-        // Create progress bar with uv-style format, registered to the global MultiProgress
-        // so it doesn't interleave with other progress bars or text output.
-        // cpython-3.10.19-windows-x86_64-none (download) ━━━━━━━━━━━━━━ 1.47 MiB/21.49 MiB
-        let pm = global_progress_manager();
-        let progress_bar = if total_size > 0 {
-            let pb = pm.multi().add(ProgressBar::new(total_size));
-            pb.set_style(
-                ProgressStyle::with_template(&format!(
-                    "{filename}{cdn_suffix} (download) {{wide_bar:.cyan/blue}} {{bytes}}/{{total_bytes}}"
-                ))
-                .unwrap_or_else(|_| ProgressStyle::default_bar())
-                .progress_chars("━━╺"),
-            );
-            pb
-        } else {
-            let pb = pm.multi().add(ProgressBar::new_spinner());
-            pb.set_style(
-                ProgressStyle::with_template(&format!(
-                    "{{spinner:.green}} {filename}{cdn_suffix} (download) {{bytes}}"
-                ))
-                .unwrap_or_else(|_| ProgressStyle::default_spinner()),
-            );
-            pb.enable_steady_tick(std::time::Duration::from_millis(100));
-            pb
-        };
+        let mut progress_bar: Option<ProgressBar> = None;
 
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk?;
-            file.write_all(&chunk).await?;
-            progress_bar.inc(chunk.len() as u64);
-        }
+        let result = self
+            .download_resumable(url, dest, &mut |progress| {
+                progress_bar
+                    .get_or_insert_with(|| Self::download_progress_bar(&filename, progress))
+                    .set_position(progress.downloaded);
+            })
+            .await;
 
         // Finish with summary (uv-style: just clear the progress bar)
-        progress_bar.finish_and_clear();
-
-        file.flush().await?;
-        Ok(())
+        if let Some(pb) = progress_bar {
+            pb.finish_and_clear();
+        }
+        result.map(|_| ())
     }
 
     async fn download_with_progress(
@@ -643,90 +692,14 @@ impl HttpClient for RealHttpClient {
         dest: &Path,
         on_progress: &(dyn Fn(u64, u64) + Send + Sync),
     ) -> Result<()> {
-        use tokio::io::AsyncWriteExt;
-
-        // Optimize URL with CDN if enabled
-        let download_url = self.optimize_url(url).await;
-        if download_url != url {
-            tracing::info!(
-                original = url,
-                optimized = %download_url,
-                "Using CDN accelerated URL"
-            );
-        }
-        let using_cdn = download_url != url;
-
-        let response = self.client.get(&download_url).send().await;
-
-        // If CDN URL failed, fallback to original URL
-        let response = match response {
-            Ok(resp) if resp.status().is_success() => resp,
-            Ok(resp) if using_cdn => {
-                tracing::warn!(
-                    cdn_url = %download_url,
-                    status = %resp.status(),
-                    "CDN download failed, falling back to original URL"
-                );
-                let fallback_resp = self.client.get(url).send().await?;
-                if !fallback_resp.status().is_success() {
-                    return Err(anyhow::anyhow!(
-                        "Download failed: HTTP {} for {}",
-                        fallback_resp.status(),
-                        url
-                    ));
-                }
-                fallback_resp
-            }
-            Ok(resp) => {
-                return Err(anyhow::anyhow!(
-                    "Download failed: HTTP {} for {}",
-                    resp.status(),
-                    url
-                ));
-            }
-            Err(e) if using_cdn => {
-                tracing::warn!(
-                    cdn_url = %download_url,
-                    error = %e,
-                    "CDN download error, falling back to original URL"
-                );
-                let fallback_resp = self.client.get(url).send().await?;
-                if !fallback_resp.status().is_success() {
-                    return Err(anyhow::anyhow!(
-                        "Download failed: HTTP {} for {}",
-                        fallback_resp.status(),
-                        url
-                    ));
-                }
-                fallback_resp
-            }
-            Err(e) => return Err(e.into()),
-        };
-
-        let total_size = response.content_length().unwrap_or(0);
-
-        if let Some(parent) = dest.parent() {
-            std::fs::create_dir_all(parent)?;
-        }
-
-        let mut file = tokio::fs::File::create(dest).await?;
-        let mut downloaded: u64 = 0;
-        let mut stream = response.bytes_stream();
-
-        use futures_util::StreamExt;
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk?;
-            file.write_all(&chunk).await?;
-            downloaded += chunk.len() as u64;
-            on_progress(total_size, downloaded);
-        }
-
-        file.flush().await?;
-        Ok(())
+        self.download_resumable(url, dest, &mut |progress| {
+            on_progress(progress.total, progress.downloaded)
+        })
+        .await
+        .map(|_| ())
     }
 
     async fn download_cached(&self, url: &str, dest: &Path) -> Result<bool> {
-        use indicatif::{ProgressBar, ProgressStyle};
         use vx_console::global_progress_manager;
 
         // Check if we have a download cache
@@ -795,15 +768,21 @@ impl HttpClient for RealHttpClient {
         }
 
         // Download to a temp file first
-        let temp_dir = tempfile::tempdir()?;
-        let temp_path = temp_dir.path().join("download");
-
-        // Use standard download (which shows progress)
-        self.download(url, &temp_path).await?;
+        let workspace = vx_paths::TempWorkspace::new("download")?;
+        let temp_path = workspace.join("download");
+
+        // Prefer the remote shared cache, then a verified copy from a LAN
+        // peer, then the standard download (which shows progress)
+        let from_remote = self.download_from_remote(url, &temp_path).await;
+        if !from_remote && self.download_from_peers(url, &temp_path).await.is_none() {
+            self.download(url, &temp_path).await?;
+        }
 
         // Store in cache
         if let Err(e) = cache.store(url, &temp_path, None, None, None) {
             tracing::warn!(url = url, error = %e, "Failed to cache download");
+        } else if !from_remote {
+            self.upload_to_remote(url);
         }
 
         // Move to final destination
@@ -820,4 +799,17 @@ impl HttpClient for RealHttpClient {
             .as_ref()
             .is_some_and(|c| c.is_cached(url))
     }
+
+    async fn content_length(&self, url: &str) -> Result<Option<u64>> {
+        let request = vx_proxy::credentials::authorize(self.client.head(url), url);
+        let response = request.send().await?;
+        if !response.status().is_success() {
+            return Ok(None);
+        }
+        Ok(response
+            .headers()
+            .get(reqwest::header::CONTENT_LENGTH)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok()))
+    }
 }
@@ -1,12 +1,15 @@
 //! Real installer implementation
 
+use crate::download::DownloadOutcome;
+use crate::extract_filter::ExtractFilter;
 use crate::http_client::RealHttpClient;
 use anyhow::Result;
 use async_trait::async_trait;
 use backon::{ExponentialBuilder, Retryable};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
-use vx_runtime::Installer;
+use vx_paths::TempWorkspace;
+use vx_runtime::{Installer, preflight};
 
 /// Real installer for downloading and extracting archives
 pub struct RealInstaller {
@@ -34,12 +37,11 @@ impl RealInstaller {
     /// 1. Content-Disposition header
     /// 2. Final URL path (after redirects)
     fn extract_filename_from_response(
-        response: &reqwest::Response,
+        outcome: &DownloadOutcome,
         original_url: &str,
     ) -> Option<String> {
         // Try Content-Disposition header first
-        if let Some(content_disposition) = response.headers().get("content-disposition")
-            && let Ok(value) = content_disposition.to_str()
+        if let Some(value) = &outcome.content_disposition
             && let Some(filename) = Self::parse_content_disposition(value)
         {
             tracing::debug!("Got filename from Content-Disposition: {}", filename);
@@ -47,7 +49,7 @@ impl RealInstaller {
         }
 
         // Try to get filename from final URL (after redirects)
-        let final_url = response.url().as_str();
+        let final_url = outcome.final_url.as_str();
         if final_url != original_url {
             let filename = final_url
                 .split('/')
@@ -73,9 +75,7 @@ impl RealInstaller {
     /// For APIs like Adoptium that use redirect chains (307 → 302 → CDN),
     /// this saves ~3-5 seconds by eliminating the redundant HEAD round-trip.
     async fn download_and_detect_filename(&self, url: &str, dest: &Path) -> Result<Option<String>> {
-        use futures_util::StreamExt;
         use indicatif::{ProgressBar, ProgressStyle};
-        use tokio::io::AsyncWriteExt;
 
         // Check download cache first
         if let Some(cache) = &self.http.download_cache {
@@ -132,151 +132,49 @@ impl RealInstaller {
             }
         }
 
-        // Optimize URL with CDN if enabled
-        let download_url = self.http.optimize_url(url).await;
-        let using_cdn = download_url.as_str() != url;
-        if using_cdn {
-            tracing::info!(
-                original = url,
-                optimized = %download_url,
-                "Using CDN accelerated URL"
-            );
+        // Copies from the remote cache or a LAN peer have no response headers
+        // to detect the file name from, like a cache hit with ETag
+        if self.http.download_from_remote(url, dest).await {
+            self.store_in_cache(url, dest);
+            return Ok(None);
         }
-
-        // Retry the initial HTTP GET request with exponential backoff.
-        // This handles transient errors like 502 Bad Gateway from GitHub CDN.
-        let url_owned = url.to_string();
-        let download_url_owned = download_url.to_string();
-        let client = self.http.client.clone();
-
-        let fetch_response = {
-            let url_ref = &url_owned;
-            let download_url_ref = &download_url_owned;
-            let client_ref = &client;
-
-            (|| async {
-                let response = client_ref.get(download_url_ref.as_str()).send().await;
-
-                match response {
-                    Ok(resp) if resp.status().is_success() => Ok((resp, using_cdn)),
-                    Ok(resp) if using_cdn => {
-                        let status = resp.status();
-                        tracing::warn!(
-                            cdn_url = %download_url_ref,
-                            status = %status,
-                            original_url = url_ref,
-                            "CDN download failed, falling back to original URL"
-                        );
-                        let fallback_resp = client_ref.get(url_ref).send().await.map_err(|e| {
-                            DownloadError::retryable(format!("Download failed: {}", e))
-                        })?;
-                        if !fallback_resp.status().is_success() {
-                            let status = fallback_resp.status();
-                            return Err(DownloadError::from_status(status, url_ref));
-                        }
-                        Ok((fallback_resp, false))
-                    }
-                    Ok(resp) => {
-                        let status = resp.status();
-                        Err(DownloadError::from_status(status, url_ref))
-                    }
-                    Err(e) if using_cdn => {
-                        tracing::warn!(
-                            cdn_url = %download_url_ref,
-                            error = %e,
-                            original_url = url_ref,
-                            "CDN download error, falling back to original URL"
-                        );
-                        let fallback_resp = client_ref.get(url_ref).send().await.map_err(|e| {
-                            DownloadError::retryable(format!("Download failed: {}", e))
-                        })?;
-                        if !fallback_resp.status().is_success() {
-                            let status = fallback_resp.status();
-                            return Err(DownloadError::from_status(status, url_ref));
-                        }
-                        Ok((fallback_resp, false))
-                    }
-                    Err(e) => Err(DownloadError::retryable(format!("Download failed: {}", e))),
-                }
-            })
-            .retry(
-                ExponentialBuilder::default()
-                    .with_min_delay(Duration::from_secs(2))
-                    .with_max_delay(Duration::from_secs(30))
-                    .with_max_times(3)
-                    .with_jitter(),
-            )
-            .notify(|err: &DownloadError, dur: Duration| {
-                tracing::warn!(
-                    error = %err,
-                    retry_in = ?dur,
-                    url = %url_owned,
-                    "Retrying download after transient error"
-                );
-            })
-            .when(|e: &DownloadError| e.is_retryable)
-            .await
-            .map_err(|e| anyhow::anyhow!("{}", e))?
-        };
-
-        let (response, actual_using_cdn) = fetch_response;
-
-        // Extract filename from response BEFORE consuming the body
-        let detected_filename = Self::extract_filename_from_response(&response, url);
-        let total_size = response.content_length().unwrap_or(0);
-
-        if let Some(parent) = dest.parent() {
-            std::fs::create_dir_all(parent)?;
+        if self.http.download_from_peers(url, dest).await.is_some() {
+            self.store_in_cache(url, dest);
+            self.http.upload_to_remote(url);
+            return Ok(None);
         }
 
-        let mut file = tokio::fs::File::create(dest).await?;
-        let mut stream = response.bytes_stream();
-
+        // Resumable download with retry and CDN failover. A single GET both
+        // downloads the file and reports the headers used to detect its name.
         let filename_display = RealHttpClient::extract_display_name_from_url(url);
-        let cdn_suffix = if actual_using_cdn { " [CDN]" } else { "" };
-
-        // Use global progress manager to ensure progress bar is properly coordinated
-        // with other output (messages, spinners, etc.)
-        let pm = vx_console::global_progress_manager();
-        let progress_bar = if total_size > 0 {
-            let pb = pm.multi().add(ProgressBar::new(total_size));
-            pb.set_style(
-                ProgressStyle::with_template(&format!(
-                    "{filename_display}{cdn_suffix} (download) {{wide_bar:.cyan/blue}} {{bytes}}/{{total_bytes}}"
-                ))
-                .unwrap_or_else(|_| ProgressStyle::default_bar())
-                .progress_chars("━━╺"),
-            );
-            pb
-        } else {
-            let pb = pm.multi().add(ProgressBar::new_spinner());
-            pb.set_style(
-                ProgressStyle::with_template(&format!(
-                    "{{spinner:.green}} {filename_display}{cdn_suffix} (download) {{bytes}}"
-                ))
-                .unwrap_or_else(|_| ProgressStyle::default_spinner()),
-            );
-            pb.enable_steady_tick(std::time::Duration::from_millis(100));
-            pb
-        };
-
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk?;
-            file.write_all(&chunk).await?;
-            progress_bar.inc(chunk.len() as u64);
+        let mut progress_bar: Option<ProgressBar> = None;
+        let outcome = self
+            .http
+            .download_resumable(url, dest, &mut |progress| {
+                progress_bar
+                    .get_or_insert_with(|| {
+                        RealHttpClient::download_progress_bar(&filename_display, progress)
+                    })
+                    .set_position(progress.downloaded);
+            })
+            .await;
+        if let Some(pb) = progress_bar {
+            pb.finish_and_clear();
         }
+        let detected_filename = Self::extract_filename_from_response(&outcome?, url);
+        self.store_in_cache(url, dest);
+        self.http.upload_to_remote(url);
 
-        progress_bar.finish_and_clear();
-        file.flush().await?;
+        Ok(detected_filename)
+    }
 
-        // Store in download cache if enabled
+    /// Store a downloaded file in the download cache if enabled
+    fn store_in_cache(&self, url: &str, path: &Path) {
         if let Some(cache) = &self.http.download_cache
-            && let Err(e) = cache.store(url, dest, None, None, None)
+            && let Err(e) = cache.store(url, path, None, None, None)
         {
             tracing::warn!(url = url, error = %e, "Failed to cache download");
         }
-
-        Ok(detected_filename)
     }
 
     /// Parse filename from Content-Disposition header value
@@ -322,9 +220,14 @@ impl Default for RealInstaller {
     }
 }
 
-#[async_trait]
-impl Installer for RealInstaller {
-    async fn extract(&self, archive: &Path, dest: &Path) -> Result<()> {
+impl RealInstaller {
+    /// Extract `archive` into `dest`, limited to the entries `filter` selects
+    fn extract_archive(
+        &self,
+        archive: &Path,
+        dest: &Path,
+        filter: Option<&ExtractFilter>,
+    ) -> Result<()> {
         std::fs::create_dir_all(dest)?;
 
         let archive_str = archive.to_string_lossy();
@@ -421,34 +324,35 @@ impl Installer for RealInstaller {
             }
         };
 
+        if filter.is_some() && !matches!(format, Some("tar.gz" | "tar.xz" | "tar.bz2" | "tar.zst"))
+        {
+            tracing::debug!(
+                "Include patterns only apply to tar archives; extracting all of {}",
+                archive_str
+            );
+        }
+
         match format {
-            Some("tar.gz") => {
-                let file = std::fs::File::open(archive)?;
-                let decoder = flate2::read::GzDecoder::new(file);
-                let mut archive = tar::Archive::new(decoder);
-                archive.unpack(dest)?;
-            }
-            Some("tar.xz") => {
-                use xz2::read::XzDecoder;
-                let file = std::fs::File::open(archive)?;
-                let decoder = XzDecoder::new(file);
-                let mut archive = tar::Archive::new(decoder);
-                archive.unpack(dest)?;
-            }
-            Some("tar.bz2") => {
-                use bzip2::read::BzDecoder;
-                let file = std::fs::File::open(archive)?;
-                let decoder = BzDecoder::new(file);
-                let mut archive = tar::Archive::new(decoder);
-                archive.unpack(dest)?;
-            }
-            Some("tar.zst") => {
-                let file = std::fs::File::open(archive)?;
-                let decoder = zstd::stream::read::Decoder::new(std::io::BufReader::new(file))?;
-                let mut archive = tar::Archive::new(decoder);
-                archive.unpack(dest)?;
+            Some(format @ ("tar.gz" | "tar.xz" | "tar.bz2" | "tar.zst")) => {
+                if is_case_insensitive(dest) {
+                    let names = tar_entry_names(open_tar(archive, format)?)?;
+                    let names = names
+                        .iter()
+                        .map(String::as_str)
+                        .filter(|name| filter.is_none_or(|f| f.matches(name)));
+                    preflight::ensure_case_safe(names, dest)?;
+                }
+                match filter {
+                    Some(filter) => unpack_tar_filtered(open_tar(archive, format)?, dest, filter)?,
+                    None => open_tar(archive, format)?.unpack(dest)?,
+                }
             }
             Some("zip") => {
+                if is_case_insensitive(dest) {
+                    let zip = zip::ZipArchive::new(std::fs::File::open(archive)?)
+                        .map_err(|e| anyhow::anyhow!("Failed to read zip archive: {}", e))?;
+                    preflight::ensure_case_safe(zip.file_names(), dest)?;
+                }
                 extract_zip_robust(archive, dest)?;
             }
             Some("7z") => {
@@ -536,15 +440,47 @@ impl Installer for RealInstaller {
         Ok(())
     }
 
-    async fn download_and_extract(&self, url: &str, dest: &Path) -> Result<()> {
-        // Create temp file for download
-        let temp_dir = tempfile::tempdir()?;
+    /// Download URL of the bottle for this machine from a bottle image index
+    async fn resolve_bottle(&self, index_url: &str, formula: &str) -> Result<String> {
+        use vx_installer::bottle;
+        use vx_runtime::HttpClient;
+
+        let tags = bottle::host_tags().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Homebrew bottles can only be installed on macOS (formula '{}')",
+                formula
+            )
+        })?;
+        let index = self.http.get_json_value(index_url).await?;
+        let file = bottle::select_bottle(formula, &index, &tags).ok_or_else(|| {
+            anyhow::anyhow!(
+                "No Homebrew bottle of '{}' for this Mac (looked for {})",
+                formula,
+                tags.join(", ")
+            )
+        })?;
+        tracing::info!("Using Homebrew bottle {} ({})", formula, file.tag);
+        // Blob URLs have no file name to detect the format from
+        Ok(format!("{}#.tar.gz", file.url))
+    }
+
+    async fn download_and_extract_filtered(
+        &self,
+        url: &str,
+        dest: &Path,
+        filter: Option<&ExtractFilter>,
+    ) -> Result<()> {
+        // Download and extract in a workspace under ~/.vx/tmp; it is removed
+        // when this returns, whether or not the install succeeded
+        let workspace = TempWorkspace::new("install")?;
+        let download_dir = workspace.join("download");
+        std::fs::create_dir_all(&download_dir)?;
 
         // Extract archive name from URL, handling URL fragments (e.g., #.zip hint)
         let url_without_fragment = url.split('#').next().unwrap_or(url);
 
         // Download and detect filename in a single GET request (no separate HEAD).
-        let temp_download_path = temp_dir.path().join("download_temp");
+        let temp_download_path = download_dir.join("download_temp");
         let detected_filename = self
             .download_and_detect_filename(url_without_fragment, &temp_download_path)
             .await?;
@@ -561,7 +497,7 @@ impl Installer for RealInstaller {
         });
 
         // Rename temp file to actual filename so extraction can detect format
-        let temp_path = temp_dir.path().join(&archive_name);
+        let temp_path = download_dir.join(&archive_name);
         if temp_download_path != temp_path {
             std::fs::rename(&temp_download_path, &temp_path)?;
         }
@@ -615,9 +551,18 @@ impl Installer for RealInstaller {
             // Large zip archives (e.g. Go 1.26.2 with 15 009 entries) can
             // experience truncated extraction on Windows due to filesystem
             // pressure. Retry with exponential backoff to recover.
+            //
+            // The archive is extracted inside the workspace and only moved
+            // into `dest` once complete, so a failed extraction leaves
+            // nothing behind in the store.
             let temp_path_owned = temp_path.clone();
-            let dest_owned = dest.to_path_buf();
-            let extract_op = || async { self.extract(&temp_path_owned, &dest_owned).await };
+            let extract_dir = workspace.join("extract");
+            let extract_op = || async {
+                if extract_dir.exists() {
+                    std::fs::remove_dir_all(&extract_dir)?;
+                }
+                self.extract_archive(&temp_path_owned, &extract_dir, filter)
+            };
             extract_op
                 .retry(
                     ExponentialBuilder::default()
@@ -634,6 +579,7 @@ impl Installer for RealInstaller {
                     );
                 })
                 .await?;
+            workspace.move_contents(&extract_dir, dest)?;
         } else {
             // Single executable file - place under bin/
             let bin_dir = dest.join("bin");
@@ -656,6 +602,17 @@ impl Installer for RealInstaller {
 
         Ok(())
     }
+}
+
+#[async_trait]
+impl Installer for RealInstaller {
+    async fn extract(&self, archive: &Path, dest: &Path) -> Result<()> {
+        self.extract_archive(archive, dest, None)
+    }
+
+    async fn download_and_extract(&self, url: &str, dest: &Path) -> Result<()> {
+        self.download_and_extract_filtered(url, dest, None).await
+    }
 
     async fn download_with_layout(
         &self,
@@ -663,8 +620,17 @@ impl Installer for RealInstaller {
         dest: &Path,
         metadata: &std::collections::HashMap<String, String>,
     ) -> Result<()> {
-        // First download and extract
-        self.download_and_extract(url, dest).await?;
+        // Homebrew bottle layouts point at the image index of the bottles
+        let bottle = metadata.get("bottle");
+        let url = match bottle {
+            Some(formula) => self.resolve_bottle(url, formula).await?,
+            None => url.to_string(),
+        };
+
+        // First download and extract, limited to the `include` patterns
+        let filter = ExtractFilter::from_layout(metadata)?;
+        self.download_and_extract_filtered(&url, dest, filter.as_ref())
+            .await?;
 
         // Debug: log metadata and dest contents
         tracing::info!("download_with_layout: dest = {}", dest.display());
@@ -697,23 +663,16 @@ impl Installer for RealInstaller {
                     None
                 }
             } else {
-                let p = dest.join(strip_prefix);
-                tracing::info!(
-                    "download_with_layout: checking path {} (exists={}, is_dir={})",
-                    p.display(),
-                    p.exists(),
-                    p.is_dir()
-                );
-                if p.exists() && p.is_dir() {
-                    Some(p)
-                } else {
+                let p = resolve_strip_prefix(dest, strip_prefix);
+                tracing::info!("download_with_layout: resolved strip_prefix to {:?}", p);
+                if p.is_none() {
                     tracing::warn!(
-                        "strip_prefix directory not found: {} (expected at {})",
+                        "strip_prefix directory not found: {} (expected in {})",
                         strip_prefix,
-                        p.display()
+                        dest.display()
                     );
-                    None
                 }
+                p
             };
 
             if let Some(prefix_dir) = prefix_dir {
@@ -840,17 +799,17 @@ impl Installer for RealInstaller {
 
             // Helper: set 0o755 on all regular files under a directory.
             let chmod_dir = |dir: &std::path::Path| {
-                if dir.is_dir() {
-                    if let Ok(entries) = std::fs::read_dir(dir) {
-                        for entry in entries.filter_map(|e| e.ok()) {
-                            let path = entry.path();
-                            if path.is_file() {
-                                if let Ok(meta) = std::fs::metadata(&path) {
-                                    let mut perms = meta.permissions();
-                                    perms.set_mode(0o755);
-                                    let _ = std::fs::set_permissions(&path, perms);
-                                }
-                            }
+                if dir.is_dir()
+                    && let Ok(entries) = std::fs::read_dir(dir)
+                {
+                    for entry in entries.filter_map(|e| e.ok()) {
+                        let path = entry.path();
+                        if path.is_file()
+                            && let Ok(meta) = std::fs::metadata(&path)
+                        {
+                            let mut perms = meta.permissions();
+                            perms.set_mode(0o755);
+                            let _ = std::fs::set_permissions(&path, perms);
                         }
                     }
                 }
@@ -863,10 +822,123 @@ impl Installer for RealInstaller {
             chmod_dir(dest);
         }
 
+        if let Some(formula) = bottle {
+            let report = vx_installer::bottle::relocate(dest, formula)?;
+            tracing::debug!(
+                "Relocated bottle {}: {} text file(s), {} Mach-O file(s)",
+                formula,
+                report.text_files,
+                report.macho_files
+            );
+            if !report.unresolved.is_empty() {
+                tracing::warn!(
+                    "The {} bottle refers to other Homebrew formulae vx does not install: {}",
+                    formula,
+                    report
+                        .unresolved
+                        .iter()
+                        .cloned()
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            }
+        }
+
         Ok(())
     }
 }
 
+/// Find the `strip_prefix` directory inside `dest`
+///
+/// Each `/`-separated segment may be a glob, for archives whose top-level
+/// directory carries the full version (`jdk-*/Contents/Home`). A glob
+/// segment must match exactly one directory.
+fn resolve_strip_prefix(dest: &Path, strip_prefix: &str) -> Option<PathBuf> {
+    let mut dir = dest.to_path_buf();
+    for segment in strip_prefix.split('/').filter(|s| !s.is_empty()) {
+        if !segment.contains(['*', '?', '[']) {
+            dir.push(segment);
+            continue;
+        }
+        let pattern = glob::Pattern::new(segment).ok()?;
+        let mut matches = std::fs::read_dir(&dir)
+            .ok()?
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_dir() && pattern.matches(&e.file_name().to_string_lossy()));
+        let found = matches.next()?;
+        if matches.next().is_some() {
+            return None;
+        }
+        dir = found.path();
+    }
+    dir.is_dir().then_some(dir)
+}
+
+/// Whether `dest` is on a case-insensitive filesystem (macOS and Windows defaults)
+fn is_case_insensitive(dest: &Path) -> bool {
+    preflight::FsProperties::probe(dest).is_ok_and(|props| !props.case_sensitive)
+}
+
+/// Open a compressed tar archive
+fn open_tar(archive: &Path, format: &str) -> Result<tar::Archive<Box<dyn std::io::Read>>> {
+    let file = std::fs::File::open(archive)?;
+    let decoder: Box<dyn std::io::Read> = match format {
+        "tar.gz" => Box::new(flate2::read::GzDecoder::new(file)),
+        "tar.xz" => Box::new(xz2::read::XzDecoder::new(file)),
+        "tar.bz2" => Box::new(bzip2::read::BzDecoder::new(file)),
+        _ => Box::new(zstd::stream::read::Decoder::new(std::io::BufReader::new(
+            file,
+        ))?),
+    };
+    Ok(tar::Archive::new(decoder))
+}
+
+/// Paths of the non-directory entries of a tar archive, without extracting them
+fn tar_entry_names(mut archive: tar::Archive<Box<dyn std::io::Read>>) -> Result<Vec<String>> {
+    let mut names = Vec::new();
+    for entry in archive.entries()? {
+        let entry = entry?;
+        if !entry.header().entry_type().is_dir() {
+            names.push(entry.path()?.to_string_lossy().into_owned());
+        }
+    }
+    Ok(names)
+}
+
+/// Unpack the entries of a tar archive that `filter` selects
+///
+/// The archive is decompressed as a stream and entries that are not selected
+/// are skipped without being written, so a multi-gigabyte toolchain archive
+/// only costs the disk space of the components that were asked for.
+fn unpack_tar_filtered(
+    mut archive: tar::Archive<Box<dyn std::io::Read>>,
+    dest: &Path,
+    filter: &ExtractFilter,
+) -> Result<()> {
+    let mut unpacked = 0usize;
+    let mut skipped = 0usize;
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if entry.header().entry_type().is_dir() {
+            continue;
+        }
+        let path = entry.path()?.to_string_lossy().into_owned();
+        if filter.matches(&path) {
+            entry.unpack_in(dest)?;
+            unpacked += 1;
+        } else {
+            skipped += 1;
+        }
+    }
+    tracing::debug!(unpacked, skipped, "Extracted selected tar entries");
+    if unpacked == 0 {
+        return Err(anyhow::anyhow!(
+            "No archive entries matched the selected components"
+        ));
+    }
+    Ok(())
+}
+
 /// Extract a zip archive entry-by-entry with Windows long-path support,
 /// error tracking, and completeness verification.
 ///
@@ -1015,51 +1087,6 @@ fn extract_zip_robust(archive_path: &Path, dest: &Path) -> Result<()> {
     Ok(())
 }
 
-/// Download error type that supports retry classification
-#[derive(Debug)]
-struct DownloadError {
-    message: String,
-    is_retryable: bool,
-}
-
-impl std::fmt::Display for DownloadError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.message)
-    }
-}
-
-impl std::error::Error for DownloadError {}
-
-impl DownloadError {
-    fn retryable(message: impl Into<String>) -> Self {
-        Self {
-            message: message.into(),
-            is_retryable: true,
-        }
-    }
-
-    /// Classify HTTP status code as retryable or not
-    fn from_status(status: reqwest::StatusCode, url: &str) -> Self {
-        let is_retryable = matches!(
-            status.as_u16(),
-            // Server errors that are often transient
-            500..=504 |
-            // Rate limiting
-            429
-        );
-        let message = format!(
-            "Download failed: HTTP {} {} for {}",
-            status.as_u16(),
-            status.canonical_reason().unwrap_or(""),
-            url
-        );
-        Self {
-            message,
-            is_retryable,
-        }
-    }
-}
-
 /// Promote files from Payload directories to the target directory after pkgutil --expand-full.
 #[cfg(target_os = "macos")]
 fn promote_pkg_payload_contents(expand_dir: &Path, target_dir: &Path) -> Result<()> {
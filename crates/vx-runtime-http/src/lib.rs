@@ -4,7 +4,11 @@
 //! defined in `vx-runtime`. It contains:
 //!
 //! - `RealHttpClient`: HTTP client using reqwest with CDN acceleration, retry logic
+//!   and resumable downloads (`RetryPolicy`)
 //! - `RealInstaller`: Archive downloader and extractor (tar, zip, 7z, msi, pkg)
+//! - `ExtractFilter`: Partial extraction of selected archive components
+//! - LAN peer cache: verified downloads from colleagues' caches (`vx cache serve`)
+//! - Remote cache: downloads shared through an S3/GCS bucket (`wait_for_remote_uploads`)
 //! - `create_runtime_context()`: Factory function for production RuntimeContext
 //!
 //! # Architecture (RFC 0032)
@@ -17,12 +21,19 @@
 //! Only `vx-cli` needs to depend on this crate. Providers only need `vx-runtime`.
 
 mod context;
+mod download;
+mod extract_filter;
 mod http_client;
 mod installer;
+mod peer;
+mod remote_cache;
 
 pub use context::{create_runtime_context, create_runtime_context_with_base};
+pub use download::RetryPolicy;
+pub use extract_filter::ExtractFilter;
 pub use http_client::RealHttpClient;
 pub use installer::RealInstaller;
+pub use remote_cache::wait_for_remote_uploads;
 
 // Re-export region utilities from vx-runtime (avoid code duplication)
 pub use vx_runtime::region::{
@@ -0,0 +1,222 @@
+//! Downloads from LAN peer caches
+//!
+//! When the peer cache is enabled (see [`vx_proxy::peers`]), a download that
+//! misses the local cache is requested from peers running `vx cache serve`
+//! before going upstream. A peer's file is only used when its SHA-256 matches
+//! the checksum published upstream (`<url>.sha256`, `<url>.sha256sum` or a
+//! `SHASUMS256.txt` next to the file), so a peer cannot substitute content.
+//! Any failure falls back to the normal upstream download.
+
+use crate::http_client::RealHttpClient;
+use futures_util::StreamExt;
+use sha2::{Digest, Sha256};
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use vx_cache::peer::{DOWNLOADS_PATH, SHA256_HEADER};
+use vx_proxy::PeerConfig;
+
+/// Peers found by mDNS, discovered once per process
+static DISCOVERED: OnceLock<Vec<SocketAddr>> = OnceLock::new();
+
+impl RealHttpClient {
+    /// Try to download `url` from a LAN peer into `dest`
+    ///
+    /// Returns the peer that served the file, or `None` when the peer cache
+    /// is disabled or no peer had a verified copy.
+    pub(crate) async fn download_from_peers(&self, url: &str, dest: &Path) -> Option<SocketAddr> {
+        let config = self.peer_config();
+        if !config.enabled {
+            return None;
+        }
+
+        let expected = self.upstream_sha256(url).await;
+        if expected.is_none() && config.require_checksum {
+            tracing::debug!(url, "No upstream checksum, skipping peer cache");
+            return None;
+        }
+
+        let peers = peers(config).await;
+        if peers.is_empty() {
+            return None;
+        }
+
+        let client = reqwest::Client::builder()
+            .no_proxy()
+            .connect_timeout(Duration::from_secs(1))
+            .read_timeout(Duration::from_secs(30))
+            .build()
+            .ok()?;
+        let key = vx_cache::DownloadCache::cache_key(url);
+
+        for peer in peers {
+            match fetch_from_peer(&client, peer, &key, expected.as_deref(), dest).await {
+                Ok(()) => {
+                    tracing::info!(url, %peer, "Downloaded from LAN peer cache");
+                    return Some(peer);
+                }
+                Err(e) => tracing::debug!(url, %peer, error = %e, "Peer cache miss"),
+            }
+        }
+        None
+    }
+
+    /// SHA-256 published upstream for `url`, if any
+    pub(crate) async fn upstream_sha256(&self, url: &str) -> Option<String> {
+        let path = url.split(['?', '#']).next().unwrap_or(url);
+        let (dir, filename) = path.rsplit_once('/')?;
+
+        let candidates = [
+            format!("{}.sha256", path),
+            format!("{}.sha256sum", path),
+            format!("{}/SHASUMS256.txt", dir),
+        ];
+        for candidate in candidates {
+            let request = vx_proxy::credentials::authorize(self.client.get(&candidate), &candidate);
+            match vx_proxy::http_cache::send(request).await {
+                Ok(response) if response.status().is_success() => {
+                    if let Some(hash) = parse_checksum(&response.text(), filename) {
+                        return Some(hash);
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => tracing::debug!(url = %candidate, error = %e, "Checksum lookup failed"),
+            }
+        }
+        None
+    }
+}
+
+/// Static peers plus peers discovered with mDNS
+async fn peers(config: PeerConfig) -> Vec<SocketAddr> {
+    // Name resolution and discovery block, so keep them off the runtime
+    tokio::task::spawn_blocking(move || {
+        let mut peers = config.static_peers();
+        if config.discovery {
+            let discovered = DISCOVERED.get_or_init(|| {
+                vx_cache::peer::discover(config.discovery_timeout()).unwrap_or_else(|e| {
+                    tracing::debug!(error = %e, "Peer discovery failed");
+                    Vec::new()
+                })
+            });
+            for peer in discovered {
+                if !peers.contains(peer) {
+                    peers.push(*peer);
+                }
+            }
+        }
+        peers
+    })
+    .await
+    .unwrap_or_default()
+}
+
+/// Download one cache entry from `peer`, keeping it only if its hash checks out
+async fn fetch_from_peer(
+    client: &reqwest::Client,
+    peer: SocketAddr,
+    key: &str,
+    expected: Option<&str>,
+    dest: &Path,
+) -> Result<(), String> {
+    let url = format!("http://{}{}{}", peer, DOWNLOADS_PATH, key);
+    let response = client.get(&url).send().await.map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("HTTP {}", response.status()));
+    }
+
+    // Without an upstream checksum the peer's hash still guards the transfer
+    let advertised = response
+        .headers()
+        .get(SHA256_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_ascii_lowercase);
+    let expected = match (expected, &advertised) {
+        (Some(expected), Some(advertised)) if expected != advertised => {
+            return Err(format!("peer has a different file (sha256 {})", advertised));
+        }
+        (Some(expected), _) => expected.to_string(),
+        (None, Some(advertised)) => advertised.clone(),
+        (None, None) => return Err("peer sent no checksum".to_string()),
+    };
+
+    write_verified(response, Some(&expected), dest).await
+}
+
+/// Stream a response body into `dest`, checking its SHA-256 when `expected` is set
+///
+/// The file only appears at `dest` once it is complete and verified.
+pub(crate) async fn write_verified(
+    response: reqwest::Response,
+    expected: Option<&str>,
+    dest: &Path,
+) -> Result<(), String> {
+    if let Some(parent) = dest.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    let partial = dest.with_extension("shared-partial");
+    let result = async {
+        let mut file = tokio::fs::File::create(&partial)
+            .await
+            .map_err(|e| e.to_string())?;
+        let mut hasher = Sha256::new();
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|e| e.to_string())?;
+            hasher.update(&chunk);
+            file.write_all(&chunk).await.map_err(|e| e.to_string())?;
+        }
+        file.flush().await.map_err(|e| e.to_string())?;
+
+        let actual = hex::encode(hasher.finalize());
+        if let Some(expected) = expected
+            && actual != expected
+        {
+            return Err(format!(
+                "checksum mismatch (expected {}, got {})",
+                expected, actual
+            ));
+        }
+        tokio::fs::rename(&partial, dest)
+            .await
+            .map_err(|e| e.to_string())
+    }
+    .await;
+
+    if result.is_err() {
+        let _ = tokio::fs::remove_file(&partial).await;
+    }
+    result
+}
+
+/// Find the hash for `filename` in a checksum file
+///
+/// Accepts a bare hash or `sha256sum`-style `<hash>  <filename>` lines.
+pub(crate) fn parse_checksum(content: &str, filename: &str) -> Option<String> {
+    let is_hash = |s: &str| s.len() == 64 && s.bytes().all(|b| b.is_ascii_hexdigit());
+    let lines = content.lines().map(str::trim).filter(|l| !l.is_empty());
+    let mut single = None;
+
+    for (index, line) in lines.enumerate() {
+        let mut parts = line.split_whitespace();
+        let Some(hash) = parts.next().filter(|h| is_hash(h)) else {
+            continue;
+        };
+        match parts.next() {
+            Some(name) => {
+                let name = name.trim_start_matches('*');
+                let name = name.rsplit('/').next().unwrap_or(name);
+                if name == filename {
+                    return Some(hash.to_ascii_lowercase());
+                }
+            }
+            None if index == 0 => single = Some(hash.to_ascii_lowercase()),
+            None => {}
+        }
+    }
+    single
+}
@@ -0,0 +1,211 @@
+//! Downloads from and uploads to the remote shared cache
+//!
+//! When a remote cache is configured (see [`vx_proxy::remote_cache`]), a
+//! download that misses the local cache is looked up in the bucket under its
+//! download cache key before going to peers or upstream. Files fetched
+//! elsewhere are uploaded in the background once they are in the local cache;
+//! [`wait_for_remote_uploads`] lets the CLI finish them before exiting.
+
+use crate::http_client::RealHttpClient;
+use crate::peer::write_verified;
+use reqwest::Method;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use vx_cache::DownloadCache;
+use vx_proxy::RemoteStore;
+use vx_proxy::remote_cache::SHA256_METADATA;
+
+/// GCS returns `x-amz-meta-*` metadata under its own prefix
+const GCS_SHA256_METADATA: &str = "x-goog-meta-vx-sha256";
+
+/// Uploads still running in the background
+static UPLOADS: Mutex<Vec<JoinHandle<()>>> = Mutex::new(Vec::new());
+
+impl RealHttpClient {
+    /// The configured remote cache bucket, if any
+    fn remote_store(&self) -> Option<RemoteStore> {
+        // A cassette has to see every download
+        if vx_cache::cassette::active().is_some() {
+            return None;
+        }
+        match self.remote_cache_config().store() {
+            Ok(store) => store,
+            Err(e) => {
+                tracing::warn!(error = %e, "Ignoring invalid remote cache config");
+                None
+            }
+        }
+    }
+
+    /// Try to download `url` from the remote cache into `dest`
+    ///
+    /// Returns `false` when no remote cache is configured or it has no copy.
+    pub(crate) async fn download_from_remote(&self, url: &str, dest: &Path) -> bool {
+        let Some(store) = self.remote_store() else {
+            return false;
+        };
+        let key = DownloadCache::cache_key(url);
+
+        let response = match store
+            .request(&self.client, Method::GET, &key, &[], None)
+            .send()
+            .await
+        {
+            Ok(response) if response.status().is_success() => response,
+            Ok(response) => {
+                tracing::debug!(url, status = %response.status(), "Remote cache miss");
+                return false;
+            }
+            Err(e) => {
+                tracing::debug!(url, error = %e, "Remote cache unavailable");
+                return false;
+            }
+        };
+
+        let expected = [SHA256_METADATA, GCS_SHA256_METADATA]
+            .iter()
+            .find_map(|name| response.headers().get(*name))
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_ascii_lowercase);
+        match write_verified(response, expected.as_deref(), dest).await {
+            Ok(()) => {
+                tracing::info!(url, remote = %store.display_url(), "Downloaded from remote cache");
+                true
+            }
+            Err(e) => {
+                tracing::warn!(url, error = %e, "Discarding remote cache download");
+                false
+            }
+        }
+    }
+
+    /// Upload the local cache entry for `url` to the remote cache in the background
+    ///
+    /// Does nothing without a remote cache, with uploads disabled, or when
+    /// the file is not in the local download cache.
+    pub(crate) fn upload_to_remote(&self, url: &str) {
+        let Some(store) = self.remote_store().filter(|store| store.upload) else {
+            return;
+        };
+        let Some(cache) = &self.download_cache else {
+            return;
+        };
+        let key = DownloadCache::cache_key(url);
+        let Some((path, metadata)) = cache.entry(&key) else {
+            return;
+        };
+        let sha256 = match cache.content_sha256(&key) {
+            Ok(Some(sha256)) => sha256,
+            _ => return,
+        };
+
+        let client = self.client.clone();
+        let url = url.to_string();
+        let upload = Upload {
+            key,
+            path,
+            size: metadata.size,
+            sha256,
+        };
+        let handle = tokio::spawn(async move {
+            match upload.run(&client, &store).await {
+                Ok(true) => {
+                    tracing::debug!(url, remote = %store.display_url(), "Uploaded to remote cache")
+                }
+                Ok(false) => {}
+                Err(e) => tracing::warn!(url, error = %e, "Failed to upload to remote cache"),
+            }
+        });
+
+        let mut uploads = UPLOADS.lock().unwrap_or_else(|e| e.into_inner());
+        uploads.retain(|handle| !handle.is_finished());
+        uploads.push(handle);
+    }
+}
+
+/// Wait up to `timeout` for background uploads to the remote cache
+///
+/// Uploads still running afterwards are cancelled. Returns how many were
+/// cancelled.
+pub async fn wait_for_remote_uploads(timeout: Duration) -> usize {
+    let handles: Vec<_> = std::mem::take(&mut *UPLOADS.lock().unwrap_or_else(|e| e.into_inner()));
+    if handles.is_empty() {
+        return 0;
+    }
+
+    let deadline = tokio::time::Instant::now() + timeout;
+    let mut cancelled = 0;
+    for mut handle in handles {
+        if tokio::time::timeout_at(deadline, &mut handle)
+            .await
+            .is_err()
+        {
+            handle.abort();
+            cancelled += 1;
+        }
+    }
+    if cancelled > 0 {
+        tracing::warn!(cancelled, "Cancelled unfinished remote cache uploads");
+    }
+    cancelled
+}
+
+/// One local cache entry to upload
+struct Upload {
+    key: String,
+    path: PathBuf,
+    size: u64,
+    sha256: String,
+}
+
+impl Upload {
+    /// Upload unless the bucket already has the object; returns whether it uploaded
+    async fn run(&self, client: &reqwest::Client, store: &RemoteStore) -> anyhow::Result<bool> {
+        // Another machine may have uploaded it since our lookup
+        let head = store
+            .request(client, Method::HEAD, &self.key, &[], None)
+            .send()
+            .await?;
+        if head.status().is_success() {
+            return Ok(false);
+        }
+
+        let file = tokio::fs::File::open(&self.path).await?;
+        let body = reqwest::Body::wrap_stream(file_chunks(file));
+        let response = store
+            .request(
+                client,
+                Method::PUT,
+                &self.key,
+                &[(SHA256_METADATA, self.sha256.as_str())],
+                Some(&self.sha256),
+            )
+            .header(reqwest::header::CONTENT_LENGTH, self.size)
+            .body(body)
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            anyhow::bail!("HTTP {}", response.status());
+        }
+        Ok(true)
+    }
+}
+
+/// Read a file as a stream of chunks
+fn file_chunks(
+    file: tokio::fs::File,
+) -> impl futures_util::Stream<Item = std::io::Result<Vec<u8>>> {
+    use tokio::io::AsyncReadExt;
+
+    futures_util::stream::try_unfold(file, |mut file| async move {
+        let mut buf = vec![0u8; 64 * 1024];
+        let n = file.read(&mut buf).await?;
+        if n == 0 {
+            return Ok(None);
+        }
+        buf.truncate(n);
+        Ok(Some((buf, file)))
+    })
+}
@@ -0,0 +1,86 @@
+//! Replaying HTTP traffic and processes from a `VX_REPLAY` cassette
+//!
+//! Every URL points at a closed port, so anything not answered from the
+//! cassette fails.
+
+use std::collections::{BTreeMap, HashMap};
+use vx_cache::{Cassette, HttpInteraction, ProcessInteraction, REPLAY_ENV};
+use vx_runtime::RealCommandExecutor;
+use vx_runtime::traits::{CommandExecutor, HttpClient};
+use vx_runtime_http::RealHttpClient;
+
+const VERSIONS_URL: &str = "http://127.0.0.1:9/versions.json";
+const ARCHIVE_URL: &str = "http://127.0.0.1:9/tool-1.0.0.tar.gz";
+const ARCHIVE: &[u8] = &[0x1f, 0x8b, 0x08, 0x00, 0xff, 0xfe];
+
+#[tokio::test]
+async fn test_replay_answers_requests_and_processes_from_the_cassette() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("cassette.json");
+    let recorder = Cassette::record(&path).unwrap();
+    let json = BTreeMap::from([("content-type".to_string(), "application/json".to_string())]);
+    recorder
+        .record_http(HttpInteraction::new(
+            "GET",
+            VERSIONS_URL,
+            200,
+            json,
+            br#"[{"version": "1.0.0"}]"#,
+        ))
+        .unwrap();
+    recorder
+        .record_http(HttpInteraction::new(
+            "GET",
+            ARCHIVE_URL,
+            200,
+            BTreeMap::new(),
+            ARCHIVE,
+        ))
+        .unwrap();
+    recorder
+        .record_process(ProcessInteraction {
+            program: "tool".to_string(),
+            args: vec!["--version".to_string()],
+            exit_code: 0,
+            stdout: Some("tool 1.0.0\n".to_string()),
+            stderr: Some(String::new()),
+        })
+        .unwrap();
+
+    // Only test in this binary, so nothing else reads the environment concurrently
+    unsafe { std::env::set_var(REPLAY_ENV, &path) };
+
+    let client = RealHttpClient::new();
+    let versions = client.get_json_value(VERSIONS_URL).await.unwrap();
+    assert_eq!(versions[0]["version"], "1.0.0");
+
+    let dest = dir.path().join("download").join("tool.tar.gz");
+    client.download(ARCHIVE_URL, &dest).await.unwrap();
+    assert_eq!(std::fs::read(&dest).unwrap(), ARCHIVE);
+
+    let err = client
+        .download("http://127.0.0.1:9/other.tar.gz", &dest)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("not recorded"), "{}", err);
+
+    // The recorded process is answered without running anything
+    let result = RealCommandExecutor
+        .execute(
+            "/opt/tools/bin/tool",
+            &["--version".to_string()],
+            None,
+            &HashMap::new(),
+            true,
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.exit_code, 0);
+    assert_eq!(result.stdout.as_deref(), Some("tool 1.0.0\n"));
+    assert!(
+        RealCommandExecutor
+            .execute("tool", &[], None, &HashMap::new(), true)
+            .await
+            .is_err()
+    );
+}
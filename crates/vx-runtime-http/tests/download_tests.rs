@@ -0,0 +1,216 @@
+//! Resumable download tests for RealHttpClient
+//!
+//! A minimal local HTTP server serves each connection from a scripted
+//! response, so interrupted and stalled transfers can be reproduced.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use vx_runtime::traits::HttpClient;
+use vx_runtime_http::{RealHttpClient, RetryPolicy};
+
+const BODY: &[u8] = b"0123456789abcdef";
+
+/// How the server answers one connection
+#[derive(Clone, Copy)]
+enum Reply {
+    /// Full body with `200 OK`
+    Full,
+    /// `200 OK` headers for the full body, but only `n` bytes before closing
+    Truncated(usize),
+    /// `200 OK` headers and `n` bytes, then no further data
+    Stall(usize),
+    /// `206 Partial Content` for the requested range
+    Range,
+}
+
+/// Serve `replies` in order and return the request headers seen
+async fn serve(replies: Vec<Reply>) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/tool.tar.gz", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let seen = requests.clone();
+
+    tokio::spawn(async move {
+        for reply in replies {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            let request = String::from_utf8_lossy(&buf[..n]).to_lowercase();
+            seen.lock().unwrap().push(request.clone());
+
+            let head = |status: &str, extra: &str, len: usize| {
+                format!(
+                    "HTTP/1.1 {status}\r\nContent-Length: {len}\r\nETag: \"v1\"\r\nConnection: close\r\n{extra}\r\n"
+                )
+            };
+            match reply {
+                Reply::Full => {
+                    let _ = socket
+                        .write_all(head("200 OK", "", BODY.len()).as_bytes())
+                        .await;
+                    let _ = socket.write_all(BODY).await;
+                }
+                Reply::Truncated(n) => {
+                    let _ = socket
+                        .write_all(head("200 OK", "", BODY.len()).as_bytes())
+                        .await;
+                    let _ = socket.write_all(&BODY[..n]).await;
+                }
+                Reply::Stall(n) => {
+                    let _ = socket
+                        .write_all(head("200 OK", "", BODY.len()).as_bytes())
+                        .await;
+                    let _ = socket.write_all(&BODY[..n]).await;
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                }
+                Reply::Range => {
+                    let start: usize = request
+                        .split("range: bytes=")
+                        .nth(1)
+                        .and_then(|r| r.split('-').next())
+                        .and_then(|s| s.parse().ok())
+                        .unwrap_or(0);
+                    let range = format!(
+                        "Content-Range: bytes {}-{}/{}\r\n",
+                        start,
+                        BODY.len() - 1,
+                        BODY.len()
+                    );
+                    let _ = socket
+                        .write_all(
+                            head("206 Partial Content", &range, BODY.len() - start).as_bytes(),
+                        )
+                        .await;
+                    let _ = socket.write_all(&BODY[start..]).await;
+                }
+            }
+            let _ = socket.shutdown().await;
+        }
+    });
+
+    (url, requests)
+}
+
+fn client(partials: &std::path::Path) -> RealHttpClient {
+    // Statistics go next to the partials instead of the user's cache
+    let cache_dir = partials.parent().unwrap().to_path_buf();
+    RealHttpClient::with_cdn(false)
+        .with_partials_dir(partials.to_path_buf())
+        .with_network_stats(cache_dir)
+        .with_retry_policy(
+            RetryPolicy::default()
+                .with_delays(Duration::from_millis(10), Duration::from_millis(10))
+                .with_jitter(false)
+                .with_stall_timeout(Duration::from_millis(300)),
+        )
+}
+
+#[test]
+fn test_retry_policy_delays_without_jitter() {
+    let policy = RetryPolicy::default()
+        .with_max_retries(4)
+        .with_delays(Duration::from_secs(1), Duration::from_secs(5))
+        .with_jitter(false);
+
+    let delays: Vec<u64> = policy.delays().map(|d| d.as_secs()).collect();
+    assert_eq!(delays, vec![1, 2, 4, 5]);
+    assert_eq!(policy.with_max_retries(0).delays().count(), 0);
+}
+
+#[tokio::test]
+async fn test_download_resumes_after_connection_drop() {
+    let (url, requests) = serve(vec![Reply::Truncated(6), Reply::Range]).await;
+    let temp = tempfile::tempdir().unwrap();
+    let dest = temp.path().join("out/tool.tar.gz");
+
+    client(&temp.path().join("partials"))
+        .download(&url, &dest)
+        .await
+        .unwrap();
+
+    assert_eq!(std::fs::read(&dest).unwrap(), BODY);
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(!requests[0].contains("range:"));
+    assert!(requests[1].contains("range: bytes=6-"));
+    assert!(requests[1].contains("if-range: \"v1\""));
+    // The partial file is moved into place once complete
+    assert_eq!(
+        std::fs::read_dir(temp.path().join("partials"))
+            .unwrap()
+            .count(),
+        0
+    );
+}
+
+#[tokio::test]
+async fn test_download_resumes_after_stall() {
+    let (url, requests) = serve(vec![Reply::Stall(4), Reply::Range]).await;
+    let temp = tempfile::tempdir().unwrap();
+    let dest = temp.path().join("tool.tar.gz");
+
+    client(&temp.path().join("partials"))
+        .download(&url, &dest)
+        .await
+        .unwrap();
+
+    assert_eq!(std::fs::read(&dest).unwrap(), BODY);
+    assert!(requests.lock().unwrap()[1].contains("range: bytes=4-"));
+}
+
+#[tokio::test]
+async fn test_download_restarts_when_range_is_ignored() {
+    let (url, _) = serve(vec![Reply::Truncated(6), Reply::Full]).await;
+    let temp = tempfile::tempdir().unwrap();
+    let dest = temp.path().join("tool.tar.gz");
+
+    client(&temp.path().join("partials"))
+        .download(&url, &dest)
+        .await
+        .unwrap();
+
+    assert_eq!(std::fs::read(&dest).unwrap(), BODY);
+}
+
+#[tokio::test]
+async fn test_download_gives_up_after_retries() {
+    let (url, requests) = serve(vec![Reply::Truncated(2); 3]).await;
+    let temp = tempfile::tempdir().unwrap();
+    let partials = temp.path().join("partials");
+
+    let client = client(&partials).with_retry_policy(
+        RetryPolicy::default()
+            .with_max_retries(2)
+            .with_delays(Duration::from_millis(10), Duration::from_millis(10)),
+    );
+    let result = client
+        .download(&url, &temp.path().join("tool.tar.gz"))
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(requests.lock().unwrap().len(), 3);
+    // The partial file is kept for the next run
+    assert!(std::fs::read_dir(&partials).unwrap().count() > 0);
+}
+
+#[tokio::test]
+async fn test_download_attempts_are_recorded_per_source() {
+    let (url, _) = serve(vec![Reply::Truncated(6), Reply::Range]).await;
+    let temp = tempfile::tempdir().unwrap();
+
+    client(&temp.path().join("partials"))
+        .download(&url, &temp.path().join("tool.tar.gz"))
+        .await
+        .unwrap();
+
+    let sources = vx_cache::NetworkStats::new(temp.path().to_path_buf()).sources();
+    assert_eq!(sources.len(), 1);
+    let source = &sources[0];
+    assert!(url.contains(&source.host));
+    assert_eq!((source.successes, source.failures), (1, 1));
+    assert_eq!(source.bytes, BODY.len() as u64 - 6);
+    assert!(source.last_error.is_some());
+    assert!(source.median_latency_ms.is_some());
+}
@@ -0,0 +1,109 @@
+//! Partial extraction tests for RealInstaller
+//!
+//! A tar.gz built in memory is served from a local HTTP server and installed
+//! with `include` patterns, as a provider layout with components would.
+
+use std::collections::HashMap;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use vx_runtime::traits::Installer;
+use vx_runtime_http::{ExtractFilter, RealInstaller};
+
+/// `LLVM-20.1.0-Linux-X64/` with a few tools, headers and static libraries
+fn llvm_archive() -> Vec<u8> {
+    let encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+    let mut builder = tar::Builder::new(encoder);
+    let mut add = |path: &str, data: &[u8]| {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o755);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, format!("LLVM-20.1.0-Linux-X64/{}", path), data)
+            .unwrap();
+    };
+    add("bin/clang-20", b"clang");
+    add("bin/clang-format", b"clang-format");
+    add("bin/clang-tidy", b"clang-tidy");
+    add("lib/clang/20/include/stddef.h", b"header");
+    add("lib/libLLVMSupport.a", b"static");
+    add("include/llvm/ADT/APInt.h", b"header");
+    builder.into_inner().unwrap().finish().unwrap()
+}
+
+/// Serve `body` once and return its URL
+async fn serve(body: Vec<u8>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!(
+        "http://{}/LLVM-20.1.0-Linux-X64.tar.gz",
+        listener.local_addr().unwrap()
+    );
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buf = vec![0u8; 4096];
+        let _ = socket.read(&mut buf).await.unwrap();
+        let head = format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            body.len()
+        );
+        let _ = socket.write_all(head.as_bytes()).await;
+        let _ = socket.write_all(&body).await;
+        let _ = socket.shutdown().await;
+    });
+    url
+}
+
+#[test]
+fn test_filter_matches_below_strip_prefix() {
+    let metadata = HashMap::from([
+        (
+            "strip_prefix".to_string(),
+            "LLVM-20.1.0-Linux-X64".to_string(),
+        ),
+        (
+            "include".to_string(),
+            "bin/clang-tidy, lib/clang/*".to_string(),
+        ),
+    ]);
+    let filter = ExtractFilter::from_layout(&metadata).unwrap().unwrap();
+
+    assert!(filter.matches("LLVM-20.1.0-Linux-X64/bin/clang-tidy"));
+    assert!(filter.matches("./LLVM-20.1.0-Linux-X64/lib/clang/20/include/stddef.h"));
+    assert!(!filter.matches("LLVM-20.1.0-Linux-X64/bin/clang-format"));
+    assert!(!filter.matches("LLVM-20.1.0-Linux-X64/lib/libLLVMSupport.a"));
+    assert!(!filter.matches("LLVM-20.1.0-Linux-X64"));
+
+    let unfiltered = HashMap::from([("strip_prefix".to_string(), "x".to_string())]);
+    assert!(ExtractFilter::from_layout(&unfiltered).unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_download_with_layout_extracts_selected_entries() {
+    let home = tempfile::tempdir().unwrap();
+    // SAFETY: this is the only test in this binary reading VX_HOME
+    unsafe { std::env::set_var("VX_HOME", home.path()) };
+    let dest = home.path().join("store").join("llvm").join("20.1.0");
+
+    let url = serve(llvm_archive()).await;
+    let metadata = HashMap::from([
+        (
+            "strip_prefix".to_string(),
+            "LLVM-20.1.0-Linux-X64".to_string(),
+        ),
+        (
+            "include".to_string(),
+            "bin/clang-[0-9]*,bin/clang-tidy,lib/clang/*".to_string(),
+        ),
+    ]);
+    RealInstaller::new()
+        .download_with_layout(&url, &dest, &metadata)
+        .await
+        .unwrap();
+
+    assert!(dest.join("bin").join("clang-20").is_file());
+    assert!(dest.join("bin").join("clang-tidy").is_file());
+    assert!(dest.join("lib/clang/20/include/stddef.h").is_file());
+    assert!(!dest.join("bin").join("clang-format").exists());
+    assert!(!dest.join("lib").join("libLLVMSupport.a").exists());
+    assert!(!dest.join("include").exists());
+}
@@ -0,0 +1,113 @@
+//! LAN peer cache tests for RealHttpClient
+//!
+//! A local "upstream" publishes `SHASUMS256.txt` and the files, and a
+//! `PeerServer` shares a download cache, so downloads can be checked to come
+//! from the peer only when the hashes match.
+
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use vx_cache::DownloadCache;
+use vx_cache::peer::PeerServer;
+use vx_proxy::PeerConfig;
+use vx_runtime::traits::HttpClient;
+use vx_runtime_http::RealHttpClient;
+
+const TOOL: &[u8] = b"tool archive contents";
+const OTHER: &[u8] = b"other archive contents";
+
+/// Serve `SHASUMS256.txt` and the files under `/dist/`, counting file downloads
+async fn serve_upstream() -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}/dist", listener.local_addr().unwrap());
+    let downloads = Arc::new(AtomicUsize::new(0));
+    let counter = downloads.clone();
+
+    let shasums = format!(
+        "{}  tool.tar.gz\n{}  other.tar.gz\n",
+        hex::encode(Sha256::digest(TOOL)),
+        hex::encode(Sha256::digest(OTHER))
+    );
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap_or(0);
+            let request = String::from_utf8_lossy(&buf[..n]).to_string();
+            let path = request.split_whitespace().nth(1).unwrap_or_default();
+
+            let (status, body): (&str, &[u8]) = match path {
+                "/dist/SHASUMS256.txt" => ("200 OK", shasums.as_bytes()),
+                "/dist/tool.tar.gz" => {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    ("200 OK", TOOL)
+                }
+                "/dist/other.tar.gz" => {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    ("200 OK", OTHER)
+                }
+                _ => ("404 Not Found", b""),
+            };
+            let head = format!(
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                status,
+                body.len()
+            );
+            let _ = socket.write_all(head.as_bytes()).await;
+            let _ = socket.write_all(body).await;
+        }
+    });
+    (base, downloads)
+}
+
+#[tokio::test]
+async fn test_download_prefers_verified_peer_copy() {
+    // Only test in this binary, so nothing else reads the environment concurrently
+    unsafe { std::env::set_var("VX_HTTP_CACHE", "off") };
+
+    let (base, upstream_downloads) = serve_upstream().await;
+    let tool_url = format!("{}/tool.tar.gz", base);
+    let other_url = format!("{}/other.tar.gz", base);
+
+    // The peer has the real tool archive and a tampered copy of the other one
+    let peer_dir = tempfile::tempdir().unwrap();
+    let peer_cache = DownloadCache::new(peer_dir.path().to_path_buf());
+    let source = peer_dir.path().join("source");
+    std::fs::write(&source, TOOL).unwrap();
+    peer_cache
+        .store(&tool_url, &source, None, None, None)
+        .unwrap();
+    std::fs::write(&source, b"tampered").unwrap();
+    peer_cache
+        .store(&other_url, &source, None, None, None)
+        .unwrap();
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let peer = listener.local_addr().unwrap();
+    std::thread::spawn(move || PeerServer::new(peer_cache).serve(listener));
+
+    let local_dir = tempfile::tempdir().unwrap();
+    let client = RealHttpClient::new()
+        .with_download_cache(local_dir.path().to_path_buf())
+        .with_peer_config(PeerConfig {
+            enabled: true,
+            discovery: false,
+            peers: vec![peer.to_string()],
+            ..Default::default()
+        });
+
+    let dest = local_dir.path().join("out/tool.tar.gz");
+    assert!(!client.download_cached(&tool_url, &dest).await.unwrap());
+    assert_eq!(std::fs::read(&dest).unwrap(), TOOL);
+    assert_eq!(upstream_downloads.load(Ordering::SeqCst), 0);
+    // The peer copy is cached locally, ready to be shared again
+    assert!(client.is_cached(&tool_url));
+
+    // A peer copy that doesn't match the upstream checksum is ignored
+    let dest = local_dir.path().join("out/other.tar.gz");
+    assert!(!client.download_cached(&other_url, &dest).await.unwrap());
+    assert_eq!(std::fs::read(&dest).unwrap(), OTHER);
+    assert_eq!(upstream_downloads.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,182 @@
+//! Remote shared cache tests for RealHttpClient
+//!
+//! A minimal in-memory bucket speaks enough of the S3 object API (GET, HEAD,
+//! PUT on path-style URLs) to check lookups and background uploads.
+
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use vx_cache::DownloadCache;
+use vx_proxy::{PeerConfig, RemoteCacheConfig};
+use vx_runtime::traits::HttpClient;
+use vx_runtime_http::{RealHttpClient, wait_for_remote_uploads};
+
+type Objects = Arc<Mutex<HashMap<String, (Vec<u8>, Option<String>)>>>;
+
+/// Serve a bucket of `objects`, keyed by request path
+async fn serve_bucket(objects: Objects) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let objects = objects.clone();
+            tokio::spawn(async move {
+                let mut reader = BufReader::new(socket);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).await.unwrap();
+                let mut parts = request_line.split_whitespace();
+                let method = parts.next().unwrap_or_default().to_string();
+                let path = parts.next().unwrap_or_default().to_string();
+
+                let mut headers = HashMap::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).await.unwrap();
+                    let Some((name, value)) = line.trim_end().split_once(':') else {
+                        break;
+                    };
+                    headers.insert(name.to_ascii_lowercase(), value.trim().to_string());
+                }
+
+                let response = match method.as_str() {
+                    "PUT" => {
+                        let len = headers["content-length"].parse().unwrap();
+                        let mut body = vec![0u8; len];
+                        reader.read_exact(&mut body).await.unwrap();
+                        let sha256 = headers.get("x-amz-meta-vx-sha256").cloned();
+                        objects.lock().unwrap().insert(path, (body, sha256));
+                        "HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n"
+                            .as_bytes()
+                            .to_vec()
+                    }
+                    _ => match objects.lock().unwrap().get(&path) {
+                        Some((body, sha256)) => {
+                            let mut response =
+                                format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\n", body.len());
+                            if let Some(sha256) = sha256 {
+                                response.push_str(&format!("x-amz-meta-vx-sha256: {}\r\n", sha256));
+                            }
+                            let mut response = format!("{}\r\n", response).into_bytes();
+                            if method == "GET" {
+                                response.extend_from_slice(body);
+                            }
+                            response
+                        }
+                        None => b"HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\n\r\n".to_vec(),
+                    },
+                };
+                let mut socket = reader.into_inner();
+                let _ = socket.write_all(&response).await;
+            });
+        }
+    });
+    endpoint
+}
+
+/// Serve `body` for every request, counting them
+async fn serve_upstream(body: &'static [u8]) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let _ = socket.read(&mut buf).await;
+            counter.fetch_add(1, Ordering::SeqCst);
+            let head = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            );
+            let _ = socket.write_all(head.as_bytes()).await;
+            let _ = socket.write_all(body).await;
+        }
+    });
+    (base, requests)
+}
+
+fn client(cache_dir: std::path::PathBuf, endpoint: &str, upload: bool) -> RealHttpClient {
+    RealHttpClient::new()
+        .with_download_cache(cache_dir)
+        .with_peer_config(PeerConfig::default())
+        .with_remote_cache(RemoteCacheConfig {
+            url: Some("s3://team/vx".to_string()),
+            endpoint: Some(endpoint.to_string()),
+            upload,
+            ..Default::default()
+        })
+}
+
+#[tokio::test]
+async fn test_download_uses_remote_cache() {
+    let objects = Objects::default();
+    let endpoint = serve_bucket(objects.clone()).await;
+    let (base, upstream_requests) = serve_upstream(b"upstream").await;
+    let url = format!("{}/tool.tar.gz", base);
+    let object = format!("/team/vx/{}", DownloadCache::cache_key(&url));
+    objects
+        .lock()
+        .unwrap()
+        .insert(object, (b"from the bucket".to_vec(), None));
+
+    let temp = tempfile::tempdir().unwrap();
+    let client = client(temp.path().join("cache"), &endpoint, true);
+    let dest = temp.path().join("tool.tar.gz");
+    assert!(!client.download_cached(&url, &dest).await.unwrap());
+
+    assert_eq!(std::fs::read(&dest).unwrap(), b"from the bucket");
+    assert_eq!(upstream_requests.load(Ordering::SeqCst), 0);
+    assert!(client.is_cached(&url));
+}
+
+#[tokio::test]
+async fn test_download_rejects_corrupt_remote_copy() {
+    let objects = Objects::default();
+    let endpoint = serve_bucket(objects.clone()).await;
+    let (base, upstream_requests) = serve_upstream(b"upstream").await;
+    let url = format!("{}/tool.tar.gz", base);
+    let object = format!("/team/vx/{}", DownloadCache::cache_key(&url));
+    objects
+        .lock()
+        .unwrap()
+        .insert(object, (b"truncated".to_vec(), Some("0".repeat(64))));
+
+    let temp = tempfile::tempdir().unwrap();
+    let client = client(temp.path().join("cache"), &endpoint, false);
+    let dest = temp.path().join("tool.tar.gz");
+    client.download_cached(&url, &dest).await.unwrap();
+
+    assert_eq!(std::fs::read(&dest).unwrap(), b"upstream");
+    assert_eq!(upstream_requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_upstream_downloads_are_uploaded() {
+    let objects = Objects::default();
+    let endpoint = serve_bucket(objects.clone()).await;
+    let (base, _) = serve_upstream(b"upstream").await;
+    let url = format!("{}/tool.tar.gz", base);
+
+    let temp = tempfile::tempdir().unwrap();
+    let client = client(temp.path().join("cache"), &endpoint, true);
+    client
+        .download_cached(&url, &temp.path().join("tool.tar.gz"))
+        .await
+        .unwrap();
+    assert_eq!(
+        wait_for_remote_uploads(std::time::Duration::from_secs(10)).await,
+        0
+    );
+
+    let object = format!("/team/vx/{}", DownloadCache::cache_key(&url));
+    let objects = objects.lock().unwrap();
+    let (body, sha256) = &objects[&object];
+    assert_eq!(body, b"upstream");
+    let expected = hex::encode(Sha256::digest(b"upstream"));
+    assert_eq!(sha256.as_deref(), Some(expected.as_str()));
+}
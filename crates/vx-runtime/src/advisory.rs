@@ -0,0 +1,273 @@
+//! Security advisories and vulnerability-aware version resolution
+//!
+//! With a [`SecurityPolicy`] on the [`RuntimeContext`](crate::RuntimeContext),
+//! resolving `latest` or a range skips versions that have advisories at or
+//! above a severity, and never picks a version below the runtime's security
+//! floor. Exact versions are installed as requested.
+//!
+//! Advisories come from [OSV](https://osv.dev) through [`OsvClient`] for
+//! runtimes that declare their OSV package in `provider.star`:
+//!
+//! ```python
+//! osv = {"ecosystem": "npm", "name": "pnpm"}
+//! ```
+
+use crate::traits::HttpClient;
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use vx_versions::{Version, VersionInfo};
+
+/// OSV query endpoint
+pub const OSV_QUERY_URL: &str = "https://api.osv.dev/v1/query";
+
+/// Most candidate versions checked for advisories in one resolution
+const MAX_ADVISORY_CHECKS: usize = 10;
+
+/// Advisory severity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl Severity {
+    /// Parse `low`, `medium` (or `moderate`), `high` or `critical`
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "low" => Some(Self::Low),
+            "medium" | "moderate" => Some(Self::Medium),
+            "high" => Some(Self::High),
+            "critical" => Some(Self::Critical),
+            _ => None,
+        }
+    }
+
+    /// Lowercase name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+            Self::Critical => "critical",
+        }
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A published advisory affecting a version
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Advisory {
+    /// Advisory ID (`GHSA-...`, `CVE-...`, `GO-...`)
+    pub id: String,
+    /// One-line summary
+    pub summary: String,
+    /// Severity, when the advisory database rates it
+    pub severity: Option<Severity>,
+}
+
+/// Where advisories for a runtime version come from
+#[async_trait]
+pub trait AdvisorySource: Send + Sync {
+    /// Advisories affecting `version` of `runtime`
+    async fn advisories(&self, runtime: &str, version: &str) -> Result<Vec<Advisory>>;
+}
+
+/// A package in an OSV ecosystem
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OsvPackage {
+    /// OSV ecosystem (`npm`, `PyPI`, `crates.io`, `Go`, ...)
+    pub ecosystem: String,
+    /// Package name within the ecosystem
+    pub name: String,
+}
+
+/// Maps a runtime name to its OSV package
+pub type OsvPackageLookup = Arc<dyn Fn(&str) -> Option<OsvPackage> + Send + Sync>;
+
+/// Advisories from the OSV API
+pub struct OsvClient {
+    http: Arc<dyn HttpClient>,
+    packages: OsvPackageLookup,
+}
+
+impl OsvClient {
+    /// Query OSV over `http` for runtimes `packages` knows about
+    pub fn new(http: Arc<dyn HttpClient>, packages: OsvPackageLookup) -> Self {
+        Self { http, packages }
+    }
+}
+
+#[async_trait]
+impl AdvisorySource for OsvClient {
+    async fn advisories(&self, runtime: &str, version: &str) -> Result<Vec<Advisory>> {
+        let Some(package) = (self.packages)(runtime) else {
+            return Ok(Vec::new());
+        };
+        let body = serde_json::json!({
+            "version": version,
+            "package": { "name": package.name, "ecosystem": package.ecosystem },
+        });
+        let response = self.http.post_json(OSV_QUERY_URL, &body).await?;
+        Ok(parse_osv_response(&response))
+    }
+}
+
+/// Advisories in an OSV `/v1/query` response
+///
+/// The severity is the database's rating (`database_specific.severity`, as
+/// GitHub advisories carry it), falling back to the first affected entry's
+/// `ecosystem_specific.severity`.
+pub fn parse_osv_response(response: &serde_json::Value) -> Vec<Advisory> {
+    let Some(vulns) = response.get("vulns").and_then(|v| v.as_array()) else {
+        return Vec::new();
+    };
+    vulns
+        .iter()
+        .filter_map(|vuln| {
+            let id = vuln.get("id")?.as_str()?.to_string();
+            let summary = vuln
+                .get("summary")
+                .or_else(|| vuln.get("details"))
+                .and_then(|s| s.as_str())
+                .and_then(|s| s.lines().next())
+                .unwrap_or_default()
+                .to_string();
+            let severity = vuln
+                .pointer("/database_specific/severity")
+                .or_else(|| vuln.pointer("/affected/0/ecosystem_specific/severity"))
+                .and_then(|s| s.as_str())
+                .and_then(Severity::parse);
+            Some(Advisory {
+                id,
+                summary,
+                severity,
+            })
+        })
+        .collect()
+}
+
+/// A version resolution passed over, and why
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedVersion {
+    /// The version that was passed over
+    pub version: String,
+    /// Why, e.g. `critical advisory GHSA-xxxx: Prototype pollution`
+    pub reason: String,
+}
+
+/// Outcome of a vulnerability-aware resolution
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SecureResolution {
+    /// The chosen version, if any candidate passed
+    pub version: Option<String>,
+    /// Candidates passed over, best first
+    pub skipped: Vec<SkippedVersion>,
+    /// The security floor applied, if the runtime has one
+    pub floor: Option<String>,
+}
+
+/// Vulnerability-aware resolution settings
+pub struct SecurityPolicy {
+    /// Skip versions with advisories at or above this severity (`None`
+    /// only applies the floors)
+    pub skip_severity: Option<Severity>,
+    /// Runtime name → lowest version resolution may pick
+    pub floors: HashMap<String, String>,
+    /// Where advisories come from
+    pub advisories: Arc<dyn AdvisorySource>,
+}
+
+impl SecurityPolicy {
+    /// Security floor for `runtime`
+    pub fn floor(&self, runtime: &str) -> Option<&str> {
+        self.floors.get(runtime).map(String::as_str)
+    }
+
+    /// Resolve among `versions` with `pick`, skipping vulnerable versions
+    ///
+    /// Versions below the floor are removed first. `pick` then chooses the
+    /// best remaining version; while it has an advisory at or above
+    /// `skip_severity` it is dropped and `pick` runs again, checking at most
+    /// ten versions. Failing to fetch advisories keeps the version.
+    pub async fn resolve(
+        &self,
+        runtime: &str,
+        versions: &[VersionInfo],
+        pick: impl Fn(&[VersionInfo]) -> Option<String>,
+    ) -> SecureResolution {
+        let floor = self.floor(runtime).map(str::to_string);
+        let mut candidates: Vec<VersionInfo> = match floor.as_deref().and_then(Version::parse) {
+            Some(floor) => versions
+                .iter()
+                .filter(|v| Version::parse(&v.version).is_none_or(|v| v >= floor))
+                .cloned()
+                .collect(),
+            None => versions.to_vec(),
+        };
+
+        let mut skipped = Vec::new();
+        let Some(threshold) = self.skip_severity else {
+            return SecureResolution {
+                version: pick(&candidates),
+                skipped,
+                floor,
+            };
+        };
+
+        for _ in 0..MAX_ADVISORY_CHECKS {
+            let Some(version) = pick(&candidates) else {
+                break;
+            };
+            let advisories = match self.advisories.advisories(runtime, &version).await {
+                Ok(advisories) => advisories,
+                Err(e) => {
+                    tracing::warn!(
+                        "Could not check advisories for {} {}: {}",
+                        runtime,
+                        version,
+                        e
+                    );
+                    Vec::new()
+                }
+            };
+            let blocking = advisories
+                .iter()
+                .filter(|a| a.severity.is_some_and(|s| s >= threshold))
+                .max_by_key(|a| a.severity);
+            let Some(advisory) = blocking else {
+                return SecureResolution {
+                    version: Some(version),
+                    skipped,
+                    floor,
+                };
+            };
+            skipped.push(SkippedVersion {
+                reason: format!(
+                    "{} advisory {}: {}",
+                    advisory.severity.unwrap_or(threshold),
+                    advisory.id,
+                    advisory.summary
+                ),
+                version: version.clone(),
+            });
+            candidates.retain(|v| v.version != version);
+        }
+
+        SecureResolution {
+            version: None,
+            skipped,
+            floor,
+        }
+    }
+}
@@ -0,0 +1,425 @@
+//! asdf plugin compatibility layer
+//!
+//! Uses [asdf](https://asdf-vm.com) plugins as vx providers, so the tools of
+//! the asdf plugin ecosystem can be installed and run with vx. An asdf plugin
+//! is a directory (usually a git clone) with shell scripts under `bin/`:
+//!
+//! - `bin/list-all` (required): prints all versions, space separated
+//! - `bin/install` (required): installs `ASDF_INSTALL_VERSION` into
+//!   `ASDF_INSTALL_PATH`
+//! - `bin/download` (optional): fetches the sources into `ASDF_DOWNLOAD_PATH`
+//!   before `bin/install` runs
+//! - `bin/list-bin-paths` (optional): directories holding the executables,
+//!   relative to the install path (default: `bin`)
+//!
+//! Plugins live in `~/.vx/asdf-plugins/<name>`. Each plugin becomes a
+//! provider with one runtime named like the plugin; versions are installed
+//! into the regular store (`~/.vx/store/<name>/<version>`). Scripts run in an
+//! [`AsdfSandbox`]. Other asdf scripts (`exec-env`, `latest-stable`, ...) are
+//! not used.
+
+mod sandbox;
+
+pub use sandbox::{AsdfSandbox, INSTALL_TIMEOUT, PASSTHROUGH_ENV, QUERY_TIMEOUT};
+
+use crate::context::RuntimeContext;
+use crate::platform::Platform;
+use crate::provider::Provider;
+use crate::runtime::{Runtime, VerificationResult};
+use crate::types::{InstallResult, VersionInfo};
+use anyhow::{Context, Result, bail};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+
+/// Optional vx settings of a plugin, stored in its directory
+pub const PLUGIN_CONFIG_FILE: &str = ".vx-plugin.toml";
+
+/// Bin paths reported by `bin/list-bin-paths`, recorded in the install directory
+const BIN_PATHS_FILE: &str = ".vx-bin-paths";
+
+static PRERELEASE_RE: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(r"(?i)(alpha|beta|rc|pre|dev|nightly|snapshot|preview)").unwrap()
+});
+
+/// vx settings of an asdf plugin (`.vx-plugin.toml`)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AsdfPluginConfig {
+    /// Main executable, when it is not named like the plugin (e.g. `node` for
+    /// the `nodejs` plugin)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub executable: Option<String>,
+    /// One-line description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Repository the plugin was cloned from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+}
+
+impl AsdfPluginConfig {
+    /// Read the config of the plugin in `dir`; the default when there is none
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(PLUGIN_CONFIG_FILE);
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("Invalid {}", path.display()))
+    }
+
+    /// Write the config into the plugin directory `dir`
+    pub fn save(&self, dir: &Path) -> Result<()> {
+        let path = dir.join(PLUGIN_CONFIG_FILE);
+        std::fs::write(&path, toml::to_string(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// An asdf plugin directory
+#[derive(Debug, Clone)]
+pub struct AsdfPlugin {
+    name: String,
+    dir: PathBuf,
+    config: AsdfPluginConfig,
+}
+
+impl AsdfPlugin {
+    /// Load the plugin in `dir`, named after the directory
+    ///
+    /// Fails when the name is not a valid runtime name or `bin/list-all` or
+    /// `bin/install` is missing.
+    pub fn load(dir: &Path) -> Result<Self> {
+        let name = dir
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        validate_plugin_name(&name)?;
+        for script in ["list-all", "install"] {
+            if !dir.join("bin").join(script).is_file() {
+                bail!("asdf plugin '{}' has no bin/{}", name, script);
+            }
+        }
+        Ok(Self {
+            config: AsdfPluginConfig::load(dir)?,
+            name,
+            dir: dir.to_path_buf(),
+        })
+    }
+
+    /// Plugin name, also the runtime name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Plugin directory
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// vx settings of the plugin
+    pub fn config(&self) -> &AsdfPluginConfig {
+        &self.config
+    }
+
+    /// Main executable name
+    pub fn executable(&self) -> &str {
+        self.config.executable.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Whether the plugin has `bin/<script>`
+    pub fn has_script(&self, script: &str) -> bool {
+        self.dir.join("bin").join(script).is_file()
+    }
+
+    /// Path of `bin/<script>`, which must resolve to a file inside the plugin
+    pub fn script(&self, script: &str) -> Result<PathBuf> {
+        let path = self.dir.join("bin").join(script);
+        let resolved = path
+            .canonicalize()
+            .with_context(|| format!("asdf plugin '{}' has no bin/{}", self.name, script))?;
+        let root = self
+            .dir
+            .canonicalize()
+            .with_context(|| format!("Failed to resolve {}", self.dir.display()))?;
+        if !resolved.starts_with(&root) || !resolved.is_file() {
+            bail!(
+                "asdf plugin '{}': bin/{} points outside the plugin directory",
+                self.name,
+                script
+            );
+        }
+        Ok(resolved)
+    }
+}
+
+/// Check that `name` can be used as a plugin and runtime name
+pub fn validate_plugin_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && !name.starts_with(['.', '-'])
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !valid {
+        bail!("Invalid asdf plugin name '{}'", name);
+    }
+    Ok(())
+}
+
+/// Load every plugin directory under `root`, sorted by name
+///
+/// Returns the plugins and the directories that were skipped with the reason.
+/// A missing `root` yields no plugins.
+pub fn discover_asdf_plugins(root: &Path) -> (Vec<AsdfPlugin>, Vec<(PathBuf, String)>) {
+    let mut plugins = Vec::new();
+    let mut skipped = Vec::new();
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return (plugins, skipped);
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        match AsdfPlugin::load(&path) {
+            Ok(plugin) => plugins.push(plugin),
+            Err(e) => skipped.push((path, e.to_string())),
+        }
+    }
+    plugins.sort_by(|a, b| a.name.cmp(&b.name));
+    (plugins, skipped)
+}
+
+/// Versions printed by `bin/list-all`, newest first
+pub fn parse_list_all(output: &str) -> Vec<VersionInfo> {
+    output
+        .split_whitespace()
+        .rev()
+        .map(|v| VersionInfo::new(v).with_prerelease(PRERELEASE_RE.is_match(v)))
+        .collect()
+}
+
+/// Provider wrapping an asdf plugin
+pub struct AsdfProvider {
+    plugin: AsdfPlugin,
+    runtime: Arc<AsdfRuntime>,
+}
+
+impl AsdfProvider {
+    /// Provider for `plugin`
+    pub fn new(plugin: AsdfPlugin) -> Self {
+        Self {
+            runtime: Arc::new(AsdfRuntime::new(plugin.clone())),
+            plugin,
+        }
+    }
+}
+
+impl Provider for AsdfProvider {
+    fn name(&self) -> &str {
+        self.plugin.name()
+    }
+
+    fn description(&self) -> &str {
+        self.runtime.description()
+    }
+
+    fn runtimes(&self) -> Vec<Arc<dyn Runtime>> {
+        vec![self.runtime.clone()]
+    }
+}
+
+/// Runtime backed by the scripts of an asdf plugin
+pub struct AsdfRuntime {
+    plugin: AsdfPlugin,
+    description: String,
+}
+
+impl AsdfRuntime {
+    /// Runtime for `plugin`
+    pub fn new(plugin: AsdfPlugin) -> Self {
+        let description = plugin
+            .config
+            .description
+            .clone()
+            .unwrap_or_else(|| format!("{} (asdf plugin)", plugin.name));
+        Self {
+            plugin,
+            description,
+        }
+    }
+
+    fn sandbox(&self, ctx: &RuntimeContext) -> AsdfSandbox {
+        AsdfSandbox::new(ctx.paths.cache_dir().join("asdf").join(self.plugin.name()))
+    }
+
+    /// Bin paths of an installed version, relative to its install directory
+    fn bin_paths(install_path: &Path) -> Vec<String> {
+        let paths: Vec<String> = std::fs::read_to_string(install_path.join(BIN_PATHS_FILE))
+            .map(|s| s.split_whitespace().map(String::from).collect())
+            .unwrap_or_default();
+        if paths.is_empty() {
+            vec!["bin".to_string()]
+        } else {
+            paths
+        }
+    }
+
+    async fn run_install_scripts(
+        &self,
+        version: &str,
+        install_path: &Path,
+        ctx: &RuntimeContext,
+    ) -> Result<()> {
+        let sandbox = self.sandbox(ctx);
+        let download_path = sandbox.scratch_dir().join("downloads").join(version);
+        std::fs::create_dir_all(&download_path)?;
+        std::fs::create_dir_all(install_path)?;
+
+        let concurrency = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        let env = [
+            ("ASDF_INSTALL_TYPE", "version".to_string()),
+            ("ASDF_INSTALL_VERSION", version.to_string()),
+            ("ASDF_INSTALL_PATH", install_path.display().to_string()),
+            ("ASDF_DOWNLOAD_PATH", download_path.display().to_string()),
+            ("ASDF_CONCURRENCY", concurrency.to_string()),
+        ];
+
+        let result = async {
+            if self.plugin.has_script("download") {
+                sandbox
+                    .run(&self.plugin, "download", &env, INSTALL_TIMEOUT)
+                    .await?;
+            }
+            sandbox
+                .run(&self.plugin, "install", &env, INSTALL_TIMEOUT)
+                .await?;
+            if self.plugin.has_script("list-bin-paths") {
+                let paths = sandbox
+                    .run(&self.plugin, "list-bin-paths", &env, QUERY_TIMEOUT)
+                    .await?;
+                std::fs::write(install_path.join(BIN_PATHS_FILE), paths.trim())?;
+            }
+            Ok(())
+        }
+        .await;
+
+        let _ = std::fs::remove_dir_all(&download_path);
+        result
+    }
+}
+
+#[async_trait]
+impl Runtime for AsdfRuntime {
+    fn name(&self) -> &str {
+        self.plugin.name()
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        let mut meta = HashMap::new();
+        meta.insert("source".to_string(), "asdf".to_string());
+        meta.insert(
+            "plugin_dir".to_string(),
+            self.plugin.dir().display().to_string(),
+        );
+        if let Some(repository) = &self.plugin.config.repository {
+            meta.insert("repository".to_string(), repository.clone());
+        }
+        meta
+    }
+
+    /// asdf plugins are shell scripts
+    fn supported_platforms(&self) -> Vec<Platform> {
+        Platform::unix_only()
+    }
+
+    fn executable_name(&self) -> &str {
+        self.plugin.executable()
+    }
+
+    fn executable_dir_path(&self, _version: &str, _platform: &Platform) -> Option<String> {
+        Some("bin".to_string())
+    }
+
+    fn verify_installation(
+        &self,
+        _version: &str,
+        install_path: &Path,
+        _platform: &Platform,
+    ) -> VerificationResult {
+        let bin_paths = Self::bin_paths(install_path);
+        for dir in &bin_paths {
+            let exe = install_path.join(dir).join(self.executable_name());
+            if exe.is_file() {
+                return VerificationResult::success(exe);
+            }
+        }
+        VerificationResult::failure(
+            vec![format!(
+                "'{}' not found in {} ({})",
+                self.executable_name(),
+                install_path.display(),
+                bin_paths.join(", ")
+            )],
+            vec![format!(
+                "Set `executable` in {} of the plugin",
+                PLUGIN_CONFIG_FILE
+            )],
+        )
+    }
+
+    async fn fetch_versions(&self, ctx: &RuntimeContext) -> Result<Vec<VersionInfo>> {
+        self.check_platform_support().map_err(anyhow::Error::msg)?;
+        let output = self
+            .sandbox(ctx)
+            .run(&self.plugin, "list-all", &[], QUERY_TIMEOUT)
+            .await?;
+        Ok(parse_list_all(&output))
+    }
+
+    async fn install(&self, version: &str, ctx: &RuntimeContext) -> Result<InstallResult> {
+        self.check_platform_support().map_err(anyhow::Error::msg)?;
+        let platform = Platform::current();
+        let install_path = ctx.paths.version_store_dir(self.store_name(), version);
+
+        if install_path.exists() {
+            let verification = self.verify_installation(version, &install_path, &platform);
+            if let Some(exe) = verification.executable_path {
+                return Ok(InstallResult::already_installed(
+                    install_path,
+                    exe,
+                    version.to_string(),
+                ));
+            }
+            std::fs::remove_dir_all(&install_path)?;
+        }
+
+        if let Err(e) = self.run_install_scripts(version, &install_path, ctx).await {
+            let _ = std::fs::remove_dir_all(&install_path);
+            return Err(e);
+        }
+
+        let verification = self.verify_installation(version, &install_path, &platform);
+        let Some(exe) = verification.executable_path else {
+            let _ = std::fs::remove_dir_all(&install_path);
+            bail!(
+                "Installation of {} {} completed but {}",
+                self.name(),
+                version,
+                verification.issues.join("; ")
+            );
+        };
+        let result = InstallResult::success(install_path, exe, version.to_string());
+        crate::runtime::install_impl::record_install(&result, ctx);
+        Ok(result)
+    }
+}
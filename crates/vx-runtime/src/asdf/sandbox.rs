@@ -0,0 +1,145 @@
+//! Sandboxed execution of asdf plugin scripts
+//!
+//! Plugin scripts are third-party shell code, so they never see the user's
+//! environment or home directory:
+//!
+//! - the environment is cleared; only [`PASSTHROUGH_ENV`] and the `ASDF_*`
+//!   variables of the call are set
+//! - `HOME`, `TMPDIR` and `ASDF_DATA_DIR` point into a per-plugin scratch
+//!   directory, which is also the working directory
+//! - stdin is closed and every run has a timeout
+//! - only scripts inside the plugin directory can be run (symlinks pointing
+//!   out of it are rejected)
+//!
+//! This is environment isolation, not a security boundary: scripts still run
+//! with the user's permissions.
+
+use super::AsdfPlugin;
+use anyhow::{Context, Result, bail};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
+
+/// Variables passed through from the vx environment
+///
+/// Proxy and certificate settings keep downloads working behind corporate
+/// networks; `GITHUB_API_TOKEN` is read by most plugins to avoid GitHub rate
+/// limits.
+pub const PASSTHROUGH_ENV: &[&str] = &[
+    "PATH",
+    "LANG",
+    "LC_ALL",
+    "TERM",
+    "HTTP_PROXY",
+    "HTTPS_PROXY",
+    "NO_PROXY",
+    "http_proxy",
+    "https_proxy",
+    "no_proxy",
+    "SSL_CERT_FILE",
+    "SSL_CERT_DIR",
+    "GITHUB_API_TOKEN",
+];
+
+/// Timeout of `bin/list-all` and other query scripts
+pub const QUERY_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Timeout of `bin/download` and `bin/install`
+pub const INSTALL_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+/// Lines of stderr quoted in the error of a failed script
+const STDERR_TAIL_LINES: usize = 20;
+
+/// Runs the scripts of one plugin inside a scratch directory
+#[derive(Debug, Clone)]
+pub struct AsdfSandbox {
+    scratch_dir: PathBuf,
+}
+
+impl AsdfSandbox {
+    /// Sandbox using `scratch_dir` as home, temp and working directory
+    pub fn new(scratch_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            scratch_dir: scratch_dir.into(),
+        }
+    }
+
+    /// The scratch directory
+    pub fn scratch_dir(&self) -> &Path {
+        &self.scratch_dir
+    }
+
+    /// Run `bin/<script>` of `plugin` and return its stdout
+    ///
+    /// Fails when the script exits non-zero or exceeds `timeout`; the error
+    /// quotes the end of its stderr.
+    pub async fn run(
+        &self,
+        plugin: &AsdfPlugin,
+        script: &str,
+        env: &[(&str, String)],
+        timeout: Duration,
+    ) -> Result<String> {
+        let script_path = plugin.script(script)?;
+        let home = self.scratch_dir.join("home");
+        let tmp = self.scratch_dir.join("tmp");
+        for dir in [&home, &tmp] {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+
+        let mut cmd = tokio::process::Command::new(&script_path);
+        cmd.env_clear()
+            .current_dir(&self.scratch_dir)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        for key in PASSTHROUGH_ENV {
+            if let Some(value) = std::env::var_os(key) {
+                cmd.env(key, value);
+            }
+        }
+        cmd.env("HOME", &home)
+            .env("TMPDIR", &tmp)
+            .env("ASDF_DATA_DIR", &self.scratch_dir)
+            .env("ASDF_PLUGIN_PATH", plugin.dir());
+        for (key, value) in env {
+            cmd.env(key, value);
+        }
+
+        tracing::debug!(
+            plugin = %plugin.name(),
+            "Running asdf script {}",
+            script_path.display()
+        );
+        let child = cmd
+            .spawn()
+            .with_context(|| format!("Failed to run {}", script_path.display()))?;
+        let output = tokio::time::timeout(timeout, child.wait_with_output())
+            .await
+            .map_err(|_| {
+                anyhow::anyhow!(
+                    "asdf plugin '{}': bin/{} timed out after {}s",
+                    plugin.name(),
+                    script,
+                    timeout.as_secs()
+                )
+            })?
+            .with_context(|| format!("Failed to run {}", script_path.display()))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let lines: Vec<&str> = stderr.lines().collect();
+            let tail = lines[lines.len().saturating_sub(STDERR_TAIL_LINES)..].join("\n");
+            bail!(
+                "asdf plugin '{}': bin/{} failed ({})\n{}",
+                plugin.name(),
+                script,
+                output.status,
+                tail
+            );
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
@@ -0,0 +1,218 @@
+//! CLI subcommands contributed by providers
+//!
+//! A provider can add its own subcommands, invoked as
+//! `vx <provider> <subcommand> [args]`, by returning them from
+//! [`Provider::commands`](crate::Provider::commands). Each contribution
+//! describes itself with a [`CommandSpec`]; the CLI turns that into a parser
+//! with help text and shell completions, and calls
+//! [`CommandContribution::run`] with the parsed [`CommandInput`].
+//!
+//! ```rust,no_run
+//! use anyhow::Result;
+//! use async_trait::async_trait;
+//! use vx_runtime::{CommandArg, CommandContribution, CommandInput, CommandSpec, RuntimeContext};
+//!
+//! struct CleanCache;
+//!
+//! #[async_trait]
+//! impl CommandContribution for CleanCache {
+//!     fn spec(&self) -> CommandSpec {
+//!         CommandSpec::new("clean-cache", "Remove the package cache")
+//!             .arg(CommandArg::flag("dry-run", "Only show what would be removed").short('n'))
+//!     }
+//!
+//!     async fn run(&self, input: &CommandInput, _ctx: &RuntimeContext) -> Result<i32> {
+//!         if input.flag("dry-run") {
+//!             println!("would remove the cache");
+//!         }
+//!         Ok(0)
+//!     }
+//! }
+//! ```
+
+use crate::context::RuntimeContext;
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+/// How a command argument is given on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandArgKind {
+    /// `--name`, no value
+    Flag,
+    /// `--name <value>`
+    Option,
+    /// A bare value, in declaration order
+    Positional,
+}
+
+/// One argument of a contributed command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandArg {
+    /// Argument name; also the long flag for flags and options
+    pub name: String,
+    pub kind: CommandArgKind,
+    /// Help text
+    pub help: String,
+    /// Short flag for flags and options
+    pub short: Option<char>,
+    pub required: bool,
+    /// Accept the argument more than once (or several positional values)
+    pub multiple: bool,
+    /// Value used when the argument is not given
+    pub default: Option<String>,
+    /// Allowed values (empty: any value)
+    pub choices: Vec<String>,
+}
+
+impl CommandArg {
+    fn new(name: impl Into<String>, kind: CommandArgKind, help: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            help: help.into(),
+            short: None,
+            required: false,
+            multiple: false,
+            default: None,
+            choices: Vec::new(),
+        }
+    }
+
+    /// A `--name` flag
+    pub fn flag(name: impl Into<String>, help: impl Into<String>) -> Self {
+        Self::new(name, CommandArgKind::Flag, help)
+    }
+
+    /// A `--name <value>` option
+    pub fn option(name: impl Into<String>, help: impl Into<String>) -> Self {
+        Self::new(name, CommandArgKind::Option, help)
+    }
+
+    /// A positional argument
+    pub fn positional(name: impl Into<String>, help: impl Into<String>) -> Self {
+        Self::new(name, CommandArgKind::Positional, help)
+    }
+
+    /// Set the short flag
+    pub fn short(mut self, short: char) -> Self {
+        self.short = Some(short);
+        self
+    }
+
+    /// Mark the argument as required
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Accept several values
+    pub fn multiple(mut self) -> Self {
+        self.multiple = true;
+        self
+    }
+
+    /// Set the default value
+    pub fn default_value(mut self, value: impl Into<String>) -> Self {
+        self.default = Some(value.into());
+        self
+    }
+
+    /// Restrict the value to `choices`
+    pub fn choices<I, S>(mut self, choices: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.choices = choices.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+/// Name, help text and argument schema of a contributed command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandSpec {
+    /// Subcommand name (`vx <provider> <name>`)
+    pub name: String,
+    /// One-line description shown in help and completions
+    pub about: String,
+    /// Longer help shown by `--help`
+    pub long_about: Option<String>,
+    pub args: Vec<CommandArg>,
+}
+
+impl CommandSpec {
+    /// Create a command without arguments
+    pub fn new(name: impl Into<String>, about: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            about: about.into(),
+            long_about: None,
+            args: Vec::new(),
+        }
+    }
+
+    /// Set the longer help text
+    pub fn long_about(mut self, text: impl Into<String>) -> Self {
+        self.long_about = Some(text.into());
+        self
+    }
+
+    /// Add an argument
+    pub fn arg(mut self, arg: CommandArg) -> Self {
+        self.args.push(arg);
+        self
+    }
+}
+
+/// Parsed arguments of a contributed command, keyed by argument name
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandInput {
+    values: HashMap<String, Vec<String>>,
+    flags: HashMap<String, bool>,
+}
+
+impl CommandInput {
+    /// Create empty input
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the values of an option or positional argument
+    pub fn insert_values(&mut self, name: impl Into<String>, values: Vec<String>) {
+        self.values.insert(name.into(), values);
+    }
+
+    /// Record whether a flag was given
+    pub fn insert_flag(&mut self, name: impl Into<String>, set: bool) {
+        self.flags.insert(name.into(), set);
+    }
+
+    /// First value of an option or positional argument
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values
+            .get(name)
+            .and_then(|values| values.first())
+            .map(String::as_str)
+    }
+
+    /// All values of an option or positional argument
+    pub fn get_all(&self, name: &str) -> &[String] {
+        self.values.get(name).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Whether a flag was given
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.get(name).copied().unwrap_or(false)
+    }
+}
+
+/// A subcommand contributed by a provider
+#[async_trait]
+pub trait CommandContribution: Send + Sync {
+    /// Name, help text and arguments
+    fn spec(&self) -> CommandSpec;
+
+    /// Run the command, returning its exit code
+    async fn run(&self, input: &CommandInput, ctx: &RuntimeContext) -> Result<i32>;
+}
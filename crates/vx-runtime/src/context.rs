@@ -3,6 +3,7 @@
 //! These contexts provide all external dependencies needed by runtimes,
 //! allowing for easy testing through mock implementations.
 
+use crate::advisory::SecurityPolicy;
 use crate::traits::{CommandExecutor, FileSystem, HttpClient, Installer, PathProvider};
 use crate::types::VersionInfo;
 use std::collections::HashMap;
@@ -77,6 +78,12 @@ pub struct RuntimeContext {
     /// The environment variable fallback is still supported for backward compatibility
     /// (e.g., `VX_MSVC_COMPONENTS=spectre vx install msvc`).
     pub install_options: HashMap<String, String>,
+
+    /// Vulnerability-aware resolution (`[security.resolution]` in vx.toml)
+    ///
+    /// When set, resolving `latest` or a range skips versions with advisories
+    /// and respects security floors; see [`crate::advisory`].
+    pub security: Option<Arc<SecurityPolicy>>,
 }
 
 impl RuntimeContext {
@@ -96,6 +103,7 @@ impl RuntimeContext {
             version_cache: None,
             download_url_cache: None,
             install_options: HashMap::new(),
+            security: None,
         }
     }
 
@@ -111,6 +119,12 @@ impl RuntimeContext {
         self
     }
 
+    /// Set the vulnerability-aware resolution policy
+    pub fn with_security_policy(mut self, policy: Option<Arc<SecurityPolicy>>) -> Self {
+        self.security = policy;
+        self
+    }
+
     /// Set cache mode
     pub fn with_cache_mode(mut self, mode: CacheMode) -> Self {
         self.config.cache_mode = mode;
@@ -152,6 +166,32 @@ impl RuntimeContext {
         self.install_options.get(key).map(|s| s.as_str())
     }
 
+    /// Install option carrying a tool's `components` from vx.toml
+    ///
+    /// `VX_<TOOL>_COMPONENTS`, e.g. `VX_MSVC_COMPONENTS` or `VX_LLVM_COMPONENTS`.
+    pub fn components_option_key(tool: &str) -> String {
+        format!("VX_{}_COMPONENTS", tool.to_uppercase().replace('-', "_"))
+    }
+
+    /// Components selected for `tool`, from its install options or the
+    /// `VX_<TOOL>_COMPONENTS` environment variable
+    ///
+    /// Returns `None` when no selection was made.
+    pub fn selected_components(&self, tool: &str) -> Option<Vec<String>> {
+        let key = Self::components_option_key(tool);
+        let value = self
+            .get_install_option(&key)
+            .map(str::to_string)
+            .or_else(|| std::env::var(&key).ok())?;
+        let components: Vec<String> = value
+            .split(',')
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+            .map(str::to_string)
+            .collect();
+        (!components.is_empty()).then_some(components)
+    }
+
     /// Set download URL cache from lock file (mutating version)
     pub fn set_download_url_cache(&mut self, cache: HashMap<String, String>) {
         self.download_url_cache = Some(cache);
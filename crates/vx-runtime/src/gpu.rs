@@ -0,0 +1,178 @@
+//! GPU and CUDA detection
+//!
+//! [`GpuInfo::current`] probes the NVIDIA driver once per process: the GPUs,
+//! the driver version and the newest CUDA version the driver supports come
+//! from `nvidia-smi`, the installed CUDA toolkit from `nvcc --version`
+//! (on PATH or under `CUDA_PATH` / `CUDA_HOME`).
+//!
+//! `VX_CUDA` overrides detection: `VX_CUDA=12.4` behaves like a driver that
+//! supports CUDA 12.4 (e.g. to solve GPU environments in a CI container
+//! without a GPU), `VX_CUDA=none` hides the GPU.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::OnceLock;
+
+/// A detected GPU
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GpuDevice {
+    /// Marketing name (e.g. "NVIDIA GeForce RTX 4090")
+    pub name: String,
+    /// Total memory in MiB
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_mib: Option<u64>,
+}
+
+/// NVIDIA driver and CUDA availability of this machine
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GpuInfo {
+    /// NVIDIA driver version (e.g. "550.54.14")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver_version: Option<String>,
+    /// Newest CUDA version the driver supports (e.g. "12.4")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cuda_version: Option<String>,
+    /// Installed CUDA toolkit version from `nvcc` (e.g. "12.4")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub toolkit_version: Option<String>,
+    /// Detected GPUs
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub devices: Vec<GpuDevice>,
+}
+
+impl GpuInfo {
+    /// Environment variable that overrides detection (`12.4` or `none`)
+    pub const ENV_VAR: &'static str = "VX_CUDA";
+
+    /// GPU information of this machine, detected on first use
+    pub fn current() -> &'static GpuInfo {
+        static CURRENT: OnceLock<GpuInfo> = OnceLock::new();
+        CURRENT.get_or_init(|| {
+            std::env::var(Self::ENV_VAR)
+                .ok()
+                .and_then(|value| Self::from_override(&value))
+                .unwrap_or_else(Self::detect)
+        })
+    }
+
+    /// GPU information for a `VX_CUDA` value, `None` when it is not set
+    pub fn from_override(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.is_empty() {
+            return None;
+        }
+        if matches!(value.to_ascii_lowercase().as_str(), "none" | "0" | "false") {
+            return Some(Self::default());
+        }
+        Some(Self {
+            cuda_version: Some(value.to_string()),
+            ..Self::default()
+        })
+    }
+
+    /// Probe the NVIDIA driver and CUDA toolkit
+    pub fn detect() -> Self {
+        let mut info = Self::default();
+        if let Ok(smi) = which::which("nvidia-smi") {
+            if let Some(query) = run(Command::new(&smi).args([
+                "--query-gpu=name,driver_version,memory.total",
+                "--format=csv,noheader,nounits",
+            ])) {
+                let (devices, driver) = parse_gpu_query(&query);
+                info.devices = devices;
+                info.driver_version = driver;
+            }
+            if !info.devices.is_empty() {
+                info.cuda_version = run(&mut Command::new(&smi))
+                    .as_deref()
+                    .and_then(parse_cuda_version);
+            }
+        }
+        info.toolkit_version = nvcc_path()
+            .and_then(|nvcc| run(Command::new(nvcc).arg("--version")))
+            .as_deref()
+            .and_then(parse_nvcc_version);
+        info
+    }
+
+    /// Whether a CUDA-capable GPU is usable
+    pub fn is_available(&self) -> bool {
+        self.cuda_version.is_some()
+    }
+
+    /// Major version of the CUDA version the driver supports
+    pub fn cuda_major(&self) -> Option<u32> {
+        self.cuda_version
+            .as_deref()
+            .and_then(|v| v.split('.').next())
+            .and_then(|major| major.parse().ok())
+    }
+
+    /// One-line description for diagnostics (e.g. "CUDA 12.4, driver 550.54.14")
+    pub fn summary(&self) -> String {
+        match (&self.cuda_version, &self.driver_version) {
+            (Some(cuda), Some(driver)) => format!("CUDA {}, driver {}", cuda, driver),
+            (Some(cuda), None) => format!("CUDA {}", cuda),
+            (None, _) if !self.devices.is_empty() => "GPU without CUDA support".to_string(),
+            (None, _) => "no NVIDIA GPU detected".to_string(),
+        }
+    }
+}
+
+/// Parse `nvidia-smi --query-gpu=name,driver_version,memory.total
+/// --format=csv,noheader,nounits` into the devices and the driver version
+pub fn parse_gpu_query(output: &str) -> (Vec<GpuDevice>, Option<String>) {
+    let mut driver = None;
+    let devices = output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split(',').map(str::trim);
+            let name = fields.next().filter(|n| !n.is_empty())?;
+            if let Some(version) = fields.next().filter(|v| !v.is_empty()) {
+                driver.get_or_insert_with(|| version.to_string());
+            }
+            Some(GpuDevice {
+                name: name.to_string(),
+                memory_mib: fields.next().and_then(|m| m.parse().ok()),
+            })
+        })
+        .collect();
+    (devices, driver)
+}
+
+/// CUDA version from the `nvidia-smi` banner (`CUDA Version: 12.4`)
+pub fn parse_cuda_version(output: &str) -> Option<String> {
+    let rest = output.split("CUDA Version:").nth(1)?;
+    let version = rest.split_whitespace().next()?;
+    version
+        .chars()
+        .all(|c| c.is_ascii_digit() || c == '.')
+        .then(|| version.to_string())
+}
+
+/// Toolkit version from `nvcc --version` (`release 12.4, V12.4.131`)
+pub fn parse_nvcc_version(output: &str) -> Option<String> {
+    let rest = output.split("release ").nth(1)?;
+    let version = rest.split([',', ' ', '\n']).next()?.trim();
+    (!version.is_empty()).then(|| version.to_string())
+}
+
+fn nvcc_path() -> Option<PathBuf> {
+    let exe = if cfg!(windows) { "nvcc.exe" } else { "nvcc" };
+    which::which("nvcc").ok().or_else(|| {
+        ["CUDA_PATH", "CUDA_HOME"]
+            .iter()
+            .filter_map(std::env::var_os)
+            .map(|root| PathBuf::from(root).join("bin").join(exe))
+            .find(|path| path.is_file())
+    })
+}
+
+fn run(command: &mut Command) -> Option<String> {
+    let output = command.output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
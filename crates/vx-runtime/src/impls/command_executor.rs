@@ -1,4 +1,7 @@
 //! Real command executor implementation
+//!
+//! While a cassette is active (`VX_RECORD` / `VX_REPLAY`), every run is
+//! recorded into it, or answered from it without spawning the process.
 
 use crate::traits::CommandExecutor;
 use crate::types::ExecutionResult;
@@ -6,6 +9,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use vx_cache::cassette::{self, CassetteMode, ProcessInteraction};
 
 /// Real command executor
 pub struct RealCommandExecutor;
@@ -33,47 +37,88 @@ impl CommandExecutor for RealCommandExecutor {
         env: &HashMap<String, String>,
         capture_output: bool,
     ) -> Result<ExecutionResult> {
-        use std::process::Stdio;
-        use tokio::process::Command;
-
-        let mut cmd = Command::new(program);
-        cmd.args(args);
-
-        if let Some(dir) = working_dir {
-            cmd.current_dir(dir);
+        let Some(cassette) = cassette::active() else {
+            return run(program, args, working_dir, env, capture_output).await;
+        };
+        if cassette.mode() == CassetteMode::Replay {
+            let recorded = cassette.replay_process(program, args).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "`{} {}` is not recorded in cassette {}",
+                    program,
+                    args.join(" "),
+                    cassette.path().display()
+                )
+            })?;
+            return Ok(ExecutionResult {
+                exit_code: recorded.exit_code,
+                stdout: capture_output.then(|| recorded.stdout.unwrap_or_default()),
+                stderr: capture_output.then(|| recorded.stderr.unwrap_or_default()),
+            });
         }
 
-        for (key, value) in env {
-            cmd.env(key, value);
-        }
-
-        if capture_output {
-            cmd.stdout(Stdio::piped());
-            cmd.stderr(Stdio::piped());
-
-            let output = cmd.output().await?;
-
-            Ok(ExecutionResult {
-                exit_code: output.status.code().unwrap_or(-1),
-                stdout: Some(String::from_utf8_lossy(&output.stdout).to_string()),
-                stderr: Some(String::from_utf8_lossy(&output.stderr).to_string()),
-            })
-        } else {
-            cmd.stdin(Stdio::inherit());
-            cmd.stdout(Stdio::inherit());
-            cmd.stderr(Stdio::inherit());
-
-            let status = cmd.status().await?;
-
-            Ok(ExecutionResult {
-                exit_code: status.code().unwrap_or(-1),
-                stdout: None,
-                stderr: None,
-            })
+        let result = run(program, args, working_dir, env, capture_output).await?;
+        let interaction = ProcessInteraction {
+            program: program.to_string(),
+            args: args.to_vec(),
+            exit_code: result.exit_code,
+            stdout: result.stdout.clone(),
+            stderr: result.stderr.clone(),
+        };
+        if let Err(e) = cassette.record_process(interaction) {
+            tracing::warn!(error = %e, "Failed to record process run");
         }
+        Ok(result)
     }
 
     fn which(&self, program: &str) -> Option<PathBuf> {
         which::which(program).ok()
     }
 }
+
+/// Spawn the process
+async fn run(
+    program: &str,
+    args: &[String],
+    working_dir: Option<&Path>,
+    env: &HashMap<String, String>,
+    capture_output: bool,
+) -> Result<ExecutionResult> {
+    use std::process::Stdio;
+    use tokio::process::Command;
+
+    let mut cmd = Command::new(program);
+    cmd.args(args);
+
+    if let Some(dir) = working_dir {
+        cmd.current_dir(dir);
+    }
+
+    for (key, value) in env {
+        cmd.env(key, value);
+    }
+
+    if capture_output {
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::piped());
+
+        let output = cmd.output().await?;
+
+        Ok(ExecutionResult {
+            exit_code: output.status.code().unwrap_or(-1),
+            stdout: Some(String::from_utf8_lossy(&output.stdout).to_string()),
+            stderr: Some(String::from_utf8_lossy(&output.stderr).to_string()),
+        })
+    } else {
+        cmd.stdin(Stdio::inherit());
+        cmd.stdout(Stdio::inherit());
+        cmd.stderr(Stdio::inherit());
+
+        let status = cmd.status().await?;
+
+        Ok(ExecutionResult {
+            exit_code: status.code().unwrap_or(-1),
+            stdout: None,
+            stderr: None,
+        })
+    }
+}
@@ -5,6 +5,7 @@
 //! - `Runtime` trait: Core abstraction for executable runtimes (node, go, uv, etc.)
 //! - `Provider` trait: Container for related runtimes
 //! - `ProviderRegistry`: Registry for all providers
+//! - `CommandContribution` trait: CLI subcommands contributed by providers
 //! - Dependency injection via `RuntimeContext` and `ExecutionContext`
 //! - Mock implementations for testing
 //!
@@ -43,9 +44,13 @@
 //! }
 //! ```
 
+pub mod advisory;
+pub mod asdf;
+pub mod command;
 pub mod constraints;
 pub mod context;
 pub mod ecosystem;
+pub mod gpu;
 pub mod impls;
 pub mod integrated_resolver;
 pub mod layout;
@@ -53,6 +58,7 @@ pub mod manifest_runtime;
 pub mod normalizer;
 pub mod package_runtime;
 pub mod platform;
+pub mod preflight;
 pub mod provider;
 pub mod provider_env;
 pub mod provider_loader;
@@ -64,10 +70,18 @@ pub mod shim;
 pub mod testing;
 pub mod traits;
 pub mod types;
+pub mod user_mirrors;
 
 // Re-exports
+pub use advisory::{
+    Advisory, AdvisorySource, OsvClient, OsvPackage, SecureResolution, SecurityPolicy, Severity,
+    SkippedVersion,
+};
+pub use asdf::{AsdfPlugin, AsdfPluginConfig, AsdfProvider, AsdfRuntime, discover_asdf_plugins};
+pub use command::{CommandArg, CommandArgKind, CommandContribution, CommandInput, CommandSpec};
 pub use context::{ExecutionContext, RuntimeContext};
 pub use ecosystem::Ecosystem;
+pub use gpu::{GpuDevice, GpuInfo};
 pub use impls::{RealCommandExecutor, RealFileSystem, RealPathProvider};
 // Note: RealHttpClient, RealInstaller, create_runtime_context* have moved to vx-runtime-http
 pub use layout::{
@@ -75,9 +89,9 @@ pub use layout::{
     ResolvedLayout,
 };
 pub use package_runtime::{InstallMethod, PackageRuntime};
-pub use platform::{Arch, Libc, Os, Platform, compare_semver};
+pub use platform::{Arch, ArchFallback, Libc, Os, Platform, compare_semver};
 pub use provider::Provider;
-pub use registry::{PlatformError, ProviderRegistry};
+pub use registry::{PlatformError, ProviderFailure, ProviderRegistry};
 pub use runtime::{
     Runtime,
     // ISP sub-traits (blanket-implemented for all Runtime types)
@@ -97,8 +111,8 @@ pub use traits::{
     CommandExecutor, CorePathProvider, FileSystem, HttpClient, Installer, PathProvider,
 };
 pub use types::{
-    ExecutionPrep, ExecutionResult, InstallResult, RuntimeDependency, RuntimeSpec, VersionInfo,
-    VersionInfoResult,
+    ExecutionPrep, ExecutionResult, InstallKind, InstallResult, RuntimeDependency, RuntimeSpec,
+    VersionInfo, VersionInfoResult,
 };
 
 // Re-export testing utilities (only available with "testing" feature or in test builds)
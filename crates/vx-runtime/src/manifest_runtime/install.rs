@@ -4,6 +4,7 @@
 //! - Starlark-driven install_layout (direct download with custom strip_prefix)
 //! - Direct download URL (from `download_url_fn` or `InstallStrategy::DirectDownload`)
 //! - System package managers (brew, choco, apt, etc.)
+//! - System-managed installs (winget/choco with pinned versions, recorded in the store)
 //! - Script-based installation
 //! - ProvidedBy (executable from another runtime)
 
@@ -12,7 +13,8 @@ use std::path::PathBuf;
 
 use anyhow::Result;
 use tracing::{debug, info, warn};
-use vx_system_pm::{PackageInstallSpec, PackageManagerRegistry};
+use vx_paths::SystemManagedRecord;
+use vx_system_pm::{PackageInstallSpec, PackageManagerRegistry, SystemPackageManager};
 
 use crate::{InstallResult, Runtime, RuntimeContext, platform::Platform};
 
@@ -59,23 +61,38 @@ impl ManifestDrivenRuntime {
                     self.name, url
                 );
 
+                let selection = self.select_components(&layout, ctx)?;
                 if ctx.fs.exists(&install_path) {
-                    let exe_path = self.resolve_exe_path_from_layout(&install_path, &layout);
-                    return Ok(InstallResult::already_installed(
-                        install_path,
-                        exe_path,
-                        version.to_string(),
-                    ));
+                    if components_installed(ctx, &install_path, selection.as_ref()) {
+                        let exe_path = self.resolve_exe_path_from_layout(&install_path, &layout);
+                        return Ok(InstallResult::already_installed(
+                            install_path,
+                            exe_path,
+                            version.to_string(),
+                        ));
+                    }
+                    info!(
+                        "Reinstalling {} {} with more components",
+                        self.name, version
+                    );
+                    ctx.fs.remove_dir_all(&install_path)?;
                 }
 
                 let mut layout_meta = HashMap::new();
                 if let Some(prefix) = layout.get("strip_prefix").and_then(|s| s.as_str()) {
                     layout_meta.insert("strip_prefix".to_string(), prefix.to_string());
                 }
+                if let Some(ref selection) = selection {
+                    layout_meta.insert("include".to_string(), selection.include.join(","));
+                }
+                if let Some(formula) = layout.get("bottle").and_then(|s| s.as_str()) {
+                    layout_meta.insert("bottle".to_string(), formula.to_string());
+                }
 
                 ctx.installer
                     .download_with_layout(&url, &install_path, &layout_meta)
                     .await?;
+                record_components(ctx, &install_path, selection.as_ref())?;
 
                 let exe_path = self.resolve_exe_path_from_layout(&install_path, &layout);
                 return Ok(InstallResult::success(
@@ -110,27 +127,39 @@ impl ManifestDrivenRuntime {
 
         // Resolve install_layout once for strip_prefix / executable_paths hints
         let layout_hint = self.resolve_layout_hint(version).await;
+        let selection = match layout_hint {
+            Some(ref layout) => self.select_components(layout, ctx)?,
+            None => None,
+        };
 
         if ctx.fs.exists(install_path) {
-            let exe_path = if let Some(ref layout) = layout_hint {
-                self.resolve_exe_path_from_layout(install_path, layout)
-            } else {
-                install_path.join(vx_paths::with_executable_extension(&self.executable))
-            };
-            return Ok(InstallResult::already_installed(
-                install_path.to_path_buf(),
-                exe_path,
-                version.to_string(),
-            ));
+            if components_installed(ctx, install_path, selection.as_ref()) {
+                let exe_path = if let Some(ref layout) = layout_hint {
+                    self.resolve_exe_path_from_layout(install_path, layout)
+                } else {
+                    install_path.join(vx_paths::with_executable_extension(&self.executable))
+                };
+                return Ok(InstallResult::already_installed(
+                    install_path.to_path_buf(),
+                    exe_path,
+                    version.to_string(),
+                ));
+            }
+            info!(
+                "Reinstalling {} {} with more components",
+                self.name, version
+            );
+            ctx.fs.remove_dir_all(install_path)?;
         }
 
         // Build layout metadata for download_with_layout
-        let layout_meta = build_layout_meta(layout_hint.as_ref());
+        let layout_meta = build_layout_meta(layout_hint.as_ref(), selection.as_ref());
         debug!("layout_meta for download_with_layout: {:?}", layout_meta);
 
         ctx.installer
             .download_with_layout(url, install_path, &layout_meta)
             .await?;
+        record_components(ctx, install_path, selection.as_ref())?;
 
         let exe_path = if let Some(ref layout) = layout_hint {
             self.resolve_exe_path_from_layout(install_path, layout)
@@ -166,13 +195,62 @@ impl ManifestDrivenRuntime {
         }
     }
 
+    /// Components of `layout` to extract for this install
+    ///
+    /// Reads the selection from the tool's vx.toml `components` (see
+    /// [`RuntimeContext::selected_components`]). Without a selection every
+    /// declared component is extracted, which still leaves out the parts of
+    /// the archive no component covers. Returns `None` when the layout
+    /// declares no components or `all` is selected, meaning the whole archive
+    /// is extracted.
+    pub(crate) fn select_components(
+        &self,
+        layout: &serde_json::Value,
+        ctx: &RuntimeContext,
+    ) -> Result<Option<ComponentSelection>> {
+        let Some(declared) = layout.get("components").and_then(|c| c.as_object()) else {
+            return Ok(None);
+        };
+        if declared.is_empty() {
+            return Ok(None);
+        }
+        let store_name = self.bundled_with.as_deref().unwrap_or(&self.name);
+        let requested = ctx
+            .selected_components(&self.name)
+            .or_else(|| ctx.selected_components(store_name));
+        let names = match requested {
+            Some(names) if names.iter().any(|n| n == "all") => return Ok(None),
+            Some(names) => names,
+            None => declared.keys().cloned().collect(),
+        };
+
+        let mut include: Vec<String> = Vec::new();
+        for name in &names {
+            let Some(patterns) = declared.get(name).and_then(|p| p.as_array()) else {
+                let available: Vec<&str> = declared.keys().map(String::as_str).collect();
+                anyhow::bail!(
+                    "Unknown {} component '{}' (available: {}, all)",
+                    store_name,
+                    name,
+                    available.join(", ")
+                );
+            };
+            for pattern in patterns.iter().filter_map(|p| p.as_str()) {
+                if !include.iter().any(|p| p == pattern) {
+                    include.push(pattern.to_string());
+                }
+            }
+        }
+        Ok(Some(ComponentSelection { names, include }))
+    }
+
     /// Install via system package managers and script strategies.
     async fn install_via_system_strategies(
         &self,
-        _version: &str,
+        version: &str,
         platform: &Platform,
-        _install_path: &std::path::Path,
-        _ctx: &RuntimeContext,
+        install_path: &std::path::Path,
+        ctx: &RuntimeContext,
     ) -> Result<InstallResult> {
         info!(
             "No direct download for {} on {:?}, trying system package managers",
@@ -215,17 +293,9 @@ impl ManifestDrivenRuntime {
                         match pm.install_package(&spec).await {
                             Ok(_) => {
                                 info!("Successfully installed {} via {}", self.name, manager);
-                                // For tools like MSVC cl.exe that are not on PATH,
-                                // search system_paths glob patterns first, then fall back to which.
-                                let exe_path = if !self.system_paths.is_empty() {
-                                    super::find_first_glob_match(&self.system_paths)
-                                        .or_else(|| which::which(&self.executable).ok())
-                                } else {
-                                    which::which(&self.executable).ok()
-                                };
                                 return Ok(InstallResult::system_installed(
                                     format!("system ({})", manager),
-                                    exe_path,
+                                    self.locate_system_executable(),
                                 ));
                             }
                             Err(e) => {
@@ -237,6 +307,38 @@ impl ManifestDrivenRuntime {
                         debug!("Package manager {} not available, skipping", manager);
                     }
                 }
+                InstallStrategy::SystemManaged {
+                    manager,
+                    package,
+                    install_args,
+                    ..
+                } => {
+                    let Some(pm) = available_managers
+                        .iter()
+                        .find(|pm| pm.name().eq_ignore_ascii_case(manager))
+                    else {
+                        debug!("Package manager {} not available, skipping", manager);
+                        continue;
+                    };
+
+                    match self
+                        .install_system_managed(
+                            pm.as_ref(),
+                            package,
+                            install_args.as_deref(),
+                            version,
+                            install_path,
+                            ctx,
+                        )
+                        .await
+                    {
+                        Ok(result) => return Ok(result),
+                        Err(e) => {
+                            warn!("Failed to install {} via {}: {}", self.name, manager, e);
+                            continue;
+                        }
+                    }
+                }
                 InstallStrategy::Script {
                     url,
                     script_type,
@@ -272,7 +374,8 @@ impl ManifestDrivenRuntime {
             .install_strategies
             .iter()
             .filter_map(|s| match s {
-                InstallStrategy::PackageManager { manager, .. } => Some(manager.as_str()),
+                InstallStrategy::PackageManager { manager, .. }
+                | InstallStrategy::SystemManaged { manager, .. } => Some(manager.as_str()),
                 _ => None,
             })
             .collect();
@@ -292,10 +395,147 @@ impl ManifestDrivenRuntime {
             ))
         }
     }
+
+    /// Install through winget/choco with the version pinned, then record the
+    /// installation in the store as system-managed.
+    ///
+    /// `latest`/`system` requests install the package manager's current
+    /// version, and the record is stored under the version it reports.
+    async fn install_system_managed(
+        &self,
+        pm: &dyn SystemPackageManager,
+        package: &str,
+        install_args: Option<&str>,
+        version: &str,
+        install_path: &std::path::Path,
+        ctx: &RuntimeContext,
+    ) -> Result<InstallResult> {
+        if let Some(record) = SystemManagedRecord::read(install_path)
+            && let Some(exe_path) = record.existing_executable()
+        {
+            return Ok(InstallResult::already_installed(
+                install_path.to_path_buf(),
+                exe_path.to_path_buf(),
+                record.version,
+            ));
+        }
+
+        let pinned = !matches!(version, "latest" | "system" | "*");
+        let mut spec = PackageInstallSpec::new(package);
+        spec.install_args = install_args.map(str::to_string);
+        if pinned {
+            spec.version = Some(version.to_string());
+        }
+
+        info!(
+            "Installing {} {} via {} (package: {})",
+            self.name,
+            version,
+            pm.name(),
+            package
+        );
+        let result = pm.install_package(&spec).await?;
+
+        let installed_version = if pinned {
+            version.to_string()
+        } else {
+            result
+                .version
+                .filter(|v| v != "unknown")
+                .unwrap_or_else(|| version.to_string())
+        };
+        let version_dir = if pinned {
+            install_path.to_path_buf()
+        } else {
+            ctx.paths
+                .version_store_dir(self.store_name(), &installed_version)
+        };
+
+        let exe_path = self.locate_system_executable();
+        SystemManagedRecord::new(pm.name(), package, &installed_version, exe_path.clone())
+            .write(&version_dir)?;
+        info!(
+            "Recorded {} {} as system-managed ({})",
+            self.name,
+            installed_version,
+            pm.name()
+        );
+
+        Ok(InstallResult::success(
+            version_dir,
+            exe_path.unwrap_or_else(|| PathBuf::from(&self.executable)),
+            installed_version,
+        ))
+    }
+
+    /// Locate an executable installed outside the store.
+    ///
+    /// For tools like MSVC cl.exe that are not on PATH, search system_paths
+    /// glob patterns first, then fall back to which.
+    pub(crate) fn locate_system_executable(&self) -> Option<PathBuf> {
+        if !self.system_paths.is_empty() {
+            super::find_first_glob_match(&self.system_paths)
+                .or_else(|| which::which(&self.executable).ok())
+        } else {
+            which::which(&self.executable).ok()
+        }
+    }
+}
+
+/// File in an install directory listing the layout components it contains
+const COMPONENTS_MARKER: &str = ".vx-components";
+
+/// Layout components selected for an install
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ComponentSelection {
+    /// Selected component names
+    pub names: Vec<String>,
+    /// Archive path globs of the selected components
+    pub include: Vec<String>,
+}
+
+/// Whether the install at `install_path` already has the `selection`
+///
+/// An install without a marker extracted the whole archive and has every
+/// component.
+fn components_installed(
+    ctx: &RuntimeContext,
+    install_path: &std::path::Path,
+    selection: Option<&ComponentSelection>,
+) -> bool {
+    let Ok(marker) = ctx.fs.read_to_string(&install_path.join(COMPONENTS_MARKER)) else {
+        return true;
+    };
+    let Some(selection) = selection else {
+        return false;
+    };
+    let installed: Vec<&str> = marker.lines().map(str::trim).collect();
+    selection
+        .names
+        .iter()
+        .all(|name| installed.contains(&name.as_str()))
+}
+
+/// Record the components a partial install extracted
+fn record_components(
+    ctx: &RuntimeContext,
+    install_path: &std::path::Path,
+    selection: Option<&ComponentSelection>,
+) -> Result<()> {
+    if let Some(selection) = selection {
+        ctx.fs.write(
+            &install_path.join(COMPONENTS_MARKER),
+            &selection.names.join("\n"),
+        )?;
+    }
+    Ok(())
 }
 
 /// Build layout metadata HashMap from an optional Starlark layout descriptor.
-fn build_layout_meta(layout: Option<&serde_json::Value>) -> HashMap<String, String> {
+fn build_layout_meta(
+    layout: Option<&serde_json::Value>,
+    selection: Option<&ComponentSelection>,
+) -> HashMap<String, String> {
     let mut meta = HashMap::new();
     let Some(layout) = layout else {
         return meta;
@@ -314,5 +554,11 @@ fn build_layout_meta(layout: Option<&serde_json::Value>) -> HashMap<String, Stri
     if let Some(dir) = layout.get("target_dir").and_then(|s| s.as_str()) {
         meta.insert("target_dir".to_string(), dir.to_string());
     }
+    if let Some(formula) = layout.get("bottle").and_then(|s| s.as_str()) {
+        meta.insert("bottle".to_string(), formula.to_string());
+    }
+    if let Some(selection) = selection {
+        meta.insert("include".to_string(), selection.include.join(","));
+    }
     meta
 }
@@ -418,12 +418,23 @@ impl ManifestDrivenRuntime {
             })
             .collect();
 
-        self.system_deps = Some(SystemDepsConfig {
-            pre_depends,
-            depends: vec![],
-            recommends: vec![],
-            suggests: vec![],
-        });
+        self.system_deps
+            .get_or_insert_with(SystemDepsConfig::default)
+            .pre_depends
+            .extend(pre_depends);
+        self
+    }
+
+    /// Add system-level prerequisites (VC++ redistributables, system packages, ...).
+    pub fn with_system_deps(mut self, deps: Vec<SystemDependency>) -> Self {
+        if deps.is_empty() {
+            return self;
+        }
+
+        self.system_deps
+            .get_or_insert_with(SystemDepsConfig::default)
+            .pre_depends
+            .extend(deps);
         self
     }
 
@@ -584,7 +595,8 @@ impl ManifestDrivenRuntime {
 
     async fn is_strategy_available(&self, strategy: &InstallStrategy) -> bool {
         match strategy {
-            InstallStrategy::PackageManager { manager, .. } => {
+            InstallStrategy::PackageManager { manager, .. }
+            | InstallStrategy::SystemManaged { manager, .. } => {
                 is_package_manager_available(manager).await
             }
             InstallStrategy::DirectDownload { .. } => true,
@@ -693,6 +705,19 @@ impl Runtime for ManifestDrivenRuntime {
         }
     }
 
+    fn system_dependencies(&self) -> Vec<vx_system_pm::SystemDependency> {
+        let Some(ref deps) = self.system_deps else {
+            return vec![];
+        };
+        // Runtime-type entries are vx-managed and handled by the resolver
+        deps.pre_depends
+            .iter()
+            .chain(&deps.depends)
+            .filter(|dep| dep.dep_type != SystemDepType::Runtime)
+            .map(vx_system_pm::SystemDependency::from)
+            .collect()
+    }
+
     fn mirror_urls(&self) -> Vec<MirrorConfig> {
         self.mirrors.clone()
     }
@@ -705,6 +730,34 @@ impl Runtime for ManifestDrivenRuntime {
         self.bundled_with.is_none()
     }
 
+    /// Mirrors the order `install` tries: pip, download, then the manifest's
+    /// strategies by priority
+    fn install_kinds(&self) -> Vec<crate::InstallKind> {
+        use crate::InstallKind;
+        if let Some(ref parent) = self.bundled_with {
+            return vec![InstallKind::ProvidedBy {
+                runtime: parent.clone(),
+            }];
+        }
+        let mut kinds = Vec::new();
+        if let Some(ref pkg) = self.pip_package {
+            kinds.push(InstallKind::Pip {
+                package: pkg.clone(),
+            });
+        }
+        if self.download_url_fn.is_some() || self.install_layout_fn.is_some() {
+            kinds.push(InstallKind::Download);
+        }
+        let mut strategies: Vec<&InstallStrategy> = self.install_strategies.iter().collect();
+        strategies.sort_by_key(|s| std::cmp::Reverse(s.priority()));
+        for kind in strategies.into_iter().map(InstallStrategy::kind) {
+            if !kinds.contains(&kind) {
+                kinds.push(kind);
+            }
+        }
+        kinds
+    }
+
     async fn prepare_execution(
         &self,
         version: &str,
@@ -1027,6 +1080,14 @@ impl Runtime for ManifestDrivenRuntime {
             return Ok(None);
         }
 
+        // System-managed installs only keep a record in the store
+        if let Some(record) = vx_paths::SystemManagedRecord::read(&install_path) {
+            return Ok(record
+                .existing_executable()
+                .map(|p| p.to_path_buf())
+                .or_else(|| self.locate_system_executable()));
+        }
+
         if let Some(ref layout_fn) = self.install_layout_fn
             && let Ok(Some(layout)) = layout_fn(version.to_string()).await
         {
@@ -1064,7 +1125,9 @@ impl Runtime for ManifestDrivenRuntime {
     }
 
     async fn install(&self, version: &str, ctx: &RuntimeContext) -> Result<InstallResult> {
-        self.install_impl(version, ctx).await
+        let result = self.install_impl(version, ctx).await?;
+        crate::runtime::install_impl::record_install(&result, ctx);
+        Ok(result)
     }
 
     /// Run the Starlark `post_extract` hook after a successful installation.
@@ -1168,15 +1231,26 @@ impl Runtime for ManifestDrivenRuntime {
                             .and_then(|f| f.as_str())
                             .unwrap_or("ignore");
 
+                        // Installer scripts (e.g. gcloud's install.sh) expect to run
+                        // from inside the extracted tree
+                        let working_dir = post_install_working_dir(
+                            &install_dir,
+                            action.get("working_dir").and_then(|d| d.as_str()),
+                        );
+
                         tracing::debug!(
-                            "post_install: run_command {} {:?} (on_failure={})",
+                            "post_install: run_command {} {:?} in {} (on_failure={})",
                             cmd_str,
                             args,
+                            working_dir.display(),
                             on_failure
                         );
 
                         let mut cmd = std::process::Command::new(cmd_str);
                         cmd.args(&args);
+                        if working_dir.is_dir() {
+                            cmd.current_dir(&working_dir);
+                        }
                         for (k, v) in &env_map {
                             cmd.env(k, v);
                         }
@@ -1206,6 +1280,29 @@ impl Runtime for ManifestDrivenRuntime {
                         }
                     }
                 }
+                "write_file" => {
+                    let (Some(path_str), Some(content)) = (
+                        action.get("path").and_then(|p| p.as_str()),
+                        action.get("content").and_then(|c| c.as_str()),
+                    ) else {
+                        continue;
+                    };
+                    let overwrite = action
+                        .get("overwrite")
+                        .and_then(|o| o.as_bool())
+                        .unwrap_or(false);
+                    let full = install_dir.join(path_str);
+                    if full.exists() && !overwrite {
+                        tracing::debug!("post_install: keeping existing {}", full.display());
+                        continue;
+                    }
+                    if let Some(parent) = full.parent() {
+                        std::fs::create_dir_all(parent)?;
+                    }
+                    std::fs::write(&full, content).map_err(|e| {
+                        anyhow::anyhow!("post_install: failed to write {}: {}", full.display(), e)
+                    })?;
+                }
                 other => {
                     tracing::debug!("post_install: unknown action type '{}', skipping", other);
                 }
@@ -1258,6 +1355,7 @@ async fn is_package_manager_available(manager: &str) -> bool {
         "pacman" => which::which("pacman").is_ok(),
         "zypper" => which::which("zypper").is_ok(),
         "apk" => which::which("apk").is_ok(),
+        "nix" => which::which("nix").is_ok(),
         _ => false,
     }
 }
@@ -1301,6 +1399,18 @@ async fn fetch_pypi_versions(pkg: &str, ctx: &RuntimeContext) -> Result<Vec<Vers
     Ok(vec![])
 }
 
+/// Directory a post-extract `run_command` runs in
+///
+/// Relative `working_dir` values are taken from the install directory, which
+/// is also the default, so hooks never depend on where `vx` was invoked.
+fn post_install_working_dir(install_dir: &Path, working_dir: Option<&str>) -> PathBuf {
+    match working_dir {
+        Some(dir) if Path::new(dir).is_absolute() => PathBuf::from(dir),
+        Some(dir) => install_dir.join(dir),
+        None => install_dir.to_path_buf(),
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -1359,4 +1469,160 @@ mod tests {
         assert_eq!(runtime.description(), "A simple, fast alternative to find");
         assert_eq!(runtime.install_strategies.len(), 1);
     }
+
+    #[test]
+    fn test_system_dependencies_skip_runtime_deps() {
+        let runtime = ManifestDrivenRuntime::new("cl", "msvc", ProviderSource::BuiltIn)
+            .with_install_deps(vec!["7zip".to_string()])
+            .with_system_deps(vec![SystemDependency {
+                dep_type: SystemDepType::VcRedist,
+                id: "vcredist140".to_string(),
+                version: None,
+                reason: Some("C++ runtime".to_string()),
+                platforms: vec!["windows".to_string()],
+                optional: false,
+            }]);
+
+        let deps = runtime.system_dependencies();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].id, "vcredist140");
+        assert_eq!(deps[0].dep_type, vx_system_pm::SystemDepType::VcRedist);
+        assert_eq!(deps[0].platforms, vec!["windows".to_string()]);
+
+        // Install deps are kept alongside system deps
+        let config = runtime.system_deps.as_ref().unwrap();
+        assert_eq!(config.pre_depends.len(), 2);
+    }
+
+    #[test]
+    fn test_system_managed_strategy_priority_and_platform() {
+        let strategy = InstallStrategy::SystemManaged {
+            manager: "winget".to_string(),
+            package: "Microsoft.NuGet".to_string(),
+            install_args: None,
+            priority: 90,
+            platforms: vec!["windows".to_string()],
+        };
+
+        assert_eq!(strategy.priority(), 90);
+        assert!(strategy.matches_platform(&Platform::new(crate::Os::Windows, crate::Arch::X86_64)));
+        assert!(!strategy.matches_platform(&Platform::new(crate::Os::Linux, crate::Arch::X86_64)));
+    }
+
+    #[tokio::test]
+    async fn test_system_managed_record_resolves_executable() {
+        use crate::testing::{MockHttpClient, MockInstaller, MockPathProvider};
+
+        let temp = tempfile::tempdir().unwrap();
+        let exe = temp.path().join("nuget.exe");
+        std::fs::write(&exe, b"").unwrap();
+
+        let ctx = RuntimeContext::new(
+            Arc::new(MockPathProvider::new(temp.path())),
+            Arc::new(MockHttpClient::new()),
+            Arc::new(crate::RealFileSystem),
+            Arc::new(MockInstaller::new()),
+        );
+        let runtime = ManifestDrivenRuntime::new("nuget", "nuget", ProviderSource::BuiltIn);
+
+        let version_dir = ctx.paths.version_store_dir("nuget", "6.9.1");
+        vx_paths::SystemManagedRecord::new("winget", "Microsoft.NuGet", "6.9.1", Some(exe.clone()))
+            .write(&version_dir)
+            .unwrap();
+
+        assert!(runtime.is_installed("6.9.1", &ctx).await.unwrap());
+        assert_eq!(
+            runtime.installed_versions(&ctx).await.unwrap(),
+            vec!["6.9.1".to_string()]
+        );
+        assert_eq!(
+            runtime
+                .get_executable_path_for_version("6.9.1", &ctx)
+                .await
+                .unwrap(),
+            Some(exe)
+        );
+    }
+
+    #[test]
+    fn test_post_install_working_dir() {
+        let install_dir = Path::new("/opt/vx/store/gcloud/500.0.0");
+        assert_eq!(post_install_working_dir(install_dir, None), install_dir);
+        assert_eq!(
+            post_install_working_dir(install_dir, Some("bin")),
+            install_dir.join("bin")
+        );
+        let absolute = std::env::temp_dir();
+        assert_eq!(
+            post_install_working_dir(install_dir, absolute.to_str()),
+            absolute
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_post_install_runs_command_in_install_dir() {
+        use crate::testing::{MockHttpClient, MockInstaller, MockPathProvider};
+
+        let temp = tempfile::tempdir().unwrap();
+        let ctx = RuntimeContext::new(
+            Arc::new(MockPathProvider::new(temp.path())),
+            Arc::new(MockHttpClient::new()),
+            Arc::new(crate::RealFileSystem),
+            Arc::new(MockInstaller::new()),
+        );
+        let version_dir = ctx.paths.version_store_dir("gcloud", "500.0.0");
+        std::fs::create_dir_all(&version_dir).unwrap();
+
+        let runtime = ManifestDrivenRuntime::new("gcloud", "gcloud", ProviderSource::BuiltIn)
+            .with_post_extract(Arc::new(|_version, _install_dir| {
+                Box::pin(async {
+                    Ok(vec![serde_json::json!({
+                        "type": "run_command",
+                        "command": "sh",
+                        "args": ["-c", "touch installed"],
+                        "on_failure": "error",
+                    })])
+                })
+            }));
+        runtime.post_install("500.0.0", &ctx).await.unwrap();
+
+        assert!(version_dir.join("installed").exists());
+    }
+
+    #[tokio::test]
+    async fn test_post_install_writes_files() {
+        use crate::testing::{MockHttpClient, MockInstaller, MockPathProvider};
+
+        let temp = tempfile::tempdir().unwrap();
+        let ctx = RuntimeContext::new(
+            Arc::new(MockPathProvider::new(temp.path())),
+            Arc::new(MockHttpClient::new()),
+            Arc::new(crate::RealFileSystem),
+            Arc::new(MockInstaller::new()),
+        );
+        let version_dir = ctx.paths.version_store_dir("php", "8.3.12");
+        std::fs::create_dir_all(&version_dir).unwrap();
+        std::fs::write(version_dir.join("php.ini"), "; edited").unwrap();
+
+        let runtime = ManifestDrivenRuntime::new("php", "php", ProviderSource::BuiltIn)
+            .with_post_extract(Arc::new(|_version, _install_dir| {
+                Box::pin(async {
+                    Ok(vec![
+                        serde_json::json!({"type": "write_file", "path": "php.ini", "content": "; template"}),
+                        serde_json::json!({"type": "write_file", "path": "ext/README", "content": "extensions"}),
+                    ])
+                })
+            }));
+        runtime.post_install("8.3.12", &ctx).await.unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(version_dir.join("php.ini")).unwrap(),
+            "; edited"
+        );
+        assert_eq!(
+            std::fs::read_to_string(version_dir.join("ext/README")).unwrap(),
+            "extensions"
+        );
+    }
 }
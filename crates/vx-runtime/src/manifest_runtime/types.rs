@@ -45,6 +45,24 @@ pub enum InstallStrategy {
         /// Platform filter
         platforms: Vec<String>,
     },
+    /// Delegate to winget/choco for tools that only ship installers
+    ///
+    /// Unlike [`InstallStrategy::PackageManager`], the requested version is
+    /// pinned and the installation is recorded in the vx store as
+    /// system-managed (see [`vx_paths::SystemManagedRecord`]), so installed
+    /// versions are tracked and `vx which` resolves the executable.
+    SystemManaged {
+        /// Package manager name (winget, choco)
+        manager: String,
+        /// Package identifier for the manager
+        package: String,
+        /// Native installer arguments
+        install_args: Option<String>,
+        /// Priority (higher = preferred)
+        priority: i32,
+        /// Platform filter
+        platforms: Vec<String>,
+    },
     /// Direct download
     DirectDownload {
         /// URL template (supports {version}, {platform}, {arch})
@@ -89,16 +107,36 @@ impl InstallStrategy {
     pub fn priority(&self) -> i32 {
         match self {
             InstallStrategy::PackageManager { priority, .. } => *priority,
+            InstallStrategy::SystemManaged { priority, .. } => *priority,
             InstallStrategy::DirectDownload { priority, .. } => *priority,
             InstallStrategy::Script { priority, .. } => *priority,
             InstallStrategy::ProvidedBy { priority, .. } => *priority,
         }
     }
 
+    /// The kind of installation this strategy performs
+    pub fn kind(&self) -> crate::InstallKind {
+        use crate::InstallKind;
+        match self {
+            InstallStrategy::PackageManager { manager, .. } => InstallKind::PackageManager {
+                manager: manager.clone(),
+            },
+            InstallStrategy::SystemManaged { manager, .. } => InstallKind::SystemManaged {
+                manager: manager.clone(),
+            },
+            InstallStrategy::DirectDownload { .. } => InstallKind::Download,
+            InstallStrategy::Script { .. } => InstallKind::Script,
+            InstallStrategy::ProvidedBy { provider, .. } => InstallKind::ProvidedBy {
+                runtime: provider.clone(),
+            },
+        }
+    }
+
     /// Check if this strategy matches the current platform
     pub fn matches_platform(&self, platform: &Platform) -> bool {
         let platforms = match self {
             InstallStrategy::PackageManager { platforms, .. } => platforms,
+            InstallStrategy::SystemManaged { platforms, .. } => platforms,
             InstallStrategy::DirectDownload { platforms, .. } => platforms,
             InstallStrategy::Script { platforms, .. } => platforms,
             InstallStrategy::ProvidedBy { platforms, .. } => platforms,
@@ -192,6 +230,42 @@ pub enum SystemDepType {
     Runtime,
 }
 
+impl SystemDepType {
+    /// Parse a dependency type name as used in provider manifests
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "windows_kb" => Some(Self::WindowsKb),
+            "windows_feature" => Some(Self::WindowsFeature),
+            "vc_redist" | "vcredist" => Some(Self::VcRedist),
+            "dotnet" | "dotnet_framework" => Some(Self::DotNet),
+            "package" => Some(Self::Package),
+            "runtime" => Some(Self::Runtime),
+            _ => None,
+        }
+    }
+}
+
+impl From<&SystemDependency> for vx_system_pm::SystemDependency {
+    fn from(dep: &SystemDependency) -> Self {
+        let dep_type = match dep.dep_type {
+            SystemDepType::WindowsKb => vx_system_pm::SystemDepType::WindowsKb,
+            SystemDepType::WindowsFeature => vx_system_pm::SystemDepType::WindowsFeature,
+            SystemDepType::VcRedist => vx_system_pm::SystemDepType::VcRedist,
+            SystemDepType::DotNet => vx_system_pm::SystemDepType::DotNet,
+            SystemDepType::Package => vx_system_pm::SystemDepType::Package,
+            SystemDepType::Runtime => vx_system_pm::SystemDepType::Runtime,
+        };
+        Self {
+            dep_type,
+            id: dep.id.clone(),
+            version: dep.version.clone(),
+            reason: dep.reason.clone(),
+            platforms: dep.platforms.clone(),
+            optional: dep.optional,
+        }
+    }
+}
+
 /// Shell definition for runtime-provided shells (RFC 0038)
 #[derive(Debug, Clone)]
 pub struct ShellDefinition {
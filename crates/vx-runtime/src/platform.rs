@@ -13,15 +13,22 @@ pub enum Libc {
 }
 
 impl Libc {
+    /// Environment variable that overrides detection (`musl` or `gnu`)
+    pub const ENV_VAR: &'static str = "VX_LIBC";
+
     /// Detect the current libc implementation at runtime
     ///
-    /// This checks if running on a musl-based system by examining /etc/os-release
-    /// or checking for musl-specific indicators.
+    /// `VX_LIBC` wins; otherwise a Linux host is musl when `/etc/os-release`
+    /// names Alpine, or when its only dynamic linker is musl's
+    /// (`/lib/ld-musl-*.so.1`). glibc hosts with musl-tools installed have
+    /// both linkers and stay glibc.
     pub fn current() -> Self {
-        // First check if we're in a musl environment via environment variable
-        // This allows explicit override in containers
-        if std::env::var("VX_LIBC").ok().as_deref() == Some("musl") {
-            return Libc::Musl;
+        // Explicit override, e.g. `settings.libc` in vx.toml or a container
+        if let Some(libc) = std::env::var(Self::ENV_VAR)
+            .ok()
+            .and_then(|v| Self::parse(&v))
+        {
+            return libc;
         }
 
         // Only relevant for Linux
@@ -29,23 +36,42 @@ impl Libc {
             return Libc::Gnu;
         }
 
-        // Check /etc/os-release for Alpine (uses musl)
-        if let Ok(content) = std::fs::read_to_string("/etc/os-release") {
-            let content_lower = content.to_lowercase();
-            if content_lower.contains("alpine") {
-                return Libc::Musl;
-            }
+        let os_release = std::fs::read_to_string("/etc/os-release").ok();
+        let loaders: Vec<String> = ["/lib", "/lib64"]
+            .iter()
+            .filter_map(|dir| std::fs::read_dir(dir).ok())
+            .flat_map(|entries| entries.flatten())
+            .map(|e| e.file_name().to_string_lossy().into_owned())
+            .filter(|name| name.starts_with("ld-"))
+            .collect();
+        let musl_loader_only = loaders.iter().any(|name| name.starts_with("ld-musl-"))
+            && !loaders.iter().any(|name| name.starts_with("ld-linux"));
+        Self::detect(os_release.as_deref(), musl_loader_only)
+    }
+
+    /// Libc of a Linux host from its `/etc/os-release` and whether musl's is
+    /// the only dynamic linker installed
+    pub fn detect(os_release: Option<&str>, musl_loader_only: bool) -> Self {
+        let alpine = os_release.is_some_and(|content| {
+            content.lines().any(|line| {
+                let line = line.trim().to_lowercase();
+                (line.starts_with("id=") || line.starts_with("id_like=")) && line.contains("alpine")
+            })
+        });
+        if alpine || musl_loader_only {
+            Libc::Musl
+        } else {
+            Libc::Gnu
         }
+    }
 
-        // Check if /lib/ld-musl-*.so.1 exists (musl dynamic linker)
-        if std::path::Path::new("/lib/ld-musl-x86_64.so.1").exists()
-            || std::path::Path::new("/lib/ld-musl-aarch64.so.1").exists()
-        {
-            return Libc::Musl;
+    /// Parse `musl`, `gnu` or `glibc`
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "musl" => Some(Libc::Musl),
+            "gnu" | "glibc" => Some(Libc::Gnu),
+            _ => None,
         }
-
-        // Default to GNU libc
-        Libc::Gnu
     }
 
     /// Get the libc suffix for Rust target triples
@@ -141,8 +167,44 @@ pub enum Arch {
 }
 
 impl Arch {
-    /// Detect current architecture
+    /// Detect the host architecture
+    ///
+    /// On Windows this is the machine's native architecture, so an x64 build
+    /// of vx running under emulation on an ARM64 machine reports `Aarch64`.
     pub fn current() -> Self {
+        if cfg!(target_os = "windows") {
+            let var = |name| std::env::var(name).ok();
+            if let Some(arch) = Self::from_windows_env(
+                var("PROCESSOR_IDENTIFIER").as_deref(),
+                var("PROCESSOR_ARCHITEW6432")
+                    .or_else(|| var("PROCESSOR_ARCHITECTURE"))
+                    .as_deref(),
+            ) {
+                return arch;
+            }
+        }
+        Self::compiled()
+    }
+
+    /// Native architecture from Windows' `PROCESSOR_IDENTIFIER` and
+    /// `PROCESSOR_ARCHITECTURE` variables
+    ///
+    /// Emulated processes see the emulated `PROCESSOR_ARCHITECTURE`, but the
+    /// identifier still names the real CPU (`ARMv8 (64-bit) Family 8 ...`).
+    pub fn from_windows_env(identifier: Option<&str>, architecture: Option<&str>) -> Option<Self> {
+        if identifier.is_some_and(|id| id.trim_start().to_ascii_uppercase().starts_with("ARM")) {
+            return Some(Arch::Aarch64);
+        }
+        match architecture?.trim().to_ascii_uppercase().as_str() {
+            "ARM64" => Some(Arch::Aarch64),
+            "AMD64" => Some(Arch::X86_64),
+            "X86" => Some(Arch::X86),
+            _ => None,
+        }
+    }
+
+    /// Architecture vx itself was compiled for
+    fn compiled() -> Self {
         if cfg!(target_arch = "x86_64") {
             Arch::X86_64
         } else if cfg!(target_arch = "aarch64") {
@@ -190,6 +252,51 @@ impl std::fmt::Display for Arch {
     }
 }
 
+/// What to install when a tool has no build for the host architecture
+///
+/// Only Windows on ARM64 has a fallback: it runs x64 builds under emulation.
+/// Set with `VX_ARCH_FALLBACK` or `arch_fallback` in the `[settings]` of
+/// vx.toml.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ArchFallback {
+    /// Install the x64 build and run it under emulation
+    #[default]
+    Emulate,
+    /// Only install native builds
+    Never,
+}
+
+impl ArchFallback {
+    /// Environment variable holding the policy
+    pub const ENV_VAR: &'static str = "VX_ARCH_FALLBACK";
+
+    /// Policy from `VX_ARCH_FALLBACK`, defaulting to [`ArchFallback::Emulate`]
+    pub fn current() -> Self {
+        std::env::var(Self::ENV_VAR)
+            .ok()
+            .and_then(|value| Self::parse(&value))
+            .unwrap_or_default()
+    }
+
+    /// Parse `emulate` or `never`
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "emulate" => Some(Self::Emulate),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+
+    /// Name as written in configuration
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Emulate => "emulate",
+            Self::Never => "never",
+        }
+    }
+}
+
 /// Platform information (OS + Architecture + Libc)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Platform {
@@ -224,6 +331,14 @@ impl Platform {
         }
     }
 
+    /// The platform whose builds this one runs under emulation, if allowed
+    ///
+    /// Windows on ARM64 runs x64 builds; `never` disables the fallback.
+    pub fn emulation_fallback(&self, policy: ArchFallback) -> Option<Platform> {
+        (policy == ArchFallback::Emulate && self.os == Os::Windows && self.arch == Arch::Aarch64)
+            .then(|| Platform::new(Os::Windows, Arch::X86_64))
+    }
+
     /// Check if this platform uses musl libc
     pub fn is_musl(&self) -> bool {
         self.os == Os::Linux && self.libc.is_musl()
@@ -0,0 +1,159 @@
+//! Filesystem preflight checks for installs
+//!
+//! Some tool archives only extract correctly on filesystems with the right
+//! properties: archives with entries that differ only in case (`Makefile`
+//! and `makefile`) silently overwrite each other on case-insensitive macOS
+//! and Windows volumes, and deep trees break tools on Windows when the
+//! install path leaves no room under the legacy 260-character `MAX_PATH`.
+//!
+//! [`FsProperties::probe`] inspects the target directory before anything is
+//! downloaded, so an unusable store fails with a clear message instead of
+//! after a multi-GB download. [`ensure_case_safe`] lets extractors verify an
+//! archive against a case-insensitive target before writing any file.
+
+use anyhow::{Result, anyhow};
+use std::collections::HashMap;
+use std::path::Path;
+
+pub use vx_paths::windows::WINDOWS_MAX_PATH;
+
+/// Room reserved for paths inside an extracted tool (e.g. npm's nested
+/// `node_modules`) when checking the install path against the path limit
+pub const INSTALL_PATH_HEADROOM: usize = 100;
+
+/// Properties of the filesystem holding an install directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FsProperties {
+    /// Whether `Foo` and `foo` are different files
+    pub case_sensitive: bool,
+    /// Longest usable path, or `None` when effectively unlimited
+    pub max_path: Option<usize>,
+}
+
+impl FsProperties {
+    /// Probe the filesystem holding `dir`, creating `dir` if needed
+    ///
+    /// Fails when `dir` cannot be created or written to.
+    pub fn probe(dir: &Path) -> Result<Self> {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| anyhow!("Cannot create install directory {}: {}", dir.display(), e))?;
+
+        let probe = dir.join(format!(".vx-Case-Probe-{}", std::process::id()));
+        std::fs::write(&probe, b"")
+            .map_err(|e| anyhow!("Install directory {} is not writable: {}", dir.display(), e))?;
+        let folded = dir.join(format!(".vx-case-probe-{}", std::process::id()));
+        let case_sensitive = !folded.exists();
+        let _ = std::fs::remove_file(&probe);
+
+        Ok(Self {
+            case_sensitive,
+            max_path: max_path(),
+        })
+    }
+}
+
+/// Fail if `install_path` is too long to leave room for the tool's own files
+pub fn check_install_path(install_path: &Path, props: &FsProperties) -> Result<()> {
+    let Some(limit) = props.max_path else {
+        return Ok(());
+    };
+    let len = install_path.as_os_str().len();
+    if len + INSTALL_PATH_HEADROOM <= limit {
+        return Ok(());
+    }
+    Err(anyhow!(
+        "Install path {} is {} characters long, leaving too little room under the {}-character \
+         path limit for the files inside the tool.\n{}",
+        install_path.display(),
+        len,
+        limit,
+        vx_paths::windows::get_long_path_enable_instructions().trim_end()
+    ))
+}
+
+/// Pairs of archive files that would overwrite each other on a
+/// case-insensitive filesystem, in archive order
+///
+/// Directory entries (ending in `/`) are skipped: `bin/` and `Bin/` merge
+/// harmlessly, and only files inside them can collide.
+pub fn case_collisions<'a>(names: impl IntoIterator<Item = &'a str>) -> Vec<(String, String)> {
+    let mut seen: HashMap<String, &str> = HashMap::new();
+    let mut collisions = Vec::new();
+    for name in names {
+        if name.is_empty() || name.ends_with('/') {
+            continue;
+        }
+        match seen.get(&name.to_lowercase()) {
+            Some(first) if *first != name => {
+                collisions.push((first.to_string(), name.to_string()));
+            }
+            Some(_) => {}
+            None => {
+                seen.insert(name.to_lowercase(), name);
+            }
+        }
+    }
+    collisions
+}
+
+/// Make sure extracting `names` into the case-insensitive `dest` loses no files
+///
+/// On Windows, colliding archives are handled by enabling per-directory case
+/// sensitivity on the still-empty `dest` (inherited by its subdirectories);
+/// elsewhere, or when that is unavailable, this fails with the collisions.
+pub fn ensure_case_safe<'a>(names: impl IntoIterator<Item = &'a str>, dest: &Path) -> Result<()> {
+    let collisions = case_collisions(names);
+    let Some((first, second)) = collisions.first() else {
+        return Ok(());
+    };
+    if enable_case_sensitivity(dest) {
+        tracing::debug!(
+            dest = %dest.display(),
+            collisions = collisions.len(),
+            "Enabled case sensitivity for archive with case-colliding entries"
+        );
+        return Ok(());
+    }
+    let hint = if cfg!(windows) {
+        format!(
+            "enable case sensitivity with `fsutil.exe file setCaseSensitiveInfo {} enable` \
+             (requires the Windows Subsystem for Linux feature) and retry",
+            dest.display()
+        )
+    } else {
+        "move VX_HOME to a case-sensitive volume (e.g. a case-sensitive APFS volume)".to_string()
+    };
+    Err(anyhow!(
+        "{} is on a case-insensitive filesystem, but the archive has {} entries that differ \
+         only in case from another (e.g. '{}' and '{}'); {}",
+        dest.display(),
+        collisions.len(),
+        first,
+        second,
+        hint
+    ))
+}
+
+/// Turn on case sensitivity for `dir`; true if it is case-sensitive afterwards
+#[cfg(windows)]
+fn enable_case_sensitivity(dir: &Path) -> bool {
+    let enabled = std::process::Command::new("fsutil.exe")
+        .args(["file", "setCaseSensitiveInfo"])
+        .arg(dir)
+        .arg("enable")
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success());
+    enabled && FsProperties::probe(dir).is_ok_and(|props| props.case_sensitive)
+}
+
+#[cfg(not(windows))]
+fn enable_case_sensitivity(_dir: &Path) -> bool {
+    false
+}
+
+/// Longest usable path on this system
+fn max_path() -> Option<usize> {
+    (cfg!(windows) && !vx_paths::windows::is_long_path_enabled()).then_some(WINDOWS_MAX_PATH)
+}
@@ -3,6 +3,7 @@
 //! A Provider is a container for related runtimes.
 
 use crate::Platform;
+use crate::command::CommandContribution;
 use crate::runtime::Runtime;
 use std::sync::Arc;
 
@@ -49,6 +50,14 @@ pub trait Provider: Send + Sync {
     /// Get all runtimes provided by this provider
     fn runtimes(&self) -> Vec<Arc<dyn Runtime>>;
 
+    /// Subcommands this provider adds under `vx <provider> <subcommand>`
+    ///
+    /// A contributed subcommand takes precedence over passing the same word to
+    /// the tool, so names should not clash with the tool's own arguments.
+    fn commands(&self) -> Vec<Arc<dyn CommandContribution>> {
+        Vec::new()
+    }
+
     /// Check if this provider supports a runtime by name or alias
     fn supports(&self, name: &str) -> bool {
         self.runtimes()
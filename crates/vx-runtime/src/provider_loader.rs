@@ -552,15 +552,7 @@ impl ProviderLoader {
     /// Parse a single system dependency
     fn parse_system_dependency(&self, value: &toml::Value) -> Option<SystemDependency> {
         let dep_type_str = value.get("type").and_then(|v| v.as_str())?;
-        let dep_type = match dep_type_str {
-            "windows_kb" => SystemDepType::WindowsKb,
-            "windows_feature" => SystemDepType::WindowsFeature,
-            "vc_redist" | "vcredist" => SystemDepType::VcRedist,
-            "dotnet" | "dotnet_framework" => SystemDepType::DotNet,
-            "package" => SystemDepType::Package,
-            "runtime" => SystemDepType::Runtime,
-            _ => return None,
-        };
+        let dep_type = SystemDepType::from_name(dep_type_str)?;
 
         let id = value.get("id").and_then(|v| v.as_str())?.to_string();
         let version = value
@@ -12,11 +12,20 @@
 //!
 //! Methods that need all providers (e.g., `providers()`, `runtime_names()`,
 //! `supported_runtimes()`) will materialize all pending factories first.
+//!
+//! ## Failure Isolation
+//!
+//! A factory that panics (or returns a provider whose runtimes panic) does
+//! not take the registry down: the provider is left out and the failure is
+//! kept in [`ProviderRegistry::failed_providers`] so `vx doctor` can report
+//! it. Every other provider stays usable.
 
 use crate::Platform;
 use crate::provider::Provider;
 use crate::runtime::Runtime;
+use serde::Serialize;
 use std::collections::HashMap;
+use std::panic::{AssertUnwindSafe, catch_unwind};
 use std::sync::{Arc, Mutex, RwLock};
 use thiserror::Error;
 use tracing::trace;
@@ -24,6 +33,24 @@ use tracing::trace;
 /// Type alias for a provider factory closure
 type ProviderFactory = Box<dyn Fn() -> Arc<dyn Provider> + Send + Sync>;
 
+/// A provider that failed to initialize and was left out of the registry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProviderFailure {
+    /// Provider name
+    pub provider: String,
+    /// The underlying error (panic message or load error)
+    pub error: String,
+}
+
+impl ProviderFailure {
+    pub fn new(provider: impl Into<String>, error: impl Into<String>) -> Self {
+        Self {
+            provider: provider.into(),
+            error: error.into(),
+        }
+    }
+}
+
 /// Registry for all providers
 ///
 /// The registry provides a central place to register and look up
@@ -58,6 +85,8 @@ pub struct ProviderRegistry {
     /// Index: runtime name or alias -> provider name (for pending factories).
     /// Used to find which factory to materialize when `get_runtime()` is called.
     pending_index: RwLock<HashMap<String, String>>,
+    /// Providers whose factory panicked during materialization
+    failures: RwLock<Vec<ProviderFailure>>,
 }
 
 impl ProviderRegistry {
@@ -68,6 +97,7 @@ impl ProviderRegistry {
             runtime_cache: RwLock::new(HashMap::new()),
             pending_factories: Mutex::new(HashMap::new()),
             pending_index: RwLock::new(HashMap::new()),
+            failures: RwLock::new(Vec::new()),
         }
     }
 
@@ -163,10 +193,6 @@ impl ProviderRegistry {
             return false;
         };
 
-        // Call the factory and register eagerly
-        let provider = factory();
-        tracing::trace!("lazy-loaded provider '{}'", provider_name);
-
         // Remove all pending index entries for this provider
         {
             let mut index = self
@@ -176,11 +202,70 @@ impl ProviderRegistry {
             index.retain(|_, v| v != provider_name);
         }
 
-        // Register the provider (this updates runtime_cache)
+        // Call the factory and register eagerly (this updates runtime_cache)
+        let Some(provider) = self.build_provider(provider_name, &factory) else {
+            return false;
+        };
+        tracing::trace!("lazy-loaded provider '{}'", provider_name);
         self.register(provider);
         true
     }
 
+    /// Call a factory, recording a panic as a failure instead of propagating it
+    ///
+    /// The provider's runtimes are listed inside the guard too, as that is
+    /// where parsing happens for providers that build them on demand.
+    fn build_provider(
+        &self,
+        provider_name: &str,
+        factory: &ProviderFactory,
+    ) -> Option<Arc<dyn Provider>> {
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let provider = factory();
+            provider.runtimes();
+            provider
+        }));
+        match result {
+            Ok(provider) => Some(provider),
+            Err(payload) => {
+                let error = panic_message(payload.as_ref());
+                tracing::warn!(
+                    provider = %provider_name,
+                    error = %error,
+                    "Provider failed to initialize and was disabled"
+                );
+                self.record_failure(ProviderFailure::new(provider_name, error));
+                None
+            }
+        }
+    }
+
+    /// Record a provider that failed to initialize
+    ///
+    /// Used by callers that load providers outside of a factory (parsing
+    /// `provider.star` handles, user scripts) so that every failure ends up
+    /// in one report. A later failure of the same provider replaces the
+    /// earlier one.
+    pub fn record_failure(&self, failure: ProviderFailure) {
+        let mut failures = self.failures.write().expect("failures lock poisoned");
+        failures.retain(|f| f.provider != failure.provider);
+        failures.push(failure);
+    }
+
+    /// Providers that failed to initialize (materializes all pending factories)
+    ///
+    /// Sorted by provider name.
+    pub fn failed_providers(&self) -> Vec<ProviderFailure> {
+        self.materialize_all();
+        let mut failures = self
+            .failures
+            .read()
+            .expect("failures lock poisoned")
+            .clone();
+        failures.sort_by(|a, b| a.provider.cmp(&b.provider));
+        failures
+    }
+
     /// Materialize all pending providers.
     ///
     /// Called by methods that need the full set of providers (e.g., `providers()`,
@@ -210,9 +295,10 @@ impl ProviderRegistry {
 
         // Materialize each factory
         for (name, factory) in factories {
-            let provider = factory();
-            tracing::trace!("lazy-loaded provider '{}' (bulk)", name);
-            self.register(provider);
+            if let Some(provider) = self.build_provider(&name, &factory) {
+                tracing::trace!("lazy-loaded provider '{}' (bulk)", name);
+                self.register(provider);
+            }
         }
     }
 
@@ -382,6 +468,10 @@ impl ProviderRegistry {
             .write()
             .expect("pending_index lock poisoned")
             .clear();
+        self.failures
+            .write()
+            .expect("failures lock poisoned")
+            .clear();
     }
 
     /// Get all runtimes that support the current platform (materializes all pending)
@@ -491,6 +581,17 @@ pub enum PlatformError {
     },
 }
 
+/// Text of a panic payload (`panic!` with a literal or a formatted message)
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "provider panicked".to_string()
+    }
+}
+
 impl Default for ProviderRegistry {
     fn default() -> Self {
         Self::new()
@@ -7,6 +7,7 @@
 //! - Already-installed detection (with verification)
 //! - Cached download URL from lock file
 //! - Mirror URL chain construction
+//! - Filesystem preflight (writability, path length)
 //! - Download with layout metadata
 //! - Post-extract hook
 //! - RFC 0022 normalization
@@ -23,6 +24,7 @@ use tracing::{debug, info};
 use crate::context::RuntimeContext;
 use crate::layout::LayoutContext;
 use crate::platform::{Os, Platform};
+use crate::preflight;
 use crate::types::InstallResult;
 
 use super::verify::verify_installation_default;
@@ -57,6 +59,13 @@ pub fn is_url_plausible_for_platform(url: &str, platform: &Platform) -> bool {
     }
 }
 
+/// Count a fresh install in the usage counters of the cache directory
+pub fn record_install(result: &InstallResult, ctx: &RuntimeContext) {
+    if result.success && !result.already_installed {
+        vx_cache::UsageCounters::new(ctx.paths.cache_dir()).increment(vx_cache::usage::INSTALLS);
+    }
+}
+
 /// Parameters extracted from a `Runtime` for use in `default_install_inner`.
 ///
 /// This struct avoids the `?Sized` problem by extracting all needed data from
@@ -117,6 +126,16 @@ pub async fn default_install_inner(
         }
     }
 
+    // Check the target filesystem before spending time on the download. Probe
+    // the runtime's store dir: an empty version dir would look installed.
+    let store_dir = install_path.parent().unwrap_or(&install_path);
+    let props = preflight::FsProperties::probe(store_dir)?;
+    debug!(
+        "Install filesystem: case-sensitive={}, max path={:?}",
+        props.case_sensitive, props.max_path
+    );
+    preflight::check_install_path(&install_path, &props)?;
+
     info!(
         "Downloading {} {} ({})",
         params.name,
@@ -49,7 +49,7 @@ use crate::ecosystem::Ecosystem;
 use crate::platform::Platform;
 use crate::region;
 use crate::types::{
-    ExecutionPrep, ExecutionResult, InstallResult, RuntimeDependency, VersionInfo,
+    ExecutionPrep, ExecutionResult, InstallKind, InstallResult, RuntimeDependency, VersionInfo,
     VersionInfoResult,
 };
 use anyhow::Result;
@@ -161,6 +161,15 @@ pub trait Runtime: Send + Sync {
         &[]
     }
 
+    /// System-level prerequisites (VC++ redistributables, system packages, ...)
+    ///
+    /// These are not managed by vx; the install command checks them with
+    /// `vx_system_pm::SystemDependencyResolver` and offers to install missing
+    /// ones through the system package manager.
+    fn system_dependencies(&self) -> Vec<vx_system_pm::SystemDependency> {
+        vec![]
+    }
+
     /// Version-aware dependencies resolved at runtime.
     ///
     /// This is used for providers whose dependency rules depend on the selected
@@ -743,7 +752,7 @@ pub trait Runtime: Send + Sync {
     async fn install(&self, version: &str, ctx: &RuntimeContext) -> Result<InstallResult> {
         use install_impl::{
             InstallParams, build_layout_metadata, default_install_inner,
-            is_url_plausible_for_platform,
+            is_url_plausible_for_platform, record_install,
         };
 
         // Fail early with a clear message when the provider doesn't support this platform.
@@ -789,7 +798,10 @@ pub trait Runtime: Send + Sync {
             normalize_config: self.normalize_config(),
         };
 
-        default_install_inner(params, version, ctx, |v, p| self.post_extract(v, p)).await
+        let result =
+            default_install_inner(params, version, ctx, |v, p| self.post_extract(v, p)).await?;
+        record_install(&result, ctx);
+        Ok(result)
     }
 
     /// Check if a version is installed
@@ -1001,6 +1013,14 @@ pub trait Runtime: Send + Sync {
         true // Default: all versions are directly installable
     }
 
+    /// Ways vx can install this runtime, most preferred first
+    ///
+    /// Used for capability discovery (`vx capabilities`); installation itself
+    /// goes through [`Runtime::install`]. Default: a download into the store.
+    fn install_kinds(&self) -> Vec<InstallKind> {
+        vec![InstallKind::Download]
+    }
+
     /// Prepare execution for proxy-managed or bundled tool versions
     ///
     /// This method is called before executing a version that returns `false` from
@@ -1078,25 +1098,69 @@ pub trait Runtime: Send + Sync {
     /// Build a download URL chain with mirror fallback support
     ///
     /// Returns a list of URLs to try in order:
-    /// 1. Region-matching mirror URLs (if in China and mirrors configured)
-    /// 2. Original download URL (always last as fallback)
+    /// 1. User mirrors from `~/.vx/config/mirrors.toml` for this runtime
+    /// 2. Region-matching mirror URLs (if in China and mirrors configured)
+    /// 3. Original download URL (always last as fallback)
     ///
     /// This enables automatic mirror selection based on the user's region,
     /// with transparent fallback to the original source.
+    ///
+    /// Mirrors pinned for this runtime by the machine policy replace the
+    /// whole chain.
     async fn build_download_url_chain(
         &self,
         original_url: &str,
         version: &str,
         platform: &Platform,
     ) -> Vec<String> {
-        let mirrors = self.mirror_urls();
-        if mirrors.is_empty() {
-            return vec![original_url.to_string()];
-        }
-
         // Detect current region
         let detected_region = detect_download_region();
 
+        let mut urls = Vec::new();
+
+        // Policy-pinned mirrors are exclusive; otherwise user-declared
+        // mirrors take precedence over provider mirrors
+        let policy_mirrors = crate::user_mirrors::UserMirrors::load_policy();
+        let pinned = !policy_mirrors
+            .for_tool(self.name(), detected_region)
+            .is_empty();
+        let user_mirrors = if pinned {
+            policy_mirrors
+        } else {
+            crate::user_mirrors::UserMirrors::load()
+        };
+        for mirror in user_mirrors.for_tool(self.name(), detected_region) {
+            let mirror_url = if crate::user_mirrors::is_url_template(&mirror.url) {
+                crate::user_mirrors::render_url_template(
+                    &mirror.url,
+                    original_url,
+                    version,
+                    platform,
+                )
+            } else {
+                match self
+                    .download_url_for_mirror(&mirror.url, version, platform)
+                    .await
+                {
+                    Ok(Some(url)) => url,
+                    _ => crate::user_mirrors::join_mirror_base(&mirror.url, original_url),
+                }
+            };
+            tracing::debug!(
+                mirror = mirror.name,
+                url = %mirror_url,
+                "Added user mirror URL to download chain"
+            );
+            if !urls.contains(&mirror_url) {
+                urls.push(mirror_url);
+            }
+        }
+        if pinned {
+            return urls;
+        }
+
+        let mirrors = self.mirror_urls();
+
         // Filter and sort mirrors by region match and priority
         let mut matching_mirrors: Vec<_> = mirrors
             .iter()
@@ -1112,8 +1176,6 @@ pub trait Runtime: Send + Sync {
         // Sort by priority (higher = preferred)
         matching_mirrors.sort_by_key(|b| std::cmp::Reverse(b.priority));
 
-        let mut urls = Vec::new();
-
         // Try to construct mirror URLs
         for mirror in &matching_mirrors {
             match self
@@ -1127,7 +1189,9 @@ pub trait Runtime: Send + Sync {
                         url = %mirror_url,
                         "Added mirror URL to download chain"
                     );
-                    urls.push(mirror_url);
+                    if !urls.contains(&mirror_url) {
+                        urls.push(mirror_url);
+                    }
                 }
                 Ok(None) => {
                     tracing::debug!(
@@ -1146,7 +1210,9 @@ pub trait Runtime: Send + Sync {
         }
 
         // Always include original URL as fallback
-        urls.push(original_url.to_string());
+        if !urls.iter().any(|u| u == original_url) {
+            urls.push(original_url.to_string());
+        }
 
         urls
     }
@@ -1184,7 +1250,48 @@ pub trait Runtime: Send + Sync {
         let resolver = VersionResolver::new();
         let ecosystem = self.ecosystem();
 
-        if let Some(resolved) = resolver.resolve(version, &versions, &ecosystem) {
+        // Exact versions are installed as requested; only `latest` and
+        // ranges avoid vulnerable versions
+        if let Some(policy) = ctx.security.as_deref()
+            && !matches!(
+                resolver.parse_constraint(version),
+                vx_versions::VersionConstraint::Exact(_)
+            )
+        {
+            let resolution = policy
+                .resolve(self.name(), &versions, |candidates| {
+                    resolver.resolve(version, candidates, &ecosystem)
+                })
+                .await;
+            for skipped in &resolution.skipped {
+                tracing::info!(
+                    "Skipping {} {}: {}",
+                    self.name(),
+                    skipped.version,
+                    skipped.reason
+                );
+            }
+            if let Some(resolved) = resolution.version {
+                return Ok(resolved);
+            }
+            if !resolution.skipped.is_empty() || resolution.floor.is_some() {
+                let mut message = format!(
+                    "No acceptable version found for {} matching '{}'",
+                    self.name(),
+                    version
+                );
+                if let Some(floor) = &resolution.floor {
+                    message.push_str(&format!(" at or above the security floor {}", floor));
+                }
+                for skipped in &resolution.skipped {
+                    message.push_str(&format!(
+                        "\n  {} skipped: {}",
+                        skipped.version, skipped.reason
+                    ));
+                }
+                return Err(anyhow::anyhow!(message));
+            }
+        } else if let Some(resolved) = resolver.resolve(version, &versions, &ecosystem) {
             return Ok(resolved);
         }
 
@@ -21,7 +21,7 @@
 //! | [`RuntimePlatform`] | supported_platforms, is_platform_supported, check_platform_support |
 //! | [`RuntimeVersioning`] | fetch_versions, resolve_version, resolve_installed_version, installed_versions |
 //! | [`RuntimeExecutable`] | executable_name, executable_dir_path, executable_relative_path, store_name, … |
-//! | [`RuntimeInstallable`] | download_url, install, uninstall, verify_installation, mirror_urls, … |
+//! | [`RuntimeInstallable`] | download_url, install, uninstall, verify_installation, system_dependencies, … |
 //! | [`RuntimeHooks`] | pre/post install, execute, switch, update hooks + pre_run |
 //! | [`RuntimeEnvironment`] | prepare_environment, execution_environment |
 //! | [`RuntimeExecuteOps`] | execute |
@@ -246,6 +246,8 @@ pub trait RuntimeInstallable: Send + Sync {
     async fn install(&self, version: &str, ctx: &RuntimeContext) -> Result<InstallResult>;
     /// Remove an installed version.
     async fn uninstall(&self, version: &str, ctx: &RuntimeContext) -> Result<()>;
+    /// System-level prerequisites checked before installing.
+    fn system_dependencies(&self) -> Vec<vx_system_pm::SystemDependency>;
     /// Prepare execution context for proxy-managed / bundled versions.
     async fn prepare_execution(
         &self,
@@ -274,6 +276,9 @@ impl<T: Runtime + ?Sized> RuntimeInstallable for T {
     async fn uninstall(&self, v: &str, ctx: &RuntimeContext) -> Result<()> {
         Runtime::uninstall(self, v, ctx).await
     }
+    fn system_dependencies(&self) -> Vec<vx_system_pm::SystemDependency> {
+        Runtime::system_dependencies(self)
+    }
     async fn prepare_execution(&self, v: &str, ctx: &ExecutionContext) -> Result<ExecutionPrep> {
         Runtime::prepare_execution(self, v, ctx).await
     }
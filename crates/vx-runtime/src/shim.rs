@@ -253,7 +253,10 @@ impl Shim {
     /// The path to the created shim file
     pub fn create(&self, dir: &Path, platform: &Platform) -> Result<PathBuf> {
         let shim_file = dir.join(self.file_name(platform));
-        let content = self.content(platform);
+        let content = match self.portable(dir, platform) {
+            Some(portable) => portable.content(platform),
+            None => self.content(platform),
+        };
 
         // Write the file
         std::fs::write(&shim_file, &content)
@@ -272,6 +275,29 @@ impl Shim {
         Ok(shim_file)
     }
 
+    /// In portable mode, a copy whose target is resolved from the directory
+    /// of the shim at run time, so the vx home can move between machines
+    fn portable(&self, dir: &Path, platform: &Platform) -> Option<Self> {
+        if !vx_paths::portable::is_active() {
+            return None;
+        }
+        let relative = vx_paths::portable::relative_path(dir, &self.target)?;
+        let shim_dir = match self.shim_type.unwrap_or_else(|| {
+            if platform.is_windows() {
+                ShimType::Batch
+            } else {
+                ShimType::Shell
+            }
+        }) {
+            ShimType::Batch => "%~dp0",
+            ShimType::PowerShell => "$PSScriptRoot/",
+            ShimType::Shell => "$(dirname \"$0\")/",
+        };
+        let mut shim = self.clone();
+        shim.target = PathBuf::from(format!("{}{}", shim_dir, relative.display()));
+        Some(shim)
+    }
+
     /// Create shim in the same directory as the target executable
     ///
     /// This is useful for creating shims like `bunx` next to `bun`.
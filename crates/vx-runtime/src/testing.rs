@@ -526,6 +526,7 @@ pub fn mock_context() -> RuntimeContext {
         version_cache: None,
         download_url_cache: None,
         install_options: HashMap::new(),
+        security: None,
     }
 }
 
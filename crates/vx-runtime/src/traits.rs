@@ -17,6 +17,11 @@ pub trait HttpClient: Send + Sync {
     /// Perform a GET request and return the response body as JSON Value
     async fn get_json_value(&self, url: &str) -> Result<serde_json::Value>;
 
+    /// Perform a POST request with a JSON body and return the JSON response
+    async fn post_json(&self, url: &str, _body: &serde_json::Value) -> Result<serde_json::Value> {
+        anyhow::bail!("POST is not supported by this HTTP client ({})", url)
+    }
+
     /// Download a file to the specified path
     async fn download(&self, url: &str, dest: &Path) -> Result<()>;
 
@@ -44,6 +49,12 @@ pub trait HttpClient: Send + Sync {
     fn is_cached(&self, _url: &str) -> bool {
         false
     }
+
+    /// Size in bytes a download of `url` would transfer, when the server
+    /// reports it
+    async fn content_length(&self, _url: &str) -> Result<Option<u64>> {
+        Ok(None)
+    }
 }
 
 /// File system abstraction for testability
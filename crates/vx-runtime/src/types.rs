@@ -195,6 +195,37 @@ impl RuntimeSpec {
     }
 }
 
+/// A way vx can install a runtime, as reported by [`Runtime::install_kinds`]
+///
+/// [`Runtime::install_kinds`]: crate::Runtime::install_kinds
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum InstallKind {
+    /// Archive or binary downloaded into the vx store
+    Download,
+    /// Python package installed with `uv pip`
+    Pip { package: String },
+    /// System package manager (brew, apt, choco, ...), outside the vx store
+    PackageManager { manager: String },
+    /// winget/choco installer recorded in the vx store
+    SystemManaged { manager: String },
+    /// Vendor install script
+    Script,
+    /// Installed together with another runtime
+    ProvidedBy { runtime: String },
+}
+
+impl InstallKind {
+    /// Whether this method works without network access, from the download
+    /// cache or an offline bundle
+    ///
+    /// [`InstallKind::ProvidedBy`] depends on the providing runtime and is
+    /// reported as `false` here.
+    pub fn is_offline_capable(&self) -> bool {
+        matches!(self, InstallKind::Download)
+    }
+}
+
 /// Result of an installation operation
 #[derive(Debug, Clone)]
 pub struct InstallResult {
@@ -0,0 +1,164 @@
+//! User-declared download mirrors
+//!
+//! Users behind corporate proxies or in regions with slow access to upstream
+//! hosts can declare mirrors per tool in `~/.vx/config/mirrors.toml`:
+//!
+//! ```toml
+//! [[mirrors.node]]
+//! name = "npmmirror"
+//! region = "cn"
+//! url = "https://npmmirror.com/mirrors/node"
+//! priority = 100
+//!
+//! [[mirrors.go]]
+//! name = "corp-artifactory"
+//! url = "https://artifactory.corp.example/go/{filename}"
+//! ```
+//!
+//! Entries use the same shape as provider mirrors ([`MirrorConfig`]). A mirror
+//! without a `region` applies everywhere; one with a region only applies when
+//! it matches the detected region (see [`crate::region`]).
+//!
+//! The `url` is either a URL template or a base URL:
+//! - Templates contain placeholders that are filled from the original download:
+//!   `{version}`, `{os}`, `{arch}`, `{platform}`, `{filename}` (last path
+//!   segment of the original URL) and `{path}` (original URL path without the
+//!   leading `/`).
+//! - Base URLs are handed to the runtime's `download_url_for_mirror`, and
+//!   fall back to `<url>/<filename>` when the runtime has no mirror layout.
+
+use crate::platform::Platform;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use vx_manifest::MirrorConfig;
+
+/// Placeholders recognised in mirror URL templates
+const TEMPLATE_PLACEHOLDERS: &[&str] = &[
+    "{version}",
+    "{os}",
+    "{arch}",
+    "{platform}",
+    "{filename}",
+    "{path}",
+];
+
+/// Mirrors declared in `~/.vx/config/mirrors.toml`, keyed by tool name
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UserMirrors {
+    /// Tool name → mirrors for that tool
+    #[serde(default)]
+    pub mirrors: HashMap<String, Vec<MirrorConfig>>,
+}
+
+impl UserMirrors {
+    /// Load mirrors from the default vx config directory
+    ///
+    /// A missing file yields no mirrors; an invalid file is logged and ignored
+    /// so a typo never blocks installs.
+    pub fn load() -> Self {
+        let Ok(paths) = vx_paths::VxPaths::new() else {
+            return Self::default();
+        };
+        let path = paths.mirrors_config();
+        if !path.exists() {
+            return Self::default();
+        }
+        Self::from_file(&path).unwrap_or_else(|e| {
+            tracing::warn!(path = %path.display(), error = %e, "Ignoring invalid mirrors config");
+            Self::default()
+        })
+    }
+
+    /// Load mirrors pinned by the machine policy ([`vx_paths::policy_file`])
+    ///
+    /// Tools listed here only download from these mirrors, with no fallback
+    /// to user mirrors or the upstream URL.
+    pub fn load_policy() -> Self {
+        let path = vx_paths::policy_file();
+        if !path.exists() {
+            return Self::default();
+        }
+        Self::from_file(&path).unwrap_or_else(|e| {
+            tracing::warn!(path = %path.display(), error = %e, "Ignoring invalid policy mirrors");
+            Self::default()
+        })
+    }
+
+    /// Load mirrors from a specific file
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Parse mirrors from TOML content
+    pub fn parse(content: &str) -> Result<Self> {
+        Ok(toml::from_str(content)?)
+    }
+
+    /// Enabled mirrors for `tool` that apply in `region`, highest priority first
+    pub fn for_tool(&self, tool: &str, region: &str) -> Vec<&MirrorConfig> {
+        let mut mirrors: Vec<_> = self
+            .mirrors
+            .get(tool)
+            .map(|list| {
+                list.iter()
+                    .filter(|m| m.enabled)
+                    .filter(|m| m.region.as_deref().is_none_or(|r| r == region))
+                    .collect()
+            })
+            .unwrap_or_default();
+        mirrors.sort_by_key(|m| std::cmp::Reverse(m.priority));
+        mirrors
+    }
+}
+
+/// Whether a mirror URL contains template placeholders
+pub fn is_url_template(url: &str) -> bool {
+    TEMPLATE_PLACEHOLDERS.iter().any(|p| url.contains(p))
+}
+
+/// Render a mirror URL template against the original download URL
+pub fn render_url_template(
+    template: &str,
+    original_url: &str,
+    version: &str,
+    platform: &Platform,
+) -> String {
+    template
+        .replace("{version}", version)
+        .replace("{os}", platform.os.as_str())
+        .replace("{arch}", platform.arch.as_str())
+        .replace("{platform}", &platform.as_str())
+        .replace("{filename}", url_filename(original_url))
+        .replace("{path}", url_path(original_url))
+}
+
+/// Append the original download's filename to a mirror base URL
+pub fn join_mirror_base(base_url: &str, original_url: &str) -> String {
+    format!(
+        "{}/{}",
+        base_url.trim_end_matches('/'),
+        url_filename(original_url)
+    )
+}
+
+/// Path of a URL without scheme, host, query or leading `/`
+fn url_path(url: &str) -> &str {
+    let without_query = url.split(['?', '#']).next().unwrap_or(url);
+    let after_scheme = without_query
+        .split_once("://")
+        .map(|(_, rest)| rest)
+        .unwrap_or(without_query);
+    after_scheme
+        .split_once('/')
+        .map(|(_, path)| path)
+        .unwrap_or("")
+}
+
+/// Last path segment of a URL
+fn url_filename(url: &str) -> &str {
+    url_path(url).rsplit('/').next().unwrap_or("")
+}
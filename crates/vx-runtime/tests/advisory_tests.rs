@@ -0,0 +1,282 @@
+//! Tests for OSV advisories and vulnerability-aware resolution
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{Result, bail};
+use async_trait::async_trait;
+use tempfile::TempDir;
+use vx_runtime::{
+    Advisory, AdvisorySource, HttpClient, Installer, RealFileSystem, RealPathProvider, Runtime,
+    RuntimeContext, SecurityPolicy, Severity, VersionInfo, advisory::parse_osv_response,
+};
+
+/// Advisories by version; versions listed in `failing` error out
+#[derive(Default)]
+struct StaticAdvisories {
+    advisories: HashMap<&'static str, Vec<Advisory>>,
+    failing: Vec<&'static str>,
+}
+
+impl StaticAdvisories {
+    fn with(mut self, version: &'static str, severity: Severity) -> Self {
+        self.advisories.insert(
+            version,
+            vec![Advisory {
+                id: format!("GHSA-{}", version),
+                summary: "Remote code execution".to_string(),
+                severity: Some(severity),
+            }],
+        );
+        self
+    }
+}
+
+#[async_trait]
+impl AdvisorySource for StaticAdvisories {
+    async fn advisories(&self, _runtime: &str, version: &str) -> Result<Vec<Advisory>> {
+        if self.failing.contains(&version) {
+            bail!("network unreachable");
+        }
+        Ok(self.advisories.get(version).cloned().unwrap_or_default())
+    }
+}
+
+fn policy(advisories: StaticAdvisories, floors: &[(&str, &str)]) -> SecurityPolicy {
+    SecurityPolicy {
+        skip_severity: Some(Severity::Critical),
+        floors: floors
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        advisories: Arc::new(advisories),
+    }
+}
+
+fn versions(list: &[&str]) -> Vec<VersionInfo> {
+    list.iter().map(|v| VersionInfo::new(*v)).collect()
+}
+
+/// Picks the first (newest) candidate, like resolving `latest`
+fn first(candidates: &[VersionInfo]) -> Option<String> {
+    candidates.first().map(|v| v.version.clone())
+}
+
+#[test]
+fn test_severity_parse_and_order() {
+    assert_eq!(Severity::parse("CRITICAL"), Some(Severity::Critical));
+    assert_eq!(Severity::parse("moderate"), Some(Severity::Medium));
+    assert_eq!(Severity::parse("unknown"), None);
+    assert!(Severity::Critical > Severity::High);
+    assert!(Severity::Medium > Severity::Low);
+}
+
+#[test]
+fn test_parse_osv_response() {
+    let response = serde_json::json!({
+        "vulns": [
+            {
+                "id": "GHSA-aaaa",
+                "summary": "Prototype pollution",
+                "database_specific": { "severity": "CRITICAL" }
+            },
+            {
+                "id": "PYSEC-2024-1",
+                "details": "Path traversal in archive extraction\nMore details",
+                "affected": [{ "ecosystem_specific": { "severity": "HIGH" } }]
+            },
+            { "id": "GO-2024-2" }
+        ]
+    });
+
+    let advisories = parse_osv_response(&response);
+    assert_eq!(advisories.len(), 3);
+    assert_eq!(advisories[0].summary, "Prototype pollution");
+    assert_eq!(advisories[0].severity, Some(Severity::Critical));
+    assert_eq!(
+        advisories[1].summary,
+        "Path traversal in archive extraction"
+    );
+    assert_eq!(advisories[1].severity, Some(Severity::High));
+    assert_eq!(advisories[2].severity, None);
+
+    assert!(parse_osv_response(&serde_json::json!({})).is_empty());
+}
+
+#[tokio::test]
+async fn test_resolve_skips_critical_versions() {
+    let policy = policy(
+        StaticAdvisories::default()
+            .with("3.0.0", Severity::Critical)
+            .with("2.1.0", Severity::High),
+        &[],
+    );
+
+    let resolution = policy
+        .resolve("pnpm", &versions(&["3.0.0", "2.1.0", "2.0.0"]), first)
+        .await;
+
+    assert_eq!(resolution.version.as_deref(), Some("2.1.0"));
+    assert_eq!(resolution.skipped.len(), 1);
+    assert_eq!(resolution.skipped[0].version, "3.0.0");
+    assert_eq!(
+        resolution.skipped[0].reason,
+        "critical advisory GHSA-3.0.0: Remote code execution"
+    );
+}
+
+#[tokio::test]
+async fn test_resolve_never_goes_below_floor() {
+    let policy = policy(
+        StaticAdvisories::default().with("3.0.0", Severity::Critical),
+        &[("pnpm", "2.5.0")],
+    );
+
+    let resolution = policy
+        .resolve("pnpm", &versions(&["3.0.0", "2.1.0", "2.0.0"]), first)
+        .await;
+
+    assert_eq!(resolution.version, None);
+    assert_eq!(resolution.floor.as_deref(), Some("2.5.0"));
+    assert_eq!(resolution.skipped.len(), 1);
+}
+
+#[tokio::test]
+async fn test_resolve_floor_without_advisories() {
+    let policy = SecurityPolicy {
+        skip_severity: None,
+        ..policy(
+            StaticAdvisories::default().with("1.5.0", Severity::Critical),
+            &[("uv", "1.2.0")],
+        )
+    };
+
+    let resolution = policy
+        .resolve("uv", &versions(&["1.5.0", "1.1.0"]), |c| {
+            c.last().map(|v| v.version.clone())
+        })
+        .await;
+
+    // Oldest candidate at or above the floor; advisories aren't consulted
+    assert_eq!(resolution.version.as_deref(), Some("1.5.0"));
+    assert!(resolution.skipped.is_empty());
+}
+
+#[tokio::test]
+async fn test_resolve_keeps_version_when_advisories_unavailable() {
+    let policy = policy(
+        StaticAdvisories {
+            failing: vec!["3.0.0"],
+            ..Default::default()
+        },
+        &[],
+    );
+
+    let resolution = policy
+        .resolve("pnpm", &versions(&["3.0.0", "2.0.0"]), first)
+        .await;
+
+    assert_eq!(resolution.version.as_deref(), Some("3.0.0"));
+    assert!(resolution.skipped.is_empty());
+}
+
+struct NoopHttpClient;
+
+#[async_trait]
+impl HttpClient for NoopHttpClient {
+    async fn get(&self, _url: &str) -> Result<String> {
+        bail!("not used in advisory_tests")
+    }
+
+    async fn get_json_value(&self, _url: &str) -> Result<serde_json::Value> {
+        bail!("not used in advisory_tests")
+    }
+
+    async fn download(&self, _url: &str, _dest: &Path) -> Result<()> {
+        bail!("not used in advisory_tests")
+    }
+
+    async fn download_with_progress(
+        &self,
+        _url: &str,
+        _dest: &Path,
+        _on_progress: &(dyn Fn(u64, u64) + Send + Sync),
+    ) -> Result<()> {
+        bail!("not used in advisory_tests")
+    }
+}
+
+struct NoopInstaller;
+
+#[async_trait]
+impl Installer for NoopInstaller {
+    async fn extract(&self, _archive: &Path, _dest: &Path) -> Result<()> {
+        bail!("not used in advisory_tests")
+    }
+
+    async fn download_and_extract(&self, _url: &str, _dest: &Path) -> Result<()> {
+        bail!("not used in advisory_tests")
+    }
+}
+
+fn test_context(policy: SecurityPolicy) -> (TempDir, RuntimeContext) {
+    let temp_dir = tempfile::tempdir().expect("temp dir should be created");
+    let ctx = RuntimeContext::new(
+        Arc::new(RealPathProvider::with_base_dir(temp_dir.path())),
+        Arc::new(NoopHttpClient),
+        Arc::new(RealFileSystem::new()),
+        Arc::new(NoopInstaller),
+    )
+    .with_security_policy(Some(Arc::new(policy)));
+    (temp_dir, ctx)
+}
+
+struct TestRuntime;
+
+#[async_trait]
+impl Runtime for TestRuntime {
+    fn name(&self) -> &str {
+        "pnpm"
+    }
+
+    async fn fetch_versions(&self, _ctx: &RuntimeContext) -> Result<Vec<VersionInfo>> {
+        Ok(versions(&["3.0.0", "2.1.0", "2.0.0"]))
+    }
+}
+
+#[tokio::test]
+async fn test_resolve_version_applies_policy() {
+    let policy = policy(
+        StaticAdvisories::default().with("3.0.0", Severity::Critical),
+        &[],
+    );
+    let (_temp_dir, ctx) = test_context(policy);
+
+    assert_eq!(
+        TestRuntime.resolve_version("latest", &ctx).await.unwrap(),
+        "2.1.0"
+    );
+    // Exact versions are installed as requested
+    assert_eq!(
+        TestRuntime.resolve_version("3.0.0", &ctx).await.unwrap(),
+        "3.0.0"
+    );
+}
+
+#[tokio::test]
+async fn test_resolve_version_reports_skips_when_exhausted() {
+    let policy = policy(
+        StaticAdvisories::default().with("3.0.0", Severity::Critical),
+        &[("pnpm", "3.0.0")],
+    );
+    let (_temp_dir, ctx) = test_context(policy);
+
+    let err = TestRuntime
+        .resolve_version("latest", &ctx)
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("security floor 3.0.0"), "{}", err);
+    assert!(err.contains("3.0.0 skipped: critical advisory"), "{}", err);
+}
@@ -0,0 +1,226 @@
+//! Tests for the asdf plugin compatibility layer
+
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{Result, bail};
+use async_trait::async_trait;
+use vx_runtime::asdf::parse_list_all;
+use vx_runtime::{
+    AsdfPlugin, AsdfPluginConfig, AsdfProvider, HttpClient, Installer, Provider, RealFileSystem,
+    RealPathProvider, RuntimeContext, discover_asdf_plugins,
+};
+
+#[derive(Debug)]
+struct NoopHttpClient;
+
+#[async_trait]
+impl HttpClient for NoopHttpClient {
+    async fn get(&self, _url: &str) -> Result<String> {
+        bail!("not used")
+    }
+
+    async fn get_json_value(&self, _url: &str) -> Result<serde_json::Value> {
+        bail!("not used")
+    }
+
+    async fn download(&self, _url: &str, _dest: &Path) -> Result<()> {
+        bail!("not used")
+    }
+
+    async fn download_with_progress(
+        &self,
+        _url: &str,
+        _dest: &Path,
+        _on_progress: &(dyn Fn(u64, u64) + Send + Sync),
+    ) -> Result<()> {
+        bail!("not used")
+    }
+}
+
+#[derive(Debug)]
+struct NoopInstaller;
+
+#[async_trait]
+impl Installer for NoopInstaller {
+    async fn extract(&self, _archive: &Path, _dest: &Path) -> Result<()> {
+        bail!("not used")
+    }
+
+    async fn download_and_extract(&self, _url: &str, _dest: &Path) -> Result<()> {
+        bail!("not used")
+    }
+}
+
+fn context(home: &Path) -> RuntimeContext {
+    RuntimeContext::new(
+        Arc::new(RealPathProvider::with_base_dir(home)),
+        Arc::new(NoopHttpClient),
+        Arc::new(RealFileSystem::new()),
+        Arc::new(NoopInstaller),
+    )
+}
+
+#[cfg(unix)]
+fn write_script(path: &Path, body: &str) {
+    use std::os::unix::fs::PermissionsExt;
+
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, format!("#!/bin/sh\nset -e\n{body}\n")).unwrap();
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+/// A plugin that "builds" a shell script from the downloaded sources
+#[cfg(unix)]
+fn demo_plugin(root: &Path, name: &str) -> std::path::PathBuf {
+    let dir = root.join(name);
+    write_script(
+        &dir.join("bin/list-all"),
+        r#"echo "1.0.0 1.1.0 2.0.0-rc.1""#,
+    );
+    write_script(
+        &dir.join("bin/download"),
+        r#"echo "echo demo $ASDF_INSTALL_VERSION" > "$ASDF_DOWNLOAD_PATH/src.sh""#,
+    );
+    write_script(
+        &dir.join("bin/install"),
+        r#"mkdir -p "$ASDF_INSTALL_PATH/libexec"
+cp "$ASDF_DOWNLOAD_PATH/src.sh" "$ASDF_INSTALL_PATH/libexec/demo"
+chmod +x "$ASDF_INSTALL_PATH/libexec/demo"
+echo "$HOME" > "$ASDF_INSTALL_PATH/home.txt"
+echo "${CARGO_MANIFEST_DIR:-unset}" > "$ASDF_INSTALL_PATH/leaked.txt""#,
+    );
+    write_script(&dir.join("bin/list-bin-paths"), "echo libexec");
+    dir
+}
+
+#[test]
+fn test_parse_list_all() {
+    let versions = parse_list_all("1.0.0 1.1.0\n2.0.0-rc.1\n");
+    let names: Vec<&str> = versions.iter().map(|v| v.version.as_str()).collect();
+    assert_eq!(names, ["2.0.0-rc.1", "1.1.0", "1.0.0"]);
+    assert!(versions[0].prerelease);
+    assert!(!versions[1].prerelease);
+}
+
+#[test]
+fn test_plugin_config_roundtrip() {
+    let dir = tempfile::tempdir().unwrap();
+    assert_eq!(
+        AsdfPluginConfig::load(dir.path()).unwrap(),
+        AsdfPluginConfig::default()
+    );
+
+    let config = AsdfPluginConfig {
+        executable: Some("node".to_string()),
+        description: None,
+        repository: Some("https://github.com/asdf-vm/asdf-nodejs".to_string()),
+    };
+    config.save(dir.path()).unwrap();
+    assert_eq!(AsdfPluginConfig::load(dir.path()).unwrap(), config);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_discover_skips_invalid_plugins() {
+    let root = tempfile::tempdir().unwrap();
+    demo_plugin(root.path(), "demo");
+    std::fs::create_dir_all(root.path().join("no-scripts")).unwrap();
+
+    let (plugins, skipped) = discover_asdf_plugins(root.path());
+    assert_eq!(plugins.len(), 1);
+    assert_eq!(plugins[0].name(), "demo");
+    assert_eq!(skipped.len(), 1);
+    assert!(skipped[0].1.contains("bin/list-all"), "{}", skipped[0].1);
+
+    assert!(
+        discover_asdf_plugins(&root.path().join("missing"))
+            .0
+            .is_empty()
+    );
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_install_runs_scripts_in_sandbox() {
+    let root = tempfile::tempdir().unwrap();
+    let home = tempfile::tempdir().unwrap();
+    let ctx = context(home.path());
+    let plugin = AsdfPlugin::load(&demo_plugin(root.path(), "demo")).unwrap();
+    let provider = AsdfProvider::new(plugin);
+    let runtime = provider.get_runtime("demo").unwrap();
+
+    let versions = runtime.fetch_versions(&ctx).await.unwrap();
+    assert_eq!(versions.len(), 3);
+    assert_eq!(runtime.resolve_version("1", &ctx).await.unwrap(), "1.1.0");
+
+    let result = runtime.install("1.1.0", &ctx).await.unwrap();
+    assert!(!result.already_installed);
+    assert_eq!(result.install_path, home.path().join("store/demo/1.1.0"));
+    assert_eq!(
+        result.executable_path,
+        result.install_path.join("libexec/demo")
+    );
+    let exe = std::fs::read_to_string(&result.executable_path).unwrap();
+    assert_eq!(exe.trim(), "echo demo 1.1.0");
+
+    // Scripts get a private HOME and none of the caller's variables
+    let script_home = std::fs::read_to_string(result.install_path.join("home.txt")).unwrap();
+    assert!(
+        Path::new(script_home.trim()).starts_with(home.path().join("cache/asdf/demo")),
+        "{script_home}"
+    );
+    // cargo sets CARGO_MANIFEST_DIR for the test process
+    assert!(std::env::var_os("CARGO_MANIFEST_DIR").is_some());
+    let leaked = std::fs::read_to_string(result.install_path.join("leaked.txt")).unwrap();
+    assert_eq!(leaked.trim(), "unset");
+
+    assert_eq!(
+        runtime.installed_versions(&ctx).await.unwrap(),
+        ["1.1.0".to_string()]
+    );
+    let again = runtime.install("1.1.0", &ctx).await.unwrap();
+    assert!(again.already_installed);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_failed_install_is_cleaned_up() {
+    let root = tempfile::tempdir().unwrap();
+    let home = tempfile::tempdir().unwrap();
+    let ctx = context(home.path());
+    let dir = demo_plugin(root.path(), "broken");
+    write_script(
+        &dir.join("bin/install"),
+        r#"mkdir -p "$ASDF_INSTALL_PATH/bin"
+echo "compiler exploded" >&2
+exit 3"#,
+    );
+    let provider = AsdfProvider::new(AsdfPlugin::load(&dir).unwrap());
+    let runtime = provider.get_runtime("broken").unwrap();
+
+    let err = runtime.install("1.0.0", &ctx).await.unwrap_err();
+    assert!(err.to_string().contains("compiler exploded"), "{err}");
+    assert!(!home.path().join("store/broken/1.0.0").exists());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_scripts_outside_plugin_are_rejected() {
+    let root = tempfile::tempdir().unwrap();
+    let outside = tempfile::tempdir().unwrap();
+    let dir = demo_plugin(root.path(), "escape");
+    let target = outside.path().join("list-all");
+    write_script(&target, "echo 1.0.0");
+    std::fs::remove_file(dir.join("bin/list-all")).unwrap();
+    std::os::unix::fs::symlink(&target, dir.join("bin/list-all")).unwrap();
+
+    let home = tempfile::tempdir().unwrap();
+    let provider = AsdfProvider::new(AsdfPlugin::load(&dir).unwrap());
+    let runtime = provider.get_runtime("escape").unwrap();
+    let err = runtime
+        .fetch_versions(&context(home.path()))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("outside the plugin"), "{err}");
+}
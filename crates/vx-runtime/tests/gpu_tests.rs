@@ -0,0 +1,65 @@
+//! Tests for GPU and CUDA detection
+
+use vx_runtime::gpu::{parse_cuda_version, parse_gpu_query, parse_nvcc_version};
+use vx_runtime::{GpuDevice, GpuInfo};
+
+const SMI_BANNER: &str = "\
++-----------------------------------------------------------------------------------------+
+| NVIDIA-SMI 550.54.14              Driver Version: 550.54.14      CUDA Version: 12.4     |
+|-----------------------------------------+------------------------+----------------------+
+";
+
+#[test]
+fn test_parse_gpu_query() {
+    let (devices, driver) = parse_gpu_query(
+        "NVIDIA GeForce RTX 4090, 550.54.14, 24564\nNVIDIA A100-SXM4-80GB, 550.54.14, 81920\n",
+    );
+    assert_eq!(driver.as_deref(), Some("550.54.14"));
+    assert_eq!(
+        devices,
+        [
+            GpuDevice {
+                name: "NVIDIA GeForce RTX 4090".to_string(),
+                memory_mib: Some(24564),
+            },
+            GpuDevice {
+                name: "NVIDIA A100-SXM4-80GB".to_string(),
+                memory_mib: Some(81920),
+            },
+        ]
+    );
+
+    let (devices, driver) = parse_gpu_query("");
+    assert!(devices.is_empty());
+    assert!(driver.is_none());
+}
+
+#[test]
+fn test_parse_cuda_version() {
+    assert_eq!(parse_cuda_version(SMI_BANNER).as_deref(), Some("12.4"));
+    assert_eq!(parse_cuda_version("CUDA Version: N/A"), None);
+    assert_eq!(parse_cuda_version("No devices were found"), None);
+}
+
+#[test]
+fn test_parse_nvcc_version() {
+    let output = "nvcc: NVIDIA (R) Cuda compiler driver\n\
+                  Copyright (c) 2005-2024 NVIDIA Corporation\n\
+                  Cuda compilation tools, release 12.4, V12.4.131\n";
+    assert_eq!(parse_nvcc_version(output).as_deref(), Some("12.4"));
+    assert_eq!(parse_nvcc_version("command not found"), None);
+}
+
+#[test]
+fn test_override() {
+    let gpu = GpuInfo::from_override("12.1").unwrap();
+    assert!(gpu.is_available());
+    assert_eq!(gpu.cuda_major(), Some(12));
+    assert_eq!(gpu.summary(), "CUDA 12.1");
+
+    let none = GpuInfo::from_override("none").unwrap();
+    assert!(!none.is_available());
+    assert_eq!(none.summary(), "no NVIDIA GPU detected");
+
+    assert!(GpuInfo::from_override("  ").is_none());
+}
@@ -0,0 +1,80 @@
+//! Tests for the install strategies runtimes report for capability discovery
+
+use vx_runtime::{InstallKind, InstallStrategy, ManifestDrivenRuntime, ProviderSource, Runtime};
+
+fn package_manager(manager: &str, priority: i32) -> InstallStrategy {
+    InstallStrategy::PackageManager {
+        manager: manager.to_string(),
+        package: "tool".to_string(),
+        params: None,
+        install_args: None,
+        priority,
+        platforms: Vec::new(),
+    }
+}
+
+#[test]
+fn test_download_comes_before_strategies_by_priority() {
+    let runtime = ManifestDrivenRuntime::new("tool", "tool", ProviderSource::BuiltIn)
+        .with_download_url(|_| Box::pin(async { Ok(None) }))
+        .with_strategy(package_manager("apt", 50))
+        .with_strategy(package_manager("brew", 90))
+        .with_strategy(package_manager("brew", 10));
+
+    assert_eq!(
+        runtime.install_kinds(),
+        [
+            InstallKind::Download,
+            InstallKind::PackageManager {
+                manager: "brew".to_string()
+            },
+            InstallKind::PackageManager {
+                manager: "apt".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_bundled_and_pip_runtimes() {
+    let npx = ManifestDrivenRuntime::new("npx", "node", ProviderSource::BuiltIn)
+        .with_bundled_with("node");
+    assert_eq!(
+        npx.install_kinds(),
+        [InstallKind::ProvidedBy {
+            runtime: "node".to_string()
+        }]
+    );
+
+    let rez =
+        ManifestDrivenRuntime::new("rez", "rez", ProviderSource::BuiltIn).with_pip_package("rez");
+    assert_eq!(
+        rez.install_kinds(),
+        [InstallKind::Pip {
+            package: "rez".to_string()
+        }]
+    );
+
+    let detected = ManifestDrivenRuntime::new("systemctl", "systemctl", ProviderSource::BuiltIn);
+    assert!(detected.install_kinds().is_empty());
+}
+
+#[test]
+fn test_install_kind_offline_and_json() {
+    assert!(InstallKind::Download.is_offline_capable());
+    assert!(
+        !InstallKind::PackageManager {
+            manager: "brew".to_string()
+        }
+        .is_offline_capable()
+    );
+
+    let json = serde_json::to_value(InstallKind::SystemManaged {
+        manager: "winget".to_string(),
+    })
+    .unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({"kind": "system_managed", "manager": "winget"})
+    );
+}
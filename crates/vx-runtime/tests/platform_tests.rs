@@ -0,0 +1,76 @@
+//! Tests for host architecture and libc detection and the emulation fallback
+
+use vx_runtime::{Arch, ArchFallback, Libc, Os, Platform};
+
+#[test]
+fn test_windows_arch_from_env() {
+    // x64 process emulated on an ARM64 machine
+    assert_eq!(
+        Arch::from_windows_env(
+            Some("ARMv8 (64-bit) Family 8 Model D4B Revision 0, Qualcomm Technologies Inc"),
+            Some("AMD64"),
+        ),
+        Some(Arch::Aarch64)
+    );
+    assert_eq!(
+        Arch::from_windows_env(None, Some("ARM64")),
+        Some(Arch::Aarch64)
+    );
+    assert_eq!(
+        Arch::from_windows_env(
+            Some("Intel64 Family 6 Model 154 Stepping 3, GenuineIntel"),
+            Some("AMD64"),
+        ),
+        Some(Arch::X86_64)
+    );
+    assert_eq!(Arch::from_windows_env(None, Some("x86")), Some(Arch::X86));
+    assert_eq!(Arch::from_windows_env(None, None), None);
+}
+
+#[test]
+fn test_arch_fallback_parse() {
+    assert_eq!(ArchFallback::parse("emulate"), Some(ArchFallback::Emulate));
+    assert_eq!(ArchFallback::parse(" Never "), Some(ArchFallback::Never));
+    assert_eq!(ArchFallback::parse("x64"), None);
+    assert_eq!(ArchFallback::default(), ArchFallback::Emulate);
+}
+
+#[test]
+fn test_emulation_fallback() {
+    let windows_arm64 = Platform::new(Os::Windows, Arch::Aarch64);
+    assert_eq!(
+        windows_arm64.emulation_fallback(ArchFallback::Emulate),
+        Some(Platform::new(Os::Windows, Arch::X86_64))
+    );
+    assert_eq!(windows_arm64.emulation_fallback(ArchFallback::Never), None);
+
+    for platform in [
+        Platform::new(Os::Windows, Arch::X86_64),
+        Platform::new(Os::MacOS, Arch::Aarch64),
+        Platform::new(Os::Linux, Arch::Aarch64),
+    ] {
+        assert_eq!(platform.emulation_fallback(ArchFallback::Emulate), None);
+    }
+}
+
+#[test]
+fn test_libc_detect() {
+    let alpine = "NAME=\"Alpine Linux\"\nID=alpine\nVERSION_ID=3.20.0\n";
+    assert_eq!(Libc::detect(Some(alpine), false), Libc::Musl);
+    let ubuntu = "NAME=\"Ubuntu\"\nID=ubuntu\nID_LIKE=debian\n";
+    assert_eq!(Libc::detect(Some(ubuntu), false), Libc::Gnu);
+    // Distros without os-release but with only a musl linker (e.g. Void musl)
+    assert_eq!(Libc::detect(None, true), Libc::Musl);
+    assert_eq!(Libc::detect(None, false), Libc::Gnu);
+    // A description mentioning Alpine doesn't make a glibc distro musl
+    let wolfi = "ID=wolfi\nPRETTY_NAME=\"Wolfi (Alpine-compatible tooling)\"\n";
+    assert_eq!(Libc::detect(Some(wolfi), false), Libc::Gnu);
+}
+
+#[test]
+fn test_libc_parse() {
+    assert_eq!(Libc::parse("musl"), Some(Libc::Musl));
+    assert_eq!(Libc::parse(" GLIBC "), Some(Libc::Gnu));
+    assert_eq!(Libc::parse("gnu"), Some(Libc::Gnu));
+    assert_eq!(Libc::parse("uclibc"), None);
+}
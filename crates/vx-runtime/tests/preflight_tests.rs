@@ -0,0 +1,80 @@
+//! Tests for the install filesystem preflight checks
+
+use std::path::{Path, PathBuf};
+use vx_runtime::preflight::{
+    FsProperties, INSTALL_PATH_HEADROOM, WINDOWS_MAX_PATH, case_collisions, check_install_path,
+    ensure_case_safe,
+};
+
+#[test]
+fn test_probe_creates_dir_and_cleans_up() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path().join("store").join("node");
+
+    let props = FsProperties::probe(&dir).unwrap();
+    assert!(dir.is_dir());
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+    if cfg!(target_os = "linux") {
+        assert!(props.case_sensitive);
+        assert_eq!(props.max_path, None);
+    }
+}
+
+#[test]
+fn test_check_install_path() {
+    let limited = FsProperties {
+        case_sensitive: false,
+        max_path: Some(WINDOWS_MAX_PATH),
+    };
+    let short = Path::new(r"C:\Users\dev\.vx\store\node\20.11.1");
+    assert!(check_install_path(short, &limited).is_ok());
+
+    let deep = PathBuf::from(format!(
+        r"C:\{}\node\20.11.1",
+        "d".repeat(WINDOWS_MAX_PATH - INSTALL_PATH_HEADROOM)
+    ));
+    let err = check_install_path(&deep, &limited).unwrap_err().to_string();
+    assert!(err.contains("LongPathsEnabled") && err.contains("VX_HOME"));
+
+    let unlimited = FsProperties {
+        max_path: None,
+        ..limited
+    };
+    assert!(check_install_path(&deep, &unlimited).is_ok());
+}
+
+#[test]
+fn test_case_collisions() {
+    let names = [
+        "linux/include/",
+        "linux/include/Makefile",
+        "linux/Include/",
+        "linux/include/makefile",
+        "linux/README",
+        "linux/include/Makefile",
+        "linux/readme",
+    ];
+    assert_eq!(
+        case_collisions(names),
+        [
+            (
+                "linux/include/Makefile".to_string(),
+                "linux/include/makefile".to_string()
+            ),
+            ("linux/README".to_string(), "linux/readme".to_string()),
+        ]
+    );
+    assert!(case_collisions(["bin/", "Bin/", "bin/node", "lib/node"]).is_empty());
+}
+
+#[test]
+#[cfg(not(windows))]
+fn test_ensure_case_safe() {
+    let dest = Path::new("/Users/dev/.vx/store/linux-headers/6.1");
+    assert!(ensure_case_safe(["a/B", "a/c"], dest).is_ok());
+
+    let err = ensure_case_safe(["xt_DSCP.h", "xt_dscp.h"], dest)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("case-insensitive") && err.contains("'xt_DSCP.h' and 'xt_dscp.h'"));
+}
@@ -2,7 +2,9 @@
 
 use async_trait::async_trait;
 use std::sync::Arc;
-use vx_runtime::{Provider, ProviderRegistry, Runtime, RuntimeContext, VersionInfo};
+use vx_runtime::{
+    Provider, ProviderFailure, ProviderRegistry, Runtime, RuntimeContext, VersionInfo,
+};
 
 /// Test runtime
 struct TestRuntime {
@@ -205,3 +207,72 @@ fn test_registry_multiple_providers() {
     assert!(registry.supports("go"));
     assert!(registry.supports("golang"));
 }
+
+fn register_panicking(registry: &ProviderRegistry, name: &'static str) {
+    registry.register_lazy(
+        name.to_string(),
+        vec![name.to_string()],
+        Box::new(move || panic!("{} failed to parse", name)),
+    );
+}
+
+fn register_lazy_test(registry: &ProviderRegistry, name: &'static str) {
+    registry.register_lazy(
+        name.to_string(),
+        vec![name.to_string()],
+        Box::new(move || {
+            Arc::new(TestProvider::new(
+                name,
+                vec![Arc::new(TestRuntime { name, aliases: &[] })],
+            ))
+        }),
+    );
+}
+
+#[test]
+fn test_registry_isolates_panicking_provider() {
+    let registry = ProviderRegistry::new();
+    register_panicking(&registry, "broken");
+    register_lazy_test(&registry, "node");
+
+    assert!(registry.get_runtime("broken").is_none());
+    assert!(registry.get_runtime("node").is_some());
+    assert_eq!(registry.runtime_names(), vec!["node".to_string()]);
+
+    let failures = registry.failed_providers();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].provider, "broken");
+    assert_eq!(failures[0].error, "broken failed to parse");
+}
+
+#[test]
+fn test_registry_isolates_panics_when_materializing_all() {
+    let registry = ProviderRegistry::new();
+    register_lazy_test(&registry, "go");
+    register_panicking(&registry, "broken");
+
+    assert_eq!(registry.providers().len(), 1);
+    assert!(registry.supports("go"));
+    assert!(!registry.has_pending());
+    assert_eq!(registry.failed_providers()[0].provider, "broken");
+}
+
+#[test]
+fn test_registry_record_failure_replaces_earlier_one() {
+    let registry = ProviderRegistry::new();
+    registry.record_failure(ProviderFailure::new("tool", "first"));
+    registry.record_failure(ProviderFailure::new("tool", "second"));
+    registry.record_failure(ProviderFailure::new("another", "error"));
+
+    let failures = registry.failed_providers();
+    assert_eq!(
+        failures,
+        vec![
+            ProviderFailure::new("another", "error"),
+            ProviderFailure::new("tool", "second"),
+        ]
+    );
+
+    registry.clear();
+    assert!(registry.failed_providers().is_empty());
+}
@@ -0,0 +1,123 @@
+//! Tests for user-declared download mirrors (`~/.vx/config/mirrors.toml`)
+
+use vx_runtime::user_mirrors::{
+    UserMirrors, is_url_template, join_mirror_base, render_url_template,
+};
+use vx_runtime::{Arch, Os, Platform};
+
+const CONFIG: &str = r#"
+[[mirrors.node]]
+name = "npmmirror"
+region = "cn"
+url = "https://npmmirror.com/mirrors/node"
+priority = 100
+
+[[mirrors.node]]
+name = "corp"
+url = "https://artifactory.corp.example/node/{version}/{filename}"
+priority = 50
+
+[[mirrors.node]]
+name = "disabled"
+url = "https://old.example/node"
+priority = 200
+enabled = false
+"#;
+
+#[test]
+fn test_parse_user_mirrors() {
+    let mirrors = UserMirrors::parse(CONFIG).unwrap();
+    assert_eq!(mirrors.mirrors["node"].len(), 3);
+    assert!(mirrors.mirrors["node"][0].enabled);
+    assert!(!mirrors.mirrors["node"][2].enabled);
+}
+
+#[test]
+fn test_parse_empty_config() {
+    let mirrors = UserMirrors::parse("").unwrap();
+    assert!(mirrors.mirrors.is_empty());
+}
+
+#[test]
+fn test_parse_invalid_config() {
+    assert!(UserMirrors::parse("[[mirrors.node]]\nname = 1").is_err());
+}
+
+#[test]
+fn test_for_tool_filters_region_and_sorts_by_priority() {
+    let mirrors = UserMirrors::parse(CONFIG).unwrap();
+
+    let cn: Vec<_> = mirrors
+        .for_tool("node", "cn")
+        .iter()
+        .map(|m| m.name.as_str())
+        .collect();
+    assert_eq!(cn, vec!["npmmirror", "corp"]);
+
+    let global: Vec<_> = mirrors
+        .for_tool("node", "global")
+        .iter()
+        .map(|m| m.name.as_str())
+        .collect();
+    assert_eq!(global, vec!["corp"]);
+
+    assert!(mirrors.for_tool("go", "cn").is_empty());
+}
+
+#[test]
+fn test_from_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("mirrors.toml");
+    std::fs::write(&path, CONFIG).unwrap();
+
+    let mirrors = UserMirrors::from_file(&path).unwrap();
+    assert_eq!(mirrors.for_tool("node", "cn").len(), 2);
+    assert!(UserMirrors::from_file(&dir.path().join("missing.toml")).is_err());
+}
+
+#[test]
+fn test_is_url_template() {
+    assert!(is_url_template("https://m.example/{version}/{filename}"));
+    assert!(is_url_template("https://m.example/{path}"));
+    assert!(!is_url_template("https://npmmirror.com/mirrors/node"));
+}
+
+#[test]
+fn test_render_url_template() {
+    let platform = Platform::new(Os::Linux, Arch::X86_64);
+    let original = "https://nodejs.org/dist/v22.0.0/node-v22.0.0-linux-x64.tar.gz?x=1";
+
+    assert_eq!(
+        render_url_template(
+            "https://m.example/node/{version}/{filename}",
+            original,
+            "22.0.0",
+            &platform
+        ),
+        "https://m.example/node/22.0.0/node-v22.0.0-linux-x64.tar.gz"
+    );
+    assert_eq!(
+        render_url_template("https://m.example/{path}", original, "22.0.0", &platform),
+        "https://m.example/dist/v22.0.0/node-v22.0.0-linux-x64.tar.gz"
+    );
+    assert_eq!(
+        render_url_template(
+            "https://m.example/{os}/{arch}/{platform}",
+            original,
+            "22.0.0",
+            &platform
+        ),
+        "https://m.example/linux/x64/linux-x64"
+    );
+}
+
+#[test]
+fn test_join_mirror_base() {
+    assert_eq!(
+        join_mirror_base(
+            "https://m.example/tools/",
+            "https://github.com/o/r/releases/download/v1.0/tool-linux.tar.gz"
+        ),
+        "https://m.example/tools/tool-linux.tar.gz"
+    );
+}
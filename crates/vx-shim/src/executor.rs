@@ -34,6 +34,7 @@ use vx_paths::shims;
 
 use crate::error::{ShimError, ShimResult};
 use crate::request::PackageRequest;
+use crate::telemetry::{self, DispatchTimer};
 
 /// Shim executor that handles execution of globally installed packages
 pub struct ShimExecutor {
@@ -41,17 +42,28 @@ pub struct ShimExecutor {
     registry_path: PathBuf,
     /// Path to the shims directory
     shims_dir: PathBuf,
+    /// Metrics directory for dispatch telemetry (None = disabled)
+    telemetry_dir: Option<PathBuf>,
 }
 
 impl ShimExecutor {
     /// Create a new shim executor
+    ///
+    /// Dispatch telemetry is enabled when `VX_SHIM_TELEMETRY` is set.
     pub fn new(registry_path: PathBuf, shims_dir: PathBuf) -> Self {
         Self {
             registry_path,
             shims_dir,
+            telemetry_dir: telemetry::telemetry_dir_from_env(),
         }
     }
 
+    /// Record dispatch telemetry into `metrics_dir` (None disables it)
+    pub fn with_telemetry_dir(mut self, metrics_dir: Option<PathBuf>) -> Self {
+        self.telemetry_dir = metrics_dir;
+        self
+    }
+
     /// Try to execute an executable by name
     ///
     /// This looks up the executable in the package registry and runs the shim.
@@ -71,6 +83,8 @@ impl ShimExecutor {
         args: &[String],
         with_deps: &[vx_runtime_core::WithDependency],
     ) -> ShimResult<Option<i32>> {
+        let timer = DispatchTimer::start();
+
         // Load the package registry
         let registry = match PackageRegistry::load(&self.registry_path) {
             Ok(r) => r,
@@ -89,7 +103,7 @@ impl ShimExecutor {
             }
         };
 
-        self.execute_package_shim_with_deps(package, exe_name, args, with_deps)
+        self.execute_package_shim_with_deps(package, exe_name, args, with_deps, timer)
             .await
     }
 
@@ -120,6 +134,7 @@ impl ShimExecutor {
         if request.is_shell_request() {
             return self.execute_shell_request(request, args, with_deps).await;
         }
+        let timer = DispatchTimer::start();
 
         let exe_name = request.executable_name();
         debug!("Executable name: {}", exe_name);
@@ -139,7 +154,7 @@ impl ShimExecutor {
             debug!("Package found: {:?}", package.name);
             // Package is installed, execute it
             if let Some(exit_code) = self
-                .execute_package_shim_with_deps(package, exe_name, args, with_deps)
+                .execute_package_shim_with_deps(package, exe_name, args, with_deps, timer)
                 .await?
             {
                 return Ok(exit_code);
@@ -358,7 +373,7 @@ impl ShimExecutor {
         exe_name: &str,
         args: &[String],
     ) -> ShimResult<Option<i32>> {
-        self.execute_package_shim_with_deps(package, exe_name, args, &[])
+        self.execute_package_shim_with_deps(package, exe_name, args, &[], DispatchTimer::start())
             .await
     }
 
@@ -369,12 +384,16 @@ impl ShimExecutor {
     ///
     /// The --with dependencies are injected BEFORE the package's own runtime
     /// dependencies, giving them higher priority in PATH resolution.
+    ///
+    /// `timer` was started when the shim was entered; it is recorded when
+    /// dispatch telemetry is enabled.
     async fn execute_package_shim_with_deps(
         &self,
         package: &GlobalPackage,
         exe_name: &str,
         args: &[String],
         with_deps: &[vx_runtime_core::WithDependency],
+        mut timer: DispatchTimer,
     ) -> ShimResult<Option<i32>> {
         debug!(
             "Execute package shim for: {} (package: {}, with_deps: {:?})",
@@ -403,6 +422,7 @@ impl ShimExecutor {
                 shims::get_shim_path(&self.shims_dir, exe_name)
             }
         };
+        timer.mark_resolved();
 
         debug!(
             "Executing: {} (from {}:{}) at {:?}",
@@ -414,6 +434,7 @@ impl ShimExecutor {
         let env = self.build_runtime_environment_with_deps(package, with_deps)?;
 
         // Execute the target directly with the prepared environment
+        timer.mark_spawned();
         let status = Command::new(&target_path)
             .args(args)
             .envs(&env)
@@ -422,8 +443,16 @@ impl ShimExecutor {
             .stderr(Stdio::inherit())
             .status()
             .await?;
+        let exit_code = status.code().unwrap_or(1);
+
+        if let Some(metrics_dir) = &self.telemetry_dir {
+            telemetry::record(
+                metrics_dir,
+                &timer.finish(exe_name, &package.key(), exit_code),
+            );
+        }
 
-        Ok(Some(status.code().unwrap_or(1)))
+        Ok(Some(exit_code))
     }
 
     /// Find the executable in the package's install directory
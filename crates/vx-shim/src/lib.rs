@@ -15,10 +15,12 @@
 mod error;
 mod executor;
 mod request;
+mod telemetry;
 
 pub use error::{ShimError, ShimResult};
 pub use executor::ShimExecutor;
 pub use request::{PackageRequest, RuntimeSpec};
+pub use telemetry::{SHIM_TELEMETRY_ENV, telemetry_dir_from_env, telemetry_enabled};
 
 /// Re-export commonly used types
 pub mod prelude {
@@ -0,0 +1,128 @@
+//! Opt-in dispatch telemetry (`VX_SHIM_TELEMETRY`)
+//!
+//! When enabled, every shim execution records how long vx took to resolve the
+//! target executable and to spawn it, next to the other metrics in
+//! `~/.vx/metrics` (see [`vx_metrics::shim`]). Recording never fails the
+//! shim: errors are only logged.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use tracing::debug;
+use vx_metrics::ShimInvocation;
+
+/// Environment variable enabling shim telemetry
+pub const SHIM_TELEMETRY_ENV: &str = "VX_SHIM_TELEMETRY";
+
+/// Whether a `VX_SHIM_TELEMETRY` value turns telemetry on
+pub fn telemetry_enabled(value: Option<&str>) -> bool {
+    matches!(
+        value.map(|v| v.trim().to_ascii_lowercase()).as_deref(),
+        Some("1" | "true" | "yes" | "on")
+    )
+}
+
+/// Metrics directory to record into, when `VX_SHIM_TELEMETRY` is set
+pub fn telemetry_dir_from_env() -> Option<PathBuf> {
+    let value = std::env::var(SHIM_TELEMETRY_ENV).ok();
+    telemetry_enabled(value.as_deref())
+        .then(|| vx_paths::VxPaths::default().base_dir.join("metrics"))
+}
+
+/// Timestamps of one shim execution, from entering the shim to the exit of
+/// the target process
+#[derive(Debug, Clone)]
+pub(crate) struct DispatchTimer {
+    started: Instant,
+    started_at: chrono::DateTime<chrono::Utc>,
+    resolved: Option<Duration>,
+    spawned: Option<Duration>,
+}
+
+impl DispatchTimer {
+    pub(crate) fn start() -> Self {
+        Self {
+            started: Instant::now(),
+            started_at: chrono::Utc::now(),
+            resolved: None,
+            spawned: None,
+        }
+    }
+
+    /// The target executable has been found
+    pub(crate) fn mark_resolved(&mut self) {
+        self.resolved = Some(self.started.elapsed());
+    }
+
+    /// The target process is about to be spawned
+    pub(crate) fn mark_spawned(&mut self) {
+        self.spawned = Some(self.started.elapsed());
+    }
+
+    /// Build the record once the target has exited
+    pub(crate) fn finish(&self, executable: &str, package: &str, exit_code: i32) -> ShimInvocation {
+        let total = self.started.elapsed();
+        let spawned = self.spawned.unwrap_or(total);
+        let resolved = self.resolved.unwrap_or(spawned);
+        ShimInvocation {
+            timestamp: self.started_at.to_rfc3339(),
+            executable: executable.to_string(),
+            package: package.to_string(),
+            resolve_us: resolved.as_micros() as u64,
+            dispatch_us: spawned.as_micros() as u64,
+            run_ms: total.saturating_sub(spawned).as_millis() as u64,
+            exit_code,
+        }
+    }
+}
+
+/// Append `invocation` to the shim log under `metrics_dir`
+pub(crate) fn record(metrics_dir: &Path, invocation: &ShimInvocation) {
+    if let Err(e) = vx_metrics::record_shim_invocation(metrics_dir, invocation) {
+        debug!("Failed to record shim telemetry: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_telemetry_enabled_values() {
+        assert!(telemetry_enabled(Some("1")));
+        assert!(telemetry_enabled(Some(" TRUE ")));
+        assert!(telemetry_enabled(Some("on")));
+        assert!(!telemetry_enabled(Some("0")));
+        assert!(!telemetry_enabled(Some("")));
+        assert!(!telemetry_enabled(None));
+    }
+
+    #[test]
+    fn test_timer_orders_resolve_dispatch_and_run() {
+        let mut timer = DispatchTimer::start();
+        timer.mark_resolved();
+        std::thread::sleep(Duration::from_millis(2));
+        timer.mark_spawned();
+        std::thread::sleep(Duration::from_millis(5));
+        let invocation = timer.finish("tsc", "npm:typescript", 0);
+
+        assert_eq!(invocation.executable, "tsc");
+        assert_eq!(invocation.package, "npm:typescript");
+        assert!(invocation.resolve_us <= invocation.dispatch_us);
+        assert!(invocation.dispatch_us >= 2000);
+        assert!(invocation.run_ms >= 5);
+    }
+
+    #[test]
+    fn test_record_appends_to_metrics_dir() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut timer = DispatchTimer::start();
+        timer.mark_spawned();
+        record(temp.path(), &timer.finish("http", "pip:httpie", 2));
+        record(temp.path(), &timer.finish("http", "pip:httpie", 0));
+
+        let records = vx_metrics::load_shim_invocations(temp.path(), 10);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].exit_code, 2);
+    }
+}
@@ -113,6 +113,11 @@ fn collect_runtime_platforms(
             continue;
         }
 
+        // Skip tool bundles — their members are tested through their own providers
+        if !metadata.tool_bundle.is_empty() {
+            continue;
+        }
+
         let provider_name = metadata
             .name
             .clone()
@@ -14,6 +14,9 @@ pub struct StarMetadata {
     pub homepage: Option<String>,
     /// Provider repository
     pub repository: Option<String>,
+    /// Changelog URL template (from `changelog = "..."`), may contain
+    /// `{version}`, `{major}` and `{minor}` placeholders
+    pub changelog: Option<String>,
     /// Provider license
     pub license: Option<String>,
     /// Provider ecosystem
@@ -26,6 +29,9 @@ pub struct StarMetadata {
     pub pip_package: Option<String>,
     /// Package alias (from `package_alias = {"ecosystem": "uvx", "package": "meson"}`)
     pub package_alias: Option<(String, String)>,
+    /// Member runtimes installed together by a bundle provider
+    /// (from `tool_bundle = ["jq", "yq", "fzf"]`)
+    pub tool_bundle: Vec<String>,
     /// Supported package prefixes for ecosystem:package syntax (RFC 0027)
     pub package_prefixes: Vec<String>,
     /// Ecosystem aliases declaring that this provider handles specific `ecosystem:package` calls.
@@ -33,8 +39,20 @@ pub struct StarMetadata {
     /// When set, `vx cargo:audit` routes directly to this provider's pre-compiled binary
     /// instead of falling back to `cargo install`.
     pub ecosystem_aliases: Vec<(String, String)>,
+    /// System package manager packages, as `(manager, package)` pairs
+    /// (from `brew_install("sevenzip")`, `scoop_install("7zip")`, ...)
+    pub system_packages: Vec<(String, String)>,
     /// Minimum vx version required to use this provider (semver constraint)
     pub vx_version: Option<String>,
+    /// End-of-life dates by version line, as `(version prefix, YYYY-MM-DD)`
+    /// pairs (from `eol = {"18": "2025-04-30", "3.8": "2024-10-07"}`)
+    pub eol: Vec<(String, String)>,
+    /// OSV package as `(ecosystem, name)`, for security advisories
+    /// (from `osv = {"ecosystem": "npm", "name": "pnpm"}`)
+    pub osv: Option<(String, String)>,
+    /// Version normalization rules as `(key, value)` pairs
+    /// (from `version_format = {"strip_prefix": "bun-v", "build_metadata": "strip"}`)
+    pub version_format: Vec<(String, String)>,
 }
 
 /// Metadata for a single runtime entry inside the `runtimes` list.
@@ -61,12 +79,32 @@ pub struct StarRuntimeMeta {
     pub shells: Vec<(String, String)>,
     /// Install dependencies (vx-managed runtimes that must be installed first)
     pub install_deps: Vec<String>,
+    /// System-level prerequisites (VC++ redistributables, system packages, ...)
+    pub system_deps: Vec<StarSystemDep>,
     /// Glob patterns for locating the executable on the system (for tools not on PATH, e.g. MSVC cl.exe)
     pub system_paths: Vec<String>,
     /// Priority (lower = higher priority)
     pub priority: Option<u32>,
 }
 
+/// A system dependency declared in a runtime's `system_deps` list, e.g.
+/// `{"type": "vc_redist", "id": "vcredist140", "platforms": ["windows"]}`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StarSystemDep {
+    /// Dependency type (`vc_redist`, `package`, `dotnet`, `windows_kb`, `windows_feature`)
+    pub dep_type: String,
+    /// Dependency identifier (package name, KB number, ...)
+    pub id: String,
+    /// Version constraint
+    pub version: Option<String>,
+    /// Why the runtime needs it
+    pub reason: Option<String>,
+    /// Platforms the dependency applies to (empty = all)
+    pub platforms: Vec<String>,
+    /// Whether the dependency is optional
+    pub optional: bool,
+}
+
 impl StarMetadata {
     /// Parse metadata from the raw content of a `provider.star` file.
     pub fn parse(source: &str) -> Self {
@@ -75,15 +113,21 @@ impl StarMetadata {
             description: extract_simple_return(source, "description"),
             homepage: extract_simple_return(source, "homepage"),
             repository: extract_simple_return(source, "repository"),
+            changelog: extract_simple_return(source, "changelog"),
             license: extract_simple_return(source, "license"),
             ecosystem: extract_simple_return(source, "ecosystem"),
             platforms: extract_platforms_os(source),
             runtimes: extract_runtimes(source),
             pip_package: extract_simple_return(source, "pip_package"),
             package_alias: extract_package_alias(source),
+            tool_bundle: extract_string_list_var(source, "tool_bundle"),
             package_prefixes: extract_string_list_var(source, "package_prefixes"),
             ecosystem_aliases: extract_ecosystem_aliases(source),
+            system_packages: extract_system_packages(source),
             vx_version: extract_simple_return(source, "vx_version"),
+            eol: extract_string_dict_var(source, "eol"),
+            osv: extract_osv_package(source),
+            version_format: extract_string_dict_var(source, "version_format"),
         }
     }
 
@@ -109,6 +153,14 @@ impl StarMetadata {
             .find(|r| r.name.as_deref() == Some(name) || r.aliases.iter().any(|a| a == name))
     }
 
+    /// Package name for a system package manager (e.g. `"brew"`, `"scoop"`).
+    pub fn system_package(&self, manager: &str) -> Option<&str> {
+        self.system_packages
+            .iter()
+            .find(|(m, _)| m == manager)
+            .map(|(_, p)| p.as_str())
+    }
+
     /// Collect all aliases across all runtimes.
     pub fn all_aliases(&self) -> Vec<&str> {
         self.runtimes
@@ -412,6 +464,7 @@ fn parse_runtime_def_call(args_body: &str, source: &str) -> StarRuntimeMeta {
     // system_paths may be a direct list `[...]` or a variable reference like `_MSVC_PATHS`.
     // Try direct list first; fall back to variable reference resolution.
     let system_paths = extract_kwarg_string_list_or_var(args_body, "system_paths", source);
+    let system_deps = extract_system_deps(args_body);
 
     StarRuntimeMeta {
         name,
@@ -424,6 +477,7 @@ fn parse_runtime_def_call(args_body: &str, source: &str) -> StarRuntimeMeta {
         command_prefix,
         shells: Vec::new(),
         install_deps: Vec::new(),
+        system_deps,
         system_paths,
         priority,
     }
@@ -459,6 +513,7 @@ fn parse_bundled_runtime_def_call(args_body: &str) -> StarRuntimeMeta {
         command_prefix,
         shells: Vec::new(),
         install_deps: Vec::new(),
+        system_deps: Vec::new(),
         system_paths: Vec::new(),
         priority,
     }
@@ -804,6 +859,7 @@ fn parse_runtime_dict(body: &str) -> StarRuntimeMeta {
         command_prefix: extract_dict_string_list(body, "command_prefix"),
         shells: extract_dict_shells(body),
         install_deps: extract_dict_string_list(body, "install_deps"),
+        system_deps: extract_system_deps(body),
         system_paths: extract_dict_string_list(body, "system_paths"),
         priority: extract_dict_u32_value(body, "priority"),
     }
@@ -845,6 +901,67 @@ fn extract_dict_shells(body: &str) -> Vec<(String, String)> {
     shells
 }
 
+/// Extract the `system_deps` list from a runtime dict body (`"system_deps": [...]`)
+/// or from `runtime_def(...)` keyword arguments (`system_deps = [...]`).
+fn extract_system_deps(body: &str) -> Vec<StarSystemDep> {
+    let key = "system_deps";
+    let mut search_start = 0;
+    while let Some(pos) = body[search_start..].find(key) {
+        let actual_pos = search_start + pos;
+        search_start = actual_pos + key.len();
+
+        let preceded_ok = body[..actual_pos]
+            .chars()
+            .last()
+            .is_none_or(|c| c.is_whitespace() || c == ',' || c == '(' || c == '"' || c == '\'');
+        if !preceded_ok {
+            continue;
+        }
+
+        let after_key = body[search_start..]
+            .trim_start_matches(['"', '\''])
+            .trim_start();
+        let Some(after_sep) = after_key
+            .strip_prefix(':')
+            .or_else(|| after_key.strip_prefix('='))
+        else {
+            continue;
+        };
+        let after_sep = after_sep.trim_start();
+        if !after_sep.starts_with('[') {
+            continue;
+        }
+        let Some(list_body) = find_matching_bracket(after_sep, 0, '[', ']') else {
+            continue;
+        };
+
+        let mut deps = Vec::new();
+        let mut remaining = list_body;
+        while let Some(dict_start) = remaining.find('{') {
+            let Some(dict_body) = find_matching_bracket(remaining, dict_start, '{', '}') else {
+                break;
+            };
+            if let (Some(dep_type), Some(id)) = (
+                extract_dict_string_value(dict_body, "type"),
+                extract_dict_string_value(dict_body, "id"),
+            ) {
+                deps.push(StarSystemDep {
+                    dep_type,
+                    id,
+                    version: extract_dict_string_value(dict_body, "version"),
+                    reason: extract_dict_string_value(dict_body, "reason"),
+                    platforms: extract_dict_string_list(dict_body, "platforms"),
+                    optional: extract_dict_bool_value(dict_body, "optional").unwrap_or(false),
+                });
+            }
+            let consumed = dict_start + dict_body.len() + 2;
+            remaining = &remaining[consumed.min(remaining.len())..];
+        }
+        return deps;
+    }
+    Vec::new()
+}
+
 /// Extract a string value for a given key from a dict body.
 fn extract_dict_string_value(body: &str, key: &str) -> Option<String> {
     for key_str in &[format!("\"{}\"", key), format!("'{}'", key)] {
@@ -997,6 +1114,38 @@ fn extract_ecosystem_aliases(source: &str) -> Vec<(String, String)> {
     result
 }
 
+/// Extract `<manager>_install("package", ...)` calls from `system_install` strategies.
+///
+/// Only the first declaration per manager is kept. Names in `load(...)`
+/// statements are skipped since they are not followed by `(`.
+fn extract_system_packages(source: &str) -> Vec<(String, String)> {
+    const MANAGERS: &[&str] = &[
+        "winget", "choco", "scoop", "brew", "apt", "dnf", "pacman", "zypper", "apk", "nix",
+    ];
+
+    let mut result: Vec<(String, String)> = Vec::new();
+    for manager in MANAGERS {
+        let pattern = format!("{}_install(", manager);
+        let mut search = source;
+        while let Some(pos) = search.find(&pattern) {
+            let preceded_by_ident = search[..pos]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_');
+            let args = &search[pos + pattern.len()..];
+            search = args;
+            if preceded_by_ident {
+                continue;
+            }
+            if let Some(package) = extract_first_positional_string(args) {
+                result.push((manager.to_string(), package));
+                break;
+            }
+        }
+    }
+    result
+}
+
 /// Extract a top-level string list variable like `package_prefixes = ["deno", "npm"]`.
 fn extract_string_list_var(source: &str, var_name: &str) -> Vec<String> {
     // Try top-level variable format first: `var_name = [...]`
@@ -1032,3 +1181,71 @@ fn extract_string_list_var(source: &str, var_name: &str) -> Vec<String> {
 
     Vec::new()
 }
+
+/// Extract a top-level dict of strings like `eol = {"18": "2025-04-30"}`,
+/// in declaration order.
+fn extract_string_dict_var(source: &str, var_name: &str) -> Vec<(String, String)> {
+    for (offset, line) in source_line_offsets(source) {
+        let Some(rest) = line.strip_prefix(var_name) else {
+            continue;
+        };
+        let Some(after_eq) = rest.trim_start().strip_prefix('=') else {
+            continue;
+        };
+        let value = after_eq.trim_start();
+        if !value.starts_with('{') {
+            continue;
+        }
+        let open_pos = offset + (line.len() - value.len());
+        let Some(body) = find_matching_bracket(source, open_pos, '{', '}') else {
+            continue;
+        };
+        let literals = extract_string_literals(body);
+        return literals
+            .chunks_exact(2)
+            .map(|pair| (pair[0].clone(), pair[1].clone()))
+            .collect();
+    }
+    Vec::new()
+}
+
+/// Extract `osv = {"ecosystem": "...", "name": "..."}` as `(ecosystem, name)`.
+fn extract_osv_package(source: &str) -> Option<(String, String)> {
+    let fields = extract_string_dict_var(source, "osv");
+    let field = |key: &str| {
+        fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.clone())
+    };
+    Some((field("ecosystem")?, field("name")?))
+}
+
+/// All string literals in `body`, skipping `#` comments.
+fn extract_string_literals(body: &str) -> Vec<String> {
+    let mut literals = Vec::new();
+    let mut chars = body.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '#' => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '"' | '\'' => {
+                let mut literal = String::new();
+                for next in chars.by_ref() {
+                    if next == c {
+                        break;
+                    }
+                    literal.push(next);
+                }
+                literals.push(literal);
+            }
+            _ => {}
+        }
+    }
+    literals
+}
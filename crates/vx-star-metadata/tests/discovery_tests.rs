@@ -272,6 +272,37 @@ runtimes = [runtime_def("vite")]
     fs::remove_dir_all(root).ok();
 }
 
+#[test]
+fn discovery_excludes_tool_bundle_providers() {
+    let root = create_temp_dir();
+
+    write_provider(
+        &root,
+        "jq",
+        r#"
+name = "jq"
+runtimes = [runtime_def("jq")]
+"#,
+    );
+    write_provider(
+        &root,
+        "devtools",
+        r#"
+name = "devtools"
+tool_bundle = ["jq"]
+runtimes = [runtime_def("devtools")]
+"#,
+    );
+
+    let config = DiscoveryConfig::new(&root, 10);
+    let result = discover_providers(&config).expect("discovery should succeed");
+
+    assert_eq!(result.total_runtimes, 1);
+    assert_eq!(result.linux.runtimes, vec!["jq"]);
+
+    fs::remove_dir_all(root).ok();
+}
+
 #[test]
 fn discovery_multiline_platforms_excludes_from_other_os() {
     let root = create_temp_dir();
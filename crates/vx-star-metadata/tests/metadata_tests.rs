@@ -178,6 +178,88 @@ fn test_parse_homepage() {
     );
 }
 
+#[test]
+fn test_parse_changelog() {
+    let meta = StarMetadata::parse(
+        r#"
+name       = "go"
+repository = "https://github.com/golang/go"
+changelog  = "https://go.dev/doc/devel/release#go{version}"
+"#,
+    );
+    assert_eq!(
+        meta.changelog,
+        Some("https://go.dev/doc/devel/release#go{version}".to_string())
+    );
+    assert_eq!(StarMetadata::parse(SAMPLE_STAR).changelog, None);
+}
+
+#[test]
+fn test_parse_eol() {
+    let meta = StarMetadata::parse(
+        r#"
+name = "node"
+
+# End-of-life dates per release line
+eol = {
+    "16": "2023-09-11",  # ended early (OpenSSL 1.1.1)
+    '18': '2025-04-30',
+    "20": "2026-04-30",
+}
+
+def download_url(ctx, version):
+    eol = {"ignored": "inside a function"}
+    return None
+"#,
+    );
+    assert_eq!(
+        meta.eol,
+        [
+            ("16".to_string(), "2023-09-11".to_string()),
+            ("18".to_string(), "2025-04-30".to_string()),
+            ("20".to_string(), "2026-04-30".to_string()),
+        ]
+    );
+    assert!(StarMetadata::parse(SAMPLE_STAR).eol.is_empty());
+}
+
+#[test]
+fn test_parse_osv() {
+    let meta = StarMetadata::parse(
+        r#"
+name = "pnpm"
+osv = {"ecosystem": "npm", "name": "pnpm"}
+"#,
+    );
+    assert_eq!(meta.osv, Some(("npm".to_string(), "pnpm".to_string())));
+    assert_eq!(
+        StarMetadata::parse(r#"osv = {"ecosystem": "npm"}"#).osv,
+        None
+    );
+    assert!(StarMetadata::parse(SAMPLE_STAR).osv.is_none());
+}
+
+#[test]
+fn test_parse_version_format() {
+    let meta = StarMetadata::parse(
+        r#"
+name = "bun"
+version_format = {
+    "strip_prefix": "bun-v",   # tags are bun-v1.2.3
+    "build_metadata": "strip",
+}
+"#,
+    );
+    assert_eq!(
+        meta.version_format,
+        [
+            ("strip_prefix".to_string(), "bun-v".to_string()),
+            ("build_metadata".to_string(), "strip".to_string()),
+        ]
+    );
+    assert!(StarMetadata::parse(SAMPLE_STAR).version_format.is_empty());
+}
+
 #[test]
 fn test_parse_ecosystem() {
     let meta = StarMetadata::parse(SAMPLE_STAR);
@@ -552,3 +634,72 @@ runtimes = [
     assert_eq!(meta.runtimes[3].name, Some("rustfmt".to_string()));
     assert_eq!(meta.runtimes[3].bundled_with, Some("rust".to_string()));
 }
+
+#[test]
+fn test_parse_system_packages() {
+    let source = r#"
+load("@vx//stdlib:system_install.star", "system_install_strategies",
+     "winget_install", "scoop_install", "brew_install")
+
+name = "7zip"
+
+system_install = system_install_strategies([
+    winget_install("7zip.7zip", priority = 90),
+    scoop_install("7zip",       priority = 60),
+    brew_install("sevenzip",    priority = 70),
+])
+"#;
+    let meta = StarMetadata::parse(source);
+    assert_eq!(meta.system_package("brew"), Some("sevenzip"));
+    assert_eq!(meta.system_package("scoop"), Some("7zip"));
+    assert_eq!(meta.system_package("winget"), Some("7zip.7zip"));
+    assert_eq!(meta.system_package("apt"), None);
+}
+
+#[test]
+fn test_parse_tool_bundle() {
+    let source = r#"
+name = "devtools"
+tool_bundle = ["jq", "yq", "ripgrep"]
+runtimes = [runtime_def("devtools")]
+"#;
+    let meta = StarMetadata::parse(source);
+    assert_eq!(meta.tool_bundle, vec!["jq", "yq", "ripgrep"]);
+    assert!(StarMetadata::parse("name = \"jq\"").tool_bundle.is_empty());
+}
+
+#[test]
+fn test_parse_runtime_system_deps() {
+    let source = r#"
+name = "msvc"
+
+runtimes = [
+    {
+        "name": "cl",
+        "executable": "cl",
+        "system_deps": [
+            {"type": "vc_redist", "id": "vcredist140", "platforms": ["windows"],
+             "reason": "C++ runtime"},
+            {"type": "package", "id": "build-essential", "optional": True},
+        ],
+    },
+    runtime_def("openssl-tool",
+        system_deps = [{"type": "package", "id": "libssl-dev", "version": ">=3.0"}]),
+]
+"#;
+    let meta = StarMetadata::parse(source);
+
+    let cl = meta.find_runtime("cl").unwrap();
+    assert_eq!(cl.system_deps.len(), 2);
+    assert_eq!(cl.system_deps[0].dep_type, "vc_redist");
+    assert_eq!(cl.system_deps[0].id, "vcredist140");
+    assert_eq!(cl.system_deps[0].platforms, vec!["windows".to_string()]);
+    assert_eq!(cl.system_deps[0].reason.as_deref(), Some("C++ runtime"));
+    assert!(!cl.system_deps[0].optional);
+    assert!(cl.system_deps[1].optional);
+
+    let tool = meta.find_runtime("openssl-tool").unwrap();
+    assert_eq!(tool.system_deps.len(), 1);
+    assert_eq!(tool.system_deps[0].id, "libssl-dev");
+    assert_eq!(tool.system_deps[0].version.as_deref(), Some(">=3.0"));
+}
@@ -0,0 +1,228 @@
+//! `commands = [...]` declarations of provider.star files
+
+use crate::{ProviderContext, StarlarkEngine};
+use anyhow::{Context, Result, bail};
+use async_trait::async_trait;
+use std::path::Path;
+use std::sync::Arc;
+use tracing::{debug, warn};
+use vx_runtime::{
+    CommandArg, CommandArgKind, CommandContribution, CommandInput, CommandSpec, RuntimeContext,
+};
+
+/// Subcommands declared by the `commands = [...]` variable of a provider.star
+///
+/// Each entry is a `command_def(...)` dict; entries without a name or a `run`
+/// function are skipped with a warning.
+pub fn star_commands(provider_name: &str, content: &str) -> Vec<Arc<dyn CommandContribution>> {
+    // Fast pre-filter: only evaluate scripts that assign a top-level
+    // `commands` variable (`test_commands = ...` alone does not count).
+    if !content
+        .lines()
+        .any(|line| line.starts_with("commands") && line[8..].trim_start().starts_with('='))
+    {
+        return Vec::new();
+    }
+
+    let engine = StarlarkEngine::new();
+    let value = match engine.get_variable(Path::new(provider_name), content, "commands") {
+        Ok(Some(value)) => value,
+        Ok(None) => return Vec::new(),
+        Err(e) => {
+            warn!(provider = %provider_name, "Failed to evaluate commands: {e}");
+            return Vec::new();
+        }
+    };
+
+    let provider_name: Arc<str> = Arc::from(provider_name);
+    let content: Arc<str> = Arc::from(content);
+
+    value
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let command = parse_command(entry);
+            if command.is_none() {
+                warn!(provider = %provider_name, "Skipping invalid commands entry: {entry}");
+            }
+            command
+        })
+        .map(|(spec, run)| {
+            Arc::new(StarlarkCommand {
+                provider_name: Arc::clone(&provider_name),
+                content: Arc::clone(&content),
+                spec,
+                run,
+            }) as Arc<dyn CommandContribution>
+        })
+        .collect()
+}
+
+fn parse_command(entry: &serde_json::Value) -> Option<(CommandSpec, String)> {
+    let name = entry.get("name")?.as_str()?;
+    let run = entry.get("run")?.as_str()?;
+    let about = entry.get("about").and_then(|v| v.as_str()).unwrap_or("");
+
+    let mut spec = CommandSpec::new(name, about);
+    if let Some(long_about) = entry.get("long_about").and_then(|v| v.as_str()) {
+        spec = spec.long_about(long_about);
+    }
+    for arg in entry
+        .get("args")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+    {
+        spec = spec.arg(parse_arg(arg)?);
+    }
+    Some((spec, run.to_string()))
+}
+
+fn parse_arg(entry: &serde_json::Value) -> Option<CommandArg> {
+    let name = entry.get("name")?.as_str()?;
+    let help = entry.get("help").and_then(|v| v.as_str()).unwrap_or("");
+
+    let mut arg = match entry.get("kind").and_then(|v| v.as_str()) {
+        Some("flag") => CommandArg::flag(name, help),
+        Some("option") => CommandArg::option(name, help),
+        Some("positional") | None => CommandArg::positional(name, help),
+        Some(_) => return None,
+    };
+    if let Some(short) = entry.get("short").and_then(|v| v.as_str()) {
+        arg = arg.short(short.chars().next()?);
+    }
+    if entry.get("required").and_then(|v| v.as_bool()) == Some(true) {
+        arg = arg.required();
+    }
+    if entry.get("multiple").and_then(|v| v.as_bool()) == Some(true) {
+        arg = arg.multiple();
+    }
+    if let Some(default) = entry.get("default").and_then(|v| v.as_str()) {
+        arg = arg.default_value(default);
+    }
+    if let Some(choices) = entry.get("choices").and_then(|v| v.as_array()) {
+        arg = arg.choices(choices.iter().filter_map(|c| c.as_str()));
+    }
+    Some(arg)
+}
+
+/// A `command_def(...)` entry, run by calling its Starlark function
+struct StarlarkCommand {
+    provider_name: Arc<str>,
+    content: Arc<str>,
+    spec: CommandSpec,
+    run: String,
+}
+
+impl StarlarkCommand {
+    /// The `args` dict passed to the run function
+    fn args_value(&self, input: &CommandInput) -> serde_json::Value {
+        let args = self
+            .spec
+            .args
+            .iter()
+            .map(|arg| {
+                let value = match arg.kind {
+                    CommandArgKind::Flag => serde_json::Value::Bool(input.flag(&arg.name)),
+                    _ if arg.multiple => serde_json::json!(input.get_all(&arg.name)),
+                    _ => serde_json::json!(input.get(&arg.name)),
+                };
+                (arg.name.clone(), value)
+            })
+            .collect();
+        serde_json::Value::Object(args)
+    }
+}
+
+#[async_trait]
+impl CommandContribution for StarlarkCommand {
+    fn spec(&self) -> CommandSpec {
+        self.spec.clone()
+    }
+
+    async fn run(&self, input: &CommandInput, ctx: &RuntimeContext) -> Result<i32> {
+        let vx_home = ctx.paths.vx_home();
+        let provider_ctx = ProviderContext::new(&self.provider_name, vx_home);
+        let script_path = format!("<builtin:{}>", self.provider_name);
+
+        let result = StarlarkEngine::new()
+            .call_function_with_data(
+                Path::new(&script_path),
+                &self.content,
+                &self.run,
+                &provider_ctx,
+                &self.args_value(input),
+            )
+            .with_context(|| {
+                format!(
+                    "{} {} failed to run '{}'",
+                    self.provider_name, self.spec.name, self.run
+                )
+            })?;
+
+        let steps = match result {
+            serde_json::Value::Null => return Ok(0),
+            serde_json::Value::Number(code) => {
+                return Ok(code
+                    .as_i64()
+                    .and_then(|c| i32::try_from(c).ok())
+                    .unwrap_or(1));
+            }
+            serde_json::Value::Array(steps) => steps,
+            other => bail!(
+                "'{}' must return a list of run_command() steps or an exit code, got: {other}",
+                self.run
+            ),
+        };
+
+        for step in &steps {
+            let code = run_step(step).await?;
+            let ignore = step.get("on_failure").and_then(|v| v.as_str()) == Some("ignore");
+            if code != 0 && !ignore {
+                return Ok(code);
+            }
+        }
+        Ok(0)
+    }
+}
+
+/// Execute one `run_command(...)` descriptor, returning its exit code
+async fn run_step(step: &serde_json::Value) -> Result<i32> {
+    if step.get("__type").and_then(|v| v.as_str()) != Some("run_command") {
+        bail!("Unsupported command step, expected run_command(): {step}");
+    }
+    let Some(executable) = step.get("executable").and_then(|v| v.as_str()) else {
+        bail!("run_command() step without an executable: {step}");
+    };
+    let args: Vec<&str> = step
+        .get("args")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|a| a.as_str())
+        .collect();
+
+    let mut cmd = tokio::process::Command::new(executable);
+    cmd.args(&args);
+    if let Some(dir) = step.get("working_dir").and_then(|v| v.as_str()) {
+        cmd.current_dir(dir);
+    }
+    for (key, value) in step
+        .get("env")
+        .and_then(|v| v.as_object())
+        .into_iter()
+        .flatten()
+    {
+        if let Some(value) = value.as_str() {
+            cmd.env(key, value);
+        }
+    }
+
+    debug!("Running command step: {executable} {args:?}");
+    let status = cmd
+        .status()
+        .await
+        .with_context(|| format!("Failed to run {executable}"))?;
+    Ok(status.code().unwrap_or(1))
+}
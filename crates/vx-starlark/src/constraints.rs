@@ -0,0 +1,185 @@
+//! `constraints = [...]` declarations of provider.star files
+
+use crate::{StarMetadata, StarlarkEngine};
+use std::collections::HashMap;
+use std::path::Path;
+use vx_runtime::{ConstraintRule, DependencyConstraint, ManifestVersionPattern};
+use vx_versions::{RangeOp, VersionConstraint, VersionRequest};
+
+/// Dependency constraints declared by the `constraints = [...]` variable of a
+/// provider.star, grouped by runtime
+///
+/// Entries without a `runtime` apply to the runtime named like the provider,
+/// or the provider's first runtime.
+pub fn star_constraints(provider_name: &str, content: &str) -> Vec<(String, Vec<ConstraintRule>)> {
+    // Fast pre-filter: skip providers that don't mention "constraints" at all.
+    // This avoids creating a Starlark engine + full AST evaluation for the ~90%
+    // of providers that have no constraints, saving significant startup time.
+    if !content.contains("constraints") {
+        return Vec::new();
+    }
+
+    let engine = StarlarkEngine::new();
+    let script_path = Path::new(provider_name);
+
+    let Ok(Some(value)) = engine.get_variable(script_path, content, "constraints") else {
+        return Vec::new();
+    };
+
+    let meta = StarMetadata::parse(content);
+    let runtime_names: Vec<String> = meta
+        .runtimes
+        .iter()
+        .filter_map(|r| r.name.clone())
+        .collect();
+
+    let default_runtime = if runtime_names.iter().any(|n| n == provider_name) {
+        Some(provider_name.to_string())
+    } else {
+        runtime_names.first().cloned()
+    };
+
+    let Some(default_runtime) = default_runtime else {
+        return Vec::new();
+    };
+
+    parse_constraints_value(&value, &default_runtime)
+}
+
+fn parse_constraints_value(
+    value: &serde_json::Value,
+    default_runtime: &str,
+) -> Vec<(String, Vec<ConstraintRule>)> {
+    let Some(entries) = value.as_array() else {
+        return Vec::new();
+    };
+
+    let mut rules_by_runtime: HashMap<String, Vec<ConstraintRule>> = HashMap::new();
+
+    for entry in entries {
+        let Some(obj) = entry.as_object() else {
+            continue;
+        };
+
+        let when = obj.get("when").and_then(|v| v.as_str()).unwrap_or("*");
+
+        let runtime_name = obj
+            .get("runtime")
+            .and_then(|v| v.as_str())
+            .unwrap_or(default_runtime)
+            .to_string();
+
+        let mut constraints: Vec<DependencyConstraint> = Vec::new();
+
+        if let Some(reqs) = obj.get("requires").and_then(|v| v.as_array()) {
+            for req in reqs {
+                if let Some(dep) = parse_constraint_dep(req, false) {
+                    constraints.push(dep);
+                }
+            }
+        }
+
+        if let Some(reqs) = obj.get("recommends").and_then(|v| v.as_array()) {
+            for req in reqs {
+                if let Some(dep) = parse_constraint_dep(req, true) {
+                    constraints.push(dep);
+                }
+            }
+        }
+
+        if constraints.is_empty() {
+            continue;
+        }
+
+        let mut rule = ConstraintRule::with_manifest_pattern(ManifestVersionPattern::new(when));
+        for constraint in constraints {
+            rule = rule.with_constraint(constraint);
+        }
+
+        rules_by_runtime.entry(runtime_name).or_default().push(rule);
+    }
+
+    rules_by_runtime.into_iter().collect()
+}
+
+fn parse_constraint_dep(
+    value: &serde_json::Value,
+    force_optional: bool,
+) -> Option<DependencyConstraint> {
+    let obj = value.as_object()?;
+    let runtime = obj.get("runtime")?.as_str()?.to_string();
+    let version = obj.get("version").and_then(|v| v.as_str()).unwrap_or("*");
+
+    let (min, max) = parse_version_min_max(version);
+    let mut constraint = DependencyConstraint::required(runtime);
+
+    if let Some(min) = min {
+        constraint = constraint.min(min);
+    }
+    if let Some(max) = max {
+        constraint = constraint.max(max);
+    }
+
+    if let Some(rec) = obj.get("recommended").and_then(|v| v.as_str()) {
+        constraint = constraint.recommended(rec);
+    }
+
+    if let Some(reason) = obj.get("reason").and_then(|v| v.as_str()) {
+        constraint = constraint.reason(reason);
+    }
+
+    let optional = obj
+        .get("optional")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+        || force_optional;
+
+    if optional {
+        constraint.optional = true;
+    }
+
+    Some(constraint)
+}
+
+fn parse_version_min_max(version_req: &str) -> (Option<String>, Option<String>) {
+    let req = VersionRequest::parse(version_req);
+    match &req.constraint {
+        VersionConstraint::Range(constraints) => {
+            let mut min = None;
+            let mut max = None;
+            for c in constraints {
+                match c.op {
+                    RangeOp::Gte | RangeOp::Gt => {
+                        min = Some(c.version.to_string());
+                    }
+                    RangeOp::Lte | RangeOp::Lt => {
+                        max = Some(c.version.to_string());
+                    }
+                    _ => {}
+                }
+            }
+            (min, max)
+        }
+        VersionConstraint::Caret(v) => {
+            let min = v.to_string();
+            let max = if v.major > 0 {
+                format!("{}.0.0", v.major + 1)
+            } else if v.minor > 0 {
+                format!("0.{}.0", v.minor + 1)
+            } else {
+                format!("0.0.{}", v.patch + 1)
+            };
+            (Some(min), Some(max))
+        }
+        VersionConstraint::Tilde(v) => {
+            let min = v.to_string();
+            let max = format!("{}.{}.0", v.major, v.minor + 1);
+            (Some(min), Some(max))
+        }
+        VersionConstraint::Exact(v) => {
+            let ver = v.to_string();
+            (Some(ver.clone()), Some(ver))
+        }
+        _ => (None, None),
+    }
+}
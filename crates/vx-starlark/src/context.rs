@@ -19,64 +19,72 @@ pub struct PlatformInfo {
     pub arch: String,
     /// Full target triple: "x86_64-pc-windows-msvc"
     pub target: String,
+    /// C library on Linux: "gnu" or "musl" (empty elsewhere)
+    #[serde(default)]
+    pub libc: String,
 }
 
 impl PlatformInfo {
     /// Create platform info from the current system
+    ///
+    /// The architecture is the host's, so Windows on ARM64 reports `arm64`
+    /// even when vx itself is an emulated x64 build.
     pub fn current() -> Self {
-        Self {
-            os: Self::detect_os(),
-            arch: Self::detect_arch(),
-            target: Self::detect_target(),
-        }
-    }
-
-    fn detect_os() -> String {
-        if cfg!(target_os = "windows") {
-            "windows".to_string()
-        } else if cfg!(target_os = "macos") {
-            "macos".to_string()
-        } else if cfg!(target_os = "linux") {
-            "linux".to_string()
-        } else {
-            "unknown".to_string()
+        let mut info = Self::from_platform(&vx_runtime::Platform::current());
+        // Allow overriding the target triple at runtime
+        if let Ok(target) = std::env::var("TARGET") {
+            info.target = target;
         }
-    }
-
-    fn detect_arch() -> String {
-        if cfg!(target_arch = "x86_64") {
-            "x64".to_string()
-        } else if cfg!(target_arch = "aarch64") {
-            "arm64".to_string()
-        } else if cfg!(target_arch = "x86") {
-            "x86".to_string()
-        } else {
-            "unknown".to_string()
+        info
+    }
+
+    /// Platform info for `platform`
+    pub fn from_platform(platform: &vx_runtime::Platform) -> Self {
+        use vx_runtime::{Arch, Os};
+
+        let musl = platform.os == Os::Linux && platform.libc.is_musl();
+        let os = match platform.os {
+            Os::Windows => "windows",
+            Os::MacOS => "macos",
+            Os::Linux => "linux",
+            _ => "unknown",
+        };
+        let arch = match platform.arch {
+            Arch::X86_64 => "x64",
+            Arch::Aarch64 => "arm64",
+            Arch::X86 => "x86",
+            _ => "unknown",
+        };
+        let target = match (platform.arch, platform.os) {
+            (Arch::X86_64, Os::Windows) => "x86_64-pc-windows-msvc",
+            (Arch::X86_64, Os::MacOS) => "x86_64-apple-darwin",
+            (Arch::X86_64, _) if musl => "x86_64-unknown-linux-musl",
+            (Arch::X86_64, _) => "x86_64-unknown-linux-gnu",
+            (Arch::Aarch64, Os::Windows) => "aarch64-pc-windows-msvc",
+            (Arch::Aarch64, Os::MacOS) => "aarch64-apple-darwin",
+            (Arch::Aarch64, _) if musl => "aarch64-unknown-linux-musl",
+            (Arch::Aarch64, _) => "aarch64-unknown-linux-gnu",
+            _ => "unknown",
+        };
+        Self {
+            os: os.to_string(),
+            arch: arch.to_string(),
+            target: target.to_string(),
+            libc: match platform.os {
+                Os::Linux => platform.libc.as_str().to_string(),
+                _ => String::new(),
+            },
         }
     }
 
-    fn detect_target() -> String {
-        // Get target at runtime
-        std::env::var("TARGET").unwrap_or_else(|_| {
-            // Fallback to compile-time target triple
-            if cfg!(target_arch = "x86_64") {
-                if cfg!(target_os = "windows") {
-                    "x86_64-pc-windows-msvc".to_string()
-                } else if cfg!(target_os = "macos") {
-                    "x86_64-apple-darwin".to_string()
-                } else {
-                    "x86_64-unknown-linux-gnu".to_string()
-                }
-            } else if cfg!(target_arch = "aarch64") {
-                if cfg!(target_os = "macos") {
-                    "aarch64-apple-darwin".to_string()
-                } else {
-                    "aarch64-unknown-linux-gnu".to_string()
-                }
-            } else {
-                "unknown".to_string()
-            }
-        })
+    /// The x64 platform to retry with when a provider has no native build
+    ///
+    /// Only Windows on ARM64 has one; see [`vx_runtime::ArchFallback`].
+    pub fn emulation_fallback(&self) -> Option<Self> {
+        (self.os == "windows" && self.arch == "arm64")
+            .then(|| vx_runtime::Platform::new(vx_runtime::Os::Windows, vx_runtime::Arch::Aarch64))
+            .and_then(|p| p.emulation_fallback(vx_runtime::ArchFallback::current()))
+            .map(|p| Self::from_platform(&p))
     }
 }
 
@@ -91,6 +99,12 @@ pub struct VersionInfo {
     pub stable: bool,
     /// Release date (optional)
     pub date: Option<String>,
+    /// Release page URL (optional, e.g. the GitHub release)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_url: Option<String>,
+    /// Short release notes summary (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_notes: Option<String>,
 }
 
 impl VersionInfo {
@@ -101,6 +115,8 @@ impl VersionInfo {
             lts: false,
             stable: true,
             date: None,
+            release_url: None,
+            release_notes: None,
         }
     }
 
@@ -274,6 +290,9 @@ pub struct ProviderContext {
     /// Platform information
     pub platform: PlatformInfo,
 
+    /// NVIDIA driver / CUDA availability (exposed as `ctx.gpu`)
+    pub gpu: vx_runtime::GpuInfo,
+
     /// Path management
     pub paths: PathManager,
 
@@ -308,6 +327,7 @@ impl ProviderContext {
     pub fn new(provider_name: &str, vx_home: PathBuf) -> Self {
         Self {
             platform: PlatformInfo::current(),
+            gpu: vx_runtime::GpuInfo::current().clone(),
             paths: PathManager::new(provider_name, vx_home),
             sandbox: SandboxConfig::default(),
             env: HashMap::new(),
@@ -11,16 +11,25 @@ use crate::error::{Error, Result};
 use crate::loader::VxModuleLoader;
 use serde_json::Value as JsonValue;
 use starlark::analysis::AstModuleLint;
-use starlark::environment::{FrozenModule, GlobalsBuilder, Module};
+use starlark::environment::{FrozenModule, Globals, GlobalsBuilder, LibraryExtension, Module};
 use starlark::eval::{Evaluator, FileLoader};
 use starlark::syntax::{AstModule, Dialect};
 use starlark::values::Value;
+use starlark::values::dict::AllocDict;
 use starlark::values::structs::AllocStruct;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::path::Path;
 use tracing::trace;
 
+/// Builtins for provider scripts and `@vx//stdlib` modules
+///
+/// The standard library plus `struct`, which stdlib modules use for
+/// namespaces such as `http.get_json`.
+pub(crate) fn provider_globals() -> Globals {
+    GlobalsBuilder::extended_by(&[LibraryExtension::StructType]).build()
+}
+
 /// FileLoader implementation for @vx//stdlib modules
 ///
 /// Implements Buck2-style `load("@vx//stdlib:github.star", ...)` support.
@@ -267,6 +276,32 @@ impl StarlarkEngine {
         script_content: &str,
         var_name: &str,
     ) -> Result<Option<JsonValue>> {
+        trace!(
+            var = %var_name,
+            path = %script_path.display(),
+            "Getting Starlark variable"
+        );
+
+        let module = self.eval_module(script_path, script_content)?;
+        match module.get(var_name) {
+            Some(value) => Ok(Some(self.starlark_value_to_json(value))),
+            None => Ok(None),
+        }
+    }
+
+    /// Names of all top-level globals defined by a Starlark script
+    ///
+    /// Used to validate that a provider exports the functions vx calls
+    /// (`fetch_versions`, `download_url`, …) before registering it.
+    pub fn defined_globals(&self, script_path: &Path, script_content: &str) -> Result<Vec<String>> {
+        let module = self.eval_module(script_path, script_content)?;
+        let mut names: Vec<String> = module.names().map(|n| n.as_str().to_string()).collect();
+        names.sort();
+        Ok(names)
+    }
+
+    /// Parse and evaluate a script, returning the populated module
+    fn eval_module(&self, script_path: &Path, script_content: &str) -> Result<Module> {
         let path_lossy = script_path.to_string_lossy();
         let script_name = script_path
             .file_name()
@@ -278,12 +313,6 @@ impl StarlarkEngine {
         // breaks virtual paths like "<builtin:7zip>".
         let parse_name = sanitize_script_name(script_name);
 
-        trace!(
-            var = %var_name,
-            path = %script_path.display(),
-            "Getting Starlark variable"
-        );
-
         // Parse the script once (strip UTF-8 BOM if present).
         // The AST is reused for both linting and evaluation to avoid double-parse overhead.
         let ast = AstModule::parse(
@@ -293,7 +322,7 @@ impl StarlarkEngine {
         )
         .map_err(|e| Error::ParseError(e.to_string()))?;
 
-        let globals = GlobalsBuilder::standard().build();
+        let globals = provider_globals();
         let loader = VxFileLoader::new(self.dialect.clone());
         let module = Module::new();
         {
@@ -302,11 +331,7 @@ impl StarlarkEngine {
             eval.eval_module(ast, &globals)
                 .map_err(|e| Error::EvalError(e.to_string()))?;
         }
-
-        match module.get(var_name) {
-            Some(value) => Ok(Some(self.starlark_value_to_json(value))),
-            None => Ok(None),
-        }
+        Ok(module)
     }
 
     /// Execute a named function from a Starlark script
@@ -324,6 +349,41 @@ impl StarlarkEngine {
         func_name: &str,
         ctx: &ProviderContext,
         extra_args: &[JsonValue],
+    ) -> Result<JsonValue> {
+        self.call_function_impl(
+            script_path,
+            script_content,
+            func_name,
+            ctx,
+            extra_args,
+            None,
+        )
+    }
+
+    /// Execute a named function as `func(ctx, data)`
+    ///
+    /// Unlike [`call_function`](Self::call_function), JSON objects in `data`
+    /// become dicts rather than structs, so scripts can walk fetched API
+    /// responses with `data["key"]`, `.get()` and `.items()`.
+    pub fn call_function_with_data(
+        &self,
+        script_path: &Path,
+        script_content: &str,
+        func_name: &str,
+        ctx: &ProviderContext,
+        data: &JsonValue,
+    ) -> Result<JsonValue> {
+        self.call_function_impl(script_path, script_content, func_name, ctx, &[], Some(data))
+    }
+
+    fn call_function_impl(
+        &self,
+        script_path: &Path,
+        script_content: &str,
+        func_name: &str,
+        ctx: &ProviderContext,
+        extra_args: &[JsonValue],
+        data: Option<&JsonValue>,
     ) -> Result<JsonValue> {
         let path_lossy = script_path.to_string_lossy();
         let script_name = script_path
@@ -353,7 +413,7 @@ impl StarlarkEngine {
         .map_err(|e| Error::ParseError(e.to_string()))?;
 
         // Build globals with standard builtins
-        let globals = GlobalsBuilder::standard().build();
+        let globals = provider_globals();
 
         // Create module and evaluator with @vx//stdlib FileLoader
         // This enables load("@vx//stdlib:github.star", ...) in provider scripts
@@ -382,6 +442,9 @@ impl StarlarkEngine {
         for arg in extra_args {
             pos_args.push(self.json_to_starlark_value(heap, arg));
         }
+        if let Some(data) = data {
+            pos_args.push(self.json_to_starlark_data(heap, data));
+        }
 
         // Look up the function by name (must happen after args are built,
         // since get() returns a Value tied to the module's heap)
@@ -441,6 +504,15 @@ impl StarlarkEngine {
                 "os":     ctx.platform.os,
                 "arch":   ctx.platform.arch,
                 "target": ctx.platform.target,
+                "libc":   ctx.platform.libc,
+            },
+            // NVIDIA driver / CUDA availability; cuda is "" without a usable GPU
+            "gpu": {
+                "available": ctx.gpu.is_available(),
+                "cuda":      ctx.gpu.cuda_version.as_deref().unwrap_or(""),
+                "driver":    ctx.gpu.driver_version.as_deref().unwrap_or(""),
+                "toolkit":   ctx.gpu.toolkit_version.as_deref().unwrap_or(""),
+                "devices":   ctx.gpu.devices.iter().map(|d| d.name.as_str()).collect::<Vec<_>>(),
             },
             "env": ctx.env,
             // platform_install_dir = install_dir/<platform> — the actual on-disk location
@@ -496,6 +568,33 @@ impl StarlarkEngine {
         }
     }
 
+    /// Convert a JSON value to Starlark data, with objects as dicts
+    fn json_to_starlark_data<'v>(
+        &self,
+        heap: &'v starlark::values::Heap,
+        json: &JsonValue,
+    ) -> Value<'v> {
+        match json {
+            JsonValue::Array(arr) => {
+                let items: Vec<Value> = arr
+                    .iter()
+                    .map(|v| self.json_to_starlark_data(heap, v))
+                    .collect();
+                heap.alloc(items)
+            }
+            JsonValue::Object(obj) => {
+                let entries: Vec<(&str, Value)> = obj
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), self.json_to_starlark_data(heap, v)))
+                    .collect();
+                heap.alloc(AllocDict(entries))
+            }
+            // Keep ids and timestamps that don't fit in an i32
+            JsonValue::Number(n) if n.is_i64() => heap.alloc(n.as_i64().unwrap_or_default()),
+            _ => self.json_to_starlark_value(heap, json),
+        }
+    }
+
     /// Convert a Starlark Value to JSON
     fn starlark_value_to_json(&self, value: Value) -> JsonValue {
         if value.is_none() {
@@ -70,8 +70,21 @@ pub enum PostInstallOps {
         executable: String,
         /// Arguments
         args: Vec<String>,
-        /// Working directory
+        /// Working directory, relative to the install directory
         working_dir: Option<String>,
+        /// Extra environment variables
+        env: HashMap<String, String>,
+        /// How to handle command failure: "warn", "error", "ignore"
+        on_failure: String,
+    },
+    /// Write a text file into the install directory
+    WriteFile {
+        /// Path relative to the install directory
+        path: String,
+        /// File content
+        content: String,
+        /// Replace an existing file
+        overwrite: bool,
     },
 }
 
@@ -687,6 +700,14 @@ impl ProviderHandle {
         // Get executable name for the current runtime
         let exe_name = self.runtime_executable_name();
 
+        // System-managed installs (winget/choco) only keep a record in the store
+        if let Some(record) = vx_paths::SystemManagedRecord::read(&version_dir) {
+            return record
+                .existing_executable()
+                .map(|p| p.to_path_buf())
+                .or_else(|| which::which(&exe_name).ok());
+        }
+
         // Build all possible executable file names.
         // On Windows, many bundled runtimes (npm, npx, yarn, corepack) use .cmd,
         // not .exe. We must search for both extensions.
@@ -749,13 +770,25 @@ fn post_extract_to_post_install(action: PostExtractAction) -> Option<PostInstall
             executable,
             args,
             working_dir,
-            ..
+            env,
+            on_failure,
         } => Some(PostInstallOps::RunCommand {
             executable,
             args,
             working_dir,
+            env,
+            on_failure,
+        }),
+        PostExtractAction::WriteFile {
+            path,
+            content,
+            overwrite,
+        } => Some(PostInstallOps::WriteFile {
+            path,
+            content,
+            overwrite,
         }),
-        // CreateShim is not directly mappable to PostInstallOps
+        // CreateShim and FlattenDir are not directly mappable to PostInstallOps
         _ => None,
     }
 }
@@ -22,6 +22,8 @@
 //! let versions = provider.fetch_versions().await?;
 //! ```
 
+pub mod commands;
+pub mod constraints;
 pub mod context;
 pub mod engine;
 pub mod error;
@@ -31,12 +33,15 @@ pub mod provider;
 pub mod provider_test_support;
 pub mod sandbox;
 pub mod stdlib;
+pub mod user_providers;
 
 /// Test mocks for provider tests (only available with #[cfg(test)] or in dev builds)
 #[cfg(any(test, feature = "test-mocks"))]
 pub mod test_mocks;
 
 // Re-exports
+pub use commands::star_commands;
+pub use constraints::star_constraints;
 pub use context::ProviderContext;
 pub use engine::{ProviderLint, StarlarkEngine};
 pub use error::{Error, Result};
@@ -50,11 +55,16 @@ pub use provider::version_cache::{
     global_version_cache,
 };
 pub use provider::{
-    EnvOp, InstallLayout, PostExtractAction, ProviderMeta, RuntimeMeta, StarlarkProvider,
-    apply_env_ops, build_runtimes, create_provider, make_download_url_fn, make_fetch_versions_fn,
-    make_install_layout_fn,
+    ANALYSIS_CACHE_DIR_NAME, AnalysisDiskCache, EnvOp, InstallLayout, PostExtractAction,
+    ProviderMeta, RuntimeMeta, StarlarkProvider, apply_env_ops, build_runtimes, create_provider,
+    make_download_url_fn, make_fetch_versions_fn, make_install_layout_fn,
+};
+pub use sandbox::{PermissionsDecl, SandboxConfig, register_script_sandbox};
+pub use user_providers::{
+    DEV_LINKS_DIR, SkippedUserProvider, UserProvider, dev_link_file, discover_dev_providers,
+    discover_dev_providers_checked, discover_user_providers, discover_user_providers_checked,
+    validate_user_provider,
 };
-pub use sandbox::SandboxConfig;
 pub use vx_star_metadata::{StarMetadata, StarRuntimeMeta};
 
 /// Starlark provider file extension
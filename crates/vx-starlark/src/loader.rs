@@ -99,7 +99,7 @@ impl VxModuleLoader {
         let ast = AstModule::parse(path, source.to_string(), dialect)
             .map_err(|e| anyhow::anyhow!("Failed to parse vx module '{}': {}", path, e))?;
 
-        let globals = starlark::environment::GlobalsBuilder::standard().build();
+        let globals = crate::engine::provider_globals();
         let module = starlark::environment::Module::new();
         {
             // Use a recursive loader so modules can load each other.
@@ -141,7 +141,7 @@ impl VxModuleLoader {
         let ast = AstModule::parse(path, source.to_string(), dialect)
             .map_err(|e| anyhow::anyhow!("Failed to parse vx module '{}': {}", path, e))?;
 
-        let globals = starlark::environment::GlobalsBuilder::standard().build();
+        let globals = crate::engine::provider_globals();
         let module = starlark::environment::Module::new();
         {
             let recursive_loader = VxModuleLoader::new();
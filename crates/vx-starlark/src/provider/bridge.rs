@@ -335,18 +335,27 @@ fn extract_max_version(constraint: &str) -> Option<String> {
 fn versions_to_runtime(versions: Vec<crate::context::VersionInfo>) -> Vec<vx_runtime::VersionInfo> {
     versions
         .into_iter()
-        .map(|v| vx_runtime::VersionInfo {
-            version: v.version,
-            released_at: v.date.and_then(|d| {
-                chrono::DateTime::parse_from_rfc3339(&d)
-                    .ok()
-                    .map(|dt| dt.with_timezone(&chrono::Utc))
-            }),
-            prerelease: !v.stable,
-            lts: v.lts,
-            download_url: None,
-            checksum: None,
-            metadata: std::collections::HashMap::new(),
+        .map(|v| {
+            let mut info = vx_runtime::VersionInfo {
+                version: v.version,
+                released_at: v.date.and_then(|d| {
+                    chrono::DateTime::parse_from_rfc3339(&d)
+                        .ok()
+                        .map(|dt| dt.with_timezone(&chrono::Utc))
+                }),
+                prerelease: !v.stable,
+                lts: v.lts,
+                download_url: None,
+                checksum: None,
+                metadata: std::collections::HashMap::new(),
+            };
+            if let Some(url) = v.release_url {
+                info = info.with_release_url(url);
+            }
+            if let Some(notes) = v.release_notes {
+                info = info.with_release_notes(notes);
+            }
+            info
         })
         .collect()
 }
@@ -414,7 +423,7 @@ pub fn make_version_info_fn_owned(
 /// The returned function calls `post_extract(ctx, version, install_dir)` in the
 /// Starlark script and returns the raw action descriptors as JSON values.
 /// `ManifestDrivenRuntime::post_install` iterates over those descriptors and
-/// executes each one (SetPermissions / RunCommand).
+/// executes each one (SetPermissions / RunCommand / WriteFile).
 ///
 /// This is the type alias used in `vx-runtime` for the post_extract function pointer.
 /// It must exactly match `vx_runtime::PostExtractFn`.
@@ -461,18 +470,31 @@ pub(super) fn make_post_extract_fn_owned(
                     crate::provider::types::PostExtractAction::RunCommand {
                         executable,
                         args,
+                        working_dir,
                         env,
                         on_failure,
-                        ..
                     } => {
                         serde_json::json!({
                             "type": "run_command",
                             "command": executable,
                             "args": args,
+                            "working_dir": working_dir,
                             "env": env,
                             "on_failure": on_failure,
                         })
                     }
+                    crate::provider::types::PostExtractAction::WriteFile {
+                        path,
+                        content,
+                        overwrite,
+                    } => {
+                        serde_json::json!({
+                            "type": "write_file",
+                            "path": path,
+                            "content": content,
+                            "overwrite": overwrite,
+                        })
+                    }
                     crate::provider::types::PostExtractAction::CreateShim { .. }
                     | crate::provider::types::PostExtractAction::FlattenDir { .. } => {
                         // Shims and flatten-dir are not yet handled in the manifest-driven post_install path.
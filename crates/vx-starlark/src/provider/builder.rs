@@ -5,7 +5,7 @@
 //! - [`build_runtimes`]  — build a `Vec<Arc<dyn Runtime>>` from a star file.
 
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use vx_star_metadata::StarMetadata;
 
@@ -37,7 +37,9 @@ pub fn create_provider(
     struct StarOnlyProvider {
         name: String,
         description: String,
+        content: String,
         runtimes: Vec<Arc<dyn vx_runtime::Runtime>>,
+        commands: OnceLock<Vec<Arc<dyn vx_runtime::CommandContribution>>>,
     }
 
     impl vx_runtime::Provider for StarOnlyProvider {
@@ -52,6 +54,14 @@ pub fn create_provider(
         fn runtimes(&self) -> Vec<Arc<dyn vx_runtime::Runtime>> {
             self.runtimes.clone()
         }
+
+        // Evaluated on first use: only `vx <provider> <subcommand>`, help and
+        // completions need the declared commands.
+        fn commands(&self) -> Vec<Arc<dyn vx_runtime::CommandContribution>> {
+            self.commands
+                .get_or_init(|| crate::commands::star_commands(&self.name, &self.content))
+                .clone()
+        }
     }
 
     let provider_name = provider_name.into();
@@ -62,12 +72,14 @@ pub fn create_provider(
         .clone()
         .unwrap_or_else(|| format!("{} provider", provider_name));
 
-    let runtimes = build_runtimes(provider_name.clone(), content, None::<String>);
+    let runtimes = build_runtimes(provider_name.clone(), content.clone(), None::<String>);
 
     Arc::new(StarOnlyProvider {
         name: provider_name,
         description,
+        content,
         runtimes,
+        commands: OnceLock::new(),
     })
 }
 
@@ -199,6 +211,10 @@ pub fn build_runtimes(
                 runtime = runtime.with_install_deps(rt.install_deps.clone());
             }
 
+            if !rt.system_deps.is_empty() {
+                runtime = runtime.with_system_deps(system_deps_from_meta(&rt.system_deps));
+            }
+
             if !rt.shells.is_empty() {
                 use vx_runtime::manifest_runtime::ShellDefinition;
                 let shells: Vec<ShellDefinition> = rt
@@ -336,6 +352,32 @@ fn parse_system_install_strategies(
     vec![]
 }
 
+/// Convert `system_deps` entries from provider.star into runtime system dependencies.
+///
+/// Entries with an unknown `type` are skipped.
+fn system_deps_from_meta(
+    deps: &[vx_star_metadata::StarSystemDep],
+) -> Vec<vx_runtime::manifest_runtime::SystemDependency> {
+    use vx_runtime::manifest_runtime::{SystemDepType, SystemDependency};
+
+    deps.iter()
+        .filter_map(|dep| {
+            let Some(dep_type) = SystemDepType::from_name(&dep.dep_type) else {
+                tracing::debug!(id = %dep.id, "unknown system dependency type: {}", dep.dep_type);
+                return None;
+            };
+            Some(SystemDependency {
+                dep_type,
+                id: dep.id.clone(),
+                version: dep.version.clone(),
+                reason: dep.reason.clone(),
+                platforms: dep.platforms.clone(),
+                optional: dep.optional,
+            })
+        })
+        .collect()
+}
+
 /// Parse a JSON array of strategy dicts into [`InstallStrategy`] values.
 fn parse_strategies_array(
     arr: &[serde_json::Value],
@@ -364,6 +406,18 @@ fn parse_strategies_array(
                 })
                 .unwrap_or_default();
 
+            // `system_managed_install()` marks winget/choco strategies whose
+            // installs are version-pinned and recorded in the store
+            if s.get("system_managed").and_then(|v| v.as_bool()) == Some(true) {
+                return Some(InstallStrategy::SystemManaged {
+                    manager,
+                    package,
+                    install_args,
+                    priority,
+                    platforms,
+                });
+            }
+
             Some(InstallStrategy::PackageManager {
                 manager,
                 package,
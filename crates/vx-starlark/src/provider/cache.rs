@@ -3,19 +3,37 @@
 //! Inspired by Buck2's incremental analysis: cache the frozen ProviderInfo
 //! keyed by the SHA256 hash of the script content. If the script hasn't
 //! changed (same hash), reuse the cached analysis result without re-executing.
+//!
+//! Two levels:
+//!
+//! - **L1** (`ANALYSIS_CACHE`): in-memory, per process
+//! - **L2** ([`AnalysisDiskCache`]): `~/.vx/cache/starlark/<hash>.json`, so a
+//!   new vx invocation doesn't evaluate every provider.star again. Entries
+//!   written by another vx version are ignored and removed, since the parser
+//!   and the stdlib may have changed in between.
 
 use super::types::{ProviderMeta, RuntimeMeta};
 use crate::engine::FrozenProviderInfo;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::SystemTime;
+use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::sync::RwLock;
+use tracing::{debug, trace};
+
+/// Directory under the vx cache dir holding persisted analysis results
+pub const ANALYSIS_CACHE_DIR_NAME: &str = "starlark";
+
+/// Bump when the layout of [`CachedAnalysis`] changes
+const ANALYSIS_CACHE_FORMAT_VERSION: u8 = 1;
+
+/// vx version written into each disk entry
+const VX_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 /// Incremental analysis cache entry (Buck2-inspired content-hash cache)
 #[derive(Debug, Clone)]
 pub(super) struct AnalysisCacheEntry {
-    /// SHA256 hash of the provider.star content
-    pub script_hash: [u8; 32],
     /// Frozen analysis result (immutable after analysis phase)
     /// NOTE: Used in Phase 2 when full Starlark execution engine is implemented
     #[allow(dead_code)]
@@ -42,6 +60,157 @@ pub(super) type AnalysisCache = Arc<RwLock<HashMap<[u8; 32], AnalysisCacheEntry>
 pub(super) static ANALYSIS_CACHE: once_cell::sync::Lazy<AnalysisCache> =
     once_cell::sync::Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
 
+/// Global on-disk analysis cache under the default vx cache directory
+pub(super) static ANALYSIS_DISK_CACHE: once_cell::sync::Lazy<AnalysisDiskCache> =
+    once_cell::sync::Lazy::new(AnalysisDiskCache::with_default_dir);
+
+/// An analysis result as stored on disk
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedAnalysis {
+    format_version: u8,
+    /// vx version that analyzed the script
+    vx_version: String,
+    script_hash_hex: String,
+    meta: ProviderMeta,
+    runtimes: Vec<RuntimeMeta>,
+    cached_at_unix: u64,
+}
+
+/// Persistent (L2) analysis cache, one JSON file per script content hash
+///
+/// Failures to read or write are never fatal: the script is simply analyzed
+/// again.
+#[derive(Debug, Clone)]
+pub struct AnalysisDiskCache {
+    dir: PathBuf,
+}
+
+impl AnalysisDiskCache {
+    /// Create a cache stored in `dir`
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Create a cache in `~/.vx/cache/starlark`
+    pub fn with_default_dir() -> Self {
+        let cache_dir = vx_paths::VxPaths::new()
+            .map(|p| p.cache_dir)
+            .unwrap_or_else(|_| {
+                dirs::home_dir()
+                    .unwrap_or_default()
+                    .join(".vx")
+                    .join("cache")
+            });
+        Self::new(cache_dir.join(ANALYSIS_CACHE_DIR_NAME))
+    }
+
+    /// Directory the entries are stored in
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Look up the analysis of the script with this content hash
+    ///
+    /// Entries from another vx version or cache format, or that fail to
+    /// parse, are removed and reported as a miss.
+    pub fn get(&self, script_hash: &[u8; 32]) -> Option<(ProviderMeta, Vec<RuntimeMeta>)> {
+        let hash_hex = hash_hex(script_hash);
+        let path = self.entry_path(&hash_hex);
+        let content = std::fs::read_to_string(&path).ok()?;
+
+        match serde_json::from_str::<CachedAnalysis>(&content) {
+            Ok(entry)
+                if entry.format_version == ANALYSIS_CACHE_FORMAT_VERSION
+                    && entry.vx_version == VX_VERSION
+                    && entry.script_hash_hex == hash_hex =>
+            {
+                trace!(hash = %hash_hex, "L2 analysis cache hit");
+                Some((entry.meta, entry.runtimes))
+            }
+            Ok(entry) => {
+                debug!(
+                    hash = %hash_hex,
+                    cached_by = %entry.vx_version,
+                    "Discarding analysis cache entry from another vx version"
+                );
+                let _ = std::fs::remove_file(&path);
+                None
+            }
+            Err(e) => {
+                debug!(hash = %hash_hex, error = %e, "Discarding unreadable analysis cache entry");
+                let _ = std::fs::remove_file(&path);
+                None
+            }
+        }
+    }
+
+    /// Store the analysis of the script with this content hash
+    pub fn put(&self, script_hash: &[u8; 32], meta: &ProviderMeta, runtimes: &[RuntimeMeta]) {
+        let hash_hex = hash_hex(script_hash);
+        let entry = CachedAnalysis {
+            format_version: ANALYSIS_CACHE_FORMAT_VERSION,
+            vx_version: VX_VERSION.to_string(),
+            script_hash_hex: hash_hex.clone(),
+            meta: meta.clone(),
+            runtimes: runtimes.to_vec(),
+            cached_at_unix: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        };
+        if let Err(e) = self.write_entry(&hash_hex, &entry) {
+            debug!(hash = %hash_hex, error = %e, "Failed to write analysis cache entry");
+        }
+    }
+
+    /// Remove the entry for this content hash, if any
+    pub fn remove(&self, script_hash: &[u8; 32]) {
+        let _ = std::fs::remove_file(self.entry_path(&hash_hex(script_hash)));
+    }
+
+    /// Remove all entries, returning how many were removed
+    pub fn clear(&self) -> usize {
+        self.entry_paths()
+            .filter(|path| std::fs::remove_file(path).is_ok())
+            .count()
+    }
+
+    /// Number of entries on disk
+    pub fn entry_count(&self) -> usize {
+        self.entry_paths().count()
+    }
+
+    fn entry_path(&self, hash_hex: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", hash_hex))
+    }
+
+    fn entry_paths(&self) -> impl Iterator<Item = PathBuf> {
+        std::fs::read_dir(&self.dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+    }
+
+    fn write_entry(&self, hash_hex: &str, entry: &CachedAnalysis) -> std::io::Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        // Write then rename so concurrent vx processes never read half an entry
+        let tmp = self
+            .dir
+            .join(format!("{}.json.{}.tmp", hash_hex, std::process::id()));
+        std::fs::write(&tmp, serde_json::to_vec(entry)?)?;
+        std::fs::rename(&tmp, self.entry_path(hash_hex)).inspect_err(|_| {
+            let _ = std::fs::remove_file(&tmp);
+        })
+    }
+}
+
+/// Lowercase hex form of a content hash
+pub(super) fn hash_hex(hash: &[u8; 32]) -> String {
+    hash.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
 /// Compute SHA256 hash of content bytes
 ///
 /// Uses multiple hash passes to produce a 32-byte representation.
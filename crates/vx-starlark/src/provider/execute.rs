@@ -301,11 +301,38 @@ impl StarlarkProvider {
                                     .collect()
                             })
                             .unwrap_or_default();
+                        let components = json
+                            .get("components")
+                            .and_then(|c| c.as_object())
+                            .map(|obj| {
+                                obj.iter()
+                                    .map(|(name, patterns)| {
+                                        let patterns = patterns
+                                            .as_array()
+                                            .map(|arr| {
+                                                arr.iter()
+                                                    .filter_map(|v| {
+                                                        v.as_str().map(|s| s.to_string())
+                                                    })
+                                                    .collect()
+                                            })
+                                            .unwrap_or_default();
+                                        (name.clone(), patterns)
+                                    })
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+                        let bottle = json
+                            .get("bottle")
+                            .and_then(|b| b.as_str())
+                            .map(|s| s.to_string());
                         debug!(provider = %self.meta.name, url = ?url, strip_prefix = ?strip_prefix, "Resolved archive_install/archive descriptor");
                         Ok(Some(InstallLayout::Archive {
                             url,
                             strip_prefix,
                             executable_paths,
+                            components,
+                            bottle,
                         }))
                     }
                     "binary_install" | "binary" => {
@@ -574,6 +601,13 @@ impl StarlarkProvider {
                 "brew" => (manager, vec!["uninstall", package]),
                 "apt" => ("sudo", vec!["apt", "remove", "-y", package]),
                 "dnf" => ("sudo", vec!["dnf", "remove", "-y", package]),
+                "pacman" => ("sudo", vec!["pacman", "-R", "--noconfirm", package]),
+                "zypper" => (
+                    "sudo",
+                    vec!["zypper", "--non-interactive", "remove", package],
+                ),
+                "apk" => ("sudo", vec!["apk", "del", package]),
+                "nix" => (manager, vec!["profile", "remove", package]),
                 other => {
                     debug!(provider = %self.meta.name, manager = %other, "Unknown package manager, skipping");
                     continue;
@@ -770,10 +804,15 @@ impl StarlarkProvider {
             .get("version")
             .and_then(|v| v.as_str())
             .unwrap_or("");
-        let linux_libc = descriptor
-            .get("linux_libc")
-            .and_then(|l| l.as_str())
-            .unwrap_or("musl");
+        // glibc builds don't run on musl hosts, so Alpine always prefers musl
+        let linux_libc = if core_ctx.platform.libc == "musl" {
+            "musl"
+        } else {
+            descriptor
+                .get("linux_libc")
+                .and_then(|l| l.as_str())
+                .unwrap_or("musl")
+        };
         let threshold = descriptor
             .get("score_threshold")
             .and_then(|t| t.as_u64())
@@ -837,6 +876,7 @@ impl StarlarkProvider {
                 "os": core_ctx.platform.os,
                 "arch": core_ctx.platform.arch,
                 "target": core_ctx.platform.target,
+                "libc": core_ctx.platform.libc,
             },
         });
 
@@ -140,6 +140,21 @@ impl StarlarkProvider {
                 })
             }
 
+            "write_file" => {
+                let path = json.get("path").and_then(|p| p.as_str())?.to_string();
+                let content = json.get("content").and_then(|c| c.as_str())?.to_string();
+                let overwrite = json
+                    .get("overwrite")
+                    .and_then(|o| o.as_bool())
+                    .unwrap_or(false);
+                debug!(provider = %self.meta.name, path = %path, "Resolved write_file descriptor");
+                Some(PostExtractAction::WriteFile {
+                    path,
+                    content,
+                    overwrite,
+                })
+            }
+
             other => {
                 warn!(provider = %self.meta.name, type_ = %other, "Unknown post_extract action type, ignoring");
                 None
@@ -3,7 +3,8 @@
 //! This module implements the core functionality for:
 //! - Loading and parsing provider.star files
 //! - Executing provider functions
-//! - Incremental analysis caching (content-hash based, Buck2-inspired)
+//! - Incremental analysis caching (content-hash based, Buck2-inspired, persisted
+//!   under `~/.vx/cache/starlark`)
 //! - Providing a trait-based interface compatible with vx's Provider system
 //!
 //! # Module structure
@@ -28,13 +29,14 @@ mod versions;
 use crate::context::{InstallResult, ProviderContext, VersionInfo};
 use crate::engine::{FrozenProviderInfo, StarlarkEngine};
 use crate::error::{Error, Result};
-use crate::sandbox::SandboxConfig;
+use crate::sandbox::{SandboxConfig, sandbox_for_script, url_host};
 pub use bridge::{
     make_download_url_fn, make_fetch_versions_fn, make_install_layout_fn,
     make_version_info_fn_owned,
 };
 pub use builder::{build_runtimes, create_provider};
-use cache::{ANALYSIS_CACHE, AnalysisCacheEntry, sha256_bytes};
+use cache::{ANALYSIS_CACHE, ANALYSIS_DISK_CACHE, AnalysisCacheEntry, hash_hex, sha256_bytes};
+pub use cache::{ANALYSIS_CACHE_DIR_NAME, AnalysisDiskCache};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -45,6 +47,11 @@ pub use types::{
     VersionInfoResult, apply_env_ops, has_starlark_provider, is_starlark_provider,
 };
 
+/// Content hash used to key the analysis cache and registered sandboxes
+pub(crate) fn script_hash(content: &str) -> [u8; 32] {
+    sha256_bytes(content.as_bytes())
+}
+
 /// A loaded Starlark provider
 #[derive(Debug, Clone)]
 pub struct StarlarkProvider {
@@ -77,9 +84,10 @@ impl StarlarkProvider {
     ///
     /// Uses content-hash-based incremental analysis cache (Buck2-inspired):
     /// 1. Read the script content and compute its SHA256 hash
-    /// 2. Check the analysis cache by content hash (not file path)
-    /// 3. On cache hit: reuse the frozen ProviderInfo without re-executing
-    /// 4. On cache miss: parse metadata, cache the result
+    /// 2. Check the in-memory, then the on-disk analysis cache by content hash
+    ///    (not file path)
+    /// 3. On cache hit: reuse the analysis result without re-executing
+    /// 4. On cache miss: parse metadata, cache the result at both levels
     pub async fn load(path: impl AsRef<Path>) -> Result<Self> {
         let path = path.as_ref().to_path_buf();
 
@@ -91,56 +99,18 @@ impl StarlarkProvider {
         debug!("Loading Starlark provider from: {:?}", path);
 
         let script_hash = sha256_bytes(content.as_bytes());
-
-        {
-            let cache = ANALYSIS_CACHE.read().await;
-            if let Some(entry) = cache.get(&script_hash) {
-                debug!(path = %path.display(), "Using cached analysis result (content hash match)");
-                let vx_home = Self::resolve_vx_home();
-                return Ok(Self {
-                    script_path: path,
-                    meta: entry.meta.clone(),
-                    runtimes: entry.runtimes.clone(),
-                    sandbox: SandboxConfig::default(),
-                    vx_home,
-                    script_content: Arc::new(content),
-                    script_hash: entry.script_hash,
-                });
-            }
-        }
-
-        let (meta, runtimes) = Self::parse_metadata(&content)?;
-        let vx_home = Self::resolve_vx_home();
+        let (meta, runtimes) = Self::analyze(&content, script_hash).await?;
 
         let provider = Self {
-            script_path: path.clone(),
-            meta: meta.clone(),
-            runtimes: runtimes.clone(),
-            sandbox: SandboxConfig::default(),
-            vx_home,
+            script_path: path,
+            meta,
+            runtimes,
+            sandbox: sandbox_for_script(&script_hash),
+            vx_home: Self::resolve_vx_home(),
             script_content: Arc::new(content),
             script_hash,
         };
 
-        {
-            let mut cache = ANALYSIS_CACHE.write().await;
-            cache.insert(
-                script_hash,
-                AnalysisCacheEntry {
-                    script_hash,
-                    frozen_info: FrozenProviderInfo {
-                        versions_url: None,
-                        download_url: None,
-                        env_template: HashMap::new(),
-                        metadata: HashMap::new(),
-                    },
-                    meta,
-                    runtimes,
-                    cached_at: SystemTime::now(),
-                },
-            );
-        }
-
         info!("Loaded Starlark provider: {}", provider.meta.name);
         Ok(provider)
     }
@@ -152,7 +122,13 @@ impl StarlarkProvider {
         Ok(provider)
     }
 
-    /// Create a provider from in-memory script content (no filesystem access).
+    /// Replace the sandbox configuration of an already-loaded provider
+    pub fn with_sandbox(mut self, sandbox: SandboxConfig) -> Self {
+        self.sandbox = sandbox;
+        self
+    }
+
+    /// Create a provider from in-memory script content (no script file needed).
     ///
     /// This is the preferred entry point for built-in providers that embed their
     /// `provider.star` at compile time via `include_str!`.
@@ -168,56 +144,18 @@ impl StarlarkProvider {
         let content = content.into();
         let virtual_path = PathBuf::from(format!("<builtin:{}>", name));
         let script_hash = sha256_bytes(content.as_bytes());
-
-        {
-            let cache = ANALYSIS_CACHE.read().await;
-            if let Some(entry) = cache.get(&script_hash) {
-                debug!(provider = %name, "Using cached analysis result for built-in provider (content hash match)");
-                let vx_home = Self::resolve_vx_home();
-                return Ok(Self {
-                    script_path: virtual_path,
-                    meta: entry.meta.clone(),
-                    runtimes: entry.runtimes.clone(),
-                    sandbox: SandboxConfig::default(),
-                    vx_home,
-                    script_content: Arc::new(content),
-                    script_hash: entry.script_hash,
-                });
-            }
-        }
-
-        let (meta, runtimes) = Self::parse_metadata(&content)?;
-        let vx_home = Self::resolve_vx_home();
+        let (meta, runtimes) = Self::analyze(&content, script_hash).await?;
 
         let provider = Self {
             script_path: virtual_path,
-            meta: meta.clone(),
-            runtimes: runtimes.clone(),
-            sandbox: SandboxConfig::default(),
-            vx_home,
+            meta,
+            runtimes,
+            sandbox: sandbox_for_script(&script_hash),
+            vx_home: Self::resolve_vx_home(),
             script_content: Arc::new(content),
             script_hash,
         };
 
-        {
-            let mut cache = ANALYSIS_CACHE.write().await;
-            cache.insert(
-                script_hash,
-                AnalysisCacheEntry {
-                    script_hash,
-                    frozen_info: FrozenProviderInfo {
-                        versions_url: None,
-                        download_url: None,
-                        env_template: HashMap::new(),
-                        metadata: HashMap::new(),
-                    },
-                    meta,
-                    runtimes,
-                    cached_at: SystemTime::now(),
-                },
-            );
-        }
-
         debug!("Loaded built-in Starlark provider: {}", provider.meta.name);
         Ok(provider)
     }
@@ -236,6 +174,9 @@ impl StarlarkProvider {
     pub fn runtimes(&self) -> &[RuntimeMeta] {
         &self.runtimes
     }
+    pub fn sandbox(&self) -> &SandboxConfig {
+        &self.sandbox
+    }
     pub fn script_path(&self) -> &Path {
         &self.script_path
     }
@@ -245,10 +186,7 @@ impl StarlarkProvider {
     }
 
     pub fn script_hash_hex(&self) -> String {
-        self.script_hash
-            .iter()
-            .map(|b| format!("{:02x}", b))
-            .collect()
+        hash_hex(&self.script_hash)
     }
 
     // ── Public provider functions ─────────────────────────────────────────────
@@ -387,7 +325,79 @@ impl StarlarkProvider {
             ctx = ctx.with_runtime_name(name);
         }
 
-        self.execute_download_url(&ctx, version).await
+        let url = self.download_url_with_fallback(&mut ctx, version).await?;
+        if let Some(host) = url.as_deref().and_then(url_host)
+            && !self.sandbox.is_host_allowed(host)
+        {
+            return Err(Error::HttpHostDenied {
+                host: host.to_string(),
+            });
+        }
+        Ok(url)
+    }
+
+    /// Run `download_url`, retrying as x64 when the host has no native build
+    ///
+    /// On Windows ARM64 a provider that returns nothing for `arm64` is asked
+    /// again for `x64`, which Windows runs under emulation. `ctx.platform` is
+    /// left as the platform the URL was resolved for.
+    async fn download_url_with_fallback(
+        &self,
+        ctx: &mut ProviderContext,
+        version: &str,
+    ) -> Result<Option<String>> {
+        let url = self.execute_download_url(ctx, version).await?;
+        if url.is_some() {
+            return Ok(url);
+        }
+        let Some(fallback) = ctx.platform.emulation_fallback() else {
+            return Ok(None);
+        };
+
+        let native = std::mem::replace(&mut ctx.platform, fallback);
+        let url = self.execute_download_url(ctx, version).await?;
+        if url.is_some() {
+            info!(
+                provider = %self.meta.name,
+                version = %version,
+                "No native {}-{} build; using the {} build under emulation",
+                native.os,
+                native.arch,
+                ctx.platform.arch
+            );
+        } else {
+            ctx.platform = native;
+        }
+        Ok(url)
+    }
+
+    /// Context for a layout or install hook, on the platform `download_url`
+    /// resolves to
+    async fn install_ctx(
+        &self,
+        version: &str,
+        runtime_name: Option<&str>,
+    ) -> Result<ProviderContext> {
+        let new_ctx = || {
+            let ctx = ProviderContext::new(&self.meta.name, self.vx_home.clone())
+                .with_description(&self.meta.description)
+                .with_sandbox(self.sandbox.clone())
+                .with_version(version);
+            match runtime_name {
+                Some(name) => ctx.with_runtime_name(name),
+                None => ctx,
+            }
+        };
+        let mut ctx = new_ctx();
+        if ctx.platform.emulation_fallback().is_some() {
+            let mut probe = new_ctx();
+            if let Some(date) = self.lookup_version_date(version, runtime_name).await {
+                probe = probe.with_version_date(date);
+            }
+            self.download_url_with_fallback(&mut probe, version).await?;
+            ctx.platform = probe.platform;
+        }
+        Ok(ctx)
     }
 
     /// Call the `install_layout` function and resolve the returned descriptor
@@ -401,13 +411,7 @@ impl StarlarkProvider {
         version: &str,
         runtime_name: Option<&str>,
     ) -> Result<Option<InstallLayout>> {
-        let mut ctx = ProviderContext::new(&self.meta.name, self.vx_home.clone())
-            .with_description(&self.meta.description)
-            .with_sandbox(self.sandbox.clone())
-            .with_version(version);
-        if let Some(name) = runtime_name {
-            ctx = ctx.with_runtime_name(name);
-        }
+        let ctx = self.install_ctx(version, runtime_name).await?;
         self.execute_install_layout(&ctx, version).await
     }
 
@@ -427,13 +431,7 @@ impl StarlarkProvider {
         version: &str,
         runtime_name: Option<&str>,
     ) -> Result<Option<serde_json::Value>> {
-        let mut ctx = ProviderContext::new(&self.meta.name, self.vx_home.clone())
-            .with_description(&self.meta.description)
-            .with_sandbox(self.sandbox.clone())
-            .with_version(version);
-        if let Some(name) = runtime_name {
-            ctx = ctx.with_runtime_name(name);
-        }
+        let ctx = self.install_ctx(version, runtime_name).await?;
         self.execute_install_layout_raw(&ctx, version).await
     }
 
@@ -506,10 +504,11 @@ impl StarlarkProvider {
 
     // ── Cache management ──────────────────────────────────────────────────────
 
-    /// Clear the incremental analysis cache
+    /// Clear the incremental analysis cache (memory and disk)
     pub async fn clear_cache() {
         let mut cache = ANALYSIS_CACHE.write().await;
         cache.clear();
+        ANALYSIS_DISK_CACHE.clear();
         info!("Cleared Starlark incremental analysis cache");
     }
 
@@ -521,6 +520,7 @@ impl StarlarkProvider {
 
     /// Invalidate a specific cache entry by script content hash
     pub async fn invalidate_cache_entry(script_hash: &[u8; 32]) {
+        ANALYSIS_DISK_CACHE.remove(script_hash);
         let mut cache = ANALYSIS_CACHE.write().await;
         if cache.remove(script_hash).is_some() {
             debug!(
@@ -638,6 +638,48 @@ impl StarlarkProvider {
         None
     }
 
+    /// Analyze a script, going through the memory (L1) and disk (L2) caches
+    async fn analyze(
+        content: &str,
+        script_hash: [u8; 32],
+    ) -> Result<(ProviderMeta, Vec<RuntimeMeta>)> {
+        if let Some(entry) = ANALYSIS_CACHE.read().await.get(&script_hash) {
+            debug!(
+                provider = %entry.meta.name,
+                "Using cached analysis result (content hash match)"
+            );
+            return Ok((entry.meta.clone(), entry.runtimes.clone()));
+        }
+
+        let (meta, runtimes) = match ANALYSIS_DISK_CACHE.get(&script_hash) {
+            Some(analysis) => {
+                debug!(provider = %analysis.0.name, "Using analysis result from disk cache");
+                analysis
+            }
+            None => {
+                let (meta, runtimes) = Self::parse_metadata(content)?;
+                ANALYSIS_DISK_CACHE.put(&script_hash, &meta, &runtimes);
+                (meta, runtimes)
+            }
+        };
+
+        ANALYSIS_CACHE.write().await.insert(
+            script_hash,
+            AnalysisCacheEntry {
+                frozen_info: FrozenProviderInfo {
+                    versions_url: None,
+                    download_url: None,
+                    env_template: HashMap::new(),
+                    metadata: HashMap::new(),
+                },
+                meta: meta.clone(),
+                runtimes: runtimes.clone(),
+                cached_at: SystemTime::now(),
+            },
+        );
+        Ok((meta, runtimes))
+    }
+
     fn resolve_vx_home() -> PathBuf {
         vx_paths::VxPaths::new()
             .map(|p| p.base_dir)
@@ -1,7 +1,7 @@
 //! Type definitions for Starlark provider descriptors and metadata.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Resolved install layout from a Starlark `install_layout()` descriptor
 ///
@@ -25,6 +25,10 @@ pub enum InstallLayout {
         url: Option<String>,
         strip_prefix: Option<String>,
         executable_paths: Vec<String>,
+        /// Optional components: name -> archive path globs below `strip_prefix`
+        components: BTreeMap<String, Vec<String>>,
+        /// Homebrew formula when `url` is a bottle index on ghcr.io
+        bottle: Option<String>,
     },
     /// Single binary installation
     Binary {
@@ -57,6 +61,8 @@ impl InstallLayout {
                 url,
                 strip_prefix,
                 executable_paths,
+                components,
+                bottle,
             } => {
                 let mut map = serde_json::Map::new();
                 if let Some(u) = url {
@@ -74,6 +80,12 @@ impl InstallLayout {
                             .collect(),
                     ),
                 );
+                if !components.is_empty() {
+                    map.insert("components".into(), serde_json::json!(components));
+                }
+                if let Some(formula) = bottle {
+                    map.insert("bottle".into(), serde_json::Value::String(formula));
+                }
                 serde_json::Value::Object(map)
             }
             InstallLayout::Binary {
@@ -189,6 +201,16 @@ pub enum PostExtractAction {
         pattern: Option<String>,
         keep_subdirs: Vec<String>,
     },
+    /// Write a text file into the install directory
+    ///
+    /// Starlark: `write_file("php.ini", content)`
+    ///
+    /// An existing file is kept unless `overwrite` is set.
+    WriteFile {
+        path: String,
+        content: String,
+        overwrite: bool,
+    },
 }
 
 /// Actions returned by `pre_run()` hook in Starlark provider scripts
@@ -341,7 +363,7 @@ pub struct PackageAlias {
 }
 
 /// Provider metadata parsed from the script
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProviderMeta {
     pub name: String,
     #[serde(default)]
@@ -443,7 +465,7 @@ fn default_true() -> bool {
 }
 
 /// Runtime metadata parsed from the script
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RuntimeMeta {
     pub name: String,
     #[serde(default)]
@@ -93,6 +93,10 @@ pub struct CachedVersionInfo {
     pub lts: bool,
     pub stable: bool,
     pub date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_notes: Option<String>,
 }
 
 impl From<&VersionInfo> for CachedVersionInfo {
@@ -102,6 +106,8 @@ impl From<&VersionInfo> for CachedVersionInfo {
             lts: v.lts,
             stable: v.stable,
             date: v.date.clone(),
+            release_url: v.release_url.clone(),
+            release_notes: v.release_notes.clone(),
         }
     }
 }
@@ -113,6 +119,8 @@ impl From<CachedVersionInfo> for VersionInfo {
             lts: v.lts,
             stable: v.stable,
             date: v.date,
+            release_url: v.release_url,
+            release_notes: v.release_notes,
         }
     }
 }
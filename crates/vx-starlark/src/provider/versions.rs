@@ -15,12 +15,20 @@
 use crate::context::{ProviderContext, VersionInfo};
 use crate::engine::StarlarkEngine;
 use crate::error::{Error, Result};
+use crate::sandbox::url_host;
 use tracing::{debug, info, warn};
+use vx_cache::{HttpCacheHeaders, HttpCacheLookup};
 use vx_version_fetcher::VersionFetcherBuilder;
 
 use super::StarlarkProvider;
 use super::version_cache::global_version_cache;
 
+/// Most `http.get_json()` requests one `fetch_versions` call may chain
+const MAX_HTTP_GET_JSON_HOPS: usize = 10;
+
+/// `cache_ttl` of `http.get_json()` when the descriptor has none
+const DEFAULT_HTTP_GET_JSON_TTL_SECS: u64 = 60 * 60;
+
 impl StarlarkProvider {
     /// Execute fetch_versions function using the Starlark engine.
     ///
@@ -37,6 +45,9 @@ impl StarlarkProvider {
     /// 2. **Plain list** of `{version, lts, prerelease, date}` dicts:
     ///    returned by custom `fetch_versions` implementations that build
     ///    the list themselves.
+    ///
+    /// `http.get_json()` descriptors are fetched and passed to the script's
+    /// parse function first, and its result is handled as above.
     pub(super) async fn execute_fetch_versions(
         &self,
         ctx: &ProviderContext,
@@ -54,8 +65,11 @@ impl StarlarkProvider {
             _ => provider_name.clone(),
         };
 
+        // A record/replay cassette has to see every fetch, so it skips the cache
+        let use_cache = vx_cache::cassette::active().is_none();
+
         // ── Cache lookup (L1 → L2) ────────────────────────────────────────────
-        if let Some(cached) = cache.get(&cache_key, &hash_hex).await {
+        if use_cache && let Some(cached) = cache.get(&cache_key, &hash_hex).await {
             debug!(
                 provider = %provider_name,
                 cache_key = %cache_key,
@@ -83,6 +97,9 @@ impl StarlarkProvider {
 
         let versions = match result {
             Ok(json) => {
+                // http.get_json(): fetch, then hand the response to the script
+                let json = self.resolve_http_get_json(ctx, &engine, json).await?;
+
                 // Shape 1: github_versions descriptor from http.star
                 if let Some(type_str) = json.get("__type").and_then(|t| t.as_str())
                     && type_str == "github_versions"
@@ -114,6 +131,8 @@ impl StarlarkProvider {
                                     .get("date")
                                     .and_then(|d| d.as_str())
                                     .map(|s| s.to_string()),
+                                release_url: None,
+                                release_notes: None,
                             })
                         })
                         .collect()
@@ -132,7 +151,7 @@ impl StarlarkProvider {
         };
 
         // ── Cache write (L1 + L2) ─────────────────────────────────────────────
-        if !versions.is_empty() {
+        if use_cache && !versions.is_empty() {
             info!(
                 provider = %provider_name,
                 cache_key = %cache_key,
@@ -148,6 +167,66 @@ impl StarlarkProvider {
 
     // ── Descriptor resolvers ──────────────────────────────────────────────────
 
+    /// Resolve `http_get_json` descriptors from `http.get_json()` in http.star.
+    ///
+    /// Fetches the URL, reusing an HTTP cache entry younger than `cache_ttl`,
+    /// and calls the provider function named by `then` as `then(ctx, data)`.
+    /// Its result may be another `http_get_json` descriptor (e.g. the next
+    /// page), followed up to [`MAX_HTTP_GET_JSON_HOPS`] times. Any other
+    /// value is returned for the usual shapes to handle.
+    ///
+    /// ```json
+    /// {
+    ///   "__type":    "http_get_json",
+    ///   "url":       "https://releases.example.com/index.json",
+    ///   "cache_ttl": "6h",
+    ///   "then":      "parse_versions"
+    /// }
+    /// ```
+    async fn resolve_http_get_json(
+        &self,
+        ctx: &ProviderContext,
+        engine: &StarlarkEngine,
+        mut json: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        for _ in 0..MAX_HTTP_GET_JSON_HOPS {
+            if json.get("__type").and_then(|t| t.as_str()) != Some("http_get_json") {
+                return Ok(json);
+            }
+            let url = json
+                .get("url")
+                .and_then(|u| u.as_str())
+                .ok_or_else(|| Error::EvalError("http_get_json descriptor missing 'url'".into()))?
+                .to_string();
+            if let Some(host) = url_host(&url)
+                && !self.sandbox.is_host_allowed(host)
+            {
+                return Err(Error::HttpHostDenied {
+                    host: host.to_string(),
+                });
+            }
+            let ttl = parse_cache_ttl(json.get("cache_ttl"))?;
+
+            let data = get_json_cached(&url, ttl)
+                .await
+                .map_err(|e| Error::EvalError(format!("HTTP fetch failed for {}: {}", url, e)))?;
+            json = match json.get("then").and_then(|t| t.as_str()) {
+                Some(then) => engine.call_function_with_data(
+                    &self.script_path,
+                    &self.script_content,
+                    then,
+                    ctx,
+                    &data,
+                )?,
+                None => data,
+            };
+        }
+        Err(Error::EvalError(format!(
+            "http.get_json: more than {} chained requests",
+            MAX_HTTP_GET_JSON_HOPS
+        )))
+    }
+
     /// Resolve a `github_versions` descriptor by calling the GitHub API.
     ///
     /// Delegates to `vx-version-fetcher`'s `GitHubReleasesFetcher`, which handles:
@@ -230,11 +309,13 @@ impl StarlarkProvider {
 
         let versions: Vec<VersionInfo> = runtime_versions
             .into_iter()
-            .map(|v| VersionInfo {
+            .map(|mut v| VersionInfo {
                 version: v.version,
                 lts: v.lts,
                 stable: !v.prerelease,
                 date: v.released_at.map(|dt| dt.to_rfc3339()),
+                release_url: v.metadata.remove("release_url"),
+                release_notes: v.metadata.remove("release_notes"),
             })
             .collect();
 
@@ -274,7 +355,13 @@ impl StarlarkProvider {
     /// - `"github_tags"`        — GitHub tags API
     /// - `"vscode_releases"`    — VS Code update API
     /// - `"gcloud_manifest"`    — Google Cloud SDK manifest
-    /// - `"dotnet_releases"`    — .NET releases index
+    /// - `"dotnet_releases"`    — .NET releases index, expanded to every SDK
+    ///   of each supported channel
+    /// - `"php_releases"`       — php.net releases JSON: `{"8.3.12": {...}}`
+    /// - `"zig_index"`          — ziglang.org download index, including the
+    ///   `master` development build
+    /// - `"sqlite_chronology"`  — sqlite.org release history (an HTML table),
+    ///   with the release date of every version
     async fn resolve_fetch_json_versions_descriptor(
         &self,
         descriptor: &serde_json::Value,
@@ -305,6 +392,17 @@ impl StarlarkProvider {
             return self.resolve_python_build_standalone_versions(url).await;
         }
 
+        // Special case: the .NET index only names the latest SDK of each
+        // channel; the full SDK list lives in one releases.json per channel.
+        if transform == "dotnet_releases" {
+            return self.resolve_dotnet_release_versions(url).await;
+        }
+
+        // Special case: the SQLite release history is an HTML page
+        if transform == "sqlite_chronology" {
+            return self.resolve_sqlite_chronology_versions(url).await;
+        }
+
         // Build a custom API fetcher using vx-version-fetcher
         // The transform function is passed as the parser to CustomApiFetcher
         let url_owned = url.to_string();
@@ -327,6 +425,8 @@ impl StarlarkProvider {
                     "vscode_releases" => Self::transform_vscode_releases(raw)?,
                     "gcloud_manifest" => Self::transform_gcloud_manifest(raw)?,
                     "dotnet_releases" => Self::transform_dotnet_releases(raw)?,
+                    "php_releases" => Self::transform_php_releases(raw)?,
+                    "zig_index" => Self::transform_zig_index(raw)?,
                     "python_build_standalone" => Self::transform_python_build_standalone(raw)?,
                     other => {
                         tracing::warn!(
@@ -365,11 +465,13 @@ impl StarlarkProvider {
 
         let versions: Vec<VersionInfo> = runtime_versions
             .into_iter()
-            .map(|v| VersionInfo {
+            .map(|mut v| VersionInfo {
                 version: v.version,
                 lts: v.lts,
                 stable: !v.prerelease,
                 date: v.released_at.map(|dt| dt.to_rfc3339()),
+                release_url: v.metadata.remove("release_url"),
+                release_notes: v.metadata.remove("release_notes"),
             })
             .collect();
 
@@ -434,6 +536,98 @@ impl StarlarkProvider {
         }
     }
 
+    /// Resolve .NET SDK versions from the releases index and its channels.
+    ///
+    /// Every supported (non-EOL) channel in the index links to a
+    /// `releases.json` listing all of its releases, each shipping one or
+    /// more SDKs (feature bands). A channel that cannot be fetched falls
+    /// back to the `latest-sdk` named in the index.
+    async fn resolve_dotnet_release_versions(&self, url: &str) -> Result<Vec<VersionInfo>> {
+        let client = StarlarkHttpClient::new();
+        let index = client
+            .fetch_json(url)
+            .await
+            .map_err(|e| Error::EvalError(format!("HTTP API fetch failed for {}: {}", url, e)))?;
+        let latest = Self::transform_dotnet_releases(&index)?;
+
+        let channels = index
+            .get("releases-index")
+            .and_then(|i| i.as_array())
+            .map(|a| a.as_slice())
+            .unwrap_or(&[]);
+        let mut versions = Vec::new();
+        for channel in channels {
+            if channel.get("support-phase").and_then(|s| s.as_str()) == Some("eol") {
+                continue;
+            }
+            let Some(releases_url) = channel.get("releases.json").and_then(|u| u.as_str()) else {
+                continue;
+            };
+            let lts = channel.get("release-type").and_then(|t| t.as_str()) == Some("lts");
+            match client.fetch_json(releases_url).await {
+                Ok(raw) => versions.extend(Self::transform_dotnet_channel_releases(&raw, lts)),
+                Err(e) => warn!(
+                    provider = %self.meta.name,
+                    url = %releases_url,
+                    error = %e,
+                    "Failed to fetch .NET channel releases, using the latest SDK only"
+                ),
+            }
+        }
+
+        let mut seen: std::collections::HashSet<String> =
+            versions.iter().map(|v| v.version.clone()).collect();
+        for version in latest {
+            if seen.insert(version.version.clone()) {
+                versions.push(version);
+            }
+        }
+        versions.sort_by(|a, b| {
+            let parse = |v: &str| -> Vec<u64> {
+                v.split(['.', '-']).map_while(|p| p.parse().ok()).collect()
+            };
+            parse(&b.version)
+                .cmp(&parse(&a.version))
+                .then_with(|| a.version.contains('-').cmp(&b.version.contains('-')))
+                .then_with(|| b.version.cmp(&a.version))
+        });
+
+        debug!(
+            provider = %self.meta.name,
+            count = versions.len(),
+            "Resolved {} .NET SDK versions",
+            versions.len()
+        );
+        Ok(versions)
+    }
+
+    /// Resolve SQLite versions from the sqlite.org release history page
+    async fn resolve_sqlite_chronology_versions(&self, url: &str) -> Result<Vec<VersionInfo>> {
+        use vx_runtime::HttpClient;
+
+        let html = StarlarkHttpClient::new()
+            .get(url)
+            .await
+            .map_err(|e| Error::EvalError(format!("HTTP fetch failed for {}: {}", url, e)))?;
+        let mut versions = Self::transform_sqlite_chronology(&html);
+        let mut seen = std::collections::HashSet::new();
+        versions.retain(|v| seen.insert(v.version.clone()));
+        if versions.is_empty() {
+            return Err(Error::EvalError(format!(
+                "No SQLite releases found in {}",
+                url
+            )));
+        }
+
+        debug!(
+            provider = %self.meta.name,
+            count = versions.len(),
+            "Resolved {} SQLite versions",
+            versions.len()
+        );
+        Ok(versions)
+    }
+
     /// Fetch Python versions from GitHub API with pagination.
     /// Returns an error if all pages fail (network/rate limit issues).
     async fn fetch_python_versions_from_github(&self, url: &str) -> Result<Vec<VersionInfo>> {
@@ -558,6 +752,8 @@ impl StarlarkProvider {
                 lts: *lts,
                 stable: true,
                 date: Some(build_tag.to_string()),
+                release_url: None,
+                release_notes: None,
             })
             .collect();
 
@@ -676,6 +872,8 @@ impl StarlarkProvider {
                 lts: v.lts,
                 stable: !v.prerelease,
                 date: None,
+                release_url: None,
+                release_notes: None,
             })
             .collect();
 
@@ -719,6 +917,8 @@ impl StarlarkProvider {
                 lts: stable,
                 stable,
                 date: None,
+                release_url: None,
+                release_notes: None,
             });
         }
         Ok(versions)
@@ -752,6 +952,8 @@ impl StarlarkProvider {
                     lts,
                     stable: true,
                     date,
+                    release_url: None,
+                    release_notes: None,
                 })
             })
             .collect();
@@ -773,6 +975,8 @@ impl StarlarkProvider {
                 lts: false,
                 stable: true,
                 date: None,
+                release_url: None,
+                release_notes: None,
             })
             .collect();
 
@@ -796,6 +1000,8 @@ impl StarlarkProvider {
                 lts: false,
                 stable: true,
                 date: None,
+                release_url: None,
+                release_notes: None,
             })
             .collect();
 
@@ -823,6 +1029,8 @@ impl StarlarkProvider {
                     lts: status == "supported",
                     stable: status != "deprecated",
                     date: None,
+                    release_url: None,
+                    release_notes: None,
                 }
             })
             .collect();
@@ -870,6 +1078,8 @@ impl StarlarkProvider {
                     lts: status == "supported",
                     stable: status != "deprecated",
                     date: None,
+                    release_url: None,
+                    release_notes: None,
                 })
             })
             .collect();
@@ -908,6 +1118,8 @@ impl StarlarkProvider {
                 lts: major == most_recent_lts || major % 4 == 1, // LTS: 8, 11, 17, 21...
                 stable: true,
                 date: None,
+                release_url: None,
+                release_notes: None,
             })
             .collect();
 
@@ -933,6 +1145,8 @@ impl StarlarkProvider {
                     lts: false,
                     stable: true,
                     date: None,
+                    release_url: None,
+                    release_notes: None,
                 })
             })
             .collect();
@@ -940,6 +1154,44 @@ impl StarlarkProvider {
         Ok(versions)
     }
 
+    /// Transform the sqlite.org release history into dated versions
+    ///
+    /// Each table row holds a release date (`2024-08-13`) and a version
+    /// (`3.46.1`); rows without both, such as four-part versions of old
+    /// releases, are skipped. The date is what sqlite.org names the download
+    /// directory of a release after.
+    fn transform_sqlite_chronology(html: &str) -> Vec<VersionInfo> {
+        html.split("<tr")
+            .skip(1)
+            .filter_map(|row| {
+                let tokens: Vec<&str> = row
+                    .split(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-'))
+                    .collect();
+                let is_number = |p: &str| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit());
+                let date = tokens.iter().find(|t| {
+                    let parts: Vec<&str> = t.split('-').collect();
+                    parts.len() == 3
+                        && parts.iter().all(|p| is_number(p))
+                        && parts[0].len() == 4
+                        && parts[1].len() == 2
+                        && parts[2].len() == 2
+                })?;
+                let version = tokens.iter().find(|t| {
+                    let parts: Vec<&str> = t.split('.').collect();
+                    parts.len() == 3 && parts.iter().all(|p| is_number(p))
+                })?;
+                Some(VersionInfo {
+                    version: version.to_string(),
+                    lts: false,
+                    stable: true,
+                    date: Some(date.to_string()),
+                    release_url: None,
+                    release_notes: None,
+                })
+            })
+            .collect()
+    }
+
     /// Transform VS Code update API: `["1.85.0", "1.84.2", ...]` (plain string array)
     fn transform_vscode_releases(raw: &serde_json::Value) -> Result<Vec<VersionInfo>> {
         let releases = raw
@@ -958,6 +1210,8 @@ impl StarlarkProvider {
                     lts: true,
                     stable: true,
                     date: None,
+                    release_url: None,
+                    release_notes: None,
                 })
             })
             .collect();
@@ -984,6 +1238,8 @@ impl StarlarkProvider {
             lts: true,
             stable: true,
             date: None,
+            release_url: None,
+            release_notes: None,
         }])
     }
 
@@ -1020,10 +1276,12 @@ impl StarlarkProvider {
                     .map(|t| t == "lts")
                     .unwrap_or(false);
                 Some(VersionInfo {
+                    stable: !latest_sdk.contains('-'),
                     version: latest_sdk,
                     lts: is_lts,
-                    stable: true,
                     date: None,
+                    release_url: None,
+                    release_notes: None,
                 })
             })
             .collect();
@@ -1033,6 +1291,141 @@ impl StarlarkProvider {
         Ok(versions)
     }
 
+    /// Transform the php.net releases API, newest first
+    ///
+    /// `https://www.php.net/releases/index.php?json&version=8&max=-1` returns
+    /// an object keyed by version: `{"8.3.12": {"date": "26 Sep 2024", ...}}`.
+    fn transform_php_releases(raw: &serde_json::Value) -> Result<Vec<VersionInfo>> {
+        let releases = raw
+            .as_object()
+            .ok_or_else(|| Error::EvalError("php_releases: expected JSON object".into()))?;
+
+        let mut versions: Vec<VersionInfo> = releases
+            .keys()
+            .filter(|v| !v.is_empty() && v.split('.').all(|p| p.parse::<u64>().is_ok()))
+            .map(|version| VersionInfo {
+                version: version.clone(),
+                lts: false,
+                stable: true,
+                date: None,
+                release_url: None,
+                release_notes: None,
+            })
+            .collect();
+
+        versions.sort_by_cached_key(|v| {
+            std::cmp::Reverse(
+                v.version
+                    .split('.')
+                    .map(|p| p.parse::<u64>().unwrap_or(0))
+                    .collect::<Vec<_>>(),
+            )
+        });
+        Ok(versions)
+    }
+
+    /// Transform the ziglang.org download index, newest first
+    ///
+    /// `https://ziglang.org/download/index.json` is keyed by release version,
+    /// plus a `master` entry naming the latest development build:
+    /// `{"master": {"version": "0.15.0-dev.847+850655f06", "date": "2025-06-20"},
+    /// "0.14.1": {"date": "2025-05-21"}}`. The development build is listed
+    /// first, as a prerelease.
+    fn transform_zig_index(raw: &serde_json::Value) -> Result<Vec<VersionInfo>> {
+        let index = raw
+            .as_object()
+            .ok_or_else(|| Error::EvalError("zig_index: expected JSON object".into()))?;
+        let date = |entry: &serde_json::Value| {
+            entry
+                .get("date")
+                .and_then(|d| d.as_str())
+                .map(str::to_string)
+        };
+
+        let mut versions: Vec<VersionInfo> = index
+            .iter()
+            .filter(|(v, _)| !v.is_empty() && v.split('.').all(|p| p.parse::<u64>().is_ok()))
+            .map(|(version, entry)| VersionInfo {
+                version: version.clone(),
+                lts: false,
+                stable: true,
+                date: date(entry),
+                release_url: None,
+                release_notes: None,
+            })
+            .collect();
+
+        versions.sort_by_cached_key(|v| {
+            std::cmp::Reverse(
+                v.version
+                    .split('.')
+                    .map(|p| p.parse::<u64>().unwrap_or(0))
+                    .collect::<Vec<_>>(),
+            )
+        });
+
+        if let Some(master) = index.get("master")
+            && let Some(version) = master.get("version").and_then(|v| v.as_str())
+        {
+            versions.insert(
+                0,
+                VersionInfo {
+                    version: version.to_string(),
+                    lts: false,
+                    stable: false,
+                    date: date(master),
+                    release_url: None,
+                    release_notes: None,
+                },
+            );
+        }
+        Ok(versions)
+    }
+
+    /// Transform one .NET channel's `releases.json` into SDK versions
+    ///
+    /// `{"releases": [{"release-date": "2024-11-12", "sdk": {"version": "8.0.404"},
+    /// "sdks": [{"version": "8.0.404"}, {"version": "8.0.307"}]}]}`
+    fn transform_dotnet_channel_releases(raw: &serde_json::Value, lts: bool) -> Vec<VersionInfo> {
+        let mut seen = std::collections::HashSet::new();
+        let mut versions = Vec::new();
+        let releases = raw
+            .get("releases")
+            .and_then(|r| r.as_array())
+            .map(|a| a.as_slice())
+            .unwrap_or(&[]);
+        for release in releases {
+            let date = release
+                .get("release-date")
+                .and_then(|d| d.as_str())
+                .map(str::to_string);
+            let sdks = release
+                .get("sdks")
+                .and_then(|s| s.as_array())
+                .into_iter()
+                .flatten()
+                .chain(release.get("sdk"));
+            for sdk in sdks {
+                let Some(version) = sdk.get("version").and_then(|v| v.as_str()) else {
+                    continue;
+                };
+                if version.is_empty() || !seen.insert(version.to_string()) {
+                    continue;
+                }
+                versions.push(VersionInfo {
+                    version: version.to_string(),
+                    lts,
+                    // Previews and release candidates: 9.0.100-rc.2.24474.11
+                    stable: !version.contains('-'),
+                    date: date.clone(),
+                    release_url: None,
+                    release_notes: None,
+                });
+            }
+        }
+        versions
+    }
+
     /// Transform python-build-standalone GitHub releases API.
     ///
     /// python-build-standalone releases are tagged by date (e.g. `20240107`).
@@ -1126,6 +1519,8 @@ impl StarlarkProvider {
                     stable: true,
                     // Store build_tag in date field — used by download_url via version_date
                     date: Some(build_tag),
+                    release_url: None,
+                    release_notes: None,
                 }
             })
             .collect();
@@ -1157,6 +1552,74 @@ pub(crate) fn build_minimal_runtime_ctx() -> vx_runtime::RuntimeContext {
     RuntimeContext::new(paths, http, fs, installer)
 }
 
+/// `cache_ttl` of an `http_get_json` descriptor in seconds
+///
+/// Accepts a number of seconds or a string such as `"90"`, `"30s"`, `"15m"`,
+/// `"6h"` or `"1d"`.
+fn parse_cache_ttl(value: Option<&serde_json::Value>) -> Result<u64> {
+    let invalid = |value: &dyn std::fmt::Display| {
+        Error::EvalError(format!(
+            "http.get_json: invalid cache_ttl '{}' (use seconds, or e.g. \"15m\", \"6h\", \"1d\")",
+            value
+        ))
+    };
+    match value {
+        None | Some(serde_json::Value::Null) => Ok(DEFAULT_HTTP_GET_JSON_TTL_SECS),
+        Some(serde_json::Value::Number(n)) => n.as_u64().ok_or_else(|| invalid(n)),
+        Some(serde_json::Value::String(s)) => {
+            let s = s.trim();
+            let (digits, unit) =
+                s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()));
+            let n: u64 = digits.parse().map_err(|_| invalid(&s))?;
+            let scale = match unit.trim() {
+                "" | "s" => 1,
+                "m" => 60,
+                "h" => 60 * 60,
+                "d" => 24 * 60 * 60,
+                _ => return Err(invalid(&s)),
+            };
+            Ok(n.saturating_mul(scale))
+        }
+        Some(other) => Err(invalid(other)),
+    }
+}
+
+/// Fetch JSON from `url`, reusing a cached response stored less than `ttl`
+/// seconds ago
+///
+/// The response is cached for `ttl` seconds whatever the server's
+/// `Cache-Control` says; `ttl == 0` always fetches and caches nothing.
+async fn get_json_cached(url: &str, ttl: u64) -> anyhow::Result<serde_json::Value> {
+    let cache = vx_proxy::http_cache::shared().filter(|_| ttl > 0);
+    if let Some(cache) = cache {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        if let HttpCacheLookup::Fresh { entry, body } | HttpCacheLookup::Stale { entry, body } =
+            cache.lookup(url)
+            && now.saturating_sub(entry.stored_at) < ttl
+            && let Ok(value) = serde_json::from_slice(&body)
+        {
+            debug!(url = %url, "http.get_json: using cached response");
+            return Ok(value);
+        }
+    }
+
+    let value = StarlarkHttpClient::new().fetch_json(url).await?;
+    if let Some(cache) = cache {
+        let headers = HttpCacheHeaders {
+            cache_control: Some(format!("max-age={}", ttl)),
+            content_type: Some("application/json".to_string()),
+            ..Default::default()
+        };
+        if let Err(e) = cache.store(url, &serde_json::to_vec(&value)?, &headers) {
+            debug!(url = %url, "http.get_json: failed to cache response: {}", e);
+        }
+    }
+    Ok(value)
+}
+
 /// Lightweight reqwest-based `HttpClient` implementation for Starlark descriptor resolvers.
 ///
 /// Only `get_json_value` is used by `vx-version-fetcher` fetchers.
@@ -1166,7 +1629,7 @@ struct StarlarkHttpClient {
 
 impl StarlarkHttpClient {
     fn new() -> Self {
-        let client = reqwest::Client::builder()
+        let client = vx_proxy::client_builder()
             .user_agent("vx/0.1 (https://github.com/loonghao/vx)")
             .timeout(std::time::Duration::from_secs(30))
             // Force HTTP/1.1 — some GitHub API endpoints return 503 with HTTP/2
@@ -1177,7 +1640,7 @@ impl StarlarkHttpClient {
     }
 
     /// Fetch a URL and return the response body as a JSON Value.
-    /// Adds GitHub token if available and the URL is a GitHub API endpoint.
+    /// Adds the GitHub token or stored registry credentials for the URL.
     /// Retries up to 3 times on 5xx errors.
     async fn fetch_json(&self, url: &str) -> anyhow::Result<serde_json::Value> {
         let mut last_err = anyhow::anyhow!("No attempts made");
@@ -1191,24 +1654,19 @@ impl StarlarkHttpClient {
                     url
                 );
             }
-            let mut req = self
+            let req = self
                 .client
                 .get(url)
                 .header("Accept", "application/vnd.github+json")
                 .header("X-GitHub-Api-Version", "2022-11-28");
-            if url.contains("api.github.com")
-                && let Ok(token) =
-                    std::env::var("GITHUB_TOKEN").or_else(|_| std::env::var("GH_TOKEN"))
-            {
-                req = req.bearer_auth(token);
-            }
-            match req.send().await {
+            let req = vx_proxy::credentials::authorize(req, url);
+            match vx_proxy::http_cache::send(req).await {
                 Ok(response) => {
                     let status = response.status();
                     if status.is_success() {
-                        return Ok(response.json().await?);
+                        return Ok(serde_json::from_slice(response.bytes())?);
                     }
-                    let body = response.text().await.unwrap_or_default();
+                    let body = response.text();
                     last_err = anyhow::anyhow!("HTTP {} from {}: {}", status, url, body);
                     // Only retry on 5xx errors
                     if !status.is_server_error() {
@@ -1227,29 +1685,25 @@ impl StarlarkHttpClient {
 #[async_trait::async_trait]
 impl vx_runtime::HttpClient for StarlarkHttpClient {
     async fn get(&self, url: &str) -> anyhow::Result<String> {
-        let response = self.client.get(url).send().await?;
-        Ok(response.text().await?)
+        let response = vx_proxy::http_cache::send(self.client.get(url)).await?;
+        Ok(response.text())
     }
 
     async fn get_json_value(&self, url: &str) -> anyhow::Result<serde_json::Value> {
-        // Support GITHUB_TOKEN for GitHub API requests
-        let mut req = self
+        // GitHub token or stored registry credentials
+        let req = self
             .client
             .get(url)
             .header("Accept", "application/vnd.github+json")
             .header("X-GitHub-Api-Version", "2022-11-28");
-        if url.contains("api.github.com")
-            && let Ok(token) = std::env::var("GITHUB_TOKEN").or_else(|_| std::env::var("GH_TOKEN"))
-        {
-            req = req.bearer_auth(token);
-        }
-        let response = req.send().await?;
+        let req = vx_proxy::credentials::authorize(req, url);
+        let response = vx_proxy::http_cache::send(req).await?;
         let status = response.status();
         if !status.is_success() {
-            let body = response.text().await.unwrap_or_default();
+            let body = response.text();
             return Err(anyhow::anyhow!("HTTP {} from {}: {}", status, url, body));
         }
-        Ok(response.json().await?)
+        Ok(serde_json::from_slice(response.bytes())?)
     }
 
     async fn download(&self, url: &str, dest: &std::path::Path) -> anyhow::Result<()> {
@@ -69,6 +69,7 @@ pub fn provider_lint_known_globals() -> HashSet<String> {
         "description",
         "homepage",
         "repository",
+        "changelog",
         "license",
         "ecosystem",
         "runtimes",
@@ -76,7 +77,11 @@ pub fn provider_lint_known_globals() -> HashSet<String> {
         "requires",
         "package_alias",
         "package_prefixes",
+        "tool_bundle",
         "vx_version",
+        "eol",
+        "osv",
+        "version_format",
         // Common function names in provider.star
         "fetch_versions",
         "download_url",
@@ -21,7 +21,10 @@
 //! via `SandboxConfig::from_permissions()`.
 
 use anyhow::Result;
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::{LazyLock, RwLock};
 use std::time::Duration;
 
 /// Declarative permissions declared in provider.star
@@ -48,6 +51,31 @@ pub struct PermissionsDecl {
     pub exec: Vec<String>,
 }
 
+impl PermissionsDecl {
+    /// Parse the evaluated `permissions` variable of a provider.star
+    ///
+    /// Unknown keys and non-string entries are ignored.
+    pub fn from_json(value: &JsonValue) -> Self {
+        let list = |key: &str| -> Vec<String> {
+            value
+                .get(key)
+                .and_then(|v| v.as_array())
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter_map(|i| i.as_str().map(str::to_string))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        Self {
+            fs: list("fs"),
+            http: list("http"),
+            exec: list("exec"),
+        }
+    }
+}
+
 /// Sandbox configuration for Starlark script execution
 #[derive(Clone, Debug)]
 pub struct SandboxConfig {
@@ -303,6 +331,41 @@ impl SandboxConfig {
     }
 }
 
+/// Sandboxes registered for specific provider scripts, keyed by content hash.
+///
+/// Built-in providers run with [`SandboxConfig::default`]; user providers
+/// discovered on disk register the sandbox derived from their `permissions`
+/// so that every `StarlarkProvider` built from that script — including the
+/// ones created lazily by the runtime bridges — enforces it.
+static SCRIPT_SANDBOXES: LazyLock<RwLock<HashMap<[u8; 32], SandboxConfig>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Register the sandbox to apply whenever `content` is loaded as a provider
+pub fn register_script_sandbox(content: &str, sandbox: SandboxConfig) {
+    let hash = crate::provider::script_hash(content);
+    if let Ok(mut map) = SCRIPT_SANDBOXES.write() {
+        map.insert(hash, sandbox);
+    }
+}
+
+/// Sandbox registered for a script hash, or the default sandbox
+pub(crate) fn sandbox_for_script(hash: &[u8; 32]) -> SandboxConfig {
+    SCRIPT_SANDBOXES
+        .read()
+        .ok()
+        .and_then(|map| map.get(hash).cloned())
+        .unwrap_or_default()
+}
+
+/// Extract the host part of a URL (`https://user@host:443/path` → `host`)
+pub(crate) fn url_host(url: &str) -> Option<&str> {
+    let rest = url.split_once("://").map(|(_, r)| r)?;
+    let authority = rest.split(['/', '?', '#']).next()?;
+    let host_port = authority.rsplit('@').next()?;
+    let host = host_port.split(':').next()?;
+    (!host.is_empty()).then_some(host)
+}
+
 /// Expand `~` to the user's home directory
 fn expand_home_dir(path: &str) -> PathBuf {
     if (path.starts_with("~/") || path == "~")
@@ -47,6 +47,12 @@ def fetch_json_versions(_ctx, _url, _kind):
 def fetch_versions_from_api(_ctx, _url, _kind):
     """Mock: returns an empty descriptor."""
     return {"kind": _kind, "url": _url}
+
+def _get_json(url, cache_ttl = "1h", then = None):
+    """Mock: returns the http_get_json descriptor."""
+    return {"__type": "http_get_json", "url": url, "cache_ttl": cache_ttl, "then": then}
+
+http = struct(get_json = _get_json)
 "#;
 
 /// Standard mock for @vx//stdlib:github.star
@@ -120,6 +126,9 @@ def flatten_dir(pattern = None, keep_subdirs = None):
     if keep_subdirs != None:
         result["keep_subdirs"] = keep_subdirs
     return result
+
+def write_file(path, content, overwrite = False):
+    return {"__type": "write_file", "path": path, "content": content, "overwrite": overwrite}
 "#;
 
 /// Standard mock for @vx//stdlib:provider.star
@@ -183,6 +192,9 @@ def dep_def(runtime, version = "*", optional = False, reason = None,
     return result
 
 # --- platform.star ---
+def is_musl(ctx):
+    return ctx.platform.os == "linux" and getattr(ctx.platform, "libc", "") == "musl"
+
 def platform_map(ctx, mapping):
     key = ctx.platform.os + "/" + ctx.platform.arch
     return mapping.get(key)
@@ -340,6 +352,15 @@ def dnf_install(package, **kwargs):
 def pacman_install(package, **kwargs):
     return pkg_strategy("pacman", package, **kwargs)
 
+def zypper_install(package, **kwargs):
+    return pkg_strategy("zypper", package, **kwargs)
+
+def apk_install(package, **kwargs):
+    return pkg_strategy("apk", package, **kwargs)
+
+def nix_install(package, **kwargs):
+    return pkg_strategy("nix", package, **kwargs)
+
 def snap_install(package, **kwargs):
     return pkg_strategy("snap", package, **kwargs)
 
@@ -349,6 +370,9 @@ def cross_platform_install(**kwargs):
 def windows_install(**kwargs):
     return kwargs
 
+def system_managed_install(**kwargs):
+    return kwargs
+
 def multi_platform_install(**kwargs):
     return kwargs
 
@@ -376,6 +400,8 @@ def _mock_archive_ext(ctx):
     return "zip" if ctx.platform.os == "windows" else "tar.gz"
 
 def _mock_rust_triple(ctx, linux_libc = "musl"):
+    if is_musl(ctx):
+        linux_libc = "musl"
     return {
         "windows/x64": "x86_64-pc-windows-msvc",
         "windows/arm64": "aarch64-pc-windows-msvc",
@@ -662,6 +688,9 @@ def fetch_versions_with_tag_prefix(owner, repo, tag_prefix = "v", prereleases =
 
 /// Standard mock for @vx//stdlib:platform.star
 pub const MOCK_PLATFORM_STAR: &str = r#"
+def is_musl(ctx):
+    return ctx.platform.os == "linux" and getattr(ctx.platform, "libc", "") == "musl"
+
 def exe_suffix(ctx):
     return ".exe" if ctx.platform.os == "windows" else ""
 
@@ -669,6 +698,8 @@ def archive_ext(ctx):
     return "zip" if ctx.platform.os == "windows" else "tar.gz"
 
 def rust_triple(ctx, linux_libc = "musl"):
+    if is_musl(ctx):
+        linux_libc = "musl"
     key = ctx.platform.os + "/" + ctx.platform.arch
     mapping = {
         "windows/x64": "x86_64-pc-windows-msvc",
@@ -913,6 +944,11 @@ def fetch_json_versions(_ctx, _url, _kind):
 def fetch_versions_from_api(url, kind):
     return {{"url": url, "kind": kind}}
 
+def _get_json(url, cache_ttl = "1h", then = None):
+    return {{"__type": "http_get_json", "url": url, "cache_ttl": cache_ttl, "then": then}}
+
+http = struct(get_json = _get_json)
+
 # --- runtime.star ---
 def runtime_def(name, executable = None, aliases = None, description = None,
                 priority = 100, auto_installable = None, platform_constraint = None,
@@ -1002,8 +1038,14 @@ def flatten_dir(pattern = None, keep_subdirs = None):
         result["keep_subdirs"] = keep_subdirs
     return result
 
+def write_file(path, content, overwrite = False):
+    return {{"__type": "write_file", "path": path, "content": content, "overwrite": overwrite}}
+
 # --- platform.star ---
 
+def is_musl(ctx):
+    return ctx.platform.os == "linux" and getattr(ctx.platform, "libc", "") == "musl"
+
 def platform_map(ctx, mapping):
     key = ctx.platform.os + "/" + ctx.platform.arch
     return mapping.get(key)
@@ -1020,6 +1062,8 @@ def platform_select(ctx, windows = None, macos = None, linux = None, default = N
 
 def rust_triple(ctx, linux_libc = "musl"):
     """Mock: returns the Rust target triple for the current platform."""
+    if is_musl(ctx):
+        linux_libc = "musl"
     return {{
         "windows/x64":   "x86_64-pc-windows-msvc",
         "windows/arm64": "aarch64-pc-windows-msvc",
@@ -1138,6 +1182,15 @@ def dnf_install(package, **kwargs):
 def pacman_install(package, **kwargs):
     return pkg_strategy("pacman", package, **kwargs)
 
+def zypper_install(package, **kwargs):
+    return pkg_strategy("zypper", package, **kwargs)
+
+def apk_install(package, **kwargs):
+    return pkg_strategy("apk", package, **kwargs)
+
+def nix_install(package, **kwargs):
+    return pkg_strategy("nix", package, **kwargs)
+
 def snap_install(package, **kwargs):
     return pkg_strategy("snap", package, **kwargs)
 
@@ -1147,6 +1200,9 @@ def cross_platform_install(**kwargs):
 def windows_install(**kwargs):
     return kwargs
 
+def system_managed_install(**kwargs):
+    return kwargs
+
 def multi_platform_install(**kwargs):
     return kwargs
 
@@ -1174,6 +1230,8 @@ def _mock_archive_ext(ctx):
     return "zip" if ctx.platform.os == "windows" else "tar.gz"
 
 def _mock_rust_triple(ctx, linux_libc = "musl"):
+    if is_musl(ctx):
+        linux_libc = "musl"
     return {{
         "windows/x64": "x86_64-pc-windows-msvc",
         "windows/arm64": "aarch64-pc-windows-msvc",
@@ -0,0 +1,230 @@
+//! User provider discovery (hot-loaded `provider.star` files)
+//!
+//! Any directory containing a `provider.star` dropped into a providers root
+//! (`~/.vx/providers/<name>/provider.star`, `<project>/.vx/providers/...`)
+//! is picked up at startup and registered as a full provider — versions,
+//! download URL and install layout all come from the script — without a
+//! dedicated crate.
+//!
+//! `vx provider dev` links a script in place instead: a
+//! `~/.vx/providers/.dev/<name>.path` file holds the path of a provider.star
+//! being worked on, and [`discover_dev_providers`] reads the script from there.
+//!
+//! User scripts are untrusted, so each one is:
+//! 1. **Validated** — it must evaluate cleanly and define `fetch_versions`
+//!    and `download_url`.
+//! 2. **Sandboxed** — its `permissions` declaration is turned into a
+//!    [`SandboxConfig`] via [`SandboxConfig::from_permissions`] and registered
+//!    with [`register_script_sandbox`], so every provider instance built from
+//!    the script enforces it.
+
+use crate::PROVIDER_FILENAME;
+use crate::engine::StarlarkEngine;
+use crate::error::{Error, Result};
+use crate::sandbox::{PermissionsDecl, SandboxConfig, register_script_sandbox};
+use std::path::{Path, PathBuf};
+use tracing::{debug, warn};
+
+/// Globals every user provider must define
+pub const REQUIRED_FUNCTIONS: &[&str] = &["fetch_versions", "download_url"];
+
+/// Directory inside a providers root holding `vx provider dev` links
+pub const DEV_LINKS_DIR: &str = ".dev";
+
+/// A validated, sandboxed user provider
+#[derive(Debug, Clone)]
+pub struct UserProvider {
+    /// Provider name (the directory name)
+    pub name: String,
+    /// Path of the `provider.star` file
+    pub path: PathBuf,
+    /// Script content
+    pub content: String,
+    /// Permissions declared by the script
+    pub permissions: PermissionsDecl,
+    /// Sandbox derived from the declared permissions
+    pub sandbox: SandboxConfig,
+    /// Whether the script defines `install_layout` (otherwise the default layout is used)
+    pub has_install_layout: bool,
+}
+
+/// A user provider script that was skipped during discovery
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedUserProvider {
+    /// Provider name (the directory name)
+    pub name: String,
+    /// Path of the `provider.star` file
+    pub path: PathBuf,
+    /// Why the script was skipped
+    pub error: String,
+}
+
+/// Validate a user provider script and derive its sandbox
+pub fn validate_user_provider(
+    name: impl Into<String>,
+    path: impl Into<PathBuf>,
+    content: impl Into<String>,
+) -> Result<UserProvider> {
+    let name = name.into();
+    let path = path.into();
+    let content = content.into();
+
+    let engine = StarlarkEngine::new();
+    let globals = engine.defined_globals(&path, &content)?;
+
+    if let Some(missing) = REQUIRED_FUNCTIONS
+        .iter()
+        .find(|f| !globals.iter().any(|g| g == *f))
+    {
+        return Err(Error::function_not_found(*missing));
+    }
+
+    let permissions = engine
+        .get_variable(&path, &content, "permissions")?
+        .map(|v| PermissionsDecl::from_json(&v))
+        .unwrap_or_default();
+    let sandbox = SandboxConfig::from_permissions(&permissions)
+        .map_err(|e| Error::InvalidConfig(e.to_string()))?;
+
+    Ok(UserProvider {
+        name,
+        path,
+        content,
+        permissions,
+        sandbox,
+        has_install_layout: globals.iter().any(|g| g == "install_layout"),
+    })
+}
+
+/// Discover and validate user providers in the given roots.
+///
+/// Each root is scanned for `<name>/provider.star`. Roots are processed in
+/// order; invalid scripts are skipped with a warning. Valid providers have
+/// their sandbox registered before being returned.
+pub fn discover_user_providers(roots: &[PathBuf]) -> Vec<UserProvider> {
+    discover_user_providers_checked(roots).0
+}
+
+/// Like [`discover_user_providers`], also returning the skipped scripts
+pub fn discover_user_providers_checked(
+    roots: &[PathBuf],
+) -> (Vec<UserProvider>, Vec<SkippedUserProvider>) {
+    partition_loaded(
+        roots
+            .iter()
+            .flat_map(|root| provider_scripts(root))
+            .map(|(name, path)| load_user_provider(name, path)),
+    )
+}
+
+/// Link file of the dev provider `name` in `links_dir`
+pub fn dev_link_file(links_dir: &Path, name: &str) -> PathBuf {
+    links_dir.join(format!("{}.path", name))
+}
+
+/// Discover and validate the providers linked by `vx provider dev`.
+///
+/// Each `<links_dir>/<name>.path` holds the path of a provider.star, which is
+/// read from where its author edits it. Links whose script no longer exists
+/// are skipped, like invalid scripts.
+pub fn discover_dev_providers(links_dir: &Path) -> Vec<UserProvider> {
+    discover_dev_providers_checked(links_dir).0
+}
+
+/// Like [`discover_dev_providers`], also returning the skipped scripts
+pub fn discover_dev_providers_checked(
+    links_dir: &Path,
+) -> (Vec<UserProvider>, Vec<SkippedUserProvider>) {
+    let Ok(entries) = std::fs::read_dir(links_dir) else {
+        return (Vec::new(), Vec::new());
+    };
+    let mut links: Vec<(String, PathBuf)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let link = entry.path();
+            if link.extension().is_none_or(|ext| ext != "path") {
+                return None;
+            }
+            let name = link.file_stem()?.to_string_lossy().to_string();
+            let target = std::fs::read_to_string(&link).ok()?;
+            Some((name, PathBuf::from(target.trim())))
+        })
+        .collect();
+    links.sort();
+    partition_loaded(
+        links
+            .into_iter()
+            .map(|(name, path)| load_user_provider(name, path)),
+    )
+}
+
+fn partition_loaded(
+    results: impl Iterator<Item = std::result::Result<UserProvider, SkippedUserProvider>>,
+) -> (Vec<UserProvider>, Vec<SkippedUserProvider>) {
+    let mut providers = Vec::new();
+    let mut skipped = Vec::new();
+    for result in results {
+        match result {
+            Ok(provider) => providers.push(provider),
+            Err(skip) => skipped.push(skip),
+        }
+    }
+    (providers, skipped)
+}
+
+/// Read and validate one user provider, registering its sandbox
+///
+/// Failures are logged and returned as a [`SkippedUserProvider`].
+fn load_user_provider(
+    name: String,
+    path: PathBuf,
+) -> std::result::Result<UserProvider, SkippedUserProvider> {
+    let content = match std::fs::read_to_string(&path) {
+        Ok(c) => c,
+        Err(e) => {
+            warn!(provider = %name, path = %path.display(), error = %e, "Failed to read user provider");
+            return Err(SkippedUserProvider {
+                name,
+                path,
+                error: format!("failed to read provider.star: {}", e),
+            });
+        }
+    };
+    match validate_user_provider(&name, &path, content) {
+        Ok(provider) => {
+            register_script_sandbox(&provider.content, provider.sandbox.clone());
+            debug!(provider = %name, path = %path.display(), "Discovered user provider");
+            Ok(provider)
+        }
+        Err(e) => {
+            warn!(
+                provider = %name,
+                path = %path.display(),
+                error = %e,
+                "Skipping invalid user provider"
+            );
+            Err(SkippedUserProvider {
+                name,
+                path,
+                error: e.to_string(),
+            })
+        }
+    }
+}
+
+/// `(name, path)` of every `<root>/<name>/provider.star`, sorted by name
+fn provider_scripts(root: &Path) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return Vec::new();
+    };
+    let mut scripts: Vec<(String, PathBuf)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path().join(PROVIDER_FILENAME);
+            path.is_file()
+                .then(|| (entry.file_name().to_string_lossy().to_string(), path))
+        })
+        .collect();
+    scripts.sort();
+    scripts
+}
@@ -0,0 +1,98 @@
+//! Tests for the on-disk Starlark analysis cache
+
+use vx_starlark::{AnalysisDiskCache, StarlarkProvider};
+
+const CONTENT: &str = r#"name = "cached-tool"
+description = "Cached tool"
+runtimes = [{"name": "cached-tool", "executable": "ct", "aliases": ["cached"]}]
+"#;
+
+async fn analyzed() -> StarlarkProvider {
+    StarlarkProvider::from_content("cached-tool", CONTENT)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_disk_cache_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = AnalysisDiskCache::new(dir.path());
+    let provider = analyzed().await;
+
+    assert!(cache.get(provider.script_hash()).is_none());
+    cache.put(provider.script_hash(), provider.meta(), provider.runtimes());
+    assert_eq!(cache.entry_count(), 1);
+    assert!(
+        dir.path()
+            .join(format!("{}.json", provider.script_hash_hex()))
+            .exists()
+    );
+
+    let (meta, runtimes) = cache.get(provider.script_hash()).unwrap();
+    assert_eq!(meta.name, "cached-tool");
+    assert_eq!(meta.description, "Cached tool");
+    assert_eq!(runtimes.len(), 1);
+    assert_eq!(runtimes[0].executable, "ct");
+    assert_eq!(runtimes[0].aliases, ["cached"]);
+}
+
+#[tokio::test]
+async fn test_disk_cache_discards_entries_from_other_vx_versions() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = AnalysisDiskCache::new(dir.path());
+    let provider = analyzed().await;
+    cache.put(provider.script_hash(), provider.meta(), provider.runtimes());
+
+    let path = dir
+        .path()
+        .join(format!("{}.json", provider.script_hash_hex()));
+    let mut entry: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(entry["vx_version"], env!("CARGO_PKG_VERSION"));
+    entry["vx_version"] = "0.0.1".into();
+    std::fs::write(&path, entry.to_string()).unwrap();
+
+    assert!(cache.get(provider.script_hash()).is_none());
+    assert!(!path.exists(), "stale entry should be removed");
+}
+
+#[tokio::test]
+async fn test_disk_cache_discards_unreadable_entries() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = AnalysisDiskCache::new(dir.path());
+    let provider = analyzed().await;
+
+    let path = dir
+        .path()
+        .join(format!("{}.json", provider.script_hash_hex()));
+    std::fs::write(&path, "{ not json").unwrap();
+
+    assert!(cache.get(provider.script_hash()).is_none());
+    assert!(!path.exists());
+}
+
+#[tokio::test]
+async fn test_disk_cache_remove_and_clear() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = AnalysisDiskCache::new(dir.path().join("starlark"));
+    // Clearing a cache that was never written is a no-op
+    assert_eq!(cache.clear(), 0);
+
+    let provider = analyzed().await;
+    let other = StarlarkProvider::from_content(
+        "other-tool",
+        "name = \"other-tool\"\nruntimes = [{\"name\": \"other-tool\", \"executable\": \"ot\"}]\n",
+    )
+    .await
+    .unwrap();
+    cache.put(provider.script_hash(), provider.meta(), provider.runtimes());
+    cache.put(other.script_hash(), other.meta(), other.runtimes());
+    assert_eq!(cache.entry_count(), 2);
+
+    cache.remove(provider.script_hash());
+    assert!(cache.get(provider.script_hash()).is_none());
+    assert!(cache.get(other.script_hash()).is_some());
+
+    assert_eq!(cache.clear(), 1);
+    assert_eq!(cache.entry_count(), 0);
+}
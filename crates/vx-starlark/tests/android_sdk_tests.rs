@@ -0,0 +1,79 @@
+//! Tests for the `android-sdk` provider (sdkmanager / avdmanager)
+
+use vx_starlark::StarlarkEngine;
+use vx_starlark::StarlarkProvider;
+
+fn star_path() -> std::path::PathBuf {
+    std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent() // crates/
+        .unwrap()
+        .join("vx-providers")
+        .join("android-sdk")
+        .join("provider.star")
+}
+
+fn call(
+    function: &str,
+    os: &str,
+    vx_home: &std::path::Path,
+    args: &[serde_json::Value],
+) -> serde_json::Value {
+    let path = star_path();
+    let content = std::fs::read_to_string(&path).unwrap();
+    let mut ctx = vx_starlark::ProviderContext::new("android-sdk", vx_home.to_path_buf());
+    ctx.platform.os = os.to_string();
+    ctx.platform.arch = "x64".to_string();
+    StarlarkEngine::new()
+        .call_function(&path, &content, function, &ctx, args)
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_load_android_sdk_provider() {
+    let provider = StarlarkProvider::load(&star_path()).await.unwrap();
+    assert_eq!(provider.name(), "android-sdk");
+}
+
+#[test]
+fn test_android_sdk_download_urls() {
+    let home = std::env::temp_dir().join("vx-test");
+    let version = [serde_json::json!("19.0")];
+    assert_eq!(
+        call("download_url", "linux", &home, &version),
+        "https://dl.google.com/android/repository/commandlinetools-linux-13114758_latest.zip"
+    );
+    assert_eq!(
+        call("download_url", "macos", &home, &version),
+        "https://dl.google.com/android/repository/commandlinetools-mac-13114758_latest.zip"
+    );
+    assert_eq!(
+        call("download_url", "windows", &home, &version),
+        "https://dl.google.com/android/repository/commandlinetools-win-13114758_latest.zip"
+    );
+    assert!(call("download_url", "linux", &home, &[serde_json::json!("2.0")]).is_null());
+}
+
+#[test]
+fn test_android_sdk_fetch_versions_newest_first() {
+    let home = std::env::temp_dir().join("vx-test");
+    let versions = call("fetch_versions", "linux", &home, &[]);
+    let versions = versions.as_array().unwrap();
+    assert_eq!(versions[0]["version"], "19.0");
+    assert!(versions.iter().any(|v| v["version"] == "9.0"));
+}
+
+#[test]
+fn test_android_sdk_environment_sets_android_home() {
+    let home = std::env::temp_dir().join("vx-test");
+    let env = call("environment", "linux", &home, &[serde_json::json!("19.0")]);
+    let sdk_root = format!("{}/android/sdk", home.display());
+    let value = |key: &str| {
+        env.as_array()
+            .unwrap()
+            .iter()
+            .find(|op| op["key"] == key)
+            .map(|op| op["value"].as_str().unwrap().to_string())
+    };
+    assert_eq!(value("ANDROID_HOME"), Some(sdk_root.clone()));
+    assert_eq!(value("ANDROID_SDK_ROOT"), Some(sdk_root));
+}
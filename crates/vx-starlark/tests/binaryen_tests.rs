@@ -0,0 +1,68 @@
+//! Tests for the `binaryen` provider (wasm-opt and friends).
+//!
+//! Verifies the version_123 GitHub release asset layout and archive descriptors.
+
+use vx_starlark::{StarlarkEngine, StarlarkProvider};
+
+fn load_provider_content(provider_name: &str) -> (std::path::PathBuf, String) {
+    let manifest_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let provider_dir = manifest_dir
+        .parent()
+        .unwrap()
+        .join("vx-providers")
+        .join(provider_name);
+    let star_path = provider_dir.join("provider.star");
+    let content = std::fs::read_to_string(&star_path).unwrap();
+    (star_path, content)
+}
+
+fn call(function: &str, os: &str, arch: &str) -> serde_json::Value {
+    let (star_path, content) = load_provider_content("binaryen");
+    let mut ctx =
+        vx_starlark::ProviderContext::new("binaryen", std::env::temp_dir().join("vx-test"));
+    ctx.platform.os = os.to_string();
+    ctx.platform.arch = arch.to_string();
+    StarlarkEngine::new()
+        .call_function(
+            &star_path,
+            &content,
+            function,
+            &ctx,
+            &[serde_json::json!("123")],
+        )
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_load_binaryen_provider() {
+    let (star_path, _) = load_provider_content("binaryen");
+    let provider = StarlarkProvider::load(&star_path).await.unwrap();
+    assert_eq!(provider.name(), "binaryen");
+}
+
+#[test]
+fn test_binaryen_download_urls() {
+    assert_eq!(
+        call("download_url", "linux", "x64"),
+        "https://github.com/WebAssembly/binaryen/releases/download/version_123/binaryen-version_123-x86_64-linux.tar.gz"
+    );
+    assert_eq!(
+        call("download_url", "macos", "arm64"),
+        "https://github.com/WebAssembly/binaryen/releases/download/version_123/binaryen-version_123-arm64-macos.tar.gz"
+    );
+    assert_eq!(
+        call("download_url", "windows", "x64"),
+        "https://github.com/WebAssembly/binaryen/releases/download/version_123/binaryen-version_123-x86_64-windows.tar.gz"
+    );
+    assert!(call("download_url", "windows", "arm64").is_null());
+}
+
+#[test]
+fn test_binaryen_install_layout_strips_archive_directory() {
+    let result = call("install_layout", "linux", "arm64");
+    let layout = result.as_object().unwrap();
+
+    assert_eq!(layout["__type"], "archive");
+    assert_eq!(layout["strip_prefix"], "binaryen-version_123");
+    assert_eq!(layout["executable_paths"][0], "bin/wasm-opt");
+}
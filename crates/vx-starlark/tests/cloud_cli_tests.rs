@@ -0,0 +1,145 @@
+//! Tests for the cloud CLI providers (`awscli`, `gcloud`, `azcli`).
+//!
+//! Verifies the per-platform installer formats, install layouts and the
+//! post-extract actions that finish their installation.
+
+use vx_starlark::{StarlarkEngine, StarlarkProvider};
+
+fn load_provider_content(provider_name: &str) -> (std::path::PathBuf, String) {
+    let manifest_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let provider_dir = manifest_dir
+        .parent()
+        .unwrap()
+        .join("vx-providers")
+        .join(provider_name);
+    let star_path = provider_dir.join("provider.star");
+    let content = std::fs::read_to_string(&star_path).unwrap();
+    (star_path, content)
+}
+
+fn call(
+    provider: &str,
+    function: &str,
+    os: &str,
+    arch: &str,
+    args: &[serde_json::Value],
+) -> serde_json::Value {
+    let (star_path, content) = load_provider_content(provider);
+    let mut ctx = vx_starlark::ProviderContext::new(provider, std::env::temp_dir().join("vx-test"));
+    ctx.platform.os = os.to_string();
+    ctx.platform.arch = arch.to_string();
+    StarlarkEngine::new()
+        .call_function(&star_path, &content, function, &ctx, args)
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_load_cloud_cli_providers() {
+    for name in ["awscli", "gcloud", "azcli"] {
+        let (star_path, _) = load_provider_content(name);
+        let provider = StarlarkProvider::load(&star_path).await.unwrap();
+        assert_eq!(provider.name(), name);
+    }
+}
+
+#[test]
+fn test_awscli_download_urls() {
+    let version = [serde_json::json!("2.15.0")];
+    assert_eq!(
+        call("awscli", "download_url", "linux", "arm64", &version),
+        "https://awscli.amazonaws.com/awscli-exe-linux-aarch64-2.15.0.zip"
+    );
+    assert_eq!(
+        call("awscli", "download_url", "macos", "arm64", &version),
+        "https://awscli.amazonaws.com/AWSCLIV2-2.15.0.pkg"
+    );
+    assert!(call("awscli", "download_url", "windows", "x64", &version).is_null());
+}
+
+#[test]
+fn test_awscli_install_layouts() {
+    let version = [serde_json::json!("2.15.0")];
+
+    let linux = call("awscli", "install_layout", "linux", "x64", &version);
+    assert_eq!(linux["__type"], "archive");
+    assert_eq!(linux["strip_prefix"], "aws");
+    assert_eq!(linux["executable_paths"][0], "dist/aws");
+
+    // The pkg handler promotes the payload, leaving aws-cli/ at the root
+    let macos = call("awscli", "install_layout", "macos", "x64", &version);
+    assert!(macos.get("strip_prefix").is_none());
+    assert_eq!(macos["executable_paths"][0], "aws-cli/aws");
+
+    assert!(call("awscli", "install_layout", "windows", "x64", &version).is_null());
+}
+
+#[test]
+fn test_awscli_post_extract_sets_permissions() {
+    let args = [
+        serde_json::json!("2.15.0"),
+        serde_json::json!("/opt/vx/store/awscli/2.15.0"),
+    ];
+    let actions = call("awscli", "post_extract", "macos", "arm64", &args);
+    let paths: Vec<&str> = actions
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|a| a["__type"] == "set_permissions")
+        .map(|a| a["path"].as_str().unwrap())
+        .collect();
+    assert_eq!(paths, ["aws-cli/aws", "aws-cli/aws_completer"]);
+}
+
+#[test]
+fn test_gcloud_post_extract_runs_installer_in_install_dir() {
+    let args = [
+        serde_json::json!("500.0.0"),
+        serde_json::json!("/opt/vx/store/gcloud/500.0.0"),
+    ];
+    let actions = call("gcloud", "post_extract", "linux", "x64", &args);
+    let action = &actions[0];
+
+    assert_eq!(action["__type"], "run_command");
+    assert_eq!(
+        action["executable"],
+        "/opt/vx/store/gcloud/500.0.0/install.sh"
+    );
+    assert_eq!(action["working_dir"], "/opt/vx/store/gcloud/500.0.0");
+    assert_eq!(action["env"]["CLOUDSDK_CORE_DISABLE_PROMPTS"], "1");
+    assert_eq!(action["on_failure"], "warn");
+    let args: Vec<&str> = action["args"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| a.as_str().unwrap())
+        .collect();
+    assert!(args.contains(&"--quiet"));
+    assert!(args.windows(2).any(|w| w == ["--path-update", "false"]));
+
+    let windows_args = [
+        serde_json::json!("500.0.0"),
+        serde_json::json!("C:/vx/store/gcloud/500.0.0"),
+    ];
+    let windows = call("gcloud", "post_extract", "windows", "x64", &windows_args);
+    assert_eq!(
+        windows[0]["executable"],
+        "C:/vx/store/gcloud/500.0.0/install.bat"
+    );
+}
+
+#[test]
+fn test_azcli_windows_zip() {
+    let version = [serde_json::json!("2.60.0")];
+    assert_eq!(
+        call("azcli", "download_url", "windows", "x64", &version),
+        "https://azcliprod.blob.core.windows.net/zip/azure-cli-2.60.0-x64.zip"
+    );
+    let layout = call("azcli", "install_layout", "windows", "x64", &version);
+    assert_eq!(layout["executable_paths"][0], "bin/az.cmd");
+
+    // Releases before the ZIP package, and other platforms, use system_install
+    let old = [serde_json::json!("2.50.0")];
+    assert!(call("azcli", "download_url", "windows", "x64", &old).is_null());
+    assert!(call("azcli", "download_url", "linux", "x64", &version).is_null());
+    assert!(call("azcli", "install_layout", "macos", "arm64", &version).is_null());
+}
@@ -0,0 +1,31 @@
+//! Tests for `constraints = [...]` parsing
+
+use vx_starlark::star_constraints;
+
+#[test]
+fn test_star_constraints_default_runtime() {
+    let content = r#"
+name = "demo"
+runtimes = [{"name": "demo", "executable": "demo"}]
+
+constraints = [
+    {
+        "when": ">=2",
+        "requires": [{"runtime": "node", "version": ">=18", "reason": "needs node"}],
+    },
+]
+"#;
+    let rules = star_constraints("demo", content);
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].0, "demo");
+    assert_eq!(rules[0].1.len(), 1);
+}
+
+#[test]
+fn test_star_constraints_absent() {
+    let content = r#"
+name = "demo"
+runtimes = [{"name": "demo", "executable": "demo"}]
+"#;
+    assert!(star_constraints("demo", content).is_empty());
+}
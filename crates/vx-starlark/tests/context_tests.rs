@@ -1,6 +1,7 @@
 //! ProviderContext tests for vx-starlark
 
 use std::path::PathBuf;
+use vx_runtime::{Arch, Os};
 use vx_starlark::context::{InstallResult, PathManager, PlatformInfo, VersionInfo};
 use vx_starlark::{ProviderContext, SandboxConfig};
 
@@ -35,6 +36,46 @@ fn test_platform_info_arch_is_known_value() {
     );
 }
 
+#[test]
+fn test_platform_info_windows_arm64() {
+    let platform =
+        PlatformInfo::from_platform(&vx_runtime::Platform::new(Os::Windows, Arch::Aarch64));
+    assert_eq!(platform.os, "windows");
+    assert_eq!(platform.arch, "arm64");
+    assert_eq!(platform.target, "aarch64-pc-windows-msvc");
+}
+
+#[test]
+fn test_platform_info_linux_libc() {
+    use vx_runtime::{Libc, Platform};
+
+    let alpine =
+        PlatformInfo::from_platform(&Platform::with_libc(Os::Linux, Arch::X86_64, Libc::Musl));
+    assert_eq!(alpine.libc, "musl");
+    assert_eq!(alpine.target, "x86_64-unknown-linux-musl");
+
+    let debian =
+        PlatformInfo::from_platform(&Platform::with_libc(Os::Linux, Arch::Aarch64, Libc::Gnu));
+    assert_eq!(debian.libc, "gnu");
+    assert_eq!(debian.target, "aarch64-unknown-linux-gnu");
+
+    let mac =
+        PlatformInfo::from_platform(&Platform::with_libc(Os::MacOS, Arch::Aarch64, Libc::Musl));
+    assert_eq!(mac.libc, "");
+    assert_eq!(mac.target, "aarch64-apple-darwin");
+}
+
+#[test]
+fn test_platform_info_emulation_fallback() {
+    let arm64 = PlatformInfo::from_platform(&vx_runtime::Platform::new(Os::Windows, Arch::Aarch64));
+    let fallback = arm64.emulation_fallback().expect("x64 fallback");
+    assert_eq!(fallback.arch, "x64");
+    assert_eq!(fallback.target, "x86_64-pc-windows-msvc");
+
+    let mac = PlatformInfo::from_platform(&vx_runtime::Platform::new(Os::MacOS, Arch::Aarch64));
+    assert!(mac.emulation_fallback().is_none());
+}
+
 // ============================================================
 // VersionInfo tests
 // ============================================================
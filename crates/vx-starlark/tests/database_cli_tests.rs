@@ -0,0 +1,177 @@
+//! Database CLI provider tests (sqlite, postgresql, mysqlsh)
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use vx_starlark::StarlarkEngine;
+use vx_starlark::StarlarkProvider;
+
+fn star_path(provider_name: &str) -> std::path::PathBuf {
+    std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent() // crates/
+        .unwrap()
+        .join("vx-providers")
+        .join(provider_name)
+        .join("provider.star")
+}
+
+fn call(
+    provider_name: &str,
+    function: &str,
+    os: &str,
+    arch: &str,
+    version: &str,
+) -> serde_json::Value {
+    let path = star_path(provider_name);
+    let content = std::fs::read_to_string(&path).unwrap();
+    let mut ctx =
+        vx_starlark::ProviderContext::new(provider_name, std::env::temp_dir().join("vx-test"));
+    ctx.platform.os = os.to_string();
+    ctx.platform.arch = arch.to_string();
+    StarlarkEngine::new()
+        .call_function(
+            &path,
+            &content,
+            function,
+            &ctx,
+            &[serde_json::json!(version)],
+        )
+        .unwrap()
+}
+
+/// Serve the `content_type` `body` for every request on a local port,
+/// returning the base URL
+fn serve(content_type: &'static str, body: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { continue };
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            // Skip the request line and headers
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok_and(|n| n > 2) {
+                line.clear();
+            }
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                content_type,
+                body.len(),
+                body
+            );
+        }
+    });
+    base
+}
+
+#[tokio::test]
+async fn test_load_database_cli_providers() {
+    for name in ["sqlite", "postgresql", "mysqlsh"] {
+        let provider = StarlarkProvider::load(&star_path(name)).await.unwrap();
+        assert_eq!(provider.name(), name);
+    }
+}
+
+#[test]
+fn test_sqlite_download_urls() {
+    assert_eq!(
+        call("sqlite", "download_url", "linux", "x64", "3.46.1"),
+        "https://www.sqlite.org/2024/sqlite-tools-linux-x64-3460100.zip"
+    );
+    assert_eq!(
+        call("sqlite", "download_url", "macos", "x64", "3.44.2"),
+        "https://www.sqlite.org/2023/sqlite-tools-osx-x64-3440200.zip"
+    );
+    // No official arm64 Linux build: falls back to system_install
+    assert!(call("sqlite", "download_url", "linux", "arm64", "3.46.1").is_null());
+}
+
+#[test]
+fn test_postgresql_download_url_and_layout() {
+    assert_eq!(
+        call("postgresql", "download_url", "macos", "arm64", "17.2.0"),
+        "https://github.com/theseus-rs/postgresql-binaries/releases/download/17.2.0/postgresql-17.2.0-aarch64-apple-darwin.tar.gz"
+    );
+    let layout = call("postgresql", "install_layout", "windows", "x64", "17.2.0");
+    assert_eq!(
+        layout["strip_prefix"],
+        "postgresql-17.2.0-x86_64-pc-windows-msvc"
+    );
+}
+
+#[test]
+fn test_mysqlsh_download_url_and_layout() {
+    assert_eq!(
+        call("mysqlsh", "download_url", "windows", "x64", "8.4.3"),
+        "https://dev.mysql.com/get/Downloads/MySQL-Shell/mysql-shell-8.4.3-windows-x86-64bit.zip"
+    );
+    let layout = call("mysqlsh", "install_layout", "linux", "x64", "8.4.3");
+    assert_eq!(
+        layout["strip_prefix"],
+        "mysql-shell-8.4.3-linux-glibc2.28-x86-64bit"
+    );
+}
+
+/// Rows in the layout of https://www.sqlite.org/chronology.html
+const SQLITE_CHRONOLOGY: &str = r#"<table>
+<thead><tr><th>Date</th><th>Version</th></tr></thead>
+<tr><td><a href="https://sqlite.org/src/timeline?c=c9c2ab54ba&y=ci">2027-01-09</a></td>
+<td><a href="releaselog/3_53_0.html">3.53.0</a></td></tr>
+<tr><td><a href="https://sqlite.org/src/timeline?c=e6e5ca3131&y=ci">2024-08-13</a></td>
+<td><a href="releaselog/3_46_1.html">3.46.1</a></td></tr>
+<tr><td><a href="https://sqlite.org/src/timeline?c=96c92aba00&y=ci">2024-05-23</a></td>
+<td><a href="releaselog/3_46_0.html">3.46.0</a></td></tr>
+<tr><td><a href="https://sqlite.org/src/timeline?c=1ce93ee6e7&y=ci">2001-11-12</a></td>
+<td><a href="releaselog/2_0_8.html">2.0.8</a></td></tr>
+<tr><td>2000-05-30</td><td>1.0.32.1</td></tr>
+</table>"#;
+
+#[tokio::test]
+async fn test_sqlite_chronology_versions_are_dated() {
+    let base = serve("text/html", SQLITE_CHRONOLOGY);
+    let content = format!(
+        r#"
+load("@vx//stdlib:http.star", "fetch_json_versions")
+
+def fetch_versions(ctx):
+    return fetch_json_versions(ctx, "{base}/chronology.html", "sqlite_chronology")
+"#
+    );
+    let name = format!("sqlite-chronology-{}", base.rsplit(':').next().unwrap());
+    let provider = StarlarkProvider::from_content(name, content).await.unwrap();
+    let versions = provider.fetch_versions().await.unwrap();
+
+    let mut dated: Vec<(&str, Option<&str>)> = versions
+        .iter()
+        .map(|v| (v.version.as_str(), v.date.as_deref()))
+        .collect();
+    dated.sort();
+    assert_eq!(
+        dated,
+        [
+            ("2.0.8", Some("2001-11-12")),
+            ("3.46.0", Some("2024-05-23")),
+            ("3.46.1", Some("2024-08-13")),
+            ("3.53.0", Some("2027-01-09")),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_sqlite_download_url_uses_release_date_year() {
+    let base = serve("text/html", SQLITE_CHRONOLOGY);
+    let content = std::fs::read_to_string(star_path("sqlite"))
+        .unwrap()
+        .replace(
+            "https://www.sqlite.org/chronology.html",
+            &format!("{base}/chronology.html"),
+        );
+    let name = format!("sqlite-years-{}", base.rsplit(':').next().unwrap());
+    let provider = StarlarkProvider::from_content(name, content).await.unwrap();
+
+    // Not in the fallback table: the year comes from the release date
+    let url = provider.download_url("3.53.0").await.unwrap().unwrap();
+    assert!(url.contains("/2027/sqlite-tools-"), "{url}");
+    let url = provider.download_url("3.46.1").await.unwrap().unwrap();
+    assert!(url.contains("/2024/sqlite-tools-"), "{url}");
+}
@@ -0,0 +1,55 @@
+//! Tests for the `devtools` tool bundle provider
+
+use vx_starlark::{StarlarkEngine, StarlarkProvider};
+
+fn star_path() -> std::path::PathBuf {
+    std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent() // crates/
+        .unwrap()
+        .join("vx-providers")
+        .join("devtools")
+        .join("provider.star")
+}
+
+#[tokio::test]
+async fn test_load_devtools_provider() {
+    let provider = StarlarkProvider::load(&star_path()).await.unwrap();
+    assert_eq!(provider.name(), "devtools");
+}
+
+#[test]
+fn test_devtools_bundle_members_have_providers() {
+    let content = std::fs::read_to_string(star_path()).unwrap();
+    let meta = vx_starlark::StarMetadata::parse(&content);
+    assert!(!meta.tool_bundle.is_empty());
+
+    let providers_dir = star_path()
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    for member in &meta.tool_bundle {
+        assert!(
+            providers_dir.join(member).join("provider.star").is_file(),
+            "bundle member '{}' has no provider",
+            member
+        );
+    }
+}
+
+#[test]
+fn test_devtools_downloads_nothing() {
+    let content = std::fs::read_to_string(star_path()).unwrap();
+    let ctx = vx_starlark::ProviderContext::new("devtools", std::env::temp_dir().join("vx-test"));
+    let url = StarlarkEngine::new()
+        .call_function(
+            &star_path(),
+            &content,
+            "download_url",
+            &ctx,
+            &[serde_json::json!("1.0.0")],
+        )
+        .unwrap();
+    assert!(url.is_null());
+}
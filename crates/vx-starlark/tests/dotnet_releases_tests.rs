@@ -0,0 +1,122 @@
+//! Tests for the `dotnet_releases` transform of `fetch_json_versions()`
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use vx_starlark::StarlarkProvider;
+
+/// Serve the routes built by `routes(base)` on a local port, returning the base URL
+fn serve_json(routes: impl FnOnce(&str) -> HashMap<String, String>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let routes = routes(&base);
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { continue };
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            let _ = reader.read_line(&mut request_line);
+            // Skip the headers
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok_and(|n| n > 2) {
+                line.clear();
+            }
+            let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+            let (status, body) = match routes.get(path) {
+                Some(body) => ("200 OK", body.as_str()),
+                None => ("404 Not Found", "{}"),
+            };
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+        }
+    });
+    base
+}
+
+fn index(base: &str) -> String {
+    format!(
+        r#"{{"releases-index": [
+            {{"channel-version": "10.0", "latest-sdk": "10.0.100-rc.2.25502.107", "release-type": "lts", "support-phase": "preview", "releases.json": "{base}/10.0/releases.json"}},
+            {{"channel-version": "9.0", "latest-sdk": "9.0.306", "release-type": "sts", "support-phase": "active", "releases.json": "{base}/9.0/releases.json"}},
+            {{"channel-version": "8.0", "latest-sdk": "8.0.415", "release-type": "lts", "support-phase": "active", "releases.json": "{base}/8.0/releases.json"}},
+            {{"channel-version": "7.0", "latest-sdk": "7.0.410", "release-type": "sts", "support-phase": "eol", "releases.json": "{base}/7.0/releases.json"}}
+        ]}}"#
+    )
+}
+
+async fn fetch(name: &str, base: &str) -> Vec<vx_starlark::context::VersionInfo> {
+    let content = format!(
+        r#"
+load("@vx//stdlib:http.star", "fetch_json_versions")
+
+def fetch_versions(ctx):
+    return fetch_json_versions(ctx, "{base}/releases-index.json", "dotnet_releases")
+"#
+    );
+    let name = format!("{}-{}", name, base.rsplit(':').next().unwrap());
+    let provider = StarlarkProvider::from_content(name, content).await.unwrap();
+    provider.fetch_versions().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_dotnet_releases_lists_every_sdk_of_supported_channels() {
+    let base = serve_json(|base| {
+        HashMap::from([
+            ("/releases-index.json".to_string(), index(base)),
+            (
+                "/10.0/releases.json".to_string(),
+                r#"{"releases": [{"release-date": "2025-10-14", "sdk": {"version": "10.0.100-rc.2.25502.107"}, "sdks": [{"version": "10.0.100-rc.2.25502.107"}]}]}"#.to_string(),
+            ),
+            (
+                "/8.0/releases.json".to_string(),
+                r#"{"releases": [
+                    {"release-date": "2025-10-14", "sdk": {"version": "8.0.415"}, "sdks": [{"version": "8.0.415"}, {"version": "8.0.318"}]},
+                    {"release-date": "2025-09-09", "sdk": {"version": "8.0.414"}, "sdks": [{"version": "8.0.414"}, {"version": "8.0.317"}]}
+                ]}"#.to_string(),
+            ),
+            (
+                "/7.0/releases.json".to_string(),
+                r#"{"releases": [{"sdks": [{"version": "7.0.410"}]}]}"#.to_string(),
+            ),
+        ])
+    });
+
+    let versions = fetch("dotnet-releases", &base).await;
+    let names: Vec<&str> = versions.iter().map(|v| v.version.as_str()).collect();
+    // 9.0 has no releases.json route and falls back to its latest SDK; 7.0 is EOL
+    assert_eq!(
+        names,
+        [
+            "10.0.100-rc.2.25502.107",
+            "9.0.306",
+            "8.0.415",
+            "8.0.414",
+            "8.0.318",
+            "8.0.317"
+        ]
+    );
+    assert!(!versions[0].stable);
+    assert!(versions[2].lts && versions[2].stable);
+    assert!(!versions[1].lts);
+    assert_eq!(versions[3].date.as_deref(), Some("2025-09-09"));
+}
+
+#[tokio::test]
+async fn test_dotnet_releases_without_channel_urls_uses_index() {
+    let base = serve_json(|_| {
+        HashMap::from([(
+            "/releases-index.json".to_string(),
+            r#"{"releases-index": [{"latest-sdk": "8.0.415", "release-type": "lts"}]}"#.to_string(),
+        )])
+    });
+
+    let versions = fetch("dotnet-releases-index", &base).await;
+    assert_eq!(versions.len(), 1);
+    assert_eq!(versions[0].version, "8.0.415");
+    assert!(versions[0].lts);
+}
@@ -0,0 +1,67 @@
+//! Tests for `ctx.gpu` and the conda provider's CUDA wiring
+
+use std::path::{Path, PathBuf};
+use vx_runtime::GpuInfo;
+use vx_starlark::StarlarkEngine;
+use vx_starlark::context::ProviderContext;
+
+fn ctx_with_gpu(gpu: GpuInfo) -> ProviderContext {
+    let mut ctx = ProviderContext::new("conda", std::env::temp_dir().join("vx-test"));
+    ctx.platform.os = "linux".to_string();
+    ctx.gpu = gpu;
+    ctx
+}
+
+fn conda_environment(ctx: &ProviderContext) -> serde_json::Value {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join("vx-providers/conda/provider.star");
+    let content = std::fs::read_to_string(&path).unwrap();
+    StarlarkEngine::new()
+        .call_function(
+            &path,
+            &content,
+            "environment",
+            ctx,
+            &[serde_json::json!("2.0.5-0")],
+        )
+        .unwrap()
+}
+
+fn env_value<'a>(ops: &'a serde_json::Value, key: &str) -> Option<&'a str> {
+    ops.as_array()?
+        .iter()
+        .find(|op| op["key"] == key)
+        .and_then(|op| op["value"].as_str())
+}
+
+#[test]
+fn test_ctx_gpu_fields() {
+    let ctx = ctx_with_gpu(GpuInfo {
+        driver_version: Some("550.54.14".to_string()),
+        cuda_version: Some("12.4".to_string()),
+        ..GpuInfo::default()
+    });
+    let script = r#"
+def gpu(ctx):
+    return [ctx.gpu.available, ctx.gpu.cuda, ctx.gpu.driver, ctx.gpu.toolkit]
+"#;
+    let result = StarlarkEngine::new()
+        .call_function(Path::new("gpu.star"), script, "gpu", &ctx, &[])
+        .unwrap();
+    assert_eq!(result, serde_json::json!([true, "12.4", "550.54.14", ""]));
+}
+
+#[test]
+fn test_conda_pins_detected_cuda() {
+    let ops = conda_environment(&ctx_with_gpu(GpuInfo::from_override("12.1").unwrap()));
+    assert_eq!(env_value(&ops, "CONDA_OVERRIDE_CUDA"), Some("12.1"));
+    assert!(env_value(&ops, "MAMBA_ROOT_PREFIX").is_some());
+}
+
+#[test]
+fn test_conda_without_gpu_leaves_cuda_unset() {
+    let ops = conda_environment(&ctx_with_gpu(GpuInfo::default()));
+    assert_eq!(env_value(&ops, "CONDA_OVERRIDE_CUDA"), None);
+}
@@ -0,0 +1,72 @@
+//! Tests for the `homebrew_bottle_layout` stdlib helper.
+//!
+//! On macOS the layout points at the formula's bottle index on ghcr.io and
+//! carries the formula for relocation; other platforms use the fallback.
+
+use vx_starlark::{StarlarkEngine, StarlarkProvider};
+
+const PROVIDER: &str = r#"
+load("@vx//stdlib:provider.star", "archive_layout", "homebrew_bottle_layout")
+
+name        = "jq"
+description = "Command-line JSON processor"
+
+runtimes = [{"name": "jq", "executable": "jq"}]
+
+install_layout = homebrew_bottle_layout("jq", ["jq"], fallback = archive_layout("jq"))
+"#;
+
+fn install_layout(os: &str, arch: &str, version: &str) -> serde_json::Value {
+    let star_path = std::env::temp_dir().join("vx-test").join("jq.star");
+    let mut ctx = vx_starlark::ProviderContext::new("jq", std::env::temp_dir().join("vx-test"));
+    ctx.platform.os = os.to_string();
+    ctx.platform.arch = arch.to_string();
+    StarlarkEngine::new()
+        .call_function(
+            &star_path,
+            PROVIDER,
+            "install_layout",
+            &ctx,
+            &[serde_json::json!(version)],
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_macos_layout_points_at_bottle_index() {
+    let layout = install_layout("macos", "arm64", "1.7.1_1");
+    assert_eq!(layout["type"], "archive_install");
+    assert_eq!(
+        layout["url"],
+        "https://ghcr.io/v2/homebrew/core/jq/manifests/1.7.1_1"
+    );
+    assert_eq!(layout["bottle"], "jq");
+    assert_eq!(layout["strip_prefix"], "jq/1.7.1_1*");
+    assert_eq!(layout["executable_paths"], serde_json::json!(["bin/jq"]));
+}
+
+#[test]
+fn test_other_platforms_use_fallback() {
+    let layout = install_layout("linux", "x64", "1.7.1");
+    assert_eq!(layout["type"], "archive");
+    assert!(layout.get("bottle").is_none());
+}
+
+#[tokio::test]
+async fn test_bottle_survives_layout_resolution() {
+    let provider = StarlarkProvider::from_content("jq", PROVIDER)
+        .await
+        .unwrap();
+    let layout = provider
+        .install_layout("1.7.1_1")
+        .await
+        .unwrap()
+        .expect("jq has an install layout")
+        .to_flat_json();
+
+    if cfg!(target_os = "macos") {
+        assert_eq!(layout["bottle"], "jq");
+    } else {
+        assert!(layout.get("bottle").is_none());
+    }
+}
@@ -0,0 +1,175 @@
+//! Tests for `http.get_json()` from `@vx//stdlib:http.star`
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use vx_starlark::{Error, SandboxConfig, StarlarkProvider};
+
+/// Serve `routes` (path → JSON body) on a local port, returning the base URL
+fn serve_json(routes: HashMap<&'static str, &'static str>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { continue };
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            let _ = reader.read_line(&mut request_line);
+            // Skip the headers
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok_and(|n| n > 2) {
+                line.clear();
+            }
+            let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+            let (status, body) = match routes.get(path) {
+                Some(body) => ("200 OK", *body),
+                None => ("404 Not Found", "{}"),
+            };
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+        }
+    });
+    base
+}
+
+#[tokio::test]
+async fn test_get_json_descriptor_defaults() {
+    let content = r#"
+load("@vx//stdlib:http.star", "http")
+
+def fetch_versions(ctx):
+    return http.get_json("https://releases.example.com/index.json")
+"#;
+    let engine = vx_starlark::StarlarkEngine::new();
+    let ctx = vx_starlark::ProviderContext::new("example", std::env::temp_dir().join("vx-test"));
+    let json = engine
+        .call_function(
+            std::path::Path::new("provider.star"),
+            content,
+            "fetch_versions",
+            &ctx,
+            &[],
+        )
+        .unwrap();
+
+    assert_eq!(json["__type"], "http_get_json");
+    assert_eq!(json["url"], "https://releases.example.com/index.json");
+    assert_eq!(json["cache_ttl"], "1h");
+    assert!(json["then"].is_null());
+}
+
+#[tokio::test]
+async fn test_get_json_parses_and_chains_pages() {
+    let base = serve_json(HashMap::from([
+        (
+            "/page1.json",
+            r#"{"releases": [{"name": "2.1.0"}, {"name": "2.0.0", "beta": false}], "next": "/page2.json"}"#,
+        ),
+        (
+            "/page2.json",
+            r#"{"releases": [{"name": "2.0.0-rc1", "beta": true}], "next": null}"#,
+        ),
+    ]));
+    let content = format!(
+        r#"
+load("@vx//stdlib:http.star", "http")
+
+BASE = "{base}"
+
+def fetch_versions(ctx):
+    return http.get_json(BASE + "/page1.json", cache_ttl = 0, then = "parse_page1")
+
+def _versions(data):
+    return [{{"version": r["name"], "stable": not r.get("beta", False)}} for r in data["releases"]]
+
+def parse_page1(ctx, data):
+    return http.get_json(BASE + data["next"], cache_ttl = 0, then = "parse_page2")
+
+def parse_page2(ctx, data):
+    return _versions(data)
+"#
+    );
+    let name = format!("http-get-json-{}", base.rsplit(':').next().unwrap());
+    let provider = StarlarkProvider::from_content(name, content).await.unwrap();
+
+    let versions = provider.fetch_versions().await.unwrap();
+    assert_eq!(versions.len(), 1);
+    assert_eq!(versions[0].version, "2.0.0-rc1");
+    assert!(!versions[0].stable);
+}
+
+#[tokio::test]
+async fn test_get_json_without_then_uses_response_as_versions() {
+    let base = serve_json(HashMap::from([(
+        "/versions.json",
+        r#"[{"version": "1.2.0", "lts": true}, {"version": "1.1.0"}]"#,
+    )]));
+    let content = format!(
+        r#"
+load("@vx//stdlib:http.star", "http")
+
+def fetch_versions(ctx):
+    return http.get_json("{base}/versions.json", cache_ttl = 0)
+"#
+    );
+    let name = format!("http-get-json-plain-{}", base.rsplit(':').next().unwrap());
+    let provider = StarlarkProvider::from_content(name, content).await.unwrap();
+
+    let versions = provider.fetch_versions().await.unwrap();
+    let names: Vec<&str> = versions.iter().map(|v| v.version.as_str()).collect();
+    assert_eq!(names, ["1.2.0", "1.1.0"]);
+    assert!(versions[0].lts);
+}
+
+#[tokio::test]
+async fn test_get_json_respects_sandbox_hosts() {
+    let content = r#"
+load("@vx//stdlib:http.star", "http")
+
+def fetch_versions(ctx):
+    return http.get_json("https://evil.example.net/versions.json", then = "parse")
+
+def parse(ctx, data):
+    return []
+"#;
+    let sandbox = SandboxConfig {
+        http_allowed_hosts: vec!["releases.example.com".to_string()],
+        ..Default::default()
+    };
+    let provider = StarlarkProvider::from_content("http-get-json-denied", content)
+        .await
+        .unwrap()
+        .with_sandbox(sandbox);
+
+    let err = provider.fetch_versions().await.unwrap_err();
+    assert!(
+        matches!(&err, Error::HttpHostDenied { host } if host == "evil.example.net"),
+        "unexpected error: {}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn test_get_json_rejects_invalid_cache_ttl() {
+    let content = r#"
+load("@vx//stdlib:http.star", "http")
+
+def fetch_versions(ctx):
+    return http.get_json("https://releases.example.com/index.json", cache_ttl = "soon")
+"#;
+    let provider = StarlarkProvider::from_content("http-get-json-bad-ttl", content)
+        .await
+        .unwrap();
+
+    let err = provider.fetch_versions().await.unwrap_err();
+    assert!(
+        err.to_string().contains("invalid cache_ttl 'soon'"),
+        "{}",
+        err
+    );
+}
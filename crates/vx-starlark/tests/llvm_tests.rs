@@ -0,0 +1,110 @@
+//! Tests for the `llvm` provider's archive components.
+//!
+//! The release archives are extracted partially: `install_layout` declares
+//! the archive paths of each component and the installer only writes the
+//! selected ones.
+
+use vx_starlark::{StarlarkEngine, StarlarkProvider};
+
+fn load_provider_content() -> (std::path::PathBuf, String) {
+    let manifest_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let star_path = manifest_dir
+        .parent()
+        .unwrap()
+        .join("vx-providers")
+        .join("llvm")
+        .join("provider.star");
+    let content = std::fs::read_to_string(&star_path).unwrap();
+    (star_path, content)
+}
+
+fn install_layout(os: &str, arch: &str, version: &str) -> serde_json::Value {
+    let (star_path, content) = load_provider_content();
+    let mut ctx = vx_starlark::ProviderContext::new("llvm", std::env::temp_dir().join("vx-test"));
+    ctx.platform.os = os.to_string();
+    ctx.platform.arch = arch.to_string();
+    StarlarkEngine::new()
+        .call_function(
+            &star_path,
+            &content,
+            "install_layout",
+            &ctx,
+            &[serde_json::json!(version)],
+        )
+        .unwrap()
+}
+
+fn patterns<'a>(layout: &'a serde_json::Value, component: &str) -> Vec<&'a str> {
+    layout["components"][component]
+        .as_array()
+        .unwrap_or_else(|| panic!("missing component {}", component))
+        .iter()
+        .map(|p| p.as_str().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_components_cover_bundled_tools() {
+    let layout = install_layout("linux", "x64", "20.1.0");
+    assert_eq!(layout["strip_prefix"], "LLVM-20.1.0-Linux-X64");
+
+    let names: Vec<&str> = layout["components"]
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "clang",
+            "clang-format",
+            "clang-tidy",
+            "clangd",
+            "lld",
+            "binutils"
+        ]
+    );
+
+    // clang is a symlink to clang-<major>, which must come along
+    let clang = patterns(&layout, "clang");
+    assert!(clang.contains(&"bin/clang"));
+    assert!(clang.contains(&"bin/clang-[0-9]*"));
+    assert!(clang.contains(&"lib/clang/*"));
+    assert!(clang.contains(&"lib/libLLVM.so*"));
+
+    let format = patterns(&layout, "clang-format");
+    assert!(format.contains(&"bin/git-clang-format"));
+    assert!(!format.contains(&"lib/clang/*"));
+    assert!(patterns(&layout, "clang-tidy").contains(&"lib/clang/*"));
+    // llvm-strip is a symlink to llvm-objcopy
+    assert!(patterns(&layout, "binutils").contains(&"bin/llvm-objcopy"));
+}
+
+#[test]
+fn test_windows_components_use_exe_names() {
+    let layout = install_layout("windows", "x64", "20.1.0");
+    assert_eq!(
+        layout["strip_prefix"],
+        "clang+llvm-20.1.0-x86_64-pc-windows-msvc"
+    );
+    let lld = patterns(&layout, "lld");
+    assert!(lld.contains(&"bin/lld-link.exe"));
+    assert!(lld.iter().all(|p| p.ends_with(".exe")));
+    assert!(patterns(&layout, "clang").contains(&"lib/clang/*"));
+}
+
+#[tokio::test]
+async fn test_components_survive_layout_resolution() {
+    let (star_path, _) = load_provider_content();
+    let provider = StarlarkProvider::load(&star_path).await.unwrap();
+    let layout = provider
+        .install_layout("19.1.7")
+        .await
+        .unwrap()
+        .expect("llvm has an install layout")
+        .to_flat_json();
+
+    assert!(layout["components"]["clang-format"].is_array());
+    assert!(layout["executable_paths"].is_array());
+}
@@ -0,0 +1,57 @@
+//! Tests for the `php_releases` transform of `fetch_json_versions()`
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use vx_starlark::StarlarkProvider;
+
+/// Serve `body` for every request on a local port, returning the base URL
+fn serve_json(body: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { continue };
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            // Skip the request line and headers
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok_and(|n| n > 2) {
+                line.clear();
+            }
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+        }
+    });
+    base
+}
+
+#[tokio::test]
+async fn test_php_releases_lists_versions_newest_first() {
+    let base = serve_json(
+        r#"{
+            "8.3.9": {"date": "04 Jul 2024"},
+            "8.3.12": {"date": "26 Sep 2024"},
+            "8.4.0": {"date": "21 Nov 2024"},
+            "8.2.24": {"date": "26 Sep 2024"},
+            "announcement": true
+        }"#,
+    );
+    let content = format!(
+        r#"
+load("@vx//stdlib:http.star", "fetch_json_versions")
+
+def fetch_versions(ctx):
+    return fetch_json_versions(ctx, "{base}/releases/index.php?json&version=8&max=-1", "php_releases")
+"#
+    );
+    let name = format!("php-releases-{}", base.rsplit(':').next().unwrap());
+    let provider = StarlarkProvider::from_content(name, content).await.unwrap();
+    let versions = provider.fetch_versions().await.unwrap();
+
+    let names: Vec<&str> = versions.iter().map(|v| v.version.as_str()).collect();
+    assert_eq!(names, ["8.4.0", "8.3.12", "8.3.9", "8.2.24"]);
+    assert!(versions.iter().all(|v| v.stable));
+}
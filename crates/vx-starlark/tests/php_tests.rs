@@ -0,0 +1,97 @@
+//! PHP and Composer provider tests
+
+use vx_starlark::StarlarkEngine;
+use vx_starlark::StarlarkProvider;
+use vx_starlark::provider::PostExtractAction;
+
+fn star_path(provider_name: &str) -> std::path::PathBuf {
+    std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent() // crates/
+        .unwrap()
+        .join("vx-providers")
+        .join(provider_name)
+        .join("provider.star")
+}
+
+fn download_url(provider_name: &str, os: &str, arch: &str, version: &str) -> serde_json::Value {
+    let path = star_path(provider_name);
+    let content = std::fs::read_to_string(&path).unwrap();
+    let mut ctx =
+        vx_starlark::ProviderContext::new(provider_name, std::env::temp_dir().join("vx-test"));
+    ctx.platform.os = os.to_string();
+    ctx.platform.arch = arch.to_string();
+    StarlarkEngine::new()
+        .call_function(
+            &path,
+            &content,
+            "download_url",
+            &ctx,
+            &[serde_json::json!(version)],
+        )
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_load_php_and_composer_providers() {
+    let php = StarlarkProvider::load(&star_path("php")).await.unwrap();
+    assert_eq!(php.name(), "php");
+    let composer = StarlarkProvider::load(&star_path("composer"))
+        .await
+        .unwrap();
+    assert_eq!(composer.name(), "composer");
+}
+
+#[test]
+fn test_php_download_urls() {
+    assert_eq!(
+        download_url("php", "windows", "x64", "8.3.12"),
+        "https://windows.php.net/downloads/releases/php-8.3.12-nts-Win32-vs16-x64.zip"
+    );
+    assert_eq!(
+        download_url("php", "windows", "x64", "8.4.1"),
+        "https://windows.php.net/downloads/releases/php-8.4.1-nts-Win32-vs17-x64.zip"
+    );
+    assert_eq!(
+        download_url("php", "linux", "x64", "8.3.12"),
+        "https://dl.static-php.dev/static-php-cli/common/php-8.3.12-cli-linux-x86_64.tar.gz"
+    );
+    assert_eq!(
+        download_url("php", "macos", "arm64", "8.3.12"),
+        "https://dl.static-php.dev/static-php-cli/common/php-8.3.12-cli-macos-aarch64.tar.gz"
+    );
+    assert!(download_url("php", "windows", "arm64", "8.3.12").is_null());
+}
+
+#[test]
+fn test_composer_download_url() {
+    assert_eq!(
+        download_url("composer", "linux", "x64", "2.8.1"),
+        "https://getcomposer.org/download/2.8.1/composer.phar"
+    );
+}
+
+#[tokio::test]
+async fn test_php_post_extract_writes_php_ini() {
+    let provider = StarlarkProvider::load(&star_path("php")).await.unwrap();
+    let install_dir = std::path::Path::new("/opt/vx/store/php/8.3.12");
+    let actions = provider.post_extract("8.3.12", install_dir).await.unwrap();
+
+    let ini = actions
+        .iter()
+        .find_map(|a| match a {
+            PostExtractAction::WriteFile {
+                path,
+                content,
+                overwrite,
+            } if path == "php.ini" => Some((content.clone(), *overwrite)),
+            _ => None,
+        })
+        .expect("php.ini is written");
+    assert!(
+        ini.0
+            .contains("extension_dir = \"/opt/vx/store/php/8.3.12/ext\""),
+        "{}",
+        ini.0
+    );
+    assert!(!ini.1);
+}
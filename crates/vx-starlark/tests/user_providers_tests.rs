@@ -0,0 +1,215 @@
+//! User provider discovery tests for vx-starlark
+//!
+//! Tests for hot-loading `provider.star` files from user directories:
+//! validation, permission-derived sandboxes and directory scanning.
+
+use std::path::{Path, PathBuf};
+use vx_starlark::{
+    DEV_LINKS_DIR, Error, PermissionsDecl, StarlarkProvider, dev_link_file, discover_dev_providers,
+    discover_user_providers, discover_user_providers_checked, validate_user_provider,
+};
+
+const SCRIPT: &str = r#"
+name = "mytool"
+description = "Community tool"
+
+runtimes = [{"name": "mytool", "executable": "mytool"}]
+
+permissions = {
+    "http": ["dl.example.com"],
+    "exec": ["mytool"],
+}
+
+def fetch_versions(ctx):
+    return [{"version": "1.0.0"}]
+
+def download_url(ctx, version):
+    return "https://dl.example.com/mytool-" + version + ".tar.gz"
+
+def install_layout(ctx, version):
+    return None
+"#;
+
+fn write_provider(root: &Path, name: &str, content: &str) -> PathBuf {
+    let dir = root.join(name);
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("provider.star");
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+// ============================================================
+// Validation
+// ============================================================
+
+#[test]
+fn test_validate_reads_permissions_into_sandbox() {
+    let provider = validate_user_provider("mytool", "provider.star", SCRIPT).unwrap();
+
+    assert_eq!(provider.name, "mytool");
+    assert!(provider.has_install_layout);
+    assert_eq!(provider.permissions.http, vec!["dl.example.com"]);
+    assert!(provider.sandbox.is_host_allowed("dl.example.com"));
+    assert!(!provider.sandbox.is_host_allowed("evil.example.com"));
+    assert!(provider.sandbox.is_command_allowed("mytool"));
+    // No fs permissions declared → fs access disabled
+    assert!(!provider.sandbox.enable_fs);
+}
+
+#[test]
+fn test_validate_rejects_missing_download_url() {
+    let script = r#"
+name = "broken"
+def fetch_versions(ctx):
+    return []
+"#;
+    let err = validate_user_provider("broken", "provider.star", script).unwrap_err();
+    assert!(matches!(err, Error::FunctionNotFound { ref name } if name == "download_url"));
+}
+
+#[test]
+fn test_validate_rejects_eval_errors() {
+    let script = "def fetch_versions(ctx):\n    return []\nundefined_call()\n";
+    assert!(validate_user_provider("bad", "provider.star", script).is_err());
+}
+
+#[test]
+fn test_validate_without_permissions_uses_restricted_defaults() {
+    let script = r#"
+def fetch_versions(ctx):
+    return []
+def download_url(ctx, version):
+    return None
+"#;
+    let provider = validate_user_provider("plain", "provider.star", script).unwrap();
+    assert!(!provider.has_install_layout);
+    assert!(!provider.sandbox.enable_fs);
+    assert!(!provider.sandbox.enable_execute);
+    assert!(provider.sandbox.is_host_allowed("github.com"));
+}
+
+#[test]
+fn test_permissions_from_json_ignores_non_strings() {
+    let value = serde_json::json!({"fs": ["/opt", 1], "http": "not-a-list"});
+    let decl = PermissionsDecl::from_json(&value);
+    assert_eq!(decl.fs, vec!["/opt"]);
+    assert!(decl.http.is_empty());
+    assert!(decl.exec.is_empty());
+}
+
+// ============================================================
+// Discovery
+// ============================================================
+
+#[test]
+fn test_discover_skips_invalid_and_sorts() {
+    let temp = tempfile::tempdir().unwrap();
+    write_provider(temp.path(), "zeta", &SCRIPT.replace("mytool", "zeta"));
+    write_provider(temp.path(), "alpha", &SCRIPT.replace("mytool", "alpha"));
+    write_provider(temp.path(), "broken", "name = 'broken'\n");
+    std::fs::create_dir_all(temp.path().join("empty-dir")).unwrap();
+
+    let found = discover_user_providers(&[temp.path().to_path_buf()]);
+    let names: Vec<&str> = found.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["alpha", "zeta"]);
+}
+
+#[test]
+fn test_discover_checked_reports_skipped_scripts() {
+    let temp = tempfile::tempdir().unwrap();
+    write_provider(temp.path(), "alpha", &SCRIPT.replace("mytool", "alpha"));
+    let broken = write_provider(temp.path(), "broken", "name = 'broken'\n");
+
+    let (found, skipped) = discover_user_providers_checked(&[temp.path().to_path_buf()]);
+    assert_eq!(found.len(), 1);
+    assert_eq!(skipped.len(), 1);
+    assert_eq!(skipped[0].name, "broken");
+    assert_eq!(skipped[0].path, broken);
+    assert!(
+        skipped[0].error.contains("fetch_versions"),
+        "{}",
+        skipped[0].error
+    );
+}
+
+#[test]
+fn test_discover_missing_root_is_empty() {
+    let found = discover_user_providers(&[PathBuf::from("/nonexistent/vx/providers")]);
+    assert!(found.is_empty());
+}
+
+#[test]
+fn test_discover_dev_providers_reads_linked_scripts() {
+    let workspace = tempfile::tempdir().unwrap();
+    let script = write_provider(workspace.path(), "checkout", SCRIPT);
+
+    let providers_root = tempfile::tempdir().unwrap();
+    let links_dir = providers_root.path().join(DEV_LINKS_DIR);
+    std::fs::create_dir_all(&links_dir).unwrap();
+    std::fs::write(
+        dev_link_file(&links_dir, "mytool"),
+        format!("{}\n", script.display()),
+    )
+    .unwrap();
+    // A link whose script is gone and a stray file are skipped
+    std::fs::write(
+        dev_link_file(&links_dir, "gone"),
+        "/nonexistent/provider.star",
+    )
+    .unwrap();
+    std::fs::write(links_dir.join("notes.txt"), script.display().to_string()).unwrap();
+
+    let found = discover_dev_providers(&links_dir);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].name, "mytool");
+    assert_eq!(found[0].path, script);
+    assert_eq!(found[0].content, SCRIPT);
+
+    // The links dir never shows up as a regular provider
+    assert!(discover_user_providers(&[providers_root.path().to_path_buf()]).is_empty());
+}
+
+#[tokio::test]
+async fn test_discovered_sandbox_applies_to_loaded_provider() {
+    let temp = tempfile::tempdir().unwrap();
+    let script = SCRIPT.replace("mytool", "sandboxed-tool");
+    write_provider(temp.path(), "sandboxed-tool", &script);
+
+    let found = discover_user_providers(&[temp.path().to_path_buf()]);
+    assert_eq!(found.len(), 1);
+
+    // Providers built lazily from the same script pick up the registered sandbox
+    let provider = StarlarkProvider::from_content("sandboxed-tool", script)
+        .await
+        .unwrap();
+    assert!(!provider.sandbox().enable_fs);
+    assert!(provider.sandbox().is_host_allowed("dl.example.com"));
+    assert!(!provider.sandbox().is_host_allowed("evil.example.com"));
+}
+
+#[tokio::test]
+async fn test_download_url_outside_allowed_hosts_is_denied() {
+    let script = r#"
+name = "leaky"
+runtimes = [{"name": "leaky"}]
+permissions = {"http": ["dl.example.com"]}
+
+def fetch_versions(ctx):
+    return []
+
+def download_url(ctx, version):
+    return "https://evil.example.net/leaky.tar.gz"
+"#;
+    let temp = tempfile::tempdir().unwrap();
+    write_provider(temp.path(), "leaky", script);
+    assert_eq!(
+        discover_user_providers(&[temp.path().to_path_buf()]).len(),
+        1
+    );
+
+    let provider = StarlarkProvider::from_content("leaky", script)
+        .await
+        .unwrap();
+    let err = provider.download_url("1.0.0").await.unwrap_err();
+    assert!(matches!(err, Error::HttpHostDenied { ref host } if host == "evil.example.net"));
+}
@@ -0,0 +1,151 @@
+//! Zig and ZLS provider tests
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use vx_starlark::StarlarkEngine;
+use vx_starlark::StarlarkProvider;
+
+fn star_path(provider_name: &str) -> std::path::PathBuf {
+    std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent() // crates/
+        .unwrap()
+        .join("vx-providers")
+        .join(provider_name)
+        .join("provider.star")
+}
+
+fn call(
+    provider_name: &str,
+    function: &str,
+    os: &str,
+    arch: &str,
+    version: &str,
+) -> serde_json::Value {
+    let path = star_path(provider_name);
+    let content = std::fs::read_to_string(&path).unwrap();
+    let mut ctx =
+        vx_starlark::ProviderContext::new(provider_name, std::env::temp_dir().join("vx-test"));
+    ctx.platform.os = os.to_string();
+    ctx.platform.arch = arch.to_string();
+    StarlarkEngine::new()
+        .call_function(
+            &path,
+            &content,
+            function,
+            &ctx,
+            &[serde_json::json!(version)],
+        )
+        .unwrap()
+}
+
+/// Serve `body` for every request on a local port, returning the base URL
+fn serve_json(body: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { continue };
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            // Skip the request line and headers
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok_and(|n| n > 2) {
+                line.clear();
+            }
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+        }
+    });
+    base
+}
+
+#[tokio::test]
+async fn test_load_zig_and_zls_providers() {
+    let zig = StarlarkProvider::load(&star_path("zig")).await.unwrap();
+    assert_eq!(zig.name(), "zig");
+    let zls = StarlarkProvider::load(&star_path("zls")).await.unwrap();
+    assert_eq!(zls.name(), "zls");
+}
+
+#[test]
+fn test_zig_download_urls() {
+    assert_eq!(
+        call("zig", "download_url", "linux", "x64", "0.14.1"),
+        "https://ziglang.org/download/0.14.1/zig-x86_64-linux-0.14.1.tar.xz"
+    );
+    assert_eq!(
+        call("zig", "download_url", "windows", "x64", "0.13.0"),
+        "https://ziglang.org/download/0.13.0/zig-windows-x86_64-0.13.0.zip"
+    );
+    assert_eq!(
+        call(
+            "zig",
+            "download_url",
+            "macos",
+            "arm64",
+            "0.15.0-dev.847+850655f06"
+        ),
+        "https://ziglang.org/builds/zig-aarch64-macos-0.15.0-dev.847+850655f06.tar.xz"
+    );
+}
+
+#[test]
+fn test_zig_strip_prefix_matches_archive_name() {
+    let layout = call("zig", "install_layout", "linux", "x64", "0.13.0");
+    assert_eq!(layout["strip_prefix"], "zig-linux-x86_64-0.13.0");
+    let layout = call("zig", "install_layout", "windows", "x64", "0.14.1");
+    assert_eq!(layout["strip_prefix"], "zig-x86_64-windows-0.14.1");
+}
+
+#[test]
+fn test_zls_download_urls() {
+    assert_eq!(
+        call("zls", "download_url", "linux", "x64", "0.14.0"),
+        "https://github.com/zigtools/zls/releases/download/0.14.0/zls-x86_64-linux.tar.xz"
+    );
+    assert_eq!(
+        call("zls", "download_url", "windows", "arm64", "0.14.0"),
+        "https://github.com/zigtools/zls/releases/download/0.14.0/zls-aarch64-windows.zip"
+    );
+}
+
+#[tokio::test]
+async fn test_zig_index_lists_master_then_releases() {
+    let base = serve_json(
+        r#"{
+            "master": {"version": "0.15.0-dev.847+850655f06", "date": "2025-06-20"},
+            "0.13.0": {"date": "2024-06-07"},
+            "0.14.1": {"date": "2025-05-21"},
+            "0.9.1": {"date": "2022-02-14"},
+            "0.14.0": {"date": "2025-03-05"}
+        }"#,
+    );
+    let content = format!(
+        r#"
+load("@vx//stdlib:http.star", "fetch_json_versions")
+
+def fetch_versions(ctx):
+    return fetch_json_versions(ctx, "{base}/download/index.json", "zig_index")
+"#
+    );
+    let name = format!("zig-index-{}", base.rsplit(':').next().unwrap());
+    let provider = StarlarkProvider::from_content(name, content).await.unwrap();
+    let versions = provider.fetch_versions().await.unwrap();
+
+    let names: Vec<&str> = versions.iter().map(|v| v.version.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "0.15.0-dev.847+850655f06",
+            "0.14.1",
+            "0.14.0",
+            "0.13.0",
+            "0.9.1"
+        ]
+    );
+    assert!(!versions[0].stable);
+    assert!(versions[1..].iter().all(|v| v.stable));
+}
@@ -40,6 +40,8 @@ impl PackageManagerDetector {
             "dnf" => self.detect_dnf().await,
             "pacman" => self.detect_pacman().await,
             "zypper" => self.detect_zypper().await,
+            "apk" => self.detect_apk().await,
+            "nix" => self.detect_nix().await,
             _ => {
                 debug!("Unknown package manager: {}", name);
                 false
@@ -155,6 +157,30 @@ impl PackageManagerDetector {
         }
     }
 
+    /// Detect APK
+    async fn detect_apk(&self) -> bool {
+        #[cfg(target_os = "linux")]
+        {
+            which::which("apk").is_ok()
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            false
+        }
+    }
+
+    /// Detect Nix
+    async fn detect_nix(&self) -> bool {
+        #[cfg(any(target_os = "macos", target_os = "linux"))]
+        {
+            which::which("nix").is_ok()
+        }
+        #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+        {
+            false
+        }
+    }
+
     /// Get the preferred package manager for the current platform
     pub async fn get_preferred(&mut self) -> Option<String> {
         #[cfg(windows)]
@@ -169,15 +195,17 @@ impl PackageManagerDetector {
 
         #[cfg(target_os = "macos")]
         {
-            if self.is_available("brew").await {
-                return Some("brew".to_string());
+            for pm in ["brew", "nix"] {
+                if self.is_available(pm).await {
+                    return Some(pm.to_string());
+                }
             }
         }
 
         #[cfg(target_os = "linux")]
         {
-            // Linux: try common package managers
-            for pm in ["apt", "dnf", "yum", "pacman", "zypper"] {
+            // Linux: try the distribution package managers, then Nix
+            for pm in ["apt", "dnf", "yum", "pacman", "zypper", "apk", "nix"] {
                 if self.is_available(pm).await {
                     return Some(pm.to_string());
                 }
@@ -195,10 +223,12 @@ impl PackageManagerDetector {
         let candidates = vec!["winget", "choco", "scoop"];
 
         #[cfg(target_os = "macos")]
-        let candidates = vec!["brew"];
+        let candidates = vec!["brew", "nix"];
 
         #[cfg(target_os = "linux")]
-        let candidates = vec!["apt", "dnf", "yum", "pacman", "zypper", "brew"];
+        let candidates = vec![
+            "apt", "dnf", "yum", "pacman", "zypper", "apk", "brew", "nix",
+        ];
 
         #[cfg(not(any(windows, target_os = "macos", target_os = "linux")))]
         let candidates: Vec<&str> = vec![];
@@ -3,8 +3,8 @@
 //! System package manager integration for vx.
 //!
 //! This crate provides abstractions for interacting with system package managers
-//! (Chocolatey, winget, Homebrew, APT, etc.) and managing system-level dependencies
-//! (VCRedist, .NET Framework, Windows KB updates).
+//! (Chocolatey, winget, Homebrew, APT, DNF, pacman, zypper, apk, Nix) and managing
+//! system-level dependencies (VCRedist, .NET Framework, Windows KB updates).
 //!
 //! ## Features
 //!
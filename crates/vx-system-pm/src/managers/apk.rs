@@ -0,0 +1,199 @@
+//! APK package manager implementation
+
+use super::{
+    InstallResult, PackageInstallSpec, ProgressCallback, SystemPackageManager, elevated_command,
+    run_with_optional_progress,
+};
+use crate::{Result, SystemPmError};
+use async_trait::async_trait;
+use std::process::Command;
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+/// APK package manager (Alpine Linux)
+pub struct ApkManager {
+    /// Optional progress callback
+    progress_callback: Option<ProgressCallback>,
+}
+
+impl ApkManager {
+    /// Create a new APK manager
+    pub fn new() -> Self {
+        Self {
+            progress_callback: None,
+        }
+    }
+
+    /// Create an APK manager with progress callback
+    pub fn with_progress<F>(callback: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        Self {
+            progress_callback: Some(Arc::new(callback)),
+        }
+    }
+
+    /// Report progress through callback
+    fn report_progress(&self, message: &str) {
+        if let Some(ref callback) = self.progress_callback {
+            callback(message);
+        }
+    }
+
+    /// Run an apk command (with sudo if needed)
+    fn run_apk(&self, args: &[&str]) -> std::io::Result<std::process::Output> {
+        let mut cmd = elevated_command("apk");
+        cmd.args(args);
+        run_with_optional_progress(cmd, self.progress_callback.as_ref())
+    }
+}
+
+impl Default for ApkManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parse the version from `apk list --installed <package>` output
+///
+/// Lines look like `openssl-3.3.1-r0 x86_64 {openssl} (Apache-2.0) [installed]`;
+/// the version is what follows `<package>-` in the first field.
+fn parse_list_version(output: &str, package: &str) -> Option<String> {
+    let prefix = format!("{}-", package);
+    output.lines().find_map(|line| {
+        let field = line.split_whitespace().next()?;
+        let version = field.strip_prefix(&prefix)?;
+        // Guard against packages sharing a prefix (e.g. openssl-dev)
+        version
+            .starts_with(|c: char| c.is_ascii_digit())
+            .then(|| version.to_string())
+    })
+}
+
+#[async_trait]
+impl SystemPackageManager for ApkManager {
+    fn name(&self) -> &str {
+        "apk"
+    }
+
+    fn supported_platforms(&self) -> Vec<&str> {
+        vec!["linux"]
+    }
+
+    async fn is_installed(&self) -> bool {
+        which::which("apk").is_ok()
+    }
+
+    async fn install_self(&self) -> Result<()> {
+        Err(SystemPmError::Other(anyhow::anyhow!(
+            "APK is not available. This system is not Alpine based."
+        )))
+    }
+
+    async fn install_package(&self, spec: &PackageInstallSpec) -> Result<InstallResult> {
+        if !self.is_installed().await {
+            return Err(SystemPmError::PackageManagerNotInstalled("apk".to_string()));
+        }
+
+        let package = match &spec.version {
+            Some(version) => format!("{}={}", spec.package, version),
+            None => spec.package.clone(),
+        };
+        let args = vec!["add", "--no-progress", "--update-cache", &package];
+
+        debug!("Running: apk {}", args.join(" "));
+        self.report_progress(&format!("Installing {} via apk...", spec.package));
+
+        let output = self.run_apk(&args)?;
+        if output.status.success() {
+            info!("Package {} installed successfully", spec.package);
+            self.report_progress(&format!("{} installed successfully", spec.package));
+
+            let version = self.get_installed_version(&spec.package).await?;
+            Ok(InstallResult::success()
+                .with_version(version.unwrap_or_else(|| "unknown".to_string())))
+        } else {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            warn!("Failed to install {}: {}", spec.package, stderr);
+            Err(SystemPmError::InstallationFailed {
+                package: spec.package.clone(),
+                reason: format!("{}\n{}", stdout, stderr),
+            })
+        }
+    }
+
+    async fn uninstall_package(&self, package: &str) -> Result<()> {
+        if !self.is_installed().await {
+            return Err(SystemPmError::PackageManagerNotInstalled("apk".to_string()));
+        }
+
+        let output = self.run_apk(&["del", "--no-progress", package])?;
+        if output.status.success() {
+            info!("Package {} uninstalled successfully", package);
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(SystemPmError::CommandFailed(format!(
+                "Failed to uninstall {}: {}",
+                package, stderr
+            )))
+        }
+    }
+
+    async fn is_package_installed(&self, package: &str) -> Result<bool> {
+        if !self.is_installed().await {
+            return Ok(false);
+        }
+
+        let output = Command::new("apk").args(["info", "-e", package]).output()?;
+        Ok(output.status.success())
+    }
+
+    async fn get_installed_version(&self, package: &str) -> Result<Option<String>> {
+        if !self.is_installed().await {
+            return Ok(None);
+        }
+
+        let output = Command::new("apk")
+            .args(["list", "--installed", package])
+            .output()?;
+        if output.status.success() {
+            Ok(parse_list_version(
+                &String::from_utf8_lossy(&output.stdout),
+                package,
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        90
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apk_manager_creation() {
+        let manager = ApkManager::new();
+        assert_eq!(manager.name(), "apk");
+        assert_eq!(manager.supported_platforms(), vec!["linux"]);
+        assert_eq!(manager.priority(), 90);
+    }
+
+    #[test]
+    fn test_parse_list_version() {
+        let output = "openssl-dev-3.3.1-r0 x86_64 {openssl} (Apache-2.0) [installed]\n\
+                      openssl-3.3.1-r0 x86_64 {openssl} (Apache-2.0) [installed]\n";
+        assert_eq!(
+            parse_list_version(output, "openssl"),
+            Some("3.3.1-r0".to_string())
+        );
+        assert_eq!(parse_list_version(output, "curl"), None);
+    }
+}
@@ -0,0 +1,158 @@
+//! DNF package manager implementation
+
+use super::{
+    InstallResult, PackageInstallSpec, ProgressCallback, SystemPackageManager, elevated_command,
+    rpm_installed_version, run_with_optional_progress,
+};
+use crate::{Result, SystemPmError};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+/// DNF package manager (Fedora/RHEL/CentOS Stream)
+pub struct DnfManager {
+    /// Optional progress callback
+    progress_callback: Option<ProgressCallback>,
+}
+
+impl DnfManager {
+    /// Create a new DNF manager
+    pub fn new() -> Self {
+        Self {
+            progress_callback: None,
+        }
+    }
+
+    /// Create a DNF manager with progress callback
+    pub fn with_progress<F>(callback: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        Self {
+            progress_callback: Some(Arc::new(callback)),
+        }
+    }
+
+    /// Report progress through callback
+    fn report_progress(&self, message: &str) {
+        if let Some(ref callback) = self.progress_callback {
+            callback(message);
+        }
+    }
+
+    /// Run a dnf command (with sudo if needed)
+    fn run_dnf(&self, args: &[&str]) -> std::io::Result<std::process::Output> {
+        let mut cmd = elevated_command("dnf");
+        cmd.args(args);
+        run_with_optional_progress(cmd, self.progress_callback.as_ref())
+    }
+}
+
+impl Default for DnfManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SystemPackageManager for DnfManager {
+    fn name(&self) -> &str {
+        "dnf"
+    }
+
+    fn supported_platforms(&self) -> Vec<&str> {
+        vec!["linux"]
+    }
+
+    async fn is_installed(&self) -> bool {
+        which::which("dnf").is_ok()
+    }
+
+    async fn install_self(&self) -> Result<()> {
+        Err(SystemPmError::Other(anyhow::anyhow!(
+            "DNF is not available. This system is not Fedora/RHEL based."
+        )))
+    }
+
+    async fn install_package(&self, spec: &PackageInstallSpec) -> Result<InstallResult> {
+        if !self.is_installed().await {
+            return Err(SystemPmError::PackageManagerNotInstalled("dnf".to_string()));
+        }
+
+        let package = match &spec.version {
+            Some(version) => format!("{}-{}", spec.package, version),
+            None => spec.package.clone(),
+        };
+        let args = vec!["install", "-y", "-q", &package];
+
+        debug!("Running: dnf {}", args.join(" "));
+        self.report_progress(&format!("Installing {} via dnf...", spec.package));
+
+        let output = self.run_dnf(&args)?;
+        if output.status.success() {
+            info!("Package {} installed successfully", spec.package);
+            self.report_progress(&format!("{} installed successfully", spec.package));
+
+            let version = self.get_installed_version(&spec.package).await?;
+            Ok(InstallResult::success()
+                .with_version(version.unwrap_or_else(|| "unknown".to_string())))
+        } else {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            warn!("Failed to install {}: {}", spec.package, stderr);
+            Err(SystemPmError::InstallationFailed {
+                package: spec.package.clone(),
+                reason: format!("{}\n{}", stdout, stderr),
+            })
+        }
+    }
+
+    async fn uninstall_package(&self, package: &str) -> Result<()> {
+        if !self.is_installed().await {
+            return Err(SystemPmError::PackageManagerNotInstalled("dnf".to_string()));
+        }
+
+        let output = self.run_dnf(&["remove", "-y", "-q", package])?;
+        if output.status.success() {
+            info!("Package {} uninstalled successfully", package);
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(SystemPmError::CommandFailed(format!(
+                "Failed to uninstall {}: {}",
+                package, stderr
+            )))
+        }
+    }
+
+    async fn is_package_installed(&self, package: &str) -> Result<bool> {
+        if !self.is_installed().await {
+            return Ok(false);
+        }
+        Ok(rpm_installed_version(package).is_some())
+    }
+
+    async fn get_installed_version(&self, package: &str) -> Result<Option<String>> {
+        if !self.is_installed().await {
+            return Ok(None);
+        }
+        Ok(rpm_installed_version(package))
+    }
+
+    fn priority(&self) -> i32 {
+        90
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dnf_manager_creation() {
+        let manager = DnfManager::new();
+        assert_eq!(manager.name(), "dnf");
+        assert_eq!(manager.supported_platforms(), vec!["linux"]);
+        assert_eq!(manager.priority(), 90);
+    }
+}
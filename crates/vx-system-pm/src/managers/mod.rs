@@ -7,17 +7,27 @@ use std::process::{Command, Output, Stdio};
 use std::sync::{Arc, Mutex, mpsc};
 use std::thread;
 
+pub mod apk;
 pub mod apt;
 pub mod chocolatey;
+pub mod dnf;
 pub mod homebrew;
+pub mod nix;
+pub mod pacman;
 pub mod scoop;
 pub mod winget;
+pub mod zypper;
 
+pub use apk::ApkManager;
 pub use apt::AptManager;
 pub use chocolatey::ChocolateyManager;
+pub use dnf::DnfManager;
 pub use homebrew::HomebrewManager;
+pub use nix::NixManager;
+pub use pacman::PacmanManager;
 pub use scoop::ScoopManager;
 pub use winget::WingetManager;
+pub use zypper::ZypperManager;
 
 /// Shared progress callback type for package managers
 pub type ProgressCallback = Arc<dyn Fn(&str) + Send + Sync>;
@@ -64,6 +74,41 @@ fn spawn_stream_reader<R: Read + Send + 'static>(
     })
 }
 
+/// Build a command for `program`, prefixed with `sudo` unless running as root
+pub(crate) fn elevated_command(program: &str) -> Command {
+    if crate::detector::is_elevated() {
+        Command::new(program)
+    } else {
+        let mut cmd = Command::new("sudo");
+        cmd.arg(program);
+        cmd
+    }
+}
+
+/// Run a command, streaming its output to the progress callback if one is set
+pub(crate) fn run_with_optional_progress(
+    mut cmd: Command,
+    progress_callback: Option<&ProgressCallback>,
+) -> std::io::Result<Output> {
+    match progress_callback {
+        Some(callback) => run_command_with_progress(cmd, callback),
+        None => cmd.output(),
+    }
+}
+
+/// Installed version of an RPM package (`version-release`), used by dnf and zypper
+pub(crate) fn rpm_installed_version(package: &str) -> Option<String> {
+    let output = Command::new("rpm")
+        .args(["-q", "--queryformat", "%{VERSION}-%{RELEASE}", package])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!version.is_empty()).then_some(version)
+}
+
 pub(crate) fn run_command_with_progress(
     mut cmd: Command,
     progress_callback: &ProgressCallback,
@@ -0,0 +1,249 @@
+//! Nix package manager implementation
+//!
+//! Packages are installed into the user's default profile with
+//! `nix profile install nixpkgs#<package>`, so no root access is needed.
+//! The `nix-command` and `flakes` experimental features are enabled per
+//! invocation, which works whether or not they are set in `nix.conf`.
+
+use super::{
+    InstallResult, PackageInstallSpec, ProgressCallback, SystemPackageManager,
+    run_with_optional_progress,
+};
+use crate::{Result, SystemPmError};
+use async_trait::async_trait;
+use std::process::Command;
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+/// Flake that package names are resolved against
+const NIXPKGS_FLAKE: &str = "nixpkgs";
+
+/// Nix package manager (profile installs, Linux and macOS)
+pub struct NixManager {
+    /// Optional progress callback
+    progress_callback: Option<ProgressCallback>,
+}
+
+impl NixManager {
+    /// Create a new Nix manager
+    pub fn new() -> Self {
+        Self {
+            progress_callback: None,
+        }
+    }
+
+    /// Create a Nix manager with progress callback
+    pub fn with_progress<F>(callback: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        Self {
+            progress_callback: Some(Arc::new(callback)),
+        }
+    }
+
+    /// Report progress through callback
+    fn report_progress(&self, message: &str) {
+        if let Some(ref callback) = self.progress_callback {
+            callback(message);
+        }
+    }
+
+    /// `nix` command with the experimental features it needs
+    fn nix_command() -> Command {
+        let mut cmd = Command::new("nix");
+        cmd.args(["--extra-experimental-features", "nix-command flakes"]);
+        cmd
+    }
+
+    /// Run a `nix profile` subcommand
+    fn run_profile(&self, args: &[&str]) -> std::io::Result<std::process::Output> {
+        let mut cmd = Self::nix_command();
+        cmd.arg("profile").args(args);
+        run_with_optional_progress(cmd, self.progress_callback.as_ref())
+    }
+
+    /// Version of `package` in the default profile, if installed
+    fn profile_version(&self, package: &str) -> Result<Option<String>> {
+        let output = Self::nix_command()
+            .args(["profile", "list", "--json"])
+            .output()?;
+        if !output.status.success() {
+            return Ok(None);
+        }
+        Ok(parse_profile_version(
+            &String::from_utf8_lossy(&output.stdout),
+            package,
+        ))
+    }
+}
+
+impl Default for NixManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Find `package` in `nix profile list --json` output and return its version
+///
+/// Newer Nix versions key `elements` by name; older ones use a list. The
+/// version is taken from the store path (`/nix/store/<hash>-<name>-<version>`)
+/// and is `"unknown"` when it cannot be derived.
+fn parse_profile_version(json: &str, package: &str) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_str(json).ok()?;
+    let elements = value.get("elements")?;
+
+    let attr_matches = |element: &serde_json::Value| {
+        element
+            .get("attrPath")
+            .and_then(|a| a.as_str())
+            .and_then(|a| a.rsplit('.').next())
+            == Some(package)
+    };
+    let element = match elements {
+        serde_json::Value::Object(map) => map
+            .get(package)
+            .or_else(|| map.values().find(|e| attr_matches(e)))?,
+        serde_json::Value::Array(list) => list.iter().find(|e| attr_matches(e))?,
+        _ => return None,
+    };
+
+    let version = element
+        .get("storePaths")
+        .and_then(|p| p.as_array())
+        .and_then(|paths| paths.first())
+        .and_then(|p| p.as_str())
+        .and_then(|path| {
+            let name = path.rsplit('/').next()?;
+            // Strip the store hash, then the package name
+            let (_, name) = name.split_once('-')?;
+            name.strip_prefix(package)?
+                .strip_prefix('-')
+                .map(str::to_string)
+        })
+        .filter(|v| !v.is_empty());
+
+    Some(version.unwrap_or_else(|| "unknown".to_string()))
+}
+
+#[async_trait]
+impl SystemPackageManager for NixManager {
+    fn name(&self) -> &str {
+        "nix"
+    }
+
+    fn supported_platforms(&self) -> Vec<&str> {
+        vec!["linux", "macos"]
+    }
+
+    async fn is_installed(&self) -> bool {
+        which::which("nix").is_ok()
+    }
+
+    async fn install_self(&self) -> Result<()> {
+        Err(SystemPmError::Other(anyhow::anyhow!(
+            "Nix is not installed. See https://nixos.org/download for installation instructions."
+        )))
+    }
+
+    async fn install_package(&self, spec: &PackageInstallSpec) -> Result<InstallResult> {
+        if !self.is_installed().await {
+            return Err(SystemPmError::PackageManagerNotInstalled("nix".to_string()));
+        }
+
+        // nixpkgs pins one version per package; `version` is not selectable here
+        let installable = format!("{}#{}", NIXPKGS_FLAKE, spec.package);
+        let args = vec!["install", installable.as_str()];
+
+        debug!("Running: nix profile {}", args.join(" "));
+        self.report_progress(&format!("Installing {} via nix...", spec.package));
+
+        let output = self.run_profile(&args)?;
+        if output.status.success() {
+            info!("Package {} installed successfully", spec.package);
+            self.report_progress(&format!("{} installed successfully", spec.package));
+
+            let version = self.profile_version(&spec.package)?;
+            Ok(InstallResult::success()
+                .with_version(version.unwrap_or_else(|| "unknown".to_string())))
+        } else {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            warn!("Failed to install {}: {}", spec.package, stderr);
+            Err(SystemPmError::InstallationFailed {
+                package: spec.package.clone(),
+                reason: format!("{}\n{}", stdout, stderr),
+            })
+        }
+    }
+
+    async fn uninstall_package(&self, package: &str) -> Result<()> {
+        if !self.is_installed().await {
+            return Err(SystemPmError::PackageManagerNotInstalled("nix".to_string()));
+        }
+
+        let output = self.run_profile(&["remove", package])?;
+        if output.status.success() {
+            info!("Package {} uninstalled successfully", package);
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(SystemPmError::CommandFailed(format!(
+                "Failed to uninstall {}: {}",
+                package, stderr
+            )))
+        }
+    }
+
+    async fn is_package_installed(&self, package: &str) -> Result<bool> {
+        Ok(self.get_installed_version(package).await?.is_some())
+    }
+
+    async fn get_installed_version(&self, package: &str) -> Result<Option<String>> {
+        if !self.is_installed().await {
+            return Ok(None);
+        }
+        self.profile_version(package)
+    }
+
+    fn priority(&self) -> i32 {
+        // User-level installs: prefer the distribution package manager
+        60
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nix_manager_creation() {
+        let manager = NixManager::new();
+        assert_eq!(manager.name(), "nix");
+        assert_eq!(manager.supported_platforms(), vec!["linux", "macos"]);
+        assert_eq!(manager.priority(), 60);
+    }
+
+    #[test]
+    fn test_parse_profile_version_keyed_elements() {
+        let json = r#"{"elements": {"ripgrep": {
+            "attrPath": "legacyPackages.x86_64-linux.ripgrep",
+            "storePaths": ["/nix/store/abc123-ripgrep-14.1.0"]}}, "version": 3}"#;
+        assert_eq!(
+            parse_profile_version(json, "ripgrep"),
+            Some("14.1.0".to_string())
+        );
+        assert_eq!(parse_profile_version(json, "fd"), None);
+    }
+
+    #[test]
+    fn test_parse_profile_version_list_elements() {
+        let json = r#"{"elements": [{
+            "attrPath": "legacyPackages.aarch64-darwin.openssl",
+            "storePaths": ["/nix/store/xyz-openssl-3.0.14-bin"]}], "version": 2}"#;
+        assert_eq!(
+            parse_profile_version(json, "openssl"),
+            Some("3.0.14-bin".to_string())
+        );
+    }
+}
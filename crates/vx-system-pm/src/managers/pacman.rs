@@ -0,0 +1,185 @@
+//! Pacman package manager implementation
+
+use super::{
+    InstallResult, PackageInstallSpec, ProgressCallback, SystemPackageManager, elevated_command,
+    run_with_optional_progress,
+};
+use crate::{Result, SystemPmError};
+use async_trait::async_trait;
+use std::process::Command;
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+/// Pacman package manager (Arch Linux/Manjaro)
+pub struct PacmanManager {
+    /// Optional progress callback
+    progress_callback: Option<ProgressCallback>,
+}
+
+impl PacmanManager {
+    /// Create a new Pacman manager
+    pub fn new() -> Self {
+        Self {
+            progress_callback: None,
+        }
+    }
+
+    /// Create a Pacman manager with progress callback
+    pub fn with_progress<F>(callback: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        Self {
+            progress_callback: Some(Arc::new(callback)),
+        }
+    }
+
+    /// Report progress through callback
+    fn report_progress(&self, message: &str) {
+        if let Some(ref callback) = self.progress_callback {
+            callback(message);
+        }
+    }
+
+    /// Run a pacman command (with sudo if needed)
+    fn run_pacman(&self, args: &[&str]) -> std::io::Result<std::process::Output> {
+        let mut cmd = elevated_command("pacman");
+        cmd.args(args);
+        run_with_optional_progress(cmd, self.progress_callback.as_ref())
+    }
+}
+
+impl Default for PacmanManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parse the version from `pacman -Q <package>` output (`<name> <version>`)
+fn parse_query_version(output: &str) -> Option<String> {
+    output
+        .lines()
+        .next()?
+        .split_whitespace()
+        .nth(1)
+        .map(str::to_string)
+}
+
+#[async_trait]
+impl SystemPackageManager for PacmanManager {
+    fn name(&self) -> &str {
+        "pacman"
+    }
+
+    fn supported_platforms(&self) -> Vec<&str> {
+        vec!["linux"]
+    }
+
+    async fn is_installed(&self) -> bool {
+        which::which("pacman").is_ok()
+    }
+
+    async fn install_self(&self) -> Result<()> {
+        Err(SystemPmError::Other(anyhow::anyhow!(
+            "Pacman is not available. This system is not Arch Linux based."
+        )))
+    }
+
+    async fn install_package(&self, spec: &PackageInstallSpec) -> Result<InstallResult> {
+        if !self.is_installed().await {
+            return Err(SystemPmError::PackageManagerNotInstalled(
+                "pacman".to_string(),
+            ));
+        }
+
+        // Pacman only installs the repository version. `-S` without `-y`
+        // uses the current package database, avoiding a partial upgrade.
+        let args = vec!["-S", "--needed", "--noconfirm", &spec.package];
+
+        debug!("Running: pacman {}", args.join(" "));
+        self.report_progress(&format!("Installing {} via pacman...", spec.package));
+
+        let output = self.run_pacman(&args)?;
+        if output.status.success() {
+            info!("Package {} installed successfully", spec.package);
+            self.report_progress(&format!("{} installed successfully", spec.package));
+
+            let version = self.get_installed_version(&spec.package).await?;
+            Ok(InstallResult::success()
+                .with_version(version.unwrap_or_else(|| "unknown".to_string())))
+        } else {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            warn!("Failed to install {}: {}", spec.package, stderr);
+            Err(SystemPmError::InstallationFailed {
+                package: spec.package.clone(),
+                reason: format!("{}\n{}", stdout, stderr),
+            })
+        }
+    }
+
+    async fn uninstall_package(&self, package: &str) -> Result<()> {
+        if !self.is_installed().await {
+            return Err(SystemPmError::PackageManagerNotInstalled(
+                "pacman".to_string(),
+            ));
+        }
+
+        let output = self.run_pacman(&["-R", "--noconfirm", package])?;
+        if output.status.success() {
+            info!("Package {} uninstalled successfully", package);
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(SystemPmError::CommandFailed(format!(
+                "Failed to uninstall {}: {}",
+                package, stderr
+            )))
+        }
+    }
+
+    async fn is_package_installed(&self, package: &str) -> Result<bool> {
+        Ok(self.get_installed_version(package).await?.is_some())
+    }
+
+    async fn get_installed_version(&self, package: &str) -> Result<Option<String>> {
+        if !self.is_installed().await {
+            return Ok(None);
+        }
+
+        let output = Command::new("pacman").args(["-Q", package]).output()?;
+        if output.status.success() {
+            Ok(parse_query_version(&String::from_utf8_lossy(
+                &output.stdout,
+            )))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        90
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pacman_manager_creation() {
+        let manager = PacmanManager::new();
+        assert_eq!(manager.name(), "pacman");
+        assert_eq!(manager.supported_platforms(), vec!["linux"]);
+        assert_eq!(manager.priority(), 90);
+    }
+
+    #[test]
+    fn test_parse_query_version() {
+        assert_eq!(
+            parse_query_version("openssl 3.3.1-1\n"),
+            Some("3.3.1-1".to_string())
+        );
+        assert_eq!(parse_query_version(""), None);
+    }
+}
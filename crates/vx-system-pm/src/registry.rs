@@ -1,7 +1,8 @@
 //! Package manager registry
 
 use crate::managers::{
-    AptManager, ChocolateyManager, HomebrewManager, SystemPackageManager, WingetManager,
+    ApkManager, AptManager, ChocolateyManager, DnfManager, HomebrewManager, NixManager,
+    PacmanManager, SystemPackageManager, WingetManager, ZypperManager,
 };
 use crate::{Result, SystemPmError};
 use std::collections::HashMap;
@@ -24,6 +25,11 @@ impl PackageManagerRegistry {
         registry.register(Arc::new(WingetManager::new()));
         registry.register(Arc::new(HomebrewManager::new()));
         registry.register(Arc::new(AptManager::new()));
+        registry.register(Arc::new(DnfManager::new()));
+        registry.register(Arc::new(PacmanManager::new()));
+        registry.register(Arc::new(ZypperManager::new()));
+        registry.register(Arc::new(ApkManager::new()));
+        registry.register(Arc::new(NixManager::new()));
 
         registry
     }
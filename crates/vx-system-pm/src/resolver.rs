@@ -1,11 +1,12 @@
 //! System dependency resolver
 
-use crate::Result;
 use crate::dependency::{SystemDepType, SystemDependency};
 use crate::detector::PackageManagerDetector;
+use crate::managers::{InstallResult, PackageInstallSpec};
 use crate::registry::PackageManagerRegistry;
 use crate::strategy::InstallStrategy;
-use tracing::{debug, warn};
+use crate::{Result, SystemPmError};
+use tracing::{debug, info, warn};
 
 /// Dependency resolution result
 #[derive(Debug)]
@@ -161,6 +162,43 @@ impl SystemDependencyResolver {
         })
     }
 
+    /// Install a resolved dependency
+    ///
+    /// Only package manager strategies are installed automatically; direct
+    /// downloads and scripts are returned as errors describing the manual step.
+    pub async fn install(&self, resolved: &ResolvedDependency) -> Result<InstallResult> {
+        match &resolved.strategy {
+            InstallStrategy::PackageManager {
+                manager,
+                package,
+                params,
+                install_args,
+                ..
+            } => {
+                let pm = self.registry.get(manager)?;
+                if !pm.is_installed().await {
+                    return Err(SystemPmError::PackageManagerNotInstalled(manager.clone()));
+                }
+
+                let mut spec = PackageInstallSpec::new(package);
+                spec.params = params.clone();
+                spec.install_args = install_args.clone();
+
+                info!("Installing {} via {}", package, manager);
+                pm.install_package(&spec).await
+            }
+            InstallStrategy::DirectDownload { url, .. } | InstallStrategy::Script { url, .. } => {
+                Err(SystemPmError::InstallFailed(format!(
+                    "{} must be installed manually from {}",
+                    resolved.dep.id, url
+                )))
+            }
+            InstallStrategy::ProvidedBy { provider, .. } => Err(SystemPmError::InstallFailed(
+                format!("{} is provided by {}", resolved.dep.id, provider),
+            )),
+        }
+    }
+
     /// Check if a dependency is installed
     async fn check_installed(&self, dep: &SystemDependency) -> Result<InstallStatus> {
         match dep.dep_type {
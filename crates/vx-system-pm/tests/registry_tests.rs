@@ -11,6 +11,11 @@ fn test_registry_creation() {
     assert!(registry.get("winget").is_ok());
     assert!(registry.get("brew").is_ok());
     assert!(registry.get("apt").is_ok());
+    assert!(registry.get("dnf").is_ok());
+    assert!(registry.get("pacman").is_ok());
+    assert!(registry.get("zypper").is_ok());
+    assert!(registry.get("apk").is_ok());
+    assert!(registry.get("nix").is_ok());
 }
 
 #[test]
@@ -1,6 +1,8 @@
 //! Tests for SystemDependencyResolver
 
-use vx_system_pm::{SystemDepType, SystemDependency, SystemDependencyResolver};
+use vx_system_pm::{
+    InstallStrategy, ResolvedDependency, SystemDepType, SystemDependency, SystemDependencyResolver,
+};
 
 #[tokio::test]
 async fn test_resolver_creation() {
@@ -38,6 +40,35 @@ async fn test_resolve_platform_filtered() {
     assert!(result.unresolved.is_empty());
 }
 
+#[tokio::test]
+async fn test_install_direct_download_is_manual() {
+    let resolver = SystemDependencyResolver::new();
+    let resolved = ResolvedDependency {
+        dep: SystemDependency::new(SystemDepType::VcRedist, "vcredist140"),
+        strategy: InstallStrategy::direct_download(
+            "https://aka.ms/vs/17/release/vc_redist.x64.exe",
+        ),
+        installed_version: None,
+    };
+
+    let err = resolver.install(&resolved).await.unwrap_err();
+    assert!(err.to_string().contains("must be installed manually"));
+    assert!(err.to_string().contains("vc_redist.x64.exe"));
+}
+
+#[tokio::test]
+async fn test_install_unknown_manager() {
+    let resolver = SystemDependencyResolver::new();
+    let resolved = ResolvedDependency {
+        dep: SystemDependency::new(SystemDepType::Package, "libssl-dev"),
+        strategy: InstallStrategy::package_manager("no-such-pm", "libssl-dev"),
+        installed_version: None,
+    };
+
+    let err = resolver.install(&resolved).await.unwrap_err();
+    assert!(err.to_string().contains("no-such-pm"));
+}
+
 #[test]
 fn test_system_dependency_builder() {
     let dep = SystemDependency::new(SystemDepType::VcRedist, "vcredist140")
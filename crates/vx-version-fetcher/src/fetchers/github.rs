@@ -9,6 +9,9 @@ use crate::utils::version_utils;
 use async_trait::async_trait;
 use vx_versions::{FetchContext, VersionInfo};
 
+/// Number of release-body lines kept as the release notes summary
+const RELEASE_NOTES_LINES: usize = 5;
+
 /// Configuration for GitHub Releases fetcher
 #[derive(Debug, Clone)]
 pub struct GitHubReleasesConfig {
@@ -233,7 +236,23 @@ impl GitHubReleasesFetcher {
                         .get("prerelease")
                         .and_then(|p| p.as_bool())
                         .unwrap_or(false);
-                    self.parse_version(tag_name, is_prerelease)
+                    let mut info = self.parse_version(tag_name, is_prerelease)?;
+                    // Keep release-note metadata so `vx update` can show changelogs
+                    // without a second round of API calls.
+                    if let Some(url) = release.get("html_url").and_then(|u| u.as_str()) {
+                        info = info.with_release_url(url);
+                    }
+                    if let Some(notes) =
+                        release.get("body").and_then(|b| b.as_str()).and_then(|b| {
+                            version_utils::summarize_release_notes(b, RELEASE_NOTES_LINES)
+                        })
+                    {
+                        info = info.with_release_notes(notes);
+                    }
+                    if let Some(date) = release.get("published_at").and_then(|d| d.as_str()) {
+                        info = info.with_release_date(date);
+                    }
+                    Some(info)
                 })
                 .collect();
 
@@ -301,7 +320,70 @@ pub struct GitHubAssetInfo {
     pub browser_download_url: String,
 }
 
+/// Release information from a GitHub release, including the full body
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct GitHubReleaseInfo {
+    pub tag_name: String,
+    pub name: Option<String>,
+    pub body: Option<String>,
+    pub html_url: Option<String>,
+    pub published_at: Option<String>,
+    pub prerelease: bool,
+}
+
 impl GitHubReleasesFetcher {
+    /// Fetch one page of releases (newest first).
+    ///
+    /// Calls `GET /repos/{owner}/{repo}/releases?per_page=100&page={page}`
+    /// and keeps the full release body, unlike [`VersionFetcher::fetch`]
+    /// which only keeps a summary.
+    pub async fn fetch_releases(
+        owner: &str,
+        repo: &str,
+        page: usize,
+        ctx: &dyn FetchContext,
+    ) -> FetchResult<Vec<GitHubReleaseInfo>> {
+        let url = format!(
+            "https://api.github.com/repos/{}/{}/releases?per_page=100&page={}",
+            owner, repo, page
+        );
+
+        tracing::debug!(owner = %owner, repo = %repo, page = %page, "Fetching releases");
+
+        let response = ctx
+            .get_json_value(&url)
+            .await
+            .map_err(|e| FetchError::network(e.to_string()))?;
+
+        let releases = response
+            .as_array()
+            .ok_or_else(|| FetchError::invalid_format("GitHub", "Expected array of releases"))?;
+
+        let text = |r: &serde_json::Value, key: &str| {
+            r.get(key)
+                .and_then(|v| v.as_str())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+        };
+
+        Ok(releases
+            .iter()
+            .filter_map(|r| {
+                Some(GitHubReleaseInfo {
+                    tag_name: r.get("tag_name")?.as_str()?.to_string(),
+                    name: text(r, "name"),
+                    body: text(r, "body"),
+                    html_url: text(r, "html_url"),
+                    published_at: text(r, "published_at"),
+                    prerelease: r
+                        .get("prerelease")
+                        .and_then(|p| p.as_bool())
+                        .unwrap_or(false),
+                })
+            })
+            .collect())
+    }
+
     /// Fetch assets for a specific release tag.
     ///
     /// Calls `GET /repos/{owner}/{repo}/releases/tags/{tag}` and extracts
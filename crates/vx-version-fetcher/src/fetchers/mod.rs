@@ -7,7 +7,7 @@ pub mod npm;
 pub mod pypi;
 
 pub use custom::CustomApiFetcher;
-pub use github::{GitHubAssetInfo, GitHubReleasesConfig, GitHubReleasesFetcher};
+pub use github::{GitHubAssetInfo, GitHubReleaseInfo, GitHubReleasesConfig, GitHubReleasesFetcher};
 pub use jsdelivr::{JsDelivrConfig, JsDelivrFetcher};
 pub use npm::{NpmConfig, NpmFetcher};
 pub use pypi::{PyPiConfig, PyPiFetcher};
@@ -139,6 +139,51 @@ pub mod version_utils {
         core_vu::compare_versions_str(a, b).unwrap_or(Ordering::Equal)
     }
 
+    /// Reduce a release body to a short summary for changelog listings.
+    ///
+    /// Keeps the first `max_lines` non-empty lines, dropping HTML comments and
+    /// blank headings. Returns `None` when nothing meaningful remains.
+    ///
+    /// # Examples
+    /// ```
+    /// use vx_version_fetcher::version_utils::summarize_release_notes;
+    /// let body = "<!-- generated -->\n## What's Changed\n\n* Fix crash\n* Faster startup\n* Docs";
+    /// assert_eq!(
+    ///     summarize_release_notes(body, 2).as_deref(),
+    ///     Some("## What's Changed\n* Fix crash")
+    /// );
+    /// assert_eq!(summarize_release_notes("  \n<!-- x -->", 3), None);
+    /// ```
+    pub fn summarize_release_notes(body: &str, max_lines: usize) -> Option<String> {
+        const MAX_LINE_LEN: usize = 200;
+
+        let lines: Vec<String> = body
+            .lines()
+            .map(str::trim_end)
+            .filter(|line| {
+                let trimmed = line.trim();
+                !trimmed.is_empty()
+                    && !trimmed.starts_with("<!--")
+                    && trimmed.trim_start_matches('#').trim() != ""
+            })
+            .take(max_lines)
+            .map(|line| {
+                if line.chars().count() > MAX_LINE_LEN {
+                    let cut: String = line.chars().take(MAX_LINE_LEN).collect();
+                    format!("{}…", cut)
+                } else {
+                    line.to_string()
+                }
+            })
+            .collect();
+
+        if lines.is_empty() {
+            None
+        } else {
+            Some(lines.join("\n"))
+        }
+    }
+
     /// Filter and transform raw version strings with common operations:
     /// strip prefix, skip prereleases, validate semver.
     pub fn filter_versions<'a>(
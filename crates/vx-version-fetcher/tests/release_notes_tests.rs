@@ -0,0 +1,61 @@
+//! Tests for release-note metadata captured by `GitHubReleasesFetcher`
+
+use anyhow::Result;
+use vx_version_fetcher::VersionFetcher;
+use vx_version_fetcher::fetchers::GitHubReleasesFetcher;
+use vx_versions::FetchContext;
+
+/// Serves a single page of GitHub releases
+struct StaticReleases(serde_json::Value);
+
+#[async_trait::async_trait]
+impl FetchContext for StaticReleases {
+    async fn get_json_value(&self, url: &str) -> Result<serde_json::Value> {
+        if url.ends_with("page=1") {
+            Ok(self.0.clone())
+        } else {
+            Ok(serde_json::json!([]))
+        }
+    }
+}
+
+#[test]
+fn test_github_releases_keep_release_notes() {
+    let ctx = StaticReleases(serde_json::json!([
+        {
+            "tag_name": "v1.2.0",
+            "prerelease": false,
+            "html_url": "https://github.com/owner/tool/releases/tag/v1.2.0",
+            "published_at": "2024-05-01T12:00:00Z",
+            "body": "## Highlights\r\n\r\n* Faster startup\r\n* New `--json` flag\r\n",
+            "assets": [{ "name": "tool.tar.gz" }]
+        },
+        {
+            "tag_name": "v1.1.0",
+            "prerelease": false,
+            "body": "",
+            "assets": [{ "name": "tool.tar.gz" }]
+        }
+    ]));
+
+    let fetcher = GitHubReleasesFetcher::new("owner", "tool");
+    let versions = tokio_test::block_on(fetcher.fetch(&ctx)).unwrap();
+
+    assert_eq!(versions[0].version, "1.2.0");
+    assert_eq!(
+        versions[0].release_url(),
+        Some("https://github.com/owner/tool/releases/tag/v1.2.0")
+    );
+    assert_eq!(
+        versions[0].release_notes(),
+        Some("## Highlights\n* Faster startup\n* New `--json` flag")
+    );
+    assert_eq!(
+        versions[0].metadata.get("release_date").map(String::as_str),
+        Some("2024-05-01T12:00:00Z")
+    );
+
+    assert_eq!(versions[1].version, "1.1.0");
+    assert_eq!(versions[1].release_url(), None);
+    assert_eq!(versions[1].release_notes(), None);
+}
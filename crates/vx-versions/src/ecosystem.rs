@@ -42,7 +42,7 @@ pub enum Ecosystem {
     Ai,
     /// C++ ecosystem (vcpkg, cmake, meson)
     Cpp,
-    /// Zig ecosystem (zig toolchain)
+    /// Zig ecosystem (zig, zls)
     Zig,
     /// System tools (not tied to a specific language)
     #[default]
@@ -193,7 +193,7 @@ impl Ecosystem {
                 matches!(name, "dotnet" | "dotnet-sdk" | "nuget" | "msbuild")
             }
             Self::Zig => {
-                matches!(name, "zig")
+                matches!(name, "zig" | "zls")
             }
             _ => false,
         }
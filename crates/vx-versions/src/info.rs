@@ -71,6 +71,22 @@ impl VersionInfo {
         self
     }
 
+    /// Set the release page URL
+    pub fn with_release_url(mut self, url: impl Into<String>) -> Self {
+        self.metadata.insert("release_url".to_string(), url.into());
+        self
+    }
+
+    /// Release notes summary, if the source provided one
+    pub fn release_notes(&self) -> Option<&str> {
+        self.metadata.get("release_notes").map(String::as_str)
+    }
+
+    /// Release page URL, if the source provided one
+    pub fn release_url(&self) -> Option<&str> {
+        self.metadata.get("release_url").map(String::as_str)
+    }
+
     /// Add metadata
     pub fn with_metadata(mut self, key: String, value: String) -> Self {
         self.metadata.insert(key, value);
@@ -7,6 +7,7 @@
 //! - [`VersionConstraint`]: Version constraint types (exact, range, caret, tilde, etc.)
 //! - [`VersionResolver`]: Resolves version strings against available versions
 //! - [`VersionCache`]: High-performance bincode-based version cache
+//! - [`VersionNormalizer`]: Provider rules for displaying and comparing versions
 //!
 //! # Dependency Direction
 //!
@@ -23,6 +24,7 @@ pub mod cache;
 pub mod ecosystem;
 pub mod fetch_context;
 pub mod info;
+pub mod normalize;
 pub mod resolver;
 // resolver/ directory contains: mod.rs, core.rs, nodejs.rs, python.rs, rust_eco.rs, opaque.rs
 
@@ -34,6 +36,7 @@ pub use cache::{
 pub use ecosystem::Ecosystem;
 pub use fetch_context::FetchContext;
 pub use info::VersionInfo;
+pub use normalize::{BuildMetadata, VersionNormalizer};
 pub use resolver::{
     RangeConstraint, RangeOp, Version, VersionConstraint, VersionRequest, VersionResolver,
 };
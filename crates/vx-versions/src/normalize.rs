@@ -0,0 +1,109 @@
+//! Version display normalization
+//!
+//! Providers spell the same release differently: GitHub tags carry `v1.2.3`,
+//! bun tags `bun-v1.2.3`, jq tags `jq-1.7`, and some releases append build
+//! metadata (`1.2.3+build.5`). [`VersionNormalizer`] turns these into the one
+//! form vx shows in `vx list`, compares installed against available versions
+//! with, and writes to `vx.lock`.
+//!
+//! Providers declare their rules in `provider.star`:
+//!
+//! ```python
+//! version_format = {
+//!     "strip_prefix": "bun-v",     # in addition to "v"
+//!     "build_metadata": "strip",   # "keep" (default) or "strip"
+//! }
+//! ```
+
+/// Prefixes stripped for every provider
+const DEFAULT_PREFIXES: &[&str] = &["v"];
+
+/// What to do with build metadata (`+...`) at the end of a version
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BuildMetadata {
+    /// Keep it: `1.2.3+build.5` stays as is
+    #[default]
+    Keep,
+    /// Drop it: `1.2.3+build.5` becomes `1.2.3`
+    Strip,
+}
+
+/// Normalization rules for the versions of one provider
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionNormalizer {
+    /// Prefixes to strip, longest first
+    prefixes: Vec<String>,
+    /// Build metadata handling
+    build_metadata: BuildMetadata,
+}
+
+impl Default for VersionNormalizer {
+    fn default() -> Self {
+        Self {
+            prefixes: DEFAULT_PREFIXES.iter().map(|p| p.to_string()).collect(),
+            build_metadata: BuildMetadata::Keep,
+        }
+    }
+}
+
+impl VersionNormalizer {
+    /// Rules that only strip the `v` prefix
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also strip `prefix` (e.g. `bun-v`)
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        let prefix = prefix.into();
+        if !prefix.is_empty() && !self.prefixes.contains(&prefix) {
+            self.prefixes.push(prefix);
+            self.prefixes.sort_by_key(|p| std::cmp::Reverse(p.len()));
+        }
+        self
+    }
+
+    /// Set the build metadata handling
+    pub fn with_build_metadata(mut self, build_metadata: BuildMetadata) -> Self {
+        self.build_metadata = build_metadata;
+        self
+    }
+
+    /// Rules from the `(key, value)` pairs of a provider's `version_format`
+    ///
+    /// Unknown keys and values are ignored.
+    pub fn from_pairs(pairs: &[(String, String)]) -> Self {
+        pairs
+            .iter()
+            .fold(Self::new(), |rules, (key, value)| match key.as_str() {
+                "strip_prefix" => rules.with_prefix(value.as_str()),
+                "build_metadata" if value == "strip" => {
+                    rules.with_build_metadata(BuildMetadata::Strip)
+                }
+                _ => rules,
+            })
+    }
+
+    /// The normalized form of `version`
+    ///
+    /// A prefix is only stripped when a digit follows it, so channels and
+    /// names such as `latest` or `vnext` are left alone.
+    pub fn normalize(&self, version: &str) -> String {
+        let version = version.trim();
+        let version = self
+            .prefixes
+            .iter()
+            .filter_map(|prefix| version.strip_prefix(prefix.as_str()))
+            .find(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+            .unwrap_or(version);
+        let version = match self.build_metadata {
+            BuildMetadata::Keep => version,
+            BuildMetadata::Strip => version.split('+').next().unwrap_or(version),
+        };
+        version.to_string()
+    }
+
+    /// Whether `a` and `b` name the same version once normalized
+    pub fn same_version(&self, a: &str, b: &str) -> bool {
+        a == b || self.normalize(a) == self.normalize(b)
+    }
+}
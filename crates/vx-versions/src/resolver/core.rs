@@ -518,6 +518,12 @@ pub fn resolve_constraint(
     #[allow(clippy::unnecessary_sort_by)]
     all_versions.sort_by(|a, b| b.0.cmp(&a.0));
 
+    // `latest-prerelease` (Rust `nightly`, Zig `master`) is the newest entry,
+    // prerelease or not
+    if matches!(constraint, VersionConstraint::LatestPrerelease) {
+        return all_versions.first().map(|(_, v)| v.version.clone());
+    }
+
     let stable_versions: Vec<_> = all_versions
         .iter()
         .filter(|(parsed, info)| {
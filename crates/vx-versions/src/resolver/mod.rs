@@ -19,6 +19,7 @@
 //! - **Python**: PEP 440 `~=` compatible release
 //! - **Go**: `go` prefix stripping
 //! - **Rust**: `stable`, `beta`, `nightly` aliases
+//! - **Zig**: `master`, `nightly` aliases for development builds
 //! - **System/opaque**: non-numeric versions (e.g. `system`)
 //! - **Generic**: standard semver
 
@@ -27,6 +28,7 @@ mod nodejs;
 mod opaque;
 mod python;
 mod rust_eco;
+mod zig;
 
 // Re-export all public types from core
 pub use core::{RangeConstraint, RangeOp, Version, VersionConstraint, VersionRequest};
@@ -72,6 +74,7 @@ impl VersionResolver {
             Ecosystem::NodeJs => nodejs::resolve(self, version_str, available),
             Ecosystem::Python => python::resolve(self, version_str, available),
             Ecosystem::Rust => rust_eco::resolve(self, version_str, available),
+            Ecosystem::Zig => zig::resolve(self, version_str, available),
             Ecosystem::System => opaque::resolve(self, version_str, available),
             _ => {
                 // Generic semver resolution for Go, Java, Dotnet, Git, Generic, etc.
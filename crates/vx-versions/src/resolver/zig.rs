@@ -0,0 +1,27 @@
+//! Zig ecosystem version resolution
+//!
+//! Special handling:
+//! - `master` / `nightly` / `dev` → latest development build
+//!   (e.g. `0.15.0-dev.847+850655f06`, listed as a prerelease)
+//! - `0.14.1` → exact version (standard semver)
+
+use super::VersionResolver;
+use super::core::{self, VersionConstraint};
+use crate::VersionInfo;
+
+/// Resolve a version string for the Zig ecosystem.
+pub fn resolve(
+    resolver: &VersionResolver,
+    version_str: &str,
+    available: &[VersionInfo],
+) -> Option<String> {
+    let trimmed = version_str.trim();
+
+    let lower = trimmed.to_lowercase();
+    let constraint = match lower.as_str() {
+        "master" | "nightly" | "dev" => VersionConstraint::LatestPrerelease,
+        _ => core::parse_constraint(trimmed),
+    };
+
+    resolver.resolve_constraint(&constraint, available)
+}
@@ -0,0 +1,64 @@
+use rstest::rstest;
+use vx_versions::{BuildMetadata, VersionNormalizer};
+
+fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[rstest]
+#[case("v1.2.3", "1.2.3")]
+#[case("1.2.3", "1.2.3")]
+#[case(" v20.10.0\n", "20.10.0")]
+#[case("latest", "latest")]
+#[case("vnext", "vnext")]
+#[case("1.2.3+build.5", "1.2.3+build.5")]
+fn test_default_rules(#[case] version: &str, #[case] expected: &str) {
+    assert_eq!(VersionNormalizer::new().normalize(version), expected);
+}
+
+#[rstest]
+#[case("bun-v1.1.0", "1.1.0")]
+#[case("v1.1.0", "1.1.0")]
+#[case("1.1.0", "1.1.0")]
+#[case("bun-vnext", "bun-vnext")]
+fn test_provider_prefix(#[case] version: &str, #[case] expected: &str) {
+    let rules = VersionNormalizer::new().with_prefix("bun-v");
+    assert_eq!(rules.normalize(version), expected);
+}
+
+#[rstest]
+fn test_build_metadata_strip() {
+    let rules = VersionNormalizer::new().with_build_metadata(BuildMetadata::Strip);
+    assert_eq!(rules.normalize("v1.2.3+build.5"), "1.2.3");
+    assert_eq!(rules.normalize("1.2.3-rc.1+abc"), "1.2.3-rc.1");
+}
+
+#[rstest]
+fn test_from_pairs() {
+    let rules = VersionNormalizer::from_pairs(&pairs(&[
+        ("strip_prefix", "jq-"),
+        ("build_metadata", "strip"),
+        ("unknown", "ignored"),
+    ]));
+    assert_eq!(
+        rules,
+        VersionNormalizer::new()
+            .with_prefix("jq-")
+            .with_build_metadata(BuildMetadata::Strip)
+    );
+    assert_eq!(rules.normalize("jq-1.7.1+1"), "1.7.1");
+
+    let keep = VersionNormalizer::from_pairs(&pairs(&[("build_metadata", "keep")]));
+    assert_eq!(keep, VersionNormalizer::new());
+}
+
+#[rstest]
+fn test_same_version() {
+    let rules = VersionNormalizer::new().with_prefix("bun-v");
+    assert!(rules.same_version("bun-v1.1.0", "1.1.0"));
+    assert!(rules.same_version("v1.1.0", "bun-v1.1.0"));
+    assert!(!rules.same_version("1.1.0", "1.1.1"));
+}
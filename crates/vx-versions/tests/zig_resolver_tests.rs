@@ -0,0 +1,25 @@
+//! Tests for the Zig ecosystem version resolver.
+
+use rstest::rstest;
+use vx_versions::{Ecosystem, VersionInfo, VersionResolver};
+
+fn zig_versions() -> Vec<VersionInfo> {
+    vec![
+        VersionInfo::new("0.15.0-dev.847+850655f06").with_prerelease(true),
+        VersionInfo::new("0.14.1"),
+        VersionInfo::new("0.14.0"),
+        VersionInfo::new("0.13.0"),
+    ]
+}
+
+#[rstest]
+#[case("master", "0.15.0-dev.847+850655f06")]
+#[case("nightly", "0.15.0-dev.847+850655f06")]
+#[case("latest", "0.14.1")]
+#[case("0.14", "0.14.1")]
+#[case("0.13.0", "0.13.0")]
+fn test_zig_resolver(#[case] input: &str, #[case] expected: &str) {
+    let resolver = VersionResolver::new();
+    let result = resolver.resolve(input, &zig_versions(), &Ecosystem::Zig);
+    assert_eq!(result, Some(expected.to_string()));
+}
@@ -22,6 +22,7 @@
 #   VX_RELEASE_BASE_URLS- Comma-separated release mirror URLs
 #   GITHUB_TOKEN        - GitHub API token to avoid rate limits
 #   VX_CDN              - Set to "1" to enable CDN acceleration (disabled by default)
+#   VX_NO_MODIFY_PATH   - Set to "1" to leave PATH unchanged
 
 param(
     [string]$Version         = $env:VX_VERSION,
@@ -231,6 +232,11 @@ function Main {
 
         Write-Ok "Installed: vx $installedVersion"
 
+        if ($env:VX_NO_MODIFY_PATH -eq "1") {
+            Write-Ok "vx installed to $InstallDir (PATH unchanged)"
+            return
+        }
+
         # Update PATH
         $currentPath = [Environment]::GetEnvironmentVariable("PATH", "User")
         if ($currentPath -notlike "*$InstallDir*") {
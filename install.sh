@@ -25,6 +25,7 @@
 #   VX_RELEASE_BASE_URLS- Comma-separated release mirror URLs
 #   GITHUB_TOKEN        - GitHub API token to avoid rate limits
 #   VX_CDN              - Set to "1" to enable CDN acceleration (disabled by default)
+#   VX_NO_MODIFY_PATH   - Set to "1" to leave PATH and shell profiles unchanged
 
 
 set -euo pipefail
@@ -320,6 +321,12 @@ main() {
 
     ok "Installed: vx $installed_version"
 
+    if [[ "${VX_NO_MODIFY_PATH:-}" == "1" ]]; then
+        echo "" >&2
+        ok "vx installed to $VX_INSTALL_DIR (PATH unchanged)"
+        return 0
+    fi
+
     # Update PATH
     local path_export="export PATH=\"$VX_INSTALL_DIR:\$PATH\""
     if [[ ":$PATH:" != *":$VX_INSTALL_DIR:"* ]]; then
@@ -126,6 +126,10 @@ mod thresholds {
     pub const CONFIG_VALIDATE_MS: u64 = 2000;
     #[cfg(not(windows))]
     pub const CONFIG_VALIDATE_MS: u64 = 1000;
+
+    /// Maximum time spent in vx before a shimmed executable is spawned
+    /// (VX_SHIM_TELEMETRY dispatch_us)
+    pub const SHIM_DISPATCH_MS: u64 = 500;
 }
 
 // ============================================
@@ -780,6 +784,90 @@ ports = ["{}:{}"]
     println!("bench_many_services_config: {}ms", duration.as_millis());
 }
 
+// ============================================
+// Shim Dispatch Performance Tests
+// ============================================
+
+#[cfg(unix)]
+#[test]
+fn bench_shim_dispatch_overhead() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let env = E2ETestEnv::new();
+
+    // A globally installed package providing `vx-bench-hello`
+    let install_dir = env.home.path().join("packages").join("bench-hello");
+    fs::create_dir_all(&install_dir).unwrap();
+    let exe = install_dir.join("vx-bench-hello");
+    fs::write(&exe, "#!/bin/sh\nexit 0\n").unwrap();
+    fs::set_permissions(&exe, fs::Permissions::from_mode(0o755)).unwrap();
+
+    let registry = serde_json::json!({
+        "packages": {
+            "go:bench-hello": {
+                "name": "bench-hello",
+                "version": "1.0.0",
+                "ecosystem": "go",
+                "installed_at": "2026-01-01T00:00:00Z",
+                "executables": ["vx-bench-hello"],
+                "install_dir": install_dir,
+            }
+        },
+        "executable_index": { "vx-bench-hello": "go:bench-hello" },
+    });
+    let config_dir = env.home.path().join("config");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(
+        config_dir.join("packages-registry.json"),
+        registry.to_string(),
+    )
+    .unwrap();
+
+    for _ in 0..3 {
+        let output = Command::new(vx_binary())
+            .arg("vx-bench-hello")
+            .env("VX_HOME", env.home.path())
+            .env("VX_SHIM_TELEMETRY", "1")
+            .current_dir(env.workdir.path())
+            .output()
+            .expect("Failed to execute vx command");
+        assert!(
+            output.status.success(),
+            "shim run failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let log = env
+        .home
+        .path()
+        .join("metrics")
+        .join("shim")
+        .join("invocations.jsonl");
+    let content = fs::read_to_string(&log).expect("shim telemetry was not recorded");
+    let mut dispatch_us: Vec<u64> = content
+        .lines()
+        .map(|line| {
+            let record: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(record["executable"], "vx-bench-hello");
+            record["dispatch_us"].as_u64().unwrap()
+        })
+        .collect();
+    assert_eq!(dispatch_us.len(), 3);
+
+    // The first run warms caches; judge the best of the rest
+    dispatch_us.remove(0);
+    let best_ms = dispatch_us.iter().min().unwrap() / 1000;
+    assert!(
+        best_ms < thresholds::SHIM_DISPATCH_MS,
+        "Shim dispatch took {}ms, expected < {}ms",
+        best_ms,
+        thresholds::SHIM_DISPATCH_MS
+    );
+
+    println!("bench_shim_dispatch_overhead: {}ms", best_ms);
+}
+
 // ============================================
 // Summary Report
 // ============================================
@@ -812,5 +900,6 @@ fn bench_summary_report() {
     );
     println!("  Script List: < {}ms", thresholds::SCRIPT_LIST_MS);
     println!("  Config Validate: < {}ms", thresholds::CONFIG_VALIDATE_MS);
+    println!("  Shim Dispatch: < {}ms", thresholds::SHIM_DISPATCH_MS);
     println!("========================================\n");
 }
@@ -0,0 +1,128 @@
+//! E2E tests for homes and projects whose paths contain spaces and non-ASCII
+//! characters (e.g. localized Windows user names)
+//!
+//! VX_HOME and the working directory are both placed under such a directory.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+/// Directory name mixing spaces, CJK, accents and a quote
+const SPECIAL_DIR: &str = "My Folder/用户 Jürgen's";
+
+/// Get the path to the vx binary for testing
+fn vx_binary() -> PathBuf {
+    let mut path = env::current_exe().unwrap();
+    path.pop(); // Remove test binary name
+    if path.ends_with("deps") {
+        path.pop(); // Remove deps directory
+    }
+    path.push("vx");
+    if cfg!(windows) {
+        path.set_extension("exe");
+    }
+    path
+}
+
+/// E2E test environment rooted in a directory with special characters
+struct E2ETestEnv {
+    _temp: TempDir,
+    home: PathBuf,
+    workdir: PathBuf,
+}
+
+impl E2ETestEnv {
+    fn new() -> Self {
+        let temp = TempDir::new().expect("Failed to create temp dir");
+        let root = temp.path().join(SPECIAL_DIR);
+        let home = root.join(".vx");
+        let workdir = root.join("project dir");
+        fs::create_dir_all(&home).unwrap();
+        fs::create_dir_all(&workdir).unwrap();
+        Self {
+            _temp: temp,
+            home,
+            workdir,
+        }
+    }
+
+    fn run(&self, args: &[&str]) -> std::process::Output {
+        Command::new(vx_binary())
+            .args(args)
+            .env("VX_HOME", &self.home)
+            .current_dir(&self.workdir)
+            .output()
+            .expect("Failed to execute vx command")
+    }
+
+    fn run_success(&self, args: &[&str]) -> String {
+        let output = self.run(args);
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        if !output.status.success() {
+            panic!(
+                "Command failed: vx {}\nstdout: {}\nstderr: {}",
+                args.join(" "),
+                stdout,
+                stderr
+            );
+        }
+        stdout
+    }
+
+    /// Register a globally installed package providing `exe_name`
+    fn register_package(&self, exe_name: &str, install_dir: &Path) {
+        let registry = serde_json::json!({
+            "packages": {
+                "go:special": {
+                    "name": "special",
+                    "version": "1.0.0",
+                    "ecosystem": "go",
+                    "installed_at": "2026-01-01T00:00:00Z",
+                    "executables": [exe_name],
+                    "install_dir": install_dir,
+                }
+            },
+            "executable_index": { exe_name: "go:special" },
+        });
+        let config_dir = self.home.join("config");
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(
+            config_dir.join("packages-registry.json"),
+            registry.to_string(),
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn test_list_with_special_home() {
+    let env = E2ETestEnv::new();
+    env.run_success(&["list"]);
+}
+
+#[test]
+fn test_init_in_special_project_dir() {
+    let env = E2ETestEnv::new();
+    env.run_success(&["init", "--force"]);
+    assert!(env.workdir.join("vx.toml").exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_package_shim_runs_from_special_home() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let env = E2ETestEnv::new();
+    let install_dir = env.home.join("packages").join("special tool");
+    fs::create_dir_all(&install_dir).unwrap();
+    let exe = install_dir.join("vx-special-echo");
+    fs::write(&exe, "#!/bin/sh\nprintf '%s|' \"$@\"\n").unwrap();
+    fs::set_permissions(&exe, fs::Permissions::from_mode(0o755)).unwrap();
+    env.register_package("vx-special-echo", &install_dir);
+
+    let stdout = env.run_success(&["vx-special-echo", "a b", "ü $x"]);
+    assert_eq!(stdout, "a b|ü $x|");
+}